@@ -0,0 +1,30 @@
+//! Packaging hook: with `TURFOPS_GEN_DIR` set at build time, writes bash, zsh
+//! and fish completions and the `turfops-backend.1` man page into that
+//! directory. Without it the build script does nothing.
+
+use std::path::PathBuf;
+
+#[allow(dead_code)]
+mod cli {
+    include!("src/cli/args.rs");
+}
+
+fn main() -> std::io::Result<()> {
+    println!("cargo:rerun-if-changed=src/cli/args.rs");
+    println!("cargo:rerun-if-env-changed=TURFOPS_GEN_DIR");
+    let Some(dir) = std::env::var_os("TURFOPS_GEN_DIR").map(PathBuf::from) else {
+        return Ok(());
+    };
+    std::fs::create_dir_all(&dir)?;
+
+    let mut command = <cli::Cli as clap::CommandFactory>::command();
+    for shell in [
+        clap_complete::Shell::Bash,
+        clap_complete::Shell::Zsh,
+        clap_complete::Shell::Fish,
+    ] {
+        clap_complete::generate_to(shell, &mut command, "turfops-backend", &dir)?;
+    }
+    let mut man = std::fs::File::create(dir.join("turfops-backend.1"))?;
+    clap_mangen::Man::new(command).render(&mut man)
+}
@@ -0,0 +1,126 @@
+use crate::db::{annual_review_queries, queries};
+use crate::error::TurfOpsError;
+use crate::logic::annual_review::{draft_application_reviews, merge_accepted};
+use crate::models::{AnnualReview, ApplicationReview, ReviewAdjustment, ReviewObservations};
+use crate::state::AppState;
+use axum::extract::{Path, State};
+use axum::Json;
+use chrono::{Datelike, Local, Utc};
+use serde::Deserialize;
+
+async fn active_profile_id(state: &AppState) -> Result<i64, TurfOpsError> {
+    let profile = queries::get_active_lawn_profile(&state.pool)
+        .await?
+        .ok_or_else(|| TurfOpsError::NotFound("No lawn profile found".into()))?;
+
+    profile
+        .id
+        .ok_or_else(|| TurfOpsError::InvalidData("Profile missing ID".into()))
+}
+
+fn validate_year(year: i32) -> Result<(), TurfOpsError> {
+    let current = Local::now().year();
+    if !(2000..=current).contains(&year) {
+        return Err(TurfOpsError::InvalidData(format!(
+            "Review year must be between 2000 and {}",
+            current
+        )));
+    }
+    Ok(())
+}
+
+/// GET /api/v1/annual-reviews
+/// Saved reviews, newest year first.
+pub async fn list_annual_reviews(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<AnnualReview>>, TurfOpsError> {
+    let profile_id = active_profile_id(&state).await?;
+    let reviews = annual_review_queries::list_annual_reviews(&state.pool, profile_id).await?;
+    Ok(Json(reviews))
+}
+
+/// GET /api/v1/annual-reviews/{year}
+/// The saved review, or an unsaved draft listing that year's major applications.
+pub async fn get_annual_review(
+    State(state): State<AppState>,
+    Path(year): Path<i32>,
+) -> Result<Json<AnnualReview>, TurfOpsError> {
+    validate_year(year)?;
+    let profile_id = active_profile_id(&state).await?;
+
+    if let Some(saved) =
+        annual_review_queries::get_annual_review(&state.pool, profile_id, year).await?
+    {
+        return Ok(Json(saved));
+    }
+
+    let in_year = queries::get_applications_made_in_year(&state.pool, profile_id, year).await?;
+
+    let now = Utc::now();
+    Ok(Json(AnnualReview {
+        id: None,
+        lawn_profile_id: profile_id,
+        year,
+        application_reviews: draft_application_reviews(&in_year),
+        observations: ReviewObservations::default(),
+        adjustments: Vec::new(),
+        completed_at: None,
+        created_at: now,
+        updated_at: now,
+    }))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReviewRequest {
+    pub application_reviews: Vec<ApplicationReview>,
+    pub observations: ReviewObservations,
+    /// Previously chosen adjustments; their `accepted` flags carry over
+    #[serde(default)]
+    pub adjustments: Vec<ReviewAdjustment>,
+}
+
+/// POST /api/v1/annual-reviews/{year}/suggestions
+/// Generate next-season adjustments from outcomes + observations (not persisted).
+pub async fn suggest_adjustments(
+    Path(year): Path<i32>,
+    Json(req): Json<ReviewRequest>,
+) -> Result<Json<Vec<ReviewAdjustment>>, TurfOpsError> {
+    validate_year(year)?;
+    let suggestions = crate::logic::annual_review::suggest_adjustments(
+        &req.application_reviews,
+        &req.observations,
+    );
+    Ok(Json(merge_accepted(suggestions, &req.adjustments)))
+}
+
+/// PUT /api/v1/annual-reviews/{year}
+/// Save the completed review, including which adjustments were accepted.
+pub async fn save_annual_review(
+    State(state): State<AppState>,
+    Path(year): Path<i32>,
+    Json(req): Json<ReviewRequest>,
+) -> Result<Json<AnnualReview>, TurfOpsError> {
+    validate_year(year)?;
+    let profile_id = active_profile_id(&state).await?;
+
+    let now = Utc::now();
+    let review = AnnualReview {
+        id: None,
+        lawn_profile_id: profile_id,
+        year,
+        application_reviews: req.application_reviews,
+        observations: req.observations,
+        adjustments: req.adjustments,
+        completed_at: Some(now),
+        created_at: now,
+        updated_at: now,
+    };
+
+    let saved = annual_review_queries::upsert_annual_review(&state.pool, &review).await?;
+    tracing::info!(
+        year,
+        accepted = saved.adjustments.iter().filter(|a| a.accepted).count(),
+        "Annual review saved"
+    );
+    Ok(Json(saved))
+}
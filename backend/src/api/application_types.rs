@@ -0,0 +1,124 @@
+use crate::db::custom_type_queries;
+use crate::error::TurfOpsError;
+use crate::models::{ApplicationType, CustomApplicationType, TypeEntry, TypeRegistry};
+use crate::state::AppState;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use std::str::FromStr;
+
+#[derive(Debug, Serialize)]
+pub struct ApplicationTypesResponse {
+    /// Built-in types, then custom types; what filters and forms offer.
+    pub types: Vec<TypeEntry>,
+    pub custom: Vec<CustomApplicationType>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CustomTypeRequest {
+    pub name: String,
+    pub color: String,
+    /// Built-in category the type counts as. Defaults to Other.
+    pub base_type: Option<String>,
+}
+
+fn build_custom_type(
+    id: Option<i64>,
+    req: CustomTypeRequest,
+) -> Result<CustomApplicationType, TurfOpsError> {
+    let base_type = req
+        .base_type
+        .as_deref()
+        .filter(|s| !s.is_empty())
+        .map(ApplicationType::from_str)
+        .transpose()
+        .map_err(TurfOpsError::InvalidData)?
+        .unwrap_or(ApplicationType::Other);
+
+    let custom = CustomApplicationType {
+        id,
+        name: req.name.trim().to_string(),
+        color: req.color.trim().to_lowercase(),
+        base_type,
+        created_at: Utc::now(),
+    };
+    custom.validate().map_err(TurfOpsError::InvalidData)?;
+    Ok(custom)
+}
+
+/// Type names are unique regardless of case; check up front for a readable
+/// 400 instead of a constraint violation.
+async fn ensure_unique_name(
+    pool: &PgPool,
+    custom: &CustomApplicationType,
+    exclude: Option<i64>,
+) -> Result<(), TurfOpsError> {
+    let existing = custom_type_queries::list_custom_types(pool).await?;
+    if existing
+        .iter()
+        .any(|t| t.id != exclude && t.name.eq_ignore_ascii_case(&custom.name))
+    {
+        return Err(TurfOpsError::InvalidData(format!(
+            "A type named {} already exists",
+            custom.name
+        )));
+    }
+    Ok(())
+}
+
+/// GET /api/v1/application-types
+/// Built-in and custom application types, merged.
+pub async fn list_application_types(
+    State(state): State<AppState>,
+) -> Result<Json<ApplicationTypesResponse>, TurfOpsError> {
+    let custom = custom_type_queries::list_custom_types(&state.pool).await?;
+    let types = TypeRegistry::new(custom.clone()).entries();
+    Ok(Json(ApplicationTypesResponse { types, custom }))
+}
+
+/// POST /api/v1/application-types
+pub async fn create_custom_type(
+    State(state): State<AppState>,
+    Json(req): Json<CustomTypeRequest>,
+) -> Result<(StatusCode, Json<CustomApplicationType>), TurfOpsError> {
+    let custom = build_custom_type(None, req)?;
+    ensure_unique_name(&state.pool, &custom, None).await?;
+
+    let id = custom_type_queries::create_custom_type(&state.pool, &custom).await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(CustomApplicationType {
+            id: Some(id),
+            ..custom
+        }),
+    ))
+}
+
+/// PUT /api/v1/application-types/:id
+/// Changing the base type moves the type's applications along with it.
+pub async fn update_custom_type(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Json(req): Json<CustomTypeRequest>,
+) -> Result<Json<CustomApplicationType>, TurfOpsError> {
+    custom_type_queries::get_custom_type(&state.pool, id).await?;
+    let custom = build_custom_type(Some(id), req)?;
+    ensure_unique_name(&state.pool, &custom, Some(id)).await?;
+
+    let updated = custom_type_queries::update_custom_type(&state.pool, id, &custom).await?;
+    Ok(Json(updated))
+}
+
+/// DELETE /api/v1/application-types/:id
+/// Applications logged as the type stay, under its base category.
+pub async fn delete_custom_type(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<StatusCode, TurfOpsError> {
+    custom_type_queries::get_custom_type(&state.pool, id).await?;
+    custom_type_queries::delete_custom_type(&state.pool, id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
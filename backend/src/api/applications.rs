@@ -1,13 +1,20 @@
-use crate::db::queries;
+use crate::db::{area_queries, custom_type_queries, equipment_queries, event_queries, queries};
 use crate::error::TurfOpsError;
-use crate::models::{Application, ApplicationScope, ApplicationType, WeatherSnapshot};
+use crate::logic::weather_backfill;
+use crate::models::dates::{parse_date, parse_optional_date};
+use crate::models::{
+    Application, ApplicationScope, ApplicationType, Event, EventKind, EventLevel, LawnProfile,
+    TypeRegistry, WeatherSnapshot,
+};
 use crate::state::AppState;
 use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
 use axum::Json;
-use chrono::{NaiveDate, Utc};
-use serde::Deserialize;
+use chrono::{Local, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::str::FromStr;
+use tracing::warn;
 
 const DEFAULT_PAGE_LIMIT: i64 = 50;
 const MAX_PAGE_LIMIT: i64 = 200;
@@ -16,6 +23,10 @@ const MAX_PAGE_LIMIT: i64 = 200;
 pub struct ListApplicationsQuery {
     #[serde(rename = "type")]
     pub app_type: Option<String>,
+    /// Only applications logged as this custom type.
+    pub custom_type_id: Option<i64>,
+    /// Only applications tagged to this area, plus whole-lawn ones.
+    pub area_id: Option<i64>,
     pub limit: Option<i64>,
     pub offset: Option<i64>,
 }
@@ -24,7 +35,7 @@ pub async fn list_applications(
     State(state): State<AppState>,
     Query(params): Query<ListApplicationsQuery>,
 ) -> Result<Json<Vec<Application>>, TurfOpsError> {
-    let profile = queries::get_default_lawn_profile(&state.pool)
+    let profile = queries::get_active_lawn_profile(&state.pool)
         .await?
         .ok_or_else(|| TurfOpsError::NotFound("No lawn profile found".into()))?;
 
@@ -49,6 +60,15 @@ pub async fn list_applications(
         apps.retain(|a| a.application_type == app_type);
     }
 
+    if let Some(custom_type_id) = params.custom_type_id {
+        apps.retain(|a| a.custom_type_id == Some(custom_type_id));
+    }
+
+    if let Some(area_id) = params.area_id {
+        let area = area_queries::get_area_for_profile(&state.pool, profile_id, area_id).await?;
+        apps.retain(|a| area.covers(a));
+    }
+
     Ok(Json(apps))
 }
 
@@ -66,30 +86,114 @@ pub struct CreateApplicationRequest {
     pub potassium_pct: Option<f64>,
     pub plant_id: Option<i64>,
     pub follow_up_date: Option<String>,
+    pub area_id: Option<i64>,
+    pub equipment_id: Option<i64>,
+    /// Log as this custom type; `application_type` is then taken from its base.
+    pub custom_type_id: Option<i64>,
+}
+
+/// The built-in type to store and the custom type, if any. A custom type id
+/// wins; otherwise `application_type` may name a built-in or custom type.
+fn resolve_type(
+    registry: &TypeRegistry,
+    req: &CreateApplicationRequest,
+) -> Result<(ApplicationType, Option<i64>), TurfOpsError> {
+    if let Some(id) = req.custom_type_id {
+        let custom = registry
+            .get(id)
+            .ok_or_else(|| TurfOpsError::NotFound(format!("Application type {id} not found")))?;
+        return Ok((custom.base_type, Some(id)));
+    }
+    registry.resolve(&req.application_type).ok_or_else(|| {
+        TurfOpsError::InvalidData(format!(
+            "Unknown application type: {}",
+            req.application_type
+        ))
+    })
+}
+
+/// Coverage for an application: as given, or the tagged area's size.
+async fn resolve_coverage(
+    state: &AppState,
+    profile_id: i64,
+    req: &CreateApplicationRequest,
+) -> Result<Option<f64>, TurfOpsError> {
+    match req.area_id {
+        Some(area_id) => {
+            let area = area_queries::get_area_for_profile(&state.pool, profile_id, area_id).await?;
+            Ok(req.coverage_sqft.or(area.size_sqft))
+        }
+        None => Ok(req.coverage_sqft),
+    }
+}
+
+/// Rebuild the day's conditions from the profile's station for an entry
+/// that came without a snapshot, or from the weather archive for days the
+/// station has none. Best effort: planned (future) entries, no source, or a
+/// source error just leave it empty.
+async fn reconstruct_weather(
+    state: &AppState,
+    profile: &LawnProfile,
+    date: NaiveDate,
+) -> Option<WeatherSnapshot> {
+    if date > Local::now().date_naive() {
+        return None;
+    }
+    let (lake, archive) = {
+        let sync = state.sync_service.read().await;
+        (
+            sync.weather_client_for(profile),
+            sync.historical_client_for(profile),
+        )
+    };
+
+    if let Some(client) = lake {
+        let (start, end) = weather_backfill::day_bounds(date);
+        match client.fetch_range(start, end).await {
+            Ok(readings) => {
+                if let Some(snapshot) = weather_backfill::snapshot_from_readings(&readings) {
+                    return Some(snapshot);
+                }
+            }
+            Err(e) => warn!(%date, error = %e, "Could not reconstruct weather for application"),
+        }
+    }
+
+    match archive?.fetch_daily(date, date).await {
+        Ok(days) => days
+            .first()
+            .and_then(weather_backfill::snapshot_from_history),
+        Err(e) => {
+            warn!(%date, error = %e, "Could not fetch archived weather for application");
+            None
+        }
+    }
+}
+
+/// The tagged equipment must belong to the profile.
+async fn check_equipment(
+    state: &AppState,
+    profile_id: i64,
+    req: &CreateApplicationRequest,
+) -> Result<(), TurfOpsError> {
+    if let Some(equipment_id) = req.equipment_id {
+        equipment_queries::get_equipment_for_profile(&state.pool, profile_id, equipment_id).await?;
+    }
+    Ok(())
 }
 
 pub async fn create_application(
     State(state): State<AppState>,
     Json(req): Json<CreateApplicationRequest>,
 ) -> Result<(StatusCode, Json<Application>), TurfOpsError> {
-    let profile = queries::get_default_lawn_profile(&state.pool)
+    let profile = queries::get_active_lawn_profile(&state.pool)
         .await?
         .ok_or_else(|| TurfOpsError::NotFound("No lawn profile found".into()))?;
 
-    let application_type = ApplicationType::from_str(&req.application_type).map_err(|_| {
-        TurfOpsError::InvalidData(format!(
-            "Unknown application type: {}",
-            req.application_type
-        ))
-    })?;
-
-    let application_date =
-        NaiveDate::parse_from_str(&req.application_date, "%Y-%m-%d").map_err(|_| {
-            TurfOpsError::InvalidData(format!(
-                "Invalid date format: {}. Expected YYYY-MM-DD",
-                req.application_date
-            ))
-        })?;
+    let registry = custom_type_queries::load_registry(&state.pool).await?;
+    let (application_type, custom_type_id) = resolve_type(&registry, &req)?;
+
+    let application_date = parse_date("application_date", &req.application_date)?;
 
     match application_type.scope() {
         ApplicationScope::PlantRequired if req.plant_id.is_none() => {
@@ -107,18 +211,7 @@ pub async fn create_application(
         _ => {}
     }
 
-    let follow_up_date = req
-        .follow_up_date
-        .as_deref()
-        .map(|s| {
-            NaiveDate::parse_from_str(s, "%Y-%m-%d").map_err(|_| {
-                TurfOpsError::InvalidData(format!(
-                    "Invalid follow_up_date format: {}. Expected YYYY-MM-DD",
-                    s
-                ))
-            })
-        })
-        .transpose()?;
+    let follow_up_date = parse_optional_date("follow_up_date", req.follow_up_date.as_deref())?;
 
     if let Some(fu) = follow_up_date {
         if fu < application_date {
@@ -128,23 +221,34 @@ pub async fn create_application(
         }
     }
 
+    let profile_id = profile
+        .id
+        .ok_or_else(|| TurfOpsError::InvalidData("Profile missing ID".into()))?;
+    let coverage_sqft = resolve_coverage(&state, profile_id, &req).await?;
+    check_equipment(&state, profile_id, &req).await?;
+    let weather_snapshot = match req.weather_snapshot {
+        Some(snapshot) => Some(snapshot),
+        None => reconstruct_weather(&state, &profile, application_date).await,
+    };
+
     let app = Application {
         id: None,
-        lawn_profile_id: profile
-            .id
-            .ok_or_else(|| TurfOpsError::InvalidData("Profile missing ID".into()))?,
+        lawn_profile_id: profile_id,
         application_type,
         product_name: req.product_name,
         application_date,
         rate_per_1000sqft: req.rate_per_1000sqft,
-        coverage_sqft: req.coverage_sqft,
+        coverage_sqft,
         notes: req.notes,
-        weather_snapshot: req.weather_snapshot,
+        weather_snapshot,
         nitrogen_pct: req.nitrogen_pct,
         phosphorus_pct: req.phosphorus_pct,
         potassium_pct: req.potassium_pct,
         plant_id: req.plant_id,
         follow_up_date,
+        area_id: req.area_id,
+        equipment_id: req.equipment_id,
+        custom_type_id,
         created_at: Utc::now(),
     };
 
@@ -155,6 +259,21 @@ pub async fn create_application(
         ..app
     };
 
+    event_queries::record_event(
+        &state.pool,
+        Event::new(
+            EventKind::ApplicationAdded,
+            EventLevel::Info,
+            format!(
+                "{} logged for {}",
+                registry.label(&created),
+                created.application_date
+            ),
+        )
+        .with_details(format!("application_id={}", id)),
+    )
+    .await;
+
     Ok((StatusCode::CREATED, Json(created)))
 }
 
@@ -167,20 +286,10 @@ pub async fn update_application(
         .await?
         .ok_or_else(|| TurfOpsError::NotFound(format!("Application {} not found", id)))?;
 
-    let application_type = ApplicationType::from_str(&req.application_type).map_err(|_| {
-        TurfOpsError::InvalidData(format!(
-            "Unknown application type: {}",
-            req.application_type
-        ))
-    })?;
-
-    let application_date =
-        NaiveDate::parse_from_str(&req.application_date, "%Y-%m-%d").map_err(|_| {
-            TurfOpsError::InvalidData(format!(
-                "Invalid date format: {}. Expected YYYY-MM-DD",
-                req.application_date
-            ))
-        })?;
+    let registry = custom_type_queries::load_registry(&state.pool).await?;
+    let (application_type, custom_type_id) = resolve_type(&registry, &req)?;
+
+    let application_date = parse_date("application_date", &req.application_date)?;
 
     match application_type.scope() {
         ApplicationScope::PlantRequired if req.plant_id.is_none() => {
@@ -198,18 +307,7 @@ pub async fn update_application(
         _ => {}
     }
 
-    let follow_up_date = req
-        .follow_up_date
-        .as_deref()
-        .map(|s| {
-            NaiveDate::parse_from_str(s, "%Y-%m-%d").map_err(|_| {
-                TurfOpsError::InvalidData(format!(
-                    "Invalid follow_up_date format: {}. Expected YYYY-MM-DD",
-                    s
-                ))
-            })
-        })
-        .transpose()?;
+    let follow_up_date = parse_optional_date("follow_up_date", req.follow_up_date.as_deref())?;
 
     if let Some(fu) = follow_up_date {
         if fu < application_date {
@@ -219,6 +317,24 @@ pub async fn update_application(
         }
     }
 
+    let coverage_sqft = resolve_coverage(&state, existing.lawn_profile_id, &req).await?;
+    check_equipment(&state, existing.lawn_profile_id, &req).await?;
+    // A reconstructed snapshot follows the date; a recorded one is kept.
+    let weather_snapshot = match (req.weather_snapshot, existing.weather_snapshot) {
+        (Some(snapshot), _) => Some(snapshot),
+        (None, Some(old))
+            if !(old.reconstructed && existing.application_date != application_date) =>
+        {
+            Some(old)
+        }
+        (None, _) => {
+            match queries::get_lawn_profile(&state.pool, existing.lawn_profile_id).await? {
+                Some(profile) => reconstruct_weather(&state, &profile, application_date).await,
+                None => None,
+            }
+        }
+    };
+
     let updated = Application {
         id: Some(id),
         lawn_profile_id: existing.lawn_profile_id,
@@ -226,14 +342,17 @@ pub async fn update_application(
         product_name: req.product_name,
         application_date,
         rate_per_1000sqft: req.rate_per_1000sqft,
-        coverage_sqft: req.coverage_sqft,
+        coverage_sqft,
         notes: req.notes,
-        weather_snapshot: req.weather_snapshot.or(existing.weather_snapshot),
+        weather_snapshot,
         nitrogen_pct: req.nitrogen_pct,
         phosphorus_pct: req.phosphorus_pct,
         potassium_pct: req.potassium_pct,
         plant_id: req.plant_id,
         follow_up_date,
+        area_id: req.area_id,
+        equipment_id: req.equipment_id,
+        custom_type_id,
         created_at: existing.created_at,
     };
 
@@ -245,6 +364,21 @@ pub async fn update_application(
         )));
     }
 
+    event_queries::record_event(
+        &state.pool,
+        Event::new(
+            EventKind::ApplicationUpdated,
+            EventLevel::Info,
+            format!(
+                "{} on {} updated",
+                registry.label(&updated),
+                updated.application_date
+            ),
+        )
+        .with_details(format!("application_id={}", id)),
+    )
+    .await;
+
     Ok(Json(updated))
 }
 
@@ -253,5 +387,80 @@ pub async fn delete_application(
     Path(id): Path<i64>,
 ) -> Result<StatusCode, TurfOpsError> {
     queries::delete_application(&state.pool, id).await?;
+
+    event_queries::record_event(
+        &state.pool,
+        Event::new(
+            EventKind::ApplicationDeleted,
+            EventLevel::Info,
+            format!("Application {} deleted", id),
+        ),
+    )
+    .await;
+
     Ok(StatusCode::NO_CONTENT)
 }
+
+#[derive(Debug, Serialize)]
+pub struct WeatherBackfillResult {
+    /// Applications that got a reconstructed snapshot.
+    pub updated: usize,
+    /// Past applications without a snapshot where the station had no data.
+    pub missing: usize,
+}
+
+/// POST /api/v1/applications/backfill-weather
+/// Reconstruct weather snapshots for the active profile's past applications
+/// that have none (entered after the fact or imported), from the station's
+/// hourly history for each date or the weather archive before it.
+pub async fn backfill_weather(
+    State(state): State<AppState>,
+) -> Result<Json<WeatherBackfillResult>, TurfOpsError> {
+    let profile = queries::get_active_lawn_profile(&state.pool)
+        .await?
+        .ok_or_else(|| TurfOpsError::NotFound("No lawn profile found".into()))?;
+    let profile_id = profile
+        .id
+        .ok_or_else(|| TurfOpsError::InvalidData("Profile missing ID".into()))?;
+    {
+        let sync = state.sync_service.read().await;
+        if sync.weather_client_for(&profile).is_none()
+            && sync.historical_client_for(&profile).is_none()
+        {
+            return Err(TurfOpsError::DataSourceUnavailable(
+                "Neither the weather data lake nor historical weather is available".into(),
+            ));
+        }
+    }
+
+    let today = Local::now().date_naive();
+    let apps = queries::get_applications_for_profile(&state.pool, profile_id, i64::MAX, 0).await?;
+    let mut by_date: HashMap<NaiveDate, Option<WeatherSnapshot>> = HashMap::new();
+    let mut result = WeatherBackfillResult {
+        updated: 0,
+        missing: 0,
+    };
+    for mut app in apps {
+        if app.weather_snapshot.is_some() || app.application_date > today {
+            continue;
+        }
+        let snapshot = match by_date.get(&app.application_date) {
+            Some(snapshot) => snapshot.clone(),
+            None => {
+                let snapshot = reconstruct_weather(&state, &profile, app.application_date).await;
+                by_date.insert(app.application_date, snapshot.clone());
+                snapshot
+            }
+        };
+        match snapshot {
+            Some(snapshot) => {
+                app.weather_snapshot = Some(snapshot);
+                queries::update_application(&state.pool, &app).await?;
+                result.updated += 1;
+            }
+            None => result.missing += 1,
+        }
+    }
+
+    Ok(Json(result))
+}
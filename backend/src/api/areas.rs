@@ -0,0 +1,155 @@
+use crate::db::{area_queries, queries};
+use crate::error::TurfOpsError;
+use crate::models::{GrassType, LawnArea, SunExposure};
+use crate::state::AppState;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use chrono::Utc;
+use serde::Deserialize;
+use sqlx::PgPool;
+use std::str::FromStr;
+
+#[derive(Debug, Deserialize)]
+pub struct AreaRequest {
+    pub name: String,
+    pub size_sqft: Option<f64>,
+    /// Defaults to full sun.
+    pub sun_exposure: Option<String>,
+    /// Overrides the profile's grass type for this area's recommendations.
+    pub grass_type: Option<String>,
+    pub grass_mix: Option<String>,
+}
+
+async fn active_profile_id(state: &AppState) -> Result<i64, TurfOpsError> {
+    let profile = queries::get_active_lawn_profile(&state.pool)
+        .await?
+        .ok_or_else(|| TurfOpsError::NotFound("No lawn profile found".into()))?;
+
+    profile
+        .id
+        .ok_or_else(|| TurfOpsError::InvalidData("Profile missing ID".into()))
+}
+
+fn build_area(
+    profile_id: i64,
+    id: Option<i64>,
+    req: AreaRequest,
+) -> Result<LawnArea, TurfOpsError> {
+    let name = req.name.trim().to_string();
+    if name.is_empty() {
+        return Err(TurfOpsError::InvalidData("Area name can't be empty".into()));
+    }
+    if let Some(sqft) = req.size_sqft {
+        if !sqft.is_finite() || sqft <= 0.0 {
+            return Err(TurfOpsError::InvalidData(
+                "size_sqft must be a positive number".into(),
+            ));
+        }
+    }
+
+    let sun_exposure = req
+        .sun_exposure
+        .as_deref()
+        .map(SunExposure::from_str)
+        .transpose()
+        .map_err(TurfOpsError::InvalidData)?
+        .unwrap_or(SunExposure::FullSun);
+    let grass_type = req
+        .grass_type
+        .as_deref()
+        .filter(|s| !s.is_empty())
+        .map(GrassType::from_str)
+        .transpose()
+        .map_err(TurfOpsError::InvalidData)?;
+
+    Ok(LawnArea {
+        id,
+        lawn_profile_id: profile_id,
+        name,
+        size_sqft: req.size_sqft,
+        sun_exposure,
+        grass_type,
+        grass_mix: req
+            .grass_mix
+            .map(|m| m.trim().to_string())
+            .filter(|m| !m.is_empty()),
+        created_at: Utc::now(),
+    })
+}
+
+/// Area names are unique per profile; check up front for a readable 400
+/// instead of a constraint violation.
+async fn ensure_unique_name(
+    pool: &PgPool,
+    area: &LawnArea,
+    exclude: Option<i64>,
+) -> Result<(), TurfOpsError> {
+    let existing = area_queries::list_areas_for_profile(pool, area.lawn_profile_id).await?;
+    if existing
+        .iter()
+        .any(|a| a.id != exclude && a.name.eq_ignore_ascii_case(&area.name))
+    {
+        return Err(TurfOpsError::InvalidData(format!(
+            "An area named {} already exists",
+            area.name
+        )));
+    }
+    Ok(())
+}
+
+/// GET /api/v1/areas
+/// Areas of the active profile, by name.
+pub async fn list_areas(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<LawnArea>>, TurfOpsError> {
+    let profile_id = active_profile_id(&state).await?;
+    let areas = area_queries::list_areas_for_profile(&state.pool, profile_id).await?;
+    Ok(Json(areas))
+}
+
+/// POST /api/v1/areas
+pub async fn create_area(
+    State(state): State<AppState>,
+    Json(req): Json<AreaRequest>,
+) -> Result<(StatusCode, Json<LawnArea>), TurfOpsError> {
+    let profile_id = active_profile_id(&state).await?;
+    let area = build_area(profile_id, None, req)?;
+    ensure_unique_name(&state.pool, &area, None).await?;
+
+    let id = area_queries::create_area(&state.pool, &area).await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(LawnArea {
+            id: Some(id),
+            ..area
+        }),
+    ))
+}
+
+/// PUT /api/v1/areas/:id
+pub async fn update_area(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Json(req): Json<AreaRequest>,
+) -> Result<Json<LawnArea>, TurfOpsError> {
+    let profile_id = active_profile_id(&state).await?;
+    area_queries::get_area_for_profile(&state.pool, profile_id, id).await?;
+    let area = build_area(profile_id, Some(id), req)?;
+    ensure_unique_name(&state.pool, &area, Some(id)).await?;
+
+    let updated = area_queries::update_area(&state.pool, id, &area).await?;
+    Ok(Json(updated))
+}
+
+/// DELETE /api/v1/areas/:id
+/// Applications tagged to the area become whole-lawn entries.
+pub async fn delete_area(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<StatusCode, TurfOpsError> {
+    let profile_id = active_profile_id(&state).await?;
+    area_queries::get_area_for_profile(&state.pool, profile_id, id).await?;
+    area_queries::delete_area(&state.pool, id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -1,12 +1,15 @@
-use crate::db::queries;
+use crate::db::{observation_queries, queries};
 use crate::error::TurfOpsError;
-use crate::models::Application;
+use crate::logic::agenda::{self, AgendaDay, AGENDA_DAYS};
+use crate::models::dates::parse_optional_date;
+use crate::models::{Application, Observation};
 use crate::state::AppState;
 use axum::extract::{Query, State};
 use axum::Json;
-use chrono::{Datelike, Local, NaiveDate};
+use chrono::{Datelike, Duration, Local, NaiveDate};
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
+use tracing::warn;
 
 #[derive(Debug, Deserialize)]
 pub struct CalendarQuery {
@@ -19,6 +22,8 @@ pub struct CalendarResponse {
     pub year: i32,
     pub month: u32,
     pub days: BTreeMap<String, Vec<Application>>,
+    /// Journal observations keyed by local date.
+    pub observations: BTreeMap<String, Vec<Observation>>,
 }
 
 pub async fn get_calendar(
@@ -29,7 +34,7 @@ pub async fn get_calendar(
     let year = params.year.unwrap_or(today.year());
     let month = params.month.unwrap_or(today.month());
 
-    let profile = queries::get_default_lawn_profile(&state.pool)
+    let profile = queries::get_active_lawn_profile(&state.pool)
         .await?
         .ok_or_else(|| TurfOpsError::NotFound("No lawn profile found".into()))?;
 
@@ -62,5 +67,92 @@ pub async fn get_calendar(
         days.entry(date_key).or_default().push(app);
     }
 
-    Ok(Json(CalendarResponse { year, month, days }))
+    // Observations are timestamped, so pad the UTC range by a day on each side
+    // and bucket by local date.
+    let pad = Duration::days(1);
+    let range_start = month_start
+        .and_hms_opt(0, 0, 0)
+        .unwrap_or_default()
+        .and_utc()
+        - pad;
+    let range_end = month_end.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc() + pad;
+    let observed = observation_queries::get_observations_in_range(
+        &state.pool,
+        profile_id,
+        range_start,
+        range_end,
+    )
+    .await?;
+
+    let mut observations: BTreeMap<String, Vec<Observation>> = BTreeMap::new();
+    for obs in observed {
+        let local_date = obs.observed_at.with_timezone(&Local).date_naive();
+        if local_date < month_start || local_date >= month_end {
+            continue;
+        }
+        let date_key = local_date.format("%Y-%m-%d").to_string();
+        observations.entry(date_key).or_default().push(obs);
+    }
+
+    Ok(Json(CalendarResponse {
+        year,
+        month,
+        days,
+        observations,
+    }))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AgendaQuery {
+    /// First day, `YYYY-MM-DD`. Defaults to the start of this week (Sunday).
+    pub start: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AgendaResponse {
+    pub start: NaiveDate,
+    pub days: Vec<AgendaDay>,
+}
+
+/// GET /api/v1/applications/agenda?start=YYYY-MM-DD
+/// Seven days of logged and planned applications with each day's forecast.
+pub async fn get_agenda(
+    State(state): State<AppState>,
+    Query(params): Query<AgendaQuery>,
+) -> Result<Json<AgendaResponse>, TurfOpsError> {
+    let start = parse_optional_date("start", params.start.as_deref())?
+        .unwrap_or_else(|| agenda::week_start(Local::now().date_naive()));
+
+    let profile = queries::get_active_lawn_profile(&state.pool)
+        .await?
+        .ok_or_else(|| TurfOpsError::NotFound("No lawn profile found".into()))?;
+    let profile_id = profile
+        .id
+        .ok_or_else(|| TurfOpsError::InvalidData("Profile missing ID".into()))?;
+
+    let apps = queries::get_applications_for_profile_in_range(
+        &state.pool,
+        profile_id,
+        start,
+        start + Duration::days(AGENDA_DAYS),
+    )
+    .await?;
+
+    // The agenda is still useful without a forecast
+    let summary = {
+        let mut service = state.sync_service.write().await;
+        match service.get_or_refresh(&profile).await {
+            Ok(summary) => Some(summary),
+            Err(e) => {
+                warn!(error = %e, "No forecast for the agenda");
+                None
+            }
+        }
+    };
+    let forecast = summary.as_ref().and_then(|s| s.forecast.as_ref());
+
+    Ok(Json(AgendaResponse {
+        start,
+        days: agenda::build_agenda(start, &apps, forecast),
+    }))
 }
@@ -1,72 +1,149 @@
-use crate::db::queries;
+use crate::api::recommendations::evaluate_recommendations;
+use crate::db::{queries, settings_queries};
 use crate::error::TurfOpsError;
+use crate::logic::api_quota::QuotaUsage;
 use crate::logic::data_sync::ConnectionStatus;
-use crate::models::{Application, EnvironmentalSummary, LawnProfile, Recommendation};
+use crate::logic::rules::germination::{self, GerminationStatus};
+use crate::logic::rules::WindowProjection;
+use crate::models::{
+    Application, DashboardLayout, DashboardPanel, EnvironmentalSummary, LawnProfile,
+    Recommendation, SeverityCounts,
+};
 use crate::state::AppState;
 use axum::extract::State;
 use axum::Json;
+use chrono::{Local, Utc};
 use serde::Serialize;
 
+/// Active recommendations shown on the dashboard; the rest are counted.
+const DASHBOARD_ALERT_LIMIT: usize = 3;
+
 #[derive(Debug, Serialize)]
 pub struct DashboardResponse {
     pub profile: LawnProfile,
     pub environmental: EnvironmentalSummary,
+    /// The most severe active recommendations, most severe first.
     pub recommendations: Vec<Recommendation>,
+    /// Every active recommendation by severity, including ones not shown.
+    pub alert_counts: SeverityCounts,
+    pub total_alerts: usize,
     pub recent_applications: Vec<Application>,
     pub connections: ConnectionStatus,
+    /// OpenWeatherMap calls today against the daily quota.
+    pub openweathermap_usage: Option<QuotaUsage>,
+    /// Upcoming and open seasonal windows, soonest first.
+    pub windows: Vec<WindowProjection>,
+    /// Countdown after the latest overseeding, while seedlings are establishing.
+    pub germination: Option<GerminationStatus>,
+    /// Panels to show, in order.
+    pub layout: Vec<DashboardPanel>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DashboardLayoutResponse {
+    pub panels: Vec<DashboardPanel>,
+    /// Set by `DASHBOARD_PANELS`; the Settings page can't change it.
+    pub locked: bool,
 }
 
 /// GET /api/v1/dashboard
 /// Composite endpoint returning profile, environmental summary, top recommendations,
-/// recent applications, and datasource connection status.
+/// recent applications, datasource connection status, window countdowns, and
+/// the germination countdown after an overseeding.
 pub async fn get_dashboard(
     State(state): State<AppState>,
 ) -> Result<Json<DashboardResponse>, TurfOpsError> {
-    let profile = queries::get_default_lawn_profile(&state.pool)
+    let profile = queries::get_active_lawn_profile(&state.pool)
         .await?
         .ok_or_else(|| TurfOpsError::NotFound("No lawn profile found".into()))?;
 
     let profile_id = profile
         .id
         .ok_or_else(|| TurfOpsError::InvalidData("Profile missing ID".into()))?;
-    let apps = queries::get_applications_for_profile(&state.pool, profile_id, 10, 0).await?;
+    // Full history: windows and germination look back over the whole season
+    let apps = queries::get_applications_for_profile(&state.pool, profile_id, 1000, 0).await?;
 
     // Get environmental data (refreshes if stale)
     let summary = {
         let mut service = state.sync_service.write().await;
-        service.get_or_refresh().await?
-    };
-    // Check connections with read lock (doesn't block other readers)
-    let connections = {
-        let service = state.sync_service.read().await;
-        service.check_connections().await
+        service.get_or_refresh(&profile).await?
     };
+    // Checked in the background; pending until the first check finishes
+    let connections = state.connections.latest();
 
-    // Evaluate rules for recommendations
-    let mut recommendations = state.rules_engine.evaluate(&summary, &profile, &apps);
+    let today = Local::now().date_naive();
+    let windows = state
+        .rules_engine
+        .project_windows(&summary, &profile, &apps, today);
+    let germination = germination::germination_status(&profile, &apps, today);
 
-    // Apply dismissed/addressed state from database
-    let rec_states = queries::get_recommendation_states(&state.pool).await?;
-    for rec in &mut recommendations {
-        if let Some((dismissed, addressed)) = rec_states.get(&rec.id) {
-            rec.dismissed = *dismissed;
-            rec.addressed = *addressed;
-        }
-    }
+    // The same list the Recommendations page shows, so counts, shortcuts and
+    // New markers agree between the two
+    let mut recommendations = evaluate_recommendations(&state, None).await?;
     recommendations.retain(|r| r.is_active());
 
-    // Top 3 recommendations by severity
-    recommendations.sort_by_key(|r| std::cmp::Reverse(r.severity));
-    recommendations.truncate(3);
+    let alert_counts = SeverityCounts::tally(&recommendations);
+    let total_alerts = recommendations.len();
+    // Post-processing sorts them most severe first
+    recommendations.truncate(DASHBOARD_ALERT_LIMIT);
 
     // 5 most recent applications
     let recent_applications: Vec<Application> = apps.into_iter().take(5).collect();
+    let layout = effective_layout(&state).await?.0;
 
     Ok(Json(DashboardResponse {
         profile,
         environmental: summary,
         recommendations,
+        alert_counts,
+        total_alerts,
         recent_applications,
         connections,
+        openweathermap_usage: state.forecast_quota.as_ref().map(|q| q.usage(Utc::now())),
+        windows,
+        germination,
+        layout: layout.panels,
+    }))
+}
+
+/// The layout in force and whether it comes from `DASHBOARD_PANELS`.
+async fn effective_layout(state: &AppState) -> Result<(DashboardLayout, bool), TurfOpsError> {
+    match &state.dashboard_layout {
+        Some(layout) => Ok((layout.clone(), true)),
+        None => Ok((
+            settings_queries::get_dashboard_layout(&state.pool).await?,
+            false,
+        )),
+    }
+}
+
+/// GET /api/v1/dashboard/layout
+/// Which Dashboard panels appear and in what order.
+pub async fn get_layout(
+    State(state): State<AppState>,
+) -> Result<Json<DashboardLayoutResponse>, TurfOpsError> {
+    let (layout, locked) = effective_layout(&state).await?;
+    Ok(Json(DashboardLayoutResponse {
+        panels: layout.panels,
+        locked,
+    }))
+}
+
+/// PUT /api/v1/dashboard/layout
+/// Save the panels to show, in order. The choice is persisted in the settings table.
+pub async fn set_layout(
+    State(state): State<AppState>,
+    Json(body): Json<DashboardLayout>,
+) -> Result<Json<DashboardLayoutResponse>, TurfOpsError> {
+    if state.dashboard_layout.is_some() {
+        return Err(TurfOpsError::InvalidData(
+            "The dashboard layout is set by DASHBOARD_PANELS and can't be changed here".into(),
+        ));
+    }
+    body.validate().map_err(TurfOpsError::InvalidData)?;
+    settings_queries::set_dashboard_layout(&state.pool, &body).await?;
+    Ok(Json(DashboardLayoutResponse {
+        panels: body.panels,
+        locked: false,
     }))
 }
@@ -0,0 +1,58 @@
+use crate::db::{observation_queries, queries};
+use crate::error::TurfOpsError;
+use crate::logic::efficacy::{build_efficacy_report, daily_conditions, EfficacyReport};
+use crate::logic::weather_backfill::day_bounds;
+use crate::state::AppState;
+use axum::extract::{Path, State};
+use axum::Json;
+use chrono::{Datelike, Local, NaiveDate, Utc};
+
+/// GET /api/v1/efficacy/{year}
+/// Each of the year's applications lined up against the soil temps and
+/// disease-risk days that followed, with a timing verdict where there's a
+/// model to judge against, plus the daily conditions for a season timeline.
+pub async fn get_efficacy_report(
+    State(state): State<AppState>,
+    Path(year): Path<i32>,
+) -> Result<Json<EfficacyReport>, TurfOpsError> {
+    let current = Local::now().year();
+    if !(2000..=current).contains(&year) {
+        return Err(TurfOpsError::InvalidData(format!(
+            "Year must be between 2000 and {}",
+            current
+        )));
+    }
+
+    let profile = queries::get_active_lawn_profile(&state.pool)
+        .await?
+        .ok_or_else(|| TurfOpsError::NotFound("No lawn profile found".into()))?;
+    let profile_id = profile
+        .id
+        .ok_or_else(|| TurfOpsError::InvalidData("Profile missing ID".into()))?;
+
+    let invalid = || TurfOpsError::InvalidData(format!("Invalid year {}", year));
+    let jan1 = NaiveDate::from_ymd_opt(year, 1, 1).ok_or_else(invalid)?;
+    let next_jan1 = NaiveDate::from_ymd_opt(year + 1, 1, 1).ok_or_else(invalid)?;
+    let (start, _) = day_bounds(jan1);
+    let (end, _) = day_bounds(next_jan1);
+    let end = end.min(Utc::now());
+
+    let in_year = queries::get_applications_made_in_year(&state.pool, profile_id, year).await?;
+    let observations =
+        observation_queries::get_observations_in_range(&state.pool, profile_id, start, end).await?;
+
+    let readings = {
+        let service = state.sync_service.read().await;
+        let client = service.weather_client_for(&profile).ok_or_else(|| {
+            TurfOpsError::DataSourceUnavailable("Weather data lake not configured".into())
+        })?;
+        client.fetch_range(start, end).await?
+    };
+
+    Ok(Json(build_efficacy_report(
+        year,
+        daily_conditions(&readings),
+        &in_year,
+        &observations,
+    )))
+}
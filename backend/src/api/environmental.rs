@@ -1,26 +1,111 @@
+use crate::db::queries;
 use crate::error::TurfOpsError;
+use crate::logic::frost::{self, FrostOutlook};
+use crate::logic::gauge_ranges::{self, GaugeRanges};
+use crate::logic::rules::thresholds::FROST_ADVICE_RISK;
 use crate::models::EnvironmentalSummary;
 use crate::state::AppState;
-use axum::extract::State;
+use axum::extract::{Query, State};
 use axum::Json;
+use chrono::{Datelike, Local};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Deserialize)]
+pub struct RefreshQuery {
+    #[serde(default)]
+    pub deep: bool,
+}
 
 /// GET /api/v1/environmental
-/// Returns environmental data, refreshing from datasources only if stale.
-/// Sensors refresh after 5 minutes, forecast after 30 minutes.
+/// Returns environmental data for the active profile, refreshing from
+/// datasources only if stale. Sensors refresh after 5 minutes, forecast after 30.
 pub async fn get_environmental(
     State(state): State<AppState>,
 ) -> Result<Json<EnvironmentalSummary>, TurfOpsError> {
+    let profile = queries::get_active_lawn_profile(&state.pool)
+        .await?
+        .ok_or_else(|| TurfOpsError::NotFound("No lawn profile found".into()))?;
     let mut service = state.sync_service.write().await;
-    let summary = service.get_or_refresh().await?;
+    let summary = service.get_or_refresh(&profile).await?;
     Ok(Json(summary))
 }
 
-/// POST /api/v1/environmental/refresh
+/// POST /api/v1/environmental/refresh?deep=true
 /// Forces an immediate refresh from all datasources regardless of cache age.
+/// `deep` also re-syncs the 7-day soil window and the Drought Monitor.
 pub async fn refresh_environmental(
     State(state): State<AppState>,
+    Query(params): Query<RefreshQuery>,
 ) -> Result<Json<EnvironmentalSummary>, TurfOpsError> {
+    let profile = queries::get_active_lawn_profile(&state.pool)
+        .await?
+        .ok_or_else(|| TurfOpsError::NotFound("No lawn profile found".into()))?;
     let mut service = state.sync_service.write().await;
-    let summary = service.force_refresh().await?;
+    let summary = if params.deep {
+        service.deep_refresh(&profile).await?
+    } else {
+        service.force_refresh(&profile).await?
+    };
     Ok(Json(summary))
 }
+
+/// GET /api/v1/environmental/gauge-ranges
+/// Gauge min/max from the 5th-95th percentile of the station's long-term
+/// hourly archive. Metrics with thin history, or no lake at all, get the
+/// static scales.
+pub async fn get_gauge_ranges(
+    State(state): State<AppState>,
+) -> Result<Json<GaugeRanges>, TurfOpsError> {
+    let profile = queries::get_active_lawn_profile(&state.pool)
+        .await?
+        .ok_or_else(|| TurfOpsError::NotFound("No lawn profile found".into()))?;
+    // Clone the client and release the lock before the archive scan
+    let client = state.sync_service.read().await.weather_client_for(&profile);
+    let (Some(client), Some(profile_id)) = (client, profile.id) else {
+        return Ok(Json(gauge_ranges::static_ranges()));
+    };
+
+    match state.gauge_ranges.get_or_fit(profile_id, &client).await {
+        Ok(ranges) => Ok(Json(ranges)),
+        Err(e) => {
+            tracing::warn!("Failed to fit gauge ranges, using static scales: {}", e);
+            Ok(Json(gauge_ranges::static_ranges()))
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct FrostDatesResponse {
+    #[serde(flatten)]
+    pub outlook: FrostOutlook,
+    /// "10% chance of frost after Apr 19"
+    pub spring_advice: Option<String>,
+    /// "10% chance of frost before Oct 16"
+    pub fall_advice: Option<String>,
+}
+
+/// GET /api/v1/environmental/frost-dates
+/// This year's last spring and first fall frost as distributions fitted to the
+/// station's prior years of lows, or the USDA zone's typical dates when there
+/// are fewer than three, with the 10% risk dates the rules quote.
+pub async fn get_frost_dates(
+    State(state): State<AppState>,
+) -> Result<Json<FrostDatesResponse>, TurfOpsError> {
+    let profile = queries::get_active_lawn_profile(&state.pool)
+        .await?
+        .ok_or_else(|| TurfOpsError::NotFound("No lawn profile found".into()))?;
+    let summary = {
+        let mut service = state.sync_service.write().await;
+        service.get_or_refresh(&profile).await?
+    };
+    let outlook = frost::frost_outlook(
+        &summary.frost_years,
+        &profile.usda_zone,
+        Local::now().date_naive().year(),
+    );
+    Ok(Json(FrostDatesResponse {
+        outlook,
+        spring_advice: outlook.spring_advice(FROST_ADVICE_RISK),
+        fall_advice: outlook.fall_advice(FROST_ADVICE_RISK),
+    }))
+}
@@ -0,0 +1,234 @@
+use crate::db::{equipment_queries, queries};
+use crate::error::TurfOpsError;
+use crate::logic::equipment::equipment_statuses;
+use crate::models::dates::parse_optional_date;
+use crate::models::{Equipment, EquipmentKind, EquipmentStatus, EquipmentTask, MaintenanceEvent};
+use crate::state::AppState;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use chrono::{Local, Utc};
+use serde::Deserialize;
+use std::str::FromStr;
+
+#[derive(Debug, Deserialize)]
+pub struct EquipmentRequest {
+    pub name: String,
+    /// "Mower", "Spreader", "Sprayer" or "Other".
+    pub kind: String,
+    /// Engine hours a typical use adds.
+    pub hours_per_use: Option<f64>,
+    /// Hour meter reading when added; defaults to 0.
+    pub starting_hours: Option<f64>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MaintenanceRequest {
+    /// e.g. "BladeSharpening", "OilChange", "Winterize".
+    pub task: String,
+    /// Defaults to today.
+    pub performed_on: Option<String>,
+    pub notes: Option<String>,
+}
+
+async fn active_profile_id(state: &AppState) -> Result<i64, TurfOpsError> {
+    let profile = queries::get_active_lawn_profile(&state.pool)
+        .await?
+        .ok_or_else(|| TurfOpsError::NotFound("No lawn profile found".into()))?;
+    profile
+        .id
+        .ok_or_else(|| TurfOpsError::InvalidData("Profile missing ID".into()))
+}
+
+fn trimmed(notes: Option<String>) -> Option<String> {
+    notes
+        .map(|n| n.trim().to_string())
+        .filter(|n| !n.is_empty())
+}
+
+fn build_equipment(
+    profile_id: i64,
+    id: Option<i64>,
+    req: EquipmentRequest,
+) -> Result<Equipment, TurfOpsError> {
+    let name = req.name.trim().to_string();
+    if name.is_empty() {
+        return Err(TurfOpsError::InvalidData("Name can't be empty".into()));
+    }
+    let kind = EquipmentKind::from_str(&req.kind).map_err(TurfOpsError::InvalidData)?;
+    if let Some(h) = req.hours_per_use {
+        if !h.is_finite() || h <= 0.0 {
+            return Err(TurfOpsError::InvalidData(
+                "hours_per_use must be a positive number".into(),
+            ));
+        }
+    }
+    let starting_hours = req.starting_hours.unwrap_or(0.0);
+    if !starting_hours.is_finite() || starting_hours < 0.0 {
+        return Err(TurfOpsError::InvalidData(
+            "starting_hours must be zero or more".into(),
+        ));
+    }
+
+    Ok(Equipment {
+        id,
+        lawn_profile_id: profile_id,
+        name,
+        kind,
+        hours_per_use: req.hours_per_use,
+        starting_hours,
+        notes: trimmed(req.notes),
+        created_at: Utc::now(),
+    })
+}
+
+/// Names are unique per profile; check up front for a readable 400.
+async fn ensure_unique_name(
+    state: &AppState,
+    equipment: &Equipment,
+    exclude: Option<i64>,
+) -> Result<(), TurfOpsError> {
+    let existing =
+        equipment_queries::list_equipment_for_profile(&state.pool, equipment.lawn_profile_id)
+            .await?;
+    if existing
+        .iter()
+        .any(|e| e.id != exclude && e.name.eq_ignore_ascii_case(&equipment.name))
+    {
+        return Err(TurfOpsError::InvalidData(format!(
+            "Equipment named {} already exists",
+            equipment.name
+        )));
+    }
+    Ok(())
+}
+
+/// Statuses for the whole profile; usage depends on how many pieces of each
+/// kind there are, so one piece can't be counted on its own.
+async fn statuses(state: &AppState, profile_id: i64) -> Result<Vec<EquipmentStatus>, TurfOpsError> {
+    let equipment = equipment_queries::list_equipment_for_profile(&state.pool, profile_id).await?;
+    let events = equipment_queries::list_maintenance_for_profile(&state.pool, profile_id).await?;
+    let apps = queries::get_applications_for_profile(&state.pool, profile_id, 1000, 0).await?;
+    Ok(equipment_statuses(
+        equipment,
+        events,
+        &apps,
+        Local::now().date_naive(),
+    ))
+}
+
+async fn status_of(
+    state: &AppState,
+    profile_id: i64,
+    id: i64,
+) -> Result<EquipmentStatus, TurfOpsError> {
+    statuses(state, profile_id)
+        .await?
+        .into_iter()
+        .find(|s| s.equipment.id == Some(id))
+        .ok_or_else(|| TurfOpsError::NotFound(format!("Equipment {id} not found")))
+}
+
+/// GET /api/v1/equipment
+/// Equipment for the active profile with usage counts, estimated hours and
+/// maintenance history.
+pub async fn list_equipment(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<EquipmentStatus>>, TurfOpsError> {
+    let profile_id = active_profile_id(&state).await?;
+    Ok(Json(statuses(&state, profile_id).await?))
+}
+
+/// POST /api/v1/equipment
+pub async fn create_equipment(
+    State(state): State<AppState>,
+    Json(req): Json<EquipmentRequest>,
+) -> Result<(StatusCode, Json<EquipmentStatus>), TurfOpsError> {
+    let profile_id = active_profile_id(&state).await?;
+    let equipment = build_equipment(profile_id, None, req)?;
+    ensure_unique_name(&state, &equipment, None).await?;
+
+    let id = equipment_queries::create_equipment(&state.pool, &equipment).await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(status_of(&state, profile_id, id).await?),
+    ))
+}
+
+/// PUT /api/v1/equipment/:id
+pub async fn update_equipment(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Json(req): Json<EquipmentRequest>,
+) -> Result<Json<EquipmentStatus>, TurfOpsError> {
+    let profile_id = active_profile_id(&state).await?;
+    equipment_queries::get_equipment_for_profile(&state.pool, profile_id, id).await?;
+    let equipment = build_equipment(profile_id, Some(id), req)?;
+    ensure_unique_name(&state, &equipment, Some(id)).await?;
+
+    equipment_queries::update_equipment(&state.pool, id, &equipment).await?;
+    Ok(Json(status_of(&state, profile_id, id).await?))
+}
+
+/// DELETE /api/v1/equipment/:id
+/// Applications it was used for are kept, untagged.
+pub async fn delete_equipment(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<StatusCode, TurfOpsError> {
+    let profile_id = active_profile_id(&state).await?;
+    equipment_queries::get_equipment_for_profile(&state.pool, profile_id, id).await?;
+    equipment_queries::delete_equipment(&state.pool, id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// POST /api/v1/equipment/:id/maintenance
+/// Log maintenance; resets that task's hour counter and clears its
+/// seasonal reminder.
+pub async fn log_maintenance(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Json(req): Json<MaintenanceRequest>,
+) -> Result<(StatusCode, Json<EquipmentStatus>), TurfOpsError> {
+    let profile_id = active_profile_id(&state).await?;
+    equipment_queries::get_equipment_for_profile(&state.pool, profile_id, id).await?;
+    let task = EquipmentTask::from_str(&req.task).map_err(TurfOpsError::InvalidData)?;
+    let today = Local::now().date_naive();
+    let performed_on =
+        parse_optional_date("performed_on", req.performed_on.as_deref())?.unwrap_or(today);
+    if performed_on > today {
+        return Err(TurfOpsError::InvalidData(
+            "performed_on can't be in the future".into(),
+        ));
+    }
+
+    let event = MaintenanceEvent {
+        id: None,
+        equipment_id: id,
+        task,
+        performed_on,
+        notes: trimmed(req.notes),
+        created_at: Utc::now(),
+    };
+    equipment_queries::create_maintenance(&state.pool, &event).await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(status_of(&state, profile_id, id).await?),
+    ))
+}
+
+/// DELETE /api/v1/equipment/:id/maintenance/:event_id
+pub async fn delete_maintenance(
+    State(state): State<AppState>,
+    Path((id, event_id)): Path<(i64, i64)>,
+) -> Result<StatusCode, TurfOpsError> {
+    let profile_id = active_profile_id(&state).await?;
+    equipment_queries::get_equipment_for_profile(&state.pool, profile_id, id).await?;
+    if !equipment_queries::delete_maintenance(&state.pool, id, event_id).await? {
+        return Err(TurfOpsError::NotFound(format!(
+            "Maintenance entry {event_id} not found"
+        )));
+    }
+    Ok(StatusCode::NO_CONTENT)
+}
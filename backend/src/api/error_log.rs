@@ -0,0 +1,31 @@
+use crate::db::event_queries;
+use crate::error::ErrorDetail;
+use crate::models::{Event, EventKind, EventLevel};
+use crate::state::AppState;
+use axum::extract::{Request, State};
+use axum::middleware::Next;
+use axum::response::Response;
+
+/// Record server errors in the activity log. The response body only says "An
+/// internal error occurred", so the underlying error goes in the event details.
+pub async fn record_server_errors(
+    State(state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let method = request.method().clone();
+    let path = request.uri().path().to_string();
+    let response = next.run(request).await;
+
+    if let Some(ErrorDetail(detail)) = response.extensions().get::<ErrorDetail>() {
+        let event = Event::new(
+            EventKind::RequestFailed,
+            EventLevel::Error,
+            format!("{} {} failed ({})", method, path, response.status()),
+        )
+        .with_details(detail.clone());
+        event_queries::record_event(&state.pool, event).await;
+    }
+
+    response
+}
@@ -0,0 +1,57 @@
+use crate::db::event_queries;
+use crate::error::TurfOpsError;
+use crate::models::{Event, EventKind, EventLevel};
+use crate::state::AppState;
+use axum::extract::{Query, State};
+use axum::Json;
+use serde::Deserialize;
+use std::str::FromStr;
+
+const DEFAULT_PAGE_LIMIT: i64 = 100;
+const MAX_PAGE_LIMIT: i64 = 500;
+
+#[derive(Debug, Deserialize)]
+pub struct ListEventsQuery {
+    pub kind: Option<String>,
+    /// Minimum level to include (Info includes everything).
+    pub level: Option<String>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+/// GET /api/v1/events
+/// Activity log, newest first. Filter with `?kind=DataSourceError&level=Warning`.
+pub async fn list_events(
+    State(state): State<AppState>,
+    Query(params): Query<ListEventsQuery>,
+) -> Result<Json<Vec<Event>>, TurfOpsError> {
+    let kind = params
+        .kind
+        .as_deref()
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            EventKind::from_str(s)
+                .map_err(|_| TurfOpsError::InvalidData(format!("Unknown event kind filter: {}", s)))
+        })
+        .transpose()?;
+    let min_level = params
+        .level
+        .as_deref()
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            EventLevel::from_str(s).map_err(|_| {
+                TurfOpsError::InvalidData(format!("Unknown event level filter: {}", s))
+            })
+        })
+        .transpose()?;
+
+    let limit = params
+        .limit
+        .unwrap_or(DEFAULT_PAGE_LIMIT)
+        .clamp(1, MAX_PAGE_LIMIT);
+    let offset = params.offset.unwrap_or(0).max(0);
+
+    let events = event_queries::list_events(&state.pool, kind, min_level, limit, offset).await?;
+
+    Ok(Json(events))
+}
@@ -1,3 +1,4 @@
+use crate::db::queries;
 use crate::error::TurfOpsError;
 use crate::logic::gdd;
 use crate::models::GddSummary;
@@ -15,7 +16,9 @@ pub struct GddQuery {
 /// GET /api/v1/gdd?year=2026
 /// Returns GDD accumulation data for the requested year (defaults to current year).
 /// Reads the gold daily layer's precomputed `gdd50` from the data lake and accumulates
-/// the year-to-date running total on the fly (no DB cache).
+/// the year-to-date running total on the fly (no DB cache). Years the lake has
+/// no rows for come from the Open-Meteo archive, so seasons can be compared
+/// before the station's history starts.
 pub async fn get_gdd(
     State(state): State<AppState>,
     Query(params): Query<GddQuery>,
@@ -33,14 +36,31 @@ pub async fn get_gdd(
     };
 
     let records = if jan1 <= end_date {
-        let service = state.sync_service.read().await;
-        match service.weather_client() {
-            Some(client) => {
-                let rows = client.fetch_daily_gdd(jan1, end_date).await?;
-                gdd::accumulate_daily_gdd(&rows)
-            }
+        let profile = queries::get_active_lawn_profile(&state.pool)
+            .await?
+            .ok_or_else(|| TurfOpsError::NotFound("No lawn profile found".into()))?;
+        let (lake, archive) = {
+            let service = state.sync_service.read().await;
+            (
+                service.weather_client_for(&profile),
+                service.historical_client_for(&profile),
+            )
+        };
+        let mut rows = match lake {
+            Some(client) => client.fetch_daily_gdd(jan1, end_date).await?,
             None => Vec::new(),
+        };
+        if rows.is_empty() {
+            if let Some(client) = archive {
+                rows = client
+                    .fetch_daily(jan1, end_date)
+                    .await?
+                    .iter()
+                    .filter_map(|d| Some((d.date, d.high_temp_f?, d.low_temp_f?, d.gdd50()?)))
+                    .collect();
+            }
         }
+        gdd::accumulate_daily_gdd(&rows)
     } else {
         Vec::new()
     };
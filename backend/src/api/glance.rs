@@ -0,0 +1,46 @@
+use crate::api::recommendations::evaluate_recommendations;
+use crate::db::queries;
+use crate::error::TurfOpsError;
+use crate::logic::glance::{render_glance, GlanceInput};
+use crate::state::AppState;
+use axum::extract::State;
+use axum::response::Html;
+use chrono::{Datelike, Local, Months};
+
+/// GET /glance
+/// Read-only HTML dashboard for phones: conditions, active recommendations and
+/// this month's applications, rendered on the server from the same data as the API.
+pub async fn get_glance(State(state): State<AppState>) -> Result<Html<String>, TurfOpsError> {
+    let profile = queries::get_active_lawn_profile(&state.pool)
+        .await?
+        .ok_or_else(|| TurfOpsError::NotFound("No lawn profile found".into()))?;
+    let profile_id = profile
+        .id
+        .ok_or_else(|| TurfOpsError::InvalidData("Profile missing ID".into()))?;
+    let summary = {
+        let mut service = state.sync_service.write().await;
+        service.get_or_refresh(&profile).await?
+    };
+
+    let mut recommendations = evaluate_recommendations(&state, None).await?;
+    recommendations.retain(|r| r.is_active());
+
+    let now = Local::now().naive_local();
+    let month_start = now.date().with_day(1).unwrap_or(now.date());
+    let month_end = month_start + Months::new(1);
+    let applications = queries::get_applications_for_profile_in_range(
+        &state.pool,
+        profile_id,
+        month_start,
+        month_end,
+    )
+    .await?;
+
+    Ok(Html(render_glance(&GlanceInput {
+        lawn_name: &profile.name,
+        now,
+        summary: &summary,
+        recommendations: &recommendations,
+        applications: &applications,
+    })))
+}
@@ -1,16 +1,22 @@
+use crate::logic::api_quota::QuotaUsage;
 use crate::logic::data_sync::ConnectionStatus;
 use crate::state::AppState;
 use axum::extract::State;
 use axum::Json;
-use serde::Serialize;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
-#[derive(Serialize)]
+/// Also read by commands asking a running server (`cli/remote.rs`).
+#[derive(Serialize, Deserialize)]
 pub struct HealthResponse {
     pub status: String,
     pub version: String,
     pub database: bool,
     pub datasources: ConnectionStatus,
+    /// OpenWeatherMap calls today against the daily quota.
+    #[serde(default)]
+    pub openweathermap_usage: Option<QuotaUsage>,
 }
 
 pub async fn health_check(State(state): State<AppState>) -> Json<HealthResponse> {
@@ -22,14 +28,14 @@ pub async fn health_check(State(state): State<AppState>) -> Json<HealthResponse>
     .map(|r| r.is_ok())
     .unwrap_or(false);
 
-    // Check external datasource connectivity via read lock (doesn't block data sync)
-    let service = state.sync_service.read().await;
-    let datasources = service.check_connections().await;
+    // Checked in the background so a down source can't stall the health check
+    let datasources = state.connections.latest();
 
     Json(HealthResponse {
         status: if db_ok { "ok" } else { "degraded" }.to_string(),
         version: env!("CARGO_PKG_VERSION").to_string(),
         database: db_ok,
         datasources,
+        openweathermap_usage: state.forecast_quota.as_ref().map(|q| q.usage(Utc::now())),
     })
 }
@@ -1,3 +1,4 @@
+use crate::db::queries;
 use crate::error::TurfOpsError;
 use crate::logic::gdd;
 use crate::models::{HistoricalData, TimeSeriesPoint};
@@ -35,8 +36,11 @@ pub async fn get_historical(
         }
     };
 
+    let profile = queries::get_active_lawn_profile(&state.pool)
+        .await?
+        .ok_or_else(|| TurfOpsError::NotFound("No lawn profile found".into()))?;
     let service = state.sync_service.read().await;
-    let client = service.weather_client().ok_or_else(|| {
+    let client = service.weather_client_for(&profile).ok_or_else(|| {
         TurfOpsError::DataSourceUnavailable("Weather data lake not configured".into())
     })?;
 
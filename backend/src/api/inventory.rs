@@ -0,0 +1,200 @@
+use crate::db::{inventory_queries, queries};
+use crate::error::TurfOpsError;
+use crate::logic::inventory::inventory_statuses;
+use crate::models::dates::parse_optional_date;
+use crate::models::{ApplicationType, InventoryItem, InventoryStatus, LawnProfile, ProductUnit};
+use crate::state::AppState;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use chrono::{Local, Utc};
+use serde::Deserialize;
+use std::str::FromStr;
+
+#[derive(Debug, Deserialize)]
+pub struct InventoryRequest {
+    pub product_name: String,
+    /// What the product is used for (e.g. "PreEmergent").
+    pub application_type: Option<String>,
+    /// Defaults to pounds.
+    pub unit: Option<String>,
+    pub package_size: Option<f64>,
+    /// Amount on hand as of `counted_on`.
+    pub quantity: f64,
+    /// Defaults to today.
+    pub counted_on: Option<String>,
+    pub rate_per_1000sqft: Option<f64>,
+    pub notes: Option<String>,
+}
+
+async fn active_profile(state: &AppState) -> Result<(LawnProfile, i64), TurfOpsError> {
+    let profile = queries::get_active_lawn_profile(&state.pool)
+        .await?
+        .ok_or_else(|| TurfOpsError::NotFound("No lawn profile found".into()))?;
+    let id = profile
+        .id
+        .ok_or_else(|| TurfOpsError::InvalidData("Profile missing ID".into()))?;
+    Ok((profile, id))
+}
+
+fn positive(value: Option<f64>, field: &str) -> Result<(), TurfOpsError> {
+    match value {
+        Some(v) if !v.is_finite() || v <= 0.0 => Err(TurfOpsError::InvalidData(format!(
+            "{field} must be a positive number"
+        ))),
+        _ => Ok(()),
+    }
+}
+
+fn build_item(
+    profile_id: i64,
+    id: Option<i64>,
+    req: InventoryRequest,
+) -> Result<InventoryItem, TurfOpsError> {
+    let product_name = req.product_name.trim().to_string();
+    if product_name.is_empty() {
+        return Err(TurfOpsError::InvalidData(
+            "Product name can't be empty".into(),
+        ));
+    }
+    if !req.quantity.is_finite() || req.quantity < 0.0 {
+        return Err(TurfOpsError::InvalidData(
+            "quantity must be zero or more".into(),
+        ));
+    }
+    positive(req.package_size, "package_size")?;
+    positive(req.rate_per_1000sqft, "rate_per_1000sqft")?;
+
+    let application_type = req
+        .application_type
+        .as_deref()
+        .filter(|s| !s.is_empty())
+        .map(ApplicationType::from_str)
+        .transpose()
+        .map_err(TurfOpsError::InvalidData)?;
+    let unit = req
+        .unit
+        .as_deref()
+        .map(ProductUnit::from_str)
+        .transpose()
+        .map_err(TurfOpsError::InvalidData)?
+        .unwrap_or(ProductUnit::Pounds);
+
+    Ok(InventoryItem {
+        id,
+        lawn_profile_id: profile_id,
+        product_name,
+        application_type,
+        unit,
+        package_size: req.package_size,
+        quantity: req.quantity,
+        counted_on: parse_optional_date("counted_on", req.counted_on.as_deref())?
+            .unwrap_or_else(|| Local::now().date_naive()),
+        rate_per_1000sqft: req.rate_per_1000sqft,
+        notes: req
+            .notes
+            .map(|n| n.trim().to_string())
+            .filter(|n| !n.is_empty()),
+        created_at: Utc::now(),
+    })
+}
+
+/// Item with what's left after applications logged since its count.
+async fn status_of(
+    state: &AppState,
+    profile: &LawnProfile,
+    profile_id: i64,
+    item: InventoryItem,
+) -> Result<InventoryStatus, TurfOpsError> {
+    let apps = queries::get_applications_for_profile(&state.pool, profile_id, 1000, 0).await?;
+    let mut statuses = inventory_statuses(vec![item], &apps, profile, Local::now().date_naive());
+    statuses
+        .pop()
+        .ok_or_else(|| TurfOpsError::InvalidData("Inventory status missing".into()))
+}
+
+/// Product names are unique per profile; check up front for a readable 400.
+async fn ensure_unique_name(
+    state: &AppState,
+    item: &InventoryItem,
+    exclude: Option<i64>,
+) -> Result<(), TurfOpsError> {
+    let existing =
+        inventory_queries::list_items_for_profile(&state.pool, item.lawn_profile_id).await?;
+    if existing
+        .iter()
+        .any(|i| i.id != exclude && i.product_name.eq_ignore_ascii_case(&item.product_name))
+    {
+        return Err(TurfOpsError::InvalidData(format!(
+            "{} is already in inventory",
+            item.product_name
+        )));
+    }
+    Ok(())
+}
+
+/// GET /api/v1/inventory
+/// Products on hand for the active profile, with what's left after
+/// applications logged since each was counted.
+pub async fn list_inventory(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<InventoryStatus>>, TurfOpsError> {
+    let (profile, profile_id) = active_profile(&state).await?;
+    let items = inventory_queries::list_items_for_profile(&state.pool, profile_id).await?;
+    let apps = queries::get_applications_for_profile(&state.pool, profile_id, 1000, 0).await?;
+    Ok(Json(inventory_statuses(
+        items,
+        &apps,
+        &profile,
+        Local::now().date_naive(),
+    )))
+}
+
+/// POST /api/v1/inventory
+pub async fn create_inventory_item(
+    State(state): State<AppState>,
+    Json(req): Json<InventoryRequest>,
+) -> Result<(StatusCode, Json<InventoryStatus>), TurfOpsError> {
+    let (profile, profile_id) = active_profile(&state).await?;
+    let item = build_item(profile_id, None, req)?;
+    ensure_unique_name(&state, &item, None).await?;
+
+    let id = inventory_queries::create_item(&state.pool, &item).await?;
+    let item = InventoryItem {
+        id: Some(id),
+        ..item
+    };
+    Ok((
+        StatusCode::CREATED,
+        Json(status_of(&state, &profile, profile_id, item).await?),
+    ))
+}
+
+/// PUT /api/v1/inventory/:id
+/// Also used to restock or recount: send the new quantity and `counted_on`.
+pub async fn update_inventory_item(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Json(req): Json<InventoryRequest>,
+) -> Result<Json<InventoryStatus>, TurfOpsError> {
+    let (profile, profile_id) = active_profile(&state).await?;
+    inventory_queries::get_item_for_profile(&state.pool, profile_id, id).await?;
+    let item = build_item(profile_id, Some(id), req)?;
+    ensure_unique_name(&state, &item, Some(id)).await?;
+
+    let updated = inventory_queries::update_item(&state.pool, id, &item).await?;
+    Ok(Json(
+        status_of(&state, &profile, profile_id, updated).await?,
+    ))
+}
+
+/// DELETE /api/v1/inventory/:id
+pub async fn delete_inventory_item(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<StatusCode, TurfOpsError> {
+    let (_, profile_id) = active_profile(&state).await?;
+    inventory_queries::get_item_for_profile(&state.pool, profile_id, id).await?;
+    inventory_queries::delete_item(&state.pool, id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
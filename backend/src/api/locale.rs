@@ -0,0 +1,10 @@
+use crate::i18n::UiCatalog;
+use crate::state::AppState;
+use axum::extract::State;
+use axum::Json;
+
+/// GET /api/v1/locale
+/// Configured language and its UI labels; empty for English.
+pub async fn get_locale(State(state): State<AppState>) -> Json<UiCatalog> {
+    Json(state.rules_engine.catalog().ui())
+}
@@ -0,0 +1,150 @@
+use crate::db::{queries, settings_queries};
+use crate::error::TurfOpsError;
+use crate::models::{us_zip, Location};
+use crate::state::AppState;
+use axum::extract::{Query, State};
+use axum::Json;
+use serde::Deserialize;
+
+#[derive(Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum LocationScope {
+    /// The default for every profile without its own location.
+    #[default]
+    Global,
+    /// Only the active profile.
+    Profile,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LocationScopeQuery {
+    #[serde(default)]
+    pub scope: LocationScope,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SearchLocationQuery {
+    /// City ("West Chester, PA, US") or postal code ("19382").
+    pub q: String,
+}
+
+/// GET /api/v1/location
+/// Coordinates the active profile's forecast uses: its own location, else the
+/// global one. `null` when OpenWeatherMap isn't configured.
+pub async fn get_location(
+    State(state): State<AppState>,
+) -> Result<Json<Option<Location>>, TurfOpsError> {
+    let profile = queries::get_active_lawn_profile(&state.pool).await?;
+    let saved = settings_queries::get_location(&state.pool).await?;
+    let service = state.sync_service.read().await;
+    let Some(current) = service.location() else {
+        return Ok(Json(None));
+    };
+
+    // Prefer the saved records so the geocoded name comes back with them
+    Ok(Json(Some(
+        profile
+            .and_then(|p| p.location)
+            .or(saved)
+            .unwrap_or(current),
+    )))
+}
+
+/// GET /api/v1/location/search?q=19382
+/// Geocode a city or postal code to candidate locations for the user to confirm.
+/// US ZIP lookups also carry the location's USDA hardiness zone.
+pub async fn search_location(
+    State(state): State<AppState>,
+    Query(params): Query<SearchLocationQuery>,
+) -> Result<Json<Vec<Location>>, TurfOpsError> {
+    let query = params.q.trim();
+    if query.is_empty() {
+        return Err(TurfOpsError::InvalidData(
+            "Enter a city or postal code".into(),
+        ));
+    }
+
+    let mut results = {
+        let service = state.sync_service.read().await;
+        service.geocode(query).await?
+    };
+
+    if let (Some(zip), [place]) = (us_zip(query), results.as_mut_slice()) {
+        match state.hardiness.fetch_zone(zip).await {
+            Ok(zone) => place.usda_zone = zone,
+            // The zone is a convenience; the coordinates are still usable without it
+            Err(e) => tracing::warn!(zip, "USDA zone lookup failed: {}", e),
+        }
+    }
+
+    Ok(Json(results))
+}
+
+/// PUT /api/v1/location?scope=global|profile
+/// Save the confirmed location, either as the global default or for the active
+/// profile only, and refetch the forecast for it on the next refresh.
+pub async fn set_location(
+    State(state): State<AppState>,
+    Query(params): Query<LocationScopeQuery>,
+    Json(location): Json<Location>,
+) -> Result<Json<Location>, TurfOpsError> {
+    location.validate().map_err(TurfOpsError::InvalidData)?;
+
+    let mut service = state.sync_service.write().await;
+    if service.location().is_none() {
+        return Err(TurfOpsError::DataSourceUnavailable(
+            "OpenWeatherMap isn't configured (set OWM_API_KEY)".into(),
+        ));
+    }
+
+    let profile = queries::get_active_lawn_profile(&state.pool).await?;
+    match (params.scope, profile.clone()) {
+        (LocationScope::Global, _) => {
+            settings_queries::set_location(&state.pool, &location).await?;
+            service.set_location(&location);
+            tracing::info!(name = %location.name, "Forecast location updated");
+        }
+        (LocationScope::Profile, Some(mut profile)) => {
+            profile.location = Some(location.clone());
+            queries::update_lawn_profile(&state.pool, &profile).await?;
+            if let Some(id) = profile.id {
+                service.invalidate(id);
+            }
+            tracing::info!(name = %location.name, profile = %profile.name, "Profile forecast location updated");
+        }
+        (LocationScope::Profile, None) => {
+            return Err(TurfOpsError::NotFound("No lawn profile found".into()));
+        }
+    }
+
+    if let (Some(zone), Some(profile)) = (location.usda_zone.as_deref(), profile) {
+        if zone != profile.usda_zone {
+            tracing::warn!(
+                location_zone = zone,
+                profile_zone = %profile.usda_zone,
+                "Lawn profile USDA zone disagrees with the forecast location"
+            );
+        }
+    }
+
+    Ok(Json(location))
+}
+
+/// DELETE /api/v1/location
+/// Drop the active profile's own location so it follows the global one again.
+pub async fn clear_profile_location(
+    State(state): State<AppState>,
+) -> Result<Json<Option<Location>>, TurfOpsError> {
+    let mut profile = queries::get_active_lawn_profile(&state.pool)
+        .await?
+        .ok_or_else(|| TurfOpsError::NotFound("No lawn profile found".into()))?;
+
+    if profile.location.take().is_some() {
+        queries::update_lawn_profile(&state.pool, &profile).await?;
+        if let Some(id) = profile.id {
+            state.sync_service.write().await.invalidate(id);
+        }
+    }
+
+    get_location(State(state)).await
+}
@@ -0,0 +1,50 @@
+use crate::error::TurfOpsError;
+use crate::logging::{self, MAX_TAIL_LINES};
+use crate::state::AppState;
+use axum::extract::{Query, State};
+use axum::Json;
+use serde::{Deserialize, Serialize};
+
+const DEFAULT_TAIL_LINES: usize = 200;
+
+#[derive(Debug, Deserialize)]
+pub struct LogTailQuery {
+    pub lines: Option<usize>,
+}
+
+#[derive(Serialize)]
+pub struct LogTailResponse {
+    /// Name of the file the lines came from (the newest one), or null if none yet.
+    pub file: Option<String>,
+    pub lines: Vec<String>,
+}
+
+/// GET /api/v1/logs?lines=N
+/// Last N lines of the newest log file. Requires `LOG_DIR`.
+pub async fn tail_logs(
+    State(state): State<AppState>,
+    Query(params): Query<LogTailQuery>,
+) -> Result<Json<LogTailResponse>, TurfOpsError> {
+    let dir = state.log_dir.clone().ok_or_else(|| {
+        TurfOpsError::DataSourceUnavailable("File logging is off (set LOG_DIR)".into())
+    })?;
+    let lines = params
+        .lines
+        .unwrap_or(DEFAULT_TAIL_LINES)
+        .clamp(1, MAX_TAIL_LINES);
+
+    tokio::task::spawn_blocking(move || {
+        let Some(path) = logging::latest_log_file(&dir)? else {
+            return Ok(Json(LogTailResponse {
+                file: None,
+                lines: Vec::new(),
+            }));
+        };
+        Ok(Json(LogTailResponse {
+            file: path.file_name().map(|n| n.to_string_lossy().into_owned()),
+            lines: logging::tail(&path, lines)?,
+        }))
+    })
+    .await
+    .map_err(|e| TurfOpsError::Io(std::io::Error::other(e)))?
+}
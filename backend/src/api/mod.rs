@@ -1,14 +1,34 @@
+pub mod annual_review;
+pub mod application_types;
 pub mod applications;
+pub mod areas;
 pub mod calendar;
 pub mod dashboard;
+pub mod efficacy;
 pub mod environmental;
+pub mod equipment;
+pub mod error_log;
+pub mod events;
 pub mod gdd;
+pub mod glance;
 pub mod health;
 pub mod historical;
+pub mod inventory;
+pub mod locale;
+pub mod location;
+pub mod logs;
 pub mod nitrogen_budget;
+pub mod observations;
+pub mod photos;
 pub mod plants;
+pub mod products;
 pub mod profile;
 pub mod recommendations;
+pub mod report;
+pub mod rules;
+pub mod season_bundle;
 pub mod seasonal_plan;
 pub mod soil_temp_prediction;
 pub mod soil_tests;
+pub mod stress_index;
+pub mod ui_state;
@@ -1,6 +1,8 @@
-use crate::db::queries;
+use crate::db::{area_queries, queries};
 use crate::error::TurfOpsError;
-use crate::models::{nitrogen_budget::annual_n_target, NitrogenApplication, NitrogenBudget};
+use crate::models::{
+    lawn_fraction, nitrogen_budget::annual_n_target, NitrogenApplication, NitrogenBudget,
+};
 use crate::state::AppState;
 use axum::extract::{Query, State};
 use axum::Json;
@@ -10,17 +12,21 @@ use serde::Deserialize;
 #[derive(Debug, Deserialize)]
 pub struct NitrogenBudgetQuery {
     pub year: Option<i32>,
+    /// Budget for one lawn area instead of the whole profile.
+    pub area_id: Option<i64>,
 }
 
 /// GET /api/v1/nitrogen-budget?year=2026
 /// Returns nitrogen budget for the requested year based on application history.
+/// Area-tagged applications count toward the whole-lawn total in proportion to
+/// the area's share of the lawn; `?area_id=` budgets that area on its own.
 pub async fn get_nitrogen_budget(
     State(state): State<AppState>,
     Query(params): Query<NitrogenBudgetQuery>,
 ) -> Result<Json<NitrogenBudget>, TurfOpsError> {
     let year = params.year.unwrap_or_else(|| Local::now().year());
 
-    let profile = queries::get_default_lawn_profile(&state.pool)
+    let profile = queries::get_active_lawn_profile(&state.pool)
         .await?
         .ok_or_else(|| TurfOpsError::NotFound("No lawn profile found".into()))?;
 
@@ -33,7 +39,7 @@ pub async fn get_nitrogen_budget(
     let end_date = NaiveDate::from_ymd_opt(year + 1, 1, 1)
         .ok_or_else(|| TurfOpsError::InvalidData(format!("Invalid year: {}", year)))?;
 
-    let apps = queries::get_applications_for_profile_in_range(
+    let mut apps = queries::get_applications_for_profile_in_range(
         &state.pool,
         profile_id,
         start_date,
@@ -41,7 +47,15 @@ pub async fn get_nitrogen_budget(
     )
     .await?;
 
-    let target = annual_n_target(profile.grass_type);
+    let areas = area_queries::list_areas_for_profile(&state.pool, profile_id).await?;
+    let mut grass_type = profile.grass_type;
+    if let Some(area_id) = params.area_id {
+        let area = area_queries::get_area_for_profile(&state.pool, profile_id, area_id).await?;
+        apps.retain(|a| area.covers(a));
+        grass_type = area.grass_type.unwrap_or(grass_type);
+    }
+
+    let target = annual_n_target(grass_type);
 
     // Calculate N applied from applications that have both nitrogen_pct and rate_per_1000sqft
     let mut n_applications = Vec::new();
@@ -50,7 +64,12 @@ pub async fn get_nitrogen_budget(
     for app in &apps {
         if let (Some(n_pct), Some(rate)) = (app.nitrogen_pct, app.rate_per_1000sqft) {
             if n_pct > 0.0 && rate > 0.0 {
-                let n_lbs = n_pct / 100.0 * rate;
+                let fraction = if params.area_id.is_some() {
+                    1.0
+                } else {
+                    lawn_fraction(app, &areas, profile.lawn_size_sqft)
+                };
+                let n_lbs = n_pct / 100.0 * rate * fraction;
                 total_n_applied += n_lbs;
                 n_applications.push(NitrogenApplication {
                     date: app.application_date,
@@ -0,0 +1,141 @@
+use crate::db::{observation_queries, queries};
+use crate::error::TurfOpsError;
+use crate::models::dates::parse_timestamp;
+use crate::models::{Observation, ObservationCategory};
+use crate::state::AppState;
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::Json;
+use chrono::Utc;
+use serde::Deserialize;
+use std::str::FromStr;
+
+const DEFAULT_PAGE_LIMIT: i64 = 100;
+const MAX_PAGE_LIMIT: i64 = 500;
+
+#[derive(Debug, Deserialize)]
+pub struct ListObservationsQuery {
+    pub category: Option<String>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ObservationRequest {
+    /// RFC 3339 timestamp; defaults to now.
+    pub observed_at: Option<String>,
+    pub category: String,
+    pub notes: String,
+}
+
+async fn active_profile_id(state: &AppState) -> Result<i64, TurfOpsError> {
+    let profile = queries::get_active_lawn_profile(&state.pool)
+        .await?
+        .ok_or_else(|| TurfOpsError::NotFound("No lawn profile found".into()))?;
+
+    profile
+        .id
+        .ok_or_else(|| TurfOpsError::InvalidData("Profile missing ID".into()))
+}
+
+fn parse_category(s: &str) -> Result<ObservationCategory, TurfOpsError> {
+    ObservationCategory::from_str(s).map_err(TurfOpsError::InvalidData)
+}
+
+fn build_observation(
+    profile_id: i64,
+    id: Option<i64>,
+    req: ObservationRequest,
+) -> Result<Observation, TurfOpsError> {
+    let notes = req.notes.trim().to_string();
+    if notes.is_empty() {
+        return Err(TurfOpsError::InvalidData(
+            "Observation notes can't be empty".into(),
+        ));
+    }
+
+    let observed_at = match req.observed_at.as_deref() {
+        Some(s) => parse_timestamp("observed_at", s)?,
+        None => Utc::now(),
+    };
+
+    Ok(Observation {
+        id,
+        lawn_profile_id: profile_id,
+        observed_at,
+        category: parse_category(&req.category)?,
+        notes,
+        created_at: Utc::now(),
+    })
+}
+
+/// GET /api/v1/observations
+/// Season journal, newest first. Filter with `?category=Weeds`.
+pub async fn list_observations(
+    State(state): State<AppState>,
+    Query(params): Query<ListObservationsQuery>,
+) -> Result<Json<Vec<Observation>>, TurfOpsError> {
+    let profile_id = active_profile_id(&state).await?;
+    let category = params
+        .category
+        .as_deref()
+        .filter(|s| !s.is_empty())
+        .map(parse_category)
+        .transpose()?;
+
+    let limit = params
+        .limit
+        .unwrap_or(DEFAULT_PAGE_LIMIT)
+        .clamp(1, MAX_PAGE_LIMIT);
+    let offset = params.offset.unwrap_or(0).max(0);
+
+    let observations = observation_queries::get_observations_for_profile(
+        &state.pool,
+        profile_id,
+        category,
+        limit,
+        offset,
+    )
+    .await?;
+
+    Ok(Json(observations))
+}
+
+/// POST /api/v1/observations
+pub async fn create_observation(
+    State(state): State<AppState>,
+    Json(req): Json<ObservationRequest>,
+) -> Result<(StatusCode, Json<Observation>), TurfOpsError> {
+    let profile_id = active_profile_id(&state).await?;
+    let observation = build_observation(profile_id, None, req)?;
+
+    let id = observation_queries::create_observation(&state.pool, &observation).await?;
+    let created = Observation {
+        id: Some(id),
+        ..observation
+    };
+
+    Ok((StatusCode::CREATED, Json(created)))
+}
+
+/// PUT /api/v1/observations/:id
+pub async fn update_observation(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Json(req): Json<ObservationRequest>,
+) -> Result<Json<Observation>, TurfOpsError> {
+    let profile_id = active_profile_id(&state).await?;
+    let observation = build_observation(profile_id, Some(id), req)?;
+
+    let updated = observation_queries::update_observation(&state.pool, id, &observation).await?;
+    Ok(Json(updated))
+}
+
+/// DELETE /api/v1/observations/:id
+pub async fn delete_observation(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<StatusCode, TurfOpsError> {
+    observation_queries::delete_observation(&state.pool, id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,135 @@
+use crate::db::{area_queries, photo_queries, queries};
+use crate::error::TurfOpsError;
+use crate::models::dates::parse_optional_date;
+use crate::models::Photo;
+use crate::state::AppState;
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::Json;
+use chrono::{Local, Utc};
+use serde::Deserialize;
+
+const DEFAULT_PAGE_LIMIT: i64 = 100;
+const MAX_PAGE_LIMIT: i64 = 500;
+
+#[derive(Debug, Deserialize)]
+pub struct ListPhotosQuery {
+    pub area_id: Option<i64>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PhotoRequest {
+    /// Defaults to today.
+    pub taken_on: Option<String>,
+    pub area_id: Option<i64>,
+    pub path: String,
+    pub caption: Option<String>,
+}
+
+async fn active_profile_id(state: &AppState) -> Result<i64, TurfOpsError> {
+    let profile = queries::get_active_lawn_profile(&state.pool)
+        .await?
+        .ok_or_else(|| TurfOpsError::NotFound("No lawn profile found".into()))?;
+
+    profile
+        .id
+        .ok_or_else(|| TurfOpsError::InvalidData("Profile missing ID".into()))
+}
+
+async fn build_photo(
+    state: &AppState,
+    profile_id: i64,
+    id: Option<i64>,
+    req: PhotoRequest,
+) -> Result<Photo, TurfOpsError> {
+    let path = req.path.trim().to_string();
+    if path.is_empty() {
+        return Err(TurfOpsError::InvalidData(
+            "Photo path or file name can't be empty".into(),
+        ));
+    }
+    if let Some(area_id) = req.area_id {
+        area_queries::get_area_for_profile(&state.pool, profile_id, area_id).await?;
+    }
+
+    Ok(Photo {
+        id,
+        lawn_profile_id: profile_id,
+        area_id: req.area_id,
+        taken_on: parse_optional_date("taken_on", req.taken_on.as_deref())?
+            .unwrap_or_else(|| Local::now().date_naive()),
+        path,
+        caption: req
+            .caption
+            .map(|c| c.trim().to_string())
+            .filter(|c| !c.is_empty()),
+        created_at: Utc::now(),
+    })
+}
+
+/// GET /api/v1/photos
+/// Photo journal, newest first. Filter with `?area_id=`.
+pub async fn list_photos(
+    State(state): State<AppState>,
+    Query(params): Query<ListPhotosQuery>,
+) -> Result<Json<Vec<Photo>>, TurfOpsError> {
+    let profile_id = active_profile_id(&state).await?;
+    let limit = params
+        .limit
+        .unwrap_or(DEFAULT_PAGE_LIMIT)
+        .clamp(1, MAX_PAGE_LIMIT);
+    let offset = params.offset.unwrap_or(0).max(0);
+
+    let photos = photo_queries::get_photos_for_profile(
+        &state.pool,
+        profile_id,
+        params.area_id,
+        limit,
+        offset,
+    )
+    .await?;
+
+    Ok(Json(photos))
+}
+
+/// POST /api/v1/photos
+pub async fn create_photo(
+    State(state): State<AppState>,
+    Json(req): Json<PhotoRequest>,
+) -> Result<(StatusCode, Json<Photo>), TurfOpsError> {
+    let profile_id = active_profile_id(&state).await?;
+    let photo = build_photo(&state, profile_id, None, req).await?;
+
+    let id = photo_queries::create_photo(&state.pool, &photo).await?;
+    let created = Photo {
+        id: Some(id),
+        ..photo
+    };
+
+    Ok((StatusCode::CREATED, Json(created)))
+}
+
+/// PUT /api/v1/photos/:id
+pub async fn update_photo(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Json(req): Json<PhotoRequest>,
+) -> Result<Json<Photo>, TurfOpsError> {
+    let profile_id = active_profile_id(&state).await?;
+    let photo = build_photo(&state, profile_id, Some(id), req).await?;
+
+    let updated = photo_queries::update_photo(&state.pool, id, &photo).await?;
+    Ok(Json(updated))
+}
+
+/// DELETE /api/v1/photos/:id
+pub async fn delete_photo(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<StatusCode, TurfOpsError> {
+    let profile_id = active_profile_id(&state).await?;
+    photo_queries::delete_photo(&state.pool, profile_id, id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
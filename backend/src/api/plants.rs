@@ -1,12 +1,13 @@
 use crate::datasources::openrouter::PlantPlanRequest;
 use crate::db::{plant_queries, queries};
 use crate::error::TurfOpsError;
+use crate::models::dates::parse_optional_date;
 use crate::models::plant::{Plant, PlantType};
 use crate::state::AppState;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::Json;
-use chrono::{NaiveDate, Utc};
+use chrono::Utc;
 use serde::Deserialize;
 use std::str::FromStr;
 
@@ -50,7 +51,7 @@ pub async fn create_plant(
         })?
         .clone();
 
-    let profile = queries::get_default_lawn_profile(&state.pool)
+    let profile = queries::get_active_lawn_profile(&state.pool)
         .await?
         .ok_or_else(|| TurfOpsError::NotFound("No lawn profile found".into()))?;
     let profile_id = profile
@@ -72,13 +73,7 @@ pub async fn create_plant(
         }
     };
 
-    let planting_date = match req.planting_date.as_deref() {
-        Some(s) if !s.is_empty() => Some(
-            NaiveDate::parse_from_str(s, "%Y-%m-%d")
-                .map_err(|_| TurfOpsError::InvalidData(format!("Invalid planting_date: {}", s)))?,
-        ),
-        _ => None,
-    };
+    let planting_date = parse_optional_date("planting_date", req.planting_date.as_deref())?;
 
     let plan = openrouter
         .generate_plant_plan(PlantPlanRequest {
@@ -143,9 +138,7 @@ pub async fn update_plant(
     };
 
     let planting_date = match req.planting_date {
-        Some(Some(s)) => Some(Some(NaiveDate::parse_from_str(&s, "%Y-%m-%d").map_err(
-            |_| TurfOpsError::InvalidData(format!("Invalid planting_date: {}", s)),
-        )?)),
+        Some(Some(s)) => Some(parse_optional_date("planting_date", Some(&s))?),
         Some(None) => Some(None),
         None => None,
     };
@@ -193,7 +186,7 @@ pub async fn refresh_plant_plan(
         .await?
         .ok_or_else(|| TurfOpsError::NotFound(format!("Plant {} not found", id)))?;
 
-    let profile = queries::get_default_lawn_profile(&state.pool)
+    let profile = queries::get_active_lawn_profile(&state.pool)
         .await?
         .ok_or_else(|| TurfOpsError::NotFound("No lawn profile found".into()))?;
 
@@ -220,7 +213,7 @@ pub async fn refresh_plant_plan(
 }
 
 async fn active_profile_id(state: &AppState) -> Result<i64, TurfOpsError> {
-    queries::get_default_lawn_profile(&state.pool)
+    queries::get_active_lawn_profile(&state.pool)
         .await?
         .ok_or_else(|| TurfOpsError::NotFound("No lawn profile found".into()))?
         .id
@@ -0,0 +1,107 @@
+use crate::db::product_queries;
+use crate::error::TurfOpsError;
+use crate::logic::product_labels::{self, ImportReport, LabelFormat};
+use crate::models::{LabelEntry, LabelProduct};
+use crate::state::AppState;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use serde::Deserialize;
+use sqlx::PgPool;
+
+#[derive(Debug, Deserialize)]
+pub struct ImportRequest {
+    pub format: LabelFormat,
+    /// The label file's text.
+    pub content: String,
+}
+
+fn checked(label: LabelEntry) -> Result<LabelEntry, TurfOpsError> {
+    let label = label.normalized();
+    label.validate().map_err(TurfOpsError::InvalidData)?;
+    Ok(label)
+}
+
+/// Names are unique regardless of case; check up front for a readable 400
+/// instead of a constraint violation.
+async fn ensure_unique_name(
+    pool: &PgPool,
+    label: &LabelEntry,
+    exclude: Option<i64>,
+) -> Result<(), TurfOpsError> {
+    let existing = product_queries::list_label_products(pool).await?;
+    if existing
+        .iter()
+        .any(|p| Some(p.id) != exclude && p.label.name.eq_ignore_ascii_case(&label.name))
+    {
+        return Err(TurfOpsError::InvalidData(format!(
+            "A product named {} already exists",
+            label.name
+        )));
+    }
+    Ok(())
+}
+
+/// GET /api/v1/products
+/// Label products, imported and hand-entered, by name.
+pub async fn list_products(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<LabelProduct>>, TurfOpsError> {
+    Ok(Json(
+        product_queries::list_label_products(&state.pool).await?,
+    ))
+}
+
+/// POST /api/v1/products
+pub async fn create_product(
+    State(state): State<AppState>,
+    Json(req): Json<LabelEntry>,
+) -> Result<(StatusCode, Json<LabelProduct>), TurfOpsError> {
+    let label = checked(req)?;
+    ensure_unique_name(&state.pool, &label, None).await?;
+
+    let product = product_queries::create_label_product(&state.pool, &label).await?;
+    product_labels::reload(&state.pool).await?;
+    Ok((StatusCode::CREATED, Json(product)))
+}
+
+/// PUT /api/v1/products/:id
+/// Marks the product edited, so later imports leave it alone.
+pub async fn update_product(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Json(req): Json<LabelEntry>,
+) -> Result<Json<LabelProduct>, TurfOpsError> {
+    product_queries::get_label_product(&state.pool, id).await?;
+    let label = checked(req)?;
+    ensure_unique_name(&state.pool, &label, Some(id)).await?;
+
+    let product = product_queries::update_label_product(&state.pool, id, &label).await?;
+    product_labels::reload(&state.pool).await?;
+    Ok(Json(product))
+}
+
+/// DELETE /api/v1/products/:id
+/// A built-in product of the same name applies again; the next import
+/// brings an imported one back.
+pub async fn delete_product(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<StatusCode, TurfOpsError> {
+    product_queries::get_label_product(&state.pool, id).await?;
+    product_queries::delete_label_product(&state.pool, id).await?;
+    product_labels::reload(&state.pool).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// POST /api/v1/products/import
+/// Merge a label file: new products are added, imported ones refreshed and
+/// edited ones kept.
+pub async fn import_products(
+    State(state): State<AppState>,
+    Json(req): Json<ImportRequest>,
+) -> Result<Json<ImportReport>, TurfOpsError> {
+    Ok(Json(
+        product_labels::import_labels(&state.pool, &req.content, req.format).await?,
+    ))
+}
@@ -1,14 +1,24 @@
-use crate::db::queries;
+use crate::db::{queries, settings_queries};
 use crate::error::TurfOpsError;
-use crate::models::{GrassType, IrrigationType, LawnProfile, SoilType};
+use crate::models::{normalize_usda_zone, GrassType, IrrigationType, LawnProfile, SoilType};
 use crate::state::AppState;
-use axum::extract::State;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
 use axum::Json;
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer};
 use std::str::FromStr;
 
+/// Distinguish an explicit `null` (clear the field) from an absent field (leave it).
+fn nullable<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
 pub async fn get_profile(State(state): State<AppState>) -> Result<Json<LawnProfile>, TurfOpsError> {
-    let profile = queries::get_default_lawn_profile(&state.pool)
+    let profile = queries::get_active_lawn_profile(&state.pool)
         .await?
         .ok_or_else(|| TurfOpsError::NotFound("No lawn profile found".into()))?;
 
@@ -23,13 +33,20 @@ pub struct UpdateProfileRequest {
     pub soil_type: Option<String>,
     pub lawn_size_sqft: Option<f64>,
     pub irrigation_type: Option<String>,
+    /// USCRN station override; `null` reverts to `NOAA_STATION_WBANNO`.
+    #[serde(default, deserialize_with = "nullable")]
+    pub noaa_station_wbanno: Option<Option<i32>>,
+    pub dormant: Option<bool>,
+    /// Target height of cut in inches; `null` reverts to the grass default.
+    #[serde(default, deserialize_with = "nullable")]
+    pub mowing_height_in: Option<Option<f64>>,
 }
 
 pub async fn update_profile(
     State(state): State<AppState>,
     Json(req): Json<UpdateProfileRequest>,
 ) -> Result<Json<LawnProfile>, TurfOpsError> {
-    let mut profile = queries::get_default_lawn_profile(&state.pool)
+    let mut profile = queries::get_active_lawn_profile(&state.pool)
         .await?
         .ok_or_else(|| TurfOpsError::NotFound("No lawn profile found".into()))?;
 
@@ -41,7 +58,7 @@ pub async fn update_profile(
             .map_err(|_| TurfOpsError::InvalidData(format!("Unknown grass type: {}", gt)))?;
     }
     if let Some(zone) = req.usda_zone {
-        profile.usda_zone = zone;
+        profile.usda_zone = normalize_usda_zone(&zone).map_err(TurfOpsError::InvalidData)?;
     }
     if let Some(st) = req.soil_type {
         profile.soil_type = Some(
@@ -59,12 +76,118 @@ pub async fn update_profile(
             })?);
     }
 
+    if let Some(station) = req.noaa_station_wbanno {
+        if station.is_some_and(|s| s <= 0) {
+            return Err(TurfOpsError::InvalidData(
+                "Station WBANNO must be a positive number".into(),
+            ));
+        }
+        if station != profile.noaa_station_wbanno {
+            profile.noaa_station_wbanno = station;
+            if let Some(id) = profile.id {
+                state.sync_service.write().await.invalidate(id);
+            }
+        }
+    }
+
+    if let Some(dormant) = req.dormant {
+        profile.dormant = dormant;
+    }
+    if let Some(height) = req.mowing_height_in {
+        if height.is_some_and(|h| !(0.5..=6.0).contains(&h)) {
+            return Err(TurfOpsError::InvalidData(
+                "Mowing height must be between 0.5 and 6 inches".into(),
+            ));
+        }
+        profile.mowing_height_in = height;
+    }
+
     queries::update_lawn_profile(&state.pool, &profile).await?;
 
     // Re-fetch to get updated_at from DB
-    let updated = queries::get_default_lawn_profile(&state.pool)
+    let updated = queries::get_active_lawn_profile(&state.pool)
         .await?
         .ok_or_else(|| TurfOpsError::NotFound("Profile disappeared after update".into()))?;
 
     Ok(Json(updated))
 }
+
+/// GET /api/v1/profiles
+pub async fn list_profiles(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<LawnProfile>>, TurfOpsError> {
+    Ok(Json(queries::list_lawn_profiles(&state.pool).await?))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateProfileRequest {
+    pub name: String,
+    pub grass_type: String,
+    pub usda_zone: String,
+}
+
+/// POST /api/v1/profiles
+/// Add another lawn. It starts on the global location and station; switch to
+/// it and set its own on the Settings page.
+pub async fn create_profile(
+    State(state): State<AppState>,
+    Json(req): Json<CreateProfileRequest>,
+) -> Result<(StatusCode, Json<LawnProfile>), TurfOpsError> {
+    let name = req.name.trim();
+    if name.is_empty() {
+        return Err(TurfOpsError::InvalidData("Profile name is required".into()));
+    }
+    let grass_type = GrassType::from_str(&req.grass_type).map_err(|_| {
+        TurfOpsError::InvalidData(format!("Unknown grass type: {}", req.grass_type))
+    })?;
+    let usda_zone = normalize_usda_zone(&req.usda_zone).map_err(TurfOpsError::InvalidData)?;
+
+    let mut profile = LawnProfile::new(name.to_string(), grass_type, usda_zone);
+    profile.id = Some(queries::create_lawn_profile(&state.pool, &profile).await?);
+    tracing::info!(name = %profile.name, "Lawn profile created");
+
+    Ok((StatusCode::CREATED, Json(profile)))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SetActiveProfileRequest {
+    pub id: i64,
+}
+
+/// PUT /api/v1/profiles/active
+/// Switch which lawn the app shows. Every page reads the active profile.
+pub async fn set_active_profile(
+    State(state): State<AppState>,
+    Json(req): Json<SetActiveProfileRequest>,
+) -> Result<Json<LawnProfile>, TurfOpsError> {
+    let profile = queries::get_lawn_profile(&state.pool, req.id)
+        .await?
+        .ok_or_else(|| TurfOpsError::NotFound(format!("Profile {} not found", req.id)))?;
+
+    settings_queries::set_active_profile_id(&state.pool, req.id).await?;
+    tracing::info!(name = %profile.name, "Active lawn profile changed");
+
+    Ok(Json(profile))
+}
+
+/// DELETE /api/v1/profiles/{id}
+/// Deletes the profile and everything logged against it. The active profile
+/// can't be deleted; switch away first.
+pub async fn delete_profile(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<StatusCode, TurfOpsError> {
+    let active = queries::get_active_lawn_profile(&state.pool).await?;
+    if active.and_then(|p| p.id) == Some(id) {
+        return Err(TurfOpsError::InvalidData(
+            "Can't delete the active profile; switch to another one first".into(),
+        ));
+    }
+
+    if queries::delete_lawn_profile(&state.pool, id).await? == 0 {
+        return Err(TurfOpsError::NotFound(format!("Profile {} not found", id)));
+    }
+    state.sync_service.write().await.invalidate(id);
+
+    Ok(StatusCode::NO_CONTENT)
+}
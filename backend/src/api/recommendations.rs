@@ -1,43 +1,202 @@
-use crate::db::{plant_queries, queries, soil_test_queries};
+use crate::db::{
+    area_queries, equipment_queries, event_queries, inventory_queries, plant_queries, queries,
+    rain_check_queries, soil_test_queries,
+};
 use crate::error::TurfOpsError;
+use crate::logic::aftercare::generate_aftercare_recommendations;
+use crate::logic::equipment::{equipment_statuses, generate_equipment_recommendations};
 use crate::logic::follow_up::generate_follow_up_recommendations;
+use crate::logic::inventory::{annotate_shortfalls, inventory_statuses};
 use crate::logic::plant_maintenance::generate_plant_maintenance_recommendations;
+use crate::logic::rain_check::{generate_rain_check_recommendations, FOLLOW_UP_HOURS};
+use crate::logic::rules::engine::RuleTrace;
+use crate::logic::rules::post_process;
+use crate::logic::rules::window_closing::missed_windows;
+use crate::logic::seen::mark_changes;
 use crate::logic::soil_test_recommendations::generate_soil_test_recommendations;
-use crate::models::{DataSource, Recommendation, RecommendationCategory, Severity};
+use crate::models::{
+    Application, DataSource, Event, EventKind, EventLevel, LawnProfile, Recommendation,
+    RecommendationCategory, Severity, SoilWaterLimits,
+};
 use crate::state::AppState;
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
 use axum::Json;
-use chrono::Local;
+use chrono::{Datelike, Duration, Local, NaiveDate, Utc};
 use serde::Deserialize;
+use sqlx::PgPool;
+
+/// Days after a window closes during which its miss is still recorded.
+const MISSED_WINDOW_RECORD_DAYS: i64 = 30;
+
+#[derive(Debug, Deserialize)]
+pub struct RecommendationsQuery {
+    /// Evaluate for one lawn area instead of the whole profile.
+    pub area_id: Option<i64>,
+}
+
+async fn active_profile_id(state: &AppState) -> Result<i64, TurfOpsError> {
+    let profile = queries::get_active_lawn_profile(&state.pool)
+        .await?
+        .ok_or_else(|| TurfOpsError::NotFound("No lawn profile found".into()))?;
+
+    profile
+        .id
+        .ok_or_else(|| TurfOpsError::InvalidData("Profile missing ID".into()))
+}
+
+/// Narrow the profile and application history to one lawn area: rules see the
+/// area's grass and size, and only applications that covered it.
+async fn scope_to_area(
+    pool: &PgPool,
+    profile: LawnProfile,
+    mut apps: Vec<Application>,
+    area_id: Option<i64>,
+) -> Result<(LawnProfile, Vec<Application>), TurfOpsError> {
+    let (Some(area_id), Some(profile_id)) = (area_id, profile.id) else {
+        return Ok((profile, apps));
+    };
+    let area = area_queries::get_area_for_profile(pool, profile_id, area_id).await?;
+    apps.retain(|a| area.covers(a));
+    Ok((area.scope_profile(&profile), apps))
+}
 
 /// GET /api/v1/recommendations
 /// Evaluates all rules against current environmental data and application history.
-/// Returns active recommendations (not dismissed/addressed).
+/// Returns active recommendations (not dismissed/addressed). Pass `?area_id=`
+/// to evaluate for a single lawn area.
 pub async fn list_recommendations(
     State(state): State<AppState>,
+    Query(params): Query<RecommendationsQuery>,
 ) -> Result<Json<Vec<Recommendation>>, TurfOpsError> {
-    // Get current environmental data (refreshes if stale)
-    let summary = {
-        let mut service = state.sync_service.write().await;
-        service.get_or_refresh().await?
-    };
+    let mut recommendations = evaluate_recommendations(&state, params.area_id).await?;
 
-    // Get current profile and application history
-    let profile = queries::get_default_lawn_profile(&state.pool)
+    // Return only active recommendations
+    let produced = recommendations.len();
+    recommendations.retain(|r| r.is_active());
+
+    // Log only when the active set changes, not on every page load. Rule ids
+    // repeat across profiles and areas, so compare against the last set for
+    // this one.
+    let mut scope = format!("profile {}", active_profile_id(&state).await?);
+    if let Some(area_id) = params.area_id {
+        scope.push_str(&format!(", area {}", area_id));
+    }
+    scope.push_str(": ");
+    let mut ids: Vec<&str> = recommendations.iter().map(|r| r.id.as_str()).collect();
+    ids.sort_unstable();
+    let details = format!("{}{}", scope, ids.join(", "));
+    let unchanged = matches!(
+        event_queries::latest_details_for(&state.pool, EventKind::RuleEvaluation, &scope).await,
+        Ok(Some(last)) if last == details
+    );
+    if !unchanged {
+        event_queries::record_event(
+            &state.pool,
+            Event::new(
+                EventKind::RuleEvaluation,
+                EventLevel::Info,
+                format!(
+                    "{} active recommendations ({} suppressed as dismissed/addressed)",
+                    recommendations.len(),
+                    produced - recommendations.len()
+                ),
+            )
+            .with_details(details),
+        )
+        .await;
+    }
+
+    Ok(Json(recommendations))
+}
+
+/// Record a Missed Window event the first time a closed, unmet seasonal
+/// window is seen. Only recent closings count, so windows that closed long
+/// before the app was set up (or whose event was pruned) stay quiet. Best
+/// effort, like all event logging.
+async fn record_missed_windows(
+    pool: &PgPool,
+    profile: &LawnProfile,
+    apps: &[Application],
+    today: NaiveDate,
+) {
+    for missed in missed_windows(profile, apps, today.year(), today) {
+        if (today - missed.closed).num_days() > MISSED_WINDOW_RECORD_DAYS {
+            continue;
+        }
+        let key = missed.key();
+        match event_queries::event_exists(pool, EventKind::MissedWindow, &key).await {
+            Ok(false) => {}
+            Ok(true) => continue,
+            Err(e) => {
+                tracing::warn!(window = %key, "Failed to check for missed window event: {}", e);
+                return;
+            }
+        }
+        event_queries::record_event(
+            pool,
+            Event::new(
+                EventKind::MissedWindow,
+                EventLevel::Warning,
+                format!(
+                    "{} window ({} – {}) closed with no application logged",
+                    missed.name,
+                    missed.opened.format("%b %-d"),
+                    missed.closed.format("%b %-d")
+                ),
+            )
+            .with_details(key),
+        )
+        .await;
+    }
+}
+
+/// Every recommendation for the active profile (or one of its areas): rule
+/// output plus plant, follow-up, aftercare, rain check, equipment and soil test
+/// reminders, post-processed, with dismissed/addressed state and new/escalated
+/// markers applied.
+pub async fn evaluate_recommendations(
+    state: &AppState,
+    area_id: Option<i64>,
+) -> Result<Vec<Recommendation>, TurfOpsError> {
+    // Get current profile and its environmental data (refreshes if stale)
+    let profile = queries::get_active_lawn_profile(&state.pool)
         .await?
         .ok_or_else(|| TurfOpsError::NotFound("No lawn profile found".into()))?;
+    let summary = {
+        let mut service = state.sync_service.write().await;
+        service.get_or_refresh(&profile).await?
+    };
 
     let profile_id = profile
         .id
         .ok_or_else(|| TurfOpsError::InvalidData("Profile missing ID".into()))?;
     let apps = queries::get_applications_for_profile(&state.pool, profile_id, 1000, 0).await?;
+    let today = Local::now().date_naive();
+    record_missed_windows(&state.pool, &profile, &apps, today).await;
+    // Inventory and equipment are shared by the whole profile, so count their use before
+    // scoping to an area
+    let inventory = inventory_statuses(
+        inventory_queries::list_items_for_profile(&state.pool, profile_id).await?,
+        &apps,
+        &profile,
+        today,
+    );
+    let equipment = equipment_statuses(
+        equipment_queries::list_equipment_for_profile(&state.pool, profile_id).await?,
+        equipment_queries::list_maintenance_for_profile(&state.pool, profile_id).await?,
+        &apps,
+        today,
+    );
+    let (profile, apps) = scope_to_area(&state.pool, profile, apps, area_id).await?;
 
     // Evaluate rules
-    let mut recommendations = state.rules_engine.evaluate(&summary, &profile, &apps);
+    let mut recommendations = state
+        .rules_engine
+        .evaluate(&summary, &profile, &apps, Utc::now());
 
     // Append plant maintenance recommendations for landscape plants.
     let plants = plant_queries::list_plants_for_profile(&state.pool, profile_id).await?;
-    let today = Local::now().date_naive();
     recommendations.extend(generate_plant_maintenance_recommendations(
         &plants, &apps, today,
     ));
@@ -53,6 +212,38 @@ pub async fn list_recommendations(
         today,
     ));
 
+    // Append water-in and re-entry reminders for products applied in the last few days.
+    recommendations.extend(generate_aftercare_recommendations(
+        &apps,
+        summary.forecast.as_ref(),
+        today,
+    ));
+
+    // Append an irrigate-today follow-up when forecast rain didn't fall.
+    let now = Utc::now();
+    let rain_checks = rain_check_queries::list_reconciled_rain_checks(
+        &state.pool,
+        profile_id,
+        now - Duration::hours(FOLLOW_UP_HOURS),
+    )
+    .await?;
+    recommendations.extend(generate_rain_check_recommendations(
+        &rain_checks,
+        summary
+            .current
+            .as_ref()
+            .and_then(|c| c.primary_soil_moisture()),
+        SoilWaterLimits::for_soil(profile.soil_type),
+        now,
+    ));
+
+    // Append equipment upkeep reminders (hour-based service, seasonal jobs).
+    recommendations.extend(generate_equipment_recommendations(
+        &equipment,
+        summary.forecast.as_ref(),
+        today,
+    ));
+
     // Append soil-test-based recommendations if a test exists
     if let Ok(Some(test)) = soil_test_queries::get_latest_soil_test(&state.pool, profile_id).await {
         let soil_summary = generate_soil_test_recommendations(&test, &profile, &apps);
@@ -161,19 +352,68 @@ pub async fn list_recommendations(
         }
     }
 
+    // Re-run engine post-processing over the full list so appended plant,
+    // follow-up and soil-test recommendations are deduplicated and sorted too.
+    let mut recommendations = post_process(recommendations);
+    annotate_shortfalls(&mut recommendations, &inventory);
+
     // Apply dismissed/addressed state from database
-    let rec_states = queries::get_recommendation_states(&state.pool).await?;
+    let rec_states = queries::get_recommendation_states(&state.pool, profile_id).await?;
     for rec in &mut recommendations {
         if let Some((dismissed, addressed)) = rec_states.get(&rec.id) {
             rec.dismissed = *dismissed;
             rec.addressed = *addressed;
         }
     }
+    mark_changes(
+        &mut recommendations,
+        &queries::get_seen_recommendations(&state.pool, profile_id).await?,
+    );
 
-    // Return only active recommendations
-    recommendations.retain(|r| r.is_active());
+    Ok(recommendations)
+}
 
-    Ok(Json(recommendations))
+/// GET /api/v1/recommendations/explain
+/// Lists every registered rule with whether it fired for the current inputs,
+/// and if not, which gate stopped it.
+pub async fn explain_recommendations(
+    State(state): State<AppState>,
+    Query(params): Query<RecommendationsQuery>,
+) -> Result<Json<Vec<RuleTrace>>, TurfOpsError> {
+    let profile = queries::get_active_lawn_profile(&state.pool)
+        .await?
+        .ok_or_else(|| TurfOpsError::NotFound("No lawn profile found".into()))?;
+    let summary = {
+        let mut service = state.sync_service.write().await;
+        service.get_or_refresh(&profile).await?
+    };
+
+    let profile_id = profile
+        .id
+        .ok_or_else(|| TurfOpsError::InvalidData("Profile missing ID".into()))?;
+    let apps = queries::get_applications_for_profile(&state.pool, profile_id, 1000, 0).await?;
+    let (profile, apps) = scope_to_area(&state.pool, profile, apps, params.area_id).await?;
+
+    let mut traces = state
+        .rules_engine
+        .explain(&summary, &profile, &apps, Utc::now());
+
+    let rec_states = queries::get_recommendation_states(&state.pool, profile_id).await?;
+    for trace in &mut traces {
+        if let Some((dismissed, addressed)) = trace
+            .recommendation_id
+            .as_ref()
+            .and_then(|id| rec_states.get(id))
+        {
+            if *dismissed {
+                trace.suppressed_by = Some("dismissed");
+            } else if *addressed {
+                trace.suppressed_by = Some("addressed");
+            }
+        }
+    }
+
+    Ok(Json(traces))
 }
 
 #[derive(Debug, Deserialize)]
@@ -183,14 +423,16 @@ pub struct PatchRecommendationRequest {
 }
 
 /// PATCH /api/v1/recommendations/:id
-/// Mark a recommendation as dismissed or addressed. Persisted to database.
+/// Mark one of the active profile's recommendations as dismissed or addressed.
+/// Persisted to database.
 pub async fn patch_recommendation(
     State(state): State<AppState>,
     Path(id): Path<String>,
     Json(req): Json<PatchRecommendationRequest>,
 ) -> Result<Json<serde_json::Value>, TurfOpsError> {
+    let profile_id = active_profile_id(&state).await?;
     // Get existing state from DB
-    let rec_states = queries::get_recommendation_states(&state.pool).await?;
+    let rec_states = queries::get_recommendation_states(&state.pool, profile_id).await?;
     let (mut dismissed, mut addressed) = rec_states.get(&id).copied().unwrap_or((false, false));
 
     if let Some(d) = req.dismissed {
@@ -200,7 +442,8 @@ pub async fn patch_recommendation(
         addressed = a;
     }
 
-    queries::upsert_recommendation_state(&state.pool, &id, dismissed, addressed).await?;
+    queries::upsert_recommendation_state(&state.pool, profile_id, &id, dismissed, addressed)
+        .await?;
 
     Ok(Json(serde_json::json!({
         "id": id,
@@ -208,3 +451,22 @@ pub async fn patch_recommendation(
         "addressed": addressed,
     })))
 }
+
+#[derive(Debug, Deserialize)]
+pub struct SeenRecommendation {
+    pub id: String,
+    pub severity: Severity,
+}
+
+/// POST /api/v1/recommendations/seen
+/// Record the active profile's recommendations as viewed at the severities
+/// shown, clearing their new/escalated markers until they escalate again.
+pub async fn mark_recommendations_seen(
+    State(state): State<AppState>,
+    Json(req): Json<Vec<SeenRecommendation>>,
+) -> Result<StatusCode, TurfOpsError> {
+    let profile_id = active_profile_id(&state).await?;
+    let seen: Vec<(String, Severity)> = req.into_iter().map(|r| (r.id, r.severity)).collect();
+    queries::mark_recommendations_seen(&state.pool, profile_id, &seen).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,144 @@
+use crate::db::{annual_review_queries, area_queries, photo_queries, queries};
+use crate::error::TurfOpsError;
+use crate::logic::efficacy::{daily_conditions, DayConditions};
+use crate::logic::season_report::{
+    build_season_report, render_html, render_markdown, Compliance, ReportFormat, SeasonReportInput,
+};
+use crate::logic::weather_backfill::day_bounds;
+use crate::models::LawnProfile;
+use crate::state::AppState;
+use axum::extract::{Path, Query, State};
+use axum::http::header;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use chrono::{Datelike, Local, NaiveDate, Utc};
+use serde::Deserialize;
+use std::str::FromStr;
+use tracing::warn;
+
+/// Reject years before 2000 or in the future.
+pub(crate) fn check_year(year: i32) -> Result<(), TurfOpsError> {
+    let current = Local::now().year();
+    if !(2000..=current).contains(&year) {
+        return Err(TurfOpsError::InvalidData(format!(
+            "Year must be between 2000 and {}",
+            current
+        )));
+    }
+    Ok(())
+}
+
+/// Daily station conditions for `year` so far. Empty rather than an error
+/// when the weather history is unavailable, so callers can still answer.
+pub(crate) async fn season_days(
+    state: &AppState,
+    profile: &LawnProfile,
+    year: i32,
+) -> Vec<DayConditions> {
+    let (Some(jan1), Some(next_jan1)) = (
+        NaiveDate::from_ymd_opt(year, 1, 1),
+        NaiveDate::from_ymd_opt(year + 1, 1, 1),
+    ) else {
+        return Vec::new();
+    };
+    let (start, _) = day_bounds(jan1);
+    let (end, _) = day_bounds(next_jan1);
+
+    let service = state.sync_service.read().await;
+    let readings = match service.weather_client_for(profile) {
+        Some(client) => client
+            .fetch_range(start, end.min(Utc::now()))
+            .await
+            .unwrap_or_else(|e| {
+                warn!(error = %e, year, "Season weather history unavailable");
+                Vec::new()
+            }),
+        None => Vec::new(),
+    };
+    daily_conditions(&readings)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReportQuery {
+    /// "markdown" (default), "html" or "json".
+    pub format: Option<String>,
+}
+
+/// GET /api/v1/reports/{year}?format=markdown
+/// Shareable season summary: application totals and N applied, notable
+/// weather, recommendation follow-through, overseeding results and monthly
+/// charts. Markdown and HTML are sent as a download.
+pub async fn get_season_report(
+    State(state): State<AppState>,
+    Path(year): Path<i32>,
+    Query(params): Query<ReportQuery>,
+) -> Result<Response, TurfOpsError> {
+    let format = params
+        .format
+        .as_deref()
+        .map(ReportFormat::from_str)
+        .transpose()
+        .map_err(TurfOpsError::InvalidData)?
+        .unwrap_or(ReportFormat::Markdown);
+    check_year(year)?;
+
+    let profile = queries::get_active_lawn_profile(&state.pool)
+        .await?
+        .ok_or_else(|| TurfOpsError::NotFound("No lawn profile found".into()))?;
+    let profile_id = profile
+        .id
+        .ok_or_else(|| TurfOpsError::InvalidData("Profile missing ID".into()))?;
+
+    let invalid = || TurfOpsError::InvalidData(format!("Invalid year {}", year));
+    let jan1 = NaiveDate::from_ymd_opt(year, 1, 1).ok_or_else(invalid)?;
+    let next_jan1 = NaiveDate::from_ymd_opt(year + 1, 1, 1).ok_or_else(invalid)?;
+    let (start, _) = day_bounds(jan1);
+    let (end, _) = day_bounds(next_jan1);
+
+    let in_year = queries::get_applications_made_in_year(&state.pool, profile_id, year).await?;
+    let areas = area_queries::list_areas_for_profile(&state.pool, profile_id).await?;
+    let review = annual_review_queries::get_annual_review(&state.pool, profile_id, year).await?;
+    let photos =
+        photo_queries::get_photos_in_range(&state.pool, profile_id, jan1, next_jan1).await?;
+    let (addressed, dismissed) =
+        queries::count_recommendation_states_in_range(&state.pool, profile_id, start, end)
+            .await?;
+
+    let days = season_days(&state, &profile, year).await;
+
+    let report = build_season_report(&SeasonReportInput {
+        year,
+        today: Local::now().date_naive(),
+        profile: &profile,
+        applications: &in_year,
+        areas: &areas,
+        days: &days,
+        compliance: Compliance {
+            addressed: addressed as usize,
+            dismissed: dismissed as usize,
+        },
+        review: review.as_ref(),
+        photos: &photos,
+    });
+
+    let (body, content_type, ext) = match format {
+        ReportFormat::Json => return Ok(Json(report).into_response()),
+        ReportFormat::Markdown => (
+            render_markdown(&report),
+            "text/markdown; charset=utf-8",
+            "md",
+        ),
+        ReportFormat::Html => (render_html(&report), "text/html; charset=utf-8", "html"),
+    };
+    Ok((
+        [
+            (header::CONTENT_TYPE, content_type.to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"turfops-{year}-report.{ext}\""),
+            ),
+        ],
+        body,
+    )
+        .into_response())
+}
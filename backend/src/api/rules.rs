@@ -0,0 +1,162 @@
+use crate::db::{queries, settings_queries};
+use crate::error::TurfOpsError;
+use crate::logic::backtest::{run_backtest, BacktestInput, BacktestReport, BACKTEST_MAX_DAYS};
+use crate::logic::gdd;
+use crate::logic::rules::engine::RuleStatus;
+use crate::logic::rules::posture::RiskPosture;
+use crate::models::dates::parse_date;
+use crate::state::AppState;
+use axum::extract::{Path, Query, State};
+use axum::Json;
+use chrono::{Datelike, Duration, Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, Deserialize)]
+pub struct SetRuleEnabledRequest {
+    pub enabled: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RiskPostureBody {
+    pub posture: RiskPosture,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BacktestQuery {
+    /// `YYYY-MM-DD`.
+    pub from: String,
+    /// `YYYY-MM-DD`.
+    pub to: String,
+}
+
+/// GET /api/v1/rules
+/// Every registered rule with whether it's enabled.
+pub async fn list_rules(State(state): State<AppState>) -> Json<Vec<RuleStatus>> {
+    Json(state.rules_engine.rule_statuses())
+}
+
+/// PUT /api/v1/rules/{name}
+/// Enable or disable a rule. The choice is persisted in the settings table.
+pub async fn set_rule_enabled(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+    Json(body): Json<SetRuleEnabledRequest>,
+) -> Result<Json<RuleStatus>, TurfOpsError> {
+    let engine = &state.rules_engine;
+    let status = engine
+        .rule_statuses()
+        .into_iter()
+        .find(|s| s.rule == name)
+        .ok_or_else(|| TurfOpsError::NotFound(format!("Unknown rule: {}", name)))?;
+    if status.locked && body.enabled {
+        return Err(TurfOpsError::InvalidData(format!(
+            "{} is disabled by RULES_DISABLED and can't be enabled here",
+            name
+        )));
+    }
+
+    let mut disabled = settings_queries::get_disabled_rules(&state.pool).await?;
+    disabled.retain(|n| n != &name);
+    if !body.enabled {
+        disabled.push(name.clone());
+    }
+    settings_queries::set_disabled_rules(&state.pool, &disabled).await?;
+    engine.set_user_disabled(&disabled);
+
+    Ok(Json(RuleStatus {
+        enabled: engine.is_enabled(&name),
+        ..status
+    }))
+}
+
+/// GET /api/v1/rules/posture
+pub async fn get_posture(State(state): State<AppState>) -> Json<RiskPostureBody> {
+    Json(RiskPostureBody {
+        posture: state.rules_engine.posture(),
+    })
+}
+
+/// PUT /api/v1/rules/posture
+/// Set how eagerly rules fire. The choice is persisted in the settings table.
+pub async fn set_posture(
+    State(state): State<AppState>,
+    Json(body): Json<RiskPostureBody>,
+) -> Result<Json<RiskPostureBody>, TurfOpsError> {
+    settings_queries::set_risk_posture(&state.pool, body.posture).await?;
+    state.rules_engine.set_posture(body.posture);
+    Ok(Json(body))
+}
+
+/// GET /api/v1/rules/backtest?from=2025-03-01&to=2025-06-30
+/// Replay archived data lake readings through the rules engine day by day and
+/// report which recommendations would have fired when.
+pub async fn backtest(
+    State(state): State<AppState>,
+    Query(params): Query<BacktestQuery>,
+) -> Result<Json<BacktestReport>, TurfOpsError> {
+    let from = parse_date("from", &params.from)?;
+    let to = parse_date("to", &params.to)?;
+    if from > to {
+        return Err(TurfOpsError::InvalidData(
+            "`from` must be on or before `to`".into(),
+        ));
+    }
+    if (to - from).num_days() >= BACKTEST_MAX_DAYS {
+        return Err(TurfOpsError::InvalidData(format!(
+            "A backtest can cover at most {} days",
+            BACKTEST_MAX_DAYS
+        )));
+    }
+    if to >= Local::now().date_naive() {
+        return Err(TurfOpsError::InvalidData(
+            "A backtest can only cover days that have ended".into(),
+        ));
+    }
+
+    let profile = queries::get_active_lawn_profile(&state.pool)
+        .await?
+        .ok_or_else(|| TurfOpsError::NotFound("No lawn profile found".into()))?;
+    let profile_id = profile
+        .id
+        .ok_or_else(|| TurfOpsError::InvalidData("Profile missing ID".into()))?;
+    // Rules look back up to a season for prior applications
+    let history_start = NaiveDate::from_ymd_opt(from.year() - 1, 1, 1).unwrap_or(from);
+    let applications =
+        queries::get_applications_for_profile_in_range(&state.pool, profile_id, history_start, to)
+            .await?;
+
+    let (readings, gdd_ytd, trend_window) = {
+        let service = state.sync_service.read().await;
+        let client = service.weather_client_for(&profile).ok_or_else(|| {
+            TurfOpsError::DataSourceUnavailable("Weather data lake not configured".into())
+        })?;
+
+        // A day of slack on each side covers the local-midnight day boundaries
+        let start = (from - Duration::days(8))
+            .and_hms_opt(0, 0, 0)
+            .unwrap_or_default();
+        let end = (to + Duration::days(2))
+            .and_hms_opt(0, 0, 0)
+            .unwrap_or_default();
+        let readings = client.fetch_range(start.and_utc(), end.and_utc()).await?;
+
+        let jan1 = NaiveDate::from_ymd_opt(from.year(), 1, 1).unwrap_or(from);
+        let gdd_rows = client.fetch_daily_gdd(jan1, to).await?;
+        let gdd_ytd: HashMap<_, _> = gdd::accumulate_daily_gdd(&gdd_rows)
+            .into_iter()
+            .map(|d| (d.date, d.cumulative_gdd_base50))
+            .collect();
+
+        (readings, gdd_ytd, client.trend_window())
+    };
+
+    let input = BacktestInput {
+        profile: &profile,
+        applications: &applications,
+        readings: &readings,
+        gdd_ytd: &gdd_ytd,
+        trend_window,
+    };
+    Ok(Json(run_backtest(&state.rules_engine, &input, from, to)))
+}
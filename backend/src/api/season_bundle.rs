@@ -0,0 +1,111 @@
+use crate::api::report::{check_year, season_days};
+use crate::db::{area_queries, event_queries, queries};
+use crate::error::TurfOpsError;
+use crate::logic::season_bundle::{build_bundle, SeasonBundle};
+use crate::models::{Event, EventKind, EventLevel, LawnProfile};
+use crate::state::AppState;
+use axum::extract::{Path, State};
+use axum::http::header;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use chrono::NaiveDate;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct ImportSummary {
+    pub year: i32,
+    pub imported: usize,
+    /// Already logged with the same date, type and product.
+    pub skipped: usize,
+    /// The bundle's grass type differs from the active profile's.
+    pub grass_type_mismatch: bool,
+}
+
+async fn active_profile(state: &AppState) -> Result<(LawnProfile, i64), TurfOpsError> {
+    let profile = queries::get_active_lawn_profile(&state.pool)
+        .await?
+        .ok_or_else(|| TurfOpsError::NotFound("No lawn profile found".into()))?;
+    let id = profile
+        .id
+        .ok_or_else(|| TurfOpsError::InvalidData("Profile missing ID".into()))?;
+    Ok((profile, id))
+}
+
+fn year_range(year: i32) -> Result<(NaiveDate, NaiveDate), TurfOpsError> {
+    let invalid = || TurfOpsError::InvalidData(format!("Invalid year {}", year));
+    Ok((
+        NaiveDate::from_ymd_opt(year, 1, 1).ok_or_else(invalid)?,
+        NaiveDate::from_ymd_opt(year + 1, 1, 1).ok_or_else(invalid)?,
+    ))
+}
+
+/// GET /api/v1/season-bundles/{year}
+/// Anonymized season as a JSON download: grass type, zone, turf application
+/// timeline and weekly weather. No names, location, notes or ids.
+pub async fn export_season_bundle(
+    State(state): State<AppState>,
+    Path(year): Path<i32>,
+) -> Result<Response, TurfOpsError> {
+    check_year(year)?;
+    let (profile, profile_id) = active_profile(&state).await?;
+    let (jan1, next_jan1) = year_range(year)?;
+
+    let applications =
+        queries::get_applications_for_profile_in_range(&state.pool, profile_id, jan1, next_jan1)
+            .await?;
+    let areas = area_queries::list_areas_for_profile(&state.pool, profile_id).await?;
+    let days = season_days(&state, &profile, year).await;
+
+    let bundle = build_bundle(year, &profile, &applications, &areas, &days);
+    Ok((
+        [(
+            header::CONTENT_DISPOSITION,
+            format!("attachment; filename=\"turfops-{year}-season.json\""),
+        )],
+        Json(bundle),
+    )
+        .into_response())
+}
+
+/// POST /api/v1/season-bundles
+/// Add a bundle's applications to the active profile. Applications already
+/// logged (same date, type and product) are skipped, so re-importing is safe.
+pub async fn import_season_bundle(
+    State(state): State<AppState>,
+    Json(bundle): Json<SeasonBundle>,
+) -> Result<Json<ImportSummary>, TurfOpsError> {
+    bundle.validate().map_err(TurfOpsError::InvalidData)?;
+    let (profile, profile_id) = active_profile(&state).await?;
+    let (jan1, next_jan1) = year_range(bundle.year)?;
+
+    let existing =
+        queries::get_applications_for_profile_in_range(&state.pool, profile_id, jan1, next_jan1)
+            .await?;
+    let plan = bundle.plan_import(&profile, &existing);
+    for app in &plan.applications {
+        queries::create_application(&state.pool, app).await?;
+    }
+
+    if !plan.applications.is_empty() {
+        event_queries::record_event(
+            &state.pool,
+            Event::new(
+                EventKind::ApplicationAdded,
+                EventLevel::Info,
+                format!(
+                    "{} applications imported from a {} season bundle",
+                    plan.applications.len(),
+                    bundle.year
+                ),
+            ),
+        )
+        .await;
+    }
+
+    Ok(Json(ImportSummary {
+        year: bundle.year,
+        imported: plan.applications.len(),
+        skipped: plan.skipped,
+        grass_type_mismatch: bundle.grass_type != profile.grass_type,
+    }))
+}
@@ -2,7 +2,7 @@ use crate::db::{plant_queries, queries};
 use crate::error::TurfOpsError;
 use crate::logic::plant_maintenance::build_plant_activities;
 use crate::logic::seasonal_plan::{build_seasonal_plan, find_threshold_crossings};
-use crate::models::seasonal_plan::SeasonalPlan;
+use crate::models::seasonal_plan::{DailySoilTempAvg, SeasonalPlan};
 use crate::state::AppState;
 use axum::extract::{Query, State};
 use axum::Json;
@@ -18,7 +18,8 @@ pub struct SeasonalPlanQuery {
 ///
 /// Returns a seasonal plan for the requested year based on historical
 /// soil temperature threshold crossings from NOAA data.
-/// Lazily fills the threshold crossing cache from the weather data lake on first call.
+/// Lazily fills the threshold crossing cache from the weather data lake on first call,
+/// using the Open-Meteo archive's modeled soil temps for years the lake doesn't cover.
 pub async fn get_seasonal_plan(
     State(state): State<AppState>,
     Query(params): Query<SeasonalPlanQuery>,
@@ -35,10 +36,13 @@ pub async fn get_seasonal_plan(
         .filter(|y| !cached_years.contains(y))
         .collect();
 
-    // Backfill from the data lake if needed
+    // Backfill from the data lake (or the archive before it) if needed
     if !years_to_fill.is_empty() {
-        let sync = state.sync_service.read().await;
-        if let Some(client) = sync.weather_client() {
+        let (lake, archive) = {
+            let sync = state.sync_service.read().await;
+            (sync.weather_client().cloned(), sync.historical_client())
+        };
+        if lake.is_some() || archive.is_some() {
             for fill_year in &years_to_fill {
                 let start = Utc
                     .with_ymd_and_hms(*fill_year, 1, 1, 0, 0, 0)
@@ -49,7 +53,31 @@ pub async fn get_seasonal_plan(
                     .single()
                     .unwrap_or_default();
 
-                match client.fetch_daily_soil_temp_averages(start, end).await {
+                let fetched = match lake {
+                    Some(ref client) => client.fetch_daily_soil_temp_averages(start, end).await,
+                    None => Ok(Vec::new()),
+                };
+                let fetched = match (fetched, archive.as_ref()) {
+                    (Ok(temps), Some(client)) if temps.len() < 30 => client
+                        .fetch_daily(
+                            start.date_naive(),
+                            end.date_naive().min(Local::now().date_naive()),
+                        )
+                        .await
+                        .map(|days| {
+                            days.iter()
+                                .filter_map(|d| {
+                                    Some(DailySoilTempAvg {
+                                        date: d.date,
+                                        avg_temp_f: d.soil_temp_f?,
+                                    })
+                                })
+                                .collect()
+                        }),
+                    (fetched, _) => fetched,
+                };
+
+                match fetched {
                     Ok(daily_temps) => {
                         if daily_temps.len() >= 30 {
                             let crossings = find_threshold_crossings(*fill_year, &daily_temps);
@@ -96,7 +124,7 @@ pub async fn get_seasonal_plan(
         .len() as i32;
 
     // Get application history for the requested year
-    let profile = queries::get_default_lawn_profile(&state.pool)
+    let profile = queries::get_active_lawn_profile(&state.pool)
         .await?
         .ok_or_else(|| TurfOpsError::NotFound("No lawn profile found".into()))?;
 
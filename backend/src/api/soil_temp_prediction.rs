@@ -1,6 +1,8 @@
+use crate::db::queries;
 use crate::error::TurfOpsError;
 use crate::logic::soil_temp_prediction;
 use crate::models::soil_temp_prediction::SoilTempForecast;
+use crate::models::LawnProfile;
 use crate::state::AppState;
 use axum::extract::State;
 use axum::Json;
@@ -11,10 +13,22 @@ use chrono::{Duration, Utc};
 pub async fn get_soil_temp_forecast(
     State(state): State<AppState>,
 ) -> Result<Json<SoilTempForecast>, TurfOpsError> {
+    let profile = queries::get_active_lawn_profile(&state.pool)
+        .await?
+        .ok_or_else(|| TurfOpsError::NotFound("No lawn profile found".into()))?;
+    Ok(Json(build_soil_temp_forecast(&state, &profile).await?))
+}
+
+/// Fit the air→soil model on the profile's last 30 days and run it over the
+/// weather forecast.
+pub async fn build_soil_temp_forecast(
+    state: &AppState,
+    profile: &LawnProfile,
+) -> Result<SoilTempForecast, TurfOpsError> {
     let service = state.sync_service.read().await;
 
     // We need: weather lake client for paired data, current soil temp, and forecast
-    let weather = service.weather_client().ok_or_else(|| {
+    let weather = service.weather_client_for(profile).ok_or_else(|| {
         TurfOpsError::DataSourceUnavailable("Weather data lake not configured".into())
     })?;
 
@@ -34,7 +48,7 @@ pub async fn get_soil_temp_forecast(
     // Get current environmental summary for soil temp + forecast
     drop(service);
     let mut service = state.sync_service.write().await;
-    let summary = service.get_or_refresh().await?;
+    let summary = service.get_or_refresh(profile).await?;
 
     let current_soil_temp = summary
         .current
@@ -77,5 +91,5 @@ pub async fn get_soil_temp_forecast(
         TurfOpsError::InvalidData("Could not build prediction model (poor data correlation)".into())
     })?;
 
-    Ok(Json(forecast))
+    Ok(forecast)
 }
@@ -1,6 +1,7 @@
 use crate::db::{queries, soil_test_queries};
 use crate::error::TurfOpsError;
 use crate::logic::soil_test_recommendations::generate_soil_test_recommendations;
+use crate::models::dates::parse_date;
 use crate::models::{SoilTest, SoilTestSummary};
 use crate::state::AppState;
 use axum::extract::{Path, Query, State};
@@ -23,7 +24,7 @@ pub async fn list_soil_tests(
     State(state): State<AppState>,
     Query(params): Query<ListSoilTestsQuery>,
 ) -> Result<Json<Vec<SoilTest>>, TurfOpsError> {
-    let profile = queries::get_default_lawn_profile(&state.pool)
+    let profile = queries::get_active_lawn_profile(&state.pool)
         .await?
         .ok_or_else(|| TurfOpsError::NotFound("No lawn profile found".into()))?;
 
@@ -70,7 +71,7 @@ pub async fn create_soil_test(
     State(state): State<AppState>,
     Json(req): Json<CreateSoilTestRequest>,
 ) -> Result<(StatusCode, Json<SoilTest>), TurfOpsError> {
-    let profile = queries::get_default_lawn_profile(&state.pool)
+    let profile = queries::get_active_lawn_profile(&state.pool)
         .await?
         .ok_or_else(|| TurfOpsError::NotFound("No lawn profile found".into()))?;
 
@@ -78,12 +79,7 @@ pub async fn create_soil_test(
         .id
         .ok_or_else(|| TurfOpsError::InvalidData("Profile missing ID".into()))?;
 
-    let test_date = NaiveDate::parse_from_str(&req.test_date, "%Y-%m-%d").map_err(|_| {
-        TurfOpsError::InvalidData(format!(
-            "Invalid date format: {}. Expected YYYY-MM-DD",
-            req.test_date
-        ))
-    })?;
+    let test_date = parse_date("test_date", &req.test_date)?;
 
     if req.ph < 0.0 || req.ph > 14.0 {
         return Err(TurfOpsError::InvalidData(
@@ -129,7 +125,7 @@ pub async fn update_soil_test(
     Path(id): Path<i64>,
     Json(req): Json<CreateSoilTestRequest>,
 ) -> Result<Json<SoilTest>, TurfOpsError> {
-    let profile = queries::get_default_lawn_profile(&state.pool)
+    let profile = queries::get_active_lawn_profile(&state.pool)
         .await?
         .ok_or_else(|| TurfOpsError::NotFound("No lawn profile found".into()))?;
 
@@ -137,12 +133,7 @@ pub async fn update_soil_test(
         .id
         .ok_or_else(|| TurfOpsError::InvalidData("Profile missing ID".into()))?;
 
-    let test_date = NaiveDate::parse_from_str(&req.test_date, "%Y-%m-%d").map_err(|_| {
-        TurfOpsError::InvalidData(format!(
-            "Invalid date format: {}. Expected YYYY-MM-DD",
-            req.test_date
-        ))
-    })?;
+    let test_date = parse_date("test_date", &req.test_date)?;
 
     if req.ph < 0.0 || req.ph > 14.0 {
         return Err(TurfOpsError::InvalidData(
@@ -190,7 +181,7 @@ pub async fn delete_soil_test(
 pub async fn get_soil_test_recommendations(
     State(state): State<AppState>,
 ) -> Result<Json<SoilTestSummary>, TurfOpsError> {
-    let profile = queries::get_default_lawn_profile(&state.pool)
+    let profile = queries::get_active_lawn_profile(&state.pool)
         .await?
         .ok_or_else(|| TurfOpsError::NotFound("No lawn profile found".into()))?;
 
@@ -0,0 +1,27 @@
+use crate::db::queries;
+use crate::error::TurfOpsError;
+use crate::logic::stress_index;
+use crate::models::StressIndexSummary;
+use crate::state::AppState;
+use axum::extract::State;
+use axum::Json;
+use chrono::Utc;
+
+/// GET /api/v1/stress-index
+/// Returns the daily turf stress index (0-100) for the last 30 days, computed on
+/// demand from the data lake's hourly readings plus logged irrigation and
+/// Traffic journal notes. Empty when no data lake is configured.
+pub async fn get_stress_index(
+    State(state): State<AppState>,
+) -> Result<Json<StressIndexSummary>, TurfOpsError> {
+    let profile = queries::get_active_lawn_profile(&state.pool)
+        .await?
+        .ok_or_else(|| TurfOpsError::NotFound("No lawn profile found".into()))?;
+    let client = state.sync_service.read().await.weather_client_for(&profile);
+
+    let summary = match client {
+        Some(client) => stress_index::load(&state.pool, &client, &profile, Utc::now()).await?,
+        None => stress_index::summarize(Vec::new()),
+    };
+    Ok(Json(summary))
+}
@@ -0,0 +1,23 @@
+use crate::db::settings_queries;
+use crate::error::TurfOpsError;
+use crate::models::UiState;
+use crate::state::AppState;
+use axum::extract::State;
+use axum::Json;
+
+/// GET /api/v1/ui-state
+/// Where the web UI was left: last screen, filters and selections.
+pub async fn get_ui_state(State(state): State<AppState>) -> Result<Json<UiState>, TurfOpsError> {
+    Ok(Json(settings_queries::get_ui_state(&state.pool).await?))
+}
+
+/// PUT /api/v1/ui-state
+/// Replace the saved UI state. The client sends the whole object.
+pub async fn set_ui_state(
+    State(state): State<AppState>,
+    Json(body): Json<UiState>,
+) -> Result<Json<UiState>, TurfOpsError> {
+    body.validate().map_err(TurfOpsError::InvalidData)?;
+    settings_queries::set_ui_state(&state.pool, &body).await?;
+    Ok(Json(body))
+}
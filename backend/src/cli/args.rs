@@ -0,0 +1,111 @@
+// Command-line definition. build.rs `include!`s this file to write shell
+// completions and the man page at build time, so it may only use clap and std.
+
+/// Applications `list` shows without `--limit`.
+pub const DEFAULT_LIST_LIMIT: i64 = 20;
+
+/// Lawn care decision support. With no command, serves the web app and API;
+/// a command connects to the same database, prints its result and exits.
+#[derive(Debug, clap::Parser)]
+#[command(name = "turfops-backend", version)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// json (stable field names, for scripts) or table
+    #[arg(long, global = true, value_enum, ignore_case = true, default_value_t = OutputFormat::Table)]
+    pub format: OutputFormat,
+
+    /// Query the database directly even when a server is running, instead of
+    /// asking the server (status, recs, refresh)
+    #[arg(long, global = true)]
+    pub no_server: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, clap::Subcommand)]
+pub enum Command {
+    /// Database and data source health, current conditions
+    Status,
+    /// Refresh data from every source now, then show status. A running
+    /// server refreshes for the web app too
+    Refresh {
+        /// Also re-sync the 7-day soil window and the Drought Monitor
+        #[arg(long)]
+        deep: bool,
+    },
+    /// Active recommendations, most severe first; exits 2 if any shown is Critical
+    Recs {
+        /// Leave out recommendations below this severity
+        #[arg(long, value_enum, ignore_case = true, default_value_t = MinSeverity::Info)]
+        min_severity: MinSeverity,
+    },
+    /// Recent applications, newest first
+    List {
+        /// Number of applications to show
+        #[arg(long, default_value_t = DEFAULT_LIST_LIMIT, value_parser = clap::value_parser!(i64).range(1..))]
+        limit: i64,
+    },
+    /// Every application for the active profile, oldest first
+    Export,
+    /// Photo journal, newest first, with the application before each photo
+    Photos {
+        /// Number of photos to show
+        #[arg(long, default_value_t = DEFAULT_LIST_LIMIT, value_parser = clap::value_parser!(i64).range(1..))]
+        limit: i64,
+    },
+    /// Indexes the app's queries rely on; exits 1 if any are missing
+    CheckSchema,
+    /// Package the database, configuration (credentials left out) and rule
+    /// files into a .tar.gz for another machine
+    ExportAll {
+        /// Archive to write
+        archive: std::path::PathBuf,
+    },
+    /// Restore an export-all archive into this database, migrating it first
+    ImportAll {
+        /// Archive to read
+        archive: std::path::PathBuf,
+        /// Overwrite a database that already has data
+        #[arg(long)]
+        replace: bool,
+        /// Where to extract the configuration and rule files for review
+        #[arg(long, default_value = "turfops-restore")]
+        files_dir: std::path::PathBuf,
+    },
+    /// Merge a product label file (.yaml, .csv or .json) into the product
+    /// catalog; products edited by hand are kept as they are
+    ImportProducts {
+        /// Label file to read
+        file: std::path::PathBuf,
+    },
+    /// Encrypt stored notes and locations with the configured
+    /// DB_ENCRYPTION_KEY_FILE or DB_ENCRYPTION_KEY_COMMAND key
+    EncryptDb,
+    /// Decrypt stored notes and locations back to plaintext, after which the
+    /// app runs without a key
+    DecryptDb,
+    /// Print a shell completion script
+    Completions {
+        #[arg(value_enum)]
+        shell: clap_complete::Shell,
+    },
+    /// Print the man page (roff)
+    Man,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum OutputFormat {
+    #[default]
+    Table,
+    Json,
+}
+
+/// Severity floor for `recs`, lowest first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum MinSeverity {
+    #[default]
+    Info,
+    Advisory,
+    Warning,
+    Critical,
+}
@@ -0,0 +1,498 @@
+//! `export-all` / `import-all`: move a whole install to another machine as
+//! one `.tar.gz`. The archive holds every table as JSON, the environment
+//! configuration with credentials left blank, and the custom rule files.
+//! Importing applies this binary's migrations first, then restores the rows
+//! into whatever columns the current schema has, so an archive from an
+//! older version loads into a newer one (never the reverse).
+
+use super::output::{Table, Tabular};
+use crate::config::{Config, ENV_VARS, SECRET_ENV_VARS};
+use crate::db::backup_queries::{self, TableData};
+use crate::db::pool::MIGRATOR;
+use anyhow::{anyhow, bail, Context};
+use chrono::{DateTime, Utc};
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+const ARCHIVE_FORMAT: &str = "turfops-backup";
+const ARCHIVE_VERSION: u32 = 1;
+
+const MANIFEST: &str = "manifest.json";
+const TABLES_DIR: &str = "tables";
+const ENV_FILE: &str = "turfops.env";
+const RULES_DIR: &str = "rules.d";
+const CUSTOM_RULES_DIR: &str = "custom_rules";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Manifest {
+    pub format: String,
+    pub version: u32,
+    pub created_at: DateTime<Utc>,
+    pub app_version: String,
+    /// Newest migration applied to the exported database.
+    pub schema_version: Option<i64>,
+    pub tables: Vec<ArchivedTable>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchivedTable {
+    pub name: String,
+    pub columns: Vec<String>,
+    pub rows: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BackupReport {
+    pub archive: PathBuf,
+    pub schema_version: Option<i64>,
+    /// Rows exported or restored per table.
+    pub tables: BTreeMap<String, i64>,
+    /// Non-database files written to the archive or extracted from it.
+    pub files: Vec<PathBuf>,
+    pub notes: Vec<String>,
+}
+
+impl Tabular for BackupReport {
+    fn table(&self) -> Table {
+        let mut table = Table::new(&["Item", "Value"]);
+        table.row(vec!["Archive".into(), self.archive.display().to_string()]);
+        table.row(vec![
+            "Schema version".into(),
+            super::output::opt(self.schema_version),
+        ]);
+        for (name, rows) in &self.tables {
+            table.row(vec![format!("Table: {}", name), rows.to_string()]);
+        }
+        for file in &self.files {
+            table.row(vec!["File".into(), file.display().to_string()]);
+        }
+        for note in &self.notes {
+            table.row(vec!["Note".into(), note.clone()]);
+        }
+        table
+    }
+}
+
+/// Tables ordered so each comes after the tables it references (ties by
+/// name). Self-references are ignored; tables caught in a cycle go last.
+pub fn dependency_order(tables: &[String], foreign_keys: &[(String, String)]) -> Vec<String> {
+    let mut remaining: BTreeSet<&str> = tables.iter().map(String::as_str).collect();
+    let mut ordered = Vec::with_capacity(tables.len());
+    loop {
+        let ready: Vec<&str> = remaining
+            .iter()
+            .copied()
+            .filter(|t| {
+                !foreign_keys
+                    .iter()
+                    .any(|(from, to)| from == t && to != t && remaining.contains(to.as_str()))
+            })
+            .collect();
+        if ready.is_empty() {
+            break;
+        }
+        for t in ready {
+            remaining.remove(t);
+            ordered.push(t.to_string());
+        }
+    }
+    ordered.extend(remaining.into_iter().map(str::to_string));
+    ordered
+}
+
+/// `NAME=value` lines for the variables that are set; credentials are
+/// written commented out and empty.
+pub fn env_file(lookup: impl Fn(&str) -> Option<String>) -> String {
+    let mut out = String::from(
+        "# TurfOps configuration exported by `turfops-backend export-all`.\n\
+         # Credentials are not exported: fill them in on the new machine.\n",
+    );
+    for name in ENV_VARS {
+        let Some(value) = lookup(name) else {
+            continue;
+        };
+        if SECRET_ENV_VARS.contains(name) {
+            out.push_str(&format!("# {}=\n", name));
+        } else if value
+            .chars()
+            .any(|c| c.is_whitespace() || c == '#' || c == '"')
+        {
+            out.push_str(&format!(
+                "{}=\"{}\"\n",
+                name,
+                value.replace('\\', "\\\\").replace('"', "\\\"")
+            ));
+        } else {
+            out.push_str(&format!("{}={}\n", name, value));
+        }
+    }
+    out
+}
+
+fn append(
+    tar: &mut tar::Builder<GzEncoder<std::fs::File>>,
+    path: &str,
+    data: &[u8],
+) -> std::io::Result<()> {
+    let mut header = tar::Header::new_gnu();
+    header.set_size(data.len() as u64);
+    header.set_mode(0o600);
+    header.set_mtime(Utc::now().timestamp().max(0) as u64);
+    header.set_cksum();
+    tar.append_data(&mut header, path, data)
+}
+
+/// Rule files under `RULES_DIR` and `CUSTOM_RULES_PATH`, as (archive path, file).
+fn rule_files(config: &Config) -> anyhow::Result<Vec<(String, PathBuf)>> {
+    let mut files = Vec::new();
+    if let Some(dir) = config.rules.rules_dir.as_deref() {
+        let entries =
+            std::fs::read_dir(dir).with_context(|| format!("Reading rules directory {}", dir))?;
+        for entry in entries {
+            let path = entry?.path();
+            if path.is_file() {
+                if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
+                    files.push((format!("{}/{}", RULES_DIR, name), path.clone()));
+                }
+            }
+        }
+    }
+    if let Some(path) = config.rules.custom_rules_path.as_deref() {
+        let path = PathBuf::from(path);
+        if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
+            files.push((format!("{}/{}", CUSTOM_RULES_DIR, name), path.clone()));
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+pub async fn export_all(
+    pool: &PgPool,
+    config: &Config,
+    archive: &Path,
+) -> anyhow::Result<BackupReport> {
+    // One snapshot for every table, so a running server's writes can't leave
+    // rows whose parents weren't archived
+    let mut tx = backup_queries::begin_snapshot(pool).await?;
+    let names = backup_queries::list_tables(&mut *tx).await?;
+    let mut manifest = Manifest {
+        format: ARCHIVE_FORMAT.to_string(),
+        version: ARCHIVE_VERSION,
+        created_at: Utc::now(),
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        schema_version: backup_queries::schema_version(&mut *tx).await?,
+        tables: Vec::with_capacity(names.len()),
+    };
+
+    let file = std::fs::File::create(archive)
+        .with_context(|| format!("Creating {}", archive.display()))?;
+    let mut tar = tar::Builder::new(GzEncoder::new(file, flate2::Compression::default()));
+
+    for name in &names {
+        let rows = backup_queries::dump_table(&mut *tx, name).await?;
+        append(
+            &mut tar,
+            &format!("{}/{}.json", TABLES_DIR, name),
+            rows.as_bytes(),
+        )?;
+        manifest.tables.push(ArchivedTable {
+            name: name.clone(),
+            columns: backup_queries::table_columns(&mut *tx, name).await?,
+            rows: backup_queries::count_rows(&mut *tx, name).await?,
+        });
+    }
+    tx.commit().await?;
+
+    append(
+        &mut tar,
+        ENV_FILE,
+        env_file(|name| std::env::var(name).ok()).as_bytes(),
+    )?;
+    let mut files = vec![PathBuf::from(ENV_FILE)];
+    for (archived, path) in rule_files(config)? {
+        let data = std::fs::read(&path).with_context(|| format!("Reading {}", path.display()))?;
+        append(&mut tar, &archived, &data)?;
+        files.push(PathBuf::from(archived));
+    }
+
+    append(
+        &mut tar,
+        MANIFEST,
+        serde_json::to_string_pretty(&manifest)?.as_bytes(),
+    )?;
+    tar.into_inner()?.finish()?;
+
+    Ok(BackupReport {
+        archive: archive.to_path_buf(),
+        schema_version: manifest.schema_version,
+        tables: manifest
+            .tables
+            .iter()
+            .map(|t| (t.name.clone(), t.rows))
+            .collect(),
+        files,
+        notes: vec!["Credentials were left out of turfops.env".into()],
+    })
+}
+
+/// Every regular file in the archive by path.
+fn read_archive(archive: &Path) -> anyhow::Result<BTreeMap<String, Vec<u8>>> {
+    let file =
+        std::fs::File::open(archive).with_context(|| format!("Opening {}", archive.display()))?;
+    let mut tar = tar::Archive::new(GzDecoder::new(file));
+    let mut entries = BTreeMap::new();
+    for entry in tar.entries()? {
+        let mut entry = entry?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+        let path = entry.path()?.to_string_lossy().into_owned();
+        let mut data = Vec::new();
+        entry.read_to_end(&mut data)?;
+        entries.insert(path, data);
+    }
+    Ok(entries)
+}
+
+/// Check an archive's manifest against what this binary can restore.
+pub fn check_manifest(manifest: &Manifest, latest_migration: Option<i64>) -> anyhow::Result<()> {
+    if manifest.format != ARCHIVE_FORMAT {
+        bail!("Not a TurfOps export (format {:?})", manifest.format);
+    }
+    if manifest.version > ARCHIVE_VERSION {
+        bail!(
+            "Archive format {} is newer than this version supports ({})",
+            manifest.version,
+            ARCHIVE_VERSION
+        );
+    }
+    if let (Some(archived), Some(latest)) = (manifest.schema_version, latest_migration) {
+        if archived > latest {
+            bail!(
+                "Archive schema {} is newer than this version's migrations ({}); \
+                 upgrade TurfOps ({} or later) before importing",
+                archived,
+                latest,
+                manifest.app_version
+            );
+        }
+    }
+    Ok(())
+}
+
+pub async fn import_all(
+    pool: &PgPool,
+    archive: &Path,
+    replace: bool,
+    files_dir: &Path,
+) -> anyhow::Result<BackupReport> {
+    let mut entries = read_archive(archive)?;
+    let manifest: Manifest = serde_json::from_slice(
+        &entries
+            .remove(MANIFEST)
+            .ok_or_else(|| anyhow!("{} has no {}", archive.display(), MANIFEST))?,
+    )
+    .context("Reading the archive manifest")?;
+    let latest = MIGRATOR.iter().map(|m| m.version).max();
+    check_manifest(&manifest, latest)?;
+
+    // Bring this database to the current schema; older archives fill in around it
+    MIGRATOR
+        .run(pool)
+        .await
+        .context("Applying migrations before import")?;
+
+    if !replace && backup_queries::count_rows(pool, "lawn_profiles").await? > 0 {
+        bail!("The database already has lawn profiles; pass --replace to overwrite it");
+    }
+
+    let existing = backup_queries::list_tables(pool).await?;
+    let foreign_keys = backup_queries::list_foreign_keys(pool).await?;
+    let mut notes = Vec::new();
+    let mut data = Vec::new();
+    for name in dependency_order(&existing, &foreign_keys) {
+        let Some(archived) = manifest.tables.iter().find(|t| t.name == name) else {
+            continue;
+        };
+        let rows = entries
+            .remove(&format!("{}/{}.json", TABLES_DIR, name))
+            .ok_or_else(|| anyhow!("Archive is missing rows for table {}", name))?;
+        data.push(TableData {
+            table: name,
+            columns: archived.columns.clone(),
+            rows: String::from_utf8(rows)?,
+        });
+    }
+    for table in &manifest.tables {
+        if !existing.contains(&table.name) {
+            notes.push(format!("Skipped table {}: not in this schema", table.name));
+        }
+    }
+    let restored = backup_queries::restore_tables(pool, &data).await?;
+
+    // Configuration and rule files are left for review rather than put in place
+    let mut files = Vec::new();
+    for (path, contents) in entries {
+        if !(path == ENV_FILE
+            || path.starts_with(&format!("{}/", RULES_DIR))
+            || path.starts_with(&format!("{}/", CUSTOM_RULES_DIR)))
+        {
+            continue;
+        }
+        let relative = Path::new(&path);
+        if relative
+            .components()
+            .any(|c| !matches!(c, std::path::Component::Normal(_)))
+        {
+            notes.push(format!("Skipped unsafe path {}", path));
+            continue;
+        }
+        let target = files_dir.join(relative);
+        if let Some(parent) = target.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&target, contents)
+            .with_context(|| format!("Writing {}", target.display()))?;
+        files.push(target);
+    }
+    if !files.is_empty() {
+        notes.push(format!(
+            "Review {} and fill in credentials before starting the server",
+            files_dir.join(ENV_FILE).display()
+        ));
+    }
+
+    Ok(BackupReport {
+        archive: archive.to_path_buf(),
+        schema_version: manifest.schema_version,
+        tables: restored
+            .into_iter()
+            .map(|(name, rows)| (name, rows as i64))
+            .collect(),
+        files,
+        notes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(tables: &[&str]) -> Vec<String> {
+        tables.iter().map(|t| t.to_string()).collect()
+    }
+
+    fn fk(from: &str, to: &str) -> (String, String) {
+        (from.to_string(), to.to_string())
+    }
+
+    #[test]
+    fn orders_tables_after_the_tables_they_reference() {
+        let tables = names(&[
+            "applications",
+            "equipment",
+            "equipment_maintenance",
+            "lawn_areas",
+            "lawn_profiles",
+            "settings",
+        ]);
+        let fks = [
+            fk("applications", "lawn_profiles"),
+            fk("applications", "lawn_areas"),
+            fk("applications", "equipment"),
+            fk("equipment", "lawn_profiles"),
+            fk("equipment_maintenance", "equipment"),
+            fk("lawn_areas", "lawn_profiles"),
+            fk("lawn_profiles", "lawn_profiles"),
+        ];
+        assert_eq!(
+            dependency_order(&tables, &fks),
+            names(&[
+                "lawn_profiles",
+                "settings",
+                "equipment",
+                "lawn_areas",
+                "applications",
+                "equipment_maintenance",
+            ])
+        );
+
+        // A cycle doesn't drop tables
+        let cycle = [fk("a", "b"), fk("b", "a")];
+        assert_eq!(
+            dependency_order(&names(&["b", "a", "c"]), &cycle),
+            names(&["c", "a", "b"])
+        );
+    }
+
+    #[test]
+    fn env_file_blanks_credentials_and_lists_every_config_variable() {
+        let vars = BTreeMap::from([
+            ("LAWN_NAME", "Front yard"),
+            ("OWM_API_KEY", "abc123"),
+            ("SERVER_PORT", "3000"),
+            ("UNRELATED", "x"),
+        ]);
+        let env = env_file(|name| vars.get(name).map(|v| v.to_string()));
+        assert!(env.contains("LAWN_NAME=\"Front yard\"\n"));
+        assert!(env.contains("SERVER_PORT=3000\n"));
+        assert!(env.contains("# OWM_API_KEY=\n"));
+        assert!(!env.contains("abc123"));
+        assert!(!env.contains("UNRELATED"));
+        assert!(SECRET_ENV_VARS.iter().all(|s| ENV_VARS.contains(s)));
+    }
+
+    #[test]
+    fn env_vars_cover_every_variable_read() {
+        let sources = [
+            include_str!("../config.rs"),
+            include_str!("../logging.rs"),
+            include_str!("../main.rs"),
+        ];
+        let mut read = 0;
+        for source in sources {
+            for call in ["var(", "env_or(", "env_number(", "env_required("] {
+                // Names may sit on the line after the call once rustfmt wraps it
+                for args in source.split(call).skip(1) {
+                    let Some(quoted) = args.trim_start().strip_prefix('"') else {
+                        continue;
+                    };
+                    let name = quoted.split('"').next().unwrap();
+                    assert!(ENV_VARS.contains(&name), "{name} missing from ENV_VARS");
+                    read += 1;
+                }
+            }
+        }
+        assert!(read > 50);
+    }
+
+    #[test]
+    fn refuses_archives_from_a_newer_schema() {
+        let manifest = Manifest {
+            format: ARCHIVE_FORMAT.into(),
+            version: ARCHIVE_VERSION,
+            created_at: Utc::now(),
+            app_version: "9.9.9".into(),
+            schema_version: Some(20270101000000),
+            tables: Vec::new(),
+        };
+        assert!(check_manifest(&manifest, Some(20270101000000)).is_ok());
+        assert!(check_manifest(&manifest, Some(20300101000000)).is_ok());
+        let err = check_manifest(&manifest, Some(20261016000015)).unwrap_err();
+        assert!(err.to_string().contains("upgrade TurfOps (9.9.9 or later)"));
+        assert!(check_manifest(
+            &Manifest {
+                format: "other".into(),
+                ..manifest
+            },
+            None
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,765 @@
+//! Command-line subcommands. With no arguments `turfops-backend` serves the
+//! app; a subcommand connects to the same database, runs once and exits.
+//! `status`, `recs` and `refresh` ask a running server instead (`remote.rs`).
+//! Every subcommand takes `--format json|table` (see `output.rs`). The
+//! definition itself lives in `args.rs`, shared with build.rs.
+
+mod args;
+pub mod backup;
+pub mod output;
+mod remote;
+
+use crate::api::recommendations::evaluate_recommendations;
+use crate::config::Config;
+use crate::db::encryption::{self, Direction, ResealCount};
+use crate::db::instance_lock;
+use crate::db::pool::connect_pool;
+use crate::db::{area_queries, custom_type_queries, photo_queries, queries, schema_check};
+use crate::logic::api_quota::QuotaUsage;
+use crate::logic::data_sync::ConnectionStatus;
+use crate::logic::product_labels::{self, ImportReport, LabelFormat};
+use crate::logic::season_report::PhotoEntry;
+use crate::models::{
+    Application, ApplicationType, EnvironmentalSummary, LawnProfile, Recommendation,
+    RecommendationCategory, Severity, VolumetricWaterContent,
+};
+use crate::state::AppState;
+use anyhow::anyhow;
+pub use args::{Cli, Command, MinSeverity};
+use chrono::{DateTime, NaiveDate, Utc};
+use clap::{CommandFactory, Parser};
+use output::{opt, render, OutputFormat, Row, Table, Tabular};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+
+/// `recs` exit code when a Critical recommendation is shown. Distinct from
+/// the 1 that any error exits with, so scripts can tell "act now" from "failed".
+pub const CRITICAL_EXIT_CODE: i32 = 2;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Invocation {
+    pub command: Command,
+    pub format: OutputFormat,
+    /// Skip a running server and query the database directly.
+    pub no_server: bool,
+}
+
+/// Parse the full argv (program name first). `None` when there is no
+/// command, i.e. serve the app. Help and version come back as errors that
+/// print themselves via `clap::Error::exit`.
+pub fn parse<I, T>(args: I) -> Result<Option<Invocation>, clap::Error>
+where
+    I: IntoIterator<Item = T>,
+    T: Into<OsString> + Clone,
+{
+    let cli = Cli::try_parse_from(args)?;
+    Ok(cli.command.map(|command| Invocation {
+        command,
+        format: cli.format,
+        no_server: cli.no_server,
+    }))
+}
+
+/// Output of the commands that need no configuration or database
+/// (`completions`, `man`); `None` for every other command.
+pub fn generate(command: &Command) -> Option<std::io::Result<Vec<u8>>> {
+    let mut out = Vec::new();
+    match command {
+        Command::Completions { shell } => {
+            clap_complete::generate(*shell, &mut Cli::command(), "turfops-backend", &mut out);
+            Some(Ok(out))
+        }
+        Command::Man => Some(
+            clap_mangen::Man::new(Cli::command())
+                .render(&mut out)
+                .map(|_| out),
+        ),
+        _ => None,
+    }
+}
+
+/// Run a subcommand, print its output and return the process exit code.
+pub async fn run(invocation: Invocation, config: &Config) -> anyhow::Result<i32> {
+    let format = invocation.format;
+
+    // A running server answers from its cache, without a pool of our own
+    let asks_server = matches!(
+        invocation.command,
+        Command::Status | Command::Refresh { .. } | Command::Recs { .. }
+    );
+    if asks_server && !invocation.no_server {
+        if let Some(server) = remote::ServerClient::find(&config.server.url).await {
+            tracing::info!(url = server.base(), "Asking the running server");
+            return forward(&server, &invocation.command, format).await;
+        }
+    }
+
+    // Subcommands read the database as it is; migrations are left to the server
+    let pool = connect_pool(config.database.connect_options()).await?;
+    match &invocation.command {
+        Command::CheckSchema => {
+            let indexes = index_records(&pool).await?;
+            print!("{}", render(&indexes, format)?);
+            return Ok(if indexes.iter().all(|i| i.present) {
+                0
+            } else {
+                1
+            });
+        }
+        Command::ExportAll { archive } => {
+            let report = backup::export_all(&pool, config, archive).await?;
+            print!("{}", render(&report, format)?);
+            return Ok(0);
+        }
+        Command::ImportAll {
+            archive,
+            replace,
+            files_dir,
+        } => {
+            instance_lock::ensure_no_server(&pool, "import-all").await?;
+            let report = backup::import_all(&pool, archive, *replace, files_dir).await?;
+            print!("{}", render(&report, format)?);
+            return Ok(0);
+        }
+        Command::ImportProducts { file } => {
+            let raw = std::fs::read_to_string(file)
+                .map_err(|e| anyhow!("Reading {}: {}", file.display(), e))?;
+            let report =
+                product_labels::import_labels(&pool, &raw, LabelFormat::from_path(file)?).await?;
+            print!("{}", render(&report, format)?);
+            return Ok(0);
+        }
+        Command::EncryptDb | Command::DecryptDb => {
+            if config.database.encryption_key.is_none() {
+                return Err(anyhow!(
+                    "Set DB_ENCRYPTION_KEY_FILE or DB_ENCRYPTION_KEY_COMMAND to the key to use"
+                ));
+            }
+            let (direction, name) = match invocation.command {
+                Command::EncryptDb => (Direction::Encrypt, "encrypt-db"),
+                _ => (Direction::Decrypt, "decrypt-db"),
+            };
+            // The server would keep writing with the old setting mid-change
+            instance_lock::ensure_no_server(&pool, name).await?;
+            encryption::prepare(&pool, config.database.encryption_key.as_ref()).await?;
+            let counts = encryption::reseal_all(&pool, direction).await?;
+            print!("{}", render(&counts, format)?);
+            return Ok(0);
+        }
+        _ => {}
+    }
+
+    encryption::prepare(&pool, config.database.encryption_key.as_ref()).await?;
+    let server_running = instance_lock::server_running(&pool).await?;
+    let state = crate::build_state(config, pool).await?;
+    if server_running {
+        tracing::info!("Server is running; reading the data it syncs");
+        state.sync_service.write().await.follow_server();
+    }
+    let printed = match invocation.command {
+        Command::Status => render(&status(&state).await?, format)?,
+        Command::Refresh { deep } => {
+            let (profile, _) = active_profile(&state).await?;
+            {
+                let mut service = state.sync_service.write().await;
+                if deep {
+                    service.deep_refresh(&profile).await?;
+                } else {
+                    service.force_refresh(&profile).await?;
+                }
+            }
+            render(&status(&state).await?, format)?
+        }
+        Command::Recs { min_severity } => {
+            let recs = recommendations(&state, min_severity.into()).await?;
+            print!("{}", render(&recs, format)?);
+            return Ok(recs_exit_code(&recs));
+        }
+        Command::List { limit } => render(&applications(&state, Some(limit)).await?, format)?,
+        Command::Export => render(&applications(&state, None).await?, format)?,
+        Command::Photos { limit } => render(&photos(&state, limit).await?, format)?,
+        Command::CheckSchema
+        | Command::ExportAll { .. }
+        | Command::ImportAll { .. }
+        | Command::ImportProducts { .. }
+        | Command::EncryptDb
+        | Command::DecryptDb
+        | Command::Completions { .. }
+        | Command::Man => {
+            unreachable!("handled before building the app state")
+        }
+    };
+    print!("{}", printed);
+    Ok(0)
+}
+
+/// Run `status`, `refresh` or `recs` against a running server.
+async fn forward(
+    server: &remote::ServerClient,
+    command: &Command,
+    format: OutputFormat,
+) -> anyhow::Result<i32> {
+    let printed = match *command {
+        Command::Status => render(&server.status(None).await?, format)?,
+        Command::Refresh { deep } => render(&server.refresh(deep).await?, format)?,
+        Command::Recs { min_severity } => {
+            let recs = server.recommendations(min_severity.into()).await?;
+            print!("{}", render(&recs, format)?);
+            return Ok(recs_exit_code(&recs));
+        }
+        _ => unreachable!("only status, refresh and recs are forwarded"),
+    };
+    print!("{}", printed);
+    Ok(0)
+}
+
+async fn active_profile(state: &AppState) -> anyhow::Result<(LawnProfile, i64)> {
+    let profile = queries::get_active_lawn_profile(&state.pool)
+        .await?
+        .ok_or_else(|| anyhow!("No lawn profile found"))?;
+    let id = profile.id.ok_or_else(|| anyhow!("Profile missing ID"))?;
+    Ok((profile, id))
+}
+
+#[derive(Debug, Serialize)]
+pub struct StatusReport {
+    pub version: String,
+    pub database: bool,
+    pub profile: String,
+    /// Sensor sources by key (`uscrn`, `homeassistant`, ...) and whether each is reachable.
+    pub sources: BTreeMap<String, bool>,
+    pub openweathermap: bool,
+    pub openweathermap_usage: Option<QuotaUsage>,
+    pub last_updated: Option<DateTime<Utc>>,
+    pub soil_temp_10cm_f: Option<f64>,
+    /// 7-day soil temp average against the archive normal for the week (°F).
+    pub soil_temp_vs_normal_f: Option<f64>,
+    pub soil_moisture: Option<f64>,
+    pub ambient_temp_f: Option<f64>,
+    pub humidity_percent: Option<f64>,
+    pub active_recommendations: usize,
+}
+
+impl StatusReport {
+    fn new(
+        version: String,
+        database: bool,
+        profile: &LawnProfile,
+        connections: ConnectionStatus,
+        openweathermap_usage: Option<QuotaUsage>,
+        summary: &EnvironmentalSummary,
+        active_recommendations: usize,
+    ) -> Self {
+        let current = summary.current.as_ref();
+        StatusReport {
+            version,
+            database,
+            profile: profile.name.clone(),
+            sources: connections.sources,
+            openweathermap: connections.openweathermap,
+            openweathermap_usage,
+            last_updated: summary.last_updated,
+            soil_temp_10cm_f: current.and_then(|c| c.soil_temp_10_f),
+            soil_temp_vs_normal_f: summary.soil_temp_anomaly.map(|a| a.anomaly_f),
+            soil_moisture: current.and_then(|c| c.primary_soil_moisture()),
+            ambient_temp_f: current.and_then(|c| c.ambient_temp_f),
+            humidity_percent: current.and_then(|c| c.humidity_percent),
+            active_recommendations,
+        }
+    }
+}
+
+impl Tabular for StatusReport {
+    fn table(&self) -> Table {
+        let mut table = Table::new(&["Item", "Value"]);
+        let ok = |up: bool| if up { "ok" } else { "unavailable" }.to_string();
+        table.row(vec!["Version".into(), self.version.clone()]);
+        table.row(vec!["Database".into(), ok(self.database)]);
+        table.row(vec!["Profile".into(), self.profile.clone()]);
+        for (source, up) in &self.sources {
+            table.row(vec![format!("Source: {}", source), ok(*up)]);
+        }
+        table.row(vec!["OpenWeatherMap".into(), ok(self.openweathermap)]);
+        if let Some(ref usage) = self.openweathermap_usage {
+            let limit = match usage.limit {
+                0 => String::new(),
+                limit => format!(" of {}", limit),
+            };
+            let pace = if usage.stretched { ", slowed" } else { "" };
+            table.row(vec![
+                "OpenWeatherMap calls today".into(),
+                format!(
+                    "{}{} (forecast every {} min{})",
+                    usage.calls, limit, usage.refresh_minutes, pace
+                ),
+            ]);
+        }
+        table.row(vec![
+            "Last updated".into(),
+            opt(self.last_updated.map(|t| t.format("%Y-%m-%d %H:%M UTC"))),
+        ]);
+        table.row(vec![
+            "Soil temp (10cm)".into(),
+            opt(self.soil_temp_10cm_f.map(|t| format!("{:.1}°F", t))),
+        ]);
+        if let Some(anomaly) = self.soil_temp_vs_normal_f {
+            table.row(vec![
+                "Soil temp vs normal".into(),
+                format!("{:+.1}°F (7-day avg)", anomaly),
+            ]);
+        }
+        table.row(vec![
+            "Soil moisture".into(),
+            opt(self
+                .soil_moisture
+                .map(|m| format!("{:.0} VWC", VolumetricWaterContent(m)))),
+        ]);
+        table.row(vec![
+            "Air temp".into(),
+            opt(self.ambient_temp_f.map(|t| format!("{:.0}°F", t))),
+        ]);
+        table.row(vec![
+            "Humidity".into(),
+            opt(self.humidity_percent.map(|h| format!("{:.0}%", h))),
+        ]);
+        table.row(vec![
+            "Active recommendations".into(),
+            self.active_recommendations.to_string(),
+        ]);
+        table
+    }
+}
+
+impl Row for ResealCount {
+    const HEADERS: &'static [&'static str] = &["Column", "Rows changed"];
+
+    fn cells(&self) -> Vec<String> {
+        vec![self.column.clone(), self.rows.to_string()]
+    }
+}
+
+impl Tabular for ImportReport {
+    fn table(&self) -> Table {
+        let mut table = Table::new(&["Result", "Product"]);
+        for (result, names) in [
+            ("Added", &self.added),
+            ("Updated", &self.updated),
+            ("Kept (edited)", &self.kept),
+        ] {
+            for name in names {
+                table.row(vec![result.into(), name.clone()]);
+            }
+        }
+        table.row(vec!["Unchanged".into(), self.unchanged.to_string()]);
+        table
+    }
+}
+
+async fn status(state: &AppState) -> anyhow::Result<StatusReport> {
+    let (profile, _) = active_profile(state).await?;
+    let database = sqlx::query("SELECT 1").fetch_one(&state.pool).await.is_ok();
+    let (connections, summary) = {
+        let mut service = state.sync_service.write().await;
+        (
+            service.check_connections().await,
+            service.get_or_refresh(&profile).await?,
+        )
+    };
+    let active = evaluate_recommendations(state, None)
+        .await?
+        .iter()
+        .filter(|r| r.is_active())
+        .count();
+    Ok(StatusReport::new(
+        env!("CARGO_PKG_VERSION").into(),
+        database,
+        &profile,
+        connections,
+        state.forecast_quota.as_ref().map(|q| q.usage(Utc::now())),
+        &summary,
+        active,
+    ))
+}
+
+#[derive(Debug, Serialize)]
+pub struct RecommendationRecord {
+    pub id: String,
+    pub severity: Severity,
+    pub category: RecommendationCategory,
+    pub title: String,
+    pub description: String,
+    pub suggested_action: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<Recommendation> for RecommendationRecord {
+    fn from(rec: Recommendation) -> Self {
+        Self {
+            id: rec.id,
+            severity: rec.severity,
+            category: rec.category,
+            title: rec.title,
+            description: rec.description,
+            suggested_action: rec.suggested_action,
+            created_at: rec.created_at,
+        }
+    }
+}
+
+impl Row for RecommendationRecord {
+    const HEADERS: &'static [&'static str] = &["Severity", "Category", "Title", "Action"];
+
+    fn cells(&self) -> Vec<String> {
+        vec![
+            self.severity.to_string(),
+            self.category.to_string(),
+            self.title.clone(),
+            opt(self.suggested_action.as_deref()),
+        ]
+    }
+}
+
+impl From<MinSeverity> for Severity {
+    fn from(min: MinSeverity) -> Self {
+        match min {
+            MinSeverity::Info => Severity::Info,
+            MinSeverity::Advisory => Severity::Advisory,
+            MinSeverity::Warning => Severity::Warning,
+            MinSeverity::Critical => Severity::Critical,
+        }
+    }
+}
+
+/// Active recommendations at or above `min_severity`.
+async fn recommendations(
+    state: &AppState,
+    min_severity: Severity,
+) -> anyhow::Result<Vec<RecommendationRecord>> {
+    Ok(evaluate_recommendations(state, None)
+        .await?
+        .into_iter()
+        .filter(|r| r.is_active() && r.severity >= min_severity)
+        .map(RecommendationRecord::from)
+        .collect())
+}
+
+/// [`CRITICAL_EXIT_CODE`] if any of `recs` is Critical, else 0.
+fn recs_exit_code(recs: &[RecommendationRecord]) -> i32 {
+    if recs.iter().any(|r| r.severity == Severity::Critical) {
+        CRITICAL_EXIT_CODE
+    } else {
+        0
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ApplicationRecord {
+    pub id: Option<i64>,
+    pub date: NaiveDate,
+    pub application_type: ApplicationType,
+    /// Name of the custom type it was logged as, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_type: Option<String>,
+    pub product_name: Option<String>,
+    pub rate_per_1000sqft: Option<f64>,
+    pub coverage_sqft: Option<f64>,
+    pub nitrogen_pct: Option<f64>,
+    pub phosphorus_pct: Option<f64>,
+    pub potassium_pct: Option<f64>,
+    pub area_id: Option<i64>,
+    pub equipment_id: Option<i64>,
+    pub follow_up_date: Option<NaiveDate>,
+    pub notes: Option<String>,
+}
+
+impl From<Application> for ApplicationRecord {
+    fn from(app: Application) -> Self {
+        Self {
+            id: app.id,
+            date: app.application_date,
+            application_type: app.application_type,
+            custom_type: None,
+            product_name: app.product_name,
+            rate_per_1000sqft: app.rate_per_1000sqft,
+            coverage_sqft: app.coverage_sqft,
+            nitrogen_pct: app.nitrogen_pct,
+            phosphorus_pct: app.phosphorus_pct,
+            potassium_pct: app.potassium_pct,
+            area_id: app.area_id,
+            equipment_id: app.equipment_id,
+            follow_up_date: app.follow_up_date,
+            notes: app.notes,
+        }
+    }
+}
+
+impl Row for ApplicationRecord {
+    const HEADERS: &'static [&'static str] =
+        &["ID", "Date", "Type", "Product", "Rate/1000", "Notes"];
+
+    fn cells(&self) -> Vec<String> {
+        vec![
+            opt(self.id),
+            self.date.to_string(),
+            self.custom_type
+                .clone()
+                .unwrap_or_else(|| self.application_type.as_str().to_string()),
+            opt(self.product_name.as_deref()),
+            opt(self.rate_per_1000sqft),
+            opt(self.notes.as_deref()),
+        ]
+    }
+}
+
+/// Newest `limit` applications, or all of them oldest first when `None`.
+async fn applications(
+    state: &AppState,
+    limit: Option<i64>,
+) -> anyhow::Result<Vec<ApplicationRecord>> {
+    let (_, profile_id) = active_profile(state).await?;
+    let mut apps = queries::get_applications_for_profile(
+        &state.pool,
+        profile_id,
+        limit.unwrap_or(i64::MAX),
+        0,
+    )
+    .await?;
+    if limit.is_none() {
+        apps.reverse();
+    }
+    let registry = custom_type_queries::load_registry(&state.pool).await?;
+    Ok(apps
+        .into_iter()
+        .map(|app| {
+            let custom_type = app
+                .custom_type_id
+                .and_then(|id| registry.get(id))
+                .map(|t| t.name.clone());
+            ApplicationRecord {
+                custom_type,
+                ..ApplicationRecord::from(app)
+            }
+        })
+        .collect())
+}
+
+#[derive(Debug, Serialize)]
+pub struct PhotoRecord {
+    pub id: Option<i64>,
+    pub date: NaiveDate,
+    pub area: Option<String>,
+    pub path: String,
+    pub caption: Option<String>,
+    pub last_application: Option<String>,
+    pub days_since_application: Option<i64>,
+}
+
+impl Row for PhotoRecord {
+    const HEADERS: &'static [&'static str] =
+        &["Date", "Area", "Caption", "Path", "Last application"];
+
+    fn cells(&self) -> Vec<String> {
+        vec![
+            self.date.to_string(),
+            self.area.as_deref().unwrap_or("Whole lawn").to_string(),
+            opt(self.caption.as_deref()),
+            self.path.clone(),
+            match (&self.last_application, self.days_since_application) {
+                (Some(app), Some(days)) => format!("{app}, {days}d before"),
+                _ => String::new(),
+            },
+        ]
+    }
+}
+
+/// Newest `limit` photos, each with the application that preceded it.
+async fn photos(state: &AppState, limit: i64) -> anyhow::Result<Vec<PhotoRecord>> {
+    let (_, profile_id) = active_profile(state).await?;
+    let photos =
+        photo_queries::get_photos_for_profile(&state.pool, profile_id, None, limit, 0).await?;
+    let apps = queries::get_applications_for_profile(&state.pool, profile_id, i64::MAX, 0).await?;
+    let areas = area_queries::list_areas_for_profile(&state.pool, profile_id).await?;
+
+    Ok(photos
+        .iter()
+        .map(|photo| {
+            let entry = PhotoEntry::new(photo, &apps, &areas, &[]);
+            PhotoRecord {
+                id: photo.id,
+                date: entry.date,
+                area: entry.area_name,
+                path: entry.path,
+                caption: entry.caption,
+                last_application: entry.last_application,
+                days_since_application: entry.days_since_application,
+            }
+        })
+        .collect())
+}
+
+#[derive(Debug, Serialize)]
+pub struct IndexRecord {
+    pub table: &'static str,
+    pub columns: Vec<&'static str>,
+    pub purpose: &'static str,
+    pub present: bool,
+}
+
+impl Row for IndexRecord {
+    const HEADERS: &'static [&'static str] = &["Status", "Index", "Purpose"];
+
+    fn cells(&self) -> Vec<String> {
+        vec![
+            if self.present { "ok" } else { "MISSING" }.to_string(),
+            format!("{}({})", self.table, self.columns.join(", ")),
+            self.purpose.to_string(),
+        ]
+    }
+}
+
+async fn index_records(pool: &sqlx::PgPool) -> anyhow::Result<Vec<IndexRecord>> {
+    let missing = schema_check::missing_indexes(&schema_check::list_indexes(pool).await?);
+    Ok(schema_check::EXPECTED_INDEXES
+        .iter()
+        .map(|expected| IndexRecord {
+            table: expected.table,
+            columns: expected.columns.to_vec(),
+            purpose: expected.purpose,
+            present: !missing.contains(expected),
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_line(line: &str) -> Result<Option<Invocation>, clap::Error> {
+        parse(std::iter::once("turfops-backend").chain(line.split_whitespace()))
+    }
+
+    #[test]
+    fn parses_commands_and_shared_flags() {
+        Cli::command().debug_assert();
+
+        assert_eq!(parse_line("").unwrap(), None);
+        assert_eq!(
+            parse_line("recs --format json").unwrap(),
+            Some(Invocation {
+                command: Command::Recs {
+                    min_severity: MinSeverity::Info
+                },
+                format: OutputFormat::Json,
+                no_server: false
+            })
+        );
+        assert_eq!(
+            parse_line("recs --min-severity CRITICAL")
+                .unwrap()
+                .unwrap()
+                .command,
+            Command::Recs {
+                min_severity: MinSeverity::Critical
+            }
+        );
+        assert_eq!(
+            parse_line("--format=JSON list --limit 5").unwrap(),
+            Some(Invocation {
+                command: Command::List { limit: 5 },
+                format: OutputFormat::Json,
+                no_server: false
+            })
+        );
+        assert_eq!(
+            parse_line("refresh --deep --no-server").unwrap(),
+            Some(Invocation {
+                command: Command::Refresh { deep: true },
+                format: OutputFormat::Table,
+                no_server: true
+            })
+        );
+        assert_eq!(
+            parse_line("list").unwrap().unwrap().command,
+            Command::List {
+                limit: args::DEFAULT_LIST_LIMIT
+            }
+        );
+        assert_eq!(
+            parse_line("photos --limit 3").unwrap().unwrap().command,
+            Command::Photos { limit: 3 }
+        );
+        assert_eq!(
+            parse_line("check-schema").unwrap().unwrap().format,
+            OutputFormat::Table
+        );
+        assert_eq!(
+            parse_line("encrypt-db").unwrap().unwrap().command,
+            Command::EncryptDb
+        );
+        assert_eq!(
+            parse_line("import-products labels.csv")
+                .unwrap()
+                .unwrap()
+                .command,
+            Command::ImportProducts {
+                file: "labels.csv".into()
+            }
+        );
+        assert_eq!(
+            parse_line("completions zsh").unwrap().unwrap().command,
+            Command::Completions {
+                shell: clap_complete::Shell::Zsh
+            }
+        );
+
+        let help = parse_line("status --help").unwrap_err();
+        assert_eq!(help.kind(), clap::error::ErrorKind::DisplayHelp);
+        assert!(parse_line("recs --format yaml").is_err());
+        assert!(parse_line("recs --limit 5").is_err());
+        assert!(parse_line("recs --min-severity urgent").is_err());
+        assert!(parse_line("list --limit 0").is_err());
+        assert!(parse_line("list --format").is_err());
+        assert!(parse_line("status recs").is_err());
+        assert!(parse_line("serve").is_err());
+        assert!(parse_line("completions tcsh").is_err());
+    }
+
+    #[test]
+    fn generates_completions_and_man_page() {
+        let bash = generate(&Command::Completions {
+            shell: clap_complete::Shell::Bash,
+        })
+        .unwrap()
+        .unwrap();
+        let bash = String::from_utf8(bash).unwrap();
+        assert!(bash.contains("check-schema"));
+        assert!(bash.contains("--format"));
+
+        let man = String::from_utf8(generate(&Command::Man).unwrap().unwrap()).unwrap();
+        assert!(man.starts_with(".ie"));
+        assert!(man.contains("turfops\\-backend"));
+
+        assert!(generate(&Command::Status).is_none());
+    }
+
+    #[test]
+    fn recs_exit_code_flags_critical() {
+        let rec = |severity| {
+            RecommendationRecord::from(Recommendation::new(
+                "test",
+                RecommendationCategory::DiseasePressure,
+                severity,
+                "Title",
+                "Description",
+            ))
+        };
+        assert_eq!(recs_exit_code(&[]), 0);
+        assert_eq!(recs_exit_code(&[rec(Severity::Warning)]), 0);
+        assert_eq!(
+            recs_exit_code(&[rec(Severity::Advisory), rec(Severity::Critical)]),
+            CRITICAL_EXIT_CODE
+        );
+        assert!(Severity::from(MinSeverity::Warning) > Severity::Advisory);
+    }
+}
@@ -0,0 +1,151 @@
+//! Shared output layer for CLI subcommands: every result prints either as
+//! pretty JSON with stable field names or as an aligned text table.
+
+pub use super::args::OutputFormat;
+use serde::Serialize;
+
+/// Cells longer than this are cut in table output; JSON is never truncated.
+const MAX_CELL_CHARS: usize = 60;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Table {
+    headers: Vec<&'static str>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(headers: &[&'static str]) -> Self {
+        Self {
+            headers: headers.to_vec(),
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn row(&mut self, cells: Vec<String>) {
+        self.rows.push(cells);
+    }
+
+    pub fn render(&self) -> String {
+        let rows: Vec<Vec<String>> = self
+            .rows
+            .iter()
+            .map(|r| r.iter().map(|c| truncate(c)).collect())
+            .collect();
+        let widths: Vec<usize> = self
+            .headers
+            .iter()
+            .enumerate()
+            .map(|(i, h)| {
+                rows.iter()
+                    .filter_map(|r| r.get(i))
+                    .map(|c| c.chars().count())
+                    .chain([h.chars().count()])
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+
+        let line = |cells: Vec<&str>| {
+            let padded: Vec<String> = cells
+                .iter()
+                .zip(&widths)
+                .map(|(c, w)| format!("{:<w$}", c, w = *w))
+                .collect();
+            padded.join("  ").trim_end().to_string() + "\n"
+        };
+        let mut out = line(self.headers.clone());
+        let rules: Vec<String> = widths.iter().map(|w| "-".repeat(*w)).collect();
+        out.push_str(&line(rules.iter().map(String::as_str).collect()));
+        for row in &rows {
+            out.push_str(&line(row.iter().map(String::as_str).collect()));
+        }
+        out
+    }
+}
+
+fn truncate(cell: &str) -> String {
+    let cell = cell.replace('\n', " ");
+    if cell.chars().count() <= MAX_CELL_CHARS {
+        return cell;
+    }
+    let cut: String = cell.chars().take(MAX_CELL_CHARS - 1).collect();
+    format!("{}…", cut)
+}
+
+/// A command result with a table view; the JSON view is its `Serialize` form.
+pub trait Tabular: Serialize {
+    fn table(&self) -> Table;
+}
+
+/// One record of a list result.
+pub trait Row: Serialize {
+    const HEADERS: &'static [&'static str];
+    fn cells(&self) -> Vec<String>;
+}
+
+impl<T: Row> Tabular for Vec<T> {
+    fn table(&self) -> Table {
+        let mut table = Table::new(T::HEADERS);
+        for row in self {
+            table.row(row.cells());
+        }
+        table
+    }
+}
+
+pub fn render(value: &impl Tabular, format: OutputFormat) -> serde_json::Result<String> {
+    match format {
+        OutputFormat::Json => serde_json::to_string_pretty(value).map(|s| s + "\n"),
+        OutputFormat::Table => Ok(value.table().render()),
+    }
+}
+
+/// Empty cell for a missing value.
+pub fn opt<T: std::fmt::Display>(value: Option<T>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Item {
+        name: String,
+        count: Option<u32>,
+    }
+
+    impl Row for Item {
+        const HEADERS: &'static [&'static str] = &["Name", "Count"];
+        fn cells(&self) -> Vec<String> {
+            vec![self.name.clone(), opt(self.count)]
+        }
+    }
+
+    #[test]
+    fn table_and_json_views_of_the_same_rows() {
+        let items = vec![
+            Item {
+                name: "Barricade".into(),
+                count: Some(2),
+            },
+            Item {
+                name: "x".repeat(80),
+                count: None,
+            },
+        ];
+
+        let table = render(&items, OutputFormat::Table).unwrap();
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines[0], format!("{:<60}  Count", "Name"));
+        assert!(lines[1].starts_with("----------"));
+        assert_eq!(lines[2], format!("{:<60}  2", "Barricade"));
+        assert_eq!(lines[3], format!("{}…", "x".repeat(59)));
+
+        let json: serde_json::Value =
+            serde_json::from_str(&render(&items, OutputFormat::Json).unwrap()).unwrap();
+        assert_eq!(json[0]["name"], "Barricade");
+        assert_eq!(json[1]["count"], serde_json::Value::Null);
+        assert_eq!(json[1]["name"].as_str().unwrap().len(), 80);
+    }
+}
@@ -0,0 +1,128 @@
+//! Client mode. While a server is running, `status`, `recs` and `refresh`
+//! ask it over its HTTP API (`TURFOPS_SERVER_URL`, by default loopback on
+//! `SERVER_PORT`) instead of opening a pool and fetching from the data
+//! sources themselves: answers come from the server's cache, and a refresh
+//! it runs is the one the web app sees too. `--no-server` skips this.
+
+use super::{RecommendationRecord, StatusReport};
+use crate::api::health::HealthResponse;
+use crate::models::{EnvironmentalSummary, LawnProfile, Recommendation, Severity};
+use anyhow::anyhow;
+use serde::de::DeserializeOwned;
+use std::time::Duration;
+
+/// How long to wait for the health check before deciding no server runs.
+const PROBE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Long enough for a deep refresh of a slow data lake.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(120);
+
+pub struct ServerClient {
+    base: String,
+    http: reqwest::Client,
+}
+
+impl ServerClient {
+    /// The server answering at `base`, or `None` if nothing does.
+    pub async fn find(base: &str) -> Option<Self> {
+        let client = ServerClient {
+            base: base.to_string(),
+            http: reqwest::Client::new(),
+        };
+        let probe = client
+            .http
+            .get(client.url("/api/v1/health"))
+            .timeout(PROBE_TIMEOUT)
+            .send()
+            .await;
+        match probe {
+            Ok(response) if response.status().is_success() => Some(client),
+            Ok(response) => {
+                tracing::debug!(status = %response.status(), "No usable server; querying directly");
+                None
+            }
+            Err(e) => {
+                tracing::debug!(error = %e, "No server running; querying directly");
+                None
+            }
+        }
+    }
+
+    pub fn base(&self) -> &str {
+        &self.base
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base, path)
+    }
+
+    async fn send<T: DeserializeOwned>(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> anyhow::Result<T> {
+        let response = request
+            .timeout(REQUEST_TIMEOUT)
+            .send()
+            .await
+            .map_err(|e| anyhow!("Server at {}: {}", self.base, e))?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(anyhow!(
+                "Server at {} answered {}: {}",
+                self.base,
+                status,
+                body
+            ));
+        }
+        Ok(response.json().await?)
+    }
+
+    async fn get<T: DeserializeOwned>(&self, path: &str) -> anyhow::Result<T> {
+        self.send(self.http.get(self.url(path))).await
+    }
+
+    /// Status from the server's cached summary, or from `summary` when the
+    /// caller just refreshed.
+    pub async fn status(
+        &self,
+        summary: Option<EnvironmentalSummary>,
+    ) -> anyhow::Result<StatusReport> {
+        let health: HealthResponse = self.get("/api/v1/health").await?;
+        let profile: LawnProfile = self.get("/api/v1/profile").await?;
+        let summary = match summary {
+            Some(summary) => summary,
+            None => self.get("/api/v1/environmental").await?,
+        };
+        // The server returns only active ones
+        let recs: Vec<Recommendation> = self.get("/api/v1/recommendations").await?;
+
+        Ok(StatusReport::new(
+            health.version,
+            health.database,
+            &profile,
+            health.datasources,
+            health.openweathermap_usage,
+            &summary,
+            recs.len(),
+        ))
+    }
+
+    pub async fn refresh(&self, deep: bool) -> anyhow::Result<StatusReport> {
+        let path = format!("/api/v1/environmental/refresh?deep={}", deep);
+        let summary = self.send(self.http.post(self.url(&path))).await?;
+        self.status(Some(summary)).await
+    }
+
+    pub async fn recommendations(
+        &self,
+        min_severity: Severity,
+    ) -> anyhow::Result<Vec<RecommendationRecord>> {
+        let recs: Vec<Recommendation> = self.get("/api/v1/recommendations").await?;
+        Ok(recs
+            .into_iter()
+            .filter(|r| r.severity >= min_severity)
+            .map(RecommendationRecord::from)
+            .collect())
+    }
+}
@@ -0,0 +1,63 @@
+//! The evaluation clock. Rules and forecast look-aheads read "now" from here
+//! rather than the system clock, so the engine can be evaluated at any moment:
+//! live requests pass the current time, backtests and scenario tests a past day.
+
+use chrono::{DateTime, Local, NaiveDate, TimeZone, Utc};
+use std::cell::Cell;
+
+thread_local! {
+    /// Instant pinned by [`at`] while an evaluation runs.
+    static PINNED: Cell<Option<DateTime<Utc>>> = const { Cell::new(None) };
+}
+
+/// The pinned instant, or the system time outside [`at`].
+pub fn now() -> DateTime<Utc> {
+    PINNED.with(Cell::get).unwrap_or_else(Utc::now)
+}
+
+/// Local date of [`now`].
+pub fn today() -> NaiveDate {
+    now().with_timezone(&Local).date_naive()
+}
+
+/// Run `f` with [`now`] pinned to `instant` on this thread. Rule evaluation is
+/// synchronous, so the pin covers everything `f` evaluates.
+pub fn at<T>(instant: DateTime<Utc>, f: impl FnOnce() -> T) -> T {
+    struct Restore(Option<DateTime<Utc>>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            PINNED.with(|p| p.set(self.0));
+        }
+    }
+
+    let _restore = Restore(PINNED.with(|p| p.replace(Some(instant))));
+    f()
+}
+
+/// Local noon on `date`, the instant used when only a day is known.
+pub fn local_noon(date: NaiveDate) -> DateTime<Utc> {
+    let noon = date.and_hms_opt(12, 0, 0).expect("noon is a valid time");
+    Local
+        .from_local_datetime(&noon)
+        .earliest()
+        .map(|t| t.with_timezone(&Utc))
+        .unwrap_or_else(|| noon.and_utc())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pin_is_scoped_and_nests() {
+        let day = NaiveDate::from_ymd_opt(2025, 4, 1).unwrap();
+        let other = NaiveDate::from_ymd_opt(2025, 9, 1).unwrap();
+
+        at(local_noon(day), || {
+            assert_eq!(today(), day);
+            at(local_noon(other), || assert_eq!(today(), other));
+            assert_eq!(today(), day);
+        });
+        assert!(PINNED.with(Cell::get).is_none());
+    }
+}
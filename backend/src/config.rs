@@ -1,4 +1,5 @@
 use crate::error::{Result, TurfOpsError};
+use crate::models::DashboardLayout;
 use serde::Deserialize;
 use sqlx::postgres::PgConnectOptions;
 
@@ -8,10 +9,21 @@ pub struct Config {
     pub noaa: NoaaConfig,
     pub datalake: DataLakeConfig,
     pub homeassistant: HomeAssistantConfig,
+    pub ecowitt: Option<EcowittConfig>,
+    pub tempest: Option<TempestConfig>,
+    pub sources: SourcesConfig,
     pub openweathermap: Option<OpenWeatherMapConfig>,
     pub openrouter: Option<OpenRouterConfig>,
+    pub drought_monitor: DroughtMonitorConfig,
+    pub historical_weather: HistoricalWeatherConfig,
     pub server: ServerConfig,
     pub database: DatabaseConfig,
+    pub rules: RulesConfig,
+    pub locale: LocaleConfig,
+    pub retention: RetentionConfig,
+    pub digest: Option<DigestConfig>,
+    pub notifications: NotificationConfig,
+    pub dashboard: DashboardConfig,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -26,7 +38,9 @@ pub struct LawnConfig {
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct NoaaConfig {
-    pub station_wbanno: i32,
+    /// USCRN station WBANNOs in priority order. The first station with recent
+    /// observations wins; the rest are fallbacks for when it goes offline.
+    pub station_wbannos: Vec<i32>,
 }
 
 /// Filesystem paths to the NOAA weather data lake (parquet, produced by the
@@ -37,6 +51,14 @@ pub struct DataLakeConfig {
     pub silver_weather_path: String,
     /// Gold daily weather parquet (pre-aggregated daily means + gdd50).
     pub gold_weather_path: String,
+    /// Copy new silver rows into Postgres on each refresh and compute the 7-day
+    /// summary from the local copy, so it survives lake outages.
+    pub local_sync: bool,
+    /// Length of each window in the summary trends (last N hours vs the N before).
+    pub trend_window_hours: i64,
+    /// Longest a parquet query may run before the refresh gives up on the lake,
+    /// e.g. when a network mount hangs.
+    pub timeout_secs: u64,
 }
 
 #[derive(Clone, Deserialize)]
@@ -69,6 +91,49 @@ pub enum TemperatureUnit {
     Celsius,
 }
 
+/// An Ecowitt gateway (GW1100/GW2000 and compatibles) read over its local
+/// HTTP API.
+#[derive(Debug, Clone, Deserialize)]
+pub struct EcowittConfig {
+    /// Gateway base URL, e.g. `http://192.168.1.50`.
+    pub url: String,
+    /// WH51 soil moisture channel reported as the 10cm moisture.
+    pub soil_moisture_channel: u8,
+    /// WN34 temperature probe channel reported as the 10cm soil temperature.
+    pub soil_temp_channel: u8,
+}
+
+/// A WeatherFlow Tempest station read through the WeatherFlow REST API.
+#[derive(Clone, Deserialize)]
+pub struct TempestConfig {
+    pub station_id: String,
+    /// Personal access token from tempestwx.com → Settings → Data Authorizations.
+    pub token: String,
+}
+
+impl std::fmt::Debug for TempestConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TempestConfig")
+            .field("station_id", &self.station_id)
+            .field("token", &"[REDACTED]")
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SourcesConfig {
+    /// Per-capability merge order for sensor sources, e.g.
+    /// `ambient_temp=homeassistant,uscrn;humidity=homeassistant`. Empty keeps
+    /// registration order (lake first).
+    pub priority: String,
+    /// Hours after which a current value counts as stale and rules treat it
+    /// as missing. 0 disables the watchdog.
+    pub stale_after_hours: u32,
+    /// Per-request timeout for the HTTP sources: Home Assistant, Ecowitt,
+    /// Tempest, OpenWeatherMap and the Drought Monitor.
+    pub http_timeout_secs: u64,
+}
+
 #[derive(Clone, Deserialize)]
 pub struct OpenWeatherMapConfig {
     pub api_key: String,
@@ -76,6 +141,21 @@ pub struct OpenWeatherMapConfig {
     pub longitude: f64,
     #[serde(default = "default_enabled")]
     pub enabled: bool,
+    /// Use One Call 3.0 (48 hourly points, 8 real daily values, minutely
+    /// precipitation) instead of the 5-day/3-hour forecast. Needs a One Call
+    /// subscription on the API key.
+    #[serde(default)]
+    pub one_call: bool,
+    /// Also read current air quality (Air Pollution API, same key).
+    #[serde(default = "default_enabled")]
+    pub air_quality: bool,
+    /// Calls allowed per UTC day across every OWM request; 0 for no limit.
+    #[serde(default = "default_owm_daily_quota")]
+    pub daily_quota: u32,
+}
+
+fn default_owm_daily_quota() -> u32 {
+    1000
 }
 
 fn default_enabled() -> bool {
@@ -89,6 +169,9 @@ impl std::fmt::Debug for OpenWeatherMapConfig {
             .field("latitude", &self.latitude)
             .field("longitude", &self.longitude)
             .field("enabled", &self.enabled)
+            .field("one_call", &self.one_call)
+            .field("air_quality", &self.air_quality)
+            .field("daily_quota", &self.daily_quota)
             .finish()
     }
 }
@@ -123,11 +206,162 @@ impl std::fmt::Debug for OpenRouterConfig {
     }
 }
 
+/// U.S. Drought Monitor county status (no API key needed).
+#[derive(Debug, Clone, Deserialize)]
+pub struct DroughtMonitorConfig {
+    pub enabled: bool,
+    /// 5-digit county FIPS; looked up from the lawn's coordinates when unset.
+    pub county_fips: Option<String>,
+}
+
+/// Open-Meteo archive for dates before the station lake's history (no API key needed).
+#[derive(Debug, Clone, Deserialize)]
+pub struct HistoricalWeatherConfig {
+    pub enabled: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RulesConfig {
+    /// Optional YAML (or JSON) file of declarative custom rules (see `logic/rules/custom.rs`).
+    pub custom_rules_path: Option<String>,
+    /// Optional directory of `.rhai` script rules (see `logic/rules/script.rs`).
+    pub rules_dir: Option<String>,
+    /// Rule names that are never evaluated, e.g. `GrubControlRule`.
+    pub disabled: Vec<String>,
+}
+
+/// Language for recommendation text and UI labels (see `i18n.rs`).
+#[derive(Debug, Clone, Deserialize)]
+pub struct LocaleConfig {
+    /// Catalog name, e.g. `es`. `en` uses the source text.
+    pub locale: String,
+    /// Optional directory of `<locale>.json` catalogs that take precedence over
+    /// the built-in ones.
+    pub dir: Option<String>,
+}
+
+/// How long the maintenance task keeps rows that only serve recent views.
+/// A retention of 0 days keeps rows forever.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RetentionConfig {
+    /// Local copy of lake hourly rows (`soil_observations`); the lake itself
+    /// keeps the full history.
+    pub soil_observation_days: u32,
+    /// Activity log (`events`).
+    pub event_days: u32,
+    /// Rain checks, counted from the end of their window.
+    pub rain_check_days: u32,
+    /// Hours between maintenance runs.
+    pub interval_hours: u64,
+}
+
+/// Recommendation digest email. Enabled when `DIGEST_SMTP_HOST` and
+/// `DIGEST_TO` are both set.
+#[derive(Clone, Deserialize)]
+pub struct DigestConfig {
+    pub smtp_host: String,
+    pub smtp_port: u16,
+    pub smtp_security: SmtpSecurity,
+    pub smtp_username: Option<String>,
+    pub smtp_password: Option<String>,
+    pub from: String,
+    pub to: Vec<String>,
+    pub frequency: DigestFrequency,
+    /// Local hour (0-23) the digest goes out.
+    pub hour: u32,
+}
+
+impl std::fmt::Debug for DigestConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DigestConfig")
+            .field("smtp_host", &self.smtp_host)
+            .field("smtp_port", &self.smtp_port)
+            .field("smtp_security", &self.smtp_security)
+            .field("smtp_username", &self.smtp_username)
+            .field("smtp_password", &"[REDACTED]")
+            .field("from", &self.from)
+            .field("to", &self.to)
+            .field("frequency", &self.frequency)
+            .field("hour", &self.hour)
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum SmtpSecurity {
+    /// Plain connection upgraded with STARTTLS (usually port 587).
+    StartTls,
+    /// TLS from the first byte (usually port 465).
+    Tls,
+    /// Unencrypted, for a relay on the local network.
+    None,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum DigestFrequency {
+    Daily,
+    Weekly(chrono::Weekday),
+}
+
+/// When and how often notifications (today, the digest email) may repeat
+/// themselves. Applied by `logic/notify.rs`.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct NotificationConfig {
+    /// Local time range with no sends; a send due inside it waits for the end.
+    pub quiet_hours: Option<QuietHours>,
+    /// A recommendation already sent is left out for this many hours unless
+    /// its severity rose. 0 repeats it every time.
+    pub cooldown_hours: u32,
+    /// Repeat a recommendation that was already sent only when its severity rises.
+    pub escalation_only: bool,
+}
+
+/// Dashboard layout fixed in the environment rather than chosen in the UI.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct DashboardConfig {
+    /// Panels in display order from `DASHBOARD_PANELS`; while set, the
+    /// Settings page can't change the layout.
+    pub panels: Option<DashboardLayout>,
+}
+
+/// `22-7` or `22:30-06:45`; may wrap past midnight. Start and end equal means none.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct QuietHours {
+    pub start: chrono::NaiveTime,
+    pub end: chrono::NaiveTime,
+}
+
+impl std::str::FromStr for QuietHours {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let time = |part: &str| {
+            let part = part.trim();
+            let (hour, minute) = part.split_once(':').unwrap_or((part, "0"));
+            hour.parse()
+                .ok()
+                .zip(minute.parse().ok())
+                .and_then(|(h, m)| chrono::NaiveTime::from_hms_opt(h, m, 0))
+                .ok_or_else(|| format!("Invalid time '{}' in quiet hours '{}'", part, s))
+        };
+        let (start, end) = s
+            .split_once('-')
+            .ok_or_else(|| format!("Quiet hours '{}' should look like 22-7", s))?;
+        Ok(QuietHours {
+            start: time(start)?,
+            end: time(end)?,
+        })
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct ServerConfig {
     pub host: String,
     pub port: u16,
     pub cors_allowed_origin: Option<String>,
+    /// Where commands reach a running server (`TURFOPS_SERVER_URL`), by
+    /// default the loopback address on `port`.
+    pub url: String,
 }
 
 #[derive(Clone, Deserialize)]
@@ -137,6 +371,19 @@ pub struct DatabaseConfig {
     pub name: String,
     pub user: String,
     pub password: String,
+    /// Where the column encryption key comes from; `None` stores notes and
+    /// locations as plaintext.
+    pub encryption_key: Option<KeySource>,
+}
+
+/// Source of the 256-bit column encryption key (64 hex characters).
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub enum KeySource {
+    /// `DB_ENCRYPTION_KEY_FILE`: a file holding the key, e.g. a Docker secret.
+    File(String),
+    /// `DB_ENCRYPTION_KEY_COMMAND`: a shell command that prints the key, e.g.
+    /// an OS keyring lookup (`secret-tool lookup service turfops`).
+    Command(String),
 }
 
 impl std::fmt::Debug for DatabaseConfig {
@@ -147,6 +394,7 @@ impl std::fmt::Debug for DatabaseConfig {
             .field("name", &self.name)
             .field("user", &self.user)
             .field("password", &"[REDACTED]")
+            .field("encryption_key", &self.encryption_key)
             .finish()
     }
 }
@@ -162,14 +410,223 @@ impl DatabaseConfig {
     }
 }
 
+/// Every environment variable the backend reads, for `export-all`.
+pub const ENV_VARS: &[&str] = &[
+    "CORS_ALLOWED_ORIGIN",
+    "CUSTOM_RULES_PATH",
+    "DASHBOARD_PANELS",
+    "DATABASE_HOST",
+    "DATABASE_NAME",
+    "DATABASE_PASSWORD",
+    "DATABASE_PORT",
+    "DATABASE_USER",
+    "DATALAKE_LOCAL_SYNC",
+    "DATALAKE_ROOT",
+    "DB_ACQUIRE_TIMEOUT_SECS",
+    "DB_ENCRYPTION_KEY_COMMAND",
+    "DB_ENCRYPTION_KEY_FILE",
+    "DB_LOCK_TIMEOUT_MS",
+    "DB_MAX_CONNECTIONS",
+    "DB_STATEMENT_TIMEOUT_SECS",
+    "DIGEST_FREQUENCY",
+    "DIGEST_FROM",
+    "DIGEST_HOUR",
+    "DIGEST_SMTP_HOST",
+    "DIGEST_SMTP_PASSWORD",
+    "DIGEST_SMTP_PORT",
+    "DIGEST_SMTP_SECURITY",
+    "DIGEST_SMTP_USERNAME",
+    "DIGEST_TO",
+    "DIGEST_WEEKDAY",
+    "DROUGHT_MONITOR_ENABLED",
+    "DROUGHT_MONITOR_FIPS",
+    "ECOWITT_SOIL_MOISTURE_CHANNEL",
+    "ECOWITT_SOIL_TEMP_CHANNEL",
+    "ECOWITT_URL",
+    "EVENT_RETENTION_DAYS",
+    "HA_HUMIDITY_ENTITY",
+    "HA_TEMPERATURE_ENTITY",
+    "HA_TEMPERATURE_UNIT",
+    "HA_TOKEN",
+    "HA_URL",
+    "HISTORICAL_WEATHER_ENABLED",
+    "HTTP_TIMEOUT_SECS",
+    "LAWN_GRASS_TYPE",
+    "LAWN_IRRIGATION_TYPE",
+    "LAWN_NAME",
+    "LAWN_SIZE_SQFT",
+    "LAWN_SOIL_TYPE",
+    "LAWN_USDA_ZONE",
+    "LOCALE",
+    "LOCALE_DIR",
+    "LOG_DIR",
+    "LOG_FILE_LEVEL",
+    "LOG_MAX_FILES",
+    "LOG_ROTATION",
+    "MAINTENANCE_INTERVAL_HOURS",
+    "NOAA_STATION_WBANNO",
+    "NOTIFY_COOLDOWN_HOURS",
+    "NOTIFY_ESCALATION_ONLY",
+    "NOTIFY_QUIET_HOURS",
+    "OPENROUTER_API_KEY",
+    "OPENROUTER_BASE_URL",
+    "OPENROUTER_ENABLED",
+    "OPENROUTER_MODEL",
+    "OWM_AIR_QUALITY",
+    "OWM_API_KEY",
+    "OWM_DAILY_QUOTA",
+    "OWM_ENABLED",
+    "OWM_LATITUDE",
+    "OWM_LONGITUDE",
+    "OWM_ONE_CALL",
+    "RAIN_CHECK_RETENTION_DAYS",
+    "RULES_DIR",
+    "RULES_DISABLED",
+    "SERVER_HOST",
+    "SERVER_PORT",
+    "SOILDATA_TIMEOUT_SECS",
+    "SOIL_OBSERVATION_RETENTION_DAYS",
+    "SOURCE_PRIORITY",
+    "STALE_DATA_HOURS",
+    "STATIC_DIR",
+    "TEMPEST_STATION_ID",
+    "TEMPEST_TOKEN",
+    "TREND_WINDOW_HOURS",
+    "TURFOPS_SERVER_URL",
+    "WEATHER_GOLD_PATH",
+    "WEATHER_SILVER_PATH",
+];
+
+/// Credentials among `ENV_VARS`; `export-all` leaves their values out.
+pub const SECRET_ENV_VARS: &[&str] = &[
+    "DATABASE_PASSWORD",
+    "DIGEST_SMTP_PASSWORD",
+    "HA_TOKEN",
+    "OPENROUTER_API_KEY",
+    "OWM_API_KEY",
+    "TEMPEST_TOKEN",
+];
+
 fn env_or(key: &str, default: &str) -> String {
     std::env::var(key).unwrap_or_else(|_| default.to_string())
 }
 
+/// Parse a numeric env var, warning and falling back to `default` when it's invalid.
+fn env_number<T: std::str::FromStr + std::fmt::Display + Copy>(key: &str, default: T) -> T {
+    match std::env::var(key) {
+        Ok(raw) => raw.trim().parse().unwrap_or_else(|_| {
+            tracing::warn!(value = %raw, "Invalid {}, defaulting to {}", key, default);
+            default
+        }),
+        Err(_) => default,
+    }
+}
+
 fn env_required(key: &str) -> Result<String> {
     std::env::var(key).map_err(|_| TurfOpsError::Config(format!("Missing env var: {}", key)))
 }
 
+/// `TURFOPS_SERVER_URL`, else the server's own address, with loopback
+/// standing in for a listen-on-everything host.
+fn server_url(host: &str, port: u16) -> String {
+    if let Some(url) = std::env::var("TURFOPS_SERVER_URL")
+        .ok()
+        .filter(|u| !u.trim().is_empty())
+    {
+        return url.trim().trim_end_matches('/').to_string();
+    }
+    match host {
+        "" | "0.0.0.0" | "::" => format!("http://127.0.0.1:{}", port),
+        h if h.contains(':') => format!("http://[{}]:{}", h, port),
+        h => format!("http://{}:{}", h, port),
+    }
+}
+
+fn encryption_key_from_env() -> Result<Option<KeySource>> {
+    let var = |key: &str| std::env::var(key).ok().filter(|v| !v.trim().is_empty());
+    match (
+        var("DB_ENCRYPTION_KEY_FILE"),
+        var("DB_ENCRYPTION_KEY_COMMAND"),
+    ) {
+        (Some(_), Some(_)) => Err(TurfOpsError::Config(
+            "Set DB_ENCRYPTION_KEY_FILE or DB_ENCRYPTION_KEY_COMMAND, not both".into(),
+        )),
+        (Some(path), None) => Ok(Some(KeySource::File(path))),
+        (None, Some(command)) => Ok(Some(KeySource::Command(command))),
+        (None, None) => Ok(None),
+    }
+}
+
+fn digest_from_env() -> Option<DigestConfig> {
+    let smtp_host = std::env::var("DIGEST_SMTP_HOST")
+        .ok()
+        .map(|h| h.trim().to_string())
+        .filter(|h| !h.is_empty())?;
+    let to: Vec<String> = env_or("DIGEST_TO", "")
+        .split(',')
+        .map(str::trim)
+        .filter(|addr| !addr.is_empty())
+        .map(String::from)
+        .collect();
+    if to.is_empty() {
+        tracing::warn!("DIGEST_SMTP_HOST is set but DIGEST_TO is empty; digest email disabled");
+        return None;
+    }
+
+    let smtp_security = match env_or("DIGEST_SMTP_SECURITY", "starttls")
+        .to_lowercase()
+        .as_str()
+    {
+        "tls" => SmtpSecurity::Tls,
+        "none" => SmtpSecurity::None,
+        "starttls" => SmtpSecurity::StartTls,
+        other => {
+            tracing::warn!(value = %other, "Invalid DIGEST_SMTP_SECURITY, defaulting to starttls");
+            SmtpSecurity::StartTls
+        }
+    };
+    let default_port = match smtp_security {
+        SmtpSecurity::StartTls => 587,
+        SmtpSecurity::Tls => 465,
+        SmtpSecurity::None => 25,
+    };
+    let frequency = match env_or("DIGEST_FREQUENCY", "daily").to_lowercase().as_str() {
+        "weekly" => {
+            let raw = env_or("DIGEST_WEEKDAY", "mon");
+            DigestFrequency::Weekly(raw.trim().parse().unwrap_or_else(|_| {
+                tracing::warn!(value = %raw, "Invalid DIGEST_WEEKDAY, defaulting to Monday");
+                chrono::Weekday::Mon
+            }))
+        }
+        "daily" => DigestFrequency::Daily,
+        other => {
+            tracing::warn!(value = %other, "Invalid DIGEST_FREQUENCY, defaulting to daily");
+            DigestFrequency::Daily
+        }
+    };
+    let smtp_username = std::env::var("DIGEST_SMTP_USERNAME")
+        .ok()
+        .filter(|u| !u.is_empty());
+
+    Some(DigestConfig {
+        smtp_port: env_number("DIGEST_SMTP_PORT", default_port),
+        smtp_security,
+        smtp_password: std::env::var("DIGEST_SMTP_PASSWORD")
+            .ok()
+            .filter(|p| !p.is_empty()),
+        from: std::env::var("DIGEST_FROM")
+            .ok()
+            .filter(|f| !f.is_empty())
+            .or_else(|| smtp_username.clone())
+            .unwrap_or_else(|| format!("turfops@{}", smtp_host)),
+        smtp_username,
+        smtp_host,
+        to,
+        frequency,
+        hour: env_number("DIGEST_HOUR", 7u32).min(23),
+    })
+}
+
 impl Config {
     pub fn from_env() -> Result<Self> {
         Ok(Self {
@@ -184,12 +641,27 @@ impl Config {
                 irrigation_type: std::env::var("LAWN_IRRIGATION_TYPE").ok(),
             },
             noaa: NoaaConfig {
-                station_wbanno: {
+                station_wbannos: {
+                    // Comma-separated, highest priority first (e.g. "3761,64756")
                     let raw = env_or("NOAA_STATION_WBANNO", "3761");
-                    raw.parse().unwrap_or_else(|_| {
+                    let stations: Vec<i32> = raw
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|s| !s.is_empty())
+                        .filter_map(|s| {
+                            s.parse()
+                                .map_err(|_| {
+                                    tracing::warn!(value = %s, "Invalid NOAA_STATION_WBANNO entry, skipping")
+                                })
+                                .ok()
+                        })
+                        .collect();
+                    if stations.is_empty() {
                         tracing::warn!(value = %raw, "Invalid NOAA_STATION_WBANNO, defaulting to 3761");
-                        3761
-                    })
+                        vec![3761]
+                    } else {
+                        stations
+                    }
                 },
             },
             datalake: {
@@ -208,6 +680,16 @@ impl Config {
                 DataLakeConfig {
                     silver_weather_path,
                     gold_weather_path,
+                    local_sync: env_or("DATALAKE_LOCAL_SYNC", "false") == "true",
+                    trend_window_hours: env_or("TREND_WINDOW_HOURS", "24")
+                        .parse()
+                        .ok()
+                        .filter(|h| (1..=72).contains(h))
+                        .unwrap_or_else(|| {
+                            tracing::warn!("Invalid TREND_WINDOW_HOURS (1-72), defaulting to 24");
+                            24
+                        }),
+                    timeout_secs: env_number("SOILDATA_TIMEOUT_SECS", 30).max(1),
                 }
             },
             homeassistant: HomeAssistantConfig {
@@ -227,6 +709,30 @@ impl Config {
                     TemperatureUnit::Fahrenheit
                 },
             },
+            ecowitt: std::env::var("ECOWITT_URL")
+                .ok()
+                .filter(|url| !url.trim().is_empty())
+                .map(|url| EcowittConfig {
+                    url: url.trim_end_matches('/').to_string(),
+                    soil_moisture_channel: env_or("ECOWITT_SOIL_MOISTURE_CHANNEL", "1")
+                        .parse()
+                        .unwrap_or(1),
+                    soil_temp_channel: env_or("ECOWITT_SOIL_TEMP_CHANNEL", "1")
+                        .parse()
+                        .unwrap_or(1),
+                }),
+            tempest: std::env::var("TEMPEST_TOKEN")
+                .ok()
+                .filter(|token| !token.is_empty())
+                .map(|token| TempestConfig {
+                    station_id: env_or("TEMPEST_STATION_ID", ""),
+                    token,
+                }),
+            sources: SourcesConfig {
+                priority: env_or("SOURCE_PRIORITY", ""),
+                stale_after_hours: env_number("STALE_DATA_HOURS", 6),
+                http_timeout_secs: env_number("HTTP_TIMEOUT_SECS", 15).max(1),
+            },
             openweathermap: std::env::var("OWM_API_KEY")
                 .ok()
                 .map(|api_key| OpenWeatherMapConfig {
@@ -234,6 +740,9 @@ impl Config {
                     latitude: env_or("OWM_LATITUDE", "0").parse().unwrap_or(0.0),
                     longitude: env_or("OWM_LONGITUDE", "0").parse().unwrap_or(0.0),
                     enabled: env_or("OWM_ENABLED", "true") == "true",
+                    one_call: env_or("OWM_ONE_CALL", "false") == "true",
+                    air_quality: env_or("OWM_AIR_QUALITY", "true") == "true",
+                    daily_quota: env_number("OWM_DAILY_QUOTA", default_owm_daily_quota()),
                 }),
             openrouter: std::env::var("OPENROUTER_API_KEY")
                 .ok()
@@ -243,16 +752,31 @@ impl Config {
                     enabled: env_or("OPENROUTER_ENABLED", "true") == "true",
                     base_url: env_or("OPENROUTER_BASE_URL", "https://openrouter.ai/api/v1"),
                 }),
-            server: ServerConfig {
-                host: env_or("SERVER_HOST", "0.0.0.0"),
-                port: {
+            drought_monitor: DroughtMonitorConfig {
+                enabled: env_or("DROUGHT_MONITOR_ENABLED", "true") == "true",
+                county_fips: std::env::var("DROUGHT_MONITOR_FIPS")
+                    .ok()
+                    .map(|f| f.trim().to_string())
+                    .filter(|f| !f.is_empty()),
+            },
+            historical_weather: HistoricalWeatherConfig {
+                enabled: env_or("HISTORICAL_WEATHER_ENABLED", "true") == "true",
+            },
+            server: {
+                let host = env_or("SERVER_HOST", "0.0.0.0");
+                let port = {
                     let raw = env_or("SERVER_PORT", "3000");
                     raw.parse().unwrap_or_else(|_| {
                         tracing::warn!(value = %raw, "Invalid SERVER_PORT, defaulting to 3000");
                         3000
                     })
-                },
-                cors_allowed_origin: std::env::var("CORS_ALLOWED_ORIGIN").ok(),
+                };
+                ServerConfig {
+                    url: server_url(&host, port),
+                    host,
+                    port,
+                    cors_allowed_origin: std::env::var("CORS_ALLOWED_ORIGIN").ok(),
+                }
             },
             database: DatabaseConfig {
                 host: env_or("DATABASE_HOST", "localhost"),
@@ -266,6 +790,56 @@ impl Config {
                 name: env_or("DATABASE_NAME", "turfops"),
                 user: env_or("DATABASE_USER", "turfops"),
                 password: env_required("DATABASE_PASSWORD")?,
+                encryption_key: encryption_key_from_env()?,
+            },
+            rules: RulesConfig {
+                custom_rules_path: std::env::var("CUSTOM_RULES_PATH")
+                    .ok()
+                    .filter(|p| !p.is_empty()),
+                rules_dir: std::env::var("RULES_DIR").ok().filter(|p| !p.is_empty()),
+                disabled: env_or("RULES_DISABLED", "")
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|name| !name.is_empty())
+                    .map(String::from)
+                    .collect(),
+            },
+            locale: LocaleConfig {
+                locale: env_or("LOCALE", "en"),
+                dir: std::env::var("LOCALE_DIR").ok().filter(|p| !p.is_empty()),
+            },
+            retention: RetentionConfig {
+                soil_observation_days: env_number("SOIL_OBSERVATION_RETENTION_DAYS", 90),
+                event_days: env_number("EVENT_RETENTION_DAYS", 90),
+                rain_check_days: env_number("RAIN_CHECK_RETENTION_DAYS", 365),
+                interval_hours: env_number("MAINTENANCE_INTERVAL_HOURS", 24u64).max(1),
+            },
+            digest: digest_from_env(),
+            notifications: NotificationConfig {
+                quiet_hours: std::env::var("NOTIFY_QUIET_HOURS")
+                    .ok()
+                    .filter(|raw| !raw.trim().is_empty())
+                    .and_then(|raw| {
+                        raw.parse()
+                            .map_err(|e| {
+                                tracing::warn!("Ignoring NOTIFY_QUIET_HOURS: {}", e);
+                            })
+                            .ok()
+                    }),
+                cooldown_hours: env_number("NOTIFY_COOLDOWN_HOURS", 0),
+                escalation_only: env_or("NOTIFY_ESCALATION_ONLY", "false") == "true",
+            },
+            dashboard: DashboardConfig {
+                panels: std::env::var("DASHBOARD_PANELS")
+                    .ok()
+                    .filter(|raw| !raw.trim().is_empty())
+                    .and_then(|raw| {
+                        raw.parse()
+                            .map_err(|e| {
+                                tracing::warn!("Ignoring DASHBOARD_PANELS: {}", e);
+                            })
+                            .ok()
+                    }),
             },
         })
     }
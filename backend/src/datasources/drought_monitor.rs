@@ -0,0 +1,238 @@
+use super::request_error;
+use crate::config::DroughtMonitorConfig;
+use crate::error::{Result, TurfOpsError};
+use crate::models::{DroughtCategory, DroughtCoverage, DroughtStatus, Location};
+use chrono::{Duration, NaiveDate, Utc};
+use serde::Deserialize;
+
+/// U.S. Drought Monitor county statistics (percent area per category).
+const USDM_COUNTY_URL: &str = "https://usdmdataservices.unl.edu/api/CountyStatistics/GetDroughtSeverityStatisticsByAreaPercent";
+/// FCC Census Area API: county FIPS for a coordinate.
+const FCC_AREA_URL: &str = "https://geo.fcc.gov/api/census/area";
+/// Maps are weekly; look back far enough to always include the latest one.
+const LOOKBACK_DAYS: i64 = 14;
+/// Share of the county (percent) a category must cover to be the county's status.
+const MAJORITY_PERCENT: f64 = 50.0;
+const CONNECT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// Reads the weekly U.S. Drought Monitor map for the lawn's county. The county
+/// is `DROUGHT_MONITOR_FIPS` when set, else looked up from the coordinates.
+#[derive(Clone)]
+pub struct DroughtMonitorClient {
+    client: reqwest::Client,
+    county_fips: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FccAreaResponse {
+    results: Vec<FccArea>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FccArea {
+    county_fips: String,
+}
+
+/// One week of USDM county statistics. Percentages arrive as numbers or as
+/// numeric strings depending on the endpoint version.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct UsdmCountyRecord {
+    map_date: String,
+    #[serde(rename = "FIPS")]
+    fips: String,
+    county: String,
+    state: String,
+    #[serde(rename = "D0")]
+    d0: Percent,
+    #[serde(rename = "D1")]
+    d1: Percent,
+    #[serde(rename = "D2")]
+    d2: Percent,
+    #[serde(rename = "D3")]
+    d3: Percent,
+    #[serde(rename = "D4")]
+    d4: Percent,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Percent {
+    Number(f64),
+    Text(String),
+}
+
+impl Percent {
+    fn value(&self) -> f64 {
+        match self {
+            Percent::Number(n) => *n,
+            Percent::Text(s) => s.trim().parse().unwrap_or(0.0),
+        }
+    }
+}
+
+impl DroughtMonitorClient {
+    /// `timeout` bounds each request (`HTTP_TIMEOUT_SECS`).
+    pub fn new(config: &DroughtMonitorConfig, timeout: std::time::Duration) -> Self {
+        let client = reqwest::Client::builder()
+            .connect_timeout(CONNECT_TIMEOUT)
+            .timeout(timeout)
+            .build()
+            .expect("failed to build Drought Monitor HTTP client");
+        Self {
+            client,
+            county_fips: config.county_fips.clone(),
+        }
+    }
+
+    /// Latest county status. `None` without a configured county or a location
+    /// to look one up from, or when the location isn't in a US county.
+    pub async fn fetch_status(&self, location: Option<&Location>) -> Result<Option<DroughtStatus>> {
+        let fips = match (&self.county_fips, location) {
+            (Some(fips), _) => fips.clone(),
+            (None, Some(location)) => match self.county_for(location).await? {
+                Some(fips) => fips,
+                None => return Ok(None),
+            },
+            (None, None) => return Ok(None),
+        };
+
+        let today = Utc::now().date_naive();
+        let url = format!(
+            "{}?aoi={}&startdate={}&enddate={}&statisticsType=1",
+            USDM_COUNTY_URL,
+            fips,
+            (today - Duration::days(LOOKBACK_DAYS)).format("%-m/%-d/%Y"),
+            today.format("%-m/%-d/%Y"),
+        );
+        let body = self.get(&url, "Drought Monitor").await?;
+        parse_county_status(&body)
+    }
+
+    async fn county_for(&self, location: &Location) -> Result<Option<String>> {
+        let url = format!(
+            "{}?lat={}&lon={}&format=json",
+            FCC_AREA_URL, location.latitude, location.longitude
+        );
+        let body = self.get(&url, "County lookup").await?;
+        let response: FccAreaResponse = serde_json::from_str(&body).map_err(|e| {
+            TurfOpsError::DataSourceUnavailable(format!("Failed to parse county lookup: {}", e))
+        })?;
+        Ok(response.results.into_iter().next().map(|a| a.county_fips))
+    }
+
+    async fn get(&self, url: &str, what: &str) -> Result<String> {
+        let response = self
+            .client
+            .get(url)
+            .header(reqwest::header::ACCEPT, "application/json")
+            .send()
+            .await
+            .map_err(|e| request_error(what, e))?;
+        if !response.status().is_success() {
+            return Err(TurfOpsError::DataSourceUnavailable(format!(
+                "{} returned {}",
+                what,
+                response.status()
+            )));
+        }
+        response
+            .text()
+            .await
+            .map_err(|e| TurfOpsError::DataSourceUnavailable(format!("{}: {}", what, e)))
+    }
+}
+
+/// The newest week in a USDM county statistics response. `None` when empty.
+pub fn parse_county_status(body: &str) -> Result<Option<DroughtStatus>> {
+    let records: Vec<UsdmCountyRecord> = serde_json::from_str(body).map_err(|e| {
+        TurfOpsError::DataSourceUnavailable(format!("Failed to parse Drought Monitor data: {}", e))
+    })?;
+
+    Ok(records
+        .into_iter()
+        .filter_map(|r| Some((parse_map_date(&r.map_date)?, r)))
+        .max_by_key(|(date, _)| *date)
+        .map(|(map_date, r)| {
+            let coverage = DroughtCoverage {
+                d0: r.d0.value(),
+                d1: r.d1.value(),
+                d2: r.d2.value(),
+                d3: r.d3.value(),
+                d4: r.d4.value(),
+            };
+            DroughtStatus {
+                category: category_covering(&coverage, MAJORITY_PERCENT),
+                worst_category: category_covering(&coverage, f64::MIN_POSITIVE),
+                coverage,
+                map_date,
+                county_fips: r.fips,
+                county: format!("{}, {}", r.county, r.state),
+            }
+        }))
+}
+
+/// Map dates come as `2024-09-03`, `2024-09-03T00:00:00` or `20240903`.
+fn parse_map_date(raw: &str) -> Option<NaiveDate> {
+    let dashed = raw.get(..10).unwrap_or(raw);
+    let compact = raw.get(..8).unwrap_or(raw);
+    NaiveDate::parse_from_str(dashed, "%Y-%m-%d")
+        .or_else(|_| NaiveDate::parse_from_str(compact, "%Y%m%d"))
+        .ok()
+}
+
+/// Worst category whose cumulative coverage reaches `min_percent`.
+fn category_covering(coverage: &DroughtCoverage, min_percent: f64) -> DroughtCategory {
+    [
+        (DroughtCategory::D4, coverage.d4),
+        (DroughtCategory::D3, coverage.d3),
+        (DroughtCategory::D2, coverage.d2),
+        (DroughtCategory::D1, coverage.d1),
+        (DroughtCategory::D0, coverage.d0),
+    ]
+    .into_iter()
+    .find(|(_, percent)| *percent >= min_percent)
+    .map_or(DroughtCategory::None, |(category, _)| category)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn newest_week_and_majority_category() {
+        let body = r#"[
+            {"MapDate":"20250826","FIPS":"42029","County":"Chester County","State":"PA",
+             "None":"0.00","D0":"100.00","D1":"40.00","D2":"0.00","D3":"0.00","D4":"0.00",
+             "ValidStart":"2025-08-26","ValidEnd":"2025-09-01","StatisticFormatID":1},
+            {"MapDate":"2025-09-02T00:00:00","FIPS":"42029","County":"Chester County","State":"PA",
+             "None":0.0,"D0":100.0,"D1":88.5,"D2":61.2,"D3":7.4,"D4":0.0,
+             "ValidStart":"2025-09-02","ValidEnd":"2025-09-08","StatisticFormatID":1}
+        ]"#;
+
+        let status = parse_county_status(body).unwrap().unwrap();
+        assert_eq!(
+            status.map_date,
+            NaiveDate::from_ymd_opt(2025, 9, 2).unwrap()
+        );
+        assert_eq!(status.category, DroughtCategory::D2);
+        assert_eq!(status.worst_category, DroughtCategory::D3);
+        assert_eq!(status.coverage.d1, 88.5);
+        assert_eq!(status.county, "Chester County, PA");
+
+        assert!(parse_county_status("[]").unwrap().is_none());
+        assert!(parse_county_status("<html>").is_err());
+    }
+
+    #[test]
+    fn wet_county_has_no_category() {
+        assert_eq!(
+            category_covering(&DroughtCoverage::default(), MAJORITY_PERCENT),
+            DroughtCategory::None
+        );
+        assert_eq!(
+            category_covering(&DroughtCoverage::default(), f64::MIN_POSITIVE),
+            DroughtCategory::None
+        );
+    }
+}
@@ -0,0 +1,274 @@
+//! Ecowitt gateway local API (`GET /get_livedata_info`).
+//!
+//! Values come back as display strings with the gateway's configured units
+//! ("76.1" + "F", "55%", "0.12 in"), so each one is parsed and converted here.
+
+use super::request_error;
+use super::source::{Capability, EnvironmentalSource, SourceFuture};
+use crate::config::EcowittConfig;
+use crate::error::{Result, TurfOpsError};
+use crate::models::{DataSource, DegreesC, EnvironmentalReading, Inches};
+use chrono::Utc;
+use serde::Deserialize;
+use std::time::Duration;
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+// `common_list` / `rain` item ids
+const OUTDOOR_TEMP: &str = "0x02";
+const OUTDOOR_HUMIDITY: &str = "0x07";
+const RAIN_RATE: &str = "0x0E";
+const RAIN_HOUR: &str = "0x0F";
+
+pub struct EcowittClient {
+    client: reqwest::Client,
+    config: EcowittConfig,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct LiveData {
+    #[serde(default)]
+    common_list: Vec<LiveItem>,
+    #[serde(default)]
+    rain: Vec<LiveItem>,
+    /// WS90 haptic rain gauge, reported instead of (or alongside) `rain`.
+    #[serde(default, rename = "piezoRain")]
+    piezo_rain: Vec<LiveItem>,
+    /// WH51 soil moisture sensors.
+    #[serde(default)]
+    ch_soil: Vec<SoilChannel>,
+    /// WN34 temperature probes.
+    #[serde(default)]
+    ch_temp: Vec<TempChannel>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LiveItem {
+    id: String,
+    val: String,
+    #[serde(default)]
+    unit: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SoilChannel {
+    channel: String,
+    humidity: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct TempChannel {
+    channel: String,
+    temp: String,
+    #[serde(default)]
+    unit: Option<String>,
+}
+
+impl EcowittClient {
+    /// A backyard station: air, rain and (with probes attached) the soil.
+    pub const CAPABILITIES: &'static [Capability] = &[
+        Capability::AmbientTemperature,
+        Capability::Humidity,
+        Capability::Precipitation,
+        Capability::SoilMoisture,
+        Capability::SoilTemperature,
+    ];
+
+    /// `timeout` bounds each request (`HTTP_TIMEOUT_SECS`).
+    pub fn new(config: EcowittConfig, timeout: Duration) -> Self {
+        let client = reqwest::Client::builder()
+            .connect_timeout(CONNECT_TIMEOUT)
+            .timeout(timeout)
+            .build()
+            .expect("failed to build Ecowitt HTTP client");
+        Self { client, config }
+    }
+
+    pub async fn fetch_current(&self) -> Result<EnvironmentalReading> {
+        let data = self.get_live_data().await?;
+        let mut reading = parse_live_data(&data, &self.config);
+        reading.timestamp = Utc::now();
+        Ok(reading)
+    }
+
+    async fn get_live_data(&self) -> Result<LiveData> {
+        let url = format!("{}/get_livedata_info", self.config.url);
+
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| request_error(&format!("Ecowitt request to {}", url), e))?;
+
+        if !response.status().is_success() {
+            return Err(TurfOpsError::DataSourceUnavailable(format!(
+                "Ecowitt GET {} returned {}",
+                url,
+                response.status()
+            )));
+        }
+
+        response.json().await.map_err(|e| {
+            TurfOpsError::DataSourceUnavailable(format!("Failed to parse Ecowitt response: {}", e))
+        })
+    }
+
+    pub async fn test_connection(&self) -> Result<bool> {
+        Ok(self.get_live_data().await.is_ok())
+    }
+}
+
+/// Map a live data payload into a reading. Soil probes on the configured
+/// channels are reported at 10cm; missing sensors leave their fields empty.
+fn parse_live_data(data: &LiveData, config: &EcowittConfig) -> EnvironmentalReading {
+    let mut reading = EnvironmentalReading::new(DataSource::Ecowitt);
+
+    let item = |items: &[LiveItem], id: &str| -> Option<(f64, Option<String>)> {
+        let item = items.iter().find(|i| i.id.eq_ignore_ascii_case(id))?;
+        let (value, unit) = parse_value(&item.val)?;
+        Some((value, item.unit.clone().or(unit)))
+    };
+
+    reading.ambient_temp_f =
+        item(&data.common_list, OUTDOOR_TEMP).map(|(v, unit)| to_fahrenheit(v, unit.as_deref()));
+    reading.humidity_percent = item(&data.common_list, OUTDOOR_HUMIDITY).map(|(v, _)| v);
+
+    // Last hour's rain, or the current rate on firmware without an hourly total
+    let rain = [&data.rain, &data.piezo_rain]
+        .into_iter()
+        .find_map(|items| item(items, RAIN_HOUR).or_else(|| item(items, RAIN_RATE)));
+    reading.precipitation_mm = rain.map(|(v, unit)| to_mm(v, unit.as_deref()));
+
+    let moisture_channel = config.soil_moisture_channel.to_string();
+    reading.soil_moisture_10 = data
+        .ch_soil
+        .iter()
+        .find(|c| c.channel == moisture_channel)
+        .and_then(|c| parse_value(&c.humidity))
+        .map(|(percent, _)| percent / 100.0);
+
+    let temp_channel = config.soil_temp_channel.to_string();
+    reading.soil_temp_10_f = data
+        .ch_temp
+        .iter()
+        .find(|c| c.channel == temp_channel)
+        .and_then(|c| {
+            let (value, unit) = parse_value(&c.temp)?;
+            Some(to_fahrenheit(value, c.unit.as_deref().or(unit.as_deref())))
+        });
+
+    reading
+}
+
+/// Split "0.12 in" / "55%" / "76.1" into the number and any trailing unit.
+fn parse_value(raw: &str) -> Option<(f64, Option<String>)> {
+    let raw = raw.trim();
+    let end = raw
+        .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-'))
+        .unwrap_or(raw.len());
+    let value = raw[..end].parse().ok()?;
+    let unit = raw[end..].trim();
+    Some((value, (!unit.is_empty()).then(|| unit.to_string())))
+}
+
+fn to_fahrenheit(value: f64, unit: Option<&str>) -> f64 {
+    match unit {
+        Some(u) if u.contains('C') || u.contains('℃') => DegreesC(value).to_fahrenheit().0,
+        _ => value,
+    }
+}
+
+fn to_mm(value: f64, unit: Option<&str>) -> f64 {
+    match unit {
+        Some(u) if u.starts_with("in") => Inches(value).to_millimeters().0,
+        _ => value,
+    }
+}
+
+impl EnvironmentalSource for EcowittClient {
+    fn key(&self) -> &'static str {
+        "ecowitt"
+    }
+
+    fn source(&self) -> DataSource {
+        DataSource::Ecowitt
+    }
+
+    fn capabilities(&self) -> &'static [Capability] {
+        Self::CAPABILITIES
+    }
+
+    fn fetch_current(&self) -> SourceFuture<'_, EnvironmentalReading> {
+        Box::pin(EcowittClient::fetch_current(self))
+    }
+
+    fn test_connection(&self) -> SourceFuture<'_, bool> {
+        Box::pin(EcowittClient::test_connection(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> EcowittConfig {
+        EcowittConfig {
+            url: "http://gateway".to_string(),
+            soil_moisture_channel: 2,
+            soil_temp_channel: 1,
+        }
+    }
+
+    #[test]
+    fn parses_imperial_payload() {
+        let data: LiveData = serde_json::from_str(
+            r#"{
+                "common_list": [
+                    {"id": "0x02", "val": "76.1", "unit": "F"},
+                    {"id": "0x07", "val": "55%"},
+                    {"id": "3", "val": "75.2", "unit": "F"}
+                ],
+                "rain": [
+                    {"id": "0x0E", "val": "0.20 in/Hr"},
+                    {"id": "0x0F", "val": "0.10 in"},
+                    {"id": "0x10", "val": "0.40 in"}
+                ],
+                "ch_soil": [
+                    {"channel": "1", "name": "", "battery": "5", "humidity": "48%"},
+                    {"channel": "2", "name": "", "battery": "5", "humidity": "22%"}
+                ],
+                "ch_temp": [
+                    {"channel": "1", "name": "", "temp": "64.4", "unit": "F", "battery": "1.50V"}
+                ]
+            }"#,
+        )
+        .unwrap();
+        let r = parse_live_data(&data, &config());
+
+        assert_eq!(r.source, DataSource::Ecowitt);
+        assert_eq!(r.ambient_temp_f, Some(76.1));
+        assert_eq!(r.humidity_percent, Some(55.0));
+        assert!((r.precipitation_mm.unwrap() - 2.54).abs() < 1e-9);
+        assert_eq!(r.soil_moisture_10, Some(0.22));
+        assert_eq!(r.soil_temp_10_f, Some(64.4));
+    }
+
+    #[test]
+    fn converts_metric_and_tolerates_missing_sensors() {
+        let data: LiveData = serde_json::from_str(
+            r#"{
+                "common_list": [{"id": "0x02", "val": "20.0", "unit": "C"}],
+                "piezoRain": [{"id": "0x0E", "val": "1.5 mm/Hr"}]
+            }"#,
+        )
+        .unwrap();
+        let r = parse_live_data(&data, &config());
+
+        assert!((r.ambient_temp_f.unwrap() - 68.0).abs() < 1e-9);
+        assert_eq!(r.humidity_percent, None);
+        assert_eq!(r.precipitation_mm, Some(1.5));
+        assert_eq!(r.soil_moisture_10, None);
+        assert_eq!(r.soil_temp_10_f, None);
+    }
+}
@@ -0,0 +1,63 @@
+use crate::error::{Result, TurfOpsError};
+use crate::models::normalize_usda_zone;
+use serde::Deserialize;
+use std::time::Duration;
+
+/// Static JSON mirror of the USDA Plant Hardiness Zone Map, one file per US ZIP.
+const API_BASE_URL: &str = "https://phzmapi.org";
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Looks up the USDA hardiness zone for a US ZIP code.
+pub struct HardinessZoneClient {
+    client: reqwest::Client,
+}
+
+#[derive(Debug, Deserialize)]
+struct PhzmResponse {
+    zone: String,
+}
+
+impl HardinessZoneClient {
+    pub fn new() -> Self {
+        let client = reqwest::Client::builder()
+            .connect_timeout(CONNECT_TIMEOUT)
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .expect("failed to build hardiness zone HTTP client");
+        Self { client }
+    }
+
+    /// Zone for a 5-digit ZIP, normalized to e.g. `7a`. `None` for unknown ZIPs.
+    pub async fn fetch_zone(&self, zip: &str) -> Result<Option<String>> {
+        let url = format!("{}/{}.json", API_BASE_URL, zip);
+        let response = self.client.get(&url).send().await.map_err(|e| {
+            TurfOpsError::DataSourceUnavailable(format!("Hardiness zone lookup: {}", e))
+        })?;
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !response.status().is_success() {
+            return Err(TurfOpsError::DataSourceUnavailable(format!(
+                "Hardiness zone lookup returned {}",
+                response.status()
+            )));
+        }
+
+        let body: PhzmResponse = response.json().await.map_err(|e| {
+            TurfOpsError::DataSourceUnavailable(format!(
+                "Failed to parse hardiness zone response: {}",
+                e
+            ))
+        })?;
+
+        Ok(normalize_usda_zone(&body.zone).ok())
+    }
+}
+
+impl Default for HardinessZoneClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
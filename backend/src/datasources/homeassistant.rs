@@ -1,12 +1,13 @@
+use super::request_error;
+use super::source::{Capability, EnvironmentalSource, SourceFuture};
 use crate::config::{HomeAssistantConfig, TemperatureUnit};
 use crate::error::{Result, TurfOpsError};
-use crate::models::{celsius_to_fahrenheit, DataSource, EnvironmentalReading};
+use crate::models::{DataSource, DegreesC, EnvironmentalReading};
 use chrono::Utc;
 use serde::Deserialize;
 use std::time::Duration;
 
 const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
-const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
 
 pub struct HomeAssistantClient {
     client: reqwest::Client,
@@ -21,10 +22,15 @@ struct EntityState {
 }
 
 impl HomeAssistantClient {
-    pub fn new(config: HomeAssistantConfig) -> Self {
+    /// A patio sensor: air temperature and humidity only.
+    pub const CAPABILITIES: &'static [Capability] =
+        &[Capability::AmbientTemperature, Capability::Humidity];
+
+    /// `timeout` bounds each request (`HTTP_TIMEOUT_SECS`).
+    pub fn new(config: HomeAssistantConfig, timeout: Duration) -> Self {
         let client = reqwest::Client::builder()
             .connect_timeout(CONNECT_TIMEOUT)
-            .timeout(REQUEST_TIMEOUT)
+            .timeout(timeout)
             .build()
             .expect("failed to build Home Assistant HTTP client");
         Self { client, config }
@@ -37,7 +43,7 @@ impl HomeAssistantClient {
         match self.get_entity_state(&self.config.temperature_entity).await {
             Ok(Some(temp)) => {
                 let temp_f = match self.config.temperature_unit {
-                    TemperatureUnit::Celsius => celsius_to_fahrenheit(temp),
+                    TemperatureUnit::Celsius => DegreesC(temp).to_fahrenheit().0,
                     TemperatureUnit::Fahrenheit => temp,
                 };
                 reading.ambient_temp_f = Some(temp_f);
@@ -91,12 +97,7 @@ impl HomeAssistantClient {
             .header("Content-Type", "application/json")
             .send()
             .await
-            .map_err(|e| {
-                TurfOpsError::DataSourceUnavailable(format!(
-                    "Home Assistant request to {} failed: {}",
-                    url, e
-                ))
-            })?;
+            .map_err(|e| request_error(&format!("Home Assistant request to {}", url), e))?;
 
         if !response.status().is_success() {
             return Err(TurfOpsError::DataSourceUnavailable(format!(
@@ -125,8 +126,30 @@ impl HomeAssistantClient {
             .header("Authorization", format!("Bearer {}", self.config.token))
             .send()
             .await
-            .map_err(|e| TurfOpsError::DataSourceUnavailable(format!("Home Assistant: {}", e)))?;
+            .map_err(|e| request_error("Home Assistant", e))?;
 
         Ok(response.status().is_success())
     }
 }
+
+impl EnvironmentalSource for HomeAssistantClient {
+    fn key(&self) -> &'static str {
+        "homeassistant"
+    }
+
+    fn source(&self) -> DataSource {
+        DataSource::HomeAssistant
+    }
+
+    fn capabilities(&self) -> &'static [Capability] {
+        Self::CAPABILITIES
+    }
+
+    fn fetch_current(&self) -> SourceFuture<'_, EnvironmentalReading> {
+        Box::pin(HomeAssistantClient::fetch_current(self))
+    }
+
+    fn test_connection(&self) -> SourceFuture<'_, bool> {
+        Box::pin(HomeAssistantClient::test_connection(self))
+    }
+}
@@ -1,9 +1,34 @@
+pub mod drought_monitor;
+pub mod ecowitt;
+pub mod hardiness;
 pub mod homeassistant;
+pub mod open_meteo;
 pub mod openrouter;
 pub mod openweathermap;
+pub mod quality;
+pub mod source;
+pub mod tempest;
 pub mod weather;
 
+pub use drought_monitor::DroughtMonitorClient;
+pub use ecowitt::EcowittClient;
+pub use hardiness::HardinessZoneClient;
 pub use homeassistant::HomeAssistantClient;
+pub use open_meteo::OpenMeteoClient;
 pub use openrouter::OpenRouterClient;
 pub use openweathermap::OpenWeatherMapClient;
+pub use source::{EnvironmentalSource, SourceReading, SourceRegistry};
+pub use tempest::TempestClient;
 pub use weather::WeatherLakeClient;
+
+use crate::error::TurfOpsError;
+
+/// Error for a request to `source` that failed before a response arrived,
+/// keeping a timeout distinct from the source being unreachable.
+pub(crate) fn request_error(source: &str, e: reqwest::Error) -> TurfOpsError {
+    if e.is_timeout() {
+        TurfOpsError::Timeout(format!("{} didn't answer in time: {}", source, e))
+    } else {
+        TurfOpsError::DataSourceUnavailable(format!("{}: {}", source, e))
+    }
+}
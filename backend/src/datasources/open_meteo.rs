@@ -0,0 +1,169 @@
+//! Open-Meteo historical weather archive: daily values for any date back to
+//! 1940 at the lawn's coordinates, with no API key. Fills in for the station
+//! lake on dates before its history starts.
+
+use super::request_error;
+use crate::error::{Result, TurfOpsError};
+use crate::models::{HistoricalDay, Location};
+use chrono::NaiveDate;
+use serde::Deserialize;
+
+const ARCHIVE_URL: &str = "https://archive-api.open-meteo.com/v1/archive";
+const CONNECT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+const DAILY_VARIABLES: &str = "temperature_2m_max,temperature_2m_min,temperature_2m_mean,\
+    relative_humidity_2m_mean,precipitation_sum,\
+    soil_temperature_7_to_28cm_mean,soil_moisture_7_to_28cm_mean";
+
+/// Reads the archive at one location; see [`OpenMeteoClient::at`].
+#[derive(Clone)]
+pub struct OpenMeteoClient {
+    client: reqwest::Client,
+    location: Option<Location>,
+}
+
+/// Daily values arrive as parallel arrays indexed like `time`; any may be null.
+#[derive(Debug, Deserialize)]
+struct ArchiveResponse {
+    daily: ArchiveDaily,
+}
+
+#[derive(Debug, Deserialize)]
+struct ArchiveDaily {
+    time: Vec<NaiveDate>,
+    #[serde(default)]
+    temperature_2m_max: Vec<Option<f64>>,
+    #[serde(default)]
+    temperature_2m_min: Vec<Option<f64>>,
+    #[serde(default)]
+    temperature_2m_mean: Vec<Option<f64>>,
+    #[serde(default)]
+    relative_humidity_2m_mean: Vec<Option<f64>>,
+    #[serde(default)]
+    precipitation_sum: Vec<Option<f64>>,
+    #[serde(default)]
+    soil_temperature_7_to_28cm_mean: Vec<Option<f64>>,
+    #[serde(default)]
+    soil_moisture_7_to_28cm_mean: Vec<Option<f64>>,
+}
+
+impl OpenMeteoClient {
+    /// `timeout` bounds each request (`HTTP_TIMEOUT_SECS`).
+    pub fn new(timeout: std::time::Duration) -> Self {
+        let client = reqwest::Client::builder()
+            .connect_timeout(CONNECT_TIMEOUT)
+            .timeout(timeout)
+            .build()
+            .expect("failed to build Open-Meteo HTTP client");
+        Self {
+            client,
+            location: None,
+        }
+    }
+
+    /// The same client reading history at `location`.
+    pub fn at(&self, location: &Location) -> Self {
+        Self {
+            client: self.client.clone(),
+            location: Some(location.clone()),
+        }
+    }
+
+    /// One entry per day in [start, end]. Recent days (the archive lags about
+    /// five days) come back with every value empty.
+    pub async fn fetch_daily(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<HistoricalDay>> {
+        let location = self.location.as_ref().ok_or_else(|| {
+            TurfOpsError::DataSourceUnavailable(
+                "Historical weather needs a location (set one under Settings → Location)".into(),
+            )
+        })?;
+        let url = format!(
+            "{}?latitude={}&longitude={}&start_date={}&end_date={}&daily={}\
+             &temperature_unit=fahrenheit&timezone=auto",
+            ARCHIVE_URL, location.latitude, location.longitude, start, end, DAILY_VARIABLES
+        );
+
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| request_error("Open-Meteo archive", e))?;
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(TurfOpsError::DataSourceUnavailable(format!(
+                "Open-Meteo archive returned {}: {}",
+                status, body
+            )));
+        }
+        let body = response
+            .text()
+            .await
+            .map_err(|e| request_error("Open-Meteo archive", e))?;
+        parse_archive(&body)
+    }
+}
+
+fn parse_archive(body: &str) -> Result<Vec<HistoricalDay>> {
+    let response: ArchiveResponse = serde_json::from_str(body).map_err(|e| {
+        TurfOpsError::DataSourceUnavailable(format!("Failed to parse Open-Meteo archive: {}", e))
+    })?;
+    let daily = response.daily;
+    let at = |values: &[Option<f64>], i: usize| values.get(i).copied().flatten();
+
+    Ok(daily
+        .time
+        .iter()
+        .enumerate()
+        .map(|(i, &date)| HistoricalDay {
+            date,
+            high_temp_f: at(&daily.temperature_2m_max, i),
+            low_temp_f: at(&daily.temperature_2m_min, i),
+            mean_temp_f: at(&daily.temperature_2m_mean, i),
+            humidity_percent: at(&daily.relative_humidity_2m_mean, i),
+            precipitation_mm: at(&daily.precipitation_sum, i),
+            // Requested in °F along with the air temperatures
+            soil_temp_f: at(&daily.soil_temperature_7_to_28cm_mean, i),
+            soil_moisture: at(&daily.soil_moisture_7_to_28cm_mean, i),
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::date;
+
+    #[test]
+    fn parses_parallel_daily_arrays_with_gaps() {
+        let days = parse_archive(
+            r#"{
+                "latitude": 39.86, "longitude": -75.79,
+                "daily": {
+                    "time": ["2019-05-01", "2019-05-02"],
+                    "temperature_2m_max": [72.4, null],
+                    "temperature_2m_min": [51.6, null],
+                    "temperature_2m_mean": [61.0, null],
+                    "relative_humidity_2m_mean": [68],
+                    "precipitation_sum": [2.3, 0.0],
+                    "soil_temperature_7_to_28cm_mean": [57.2, 58.0],
+                    "soil_moisture_7_to_28cm_mean": [0.31, 0.30]
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(days.len(), 2);
+        assert_eq!(days[0].date, date(2019, 5, 1));
+        assert_eq!(days[0].humidity_percent, Some(68.0));
+        assert_eq!(days[0].gdd50(), Some(12.0));
+        // Short and null arrays leave the value empty rather than misaligning days
+        assert_eq!(days[1].humidity_percent, None);
+        assert_eq!(days[1].gdd50(), None);
+        assert_eq!(days[1].soil_temp_f, Some(58.0));
+    }
+}
@@ -1,20 +1,37 @@
+use super::request_error;
 use crate::config::OpenWeatherMapConfig;
 use crate::error::{Result, TurfOpsError};
+use crate::logic::api_quota::ApiQuota;
 use crate::models::forecast::{
-    DailyForecast, ForecastLocation, ForecastPoint, WeatherCondition, WeatherForecast,
+    DailyForecast, ForecastLocation, ForecastPoint, MinutelyPrecipitation, WeatherCondition,
+    WeatherForecast,
 };
+use crate::models::{is_postal_code, AirQuality, AirQualityLevel, Location};
 use chrono::{DateTime, NaiveDate, Utc};
 use serde::Deserialize;
 use std::collections::HashMap;
+use std::sync::Arc;
 use std::time::Duration;
 
-const API_BASE_URL: &str = "https://api.openweathermap.org/data/2.5";
+const API_HOST: &str = "https://api.openweathermap.org";
+const API_PATH: &str = "/data/2.5";
+const ONE_CALL_PATH: &str = "/data/3.0/onecall";
+const GEO_PATH: &str = "/geo/1.0";
+/// Candidates returned for a place-name search.
+const GEOCODE_LIMIT: &str = "5";
 const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
-const REQUEST_TIMEOUT: Duration = Duration::from_secs(15);
+/// A connection check within this long of another request reuses its
+/// outcome rather than spending a call.
+const CONNECTION_REUSE: Duration = Duration::from_secs(15 * 60);
 
+#[derive(Clone)]
 pub struct OpenWeatherMapClient {
     client: reqwest::Client,
     config: OpenWeatherMapConfig,
+    /// Counts every request against `OWM_DAILY_QUOTA`.
+    quota: Option<Arc<ApiQuota>>,
+    /// Scheme and host every path is appended to.
+    host: String,
 }
 
 // OpenWeatherMap API response structures
@@ -86,27 +103,184 @@ struct OwmCoord {
     lon: f64,
 }
 
+// Air Pollution API response structures
+#[derive(Debug, Deserialize)]
+struct AirPollutionResponse {
+    list: Vec<AirPollutionEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AirPollutionEntry {
+    dt: i64,
+    main: AirPollutionIndex,
+    #[serde(default)]
+    components: HashMap<String, f64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AirPollutionIndex {
+    aqi: u8,
+}
+
+// One Call 3.0 response structures
+#[derive(Debug, Deserialize)]
+struct OneCallResponse {
+    lat: f64,
+    lon: f64,
+    /// Seconds east of UTC; daily `dt` is local noon.
+    #[serde(default)]
+    timezone_offset: i64,
+    #[serde(default)]
+    minutely: Vec<OneCallMinute>,
+    #[serde(default)]
+    hourly: Vec<OneCallHour>,
+    #[serde(default)]
+    daily: Vec<OneCallDay>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OneCallMinute {
+    dt: i64,
+    #[serde(default)]
+    precipitation: f64,
+}
+
+#[derive(Debug, Deserialize)]
+struct OneCallHour {
+    dt: i64,
+    temp: f64,
+    feels_like: f64,
+    humidity: f64,
+    clouds: f64,
+    wind_speed: f64,
+    #[serde(default)]
+    wind_gust: Option<f64>,
+    #[serde(default)]
+    pop: f64,
+    #[serde(default)]
+    rain: Option<OneCallHourPrecipitation>,
+    #[serde(default)]
+    snow: Option<OneCallHourPrecipitation>,
+    weather: Vec<OwmWeather>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OneCallHourPrecipitation {
+    #[serde(rename = "1h", default)]
+    one_hour: f64,
+}
+
+#[derive(Debug, Deserialize)]
+struct OneCallDay {
+    dt: i64,
+    temp: OneCallDayTemp,
+    humidity: f64,
+    wind_speed: f64,
+    #[serde(default)]
+    wind_gust: Option<f64>,
+    #[serde(default)]
+    pop: f64,
+    /// Daily totals in mm, absent on dry days.
+    #[serde(default)]
+    rain: Option<f64>,
+    #[serde(default)]
+    snow: Option<f64>,
+    weather: Vec<OwmWeather>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OneCallDayTemp {
+    min: f64,
+    max: f64,
+}
+
+// Geocoding API response structures
+#[derive(Debug, Deserialize)]
+struct OwmGeoPlace {
+    name: String,
+    lat: f64,
+    lon: f64,
+    #[serde(default)]
+    state: Option<String>,
+    #[serde(default)]
+    country: Option<String>,
+}
+
+impl From<OwmGeoPlace> for Location {
+    fn from(place: OwmGeoPlace) -> Self {
+        let name = [Some(place.name), place.state, place.country]
+            .into_iter()
+            .flatten()
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join(", ");
+        Location {
+            name,
+            latitude: place.lat,
+            longitude: place.lon,
+            usda_zone: None,
+        }
+    }
+}
+
 impl OpenWeatherMapClient {
-    pub fn new(config: OpenWeatherMapConfig) -> Self {
+    /// `timeout` bounds each request (`HTTP_TIMEOUT_SECS`).
+    pub fn new(config: OpenWeatherMapConfig, timeout: Duration) -> Self {
         let client = reqwest::Client::builder()
             .connect_timeout(CONNECT_TIMEOUT)
-            .timeout(REQUEST_TIMEOUT)
+            .timeout(timeout)
             .build()
             .expect("failed to build OpenWeatherMap HTTP client");
-        Self { client, config }
+        Self {
+            client,
+            config,
+            quota: None,
+            host: API_HOST.to_string(),
+        }
+    }
+
+    /// Send requests to `host` instead of api.openweathermap.org (a mock server in tests).
+    #[cfg(test)]
+    pub fn with_host(mut self, host: &str) -> Self {
+        self.host = host.trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn with_quota(mut self, quota: Arc<ApiQuota>) -> Self {
+        self.quota = Some(quota);
+        self
     }
 
-    /// Fetch 5-day/3-hour forecast from OpenWeatherMap
+    /// Requests a scheduled refresh makes: the forecast, plus air quality when on.
+    pub fn calls_per_refresh(&self) -> u32 {
+        1 + u32::from(self.config.air_quality)
+    }
+
+    /// GET counted against the quota; refused once it's used up.
+    async fn get(&self, url: impl reqwest::IntoUrl, api: &str) -> Result<reqwest::Response> {
+        if let Some(ref quota) = self.quota {
+            quota.record_call().await?;
+        }
+        let result = self.client.get(url).send().await;
+        if let Some(ref quota) = self.quota {
+            quota.record_response(result.as_ref().is_ok_and(|r| {
+                r.status().is_success() || r.status() == reqwest::StatusCode::NOT_FOUND
+            }));
+        }
+        result.map_err(|e| request_error(api, e))
+    }
+
+    /// Fetch the forecast: One Call 3.0 when enabled, else the 5-day/3-hour API.
     pub async fn fetch_forecast(&self) -> Result<WeatherForecast> {
+        if self.config.one_call {
+            return self.fetch_one_call().await;
+        }
         let url = format!(
-            "{}/forecast?lat={}&lon={}&appid={}&units=imperial",
-            API_BASE_URL, self.config.latitude, self.config.longitude, self.config.api_key
+            "{}{}/forecast?lat={}&lon={}&appid={}&units=imperial",
+            self.host, API_PATH, self.config.latitude, self.config.longitude, self.config.api_key
         );
 
-        let response =
-            self.client.get(&url).send().await.map_err(|e| {
-                TurfOpsError::DataSourceUnavailable(format!("OpenWeatherMap: {}", e))
-            })?;
+        let response = self.get(&url, "OpenWeatherMap").await?;
 
         if !response.status().is_success() {
             let status = response.status();
@@ -127,17 +301,152 @@ impl OpenWeatherMapClient {
         Ok(self.convert_response(owm_response))
     }
 
-    /// Test connection to OpenWeatherMap API
+    /// Current air quality, or `None` when turned off (`OWM_AIR_QUALITY=false`).
+    pub async fn fetch_air_quality(&self) -> Result<Option<AirQuality>> {
+        if !self.config.air_quality {
+            return Ok(None);
+        }
+        let url = format!(
+            "{}{}/air_pollution?lat={}&lon={}&appid={}",
+            self.host, API_PATH, self.config.latitude, self.config.longitude, self.config.api_key
+        );
+
+        let response = self.get(&url, "OpenWeatherMap air pollution").await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(TurfOpsError::DataSourceUnavailable(format!(
+                "OpenWeatherMap air pollution returned {}: {}",
+                status, body
+            )));
+        }
+
+        let pollution: AirPollutionResponse = response.json().await.map_err(|e| {
+            TurfOpsError::DataSourceUnavailable(format!(
+                "Failed to parse OpenWeatherMap air pollution response: {}",
+                e
+            ))
+        })?;
+        Ok(convert_air_pollution(pollution))
+    }
+
+    async fn fetch_one_call(&self) -> Result<WeatherForecast> {
+        let url = format!(
+            "{}{}?lat={}&lon={}&appid={}&units=imperial&exclude=current,alerts",
+            self.host,
+            ONE_CALL_PATH,
+            self.config.latitude,
+            self.config.longitude,
+            self.config.api_key
+        );
+
+        let response = self.get(&url, "OpenWeatherMap One Call").await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(TurfOpsError::DataSourceUnavailable(format!(
+                "OpenWeatherMap One Call returned {}: {}",
+                status, body
+            )));
+        }
+
+        let one_call: OneCallResponse = response.json().await.map_err(|e| {
+            TurfOpsError::DataSourceUnavailable(format!(
+                "Failed to parse OpenWeatherMap One Call response: {}",
+                e
+            ))
+        })?;
+
+        Ok(convert_one_call(one_call, Utc::now()))
+    }
+
+    /// Point forecasts at a new location (e.g. one picked on the Settings page).
+    pub fn set_location(&mut self, location: &Location) {
+        self.config.latitude = location.latitude;
+        self.config.longitude = location.longitude;
+    }
+
+    pub fn location(&self) -> Location {
+        Location {
+            name: String::new(),
+            latitude: self.config.latitude,
+            longitude: self.config.longitude,
+            usda_zone: None,
+        }
+    }
+
+    /// Resolve a city ("West Chester, PA, US") or postal code ("19382" or
+    /// "19382,US") to candidate coordinates via the OWM geocoding API.
+    /// Postal codes without a country default to the US.
+    pub async fn geocode(&self, query: &str) -> Result<Vec<Location>> {
+        let query = query.trim();
+        let key = self.config.api_key.as_str();
+        let url = if is_postal_code(query) {
+            let zip = if query.contains(',') {
+                query.replace(' ', "")
+            } else {
+                format!("{},US", query)
+            };
+            reqwest::Url::parse_with_params(
+                &format!("{}{}/zip", self.host, GEO_PATH),
+                &[("zip", zip.as_str()), ("appid", key)],
+            )
+        } else {
+            reqwest::Url::parse_with_params(
+                &format!("{}{}/direct", self.host, GEO_PATH),
+                &[("q", query), ("limit", GEOCODE_LIMIT), ("appid", key)],
+            )
+        }
+        .map_err(|e| TurfOpsError::InvalidData(format!("Invalid geocoding query: {}", e)))?;
+
+        let response = self.get(url, "OpenWeatherMap").await?;
+
+        // The zip endpoint answers 404 for unknown codes: that's "no results", not an outage
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(Vec::new());
+        }
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(TurfOpsError::DataSourceUnavailable(format!(
+                "OpenWeatherMap geocoding returned {}: {}",
+                status, body
+            )));
+        }
+
+        let parse_err = |e: reqwest::Error| {
+            TurfOpsError::DataSourceUnavailable(format!(
+                "Failed to parse OpenWeatherMap geocoding response: {}",
+                e
+            ))
+        };
+        let places: Vec<OwmGeoPlace> = if is_postal_code(query) {
+            vec![response.json().await.map_err(parse_err)?]
+        } else {
+            response.json().await.map_err(parse_err)?
+        };
+
+        Ok(places.into_iter().map(Location::from).collect())
+    }
+
+    /// Test connection to OpenWeatherMap API. A recent forecast or other
+    /// request answers for it, so the minutely check doesn't eat the quota.
     pub async fn test_connection(&self) -> Result<bool> {
+        if let Some(ok) = self
+            .quota
+            .as_ref()
+            .and_then(|q| q.recent_response(CONNECTION_REUSE))
+        {
+            return Ok(ok);
+        }
         let url = format!(
-            "{}/weather?lat={}&lon={}&appid={}&units=imperial",
-            API_BASE_URL, self.config.latitude, self.config.longitude, self.config.api_key
+            "{}{}/weather?lat={}&lon={}&appid={}&units=imperial",
+            self.host, API_PATH, self.config.latitude, self.config.longitude, self.config.api_key
         );
 
-        let response =
-            self.client.get(&url).send().await.map_err(|e| {
-                TurfOpsError::DataSourceUnavailable(format!("OpenWeatherMap: {}", e))
-            })?;
+        let response = self.get(&url, "OpenWeatherMap").await?;
 
         Ok(response.status().is_success())
     }
@@ -153,7 +462,7 @@ impl OpenWeatherMapClient {
         let hourly: Vec<ForecastPoint> = response
             .list
             .iter()
-            .map(|item| self.convert_forecast_item(item))
+            .filter_map(|item| self.convert_forecast_item(item))
             .collect();
 
         let daily_summary = self.aggregate_daily(&hourly);
@@ -163,11 +472,20 @@ impl OpenWeatherMapClient {
             location,
             hourly,
             daily_summary,
+            minutely: Vec::new(),
         }
     }
 
-    fn convert_forecast_item(&self, item: &OwmForecastItem) -> ForecastPoint {
-        let timestamp = DateTime::from_timestamp(item.dt, 0).unwrap_or_else(Utc::now);
+    /// `None` for an item whose timestamp is out of range; stamping it "now"
+    /// would put a later hour's weather in the current slot.
+    fn convert_forecast_item(&self, item: &OwmForecastItem) -> Option<ForecastPoint> {
+        let Some(timestamp) = DateTime::from_timestamp(item.dt, 0) else {
+            tracing::warn!(
+                dt = item.dt,
+                "Skipping forecast item with invalid timestamp"
+            );
+            return None;
+        };
 
         let weather_condition = item
             .weather
@@ -180,18 +498,19 @@ impl OpenWeatherMapClient {
         let snow_mm = item.snow.as_ref().map(|s| s.three_hour).unwrap_or(0.0);
         let precipitation_mm = rain_mm + snow_mm;
 
-        ForecastPoint {
+        Some(ForecastPoint {
             timestamp,
             temp_f: item.main.temp,
             feels_like_f: item.main.feels_like,
             humidity_percent: item.main.humidity,
             precipitation_mm,
+            snow_mm,
             precipitation_prob: item.pop,
             wind_speed_mph: item.wind.speed,
             wind_gust_mph: item.wind.gust,
             cloud_cover_percent: item.clouds.all,
             weather_condition,
-        }
+        })
     }
 
     fn aggregate_daily(&self, hourly: &[ForecastPoint]) -> Vec<DailyForecast> {
@@ -229,6 +548,7 @@ impl OpenWeatherMapClient {
             points.iter().map(|p| p.humidity_percent).sum::<f64>() / points.len().max(1) as f64;
 
         let total_precipitation_mm: f64 = points.iter().map(|p| p.precipitation_mm).sum();
+        let total_snow_mm: f64 = points.iter().map(|p| p.snow_mm).sum();
 
         let max_precipitation_prob = points
             .iter()
@@ -261,6 +581,7 @@ impl OpenWeatherMapClient {
             low_temp_f,
             avg_humidity,
             total_precipitation_mm,
+            total_snow_mm,
             max_precipitation_prob,
             dominant_condition,
             avg_wind_speed_mph,
@@ -269,22 +590,193 @@ impl OpenWeatherMapClient {
     }
 }
 
+fn convert_air_pollution(response: AirPollutionResponse) -> Option<AirQuality> {
+    let entry = response.list.into_iter().next()?;
+    Some(AirQuality {
+        level: AirQualityLevel::from_index(entry.main.aqi)?,
+        pm2_5: entry.components.get("pm2_5").copied(),
+        pm10: entry.components.get("pm10").copied(),
+        o3: entry.components.get("o3").copied(),
+        observed_at: DateTime::from_timestamp(entry.dt, 0)?,
+    })
+}
+
+/// One Call carries real daily highs, lows and totals, so days aren't
+/// aggregated from the hourly points (which only cover 48 hours).
+fn convert_one_call(response: OneCallResponse, fetched_at: DateTime<Utc>) -> WeatherForecast {
+    let condition = |weather: &[OwmWeather]| {
+        weather
+            .first()
+            .map(|w| WeatherCondition::from_owm_id(w.id))
+            .unwrap_or_default()
+    };
+
+    let hourly = response
+        .hourly
+        .iter()
+        .filter_map(|h| {
+            let timestamp = DateTime::from_timestamp(h.dt, 0)?;
+            let rain_mm = h.rain.as_ref().map_or(0.0, |r| r.one_hour);
+            let snow_mm = h.snow.as_ref().map_or(0.0, |s| s.one_hour);
+            Some(ForecastPoint {
+                timestamp,
+                temp_f: h.temp,
+                feels_like_f: h.feels_like,
+                humidity_percent: h.humidity,
+                precipitation_mm: rain_mm + snow_mm,
+                snow_mm,
+                precipitation_prob: h.pop,
+                wind_speed_mph: h.wind_speed,
+                wind_gust_mph: h.wind_gust,
+                cloud_cover_percent: h.clouds,
+                weather_condition: condition(&h.weather),
+            })
+        })
+        .collect();
+
+    let daily_summary = response
+        .daily
+        .iter()
+        .filter_map(|d| {
+            let date = DateTime::from_timestamp(d.dt + response.timezone_offset, 0)?.date_naive();
+            let snow_mm = d.snow.unwrap_or(0.0);
+            Some(DailyForecast {
+                date,
+                high_temp_f: d.temp.max,
+                low_temp_f: d.temp.min,
+                avg_humidity: d.humidity,
+                total_precipitation_mm: d.rain.unwrap_or(0.0) + snow_mm,
+                total_snow_mm: snow_mm,
+                max_precipitation_prob: d.pop,
+                dominant_condition: condition(&d.weather),
+                avg_wind_speed_mph: d.wind_speed,
+                max_wind_gust_mph: d.wind_gust,
+            })
+        })
+        .collect();
+
+    let minutely = response
+        .minutely
+        .iter()
+        .filter_map(|m| {
+            Some(MinutelyPrecipitation {
+                timestamp: DateTime::from_timestamp(m.dt, 0)?,
+                precipitation_mm: m.precipitation,
+            })
+        })
+        .collect();
+
+    WeatherForecast {
+        fetched_at,
+        // One Call doesn't name the place
+        location: ForecastLocation {
+            city: String::new(),
+            country: String::new(),
+            latitude: response.lat,
+            longitude: response.lon,
+        },
+        hourly,
+        daily_summary,
+        minutely,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn geocode_results_build_display_names() {
+        let direct: Vec<OwmGeoPlace> = serde_json::from_str(
+            r#"[{"name":"West Chester","lat":39.96,"lon":-75.6,"country":"US","state":"Pennsylvania"}]"#,
+        )
+        .unwrap();
+        let loc = Location::from(direct.into_iter().next().unwrap());
+        assert_eq!(loc.name, "West Chester, Pennsylvania, US");
+        assert_eq!(loc.latitude, 39.96);
+
+        // The zip endpoint has no state
+        let zip: OwmGeoPlace = serde_json::from_str(
+            r#"{"zip":"19382","name":"West Chester","lat":39.93,"lon":-75.6,"country":"US"}"#,
+        )
+        .unwrap();
+        assert_eq!(Location::from(zip).name, "West Chester, US");
+    }
+
     fn sample_config() -> OpenWeatherMapConfig {
         OpenWeatherMapConfig {
             api_key: "test_key".to_string(),
             latitude: 39.8561,
             longitude: -75.7872,
             enabled: true,
+            one_call: false,
+            air_quality: true,
+            daily_quota: 1000,
         }
     }
 
     #[test]
     fn client_creation() {
-        let client = OpenWeatherMapClient::new(sample_config());
+        let client = OpenWeatherMapClient::new(sample_config(), Duration::from_secs(15));
         assert!(client.config.enabled);
     }
+
+    #[test]
+    fn one_call_keeps_real_daily_values() {
+        let response: OneCallResponse = serde_json::from_str(
+            r#"{
+                "lat": 39.86, "lon": -75.79, "timezone_offset": -14400,
+                "minutely": [{"dt": 1784116800, "precipitation": 0.4}],
+                "hourly": [{
+                    "dt": 1784116800, "temp": 84.2, "feels_like": 88.0, "humidity": 62,
+                    "clouds": 40, "wind_speed": 6.1, "pop": 0.3,
+                    "rain": {"1h": 0.5}, "weather": [{"id": 500, "main": "Rain", "description": "light rain"}]
+                }],
+                "daily": [{
+                    "dt": 1784134800, "temp": {"min": 66.1, "max": 91.3, "day": 88.0},
+                    "humidity": 58, "wind_speed": 7.4, "wind_gust": 15.2, "pop": 0.6,
+                    "rain": 3.2, "weather": [{"id": 501, "main": "Rain", "description": "moderate rain"}]
+                }]
+            }"#,
+        )
+        .unwrap();
+        let forecast = convert_one_call(response, Utc::now());
+
+        let day = &forecast.daily_summary[0];
+        assert_eq!(day.date, NaiveDate::from_ymd_opt(2026, 7, 15).unwrap());
+        assert_eq!(day.high_temp_f, 91.3);
+        assert_eq!(day.low_temp_f, 66.1);
+        assert_eq!(day.total_precipitation_mm, 3.2);
+        assert_eq!(forecast.hourly[0].precipitation_mm, 0.5);
+        assert_eq!(forecast.hourly[0].weather_condition, WeatherCondition::Rain);
+        assert_eq!(forecast.minutely[0].precipitation_mm, 0.4);
+    }
+
+    #[test]
+    fn air_pollution_index_maps_to_level() {
+        let response: AirPollutionResponse = serde_json::from_str(
+            r#"{
+                "coord": {"lon": -75.79, "lat": 39.86},
+                "list": [{
+                    "main": {"aqi": 4},
+                    "components": {"co": 330.5, "o3": 151.2, "pm2_5": 58.1, "pm10": 74.0},
+                    "dt": 1784116800
+                }]
+            }"#,
+        )
+        .unwrap();
+        let air = convert_air_pollution(response).unwrap();
+        assert_eq!(air.level, AirQualityLevel::Poor);
+        assert_eq!(air.level.index(), 4);
+        assert_eq!(air.pm2_5, Some(58.1));
+
+        let unknown = AirPollutionResponse {
+            list: vec![AirPollutionEntry {
+                dt: 1784116800,
+                main: AirPollutionIndex { aqi: 9 },
+                components: HashMap::new(),
+            }],
+        };
+        assert!(convert_air_pollution(unknown).is_none());
+    }
 }
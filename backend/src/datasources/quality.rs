@@ -0,0 +1,217 @@
+//! Data quality pass for sensor readings, run before anything is averaged.
+//!
+//! - Physically impossible values (USCRN sentinels like -9999, humidity over
+//!   100%) are rejected outright.
+//! - One-hour spikes that jump away from both neighbors by more than a sensor
+//!   could plausibly move are flagged suspect and left out.
+//! - Short gaps (a missing value between two good ones, at most
+//!   [`MAX_INTERPOLATION_GAP_HOURS`] apart) are filled by linear interpolation.
+
+use crate::models::{DataQuality, EnvironmentalReading};
+use chrono::Duration;
+
+/// Longest gap between two good values that gets interpolated.
+pub const MAX_INTERPOLATION_GAP_HOURS: i64 = 3;
+
+struct FieldSpec {
+    field: fn(&mut EnvironmentalReading) -> &mut Option<f64>,
+    min: f64,
+    max: f64,
+    /// Largest believable hourly change; `None` skips spike detection.
+    max_step: Option<f64>,
+    /// Fill short gaps. Off for accumulations like precipitation.
+    interpolate: bool,
+}
+
+const SOIL_TEMP: (f64, f64, Option<f64>) = (-40.0, 140.0, Some(10.0));
+const MOISTURE: (f64, f64, Option<f64>) = (0.0, 1.0, Some(0.15));
+
+fn field(
+    field: fn(&mut EnvironmentalReading) -> &mut Option<f64>,
+    (min, max, max_step): (f64, f64, Option<f64>),
+    interpolate: bool,
+) -> FieldSpec {
+    FieldSpec {
+        field,
+        min,
+        max,
+        max_step,
+        interpolate,
+    }
+}
+
+fn specs() -> [FieldSpec; 13] {
+    [
+        field(|r| &mut r.soil_temp_5_f, SOIL_TEMP, true),
+        field(|r| &mut r.soil_temp_10_f, SOIL_TEMP, true),
+        field(|r| &mut r.soil_temp_20_f, SOIL_TEMP, true),
+        field(|r| &mut r.soil_temp_50_f, SOIL_TEMP, true),
+        field(|r| &mut r.soil_temp_100_f, SOIL_TEMP, true),
+        field(|r| &mut r.soil_moisture_5, MOISTURE, true),
+        field(|r| &mut r.soil_moisture_10, MOISTURE, true),
+        field(|r| &mut r.soil_moisture_20, MOISTURE, true),
+        field(|r| &mut r.soil_moisture_50, MOISTURE, true),
+        field(|r| &mut r.soil_moisture_100, MOISTURE, true),
+        field(|r| &mut r.ambient_temp_f, (-60.0, 135.0, Some(25.0)), true),
+        field(|r| &mut r.humidity_percent, (0.0, 100.0, Some(50.0)), true),
+        field(|r| &mut r.precipitation_mm, (0.0, 200.0, None), false),
+    ]
+}
+
+/// Drop values outside physical bounds. Returns how many were rejected.
+pub fn reject_impossible(reading: &mut EnvironmentalReading) -> usize {
+    let mut rejected = 0;
+    for spec in specs() {
+        let value = (spec.field)(reading);
+        if value.is_some_and(|v| !v.is_finite() || v < spec.min || v > spec.max) {
+            *value = None;
+            rejected += 1;
+        }
+    }
+    rejected
+}
+
+/// Clean a window of readings (any order) for averaging: reject impossible
+/// values, drop suspect spikes (marking the reading), interpolate short gaps.
+/// Returns the cleaned readings in the input order.
+pub fn clean_readings(
+    readings: &[EnvironmentalReading],
+) -> (Vec<EnvironmentalReading>, DataQuality) {
+    let mut quality = DataQuality::default();
+    let mut cleaned = readings.to_vec();
+    for r in &mut cleaned {
+        quality.rejected += reject_impossible(r);
+    }
+
+    // Walk each series oldest first; `cleaned` keeps the caller's order.
+    let mut order: Vec<usize> = (0..cleaned.len()).collect();
+    order.sort_by_key(|&i| cleaned[i].timestamp);
+
+    for spec in specs() {
+        let series: Vec<Option<f64>> = order
+            .iter()
+            .map(|&i| *(spec.field)(&mut cleaned[i]))
+            .collect();
+
+        if let Some(step) = spec.max_step {
+            for k in 1..series.len().saturating_sub(1) {
+                let (Some(prev), Some(v), Some(next)) = (series[k - 1], series[k], series[k + 1])
+                else {
+                    continue;
+                };
+                let spike = (v - prev).abs() > step
+                    && (v - next).abs() > step
+                    && (v - prev).signum() == (v - next).signum();
+                if spike {
+                    let r = &mut cleaned[order[k]];
+                    *(spec.field)(r) = None;
+                    r.suspect = true;
+                    quality.suspect += 1;
+                }
+            }
+        }
+
+        if spec.interpolate {
+            quality.interpolated += interpolate_gaps(&mut cleaned, &order, &spec);
+        }
+    }
+
+    (cleaned, quality)
+}
+
+/// Fill missing values between two good ones at most
+/// `MAX_INTERPOLATION_GAP_HOURS` apart. Suspect readings stay empty.
+fn interpolate_gaps(
+    cleaned: &mut [EnvironmentalReading],
+    order: &[usize],
+    spec: &FieldSpec,
+) -> usize {
+    let max_gap = Duration::hours(MAX_INTERPOLATION_GAP_HOURS);
+    let good: Vec<(usize, f64)> = order
+        .iter()
+        .enumerate()
+        .filter_map(|(k, &i)| Some((k, (spec.field)(&mut cleaned[i]).as_ref().copied()?)))
+        .collect();
+
+    let mut filled = 0;
+    for pair in good.windows(2) {
+        let ((k0, v0), (k1, v1)) = (pair[0], pair[1]);
+        let (t0, t1) = (cleaned[order[k0]].timestamp, cleaned[order[k1]].timestamp);
+        if k1 == k0 + 1 || t1 - t0 > max_gap || t1 <= t0 {
+            continue;
+        }
+        let span = (t1 - t0).num_seconds() as f64;
+        for &i in &order[k0 + 1..k1] {
+            let r = &mut cleaned[i];
+            if r.suspect {
+                continue;
+            }
+            let frac = (r.timestamp - t0).num_seconds() as f64 / span;
+            *(spec.field)(r) = Some(v0 + (v1 - v0) * frac);
+            filled += 1;
+        }
+    }
+    filled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::DataSource;
+    use chrono::{DateTime, Utc};
+
+    fn hourly(start: DateTime<Utc>, soil: &[Option<f64>]) -> Vec<EnvironmentalReading> {
+        soil.iter()
+            .enumerate()
+            .map(|(h, v)| {
+                let mut r = EnvironmentalReading::new(DataSource::SoilData);
+                r.timestamp = start + Duration::hours(h as i64);
+                r.soil_temp_10_f = *v;
+                r.humidity_percent = Some(60.0);
+                r
+            })
+            .collect()
+    }
+
+    #[test]
+    fn rejects_sentinels_flags_spikes_and_fills_short_gaps() {
+        let start = crate::testing::at(2025, 6, 1);
+        let mut readings = hourly(
+            start,
+            &[
+                Some(60.0),
+                Some(-9999.0),
+                Some(62.0),
+                Some(90.0),
+                Some(62.0),
+                None,
+                None,
+                None,
+                None,
+                Some(64.0),
+            ],
+        );
+        readings[1].humidity_percent = Some(104.0);
+        // Newest first, like the lake and local queries return them.
+        readings.reverse();
+
+        let (cleaned, quality) = clean_readings(&readings);
+        let by_hour = |h: i64| {
+            cleaned
+                .iter()
+                .find(|r| r.timestamp == start + Duration::hours(h))
+                .unwrap()
+        };
+        assert_eq!(quality.rejected, 2);
+        assert_eq!(quality.suspect, 1);
+        // The sentinel hour sits between 60 and 62
+        assert_eq!(by_hour(1).soil_temp_10_f, Some(61.0));
+        assert_eq!(by_hour(1).humidity_percent, Some(60.0));
+        assert!(by_hour(3).suspect);
+        assert_eq!(by_hour(3).soil_temp_10_f, None);
+        // Five hours from 62 to 64 is too long to bridge
+        assert_eq!(by_hour(6).soil_temp_10_f, None);
+        assert_eq!(quality.interpolated, 2);
+        assert_eq!(cleaned[0].timestamp, readings[0].timestamp);
+    }
+}
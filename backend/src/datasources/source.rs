@@ -0,0 +1,347 @@
+//! Pluggable environmental sensor sources.
+//!
+//! Each station or sensor hub implements [`EnvironmentalSource`] and declares
+//! which [`Capability`]s it supplies. The sync service asks every registered
+//! source for a current reading and [`SourceRegistry::merge`] combines them one
+//! capability at a time, so a new source only needs a trait impl and a
+//! `register` call. The forecast (OpenWeatherMap) is not a sensor source and
+//! stays separate.
+
+use crate::error::{Result, TurfOpsError};
+use crate::models::{DataSource, EnvironmentalReading};
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Boxed future returned by source methods, so sources can live behind `dyn`.
+pub type SourceFuture<'a, T> = Pin<Box<dyn Future<Output = Result<T>> + Send + 'a>>;
+
+/// A group of reading fields a source can supply.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Capability {
+    SoilTemperature,
+    SoilMoisture,
+    AmbientTemperature,
+    Humidity,
+    Precipitation,
+}
+
+impl Capability {
+    pub const ALL: [Capability; 5] = [
+        Capability::SoilTemperature,
+        Capability::SoilMoisture,
+        Capability::AmbientTemperature,
+        Capability::Humidity,
+        Capability::Precipitation,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Capability::SoilTemperature => "soil_temp",
+            Capability::SoilMoisture => "soil_moisture",
+            Capability::AmbientTemperature => "ambient_temp",
+            Capability::Humidity => "humidity",
+            Capability::Precipitation => "precipitation",
+        }
+    }
+
+    /// Whether the reading has any of this capability's fields.
+    fn present_in(&self, r: &EnvironmentalReading) -> bool {
+        match self {
+            Capability::SoilTemperature => [
+                r.soil_temp_5_f,
+                r.soil_temp_10_f,
+                r.soil_temp_20_f,
+                r.soil_temp_50_f,
+                r.soil_temp_100_f,
+            ]
+            .iter()
+            .any(Option::is_some),
+            Capability::SoilMoisture => [
+                r.soil_moisture_5,
+                r.soil_moisture_10,
+                r.soil_moisture_20,
+                r.soil_moisture_50,
+                r.soil_moisture_100,
+            ]
+            .iter()
+            .any(Option::is_some),
+            Capability::AmbientTemperature => r.ambient_temp_f.is_some(),
+            Capability::Humidity => r.humidity_percent.is_some(),
+            Capability::Precipitation => r.precipitation_mm.is_some(),
+        }
+    }
+
+    /// Copy this capability's fields from `from` into `to`.
+    fn copy(&self, from: &EnvironmentalReading, to: &mut EnvironmentalReading) {
+        match self {
+            Capability::SoilTemperature => {
+                to.soil_temp_5_f = from.soil_temp_5_f;
+                to.soil_temp_10_f = from.soil_temp_10_f;
+                to.soil_temp_20_f = from.soil_temp_20_f;
+                to.soil_temp_50_f = from.soil_temp_50_f;
+                to.soil_temp_100_f = from.soil_temp_100_f;
+            }
+            Capability::SoilMoisture => {
+                to.soil_moisture_5 = from.soil_moisture_5;
+                to.soil_moisture_10 = from.soil_moisture_10;
+                to.soil_moisture_20 = from.soil_moisture_20;
+                to.soil_moisture_50 = from.soil_moisture_50;
+                to.soil_moisture_100 = from.soil_moisture_100;
+            }
+            Capability::AmbientTemperature => to.ambient_temp_f = from.ambient_temp_f,
+            Capability::Humidity => to.humidity_percent = from.humidity_percent,
+            Capability::Precipitation => to.precipitation_mm = from.precipitation_mm,
+        }
+    }
+}
+
+impl FromStr for Capability {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Capability::ALL
+            .into_iter()
+            .find(|c| c.as_str() == s.trim().to_lowercase())
+            .ok_or_else(|| format!("Unknown capability: {}", s))
+    }
+}
+
+/// A station or sensor hub that supplies environmental readings.
+pub trait EnvironmentalSource: Send + Sync {
+    /// Short name used in `SOURCE_PRIORITY` and connection status (e.g. "homeassistant").
+    fn key(&self) -> &'static str;
+
+    /// Attribution for readings and error events.
+    fn source(&self) -> DataSource;
+
+    /// Fields this source supplies. Anything else in its readings is ignored.
+    fn capabilities(&self) -> &'static [Capability];
+
+    /// Latest reading.
+    fn fetch_current(&self) -> SourceFuture<'_, EnvironmentalReading>;
+
+    /// Readings in `[start, end]`, newest first. Sources without a history API
+    /// keep the default, which reports it as unavailable.
+    fn fetch_history(
+        &self,
+        _start: DateTime<Utc>,
+        _end: DateTime<Utc>,
+    ) -> SourceFuture<'_, Vec<EnvironmentalReading>> {
+        let key = self.key();
+        Box::pin(async move {
+            Err(TurfOpsError::DataSourceUnavailable(format!(
+                "{} does not provide history",
+                key
+            )))
+        })
+    }
+
+    fn test_connection(&self) -> SourceFuture<'_, bool>;
+}
+
+/// One source's current reading, tagged for merging.
+pub struct SourceReading {
+    pub key: &'static str,
+    pub source: DataSource,
+    pub capabilities: &'static [Capability],
+    pub reading: EnvironmentalReading,
+}
+
+impl SourceReading {
+    pub fn from_source(source: &dyn EnvironmentalSource, reading: EnvironmentalReading) -> Self {
+        Self {
+            key: source.key(),
+            source: source.source(),
+            capabilities: source.capabilities(),
+            reading,
+        }
+    }
+}
+
+/// Registered sensor sources plus the per-capability merge priority.
+#[derive(Default)]
+pub struct SourceRegistry {
+    sources: Vec<Arc<dyn EnvironmentalSource>>,
+    /// Source keys, highest priority first. Sources not listed for a capability
+    /// follow the listed ones in the order their readings were collected.
+    priority: HashMap<Capability, Vec<String>>,
+}
+
+impl SourceRegistry {
+    pub fn new(priority: HashMap<Capability, Vec<String>>) -> Self {
+        Self {
+            sources: Vec::new(),
+            priority,
+        }
+    }
+
+    pub fn register(&mut self, source: Arc<dyn EnvironmentalSource>) {
+        tracing::info!(source = source.key(), "Environmental source registered");
+        self.sources.push(source);
+    }
+
+    pub fn sources(&self) -> &[Arc<dyn EnvironmentalSource>] {
+        &self.sources
+    }
+
+    /// Combine readings into one cached reading. For each capability the
+    /// highest-priority source that declares it and has a value wins; a source
+    /// never contributes fields it doesn't declare.
+    pub fn merge(&self, readings: &[SourceReading]) -> EnvironmentalReading {
+        let mut combined = EnvironmentalReading::new(DataSource::Cached);
+
+        for capability in Capability::ALL {
+            if let Some(r) = self.winner(capability, readings) {
+                capability.copy(&r.reading, &mut combined);
+            }
+        }
+
+        combined
+    }
+
+    /// Observation time of the reading [`merge`](Self::merge) takes `capability` from.
+    pub fn observed_at(
+        &self,
+        capability: Capability,
+        readings: &[SourceReading],
+    ) -> Option<DateTime<Utc>> {
+        self.winner(capability, readings)
+            .map(|r| r.reading.timestamp)
+    }
+
+    fn winner<'a>(
+        &self,
+        capability: Capability,
+        readings: &'a [SourceReading],
+    ) -> Option<&'a SourceReading> {
+        let rank = |key: &str| {
+            self.priority
+                .get(&capability)
+                .and_then(|keys| keys.iter().position(|k| k == key))
+                .unwrap_or(usize::MAX)
+        };
+        readings
+            .iter()
+            .enumerate()
+            .filter(|(_, r)| {
+                r.capabilities.contains(&capability) && capability.present_in(&r.reading)
+            })
+            .min_by_key(|(i, r)| (rank(r.key), *i))
+            .map(|(_, r)| r)
+    }
+}
+
+/// Parse `SOURCE_PRIORITY`: `capability=key,key;capability=key`, e.g.
+/// `ambient_temp=ecowitt,homeassistant;humidity=homeassistant`. Bad entries are
+/// skipped with a warning.
+pub fn parse_priority(raw: &str) -> HashMap<Capability, Vec<String>> {
+    raw.split(';')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .filter_map(|entry| {
+            let (capability, keys) = entry.split_once('=')?;
+            match capability.parse::<Capability>() {
+                Ok(c) => Some((
+                    c,
+                    keys.split(',')
+                        .map(|k| k.trim().to_lowercase())
+                        .filter(|k| !k.is_empty())
+                        .collect(),
+                )),
+                Err(e) => {
+                    tracing::warn!(entry, "Invalid SOURCE_PRIORITY entry: {}", e);
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reading(source: DataSource, ambient: f64, soil: f64) -> EnvironmentalReading {
+        let mut r = EnvironmentalReading::new(source);
+        r.ambient_temp_f = Some(ambient);
+        r.soil_temp_10_f = Some(soil);
+        r
+    }
+
+    const SOIL_AND_AIR: &[Capability] =
+        &[Capability::SoilTemperature, Capability::AmbientTemperature];
+    const AIR: &[Capability] = &[Capability::AmbientTemperature];
+
+    #[test]
+    fn sources_only_contribute_declared_capabilities() {
+        let registry = SourceRegistry::default();
+        let merged = registry.merge(&[
+            SourceReading {
+                key: "uscrn",
+                source: DataSource::Manual,
+                capabilities: &[Capability::SoilTemperature],
+                reading: reading(DataSource::SoilData, 70.0, 58.0),
+            },
+            SourceReading {
+                key: "hub",
+                source: DataSource::Manual,
+                capabilities: AIR,
+                reading: reading(DataSource::HomeAssistant, 74.0, 99.0),
+            },
+        ]);
+        assert_eq!(merged.soil_temp_10_f, Some(58.0));
+        assert_eq!(merged.ambient_temp_f, Some(74.0));
+    }
+
+    #[test]
+    fn configured_priority_beats_collection_order() {
+        let readings = [
+            SourceReading {
+                key: "a",
+                source: DataSource::Manual,
+                capabilities: SOIL_AND_AIR,
+                reading: reading(DataSource::SoilData, 70.0, 58.0),
+            },
+            SourceReading {
+                key: "b",
+                source: DataSource::Manual,
+                capabilities: SOIL_AND_AIR,
+                reading: reading(DataSource::HomeAssistant, 74.0, 60.0),
+            },
+        ];
+        let merged = SourceRegistry::default().merge(&readings);
+        assert_eq!(merged.ambient_temp_f, Some(70.0));
+
+        let registry = SourceRegistry::new(parse_priority("ambient_temp=b, a ; bogus=a"));
+        let merged = registry.merge(&readings);
+        assert_eq!(merged.ambient_temp_f, Some(74.0));
+        // Soil temp has no configured order, so the first reading still wins
+        assert_eq!(merged.soil_temp_10_f, Some(58.0));
+    }
+
+    #[test]
+    fn missing_value_falls_through_to_next_source() {
+        let mut top = reading(DataSource::HomeAssistant, 0.0, 0.0);
+        top.ambient_temp_f = None;
+        let registry = SourceRegistry::new(parse_priority("ambient_temp=b,a"));
+        let merged = registry.merge(&[
+            SourceReading {
+                key: "a",
+                source: DataSource::Manual,
+                capabilities: AIR,
+                reading: reading(DataSource::SoilData, 70.0, 58.0),
+            },
+            SourceReading {
+                key: "b",
+                source: DataSource::Manual,
+                capabilities: AIR,
+                reading: top,
+            },
+        ]);
+        assert_eq!(merged.ambient_temp_f, Some(70.0));
+    }
+}
@@ -0,0 +1,165 @@
+//! WeatherFlow Tempest station via the REST API
+//! (`GET /swd/rest/observations/station/{id}`). Observations come back in
+//! metric units whatever the station's display units are.
+
+use super::request_error;
+use super::source::{Capability, EnvironmentalSource, SourceFuture};
+use crate::config::TempestConfig;
+use crate::error::{Result, TurfOpsError};
+use crate::models::{DataSource, DegreesC, EnvironmentalReading};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use std::time::Duration;
+
+const BASE_URL: &str = "https://swd.weatherflow.com/swd/rest";
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+pub struct TempestClient {
+    client: reqwest::Client,
+    config: TempestConfig,
+}
+
+#[derive(Debug, Deserialize)]
+struct StationObservations {
+    #[serde(default)]
+    obs: Vec<Observation>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Observation {
+    timestamp: i64,
+    air_temperature: Option<f64>,
+    relative_humidity: Option<f64>,
+    precip_accum_last_1hr: Option<f64>,
+}
+
+impl TempestClient {
+    /// A Tempest has no soil probes: air, humidity and its haptic rain sensor.
+    pub const CAPABILITIES: &'static [Capability] = &[
+        Capability::AmbientTemperature,
+        Capability::Humidity,
+        Capability::Precipitation,
+    ];
+
+    /// `timeout` bounds each request (`HTTP_TIMEOUT_SECS`).
+    pub fn new(config: TempestConfig, timeout: Duration) -> Self {
+        let client = reqwest::Client::builder()
+            .connect_timeout(CONNECT_TIMEOUT)
+            .timeout(timeout)
+            .build()
+            .expect("failed to build Tempest HTTP client");
+        Self { client, config }
+    }
+
+    pub async fn fetch_current(&self) -> Result<EnvironmentalReading> {
+        let observations = self.get_observations().await?;
+        parse_observations(&observations).ok_or_else(|| {
+            TurfOpsError::DataSourceUnavailable(format!(
+                "Tempest station {} has no recent observation",
+                self.config.station_id
+            ))
+        })
+    }
+
+    async fn get_observations(&self) -> Result<StationObservations> {
+        let url = format!(
+            "{}/observations/station/{}",
+            BASE_URL, self.config.station_id
+        );
+
+        let response = self
+            .client
+            .get(&url)
+            .header("Authorization", format!("Bearer {}", self.config.token))
+            .send()
+            .await
+            .map_err(|e| request_error("Tempest request", e))?;
+
+        if !response.status().is_success() {
+            return Err(TurfOpsError::DataSourceUnavailable(format!(
+                "Tempest GET {} returned {}",
+                url,
+                response.status()
+            )));
+        }
+
+        response.json().await.map_err(|e| {
+            TurfOpsError::DataSourceUnavailable(format!("Failed to parse Tempest response: {}", e))
+        })
+    }
+
+    pub async fn test_connection(&self) -> Result<bool> {
+        Ok(self.get_observations().await.is_ok())
+    }
+}
+
+/// The latest observation as a reading, stamped with the station's time.
+/// `None` when the station has nothing to report (offline hub).
+fn parse_observations(data: &StationObservations) -> Option<EnvironmentalReading> {
+    let obs = data.obs.iter().max_by_key(|o| o.timestamp)?;
+    let mut reading = EnvironmentalReading::new(DataSource::Tempest);
+    reading.timestamp = DateTime::<Utc>::from_timestamp(obs.timestamp, 0)?;
+    reading.ambient_temp_f = obs.air_temperature.map(|c| DegreesC(c).to_fahrenheit().0);
+    reading.humidity_percent = obs.relative_humidity;
+    reading.precipitation_mm = obs.precip_accum_last_1hr;
+    Some(reading)
+}
+
+impl EnvironmentalSource for TempestClient {
+    fn key(&self) -> &'static str {
+        "tempest"
+    }
+
+    fn source(&self) -> DataSource {
+        DataSource::Tempest
+    }
+
+    fn capabilities(&self) -> &'static [Capability] {
+        Self::CAPABILITIES
+    }
+
+    fn fetch_current(&self) -> SourceFuture<'_, EnvironmentalReading> {
+        Box::pin(TempestClient::fetch_current(self))
+    }
+
+    fn test_connection(&self) -> SourceFuture<'_, bool> {
+        Box::pin(TempestClient::test_connection(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn latest_observation_in_fahrenheit() {
+        let data: StationObservations = serde_json::from_str(
+            r#"{
+                "station_id": 12345,
+                "status": {"status_code": 0, "status_message": "SUCCESS"},
+                "obs": [{
+                    "timestamp": 1784203200,
+                    "air_temperature": 25.0,
+                    "relative_humidity": 68,
+                    "precip": 0.0,
+                    "precip_accum_last_1hr": 3.2,
+                    "precip_accum_local_day": 11.4,
+                    "wind_avg": 1.2
+                }]
+            }"#,
+        )
+        .unwrap();
+        let r = parse_observations(&data).unwrap();
+
+        assert_eq!(r.source, DataSource::Tempest);
+        assert_eq!(r.timestamp.timestamp(), 1784203200);
+        assert!((r.ambient_temp_f.unwrap() - 77.0).abs() < 1e-9);
+        assert_eq!(r.humidity_percent, Some(68.0));
+        assert_eq!(r.precipitation_mm, Some(3.2));
+        assert_eq!(r.soil_temp_10_f, None);
+
+        let offline: StationObservations =
+            serde_json::from_str(r#"{"station_id": 12345, "obs": []}"#).unwrap();
+        assert!(parse_observations(&offline).is_none());
+    }
+}
@@ -1,10 +1,22 @@
+use super::quality;
+use super::source::{Capability, EnvironmentalSource, SourceFuture};
 use crate::config::DataLakeConfig;
 use crate::error::{Result, TurfOpsError};
-use crate::models::{
-    celsius_to_fahrenheit, DataSource, EnvironmentalReading, EnvironmentalSummary, Trend,
+use crate::logic::calculations::calculate_trend;
+use crate::logic::gauge_ranges::{ArchivePercentiles, MetricPercentiles};
+use crate::logic::rules::thresholds::{
+    TREND_AMBIENT_TEMP_F, TREND_SOIL_MOISTURE, TREND_SOIL_TEMP_F,
 };
+use crate::models::{DataSource, DegreesC, EnvironmentalReading, EnvironmentalSummary};
 use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, Utc};
 use duckdb::Connection;
+use std::collections::HashMap;
+
+/// A station whose newest silver row is older than this is treated as offline.
+const STATION_STALE_HOURS: i64 = 6;
+
+/// Days of hourly readings behind the summary averages.
+pub const SUMMARY_WINDOW_DAYS: i64 = 7;
 
 /// Reads NOAA USCRN weather data from the Dagster data lake (parquet on a mounted
 /// filesystem) using an embedded DuckDB engine.
@@ -18,11 +30,18 @@ use duckdb::Connection;
 ///
 /// DuckDB connections are not `Sync`, so every query opens a short-lived in-memory
 /// connection inside `spawn_blocking` and scans the parquet directly.
+///
+/// Hourly queries read from the first configured station with recent rows, so a
+/// USCRN station that goes offline falls back to the next one in priority order.
 #[derive(Clone)]
 pub struct WeatherLakeClient {
     silver_weather_path: String,
     gold_weather_path: String,
-    station_wbanno: i32,
+    /// Station WBANNOs in priority order (never empty).
+    stations: Vec<i32>,
+    trend_window: Duration,
+    /// `SOILDATA_TIMEOUT_SECS`: how long one query may take.
+    timeout: std::time::Duration,
 }
 
 /// One daily GDD record sourced from the gold layer, before cumulative accumulation.
@@ -30,26 +49,115 @@ pub struct WeatherLakeClient {
 pub type DailyGddRow = (NaiveDate, f64, f64, f64);
 
 impl WeatherLakeClient {
-    pub fn new(config: &DataLakeConfig, station_wbanno: i32) -> Self {
+    /// The station's air temperature and humidity aren't declared: the patio
+    /// sensor reads the lawn's own conditions, so they'd only ever be a fallback.
+    pub const CAPABILITIES: &'static [Capability] = &[
+        Capability::SoilTemperature,
+        Capability::SoilMoisture,
+        Capability::Precipitation,
+    ];
+
+    pub fn new(config: &DataLakeConfig, stations: Vec<i32>) -> Self {
+        let stations = if stations.is_empty() {
+            vec![3761]
+        } else {
+            stations
+        };
         Self {
             silver_weather_path: config.silver_weather_path.clone(),
             gold_weather_path: config.gold_weather_path.clone(),
-            station_wbanno,
+            stations,
+            trend_window: Duration::hours(config.trend_window_hours),
+            timeout: std::time::Duration::from_secs(config.timeout_secs),
+        }
+    }
+
+    /// Same lake, reading only `station` (a lawn profile's own station).
+    pub fn for_station(&self, station: i32) -> Self {
+        Self {
+            stations: vec![station],
+            ..self.clone()
+        }
+    }
+
+    /// Newest silver observation per configured station.
+    async fn latest_by_station(&self) -> Result<HashMap<i32, DateTime<Utc>>> {
+        let src = Self::parquet(&self.silver_weather_path);
+        // WBANNOs are integers from config, so inlining them is safe.
+        let ids = self
+            .stations
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        self.run(move |conn| {
+            let sql = format!(
+                "SELECT CAST(wbanno AS INTEGER), max(obs_ts_utc) \
+                 FROM {src} WHERE CAST(wbanno AS INTEGER) IN ({ids}) \
+                 GROUP BY 1"
+            );
+            let mut stmt = conn.prepare(&sql)?;
+            let mut rows = stmt.query([])?;
+            let mut out = HashMap::new();
+            while let Some(row) = rows.next()? {
+                let station: i32 = row.get(0)?;
+                let ts: NaiveDateTime = row.get(1)?;
+                out.insert(station, DateTime::<Utc>::from_naive_utc_and_offset(ts, Utc));
+            }
+            Ok(out)
+        })
+        .await
+    }
+
+    /// Station to read hourly data from. Falls back to the primary station if the
+    /// lake can't be scanned, so the caller's own query surfaces the error.
+    async fn active_station(&self) -> i32 {
+        let primary = self.stations[0];
+        if self.stations.len() == 1 {
+            return primary;
+        }
+
+        match self.latest_by_station().await {
+            Ok(latest) => {
+                let station = pick_station(&self.stations, &latest, Utc::now());
+                if station != primary {
+                    tracing::warn!(
+                        primary,
+                        fallback = station,
+                        "Primary USCRN station has no recent data, using fallback"
+                    );
+                }
+                station
+            }
+            Err(e) => {
+                tracing::warn!("Failed to check USCRN station freshness: {}", e);
+                primary
+            }
         }
     }
 
     /// Wrap a blocking DuckDB closure in `spawn_blocking` and normalize errors.
-    async fn run<T, F>(f: F) -> Result<T>
+    async fn run<T, F>(&self, f: F) -> Result<T>
     where
         T: Send + 'static,
         F: FnOnce(&Connection) -> Result<T> + Send + 'static,
     {
-        tokio::task::spawn_blocking(move || {
+        let task = tokio::task::spawn_blocking(move || {
             let conn = Connection::open_in_memory()?;
             f(&conn)
-        })
-        .await
-        .map_err(|e| TurfOpsError::DataSourceUnavailable(format!("data lake task failed: {e}")))?
+        });
+        // A hung mount can't be interrupted; the blocking thread finishes on its own
+        tokio::time::timeout(self.timeout, task)
+            .await
+            .map_err(|_| {
+                TurfOpsError::Timeout(format!(
+                    "data lake query took longer than {}s",
+                    self.timeout.as_secs()
+                ))
+            })?
+            .map_err(|e| {
+                TurfOpsError::DataSourceUnavailable(format!("data lake task failed: {e}"))
+            })?
     }
 
     /// `read_parquet('<path>')` with single quotes escaped (paths come from trusted config).
@@ -57,10 +165,9 @@ impl WeatherLakeClient {
         format!("read_parquet('{}')", path.replace('\'', "''"))
     }
 
-    pub async fn fetch_latest(&self) -> Result<Option<EnvironmentalReading>> {
+    async fn fetch_latest_for(&self, station: i32) -> Result<Option<EnvironmentalReading>> {
         let src = Self::parquet(&self.silver_weather_path);
-        let station = self.station_wbanno;
-        Self::run(move |conn| {
+        self.run(move |conn| {
             let sql = format!(
                 "SELECT obs_ts_utc, soil_temp_5, soil_temp_10, soil_temp_20, soil_temp_50, soil_temp_100, \
                         soil_moisture_5, soil_moisture_10, soil_moisture_20, soil_moisture_50, soil_moisture_100, \
@@ -83,11 +190,20 @@ impl WeatherLakeClient {
         &self,
         start: DateTime<Utc>,
         end: DateTime<Utc>,
+    ) -> Result<Vec<EnvironmentalReading>> {
+        let station = self.active_station().await;
+        self.fetch_range_for(station, start, end).await
+    }
+
+    async fn fetch_range_for(
+        &self,
+        station: i32,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
     ) -> Result<Vec<EnvironmentalReading>> {
         let src = Self::parquet(&self.silver_weather_path);
-        let station = self.station_wbanno;
         let (start_s, end_s) = (fmt_ts(start), fmt_ts(end));
-        Self::run(move |conn| {
+        self.run(move |conn| {
             let sql = format!(
                 "SELECT obs_ts_utc, soil_temp_5, soil_temp_10, soil_temp_20, soil_temp_50, soil_temp_100, \
                         soil_moisture_5, soil_moisture_10, soil_moisture_20, soil_moisture_50, soil_moisture_100, \
@@ -110,49 +226,38 @@ impl WeatherLakeClient {
     /// Current reading plus 7-day rolling averages and the soil-temp trend.
     pub async fn fetch_summary(&self) -> Result<EnvironmentalSummary> {
         let now = Utc::now();
-        let seven_days_ago = now - Duration::days(7);
+        let seven_days_ago = now - Duration::days(SUMMARY_WINDOW_DAYS);
 
-        let current = self.fetch_latest().await?;
-        let readings = self.fetch_range(seven_days_ago, now).await?;
+        // Resolve the station once so the reading and the 7-day window agree.
+        let station = self.active_station().await;
+        let current = self.fetch_latest_for(station).await?;
+        let readings = self.fetch_range_for(station, seven_days_ago, now).await?;
 
-        let mut summary = EnvironmentalSummary {
+        Ok(summarize_readings(
             current,
-            last_updated: Some(now),
-            ..Default::default()
-        };
-
-        if readings.is_empty() {
-            return Ok(summary);
-        }
-
-        let soil_temps: Vec<f64> = readings.iter().filter_map(|r| r.soil_temp_10_f).collect();
-        if !soil_temps.is_empty() {
-            summary.soil_temp_7day_avg_f =
-                Some(soil_temps.iter().sum::<f64>() / soil_temps.len() as f64);
-        }
-
-        let ambient_temps: Vec<f64> = readings.iter().filter_map(|r| r.ambient_temp_f).collect();
-        if !ambient_temps.is_empty() {
-            summary.ambient_temp_7day_avg_f =
-                Some(ambient_temps.iter().sum::<f64>() / ambient_temps.len() as f64);
-        }
-
-        let humidities: Vec<f64> = readings.iter().filter_map(|r| r.humidity_percent).collect();
-        if !humidities.is_empty() {
-            summary.humidity_7day_avg =
-                Some(humidities.iter().sum::<f64>() / humidities.len() as f64);
-        }
-
-        let precip_sum: f64 = readings
-            .iter()
-            .filter_map(|r| r.precipitation_mm)
-            .filter(|p| *p >= 0.0)
-            .sum();
-        summary.precipitation_7day_total_mm = Some(precip_sum);
+            &readings,
+            now,
+            self.trend_window,
+        ))
+    }
 
-        summary.soil_temp_trend = calculate_trend(&readings);
+    /// Window used for the summary trends.
+    pub fn trend_window(&self) -> Duration {
+        self.trend_window
+    }
 
-        Ok(summary)
+    /// Hourly readings newer than `after` from the active station, newest first,
+    /// for the incremental local sync. Returns the station they came from.
+    pub async fn fetch_new_readings(
+        &self,
+        after: DateTime<Utc>,
+    ) -> Result<(i32, Vec<EnvironmentalReading>)> {
+        let station = self.active_station().await;
+        // fetch_range_for is inclusive and timestamps are whole hours
+        let readings = self
+            .fetch_range_for(station, after + Duration::seconds(1), Utc::now())
+            .await?;
+        Ok((station, readings))
     }
 
     /// Daily mean soil temp at 10cm (°F) per day in range, for the seasonal plan.
@@ -164,7 +269,7 @@ impl WeatherLakeClient {
     ) -> Result<Vec<crate::models::seasonal_plan::DailySoilTempAvg>> {
         let src = Self::parquet(&self.gold_weather_path);
         let (start_s, end_s) = (fmt_date(start), fmt_date(end));
-        Self::run(move |conn| {
+        self.run(move |conn| {
             let sql = format!(
                 "SELECT day, soil_temp_10_f_mean \
                  FROM {src} \
@@ -193,7 +298,7 @@ impl WeatherLakeClient {
     ) -> Result<Vec<(NaiveDate, f64, f64)>> {
         let src = Self::parquet(&self.gold_weather_path);
         let (start_s, end_s) = (fmt_date(start), fmt_date(end));
-        Self::run(move |conn| {
+        self.run(move |conn| {
             let sql = format!(
                 "SELECT day, air_temp_avg_f, soil_temp_10_f_mean \
                  FROM {src} \
@@ -224,7 +329,7 @@ impl WeatherLakeClient {
     ) -> Result<Vec<DailyGddRow>> {
         let src = Self::parquet(&self.gold_weather_path);
         let (start_s, end_s) = (start.to_string(), end.to_string());
-        Self::run(move |conn| {
+        self.run(move |conn| {
             let sql = format!(
                 "SELECT day, air_temp_max_f, air_temp_min_f, gdd50 \
                  FROM {src} \
@@ -263,20 +368,241 @@ impl WeatherLakeClient {
         Ok(Some(rows.iter().map(|(_, _, _, gdd)| *gdd).sum()))
     }
 
+    /// Daily lows `(date, low_f)` for `[start, end]`, skipping days without one.
+    pub async fn fetch_daily_lows(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<(NaiveDate, f64)>> {
+        let src = Self::parquet(&self.gold_weather_path);
+        let (start_s, end_s) = (start.to_string(), end.to_string());
+        self.run(move |conn| {
+            let sql = format!(
+                "SELECT day, air_temp_min_f \
+                 FROM {src} \
+                 WHERE day >= ?::DATE AND day <= ?::DATE AND air_temp_min_f IS NOT NULL \
+                 ORDER BY day ASC"
+            );
+            let mut stmt = conn.prepare(&sql)?;
+            let mut rows = stmt.query(duckdb::params![start_s, end_s])?;
+            let mut out = Vec::new();
+            while let Some(row) = rows.next()? {
+                out.push((row.get(0)?, row.get(1)?));
+            }
+            Ok(out)
+        })
+        .await
+    }
+
+    /// `low`/`high` percentiles of the hourly soil temp (10cm), air temp,
+    /// humidity and soil moisture (10cm) in [start, end], in silver units.
+    pub async fn fetch_percentiles(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        low: f64,
+        high: f64,
+    ) -> Result<ArchivePercentiles> {
+        let station = self.active_station().await;
+        let src = Self::parquet(&self.silver_weather_path);
+        let (start_s, end_s) = (fmt_ts(start), fmt_ts(end));
+        self.run(move |conn| {
+            // Negative moisture and humidity are the lake's missing-value sentinels
+            let metric = |col: &str, valid: &str| {
+                format!(
+                    "count({col}) FILTER (WHERE {valid}), \
+                     quantile_cont({col}, {low}) FILTER (WHERE {valid}), \
+                     quantile_cont({col}, {high}) FILTER (WHERE {valid})"
+                )
+            };
+            let sql = format!(
+                "SELECT {}, {}, {}, {} \
+                 FROM {src} \
+                 WHERE CAST(wbanno AS INTEGER) = ? AND obs_ts_utc >= ?::TIMESTAMP AND obs_ts_utc <= ?::TIMESTAMP",
+                metric("soil_temp_10", "soil_temp_10 IS NOT NULL"),
+                metric("air_temp_c", "air_temp_c IS NOT NULL"),
+                metric("rh_pct", "rh_pct >= 0"),
+                metric("soil_moisture_10", "soil_moisture_10 >= 0"),
+            );
+            let mut stmt = conn.prepare(&sql)?;
+            let mut rows = stmt.query(duckdb::params![station, start_s, end_s])?;
+            let Some(row) = rows.next()? else {
+                return Ok(ArchivePercentiles::default());
+            };
+            let metric_at = |i: usize| -> duckdb::Result<MetricPercentiles> {
+                Ok(MetricPercentiles {
+                    samples: row.get(i)?,
+                    low: row.get(i + 1)?,
+                    high: row.get(i + 2)?,
+                })
+            };
+            Ok(ArchivePercentiles {
+                soil_temp_10_c: metric_at(0)?,
+                air_temp_c: metric_at(3)?,
+                humidity_percent: metric_at(6)?,
+                soil_moisture_10: metric_at(9)?,
+            })
+        })
+        .await
+    }
+
     /// Cheap readability probe against the gold parquet.
     pub async fn test_connection(&self) -> Result<bool> {
         let src = Self::parquet(&self.gold_weather_path);
-        let ok = Self::run(move |conn| {
-            let sql = format!("SELECT 1 FROM {src} LIMIT 1");
-            let mut stmt = conn.prepare(&sql)?;
-            let mut rows = stmt.query([])?;
-            Ok(rows.next()?.is_some())
+        let ok = self
+            .run(move |conn| {
+                let sql = format!("SELECT 1 FROM {src} LIMIT 1");
+                let mut stmt = conn.prepare(&sql)?;
+                let mut rows = stmt.query([])?;
+                Ok(rows.next()?.is_some())
+            })
+            .await;
+        match ok {
+            Err(e) if e.is_timeout() => Err(e),
+            ok => Ok(ok.unwrap_or(false)),
+        }
+    }
+}
+
+impl EnvironmentalSource for WeatherLakeClient {
+    fn key(&self) -> &'static str {
+        "uscrn"
+    }
+
+    fn source(&self) -> DataSource {
+        DataSource::SoilData
+    }
+
+    fn capabilities(&self) -> &'static [Capability] {
+        Self::CAPABILITIES
+    }
+
+    fn fetch_current(&self) -> SourceFuture<'_, EnvironmentalReading> {
+        Box::pin(async move {
+            let station = self.active_station().await;
+            self.fetch_latest_for(station).await?.ok_or_else(|| {
+                TurfOpsError::DataSourceUnavailable(format!(
+                    "No recent observations from station {}",
+                    station
+                ))
+            })
         })
-        .await;
-        Ok(ok.unwrap_or(false))
+    }
+
+    fn fetch_history(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> SourceFuture<'_, Vec<EnvironmentalReading>> {
+        Box::pin(self.fetch_range(start, end))
+    }
+
+    fn test_connection(&self) -> SourceFuture<'_, bool> {
+        Box::pin(WeatherLakeClient::test_connection(self))
     }
 }
 
+/// 7-day rolling averages, precipitation total, and soil temp / moisture / ambient
+/// trends from hourly readings. Shared by the live lake path and the local sync.
+pub fn summarize_readings(
+    current: Option<EnvironmentalReading>,
+    readings: &[EnvironmentalReading],
+    now: DateTime<Utc>,
+    trend_window: Duration,
+) -> EnvironmentalSummary {
+    let mut summary = EnvironmentalSummary {
+        current,
+        last_updated: Some(now),
+        ..Default::default()
+    };
+
+    if let Some(current) = summary.current.as_mut() {
+        quality::reject_impossible(current);
+    }
+    if readings.is_empty() {
+        return summary;
+    }
+    let (readings, data_quality) = quality::clean_readings(readings);
+    let readings = readings.as_slice();
+    summary.data_quality = data_quality;
+
+    // Hourly samples a full window would hold; coverage is the share present.
+    let expected = (SUMMARY_WINDOW_DAYS * 24) as f64;
+    let coverage = |n: usize| Some((n as f64 / expected).min(1.0));
+
+    let soil_temps: Vec<f64> = readings.iter().filter_map(|r| r.soil_temp_10_f).collect();
+    summary.coverage.soil_temp = coverage(soil_temps.len());
+    if !soil_temps.is_empty() {
+        summary.soil_temp_7day_avg_f =
+            Some(soil_temps.iter().sum::<f64>() / soil_temps.len() as f64);
+    }
+
+    let ambient_temps: Vec<f64> = readings.iter().filter_map(|r| r.ambient_temp_f).collect();
+    summary.coverage.ambient_temp = coverage(ambient_temps.len());
+    if !ambient_temps.is_empty() {
+        summary.ambient_temp_7day_avg_f =
+            Some(ambient_temps.iter().sum::<f64>() / ambient_temps.len() as f64);
+    }
+
+    let humidities: Vec<f64> = readings.iter().filter_map(|r| r.humidity_percent).collect();
+    summary.coverage.humidity = coverage(humidities.len());
+    if !humidities.is_empty() {
+        summary.humidity_7day_avg = Some(humidities.iter().sum::<f64>() / humidities.len() as f64);
+    }
+
+    let precip_sum: f64 = readings
+        .iter()
+        .filter_map(|r| r.precipitation_mm)
+        .filter(|p| *p >= 0.0)
+        .sum();
+    summary.precipitation_7day_total_mm = Some(precip_sum);
+    summary.coverage.precipitation = coverage(
+        readings
+            .iter()
+            .filter(|r| r.precipitation_mm.is_some())
+            .count(),
+    );
+
+    summary.soil_temp_trend = calculate_trend(
+        readings,
+        |r| r.soil_temp_10_f,
+        trend_window,
+        TREND_SOIL_TEMP_F,
+    );
+    summary.soil_moisture_trend = calculate_trend(
+        readings,
+        EnvironmentalReading::primary_soil_moisture,
+        trend_window,
+        TREND_SOIL_MOISTURE,
+    );
+    summary.ambient_temp_trend = calculate_trend(
+        readings,
+        |r| r.ambient_temp_f,
+        trend_window,
+        TREND_AMBIENT_TEMP_F,
+    );
+
+    summary
+}
+
+/// First station (priority order) whose newest row is within [`STATION_STALE_HOURS`].
+/// If none are recent, the one with the newest data; if none have data, the primary.
+fn pick_station(stations: &[i32], latest: &HashMap<i32, DateTime<Utc>>, now: DateTime<Utc>) -> i32 {
+    let cutoff = now - Duration::hours(STATION_STALE_HOURS);
+    stations
+        .iter()
+        .find(|s| latest.get(s).is_some_and(|ts| *ts >= cutoff))
+        .or_else(|| {
+            stations
+                .iter()
+                .filter(|s| latest.contains_key(s))
+                .max_by_key(|s| latest[s])
+        })
+        .or(stations.first())
+        .copied()
+        .unwrap_or_default()
+}
+
 /// Format a UTC instant as a naive `YYYY-MM-DD HH:MM:SS` string for a TIMESTAMP comparison.
 fn fmt_ts(ts: DateTime<Utc>) -> String {
     ts.format("%Y-%m-%d %H:%M:%S").to_string()
@@ -294,7 +620,7 @@ fn row_to_reading(row: &duckdb::Row) -> duckdb::Result<EnvironmentalReading> {
     let mut reading = EnvironmentalReading::new(DataSource::SoilData);
     reading.timestamp = DateTime::<Utc>::from_naive_utc_and_offset(ts, Utc);
 
-    let temp_f = |c: Option<f64>| c.map(celsius_to_fahrenheit);
+    let temp_f = |c: Option<f64>| c.map(|c| DegreesC(c).to_fahrenheit().0);
     reading.soil_temp_5_f = temp_f(row.get(1)?);
     reading.soil_temp_10_f = temp_f(row.get(2)?);
     reading.soil_temp_20_f = temp_f(row.get(3)?);
@@ -311,42 +637,68 @@ fn row_to_reading(row: &duckdb::Row) -> duckdb::Result<EnvironmentalReading> {
     reading.ambient_temp_f = temp_f(row.get(11)?);
     reading.humidity_percent = row.get::<_, Option<f64>>(12)?.filter(|h| *h >= 0.0);
     reading.precipitation_mm = row.get::<_, Option<f64>>(13)?.filter(|p| *p >= 0.0);
+    quality::reject_impossible(&mut reading);
 
     Ok(reading)
 }
 
-/// Soil-temp trend from hourly readings (newest first): compares the last 24h to the
-/// previous 24h. Identical thresholds to the retired SoilData implementation.
-fn calculate_trend(readings: &[EnvironmentalReading]) -> Trend {
-    if readings.len() < 48 {
-        return Trend::Unknown;
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Trend;
 
-    let recent: Vec<f64> = readings
-        .iter()
-        .take(24)
-        .filter_map(|r| r.soil_temp_10_f)
-        .collect();
-    let previous: Vec<f64> = readings
-        .iter()
-        .skip(24)
-        .take(24)
-        .filter_map(|r| r.soil_temp_10_f)
-        .collect();
-
-    if recent.is_empty() || previous.is_empty() {
-        return Trend::Unknown;
+    #[test]
+    fn summarize_readings_averages_and_totals() {
+        let now = Utc::now();
+        let readings: Vec<EnvironmentalReading> = (0..3)
+            .map(|i| {
+                let mut r = EnvironmentalReading::new(DataSource::SoilData);
+                r.timestamp = now - Duration::hours(i);
+                r.soil_temp_10_f = Some(50.0 + i as f64);
+                r.precipitation_mm = Some(if i == 0 { -1.0 } else { 2.0 });
+                r
+            })
+            .collect();
+
+        let summary = summarize_readings(
+            readings.first().cloned(),
+            &readings,
+            now,
+            Duration::hours(24),
+        );
+        assert_eq!(summary.soil_temp_7day_avg_f, Some(51.0));
+        // 3 of the 168 hourly samples a full week would hold
+        assert_eq!(summary.coverage.soil_temp, Some(3.0 / 168.0));
+        assert_eq!(summary.coverage.humidity, Some(0.0));
+        // Negative sentinel values are excluded from the precipitation total
+        assert_eq!(summary.precipitation_7day_total_mm, Some(4.0));
+        assert!(summary.humidity_7day_avg.is_none());
+        assert_eq!(summary.soil_temp_trend, Trend::Unknown);
     }
 
-    let recent_avg = recent.iter().sum::<f64>() / recent.len() as f64;
-    let previous_avg = previous.iter().sum::<f64>() / previous.len() as f64;
-    let diff = recent_avg - previous_avg;
+    #[test]
+    fn pick_station_prefers_first_recent_station() {
+        let now = Utc::now();
+        let latest = HashMap::from([
+            (3761, now - Duration::days(2)),
+            (64756, now - Duration::hours(1)),
+            (4994, now - Duration::minutes(30)),
+        ]);
+        // Primary is stale, so the next station in priority order wins
+        assert_eq!(pick_station(&[3761, 64756, 4994], &latest, now), 64756);
+        assert_eq!(pick_station(&[4994, 64756], &latest, now), 4994);
+    }
 
-    if diff > 2.0 {
-        Trend::Rising
-    } else if diff < -2.0 {
-        Trend::Falling
-    } else {
-        Trend::Stable
+    #[test]
+    fn pick_station_falls_back_to_newest_then_primary() {
+        let now = Utc::now();
+        let latest = HashMap::from([
+            (3761, now - Duration::days(3)),
+            (64756, now - Duration::days(1)),
+        ]);
+        // Nobody is recent: take the freshest data available
+        assert_eq!(pick_station(&[3761, 64756], &latest, now), 64756);
+        // No rows at all: stay on the primary
+        assert_eq!(pick_station(&[3761, 64756], &HashMap::new(), now), 3761);
     }
 }
@@ -0,0 +1,110 @@
+use crate::error::{Result, TurfOpsError};
+use crate::models::AnnualReview;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+fn to_json<T: serde::Serialize>(value: &T, what: &str) -> Result<serde_json::Value> {
+    serde_json::to_value(value)
+        .map_err(|e| TurfOpsError::InvalidData(format!("{} serialization: {}", what, e)))
+}
+
+pub async fn get_annual_review(
+    pool: &PgPool,
+    profile_id: i64,
+    year: i32,
+) -> Result<Option<AnnualReview>> {
+    let row = sqlx::query_as::<_, AnnualReviewRow>(
+        r#"SELECT id, lawn_profile_id, year, application_reviews, observations, adjustments,
+           completed_at, created_at, updated_at
+           FROM annual_reviews WHERE lawn_profile_id = $1 AND year = $2"#,
+    )
+    .bind(profile_id)
+    .bind(year)
+    .fetch_optional(pool)
+    .await?;
+
+    row.map(AnnualReviewRow::into_review).transpose()
+}
+
+pub async fn list_annual_reviews(pool: &PgPool, profile_id: i64) -> Result<Vec<AnnualReview>> {
+    let rows = sqlx::query_as::<_, AnnualReviewRow>(
+        r#"SELECT id, lawn_profile_id, year, application_reviews, observations, adjustments,
+           completed_at, created_at, updated_at
+           FROM annual_reviews WHERE lawn_profile_id = $1
+           ORDER BY year DESC"#,
+    )
+    .bind(profile_id)
+    .fetch_all(pool)
+    .await?;
+
+    rows.into_iter().map(AnnualReviewRow::into_review).collect()
+}
+
+/// Insert or replace the review for (profile, year).
+pub async fn upsert_annual_review(pool: &PgPool, review: &AnnualReview) -> Result<AnnualReview> {
+    let row = sqlx::query_as::<_, AnnualReviewRow>(
+        r#"
+        INSERT INTO annual_reviews
+            (lawn_profile_id, year, application_reviews, observations, adjustments, completed_at)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        ON CONFLICT (lawn_profile_id, year) DO UPDATE SET
+            application_reviews = EXCLUDED.application_reviews,
+            observations = EXCLUDED.observations,
+            adjustments = EXCLUDED.adjustments,
+            completed_at = EXCLUDED.completed_at,
+            updated_at = NOW()
+        RETURNING id, lawn_profile_id, year, application_reviews, observations, adjustments,
+            completed_at, created_at, updated_at
+        "#,
+    )
+    .bind(review.lawn_profile_id)
+    .bind(review.year)
+    .bind(to_json(&review.application_reviews, "Application reviews")?)
+    .bind(to_json(&review.observations, "Observations")?)
+    .bind(to_json(&review.adjustments, "Adjustments")?)
+    .bind(review.completed_at)
+    .fetch_one(pool)
+    .await?;
+
+    row.into_review()
+}
+
+#[derive(sqlx::FromRow)]
+struct AnnualReviewRow {
+    id: i64,
+    lawn_profile_id: i64,
+    year: i32,
+    application_reviews: serde_json::Value,
+    observations: serde_json::Value,
+    adjustments: serde_json::Value,
+    completed_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl AnnualReviewRow {
+    fn into_review(self) -> Result<AnnualReview> {
+        let id = self.id;
+        let invalid = |field: &str, e: serde_json::Error| {
+            TurfOpsError::InvalidData(format!(
+                "Annual review {} has invalid {} JSON: {}",
+                id, field, e
+            ))
+        };
+
+        Ok(AnnualReview {
+            id: Some(self.id),
+            lawn_profile_id: self.lawn_profile_id,
+            year: self.year,
+            application_reviews: serde_json::from_value(self.application_reviews)
+                .map_err(|e| invalid("application_reviews", e))?,
+            observations: serde_json::from_value(self.observations)
+                .map_err(|e| invalid("observations", e))?,
+            adjustments: serde_json::from_value(self.adjustments)
+                .map_err(|e| invalid("adjustments", e))?,
+            completed_at: self.completed_at,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        })
+    }
+}
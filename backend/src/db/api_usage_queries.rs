@@ -0,0 +1,43 @@
+use crate::error::Result;
+use chrono::NaiveDate;
+use sqlx::PgPool;
+
+/// Calls made to `source` on `day` (UTC).
+pub async fn get_calls(pool: &PgPool, source: &str, day: NaiveDate) -> Result<u32> {
+    let calls =
+        sqlx::query_scalar::<_, i32>("SELECT calls FROM api_usage WHERE source = $1 AND day = $2")
+            .bind(source)
+            .bind(day)
+            .fetch_optional(pool)
+            .await?;
+
+    Ok(calls.unwrap_or(0).max(0) as u32)
+}
+
+/// Count one call unless the day's total, including calls other processes
+/// made with the same key, has already reached `limit` (0 for no limit).
+/// Returns the new total, or `None` when the call was refused. The check and
+/// the increment are one statement, so concurrent processes can't overshoot.
+pub async fn record_call(
+    pool: &PgPool,
+    source: &str,
+    day: NaiveDate,
+    limit: u32,
+) -> Result<Option<u32>> {
+    let calls = sqlx::query_scalar::<_, i32>(
+        r#"
+        INSERT INTO api_usage (source, day, calls)
+        VALUES ($1, $2, 1)
+        ON CONFLICT (source, day) DO UPDATE SET calls = api_usage.calls + 1
+        WHERE $3 = 0 OR api_usage.calls < $3
+        RETURNING calls
+        "#,
+    )
+    .bind(source)
+    .bind(day)
+    .bind(limit.min(i32::MAX as u32) as i32)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(calls.map(|c| c.max(0) as u32))
+}
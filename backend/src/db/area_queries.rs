@@ -0,0 +1,129 @@
+use super::queries::enum_to_db_string;
+use crate::error::{Result, TurfOpsError};
+use crate::models::{GrassType, LawnArea, SunExposure};
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use std::str::FromStr;
+use tracing::warn;
+
+pub async fn list_areas_for_profile(pool: &PgPool, profile_id: i64) -> Result<Vec<LawnArea>> {
+    let rows = sqlx::query_as::<_, LawnAreaRow>(
+        r#"SELECT id, lawn_profile_id, name, size_sqft, sun_exposure, grass_type, grass_mix, created_at
+           FROM lawn_areas WHERE lawn_profile_id = $1 ORDER BY name"#,
+    )
+    .bind(profile_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows.into_iter().map(|r| r.into_area()).collect())
+}
+
+pub async fn get_area(pool: &PgPool, id: i64) -> Result<Option<LawnArea>> {
+    let row = sqlx::query_as::<_, LawnAreaRow>(
+        r#"SELECT id, lawn_profile_id, name, size_sqft, sun_exposure, grass_type, grass_mix, created_at
+           FROM lawn_areas WHERE id = $1"#,
+    )
+    .bind(id)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(row.map(|r| r.into_area()))
+}
+
+/// Like `get_area`, but only if the area belongs to `profile_id`, so an id
+/// from another profile can't be used to tag applications or scope rules.
+pub async fn get_area_for_profile(pool: &PgPool, profile_id: i64, id: i64) -> Result<LawnArea> {
+    get_area(pool, id)
+        .await?
+        .filter(|a| a.lawn_profile_id == profile_id)
+        .ok_or_else(|| TurfOpsError::NotFound(format!("Lawn area {id} not found")))
+}
+
+pub async fn create_area(pool: &PgPool, area: &LawnArea) -> Result<i64> {
+    let id = sqlx::query_scalar::<_, i64>(
+        r#"
+        INSERT INTO lawn_areas
+            (lawn_profile_id, name, size_sqft, sun_exposure, grass_type, grass_mix)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        RETURNING id
+        "#,
+    )
+    .bind(area.lawn_profile_id)
+    .bind(&area.name)
+    .bind(area.size_sqft)
+    .bind(enum_to_db_string(area.sun_exposure)?)
+    .bind(area.grass_type.map(enum_to_db_string).transpose()?)
+    .bind(&area.grass_mix)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(id)
+}
+
+pub async fn update_area(pool: &PgPool, id: i64, area: &LawnArea) -> Result<LawnArea> {
+    let row = sqlx::query_as::<_, LawnAreaRow>(
+        r#"
+        UPDATE lawn_areas
+           SET name = $2, size_sqft = $3, sun_exposure = $4, grass_type = $5, grass_mix = $6
+         WHERE id = $1
+        RETURNING id, lawn_profile_id, name, size_sqft, sun_exposure, grass_type, grass_mix, created_at
+        "#,
+    )
+    .bind(id)
+    .bind(&area.name)
+    .bind(area.size_sqft)
+    .bind(enum_to_db_string(area.sun_exposure)?)
+    .bind(area.grass_type.map(enum_to_db_string).transpose()?)
+    .bind(&area.grass_mix)
+    .fetch_optional(pool)
+    .await?;
+
+    row.map(|r| r.into_area())
+        .ok_or_else(|| TurfOpsError::NotFound(format!("Lawn area {id} not found")))
+}
+
+pub async fn delete_area(pool: &PgPool, id: i64) -> Result<()> {
+    sqlx::query("DELETE FROM lawn_areas WHERE id = $1")
+        .bind(id)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+#[derive(sqlx::FromRow)]
+struct LawnAreaRow {
+    id: i64,
+    lawn_profile_id: i64,
+    name: String,
+    size_sqft: Option<f64>,
+    sun_exposure: String,
+    grass_type: Option<String>,
+    grass_mix: Option<String>,
+    created_at: DateTime<Utc>,
+}
+
+impl LawnAreaRow {
+    fn into_area(self) -> LawnArea {
+        let sun_exposure = SunExposure::from_str(&self.sun_exposure).unwrap_or_else(|_| {
+            warn!(sun_exposure = %self.sun_exposure, "Unknown sun_exposure in database, defaulting to FullSun");
+            SunExposure::FullSun
+        });
+        let grass_type = self.grass_type.as_ref().and_then(|g| {
+            GrassType::from_str(g).ok().or_else(|| {
+                warn!(grass_type = %g, "Unknown area grass_type in database, ignoring");
+                None
+            })
+        });
+
+        LawnArea {
+            id: Some(self.id),
+            lawn_profile_id: self.lawn_profile_id,
+            name: self.name,
+            size_sqft: self.size_sqft,
+            sun_exposure,
+            grass_type,
+            grass_mix: self.grass_mix,
+            created_at: self.created_at,
+        }
+    }
+}
@@ -0,0 +1,196 @@
+//! Whole-database dump and restore for `export-all` / `import-all`. Tables are
+//! discovered from the catalog and moved as JSON arrays of rows, so new
+//! tables and columns are picked up without changes here.
+
+use crate::error::{Result, TurfOpsError};
+use sqlx::{AssertSqlSafe, PgExecutor, PgPool, Postgres, Transaction};
+
+/// Migration bookkeeping; the target's own migrations recreate it.
+const SKIPPED_TABLES: &[&str] = &["_sqlx_migrations"];
+
+fn quote(ident: &str) -> String {
+    format!("\"{}\"", ident.replace('"', "\"\""))
+}
+
+/// Ordinary tables in the current schema, by name.
+pub async fn list_tables<'e>(db: impl PgExecutor<'e>) -> Result<Vec<String>> {
+    let tables = sqlx::query_scalar::<_, String>(
+        r#"SELECT c.relname::TEXT
+           FROM pg_class c
+           JOIN pg_namespace n ON n.oid = c.relnamespace
+           WHERE n.nspname = CURRENT_SCHEMA() AND c.relkind = 'r'
+           ORDER BY c.relname"#,
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(tables
+        .into_iter()
+        .filter(|t| !SKIPPED_TABLES.contains(&t.as_str()))
+        .collect())
+}
+
+/// (table, referenced table) for every foreign key in the current schema.
+pub async fn list_foreign_keys(pool: &PgPool) -> Result<Vec<(String, String)>> {
+    let rows = sqlx::query_as::<_, (String, String)>(
+        r#"SELECT t.relname::TEXT, r.relname::TEXT
+           FROM pg_constraint c
+           JOIN pg_class t ON t.oid = c.conrelid
+           JOIN pg_class r ON r.oid = c.confrelid
+           JOIN pg_namespace n ON n.oid = t.relnamespace
+           WHERE c.contype = 'f' AND n.nspname = CURRENT_SCHEMA()"#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+pub async fn table_columns<'e>(db: impl PgExecutor<'e>, table: &str) -> Result<Vec<String>> {
+    let columns = sqlx::query_scalar::<_, String>(
+        r#"SELECT column_name::TEXT
+           FROM information_schema.columns
+           WHERE table_schema = CURRENT_SCHEMA() AND table_name = $1
+             AND is_generated = 'NEVER'
+           ORDER BY ordinal_position"#,
+    )
+    .bind(table)
+    .fetch_all(db)
+    .await?;
+
+    Ok(columns)
+}
+
+/// A read-only transaction whose reads all see one snapshot, so tables
+/// dumped one after another stay consistent while a server keeps writing.
+pub async fn begin_snapshot(pool: &PgPool) -> Result<Transaction<'static, Postgres>> {
+    let mut tx = pool.begin().await?;
+    sqlx::query("SET TRANSACTION ISOLATION LEVEL REPEATABLE READ READ ONLY")
+        .execute(&mut *tx)
+        .await?;
+    Ok(tx)
+}
+
+// Table and column names below come from the catalog (or are checked against
+// it) and are quoted as identifiers.
+
+/// Every row of `table` as a JSON array.
+pub async fn dump_table<'e>(db: impl PgExecutor<'e>, table: &str) -> Result<String> {
+    let json = sqlx::query_scalar::<_, String>(AssertSqlSafe(format!(
+        "SELECT COALESCE(JSON_AGG(t), '[]'::JSON)::TEXT FROM {} t",
+        quote(table)
+    )))
+    .fetch_one(db)
+    .await?;
+
+    Ok(json)
+}
+
+/// Newest applied migration, `None` on an empty database.
+pub async fn schema_version<'e>(db: impl PgExecutor<'e>) -> Result<Option<i64>> {
+    let version = sqlx::query_scalar::<_, Option<i64>>("SELECT MAX(version) FROM _sqlx_migrations")
+        .fetch_one(db)
+        .await?;
+    Ok(version)
+}
+
+pub async fn count_rows<'e>(db: impl PgExecutor<'e>, table: &str) -> Result<i64> {
+    let n = sqlx::query_scalar::<_, i64>(AssertSqlSafe(format!(
+        "SELECT COUNT(*) FROM {}",
+        quote(table)
+    )))
+    .fetch_one(db)
+    .await?;
+    Ok(n)
+}
+
+/// One table's rows from an archive.
+pub struct TableData {
+    pub table: String,
+    /// Columns the archived rows carry.
+    pub columns: Vec<String>,
+    /// JSON array of row objects.
+    pub rows: String,
+}
+
+/// Replace the contents of each table with the archived rows, in one
+/// transaction. `tables` must be in dependency order (referenced tables
+/// first) and name existing tables. Only columns both the archive and the
+/// table have are restored; the rest take their defaults. Serial sequences
+/// continue after the restored ids. Returns the rows inserted per table.
+pub async fn restore_tables(pool: &PgPool, tables: &[TableData]) -> Result<Vec<(String, u64)>> {
+    let mut tx = pool.begin().await?;
+
+    if !tables.is_empty() {
+        let names: Vec<String> = tables.iter().map(|t| quote(&t.table)).collect();
+        sqlx::query(AssertSqlSafe(format!(
+            "TRUNCATE {} RESTART IDENTITY CASCADE",
+            names.join(", ")
+        )))
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    let mut restored = Vec::with_capacity(tables.len());
+    for data in tables {
+        let existing = sqlx::query_scalar::<_, String>(
+            r#"SELECT column_name::TEXT
+               FROM information_schema.columns
+               WHERE table_schema = CURRENT_SCHEMA() AND table_name = $1
+                 AND is_generated = 'NEVER'"#,
+        )
+        .bind(&data.table)
+        .fetch_all(&mut *tx)
+        .await?;
+        if existing.is_empty() {
+            return Err(TurfOpsError::InvalidData(format!(
+                "Table {} does not exist",
+                data.table
+            )));
+        }
+        let columns: Vec<String> = data
+            .columns
+            .iter()
+            .filter(|c| existing.contains(c))
+            .map(|c| quote(c))
+            .collect();
+        if columns.is_empty() {
+            restored.push((data.table.clone(), 0));
+            continue;
+        }
+
+        let table = quote(&data.table);
+        let inserted = sqlx::query(AssertSqlSafe(format!(
+            "INSERT INTO {table} ({cols}) SELECT {cols} FROM JSONB_POPULATE_RECORDSET(NULL::{table}, $1::JSONB)",
+            cols = columns.join(", ")
+        )))
+        .bind(&data.rows)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+
+        let serials = sqlx::query_as::<_, (String, String)>(
+            r#"SELECT column_name::TEXT, PG_GET_SERIAL_SEQUENCE(QUOTE_IDENT(table_name::TEXT), column_name::TEXT)
+               FROM information_schema.columns
+               WHERE table_schema = CURRENT_SCHEMA() AND table_name = $1
+                 AND PG_GET_SERIAL_SEQUENCE(QUOTE_IDENT(table_name::TEXT), column_name::TEXT) IS NOT NULL"#,
+        )
+        .bind(&data.table)
+        .fetch_all(&mut *tx)
+        .await?;
+        for (column, sequence) in serials {
+            sqlx::query(AssertSqlSafe(format!(
+                "SELECT SETVAL($1::REGCLASS, COALESCE((SELECT MAX({}) FROM {table}), 0) + 1, false)",
+                quote(&column)
+            )))
+            .bind(&sequence)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        restored.push((data.table.clone(), inserted));
+    }
+
+    tx.commit().await?;
+    Ok(restored)
+}
@@ -0,0 +1,121 @@
+use super::queries::enum_to_db_string;
+use crate::error::{Result, TurfOpsError};
+use crate::models::{ApplicationType, CustomApplicationType, TypeRegistry};
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use std::str::FromStr;
+use tracing::warn;
+
+pub async fn list_custom_types(pool: &PgPool) -> Result<Vec<CustomApplicationType>> {
+    let rows = sqlx::query_as::<_, CustomTypeRow>(
+        r#"SELECT id, name, color, base_type, created_at
+           FROM custom_application_types ORDER BY LOWER(name)"#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows.into_iter().map(|r| r.into_custom_type()).collect())
+}
+
+/// Built-in types plus every custom type.
+pub async fn load_registry(pool: &PgPool) -> Result<TypeRegistry> {
+    Ok(TypeRegistry::new(list_custom_types(pool).await?))
+}
+
+pub async fn get_custom_type(pool: &PgPool, id: i64) -> Result<CustomApplicationType> {
+    let row = sqlx::query_as::<_, CustomTypeRow>(
+        r#"SELECT id, name, color, base_type, created_at
+           FROM custom_application_types WHERE id = $1"#,
+    )
+    .bind(id)
+    .fetch_optional(pool)
+    .await?;
+
+    row.map(|r| r.into_custom_type())
+        .ok_or_else(|| TurfOpsError::NotFound(format!("Application type {id} not found")))
+}
+
+pub async fn create_custom_type(pool: &PgPool, custom: &CustomApplicationType) -> Result<i64> {
+    let id = sqlx::query_scalar::<_, i64>(
+        r#"
+        INSERT INTO custom_application_types (name, color, base_type)
+        VALUES ($1, $2, $3)
+        RETURNING id
+        "#,
+    )
+    .bind(&custom.name)
+    .bind(&custom.color)
+    .bind(enum_to_db_string(custom.base_type)?)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(id)
+}
+
+/// Update a custom type. Its applications move to the new base category too,
+/// so they keep being counted where the type now says they belong.
+pub async fn update_custom_type(
+    pool: &PgPool,
+    id: i64,
+    custom: &CustomApplicationType,
+) -> Result<CustomApplicationType> {
+    let mut tx = pool.begin().await?;
+    let row = sqlx::query_as::<_, CustomTypeRow>(
+        r#"
+        UPDATE custom_application_types
+           SET name = $2, color = $3, base_type = $4
+         WHERE id = $1
+        RETURNING id, name, color, base_type, created_at
+        "#,
+    )
+    .bind(id)
+    .bind(&custom.name)
+    .bind(&custom.color)
+    .bind(enum_to_db_string(custom.base_type)?)
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or_else(|| TurfOpsError::NotFound(format!("Application type {id} not found")))?;
+
+    sqlx::query("UPDATE applications SET application_type = $2 WHERE custom_type_id = $1")
+        .bind(id)
+        .bind(enum_to_db_string(custom.base_type)?)
+        .execute(&mut *tx)
+        .await?;
+    tx.commit().await?;
+
+    Ok(row.into_custom_type())
+}
+
+pub async fn delete_custom_type(pool: &PgPool, id: i64) -> Result<()> {
+    sqlx::query("DELETE FROM custom_application_types WHERE id = $1")
+        .bind(id)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+#[derive(sqlx::FromRow)]
+struct CustomTypeRow {
+    id: i64,
+    name: String,
+    color: String,
+    base_type: String,
+    created_at: DateTime<Utc>,
+}
+
+impl CustomTypeRow {
+    fn into_custom_type(self) -> CustomApplicationType {
+        let base_type = ApplicationType::from_str(&self.base_type).unwrap_or_else(|_| {
+            warn!(base_type = %self.base_type, "Unknown custom type base_type in database, defaulting to Other");
+            ApplicationType::Other
+        });
+
+        CustomApplicationType {
+            id: Some(self.id),
+            name: self.name,
+            color: self.color,
+            base_type,
+            created_at: self.created_at,
+        }
+    }
+}
@@ -0,0 +1,463 @@
+//! Column encryption at rest. With `DB_ENCRYPTION_KEY_FILE` or
+//! `DB_ENCRYPTION_KEY_COMMAND` set, free-text notes, photo captions and saved
+//! locations are sealed with AES-256-GCM before they're written, so a copied
+//! data directory or dump doesn't give them away. Everything else (dates, rates,
+//! readings) stays queryable as before.
+//!
+//! Sealed values carry the `enc1:` prefix and read back transparently;
+//! plaintext left from before encryption was switched on reads as it is.
+//! `encrypt-db` seals what's already stored and `decrypt-db` reverses it.
+//! A canary setting records that the database holds sealed values, so
+//! starting without the key, or with a different one, fails instead of
+//! showing ciphertext.
+
+use crate::config::KeySource;
+use crate::error::{Result, TurfOpsError};
+use crate::models::Location;
+use aws_lc_rs::aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM, NONCE_LEN};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use sqlx::{AssertSqlSafe, PgPool, Postgres, Transaction};
+use std::sync::OnceLock;
+use tracing::{info, warn};
+
+/// Marks a sealed value: base64 of nonce, ciphertext and tag follows.
+const SEALED_PREFIX: &str = "enc1:";
+
+/// Settings key holding a sealed known value, present while the database
+/// holds sealed columns.
+const CHECK_KEY: &str = "encryption.check";
+const CHECK_VALUE: &str = "turfops";
+
+/// Settings key holding the JSON forecast location; sealed as a whole.
+const LOCATION_KEY: &str = "location";
+
+/// Free-text columns sealed when a key is configured, as (table, column).
+/// Lawn profile locations are sealed into `lawn_profiles.location_sealed`.
+pub const SEALED_COLUMNS: &[(&str, &str)] = &[
+    ("applications", "notes"),
+    ("observations", "notes"),
+    ("soil_tests", "notes"),
+    ("inventory_items", "notes"),
+    ("equipment", "notes"),
+    ("equipment_maintenance", "notes"),
+    ("plants", "notes"),
+    ("photos", "caption"),
+];
+
+static CIPHER: OnceLock<FieldCipher> = OnceLock::new();
+
+pub struct FieldCipher {
+    key: LessSafeKey,
+}
+
+impl FieldCipher {
+    /// A 256-bit key written as 64 hex characters (`openssl rand -hex 32`).
+    pub fn from_hex(hex: &str) -> Result<Self> {
+        let hex = hex.trim();
+        // Checked up front: from_str_radix would also take a leading '+'
+        if hex.len() != 64 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(TurfOpsError::Config(
+                "DB encryption key must be 64 hex characters (openssl rand -hex 32)".into(),
+            ));
+        }
+        let bytes = (0..64)
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16))
+            .collect::<std::result::Result<Vec<u8>, _>>()
+            .map_err(|_| TurfOpsError::Config("DB encryption key must be hex".into()))?;
+        let key = UnboundKey::new(&AES_256_GCM, &bytes)
+            .map_err(|_| TurfOpsError::Encryption("Unusable key".into()))?;
+        Ok(FieldCipher {
+            key: LessSafeKey::new(key),
+        })
+    }
+
+    pub fn seal(&self, plaintext: &str) -> Result<String> {
+        let mut nonce = [0u8; NONCE_LEN];
+        aws_lc_rs::rand::fill(&mut nonce)
+            .map_err(|_| TurfOpsError::Encryption("No randomness for nonce".into()))?;
+        let mut sealed = plaintext.as_bytes().to_vec();
+        self.key
+            .seal_in_place_append_tag(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::empty(),
+                &mut sealed,
+            )
+            .map_err(|_| TurfOpsError::Encryption("Seal failed".into()))?;
+
+        let mut out = nonce.to_vec();
+        out.extend_from_slice(&sealed);
+        Ok(format!("{}{}", SEALED_PREFIX, STANDARD.encode(out)))
+    }
+
+    /// Fails on a value sealed with another key or altered since.
+    pub fn open(&self, sealed: &str) -> Result<String> {
+        let invalid = || TurfOpsError::Encryption("Sealed value can't be opened".into());
+        let encoded = sealed.strip_prefix(SEALED_PREFIX).ok_or_else(invalid)?;
+        let mut bytes = STANDARD.decode(encoded).map_err(|_| invalid())?;
+        if bytes.len() < NONCE_LEN {
+            return Err(invalid());
+        }
+        let mut ciphertext = bytes.split_off(NONCE_LEN);
+        let nonce = Nonce::try_assume_unique_for_key(&bytes).map_err(|_| invalid())?;
+        let plaintext = self
+            .key
+            .open_in_place(nonce, Aad::empty(), &mut ciphertext)
+            .map_err(|_| invalid())?;
+        String::from_utf8(plaintext.to_vec()).map_err(|_| invalid())
+    }
+}
+
+pub fn is_sealed(value: &str) -> bool {
+    value.starts_with(SEALED_PREFIX)
+}
+
+/// Whether writes are sealed: a key was configured and checked at startup.
+pub fn enabled() -> bool {
+    CIPHER.get().is_some()
+}
+
+/// Seal a value for writing, or pass it through when no key is configured.
+pub fn seal(value: &str) -> Result<String> {
+    match CIPHER.get() {
+        Some(cipher) => cipher.seal(value),
+        None => Ok(value.to_string()),
+    }
+}
+
+pub fn seal_opt(value: Option<&str>) -> Result<Option<String>> {
+    value.map(seal).transpose()
+}
+
+/// Read a stored value back. Plaintext passes through; a value that can't
+/// be opened is logged and shown as stored rather than failing the page.
+pub fn open(value: String) -> String {
+    if !is_sealed(&value) {
+        return value;
+    }
+    match CIPHER.get().map(|c| c.open(&value)) {
+        Some(Ok(plaintext)) => plaintext,
+        Some(Err(e)) => {
+            warn!(error = %e, "Stored value can't be decrypted");
+            value
+        }
+        None => {
+            warn!("Stored value is encrypted but no DB encryption key is configured");
+            value
+        }
+    }
+}
+
+pub fn open_opt(value: Option<String>) -> Option<String> {
+    value.map(open)
+}
+
+/// Read the key from its configured source.
+fn load_key(source: &KeySource) -> Result<String> {
+    match source {
+        KeySource::File(path) => std::fs::read_to_string(path)
+            .map_err(|e| TurfOpsError::Config(format!("DB_ENCRYPTION_KEY_FILE {}: {}", path, e))),
+        KeySource::Command(command) => {
+            let output = std::process::Command::new("sh")
+                .arg("-c")
+                .arg(command)
+                .stderr(std::process::Stdio::inherit())
+                .output()
+                .map_err(|e| {
+                    TurfOpsError::Config(format!("DB_ENCRYPTION_KEY_COMMAND failed to run: {}", e))
+                })?;
+            if !output.status.success() {
+                return Err(TurfOpsError::Config(format!(
+                    "DB_ENCRYPTION_KEY_COMMAND exited with {}",
+                    output.status
+                )));
+            }
+            String::from_utf8(output.stdout).map_err(|_| {
+                TurfOpsError::Config("DB_ENCRYPTION_KEY_COMMAND printed non-text output".into())
+            })
+        }
+    }
+}
+
+/// Load the configured key and check it against the database before anything
+/// reads or writes sealed columns.
+pub async fn prepare(pool: &PgPool, source: Option<&KeySource>) -> Result<()> {
+    let check = sqlx::query_scalar::<_, String>("SELECT value FROM settings WHERE key = $1")
+        .bind(CHECK_KEY)
+        .fetch_optional(pool)
+        .await?;
+
+    let Some(source) = source else {
+        if check.is_some() {
+            return Err(TurfOpsError::Config(
+                "This database holds encrypted notes and locations; set \
+                 DB_ENCRYPTION_KEY_FILE or DB_ENCRYPTION_KEY_COMMAND"
+                    .into(),
+            ));
+        }
+        return Ok(());
+    };
+
+    let cipher = FieldCipher::from_hex(&load_key(source)?)?;
+    match check {
+        Some(sealed) => {
+            if cipher.open(&sealed).ok().as_deref() != Some(CHECK_VALUE) {
+                return Err(TurfOpsError::Config(
+                    "DB encryption key doesn't match the one this database was encrypted with"
+                        .into(),
+                ));
+            }
+        }
+        None => {
+            sqlx::query("INSERT INTO settings (key, value) VALUES ($1, $2)")
+                .bind(CHECK_KEY)
+                .bind(cipher.seal(CHECK_VALUE)?)
+                .execute(pool)
+                .await?;
+            info!("DB encryption enabled; `turfops-backend encrypt-db` seals existing notes");
+        }
+    }
+
+    // A second prepare in the same process (tests, CLI) keeps the first key
+    let _ = CIPHER.set(cipher);
+    Ok(())
+}
+
+/// Lawn profile location stored sealed, as JSON, in place of the plain
+/// `location_name`/`latitude`/`longitude` columns.
+pub fn seal_location(location: Option<&Location>) -> Result<Option<String>> {
+    if !enabled() {
+        return Ok(None);
+    }
+    location
+        .map(|l| seal(&serde_json::to_string(l)?))
+        .transpose()
+}
+
+pub fn open_location(sealed: &str) -> Option<Location> {
+    serde_json::from_str(&open(sealed.to_string()))
+        .inspect_err(|e| warn!(error = %e, "Stored location can't be read"))
+        .ok()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Encrypt,
+    Decrypt,
+}
+
+/// Values changed in one place by `encrypt-db` or `decrypt-db`.
+#[derive(Debug, serde::Serialize)]
+pub struct ResealCount {
+    /// `table.column`.
+    pub column: String,
+    pub rows: u64,
+}
+
+/// The value to write back, or `None` when it's already in the target form.
+fn convert(direction: Direction, value: &str) -> Result<Option<String>> {
+    let cipher = CIPHER.get().ok_or_else(|| {
+        TurfOpsError::Config(
+            "Set DB_ENCRYPTION_KEY_FILE or DB_ENCRYPTION_KEY_COMMAND to the key to use".into(),
+        )
+    })?;
+    match direction {
+        Direction::Encrypt if !is_sealed(value) => cipher.seal(value).map(Some),
+        Direction::Decrypt if is_sealed(value) => cipher.open(value).map(Some),
+        _ => Ok(None),
+    }
+}
+
+/// Seal or open every stored note and location in one transaction, so an
+/// interrupted run changes nothing. Decrypting also drops the canary, after
+/// which the app starts without a key.
+pub async fn reseal_all(pool: &PgPool, direction: Direction) -> Result<Vec<ResealCount>> {
+    let mut tx = pool.begin().await?;
+    let mut counts = Vec::new();
+
+    for (table, column) in SEALED_COLUMNS {
+        counts.push(ResealCount {
+            column: format!("{}.{}", table, column),
+            rows: reseal_column(&mut tx, table, column, direction).await?,
+        });
+    }
+    counts.push(ResealCount {
+        column: "lawn_profiles.location".into(),
+        rows: reseal_profile_locations(&mut tx, direction).await?,
+    });
+
+    let location = sqlx::query_scalar::<_, String>("SELECT value FROM settings WHERE key = $1")
+        .bind(LOCATION_KEY)
+        .fetch_optional(&mut *tx)
+        .await?;
+    let mut rows = 0;
+    if let Some(value) = location
+        .map(|v| convert(direction, &v))
+        .transpose()?
+        .flatten()
+    {
+        sqlx::query("UPDATE settings SET value = $2 WHERE key = $1")
+            .bind(LOCATION_KEY)
+            .bind(value)
+            .execute(&mut *tx)
+            .await?;
+        rows = 1;
+    }
+    counts.push(ResealCount {
+        column: "settings.location".into(),
+        rows,
+    });
+
+    if direction == Direction::Decrypt {
+        sqlx::query("DELETE FROM settings WHERE key = $1")
+            .bind(CHECK_KEY)
+            .execute(&mut *tx)
+            .await?;
+    }
+
+    tx.commit().await?;
+    Ok(counts)
+}
+
+async fn reseal_column(
+    tx: &mut Transaction<'_, Postgres>,
+    table: &str,
+    column: &str,
+    direction: Direction,
+) -> Result<u64> {
+    // Names come from SEALED_COLUMNS, never from input
+    let rows = sqlx::query_as::<_, (i64, String)>(AssertSqlSafe(format!(
+        "SELECT id, {column} FROM {table} WHERE {column} IS NOT NULL"
+    )))
+    .fetch_all(&mut **tx)
+    .await?;
+
+    let mut changed = 0;
+    for (id, value) in rows {
+        let Some(value) = convert(direction, &value)? else {
+            continue;
+        };
+        sqlx::query(AssertSqlSafe(format!(
+            "UPDATE {table} SET {column} = $1 WHERE id = $2"
+        )))
+        .bind(value)
+        .bind(id)
+        .execute(&mut **tx)
+        .await?;
+        changed += 1;
+    }
+    Ok(changed)
+}
+
+async fn reseal_profile_locations(
+    tx: &mut Transaction<'_, Postgres>,
+    direction: Direction,
+) -> Result<u64> {
+    let mut changed = 0;
+    match direction {
+        Direction::Encrypt => {
+            let rows = sqlx::query_as::<_, (i64, Option<String>, f64, f64)>(
+                r#"SELECT id, location_name, latitude, longitude FROM lawn_profiles
+                   WHERE latitude IS NOT NULL AND longitude IS NOT NULL"#,
+            )
+            .fetch_all(&mut **tx)
+            .await?;
+            for (id, name, latitude, longitude) in rows {
+                let location = Location {
+                    name: name.unwrap_or_default(),
+                    latitude,
+                    longitude,
+                    usda_zone: None,
+                };
+                let sealed = convert(direction, &serde_json::to_string(&location)?)?;
+                sqlx::query(
+                    r#"UPDATE lawn_profiles
+                          SET location_sealed = $2, location_name = NULL,
+                              latitude = NULL, longitude = NULL
+                        WHERE id = $1"#,
+                )
+                .bind(id)
+                .bind(sealed)
+                .execute(&mut **tx)
+                .await?;
+                changed += 1;
+            }
+        }
+        Direction::Decrypt => {
+            let rows = sqlx::query_as::<_, (i64, String)>(
+                "SELECT id, location_sealed FROM lawn_profiles WHERE location_sealed IS NOT NULL",
+            )
+            .fetch_all(&mut **tx)
+            .await?;
+            for (id, sealed) in rows {
+                let json = convert(direction, &sealed)?.unwrap_or(sealed);
+                let location: Location = serde_json::from_str(&json)?;
+                sqlx::query(
+                    r#"UPDATE lawn_profiles
+                          SET location_name = $2, latitude = $3, longitude = $4,
+                              location_sealed = NULL
+                        WHERE id = $1"#,
+                )
+                .bind(id)
+                .bind(location.name)
+                .bind(location.latitude)
+                .bind(location.longitude)
+                .execute(&mut **tx)
+                .await?;
+                changed += 1;
+            }
+        }
+    }
+    Ok(changed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+
+    #[test]
+    fn seal_round_trips_with_a_fresh_nonce() {
+        let cipher = FieldCipher::from_hex(KEY).unwrap();
+        let a = cipher.seal("Grub damage by the shed").unwrap();
+        let b = cipher.seal("Grub damage by the shed").unwrap();
+        assert!(is_sealed(&a));
+        assert!(!a.contains("Grub"));
+        assert_ne!(a, b);
+        assert_eq!(cipher.open(&a).unwrap(), "Grub damage by the shed");
+        assert_eq!(cipher.open(&cipher.seal("").unwrap()).unwrap(), "");
+    }
+
+    #[test]
+    fn another_key_or_tampering_fails() {
+        let cipher = FieldCipher::from_hex(KEY).unwrap();
+        let other = FieldCipher::from_hex(&KEY.replace("00", "ff")).unwrap();
+        let sealed = cipher.seal("West Chester").unwrap();
+        assert!(other.open(&sealed).is_err());
+
+        let mut tampered = sealed.clone();
+        let last = tampered.pop().unwrap();
+        tampered.push(if last == 'A' { 'B' } else { 'A' });
+        assert!(cipher.open(&tampered).is_err());
+        assert!(cipher.open("plain notes").is_err());
+    }
+
+    #[test]
+    fn keys_must_be_64_hex_characters() {
+        assert!(FieldCipher::from_hex(&format!("  {}\n", KEY)).is_ok());
+        assert!(FieldCipher::from_hex(&KEY[..62]).is_err());
+        assert!(FieldCipher::from_hex(&KEY.replace('a', "g")).is_err());
+        assert!(FieldCipher::from_hex(&KEY.replace("0f", "+f")).is_err());
+    }
+
+    #[test]
+    fn plaintext_reads_as_stored() {
+        assert_eq!(
+            open("Mowed high before the heat".into()),
+            "Mowed high before the heat"
+        );
+        assert_eq!(open_opt(None), None);
+    }
+}
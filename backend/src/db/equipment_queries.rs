@@ -0,0 +1,199 @@
+use super::queries::enum_to_db_string;
+use crate::db::encryption;
+use crate::error::{Result, TurfOpsError};
+use crate::models::{Equipment, EquipmentKind, EquipmentTask, MaintenanceEvent};
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::PgPool;
+use std::str::FromStr;
+use tracing::warn;
+
+pub async fn list_equipment_for_profile(pool: &PgPool, profile_id: i64) -> Result<Vec<Equipment>> {
+    let rows = sqlx::query_as::<_, EquipmentRow>(
+        r#"SELECT id, lawn_profile_id, name, kind, hours_per_use, starting_hours, notes, created_at
+           FROM equipment WHERE lawn_profile_id = $1 ORDER BY name"#,
+    )
+    .bind(profile_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows.into_iter().map(|r| r.into_equipment()).collect())
+}
+
+/// The piece with `id`, only if it belongs to `profile_id`, so an id from
+/// another profile can't be used to tag applications or log maintenance.
+pub async fn get_equipment_for_profile(
+    pool: &PgPool,
+    profile_id: i64,
+    id: i64,
+) -> Result<Equipment> {
+    let row = sqlx::query_as::<_, EquipmentRow>(
+        r#"SELECT id, lawn_profile_id, name, kind, hours_per_use, starting_hours, notes, created_at
+           FROM equipment WHERE id = $1 AND lawn_profile_id = $2"#,
+    )
+    .bind(id)
+    .bind(profile_id)
+    .fetch_optional(pool)
+    .await?;
+
+    row.map(|r| r.into_equipment())
+        .ok_or_else(|| TurfOpsError::NotFound(format!("Equipment {id} not found")))
+}
+
+pub async fn create_equipment(pool: &PgPool, equipment: &Equipment) -> Result<i64> {
+    let id = sqlx::query_scalar::<_, i64>(
+        r#"
+        INSERT INTO equipment
+            (lawn_profile_id, name, kind, hours_per_use, starting_hours, notes)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        RETURNING id
+        "#,
+    )
+    .bind(equipment.lawn_profile_id)
+    .bind(&equipment.name)
+    .bind(enum_to_db_string(equipment.kind)?)
+    .bind(equipment.hours_per_use)
+    .bind(equipment.starting_hours)
+    .bind(encryption::seal_opt(equipment.notes.as_deref())?)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(id)
+}
+
+pub async fn update_equipment(pool: &PgPool, id: i64, equipment: &Equipment) -> Result<Equipment> {
+    let row = sqlx::query_as::<_, EquipmentRow>(
+        r#"
+        UPDATE equipment
+           SET name = $2, kind = $3, hours_per_use = $4, starting_hours = $5, notes = $6
+         WHERE id = $1
+        RETURNING id, lawn_profile_id, name, kind, hours_per_use, starting_hours, notes, created_at
+        "#,
+    )
+    .bind(id)
+    .bind(&equipment.name)
+    .bind(enum_to_db_string(equipment.kind)?)
+    .bind(equipment.hours_per_use)
+    .bind(equipment.starting_hours)
+    .bind(encryption::seal_opt(equipment.notes.as_deref())?)
+    .fetch_optional(pool)
+    .await?;
+
+    row.map(|r| r.into_equipment())
+        .ok_or_else(|| TurfOpsError::NotFound(format!("Equipment {id} not found")))
+}
+
+pub async fn delete_equipment(pool: &PgPool, id: i64) -> Result<()> {
+    sqlx::query("DELETE FROM equipment WHERE id = $1")
+        .bind(id)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+/// Maintenance on all of a profile's equipment, newest first.
+pub async fn list_maintenance_for_profile(
+    pool: &PgPool,
+    profile_id: i64,
+) -> Result<Vec<MaintenanceEvent>> {
+    let rows = sqlx::query_as::<_, MaintenanceRow>(
+        r#"SELECT m.id, m.equipment_id, m.task, m.performed_on, m.notes, m.created_at
+           FROM equipment_maintenance m
+           JOIN equipment e ON e.id = m.equipment_id
+           WHERE e.lawn_profile_id = $1
+           ORDER BY m.performed_on DESC, m.id DESC"#,
+    )
+    .bind(profile_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows.into_iter().map(|r| r.into_event()).collect())
+}
+
+pub async fn create_maintenance(pool: &PgPool, event: &MaintenanceEvent) -> Result<i64> {
+    let id = sqlx::query_scalar::<_, i64>(
+        r#"
+        INSERT INTO equipment_maintenance (equipment_id, task, performed_on, notes)
+        VALUES ($1, $2, $3, $4)
+        RETURNING id
+        "#,
+    )
+    .bind(event.equipment_id)
+    .bind(enum_to_db_string(event.task)?)
+    .bind(event.performed_on)
+    .bind(encryption::seal_opt(event.notes.as_deref())?)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(id)
+}
+
+/// Delete a maintenance entry on `equipment_id`; false if there was none.
+pub async fn delete_maintenance(pool: &PgPool, equipment_id: i64, id: i64) -> Result<bool> {
+    let result =
+        sqlx::query("DELETE FROM equipment_maintenance WHERE id = $1 AND equipment_id = $2")
+            .bind(id)
+            .bind(equipment_id)
+            .execute(pool)
+            .await?;
+    Ok(result.rows_affected() > 0)
+}
+
+#[derive(sqlx::FromRow)]
+struct EquipmentRow {
+    id: i64,
+    lawn_profile_id: i64,
+    name: String,
+    kind: String,
+    hours_per_use: Option<f64>,
+    starting_hours: f64,
+    notes: Option<String>,
+    created_at: DateTime<Utc>,
+}
+
+impl EquipmentRow {
+    fn into_equipment(self) -> Equipment {
+        let kind = EquipmentKind::from_str(&self.kind).unwrap_or_else(|_| {
+            warn!(kind = %self.kind, "Unknown equipment kind in database, defaulting to Other");
+            EquipmentKind::Other
+        });
+
+        Equipment {
+            id: Some(self.id),
+            lawn_profile_id: self.lawn_profile_id,
+            name: self.name,
+            kind,
+            hours_per_use: self.hours_per_use,
+            starting_hours: self.starting_hours,
+            notes: encryption::open_opt(self.notes),
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct MaintenanceRow {
+    id: i64,
+    equipment_id: i64,
+    task: String,
+    performed_on: NaiveDate,
+    notes: Option<String>,
+    created_at: DateTime<Utc>,
+}
+
+impl MaintenanceRow {
+    fn into_event(self) -> MaintenanceEvent {
+        let task = EquipmentTask::from_str(&self.task).unwrap_or_else(|_| {
+            warn!(task = %self.task, "Unknown maintenance task in database, defaulting to Other");
+            EquipmentTask::Other
+        });
+
+        MaintenanceEvent {
+            id: Some(self.id),
+            equipment_id: self.equipment_id,
+            task,
+            performed_on: self.performed_on,
+            notes: encryption::open_opt(self.notes),
+            created_at: self.created_at,
+        }
+    }
+}
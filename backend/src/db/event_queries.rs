@@ -0,0 +1,130 @@
+use crate::error::Result;
+use crate::models::{Event, EventKind, EventLevel};
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use std::str::FromStr;
+use tracing::warn;
+
+pub async fn insert_event(pool: &PgPool, event: &Event) -> Result<i64> {
+    let id = sqlx::query_scalar::<_, i64>(
+        r#"
+        INSERT INTO events (occurred_at, kind, level, message, details)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING id
+        "#,
+    )
+    .bind(event.occurred_at)
+    .bind(event.kind.as_str())
+    .bind(event.level.as_str())
+    .bind(&event.message)
+    .bind(&event.details)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(id)
+}
+
+/// Best-effort event logging. The activity log is a debugging aid, so a failed
+/// insert is logged and swallowed rather than failing the surrounding request.
+pub async fn record_event(pool: &PgPool, event: Event) {
+    if let Err(e) = insert_event(pool, &event).await {
+        warn!(kind = %event.kind, "Failed to record event: {}", e);
+    }
+}
+
+/// Whether any event of `kind` carries exactly these details.
+pub async fn event_exists(pool: &PgPool, kind: EventKind, details: &str) -> Result<bool> {
+    let exists = sqlx::query_scalar::<_, bool>(
+        "SELECT EXISTS (SELECT 1 FROM events WHERE kind = $1 AND details = $2)",
+    )
+    .bind(kind.as_str())
+    .bind(details)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(exists)
+}
+
+/// Details of the most recent event of `kind` whose details start with
+/// `scope`, if any.
+pub async fn latest_details_for(
+    pool: &PgPool,
+    kind: EventKind,
+    scope: &str,
+) -> Result<Option<String>> {
+    let details = sqlx::query_scalar::<_, Option<String>>(
+        r#"SELECT details FROM events
+           WHERE kind = $1 AND starts_with(details, $2)
+           ORDER BY occurred_at DESC, id DESC LIMIT 1"#,
+    )
+    .bind(kind.as_str())
+    .bind(scope)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(details.flatten())
+}
+
+/// List events newest first, optionally filtered by kind and minimum level.
+pub async fn list_events(
+    pool: &PgPool,
+    kind: Option<EventKind>,
+    min_level: Option<EventLevel>,
+    limit: i64,
+    offset: i64,
+) -> Result<Vec<Event>> {
+    // Levels are stored as text, so expand the minimum level into the accepted set.
+    let levels: Vec<&'static str> = [EventLevel::Info, EventLevel::Warning, EventLevel::Error]
+        .into_iter()
+        .filter(|l| min_level.is_none_or(|min| *l >= min))
+        .map(|l| l.as_str())
+        .collect();
+
+    let rows = sqlx::query_as::<_, EventRow>(
+        r#"SELECT id, occurred_at, kind, level, message, details
+           FROM events
+           WHERE ($1::TEXT IS NULL OR kind = $1) AND level = ANY($2)
+           ORDER BY occurred_at DESC, id DESC
+           LIMIT $3 OFFSET $4"#,
+    )
+    .bind(kind.map(|k| k.as_str()))
+    .bind(&levels)
+    .bind(limit)
+    .bind(offset)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows.into_iter().map(|r| r.into_event()).collect())
+}
+
+#[derive(sqlx::FromRow)]
+struct EventRow {
+    id: i64,
+    occurred_at: DateTime<Utc>,
+    kind: String,
+    level: String,
+    message: String,
+    details: Option<String>,
+}
+
+impl EventRow {
+    fn into_event(self) -> Event {
+        let kind = EventKind::from_str(&self.kind).unwrap_or_else(|_| {
+            warn!(kind = %self.kind, "Unknown event kind in database, defaulting to DataRefresh");
+            EventKind::DataRefresh
+        });
+        let level = EventLevel::from_str(&self.level).unwrap_or_else(|_| {
+            warn!(level = %self.level, "Unknown event level in database, defaulting to Info");
+            EventLevel::Info
+        });
+
+        Event {
+            id: Some(self.id),
+            occurred_at: self.occurred_at,
+            kind,
+            level,
+            message: self.message,
+            details: self.details,
+        }
+    }
+}
@@ -0,0 +1,193 @@
+//! One server per database. The server holds a Postgres advisory lock for
+//! as long as it runs, so a second server against the same database (which
+//! would double the background sync, rain checks, retention and digest
+//! emails) refuses to start. The lock lives on its own connection and is
+//! released by Postgres when the process exits, crashed or not, so there is
+//! no stale lock file to clean up. That connection is pinged while the
+//! server runs; if its session ends the lock is taken again, and if another
+//! server got there first this one shuts down. A session of ours that a
+//! network cut orphaned can keep the lock on the server for hours, so the
+//! lock connection is named after this process and such a session is ended
+//! rather than mistaken for another server.
+//!
+//! Commands check the same lock: while a server is running they read what it
+//! synced instead of writing the shared tables themselves, and the ones that
+//! rewrite the database wholesale refuse to run.
+
+use crate::error::{Result, TurfOpsError};
+use sqlx::postgres::PgConnectOptions;
+use sqlx::{Connection, PgConnection, PgPool};
+use std::sync::OnceLock;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::task::JoinHandle;
+
+/// Advisory lock key: "turfops" in ASCII.
+const INSTANCE_LOCK_KEY: i64 = 0x0074_7572_666f_7073;
+
+/// How often the lock's connection is pinged. A session ended by
+/// `idle_session_timeout`, a Postgres restart or a dropped TCP connection
+/// takes the lock with it, so it's noticed and taken again.
+const KEEPALIVE_SECS: u64 = 30;
+
+/// Held by the running server. The lock's connection lives in a keepalive
+/// task; dropping this stops the task, closing the connection and releasing
+/// the lock.
+pub struct InstanceLock {
+    keepalive: JoinHandle<()>,
+}
+
+impl InstanceLock {
+    /// Resolves if the lock is lost to another server, which should then
+    /// stop serving.
+    pub async fn lost(mut self) {
+        let _ = (&mut self.keepalive).await;
+    }
+}
+
+impl Drop for InstanceLock {
+    fn drop(&mut self) {
+        self.keepalive.abort();
+    }
+}
+
+/// `application_name` of this process's lock connection. The pid alone can
+/// repeat across containers, so the start time goes with it.
+fn lock_session_name() -> &'static str {
+    static NAME: OnceLock<String> = OnceLock::new();
+    NAME.get_or_init(|| {
+        let started = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis())
+            .unwrap_or_default();
+        format!("turfops-lock {}@{}", std::process::id(), started)
+    })
+}
+
+enum Attempt {
+    Locked(PgConnection),
+    /// Held by an orphaned session of this process, now being ended.
+    HeldByUs,
+    HeldElsewhere,
+}
+
+async fn try_lock(options: &PgConnectOptions) -> Result<Attempt> {
+    let options = options.clone().application_name(lock_session_name());
+    let mut conn = PgConnection::connect_with(&options).await?;
+    let locked = sqlx::query_scalar::<_, bool>("SELECT pg_try_advisory_lock($1)")
+        .bind(INSTANCE_LOCK_KEY)
+        .fetch_one(&mut conn)
+        .await?;
+    if locked {
+        return Ok(Attempt::Locked(conn));
+    }
+
+    let orphan = sqlx::query_scalar::<_, i32>(
+        r#"SELECT l.pid FROM pg_locks l
+             JOIN pg_stat_activity a ON a.pid = l.pid
+            WHERE l.locktype = 'advisory' AND l.granted
+              AND l.database = (SELECT oid FROM pg_database WHERE datname = current_database())
+              AND l.classid::BIGINT = ($1 >> 32) AND l.objid::BIGINT = ($1 & 4294967295)
+              AND l.objsubid = 1
+              AND a.application_name = $2"#,
+    )
+    .bind(INSTANCE_LOCK_KEY)
+    .bind(lock_session_name())
+    .fetch_optional(&mut conn)
+    .await?;
+    let Some(pid) = orphan else {
+        return Ok(Attempt::HeldElsewhere);
+    };
+    let ended = sqlx::query_scalar::<_, bool>("SELECT pg_terminate_backend($1)")
+        .bind(pid)
+        .fetch_one(&mut conn)
+        .await?;
+    if !ended {
+        return Err(TurfOpsError::Config(format!(
+            "Can't end this server's orphaned lock session (backend {})",
+            pid
+        )));
+    }
+    Ok(Attempt::HeldByUs)
+}
+
+/// Take the server lock, failing if another server already holds it.
+pub async fn acquire(options: &PgConnectOptions) -> Result<InstanceLock> {
+    let Attempt::Locked(conn) = try_lock(options).await? else {
+        return Err(TurfOpsError::Config(
+            "Another TurfOps server is already running against this database".into(),
+        ));
+    };
+    Ok(InstanceLock {
+        keepalive: tokio::spawn(keepalive(conn, options.clone())),
+    })
+}
+
+/// Ping the lock's connection, taking the lock again on a new one when the
+/// session is gone. Returns only once another server holds the lock; while
+/// the database is unreachable, or our orphaned session still holds it, no
+/// other server can take it either, so that just retries.
+async fn keepalive(mut conn: PgConnection, options: PgConnectOptions) {
+    let mut ticker = tokio::time::interval(Duration::from_secs(KEEPALIVE_SECS));
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    ticker.tick().await;
+
+    loop {
+        ticker.tick().await;
+        if conn.ping().await.is_ok() {
+            continue;
+        }
+        tracing::warn!("Lost the connection holding the server lock; taking it again");
+        loop {
+            match try_lock(&options).await {
+                Ok(Attempt::Locked(fresh)) => {
+                    conn = fresh;
+                    tracing::info!("Server lock re-acquired");
+                    break;
+                }
+                Ok(Attempt::HeldByUs) => {
+                    tracing::warn!("Ended this server's orphaned lock session; retrying");
+                    tokio::time::sleep(Duration::from_secs(1)).await;
+                }
+                Ok(Attempt::HeldElsewhere) => {
+                    tracing::error!(
+                        "Another TurfOps server took the lock while the connection was down; \
+                         shutting down"
+                    );
+                    return;
+                }
+                Err(e) => {
+                    tracing::warn!("Can't re-acquire the server lock yet: {}", e);
+                    ticker.tick().await;
+                }
+            }
+        }
+    }
+}
+
+/// Whether a server holds the lock right now.
+pub async fn server_running(pool: &PgPool) -> Result<bool> {
+    let held = sqlx::query_scalar::<_, bool>(
+        r#"SELECT EXISTS (
+               SELECT 1 FROM pg_locks
+                WHERE locktype = 'advisory' AND granted
+                  AND database = (SELECT oid FROM pg_database WHERE datname = current_database())
+                  AND classid::BIGINT = ($1 >> 32) AND objid::BIGINT = ($1 & 4294967295)
+                  AND objsubid = 1
+           )"#,
+    )
+    .bind(INSTANCE_LOCK_KEY)
+    .fetch_one(pool)
+    .await?;
+    Ok(held)
+}
+
+/// Fail when a server is running, for commands that rewrite the database.
+pub async fn ensure_no_server(pool: &PgPool, command: &str) -> Result<()> {
+    if server_running(pool).await? {
+        return Err(TurfOpsError::Config(format!(
+            "A TurfOps server is running against this database; stop it before {}",
+            command
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,145 @@
+use super::queries::enum_to_db_string;
+use crate::db::encryption;
+use crate::error::{Result, TurfOpsError};
+use crate::models::{ApplicationType, InventoryItem, ProductUnit};
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::PgPool;
+use std::str::FromStr;
+use tracing::warn;
+
+pub async fn list_items_for_profile(pool: &PgPool, profile_id: i64) -> Result<Vec<InventoryItem>> {
+    let rows = sqlx::query_as::<_, InventoryItemRow>(
+        r#"SELECT id, lawn_profile_id, product_name, application_type, unit, package_size,
+                  quantity, counted_on, rate_per_1000sqft, notes, created_at
+           FROM inventory_items WHERE lawn_profile_id = $1 ORDER BY product_name"#,
+    )
+    .bind(profile_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows.into_iter().map(|r| r.into_item()).collect())
+}
+
+/// The item with `id`, only if it belongs to `profile_id`.
+pub async fn get_item_for_profile(
+    pool: &PgPool,
+    profile_id: i64,
+    id: i64,
+) -> Result<InventoryItem> {
+    let row = sqlx::query_as::<_, InventoryItemRow>(
+        r#"SELECT id, lawn_profile_id, product_name, application_type, unit, package_size,
+                  quantity, counted_on, rate_per_1000sqft, notes, created_at
+           FROM inventory_items WHERE id = $1 AND lawn_profile_id = $2"#,
+    )
+    .bind(id)
+    .bind(profile_id)
+    .fetch_optional(pool)
+    .await?;
+
+    row.map(|r| r.into_item())
+        .ok_or_else(|| TurfOpsError::NotFound(format!("Inventory item {id} not found")))
+}
+
+pub async fn create_item(pool: &PgPool, item: &InventoryItem) -> Result<i64> {
+    let id = sqlx::query_scalar::<_, i64>(
+        r#"
+        INSERT INTO inventory_items
+            (lawn_profile_id, product_name, application_type, unit, package_size,
+             quantity, counted_on, rate_per_1000sqft, notes)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        RETURNING id
+        "#,
+    )
+    .bind(item.lawn_profile_id)
+    .bind(&item.product_name)
+    .bind(item.application_type.map(enum_to_db_string).transpose()?)
+    .bind(enum_to_db_string(item.unit)?)
+    .bind(item.package_size)
+    .bind(item.quantity)
+    .bind(item.counted_on)
+    .bind(item.rate_per_1000sqft)
+    .bind(encryption::seal_opt(item.notes.as_deref())?)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(id)
+}
+
+pub async fn update_item(pool: &PgPool, id: i64, item: &InventoryItem) -> Result<InventoryItem> {
+    let row = sqlx::query_as::<_, InventoryItemRow>(
+        r#"
+        UPDATE inventory_items
+           SET product_name = $2, application_type = $3, unit = $4, package_size = $5,
+               quantity = $6, counted_on = $7, rate_per_1000sqft = $8, notes = $9
+         WHERE id = $1
+        RETURNING id, lawn_profile_id, product_name, application_type, unit, package_size,
+                  quantity, counted_on, rate_per_1000sqft, notes, created_at
+        "#,
+    )
+    .bind(id)
+    .bind(&item.product_name)
+    .bind(item.application_type.map(enum_to_db_string).transpose()?)
+    .bind(enum_to_db_string(item.unit)?)
+    .bind(item.package_size)
+    .bind(item.quantity)
+    .bind(item.counted_on)
+    .bind(item.rate_per_1000sqft)
+    .bind(encryption::seal_opt(item.notes.as_deref())?)
+    .fetch_optional(pool)
+    .await?;
+
+    row.map(|r| r.into_item())
+        .ok_or_else(|| TurfOpsError::NotFound(format!("Inventory item {id} not found")))
+}
+
+pub async fn delete_item(pool: &PgPool, id: i64) -> Result<()> {
+    sqlx::query("DELETE FROM inventory_items WHERE id = $1")
+        .bind(id)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+#[derive(sqlx::FromRow)]
+struct InventoryItemRow {
+    id: i64,
+    lawn_profile_id: i64,
+    product_name: String,
+    application_type: Option<String>,
+    unit: String,
+    package_size: Option<f64>,
+    quantity: f64,
+    counted_on: NaiveDate,
+    rate_per_1000sqft: Option<f64>,
+    notes: Option<String>,
+    created_at: DateTime<Utc>,
+}
+
+impl InventoryItemRow {
+    fn into_item(self) -> InventoryItem {
+        let unit = ProductUnit::from_str(&self.unit).unwrap_or_else(|_| {
+            warn!(unit = %self.unit, "Unknown inventory unit in database, defaulting to Pounds");
+            ProductUnit::Pounds
+        });
+        let application_type = self.application_type.as_ref().and_then(|t| {
+            ApplicationType::from_str(t).ok().or_else(|| {
+                warn!(application_type = %t, "Unknown inventory application_type in database, ignoring");
+                None
+            })
+        });
+
+        InventoryItem {
+            id: Some(self.id),
+            lawn_profile_id: self.lawn_profile_id,
+            product_name: self.product_name,
+            application_type,
+            unit,
+            package_size: self.package_size,
+            quantity: self.quantity,
+            counted_on: self.counted_on,
+            rate_per_1000sqft: self.rate_per_1000sqft,
+            notes: encryption::open_opt(self.notes),
+            created_at: self.created_at,
+        }
+    }
+}
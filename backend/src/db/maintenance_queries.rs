@@ -0,0 +1,60 @@
+use crate::error::Result;
+use chrono::{DateTime, Utc};
+use sqlx::{AssertSqlSafe, PgPool};
+
+/// Delete synced lake rows observed before `before`.
+pub async fn prune_soil_observations(pool: &PgPool, before: DateTime<Utc>) -> Result<u64> {
+    let result = sqlx::query("DELETE FROM soil_observations WHERE observed_at < $1")
+        .bind(before)
+        .execute(pool)
+        .await?;
+    Ok(result.rows_affected())
+}
+
+/// Delete activity log entries that occurred before `before`.
+pub async fn prune_events(pool: &PgPool, before: DateTime<Utc>) -> Result<u64> {
+    let result = sqlx::query("DELETE FROM events WHERE occurred_at < $1")
+        .bind(before)
+        .execute(pool)
+        .await?;
+    Ok(result.rows_affected())
+}
+
+/// Delete rain checks whose window ended before `before`. A pending check is
+/// kept regardless, so the reconciler still gets to give up on it.
+pub async fn prune_rain_checks(pool: &PgPool, before: DateTime<Utc>) -> Result<u64> {
+    let result =
+        sqlx::query("DELETE FROM rain_checks WHERE window_end < $1 AND reconciled_at IS NOT NULL")
+            .bind(before)
+            .execute(pool)
+            .await?;
+    Ok(result.rows_affected())
+}
+
+/// On-disk size of a table including its indexes and TOAST.
+pub async fn table_size_bytes(pool: &PgPool, table: &str) -> Result<i64> {
+    let size = sqlx::query_scalar::<_, i64>("SELECT pg_total_relation_size($1::regclass)")
+        .bind(table)
+        .fetch_one(pool)
+        .await?;
+    Ok(size)
+}
+
+/// `VACUUM (ANALYZE)` one table. VACUUM takes no bind parameters, so `table`
+/// is quoted as an identifier. Runs without `DB_STATEMENT_TIMEOUT_SECS`,
+/// since a large table can take a while.
+pub async fn vacuum_analyze(pool: &PgPool, table: &str) -> Result<()> {
+    let quoted = format!("\"{}\"", table.replace('"', "\"\""));
+    let mut conn = pool.acquire().await?;
+    sqlx::query("SET statement_timeout = 0")
+        .execute(&mut *conn)
+        .await?;
+    let vacuumed = sqlx::query(AssertSqlSafe(format!("VACUUM (ANALYZE) {}", quoted)))
+        .execute(&mut *conn)
+        .await;
+    sqlx::query("RESET statement_timeout")
+        .execute(&mut *conn)
+        .await?;
+    vacuumed?;
+    Ok(())
+}
@@ -0,0 +1,13 @@
+-- Activity/audit log: data refreshes, rule evaluations, application changes, and
+-- data source errors. Browsed from the Activity Log page to debug why a
+-- recommendation did or didn't appear.
+CREATE TABLE IF NOT EXISTS events (
+    id BIGSERIAL PRIMARY KEY,
+    occurred_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    kind TEXT NOT NULL,
+    level TEXT NOT NULL,
+    message TEXT NOT NULL,
+    details TEXT
+);
+CREATE INDEX IF NOT EXISTS idx_events_occurred_at ON events(occurred_at DESC);
+CREATE INDEX IF NOT EXISTS idx_events_kind_occurred_at ON events(kind, occurred_at DESC);
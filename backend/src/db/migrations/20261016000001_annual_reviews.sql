@@ -0,0 +1,14 @@
+-- Season-end reviews: per-application outcome ratings, observations, and the
+-- threshold/program adjustments the user accepted for next year.
+CREATE TABLE IF NOT EXISTS annual_reviews (
+    id BIGSERIAL PRIMARY KEY,
+    lawn_profile_id BIGINT NOT NULL REFERENCES lawn_profiles(id) ON DELETE CASCADE,
+    year INTEGER NOT NULL,
+    application_reviews JSONB NOT NULL DEFAULT '[]',
+    observations JSONB NOT NULL DEFAULT '{}',
+    adjustments JSONB NOT NULL DEFAULT '[]',
+    completed_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT uq_annual_reviews_profile_year UNIQUE (lawn_profile_id, year)
+);
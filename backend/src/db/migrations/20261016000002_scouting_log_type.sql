@@ -0,0 +1,12 @@
+-- Scouting is a lightweight log entry (e.g. a soap flush for armyworms) rather
+-- than a product application, but it lives in the applications table so it
+-- shows up in history and the calendar.
+ALTER TABLE applications DROP CONSTRAINT IF EXISTS chk_application_type;
+ALTER TABLE applications ADD CONSTRAINT chk_application_type CHECK (
+    application_type IN (
+        'PreEmergent', 'PostEmergent', 'Fertilizer', 'Fungicide', 'Insecticide',
+        'GrubControl', 'Overseed', 'Aeration', 'Dethatching', 'Lime', 'Sulfur',
+        'Wetting', 'Mowing', 'Scouting', 'Other',
+        'Pruning', 'PlantFertilizer', 'Mulching', 'Deadheading', 'WinterProtection'
+    )
+);
@@ -0,0 +1,13 @@
+-- Season journal: free-text field observations (weed breakthrough, disease
+-- spots, insect damage, general notes). Shown on the Journal page and the
+-- calendar; kept separate from applications since nothing was applied.
+CREATE TABLE IF NOT EXISTS observations (
+    id BIGSERIAL PRIMARY KEY,
+    lawn_profile_id BIGINT NOT NULL REFERENCES lawn_profiles(id) ON DELETE CASCADE,
+    observed_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    category TEXT NOT NULL CHECK (category IN ('Weeds', 'Disease', 'Insect', 'General')),
+    notes TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE INDEX IF NOT EXISTS idx_observations_profile_observed_at
+    ON observations(lawn_profile_id, observed_at DESC);
@@ -0,0 +1,23 @@
+-- Local copy of the silver hourly USCRN observations. When DATALAKE_LOCAL_SYNC is
+-- enabled, each sensor refresh appends rows newer than MAX(observed_at) and the
+-- 7-day summary + soil-temp trend are computed from this table, so they keep
+-- working while the data lake mount is unavailable. Values are stored in the
+-- units EnvironmentalReading carries (°F, fractional moisture, % RH, mm).
+CREATE TABLE IF NOT EXISTS soil_observations (
+    observed_at TIMESTAMPTZ PRIMARY KEY,
+    station_wbanno INTEGER NOT NULL,
+    soil_temp_5_f DOUBLE PRECISION,
+    soil_temp_10_f DOUBLE PRECISION,
+    soil_temp_20_f DOUBLE PRECISION,
+    soil_temp_50_f DOUBLE PRECISION,
+    soil_temp_100_f DOUBLE PRECISION,
+    soil_moisture_5 DOUBLE PRECISION,
+    soil_moisture_10 DOUBLE PRECISION,
+    soil_moisture_20 DOUBLE PRECISION,
+    soil_moisture_50 DOUBLE PRECISION,
+    soil_moisture_100 DOUBLE PRECISION,
+    ambient_temp_f DOUBLE PRECISION,
+    humidity_percent DOUBLE PRECISION,
+    precipitation_mm DOUBLE PRECISION,
+    synced_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,27 @@
+-- Per-profile data source overrides, for tracking a second property with its own
+-- weather. NULL falls back to the global forecast location (Settings page, else
+-- OWM_LATITUDE/OWM_LONGITUDE) and NOAA_STATION_WBANNO.
+ALTER TABLE lawn_profiles ADD COLUMN IF NOT EXISTS location_name TEXT;
+ALTER TABLE lawn_profiles ADD COLUMN IF NOT EXISTS latitude DOUBLE PRECISION;
+ALTER TABLE lawn_profiles ADD COLUMN IF NOT EXISTS longitude DOUBLE PRECISION;
+ALTER TABLE lawn_profiles ADD COLUMN IF NOT EXISTS noaa_station_wbanno INTEGER;
+
+ALTER TABLE lawn_profiles DROP CONSTRAINT IF EXISTS chk_lawn_profiles_coordinates;
+ALTER TABLE lawn_profiles ADD CONSTRAINT chk_lawn_profiles_coordinates CHECK (
+    (latitude IS NULL) = (longitude IS NULL)
+    AND (latitude IS NULL OR latitude BETWEEN -90 AND 90)
+    AND (longitude IS NULL OR longitude BETWEEN -180 AND 180)
+);
+
+-- Dismissed/addressed state belongs to one lawn, so the same rule on another
+-- profile starts fresh. Rows from before profiles had their own state stay with
+-- the original (lowest id) profile.
+ALTER TABLE recommendation_states
+    ADD COLUMN IF NOT EXISTS lawn_profile_id BIGINT REFERENCES lawn_profiles(id) ON DELETE CASCADE;
+UPDATE recommendation_states
+    SET lawn_profile_id = (SELECT MIN(id) FROM lawn_profiles)
+    WHERE lawn_profile_id IS NULL;
+DELETE FROM recommendation_states WHERE lawn_profile_id IS NULL;
+ALTER TABLE recommendation_states ALTER COLUMN lawn_profile_id SET NOT NULL;
+ALTER TABLE recommendation_states DROP CONSTRAINT IF EXISTS recommendation_states_pkey;
+ALTER TABLE recommendation_states ADD PRIMARY KEY (lawn_profile_id, id);
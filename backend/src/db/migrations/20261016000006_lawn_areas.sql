@@ -0,0 +1,22 @@
+-- Lawn areas: optional sections within a profile (shady backyard, sunny
+-- front strip) that get their own size, sun exposure and grass. Applications
+-- can be tagged to one; untagged applications cover the whole lawn.
+CREATE TABLE IF NOT EXISTS lawn_areas (
+    id BIGSERIAL PRIMARY KEY,
+    lawn_profile_id BIGINT NOT NULL REFERENCES lawn_profiles(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    size_sqft DOUBLE PRECISION CHECK (size_sqft IS NULL OR size_sqft > 0),
+    sun_exposure TEXT NOT NULL DEFAULT 'FullSun'
+        CHECK (sun_exposure IN ('FullSun', 'PartShade', 'FullShade')),
+    grass_type TEXT,
+    grass_mix TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (lawn_profile_id, name)
+);
+CREATE INDEX IF NOT EXISTS idx_lawn_areas_profile ON lawn_areas(lawn_profile_id);
+
+-- Deleting an area turns its applications back into whole-lawn entries
+-- rather than losing the history.
+ALTER TABLE applications
+    ADD COLUMN IF NOT EXISTS area_id BIGINT REFERENCES lawn_areas(id) ON DELETE SET NULL;
+CREATE INDEX IF NOT EXISTS idx_applications_area ON applications(area_id);
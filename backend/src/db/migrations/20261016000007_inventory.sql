@@ -0,0 +1,19 @@
+-- Product inventory: what's on the shelf for each profile. `quantity` is the
+-- amount on hand as of `counted_on`; applications of the product logged since
+-- then (rate x coverage) are subtracted when reading it back.
+CREATE TABLE IF NOT EXISTS inventory_items (
+    id BIGSERIAL PRIMARY KEY,
+    lawn_profile_id BIGINT NOT NULL REFERENCES lawn_profiles(id) ON DELETE CASCADE,
+    product_name TEXT NOT NULL,
+    application_type TEXT,
+    unit TEXT NOT NULL DEFAULT 'Pounds'
+        CHECK (unit IN ('Pounds', 'Ounces', 'Gallons', 'FluidOunces')),
+    package_size DOUBLE PRECISION CHECK (package_size IS NULL OR package_size > 0),
+    quantity DOUBLE PRECISION NOT NULL CHECK (quantity >= 0),
+    counted_on DATE NOT NULL DEFAULT CURRENT_DATE,
+    rate_per_1000sqft DOUBLE PRECISION CHECK (rate_per_1000sqft IS NULL OR rate_per_1000sqft > 0),
+    notes TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (lawn_profile_id, product_name)
+);
+CREATE INDEX IF NOT EXISTS idx_inventory_items_profile ON inventory_items(lawn_profile_id);
@@ -0,0 +1,35 @@
+-- Equipment: mowers, spreaders and sprayers for a profile, with a log of
+-- maintenance done on them. Usage is counted from the applications tagged to
+-- a piece (mowing logs for a mower), so there's no stored hour meter beyond
+-- the reading when it was added.
+CREATE TABLE IF NOT EXISTS equipment (
+    id BIGSERIAL PRIMARY KEY,
+    lawn_profile_id BIGINT NOT NULL REFERENCES lawn_profiles(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    kind TEXT NOT NULL CHECK (kind IN ('Mower', 'Spreader', 'Sprayer', 'Other')),
+    hours_per_use DOUBLE PRECISION CHECK (hours_per_use IS NULL OR hours_per_use > 0),
+    starting_hours DOUBLE PRECISION NOT NULL DEFAULT 0 CHECK (starting_hours >= 0),
+    notes TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (lawn_profile_id, name)
+);
+CREATE INDEX IF NOT EXISTS idx_equipment_profile ON equipment(lawn_profile_id);
+
+CREATE TABLE IF NOT EXISTS equipment_maintenance (
+    id BIGSERIAL PRIMARY KEY,
+    equipment_id BIGINT NOT NULL REFERENCES equipment(id) ON DELETE CASCADE,
+    task TEXT NOT NULL CHECK (task IN (
+        'BladeSharpening', 'OilChange', 'AirFilter', 'SparkPlug',
+        'Winterize', 'Calibration', 'Cleaning', 'Other'
+    )),
+    performed_on DATE NOT NULL DEFAULT CURRENT_DATE,
+    notes TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE INDEX IF NOT EXISTS idx_equipment_maintenance_equipment
+    ON equipment_maintenance(equipment_id, performed_on DESC);
+
+-- Deleting a piece of equipment keeps the applications it was used for.
+ALTER TABLE applications
+    ADD COLUMN IF NOT EXISTS equipment_id BIGINT REFERENCES equipment(id) ON DELETE SET NULL;
+CREATE INDEX IF NOT EXISTS idx_applications_equipment ON applications(equipment_id);
@@ -0,0 +1,4 @@
+-- Weather snapshots rebuilt from station history for back-dated or imported
+-- applications, as opposed to conditions recorded when the entry was made.
+ALTER TABLE applications
+    ADD COLUMN IF NOT EXISTS weather_reconstructed BOOLEAN NOT NULL DEFAULT FALSE;
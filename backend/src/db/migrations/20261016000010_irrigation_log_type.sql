@@ -0,0 +1,11 @@
+-- Irrigation is a log entry (a sprinkler run or hand watering) kept alongside
+-- applications so it shows in history and the calendar.
+ALTER TABLE applications DROP CONSTRAINT IF EXISTS chk_application_type;
+ALTER TABLE applications ADD CONSTRAINT chk_application_type CHECK (
+    application_type IN (
+        'PreEmergent', 'PostEmergent', 'Fertilizer', 'Fungicide', 'Insecticide',
+        'GrubControl', 'Overseed', 'Aeration', 'Dethatching', 'Lime', 'Sulfur',
+        'Wetting', 'Mowing', 'Scouting', 'Irrigation', 'Other',
+        'Pruning', 'PlantFertilizer', 'Mulching', 'Deadheading', 'WinterProtection'
+    )
+);
@@ -0,0 +1,15 @@
+-- Rain checks: a forecast rain event (the kind that triggers Rain Delay and
+-- holds off irrigation) and, once its window has passed, how much the station
+-- actually recorded. A profile has at most one unreconciled check at a time.
+CREATE TABLE IF NOT EXISTS rain_checks (
+    id BIGSERIAL PRIMARY KEY,
+    lawn_profile_id BIGINT NOT NULL REFERENCES lawn_profiles(id) ON DELETE CASCADE,
+    window_start TIMESTAMPTZ NOT NULL,
+    window_end TIMESTAMPTZ NOT NULL CHECK (window_end > window_start),
+    forecast_mm DOUBLE PRECISION NOT NULL CHECK (forecast_mm >= 0),
+    observed_mm DOUBLE PRECISION CHECK (observed_mm IS NULL OR observed_mm >= 0),
+    reconciled_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE INDEX IF NOT EXISTS idx_rain_checks_profile
+    ON rain_checks(lawn_profile_id, window_end DESC);
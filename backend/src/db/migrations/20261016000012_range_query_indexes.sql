@@ -0,0 +1,9 @@
+-- The season report counts recommendation states changed in a date range;
+-- without this the count scans the whole table.
+CREATE INDEX IF NOT EXISTS idx_recommendation_states_updated_at
+    ON recommendation_states(updated_at);
+
+-- Every per-profile application query filters on the profile and orders or
+-- ranges on the date, which idx_applications_profile_date already covers; the
+-- single-column profile index only costs writes.
+DROP INDEX IF EXISTS idx_applications_lawn_profile_id;
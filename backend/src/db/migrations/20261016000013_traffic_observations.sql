@@ -0,0 +1,5 @@
+-- Traffic journal notes (foot traffic, pets, play areas) feed the turf stress
+-- index alongside heat, drought and disease pressure.
+ALTER TABLE observations DROP CONSTRAINT IF EXISTS observations_category_check;
+ALTER TABLE observations ADD CONSTRAINT observations_category_check
+    CHECK (category IN ('Weeds', 'Disease', 'Insect', 'Traffic', 'General'));
@@ -0,0 +1,11 @@
+-- Last severity each recommendation was sent at, so notifications can honor
+-- per-recommendation cooldowns and only repeat on escalation. Rows for
+-- recommendations that are no longer active are cleared, so a condition that
+-- comes back later notifies again.
+CREATE TABLE IF NOT EXISTS notification_log (
+    lawn_profile_id BIGINT NOT NULL REFERENCES lawn_profiles(id) ON DELETE CASCADE,
+    recommendation_id TEXT NOT NULL,
+    severity TEXT NOT NULL CHECK (severity IN ('Info', 'Advisory', 'Warning', 'Critical')),
+    notified_at TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (lawn_profile_id, recommendation_id)
+);
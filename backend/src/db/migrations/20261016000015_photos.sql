@@ -0,0 +1,14 @@
+-- Photo journal: progress shots by file path or name (the images themselves
+-- stay wherever the user keeps them), so the season report and exports can
+-- line each one up with the applications and weather around it.
+CREATE TABLE IF NOT EXISTS photos (
+    id BIGSERIAL PRIMARY KEY,
+    lawn_profile_id BIGINT NOT NULL REFERENCES lawn_profiles(id) ON DELETE CASCADE,
+    area_id BIGINT REFERENCES lawn_areas(id) ON DELETE SET NULL,
+    taken_on DATE NOT NULL,
+    path TEXT NOT NULL CHECK (path <> ''),
+    caption TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE INDEX IF NOT EXISTS idx_photos_profile_taken_on
+    ON photos(lawn_profile_id, taken_on DESC);
@@ -0,0 +1,18 @@
+-- User-defined application types ("Biostimulant", "Topdressing"). Each maps
+-- onto a built-in category, which is what the application row keeps in
+-- application_type so rules, nitrogen math and reports treat it as that
+-- category; the custom type only names and colors the entry.
+CREATE TABLE IF NOT EXISTS custom_application_types (
+    id BIGSERIAL PRIMARY KEY,
+    name TEXT NOT NULL CHECK (name <> ''),
+    color TEXT NOT NULL CHECK (color ~ '^#[0-9a-fA-F]{6}$'),
+    base_type TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE UNIQUE INDEX IF NOT EXISTS idx_custom_application_types_name
+    ON custom_application_types(LOWER(name));
+
+-- Deleting a custom type leaves its applications under the base category.
+ALTER TABLE applications
+    ADD COLUMN IF NOT EXISTS custom_type_id BIGINT
+    REFERENCES custom_application_types(id) ON DELETE SET NULL;
@@ -0,0 +1,4 @@
+-- Manual dormancy toggle per lawn. While set, the rules engine swaps irrigation,
+-- fertilizer and mowing recommendations for a single "lawn is dormant" card,
+-- the same as when soil temperature or dry heat puts the lawn to sleep.
+ALTER TABLE lawn_profiles ADD COLUMN IF NOT EXISTS dormant BOOLEAN NOT NULL DEFAULT FALSE;
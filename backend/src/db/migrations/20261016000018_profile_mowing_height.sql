@@ -0,0 +1,8 @@
+-- Target height of cut (inches) for the one-third rule. NULL uses the grass
+-- type's typical height.
+ALTER TABLE lawn_profiles ADD COLUMN IF NOT EXISTS mowing_height_in DOUBLE PRECISION;
+
+ALTER TABLE lawn_profiles DROP CONSTRAINT IF EXISTS chk_lawn_profiles_mowing_height;
+ALTER TABLE lawn_profiles ADD CONSTRAINT chk_lawn_profiles_mowing_height CHECK (
+    mowing_height_in IS NULL OR mowing_height_in BETWEEN 0.5 AND 6
+);
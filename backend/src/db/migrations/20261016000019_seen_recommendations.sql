@@ -0,0 +1,9 @@
+-- Severity each recommendation had when it was last viewed on a profile, for
+-- "new since last visit" markers
+CREATE TABLE IF NOT EXISTS seen_recommendations (
+    lawn_profile_id BIGINT NOT NULL REFERENCES lawn_profiles(id) ON DELETE CASCADE,
+    id TEXT NOT NULL,
+    severity TEXT NOT NULL CHECK (severity IN ('Info', 'Advisory', 'Warning', 'Critical')),
+    seen_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (lawn_profile_id, id)
+);
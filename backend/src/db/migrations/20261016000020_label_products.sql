@@ -0,0 +1,22 @@
+-- Product label facts imported from a community label file or entered by
+-- hand. Rules check these before the built-in catalog. user_edited marks
+-- rows changed (or added) on the Settings page, which later imports skip.
+CREATE TABLE IF NOT EXISTS label_products (
+    id BIGSERIAL PRIMARY KEY,
+    name TEXT NOT NULL CHECK (name <> ''),
+    active_ingredient TEXT NOT NULL CHECK (active_ingredient <> ''),
+    keywords TEXT[] NOT NULL DEFAULT '{}',
+    frac_group TEXT,
+    irac_group TEXT,
+    rate_per_1000sqft DOUBLE PRECISION CHECK (rate_per_1000sqft >= 0),
+    rate_unit TEXT,
+    water_in_inches DOUBLE PRECISION CHECK (water_in_inches >= 0),
+    water_in_days INTEGER CHECK (water_in_days >= 0),
+    reentry TEXT CHECK (reentry IN ('until_dry', 'after_watering_in')),
+    residual_days INTEGER CHECK (residual_days >= 0),
+    seeded_turf_days INTEGER CHECK (seeded_turf_days >= 0),
+    user_edited BOOLEAN NOT NULL DEFAULT FALSE,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK ((water_in_inches IS NULL) = (water_in_days IS NULL))
+);
+CREATE UNIQUE INDEX IF NOT EXISTS idx_label_products_name ON label_products(LOWER(name));
@@ -0,0 +1,3 @@
+-- Lawn profile location sealed as JSON when DB encryption is on, in place of
+-- location_name/latitude/longitude (which are then left NULL).
+ALTER TABLE lawn_profiles ADD COLUMN IF NOT EXISTS location_sealed TEXT;
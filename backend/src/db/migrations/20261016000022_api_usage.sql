@@ -0,0 +1,7 @@
+-- Calls made to metered APIs per UTC day, counted against their daily quota.
+CREATE TABLE IF NOT EXISTS api_usage (
+    source TEXT NOT NULL,
+    day DATE NOT NULL,
+    calls INTEGER NOT NULL DEFAULT 0,
+    PRIMARY KEY (source, day)
+);
@@ -1,4 +1,23 @@
+pub mod annual_review_queries;
+pub mod api_usage_queries;
+pub mod area_queries;
+pub mod backup_queries;
+pub mod custom_type_queries;
+pub mod encryption;
+pub mod equipment_queries;
+pub mod event_queries;
+pub mod instance_lock;
+pub mod inventory_queries;
+pub mod maintenance_queries;
+pub mod notification_queries;
+pub mod observation_queries;
+pub mod photo_queries;
 pub mod plant_queries;
 pub mod pool;
+pub mod product_queries;
 pub mod queries;
+pub mod rain_check_queries;
+pub mod schema_check;
+pub mod settings_queries;
+pub mod soil_observation_queries;
 pub mod soil_test_queries;
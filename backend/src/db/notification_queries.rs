@@ -0,0 +1,74 @@
+use crate::error::Result;
+use crate::logic::notify::Notified;
+use crate::models::{Recommendation, Severity};
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use std::collections::HashMap;
+use std::str::FromStr;
+use tracing::warn;
+
+/// When each of the profile's recommendations was last sent, and at what severity.
+pub async fn list_notified(pool: &PgPool, profile_id: i64) -> Result<HashMap<String, Notified>> {
+    let rows = sqlx::query_as::<_, (String, String, DateTime<Utc>)>(
+        r#"SELECT recommendation_id, severity, notified_at
+           FROM notification_log
+           WHERE lawn_profile_id = $1"#,
+    )
+    .bind(profile_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .filter_map(|(id, severity, notified_at)| {
+            let severity = Severity::from_str(&severity)
+                .map_err(|e| warn!(recommendation = %id, "Skipping notification log row: {}", e))
+                .ok()?;
+            Some((
+                id,
+                Notified {
+                    severity,
+                    notified_at,
+                },
+            ))
+        })
+        .collect())
+}
+
+/// Record that `sent` went out at `at`, and forget recommendations that are
+/// no longer among `active_ids`.
+pub async fn update_notified(
+    pool: &PgPool,
+    profile_id: i64,
+    sent: &[Recommendation],
+    active_ids: &[&str],
+    at: DateTime<Utc>,
+) -> Result<()> {
+    let mut tx = pool.begin().await?;
+    sqlx::query(
+        "DELETE FROM notification_log WHERE lawn_profile_id = $1 AND NOT (recommendation_id = ANY($2))",
+    )
+    .bind(profile_id)
+    .bind(active_ids)
+    .execute(&mut *tx)
+    .await?;
+    for rec in sent {
+        sqlx::query(
+            r#"
+            INSERT INTO notification_log (lawn_profile_id, recommendation_id, severity, notified_at)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (lawn_profile_id, recommendation_id)
+            DO UPDATE SET severity = EXCLUDED.severity, notified_at = EXCLUDED.notified_at
+            "#,
+        )
+        .bind(profile_id)
+        .bind(&rec.id)
+        .bind(rec.severity.as_str())
+        .bind(at)
+        .execute(&mut *tx)
+        .await?;
+    }
+    tx.commit().await?;
+
+    Ok(())
+}
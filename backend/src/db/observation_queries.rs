@@ -0,0 +1,135 @@
+use crate::db::encryption;
+use crate::error::Result;
+use crate::models::{Observation, ObservationCategory};
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use std::str::FromStr;
+use tracing::warn;
+
+/// List observations newest first, optionally filtered by category.
+pub async fn get_observations_for_profile(
+    pool: &PgPool,
+    profile_id: i64,
+    category: Option<ObservationCategory>,
+    limit: i64,
+    offset: i64,
+) -> Result<Vec<Observation>> {
+    let rows = sqlx::query_as::<_, ObservationRow>(
+        r#"SELECT id, lawn_profile_id, observed_at, category, notes, created_at
+           FROM observations
+           WHERE lawn_profile_id = $1 AND ($2::TEXT IS NULL OR category = $2)
+           ORDER BY observed_at DESC, id DESC
+           LIMIT $3 OFFSET $4"#,
+    )
+    .bind(profile_id)
+    .bind(category.map(|c| c.as_str()))
+    .bind(limit)
+    .bind(offset)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows.into_iter().map(|r| r.into_observation()).collect())
+}
+
+/// Observations with `start <= observed_at < end`, oldest first (for the calendar).
+pub async fn get_observations_in_range(
+    pool: &PgPool,
+    profile_id: i64,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<Vec<Observation>> {
+    let rows = sqlx::query_as::<_, ObservationRow>(
+        r#"SELECT id, lawn_profile_id, observed_at, category, notes, created_at
+           FROM observations
+           WHERE lawn_profile_id = $1 AND observed_at >= $2 AND observed_at < $3
+           ORDER BY observed_at ASC"#,
+    )
+    .bind(profile_id)
+    .bind(start)
+    .bind(end)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows.into_iter().map(|r| r.into_observation()).collect())
+}
+
+pub async fn create_observation(pool: &PgPool, observation: &Observation) -> Result<i64> {
+    let id = sqlx::query_scalar::<_, i64>(
+        r#"
+        INSERT INTO observations (lawn_profile_id, observed_at, category, notes)
+        VALUES ($1, $2, $3, $4)
+        RETURNING id
+        "#,
+    )
+    .bind(observation.lawn_profile_id)
+    .bind(observation.observed_at)
+    .bind(observation.category.as_str())
+    .bind(encryption::seal(&observation.notes)?)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(id)
+}
+
+pub async fn update_observation(
+    pool: &PgPool,
+    id: i64,
+    observation: &Observation,
+) -> Result<Observation> {
+    let row = sqlx::query_as::<_, ObservationRow>(
+        r#"
+        UPDATE observations SET observed_at = $2, category = $3, notes = $4
+        WHERE id = $1
+        RETURNING id, lawn_profile_id, observed_at, category, notes, created_at
+        "#,
+    )
+    .bind(id)
+    .bind(observation.observed_at)
+    .bind(observation.category.as_str())
+    .bind(encryption::seal(&observation.notes)?)
+    .fetch_optional(pool)
+    .await?;
+
+    match row {
+        Some(r) => Ok(r.into_observation()),
+        None => Err(crate::error::TurfOpsError::NotFound(format!(
+            "Observation {id} not found"
+        ))),
+    }
+}
+
+pub async fn delete_observation(pool: &PgPool, id: i64) -> Result<()> {
+    sqlx::query("DELETE FROM observations WHERE id = $1")
+        .bind(id)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+#[derive(sqlx::FromRow)]
+struct ObservationRow {
+    id: i64,
+    lawn_profile_id: i64,
+    observed_at: DateTime<Utc>,
+    category: String,
+    notes: String,
+    created_at: DateTime<Utc>,
+}
+
+impl ObservationRow {
+    fn into_observation(self) -> Observation {
+        let category = ObservationCategory::from_str(&self.category).unwrap_or_else(|_| {
+            warn!(category = %self.category, "Unknown observation category in database, defaulting to General");
+            ObservationCategory::General
+        });
+
+        Observation {
+            id: Some(self.id),
+            lawn_profile_id: self.lawn_profile_id,
+            observed_at: self.observed_at,
+            category,
+            notes: encryption::open(self.notes),
+            created_at: self.created_at,
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use crate::db::encryption;
+use crate::error::{Result, TurfOpsError};
+use crate::models::Photo;
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::PgPool;
+
+/// List photos newest first, optionally only those of one area.
+pub async fn get_photos_for_profile(
+    pool: &PgPool,
+    profile_id: i64,
+    area_id: Option<i64>,
+    limit: i64,
+    offset: i64,
+) -> Result<Vec<Photo>> {
+    let rows = sqlx::query_as::<_, PhotoRow>(
+        r#"SELECT id, lawn_profile_id, area_id, taken_on, path, caption, created_at
+           FROM photos
+           WHERE lawn_profile_id = $1 AND ($2::BIGINT IS NULL OR area_id = $2)
+           ORDER BY taken_on DESC, id DESC
+           LIMIT $3 OFFSET $4"#,
+    )
+    .bind(profile_id)
+    .bind(area_id)
+    .bind(limit)
+    .bind(offset)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows.into_iter().map(PhotoRow::into_photo).collect())
+}
+
+/// Photos with `start <= taken_on < end`, oldest first (for reports).
+pub async fn get_photos_in_range(
+    pool: &PgPool,
+    profile_id: i64,
+    start: NaiveDate,
+    end: NaiveDate,
+) -> Result<Vec<Photo>> {
+    let rows = sqlx::query_as::<_, PhotoRow>(
+        r#"SELECT id, lawn_profile_id, area_id, taken_on, path, caption, created_at
+           FROM photos
+           WHERE lawn_profile_id = $1 AND taken_on >= $2 AND taken_on < $3
+           ORDER BY taken_on ASC, id ASC"#,
+    )
+    .bind(profile_id)
+    .bind(start)
+    .bind(end)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows.into_iter().map(PhotoRow::into_photo).collect())
+}
+
+pub async fn create_photo(pool: &PgPool, photo: &Photo) -> Result<i64> {
+    let id = sqlx::query_scalar::<_, i64>(
+        r#"
+        INSERT INTO photos (lawn_profile_id, area_id, taken_on, path, caption)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING id
+        "#,
+    )
+    .bind(photo.lawn_profile_id)
+    .bind(photo.area_id)
+    .bind(photo.taken_on)
+    .bind(&photo.path)
+    .bind(encryption::seal_opt(photo.caption.as_deref())?)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(id)
+}
+
+pub async fn update_photo(pool: &PgPool, id: i64, photo: &Photo) -> Result<Photo> {
+    let row = sqlx::query_as::<_, PhotoRow>(
+        r#"
+        UPDATE photos SET area_id = $3, taken_on = $4, path = $5, caption = $6
+        WHERE id = $1 AND lawn_profile_id = $2
+        RETURNING id, lawn_profile_id, area_id, taken_on, path, caption, created_at
+        "#,
+    )
+    .bind(id)
+    .bind(photo.lawn_profile_id)
+    .bind(photo.area_id)
+    .bind(photo.taken_on)
+    .bind(&photo.path)
+    .bind(encryption::seal_opt(photo.caption.as_deref())?)
+    .fetch_optional(pool)
+    .await?;
+
+    row.map(PhotoRow::into_photo)
+        .ok_or_else(|| TurfOpsError::NotFound(format!("Photo {id} not found")))
+}
+
+pub async fn delete_photo(pool: &PgPool, profile_id: i64, id: i64) -> Result<()> {
+    sqlx::query("DELETE FROM photos WHERE id = $1 AND lawn_profile_id = $2")
+        .bind(id)
+        .bind(profile_id)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+#[derive(sqlx::FromRow)]
+struct PhotoRow {
+    id: i64,
+    lawn_profile_id: i64,
+    area_id: Option<i64>,
+    taken_on: NaiveDate,
+    path: String,
+    caption: Option<String>,
+    created_at: DateTime<Utc>,
+}
+
+impl PhotoRow {
+    fn into_photo(self) -> Photo {
+        Photo {
+            id: Some(self.id),
+            lawn_profile_id: self.lawn_profile_id,
+            area_id: self.area_id,
+            taken_on: self.taken_on,
+            path: self.path,
+            caption: encryption::open_opt(self.caption),
+            created_at: self.created_at,
+        }
+    }
+}
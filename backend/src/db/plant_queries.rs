@@ -1,3 +1,4 @@
+use crate::db::encryption;
 use crate::error::{Result, TurfOpsError};
 use crate::models::plant::{Plant, PlantMaintenancePlan, PlantType};
 use chrono::{DateTime, NaiveDate, Utc};
@@ -52,7 +53,7 @@ pub async fn create_plant(pool: &PgPool, plant: &Plant) -> Result<i64> {
     .bind(plant.plant_type.as_str())
     .bind(&plant.location)
     .bind(plant.planting_date)
-    .bind(&plant.notes)
+    .bind(encryption::seal_opt(plant.notes.as_deref())?)
     .bind(plan_json)
     .bind(plant.plan_generated_at)
     .bind(&plant.plan_model)
@@ -107,7 +108,7 @@ pub async fn update_plant_metadata(
     .bind(plant_type.as_str())
     .bind(location)
     .bind(planting_date)
-    .bind(notes)
+    .bind(encryption::seal_opt(notes.as_deref())?)
     .bind(id)
     .execute(pool)
     .await?;
@@ -191,7 +192,7 @@ impl PlantRow {
             plant_type,
             location: self.location,
             planting_date: self.planting_date,
-            notes: self.notes,
+            notes: encryption::open_opt(self.notes),
             maintenance_plan,
             plan_generated_at: self.plan_generated_at,
             plan_model: self.plan_model,
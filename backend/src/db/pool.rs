@@ -1,27 +1,71 @@
 use crate::error::Result;
+use sqlx::migrate::Migrator;
 use sqlx::postgres::{PgConnectOptions, PgPoolOptions};
 use sqlx::PgPool;
+use std::str::FromStr;
+use std::time::Duration;
 
-pub async fn create_pool(options: PgConnectOptions) -> Result<PgPool> {
-    let max_conn: u32 = std::env::var("DB_MAX_CONNECTIONS")
+fn env_parse<T: FromStr>(key: &str, default: T) -> T {
+    std::env::var(key)
         .ok()
         .and_then(|s| s.parse().ok())
-        .unwrap_or(10);
+        .unwrap_or(default)
+}
+
+/// The schema migrations built into this binary.
+pub static MIGRATOR: Migrator = sqlx::migrate!("./src/db/migrations");
+
+/// Connect and apply pending migrations.
+pub async fn create_pool(options: PgConnectOptions) -> Result<PgPool> {
+    let pool = connect_pool(options).await?;
 
+    // Run migrations; an index build may legitimately outlast the statement timeout
+    let mut conn = pool.acquire().await?;
+    sqlx::query("SET statement_timeout = 0")
+        .execute(&mut *conn)
+        .await?;
+    MIGRATOR
+        .run(&mut *conn)
+        .await
+        .map_err(|e| crate::error::TurfOpsError::Config(format!("Migration failed: {}", e)))?;
+    sqlx::query("RESET statement_timeout")
+        .execute(&mut *conn)
+        .await?;
+
+    tracing::info!("Database migrations applied");
+    Ok(pool)
+}
+
+/// Background refreshes, the API and the rain-check reconciler share the pool.
+/// A request waits at most `DB_ACQUIRE_TIMEOUT_SECS` for a free (or new)
+/// connection, a statement waits at most `DB_LOCK_TIMEOUT_MS` for a row lock
+/// held by another writer, and runs at most `DB_STATEMENT_TIMEOUT_SECS` (0 for
+/// no limit), so contention or a struggling server surfaces as an error
+/// instead of a hung request.
+///
+/// Doesn't run migrations, so commands can inspect the schema as it is.
+pub async fn connect_pool(options: PgConnectOptions) -> Result<PgPool> {
+    let max_conn: u32 = env_parse("DB_MAX_CONNECTIONS", 10);
+    let acquire_timeout_secs: u64 = env_parse("DB_ACQUIRE_TIMEOUT_SECS", 10);
+    let lock_timeout_ms: u64 = env_parse("DB_LOCK_TIMEOUT_MS", 5000);
+    let statement_timeout_secs: u64 = env_parse("DB_STATEMENT_TIMEOUT_SECS", 60);
+
+    let options = options.options([
+        ("lock_timeout", format!("{}ms", lock_timeout_ms)),
+        ("statement_timeout", format!("{}s", statement_timeout_secs)),
+    ]);
     let pool = PgPoolOptions::new()
         .max_connections(max_conn)
+        .acquire_timeout(Duration::from_secs(acquire_timeout_secs))
         .connect_with(options)
         .await?;
 
-    // Run migrations
-    sqlx::migrate!("./src/db/migrations")
-        .run(&pool)
-        .await
-        .map_err(|e| crate::error::TurfOpsError::Config(format!("Migration failed: {}", e)))?;
-
     tracing::info!(
-        "Database connected and migrations applied (max_connections={})",
-        max_conn
+        "Database connected (max_connections={}, acquire_timeout={}s, lock_timeout={}ms, statement_timeout={}s)",
+        max_conn,
+        acquire_timeout_secs,
+        lock_timeout_ms,
+        statement_timeout_secs
     );
     Ok(pool)
 }
@@ -0,0 +1,214 @@
+use crate::error::{Result, TurfOpsError};
+use crate::models::{LabelEntry, LabelProduct, ReEntry};
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Postgres, Transaction};
+use std::str::FromStr;
+use tracing::warn;
+
+pub async fn list_label_products(pool: &PgPool) -> Result<Vec<LabelProduct>> {
+    let rows = sqlx::query_as::<_, LabelProductRow>(
+        r#"SELECT id, name, active_ingredient, keywords, frac_group, irac_group,
+                  rate_per_1000sqft, rate_unit, water_in_inches, water_in_days, reentry,
+                  residual_days, seeded_turf_days, user_edited, updated_at
+           FROM label_products ORDER BY LOWER(name)"#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows.into_iter().map(|r| r.into_label_product()).collect())
+}
+
+pub async fn get_label_product(pool: &PgPool, id: i64) -> Result<LabelProduct> {
+    let row = sqlx::query_as::<_, LabelProductRow>(
+        r#"SELECT id, name, active_ingredient, keywords, frac_group, irac_group,
+                  rate_per_1000sqft, rate_unit, water_in_inches, water_in_days, reentry,
+                  residual_days, seeded_turf_days, user_edited, updated_at
+           FROM label_products WHERE id = $1"#,
+    )
+    .bind(id)
+    .fetch_optional(pool)
+    .await?;
+
+    row.map(|r| r.into_label_product())
+        .ok_or_else(|| TurfOpsError::NotFound(format!("Product {id} not found")))
+}
+
+/// Add a product entered by hand; it's marked edited so imports keep it.
+pub async fn create_label_product(pool: &PgPool, label: &LabelEntry) -> Result<LabelProduct> {
+    let mut tx = pool.begin().await?;
+    let product = insert(&mut tx, label, true).await?;
+    tx.commit().await?;
+    Ok(product)
+}
+
+/// Save a change made by hand, marking the product edited.
+pub async fn update_label_product(
+    pool: &PgPool,
+    id: i64,
+    label: &LabelEntry,
+) -> Result<LabelProduct> {
+    let mut tx = pool.begin().await?;
+    let product = update(&mut tx, id, label, true).await?;
+    tx.commit().await?;
+    Ok(product)
+}
+
+pub async fn delete_label_product(pool: &PgPool, id: i64) -> Result<()> {
+    sqlx::query("DELETE FROM label_products WHERE id = $1")
+        .bind(id)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+/// Apply an import in one transaction: new products are added and the
+/// listed ones replaced, neither marked edited.
+pub async fn apply_label_import(
+    pool: &PgPool,
+    added: &[LabelEntry],
+    updated: &[(i64, LabelEntry)],
+) -> Result<()> {
+    let mut tx = pool.begin().await?;
+    for label in added {
+        insert(&mut tx, label, false).await?;
+    }
+    for (id, label) in updated {
+        update(&mut tx, *id, label, false).await?;
+    }
+    tx.commit().await?;
+    Ok(())
+}
+
+async fn insert(
+    tx: &mut Transaction<'_, Postgres>,
+    label: &LabelEntry,
+    user_edited: bool,
+) -> Result<LabelProduct> {
+    let row = sqlx::query_as::<_, LabelProductRow>(
+        r#"
+        INSERT INTO label_products
+            (name, active_ingredient, keywords, frac_group, irac_group, rate_per_1000sqft,
+             rate_unit, water_in_inches, water_in_days, reentry, residual_days,
+             seeded_turf_days, user_edited)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+        RETURNING id, name, active_ingredient, keywords, frac_group, irac_group,
+                  rate_per_1000sqft, rate_unit, water_in_inches, water_in_days, reentry,
+                  residual_days, seeded_turf_days, user_edited, updated_at
+        "#,
+    )
+    .bind(&label.name)
+    .bind(&label.active_ingredient)
+    .bind(&label.keywords)
+    .bind(&label.frac_group)
+    .bind(&label.irac_group)
+    .bind(label.rate_per_1000sqft)
+    .bind(&label.rate_unit)
+    .bind(label.water_in_inches)
+    .bind(days(label.water_in_days)?)
+    .bind(label.reentry.map(|r| r.as_str()))
+    .bind(days(label.residual_days)?)
+    .bind(days(label.seeded_turf_days)?)
+    .bind(user_edited)
+    .fetch_one(&mut **tx)
+    .await?;
+
+    Ok(row.into_label_product())
+}
+
+async fn update(
+    tx: &mut Transaction<'_, Postgres>,
+    id: i64,
+    label: &LabelEntry,
+    user_edited: bool,
+) -> Result<LabelProduct> {
+    let row = sqlx::query_as::<_, LabelProductRow>(
+        r#"
+        UPDATE label_products
+           SET name = $2, active_ingredient = $3, keywords = $4, frac_group = $5,
+               irac_group = $6, rate_per_1000sqft = $7, rate_unit = $8,
+               water_in_inches = $9, water_in_days = $10, reentry = $11,
+               residual_days = $12, seeded_turf_days = $13,
+               user_edited = user_edited OR $14, updated_at = NOW()
+         WHERE id = $1
+        RETURNING id, name, active_ingredient, keywords, frac_group, irac_group,
+                  rate_per_1000sqft, rate_unit, water_in_inches, water_in_days, reentry,
+                  residual_days, seeded_turf_days, user_edited, updated_at
+        "#,
+    )
+    .bind(id)
+    .bind(&label.name)
+    .bind(&label.active_ingredient)
+    .bind(&label.keywords)
+    .bind(&label.frac_group)
+    .bind(&label.irac_group)
+    .bind(label.rate_per_1000sqft)
+    .bind(&label.rate_unit)
+    .bind(label.water_in_inches)
+    .bind(days(label.water_in_days)?)
+    .bind(label.reentry.map(|r| r.as_str()))
+    .bind(days(label.residual_days)?)
+    .bind(days(label.seeded_turf_days)?)
+    .bind(user_edited)
+    .fetch_optional(&mut **tx)
+    .await?
+    .ok_or_else(|| TurfOpsError::NotFound(format!("Product {id} not found")))?;
+
+    Ok(row.into_label_product())
+}
+
+fn days(value: Option<i64>) -> Result<Option<i32>> {
+    value
+        .map(|d| {
+            i32::try_from(d).map_err(|_| TurfOpsError::InvalidData(format!("{d} days is too many")))
+        })
+        .transpose()
+}
+
+#[derive(sqlx::FromRow)]
+struct LabelProductRow {
+    id: i64,
+    name: String,
+    active_ingredient: String,
+    keywords: Vec<String>,
+    frac_group: Option<String>,
+    irac_group: Option<String>,
+    rate_per_1000sqft: Option<f64>,
+    rate_unit: Option<String>,
+    water_in_inches: Option<f64>,
+    water_in_days: Option<i32>,
+    reentry: Option<String>,
+    residual_days: Option<i32>,
+    seeded_turf_days: Option<i32>,
+    user_edited: bool,
+    updated_at: DateTime<Utc>,
+}
+
+impl LabelProductRow {
+    fn into_label_product(self) -> LabelProduct {
+        let reentry = self.reentry.as_deref().and_then(|r| {
+            ReEntry::from_str(r)
+                .inspect_err(|_| warn!(reentry = %r, "Unknown product re-entry in database"))
+                .ok()
+        });
+
+        LabelProduct {
+            id: self.id,
+            label: LabelEntry {
+                name: self.name,
+                active_ingredient: self.active_ingredient,
+                keywords: self.keywords,
+                frac_group: self.frac_group,
+                irac_group: self.irac_group,
+                rate_per_1000sqft: self.rate_per_1000sqft,
+                rate_unit: self.rate_unit,
+                water_in_inches: self.water_in_inches,
+                water_in_days: self.water_in_days.map(i64::from),
+                reentry,
+                residual_days: self.residual_days.map(i64::from),
+                seeded_turf_days: self.seeded_turf_days.map(i64::from),
+            },
+            user_edited: self.user_edited,
+            updated_at: self.updated_at,
+        }
+    }
+}
@@ -1,7 +1,8 @@
+use crate::db::{encryption, settings_queries};
 use crate::error::{Result, TurfOpsError};
 use crate::models::{
     seasonal_plan::ThresholdCrossing, Application, ApplicationType, GrassType, IrrigationType,
-    LawnProfile, SoilType, WeatherSnapshot,
+    LawnProfile, Location, Severity, SoilType, WeatherSnapshot,
 };
 use chrono::{DateTime, NaiveDate, Utc};
 use sqlx::PgPool;
@@ -9,7 +10,7 @@ use std::str::FromStr;
 use tracing::warn;
 
 /// Safely convert a Serialize enum variant to its string representation for DB storage.
-fn enum_to_db_string<T: serde::Serialize>(value: T) -> Result<String> {
+pub(crate) fn enum_to_db_string<T: serde::Serialize>(value: T) -> Result<String> {
     let json_val = serde_json::to_value(value)
         .map_err(|e| TurfOpsError::InvalidData(format!("Failed to serialize enum: {}", e)))?;
     json_val
@@ -28,12 +29,23 @@ fn opt_enum_to_db_string<T: serde::Serialize>(value: Option<T>) -> Result<Option
 
 // Lawn Profile Queries
 
+/// With DB encryption on, the location is stored sealed and the plain
+/// location columns left empty.
+fn location_columns(profile: &LawnProfile) -> Result<(Option<&Location>, Option<String>)> {
+    let sealed = encryption::seal_location(profile.location.as_ref())?;
+    let plain = profile.location.as_ref().filter(|_| sealed.is_none());
+    Ok((plain, sealed))
+}
+
 pub async fn create_lawn_profile(pool: &PgPool, profile: &LawnProfile) -> Result<i64> {
+    let (plain, sealed) = location_columns(profile)?;
     let row = sqlx::query_scalar::<_, i64>(
         r#"
         INSERT INTO lawn_profiles
-            (name, grass_type, usda_zone, soil_type, lawn_size_sqft, irrigation_type, created_at, updated_at)
-        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            (name, grass_type, usda_zone, soil_type, lawn_size_sqft, irrigation_type,
+             location_name, latitude, longitude, location_sealed, noaa_station_wbanno,
+             dormant, mowing_height_in, created_at, updated_at)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
         RETURNING id
         "#,
     )
@@ -43,6 +55,13 @@ pub async fn create_lawn_profile(pool: &PgPool, profile: &LawnProfile) -> Result
     .bind(opt_enum_to_db_string(profile.soil_type)?)
     .bind(profile.lawn_size_sqft)
     .bind(opt_enum_to_db_string(profile.irrigation_type)?)
+    .bind(plain.map(|l| &l.name))
+    .bind(plain.map(|l| l.latitude))
+    .bind(plain.map(|l| l.longitude))
+    .bind(&sealed)
+    .bind(profile.noaa_station_wbanno)
+    .bind(profile.dormant)
+    .bind(profile.mowing_height_in)
     .bind(profile.created_at)
     .bind(profile.updated_at)
     .fetch_one(pool)
@@ -51,9 +70,10 @@ pub async fn create_lawn_profile(pool: &PgPool, profile: &LawnProfile) -> Result
     Ok(row)
 }
 
+/// The oldest profile, used when no active profile has been chosen.
 pub async fn get_default_lawn_profile(pool: &PgPool) -> Result<Option<LawnProfile>> {
     let row = sqlx::query_as::<_, LawnProfileRow>(
-        "SELECT id, name, grass_type, usda_zone, soil_type, lawn_size_sqft, irrigation_type, created_at, updated_at FROM lawn_profiles ORDER BY id LIMIT 1",
+        "SELECT id, name, grass_type, usda_zone, soil_type, lawn_size_sqft, irrigation_type, location_name, latitude, longitude, location_sealed, noaa_station_wbanno, dormant, mowing_height_in, created_at, updated_at FROM lawn_profiles ORDER BY id LIMIT 1",
     )
     .fetch_optional(pool)
     .await?;
@@ -61,17 +81,61 @@ pub async fn get_default_lawn_profile(pool: &PgPool) -> Result<Option<LawnProfil
     Ok(row.map(|r| r.into_lawn_profile()))
 }
 
+pub async fn get_lawn_profile(pool: &PgPool, id: i64) -> Result<Option<LawnProfile>> {
+    let row = sqlx::query_as::<_, LawnProfileRow>(
+        "SELECT id, name, grass_type, usda_zone, soil_type, lawn_size_sqft, irrigation_type, location_name, latitude, longitude, location_sealed, noaa_station_wbanno, dormant, mowing_height_in, created_at, updated_at FROM lawn_profiles WHERE id = $1",
+    )
+    .bind(id)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(row.map(|r| r.into_lawn_profile()))
+}
+
+pub async fn list_lawn_profiles(pool: &PgPool) -> Result<Vec<LawnProfile>> {
+    let rows = sqlx::query_as::<_, LawnProfileRow>(
+        "SELECT id, name, grass_type, usda_zone, soil_type, lawn_size_sqft, irrigation_type, location_name, latitude, longitude, location_sealed, noaa_station_wbanno, dormant, mowing_height_in, created_at, updated_at FROM lawn_profiles ORDER BY id",
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows.into_iter().map(|r| r.into_lawn_profile()).collect())
+}
+
+/// The profile selected in Settings, falling back to the oldest one if none was
+/// chosen or the chosen one was deleted.
+pub async fn get_active_lawn_profile(pool: &PgPool) -> Result<Option<LawnProfile>> {
+    if let Some(id) = settings_queries::get_active_profile_id(pool).await? {
+        if let Some(profile) = get_lawn_profile(pool, id).await? {
+            return Ok(Some(profile));
+        }
+    }
+    get_default_lawn_profile(pool).await
+}
+
+pub async fn delete_lawn_profile(pool: &PgPool, id: i64) -> Result<u64> {
+    let result = sqlx::query("DELETE FROM lawn_profiles WHERE id = $1")
+        .bind(id)
+        .execute(pool)
+        .await?;
+
+    Ok(result.rows_affected())
+}
+
 pub async fn update_lawn_profile(pool: &PgPool, profile: &LawnProfile) -> Result<()> {
     let id = profile
         .id
         .ok_or_else(|| TurfOpsError::InvalidData("Profile has no ID".into()))?;
+    let (plain, sealed) = location_columns(profile)?;
 
     sqlx::query(
         r#"
         UPDATE lawn_profiles SET
             name = $1, grass_type = $2, usda_zone = $3, soil_type = $4,
-            lawn_size_sqft = $5, irrigation_type = $6, updated_at = $7
-        WHERE id = $8
+            lawn_size_sqft = $5, irrigation_type = $6, location_name = $7,
+            latitude = $8, longitude = $9, location_sealed = $10, noaa_station_wbanno = $11,
+            dormant = $12, mowing_height_in = $13, updated_at = $14
+        WHERE id = $15
         "#,
     )
     .bind(&profile.name)
@@ -80,6 +144,13 @@ pub async fn update_lawn_profile(pool: &PgPool, profile: &LawnProfile) -> Result
     .bind(opt_enum_to_db_string(profile.soil_type)?)
     .bind(profile.lawn_size_sqft)
     .bind(opt_enum_to_db_string(profile.irrigation_type)?)
+    .bind(plain.map(|l| &l.name))
+    .bind(plain.map(|l| l.latitude))
+    .bind(plain.map(|l| l.longitude))
+    .bind(&sealed)
+    .bind(profile.noaa_station_wbanno)
+    .bind(profile.dormant)
+    .bind(profile.mowing_height_in)
     .bind(Utc::now())
     .bind(id)
     .execute(pool)
@@ -99,8 +170,8 @@ pub async fn get_applications_for_profile(
     let rows = sqlx::query_as::<_, ApplicationRow>(
         r#"SELECT id, lawn_profile_id, application_type, product_name, application_date,
            rate_per_1000sqft, coverage_sqft, notes, soil_temp_10cm_f, ambient_temp_f,
-           humidity_percent, soil_moisture, nitrogen_pct, phosphorus_pct, potassium_pct,
-           plant_id, follow_up_date, created_at
+           humidity_percent, soil_moisture, weather_reconstructed, nitrogen_pct, phosphorus_pct,
+           potassium_pct, plant_id, follow_up_date, area_id, equipment_id, custom_type_id, created_at
            FROM applications WHERE lawn_profile_id = $1 ORDER BY application_date DESC
            LIMIT $2 OFFSET $3"#,
     )
@@ -122,8 +193,8 @@ pub async fn get_applications_for_profile_in_range(
     let rows = sqlx::query_as::<_, ApplicationRow>(
         r#"SELECT id, lawn_profile_id, application_type, product_name, application_date,
            rate_per_1000sqft, coverage_sqft, notes, soil_temp_10cm_f, ambient_temp_f,
-           humidity_percent, soil_moisture, nitrogen_pct, phosphorus_pct, potassium_pct,
-           plant_id, follow_up_date, created_at
+           humidity_percent, soil_moisture, weather_reconstructed, nitrogen_pct, phosphorus_pct,
+           potassium_pct, plant_id, follow_up_date, area_id, equipment_id, custom_type_id, created_at
            FROM applications
            WHERE lawn_profile_id = $1
              AND (
@@ -141,6 +212,32 @@ pub async fn get_applications_for_profile_in_range(
     Ok(rows.into_iter().map(|r| r.into_application()).collect())
 }
 
+/// Applications made in `year`, newest first. Unlike the range query, a
+/// follow-up due in the year doesn't pull in last year's application.
+pub async fn get_applications_made_in_year(
+    pool: &PgPool,
+    profile_id: i64,
+    year: i32,
+) -> Result<Vec<Application>> {
+    let rows = sqlx::query_as::<_, ApplicationRow>(
+        r#"SELECT id, lawn_profile_id, application_type, product_name, application_date,
+           rate_per_1000sqft, coverage_sqft, notes, soil_temp_10cm_f, ambient_temp_f,
+           humidity_percent, soil_moisture, weather_reconstructed, nitrogen_pct, phosphorus_pct,
+           potassium_pct, plant_id, follow_up_date, area_id, equipment_id, custom_type_id, created_at
+           FROM applications
+           WHERE lawn_profile_id = $1
+             AND application_date >= make_date($2, 1, 1)
+             AND application_date < make_date($2 + 1, 1, 1)
+           ORDER BY application_date DESC"#,
+    )
+    .bind(profile_id)
+    .bind(year)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows.into_iter().map(|r| r.into_application()).collect())
+}
+
 pub async fn create_application(pool: &PgPool, app: &Application) -> Result<i64> {
     let weather = &app.weather_snapshot;
     let row = sqlx::query_scalar::<_, i64>(
@@ -149,8 +246,10 @@ pub async fn create_application(pool: &PgPool, app: &Application) -> Result<i64>
             (lawn_profile_id, application_type, product_name, application_date,
              rate_per_1000sqft, coverage_sqft, notes,
              soil_temp_10cm_f, ambient_temp_f, humidity_percent, soil_moisture,
-             nitrogen_pct, phosphorus_pct, potassium_pct, plant_id, follow_up_date)
-        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16)
+             nitrogen_pct, phosphorus_pct, potassium_pct, plant_id, follow_up_date, area_id,
+             equipment_id, weather_reconstructed, custom_type_id)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18,
+                $19, $20)
         RETURNING id
         "#,
     )
@@ -160,7 +259,7 @@ pub async fn create_application(pool: &PgPool, app: &Application) -> Result<i64>
     .bind(app.application_date)
     .bind(app.rate_per_1000sqft)
     .bind(app.coverage_sqft)
-    .bind(&app.notes)
+    .bind(encryption::seal_opt(app.notes.as_deref())?)
     .bind(weather.as_ref().and_then(|w| w.soil_temp_10cm_f))
     .bind(weather.as_ref().and_then(|w| w.ambient_temp_f))
     .bind(weather.as_ref().and_then(|w| w.humidity_percent))
@@ -170,6 +269,10 @@ pub async fn create_application(pool: &PgPool, app: &Application) -> Result<i64>
     .bind(app.potassium_pct)
     .bind(app.plant_id)
     .bind(app.follow_up_date)
+    .bind(app.area_id)
+    .bind(app.equipment_id)
+    .bind(weather.as_ref().is_some_and(|w| w.reconstructed))
+    .bind(app.custom_type_id)
     .fetch_one(pool)
     .await?;
 
@@ -198,7 +301,11 @@ pub async fn update_application(pool: &PgPool, app: &Application) -> Result<u64>
                phosphorus_pct = $13,
                potassium_pct = $14,
                plant_id = $15,
-               follow_up_date = $16
+               follow_up_date = $16,
+               area_id = $17,
+               equipment_id = $18,
+               weather_reconstructed = $19,
+               custom_type_id = $20
          WHERE id = $1
         "#,
     )
@@ -208,7 +315,7 @@ pub async fn update_application(pool: &PgPool, app: &Application) -> Result<u64>
     .bind(app.application_date)
     .bind(app.rate_per_1000sqft)
     .bind(app.coverage_sqft)
-    .bind(&app.notes)
+    .bind(encryption::seal_opt(app.notes.as_deref())?)
     .bind(weather.as_ref().and_then(|w| w.soil_temp_10cm_f))
     .bind(weather.as_ref().and_then(|w| w.ambient_temp_f))
     .bind(weather.as_ref().and_then(|w| w.humidity_percent))
@@ -218,6 +325,10 @@ pub async fn update_application(pool: &PgPool, app: &Application) -> Result<u64>
     .bind(app.potassium_pct)
     .bind(app.plant_id)
     .bind(app.follow_up_date)
+    .bind(app.area_id)
+    .bind(app.equipment_id)
+    .bind(weather.as_ref().is_some_and(|w| w.reconstructed))
+    .bind(app.custom_type_id)
     .execute(pool)
     .await?;
 
@@ -228,8 +339,8 @@ pub async fn get_application_by_id(pool: &PgPool, id: i64) -> Result<Option<Appl
     let row = sqlx::query_as::<_, ApplicationRow>(
         r#"SELECT id, lawn_profile_id, application_type, product_name, application_date,
                   rate_per_1000sqft, coverage_sqft, notes, soil_temp_10cm_f, ambient_temp_f,
-                  humidity_percent, soil_moisture, nitrogen_pct, phosphorus_pct, potassium_pct,
-                  plant_id, follow_up_date, created_at
+                  humidity_percent, soil_moisture, weather_reconstructed, nitrogen_pct,
+                  phosphorus_pct, potassium_pct, plant_id, follow_up_date, area_id, equipment_id, custom_type_id, created_at
            FROM applications WHERE id = $1"#,
     )
     .bind(id)
@@ -248,34 +359,60 @@ pub async fn delete_application(pool: &PgPool, id: i64) -> Result<()> {
 
 // Recommendation State Queries
 
+/// Dismissed/addressed state of a profile's recommendations, by id.
 pub async fn get_recommendation_states(
     pool: &PgPool,
+    profile_id: i64,
 ) -> Result<std::collections::HashMap<String, (bool, bool)>> {
     let rows = sqlx::query_as::<_, (String, bool, bool)>(
-        "SELECT id, dismissed, addressed FROM recommendation_states",
+        "SELECT id, dismissed, addressed FROM recommendation_states WHERE lawn_profile_id = $1",
     )
+    .bind(profile_id)
     .fetch_all(pool)
     .await?;
 
     Ok(rows.into_iter().map(|(id, d, a)| (id, (d, a))).collect())
 }
 
+/// `(addressed, dismissed)` counts for a profile's states last changed in `[start, end)`.
+pub async fn count_recommendation_states_in_range(
+    pool: &PgPool,
+    profile_id: i64,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<(i64, i64)> {
+    let counts = sqlx::query_as::<_, (i64, i64)>(
+        r#"SELECT COUNT(*) FILTER (WHERE addressed), COUNT(*) FILTER (WHERE dismissed AND NOT addressed)
+           FROM recommendation_states
+           WHERE lawn_profile_id = $1 AND updated_at >= $2 AND updated_at < $3"#,
+    )
+    .bind(profile_id)
+    .bind(start)
+    .bind(end)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(counts)
+}
+
 pub async fn upsert_recommendation_state(
     pool: &PgPool,
+    profile_id: i64,
     id: &str,
     dismissed: bool,
     addressed: bool,
 ) -> Result<()> {
     sqlx::query(
         r#"
-        INSERT INTO recommendation_states (id, dismissed, addressed, updated_at)
-        VALUES ($1, $2, $3, NOW())
-        ON CONFLICT (id) DO UPDATE SET
-            dismissed = $2,
-            addressed = $3,
+        INSERT INTO recommendation_states (lawn_profile_id, id, dismissed, addressed, updated_at)
+        VALUES ($1, $2, $3, $4, NOW())
+        ON CONFLICT (lawn_profile_id, id) DO UPDATE SET
+            dismissed = $3,
+            addressed = $4,
             updated_at = NOW()
         "#,
     )
+    .bind(profile_id)
     .bind(id)
     .bind(dismissed)
     .bind(addressed)
@@ -285,6 +422,52 @@ pub async fn upsert_recommendation_state(
     Ok(())
 }
 
+/// Severity each of a profile's recommendations had when last viewed, by id.
+pub async fn get_seen_recommendations(
+    pool: &PgPool,
+    profile_id: i64,
+) -> Result<std::collections::HashMap<String, Severity>> {
+    let rows = sqlx::query_as::<_, (String, String)>(
+        "SELECT id, severity FROM seen_recommendations WHERE lawn_profile_id = $1",
+    )
+    .bind(profile_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .filter_map(|(id, severity)| Some((id, severity.parse().ok()?)))
+        .collect())
+}
+
+/// Record a profile's recommendations as viewed at the given severities.
+pub async fn mark_recommendations_seen(
+    pool: &PgPool,
+    profile_id: i64,
+    seen: &[(String, Severity)],
+) -> Result<()> {
+    let mut tx = pool.begin().await?;
+    for (id, severity) in seen {
+        sqlx::query(
+            r#"
+            INSERT INTO seen_recommendations (lawn_profile_id, id, severity, seen_at)
+            VALUES ($1, $2, $3, NOW())
+            ON CONFLICT (lawn_profile_id, id) DO UPDATE SET
+                severity = $3,
+                seen_at = NOW()
+            "#,
+        )
+        .bind(profile_id)
+        .bind(id)
+        .bind(severity.as_str())
+        .execute(&mut *tx)
+        .await?;
+    }
+    tx.commit().await?;
+
+    Ok(())
+}
+
 // Row types for sqlx mapping
 
 #[derive(sqlx::FromRow)]
@@ -296,6 +479,13 @@ struct LawnProfileRow {
     soil_type: Option<String>,
     lawn_size_sqft: Option<f64>,
     irrigation_type: Option<String>,
+    location_name: Option<String>,
+    latitude: Option<f64>,
+    longitude: Option<f64>,
+    location_sealed: Option<String>,
+    noaa_station_wbanno: Option<i32>,
+    dormant: bool,
+    mowing_height_in: Option<f64>,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
 }
@@ -330,6 +520,21 @@ impl LawnProfileRow {
             soil_type,
             lawn_size_sqft: self.lawn_size_sqft,
             irrigation_type,
+            location: match self.location_sealed {
+                Some(sealed) => encryption::open_location(&sealed),
+                None => self
+                    .latitude
+                    .zip(self.longitude)
+                    .map(|(latitude, longitude)| Location {
+                        name: self.location_name.unwrap_or_default(),
+                        latitude,
+                        longitude,
+                        usda_zone: None,
+                    }),
+            },
+            noaa_station_wbanno: self.noaa_station_wbanno,
+            dormant: self.dormant,
+            mowing_height_in: self.mowing_height_in,
             created_at: self.created_at,
             updated_at: self.updated_at,
         }
@@ -350,11 +555,15 @@ struct ApplicationRow {
     ambient_temp_f: Option<f64>,
     humidity_percent: Option<f64>,
     soil_moisture: Option<f64>,
+    weather_reconstructed: bool,
     nitrogen_pct: Option<f64>,
     phosphorus_pct: Option<f64>,
     potassium_pct: Option<f64>,
     plant_id: Option<i64>,
     follow_up_date: Option<NaiveDate>,
+    area_id: Option<i64>,
+    equipment_id: Option<i64>,
+    custom_type_id: Option<i64>,
     created_at: DateTime<Utc>,
 }
 
@@ -370,6 +579,7 @@ impl ApplicationRow {
                 ambient_temp_f: self.ambient_temp_f,
                 humidity_percent: self.humidity_percent,
                 soil_moisture: self.soil_moisture,
+                reconstructed: self.weather_reconstructed,
             })
         } else {
             None
@@ -392,13 +602,16 @@ impl ApplicationRow {
             application_date: self.application_date,
             rate_per_1000sqft: self.rate_per_1000sqft,
             coverage_sqft: self.coverage_sqft,
-            notes: self.notes,
+            notes: encryption::open_opt(self.notes),
             weather_snapshot: weather,
             nitrogen_pct: self.nitrogen_pct,
             phosphorus_pct: self.phosphorus_pct,
             potassium_pct: self.potassium_pct,
             plant_id: self.plant_id,
             follow_up_date: self.follow_up_date,
+            area_id: self.area_id,
+            equipment_id: self.equipment_id,
+            custom_type_id: self.custom_type_id,
             created_at: self.created_at,
         }
     }
@@ -0,0 +1,124 @@
+use crate::error::Result;
+use crate::models::{RainCheck, RainWindow};
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+/// The profile's unreconciled check, if any.
+pub async fn get_pending_rain_check(pool: &PgPool, profile_id: i64) -> Result<Option<RainCheck>> {
+    let row = sqlx::query_as::<_, RainCheckRow>(
+        r#"SELECT id, lawn_profile_id, window_start, window_end, forecast_mm, observed_mm, reconciled_at
+           FROM rain_checks
+           WHERE lawn_profile_id = $1 AND reconciled_at IS NULL
+           ORDER BY window_start
+           LIMIT 1"#,
+    )
+    .bind(profile_id)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(row.map(RainCheckRow::into_rain_check))
+}
+
+/// Reconciled checks whose window ended at or after `since`, newest first.
+pub async fn list_reconciled_rain_checks(
+    pool: &PgPool,
+    profile_id: i64,
+    since: DateTime<Utc>,
+) -> Result<Vec<RainCheck>> {
+    let rows = sqlx::query_as::<_, RainCheckRow>(
+        r#"SELECT id, lawn_profile_id, window_start, window_end, forecast_mm, observed_mm, reconciled_at
+           FROM rain_checks
+           WHERE lawn_profile_id = $1 AND reconciled_at IS NOT NULL AND window_end >= $2
+           ORDER BY window_end DESC"#,
+    )
+    .bind(profile_id)
+    .bind(since)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(RainCheckRow::into_rain_check)
+        .collect())
+}
+
+pub async fn create_rain_check(pool: &PgPool, profile_id: i64, window: &RainWindow) -> Result<i64> {
+    let id = sqlx::query_scalar::<_, i64>(
+        r#"
+        INSERT INTO rain_checks (lawn_profile_id, window_start, window_end, forecast_mm)
+        VALUES ($1, $2, $3, $4)
+        RETURNING id
+        "#,
+    )
+    .bind(profile_id)
+    .bind(window.start)
+    .bind(window.end)
+    .bind(window.forecast_mm)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(id)
+}
+
+pub async fn update_rain_check_window(pool: &PgPool, id: i64, window: &RainWindow) -> Result<()> {
+    sqlx::query(
+        r#"UPDATE rain_checks SET window_start = $2, window_end = $3, forecast_mm = $4
+           WHERE id = $1"#,
+    )
+    .bind(id)
+    .bind(window.start)
+    .bind(window.end)
+    .bind(window.forecast_mm)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Record what fell (or `None` when giving up for lack of station data).
+pub async fn reconcile_rain_check(pool: &PgPool, id: i64, observed_mm: Option<f64>) -> Result<()> {
+    sqlx::query(
+        r#"UPDATE rain_checks SET observed_mm = $2, reconciled_at = NOW()
+           WHERE id = $1"#,
+    )
+    .bind(id)
+    .bind(observed_mm)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+pub async fn delete_rain_check(pool: &PgPool, id: i64) -> Result<()> {
+    sqlx::query("DELETE FROM rain_checks WHERE id = $1")
+        .bind(id)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+#[derive(sqlx::FromRow)]
+struct RainCheckRow {
+    id: i64,
+    lawn_profile_id: i64,
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+    forecast_mm: f64,
+    observed_mm: Option<f64>,
+    reconciled_at: Option<DateTime<Utc>>,
+}
+
+impl RainCheckRow {
+    fn into_rain_check(self) -> RainCheck {
+        RainCheck {
+            id: Some(self.id),
+            lawn_profile_id: self.lawn_profile_id,
+            window_start: self.window_start,
+            window_end: self.window_end,
+            forecast_mm: self.forecast_mm,
+            observed_mm: self.observed_mm,
+            reconciled_at: self.reconciled_at,
+        }
+    }
+}
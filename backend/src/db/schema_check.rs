@@ -0,0 +1,168 @@
+//! Index coverage check. Lists the indexes the app's filtered and ordered
+//! queries rely on and reports any the live database lacks, e.g. after a
+//! manual restore or a migration applied out of band. Run with
+//! `turfops-backend check-schema`.
+
+use crate::error::Result;
+use sqlx::PgPool;
+
+/// An index the app's queries rely on: `columns` must be the leading key
+/// columns of some index on `table`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExpectedIndex {
+    pub table: &'static str,
+    pub columns: &'static [&'static str],
+    /// The query pattern it serves.
+    pub purpose: &'static str,
+}
+
+const fn expect(
+    table: &'static str,
+    columns: &'static [&'static str],
+    purpose: &'static str,
+) -> ExpectedIndex {
+    ExpectedIndex {
+        table,
+        columns,
+        purpose,
+    }
+}
+
+pub const EXPECTED_INDEXES: &[ExpectedIndex] = &[
+    expect(
+        "applications",
+        &["lawn_profile_id", "application_date"],
+        "profile history by date",
+    ),
+    expect(
+        "applications",
+        &["application_date"],
+        "applications in a date range",
+    ),
+    expect(
+        "recommendation_states",
+        &["updated_at"],
+        "states changed in a date range",
+    ),
+    expect(
+        "observations",
+        &["lawn_profile_id", "observed_at"],
+        "profile observations by time",
+    ),
+    expect(
+        "soil_observations",
+        &["observed_at"],
+        "synced readings in a time range",
+    ),
+    expect(
+        "rain_checks",
+        &["lawn_profile_id", "window_end"],
+        "recent rain checks",
+    ),
+    expect(
+        "soil_tests",
+        &["lawn_profile_id", "test_date"],
+        "latest soil test",
+    ),
+    expect("events", &["occurred_at"], "event log, newest first"),
+    expect("events", &["kind", "occurred_at"], "event log by kind"),
+    expect(
+        "equipment_maintenance",
+        &["equipment_id", "performed_on"],
+        "maintenance history",
+    ),
+    expect(
+        "annual_reviews",
+        &["lawn_profile_id", "year"],
+        "review by year",
+    ),
+    expect(
+        "photos",
+        &["lawn_profile_id", "taken_on"],
+        "photo journal by date",
+    ),
+    expect("plants", &["lawn_profile_id"], "profile plants"),
+    expect("lawn_areas", &["lawn_profile_id"], "profile areas"),
+    expect("inventory_items", &["lawn_profile_id"], "profile inventory"),
+    expect("equipment", &["lawn_profile_id"], "profile equipment"),
+];
+
+/// An index in the database: its table and key columns in order.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct ExistingIndex {
+    pub table_name: String,
+    pub columns: Vec<String>,
+}
+
+/// Key columns of every index in the current schema.
+pub async fn list_indexes(pool: &PgPool) -> Result<Vec<ExistingIndex>> {
+    let rows = sqlx::query_as::<_, ExistingIndex>(
+        r#"SELECT t.relname::TEXT AS table_name,
+                  ARRAY_AGG(a.attname::TEXT ORDER BY k.ord) AS columns
+           FROM pg_index i
+           JOIN pg_class t ON t.oid = i.indrelid
+           JOIN pg_namespace n ON n.oid = t.relnamespace
+           CROSS JOIN LATERAL UNNEST(i.indkey) WITH ORDINALITY AS k(attnum, ord)
+           JOIN pg_attribute a ON a.attrelid = t.oid AND a.attnum = k.attnum
+           WHERE n.nspname = CURRENT_SCHEMA()
+           GROUP BY i.indexrelid, t.relname"#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+/// Expected indexes no existing index covers. An index covers an expectation
+/// when the expected columns are its leading key columns, in order.
+pub fn missing_indexes(existing: &[ExistingIndex]) -> Vec<ExpectedIndex> {
+    EXPECTED_INDEXES
+        .iter()
+        .filter(|expected| {
+            !existing.iter().any(|index| {
+                index.table_name == expected.table
+                    && index.columns.len() >= expected.columns.len()
+                    && index
+                        .columns
+                        .iter()
+                        .zip(expected.columns)
+                        .all(|(have, want)| have == want)
+            })
+        })
+        .copied()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn index(table: &str, columns: &[&str]) -> ExistingIndex {
+        ExistingIndex {
+            table_name: table.to_string(),
+            columns: columns.iter().map(|c| c.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn leading_columns_cover_an_expectation() {
+        let all: Vec<_> = EXPECTED_INDEXES
+            .iter()
+            .map(|e| index(e.table, e.columns))
+            .collect();
+        assert!(missing_indexes(&all).is_empty());
+
+        // A composite index covers its own prefix, not a suffix
+        let mut existing: Vec<_> = all
+            .into_iter()
+            .filter(|i| i.table_name != "applications")
+            .collect();
+        existing.push(index(
+            "applications",
+            &["lawn_profile_id", "application_date", "id"],
+        ));
+        let missing = missing_indexes(&existing);
+        assert_eq!(missing.len(), 1);
+        assert_eq!(missing[0].columns, ["application_date"]);
+    }
+}
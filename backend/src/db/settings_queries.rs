@@ -0,0 +1,120 @@
+use crate::db::encryption;
+use crate::error::Result;
+use crate::logic::rules::posture::RiskPosture;
+use crate::models::{DashboardLayout, Location, UiState};
+use sqlx::PgPool;
+
+/// Settings key holding the JSON array of rule names disabled from the UI.
+const DISABLED_RULES_KEY: &str = "rules.disabled";
+
+/// Settings key holding the JSON risk posture chosen on the Rules settings tab.
+const RISK_POSTURE_KEY: &str = "rules.posture";
+
+/// Settings key holding the JSON forecast location chosen on the Settings page.
+const LOCATION_KEY: &str = "location";
+
+/// Settings key holding the id of the lawn profile the app is showing.
+const ACTIVE_PROFILE_KEY: &str = "profile.active";
+
+/// Settings key holding the JSON web UI state restored on the next visit.
+const UI_STATE_KEY: &str = "ui.state";
+
+/// Settings key holding the JSON Dashboard layout chosen on the Settings page.
+const DASHBOARD_LAYOUT_KEY: &str = "dashboard.layout";
+
+pub async fn get_setting(pool: &PgPool, key: &str) -> Result<Option<String>> {
+    let value = sqlx::query_scalar::<_, String>("SELECT value FROM settings WHERE key = $1")
+        .bind(key)
+        .fetch_optional(pool)
+        .await?;
+
+    Ok(value)
+}
+
+pub async fn set_setting(pool: &PgPool, key: &str, value: &str) -> Result<()> {
+    sqlx::query(
+        r#"
+        INSERT INTO settings (key, value)
+        VALUES ($1, $2)
+        ON CONFLICT (key) DO UPDATE SET value = $2
+        "#,
+    )
+    .bind(key)
+    .bind(value)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Rule names the user switched off on the Rules settings tab.
+pub async fn get_disabled_rules(pool: &PgPool) -> Result<Vec<String>> {
+    match get_setting(pool, DISABLED_RULES_KEY).await? {
+        Some(json) => Ok(serde_json::from_str(&json)?),
+        None => Ok(Vec::new()),
+    }
+}
+
+pub async fn set_disabled_rules(pool: &PgPool, names: &[String]) -> Result<()> {
+    set_setting(pool, DISABLED_RULES_KEY, &serde_json::to_string(names)?).await
+}
+
+/// Risk posture from the Rules settings tab; Standard until one is saved.
+pub async fn get_risk_posture(pool: &PgPool) -> Result<RiskPosture> {
+    match get_setting(pool, RISK_POSTURE_KEY).await? {
+        Some(json) => Ok(serde_json::from_str(&json)?),
+        None => Ok(RiskPosture::default()),
+    }
+}
+
+pub async fn set_risk_posture(pool: &PgPool, posture: RiskPosture) -> Result<()> {
+    set_setting(pool, RISK_POSTURE_KEY, &serde_json::to_string(&posture)?).await
+}
+
+/// Forecast location saved from the Settings page; overrides `OWM_LATITUDE`/`OWM_LONGITUDE`.
+pub async fn get_location(pool: &PgPool) -> Result<Option<Location>> {
+    match get_setting(pool, LOCATION_KEY).await? {
+        Some(json) => Ok(Some(serde_json::from_str(&encryption::open(json))?)),
+        None => Ok(None),
+    }
+}
+
+pub async fn set_location(pool: &PgPool, location: &Location) -> Result<()> {
+    let json = serde_json::to_string(location)?;
+    set_setting(pool, LOCATION_KEY, &encryption::seal(&json)?).await
+}
+
+/// Profile chosen on the Settings page; unset means the oldest profile.
+pub async fn get_active_profile_id(pool: &PgPool) -> Result<Option<i64>> {
+    Ok(get_setting(pool, ACTIVE_PROFILE_KEY)
+        .await?
+        .and_then(|v| v.parse().ok()))
+}
+
+pub async fn set_active_profile_id(pool: &PgPool, id: i64) -> Result<()> {
+    set_setting(pool, ACTIVE_PROFILE_KEY, &id.to_string()).await
+}
+
+/// Last screen, filters and selections from the web UI; empty until first saved.
+pub async fn get_ui_state(pool: &PgPool) -> Result<UiState> {
+    match get_setting(pool, UI_STATE_KEY).await? {
+        Some(json) => Ok(serde_json::from_str(&json)?),
+        None => Ok(UiState::default()),
+    }
+}
+
+pub async fn set_ui_state(pool: &PgPool, state: &UiState) -> Result<()> {
+    set_setting(pool, UI_STATE_KEY, &serde_json::to_string(state)?).await
+}
+
+/// Dashboard panels and their order from the Settings page; the default until saved.
+pub async fn get_dashboard_layout(pool: &PgPool) -> Result<DashboardLayout> {
+    match get_setting(pool, DASHBOARD_LAYOUT_KEY).await? {
+        Some(json) => Ok(serde_json::from_str(&json)?),
+        None => Ok(DashboardLayout::default()),
+    }
+}
+
+pub async fn set_dashboard_layout(pool: &PgPool, layout: &DashboardLayout) -> Result<()> {
+    set_setting(pool, DASHBOARD_LAYOUT_KEY, &serde_json::to_string(layout)?).await
+}
@@ -0,0 +1,163 @@
+use crate::error::Result;
+use crate::models::{DataSource, EnvironmentalReading};
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use std::collections::BTreeMap;
+
+/// Timestamp of the newest synced observation — the incremental sync's watermark.
+pub async fn latest_observed_at(pool: &PgPool) -> Result<Option<DateTime<Utc>>> {
+    let ts = sqlx::query_scalar::<_, Option<DateTime<Utc>>>(
+        "SELECT MAX(observed_at) FROM soil_observations",
+    )
+    .fetch_one(pool)
+    .await?;
+
+    Ok(ts)
+}
+
+/// Rows per INSERT statement; each column goes over as one array parameter.
+const INSERT_BATCH_ROWS: usize = 1000;
+
+/// Write hourly readings from `station` in one transaction, a batch of rows per
+/// statement. An hour that is already stored is replaced, so a re-fetched range
+/// picks up lake corrections. Returns the number of rows written.
+pub async fn upsert_readings(
+    pool: &PgPool,
+    station: i32,
+    readings: &[EnvironmentalReading],
+) -> Result<u64> {
+    // One row per hour: Postgres refuses to update the same row twice in a statement
+    let mut by_hour: BTreeMap<DateTime<Utc>, &EnvironmentalReading> = BTreeMap::new();
+    for r in readings {
+        by_hour.insert(r.timestamp, r);
+    }
+    let rows: Vec<_> = by_hour.into_values().collect();
+
+    let mut tx = pool.begin().await?;
+    let mut written = 0;
+
+    for batch in rows.chunks(INSERT_BATCH_ROWS) {
+        let column = |f: fn(&EnvironmentalReading) -> Option<f64>| -> Vec<Option<f64>> {
+            batch.iter().map(|r| f(r)).collect()
+        };
+        let result = sqlx::query(
+            r#"
+            INSERT INTO soil_observations (
+                observed_at, station_wbanno,
+                soil_temp_5_f, soil_temp_10_f, soil_temp_20_f, soil_temp_50_f, soil_temp_100_f,
+                soil_moisture_5, soil_moisture_10, soil_moisture_20, soil_moisture_50, soil_moisture_100,
+                ambient_temp_f, humidity_percent, precipitation_mm
+            )
+            SELECT t.observed_at, $2, t.st5, t.st10, t.st20, t.st50, t.st100,
+                   t.sm5, t.sm10, t.sm20, t.sm50, t.sm100, t.ambient, t.humidity, t.precip
+            FROM UNNEST(
+                $1::timestamptz[],
+                $3::float8[], $4::float8[], $5::float8[], $6::float8[], $7::float8[],
+                $8::float8[], $9::float8[], $10::float8[], $11::float8[], $12::float8[],
+                $13::float8[], $14::float8[], $15::float8[]
+            ) AS t(observed_at, st5, st10, st20, st50, st100,
+                   sm5, sm10, sm20, sm50, sm100, ambient, humidity, precip)
+            ON CONFLICT (observed_at) DO UPDATE SET
+                station_wbanno = EXCLUDED.station_wbanno,
+                soil_temp_5_f = EXCLUDED.soil_temp_5_f,
+                soil_temp_10_f = EXCLUDED.soil_temp_10_f,
+                soil_temp_20_f = EXCLUDED.soil_temp_20_f,
+                soil_temp_50_f = EXCLUDED.soil_temp_50_f,
+                soil_temp_100_f = EXCLUDED.soil_temp_100_f,
+                soil_moisture_5 = EXCLUDED.soil_moisture_5,
+                soil_moisture_10 = EXCLUDED.soil_moisture_10,
+                soil_moisture_20 = EXCLUDED.soil_moisture_20,
+                soil_moisture_50 = EXCLUDED.soil_moisture_50,
+                soil_moisture_100 = EXCLUDED.soil_moisture_100,
+                ambient_temp_f = EXCLUDED.ambient_temp_f,
+                humidity_percent = EXCLUDED.humidity_percent,
+                precipitation_mm = EXCLUDED.precipitation_mm
+            "#,
+        )
+        .bind(batch.iter().map(|r| r.timestamp).collect::<Vec<_>>())
+        .bind(station)
+        .bind(column(|r| r.soil_temp_5_f))
+        .bind(column(|r| r.soil_temp_10_f))
+        .bind(column(|r| r.soil_temp_20_f))
+        .bind(column(|r| r.soil_temp_50_f))
+        .bind(column(|r| r.soil_temp_100_f))
+        .bind(column(|r| r.soil_moisture_5))
+        .bind(column(|r| r.soil_moisture_10))
+        .bind(column(|r| r.soil_moisture_20))
+        .bind(column(|r| r.soil_moisture_50))
+        .bind(column(|r| r.soil_moisture_100))
+        .bind(column(|r| r.ambient_temp_f))
+        .bind(column(|r| r.humidity_percent))
+        .bind(column(|r| r.precipitation_mm))
+        .execute(&mut *tx)
+        .await?;
+        written += result.rows_affected();
+    }
+
+    tx.commit().await?;
+    Ok(written)
+}
+
+/// Synced readings in [start, end], newest first (same ordering as the lake client).
+pub async fn get_readings_in_range(
+    pool: &PgPool,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<Vec<EnvironmentalReading>> {
+    let rows = sqlx::query_as::<_, SoilObservationRow>(
+        r#"SELECT observed_at,
+                  soil_temp_5_f, soil_temp_10_f, soil_temp_20_f, soil_temp_50_f, soil_temp_100_f,
+                  soil_moisture_5, soil_moisture_10, soil_moisture_20, soil_moisture_50, soil_moisture_100,
+                  ambient_temp_f, humidity_percent, precipitation_mm
+           FROM soil_observations
+           WHERE observed_at >= $1 AND observed_at <= $2
+           ORDER BY observed_at DESC"#,
+    )
+    .bind(start)
+    .bind(end)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows.into_iter().map(|r| r.into_reading()).collect())
+}
+
+#[derive(sqlx::FromRow)]
+struct SoilObservationRow {
+    observed_at: DateTime<Utc>,
+    soil_temp_5_f: Option<f64>,
+    soil_temp_10_f: Option<f64>,
+    soil_temp_20_f: Option<f64>,
+    soil_temp_50_f: Option<f64>,
+    soil_temp_100_f: Option<f64>,
+    soil_moisture_5: Option<f64>,
+    soil_moisture_10: Option<f64>,
+    soil_moisture_20: Option<f64>,
+    soil_moisture_50: Option<f64>,
+    soil_moisture_100: Option<f64>,
+    ambient_temp_f: Option<f64>,
+    humidity_percent: Option<f64>,
+    precipitation_mm: Option<f64>,
+}
+
+impl SoilObservationRow {
+    fn into_reading(self) -> EnvironmentalReading {
+        EnvironmentalReading {
+            timestamp: self.observed_at,
+            source: DataSource::SoilData,
+            soil_temp_5_f: self.soil_temp_5_f,
+            soil_temp_10_f: self.soil_temp_10_f,
+            soil_temp_20_f: self.soil_temp_20_f,
+            soil_temp_50_f: self.soil_temp_50_f,
+            soil_temp_100_f: self.soil_temp_100_f,
+            soil_moisture_5: self.soil_moisture_5,
+            soil_moisture_10: self.soil_moisture_10,
+            soil_moisture_20: self.soil_moisture_20,
+            soil_moisture_50: self.soil_moisture_50,
+            soil_moisture_100: self.soil_moisture_100,
+            ambient_temp_f: self.ambient_temp_f,
+            humidity_percent: self.humidity_percent,
+            precipitation_mm: self.precipitation_mm,
+            suspect: false,
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use crate::db::encryption;
 use crate::error::Result;
 use crate::models::SoilTest;
 use chrono::{DateTime, NaiveDate, Utc};
@@ -72,7 +73,7 @@ pub async fn create_soil_test(pool: &PgPool, test: &SoilTest) -> Result<i64> {
     .bind(test.copper_ppm)
     .bind(test.organic_matter_pct)
     .bind(test.cec)
-    .bind(&test.notes)
+    .bind(encryption::seal_opt(test.notes.as_deref())?)
     .fetch_one(pool)
     .await?;
 
@@ -111,7 +112,7 @@ pub async fn update_soil_test(pool: &PgPool, id: i64, test: &SoilTest) -> Result
     .bind(test.copper_ppm)
     .bind(test.organic_matter_pct)
     .bind(test.cec)
-    .bind(&test.notes)
+    .bind(encryption::seal_opt(test.notes.as_deref())?)
     .fetch_optional(pool)
     .await?;
 
@@ -176,7 +177,7 @@ impl SoilTestRow {
             copper_ppm: self.copper_ppm,
             organic_matter_pct: self.organic_matter_pct,
             cec: self.cec,
-            notes: self.notes,
+            notes: encryption::open_opt(self.notes),
             created_at: self.created_at,
         }
     }
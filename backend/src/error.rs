@@ -22,9 +22,18 @@ pub enum TurfOpsError {
     #[error("JSON parsing error: {0}")]
     Json(#[from] serde_json::Error),
 
+    #[error("Email error: {0}")]
+    Email(String),
+
+    #[error("Encryption error: {0}")]
+    Encryption(String),
+
     #[error("Data source unavailable: {0}")]
     DataSourceUnavailable(String),
 
+    #[error("Timed out: {0}")]
+    Timeout(String),
+
     #[error("Invalid data: {0}")]
     InvalidData(String),
 
@@ -34,6 +43,57 @@ pub enum TurfOpsError {
 
 pub type Result<T> = std::result::Result<T, TurfOpsError>;
 
+/// Full text of a server error, attached to the response so the error-logging
+/// middleware can record what the client-facing message hides.
+#[derive(Debug, Clone)]
+pub struct ErrorDetail(pub String);
+
+/// Postgres `lock_not_available`, raised when `lock_timeout` expires.
+const LOCK_NOT_AVAILABLE: &str = "55P03";
+
+/// Postgres `query_canceled`, raised when `statement_timeout` expires.
+const QUERY_CANCELED: &str = "57014";
+
+impl TurfOpsError {
+    /// The database was too contended to serve the request in time: no pool
+    /// connection freed up, or a row lock wasn't released. Worth retrying.
+    pub fn is_busy(&self) -> bool {
+        match self {
+            TurfOpsError::Database(sqlx::Error::PoolTimedOut) => true,
+            TurfOpsError::Database(sqlx::Error::Database(e)) => {
+                e.code().as_deref() == Some(LOCK_NOT_AVAILABLE)
+            }
+            _ => false,
+        }
+    }
+
+    /// A data source or the database didn't answer within its configured
+    /// timeout (`HTTP_TIMEOUT_SECS`, `SOILDATA_TIMEOUT_SECS`,
+    /// `DB_STATEMENT_TIMEOUT_SECS`).
+    pub fn is_timeout(&self) -> bool {
+        match self {
+            TurfOpsError::Timeout(_) => true,
+            TurfOpsError::Database(sqlx::Error::Database(e)) => {
+                e.code().as_deref() == Some(QUERY_CANCELED)
+            }
+            _ => false,
+        }
+    }
+
+    /// Machine-readable category sent alongside the message, so the UI can tell
+    /// an expected gap (data source not configured) from a real failure.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            TurfOpsError::NotFound(_) => "not_found",
+            TurfOpsError::InvalidData(_) => "invalid_data",
+            TurfOpsError::DataSourceUnavailable(_) => "data_source_unavailable",
+            e if e.is_timeout() => "timeout",
+            e if e.is_busy() => "database_busy",
+            _ => "internal",
+        }
+    }
+}
+
 impl IntoResponse for TurfOpsError {
     fn into_response(self) -> Response {
         let (status, message) = match &self {
@@ -49,6 +109,21 @@ impl IntoResponse for TurfOpsError {
             TurfOpsError::DataSourceUnavailable(msg) => {
                 (StatusCode::SERVICE_UNAVAILABLE, msg.clone())
             }
+            TurfOpsError::Timeout(msg) => (StatusCode::GATEWAY_TIMEOUT, msg.clone()),
+            other if other.is_timeout() => {
+                tracing::warn!("Database timeout: {}", other);
+                (
+                    StatusCode::GATEWAY_TIMEOUT,
+                    "The database took too long to answer".to_string(),
+                )
+            }
+            other if other.is_busy() => {
+                tracing::warn!("Database busy: {}", other);
+                (
+                    StatusCode::SERVICE_UNAVAILABLE,
+                    "The database is busy, try again shortly".to_string(),
+                )
+            }
             other => {
                 tracing::error!("Internal error: {}", other);
                 (
@@ -58,7 +133,51 @@ impl IntoResponse for TurfOpsError {
             }
         };
 
-        let body = serde_json::json!({ "error": message });
-        (status, axum::Json(body)).into_response()
+        let body = serde_json::json!({ "error": message, "kind": self.kind() });
+        let mut response = (status, axum::Json(body)).into_response();
+        if status.is_server_error() {
+            response
+                .extensions_mut()
+                .insert(ErrorDetail(self.to_string()));
+        }
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn internal_errors_keep_detail_out_of_the_body() {
+        let response = TurfOpsError::Config("bad DATABASE_URL".into()).into_response();
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        let detail = response.extensions().get::<ErrorDetail>().unwrap();
+        assert!(detail.0.contains("bad DATABASE_URL"));
+
+        let response = TurfOpsError::NotFound("No lawn profile found".into()).into_response();
+        assert!(response.extensions().get::<ErrorDetail>().is_none());
+    }
+
+    #[test]
+    fn pool_timeout_is_a_retryable_busy_error() {
+        let err = TurfOpsError::Database(sqlx::Error::PoolTimedOut);
+        assert!(err.is_busy());
+        assert_eq!(err.kind(), "database_busy");
+        assert_eq!(
+            err.into_response().status(),
+            StatusCode::SERVICE_UNAVAILABLE
+        );
+
+        assert!(!TurfOpsError::Database(sqlx::Error::RowNotFound).is_busy());
+    }
+
+    #[test]
+    fn timeouts_are_their_own_kind() {
+        let err = TurfOpsError::Timeout("Home Assistant didn't answer within 15s".into());
+        assert!(err.is_timeout());
+        assert_eq!(err.kind(), "timeout");
+        assert_eq!(err.into_response().status(), StatusCode::GATEWAY_TIMEOUT);
+        assert!(!TurfOpsError::DataSourceUnavailable("down".into()).is_timeout());
     }
 }
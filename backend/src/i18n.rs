@@ -0,0 +1,322 @@
+//! Localization catalogs, gettext style: the English text in the code is the
+//! message id, and a catalog maps it to the configured language.
+//!
+//! A catalog is a JSON file with two sections:
+//! - `ui`: frontend labels, served as-is by `GET /api/v1/locale`.
+//! - `rules`: recommendation text. Keys may contain numbered placeholders
+//!   (`"Soil at {0}°F for {1} days"`) for the parts a rule fills in with
+//!   `format!`; the translation places them wherever the language needs.
+//!
+//! Text with no catalog entry stays in English, so a partial catalog is fine.
+//! Recommendation text that doesn't match as a whole is tried sentence by
+//! sentence. Built-in catalogs live in `backend/locales/`; `LOCALE_DIR` points
+//! at a directory of `<locale>.json` files that take precedence.
+
+use crate::error::{Result, TurfOpsError};
+use crate::models::Recommendation;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Catalogs compiled into the binary.
+const BUILT_IN: &[(&str, &str)] = &[("es", include_str!("../locales/es.json"))];
+
+#[derive(Debug, Default, Deserialize)]
+struct CatalogFile {
+    #[serde(default)]
+    ui: HashMap<String, String>,
+    #[serde(default)]
+    rules: HashMap<String, String>,
+}
+
+/// Frontend half of a catalog.
+#[derive(Debug, Clone, Serialize)]
+pub struct UiCatalog {
+    pub locale: String,
+    pub messages: HashMap<String, String>,
+}
+
+#[derive(Debug)]
+struct Template {
+    /// Literal text around the placeholders; one more than `slots`.
+    literals: Vec<String>,
+    /// Placeholder numbers in the order they appear.
+    slots: Vec<usize>,
+    translation: String,
+}
+
+#[derive(Debug)]
+pub struct Catalog {
+    locale: String,
+    ui: HashMap<String, String>,
+    exact: HashMap<String, String>,
+    /// Most specific (longest literal text) first.
+    templates: Vec<Template>,
+}
+
+impl Default for Catalog {
+    fn default() -> Self {
+        Self::english()
+    }
+}
+
+impl Catalog {
+    /// The source language: every lookup falls through to the original text.
+    pub fn english() -> Self {
+        Self {
+            locale: "en".into(),
+            ui: HashMap::new(),
+            exact: HashMap::new(),
+            templates: Vec::new(),
+        }
+    }
+
+    /// Load `locale` from `dir` if given and present, else from the built-in
+    /// catalogs. English needs no catalog.
+    pub fn load(locale: &str, dir: Option<&str>) -> Result<Self> {
+        let locale = locale.trim().to_lowercase();
+        if locale.is_empty() || locale == "en" {
+            return Ok(Self::english());
+        }
+
+        if let Some(dir) = dir {
+            let path = Path::new(dir).join(format!("{}.json", locale));
+            if path.exists() {
+                let raw = std::fs::read_to_string(&path).map_err(|e| {
+                    TurfOpsError::Config(format!("Reading {}: {}", path.display(), e))
+                })?;
+                return Self::parse(&locale, &raw);
+            }
+        }
+
+        match BUILT_IN.iter().find(|(name, _)| *name == locale) {
+            Some((_, raw)) => Self::parse(&locale, raw),
+            None => Err(TurfOpsError::Config(format!(
+                "No catalog for LOCALE={} (built in: en, {})",
+                locale,
+                BUILT_IN
+                    .iter()
+                    .map(|(name, _)| *name)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ))),
+        }
+    }
+
+    fn parse(locale: &str, raw: &str) -> Result<Self> {
+        let file: CatalogFile = serde_json::from_str(raw)
+            .map_err(|e| TurfOpsError::Config(format!("Catalog {}: {}", locale, e)))?;
+
+        let mut exact = HashMap::new();
+        let mut templates = Vec::new();
+        for (key, translation) in file.rules {
+            match parse_template(&key) {
+                None => {
+                    exact.insert(key, translation);
+                }
+                Some((literals, slots)) => {
+                    if literals[1..literals.len() - 1].iter().any(String::is_empty) {
+                        return Err(TurfOpsError::Config(format!(
+                            "Catalog {}: adjacent placeholders in \"{}\"",
+                            locale, key
+                        )));
+                    }
+                    templates.push(Template {
+                        literals,
+                        slots,
+                        translation,
+                    });
+                }
+            }
+        }
+        templates
+            .sort_by_key(|t| std::cmp::Reverse(t.literals.iter().map(String::len).sum::<usize>()));
+
+        Ok(Self {
+            locale: locale.to_string(),
+            ui: file.ui,
+            exact,
+            templates,
+        })
+    }
+
+    pub fn ui(&self) -> UiCatalog {
+        UiCatalog {
+            locale: self.locale.clone(),
+            messages: self.ui.clone(),
+        }
+    }
+
+    /// Translate one piece of recommendation text, or return it unchanged.
+    pub fn translate(&self, text: &str) -> String {
+        if let Some(whole) = self.translate_whole(text) {
+            return whole;
+        }
+        // Rules often glue sentences together; translate those that have entries.
+        let sentences: Vec<&str> = text.split_inclusive(". ").collect();
+        if sentences.len() < 2 {
+            return text.to_string();
+        }
+        sentences
+            .iter()
+            .map(|s| {
+                let body = s.trim_end();
+                let tail = &s[body.len()..];
+                // Keys for whole strings usually carry no final period
+                let translated = self.translate_whole(body).or_else(|| {
+                    let bare = body.strip_suffix('.')?;
+                    Some(format!("{}.", self.translate_whole(bare)?))
+                });
+                match translated {
+                    Some(t) => format!("{}{}", t, tail),
+                    None => s.to_string(),
+                }
+            })
+            .collect()
+    }
+
+    fn translate_whole(&self, text: &str) -> Option<String> {
+        if let Some(t) = self.exact.get(text) {
+            return Some(t.clone());
+        }
+        self.templates.iter().find_map(|t| {
+            let captures = match_template(&t.literals, text)?;
+            let mut out = t.translation.clone();
+            for (slot, value) in t.slots.iter().zip(captures) {
+                // Filled-in words like grass names may have entries of their own
+                let value = self.exact.get(value).map_or(value, String::as_str);
+                out = out.replace(&format!("{{{}}}", slot), value);
+            }
+            Some(out)
+        })
+    }
+
+    /// Translate the user-facing text of a recommendation in place.
+    pub fn localize(&self, rec: &mut Recommendation) {
+        if self.exact.is_empty() && self.templates.is_empty() {
+            return;
+        }
+        rec.title = self.translate(&rec.title);
+        rec.description = self.translate(&rec.description);
+        rec.explanation = self.translate(&rec.explanation);
+        if let Some(action) = rec.suggested_action.as_mut() {
+            *action = self.translate(action);
+        }
+        if let Some(note) = rec.conflict_note.as_mut() {
+            *note = self.translate(note);
+        }
+        for point in &mut rec.data_points {
+            point.label = self.translate(&point.label);
+            point.value = self.translate(&point.value);
+        }
+    }
+}
+
+/// Split a key on `{N}` placeholders. `None` when it has none.
+fn parse_template(key: &str) -> Option<(Vec<String>, Vec<usize>)> {
+    let mut literals = vec![String::new()];
+    let mut slots = Vec::new();
+    let mut rest = key;
+    while let Some(open) = rest.find('{') {
+        let after = &rest[open + 1..];
+        let close = after.find('}');
+        let slot = close.and_then(|c| after[..c].parse::<usize>().ok());
+        let last = literals.last_mut().expect("starts non-empty");
+        match (close, slot) {
+            (Some(c), Some(n)) => {
+                last.push_str(&rest[..open]);
+                slots.push(n);
+                literals.push(String::new());
+                rest = &after[c + 1..];
+            }
+            _ => {
+                last.push_str(&rest[..=open]);
+                rest = after;
+            }
+        }
+    }
+    literals
+        .last_mut()
+        .expect("starts non-empty")
+        .push_str(rest);
+    (!slots.is_empty()).then_some((literals, slots))
+}
+
+/// Match `text` against literal pieces, returning the non-empty text between them.
+fn match_template<'a>(literals: &[String], text: &'a str) -> Option<Vec<&'a str>> {
+    let (first, rest) = literals.split_first()?;
+    let (last, middle) = rest.split_last()?;
+    let mut remaining = text.strip_prefix(first.as_str())?;
+    let mut captures = Vec::with_capacity(rest.len());
+    for literal in middle {
+        let skip = remaining.chars().next()?.len_utf8();
+        let at = remaining[skip..].find(literal.as_str())? + skip;
+        captures.push(&remaining[..at]);
+        remaining = &remaining[at + literal.len()..];
+    }
+    let value = remaining.strip_suffix(last.as_str())?;
+    if value.is_empty() {
+        return None;
+    }
+    captures.push(value);
+    Some(captures)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{RecommendationCategory, Severity};
+
+    #[test]
+    fn translates_exact_templated_and_partial_text() {
+        let catalog = Catalog::parse(
+            "xx",
+            r#"{
+                "ui": { "Dashboard": "Tablero" },
+                "rules": {
+                    "Pre-Emergent Window Open": "Ventana de preemergente abierta",
+                    "Tall Fescue": "festuca alta",
+                    "Soil at {0}°F for {1} days": "{1} días con el suelo a {0}°F",
+                    "Apply {0} now.": "Aplique {0} ahora.",
+                    "Soil Temp": "Temp. del suelo"
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            catalog.translate("Soil at 52.1°F for 3 days"),
+            "3 días con el suelo a 52.1°F"
+        );
+        assert_eq!(
+            catalog.translate("Apply Tall Fescue now."),
+            "Aplique festuca alta ahora."
+        );
+        // Unknown sentences stay English; known ones are translated in place
+        assert_eq!(
+            catalog.translate("Soil at 50°F for 2 days. Watch the forecast."),
+            "2 días con el suelo a 50°F. Watch the forecast."
+        );
+        assert_eq!(
+            catalog.translate("Soil at °F for 2 days"),
+            "Soil at °F for 2 days"
+        );
+        assert_eq!(catalog.ui().messages["Dashboard"], "Tablero");
+
+        let mut rec = Recommendation::new(
+            "pre_emergent",
+            RecommendationCategory::PreEmergent,
+            Severity::Warning,
+            "Pre-Emergent Window Open",
+            "Soil at 50°F for 2 days",
+        )
+        .with_data_point("Soil Temp", "50°F", "SoilData");
+        catalog.localize(&mut rec);
+        assert_eq!(rec.title, "Ventana de preemergente abierta");
+        assert_eq!(rec.data_points[0].label, "Temp. del suelo");
+
+        assert!(Catalog::load("es", None).is_ok());
+        assert!(Catalog::load("zz", None).is_err());
+        assert_eq!(Catalog::load("EN", None).unwrap().ui().locale, "en");
+    }
+}
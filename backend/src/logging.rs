@@ -0,0 +1,161 @@
+//! Tracing setup: stderr always, plus an optional rotating log file.
+//!
+//! The file settings are read straight from the environment rather than from
+//! `Config`, because the subscriber has to exist before `Config::from_env` runs
+//! (it logs warnings about malformed values).
+
+use std::fs::File;
+use std::io::{self, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{fmt, EnvFilter, Layer};
+
+/// Log files are named `turfops.<date>.log` (or `turfops.log` with rotation off).
+const LOG_FILE_PREFIX: &str = "turfops";
+const LOG_FILE_SUFFIX: &str = "log";
+
+/// Most lines one tail request may return.
+pub const MAX_TAIL_LINES: usize = 2000;
+
+/// Bytes read from the end of the file when tailing; plenty for
+/// `MAX_TAIL_LINES` of typical log output without loading a whole day's file.
+const TAIL_READ_BYTES: u64 = 512 * 1024;
+
+#[derive(Debug, Clone)]
+pub struct LogFileConfig {
+    pub dir: PathBuf,
+    /// `EnvFilter` directive for the file, independent of `RUST_LOG`.
+    pub level: String,
+    pub rotation: Rotation,
+    /// Rotated files kept before the oldest is deleted.
+    pub max_files: usize,
+}
+
+impl LogFileConfig {
+    /// `None` unless `LOG_DIR` is set.
+    pub fn from_env() -> Option<Self> {
+        let dir = std::env::var("LOG_DIR")
+            .ok()
+            .filter(|d| !d.trim().is_empty())?;
+        let rotation = match std::env::var("LOG_ROTATION")
+            .unwrap_or_default()
+            .to_lowercase()
+            .as_str()
+        {
+            "hourly" => Rotation::HOURLY,
+            "never" => Rotation::NEVER,
+            _ => Rotation::DAILY,
+        };
+        Some(Self {
+            dir: PathBuf::from(dir),
+            level: std::env::var("LOG_FILE_LEVEL").unwrap_or_else(|_| "info".into()),
+            rotation,
+            max_files: std::env::var("LOG_MAX_FILES")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .filter(|n| *n > 0)
+                .unwrap_or(7),
+        })
+    }
+}
+
+/// Install the global subscriber. Keep the returned guard alive for the life
+/// of the process, or buffered file output is lost on exit.
+pub fn init(file: Option<&LogFileConfig>) -> Option<WorkerGuard> {
+    let stderr = fmt::layer()
+        .with_filter(EnvFilter::try_from_default_env().unwrap_or_else(|_| "info".into()));
+
+    let (file_layer, guard) = match file.map(|config| (config, open_appender(config))) {
+        Some((config, Ok(appender))) => {
+            let (writer, guard) = tracing_appender::non_blocking(appender);
+            let layer = fmt::layer()
+                .with_writer(writer)
+                .with_ansi(false)
+                .with_filter(EnvFilter::try_new(&config.level).unwrap_or_else(|_| "info".into()));
+            (Some(layer), Some(guard))
+        }
+        Some((_, Err(e))) => {
+            // No subscriber yet, so this is the only place the failure can go
+            eprintln!("File logging disabled: {}", e);
+            (None, None)
+        }
+        None => (None, None),
+    };
+
+    tracing_subscriber::registry()
+        .with(stderr)
+        .with(file_layer)
+        .init();
+    guard
+}
+
+fn open_appender(
+    config: &LogFileConfig,
+) -> Result<RollingFileAppender, tracing_appender::rolling::InitError> {
+    RollingFileAppender::builder()
+        .rotation(config.rotation.clone())
+        .filename_prefix(LOG_FILE_PREFIX)
+        .filename_suffix(LOG_FILE_SUFFIX)
+        .max_log_files(config.max_files)
+        .build(&config.dir)
+}
+
+/// Most recently modified log file in `dir`, if any.
+pub fn latest_log_file(dir: &Path) -> io::Result<Option<PathBuf>> {
+    let mut newest: Option<(std::time::SystemTime, PathBuf)> = None;
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if !name.starts_with(LOG_FILE_PREFIX) || !name.ends_with(LOG_FILE_SUFFIX) {
+            continue;
+        }
+        let modified = entry.metadata()?.modified()?;
+        if newest.as_ref().is_none_or(|(t, _)| modified > *t) {
+            newest = Some((modified, entry.path()));
+        }
+    }
+    Ok(newest.map(|(_, path)| path))
+}
+
+/// Last `lines` lines of `path`, oldest first.
+pub fn tail(path: &Path, lines: usize) -> io::Result<Vec<String>> {
+    let mut file = File::open(path)?;
+    let len = file.metadata()?.len();
+    let start = len.saturating_sub(TAIL_READ_BYTES);
+    file.seek(SeekFrom::Start(start))?;
+    let mut buf = Vec::new();
+    file.read_to_end(&mut buf)?;
+    Ok(last_lines(&String::from_utf8_lossy(&buf), lines, start > 0))
+}
+
+/// The final `n` lines of `text`. When `partial_first` is set the text was
+/// cut mid-file, so its first line may be truncated and is dropped.
+fn last_lines(text: &str, n: usize, partial_first: bool) -> Vec<String> {
+    let mut all: Vec<&str> = text.lines().collect();
+    if partial_first && !all.is_empty() {
+        all.remove(0);
+    }
+    let skip = all.len().saturating_sub(n);
+    all[skip..].iter().map(|l| l.to_string()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn last_lines_keeps_the_tail() {
+        let text = "one\ntwo\nthree\nfour\n";
+        assert_eq!(last_lines(text, 2, false), vec!["three", "four"]);
+        assert_eq!(last_lines(text, 10, false).len(), 4);
+    }
+
+    #[test]
+    fn last_lines_drops_a_cut_first_line() {
+        assert_eq!(last_lines("ne\ntwo\nthree", 10, true), vec!["two", "three"]);
+    }
+}
@@ -0,0 +1,231 @@
+//! Aftercare reminders: short-lived recommendations right after a chemical
+//! application — water it in, and keep people and pets off — driven by the
+//! product catalog's label facts. The watering-in reminder checks the forecast
+//! and escalates when the rain needed to water the product in isn't coming.
+
+use crate::models::{
+    product_info, Application, DataSource, Millimeters, ReEntry, Recommendation,
+    RecommendationCategory, Severity, WeatherForecast,
+};
+use chrono::{Duration, NaiveDate};
+
+/// Days left before a missed watering-in escalates to Warning.
+const WATER_IN_URGENT_DAYS: i64 = 1;
+
+pub fn generate_aftercare_recommendations(
+    applications: &[Application],
+    forecast: Option<&WeatherForecast>,
+    today: NaiveDate,
+) -> Vec<Recommendation> {
+    let mut out = Vec::new();
+
+    for app in applications {
+        let (Some(app_id), Some(product_name)) = (app.id, app.product_name.as_deref()) else {
+            continue;
+        };
+        let Some(product) = product_info(product_name) else {
+            continue;
+        };
+        if app.application_date > today {
+            continue;
+        }
+
+        if let Some(water_in) = product.water_in {
+            let deadline = app.application_date + Duration::days(water_in.within_days);
+            if today <= deadline {
+                out.push(water_in_reminder(
+                    app_id,
+                    product_name,
+                    water_in.inches,
+                    deadline,
+                    forecast,
+                    today,
+                ));
+            }
+        }
+
+        if let Some(reentry) = product.reentry {
+            if app.application_date == today {
+                out.push(reentry_reminder(app_id, product_name, reentry));
+            }
+        }
+    }
+
+    out
+}
+
+fn water_in_reminder(
+    app_id: i64,
+    product_name: &str,
+    inches: f64,
+    deadline: NaiveDate,
+    forecast: Option<&WeatherForecast>,
+    today: NaiveDate,
+) -> Recommendation {
+    let days_left = (deadline - today).num_days();
+    let by = if days_left == 0 {
+        "today".to_string()
+    } else {
+        format!("by {}", deadline.format("%b %-d"))
+    };
+
+    let rain_in = forecast.map(|f| {
+        let mm = f
+            .daily_summary
+            .iter()
+            .filter(|d| d.date >= today && d.date <= deadline)
+            .map(|d| d.total_precipitation_mm)
+            .sum::<f64>();
+        Millimeters(mm).to_inches().0
+    });
+
+    let (severity, title, action) = match rain_in {
+        Some(rain) if rain >= inches => (
+            Severity::Info,
+            format!("Rain Should Water In {}", product_name),
+            format!(
+                "{:.2} in of rain is forecast {}. Irrigate to make up the difference if \
+                 it falls short of {:.1} in.",
+                rain, by, inches
+            ),
+        ),
+        Some(rain) => (
+            if days_left <= WATER_IN_URGENT_DAYS {
+                Severity::Warning
+            } else {
+                Severity::Advisory
+            },
+            format!("Water In {} {}", product_name, by),
+            format!(
+                "Only {:.2} in of rain is forecast {}. Irrigate {:.1} in to move the \
+                 product into the soil.",
+                rain, by, inches
+            ),
+        ),
+        None => (
+            Severity::Advisory,
+            format!("Water In {} {}", product_name, by),
+            format!(
+                "Apply {:.1} in of water {} unless rain does it for you.",
+                inches, by
+            ),
+        ),
+    };
+
+    let mut rec = Recommendation::new(
+        format!("water_in_{}", app_id),
+        RecommendationCategory::ApplicationTiming,
+        severity,
+        title,
+        format!(
+            "{} needs {:.1} in of water {} to activate.",
+            product_name, inches, by
+        ),
+    )
+    .with_explanation(
+        "Granular and soil-targeted products only work once water carries them off the \
+         leaves and into the root zone. Left on the surface they break down in sunlight \
+         or get picked up by mowing, and the control window is lost.",
+    )
+    .with_data_point("Product", product_name, DataSource::Manual.as_str())
+    .with_data_point(
+        "Water Needed",
+        format!("{:.1} in", inches),
+        DataSource::Agronomic.as_str(),
+    )
+    .with_data_point(
+        "Deadline",
+        deadline.format("%b %-d").to_string(),
+        DataSource::Calculated.as_str(),
+    );
+    if let Some(rain) = rain_in {
+        rec = rec.with_data_point(
+            "Forecast Rain",
+            format!("{:.2} in", rain),
+            DataSource::OpenWeatherMap.as_str(),
+        );
+    }
+    rec.with_action(action)
+}
+
+fn reentry_reminder(app_id: i64, product_name: &str, reentry: ReEntry) -> Recommendation {
+    let until = match reentry {
+        ReEntry::UntilDry => "the spray has dried, usually 1-2 hours",
+        ReEntry::AfterWateringIn => "it has been watered in and the grass has dried",
+    };
+    Recommendation::new(
+        format!("reentry_{}", app_id),
+        RecommendationCategory::ApplicationTiming,
+        Severity::Info,
+        "Keep Off Treated Area",
+        format!(
+            "{} went down today. Keep kids and pets off until {}.",
+            product_name, until
+        ),
+    )
+    .with_explanation(
+        "Product labels set re-entry intervals to limit contact with wet residue on \
+         the leaves. Once it has dried or been watered into the soil, exposure drops \
+         to a minimum.",
+    )
+    .with_data_point("Product", product_name, DataSource::Manual.as_str())
+    .with_action(format!("Stay off the lawn until {}.", until))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ApplicationType;
+    use crate::testing::{application, date, ForecastBuilder};
+
+    fn applied(product: &str, on: NaiveDate) -> Vec<Application> {
+        let mut app = application(ApplicationType::GrubControl, on);
+        app.id = Some(7);
+        app.product_name = Some(product.into());
+        vec![app]
+    }
+
+    #[test]
+    fn grubex_gets_water_in_and_reentry_on_the_day() {
+        let today = date(2025, 6, 1);
+        let recs = generate_aftercare_recommendations(&applied("GrubEx", today), None, today);
+        let ids: Vec<_> = recs.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, ["water_in_7", "reentry_7"]);
+        assert_eq!(recs[0].severity, Severity::Advisory);
+
+        // Reentry is same-day only; the water-in window closes after a day
+        let recs =
+            generate_aftercare_recommendations(&applied("GrubEx", today), None, date(2025, 6, 2));
+        assert_eq!(recs.len(), 1);
+        let recs =
+            generate_aftercare_recommendations(&applied("GrubEx", today), None, date(2025, 6, 3));
+        assert!(recs.is_empty());
+    }
+
+    #[test]
+    fn forecast_rain_decides_water_in_severity() {
+        let today = date(2025, 6, 1);
+        let dry = ForecastBuilder::starting(today)
+            .day(80.0, 60.0, 50.0, 0.0)
+            .day(82.0, 62.0, 50.0, 1.0)
+            .build();
+        let recs = generate_aftercare_recommendations(&applied("GrubEx", today), Some(&dry), today);
+        assert_eq!(recs[0].severity, Severity::Warning);
+
+        let wet = ForecastBuilder::starting(today)
+            .day(80.0, 60.0, 80.0, 4.0)
+            .day(72.0, 60.0, 90.0, 12.0)
+            .build();
+        let recs = generate_aftercare_recommendations(&applied("GrubEx", today), Some(&wet), today);
+        assert_eq!(recs[0].severity, Severity::Info);
+    }
+
+    #[test]
+    fn unknown_products_get_no_reminders() {
+        let today = date(2025, 6, 1);
+        assert!(
+            generate_aftercare_recommendations(&applied("Milorganite", today), None, today)
+                .is_empty()
+        );
+    }
+}
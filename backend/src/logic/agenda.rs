@@ -0,0 +1,108 @@
+//! Week agenda for the Calendar: each day's logged applications, entries
+//! planned for it (future-dated applications and follow-ups falling due) and
+//! the forecast, so the next few days of work can be planned at a glance.
+
+use crate::models::{Application, DailyForecast, WeatherForecast};
+use chrono::{Datelike, Duration, NaiveDate};
+use serde::Serialize;
+
+pub const AGENDA_DAYS: i64 = 7;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AgendaDay {
+    pub date: NaiveDate,
+    /// Applications dated this day. Ones after `today` are planned work.
+    pub applications: Vec<Application>,
+    /// Earlier applications whose follow-up falls due this day.
+    pub follow_ups: Vec<Application>,
+    /// `None` for past days and beyond the forecast's reach.
+    pub forecast: Option<DailyForecast>,
+}
+
+/// Sunday on or before `date`, matching the month grid's first column.
+pub fn week_start(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(date.weekday().num_days_from_sunday() as i64)
+}
+
+/// One entry per day from `start` for [`AGENDA_DAYS`] days. `apps` may hold
+/// applications from anywhere; each lands on its date and follow-up date.
+pub fn build_agenda(
+    start: NaiveDate,
+    apps: &[Application],
+    forecast: Option<&WeatherForecast>,
+) -> Vec<AgendaDay> {
+    (0..AGENDA_DAYS)
+        .map(|offset| {
+            let date = start + Duration::days(offset);
+            let mut applications: Vec<Application> = apps
+                .iter()
+                .filter(|a| a.application_date == date)
+                .cloned()
+                .collect();
+            applications.sort_by_key(|a| a.id);
+            let follow_ups = apps
+                .iter()
+                .filter(|a| a.follow_up_date == Some(date))
+                .cloned()
+                .collect();
+            let forecast = forecast
+                .and_then(|f| f.daily_summary.iter().find(|d| d.date == date))
+                .cloned();
+            AgendaDay {
+                date,
+                applications,
+                follow_ups,
+                forecast,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ApplicationType;
+    use crate::testing::{application, date, ForecastBuilder};
+
+    #[test]
+    fn weeks_start_on_sunday() {
+        // 2026-10-16 is a Friday
+        assert_eq!(week_start(date(2026, 10, 16)), date(2026, 10, 11));
+        assert_eq!(week_start(date(2026, 10, 11)), date(2026, 10, 11));
+    }
+
+    #[test]
+    fn agenda_places_applications_follow_ups_and_forecast() {
+        let start = date(2026, 10, 11);
+        let mut seeded = application(ApplicationType::Overseed, date(2026, 9, 20));
+        seeded.follow_up_date = Some(date(2026, 10, 13));
+        let planned = application(ApplicationType::Fertilizer, date(2026, 10, 15));
+        let outside = application(ApplicationType::Mowing, date(2026, 10, 18));
+        let forecast = ForecastBuilder::starting(date(2026, 10, 14))
+            .day(68.0, 50.0, 60.0, 0.0)
+            .day(64.0, 48.0, 80.0, 6.0)
+            .build();
+
+        let days = build_agenda(start, &[seeded, planned, outside], Some(&forecast));
+        assert_eq!(days.len(), 7);
+        assert_eq!(days[6].date, date(2026, 10, 17));
+
+        assert_eq!(days[2].follow_ups.len(), 1);
+        assert!(days[2].applications.is_empty());
+        assert_eq!(
+            days[4].applications[0].application_type,
+            ApplicationType::Fertilizer
+        );
+        assert!(days.iter().all(|d| d
+            .applications
+            .iter()
+            .all(|a| a.application_type != ApplicationType::Mowing)));
+
+        assert!(days[2].forecast.is_none());
+        assert_eq!(days[3].forecast.as_ref().unwrap().high_temp_f, 68.0);
+        assert_eq!(
+            days[4].forecast.as_ref().unwrap().total_precipitation_mm,
+            6.0
+        );
+    }
+}
@@ -0,0 +1,266 @@
+//! Season-end review: seed the wizard with the year's major applications, and turn
+//! the user's outcome ratings and observations into threshold/program suggestions
+//! for next season.
+
+use crate::logic::rules::thresholds::{
+    GRUB_GDD_WINDOW_OPEN, HUMIDITY_DISEASE_RISK, PRE_EMERGENT_SOIL_LOW_F,
+};
+use crate::models::{
+    AdjustmentKind, Application, ApplicationOutcome, ApplicationReview, ApplicationType,
+    IssueLevel, ReviewAdjustment, ReviewObservations,
+};
+
+/// Application types worth an outcome rating in the review.
+const REVIEWED_TYPES: &[ApplicationType] = &[
+    ApplicationType::PreEmergent,
+    ApplicationType::PostEmergent,
+    ApplicationType::Fertilizer,
+    ApplicationType::Fungicide,
+    ApplicationType::Insecticide,
+    ApplicationType::GrubControl,
+    ApplicationType::Overseed,
+    ApplicationType::Aeration,
+];
+
+/// Build the outcome checklist from a season's applications, oldest first.
+/// Turf-scoped only — plant applications belong to the Landscape page.
+pub fn draft_application_reviews(applications: &[Application]) -> Vec<ApplicationReview> {
+    let mut reviews: Vec<ApplicationReview> = applications
+        .iter()
+        .filter(|a| a.plant_id.is_none() && REVIEWED_TYPES.contains(&a.application_type))
+        .filter_map(|a| {
+            Some(ApplicationReview {
+                application_id: a.id?,
+                application_type: a.application_type,
+                application_date: a.application_date,
+                product_name: a.product_name.clone(),
+                outcome: ApplicationOutcome::Unsure,
+            })
+        })
+        .collect();
+    reviews.sort_by_key(|r| r.application_date);
+    reviews
+}
+
+fn outcomes_of(
+    reviews: &[ApplicationReview],
+    app_type: ApplicationType,
+) -> impl Iterator<Item = ApplicationOutcome> + '_ {
+    reviews
+        .iter()
+        .filter(move |r| r.application_type == app_type)
+        .map(|r| r.outcome)
+}
+
+fn adjustment(
+    key: &str,
+    kind: AdjustmentKind,
+    title: &str,
+    detail: impl Into<String>,
+    values: Option<(f64, f64)>,
+) -> ReviewAdjustment {
+    ReviewAdjustment {
+        key: key.to_string(),
+        kind,
+        title: title.to_string(),
+        detail: detail.into(),
+        current_value: values.map(|(current, _)| current),
+        suggested_value: values.map(|(_, suggested)| suggested),
+        accepted: false,
+    }
+}
+
+/// Suggest next season's adjustments from outcome ratings and observations.
+pub fn suggest_adjustments(
+    reviews: &[ApplicationReview],
+    observations: &ReviewObservations,
+) -> Vec<ReviewAdjustment> {
+    let mut suggestions = Vec::new();
+
+    // -- Weeds --
+    let pre_emergent_count = outcomes_of(reviews, ApplicationType::PreEmergent).count();
+    let pre_emergent_short =
+        outcomes_of(reviews, ApplicationType::PreEmergent).any(|o| o.fell_short());
+
+    if observations.weeds >= IssueLevel::Minor && pre_emergent_count == 0 {
+        suggestions.push(adjustment(
+            "program_add_pre_emergent",
+            AdjustmentKind::Program,
+            "Add a spring pre-emergent",
+            "Weeds were observed and no pre-emergent was logged. Plan one before soil reaches 55°F.",
+            None,
+        ));
+    }
+    if observations.weeds >= IssueLevel::Moderate && pre_emergent_short {
+        suggestions.push(adjustment(
+            "threshold_pre_emergent_soil_low_f",
+            AdjustmentKind::Threshold,
+            "Open the pre-emergent window earlier",
+            "Pre-emergent fell short with weed pressure present; trigger the reminder 2°F sooner.",
+            Some((PRE_EMERGENT_SOIL_LOW_F, PRE_EMERGENT_SOIL_LOW_F - 2.0)),
+        ));
+        if pre_emergent_count == 1 {
+            suggestions.push(adjustment(
+                "program_split_pre_emergent",
+                AdjustmentKind::Program,
+                "Split the pre-emergent application",
+                "Apply half the rate at window open and the rest 6-8 weeks later to extend the barrier.",
+                None,
+            ));
+        }
+    }
+
+    // -- Disease --
+    if observations.disease >= IssueLevel::Moderate {
+        suggestions.push(adjustment(
+            "threshold_humidity_disease_risk",
+            AdjustmentKind::Threshold,
+            "Flag disease risk at lower humidity",
+            "Disease pressure was significant; alert at 75% humidity instead of 80%.",
+            Some((HUMIDITY_DISEASE_RISK, HUMIDITY_DISEASE_RISK - 5.0)),
+        ));
+        if outcomes_of(reviews, ApplicationType::Fungicide).count() == 0 {
+            suggestions.push(adjustment(
+                "program_add_preventive_fungicide",
+                AdjustmentKind::Program,
+                "Add a preventive fungicide",
+                "Schedule a preventive application ahead of summer humidity (late May to early June).",
+                None,
+            ));
+        }
+    }
+    if outcomes_of(reviews, ApplicationType::Fungicide)
+        .any(|o| o == ApplicationOutcome::Ineffective)
+    {
+        suggestions.push(adjustment(
+            "program_rotate_frac",
+            AdjustmentKind::Program,
+            "Rotate fungicide FRAC class",
+            "A fungicide was ineffective; rotate to a different FRAC group next season.",
+            None,
+        ));
+    }
+
+    // -- Grubs --
+    if outcomes_of(reviews, ApplicationType::GrubControl).any(|o| o.fell_short()) {
+        suggestions.push(adjustment(
+            "threshold_grub_gdd_window_open",
+            AdjustmentKind::Threshold,
+            "Apply grub control earlier",
+            "Grub control fell short; open the window 50 GDD sooner so product is down before egg hatch.",
+            Some((GRUB_GDD_WINDOW_OPEN, GRUB_GDD_WINDOW_OPEN - 50.0)),
+        ));
+    }
+
+    // -- Thin areas --
+    if observations.thin_areas >= IssueLevel::Moderate {
+        let overseeded = outcomes_of(reviews, ApplicationType::Overseed).count() > 0;
+        let aerated = outcomes_of(reviews, ApplicationType::Aeration).count() > 0;
+        if !overseeded || !aerated {
+            suggestions.push(adjustment(
+                "program_aerate_overseed",
+                AdjustmentKind::Program,
+                "Core aerate and overseed in early fall",
+                "Thin areas were observed; aerate then overseed at the start of the fall window.",
+                None,
+            ));
+        }
+        if outcomes_of(reviews, ApplicationType::Fertilizer).any(|o| o.fell_short()) {
+            suggestions.push(adjustment(
+                "program_fall_nitrogen",
+                AdjustmentKind::Program,
+                "Shift nitrogen toward fall",
+                "Fertilizer underperformed with thin turf; emphasize September-November feedings.",
+                None,
+            ));
+        }
+    }
+
+    suggestions
+}
+
+/// Carry the user's accept/decline choices over onto freshly generated suggestions.
+pub fn merge_accepted(
+    mut suggestions: Vec<ReviewAdjustment>,
+    saved: &[ReviewAdjustment],
+) -> Vec<ReviewAdjustment> {
+    for s in &mut suggestions {
+        if let Some(prev) = saved.iter().find(|p| p.key == s.key) {
+            s.accepted = prev.accepted;
+        }
+    }
+    suggestions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn review(app_type: ApplicationType, outcome: ApplicationOutcome) -> ApplicationReview {
+        ApplicationReview {
+            application_id: 1,
+            application_type: app_type,
+            application_date: NaiveDate::from_ymd_opt(2026, 4, 1).unwrap(),
+            product_name: None,
+            outcome,
+        }
+    }
+
+    fn keys(s: &[ReviewAdjustment]) -> Vec<&str> {
+        s.iter().map(|a| a.key.as_str()).collect()
+    }
+
+    #[test]
+    fn clean_season_suggests_nothing() {
+        let reviews = [review(
+            ApplicationType::PreEmergent,
+            ApplicationOutcome::Effective,
+        )];
+        assert!(suggest_adjustments(&reviews, &ReviewObservations::default()).is_empty());
+    }
+
+    #[test]
+    fn weeds_after_failed_pre_emergent_shift_threshold_and_split() {
+        let reviews = [review(
+            ApplicationType::PreEmergent,
+            ApplicationOutcome::Partial,
+        )];
+        let obs = ReviewObservations {
+            weeds: IssueLevel::Moderate,
+            ..Default::default()
+        };
+        let s = suggest_adjustments(&reviews, &obs);
+        assert_eq!(
+            keys(&s),
+            vec![
+                "threshold_pre_emergent_soil_low_f",
+                "program_split_pre_emergent"
+            ]
+        );
+        assert_eq!(s[0].suggested_value, Some(PRE_EMERGENT_SOIL_LOW_F - 2.0));
+    }
+
+    #[test]
+    fn disease_without_fungicide_adds_program_and_threshold() {
+        let obs = ReviewObservations {
+            disease: IssueLevel::Severe,
+            ..Default::default()
+        };
+        let s = suggest_adjustments(&[], &obs);
+        assert!(keys(&s).contains(&"threshold_humidity_disease_risk"));
+        assert!(keys(&s).contains(&"program_add_preventive_fungicide"));
+    }
+
+    #[test]
+    fn accepted_choices_survive_regeneration() {
+        let obs = ReviewObservations {
+            thin_areas: IssueLevel::Severe,
+            ..Default::default()
+        };
+        let mut saved = suggest_adjustments(&[], &obs);
+        saved[0].accepted = true;
+        let merged = merge_accepted(suggest_adjustments(&[], &obs), &saved);
+        assert!(merged[0].accepted);
+    }
+}
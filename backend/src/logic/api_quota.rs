@@ -0,0 +1,228 @@
+//! Daily call budget for a metered API (OpenWeatherMap). Every request is
+//! counted in `api_usage` under the UTC day, so the server and commands run
+//! next to it share one count, and the count survives restarts. Once the
+//! quota is spent, further calls are refused until midnight UTC instead of
+//! being cut off (or billed) by the provider. Before that, the forecast
+//! refresh interval is stretched so the rest of the day's budget lasts until
+//! the reset. The quota check and the increment happen in one statement, so
+//! two processes calling at once can't both take the last call.
+
+use crate::db::api_usage_queries;
+use crate::error::{Result, TurfOpsError};
+use crate::models::DataSource;
+use chrono::{DateTime, Days, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Share of the quota held back from scheduled refreshes for connection
+/// checks, location searches and manual refreshes.
+const QUOTA_RESERVE_SHARE: f64 = 0.1;
+
+/// Calls made to one API today and how the forecast refresh is paced.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuotaUsage {
+    /// UTC day being counted.
+    pub day: NaiveDate,
+    pub calls: u32,
+    /// Daily quota; 0 when unlimited.
+    pub limit: u32,
+    /// Forecast refresh interval in effect, longer than usual when stretched.
+    pub refresh_minutes: u64,
+    pub stretched: bool,
+}
+
+pub struct ApiQuota {
+    pool: PgPool,
+    source: DataSource,
+    limit: u32,
+    /// Usual refresh interval and the calls each refresh makes.
+    base_interval: Duration,
+    calls_per_refresh: u32,
+    /// Today's count as of this process's last call or load.
+    today: Mutex<(NaiveDate, u32)>,
+    /// When the last request got an answer, and whether it succeeded.
+    last_response: Mutex<Option<(Instant, bool)>>,
+}
+
+impl ApiQuota {
+    /// Start from the count already recorded today.
+    pub async fn load(
+        pool: PgPool,
+        source: DataSource,
+        limit: u32,
+        base_interval: Duration,
+        calls_per_refresh: u32,
+    ) -> Self {
+        let day = Utc::now().date_naive();
+        let calls = api_usage_queries::get_calls(&pool, source.as_str(), day)
+            .await
+            .unwrap_or_else(|e| {
+                tracing::warn!(%source, error = %e, "Failed to read API usage; counting from 0");
+                0
+            });
+        ApiQuota {
+            pool,
+            source,
+            limit,
+            base_interval,
+            calls_per_refresh: calls_per_refresh.max(1),
+            today: Mutex::new((day, calls)),
+            last_response: Mutex::new(None),
+        }
+    }
+
+    fn calls_on(&self, day: NaiveDate) -> u32 {
+        match *self.today.lock().unwrap_or_else(|e| e.into_inner()) {
+            (counted, calls) if counted == day => calls,
+            _ => 0,
+        }
+    }
+
+    /// Count a call about to be made, refusing it when the quota is spent.
+    /// If the count can't be recorded, a limited quota refuses the call rather
+    /// than make one no other process will see.
+    pub async fn record_call(&self) -> Result<()> {
+        let day = Utc::now().date_naive();
+        let source = self.source.as_str();
+        let used_up = || {
+            TurfOpsError::DataSourceUnavailable(format!(
+                "Daily quota of {} {} calls is used up until midnight UTC",
+                self.limit, self.source
+            ))
+        };
+        let calls = match api_usage_queries::record_call(&self.pool, source, day, self.limit).await
+        {
+            Ok(Some(calls)) => calls,
+            Ok(None) => {
+                *self.today.lock().unwrap_or_else(|e| e.into_inner()) = (day, self.limit);
+                return Err(used_up());
+            }
+            Err(e) if self.limit > 0 => {
+                tracing::warn!(source, error = %e, "Failed to record API call; not making it");
+                return Err(TurfOpsError::DataSourceUnavailable(format!(
+                    "Can't check the daily {} quota: {}",
+                    self.source, e
+                )));
+            }
+            Err(e) => {
+                tracing::warn!(source, error = %e, "Failed to record API call");
+                self.calls_on(day) + 1
+            }
+        };
+        *self.today.lock().unwrap_or_else(|e| e.into_inner()) = (day, calls);
+        if self.limit > 0 && calls == self.limit {
+            tracing::warn!(
+                source,
+                limit = self.limit,
+                "Daily API quota used up; further calls wait for midnight UTC"
+            );
+        }
+        Ok(())
+    }
+
+    pub fn record_response(&self, ok: bool) {
+        *self.last_response.lock().unwrap_or_else(|e| e.into_inner()) = Some((Instant::now(), ok));
+    }
+
+    /// Whether the API answered, if any request got an answer within `within`.
+    pub fn recent_response(&self, within: Duration) -> Option<bool> {
+        self.last_response
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .filter(|(at, _)| at.elapsed() < within)
+            .map(|(_, ok)| ok)
+    }
+
+    /// How long the forecast may be cached before the next refresh.
+    pub fn refresh_interval(&self, now: DateTime<Utc>) -> Duration {
+        stretched_interval(
+            self.base_interval,
+            self.calls_on(now.date_naive()),
+            self.limit,
+            until_reset(now),
+            self.calls_per_refresh,
+        )
+    }
+
+    pub fn usage(&self, now: DateTime<Utc>) -> QuotaUsage {
+        let interval = self.refresh_interval(now);
+        QuotaUsage {
+            day: now.date_naive(),
+            calls: self.calls_on(now.date_naive()),
+            limit: self.limit,
+            refresh_minutes: interval.as_secs().div_ceil(60),
+            stretched: interval > self.base_interval,
+        }
+    }
+}
+
+/// Time left until the quota resets at midnight UTC.
+fn until_reset(now: DateTime<Utc>) -> Duration {
+    let midnight = now
+        .date_naive()
+        .checked_add_days(Days::new(1))
+        .and_then(|d| d.and_hms_opt(0, 0, 0))
+        .map(|t| t.and_utc());
+    midnight
+        .and_then(|m| (m - now).to_std().ok())
+        .unwrap_or_default()
+}
+
+/// `base`, or longer when refreshing that often would spend more than the
+/// rest of today's quota (less the reserve) before the reset. With nothing
+/// left to spend, refreshes wait for the reset.
+pub fn stretched_interval(
+    base: Duration,
+    used: u32,
+    limit: u32,
+    until_reset: Duration,
+    calls_per_refresh: u32,
+) -> Duration {
+    if limit == 0 {
+        return base;
+    }
+    let budget = (limit as f64 * (1.0 - QUOTA_RESERVE_SHARE)) as u32;
+    let refreshes_left = budget.saturating_sub(used) / calls_per_refresh.max(1);
+    if refreshes_left == 0 {
+        return until_reset.max(base);
+    }
+    base.max(until_reset / refreshes_left)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    const BASE: Duration = Duration::from_secs(30 * 60);
+    const DAY: Duration = Duration::from_secs(24 * 3600);
+
+    #[test]
+    fn stretches_only_when_the_budget_runs_short() {
+        // Early in the day there's plenty: 900 budget / 2 calls over 24h
+        assert_eq!(stretched_interval(BASE, 10, 1000, DAY, 2), BASE);
+        // 800 used with 12h left: 50 refreshes of 2 calls -> every 14.4 min, still under base
+        assert_eq!(stretched_interval(BASE, 800, 1000, DAY / 2, 2), BASE);
+        // 880 used: 10 refreshes over 12h -> every 72 min
+        assert_eq!(
+            stretched_interval(BASE, 880, 1000, DAY / 2, 2),
+            Duration::from_secs(72 * 60)
+        );
+        // Budget spent: wait for the reset
+        assert_eq!(stretched_interval(BASE, 950, 1000, DAY / 2, 2), DAY / 2);
+        // Less than the base interval to the reset still waits the base interval
+        let minutes = Duration::from_secs(5 * 60);
+        assert_eq!(stretched_interval(BASE, 1000, 1000, minutes, 2), BASE);
+        // No quota, no stretching
+        assert_eq!(stretched_interval(BASE, 5000, 0, DAY / 2, 2), BASE);
+    }
+
+    #[test]
+    fn quota_resets_at_midnight_utc() {
+        let now = Utc.with_ymd_and_hms(2026, 7, 14, 18, 30, 0).unwrap();
+        assert_eq!(until_reset(now), Duration::from_secs(5 * 3600 + 30 * 60));
+    }
+}
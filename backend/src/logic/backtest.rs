@@ -0,0 +1,251 @@
+//! Replay archived sensor data through the rules engine, one day at a time, to
+//! see which recommendations would have fired when.
+//!
+//! Each day is summarized from the 7 days of readings ending at local midnight,
+//! exactly as the live sync does, and evaluated with the rules' calendar pinned
+//! to that day. Forecasts aren't archived, so forecast-driven rules stay silent.
+
+use super::rules::hysteresis::HysteresisTracker;
+use super::rules::RulesEngine;
+use crate::clock;
+use crate::datasources::weather::{summarize_readings, SUMMARY_WINDOW_DAYS};
+use crate::models::{
+    Application, EnvironmentalReading, LawnProfile, RecommendationCategory, Severity,
+};
+use chrono::{DateTime, Duration, Local, NaiveDate, Utc};
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Longest range one backtest may cover.
+pub const BACKTEST_MAX_DAYS: i64 = 366;
+
+/// A recommendation firing on consecutive days, collapsed into one run.
+#[derive(Debug, Clone, Serialize)]
+pub struct FiringRun {
+    pub recommendation_id: String,
+    /// Title on the first day of the run.
+    pub title: String,
+    pub category: RecommendationCategory,
+    /// Most severe level reached during the run.
+    pub severity: Severity,
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    pub days: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BacktestReport {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub days_evaluated: usize,
+    /// Days skipped because no readings were archived in the preceding week.
+    pub days_without_data: usize,
+    /// Runs ordered by start date.
+    pub runs: Vec<FiringRun>,
+}
+
+/// Inputs that stay fixed across a backtest.
+pub struct BacktestInput<'a> {
+    pub profile: &'a LawnProfile,
+    pub applications: &'a [Application],
+    /// Archived readings covering `from - 7 days` through `to`, any order.
+    pub readings: &'a [EnvironmentalReading],
+    /// Year-to-date GDD (base 50°F) by day.
+    pub gdd_ytd: &'a HashMap<NaiveDate, f64>,
+    pub trend_window: Duration,
+}
+
+/// Evaluate every day in `from..=to` and collapse what fired into runs.
+pub fn run_backtest(
+    engine: &RulesEngine,
+    input: &BacktestInput,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> BacktestReport {
+    let mut readings = input.readings.to_vec();
+    readings.sort_by_key(|r| r.timestamp);
+
+    // The live latches keep running; replayed days get their own
+    let latches = HysteresisTracker::default();
+    let mut open: HashMap<String, FiringRun> = HashMap::new();
+    let mut runs = Vec::new();
+    let mut days_evaluated = 0;
+    let mut days_without_data = 0;
+
+    for day in from.iter_days().take_while(|d| *d <= to) {
+        let end = end_of_day(day);
+        let window = readings_between(&readings, end - Duration::days(SUMMARY_WINDOW_DAYS), end);
+
+        let fired = if window.is_empty() {
+            days_without_data += 1;
+            Vec::new()
+        } else {
+            days_evaluated += 1;
+            let current = window.last().cloned();
+            let mut env = summarize_readings(current, window, end, input.trend_window);
+            env.gdd_base50_ytd = input.gdd_ytd.get(&day).copied();
+
+            let history: Vec<_> = input
+                .applications
+                .iter()
+                .filter(|a| a.application_date <= day)
+                .cloned()
+                .collect();
+            engine.evaluate_with_latches(
+                &env,
+                input.profile,
+                &history,
+                &latches,
+                clock::local_noon(day),
+            )
+        };
+
+        // Close runs that didn't fire today, then extend or start the rest
+        let fired_ids: Vec<_> = fired.iter().map(|r| r.id.as_str()).collect();
+        let ended: Vec<_> = open
+            .keys()
+            .filter(|id| !fired_ids.contains(&id.as_str()))
+            .cloned()
+            .collect();
+        for id in ended {
+            runs.extend(open.remove(&id));
+        }
+        for rec in fired {
+            open.entry(rec.id.clone())
+                .and_modify(|run| {
+                    run.end = day;
+                    run.days += 1;
+                    run.severity = run.severity.max(rec.severity);
+                })
+                .or_insert(FiringRun {
+                    recommendation_id: rec.id,
+                    title: rec.title,
+                    category: rec.category,
+                    severity: rec.severity,
+                    start: day,
+                    end: day,
+                    days: 1,
+                });
+        }
+    }
+
+    runs.extend(open.into_values());
+    runs.sort_by(|a, b| (a.start, &a.recommendation_id).cmp(&(b.start, &b.recommendation_id)));
+
+    BacktestReport {
+        from,
+        to,
+        days_evaluated,
+        days_without_data,
+        runs,
+    }
+}
+
+/// Local midnight at the end of `day`, in UTC.
+fn end_of_day(day: NaiveDate) -> DateTime<Utc> {
+    let midnight = day
+        .succ_opt()
+        .unwrap_or(day)
+        .and_hms_opt(0, 0, 0)
+        .unwrap_or_default();
+    midnight
+        .and_local_timezone(Local)
+        .earliest()
+        .map(|t| t.with_timezone(&Utc))
+        .unwrap_or_else(|| midnight.and_utc())
+}
+
+/// Readings with `start < timestamp <= end`; `readings` must be sorted by time.
+fn readings_between(
+    readings: &[EnvironmentalReading],
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> &[EnvironmentalReading] {
+    let lo = readings.partition_point(|r| r.timestamp <= start);
+    let hi = readings.partition_point(|r| r.timestamp <= end);
+    &readings[lo..hi.max(lo)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ApplicationType, DataSource, GrassType};
+    use crate::testing::{application, date, profile};
+
+    /// Hourly readings from `start` through `end` with a constant soil temp.
+    fn hourly(start: NaiveDate, end: NaiveDate, soil_f: f64) -> Vec<EnvironmentalReading> {
+        let first = end_of_day(start) - Duration::days(1);
+        let hours = (end - start).num_days() * 24 + 24;
+        (0..hours)
+            .map(|h| {
+                let mut r = EnvironmentalReading::new(DataSource::SoilData);
+                r.timestamp = first + Duration::hours(h);
+                r.soil_temp_10_f = Some(soil_f);
+                r.ambient_temp_f = Some(soil_f + 5.0);
+                r
+            })
+            .collect()
+    }
+
+    fn backtest(readings: &[EnvironmentalReading], applications: &[Application]) -> BacktestReport {
+        let profile = profile(GrassType::TallFescue);
+        let gdd = HashMap::new();
+        let input = BacktestInput {
+            profile: &profile,
+            applications,
+            readings,
+            gdd_ytd: &gdd,
+            trend_window: Duration::hours(24),
+        };
+        run_backtest(
+            &RulesEngine::new(),
+            &input,
+            date(2026, 3, 20),
+            date(2026, 3, 26),
+        )
+    }
+
+    #[test]
+    fn pre_emergent_fires_as_a_single_run() {
+        // Soil steady at 55°F in late March: the pre-emergent window is open all week
+        let readings = hourly(date(2026, 3, 13), date(2026, 3, 26), 55.0);
+        let report = backtest(&readings, &[]);
+
+        assert_eq!(report.days_evaluated, 7);
+        assert_eq!(report.days_without_data, 0);
+        let run = report
+            .runs
+            .iter()
+            .find(|r| r.recommendation_id.starts_with("pre_emergent"))
+            .expect("pre-emergent should fire");
+        assert_eq!(
+            (run.start, run.end, run.days),
+            (date(2026, 3, 20), date(2026, 3, 26), 7)
+        );
+    }
+
+    #[test]
+    fn logged_application_ends_the_run() {
+        let readings = hourly(date(2026, 3, 13), date(2026, 3, 26), 55.0);
+        let applied = [application(ApplicationType::PreEmergent, date(2026, 3, 23))];
+        let report = backtest(&readings, &applied);
+
+        let runs: Vec<_> = report
+            .runs
+            .iter()
+            .filter(|r| r.recommendation_id.starts_with("pre_emergent"))
+            .collect();
+        assert_eq!(runs.len(), 1);
+        assert_eq!(runs[0].end, date(2026, 3, 22));
+    }
+
+    #[test]
+    fn days_without_readings_are_skipped() {
+        let readings = hourly(date(2026, 3, 13), date(2026, 3, 14), 55.0);
+        let report = backtest(&readings, &[]);
+
+        // Only Mar 20 still has readings within its trailing week
+        assert_eq!(report.days_evaluated, 1);
+        assert_eq!(report.days_without_data, 6);
+    }
+}
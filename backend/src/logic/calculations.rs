@@ -0,0 +1,502 @@
+//! Psychrometric metrics derived from air temperature and relative humidity.
+//!
+//! Inputs and outputs are typed °F (VPD in kPa, ET₀ in mm). Dew point uses the Magnus formula,
+//! wet-bulb uses Stull (2011), which is accurate to ~1°C for RH 5-99%, and heat
+//! index follows the NWS algorithm. Reference evapotranspiration uses
+//! Hargreaves (FAO-56 eq. 52), which needs only the daily temperature range.
+//!
+//! Also home to the window-over-window trend used for the 7-day summary.
+
+use super::rules::thresholds::{
+    LEAF_WETNESS_DEW_SPREAD_F, LEAF_WETNESS_WINDOW_HOURS, OVERNIGHT_END_HOUR, OVERNIGHT_START_HOUR,
+    OVERNIGHT_WETNESS_NIGHTS, OVERNIGHT_WET_DEW_SPREAD_F, OVERNIGHT_WET_HUMIDITY_PERCENT,
+};
+use crate::clock;
+use crate::models::{
+    DegreesC, DegreesF, DerivedMetrics, EnvironmentalReading, EnvironmentalSummary, ForecastPoint,
+    Millimeters, NightWetness, Trend,
+};
+use chrono::{DateTime, Days, Duration, Local, NaiveDate, TimeZone, Timelike, Utc};
+
+/// Hours represented by one OpenWeatherMap forecast point.
+const FORECAST_STEP_HOURS: f64 = 3.0;
+
+const MAGNUS_A: f64 = 17.625;
+const MAGNUS_B_C: f64 = 243.04;
+
+/// Dew point (°F). Humidity is clamped to 1-100% so the log stays finite.
+pub fn dew_point_f(temp: DegreesF, humidity_percent: f64) -> DegreesF {
+    let t = temp.to_celsius().0;
+    let rh = humidity_percent.clamp(1.0, 100.0);
+    let gamma = (rh / 100.0).ln() + MAGNUS_A * t / (MAGNUS_B_C + t);
+    DegreesC(MAGNUS_B_C * gamma / (MAGNUS_A - gamma)).to_fahrenheit()
+}
+
+/// Air temperature minus dew point (°F). Near zero means condensation on leaves.
+pub fn dew_point_spread_f(temp: DegreesF, humidity_percent: f64) -> f64 {
+    temp.0 - dew_point_f(temp, humidity_percent).0
+}
+
+/// Wet-bulb temperature (°F), Stull's empirical fit at sea-level pressure.
+pub fn wet_bulb_f(temp: DegreesF, humidity_percent: f64) -> DegreesF {
+    let t = temp.to_celsius().0;
+    let rh = humidity_percent.clamp(5.0, 99.0);
+    let tw = t * (0.151977 * (rh + 8.313659).sqrt()).atan() + (t + rh).atan()
+        - (rh - 1.676331).atan()
+        + 0.00391838 * rh.powf(1.5) * (0.023101 * rh).atan()
+        - 4.686035;
+    DegreesC(tw).to_fahrenheit()
+}
+
+/// NWS heat index (°F): Steadman's simple formula below 80°F, otherwise the
+/// Rothfusz regression with the low- and high-humidity adjustments.
+pub fn heat_index_f(temp: DegreesF, humidity_percent: f64) -> DegreesF {
+    let t = temp.0;
+    let rh = humidity_percent.clamp(0.0, 100.0);
+    let simple = 0.5 * (t + 61.0 + (t - 68.0) * 1.2 + rh * 0.094);
+    if (simple + t) / 2.0 < 80.0 {
+        return DegreesF(simple);
+    }
+
+    let mut hi = -42.379 + 2.04901523 * t + 10.14333127 * rh
+        - 0.22475541 * t * rh
+        - 0.00683783 * t * t
+        - 0.05481717 * rh * rh
+        + 0.00122874 * t * t * rh
+        + 0.00085282 * t * rh * rh
+        - 0.00000199 * t * t * rh * rh;
+    if rh < 13.0 && (80.0..=112.0).contains(&t) {
+        hi -= (13.0 - rh) / 4.0 * ((17.0 - (t - 95.0).abs()) / 17.0).sqrt();
+    } else if rh > 85.0 && (80.0..=87.0).contains(&t) {
+        hi += (rh - 85.0) / 10.0 * (87.0 - t) / 5.0;
+    }
+    DegreesF(hi)
+}
+
+/// Vapor pressure deficit (kPa): how much drying power the air has.
+pub fn vpd_kpa(temp: DegreesF, humidity_percent: f64) -> f64 {
+    let t = temp.to_celsius().0;
+    let saturation = 0.6108 * (17.27 * t / (t + 237.3)).exp();
+    saturation * (1.0 - humidity_percent.clamp(0.0, 100.0) / 100.0)
+}
+
+/// Whether leaves are likely wet at a forecast point for the 24-hour proxy:
+/// the dew point spread is under [`LEAF_WETNESS_DEW_SPREAD_F`].
+fn leaves_wet(p: &ForecastPoint) -> bool {
+    dew_point_spread_f(DegreesF(p.temp_f), p.humidity_percent) < LEAF_WETNESS_DEW_SPREAD_F
+}
+
+/// Whether leaves are likely wet at a forecast point overnight: humidity over
+/// [`OVERNIGHT_WET_HUMIDITY_PERCENT`] or a dew point spread under
+/// [`OVERNIGHT_WET_DEW_SPREAD_F`].
+fn leaves_wet_overnight(p: &ForecastPoint) -> bool {
+    p.humidity_percent > OVERNIGHT_WET_HUMIDITY_PERCENT
+        || dew_point_spread_f(DegreesF(p.temp_f), p.humidity_percent) < OVERNIGHT_WET_DEW_SPREAD_F
+}
+
+/// Forecast points in time order with the span each stands for: until the next
+/// point, at most one 3-hour step, so hourly and 3-hourly forecasts both work.
+/// The last point lasts as long as the one before it.
+fn forecast_steps<'a>(
+    points: impl IntoIterator<Item = &'a ForecastPoint>,
+) -> Vec<(DateTime<Utc>, DateTime<Utc>, &'a ForecastPoint)> {
+    let mut sorted: Vec<&ForecastPoint> = points.into_iter().collect();
+    sorted.sort_by_key(|p| p.timestamp);
+    let max_step = Duration::hours(FORECAST_STEP_HOURS as i64);
+    sorted
+        .iter()
+        .enumerate()
+        .map(|(i, p)| {
+            let step = match (sorted.get(i + 1), i.checked_sub(1).map(|j| sorted[j])) {
+                (Some(next), _) => next.timestamp - p.timestamp,
+                (None, Some(prev)) => p.timestamp - prev.timestamp,
+                (None, None) => max_step,
+            };
+            (p.timestamp, p.timestamp + step.min(max_step), *p)
+        })
+        .collect()
+}
+
+/// Leaf wetness proxy: forecast hours with wet leaves (see [`leaves_wet`]).
+pub fn leaf_wetness_hours(points: &[&ForecastPoint]) -> f64 {
+    forecast_steps(points.iter().copied())
+        .into_iter()
+        .filter(|(_, _, p)| leaves_wet(p))
+        .map(|(from, to, _)| (to - from).num_minutes() as f64 / 60.0)
+        .sum()
+}
+
+/// Estimated leaf wetness for each of the next [`OVERNIGHT_WETNESS_NIGHTS`]
+/// nights, 6 pm to 8 am in `now`'s time zone, over the same forecast steps as
+/// [`leaf_wetness_hours`] but with the overnight wet test (see
+/// [`leaves_wet_overnight`]). The night under way counts from `now`; nights
+/// the forecast doesn't reach are left out.
+pub fn overnight_wetness<Tz: TimeZone>(
+    points: &[ForecastPoint],
+    now: &DateTime<Tz>,
+) -> Vec<NightWetness> {
+    let steps = forecast_steps(points);
+
+    let local = |date: NaiveDate, hour: u32| {
+        now.timezone()
+            .from_local_datetime(&date.and_hms_opt(hour, 0, 0)?)
+            .earliest()
+            .map(|t| t.with_timezone(&Utc))
+    };
+    let now_utc = now.with_timezone(&Utc);
+    let today = now.date_naive();
+    let first = if now.hour() < OVERNIGHT_END_HOUR {
+        today.pred_opt().unwrap_or(today)
+    } else {
+        today
+    };
+
+    (0..OVERNIGHT_WETNESS_NIGHTS)
+        .filter_map(|n| {
+            let evening = first.checked_add_days(Days::new(n))?;
+            let start = local(evening, OVERNIGHT_START_HOUR)?.max(now_utc);
+            let end = local(evening.succ_opt()?, OVERNIGHT_END_HOUR)?;
+            let mut night = NightWetness {
+                evening,
+                wet_hours: 0.0,
+                forecast_hours: 0.0,
+                max_humidity_percent: 0.0,
+                min_dew_point_spread_f: f64::INFINITY,
+            };
+            for (from, to, p) in &steps {
+                let overlap = (*to).min(end) - (*from).max(start);
+                if overlap <= Duration::zero() {
+                    continue;
+                }
+                let hours = overlap.num_minutes() as f64 / 60.0;
+                let spread = dew_point_spread_f(DegreesF(p.temp_f), p.humidity_percent);
+                night.forecast_hours += hours;
+                if leaves_wet_overnight(p) {
+                    night.wet_hours += hours;
+                }
+                night.max_humidity_percent = night.max_humidity_percent.max(p.humidity_percent);
+                night.min_dew_point_spread_f = night.min_dew_point_spread_f.min(spread);
+            }
+            (night.forecast_hours > 0.0).then_some(night)
+        })
+        .collect()
+}
+
+/// Daily reference evapotranspiration (mm) by Hargreaves-Samani from the day's
+/// high and low and the extraterrestrial radiation at `latitude` on day-of-year
+/// `ordinal` (FAO-56 eqs. 21-25). Tends to overestimate in humid climates.
+pub fn hargreaves_et0_mm(
+    high: DegreesF,
+    low: DegreesF,
+    latitude: f64,
+    ordinal: u32,
+) -> Millimeters {
+    use std::f64::consts::PI;
+
+    let (tmax, tmin) = (high.to_celsius().0, low.to_celsius().0);
+    let phi = latitude.clamp(-66.0, 66.0).to_radians();
+    let j = 2.0 * PI * ordinal as f64 / 365.0;
+    let inverse_distance = 1.0 + 0.033 * j.cos();
+    let declination = 0.409 * (j - 1.39).sin();
+    let sunset_angle = (-phi.tan() * declination.tan()).clamp(-1.0, 1.0).acos();
+    let ra_mj = 24.0 * 60.0 / PI
+        * 0.0820
+        * inverse_distance
+        * (sunset_angle * phi.sin() * declination.sin()
+            + phi.cos() * declination.cos() * sunset_angle.sin());
+
+    // 0.408 converts MJ/m²/day to mm of evaporation
+    let et0 = 0.0023 * 0.408 * ra_mj * ((tmax + tmin) / 2.0 + 17.8) * (tmax - tmin).max(0.0).sqrt();
+    Millimeters(et0.max(0.0))
+}
+
+/// Derive current psychrometrics from the merged reading and leaf wetness from
+/// the forecast. Fields stay `None` when their inputs are missing.
+pub fn derive_metrics(env: &EnvironmentalSummary) -> DerivedMetrics {
+    let current = env
+        .current
+        .as_ref()
+        .and_then(|c| Some((DegreesF(c.ambient_temp_f?), c.humidity_percent?)));
+
+    DerivedMetrics {
+        dew_point_f: current.map(|(t, rh)| dew_point_f(t, rh).0),
+        dew_point_spread_f: current.map(|(t, rh)| dew_point_spread_f(t, rh)),
+        wet_bulb_f: current.map(|(t, rh)| wet_bulb_f(t, rh).0),
+        vpd_kpa: current.map(|(t, rh)| vpd_kpa(t, rh)),
+        leaf_wetness_hours_24h: env
+            .forecast
+            .as_ref()
+            .map(|f| leaf_wetness_hours(&f.next_hours(LEAF_WETNESS_WINDOW_HOURS))),
+        overnight_wetness: env
+            .forecast
+            .as_ref()
+            .map(|f| overnight_wetness(&f.hourly, &clock::now().with_timezone(&Local)))
+            .unwrap_or_default(),
+    }
+}
+
+/// Direction of `metric` over the latest `window` versus the `window` before it.
+///
+/// Readings are bucketed by timestamp relative to the newest sample, so gaps and
+/// non-hourly cadences don't shift the split. Returns `Unknown` unless both
+/// buckets have samples spanning at least half the window; otherwise a mean
+/// change beyond `threshold` (in the metric's units) is Rising/Falling.
+pub fn calculate_trend(
+    readings: &[EnvironmentalReading],
+    metric: impl Fn(&EnvironmentalReading) -> Option<f64>,
+    window: Duration,
+    threshold: f64,
+) -> Trend {
+    let samples: Vec<_> = readings
+        .iter()
+        .filter_map(|r| Some((r.timestamp, metric(r)?)))
+        .collect();
+    let Some(anchor) = samples.iter().map(|(ts, _)| *ts).max() else {
+        return Trend::Unknown;
+    };
+
+    let bucket_mean = |newer_than, up_to| {
+        let bucket: Vec<_> = samples
+            .iter()
+            .filter(|(ts, _)| *ts > newer_than && *ts <= up_to)
+            .collect();
+        let first = bucket.iter().map(|(ts, _)| *ts).min()?;
+        let last = bucket.iter().map(|(ts, _)| *ts).max()?;
+        if last - first < window / 2 {
+            return None;
+        }
+        Some(bucket.iter().map(|(_, v)| v).sum::<f64>() / bucket.len() as f64)
+    };
+
+    let recent = bucket_mean(anchor - window, anchor);
+    let previous = bucket_mean(anchor - window * 2, anchor - window);
+    let (Some(recent), Some(previous)) = (recent, previous) else {
+        return Trend::Unknown;
+    };
+
+    let diff = recent - previous;
+    if diff > threshold {
+        Trend::Rising
+    } else if diff < -threshold {
+        Trend::Falling
+    } else {
+        Trend::Stable
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{DataSource, WeatherCondition};
+    use chrono::Utc;
+
+    fn point(temp_f: f64, humidity_percent: f64) -> ForecastPoint {
+        ForecastPoint {
+            timestamp: Utc::now(),
+            temp_f,
+            feels_like_f: temp_f,
+            humidity_percent,
+            precipitation_mm: 0.0,
+            snow_mm: 0.0,
+            precipitation_prob: 0.0,
+            wind_speed_mph: 0.0,
+            wind_gust_mph: None,
+            cloud_cover_percent: 0.0,
+            weather_condition: WeatherCondition::Clear,
+        }
+    }
+
+    #[test]
+    fn dew_point_known_values() {
+        // Saturated air: dew point equals air temperature
+        assert!((dew_point_f(DegreesF(70.0), 100.0).0 - 70.0).abs() < 0.1);
+        // 77°F / 50% RH -> ~57°F dew point
+        assert!((dew_point_f(DegreesF(77.0), 50.0).0 - 57.0).abs() < 0.5);
+        assert!(dew_point_spread_f(DegreesF(77.0), 50.0) > 19.0);
+    }
+
+    #[test]
+    fn wet_bulb_known_values() {
+        // Stull's reference point: 20°C / 50% RH -> 13.7°C (56.7°F)
+        assert!((wet_bulb_f(DegreesF(68.0), 50.0).0 - 56.7).abs() < 0.5);
+        // Wet-bulb never exceeds air temp
+        assert!(wet_bulb_f(DegreesF(90.0), 95.0).0 <= 90.0);
+    }
+
+    #[test]
+    fn heat_index_known_values() {
+        // NWS table: 90°F / 60% RH -> 100°F, 95°F / 50% -> 105°F
+        assert!((heat_index_f(DegreesF(90.0), 60.0).0 - 100.0).abs() < 1.0);
+        assert!((heat_index_f(DegreesF(95.0), 50.0).0 - 105.0).abs() < 1.0);
+        // Mild conditions use the simple formula and stay close to air temp
+        assert!((heat_index_f(DegreesF(70.0), 50.0).0 - 69.0).abs() < 2.0);
+    }
+
+    #[test]
+    fn hargreaves_known_values() {
+        // Mid-July at 40°N (Ra ~40.8 MJ/m²), 90/68°F: about 5.9 mm/day
+        let july = hargreaves_et0_mm(DegreesF(90.0), DegreesF(68.0), 40.0, 196).0;
+        assert!((july - 5.88).abs() < 0.05, "{}", july);
+        // A cool, flat January day barely evaporates anything
+        assert!(hargreaves_et0_mm(DegreesF(40.0), DegreesF(30.0), 40.0, 15).0 < 1.0);
+        assert_eq!(
+            hargreaves_et0_mm(DegreesF(60.0), DegreesF(60.0), 40.0, 196).0,
+            0.0
+        );
+    }
+
+    #[test]
+    fn vpd_known_values() {
+        // 25°C saturation vapor pressure ~3.17 kPa; at 50% RH the deficit is half
+        assert!((vpd_kpa(DegreesF(77.0), 50.0) - 1.58).abs() < 0.02);
+        assert_eq!(vpd_kpa(DegreesF(77.0), 100.0), 0.0);
+    }
+
+    #[test]
+    fn overnight_and_daily_wetness_use_their_own_tests() {
+        // Spread ~2.8°F: wet for the 24-hour proxy, not overnight
+        let cool = point(50.0, 90.0);
+        assert!(leaves_wet(&cool));
+        assert!(!leaves_wet_overnight(&cool));
+        // Humidity over 90% with a spread just over 3°F: wet overnight only
+        let hot = point(95.0, 91.0);
+        assert!(!leaves_wet(&hot));
+        assert!(leaves_wet_overnight(&hot));
+    }
+
+    #[test]
+    fn overnight_wetness_splits_the_forecast_into_nights() {
+        let tz = chrono::FixedOffset::west_opt(5 * 3600).unwrap();
+        // 3 pm local; 3-hourly points from 3 pm through 3 am two nights later
+        let now = tz.with_ymd_and_hms(2026, 7, 14, 15, 0, 0).unwrap();
+        let points: Vec<ForecastPoint> = (0..13)
+            .map(|i| {
+                let at = now + Duration::hours(3 * i);
+                // Humid from 9 pm to 6 am local, dry otherwise
+                let humid = !(6..21).contains(&at.hour());
+                let mut p = if humid {
+                    point(68.0, 95.0)
+                } else {
+                    point(85.0, 55.0)
+                };
+                p.timestamp = at.with_timezone(&Utc);
+                p
+            })
+            .collect();
+
+        let nights = overnight_wetness(&points, &now);
+        assert_eq!(nights.len(), 2, "the third night is past the forecast");
+        let tonight = &nights[0];
+        assert_eq!(
+            tonight.evening,
+            NaiveDate::from_ymd_opt(2026, 7, 14).unwrap()
+        );
+        assert_eq!(tonight.forecast_hours, 14.0);
+        // 9 pm, midnight and 3 am steps
+        assert_eq!(tonight.wet_hours, 9.0);
+        assert_eq!(tonight.max_humidity_percent, 95.0);
+        // The second night runs off the end of the forecast at 6 am
+        assert_eq!(nights[1].forecast_hours, 12.0);
+
+        // At 2 am the night under way counts from now
+        let late = tz.with_ymd_and_hms(2026, 7, 15, 2, 0, 0).unwrap();
+        let nights = overnight_wetness(&points, &late);
+        assert_eq!(
+            nights[0].evening,
+            NaiveDate::from_ymd_opt(2026, 7, 14).unwrap()
+        );
+        assert_eq!(nights[0].forecast_hours, 6.0);
+        assert_eq!(nights[0].wet_hours, 4.0);
+
+        // Hourly points each count for an hour
+        let hourly: Vec<ForecastPoint> = (0..24)
+            .map(|i| {
+                let mut p = point(68.0, 95.0);
+                p.timestamp = (now + Duration::hours(i)).with_timezone(&Utc);
+                p
+            })
+            .collect();
+        assert_eq!(overnight_wetness(&hourly, &now)[0].wet_hours, 14.0);
+        assert!(overnight_wetness(&[], &now).is_empty());
+    }
+
+    #[test]
+    fn leaf_wetness_counts_near_saturated_steps() {
+        let t0 = Utc::now();
+        let at = |hours: i64, mut p: ForecastPoint| {
+            p.timestamp = t0 + Duration::hours(hours);
+            p
+        };
+        let (wet, dry) = (point(65.0, 95.0), point(80.0, 50.0));
+        let three_hourly = [at(0, wet.clone()), at(3, dry.clone()), at(6, wet.clone())];
+        assert_eq!(leaf_wetness_hours(&three_hourly.iter().collect::<Vec<_>>()), 6.0);
+        // Hourly points count an hour each, as in the nightly breakdown
+        let hourly = [at(0, wet.clone()), at(1, wet), at(2, dry)];
+        assert_eq!(leaf_wetness_hours(&hourly.iter().collect::<Vec<_>>()), 2.0);
+        assert_eq!(leaf_wetness_hours(&[]), 0.0);
+    }
+
+    /// One soil-temp reading per `step_hours`, newest first, following `temp_at(hours_ago)`.
+    fn series(
+        hours: i64,
+        step_hours: i64,
+        temp_at: impl Fn(i64) -> f64,
+    ) -> Vec<EnvironmentalReading> {
+        let now = Utc::now();
+        (0..hours)
+            .step_by(step_hours as usize)
+            .map(|h| {
+                let mut r = EnvironmentalReading::new(DataSource::SoilData);
+                r.timestamp = now - Duration::hours(h);
+                r.soil_temp_10_f = Some(temp_at(h));
+                r
+            })
+            .collect()
+    }
+
+    #[test]
+    fn trend_compares_consecutive_windows() {
+        let window = Duration::hours(24);
+        let soil = |r: &EnvironmentalReading| r.soil_temp_10_f;
+
+        let warming = series(48, 1, |h| if h < 24 { 55.0 } else { 50.0 });
+        assert_eq!(calculate_trend(&warming, soil, window, 2.0), Trend::Rising);
+
+        let cooling = series(48, 1, |h| if h < 24 { 50.0 } else { 55.0 });
+        assert_eq!(calculate_trend(&cooling, soil, window, 2.0), Trend::Falling);
+
+        let flat = series(48, 1, |_| 52.0);
+        assert_eq!(calculate_trend(&flat, soil, window, 2.0), Trend::Stable);
+    }
+
+    #[test]
+    fn trend_is_timestamp_based_not_index_based() {
+        let soil = |r: &EnvironmentalReading| r.soil_temp_10_f;
+        // 3-hourly cadence: only 16 samples, but they still cover two 24h windows
+        let sparse = series(48, 3, |h| if h < 24 { 56.0 } else { 50.0 });
+        assert_eq!(
+            calculate_trend(&sparse, soil, Duration::hours(24), 2.0),
+            Trend::Rising
+        );
+        // Longer windows just need enough history
+        assert_eq!(
+            calculate_trend(&sparse, soil, Duration::hours(72), 2.0),
+            Trend::Unknown
+        );
+    }
+
+    #[test]
+    fn trend_unknown_without_coverage() {
+        let soil = |r: &EnvironmentalReading| r.soil_temp_10_f;
+        // Only 30 hours: the previous window is barely populated
+        let short = series(30, 1, |h| if h < 24 { 55.0 } else { 50.0 });
+        assert_eq!(
+            calculate_trend(&short, soil, Duration::hours(24), 2.0),
+            Trend::Unknown
+        );
+        assert_eq!(
+            calculate_trend(&[], soil, Duration::hours(24), 2.0),
+            Trend::Unknown
+        );
+    }
+}
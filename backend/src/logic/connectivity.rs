@@ -0,0 +1,101 @@
+//! Datasource connectivity, checked in the background so the dashboard and
+//! health endpoint answer right away instead of waiting on a slow or
+//! unreachable source. Until the first check finishes they report
+//! [`ConnectionStatus::pending`], which the UI shows as "connecting…".
+
+use crate::logic::data_sync::ConnectionStatus;
+use crate::state::AppState;
+use std::sync::RwLock;
+use std::time::Duration;
+
+/// How often sources are re-checked after the first check at startup.
+const CONNECTION_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Debug)]
+pub struct ConnectionMonitor {
+    latest: RwLock<ConnectionStatus>,
+}
+
+impl Default for ConnectionMonitor {
+    fn default() -> Self {
+        Self {
+            latest: RwLock::new(ConnectionStatus::pending()),
+        }
+    }
+}
+
+impl ConnectionMonitor {
+    /// The last finished check, or a pending status before the first.
+    pub fn latest(&self) -> ConnectionStatus {
+        self.latest.read().expect("connection status lock").clone()
+    }
+
+    /// Store a finished check, returning the sensor sources whose state changed.
+    pub fn record(&self, status: ConnectionStatus) -> Vec<(String, bool)> {
+        let mut latest = self.latest.write().expect("connection status lock");
+        let changed = status
+            .sources
+            .iter()
+            .filter(|(key, ok)| latest.pending || latest.sources.get(*key) != Some(ok))
+            .map(|(key, ok)| (key.clone(), *ok))
+            .collect();
+        *latest = status;
+        changed
+    }
+}
+
+/// Check every datasource now and then every [`CONNECTION_CHECK_INTERVAL`].
+pub fn spawn_connection_monitor(state: AppState) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(CONNECTION_CHECK_INTERVAL);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            // Clone the clients and release the lock before any network I/O
+            let probe = state.sync_service.read().await.connection_probe();
+            let status = probe.run().await;
+            for (source, ok) in state.connections.record(status) {
+                if ok {
+                    tracing::info!(source = %source, "Datasource online");
+                } else {
+                    tracing::warn!(source = %source, "Datasource unreachable");
+                }
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status(uscrn: bool, homeassistant: bool) -> ConnectionStatus {
+        ConnectionStatus {
+            soildata: uscrn,
+            homeassistant,
+            sources: [
+                ("uscrn".to_string(), uscrn),
+                ("homeassistant".to_string(), homeassistant),
+            ]
+            .into(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn pending_until_first_check_then_reports_changes() {
+        let monitor = ConnectionMonitor::default();
+        assert!(monitor.latest().pending);
+
+        // Every source is news on the first check
+        assert_eq!(monitor.record(status(true, false)).len(), 2);
+        assert!(!monitor.latest().pending);
+
+        assert!(monitor.record(status(true, false)).is_empty());
+        assert_eq!(
+            monitor.record(status(true, true)),
+            vec![("homeassistant".to_string(), true)]
+        );
+    }
+}
@@ -1,27 +1,91 @@
+use crate::clock;
 use crate::config::Config;
-use crate::datasources::{HomeAssistantClient, OpenWeatherMapClient, WeatherLakeClient};
-use crate::logic::soil_temp_prediction;
-use crate::models::{DataSource, EnvironmentalReading, EnvironmentalSummary, WeatherForecast};
-use chrono::{Datelike, Duration, Utc};
+use crate::datasources::quality;
+use crate::datasources::source::{parse_priority, Capability};
+use crate::datasources::weather::{summarize_readings, SUMMARY_WINDOW_DAYS};
+use crate::datasources::{
+    DroughtMonitorClient, EcowittClient, EnvironmentalSource, HomeAssistantClient, OpenMeteoClient,
+    OpenWeatherMapClient, SourceReading, SourceRegistry, TempestClient, WeatherLakeClient,
+};
+use crate::db::{event_queries, rain_check_queries, soil_observation_queries};
+use crate::error::TurfOpsError;
+use crate::logic::api_quota::ApiQuota;
+use crate::logic::rain_check::{self, RainCheckAction};
+use crate::logic::{calculations, frost, soil_anomaly, soil_temp_prediction, stress_index};
+use crate::models::seasonal_plan::DailySoilTempAvg;
+use crate::models::{
+    AirQuality, DataSource, DroughtStatus, EnvironmentalSummary, Event, EventKind, EventLevel,
+    Freshness, FrostYear, LawnProfile, Location, WeatherForecast,
+};
+use chrono::{Datelike, Duration, NaiveDate, Utc};
 use sqlx::PgPool;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::Arc;
 use tokio::time::Instant;
 
 /// How long before sensor data (weather lake + Home Assistant) is considered stale.
 const SENSOR_STALENESS_SECS: u64 = 5 * 60; // 5 minutes
 
-/// How long before forecast data (OpenWeatherMap) is considered stale.
+/// How long before forecast data (OpenWeatherMap) is considered stale,
+/// stretched when the daily quota runs short (see `logic/api_quota.rs`).
 const FORECAST_STALENESS_SECS: u64 = 30 * 60; // 30 minutes
 
+/// How long before the Drought Monitor status is re-read. Maps change weekly.
+const DROUGHT_STALENESS_SECS: u64 = 12 * 60 * 60; // 12 hours
+
+/// How far back the first local sync reaches (covers the 7-day summary window).
+const LOCAL_SYNC_BACKFILL_DAYS: i64 = 8;
+
+/// Prior years of station history the frost dates and soil normal come from.
+const FROST_HISTORY_YEARS: i32 = 10;
+
 pub struct DataSyncService {
-    #[allow(dead_code)]
     pool: PgPool,
     weather_client: Option<WeatherLakeClient>,
-    homeassistant_client: Option<HomeAssistantClient>,
+    /// Summarize soil data from the local `soil_observations` copy instead of the lake.
+    local_sync: bool,
+    /// Sensor sources beyond the lake (Home Assistant, ...), merged into the
+    /// current reading per capability.
+    sources: SourceRegistry,
+    /// `STALE_DATA_HOURS`, `None` when the watchdog is off.
+    stale_after_hours: Option<u32>,
     openweathermap_client: Option<OpenWeatherMapClient>,
-    current_summary: EnvironmentalSummary,
-    current_forecast: Option<WeatherForecast>,
+    /// Daily call count behind `openweathermap_client`, shared by its clones.
+    forecast_quota: Option<Arc<ApiQuota>>,
+    drought_client: Option<DroughtMonitorClient>,
+    historical_client: Option<OpenMeteoClient>,
+    /// Cached data per lawn profile id, since profiles can override location and station.
+    caches: HashMap<i64, ProfileCache>,
+    /// A running server owns the shared tables: summarize the local soil copy
+    /// it keeps instead of syncing, and leave rain checks and the activity log
+    /// to it. Set for commands run alongside the server.
+    follower: bool,
+}
+
+#[derive(Default)]
+struct ProfileCache {
+    summary: EnvironmentalSummary,
+    forecast: Option<WeatherForecast>,
     last_sensor_refresh: Option<Instant>,
     last_forecast_refresh: Option<Instant>,
+    drought: Option<DroughtStatus>,
+    last_drought_refresh: Option<Instant>,
+    air_quality: Option<AirQuality>,
+    /// Frost dates from the prior years of lows, with the year they were
+    /// computed for. Past years don't change, so they're re-read only when
+    /// the year turns.
+    frost_years: Option<(i32, Vec<FrostYear>)>,
+    /// Prior years' daily soil temps behind the soil normal, likewise kept
+    /// for the year they were read in.
+    soil_temp_history: Option<(i32, Vec<DailySoilTempAvg>)>,
+}
+
+/// Data source clients resolved for one profile: its overrides, else the globals.
+struct ProfileClients {
+    lake: Option<WeatherLakeClient>,
+    /// The local `soil_observations` copy only holds the global stations.
+    local_sync: bool,
+    forecast: Option<OpenWeatherMapClient>,
 }
 
 impl DataSyncService {
@@ -29,27 +93,59 @@ impl DataSyncService {
     /// Clients are configured but not tested — connections are validated
     /// on first data fetch, avoiding slow startup when external services are down.
     pub async fn initialize(config: &Config, pool: PgPool) -> Self {
-        let homeassistant_client = if !config.homeassistant.token.is_empty() {
+        let http_timeout = std::time::Duration::from_secs(config.sources.http_timeout_secs);
+        let mut sources = SourceRegistry::new(parse_priority(&config.sources.priority));
+        if !config.homeassistant.token.is_empty() {
             tracing::info!(
                 url = %config.homeassistant.url,
                 "Home Assistant client configured (connection tested on first fetch)"
             );
-            Some(HomeAssistantClient::new(config.homeassistant.clone()))
-        } else {
+            sources.register(Arc::new(HomeAssistantClient::new(
+                config.homeassistant.clone(),
+                http_timeout,
+            )));
+        } else if config.ecowitt.is_none() && config.tempest.is_none() {
             tracing::warn!(
                 "Home Assistant token not configured - ambient data will be unavailable"
             );
-            None
-        };
+        }
+        if let Some(ref ecowitt) = config.ecowitt {
+            tracing::info!(
+                url = %ecowitt.url,
+                "Ecowitt gateway configured (connection tested on first fetch)"
+            );
+            sources.register(Arc::new(EcowittClient::new(ecowitt.clone(), http_timeout)));
+        }
+        if let Some(ref tempest) = config.tempest {
+            tracing::info!(
+                station_id = %tempest.station_id,
+                "Tempest station configured (connection tested on first fetch)"
+            );
+            sources.register(Arc::new(TempestClient::new(tempest.clone(), http_timeout)));
+        }
 
-        let openweathermap_client = config
+        let mut openweathermap_client = None;
+        let mut forecast_quota = None;
+        if let Some(owm) = config
             .openweathermap
             .as_ref()
             .filter(|c| c.enabled && !c.api_key.is_empty())
-            .map(|c| {
-                tracing::info!("OpenWeatherMap client configured for forecast data");
-                OpenWeatherMapClient::new(c.clone())
-            });
+        {
+            tracing::info!("OpenWeatherMap client configured for forecast data");
+            let client = OpenWeatherMapClient::new(owm.clone(), http_timeout);
+            let quota = Arc::new(
+                ApiQuota::load(
+                    pool.clone(),
+                    DataSource::OpenWeatherMap,
+                    owm.daily_quota,
+                    std::time::Duration::from_secs(FORECAST_STALENESS_SECS),
+                    client.calls_per_refresh(),
+                )
+                .await,
+            );
+            openweathermap_client = Some(client.with_quota(quota.clone()));
+            forecast_quota = Some(quota);
+        }
 
         if openweathermap_client.is_none() {
             tracing::info!(
@@ -57,139 +153,414 @@ impl DataSyncService {
             );
         }
 
+        let drought_client = config.drought_monitor.enabled.then(|| {
+            tracing::info!("U.S. Drought Monitor status enabled");
+            DroughtMonitorClient::new(&config.drought_monitor, http_timeout)
+        });
+
+        let historical_client = config.historical_weather.enabled.then(|| {
+            tracing::info!("Open-Meteo historical weather enabled for dates before the lake");
+            OpenMeteoClient::new(http_timeout)
+        });
+
         let weather_client = Some(WeatherLakeClient::new(
             &config.datalake,
-            config.noaa.station_wbanno,
+            config.noaa.station_wbannos.clone(),
         ));
         tracing::info!("Weather data lake client configured (parquet read on first fetch)");
+        if config.datalake.local_sync {
+            tracing::info!("Local soil data sync enabled - summaries computed from Postgres copy");
+        }
 
         Self {
             pool,
             weather_client,
-            homeassistant_client,
+            local_sync: config.datalake.local_sync,
+            sources,
+            stale_after_hours: Some(config.sources.stale_after_hours).filter(|h| *h > 0),
             openweathermap_client,
-            current_summary: EnvironmentalSummary::default(),
-            current_forecast: None,
-            last_sensor_refresh: None,
-            last_forecast_refresh: None,
+            forecast_quota,
+            drought_client,
+            historical_client,
+            caches: HashMap::new(),
+            follower: false,
         }
     }
 
-    /// Return cached summary if fresh, otherwise fetch from datasources first.
-    /// Sensor data refreshes after 5 minutes, forecast after 30 minutes.
-    pub async fn get_or_refresh(&mut self) -> crate::error::Result<EnvironmentalSummary> {
-        let sensor_stale = self.is_sensor_stale();
-        let forecast_stale = self.is_forecast_stale();
+    /// Read what the running server syncs rather than writing alongside it.
+    pub fn follow_server(&mut self) {
+        self.follower = true;
+    }
+
+    /// OpenWeatherMap calls today against the quota, if it's configured.
+    pub fn forecast_quota(&self) -> Option<Arc<ApiQuota>> {
+        self.forecast_quota.clone()
+    }
+
+    /// Return the profile's cached summary if fresh, otherwise fetch from
+    /// datasources first. Sensor data refreshes after 5 minutes, forecast after
+    /// 30 (longer while the OpenWeatherMap quota runs short).
+    pub async fn get_or_refresh(
+        &mut self,
+        profile: &LawnProfile,
+    ) -> crate::error::Result<EnvironmentalSummary> {
+        let cache = self.caches.get(&cache_key(profile));
+        let sensor_stale = is_stale(
+            cache.and_then(|c| c.last_sensor_refresh),
+            SENSOR_STALENESS_SECS,
+        );
+        let forecast_max_age = self
+            .forecast_quota
+            .as_ref()
+            .map_or(FORECAST_STALENESS_SECS, |q| {
+                q.refresh_interval(Utc::now()).as_secs()
+            });
+        let forecast_stale = is_stale(
+            cache.and_then(|c| c.last_forecast_refresh),
+            forecast_max_age,
+        );
 
         if sensor_stale || forecast_stale {
-            self.refresh_internal(sensor_stale, forecast_stale).await?;
+            return self
+                .refresh_internal(profile, sensor_stale, forecast_stale, false)
+                .await;
         }
 
-        Ok(self.current_summary.clone())
+        Ok(cache.map(|c| c.summary.clone()).unwrap_or_default())
     }
 
     /// Always fetch fresh data from all datasources, ignoring cache age.
     /// Used by the explicit refresh button in the frontend.
-    pub async fn force_refresh(&mut self) -> crate::error::Result<EnvironmentalSummary> {
-        self.refresh_internal(true, true).await
+    pub async fn force_refresh(
+        &mut self,
+        profile: &LawnProfile,
+    ) -> crate::error::Result<EnvironmentalSummary> {
+        self.refresh_internal(profile, true, true, false).await
     }
 
-    pub async fn check_connections(&self) -> ConnectionStatus {
-        let mut status = ConnectionStatus::default();
+    /// A forced refresh that also ignores the slower caches: the local soil
+    /// copy re-pulls the whole 7-day window (picking up late lake corrections)
+    /// and the Drought Monitor is re-fetched regardless of its 12-hour TTL.
+    pub async fn deep_refresh(
+        &mut self,
+        profile: &LawnProfile,
+    ) -> crate::error::Result<EnvironmentalSummary> {
+        self.refresh_internal(profile, true, true, true).await
+    }
+
+    /// Drop a profile's cached data, e.g. after its location or station changed.
+    pub fn invalidate(&mut self, profile_id: i64) {
+        self.caches.remove(&profile_id);
+    }
 
+    /// The clients `check_connections` tests, cloned so slow sources can be
+    /// probed without holding the service lock.
+    pub fn connection_probe(&self) -> ConnectionProbe {
+        let mut sources: Vec<Arc<dyn EnvironmentalSource>> = Vec::new();
         if let Some(ref client) = self.weather_client {
-            status.soildata = client.test_connection().await.unwrap_or(false);
+            sources.push(Arc::new(client.clone()));
         }
-
-        if let Some(ref client) = self.homeassistant_client {
-            status.homeassistant = client.test_connection().await.unwrap_or(false);
+        sources.extend(self.sources.sources().iter().cloned());
+        ConnectionProbe {
+            sources,
+            forecast: self.openweathermap_client.clone(),
         }
+    }
 
-        if let Some(ref client) = self.openweathermap_client {
-            status.openweathermap = client.test_connection().await.unwrap_or(false);
+    pub async fn check_connections(&self) -> ConnectionStatus {
+        self.connection_probe().run().await
+    }
+
+    /// Global forecast coordinates, if OpenWeatherMap is configured.
+    pub fn location(&self) -> Option<Location> {
+        self.openweathermap_client.as_ref().map(|c| c.location())
+    }
+
+    /// Move the global forecast to a new location and force the next refresh to
+    /// fetch it (and the county's drought status) for every profile.
+    pub fn set_location(&mut self, location: &Location) {
+        if let Some(ref mut client) = self.openweathermap_client {
+            client.set_location(location);
+            for cache in self.caches.values_mut() {
+                cache.forecast = None;
+                cache.air_quality = None;
+                cache.last_forecast_refresh = None;
+                cache.last_drought_refresh = None;
+            }
         }
+    }
 
-        status
+    /// Look up coordinates for a city or postal code.
+    pub async fn geocode(&self, query: &str) -> crate::error::Result<Vec<Location>> {
+        let client = self.openweathermap_client.as_ref().ok_or_else(|| {
+            crate::error::TurfOpsError::DataSourceUnavailable(
+                "Geocoding needs OpenWeatherMap (set OWM_API_KEY)".into(),
+            )
+        })?;
+        client.geocode(query).await
     }
 
-    /// Provide access to the weather lake client for direct queries (GDD, historical).
+    /// Weather lake client on the global stations. The seasonal plan's cached
+    /// threshold crossings are computed from these.
     pub fn weather_client(&self) -> Option<&WeatherLakeClient> {
         self.weather_client.as_ref()
     }
 
-    fn is_sensor_stale(&self) -> bool {
-        match self.last_sensor_refresh {
-            None => true,
-            Some(t) => t.elapsed().as_secs() >= SENSOR_STALENESS_SECS,
+    /// Weather lake client for direct queries (GDD, historical), reading the
+    /// profile's own station when it has one.
+    pub fn weather_client_for(&self, profile: &LawnProfile) -> Option<WeatherLakeClient> {
+        self.clients_for(profile).lake
+    }
+
+    /// Archive client at the global location, alongside [`Self::weather_client`].
+    pub fn historical_client(&self) -> Option<OpenMeteoClient> {
+        Some(self.historical_client.as_ref()?.at(&self.location()?))
+    }
+
+    /// Archive client at the profile's location (or the global one), for
+    /// dates the lake has no data for. `None` when disabled or unlocated.
+    pub fn historical_client_for(&self, profile: &LawnProfile) -> Option<OpenMeteoClient> {
+        let location = profile.location.clone().or_else(|| self.location())?;
+        Some(self.historical_client.as_ref()?.at(&location))
+    }
+
+    fn clients_for(&self, profile: &LawnProfile) -> ProfileClients {
+        let station = profile.noaa_station_wbanno;
+        ProfileClients {
+            lake: self.weather_client.as_ref().map(|client| match station {
+                Some(station) => client.for_station(station),
+                None => client.clone(),
+            }),
+            local_sync: self.local_sync && station.is_none(),
+            forecast: self.openweathermap_client.as_ref().map(|client| {
+                let mut client = client.clone();
+                if let Some(ref location) = profile.location {
+                    client.set_location(location);
+                }
+                client
+            }),
         }
     }
 
-    fn is_forecast_stale(&self) -> bool {
-        match self.last_forecast_refresh {
-            None => true,
-            Some(t) => t.elapsed().as_secs() >= FORECAST_STALENESS_SECS,
+    async fn record_source_error(&self, source: DataSource, error: &crate::error::TurfOpsError) {
+        self.record_event(
+            Event::new(
+                EventKind::DataSourceError,
+                EventLevel::Warning,
+                format!("Failed to fetch from {}", source),
+            )
+            .with_details(error.to_string()),
+        )
+        .await;
+    }
+
+    /// The server logs its own refreshes; a follower's would be duplicates.
+    async fn record_event(&self, event: Event) {
+        if !self.follower {
+            event_queries::record_event(&self.pool, event).await;
         }
     }
 
+    /// Append lake rows newer than the local watermark, then summarize the last
+    /// 7 days from the local table. A failed lake read is recorded but not fatal:
+    /// the summary falls back to whatever was synced before the outage.
+    async fn sync_and_summarize_local(
+        &self,
+        client: &WeatherLakeClient,
+        resync: bool,
+    ) -> crate::error::Result<EnvironmentalSummary> {
+        let now = Utc::now();
+        let backfill_start = now - Duration::days(LOCAL_SYNC_BACKFILL_DAYS);
+        let after = if resync {
+            backfill_start
+        } else {
+            soil_observation_queries::latest_observed_at(&self.pool)
+                .await?
+                .unwrap_or(backfill_start)
+        };
+
+        // A follower reads the copy the server keeps current
+        let fetched = if self.follower {
+            None
+        } else {
+            Some(client.fetch_new_readings(after).await)
+        };
+        match fetched {
+            None => {}
+            Some(Ok((station, readings))) => {
+                let written =
+                    soil_observation_queries::upsert_readings(&self.pool, station, &readings)
+                        .await?;
+                tracing::debug!(station, written, "Synced soil observations from data lake");
+            }
+            Some(Err(e)) => {
+                tracing::warn!("Soil data sync failed, using local copy: {}", e);
+                self.record_source_error(DataSource::SoilData, &e).await;
+            }
+        }
+
+        let readings = soil_observation_queries::get_readings_in_range(
+            &self.pool,
+            now - Duration::days(SUMMARY_WINDOW_DAYS),
+            now,
+        )
+        .await?;
+        Ok(summarize_readings(
+            readings.first().cloned(),
+            &readings,
+            now,
+            client.trend_window(),
+        ))
+    }
+
+    /// Reconcile a pending rain check whose window has passed against the
+    /// station (on sensor refreshes), then record or adjust the check for the
+    /// forecast's next rain event (on forecast refreshes).
+    async fn update_rain_checks(
+        &self,
+        profile: &LawnProfile,
+        lake: Option<&WeatherLakeClient>,
+        reconcile: bool,
+        forecast: Option<&WeatherForecast>,
+    ) -> crate::error::Result<()> {
+        let Some(profile_id) = profile.id else {
+            return Ok(());
+        };
+        let now = Utc::now();
+        let mut pending =
+            rain_check_queries::get_pending_rain_check(&self.pool, profile_id).await?;
+
+        if let (true, Some(check), Some(client)) = (reconcile, pending.as_ref(), lake) {
+            if let Some(id) = check
+                .id
+                .filter(|_| rain_check::ready_to_reconcile(check, now))
+            {
+                let readings = client
+                    .fetch_history(check.window_start, check.window_end)
+                    .await?;
+                let observed =
+                    rain_check::observed_rain_mm(&readings, check.window_start, check.window_end);
+                if observed.is_some() || rain_check::should_give_up(check, now) {
+                    rain_check_queries::reconcile_rain_check(&self.pool, id, observed).await?;
+                    tracing::debug!(
+                        id,
+                        ?observed,
+                        forecast_mm = check.forecast_mm,
+                        "Rain check reconciled"
+                    );
+                    pending = None;
+                }
+            }
+        }
+
+        let Some(forecast) = forecast else {
+            return Ok(());
+        };
+        let window = rain_check::forecast_rain_window(forecast, now);
+        match rain_check::plan_rain_check(pending.as_ref(), window, now) {
+            RainCheckAction::Create(w) => {
+                rain_check_queries::create_rain_check(&self.pool, profile_id, &w).await?;
+            }
+            RainCheckAction::Update(id, w) => {
+                rain_check_queries::update_rain_check_window(&self.pool, id, &w).await?;
+            }
+            RainCheckAction::Delete(id) => {
+                rain_check_queries::delete_rain_check(&self.pool, id).await?;
+            }
+            RainCheckAction::Keep => {}
+        }
+        Ok(())
+    }
+
     async fn refresh_internal(
         &mut self,
+        profile: &LawnProfile,
         refresh_sensors: bool,
         refresh_forecast: bool,
+        deep: bool,
     ) -> crate::error::Result<EnvironmentalSummary> {
+        let clients = self.clients_for(profile);
+        let mut cache = self.caches.remove(&cache_key(profile)).unwrap_or_default();
         let mut summary = EnvironmentalSummary::default();
-        let mut combined_reading = EnvironmentalReading::new(DataSource::Cached);
 
         if refresh_sensors {
+            let mut readings: Vec<SourceReading> = Vec::new();
+
             // Fetch soil/weather data from the data lake (silver hourly)
-            if let Some(ref client) = self.weather_client {
-                match client.fetch_summary().await {
+            if let Some(ref client) = clients.lake {
+                let result = if clients.local_sync {
+                    self.sync_and_summarize_local(client, deep).await
+                } else {
+                    client.fetch_summary().await
+                };
+                match result {
                     Ok(soil_summary) => {
                         summary = soil_summary;
-                        if let Some(ref current) = summary.current {
-                            combined_reading.soil_temp_5_f = current.soil_temp_5_f;
-                            combined_reading.soil_temp_10_f = current.soil_temp_10_f;
-                            combined_reading.soil_temp_20_f = current.soil_temp_20_f;
-                            combined_reading.soil_temp_50_f = current.soil_temp_50_f;
-                            combined_reading.soil_temp_100_f = current.soil_temp_100_f;
-                            combined_reading.soil_moisture_5 = current.soil_moisture_5;
-                            combined_reading.soil_moisture_10 = current.soil_moisture_10;
-                            combined_reading.soil_moisture_20 = current.soil_moisture_20;
-                            combined_reading.soil_moisture_50 = current.soil_moisture_50;
-                            combined_reading.soil_moisture_100 = current.soil_moisture_100;
-                            combined_reading.precipitation_mm = current.precipitation_mm;
+                        if let Some(current) = summary.current.clone() {
+                            readings.push(SourceReading::from_source(client, current));
                         }
                     }
                     Err(e) => {
                         tracing::warn!("Failed to fetch soil data: {}", e);
+                        self.record_source_error(DataSource::SoilData, &e).await;
                     }
                 }
             }
 
-            // Fetch ambient data from Home Assistant
-            if let Some(ref client) = self.homeassistant_client {
-                match client.fetch_current().await {
-                    Ok(ha_reading) => {
-                        if ha_reading.ambient_temp_f.is_some() {
-                            combined_reading.ambient_temp_f = ha_reading.ambient_temp_f;
-                        }
-                        if ha_reading.humidity_percent.is_some() {
-                            combined_reading.humidity_percent = ha_reading.humidity_percent;
-                        }
+            // Current readings from the other sensor sources (ambient from Home Assistant, ...)
+            for source in self.sources.sources() {
+                match source.fetch_current().await {
+                    Ok(reading) => {
+                        readings.push(SourceReading::from_source(source.as_ref(), reading));
                     }
                     Err(e) => {
-                        tracing::warn!("Failed to fetch Home Assistant data: {}", e);
+                        tracing::warn!("Failed to fetch {} data: {}", source.source(), e);
+                        self.record_source_error(source.source(), &e).await;
                     }
                 }
             }
 
+            let sources: Vec<&str> = readings.iter().map(|r| r.source.as_str()).collect();
+            let mut combined_reading = self.sources.merge(&readings);
+            quality::reject_impossible(&mut combined_reading);
             combined_reading.timestamp = Utc::now();
+            let observed = |c| self.sources.observed_at(c, &readings);
+            summary.freshness = Freshness {
+                max_age_hours: self.stale_after_hours,
+                soil_temp: observed(Capability::SoilTemperature),
+                soil_moisture: observed(Capability::SoilMoisture),
+                ambient_temp: observed(Capability::AmbientTemperature),
+                humidity: observed(Capability::Humidity),
+                precipitation: observed(Capability::Precipitation),
+            };
+            let stale = summary.freshness.stale_metrics(Utc::now());
+            if !stale.is_empty() {
+                tracing::warn!(
+                    metrics = %stale.join(", "),
+                    "Current readings older than {}h are treated as missing",
+                    self.stale_after_hours.unwrap_or_default()
+                );
+            }
             summary.current = Some(combined_reading.clone());
             summary.last_updated = Some(Utc::now());
-            self.last_sensor_refresh = Some(Instant::now());
+            cache.last_sensor_refresh = Some(Instant::now());
+            self.record_event(
+                Event::new(
+                    EventKind::DataRefresh,
+                    if sources.is_empty() {
+                        EventLevel::Warning
+                    } else {
+                        EventLevel::Info
+                    },
+                    format!("Sensor data refreshed from {} source(s)", sources.len()),
+                )
+                .with_details(format!("{} ({})", sources.join(", "), profile.name)),
+            )
+            .await;
 
             // Populate GDD YTD by summing the gold layer's precomputed daily gdd50
             let current_year = Utc::now().year();
-            if let Some(ref client) = self.weather_client {
+            if let Some(ref client) = clients.lake {
                 match client.fetch_gdd_ytd(current_year).await {
                     Ok(gdd) => {
                         summary.gdd_base50_ytd = gdd;
@@ -200,8 +571,48 @@ impl DataSyncService {
                 }
             }
 
+            // Spring and fall frost dates from prior years of station lows
+            if let Some(ref client) = clients.lake {
+                match cache.frost_years {
+                    Some((year, ref years)) if year == current_year => {
+                        summary.frost_years = years.clone();
+                    }
+                    _ => match fetch_frost_years(client, current_year).await {
+                        Ok(years) => {
+                            summary.frost_years = years.clone();
+                            cache.frost_years = Some((current_year, years));
+                        }
+                        Err(e) => {
+                            tracing::warn!("Failed to fetch frost history: {}", e);
+                        }
+                    },
+                }
+            }
+
+            // 7-day soil temp against the same week in the same prior years
+            if let (Some(ref client), Some(avg)) = (&clients.lake, summary.soil_temp_7day_avg_f) {
+                if !matches!(cache.soil_temp_history, Some((year, _)) if year == current_year) {
+                    match fetch_soil_temp_history(client, current_year).await {
+                        Ok(history) => {
+                            cache.soil_temp_history = Some((current_year, history));
+                        }
+                        Err(e) => {
+                            tracing::warn!("Failed to fetch soil temp history: {}", e);
+                        }
+                    }
+                }
+                if let Some((_, ref history)) = cache
+                    .soil_temp_history
+                    .as_ref()
+                    .filter(|(year, _)| *year == current_year)
+                {
+                    summary.soil_temp_anomaly =
+                        soil_anomaly::soil_temp_anomaly(history, clock::today(), avg);
+                }
+            }
+
             // Populate soil temp predictions if we have lake data + forecast
-            if let Some(ref client) = self.weather_client {
+            if let Some(ref client) = clients.lake {
                 let now = Utc::now();
                 let thirty_days_ago = now - Duration::days(30);
                 match client
@@ -245,42 +656,667 @@ impl DataSyncService {
                     }
                 }
             }
+
+            // Turf stress index for the fertilizer and mowing height rules
+            if let Some(ref client) = clients.lake {
+                match stress_index::load(&self.pool, client, profile, Utc::now()).await {
+                    Ok(index) => summary.stress_index = index.snapshot(),
+                    Err(e) => tracing::debug!("Failed to compute turf stress index: {}", e),
+                }
+            }
         } else {
             // Keep existing sensor data
-            summary = self.current_summary.clone();
+            summary = cache.summary.clone();
         }
 
         if refresh_forecast {
-            if let Some(ref client) = self.openweathermap_client {
+            if let Some(ref client) = clients.forecast {
                 match client.fetch_forecast().await {
                     Ok(forecast) => {
                         summary.forecast = Some(forecast.clone());
-                        self.current_forecast = Some(forecast);
-                        self.last_forecast_refresh = Some(Instant::now());
+                        cache.forecast = Some(forecast);
+                        cache.last_forecast_refresh = Some(Instant::now());
                         tracing::debug!("Weather forecast updated");
+                        self.record_event(
+                            Event::new(
+                                EventKind::DataRefresh,
+                                EventLevel::Info,
+                                "Weather forecast refreshed",
+                            )
+                            .with_details(profile.name.clone()),
+                        )
+                        .await;
                     }
                     Err(e) => {
                         tracing::warn!("Failed to fetch weather forecast: {}", e);
+                        self.record_source_error(DataSource::OpenWeatherMap, &e)
+                            .await;
+                    }
+                }
+                // Refreshed with the forecast; an old reading is worse than none
+                match client.fetch_air_quality().await {
+                    Ok(air_quality) => cache.air_quality = air_quality,
+                    Err(e) => {
+                        tracing::warn!("Failed to fetch air quality: {}", e);
+                        cache.air_quality = None;
                     }
                 }
             }
         } else {
             // Keep existing forecast
             if summary.forecast.is_none() {
-                summary.forecast = self.current_forecast.clone();
+                summary.forecast = cache.forecast.clone();
             }
         }
 
+        if let Some(ref client) = self.drought_client {
+            if deep || is_stale(cache.last_drought_refresh, DROUGHT_STALENESS_SECS) {
+                let location = profile.location.clone().or_else(|| self.location());
+                match client.fetch_status(location.as_ref()).await {
+                    Ok(status) => {
+                        cache.drought = status;
+                        cache.last_drought_refresh = Some(Instant::now());
+                    }
+                    Err(e) => {
+                        tracing::warn!("Failed to fetch Drought Monitor status: {}", e);
+                        self.record_source_error(DataSource::DroughtMonitor, &e)
+                            .await;
+                    }
+                }
+            }
+        }
+        summary.drought = cache.drought.clone();
+        summary.air_quality = cache.air_quality.clone();
+
+        let fresh_forecast = summary.forecast.as_ref().filter(|_| refresh_forecast);
+        // The server reconciles and records rain checks
+        let rain_checks = if self.follower {
+            Ok(())
+        } else {
+            self.update_rain_checks(
+                profile,
+                clients.lake.as_ref(),
+                refresh_sensors,
+                fresh_forecast,
+            )
+            .await
+        };
+        if let Err(e) = rain_checks {
+            tracing::warn!("Rain check update failed: {}", e);
+        }
+
+        summary.derived = calculations::derive_metrics(&summary);
+
         // Update cached summary
-        self.current_summary = summary.clone();
+        cache.summary = summary.clone();
+        self.caches.insert(cache_key(profile), cache);
 
         Ok(summary)
     }
 }
 
-#[derive(Debug, Clone, Default, serde::Serialize)]
+/// Frost dates for the `FROST_HISTORY_YEARS` before `year`.
+async fn fetch_frost_years(
+    client: &WeatherLakeClient,
+    year: i32,
+) -> crate::error::Result<Vec<FrostYear>> {
+    let (Some(start), Some(end)) = (
+        NaiveDate::from_ymd_opt(year - FROST_HISTORY_YEARS, 1, 1),
+        NaiveDate::from_ymd_opt(year - 1, 12, 31),
+    ) else {
+        return Ok(Vec::new());
+    };
+    let lows = client.fetch_daily_lows(start, end).await?;
+    Ok(frost::frost_years(&lows, year))
+}
+
+/// Daily soil temps for the `FROST_HISTORY_YEARS` before `year`.
+async fn fetch_soil_temp_history(
+    client: &WeatherLakeClient,
+    year: i32,
+) -> crate::error::Result<Vec<DailySoilTempAvg>> {
+    let (Some(start), Some(end)) = (
+        NaiveDate::from_ymd_opt(year - FROST_HISTORY_YEARS, 1, 1),
+        NaiveDate::from_ymd_opt(year - 1, 12, 31),
+    ) else {
+        return Ok(Vec::new());
+    };
+    client
+        .fetch_daily_soil_temp_averages(
+            start.and_time(chrono::NaiveTime::MIN).and_utc(),
+            end.and_time(chrono::NaiveTime::MIN).and_utc(),
+        )
+        .await
+}
+
+/// Cache slot for a profile. Unsaved profiles (no id yet) share slot 0.
+fn cache_key(profile: &LawnProfile) -> i64 {
+    profile.id.unwrap_or(0)
+}
+
+/// Whether a refresh timestamp is missing or older than `max_age_secs`.
+fn is_stale(last_refresh: Option<Instant>, max_age_secs: u64) -> bool {
+    match last_refresh {
+        None => true,
+        Some(t) => t.elapsed().as_secs() >= max_age_secs,
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct ConnectionStatus {
     pub soildata: bool,
     pub homeassistant: bool,
     pub openweathermap: bool,
+    /// Every sensor source by key (`uscrn`, `homeassistant`, ...).
+    pub sources: BTreeMap<String, bool>,
+    /// Sources (by key, plus `openweathermap`) whose last check timed out
+    /// rather than being refused.
+    pub timed_out: BTreeSet<String>,
+    /// No check has finished since startup; the flags above are placeholders.
+    pub pending: bool,
+}
+
+impl ConnectionStatus {
+    pub fn pending() -> Self {
+        Self {
+            pending: true,
+            ..Default::default()
+        }
+    }
+}
+
+/// Datasource clients to test, detached from [`DataSyncService`].
+pub struct ConnectionProbe {
+    /// The lake first, then the other sensor sources.
+    sources: Vec<Arc<dyn EnvironmentalSource>>,
+    forecast: Option<OpenWeatherMapClient>,
+}
+
+impl ConnectionProbe {
+    /// Test every client at once, so one unreachable source costs a single timeout.
+    pub async fn run(self) -> ConnectionStatus {
+        let mut checks = tokio::task::JoinSet::new();
+        for source in self.sources {
+            checks.spawn(async move {
+                let result = source.test_connection().await;
+                (source.key(), source.source(), result)
+            });
+        }
+        let forecast = self
+            .forecast
+            .map(|client| tokio::spawn(async move { client.test_connection().await }));
+
+        let mut status = ConnectionStatus::default();
+        while let Some(joined) = checks.join_next().await {
+            let Ok((key, source, result)) = joined else {
+                continue;
+            };
+            if result.as_ref().is_err_and(TurfOpsError::is_timeout) {
+                status.timed_out.insert(key.to_string());
+            }
+            let ok = result.unwrap_or(false);
+            match source {
+                DataSource::SoilData => status.soildata = ok,
+                DataSource::HomeAssistant => status.homeassistant = ok,
+                _ => {}
+            }
+            status.sources.insert(key.to_string(), ok);
+        }
+        if let Some(check) = forecast {
+            let result = check.await.unwrap_or(Ok(false));
+            if result.as_ref().is_err_and(TurfOpsError::is_timeout) {
+                status.timed_out.insert("openweathermap".to_string());
+            }
+            status.openweathermap = result.unwrap_or(false);
+        }
+        status
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::EnvironmentalReading;
+
+    /// Lake plus Home Assistant through the registry with default priority,
+    /// the way a refresh merges them.
+    fn merge_sensor_readings(
+        lake: Option<&EnvironmentalReading>,
+        ha: Option<&EnvironmentalReading>,
+    ) -> EnvironmentalReading {
+        let readings: Vec<_> = [
+            lake.map(|r| SourceReading {
+                key: "uscrn",
+                source: DataSource::SoilData,
+                capabilities: WeatherLakeClient::CAPABILITIES,
+                reading: r.clone(),
+            }),
+            ha.map(|r| SourceReading {
+                key: "homeassistant",
+                source: DataSource::HomeAssistant,
+                capabilities: HomeAssistantClient::CAPABILITIES,
+                reading: r.clone(),
+            }),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        SourceRegistry::default().merge(&readings)
+    }
+
+    fn lake_fixture() -> EnvironmentalReading {
+        let mut r = EnvironmentalReading::new(DataSource::SoilData);
+        r.soil_temp_5_f = Some(61.0);
+        r.soil_temp_10_f = Some(58.5);
+        r.soil_temp_20_f = Some(56.0);
+        r.soil_moisture_10 = Some(0.27);
+        r.precipitation_mm = Some(1.2);
+        // The station also reports air temp/RH; these must not win over HA.
+        r.ambient_temp_f = Some(70.0);
+        r.humidity_percent = Some(55.0);
+        r
+    }
+
+    fn ha_fixture() -> EnvironmentalReading {
+        let mut r = EnvironmentalReading::new(DataSource::HomeAssistant);
+        r.ambient_temp_f = Some(74.3);
+        r.humidity_percent = Some(62.0);
+        r
+    }
+
+    #[test]
+    fn merge_takes_soil_from_lake_and_ambient_from_ha() {
+        let merged = merge_sensor_readings(Some(&lake_fixture()), Some(&ha_fixture()));
+
+        assert_eq!(merged.source, DataSource::Cached);
+        assert_eq!(merged.soil_temp_5_f, Some(61.0));
+        assert_eq!(merged.soil_temp_10_f, Some(58.5));
+        assert_eq!(merged.soil_temp_20_f, Some(56.0));
+        assert_eq!(merged.soil_moisture_10, Some(0.27));
+        assert_eq!(merged.precipitation_mm, Some(1.2));
+        assert_eq!(merged.ambient_temp_f, Some(74.3));
+        assert_eq!(merged.humidity_percent, Some(62.0));
+    }
+
+    #[test]
+    fn merge_ignores_missing_ha_fields() {
+        let mut ha = ha_fixture();
+        ha.humidity_percent = None;
+        // HA never supplies soil data; stray values must be ignored.
+        ha.soil_temp_10_f = Some(99.0);
+
+        let merged = merge_sensor_readings(Some(&lake_fixture()), Some(&ha));
+
+        assert_eq!(merged.ambient_temp_f, Some(74.3));
+        assert!(merged.humidity_percent.is_none());
+        assert_eq!(merged.soil_temp_10_f, Some(58.5));
+    }
+
+    #[test]
+    fn merge_with_lake_unavailable_keeps_ha_values() {
+        let merged = merge_sensor_readings(None, Some(&ha_fixture()));
+
+        assert!(merged.soil_temp_10_f.is_none());
+        assert!(merged.primary_soil_moisture().is_none());
+        assert_eq!(merged.ambient_temp_f, Some(74.3));
+    }
+
+    #[test]
+    fn merge_with_ha_unavailable_keeps_lake_values() {
+        let merged = merge_sensor_readings(Some(&lake_fixture()), None);
+
+        assert_eq!(merged.soil_temp_10_f, Some(58.5));
+        assert!(merged.ambient_temp_f.is_none());
+        assert!(merged.humidity_percent.is_none());
+    }
+
+    #[test]
+    fn merge_with_all_sources_down_is_empty() {
+        let merged = merge_sensor_readings(None, None);
+
+        assert_eq!(merged.source, DataSource::Cached);
+        assert!(merged.soil_temp_10_f.is_none());
+        assert!(merged.ambient_temp_f.is_none());
+        assert!(merged.precipitation_mm.is_none());
+    }
+
+    /// End-to-end `refresh` harness: the lake is a parquet file written with
+    /// DuckDB, Home Assistant, Ecowitt and OpenWeatherMap are wiremock servers.
+    /// The profile is unsaved and the service runs as a follower, so nothing
+    /// reaches the (never connected) Postgres pool.
+    mod refresh {
+        use super::*;
+        use crate::config::{
+            DataLakeConfig, EcowittConfig, HomeAssistantConfig, OpenWeatherMapConfig,
+        };
+        use crate::models::GrassType;
+        use crate::testing;
+        use std::path::PathBuf;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        const TEMP_ENTITY: &str = "sensor.patio_temperature";
+        const HUMIDITY_ENTITY: &str = "sensor.patio_humidity";
+
+        /// One silver hourly row for the default station: soil 15°C at 10cm,
+        /// 27% moisture, air 21°C.
+        fn silver_fixture(name: &str) -> PathBuf {
+            let file = std::env::temp_dir().join(format!(
+                "turfops-sync-{}-{}.parquet",
+                std::process::id(),
+                name
+            ));
+            let observed = (Utc::now() - Duration::minutes(30)).format("%Y-%m-%d %H:%M:%S");
+            duckdb::Connection::open_in_memory()
+                .unwrap()
+                .execute_batch(&format!(
+                    "COPY (SELECT 3761 AS wbanno, TIMESTAMP '{observed}' AS obs_ts_utc, \
+                            16.0::DOUBLE AS soil_temp_5, 15.0::DOUBLE AS soil_temp_10, 14.0::DOUBLE AS soil_temp_20, \
+                            NULL::DOUBLE AS soil_temp_50, NULL::DOUBLE AS soil_temp_100, \
+                            0.25::DOUBLE AS soil_moisture_5, 0.27::DOUBLE AS soil_moisture_10, 0.29::DOUBLE AS soil_moisture_20, \
+                            NULL::DOUBLE AS soil_moisture_50, NULL::DOUBLE AS soil_moisture_100, \
+                            21.0::DOUBLE AS air_temp_c, 55.0::DOUBLE AS rh_pct, 1.2::DOUBLE AS precip_mm) \
+                     TO '{}' (FORMAT parquet)",
+                    file.display()
+                ))
+                .unwrap();
+            file
+        }
+
+        fn lake(silver: &str) -> WeatherLakeClient {
+            let config = DataLakeConfig {
+                silver_weather_path: silver.to_string(),
+                gold_weather_path: "/nonexistent/gold.parquet".to_string(),
+                local_sync: false,
+                trend_window_hours: 24,
+                timeout_secs: 10,
+            };
+            WeatherLakeClient::new(&config, Vec::new())
+        }
+
+        fn home_assistant(server: &MockServer) -> Arc<HomeAssistantClient> {
+            Arc::new(HomeAssistantClient::new(
+                HomeAssistantConfig {
+                    url: server.uri(),
+                    token: "token".into(),
+                    temperature_entity: TEMP_ENTITY.into(),
+                    humidity_entity: HUMIDITY_ENTITY.into(),
+                    temperature_unit: Default::default(),
+                },
+                std::time::Duration::from_secs(5),
+            ))
+        }
+
+        fn openweathermap(server: &MockServer, air_quality: bool) -> OpenWeatherMapClient {
+            let config = OpenWeatherMapConfig {
+                api_key: "test_key".into(),
+                latitude: 39.86,
+                longitude: -75.79,
+                enabled: true,
+                one_call: false,
+                air_quality,
+                daily_quota: 0,
+            };
+            OpenWeatherMapClient::new(config, std::time::Duration::from_secs(5))
+                .with_host(&server.uri())
+        }
+
+        fn service(
+            lake: Option<WeatherLakeClient>,
+            sources: SourceRegistry,
+            forecast: Option<OpenWeatherMapClient>,
+        ) -> DataSyncService {
+            let pool = sqlx::postgres::PgPoolOptions::new()
+                .connect_lazy("postgres://turfops@localhost/turfops_test")
+                .unwrap();
+            DataSyncService {
+                pool,
+                weather_client: lake,
+                local_sync: false,
+                sources,
+                stale_after_hours: None,
+                openweathermap_client: forecast,
+                forecast_quota: None,
+                drought_client: None,
+                historical_client: None,
+                caches: HashMap::new(),
+                follower: true,
+            }
+        }
+
+        fn unsaved_profile() -> LawnProfile {
+            let mut profile = testing::profile(GrassType::TallFescue);
+            profile.id = None;
+            profile
+        }
+
+        async fn mount_ha(server: &MockServer, temp_f: &str, humidity: &str, calls: u64) {
+            for (entity, state) in [(TEMP_ENTITY, temp_f), (HUMIDITY_ENTITY, humidity)] {
+                Mock::given(method("GET"))
+                    .and(path(format!("/api/states/{}", entity)))
+                    .respond_with(
+                        ResponseTemplate::new(200).set_body_json(
+                            serde_json::json!({"entity_id": entity, "state": state}),
+                        ),
+                    )
+                    .expect(calls)
+                    .mount(server)
+                    .await;
+            }
+        }
+
+        /// Eight 3-hour points starting tomorrow at 72°F.
+        fn forecast_body() -> serde_json::Value {
+            let start = (Utc::now() + Duration::days(1)).timestamp();
+            let list: Vec<_> = (0..8)
+                .map(|i| {
+                    serde_json::json!({
+                        "dt": start + i * 3 * 3600,
+                        "main": {"temp": 72.0, "feels_like": 72.0, "humidity": 60.0},
+                        "weather": [{"id": 800, "main": "Clear", "description": "clear sky"}],
+                        "clouds": {"all": 10.0},
+                        "wind": {"speed": 5.0},
+                        "pop": 0.1
+                    })
+                })
+                .collect();
+            serde_json::json!({
+                "list": list,
+                "city": {"name": "West Chester", "country": "US", "coord": {"lat": 39.86, "lon": -75.79}}
+            })
+        }
+
+        async fn mount_forecast(server: &MockServer, response: ResponseTemplate) {
+            Mock::given(method("GET"))
+                .and(path("/data/2.5/forecast"))
+                .respond_with(response)
+                .mount(server)
+                .await;
+        }
+
+        #[tokio::test]
+        async fn merges_lake_soil_with_ha_ambient_and_forecast() {
+            let silver = silver_fixture("merge");
+            let server = MockServer::start().await;
+            mount_ha(&server, "74.3", "62", 1).await;
+            mount_forecast(
+                &server,
+                ResponseTemplate::new(200).set_body_json(forecast_body()),
+            )
+            .await;
+            Mock::given(method("GET"))
+                .and(path("/data/2.5/air_pollution"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "list": [{"dt": Utc::now().timestamp(), "main": {"aqi": 2}, "components": {"pm2_5": 8.0}}]
+                })))
+                .mount(&server)
+                .await;
+
+            let mut sources = SourceRegistry::default();
+            sources.register(home_assistant(&server));
+            let mut sync = service(
+                Some(lake(&silver.to_string_lossy())),
+                sources,
+                Some(openweathermap(&server, true)),
+            );
+            let summary = sync.force_refresh(&unsaved_profile()).await.unwrap();
+            std::fs::remove_file(&silver).ok();
+
+            let current = summary.current.unwrap();
+            assert_eq!(current.source, DataSource::Cached);
+            assert_eq!(current.soil_temp_10_f, Some(59.0));
+            assert_eq!(current.soil_moisture_10, Some(0.27));
+            assert_eq!(current.precipitation_mm, Some(1.2));
+            // The station's own air readings lose to the patio sensor
+            assert_eq!(current.ambient_temp_f, Some(74.3));
+            assert_eq!(current.humidity_percent, Some(62.0));
+            assert!(summary.soil_temp_7day_avg_f.is_some());
+
+            let forecast = summary.forecast.unwrap();
+            assert_eq!(forecast.location.city, "West Chester");
+            assert_eq!(forecast.hourly.len(), 8);
+            assert!(summary.air_quality.is_some());
+        }
+
+        #[tokio::test]
+        async fn source_priority_picks_the_overlapping_capability() {
+            let server = MockServer::start().await;
+            mount_ha(&server, "74.3", "62", 1).await;
+            Mock::given(method("GET"))
+                .and(path("/get_livedata_info"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "common_list": [
+                        {"id": "0x02", "val": "71.0", "unit": "F"},
+                        {"id": "0x07", "val": "50%"}
+                    ]
+                })))
+                .mount(&server)
+                .await;
+
+            let mut sources =
+                SourceRegistry::new(parse_priority("ambient_temp=ecowitt,homeassistant"));
+            sources.register(home_assistant(&server));
+            sources.register(Arc::new(EcowittClient::new(
+                EcowittConfig {
+                    url: server.uri(),
+                    soil_moisture_channel: 1,
+                    soil_temp_channel: 1,
+                },
+                std::time::Duration::from_secs(5),
+            )));
+            let mut sync = service(None, sources, None);
+            let current = sync
+                .force_refresh(&unsaved_profile())
+                .await
+                .unwrap()
+                .current
+                .unwrap();
+
+            // Ecowitt is listed first for air temp; humidity keeps registration order
+            assert_eq!(current.ambient_temp_f, Some(71.0));
+            assert_eq!(current.humidity_percent, Some(62.0));
+        }
+
+        #[tokio::test]
+        async fn serves_the_cache_until_stale_or_forced() {
+            let server = MockServer::start().await;
+            // One fetch for the first call, one for the forced refresh
+            mount_ha(&server, "74.3", "62", 2).await;
+            Mock::given(method("GET"))
+                .and(path("/data/2.5/forecast"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(forecast_body()))
+                .expect(2)
+                .mount(&server)
+                .await;
+
+            let mut sources = SourceRegistry::default();
+            sources.register(home_assistant(&server));
+            let mut sync = service(None, sources, Some(openweathermap(&server, false)));
+            let profile = unsaved_profile();
+
+            let first = sync.get_or_refresh(&profile).await.unwrap();
+            let cached = sync.get_or_refresh(&profile).await.unwrap();
+            assert_eq!(cached.last_updated, first.last_updated);
+            assert!(cached.forecast.is_some());
+
+            let forced = sync.force_refresh(&profile).await.unwrap();
+            assert!(forced.last_updated >= first.last_updated);
+            // Expectations are checked when the server drops
+        }
+
+        #[tokio::test]
+        async fn outages_fall_back_instead_of_failing() {
+            let server = MockServer::start().await;
+            mount_ha(&server, "74.3", "62", 1).await;
+            mount_forecast(&server, ResponseTemplate::new(503)).await;
+
+            let mut sources = SourceRegistry::default();
+            sources.register(home_assistant(&server));
+            let mut sync = service(
+                Some(lake("/nonexistent/silver.parquet")),
+                sources,
+                Some(openweathermap(&server, false)),
+            );
+            let summary = sync.force_refresh(&unsaved_profile()).await.unwrap();
+
+            // Lake and forecast are down: ambient still comes through
+            let current = summary.current.unwrap();
+            assert!(current.soil_temp_10_f.is_none());
+            assert_eq!(current.ambient_temp_f, Some(74.3));
+            assert!(summary.forecast.is_none());
+        }
+
+        #[tokio::test]
+        async fn failed_forecast_refresh_keeps_the_cached_forecast() {
+            let server = MockServer::start().await;
+            mount_ha(&server, "74.3", "62", 1).await;
+            mount_forecast(
+                &server,
+                ResponseTemplate::new(200).set_body_json(forecast_body()),
+            )
+            .await;
+
+            let mut sources = SourceRegistry::default();
+            sources.register(home_assistant(&server));
+            let mut sync = service(None, sources, Some(openweathermap(&server, false)));
+            let profile = unsaved_profile();
+            let before = sync.get_or_refresh(&profile).await.unwrap();
+            assert!(before.forecast.is_some());
+
+            server.reset().await;
+            mount_forecast(&server, ResponseTemplate::new(500)).await;
+            // Forecast-only refresh, as when its TTL lapses before the sensors'
+            let after = sync
+                .refresh_internal(&profile, false, true, false)
+                .await
+                .unwrap();
+            assert_eq!(
+                after.forecast.map(|f| f.fetched_at),
+                before.forecast.map(|f| f.fetched_at)
+            );
+            assert_eq!(after.current.unwrap().ambient_temp_f, Some(74.3));
+        }
+    }
+
+    #[test]
+    fn never_refreshed_is_stale() {
+        assert!(is_stale(None, SENSOR_STALENESS_SECS));
+        assert!(is_stale(None, FORECAST_STALENESS_SECS));
+    }
+
+    #[test]
+    fn fresh_refresh_is_not_stale() {
+        assert!(!is_stale(Some(Instant::now()), SENSOR_STALENESS_SECS));
+        assert!(!is_stale(Some(Instant::now()), FORECAST_STALENESS_SECS));
+    }
+
+    #[test]
+    fn old_refresh_is_stale() {
+        let six_minutes_ago = Instant::now()
+            .checked_sub(std::time::Duration::from_secs(6 * 60))
+            .unwrap();
+        assert!(is_stale(Some(six_minutes_ago), SENSOR_STALENESS_SECS));
+        // Forecast cache tolerates a longer age
+        assert!(!is_stale(Some(six_minutes_ago), FORECAST_STALENESS_SECS));
+    }
 }
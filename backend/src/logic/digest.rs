@@ -0,0 +1,504 @@
+//! Recommendation digest email. A background task sends a daily or weekly
+//! plain-text summary of active recommendations, application windows opening
+//! soon and current conditions over SMTP (`DigestConfig`), so anyone on the
+//! household list sees what needs doing without opening the dashboard. Quiet
+//! hours and repeat limits come from `NotificationConfig` (see `notify.rs`).
+
+use crate::api::recommendations::evaluate_recommendations;
+use crate::config::{DigestConfig, DigestFrequency, NotificationConfig, SmtpSecurity};
+use crate::db::{event_queries, notification_queries, queries};
+use crate::error::{Result, TurfOpsError};
+use crate::logic::notify::{defer_past_quiet_hours, filter_repeats};
+use crate::logic::rules::{WindowPhase, WindowProjection};
+use crate::models::{
+    EnvironmentalSummary, Event, EventKind, EventLevel, Millimeters, Recommendation,
+    SeverityCounts, VolumetricWaterContent,
+};
+use crate::state::AppState;
+use chrono::{Datelike, Duration, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use lettre::message::header::ContentType;
+use lettre::message::{Mailbox, Message};
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Tokio1Executor};
+
+/// Forecast days summarized under conditions.
+const FORECAST_DAYS: usize = 3;
+const SMTP_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
+
+/// Everything one digest email says.
+#[derive(Debug, Clone)]
+pub struct Digest {
+    pub lawn_name: String,
+    pub date: NaiveDate,
+    pub frequency: DigestFrequency,
+    /// Active recommendations, most severe first, less any held back by the
+    /// notification policy.
+    pub recommendations: Vec<Recommendation>,
+    /// Active recommendations left out as already sent and unchanged.
+    pub held_back: usize,
+    /// Upcoming and open seasonal windows from the rules engine, soonest first;
+    /// the same countdown the Dashboard shows.
+    pub windows: Vec<WindowProjection>,
+    /// (label, value) lines for the conditions section.
+    pub conditions: Vec<(&'static str, String)>,
+}
+
+impl Digest {
+    pub fn subject(&self) -> String {
+        let counts = SeverityCounts::tally(&self.recommendations);
+        let urgent = counts.critical + counts.warning;
+        let status = match (self.recommendations.len(), urgent) {
+            (0, _) if self.held_back > 0 => "no changes".to_string(),
+            (0, _) => "nothing to do".to_string(),
+            (n, 0) => format!("{} recommendation{}", n, plural(n)),
+            (n, urgent) => format!("{} recommendation{}, {} urgent", n, plural(n), urgent),
+        };
+        format!(
+            "TurfOps {} digest for {}: {}",
+            frequency_label(self.frequency),
+            self.lawn_name,
+            status
+        )
+    }
+
+    pub fn render(&self) -> String {
+        let mut out = format!(
+            "{} - {}\n\n",
+            self.lawn_name,
+            self.date.format("%A, %B %-d, %Y")
+        );
+
+        out.push_str("CONDITIONS\n");
+        if self.conditions.is_empty() {
+            out.push_str("  No sensor or forecast data available.\n");
+        }
+        for (label, value) in &self.conditions {
+            out.push_str(&format!("  {}: {}\n", label, value));
+        }
+
+        out.push_str(&format!(
+            "\nRECOMMENDATIONS ({})\n",
+            self.recommendations.len()
+        ));
+        if self.recommendations.is_empty() && self.held_back == 0 {
+            out.push_str("  Nothing needs doing right now.\n");
+        }
+        for rec in &self.recommendations {
+            out.push_str(&format!("  [{}] {}\n", rec.severity, rec.title));
+            out.push_str(&format!("    {}\n", rec.description));
+            if let Some(action) = &rec.suggested_action {
+                out.push_str(&format!("    -> {}\n", action));
+            }
+        }
+
+        if self.held_back > 0 {
+            out.push_str(&format!(
+                "  {} more unchanged since the last digest; see the Recommendations page.\n",
+                self.held_back
+            ));
+        }
+
+        if !self.windows.is_empty() {
+            out.push_str("\nUPCOMING WINDOWS\n");
+        }
+        for window in &self.windows {
+            let verb = match window.phase {
+                WindowPhase::Opens => "opens",
+                WindowPhase::Closes => "open now, closes",
+            };
+            let when = match window.days {
+                0 => format!("{} today", verb),
+                1 => format!("{} tomorrow", verb),
+                days => format!(
+                    "{} ~{} (in {} days)",
+                    verb,
+                    window.date.format("%b %-d"),
+                    days
+                ),
+            };
+            out.push_str(&format!(
+                "  {}: {}, {} confidence{}\n",
+                window.window,
+                when,
+                window.confidence.as_str().to_lowercase(),
+                if window.projected { ", projected" } else { "" }
+            ));
+        }
+
+        out.push_str("\nSent by TurfOps. Dismiss or mark recommendations addressed on the Recommendations page.\n");
+        out
+    }
+}
+
+fn plural(n: usize) -> &'static str {
+    if n == 1 {
+        ""
+    } else {
+        "s"
+    }
+}
+
+fn frequency_label(frequency: DigestFrequency) -> &'static str {
+    match frequency {
+        DigestFrequency::Daily => "daily",
+        DigestFrequency::Weekly(_) => "weekly",
+    }
+}
+
+/// Condition lines for whatever data the summary has.
+pub fn conditions(summary: &EnvironmentalSummary) -> Vec<(&'static str, String)> {
+    let mut lines = Vec::new();
+    let current = summary.current.as_ref();
+
+    if let Some(soil) = current.and_then(|c| c.soil_temp_10_f) {
+        lines.push((
+            "Soil temp (10cm)",
+            format!(
+                "{:.1}°F, {}",
+                soil,
+                summary.soil_temp_trend.as_str().to_lowercase()
+            ),
+        ));
+    }
+    if let Some(moisture) = current.and_then(|c| c.primary_soil_moisture()) {
+        lines.push((
+            "Soil moisture",
+            format!("{:.0} VWC", VolumetricWaterContent(moisture)),
+        ));
+    }
+    if let Some(air) = current.and_then(|c| c.ambient_temp_f) {
+        lines.push(("Air temp", format!("{:.0}°F", air)));
+    }
+    if let Some(rain_mm) = summary.precipitation_7day_total_mm {
+        lines.push((
+            "Rain, last 7 days",
+            format!("{:.2}", Millimeters(rain_mm).to_inches()),
+        ));
+    }
+    if let Some(gdd) = summary.gdd_base50_ytd {
+        lines.push(("GDD (base 50) this year", format!("{:.0}", gdd)));
+    }
+    if let Some(stress) = &summary.stress_index {
+        lines.push((
+            "Turf stress",
+            format!("{:.0}/100 ({})", stress.score, stress.level),
+        ));
+    }
+    if let Some(drought) = &summary.drought {
+        lines.push(("Drought Monitor", drought.category.to_string()));
+    }
+    if let Some(forecast) = &summary.forecast {
+        let days: Vec<_> = forecast.daily_summary.iter().take(FORECAST_DAYS).collect();
+        if !days.is_empty() {
+            let high = days.iter().map(|d| d.high_temp_f).fold(f64::MIN, f64::max);
+            let low = days.iter().map(|d| d.low_temp_f).fold(f64::MAX, f64::min);
+            let rain_mm: f64 = days.iter().map(|d| d.total_precipitation_mm).sum();
+            let chance = days
+                .iter()
+                .map(|d| d.max_precipitation_prob)
+                .fold(0.0, f64::max);
+            lines.push((
+                "Next 3 days",
+                format!(
+                    "{:.0}-{:.0}°F, {:.2} rain ({:.0}% max chance)",
+                    low,
+                    high,
+                    Millimeters(rain_mm).to_inches(),
+                    chance * 100.0
+                ),
+            ));
+        }
+    }
+
+    lines
+}
+
+/// The first send time strictly after `now` (local wall clock).
+pub fn next_send(now: NaiveDateTime, frequency: DigestFrequency, hour: u32) -> NaiveDateTime {
+    let at_hour = NaiveTime::from_hms_opt(hour.min(23), 0, 0).unwrap_or(NaiveTime::MIN);
+    let mut date = now.date();
+    if now.time() >= at_hour {
+        date += Duration::days(1);
+    }
+    if let DigestFrequency::Weekly(weekday) = frequency {
+        while date.weekday() != weekday {
+            date += Duration::days(1);
+        }
+    }
+    date.and_time(at_hour)
+}
+
+/// What to write to `notification_log` once a digest is sent.
+pub struct SentLog {
+    profile_id: i64,
+    active_ids: Vec<String>,
+}
+
+impl SentLog {
+    pub async fn record(&self, state: &AppState, digest: &Digest) -> Result<()> {
+        let active: Vec<&str> = self.active_ids.iter().map(String::as_str).collect();
+        notification_queries::update_notified(
+            &state.pool,
+            self.profile_id,
+            &digest.recommendations,
+            &active,
+            Utc::now(),
+        )
+        .await
+    }
+}
+
+/// Gather the active profile's recommendations, windows and conditions,
+/// leaving out recommendations the notification policy holds back.
+pub async fn build_digest(
+    state: &AppState,
+    frequency: DigestFrequency,
+    notifications: &NotificationConfig,
+) -> Result<(Digest, SentLog)> {
+    let profile = queries::get_active_lawn_profile(&state.pool)
+        .await?
+        .ok_or_else(|| TurfOpsError::NotFound("No lawn profile found".into()))?;
+    let summary = {
+        let mut service = state.sync_service.write().await;
+        service.get_or_refresh(&profile).await?
+    };
+
+    let profile_id = profile
+        .id
+        .ok_or_else(|| TurfOpsError::InvalidData("Profile missing ID".into()))?;
+
+    let mut recommendations = evaluate_recommendations(state, None).await?;
+    recommendations.retain(|r| r.is_active());
+    let active_ids = recommendations.iter().map(|r| r.id.clone()).collect();
+    let notified = notification_queries::list_notified(&state.pool, profile_id).await?;
+    let (recommendations, held_back) =
+        filter_repeats(recommendations, &notified, notifications, Utc::now());
+
+    let today = Local::now().date_naive();
+    // Full history, so an application made early in the season still closes its window
+    let apps = queries::get_applications_for_profile(&state.pool, profile_id, 1000, 0).await?;
+    let windows = state
+        .rules_engine
+        .project_windows(&summary, &profile, &apps, today);
+
+    Ok((
+        Digest {
+            lawn_name: profile.name.clone(),
+            date: today,
+            frequency,
+            recommendations,
+            held_back,
+            windows,
+            conditions: conditions(&summary),
+        },
+        SentLog {
+            profile_id,
+            active_ids,
+        },
+    ))
+}
+
+/// Send one digest to every configured recipient.
+pub async fn send_digest(config: &DigestConfig, digest: &Digest) -> Result<()> {
+    let email_error = |e: &dyn std::fmt::Display| TurfOpsError::Email(e.to_string());
+
+    let mut message = Message::builder()
+        .from(config.from.parse::<Mailbox>().map_err(|e| {
+            TurfOpsError::Config(format!("Invalid DIGEST_FROM '{}': {}", config.from, e))
+        })?)
+        .subject(digest.subject());
+    for to in &config.to {
+        message = message.to(to
+            .parse::<Mailbox>()
+            .map_err(|e| TurfOpsError::Config(format!("Invalid DIGEST_TO '{}': {}", to, e)))?);
+    }
+    let message = message
+        .header(ContentType::TEXT_PLAIN)
+        .body(digest.render())
+        .map_err(|e| email_error(&e))?;
+
+    let transport = match config.smtp_security {
+        SmtpSecurity::StartTls => {
+            AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&config.smtp_host)
+                .map_err(|e| email_error(&e))?
+        }
+        SmtpSecurity::Tls => AsyncSmtpTransport::<Tokio1Executor>::relay(&config.smtp_host)
+            .map_err(|e| email_error(&e))?,
+        SmtpSecurity::None => {
+            AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&config.smtp_host)
+        }
+    };
+    let mut transport = transport.port(config.smtp_port).timeout(Some(SMTP_TIMEOUT));
+    if let (Some(user), Some(password)) = (&config.smtp_username, &config.smtp_password) {
+        transport = transport.credentials(Credentials::new(user.clone(), password.clone()));
+    }
+
+    transport
+        .build()
+        .send(message)
+        .await
+        .map_err(|e| email_error(&e))?;
+    Ok(())
+}
+
+/// Build and send the digest at each scheduled time (pushed past quiet hours),
+/// recording every attempt in the activity log. A failed send is not retried
+/// until the next slot.
+pub fn spawn_digest_task(state: AppState, config: DigestConfig, notifications: NotificationConfig) {
+    tokio::spawn(async move {
+        loop {
+            let now = Local::now();
+            let next = defer_past_quiet_hours(
+                next_send(now.naive_local(), config.frequency, config.hour),
+                notifications.quiet_hours.as_ref(),
+            );
+            // A DST gap skips to the first valid instant after it
+            let next = Local
+                .from_local_datetime(&next)
+                .earliest()
+                .unwrap_or_else(|| now + Duration::hours(1));
+            tracing::debug!(next = %next, "Next digest email scheduled");
+            let wait = (next - now).to_std().unwrap_or_default();
+            tokio::time::sleep(wait).await;
+
+            let result = match build_digest(&state, config.frequency, &notifications).await {
+                Ok((digest, sent)) => match send_digest(&config, &digest).await {
+                    Ok(()) => {
+                        if let Err(e) = sent.record(&state, &digest).await {
+                            tracing::warn!("Failed to update notification log: {}", e);
+                        }
+                        Ok(digest.subject())
+                    }
+                    Err(e) => Err(e),
+                },
+                Err(e) => Err(e),
+            };
+            let event = match result {
+                Ok(subject) => {
+                    tracing::info!(recipients = config.to.len(), "Sent digest email");
+                    Event::new(EventKind::DigestSent, EventLevel::Info, subject)
+                        .with_details(config.to.join(", "))
+                }
+                Err(e) => {
+                    tracing::warn!("Digest email failed: {}", e);
+                    Event::new(
+                        EventKind::DigestSent,
+                        EventLevel::Error,
+                        format!("Digest email failed: {}", e),
+                    )
+                }
+            };
+            event_queries::record_event(&state.pool, event).await;
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::soil_temp_prediction::PredictionConfidence;
+    use crate::models::{RecommendationCategory, Severity};
+    use crate::testing::{at, date, EnvBuilder};
+    use chrono::Weekday;
+
+    fn local(y: i32, m: u32, d: u32, h: u32, min: u32) -> NaiveDateTime {
+        date(y, m, d).and_hms_opt(h, min, 0).unwrap()
+    }
+
+    #[test]
+    fn schedules_next_daily_and_weekly_slot() {
+        // Before today's hour: later today; at or after it: tomorrow
+        assert_eq!(
+            next_send(local(2025, 9, 3, 6, 30), DigestFrequency::Daily, 7),
+            local(2025, 9, 3, 7, 0)
+        );
+        assert_eq!(
+            next_send(local(2025, 9, 3, 7, 0), DigestFrequency::Daily, 7),
+            local(2025, 9, 4, 7, 0)
+        );
+
+        // 2025-09-03 is a Wednesday
+        let monday = DigestFrequency::Weekly(Weekday::Mon);
+        assert_eq!(
+            next_send(local(2025, 9, 3, 6, 0), monday, 7),
+            local(2025, 9, 8, 7, 0)
+        );
+        let wednesday = DigestFrequency::Weekly(Weekday::Wed);
+        assert_eq!(
+            next_send(local(2025, 9, 3, 6, 0), wednesday, 7),
+            local(2025, 9, 3, 7, 0)
+        );
+        assert_eq!(
+            next_send(local(2025, 9, 3, 8, 0), wednesday, 7),
+            local(2025, 9, 10, 7, 0)
+        );
+    }
+
+    #[test]
+    fn renders_recommendations_windows_and_conditions() {
+        let env = EnvBuilder::new(at(2025, 9, 3))
+            .soil_temp(68.0, 70.0)
+            .soil_moisture(0.22)
+            .build();
+        let window = |name: &str, phase, days| WindowProjection {
+            window: name.to_string(),
+            phase,
+            date: date(2025, 9, 3) + Duration::days(days),
+            days,
+            trigger: "soil drops below 65°F".into(),
+            confidence: PredictionConfidence::Medium,
+            projected: false,
+        };
+        let digest = Digest {
+            lawn_name: "Main Lawn".into(),
+            date: date(2025, 9, 3),
+            frequency: DigestFrequency::Daily,
+            recommendations: vec![Recommendation::new(
+                "fall_overseeding",
+                RecommendationCategory::Overseeding,
+                Severity::Warning,
+                "Overseeding Window Open",
+                "Soil temps are ideal for tall fescue germination.",
+            )
+            .with_action("Seed at 6-8 lbs/1000 sqft")],
+            held_back: 0,
+            windows: vec![
+                window("Pre-emergent", WindowPhase::Opens, 0),
+                window("Overseed", WindowPhase::Opens, 5),
+                window("Fall Fertilization", WindowPhase::Closes, 30),
+            ],
+            conditions: conditions(&env),
+        };
+
+        assert_eq!(
+            digest.subject(),
+            "TurfOps daily digest for Main Lawn: 1 recommendation, 1 urgent"
+        );
+        let text = digest.render();
+        assert!(text.contains("Soil temp (10cm): 68.0°F"));
+        assert!(text.contains("Soil moisture: 22% VWC"));
+        assert!(text.contains("[Warning] Overseeding Window Open"));
+        assert!(text.contains("-> Seed at 6-8 lbs/1000 sqft"));
+        assert!(text.contains("Pre-emergent: opens today, medium confidence"));
+        assert!(text.contains("Overseed: opens ~Sep 8 (in 5 days)"));
+        assert!(text.contains("Fall Fertilization: open now, closes ~Oct 3 (in 30 days)"));
+        assert!(text.find("Pre-emergent").unwrap() < text.find("Overseed:").unwrap());
+
+        let quiet = Digest {
+            recommendations: Vec::new(),
+            windows: Vec::new(),
+            ..digest
+        };
+        assert!(quiet.subject().ends_with("nothing to do"));
+        assert!(quiet.render().contains("Nothing needs doing right now."));
+
+        let unchanged = Digest {
+            held_back: 2,
+            ..quiet
+        };
+        assert!(unchanged.subject().ends_with("no changes"));
+        let text = unchanged.render();
+        assert!(text.contains("2 more unchanged since the last digest"));
+        assert!(!text.contains("Nothing needs doing"));
+    }
+}
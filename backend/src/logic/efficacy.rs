@@ -0,0 +1,474 @@
+//! Efficacy review: line a season's applications up against the conditions
+//! that followed them, so timing can be judged against the model after the
+//! fact. Pre-emergents are compared with the spring soil-temperature
+//! crossings, fungicides with the disease-favorable days they did and didn't
+//! cover. Everything else appears on the timeline without a verdict.
+
+use super::rules::thresholds::{
+    BROWN_PATCH_NIGHT_ONSET_F, DISEASE_HUMID_HOURS, FUNGICIDE_LATE_RISK_DAYS,
+    FUNGICIDE_PROTECTION_DAYS, HUMIDITY_DISEASE_RISK, PRE_EMERGENT_DEFAULT_RESIDUAL_DAYS,
+    PRE_EMERGENT_MAX_LEAD_DAYS,
+};
+use super::seasonal_plan::find_threshold_crossings;
+use crate::models::seasonal_plan::{DailySoilTempAvg, ThresholdCrossing};
+use crate::models::{
+    product_info, Application, ApplicationType, EnvironmentalReading, Observation,
+    ObservationCategory,
+};
+use chrono::{Duration, Local, NaiveDate};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// One day of station conditions, from hourly readings.
+#[derive(Debug, Clone, Serialize)]
+pub struct DayConditions {
+    pub date: NaiveDate,
+    pub soil_temp_10_f: Option<f64>,
+    pub high_temp_f: Option<f64>,
+    pub low_temp_f: Option<f64>,
+    /// Day's rainfall; `None` when the gauge reported nothing.
+    pub precipitation_mm: Option<f64>,
+    /// Hours at or above the disease humidity threshold.
+    pub humid_hours: usize,
+    /// Humid enough for long enough, with a warm night.
+    pub disease_risk: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum TimingVerdict {
+    Early,
+    OnTime,
+    Late,
+    /// Not enough data around the application to judge.
+    Unknown,
+}
+
+/// Pre-emergent timing against the 7-day average soil temp crossings.
+#[derive(Debug, Clone, Serialize)]
+pub struct PreEmergentTiming {
+    pub soil_temp_at_application_f: Option<f64>,
+    pub soil_50f_date: Option<NaiveDate>,
+    pub soil_55f_date: Option<NaiveDate>,
+    pub soil_60f_date: Option<NaiveDate>,
+    /// Days the application preceded the 55°F crossing (crabgrass germination);
+    /// negative when it came after.
+    pub lead_days: Option<i64>,
+    pub residual_until: NaiveDate,
+}
+
+/// Disease-risk days around a fungicide application.
+#[derive(Debug, Clone, Serialize)]
+pub struct FungicideCoverage {
+    pub protected_until: NaiveDate,
+    /// Risk days in the week before the application.
+    pub risk_days_before: usize,
+    /// Risk days inside the protection window.
+    pub risk_days_covered: usize,
+    /// Risk days in the same length of time after protection ran out.
+    pub risk_days_after: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RelatedObservation {
+    pub date: NaiveDate,
+    pub category: ObservationCategory,
+    pub notes: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EfficacyEntry {
+    pub application_id: Option<i64>,
+    pub application_type: ApplicationType,
+    pub product_name: Option<String>,
+    pub date: NaiveDate,
+    /// `None` for types without a timing model.
+    pub verdict: Option<TimingVerdict>,
+    pub summary: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pre_emergent: Option<PreEmergentTiming>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fungicide: Option<FungicideCoverage>,
+    /// Weed observations during a pre-emergent's residual, disease
+    /// observations during or after a fungicide's protection.
+    pub observations: Vec<RelatedObservation>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EfficacyReport {
+    pub year: i32,
+    /// Daily conditions for the timeline, oldest first.
+    pub days: Vec<DayConditions>,
+    /// The year's applications, oldest first.
+    pub entries: Vec<EfficacyEntry>,
+    pub disease_risk_days: usize,
+}
+
+fn mean(values: &[f64]) -> Option<f64> {
+    (!values.is_empty()).then(|| values.iter().sum::<f64>() / values.len() as f64)
+}
+
+/// Group hourly readings (any order) into local calendar days, oldest first.
+pub fn daily_conditions(readings: &[EnvironmentalReading]) -> Vec<DayConditions> {
+    let mut by_day: BTreeMap<NaiveDate, Vec<&EnvironmentalReading>> = BTreeMap::new();
+    for r in readings {
+        let day = r.timestamp.with_timezone(&Local).date_naive();
+        by_day.entry(day).or_default().push(r);
+    }
+
+    by_day
+        .into_iter()
+        .map(|(date, hours)| {
+            let soil: Vec<f64> = hours.iter().filter_map(|r| r.soil_temp_10_f).collect();
+            let air: Vec<f64> = hours.iter().filter_map(|r| r.ambient_temp_f).collect();
+            let rain: Vec<f64> = hours.iter().filter_map(|r| r.precipitation_mm).collect();
+            let humid_hours = hours
+                .iter()
+                .filter(|r| {
+                    r.humidity_percent
+                        .is_some_and(|h| h >= HUMIDITY_DISEASE_RISK)
+                })
+                .count();
+            let high_temp_f = air.iter().copied().reduce(f64::max);
+            let low_temp_f = air.iter().copied().reduce(f64::min);
+            DayConditions {
+                date,
+                soil_temp_10_f: mean(&soil),
+                high_temp_f,
+                low_temp_f,
+                precipitation_mm: (!rain.is_empty()).then(|| rain.iter().sum()),
+                humid_hours,
+                disease_risk: humid_hours >= DISEASE_HUMID_HOURS
+                    && low_temp_f.is_some_and(|low| low >= BROWN_PATCH_NIGHT_ONSET_F),
+            }
+        })
+        .collect()
+}
+
+fn crossing(crossings: &[ThresholdCrossing], name: &str) -> Option<NaiveDate> {
+    crossings
+        .iter()
+        .find(|c| c.threshold_name == name)
+        .map(|c| c.crossing_date)
+}
+
+fn residual_days(app: &Application) -> i64 {
+    app.product_name
+        .as_deref()
+        .and_then(product_info)
+        .map(|p| p.reseed_interval_days)
+        .filter(|d| *d > 0)
+        .unwrap_or(PRE_EMERGENT_DEFAULT_RESIDUAL_DAYS)
+}
+
+fn related(
+    observations: &[Observation],
+    category: ObservationCategory,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Vec<RelatedObservation> {
+    observations
+        .iter()
+        .filter(|o| o.category == category)
+        .map(|o| (o.observed_at.with_timezone(&Local).date_naive(), o))
+        .filter(|(d, _)| *d >= from && *d <= to)
+        .map(|(date, o)| RelatedObservation {
+            date,
+            category: o.category,
+            notes: o.notes.clone(),
+        })
+        .collect()
+}
+
+fn review_pre_emergent(
+    app: &Application,
+    days: &[DayConditions],
+    crossings: &[ThresholdCrossing],
+    observations: &[Observation],
+) -> EfficacyEntry {
+    let date = app.application_date;
+    let timing = PreEmergentTiming {
+        soil_temp_at_application_f: days
+            .iter()
+            .find(|d| d.date == date)
+            .and_then(|d| d.soil_temp_10_f),
+        soil_50f_date: crossing(crossings, "soil_50f_rising"),
+        soil_55f_date: crossing(crossings, "soil_55f_rising"),
+        soil_60f_date: crossing(crossings, "soil_60f_rising"),
+        lead_days: crossing(crossings, "soil_55f_rising").map(|d| (d - date).num_days()),
+        residual_until: date + Duration::days(residual_days(app)),
+    };
+
+    let (verdict, summary) = match (timing.soil_50f_date, timing.soil_60f_date) {
+        (_, Some(late)) if date >= late => (
+            TimingVerdict::Late,
+            format!(
+                "Applied after soil averaged 60°F on {late}; crabgrass was already germinating."
+            ),
+        ),
+        (Some(open), _) if (open - date).num_days() > PRE_EMERGENT_MAX_LEAD_DAYS => (
+            TimingVerdict::Early,
+            format!(
+                "Applied {} days before soil averaged 50°F on {open}; the residual (until {}) \
+                 may have thinned before germination ended.",
+                (open - date).num_days(),
+                timing.residual_until
+            ),
+        ),
+        (Some(open), _) => (
+            TimingVerdict::OnTime,
+            match timing.lead_days {
+                Some(lead) if lead >= 0 => {
+                    format!("Down {lead} days before soil reached 55°F (window opened {open}).")
+                }
+                _ => {
+                    format!("Applied after the window opened on {open}, before soil reached 60°F.")
+                }
+            },
+        ),
+        (None, _) => (
+            TimingVerdict::Unknown,
+            "No spring soil temperature crossing recorded this year.".to_string(),
+        ),
+    };
+
+    EfficacyEntry {
+        application_id: app.id,
+        application_type: app.application_type,
+        product_name: app.product_name.clone(),
+        date,
+        verdict: Some(verdict),
+        summary,
+        observations: related(
+            observations,
+            ObservationCategory::Weeds,
+            date,
+            timing.residual_until,
+        ),
+        pre_emergent: Some(timing),
+        fungicide: None,
+    }
+}
+
+fn review_fungicide(
+    app: &Application,
+    days: &[DayConditions],
+    observations: &[Observation],
+) -> EfficacyEntry {
+    let date = app.application_date;
+    let protected_until = date + Duration::days(FUNGICIDE_PROTECTION_DAYS - 1);
+    let after_until = protected_until + Duration::days(FUNGICIDE_PROTECTION_DAYS);
+    let risk_between = |from: NaiveDate, to: NaiveDate| {
+        days.iter()
+            .filter(|d| d.disease_risk && d.date >= from && d.date <= to)
+            .count()
+    };
+    let coverage = FungicideCoverage {
+        protected_until,
+        risk_days_before: risk_between(date - Duration::days(7), date - Duration::days(1)),
+        risk_days_covered: risk_between(date, protected_until),
+        risk_days_after: risk_between(protected_until + Duration::days(1), after_until),
+    };
+
+    let has_data = days
+        .iter()
+        .any(|d| d.date >= date && d.date <= protected_until);
+    let (verdict, summary) = if !has_data {
+        (
+            TimingVerdict::Unknown,
+            "No station data for the protection window.".to_string(),
+        )
+    } else if coverage.risk_days_before >= FUNGICIDE_LATE_RISK_DAYS {
+        (
+            TimingVerdict::Late,
+            format!(
+                "{} disease-favorable days in the week before; this was curative rather than preventive.",
+                coverage.risk_days_before
+            ),
+        )
+    } else if coverage.risk_days_covered == 0 && coverage.risk_days_after > 0 {
+        (
+            TimingVerdict::Early,
+            format!(
+                "No risk days while protected; {} came after protection ended on {protected_until}.",
+                coverage.risk_days_after
+            ),
+        )
+    } else if coverage.risk_days_covered == 0 {
+        (
+            TimingVerdict::Unknown,
+            "No disease-favorable days around this application.".to_string(),
+        )
+    } else {
+        (
+            TimingVerdict::OnTime,
+            format!(
+                "Covered {} disease-favorable days through {protected_until}.",
+                coverage.risk_days_covered
+            ),
+        )
+    };
+
+    EfficacyEntry {
+        application_id: app.id,
+        application_type: app.application_type,
+        product_name: app.product_name.clone(),
+        date,
+        verdict: Some(verdict),
+        summary,
+        observations: related(
+            observations,
+            ObservationCategory::Disease,
+            date,
+            after_until,
+        ),
+        pre_emergent: None,
+        fungicide: Some(coverage),
+    }
+}
+
+/// Review `year`'s applications against `days` (oldest first, ideally from
+/// Jan 1 so the spring crossings can be found).
+pub fn build_efficacy_report(
+    year: i32,
+    days: Vec<DayConditions>,
+    applications: &[Application],
+    observations: &[Observation],
+) -> EfficacyReport {
+    let soil: Vec<DailySoilTempAvg> = days
+        .iter()
+        .filter_map(|d| {
+            d.soil_temp_10_f.map(|avg_temp_f| DailySoilTempAvg {
+                date: d.date,
+                avg_temp_f,
+            })
+        })
+        .collect();
+    let crossings = find_threshold_crossings(year, &soil);
+
+    let mut apps: Vec<&Application> = applications
+        .iter()
+        .filter(|a| a.application_type != ApplicationType::Mowing)
+        .collect();
+    apps.sort_by_key(|a| a.application_date);
+
+    let entries = apps
+        .into_iter()
+        .map(|app| match app.application_type {
+            ApplicationType::PreEmergent => {
+                review_pre_emergent(app, &days, &crossings, observations)
+            }
+            ApplicationType::Fungicide => review_fungicide(app, &days, observations),
+            _ => EfficacyEntry {
+                application_id: app.id,
+                application_type: app.application_type,
+                product_name: app.product_name.clone(),
+                date: app.application_date,
+                verdict: None,
+                summary: String::new(),
+                pre_emergent: None,
+                fungicide: None,
+                observations: Vec::new(),
+            },
+        })
+        .collect();
+
+    EfficacyReport {
+        year,
+        disease_risk_days: days.iter().filter(|d| d.disease_risk).count(),
+        days,
+        entries,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{application, at, date};
+
+    /// Soil warming 1°F a day from 40°F on Mar 1 (50°F ~Mar 17, 60°F ~Mar 27).
+    fn spring() -> Vec<DayConditions> {
+        (0..90)
+            .map(|i| DayConditions {
+                date: date(2025, 3, 1) + Duration::days(i),
+                soil_temp_10_f: Some(40.0 + i as f64),
+                high_temp_f: Some(70.0),
+                low_temp_f: Some(50.0),
+                precipitation_mm: None,
+                humid_hours: 0,
+                disease_risk: false,
+            })
+            .collect()
+    }
+
+    fn verdict(report: &EfficacyReport) -> Option<TimingVerdict> {
+        report.entries[0].verdict
+    }
+
+    #[test]
+    fn pre_emergent_timing_follows_soil_crossings() {
+        let days = spring();
+        let on_time = application(ApplicationType::PreEmergent, date(2025, 3, 15));
+        let report = build_efficacy_report(2025, days.clone(), &[on_time], &[]);
+        assert_eq!(verdict(&report), Some(TimingVerdict::OnTime));
+        let timing = report.entries[0].pre_emergent.as_ref().unwrap();
+        assert!(timing.lead_days.unwrap() > 0);
+
+        let late = application(ApplicationType::PreEmergent, date(2025, 4, 10));
+        let report = build_efficacy_report(2025, days.clone(), &[late], &[]);
+        assert_eq!(verdict(&report), Some(TimingVerdict::Late));
+
+        let early = application(ApplicationType::PreEmergent, date(2025, 1, 20));
+        let report = build_efficacy_report(2025, days, &[early], &[]);
+        assert_eq!(verdict(&report), Some(TimingVerdict::Early));
+    }
+
+    #[test]
+    fn fungicide_coverage_counts_risk_days() {
+        let mut days = spring();
+        // Risk days May 10-14
+        for d in days.iter_mut() {
+            if d.date >= date(2025, 5, 10) && d.date <= date(2025, 5, 14) {
+                d.disease_risk = true;
+            }
+        }
+        let fungicide = |on| application(ApplicationType::Fungicide, on);
+
+        let report = build_efficacy_report(2025, days.clone(), &[fungicide(date(2025, 5, 1))], &[]);
+        assert_eq!(verdict(&report), Some(TimingVerdict::OnTime));
+        assert_eq!(
+            report.entries[0]
+                .fungicide
+                .as_ref()
+                .unwrap()
+                .risk_days_covered,
+            5
+        );
+
+        let report = build_efficacy_report(2025, days.clone(), &[fungicide(date(2025, 4, 1))], &[]);
+        assert_eq!(verdict(&report), Some(TimingVerdict::Early));
+
+        let report = build_efficacy_report(2025, days, &[fungicide(date(2025, 5, 15))], &[]);
+        assert_eq!(verdict(&report), Some(TimingVerdict::Late));
+    }
+
+    #[test]
+    fn disease_observations_attach_to_fungicide() {
+        let observation = Observation {
+            id: Some(1),
+            lawn_profile_id: 1,
+            observed_at: at(2025, 5, 20),
+            category: ObservationCategory::Disease,
+            notes: "Brown patch by the fence".into(),
+            created_at: at(2025, 5, 20),
+        };
+        let apps = [
+            application(ApplicationType::Fungicide, date(2025, 5, 1)),
+            application(ApplicationType::Mowing, date(2025, 5, 2)),
+            application(ApplicationType::Fertilizer, date(2025, 5, 3)),
+        ];
+        let report = build_efficacy_report(2025, spring(), &apps, &[observation]);
+        assert_eq!(report.entries.len(), 2);
+        assert_eq!(report.entries[0].observations.len(), 1);
+        assert_eq!(report.entries[1].verdict, None);
+    }
+}
@@ -0,0 +1,390 @@
+//! Equipment upkeep: usage counters from logged applications, and maintenance
+//! reminders for the recommendations list — hour-based service for mowers
+//! (blades, oil) and seasonal jobs (sharpen before spring, winterize the
+//! sprayer before it freezes). Pure functions — no IO.
+
+use crate::models::{
+    Application, DataSource, Equipment, EquipmentKind, EquipmentStatus, EquipmentTask,
+    MaintenanceEvent, Recommendation, RecommendationCategory, Severity, WeatherForecast,
+};
+use chrono::{Datelike, Duration, NaiveDate};
+
+/// Mower service intervals in engine hours.
+const MOWER_HOUR_INTERVALS: &[(EquipmentTask, f64)] = &[
+    (EquipmentTask::BladeSharpening, 25.0),
+    (EquipmentTask::OilChange, 50.0),
+];
+
+/// Overdue by this multiple of the interval escalates to Warning.
+const OVERDUE_WARNING_MULTIPLE: f64 = 2.0;
+
+/// Forecast low that freezes water left in a sprayer.
+const FREEZE_LOW_F: f64 = 32.0;
+const FREEZE_LOOKAHEAD_DAYS: i64 = 7;
+
+/// A once-a-year job, reminded between `opens` and `closes` (month, day)
+/// unless it was logged on or after `done_since` this year.
+struct SeasonalTask {
+    kind: EquipmentKind,
+    task: EquipmentTask,
+    opens: (u32, u32),
+    closes: (u32, u32),
+    done_since: (u32, u32),
+    title: &'static str,
+    action: &'static str,
+    explanation: &'static str,
+    /// Escalate to Warning when the forecast shows a freeze.
+    freeze_sensitive: bool,
+}
+
+const SEASONAL_TASKS: &[SeasonalTask] = &[
+    SeasonalTask {
+        kind: EquipmentKind::Mower,
+        task: EquipmentTask::BladeSharpening,
+        opens: (2, 15),
+        closes: (4, 30),
+        done_since: (1, 1),
+        title: "Sharpen Mower Blades Before Spring",
+        action: "Sharpen or replace the blades before the first mow of the season.",
+        explanation: "Dull blades tear leaf tips instead of cutting them. The shredded \
+                      tips brown within a day or two and give disease an easy way in.",
+        freeze_sensitive: false,
+    },
+    SeasonalTask {
+        kind: EquipmentKind::Sprayer,
+        task: EquipmentTask::Winterize,
+        opens: (9, 15),
+        closes: (11, 30),
+        done_since: (8, 1),
+        title: "Winterize Sprayer Before First Frost",
+        action: "Rinse the tank, pump and lines, then drain them or run RV antifreeze \
+                 through before storing.",
+        explanation: "Water left in the pump, hose or wand expands when it freezes and \
+                      cracks seals and fittings.",
+        freeze_sensitive: true,
+    },
+    SeasonalTask {
+        kind: EquipmentKind::Mower,
+        task: EquipmentTask::Winterize,
+        opens: (10, 15),
+        closes: (12, 15),
+        done_since: (8, 1),
+        title: "Winterize Mower for Storage",
+        action: "Add fuel stabilizer or run the tank dry, change the oil and clean \
+                 the deck before storing.",
+        explanation: "Fuel left untreated over winter goes stale and gums the carburetor, \
+                      and clippings caked on the deck hold moisture against the steel.",
+        freeze_sensitive: false,
+    },
+    SeasonalTask {
+        kind: EquipmentKind::Spreader,
+        task: EquipmentTask::Cleaning,
+        opens: (10, 15),
+        closes: (12, 15),
+        done_since: (8, 1),
+        title: "Clean Spreader Before Storage",
+        action: "Wash out the hopper, let it dry, and oil the gears and axle.",
+        explanation: "Fertilizer salts left in the hopper draw moisture and corrode the \
+                      gearbox and agitator over winter.",
+        freeze_sensitive: false,
+    },
+];
+
+/// Usage and maintenance history for each piece. Untagged applications count
+/// toward a piece only when it's the profile's only one of its kind.
+pub fn equipment_statuses(
+    equipment: Vec<Equipment>,
+    events: Vec<MaintenanceEvent>,
+    applications: &[Application],
+    today: NaiveDate,
+) -> Vec<EquipmentStatus> {
+    let kinds: Vec<EquipmentKind> = equipment.iter().map(|e| e.kind).collect();
+    equipment
+        .into_iter()
+        .map(|equipment| {
+            let sole_of_kind = kinds.iter().filter(|k| **k == equipment.kind).count() == 1;
+            let added = equipment.created_at.date_naive();
+            let mut use_dates: Vec<NaiveDate> = applications
+                .iter()
+                .filter(|a| a.application_date >= added && a.application_date <= today)
+                .filter(|a| equipment.used_for(a, sole_of_kind))
+                .map(|a| a.application_date)
+                .collect();
+            use_dates.sort();
+            let mut maintenance: Vec<MaintenanceEvent> = events
+                .iter()
+                .filter(|m| equipment.id == Some(m.equipment_id))
+                .cloned()
+                .collect();
+            maintenance.sort_by_key(|m| std::cmp::Reverse(m.performed_on));
+            EquipmentStatus {
+                uses: use_dates.len(),
+                hours: equipment
+                    .use_hours()
+                    .map(|h| equipment.starting_hours + use_dates.len() as f64 * h),
+                equipment,
+                maintenance,
+                use_dates,
+            }
+        })
+        .collect()
+}
+
+pub fn generate_equipment_recommendations(
+    statuses: &[EquipmentStatus],
+    forecast: Option<&WeatherForecast>,
+    today: NaiveDate,
+) -> Vec<Recommendation> {
+    let mut recs = Vec::new();
+    for status in statuses {
+        let Some(id) = status.equipment.id else {
+            continue;
+        };
+        if status.equipment.kind == EquipmentKind::Mower {
+            recs.extend(hour_reminders(id, status));
+        }
+        recs.extend(seasonal_reminders(id, status, forecast, today));
+    }
+    recs
+}
+
+fn task_id(task: EquipmentTask) -> String {
+    format!("{:?}", task).to_lowercase()
+}
+
+fn hour_reminders(id: i64, status: &EquipmentStatus) -> Vec<Recommendation> {
+    let Some(use_hours) = status.equipment.use_hours() else {
+        return Vec::new();
+    };
+    let name = &status.equipment.name;
+    let added = status.equipment.created_at.date_naive();
+
+    MOWER_HOUR_INTERVALS
+        .iter()
+        .filter_map(|&(task, interval)| {
+            let last_done = status.last_done(task);
+            let hours_since = status.uses_after(last_done.unwrap_or(added)) as f64 * use_hours;
+            if hours_since < interval {
+                return None;
+            }
+            let severity = if hours_since >= interval * OVERDUE_WARNING_MULTIPLE {
+                Severity::Warning
+            } else {
+                Severity::Advisory
+            };
+            let since = match last_done {
+                Some(d) => format!("since the last {} on {}", task, d.format("%b %-d")),
+                None => "since it was added".to_string(),
+            };
+            Some(
+                Recommendation::new(
+                    format!("equipment_{}_{}_hours", id, task_id(task)),
+                    RecommendationCategory::Equipment,
+                    severity,
+                    format!("{} Due: {}", task, name),
+                    format!(
+                        "{} has run about {:.0} hours {}; the service interval is {:.0} hours.",
+                        name, hours_since, since, interval
+                    ),
+                )
+                .with_explanation(
+                    "Hours are estimated from the mowings logged for this mower. Sharp \
+                     blades and fresh oil keep the cut clean and the engine from wearing \
+                     early.",
+                )
+                .with_data_point("Equipment", name, DataSource::Manual.as_str())
+                .with_data_point(
+                    "Hours Since Service",
+                    format!("{:.0}", hours_since),
+                    DataSource::Calculated.as_str(),
+                )
+                .with_data_point(
+                    "Interval",
+                    format!("{:.0} h", interval),
+                    DataSource::Calculated.as_str(),
+                )
+                .with_action(format!(
+                    "Do the {} and log it on the Equipment page to reset the counter.",
+                    task.as_str().to_lowercase()
+                )),
+            )
+        })
+        .collect()
+}
+
+fn seasonal_reminders(
+    id: i64,
+    status: &EquipmentStatus,
+    forecast: Option<&WeatherForecast>,
+    today: NaiveDate,
+) -> Vec<Recommendation> {
+    let year = today.year();
+    let on = |(m, d): (u32, u32)| NaiveDate::from_ymd_opt(year, m, d);
+    let name = &status.equipment.name;
+
+    SEASONAL_TASKS
+        .iter()
+        .filter(|t| t.kind == status.equipment.kind)
+        .filter_map(|t| {
+            let (opens, closes, done_since) = (on(t.opens)?, on(t.closes)?, on(t.done_since)?);
+            if today < opens || today > closes {
+                return None;
+            }
+            if status.last_done(t.task).is_some_and(|d| d >= done_since) {
+                return None;
+            }
+
+            let freeze = if t.freeze_sensitive {
+                forecast.and_then(|f| {
+                    f.daily_summary
+                        .iter()
+                        .filter(|d| d.date >= today)
+                        .filter(|d| d.date <= today + Duration::days(FREEZE_LOOKAHEAD_DAYS))
+                        .find(|d| d.low_temp_f <= FREEZE_LOW_F)
+                })
+            } else {
+                None
+            };
+
+            let mut rec = Recommendation::new(
+                format!("equipment_{}_{}_{}", id, task_id(t.task), year),
+                RecommendationCategory::Equipment,
+                if freeze.is_some() {
+                    Severity::Warning
+                } else {
+                    Severity::Advisory
+                },
+                format!("{} ({})", t.title, name),
+                match freeze {
+                    Some(day) => format!(
+                        "A low of {:.0}°F is forecast {}. {}",
+                        day.low_temp_f,
+                        day.date.format("%b %-d"),
+                        t.action
+                    ),
+                    None => t.action.to_string(),
+                },
+            )
+            .with_explanation(t.explanation)
+            .with_data_point("Equipment", name, DataSource::Manual.as_str())
+            .with_data_point(
+                "Window",
+                format!("{} – {}", opens.format("%b %-d"), closes.format("%b %-d")),
+                DataSource::Calendar.as_str(),
+            );
+            if let Some(last) = status.last_done(t.task) {
+                rec = rec.with_data_point(
+                    "Last Done",
+                    last.format("%b %-d, %Y").to_string(),
+                    DataSource::History.as_str(),
+                );
+            }
+            Some(rec.with_action(format!(
+                "Log the {} on the Equipment page when it's done.",
+                t.task.as_str().to_lowercase()
+            )))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ApplicationType;
+    use crate::testing::{application, at, date, ForecastBuilder};
+
+    fn piece(id: i64, kind: EquipmentKind) -> Equipment {
+        Equipment {
+            id: Some(id),
+            lawn_profile_id: 1,
+            name: format!("{} {}", kind, id),
+            kind,
+            hours_per_use: None,
+            starting_hours: 100.0,
+            notes: None,
+            created_at: at(2025, 1, 1),
+        }
+    }
+
+    fn done(equipment_id: i64, task: EquipmentTask, on: NaiveDate) -> MaintenanceEvent {
+        MaintenanceEvent {
+            id: None,
+            equipment_id,
+            task,
+            performed_on: on,
+            notes: None,
+            created_at: at(2025, 1, 1),
+        }
+    }
+
+    fn mowings(count: u32) -> Vec<Application> {
+        (0..count)
+            .map(|i| {
+                application(
+                    ApplicationType::Mowing,
+                    date(2025, 4, 1) + Duration::days(i as i64),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn mowings_drive_hours_and_blade_reminder() {
+        let today = date(2025, 6, 1);
+        let statuses = equipment_statuses(
+            vec![piece(1, EquipmentKind::Mower)],
+            vec![done(1, EquipmentTask::OilChange, date(2025, 4, 10))],
+            &mowings(30),
+            today,
+        );
+        assert_eq!(statuses[0].uses, 30);
+        assert_eq!(statuses[0].hours, Some(130.0));
+
+        let recs = generate_equipment_recommendations(&statuses, None, today);
+        let ids: Vec<_> = recs.iter().map(|r| r.id.as_str()).collect();
+        // 30 h since added for blades; oil was changed 10 mowings in
+        assert_eq!(ids, ["equipment_1_bladesharpening_hours"]);
+        assert_eq!(recs[0].severity, Severity::Advisory);
+    }
+
+    #[test]
+    fn untagged_uses_skip_when_profile_has_two_of_a_kind() {
+        let statuses = equipment_statuses(
+            vec![
+                piece(1, EquipmentKind::Mower),
+                piece(2, EquipmentKind::Mower),
+            ],
+            Vec::new(),
+            &mowings(5),
+            date(2025, 6, 1),
+        );
+        assert!(statuses.iter().all(|s| s.uses == 0));
+    }
+
+    #[test]
+    fn sprayer_winterize_escalates_on_forecast_freeze_and_clears_when_logged() {
+        let today = date(2025, 10, 20);
+        let forecast = ForecastBuilder::starting(today)
+            .day(55.0, 40.0, 60.0, 0.0)
+            .day(48.0, 30.0, 60.0, 0.0)
+            .build();
+        let statuses = equipment_statuses(
+            vec![piece(3, EquipmentKind::Sprayer)],
+            Vec::new(),
+            &[],
+            today,
+        );
+        let recs = generate_equipment_recommendations(&statuses, Some(&forecast), today);
+        assert_eq!(recs.len(), 1);
+        assert_eq!(recs[0].id, "equipment_3_winterize_2025");
+        assert_eq!(recs[0].severity, Severity::Warning);
+
+        let statuses = equipment_statuses(
+            vec![piece(3, EquipmentKind::Sprayer)],
+            vec![done(3, EquipmentTask::Winterize, date(2025, 10, 1))],
+            &[],
+            today,
+        );
+        assert!(generate_equipment_recommendations(&statuses, Some(&forecast), today).is_empty());
+    }
+}
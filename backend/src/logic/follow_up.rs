@@ -149,6 +149,9 @@ mod tests {
             potassium_pct: None,
             plant_id,
             follow_up_date: follow_up,
+            area_id: None,
+            equipment_id: None,
+            custom_type_id: None,
             created_at: Utc::now(),
         }
     }
@@ -0,0 +1,345 @@
+//! Frost dates for a lawn as probabilities rather than single calendar days.
+//! The last spring and first fall frost are each modeled as a normal spread
+//! around the station's own history when it has a few years of it, otherwise
+//! around the USDA zone's typical date, so rules can say "10% chance of frost
+//! after Apr 19" and place their seasons around the local climate.
+
+use crate::logic::rules::thresholds::{
+    FROST_HISTORY_MIN_YEARS, FROST_LOW_F, FROST_MIN_STD_DEV_DAYS, FROST_ZONE_STD_DEV_DAYS,
+};
+use crate::models::FrostYear;
+use chrono::{Datelike, Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Zone 7a's frost dates, for seasons in zones with no typical frost.
+const DEFAULT_LAST_FROST: (u32, u32) = (4, 5);
+const DEFAULT_FIRST_FROST: (u32, u32) = (10, 30);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FrostDateSource {
+    /// Fitted to prior years of station lows.
+    StationHistory,
+    /// Typical date for the profile's USDA zone.
+    Zone,
+}
+
+impl FrostDateSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FrostDateSource::StationHistory => "station history",
+            FrostDateSource::Zone => "USDA zone",
+        }
+    }
+}
+
+/// Zone number and whether it's the warmer "b" half, or `None` for frost-free
+/// zones (11+) and unparseable input.
+fn parse_zone(zone: &str) -> Option<(u32, bool)> {
+    let number: u32 = zone
+        .trim_end_matches(|c: char| c.is_ascii_alphabetic())
+        .parse()
+        .ok()?;
+    (1..=10)
+        .contains(&number)
+        .then_some((number, zone.ends_with('b')))
+}
+
+/// Typical first fall frost for a USDA zone ("7a"), or `None` for frost-free
+/// zones (11+) and unparseable input. The "b" half runs a week later.
+pub fn zone_first_frost(zone: &str, year: i32) -> Option<NaiveDate> {
+    let (number, b_half) = parse_zone(zone)?;
+    let (month, day) = match number {
+        1 => (8, 20),
+        2 => (9, 1),
+        3 => (9, 12),
+        4 => (9, 22),
+        5 => (10, 5),
+        6 => (10, 17),
+        7 => (10, 30),
+        8 => (11, 15),
+        9 => (12, 1),
+        _ => (12, 20),
+    };
+    let a_half = NaiveDate::from_ymd_opt(year, month, day)?;
+    Some(if b_half {
+        a_half + Duration::days(7)
+    } else {
+        a_half
+    })
+}
+
+/// Typical last spring frost for a USDA zone, or `None` for frost-free zones.
+/// The "b" half runs a week earlier.
+pub fn zone_last_frost(zone: &str, year: i32) -> Option<NaiveDate> {
+    let (number, b_half) = parse_zone(zone)?;
+    let (month, day) = match number {
+        1 => (6, 15),
+        2 => (6, 1),
+        3 => (5, 20),
+        4 => (5, 12),
+        5 => (4, 28),
+        6 => (4, 18),
+        7 => (4, 5),
+        8 => (3, 20),
+        9 => (2, 28),
+        _ => (1, 30),
+    };
+    let a_half = NaiveDate::from_ymd_opt(year, month, day)?;
+    Some(if b_half {
+        a_half - Duration::days(7)
+    } else {
+        a_half
+    })
+}
+
+/// Last spring frost (before July) and first fall frost (July on) for each
+/// year before `year` in `lows`, oldest first. `lows` are `(date, low_f)`.
+pub fn frost_years(lows: &[(NaiveDate, f64)], year: i32) -> Vec<FrostYear> {
+    let mut years: BTreeMap<i32, FrostYear> = BTreeMap::new();
+    for &(date, low) in lows {
+        if date.year() >= year {
+            continue;
+        }
+        let entry = years.entry(date.year()).or_insert(FrostYear {
+            year: date.year(),
+            last_spring: None,
+            first_fall: None,
+        });
+        if low > FROST_LOW_F {
+            continue;
+        }
+        if date.month() < 7 {
+            entry.last_spring = entry.last_spring.max(Some(date));
+        } else {
+            entry.first_fall = Some(entry.first_fall.map_or(date, |d| d.min(date)));
+        }
+    }
+    years.into_values().collect()
+}
+
+/// Standard normal CDF (Abramowitz & Stegun 7.1.26, error under 1.5e-7).
+fn normal_cdf(z: f64) -> f64 {
+    let x = z.abs() / std::f64::consts::SQRT_2;
+    let t = 1.0 / (1.0 + 0.327_591_1 * x);
+    let poly = t
+        * (0.254_829_592
+            + t * (-0.284_496_736
+                + t * (1.421_413_741 + t * (-1.453_152_027 + t * 1.061_405_429))));
+    let erf = 1.0 - poly * (-x * x).exp();
+    if z >= 0.0 {
+        0.5 * (1.0 + erf)
+    } else {
+        0.5 * (1.0 - erf)
+    }
+}
+
+/// When one frost event (the last spring or first fall frost) falls in a
+/// given year, as a normal spread around its typical date.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct FrostDistribution {
+    pub median: NaiveDate,
+    pub std_dev_days: f64,
+    /// Years of station history behind it; 0 for a zone estimate.
+    pub years: usize,
+    pub source: FrostDateSource,
+}
+
+impl FrostDistribution {
+    fn from_history(dates: &[NaiveDate], year: i32) -> Option<Self> {
+        if dates.len() < FROST_HISTORY_MIN_YEARS {
+            return None;
+        }
+        let ordinals: Vec<f64> = dates.iter().map(|d| d.ordinal0() as f64).collect();
+        let n = ordinals.len() as f64;
+        let mean = ordinals.iter().sum::<f64>() / n;
+        let variance = ordinals.iter().map(|o| (o - mean).powi(2)).sum::<f64>() / (n - 1.0);
+        Some(Self {
+            median: NaiveDate::from_yo_opt(year, 1)?
+                .checked_add_signed(Duration::days(mean.round() as i64))?,
+            std_dev_days: variance.sqrt().max(FROST_MIN_STD_DEV_DAYS),
+            years: dates.len(),
+            source: FrostDateSource::StationHistory,
+        })
+    }
+
+    fn from_zone(median: NaiveDate) -> Self {
+        Self {
+            median,
+            std_dev_days: FROST_ZONE_STD_DEV_DAYS,
+            years: 0,
+            source: FrostDateSource::Zone,
+        }
+    }
+
+    /// Chance the frost event has happened by the end of `date`.
+    pub fn probability_by(&self, date: NaiveDate) -> f64 {
+        let days = (date - self.median).num_days() as f64;
+        // Continuity correction: count all of `date`, not just its start
+        normal_cdf((days + 0.5) / self.std_dev_days)
+    }
+
+    /// First date by which the frost event has happened with probability `p`.
+    pub fn date_at(&self, p: f64) -> NaiveDate {
+        let reach = (self.std_dev_days * 4.0).ceil() as i64;
+        (-reach..=reach)
+            .map(|d| self.median + Duration::days(d))
+            .find(|&date| self.probability_by(date) >= p)
+            .unwrap_or(self.median + Duration::days(reach))
+    }
+}
+
+/// Last spring and first fall frost for one year. Either is `None` in zones
+/// without a typical frost when there's no station history either.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct FrostOutlook {
+    pub year: i32,
+    pub last_spring: Option<FrostDistribution>,
+    pub first_fall: Option<FrostDistribution>,
+}
+
+impl FrostOutlook {
+    /// Chance of a frost on or before `date` in fall.
+    pub fn fall_risk_by(&self, date: NaiveDate) -> Option<f64> {
+        Some(self.first_fall?.probability_by(date))
+    }
+
+    /// Date after which the chance of another spring frost is down to `risk`.
+    pub fn spring_safe_date(&self, risk: f64) -> Option<NaiveDate> {
+        Some(self.last_spring?.date_at(1.0 - risk))
+    }
+
+    /// Date before which fall frost risk stays under `risk`.
+    pub fn fall_risk_date(&self, risk: f64) -> Option<NaiveDate> {
+        Some(self.first_fall?.date_at(risk))
+    }
+
+    /// "10% chance of frost after Apr 19"
+    pub fn spring_advice(&self, risk: f64) -> Option<String> {
+        self.spring_safe_date(risk).map(|d| {
+            format!(
+                "{:.0}% chance of frost after {}",
+                risk * 100.0,
+                d.format("%b %-d")
+            )
+        })
+    }
+
+    /// "10% chance of frost before Oct 16"
+    pub fn fall_advice(&self, risk: f64) -> Option<String> {
+        self.fall_risk_date(risk).map(|d| {
+            format!(
+                "{:.0}% chance of frost before {}",
+                risk * 100.0,
+                d.format("%b %-d")
+            )
+        })
+    }
+
+    /// Typical last spring frost, or zone 7a's when there isn't one, for
+    /// placing spring seasons.
+    pub fn last_spring_median(&self) -> NaiveDate {
+        self.last_spring.map(|d| d.median).unwrap_or_else(|| {
+            let (month, day) = DEFAULT_LAST_FROST;
+            NaiveDate::from_ymd_opt(self.year, month, day).expect("valid default frost date")
+        })
+    }
+
+    /// Typical first fall frost, or zone 7a's when there isn't one, for
+    /// placing fall seasons.
+    pub fn first_fall_median(&self) -> NaiveDate {
+        self.first_fall.map(|d| d.median).unwrap_or_else(|| {
+            let (month, day) = DEFAULT_FIRST_FROST;
+            NaiveDate::from_ymd_opt(self.year, month, day).expect("valid default frost date")
+        })
+    }
+}
+
+/// Frost outlook for `year`: fitted to station history for each frost that
+/// occurred in at least `FROST_HISTORY_MIN_YEARS` prior years, otherwise the
+/// zone estimate.
+pub fn frost_outlook(history: &[FrostYear], zone: &str, year: i32) -> FrostOutlook {
+    let prior = || history.iter().filter(|y| y.year < year);
+    let springs: Vec<NaiveDate> = prior().filter_map(|y| y.last_spring).collect();
+    let falls: Vec<NaiveDate> = prior().filter_map(|y| y.first_fall).collect();
+    FrostOutlook {
+        year,
+        last_spring: FrostDistribution::from_history(&springs, year)
+            .or_else(|| zone_last_frost(zone, year).map(FrostDistribution::from_zone)),
+        first_fall: FrostDistribution::from_history(&falls, year)
+            .or_else(|| zone_first_frost(zone, year).map(FrostDistribution::from_zone)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::date;
+
+    #[test]
+    fn zone_estimates() {
+        assert_eq!(zone_first_frost("7a", 2025), Some(date(2025, 10, 30)));
+        assert_eq!(zone_first_frost("7b", 2025), Some(date(2025, 11, 6)));
+        assert_eq!(zone_first_frost("11a", 2025), None);
+        assert_eq!(zone_last_frost("7a", 2025), Some(date(2025, 4, 5)));
+        assert_eq!(zone_last_frost("6b", 2025), Some(date(2025, 4, 11)));
+        assert_eq!(zone_last_frost("garden", 2025), None);
+    }
+
+    #[test]
+    fn station_history_replaces_the_zone_estimate() {
+        let mut lows = vec![(date(2025, 3, 1), 20.0)];
+        for (y, spring, fall) in [(2022, 2, 20), (2023, 12, 24), (2024, 7, 16)] {
+            lows.push((date(y, 3, 20), 25.0));
+            lows.push((date(y, 4, spring), 31.0));
+            lows.push((date(y, 5, 1), 45.0));
+            lows.push((date(y, 9, 30), 40.0));
+            lows.push((date(y, 10, fall), 31.0));
+            lows.push((date(y, 11, 2), 25.0));
+        }
+        let years = frost_years(&lows, 2025);
+        assert_eq!(years.len(), 3);
+        assert_eq!(years[0].last_spring, Some(date(2022, 4, 2)));
+        assert_eq!(years[0].first_fall, Some(date(2022, 10, 20)));
+
+        let outlook = frost_outlook(&years, "7a", 2025);
+        let spring = outlook.last_spring.unwrap();
+        let fall = outlook.first_fall.unwrap();
+        assert_eq!(spring.source, FrostDateSource::StationHistory);
+        assert_eq!(spring.median, date(2025, 4, 7));
+        assert_eq!(fall.median, date(2025, 10, 20));
+        // Three close years still get the minimum spread
+        assert_eq!(fall.std_dev_days, FROST_MIN_STD_DEV_DAYS);
+
+        // Too little history falls back to the zone
+        let thin = frost_outlook(&years[..2], "7a", 2025);
+        assert_eq!(thin.first_fall.unwrap().source, FrostDateSource::Zone);
+        assert_eq!(thin.first_fall_median(), date(2025, 10, 30));
+    }
+
+    #[test]
+    fn frost_risk_probabilities() {
+        let outlook = frost_outlook(&[], "7a", 2026);
+        let spring = outlook.last_spring.unwrap();
+        assert!((spring.probability_by(spring.median) - 0.5).abs() < 0.05);
+
+        // With an 11-day spread, 10% risk sits about two weeks out
+        assert_eq!(outlook.spring_safe_date(0.1), Some(date(2026, 4, 19)));
+        assert_eq!(
+            outlook.spring_advice(0.1).as_deref(),
+            Some("10% chance of frost after Apr 19")
+        );
+        assert_eq!(
+            outlook.fall_advice(0.1).as_deref(),
+            Some("10% chance of frost before Oct 16")
+        );
+        let risk = 1.0 - spring.probability_by(date(2026, 4, 19));
+        assert!((0.08..=0.12).contains(&risk), "{risk}");
+        assert!(outlook.fall_risk_by(date(2026, 9, 15)).unwrap() < 0.01);
+
+        // Frost-free zones fall back to default dates for placing seasons
+        let tropical = frost_outlook(&[], "11a", 2026);
+        assert_eq!(tropical.spring_advice(0.1), None);
+        assert_eq!(tropical.last_spring_median(), date(2026, 4, 5));
+    }
+}
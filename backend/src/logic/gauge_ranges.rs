@@ -0,0 +1,248 @@
+//! Dashboard gauge scales fitted to the local climate. A fixed 0-110°F ambient
+//! gauge spends most of its width on temperatures a Pennsylvania winter never
+//! sees, so each gauge spans the 5th-95th percentile of the station's hourly
+//! archive instead, rounded out to a whole step. Metrics with too little
+//! history keep the static scale.
+
+use crate::datasources::WeatherLakeClient;
+use crate::error::Result;
+use crate::models::DegreesC;
+use chrono::{Duration, Utc};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::RwLock;
+use std::time::Instant;
+
+/// Years of hourly archive the percentiles are drawn from.
+pub const GAUGE_HISTORY_YEARS: i64 = 5;
+
+/// Percentiles the gauge ends sit at.
+pub const GAUGE_LOW_PERCENTILE: f64 = 0.05;
+pub const GAUGE_HIGH_PERCENTILE: f64 = 0.95;
+
+/// Hourly samples a metric needs (about half a year) before its scale adapts,
+/// so a single season doesn't set the range for the whole year.
+const MIN_SAMPLES: i64 = 24 * 180;
+
+/// How long computed ranges are reused; years of history barely move in a day.
+const CACHE_TTL: std::time::Duration = std::time::Duration::from_secs(24 * 60 * 60);
+
+/// Low/high percentiles of one metric over the archive, in the lake's units.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct MetricPercentiles {
+    pub samples: i64,
+    pub low: Option<f64>,
+    pub high: Option<f64>,
+}
+
+/// Percentiles for each gauged metric, as read from the silver layer: soil and
+/// air temps in °C, humidity in %, soil moisture as a fraction.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ArchivePercentiles {
+    pub soil_temp_10_c: MetricPercentiles,
+    pub air_temp_c: MetricPercentiles,
+    pub humidity_percent: MetricPercentiles,
+    pub soil_moisture_10: MetricPercentiles,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct GaugeRange {
+    pub min: f64,
+    pub max: f64,
+    /// Fitted to local history rather than the static default.
+    pub adaptive: bool,
+    pub samples: i64,
+}
+
+/// Gauge scales in display units (°F and %).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct GaugeRanges {
+    pub soil_temp_f: GaugeRange,
+    pub ambient_temp_f: GaugeRange,
+    pub humidity_percent: GaugeRange,
+    pub soil_moisture_percent: GaugeRange,
+    pub history_years: i64,
+}
+
+/// Static scale for a metric, and the physical limits a fitted one stays in.
+struct Scale {
+    min: f64,
+    max: f64,
+    step: f64,
+    floor: f64,
+    ceiling: f64,
+}
+
+const SOIL_TEMP_SCALE: Scale = Scale {
+    min: 30.0,
+    max: 100.0,
+    step: 5.0,
+    floor: -20.0,
+    ceiling: 120.0,
+};
+const AMBIENT_TEMP_SCALE: Scale = Scale {
+    min: 0.0,
+    max: 110.0,
+    step: 5.0,
+    floor: -40.0,
+    ceiling: 130.0,
+};
+const HUMIDITY_SCALE: Scale = Scale {
+    min: 0.0,
+    max: 100.0,
+    step: 5.0,
+    floor: 0.0,
+    ceiling: 100.0,
+};
+const SOIL_MOISTURE_SCALE: Scale = Scale {
+    min: 0.0,
+    max: 50.0,
+    step: 5.0,
+    floor: 0.0,
+    ceiling: 60.0,
+};
+
+impl Scale {
+    fn fallback(&self, samples: i64) -> GaugeRange {
+        GaugeRange {
+            min: self.min,
+            max: self.max,
+            adaptive: false,
+            samples,
+        }
+    }
+
+    /// Fit to `p` after converting with `to_display`, or fall back when the
+    /// history is thin or degenerate.
+    fn fit(&self, p: MetricPercentiles, to_display: impl Fn(f64) -> f64) -> GaugeRange {
+        let (Some(low), Some(high)) = (p.low, p.high) else {
+            return self.fallback(p.samples);
+        };
+        if p.samples < MIN_SAMPLES {
+            return self.fallback(p.samples);
+        }
+        let min = ((to_display(low) / self.step).floor() * self.step).max(self.floor);
+        let max = ((to_display(high) / self.step).ceil() * self.step).min(self.ceiling);
+        if max - min < self.step {
+            return self.fallback(p.samples);
+        }
+        GaugeRange {
+            min,
+            max,
+            adaptive: true,
+            samples: p.samples,
+        }
+    }
+}
+
+/// Gauge scales from archive percentiles.
+pub fn fit_ranges(p: &ArchivePercentiles) -> GaugeRanges {
+    let to_f = |c: f64| DegreesC(c).to_fahrenheit().0;
+    GaugeRanges {
+        soil_temp_f: SOIL_TEMP_SCALE.fit(p.soil_temp_10_c, to_f),
+        ambient_temp_f: AMBIENT_TEMP_SCALE.fit(p.air_temp_c, to_f),
+        humidity_percent: HUMIDITY_SCALE.fit(p.humidity_percent, |h| h),
+        soil_moisture_percent: SOIL_MOISTURE_SCALE.fit(p.soil_moisture_10, |m| m * 100.0),
+        history_years: GAUGE_HISTORY_YEARS,
+    }
+}
+
+/// The static scales, for when the lake isn't configured or can't be read.
+pub fn static_ranges() -> GaugeRanges {
+    fit_ranges(&ArchivePercentiles::default())
+}
+
+/// Fitted ranges per lawn profile (each may read its own station), reused for
+/// [`CACHE_TTL`].
+#[derive(Debug, Default)]
+pub struct GaugeRangeCache {
+    entries: RwLock<HashMap<i64, (Instant, GaugeRanges)>>,
+}
+
+impl GaugeRangeCache {
+    /// Cached ranges for `profile_id`, or percentiles freshly read from `client`.
+    pub async fn get_or_fit(
+        &self,
+        profile_id: i64,
+        client: &WeatherLakeClient,
+    ) -> Result<GaugeRanges> {
+        if let Some((at, ranges)) = self
+            .entries
+            .read()
+            .expect("gauge range cache lock")
+            .get(&profile_id)
+        {
+            if at.elapsed() < CACHE_TTL {
+                return Ok(*ranges);
+            }
+        }
+
+        let now = Utc::now();
+        let start = now - Duration::days(365 * GAUGE_HISTORY_YEARS);
+        let percentiles = client
+            .fetch_percentiles(start, now, GAUGE_LOW_PERCENTILE, GAUGE_HIGH_PERCENTILE)
+            .await?;
+        let ranges = fit_ranges(&percentiles);
+        self.entries
+            .write()
+            .expect("gauge range cache lock")
+            .insert(profile_id, (Instant::now(), ranges));
+        Ok(ranges)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metric(samples: i64, low: f64, high: f64) -> MetricPercentiles {
+        MetricPercentiles {
+            samples,
+            low: Some(low),
+            high: Some(high),
+        }
+    }
+
+    #[test]
+    fn fits_local_percentiles_rounded_out_to_a_step() {
+        let ranges = fit_ranges(&ArchivePercentiles {
+            // -6.1°C / 30.2°C ≈ 21°F / 86°F
+            air_temp_c: metric(40_000, -6.1, 30.2),
+            humidity_percent: metric(40_000, 31.0, 99.5),
+            soil_moisture_10: metric(40_000, 0.112, 0.381),
+            ..Default::default()
+        });
+
+        assert_eq!(
+            (ranges.ambient_temp_f.min, ranges.ambient_temp_f.max),
+            (20.0, 90.0)
+        );
+        assert!(ranges.ambient_temp_f.adaptive);
+        assert_eq!(
+            (ranges.humidity_percent.min, ranges.humidity_percent.max),
+            (30.0, 100.0)
+        );
+        assert_eq!(
+            (
+                ranges.soil_moisture_percent.min,
+                ranges.soil_moisture_percent.max
+            ),
+            (10.0, 40.0)
+        );
+        // No soil temp history: static scale
+        assert_eq!(ranges.soil_temp_f, SOIL_TEMP_SCALE.fallback(0));
+    }
+
+    #[test]
+    fn thin_or_flat_history_keeps_the_static_scale() {
+        let ranges = fit_ranges(&ArchivePercentiles {
+            air_temp_c: metric(24 * 30, -6.1, 30.2),
+            soil_temp_10_c: metric(40_000, 10.0, 10.0),
+            ..Default::default()
+        });
+        assert!(!ranges.ambient_temp_f.adaptive);
+        assert_eq!(ranges.ambient_temp_f.samples, 24 * 30);
+        assert_eq!(ranges.soil_temp_f.min, SOIL_TEMP_SCALE.min);
+        assert_eq!(static_ranges().humidity_percent.max, 100.0);
+    }
+}
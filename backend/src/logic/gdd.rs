@@ -8,6 +8,13 @@ pub const CRABGRASS_GDD_THRESHOLD: f64 = 200.0;
 /// GDD value at which to issue a warning that germination is approaching.
 pub const CRABGRASS_GDD_WARNING: f64 = 150.0;
 
+/// GDD at which the first crabgrass seedlings emerge; germination progress
+/// starts counting here.
+pub const CRABGRASS_GERMINATION_START_GDD: f64 = CRABGRASS_GDD_WARNING;
+
+/// GDD by which the spring flush has essentially finished germinating.
+pub const CRABGRASS_GERMINATION_COMPLETE_GDD: f64 = 750.0;
+
 /// Accumulate cumulative GDD from the gold layer's daily rows
 /// `(date, high_f, low_f, gdd50)`. The lake precomputes `gdd50` with the identical
 /// base-50°F formula, so we trust it directly and only run the running sum here.
@@ -38,6 +45,14 @@ pub fn accumulate_daily_gdd(daily_data: &[DailyGddRow]) -> Vec<DailyGdd> {
     result
 }
 
+/// Estimated share of the season's crabgrass that has germinated, 0-100%.
+/// Emergence is treated as linear in GDD between the start and completion
+/// thresholds, which is close enough for a "~30% complete" readout.
+pub fn germination_pct(current_gdd: f64) -> f64 {
+    let span = CRABGRASS_GERMINATION_COMPLETE_GDD - CRABGRASS_GERMINATION_START_GDD;
+    ((current_gdd - CRABGRASS_GERMINATION_START_GDD) / span * 100.0).clamp(0.0, 100.0)
+}
+
 /// Determine crabgrass model status based on current YTD GDD accumulation.
 pub fn crabgrass_model(current_gdd: f64) -> CrabgrassModel {
     let status = if current_gdd >= CRABGRASS_GDD_THRESHOLD {
@@ -54,6 +69,7 @@ pub fn crabgrass_model(current_gdd: f64) -> CrabgrassModel {
         germination_threshold: CRABGRASS_GDD_THRESHOLD,
         current_gdd,
         status,
+        germination_pct: germination_pct(current_gdd),
         estimated_germination_date: None, // Could be computed from forecast data
     }
 }
@@ -165,4 +181,12 @@ mod tests {
         let model = crabgrass_model(220.0);
         assert_eq!(model.status, CrabgrassStatus::PostGermination);
     }
+
+    #[test]
+    fn germination_progress_runs_from_first_flush_to_complete() {
+        assert_eq!(crabgrass_model(100.0).germination_pct, 0.0);
+        assert_eq!(crabgrass_model(CRABGRASS_GDD_WARNING).germination_pct, 0.0);
+        assert!((crabgrass_model(330.0).germination_pct - 30.0).abs() < 1e-9);
+        assert_eq!(crabgrass_model(2000.0).germination_pct, 100.0);
+    }
 }
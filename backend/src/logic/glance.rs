@@ -0,0 +1,193 @@
+//! Read-only HTML dashboard (`GET /glance`): current conditions, active
+//! recommendations and this month's applications as one server-rendered page
+//! with no JavaScript, for a quick look from a phone on the LAN.
+
+use crate::logic::digest::conditions;
+use crate::logic::season_report::escape;
+use crate::models::{Application, EnvironmentalSummary, Recommendation, Severity};
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime};
+use std::fmt::Write;
+
+/// The page reloads itself this often.
+const REFRESH_SECS: u32 = 300;
+
+pub struct GlanceInput<'a> {
+    pub lawn_name: &'a str,
+    /// Local wall-clock time the page was rendered.
+    pub now: NaiveDateTime,
+    pub summary: &'a EnvironmentalSummary,
+    /// Active recommendations, most severe first.
+    pub recommendations: &'a [Recommendation],
+    /// Applications in the month of `now`; others are ignored.
+    pub applications: &'a [Application],
+}
+
+/// Matches `SEVERITY_COLORS` in the frontend.
+fn severity_color(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Info => "#9ca3af",
+        Severity::Advisory => "#3b82f6",
+        Severity::Warning => "#eab308",
+        Severity::Critical => "#ef4444",
+    }
+}
+
+pub fn render_glance(input: &GlanceInput) -> String {
+    let title = escape(input.lawn_name);
+    let mut out = format!(
+        "<!DOCTYPE html><html><head><meta charset=\"utf-8\">\
+         <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\
+         <meta http-equiv=\"refresh\" content=\"{REFRESH_SECS}\"><title>{title} — TurfOps</title>\
+         <style>body{{font-family:sans-serif;max-width:720px;margin:0 auto;padding:1rem;color:#2d3748;background:#f7fafc}}\
+         h1{{font-size:1.3rem;margin:0}}h2{{font-size:1rem;margin:1.2rem 0 .5rem}}\
+         .muted{{color:#718096;font-size:.8rem}}\
+         .tiles{{display:grid;grid-template-columns:repeat(auto-fill,minmax(150px,1fr));gap:8px}}\
+         .tile,.rec{{background:#fff;border-radius:8px;padding:.6rem .8rem;box-shadow:0 1px 2px rgba(0,0,0,.08)}}\
+         .tile b{{display:block;font-size:1.05rem}}.rec{{margin-bottom:8px;border-left:4px solid}}\
+         .rec p{{margin:.3rem 0 0;font-size:.85rem}}\
+         table{{width:100%;border-collapse:collapse;table-layout:fixed;background:#fff}}\
+         th,td{{border:1px solid #e2e8f0;padding:3px;vertical-align:top;font-size:.7rem}}\
+         td.today{{background:#ebf8ff}}td.out{{background:#f7fafc}}\
+         </style></head><body><h1>{title}</h1><div class=\"muted\">Updated {}</div>",
+        input.now.format("%a %b %-d, %-I:%M %p")
+    );
+
+    out.push_str("<h2>Conditions</h2>");
+    let tiles = conditions(input.summary);
+    if tiles.is_empty() {
+        out.push_str("<p class=\"muted\">No sensor or forecast data available.</p>");
+    } else {
+        out.push_str("<div class=\"tiles\">");
+        for (label, value) in &tiles {
+            let _ = write!(
+                out,
+                "<div class=\"tile\"><span class=\"muted\">{}</span><b>{}</b></div>",
+                escape(label),
+                escape(value)
+            );
+        }
+        out.push_str("</div>");
+    }
+
+    let _ = write!(
+        out,
+        "<h2>Recommendations ({})</h2>",
+        input.recommendations.len()
+    );
+    if input.recommendations.is_empty() {
+        out.push_str("<p class=\"muted\">Nothing needs doing right now.</p>");
+    }
+    for rec in input.recommendations {
+        let _ = write!(
+            out,
+            "<div class=\"rec\" style=\"border-color:{}\"><span class=\"muted\">{}</span> \
+             <strong>{}</strong><p>{}</p>",
+            severity_color(rec.severity),
+            rec.severity,
+            escape(&rec.title),
+            escape(&rec.description)
+        );
+        if let Some(action) = &rec.suggested_action {
+            let _ = write!(out, "<p><em>{}</em></p>", escape(action));
+        }
+        out.push_str("</div>");
+    }
+
+    out.push_str(&render_month(input.now.date(), input.applications));
+    out.push_str("</body></html>");
+    out
+}
+
+/// Sunday-first month grid with each day's applications.
+fn render_month(today: NaiveDate, applications: &[Application]) -> String {
+    let first = today.with_day(1).unwrap_or(today);
+    let mut out = format!(
+        "<h2>Applications — {}</h2><table><tr>",
+        first.format("%B %Y")
+    );
+    for day in ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"] {
+        let _ = write!(out, "<th>{day}</th>");
+    }
+    out.push_str("</tr>");
+
+    let mut day = first - Duration::days(first.weekday().num_days_from_sunday() as i64);
+    loop {
+        out.push_str("<tr>");
+        for _ in 0..7 {
+            let class = if day == today {
+                " class=\"today\""
+            } else if day.month() != first.month() {
+                " class=\"out\""
+            } else {
+                ""
+            };
+            let _ = write!(out, "<td{class}>{}", day.day());
+            if day.month() == first.month() {
+                for app in applications.iter().filter(|a| a.application_date == day) {
+                    let _ = write!(
+                        out,
+                        "<br>{}",
+                        escape(
+                            app.product_name
+                                .as_deref()
+                                .unwrap_or(app.application_type.as_str())
+                        )
+                    );
+                }
+            }
+            out.push_str("</td>");
+            day += Duration::days(1);
+        }
+        out.push_str("</tr>");
+        if day.month() != first.month() {
+            break;
+        }
+    }
+    out.push_str("</table>");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ApplicationType, RecommendationCategory};
+    use crate::testing::{application, at, date, EnvBuilder};
+
+    #[test]
+    fn renders_conditions_recommendations_and_month() {
+        let env = EnvBuilder::new(at(2025, 9, 3))
+            .soil_temp(68.0, 70.0)
+            .build();
+        let recs = vec![Recommendation::new(
+            "fall_overseeding",
+            RecommendationCategory::Overseeding,
+            Severity::Warning,
+            "Seed <now>",
+            "Soil temps are ideal & stable.",
+        )];
+        let apps = vec![
+            application(ApplicationType::Overseed, date(2025, 9, 2)),
+            application(ApplicationType::Fertilizer, date(2025, 8, 30)),
+        ];
+
+        let html = render_glance(&GlanceInput {
+            lawn_name: "Front & Back",
+            now: date(2025, 9, 3).and_hms_opt(7, 30, 0).unwrap(),
+            summary: &env,
+            recommendations: &recs,
+            applications: &apps,
+        });
+
+        assert!(html.contains("<title>Front &amp; Back — TurfOps</title>"));
+        assert!(html.contains("Soil temp (10cm)"));
+        assert!(html.contains("Recommendations (1)"));
+        assert!(html.contains("Seed &lt;now&gt;"));
+        assert!(html.contains("ideal &amp; stable"));
+        assert!(html.contains("Applications — September 2025"));
+        assert!(html.contains("<td class=\"today\">3</td>"));
+        // Sept 2025 starts on a Monday: Aug 31 pads the first week, without its applications
+        assert!(html.contains("<td class=\"out\">31</td>"));
+        assert!(html.contains("<td>2<br>Overseed</td>"));
+        assert!(!html.contains("Fertilizer"));
+    }
+}
@@ -0,0 +1,152 @@
+//! Inventory shortfall notes: when a recommendation calls for a product and
+//! the matching inventory won't cover one application to the lawn, say so on
+//! the recommendation.
+
+use super::rules::thresholds::DEFAULT_LAWN_SIZE_SQFT;
+use crate::models::{
+    Application, ApplicationType, InventoryItem, InventoryStatus, LawnProfile, Recommendation,
+    RecommendationCategory,
+};
+use chrono::NaiveDate;
+
+/// What's left of each item after the profile's applications since its count.
+pub fn inventory_statuses(
+    items: Vec<InventoryItem>,
+    applications: &[Application],
+    profile: &LawnProfile,
+    today: NaiveDate,
+) -> Vec<InventoryStatus> {
+    let lawn_size = profile.lawn_size_sqft.unwrap_or(DEFAULT_LAWN_SIZE_SQFT);
+    items
+        .into_iter()
+        .map(|item| InventoryStatus::new(item, applications, lawn_size, today))
+        .collect()
+}
+
+/// The application type a product recommendation would be logged as.
+fn application_type_for(category: RecommendationCategory) -> Option<ApplicationType> {
+    match category {
+        RecommendationCategory::PreEmergent => Some(ApplicationType::PreEmergent),
+        RecommendationCategory::GrubControl => Some(ApplicationType::GrubControl),
+        RecommendationCategory::Fertilizer => Some(ApplicationType::Fertilizer),
+        RecommendationCategory::Fungicide => Some(ApplicationType::Fungicide),
+        RecommendationCategory::Herbicide => Some(ApplicationType::PostEmergent),
+        _ => None,
+    }
+}
+
+/// Set `inventory_note` on product recommendations the shelf can't cover.
+/// Categories with no tracked product are left alone, as is a product with
+/// no known rate unless it has run out.
+pub fn annotate_shortfalls(recommendations: &mut [Recommendation], inventory: &[InventoryStatus]) {
+    for rec in recommendations.iter_mut() {
+        let Some(kind) = application_type_for(rec.category) else {
+            continue;
+        };
+        let stock: Vec<_> = inventory
+            .iter()
+            .filter(|s| s.item.application_type == Some(kind))
+            .collect();
+        if stock.is_empty() {
+            continue;
+        }
+        // Any one product that covers the application is enough
+        let covered = stock.iter().any(|s| match s.per_application {
+            Some(needed) => s.remaining >= needed,
+            None => s.remaining > 0.0,
+        });
+        if covered {
+            continue;
+        }
+
+        let shortages: Vec<String> = stock
+            .iter()
+            .map(|s| {
+                let unit = s.item.unit.as_str();
+                match s.per_application {
+                    Some(needed) => format!(
+                        "{:.1} {} of {} on hand, about {:.1} {} needed",
+                        s.remaining, unit, s.item.product_name, needed, unit
+                    ),
+                    None => format!("out of {}", s.item.product_name),
+                }
+            })
+            .collect();
+        rec.inventory_note = Some(format!(
+            "Not enough product for this application: {}.",
+            shortages.join("; ")
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{InventoryItem, ProductUnit, Severity};
+    use crate::testing::{at, date};
+
+    fn status(
+        kind: ApplicationType,
+        remaining: f64,
+        per_application: Option<f64>,
+    ) -> InventoryStatus {
+        InventoryStatus {
+            item: InventoryItem {
+                id: Some(1),
+                lawn_profile_id: 1,
+                product_name: "Prodiamine".into(),
+                application_type: Some(kind),
+                unit: ProductUnit::Pounds,
+                package_size: None,
+                quantity: remaining,
+                counted_on: date(2025, 3, 1),
+                rate_per_1000sqft: None,
+                notes: None,
+                created_at: at(2025, 3, 1),
+            },
+            used_since_count: 0.0,
+            remaining,
+            per_application,
+        }
+    }
+
+    fn rec(category: RecommendationCategory) -> Recommendation {
+        Recommendation::new("r", category, Severity::Advisory, "t", "d")
+    }
+
+    #[test]
+    fn short_inventory_notes_matching_recommendations() {
+        let mut recs = vec![
+            rec(RecommendationCategory::PreEmergent),
+            rec(RecommendationCategory::Fertilizer),
+            rec(RecommendationCategory::Mowing),
+        ];
+        annotate_shortfalls(
+            &mut recs,
+            &[status(ApplicationType::PreEmergent, 2.0, Some(5.0))],
+        );
+        assert_eq!(
+            recs[0].inventory_note.as_deref(),
+            Some("Not enough product for this application: 2.0 lb of Prodiamine on hand, about 5.0 lb needed.")
+        );
+        // Untracked categories say nothing
+        assert!(recs[1].inventory_note.is_none());
+        assert!(recs[2].inventory_note.is_none());
+    }
+
+    #[test]
+    fn enough_inventory_or_unknown_rate_leaves_note_unset() {
+        let mut recs = vec![rec(RecommendationCategory::PreEmergent)];
+        annotate_shortfalls(
+            &mut recs,
+            &[status(ApplicationType::PreEmergent, 6.0, Some(5.0))],
+        );
+        assert!(recs[0].inventory_note.is_none());
+
+        annotate_shortfalls(
+            &mut recs,
+            &[status(ApplicationType::PreEmergent, 1.0, None)],
+        );
+        assert!(recs[0].inventory_note.is_none());
+    }
+}
@@ -1,9 +1,33 @@
+pub mod aftercare;
+pub mod agenda;
+pub mod annual_review;
+pub mod api_quota;
+pub mod backtest;
+pub mod calculations;
+pub mod connectivity;
 pub mod data_sync;
+pub mod digest;
+pub mod efficacy;
+pub mod equipment;
 pub mod follow_up;
+pub mod frost;
+pub mod gauge_ranges;
 pub mod gdd;
+pub mod glance;
+pub mod inventory;
+pub mod notify;
 pub mod plant_maintenance;
+pub mod product_labels;
+pub mod rain_check;
+pub mod retention;
 pub mod rules;
+pub mod season_bundle;
+pub mod season_report;
 pub mod seasonal_plan;
+pub mod seen;
+pub mod soil_anomaly;
 pub mod soil_temp_prediction;
 pub mod soil_test_recommendations;
 pub mod soil_test_thresholds;
+pub mod stress_index;
+pub mod weather_backfill;
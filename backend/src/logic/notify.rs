@@ -0,0 +1,151 @@
+//! Notification policy (`NotificationConfig`): quiet hours, per-recommendation
+//! cooldowns and escalation-only repeats. The digest email applies it; any
+//! other channel that pushes recommendations should go through the same calls.
+
+use crate::config::{NotificationConfig, QuietHours};
+use crate::models::{Recommendation, Severity};
+use chrono::{DateTime, Duration, NaiveDateTime, NaiveTime, Utc};
+use std::collections::HashMap;
+
+/// The last time a recommendation was sent, from `notification_log`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Notified {
+    pub severity: Severity,
+    pub notified_at: DateTime<Utc>,
+}
+
+pub fn in_quiet_hours(quiet: &QuietHours, time: NaiveTime) -> bool {
+    if quiet.start <= quiet.end {
+        quiet.start <= time && time < quiet.end
+    } else {
+        time >= quiet.start || time < quiet.end
+    }
+}
+
+/// `at`, or the end of the quiet hours it falls in.
+pub fn defer_past_quiet_hours(at: NaiveDateTime, quiet: Option<&QuietHours>) -> NaiveDateTime {
+    let Some(quiet) = quiet.filter(|q| in_quiet_hours(q, at.time())) else {
+        return at;
+    };
+    let end = at.date().and_time(quiet.end);
+    if end > at {
+        end
+    } else {
+        end + Duration::days(1)
+    }
+}
+
+/// Split active recommendations into those to send and the number held back
+/// because they were already sent: never again without a severity increase
+/// in escalation-only mode, otherwise not within the cooldown.
+pub fn filter_repeats(
+    recommendations: Vec<Recommendation>,
+    notified: &HashMap<String, Notified>,
+    config: &NotificationConfig,
+    now: DateTime<Utc>,
+) -> (Vec<Recommendation>, usize) {
+    let cooldown = Duration::hours(config.cooldown_hours as i64);
+    let (send, held): (Vec<_>, Vec<_>) = recommendations.into_iter().partition(|rec| {
+        let Some(last) = notified.get(&rec.id) else {
+            return true;
+        };
+        if rec.severity > last.severity {
+            return true;
+        }
+        !config.escalation_only && now - last.notified_at >= cooldown
+    });
+    (send, held.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::RecommendationCategory;
+    use crate::testing::{at, date};
+
+    fn time(h: u32, m: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(h, m, 0).unwrap()
+    }
+
+    #[test]
+    fn quiet_hours_wrap_midnight_and_defer_to_their_end() {
+        let night: QuietHours = "22-7".parse().unwrap();
+        assert!(in_quiet_hours(&night, time(23, 0)));
+        assert!(in_quiet_hours(&night, time(2, 0)));
+        assert!(!in_quiet_hours(&night, time(7, 0)));
+        assert!(!in_quiet_hours(&night, time(12, 0)));
+
+        let at = |d, h, m| date(2025, 9, d).and_time(time(h, m));
+        assert_eq!(
+            defer_past_quiet_hours(at(3, 23, 30), Some(&night)),
+            at(4, 7, 0)
+        );
+        assert_eq!(
+            defer_past_quiet_hours(at(4, 6, 0), Some(&night)),
+            at(4, 7, 0)
+        );
+        assert_eq!(
+            defer_past_quiet_hours(at(4, 8, 0), Some(&night)),
+            at(4, 8, 0)
+        );
+        assert_eq!(defer_past_quiet_hours(at(4, 2, 0), None), at(4, 2, 0));
+
+        let nap: QuietHours = "13:30-15:00".parse().unwrap();
+        assert!(in_quiet_hours(&nap, time(14, 0)));
+        assert!(!in_quiet_hours(&nap, time(15, 0)));
+        assert!("22".parse::<QuietHours>().is_err());
+        assert!("25-7".parse::<QuietHours>().is_err());
+    }
+
+    #[test]
+    fn repeats_only_after_cooldown_or_escalation() {
+        let now = at(2025, 9, 3);
+        let rec = |id: &str, severity| {
+            Recommendation::new(
+                id,
+                RecommendationCategory::DiseasePressure,
+                severity,
+                id,
+                "",
+            )
+        };
+        let recs = || {
+            vec![
+                rec("brown_patch", Severity::Warning),
+                rec("dollar_spot", Severity::Warning),
+                rec("red_thread", Severity::Critical),
+                rec("new_one", Severity::Advisory),
+            ]
+        };
+        let sent = |severity, hours_ago| Notified {
+            severity,
+            notified_at: now - Duration::hours(hours_ago),
+        };
+        let notified = HashMap::from([
+            ("brown_patch".to_string(), sent(Severity::Warning, 30)),
+            ("dollar_spot".to_string(), sent(Severity::Warning, 2)),
+            ("red_thread".to_string(), sent(Severity::Warning, 2)),
+        ]);
+        let ids = |recs: &[Recommendation]| recs.iter().map(|r| r.id.clone()).collect::<Vec<_>>();
+
+        // No limits: everything repeats
+        let (send, held) = filter_repeats(recs(), &notified, &NotificationConfig::default(), now);
+        assert_eq!((send.len(), held), (4, 0));
+
+        let cooldown = NotificationConfig {
+            cooldown_hours: 24,
+            ..Default::default()
+        };
+        let (send, held) = filter_repeats(recs(), &notified, &cooldown, now);
+        assert_eq!(ids(&send), ["brown_patch", "red_thread", "new_one"]);
+        assert_eq!(held, 1);
+
+        let escalation = NotificationConfig {
+            escalation_only: true,
+            ..Default::default()
+        };
+        let (send, held) = filter_repeats(recs(), &notified, &escalation, now);
+        assert_eq!(ids(&send), ["red_thread", "new_one"]);
+        assert_eq!(held, 2);
+    }
+}
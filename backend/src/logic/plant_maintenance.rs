@@ -288,6 +288,9 @@ mod tests {
             potassium_pct: None,
             plant_id: Some(plant_id),
             follow_up_date: None,
+            area_id: None,
+            equipment_id: None,
+            custom_type_id: None,
             created_at: Utc::now(),
         }
     }
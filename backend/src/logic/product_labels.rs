@@ -0,0 +1,218 @@
+//! Product label files: a community-maintained list of products (actives,
+//! FRAC/IRAC groups, label rates, watering-in, residual) merged into the
+//! label catalog by `import-products` or the Settings page.
+//!
+//! A file is YAML or JSON (a list of entries) or CSV (one row per product,
+//! keywords separated by `;`), with the field names of [`LabelEntry`]. Entries
+//! match stored products by name, ignoring case. New names are added and
+//! imported products are refreshed; products added or changed by hand are
+//! never overwritten, so re-running an import after the file updates is safe.
+
+use crate::db::product_queries;
+use crate::error::{Result, TurfOpsError};
+use crate::models::{set_label_products, LabelEntry, LabelProduct};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use std::collections::HashSet;
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LabelFormat {
+    Yaml,
+    Csv,
+    Json,
+}
+
+impl LabelFormat {
+    /// From the file extension: `.yaml`/`.yml`, `.csv` or `.json`.
+    pub fn from_path(path: &Path) -> Result<Self> {
+        let ext = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(str::to_lowercase);
+        match ext.as_deref() {
+            Some("yaml" | "yml") => Ok(LabelFormat::Yaml),
+            Some("csv") => Ok(LabelFormat::Csv),
+            Some("json") => Ok(LabelFormat::Json),
+            _ => Err(TurfOpsError::InvalidData(format!(
+                "{}: label files are .yaml, .csv or .json",
+                path.display()
+            ))),
+        }
+    }
+}
+
+/// Parse and validate a label file. Every entry must be valid and each name
+/// may appear once, so a bad file changes nothing.
+pub fn parse_labels(raw: &str, format: LabelFormat) -> Result<Vec<LabelEntry>> {
+    let entries: Vec<LabelEntry> = match format {
+        LabelFormat::Yaml => serde_yaml_ng::from_str(raw)
+            .map_err(|e| TurfOpsError::InvalidData(format!("Label file: {}", e)))?,
+        LabelFormat::Json => serde_json::from_str(raw)
+            .map_err(|e| TurfOpsError::InvalidData(format!("Label file: {}", e)))?,
+        LabelFormat::Csv => csv::ReaderBuilder::new()
+            .trim(csv::Trim::All)
+            .from_reader(raw.as_bytes())
+            .deserialize()
+            .collect::<std::result::Result<_, _>>()
+            .map_err(|e| TurfOpsError::InvalidData(format!("Label file: {}", e)))?,
+    };
+
+    let mut seen = HashSet::new();
+    entries
+        .into_iter()
+        .map(LabelEntry::normalized)
+        .map(|entry| {
+            entry.validate().map_err(TurfOpsError::InvalidData)?;
+            if !seen.insert(entry.name.to_lowercase()) {
+                return Err(TurfOpsError::InvalidData(format!(
+                    "{} is listed more than once",
+                    entry.name
+                )));
+            }
+            Ok(entry)
+        })
+        .collect()
+}
+
+/// What an import will do, worked out before anything is written.
+#[derive(Debug, Default)]
+pub struct MergePlan {
+    pub added: Vec<LabelEntry>,
+    /// Imported products whose label facts changed, by id.
+    pub updated: Vec<(i64, LabelEntry)>,
+    /// Products edited by hand that the file also lists.
+    pub kept: Vec<String>,
+    pub unchanged: usize,
+}
+
+pub fn plan_merge(existing: &[LabelProduct], entries: Vec<LabelEntry>) -> MergePlan {
+    let mut plan = MergePlan::default();
+    for entry in entries {
+        match existing
+            .iter()
+            .find(|p| p.label.name.eq_ignore_ascii_case(&entry.name))
+        {
+            None => plan.added.push(entry),
+            Some(product) if product.user_edited => plan.kept.push(product.label.name.clone()),
+            Some(product) if product.label == entry => plan.unchanged += 1,
+            Some(product) => plan.updated.push((product.id, entry)),
+        }
+    }
+    plan
+}
+
+#[derive(Debug, Serialize)]
+pub struct ImportReport {
+    pub added: Vec<String>,
+    pub updated: Vec<String>,
+    /// Listed in the file but edited by hand, so left as they are.
+    pub kept: Vec<String>,
+    pub unchanged: usize,
+}
+
+/// Merge a label file into the catalog and start using it.
+pub async fn import_labels(pool: &PgPool, raw: &str, format: LabelFormat) -> Result<ImportReport> {
+    let entries = parse_labels(raw, format)?;
+    let existing = product_queries::list_label_products(pool).await?;
+    let plan = plan_merge(&existing, entries);
+
+    product_queries::apply_label_import(pool, &plan.added, &plan.updated).await?;
+    reload(pool).await?;
+
+    Ok(ImportReport {
+        added: plan.added.into_iter().map(|e| e.name).collect(),
+        updated: plan.updated.into_iter().map(|(_, e)| e.name).collect(),
+        kept: plan.kept,
+        unchanged: plan.unchanged,
+    })
+}
+
+/// Load the stored label products into the catalog rules look products up in.
+pub async fn reload(pool: &PgPool) -> Result<()> {
+    let products = product_queries::list_label_products(pool).await?;
+    set_label_products(products.iter().map(|p| p.label.info()).collect());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    const YAML: &str = r#"
+- name: Prodiamine 65 WDG
+  active_ingredient: prodiamine
+  keywords: [barricade]
+  rate_per_1000sqft: 0.37
+  rate_unit: oz
+  water_in_inches: 0.5
+  water_in_days: 14
+  reentry: until_dry
+  residual_days: 120
+  seeded_turf_days: 60
+- name: Heritage G
+  active_ingredient: azoxystrobin
+  frac_group: "11"
+"#;
+
+    const CSV: &str = "\
+name,active_ingredient,keywords,frac_group,irac_group,rate_per_1000sqft,rate_unit,water_in_inches,water_in_days,reentry,residual_days,seeded_turf_days
+Prodiamine 65 WDG,prodiamine,barricade,,,0.37,oz,0.5,14,until_dry,120,60
+Heritage G,azoxystrobin,,11,,,,,,,,
+";
+
+    fn stored(id: i64, label: LabelEntry, user_edited: bool) -> LabelProduct {
+        LabelProduct {
+            id,
+            label,
+            user_edited,
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn yaml_and_csv_read_the_same() {
+        let yaml = parse_labels(YAML, LabelFormat::Yaml).unwrap();
+        let csv = parse_labels(CSV, LabelFormat::Csv).unwrap();
+        assert_eq!(yaml, csv);
+        assert_eq!(yaml[0].keywords, vec!["barricade"]);
+        assert_eq!(yaml[0].water_in_days, Some(14));
+        assert_eq!(yaml[1].frac_group.as_deref(), Some("11"));
+        assert_eq!(yaml[1].rate_unit, None);
+
+        let twice = format!(
+            "{}- name: heritage g\n  active_ingredient: azoxystrobin\n",
+            YAML
+        );
+        assert!(parse_labels(&twice, LabelFormat::Yaml).is_err());
+    }
+
+    #[test]
+    fn merge_keeps_hand_edits() {
+        let file = parse_labels(YAML, LabelFormat::Yaml).unwrap();
+        let mut edited = file[0].clone();
+        edited.residual_days = Some(150);
+
+        // Nothing stored: everything is new
+        let plan = plan_merge(&[], file.clone());
+        assert_eq!(plan.added.len(), 2);
+
+        // The imported product refreshes; the hand-edited one is kept
+        let mut stale = file[1].clone();
+        stale.frac_group = None;
+        let existing = vec![stored(1, edited, true), stored(2, stale, false)];
+        let plan = plan_merge(&existing, file.clone());
+        assert!(plan.added.is_empty());
+        assert_eq!(plan.kept, vec!["Prodiamine 65 WDG"]);
+        assert_eq!(plan.updated.len(), 1);
+        assert_eq!(plan.updated[0].0, 2);
+
+        // Re-running with nothing changed writes nothing
+        let existing = vec![stored(2, file[1].clone(), false)];
+        let plan = plan_merge(&existing, vec![file[1].clone()]);
+        assert!(plan.updated.is_empty() && plan.added.is_empty());
+        assert_eq!(plan.unchanged, 1);
+    }
+}
@@ -0,0 +1,308 @@
+//! Rain checks: Rain Delay and the irrigation rule both lean on the forecast,
+//! so when forecast rain doesn't fall a delayed application is waiting for
+//! nothing and the lawn goes unwatered. On each forecast refresh the next rain
+//! event is recorded; once its window has passed the station's precipitation
+//! total over the window is recorded next to it, and a shortfall turns into an
+//! "irrigate today" follow-up.
+
+use crate::logic::rules::thresholds::{
+    IRRIGATION_PAW_CLEAR, IRRIGATION_PAW_WARNING, PRECIP_TRACE_MM, RAIN_DELAY_ADVISORY_HOURS,
+};
+use crate::models::{
+    DataSource, EnvironmentalReading, Millimeters, RainCheck, RainWindow, Recommendation,
+    RecommendationCategory, Severity, SoilWaterLimits, WeatherForecast,
+};
+use chrono::{DateTime, Duration, Utc};
+
+/// Forecast points are 3 hours apart; each covers the 3 hours after it.
+const FORECAST_STEP_HOURS: i64 = 3;
+
+/// Wait this long past a window's end for the station's last hours to land.
+const RECONCILE_DELAY_HOURS: i64 = 2;
+
+/// Stop waiting for station precipitation this long after a window ends.
+const GIVE_UP_HOURS: i64 = 72;
+
+/// A rain event counts as missed when less than this share of the forecast fell.
+const SHORTFALL_SHARE: f64 = 0.5;
+
+/// How long after a missed window the follow-up stays up.
+pub const FOLLOW_UP_HOURS: i64 = 36;
+
+/// The rain the forecast calls for over the Rain Delay horizon: from the first
+/// wet point to the end of the last one. `None` when it adds up to less than a
+/// trace.
+pub fn forecast_rain_window(forecast: &WeatherForecast, now: DateTime<Utc>) -> Option<RainWindow> {
+    let step = Duration::hours(FORECAST_STEP_HOURS);
+    let horizon = now + Duration::hours(RAIN_DELAY_ADVISORY_HOURS as i64);
+    let wet: Vec<_> = forecast
+        .hourly
+        .iter()
+        .filter(|p| p.timestamp + step > now && p.timestamp < horizon)
+        .filter(|p| p.precipitation_mm > 0.0)
+        .collect();
+
+    let forecast_mm: f64 = wet.iter().map(|p| p.precipitation_mm).sum();
+    if forecast_mm < PRECIP_TRACE_MM {
+        return None;
+    }
+    Some(RainWindow {
+        start: wet.first()?.timestamp,
+        end: wet.last()?.timestamp + step,
+        forecast_mm,
+    })
+}
+
+/// What to do with the pending check after a forecast refresh.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RainCheckAction {
+    Create(RainWindow),
+    Update(i64, RainWindow),
+    Delete(i64),
+    Keep,
+}
+
+/// Track the forecast while the rain is still ahead; once it has started the
+/// check is fixed except for a continuous extension of its end.
+pub fn plan_rain_check(
+    pending: Option<&RainCheck>,
+    window: Option<RainWindow>,
+    now: DateTime<Utc>,
+) -> RainCheckAction {
+    let Some(check) = pending else {
+        return window.map_or(RainCheckAction::Keep, RainCheckAction::Create);
+    };
+    let Some(id) = check.id else {
+        return RainCheckAction::Keep;
+    };
+    let started = check.window_start <= now;
+
+    match window {
+        Some(w) if !started => RainCheckAction::Update(id, w),
+        Some(w) if w.start <= check.window_end && w.end > check.window_end => {
+            RainCheckAction::Update(
+                id,
+                RainWindow {
+                    start: check.window_start,
+                    end: w.end,
+                    forecast_mm: check.forecast_mm.max(w.forecast_mm),
+                },
+            )
+        }
+        // The forecast dropped the rain before it started; the irrigation rule
+        // will see a dry forecast on its own.
+        None if !started => RainCheckAction::Delete(id),
+        _ => RainCheckAction::Keep,
+    }
+}
+
+/// Whether the check's window is far enough behind to look at the station.
+pub fn ready_to_reconcile(check: &RainCheck, now: DateTime<Utc>) -> bool {
+    check.window_end + Duration::hours(RECONCILE_DELAY_HOURS) <= now
+}
+
+/// Whether to stop waiting for station data for the check.
+pub fn should_give_up(check: &RainCheck, now: DateTime<Utc>) -> bool {
+    check.window_end + Duration::hours(GIVE_UP_HOURS) <= now
+}
+
+/// Station precipitation over the window. `None` when no reading in the window
+/// reports precipitation, so a gap isn't mistaken for a dry spell.
+pub fn observed_rain_mm(
+    readings: &[EnvironmentalReading],
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Option<f64> {
+    let amounts: Vec<f64> = readings
+        .iter()
+        .filter(|r| r.timestamp > start && r.timestamp <= end)
+        .filter_map(|r| r.precipitation_mm)
+        .collect();
+    (!amounts.is_empty()).then(|| amounts.iter().sum())
+}
+
+/// An "irrigate today" follow-up for the most recent missed rain event, unless
+/// the soil is already moist enough not to need it.
+pub fn generate_rain_check_recommendations(
+    checks: &[RainCheck],
+    soil_moisture: Option<f64>,
+    limits: SoilWaterLimits,
+    now: DateTime<Utc>,
+) -> Vec<Recommendation> {
+    let available = soil_moisture.map(|m| limits.plant_available(m));
+    if available.is_some_and(|a| a >= IRRIGATION_PAW_CLEAR) {
+        return Vec::new();
+    }
+
+    let missed = checks
+        .iter()
+        .filter(|c| c.window_end + Duration::hours(FOLLOW_UP_HOURS) > now)
+        .filter_map(|c| Some((c, c.id?, c.observed_mm?)))
+        .filter(|(c, _, observed)| {
+            *observed < c.forecast_mm * SHORTFALL_SHARE
+                && c.forecast_mm - *observed >= PRECIP_TRACE_MM
+        })
+        .max_by_key(|(c, _, _)| c.window_end);
+    let Some((check, id, observed)) = missed else {
+        return Vec::new();
+    };
+
+    let severity = if available.is_some_and(|a| a < IRRIGATION_PAW_WARNING) {
+        Severity::Warning
+    } else {
+        Severity::Advisory
+    };
+    let local_end = check.window_end.with_timezone(&chrono::Local);
+
+    let mut rec = Recommendation::new(
+        format!("rain_check_{}", id),
+        RecommendationCategory::Irrigation,
+        severity,
+        "Forecast Rain Didn't Materialize — Irrigate Today",
+        format!(
+            "{:.2} of rain was forecast through {}, but the station recorded {:.2}.",
+            Millimeters(check.forecast_mm).to_inches(),
+            local_end.format("%a %-I %p"),
+            Millimeters(observed).to_inches()
+        ),
+    )
+    .with_explanation(
+        "Rain Delay and the irrigation forecast both held off on the expectation of rain. \
+         When it doesn't come, the lawn has gone without water it was counting on, and \
+         any application delayed for the rain can go ahead.",
+    )
+    .with_data_point(
+        "Forecast Rain",
+        format!("{:.2}", Millimeters(check.forecast_mm).to_inches()),
+        DataSource::OpenWeatherMap.as_str(),
+    )
+    .with_data_point(
+        "Observed Rain",
+        format!("{:.2}", Millimeters(observed).to_inches()),
+        DataSource::SoilData.as_str(),
+    );
+    if let Some(a) = available {
+        rec = rec.with_data_point(
+            "Plant-Available Water",
+            format!("{:.0}%", a * 100.0),
+            DataSource::SoilData.as_str(),
+        );
+    }
+    vec![rec.with_action(
+        "Water 0.5-1 inch today, early morning or evening if you can. If you held off a \
+         fertilizer or herbicide application for the rain, it can go down now.",
+    )]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{SoilType, WeatherForecast};
+    use crate::testing::{at, date, ForecastBuilder};
+    use chrono::NaiveDate;
+
+    fn wet_forecast() -> WeatherForecast {
+        // Day 1 dry, day 2 wet
+        ForecastBuilder::starting(date(2026, 7, 10))
+            .day(85.0, 65.0, 60.0, 0.0)
+            .day(78.0, 64.0, 85.0, 16.0)
+            .build()
+    }
+
+    fn check(forecast_mm: f64, observed_mm: Option<f64>) -> RainCheck {
+        RainCheck {
+            id: Some(4),
+            lawn_profile_id: 1,
+            window_start: at(2026, 7, 11),
+            window_end: at(2026, 7, 12),
+            forecast_mm,
+            observed_mm,
+            reconciled_at: Some(at(2026, 7, 12) + Duration::hours(3)),
+        }
+    }
+
+    #[test]
+    fn window_spans_the_wet_points() {
+        let w = forecast_rain_window(&wet_forecast(), at(2026, 7, 10)).unwrap();
+        let midnight = |d: NaiveDate| d.and_hms_opt(0, 0, 0).unwrap().and_utc();
+        assert_eq!(w.start, midnight(date(2026, 7, 11)));
+        assert_eq!(w.end, midnight(date(2026, 7, 12)));
+        assert!((w.forecast_mm - 16.0).abs() < 1e-9);
+
+        let dry = ForecastBuilder::starting(date(2026, 7, 10))
+            .day(85.0, 65.0, 60.0, 1.0)
+            .build();
+        assert_eq!(forecast_rain_window(&dry, at(2026, 7, 10)), None);
+    }
+
+    #[test]
+    fn pending_check_follows_forecast_until_rain_starts() {
+        let now = at(2026, 7, 10);
+        let window = forecast_rain_window(&wet_forecast(), now);
+        assert_eq!(
+            plan_rain_check(None, window, now),
+            RainCheckAction::Create(window.unwrap())
+        );
+
+        let mut pending = check(16.0, None);
+        pending.reconciled_at = None;
+        assert_eq!(
+            plan_rain_check(Some(&pending), None, now),
+            RainCheckAction::Delete(4)
+        );
+        // Once it's raining a dry forecast doesn't erase the expectation
+        let during = at(2026, 7, 11) + Duration::hours(6);
+        assert_eq!(
+            plan_rain_check(Some(&pending), None, during),
+            RainCheckAction::Keep
+        );
+    }
+
+    #[test]
+    fn observed_rain_needs_station_data() {
+        let start = at(2026, 7, 11);
+        let mut r = EnvironmentalReading::new(DataSource::SoilData);
+        r.timestamp = start + Duration::hours(2);
+        assert_eq!(observed_rain_mm(&[r.clone()], start, at(2026, 7, 12)), None);
+        r.precipitation_mm = Some(0.0);
+        assert_eq!(observed_rain_mm(&[r], start, at(2026, 7, 12)), Some(0.0));
+    }
+
+    #[test]
+    fn missed_rain_on_dry_soil_says_irrigate() {
+        let loam = SoilType::Loam.water_limits();
+        let now = at(2026, 7, 12) + Duration::hours(4);
+
+        let recs =
+            generate_rain_check_recommendations(&[check(16.0, Some(1.0))], Some(0.15), loam, now);
+        assert_eq!(recs.len(), 1);
+        assert_eq!(recs[0].id, "rain_check_4");
+        assert_eq!(recs[0].severity, Severity::Warning);
+
+        // Most of it fell
+        assert!(generate_rain_check_recommendations(
+            &[check(16.0, Some(12.0))],
+            Some(0.15),
+            loam,
+            now
+        )
+        .is_empty());
+        // Soil is moist anyway
+        assert!(generate_rain_check_recommendations(
+            &[check(16.0, Some(1.0))],
+            Some(0.26),
+            loam,
+            now
+        )
+        .is_empty());
+        // Old news
+        let later = now + Duration::hours(FOLLOW_UP_HOURS);
+        assert!(generate_rain_check_recommendations(
+            &[check(16.0, Some(1.0))],
+            Some(0.15),
+            loam,
+            later
+        )
+        .is_empty());
+    }
+}
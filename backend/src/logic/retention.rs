@@ -0,0 +1,206 @@
+//! Database retention. A background task periodically deletes rows past their
+//! retention (`RetentionConfig`), vacuums the tables that lost rows and records
+//! what it did in the activity log. Only derived or short-lived data is pruned:
+//! the local lake copy (the lake keeps the full history), the activity log and
+//! old rain checks. Applications, observations and recommendation states stay.
+
+use crate::config::RetentionConfig;
+use crate::db::{event_queries, maintenance_queries};
+use crate::error::Result;
+use crate::models::{Event, EventKind, EventLevel};
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use sqlx::PgPool;
+
+/// Let startup traffic settle before the first run.
+const FIRST_RUN_DELAY_SECS: u64 = 300;
+
+/// A table with a retention period.
+#[derive(Debug, Clone, Copy)]
+enum Pruned {
+    SoilObservations,
+    Events,
+    RainChecks,
+}
+
+impl Pruned {
+    const ALL: [Pruned; 3] = [Pruned::SoilObservations, Pruned::Events, Pruned::RainChecks];
+
+    fn table(self) -> &'static str {
+        match self {
+            Pruned::SoilObservations => "soil_observations",
+            Pruned::Events => "events",
+            Pruned::RainChecks => "rain_checks",
+        }
+    }
+
+    fn retention_days(self, retention: &RetentionConfig) -> u32 {
+        match self {
+            Pruned::SoilObservations => retention.soil_observation_days,
+            Pruned::Events => retention.event_days,
+            Pruned::RainChecks => retention.rain_check_days,
+        }
+    }
+
+    async fn prune(self, pool: &PgPool, before: DateTime<Utc>) -> Result<u64> {
+        match self {
+            Pruned::SoilObservations => {
+                maintenance_queries::prune_soil_observations(pool, before).await
+            }
+            Pruned::Events => maintenance_queries::prune_events(pool, before).await,
+            Pruned::RainChecks => maintenance_queries::prune_rain_checks(pool, before).await,
+        }
+    }
+}
+
+/// What one table lost in a run.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TablePrune {
+    pub table: &'static str,
+    pub deleted: u64,
+    pub bytes_before: i64,
+    pub bytes_after: i64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct RetentionReport {
+    pub tables: Vec<TablePrune>,
+}
+
+impl RetentionReport {
+    pub fn deleted(&self) -> u64 {
+        self.tables.iter().map(|t| t.deleted).sum()
+    }
+
+    /// Bytes the vacuum gave back. Plain VACUUM mostly marks space for reuse
+    /// rather than shrinking files, so this is often zero.
+    pub fn reclaimed_bytes(&self) -> i64 {
+        self.tables
+            .iter()
+            .map(|t| (t.bytes_before - t.bytes_after).max(0))
+            .sum()
+    }
+
+    pub fn summary(&self) -> String {
+        let per_table: Vec<_> = self
+            .tables
+            .iter()
+            .filter(|t| t.deleted > 0)
+            .map(|t| format!("{} {}", t.table, t.deleted))
+            .collect();
+        format!(
+            "Pruned {} rows ({}); reclaimed {} KB",
+            self.deleted(),
+            per_table.join(", "),
+            self.reclaimed_bytes() / 1024
+        )
+    }
+}
+
+/// Rows older than this are past retention; `None` keeps them forever.
+pub fn cutoff(now: DateTime<Utc>, days: u32) -> Option<DateTime<Utc>> {
+    (days > 0).then(|| now - Duration::days(days as i64))
+}
+
+/// Prune every table past its retention and vacuum the ones that lost rows.
+pub async fn run_retention(
+    pool: &PgPool,
+    retention: &RetentionConfig,
+    now: DateTime<Utc>,
+) -> Result<RetentionReport> {
+    let mut report = RetentionReport::default();
+
+    for target in Pruned::ALL {
+        let Some(before) = cutoff(now, target.retention_days(retention)) else {
+            continue;
+        };
+        let table = target.table();
+        let bytes_before = maintenance_queries::table_size_bytes(pool, table).await?;
+        let deleted = target.prune(pool, before).await?;
+        if deleted > 0 {
+            maintenance_queries::vacuum_analyze(pool, table).await?;
+        }
+        let bytes_after = maintenance_queries::table_size_bytes(pool, table).await?;
+        report.tables.push(TablePrune {
+            table,
+            deleted,
+            bytes_before,
+            bytes_after,
+        });
+    }
+
+    Ok(report)
+}
+
+/// Run [`run_retention`] every `interval_hours`, logging each run that deleted
+/// anything. Failures are logged and retried on the next tick.
+pub fn spawn_retention_task(pool: PgPool, retention: RetentionConfig) {
+    tokio::spawn(async move {
+        let period = std::time::Duration::from_secs(retention.interval_hours * 3600);
+        let start =
+            tokio::time::Instant::now() + std::time::Duration::from_secs(FIRST_RUN_DELAY_SECS);
+        let mut ticker = tokio::time::interval_at(start, period);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            match run_retention(&pool, &retention, Utc::now()).await {
+                Ok(report) if report.deleted() > 0 => {
+                    tracing::info!(
+                        deleted = report.deleted(),
+                        reclaimed_bytes = report.reclaimed_bytes(),
+                        "Retention pruned old rows"
+                    );
+                    let details = serde_json::to_string(&report).unwrap_or_default();
+                    event_queries::record_event(
+                        &pool,
+                        Event::new(EventKind::Maintenance, EventLevel::Info, report.summary())
+                            .with_details(details),
+                    )
+                    .await;
+                }
+                Ok(_) => tracing::debug!("Retention found nothing to prune"),
+                Err(e) => tracing::warn!("Retention run failed: {}", e),
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::at;
+
+    #[test]
+    fn zero_days_keeps_forever() {
+        let now = at(2026, 7, 10);
+        assert_eq!(cutoff(now, 0), None);
+        assert_eq!(cutoff(now, 90), Some(now - Duration::days(90)));
+    }
+
+    #[test]
+    fn summary_counts_rows_and_reclaimed_space() {
+        let report = RetentionReport {
+            tables: vec![
+                TablePrune {
+                    table: "soil_observations",
+                    deleted: 1200,
+                    bytes_before: 4 * 1024 * 1024,
+                    bytes_after: 3 * 1024 * 1024,
+                },
+                TablePrune {
+                    table: "events",
+                    deleted: 0,
+                    bytes_before: 8192,
+                    bytes_after: 16384,
+                },
+            ],
+        };
+        assert_eq!(report.deleted(), 1200);
+        assert_eq!(report.reclaimed_bytes(), 1024 * 1024);
+        assert_eq!(
+            report.summary(),
+            "Pruned 1200 rows (soil_observations 1200); reclaimed 1024 KB"
+        );
+    }
+}
@@ -1,10 +1,10 @@
 use super::thresholds::*;
-use super::Rule;
+use super::{covered_soil_temp_avg, current_date, Rule};
 use crate::models::{
     Application, ApplicationType, DataSource, EnvironmentalSummary, LawnProfile, Recommendation,
     RecommendationCategory, Severity, SoilType,
 };
-use chrono::{Datelike, Local, NaiveDate};
+use chrono::{Datelike, NaiveDate};
 
 /// Core aeration timing rule
 ///
@@ -28,7 +28,7 @@ impl Rule for AerationRule {
             return None;
         }
 
-        let today = Local::now().date_naive();
+        let today = current_date();
         let current_year = today.year();
 
         // Window: August 15 - October 15
@@ -50,7 +50,7 @@ impl Rule for AerationRule {
         }
 
         // Check soil temperature
-        let soil_temp_avg = env.soil_temp_7day_avg_f?;
+        let soil_temp_avg = covered_soil_temp_avg(env).ok()?;
         if !(AERATION_SOIL_LOW_F..=AERATION_SOIL_HIGH_F).contains(&soil_temp_avg) {
             return None;
         }
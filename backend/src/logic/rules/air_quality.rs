@@ -0,0 +1,118 @@
+use super::{Gate, Rule};
+use crate::models::{
+    AirQualityLevel, Application, DataSource, EnvironmentalSummary, LawnProfile, Recommendation,
+    RecommendationCategory, Severity,
+};
+
+/// Air quality rule - hold off on dusty lawn work on bad air days
+///
+/// Mowing, dethatching and aerating throw dust, clippings and debris into the
+/// air and mean hours of exertion outdoors. On a Poor or Very Poor air quality
+/// day (OWM index 4-5, usually fine particles or ozone) that work can wait.
+///
+/// Severity: Info. Nothing happens to the turf; it's advice for the person.
+pub struct AirQualityRule;
+
+/// Index at which dusty lawn work should wait.
+const DELAY_WORK_AT: AirQualityLevel = AirQualityLevel::Poor;
+
+impl Rule for AirQualityRule {
+    fn evaluate(
+        &self,
+        env: &EnvironmentalSummary,
+        profile: &LawnProfile,
+        history: &[Application],
+    ) -> Option<Recommendation> {
+        self.explain(env, profile, history).ok()
+    }
+
+    fn explain(
+        &self,
+        env: &EnvironmentalSummary,
+        _profile: &LawnProfile,
+        _history: &[Application],
+    ) -> Result<Recommendation, Gate> {
+        let air = env
+            .air_quality
+            .as_ref()
+            .ok_or_else(|| Gate::MissingData("Air quality".into()))?;
+
+        if air.level < DELAY_WORK_AT {
+            return Err(Gate::ConditionsNotMet(format!(
+                "Air quality is {}",
+                air.level.label()
+            )));
+        }
+
+        let mut rec = Recommendation::new(
+            "air_quality",
+            RecommendationCategory::Mowing,
+            Severity::Info,
+            format!("{} Air Quality", air.level.label()),
+            format!(
+                "Air quality is {} (index {} of 5). Consider putting off mowing, \
+                 dethatching and other dusty work until it improves.",
+                air.level.label().to_lowercase(),
+                air.level.index()
+            ),
+        )
+        .with_explanation(
+            "Mowing and especially dethatching raise dust and fine debris, and the work \
+             itself means hours of heavy breathing outdoors. On days the air quality index \
+             reads Poor or worse, usually from fine particles or ozone, that exposure adds \
+             up. The lawn won't suffer from waiting a day or two.",
+        )
+        .with_data_point(
+            "Air Quality",
+            format!("{} ({}/5)", air.level.label(), air.level.index()),
+            DataSource::OpenWeatherMap.as_str(),
+        );
+        if let Some(pm2_5) = air.pm2_5 {
+            rec = rec.with_data_point(
+                "PM2.5",
+                format!("{:.0} µg/m³", pm2_5),
+                DataSource::OpenWeatherMap.as_str(),
+            );
+        }
+        if let Some(o3) = air.o3 {
+            rec = rec.with_data_point(
+                "Ozone",
+                format!("{:.0} µg/m³", o3),
+                DataSource::OpenWeatherMap.as_str(),
+            );
+        }
+
+        Ok(rec.with_action(
+            "Mow, dethatch or aerate on a better air day. If it can't wait, go early in \
+             the morning, when ozone is lowest, and wear a dust mask.",
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::GrassType;
+    use crate::testing::{at, profile, EnvBuilder};
+
+    #[test]
+    fn advises_only_on_poor_air() {
+        let rule = AirQualityRule;
+        let lawn = profile(GrassType::TallFescue);
+        let env = |level| EnvBuilder::new(at(2025, 7, 20)).air_quality(level).build();
+
+        assert!(matches!(
+            rule.explain(&EnvBuilder::new(at(2025, 7, 20)).build(), &lawn, &[]),
+            Err(Gate::MissingData(_))
+        ));
+        assert!(matches!(
+            rule.explain(&env(AirQualityLevel::Moderate), &lawn, &[]),
+            Err(Gate::ConditionsNotMet(_))
+        ));
+        let rec = rule
+            .explain(&env(AirQualityLevel::VeryPoor), &lawn, &[])
+            .unwrap();
+        assert_eq!(rec.severity, Severity::Info);
+        assert_eq!(rec.title, "Very Poor Air Quality");
+    }
+}
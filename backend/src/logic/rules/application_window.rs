@@ -1,10 +1,10 @@
 use super::thresholds::*;
 use super::Rule;
 use crate::models::{
-    Application, DataSource, EnvironmentalSummary, LawnProfile, Recommendation,
-    RecommendationCategory, Severity,
+    Application, DataSource, EnvironmentalSummary, ForecastPoint, LawnProfile, Recommendation,
+    RecommendationCategory, Severity, WeatherCondition, WeatherForecast,
 };
-use chrono::{Datelike, NaiveDate};
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, Timelike, Utc};
 
 /// Application window rule - identifies optimal windows for chemical applications
 ///
@@ -13,8 +13,16 @@ use chrono::{Datelike, NaiveDate};
 /// - Temperature 50-80°F
 /// - Wind < 10mph
 /// - Humidity < 85%
+/// - No thunderstorms (≥30% chance rules the day out)
 ///
-/// This rule provides advisory-level recommendations when good windows exist.
+/// Daily wind is an average, so the chosen day's forecast points are also
+/// searched for morning spray windows: hours with sustained wind < 10mph and
+/// no gusts > 15mph, reported with start and end times for liquid herbicide
+/// and fungicide work.
+///
+/// Severity levels:
+/// - Info: a good window exists
+/// - Advisory: the best window has a 10-30% thunderstorm chance, so watch for lightning
 pub struct ApplicationWindowRule;
 
 impl Rule for ApplicationWindowRule {
@@ -63,6 +71,12 @@ struct WindowQuality {
     humidity_ok: bool,
     no_rain_before: bool,
     no_rain_after: bool,
+    /// Highest chance of a thunderstorm point on the day (1.0 when storms dominate).
+    storm_prob: f64,
+    /// First thunderstorm point on the day.
+    storm_start: Option<DateTime<Utc>>,
+    /// Low-drift morning stretches, earliest first.
+    spray_windows: Vec<SprayWindow>,
     temp: f64,
     wind: f64,
     humidity: f64,
@@ -70,8 +84,15 @@ struct WindowQuality {
 
 impl WindowQuality {
     fn is_good(&self) -> bool {
-        // Must have no rain and acceptable temp
-        self.no_rain_before && self.no_rain_after && self.temp_ok
+        // Must have no rain, no real storm chance and acceptable temp
+        self.no_rain_before
+            && self.no_rain_after
+            && self.temp_ok
+            && self.storm_prob < APP_WINDOW_STORM_EXCLUDE_PROB
+    }
+
+    fn storm_possible(&self) -> bool {
+        self.storm_prob >= APP_WINDOW_STORM_ADVISORY_PROB
     }
 
     fn score(&self) -> u32 {
@@ -91,6 +112,12 @@ impl WindowQuality {
         if self.no_rain_after {
             score += 15;
         }
+        if !self.storm_possible() {
+            score += 5;
+        }
+        if !self.spray_windows.is_empty() {
+            score += 3;
+        }
         // Bonus for ideal temp range
         if self.temp >= APP_WINDOW_IDEAL_LOW_F && self.temp <= APP_WINDOW_IDEAL_HIGH_F {
             score += 5;
@@ -127,6 +154,69 @@ impl WindowQuality {
     }
 }
 
+/// A run of forecast points calm enough to spray without drift.
+#[derive(Debug, Clone, PartialEq)]
+struct SprayWindow {
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    max_wind_mph: f64,
+}
+
+impl SprayWindow {
+    fn describe(&self) -> String {
+        format!(
+            "{}–{}",
+            self.start.with_timezone(&Local).format("%-I %p"),
+            self.end.with_timezone(&Local).format("%-I %p")
+        )
+    }
+}
+
+fn calm(point: &ForecastPoint) -> bool {
+    point.wind_speed_mph < WIND_APP_WINDOW_MAX_MPH
+        && point.wind_gust_mph.is_none_or(|g| g <= SPRAY_MAX_GUST_MPH)
+}
+
+/// Morning stretches of calm points on local `date`. Each point stands for
+/// the interval up to the next one (3 hours, or 1 with One Call).
+fn spray_windows(forecast: &WeatherForecast, date: NaiveDate) -> Vec<SprayWindow> {
+    let step = match forecast.hourly.as_slice() {
+        [a, b, ..] => b.timestamp - a.timestamp,
+        _ => Duration::hours(3),
+    };
+    let morning: Vec<&ForecastPoint> = forecast
+        .hourly
+        .iter()
+        .filter(|p| {
+            let local = p.timestamp.with_timezone(&Local);
+            local.date_naive() == date
+                && (SPRAY_MORNING_START_HOUR..SPRAY_MORNING_END_HOUR).contains(&local.hour())
+        })
+        .collect();
+
+    let mut windows: Vec<SprayWindow> = Vec::new();
+    let mut open = false;
+    for point in morning {
+        if !calm(point) {
+            open = false;
+            continue;
+        }
+        match windows.last_mut() {
+            Some(window) if open && window.end == point.timestamp => {
+                window.end = point.timestamp + step;
+                window.max_wind_mph = window.max_wind_mph.max(point.wind_speed_mph);
+            }
+            _ => windows.push(SprayWindow {
+                start: point.timestamp,
+                end: point.timestamp + step,
+                max_wind_mph: point.wind_speed_mph,
+            }),
+        }
+        open = true;
+    }
+    windows
+}
+
 impl ApplicationWindowRule {
     fn assess_day_quality(
         &self,
@@ -174,12 +264,30 @@ impl ApplicationWindowRule {
 
         let no_rain_after = current_dry && next_day_dry;
 
+        // Lightning makes the day unsafe to be out with a spreader or sprayer
+        let storms: Vec<_> = forecast
+            .points_on(day.date)
+            .into_iter()
+            .filter(|p| p.weather_condition == WeatherCondition::Thunderstorm)
+            .collect();
+        let storm_prob = if day.dominant_condition == WeatherCondition::Thunderstorm {
+            1.0
+        } else {
+            storms
+                .iter()
+                .map(|p| p.precipitation_prob)
+                .fold(0.0, f64::max)
+        };
+
         WindowQuality {
             temp_ok,
             wind_ok,
             humidity_ok,
             no_rain_before,
             no_rain_after,
+            storm_prob,
+            storm_start: storms.first().map(|p| p.timestamp),
+            spray_windows: spray_windows(forecast, day.date),
             temp: avg_temp,
             wind: day.avg_wind_speed_mph,
             humidity: day.avg_humidity,
@@ -213,10 +321,34 @@ impl ApplicationWindowRule {
             total_good_days
         );
 
-        Recommendation::new(
+        let (severity, description) = match (quality.storm_possible(), quality.storm_start) {
+            (true, Some(start)) => (
+                Severity::Advisory,
+                format!(
+                    "{} Thunderstorms possible ({:.0}%) from around {}.",
+                    description,
+                    quality.storm_prob * 100.0,
+                    start.with_timezone(&Local).format("%-I %p")
+                ),
+            ),
+            _ => (Severity::Info, description),
+        };
+        let description = match quality.spray_windows.first() {
+            Some(window) => format!(
+                "{} Lowest drift risk for spraying: {}.",
+                description,
+                window.describe()
+            ),
+            None => format!(
+                "{} No calm morning hours for spraying; granular products are safer.",
+                description
+            ),
+        };
+
+        let mut rec = Recommendation::new(
             "application_window",
             RecommendationCategory::ApplicationTiming,
-            Severity::Info,
+            severity,
             title,
             description,
         )
@@ -224,7 +356,9 @@ impl ApplicationWindowRule {
             "Optimal conditions for fertilizer, herbicide, and fungicide applications include: \
              dry conditions (no rain 24h before, 48h after), moderate temperatures (50-80°F), \
              low wind (<10mph to prevent drift), and moderate humidity (<85%). \
-             Early morning applications are often best.",
+             Days with a real chance of thunderstorms are skipped: storm runoff carries \
+             product off the lawn, and nobody should be out in the open when lightning \
+             is around. Early morning applications are often best.",
         )
         .with_data_point(
             "Expected Temp",
@@ -240,12 +374,127 @@ impl ApplicationWindowRule {
             "Humidity",
             format!("{:.0}%", quality.humidity),
             DataSource::OpenWeatherMap.as_str(),
-        )
-        .with_action(format!(
+        );
+
+        let mut action = format!(
             "Plan applications for {} if weather holds. \
              Check forecast morning-of to confirm conditions. \
              Apply in early morning for best results.",
             day_name
-        ))
+        );
+        if !quality.spray_windows.is_empty() {
+            let windows: Vec<String> = quality
+                .spray_windows
+                .iter()
+                .map(|w| format!("{} (≤{:.0}mph)", w.describe(), w.max_wind_mph))
+                .collect();
+            rec = rec.with_data_point(
+                "Spray Window",
+                windows.join(", "),
+                DataSource::OpenWeatherMap.as_str(),
+            );
+            action.push_str(" Spray liquid herbicides or fungicides within the spray window.");
+        }
+        if quality.storm_possible() {
+            rec = rec.with_data_point(
+                "Thunderstorm Chance",
+                format!("{:.0}%", quality.storm_prob * 100.0),
+                DataSource::OpenWeatherMap.as_str(),
+            );
+            action.push_str(
+                " Finish before storms build, and stop at the first thunder: \
+                 lightning can strike miles ahead of the rain.",
+            );
+        }
+        rec.with_action(action)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{GrassType, WeatherForecast};
+    use crate::testing::{at, date, profile, EnvBuilder, ForecastBuilder};
+
+    /// Five mild, dry days; `storms` sets a thunderstorm chance on one
+    /// afternoon point of the given day index.
+    fn forecast(storms: &[(usize, f64)]) -> WeatherForecast {
+        let mut builder = ForecastBuilder::starting(date(2026, 5, 4));
+        for _ in 0..5 {
+            builder = builder.day(72.0, 55.0, 60.0, 0.0);
+        }
+        let mut forecast = builder.build();
+        for &(day, prob) in storms {
+            let point = &mut forecast.hourly[day * 8 + 5];
+            point.weather_condition = WeatherCondition::Thunderstorm;
+            point.precipitation_prob = prob;
+            forecast.daily_summary[day].max_precipitation_prob = prob;
+        }
+        forecast
+    }
+
+    fn evaluate(forecast: WeatherForecast) -> Recommendation {
+        let env = EnvBuilder::new(at(2026, 5, 4)).forecast(forecast).build();
+        ApplicationWindowRule
+            .evaluate(&env, &profile(GrassType::TallFescue), &[])
+            .expect("a window")
+    }
+
+    fn at_local(day: NaiveDate, hour: u32) -> DateTime<Utc> {
+        use chrono::TimeZone;
+        Local
+            .from_local_datetime(&day.and_hms_opt(hour, 0, 0).unwrap())
+            .earliest()
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn spray_windows_are_calm_morning_runs() {
+        let day = date(2026, 5, 4);
+        let mut forecast = forecast(&[]);
+        // Hourly local points 4 AM-1 PM: calm until 8, a gusty 8 AM, calm again 9-10
+        forecast.hourly = (4..14)
+            .map(|hour| {
+                let mut point = forecast.hourly[0].clone();
+                point.timestamp = at_local(day, hour);
+                point.wind_speed_mph = if hour >= 11 {
+                    12.0
+                } else {
+                    4.0 + hour as f64 * 0.1
+                };
+                point.wind_gust_mph = (hour == 8).then_some(18.0);
+                point
+            })
+            .collect();
+
+        let windows = spray_windows(&forecast, day);
+        assert_eq!(windows.len(), 2);
+        // 4 AM is before the morning starts
+        assert_eq!(windows[0].start, at_local(day, 5));
+        assert_eq!(windows[0].end, at_local(day, 8));
+        assert_eq!(windows[1].start, at_local(day, 9));
+        assert_eq!(windows[1].end, at_local(day, 11));
+        assert!(spray_windows(&forecast, date(2026, 5, 5)).is_empty());
+    }
+
+    #[test]
+    fn storm_days_are_not_windows() {
+        let rec = evaluate(forecast(&[(0, 0.4), (1, 0.4), (2, 0.4), (3, 0.4)]));
+        assert!(rec.title.ends_with("Friday"), "{}", rec.title);
+        assert!(rec.description.contains("1 good day(s)"));
+        assert_eq!(rec.severity, Severity::Info);
+    }
+
+    #[test]
+    fn slight_storm_chance_on_best_window_is_an_advisory() {
+        let storms: Vec<_> = (0..5).map(|day| (day, 0.2)).collect();
+        let rec = evaluate(forecast(&storms));
+        assert_eq!(rec.severity, Severity::Advisory);
+        assert!(rec.description.contains("Thunderstorms possible (20%)"));
+        assert!(rec
+            .data_points
+            .iter()
+            .any(|d| d.label == "Thunderstorm Chance" && d.value == "20%"));
     }
 }
@@ -1,10 +1,10 @@
 use super::thresholds::*;
-use super::Rule;
+use super::{covered_soil_temp_avg, current_date, Rule};
 use crate::models::{
     Application, ApplicationType, DataSource, EnvironmentalSummary, LawnProfile, Recommendation,
     RecommendationCategory, Severity,
 };
-use chrono::{Datelike, Local, NaiveDate};
+use chrono::{Datelike, NaiveDate};
 
 /// Broadleaf herbicide timing rule
 ///
@@ -28,7 +28,7 @@ impl Rule for BroadleafHerbicideRule {
             return None;
         }
 
-        let today = Local::now().date_naive();
+        let today = current_date();
         let month = today.month();
 
         // Check if overseeded within 60 days — suppress recommendation
@@ -55,7 +55,7 @@ impl Rule for BroadleafHerbicideRule {
                 && app.application_date.month() >= 9
         });
 
-        let soil_temp_avg = env.soil_temp_7day_avg_f?;
+        let soil_temp_avg = covered_soil_temp_avg(env).ok()?;
 
         // GDD data for spring window
         let gdd_ytd = env.gdd_base50_ytd;
@@ -206,6 +206,10 @@ mod tests {
             soil_type: None,
             lawn_size_sqft: Some(5000.0),
             irrigation_type: None,
+            location: None,
+            noaa_station_wbanno: None,
+            dormant: false,
+            mowing_height_in: None,
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
         }
@@ -230,7 +234,7 @@ mod tests {
         let result = rule.evaluate(&env, &base_profile(), &[]);
         // If not March and GDD < 50, spring window should not open.
         // Result may be Some in March or fall, None otherwise.
-        let today = Local::now().date_naive();
+        let today = current_date();
         let month = today.month();
         if month != 3 && !(9..=10).contains(&month) {
             assert!(
@@ -250,7 +254,7 @@ mod tests {
         let result = rule.evaluate(&env, &base_profile(), &[]);
         // If soil temp is in range and trend is rising, should produce recommendation.
         // Date-sensitive: only spring months produce spring recommendation.
-        let today = Local::now().date_naive();
+        let today = current_date();
         let month = today.month();
         if (2..=4).contains(&month) {
             if let Some(rec) = result {
@@ -270,7 +274,7 @@ mod tests {
         env.gdd_base50_ytd = Some(200.0);
         let rule = BroadleafHerbicideRule;
         let result = rule.evaluate(&env, &base_profile(), &[]);
-        let today = Local::now().date_naive();
+        let today = current_date();
         let month = today.month();
         // GDD > 150 means gdd_in_spring_range is false
         // Only month == 3 can open the spring window
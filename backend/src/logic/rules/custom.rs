@@ -0,0 +1,493 @@
+//! Declarative custom rules loaded from a YAML file at startup.
+//!
+//! Power users can add simple threshold rules without writing Rust. Each rule
+//! lists optional conditions (all must hold) plus a message template, e.g.:
+//!
+//! ```yaml
+//! - id: late_spring_feed
+//!   title: Light Spring Feeding
+//!   severity: Advisory
+//!   category: Fertilizer
+//!   message: "Soil is {soil_temp_avg_f}°F and moisture {soil_moisture_pct}."
+//!   action: Apply 0.5 lb N/1000 sqft.
+//!   conditions:
+//!     soil_temp_avg_f: { min: 55, max: 65 }
+//!     soil_moisture: { min: 0.15 }
+//!     date_window: { start: "04-15", end: "05-31" }
+//!     not_applied_within: { application_type: Fertilizer, days: 30 }
+//! ```
+//!
+//! JSON is valid YAML, so existing JSON rule files keep loading.
+//!
+//! Placeholders: `{soil_temp_avg_f}`, `{soil_temp_f}`, `{soil_moisture_pct}`,
+//! `{humidity_percent}`, `{ambient_temp_f}`, `{gdd_ytd}`. Missing values render as `n/a`.
+
+use super::{covered_soil_temp_avg, current_date, Gate, Rule};
+use crate::error::{Result, TurfOpsError};
+use crate::models::{
+    Application, ApplicationType, DataSource, EnvironmentalSummary, LawnProfile, Recommendation,
+    RecommendationCategory, Severity, VolumetricWaterContent,
+};
+use chrono::{Datelike, NaiveDate};
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::str::FromStr;
+
+/// Inclusive numeric bound; either end may be omitted.
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+pub struct Bound {
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+}
+
+impl Bound {
+    fn contains(&self, value: f64) -> bool {
+        self.min.is_none_or(|m| value >= m) && self.max.is_none_or(|m| value <= m)
+    }
+
+    fn describe(&self) -> String {
+        match (self.min, self.max) {
+            (Some(lo), Some(hi)) => format!("{}-{}", lo, hi),
+            (Some(lo), None) => format!(">= {}", lo),
+            (None, Some(hi)) => format!("<= {}", hi),
+            (None, None) => "any".into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DateWindowDef {
+    /// "MM-DD"
+    pub start: String,
+    /// "MM-DD"; may be earlier than `start` to wrap across the new year
+    pub end: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct HistoryConditionDef {
+    pub application_type: String,
+    pub days: i64,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ConditionsDef {
+    pub soil_temp_avg_f: Option<Bound>,
+    pub soil_temp_f: Option<Bound>,
+    pub soil_moisture: Option<Bound>,
+    pub humidity_percent: Option<Bound>,
+    pub ambient_temp_f: Option<Bound>,
+    pub gdd_ytd: Option<Bound>,
+    pub date_window: Option<DateWindowDef>,
+    pub not_applied_within: Option<HistoryConditionDef>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CustomRuleDef {
+    pub id: String,
+    pub title: String,
+    pub severity: Severity,
+    #[serde(default = "default_category")]
+    pub category: RecommendationCategory,
+    pub message: String,
+    pub action: Option<String>,
+    #[serde(default)]
+    pub conditions: ConditionsDef,
+}
+
+fn default_category() -> RecommendationCategory {
+    RecommendationCategory::General
+}
+
+/// A validated custom rule, ready to run alongside the built-ins.
+#[derive(Debug, Clone)]
+pub struct CustomRule {
+    def: CustomRuleDef,
+    name: String,
+    date_window: Option<((u32, u32), (u32, u32))>,
+    not_applied_within: Option<(ApplicationType, i64)>,
+}
+
+fn parse_month_day(rule_id: &str, s: &str) -> Result<(u32, u32)> {
+    let invalid = || {
+        TurfOpsError::Config(format!(
+            "Custom rule '{}': invalid date '{}', expected MM-DD",
+            rule_id, s
+        ))
+    };
+    let (m, d) = s.split_once('-').ok_or_else(invalid)?;
+    let month: u32 = m.parse().map_err(|_| invalid())?;
+    let day: u32 = d.parse().map_err(|_| invalid())?;
+    // Validate against a leap year so 02-29 is accepted
+    NaiveDate::from_ymd_opt(2024, month, day).ok_or_else(invalid)?;
+    Ok((month, day))
+}
+
+impl CustomRule {
+    pub fn compile(def: CustomRuleDef) -> Result<Self> {
+        if def.id.trim().is_empty() {
+            return Err(TurfOpsError::Config("Custom rule with empty id".into()));
+        }
+
+        let date_window = def
+            .conditions
+            .date_window
+            .as_ref()
+            .map(|w| {
+                Ok::<_, TurfOpsError>((
+                    parse_month_day(&def.id, &w.start)?,
+                    parse_month_day(&def.id, &w.end)?,
+                ))
+            })
+            .transpose()?;
+
+        let not_applied_within = def
+            .conditions
+            .not_applied_within
+            .as_ref()
+            .map(|h| {
+                ApplicationType::from_str(&h.application_type)
+                    .map(|t| (t, h.days))
+                    .map_err(|e| TurfOpsError::Config(format!("Custom rule '{}': {}", def.id, e)))
+            })
+            .transpose()?;
+
+        Ok(Self {
+            name: format!("Custom: {}", def.id),
+            def,
+            date_window,
+            not_applied_within,
+        })
+    }
+
+    /// Evaluate against an explicit date (the `Rule` impl passes today).
+    fn check(
+        &self,
+        env: &EnvironmentalSummary,
+        history: &[Application],
+        today: NaiveDate,
+    ) -> std::result::Result<Recommendation, Gate> {
+        let c = &self.def.conditions;
+
+        if let Some((start, end)) = self.date_window {
+            let md = (today.month(), today.day());
+            let inside = if start <= end {
+                md >= start && md <= end
+            } else {
+                md >= start || md <= end
+            };
+            if !inside {
+                return Err(Gate::OutOfSeason(format!(
+                    "Window {:02}-{:02} to {:02}-{:02}",
+                    start.0, start.1, end.0, end.1
+                )));
+            }
+        }
+
+        if let Some((app_type, days)) = self.not_applied_within {
+            let recent = history.iter().any(|a| {
+                a.application_type == app_type
+                    && a.application_date <= today
+                    && (today - a.application_date).num_days() < days
+            });
+            if recent {
+                return Err(Gate::AlreadyApplied(format!(
+                    "{} applied within the last {} days",
+                    app_type, days
+                )));
+            }
+        }
+
+        // A thinly sampled 7-day average isn't trusted for a threshold.
+        if c.soil_temp_avg_f.is_some() {
+            covered_soil_temp_avg(env)?;
+        }
+
+        let current = env.current.as_ref();
+        let checks: [(&str, Option<Bound>, Option<f64>); 6] = [
+            (
+                "7-day soil temp",
+                c.soil_temp_avg_f,
+                env.soil_temp_7day_avg_f,
+            ),
+            (
+                "Soil temp (10cm)",
+                c.soil_temp_f,
+                current.and_then(|r| r.soil_temp_10_f),
+            ),
+            (
+                "Soil moisture",
+                c.soil_moisture,
+                current.and_then(|r| r.primary_soil_moisture()),
+            ),
+            (
+                "Humidity",
+                c.humidity_percent,
+                current.and_then(|r| r.humidity_percent),
+            ),
+            (
+                "Ambient temp",
+                c.ambient_temp_f,
+                current.and_then(|r| r.ambient_temp_f),
+            ),
+            ("GDD YTD", c.gdd_ytd, env.gdd_base50_ytd),
+        ];
+
+        for (label, bound, value) in checks {
+            let Some(bound) = bound else { continue };
+            let value = value.ok_or_else(|| Gate::MissingData(label.to_string()))?;
+            if !bound.contains(value) {
+                return Err(Gate::ConditionsNotMet(format!(
+                    "{} {:.2} outside {}",
+                    label,
+                    value,
+                    bound.describe()
+                )));
+            }
+        }
+
+        let mut rec = Recommendation::new(
+            format!("custom_{}_{}", self.def.id, today.year()),
+            self.def.category,
+            self.def.severity,
+            &self.def.title,
+            render_template(&self.def.message, env),
+        )
+        .with_explanation(format!(
+            "User-defined custom rule '{}'. All configured conditions are met.",
+            self.def.id
+        ));
+
+        for (label, bound, value) in checks {
+            if let (Some(_), Some(v)) = (bound, value) {
+                let source = match label {
+                    "Humidity" | "Ambient temp" => DataSource::HomeAssistant,
+                    "GDD YTD" => DataSource::Calculated,
+                    _ => DataSource::SoilData,
+                };
+                rec = rec.with_data_point(label, format!("{:.2}", v), source.as_str());
+            }
+        }
+
+        if let Some(ref action) = self.def.action {
+            rec = rec.with_action(render_template(action, env));
+        }
+
+        Ok(rec)
+    }
+}
+
+fn fmt_opt(value: Option<f64>, decimals: usize) -> String {
+    value
+        .map(|v| format!("{:.*}", decimals, v))
+        .unwrap_or_else(|| "n/a".into())
+}
+
+fn render_template(template: &str, env: &EnvironmentalSummary) -> String {
+    let current = env.current.as_ref();
+    template
+        .replace("{soil_temp_avg_f}", &fmt_opt(env.soil_temp_7day_avg_f, 1))
+        .replace(
+            "{soil_temp_f}",
+            &fmt_opt(current.and_then(|r| r.soil_temp_10_f), 1),
+        )
+        .replace(
+            "{soil_moisture_pct}",
+            &current
+                .and_then(|r| r.primary_soil_moisture())
+                .map(|m| format!("{:.0}", VolumetricWaterContent(m)))
+                .unwrap_or_else(|| "n/a".into()),
+        )
+        .replace(
+            "{humidity_percent}",
+            &fmt_opt(current.and_then(|r| r.humidity_percent), 0),
+        )
+        .replace(
+            "{ambient_temp_f}",
+            &fmt_opt(current.and_then(|r| r.ambient_temp_f), 1),
+        )
+        .replace("{gdd_ytd}", &fmt_opt(env.gdd_base50_ytd, 0))
+}
+
+impl Rule for CustomRule {
+    fn evaluate(
+        &self,
+        env: &EnvironmentalSummary,
+        profile: &LawnProfile,
+        history: &[Application],
+    ) -> Option<Recommendation> {
+        self.explain(env, profile, history).ok()
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn explain(
+        &self,
+        env: &EnvironmentalSummary,
+        _profile: &LawnProfile,
+        history: &[Application],
+    ) -> std::result::Result<Recommendation, Gate> {
+        self.check(env, history, current_date())
+    }
+}
+
+/// Parse and validate a YAML (or JSON) list of custom rule definitions.
+pub fn parse_custom_rules(source: &str) -> Result<Vec<CustomRule>> {
+    let defs: Vec<CustomRuleDef> =
+        serde_yaml_ng::from_str(source).map_err(|e| TurfOpsError::Config(e.to_string()))?;
+    // The id keys the recommendation, its hysteresis latch and RULES_DISABLED
+    let mut ids = HashSet::new();
+    if let Some(def) = defs.iter().find(|d| !ids.insert(d.id.as_str())) {
+        return Err(TurfOpsError::Config(format!(
+            "Custom rule id '{}' is used more than once",
+            def.id
+        )));
+    }
+    defs.into_iter().map(CustomRule::compile).collect()
+}
+
+/// Load custom rules from a YAML or JSON file. Fails on unreadable or invalid files so a
+/// typo doesn't silently drop a rule the user is relying on.
+pub fn load_custom_rules(path: &str) -> Result<Vec<CustomRule>> {
+    let contents = std::fs::read_to_string(path).map_err(|e| {
+        TurfOpsError::Config(format!("Cannot read custom rules file {}: {}", path, e))
+    })?;
+    let rules = parse_custom_rules(&contents)
+        .map_err(|e| TurfOpsError::Config(format!("Invalid custom rules in {}: {}", path, e)))?;
+    tracing::info!(count = rules.len(), path = %path, "Custom rules loaded");
+    Ok(rules)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::EnvironmentalReading;
+
+    const SAMPLE: &str = r#"
+- id: late_spring_feed
+  title: Light Spring Feeding
+  severity: Advisory
+  category: Fertilizer
+  message: "Soil is {soil_temp_avg_f}°F, moisture {soil_moisture_pct}, GDD {gdd_ytd}."
+  conditions:
+    soil_temp_avg_f: { min: 55, max: 65 }
+    soil_moisture: { min: 0.15 }
+    date_window: { start: "04-15", end: "05-31" }
+    not_applied_within: { application_type: Fertilizer, days: 30 }
+"#;
+
+    fn env(soil_avg: f64, moisture: f64) -> EnvironmentalSummary {
+        let mut reading = EnvironmentalReading::new(DataSource::SoilData);
+        reading.soil_moisture_10 = Some(moisture);
+        EnvironmentalSummary {
+            current: Some(reading),
+            soil_temp_7day_avg_f: Some(soil_avg),
+            ..Default::default()
+        }
+    }
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn fertilizer_on(d: NaiveDate) -> Application {
+        Application {
+            id: Some(1),
+            lawn_profile_id: 1,
+            application_type: ApplicationType::Fertilizer,
+            product_name: None,
+            application_date: d,
+            rate_per_1000sqft: None,
+            coverage_sqft: None,
+            notes: None,
+            weather_snapshot: None,
+            nitrogen_pct: None,
+            phosphorus_pct: None,
+            potassium_pct: None,
+            plant_id: None,
+            follow_up_date: None,
+            area_id: None,
+            equipment_id: None,
+            custom_type_id: None,
+            created_at: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn fires_when_all_conditions_hold() {
+        let rule = parse_custom_rules(SAMPLE).unwrap().remove(0);
+        let rec = rule.check(&env(60.0, 0.22), &[], date(2026, 5, 1)).unwrap();
+        assert_eq!(rec.id, "custom_late_spring_feed_2026");
+        assert_eq!(rec.category, RecommendationCategory::Fertilizer);
+        assert_eq!(rec.description, "Soil is 60.0°F, moisture 22%, GDD n/a.");
+        assert_eq!(rule.name(), "Custom: late_spring_feed");
+    }
+
+    #[test]
+    fn gates_report_reason() {
+        let rule = parse_custom_rules(SAMPLE).unwrap().remove(0);
+        assert!(matches!(
+            rule.check(&env(60.0, 0.22), &[], date(2026, 7, 1)),
+            Err(Gate::OutOfSeason(_))
+        ));
+        assert!(matches!(
+            rule.check(&env(70.0, 0.22), &[], date(2026, 5, 1)),
+            Err(Gate::ConditionsNotMet(_))
+        ));
+        assert!(matches!(
+            rule.check(&EnvironmentalSummary::default(), &[], date(2026, 5, 1)),
+            Err(Gate::MissingData(_))
+        ));
+        // Station down most of the week: the average isn't trusted
+        let mut sparse = env(60.0, 0.22);
+        sparse.coverage.soil_temp = Some(0.3);
+        assert!(matches!(
+            rule.check(&sparse, &[], date(2026, 5, 1)),
+            Err(Gate::MissingData(reason)) if reason.contains("30%")
+        ));
+        let history = [fertilizer_on(date(2026, 4, 20))];
+        assert!(matches!(
+            rule.check(&env(60.0, 0.22), &history, date(2026, 5, 1)),
+            Err(Gate::AlreadyApplied(_))
+        ));
+        // Older application no longer blocks, nor does a planned one
+        for planned in [date(2026, 3, 1), date(2026, 5, 10)] {
+            let history = [fertilizer_on(planned)];
+            assert!(rule
+                .check(&env(60.0, 0.22), &history, date(2026, 5, 1))
+                .is_ok());
+        }
+    }
+
+    #[test]
+    fn date_window_wraps_year_end() {
+        // JSON rule files still load: JSON is a subset of YAML
+        let json = r#"[{"id": "winter", "title": "Winter", "severity": "Info",
+            "message": "m", "conditions": {"date_window": {"start": "11-15", "end": "02-15"}}}]"#;
+        let rule = parse_custom_rules(json).unwrap().remove(0);
+        let e = EnvironmentalSummary::default();
+        assert!(rule.check(&e, &[], date(2026, 12, 25)).is_ok());
+        assert!(rule.check(&e, &[], date(2026, 1, 10)).is_ok());
+        assert!(rule.check(&e, &[], date(2026, 6, 1)).is_err());
+    }
+
+    #[test]
+    fn invalid_definitions_are_rejected() {
+        let bad_date = r#"[{"id": "x", "title": "t", "severity": "Info", "message": "m",
+            "conditions": {"date_window": {"start": "13-01", "end": "02-01"}}}]"#;
+        assert!(parse_custom_rules(bad_date).is_err());
+
+        let bad_type = r#"[{"id": "x", "title": "t", "severity": "Info", "message": "m",
+            "conditions": {"not_applied_within": {"application_type": "Nope", "days": 3}}}]"#;
+        assert!(parse_custom_rules(bad_type).is_err());
+
+        let bad_severity = r#"[{"id": "x", "title": "t", "severity": "Huge", "message": "m"}]"#;
+        assert!(parse_custom_rules(bad_severity).is_err());
+
+        let duplicate = r#"[{"id": "x", "title": "t", "severity": "Info", "message": "m"},
+            {"id": "x", "title": "u", "severity": "Advisory", "message": "n"}]"#;
+        let err = parse_custom_rules(duplicate).unwrap_err();
+        assert!(err.to_string().contains("'x' is used more than once"));
+    }
+}
@@ -1,7 +1,7 @@
+use super::current_date;
 use crate::models::{
     Application, ApplicationType, DataSource, FracClass, FungicideRotationAdvice, Recommendation,
 };
-use chrono::Local;
 
 /// Build FRAC rotation guidance text from rotation analysis.
 pub fn build_rotation_guidance(advice: &FungicideRotationAdvice) -> String {
@@ -68,7 +68,7 @@ pub fn gray_leaf_spot_fungicide_rec(advice: &FungicideRotationAdvice) -> String
 
 /// Check if lawn is nitrogen-deficient (no fertilizer in the given number of days).
 pub fn is_nitrogen_deficient(history: &[Application], days: i64) -> bool {
-    let cutoff = Local::now().date_naive() - chrono::Duration::days(days);
+    let cutoff = current_date() - chrono::Duration::days(days);
     !history.iter().any(|app| {
         app.application_type == ApplicationType::Fertilizer && app.application_date >= cutoff
     })
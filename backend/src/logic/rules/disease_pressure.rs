@@ -1,12 +1,16 @@
 use super::disease_common::{
     add_frac_data_points, append_rotation_warning, build_rotation_guidance, is_nitrogen_deficient,
 };
+use super::posture::PostureInput;
 use super::thresholds::*;
 use super::Rule;
+use crate::clock;
+use crate::logic::calculations;
 use crate::models::{
     analyze_fungicide_rotation, Application, DataSource, EnvironmentalSummary, LawnProfile,
     Recommendation, RecommendationCategory, Severity,
 };
+use chrono::Local;
 
 /// Disease pressure forecast rule - predicts elevated fungal disease risk
 ///
@@ -20,6 +24,8 @@ use crate::models::{
 /// - Dollar spot activates at night temps >50°F with 10-12 hrs leaf wetness
 /// - Dollar spot is amplified by nitrogen deficiency (no N in 30-45 days)
 /// - Pythium follows thunderstorm activity
+/// - Leaf wetness is estimated from forecast hours with dew point spread < 3°F;
+///   the data points also break it down by night (humidity >90% or spread <2°F)
 ///
 /// Severity levels:
 /// - Advisory: 1-2 days of disease-favorable conditions ahead
@@ -76,6 +82,10 @@ impl Rule for DiseasePressureRule {
             history,
         ))
     }
+
+    fn posture_input(&self) -> Option<PostureInput> {
+        Some(PostureInput::Humidity)
+    }
 }
 
 impl DiseasePressureRule {
@@ -115,9 +125,25 @@ impl DiseasePressureRule {
             }
         }
 
+        // Leaf wetness proxy over the next 24h
+        if let Some(hours) = self.leaf_wetness_hours(env) {
+            if hours >= LEAF_WETNESS_SEVERE_HOURS {
+                risk += 2;
+            } else if hours >= LEAF_WETNESS_DISEASE_HOURS {
+                risk += 1;
+            }
+        }
+
         risk
     }
 
+    fn leaf_wetness_hours(&self, env: &EnvironmentalSummary) -> Option<f64> {
+        let forecast = env.forecast.as_ref()?;
+        Some(calculations::leaf_wetness_hours(
+            &forecast.next_hours(LEAF_WETNESS_WINDOW_HOURS),
+        ))
+    }
+
     fn assess_forecast_risk(&self, env: &EnvironmentalSummary) -> u32 {
         let forecast = match &env.forecast {
             Some(f) => f,
@@ -193,6 +219,11 @@ impl DiseasePressureRule {
             .map(|h| h >= HUMIDITY_HIGH_DISEASE)
             .unwrap_or(false);
 
+        // A2: Dollar spot needs 10-12 hrs of leaf wetness
+        let long_wetness = self
+            .leaf_wetness_hours(env)
+            .is_some_and(|h| h >= LEAF_WETNESS_DISEASE_HOURS);
+
         // A2: Check for dollar spot conditions — night >50°F but <68°F
         let cool_nights = forecast
             .map(|f| {
@@ -205,7 +236,9 @@ impl DiseasePressureRule {
 
         if warm_nights && very_warm_days && current_humid {
             "Brown Patch".to_string()
-        } else if (cool_nights && current_humid) || (warm_nights && !very_warm_days) {
+        } else if (cool_nights && (current_humid || long_wetness))
+            || (warm_nights && !very_warm_days)
+        {
             "Dollar Spot".to_string()
         } else {
             "Fungal Disease".to_string()
@@ -345,6 +378,32 @@ impl DiseasePressureRule {
             );
         }
 
+        if let Some(hours) = self.leaf_wetness_hours(env) {
+            rec = rec.with_data_point(
+                "Leaf Wetness (24h)",
+                format!("{:.0} hrs", hours),
+                DataSource::Calculated.as_str(),
+            );
+        }
+
+        // Likely wet hours for each of the next three nights
+        if let Some(forecast) = &env.forecast {
+            let now = clock::now().with_timezone(&Local);
+            let nights = calculations::overnight_wetness(&forecast.hourly, &now);
+            for (i, night) in nights.iter().enumerate() {
+                let label = if i == 0 {
+                    "Leaf Wetness Tonight".to_string()
+                } else {
+                    format!("Leaf Wetness {} Night", night.evening.format("%a"))
+                };
+                rec = rec.with_data_point(
+                    &label,
+                    format!("{:.0} of {:.0} hrs", night.wet_hours, night.forecast_hours),
+                    DataSource::Calculated.as_str(),
+                );
+            }
+        }
+
         // Add dollar spot N-deficiency data point
         if disease_type == "Dollar Spot" && is_nitrogen_deficient(history, N_DEFICIENCY_DAYS_45) {
             rec = rec.with_data_point(
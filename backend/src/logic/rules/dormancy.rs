@@ -0,0 +1,277 @@
+//! Dormancy: once the lawn has shut down for the winter, or gone brown to ride
+//! out summer heat and drought, watering, feeding and mowing advice is noise.
+//! The engine drops those recommendations and posts one status card instead.
+//!
+//! A lawn counts as dormant when:
+//! - it's marked dormant on its profile (Settings), or
+//! - the 7-day soil temp has fallen below the grass's growth threshold
+//!   (40°F cool-season, 50°F warm-season), or
+//! - cool-season turf sits in soil above 80°F that has dried to near wilting
+//!   point.
+
+use super::covered_soil_temp_avg;
+use super::thresholds::*;
+use crate::models::{
+    DataSource, EnvironmentalSummary, GrassType, LawnProfile, Recommendation,
+    RecommendationCategory, Severity, SoilWaterLimits, VolumetricWaterContent,
+};
+use chrono::{Datelike, NaiveDate};
+
+pub const DORMANCY_STATUS_ID: &str = "lawn_dormant";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DormancySeason {
+    Winter,
+    Summer,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Dormancy {
+    pub season: DormancySeason,
+    /// Set from the profile toggle rather than detected.
+    pub manual: bool,
+    /// 7-day soil temp average behind a detected dormancy.
+    pub soil_temp_f: Option<f64>,
+    /// Soil moisture behind a detected summer dormancy.
+    pub soil_moisture: Option<f64>,
+}
+
+fn is_warm_season(grass_type: GrassType) -> bool {
+    matches!(
+        grass_type,
+        GrassType::Bermuda | GrassType::Zoysia | GrassType::StAugustine
+    )
+}
+
+/// Whether the lawn is dormant as of `today`, and why.
+pub fn detect(
+    env: &EnvironmentalSummary,
+    profile: &LawnProfile,
+    today: NaiveDate,
+) -> Option<Dormancy> {
+    let soil_temp = covered_soil_temp_avg(env).ok();
+    if profile.dormant {
+        // May-September is summer dormancy; anything else is the winter kind
+        let season = if (5..=9).contains(&today.month()) {
+            DormancySeason::Summer
+        } else {
+            DormancySeason::Winter
+        };
+        return Some(Dormancy {
+            season,
+            manual: true,
+            soil_temp_f: soil_temp,
+            soil_moisture: None,
+        });
+    }
+
+    let soil_temp = soil_temp?;
+    let winter_threshold = if is_warm_season(profile.grass_type) {
+        WARM_SEASON_DORMANT_SOIL_F
+    } else {
+        COOL_SEASON_DORMANT_SOIL_F
+    };
+    if soil_temp < winter_threshold {
+        return Some(Dormancy {
+            season: DormancySeason::Winter,
+            manual: false,
+            soil_temp_f: Some(soil_temp),
+            soil_moisture: None,
+        });
+    }
+
+    if is_warm_season(profile.grass_type) || soil_temp < SUMMER_DORMANT_SOIL_F {
+        return None;
+    }
+    let moisture = env
+        .current
+        .as_ref()
+        .and_then(|c| c.primary_soil_moisture())?;
+    let available = SoilWaterLimits::for_soil(profile.soil_type).plant_available(moisture);
+    (available <= SUMMER_DORMANT_AVAILABLE_WATER).then_some(Dormancy {
+        season: DormancySeason::Summer,
+        manual: false,
+        soil_temp_f: Some(soil_temp),
+        soil_moisture: Some(moisture),
+    })
+}
+
+/// Replace irrigation, fertilizer and mowing recommendations with a single
+/// dormancy card while the lawn is dormant. Applied by the engine to every
+/// rule's output, so custom and scripted rules are covered too.
+pub fn suppress_when_dormant(
+    recommendations: Vec<Recommendation>,
+    env: &EnvironmentalSummary,
+    profile: &LawnProfile,
+    today: NaiveDate,
+) -> Vec<Recommendation> {
+    let Some(dormancy) = detect(env, profile, today) else {
+        return recommendations;
+    };
+    let mut kept: Vec<Recommendation> = recommendations
+        .into_iter()
+        .filter(|r| !is_dormancy_unsafe(r))
+        .collect();
+    kept.push(status_card(&dormancy, profile));
+    kept
+}
+
+/// Dormant turf can't use nitrogen, doesn't need mowing, and only needs enough
+/// water to keep the crowns alive.
+pub fn is_dormancy_unsafe(rec: &Recommendation) -> bool {
+    matches!(
+        rec.category,
+        RecommendationCategory::Irrigation
+            | RecommendationCategory::Fertilizer
+            | RecommendationCategory::Mowing
+    )
+}
+
+fn status_card(dormancy: &Dormancy, profile: &LawnProfile) -> Recommendation {
+    let (description, explanation, action) = match dormancy.season {
+        DormancySeason::Winter => (
+            format!(
+                "{} has stopped growing for the winter. Watering, fertilizer and mowing \
+                 recommendations are paused until it greens up.",
+                profile.grass_type
+            ),
+            "Below its growth threshold the grass lives on stored carbohydrates. Nitrogen \
+             applied now washes through unused or feeds snow mold, and mowing only \
+             damages crowns that can't replace leaf tissue.",
+            "Keep traffic off frozen or frosted turf and leave the lawn alone until \
+             spring growth starts.",
+        ),
+        DormancySeason::Summer => (
+            format!(
+                "{} has gone summer-dormant in the heat and dry soil. Watering, fertilizer \
+                 and mowing recommendations are paused until it recovers.",
+                profile.grass_type
+            ),
+            "Dormancy is how cool-season turf survives heat and drought: the leaves brown \
+             but the crowns stay alive for 3-4 weeks. Reviving it with light watering and \
+             then letting it dry again uses up the reserves it needs to recover.",
+            "Either commit to watering it back to green, or give about 1/4 inch every two \
+             to three weeks to keep the crowns alive. Stay off it and don't fertilize \
+             until it greens up.",
+        ),
+    };
+    let title = match dormancy.season {
+        DormancySeason::Winter => "Lawn Is Dormant (Winter)",
+        DormancySeason::Summer => "Lawn Is Dormant (Summer)",
+    };
+
+    let mut rec = Recommendation::new(
+        DORMANCY_STATUS_ID,
+        RecommendationCategory::General,
+        Severity::Info,
+        title,
+        description,
+    )
+    .with_explanation(explanation)
+    .with_action(action);
+
+    if dormancy.manual {
+        rec = rec.with_data_point("Dormancy", "Set on profile", DataSource::Manual.as_str());
+    }
+    if let Some(soil) = dormancy.soil_temp_f {
+        rec = rec.with_data_point(
+            "Soil Temp (7d avg)",
+            format!("{:.0}°F", soil),
+            DataSource::SoilData.as_str(),
+        );
+    }
+    if let Some(m) = dormancy.soil_moisture {
+        rec = rec.with_data_point(
+            "Soil Moisture",
+            format!("{:.0}", VolumetricWaterContent(m)),
+            DataSource::SoilData.as_str(),
+        );
+    }
+    rec
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{at, date, profile, EnvBuilder};
+
+    fn rec(id: &str, category: RecommendationCategory) -> Recommendation {
+        Recommendation::new(id, category, Severity::Advisory, id, "")
+    }
+
+    #[test]
+    fn detects_winter_dormancy_by_grass_season() {
+        let today = date(2026, 1, 10);
+        let env = EnvBuilder::new(at(2026, 1, 10))
+            .soil_temp(44.0, 45.0)
+            .build();
+
+        // 45°F is still above cool-season shutdown but below warm-season
+        assert_eq!(detect(&env, &profile(GrassType::TallFescue), today), None);
+        let bermuda = detect(&env, &profile(GrassType::Bermuda), today).unwrap();
+        assert_eq!(bermuda.season, DormancySeason::Winter);
+        assert!(!bermuda.manual);
+
+        let cold = EnvBuilder::new(at(2026, 1, 10))
+            .soil_temp(35.0, 36.0)
+            .build();
+        assert!(detect(&cold, &profile(GrassType::TallFescue), today).is_some());
+    }
+
+    #[test]
+    fn summer_dormancy_needs_heat_and_dry_soil() {
+        let today = date(2026, 7, 20);
+        let fescue = profile(GrassType::TallFescue);
+        let hot = |moisture| {
+            EnvBuilder::new(at(2026, 7, 20))
+                .soil_temp(84.0, 82.0)
+                .soil_moisture(moisture)
+                .build()
+        };
+
+        let dormant = detect(&hot(0.12), &fescue, today).unwrap();
+        assert_eq!(dormant.season, DormancySeason::Summer);
+        assert_eq!(detect(&hot(0.25), &fescue, today), None);
+        // Warm-season grass thrives in the same heat
+        assert_eq!(detect(&hot(0.12), &profile(GrassType::Zoysia), today), None);
+    }
+
+    #[test]
+    fn manual_toggle_wins_without_sensor_data() {
+        let mut lawn = profile(GrassType::TallFescue);
+        lawn.dormant = true;
+        let env = EnvironmentalSummary::default();
+
+        let summer = detect(&env, &lawn, date(2026, 8, 1)).unwrap();
+        assert!(summer.manual);
+        assert_eq!(summer.season, DormancySeason::Summer);
+        assert_eq!(
+            detect(&env, &lawn, date(2026, 12, 1)).unwrap().season,
+            DormancySeason::Winter
+        );
+    }
+
+    #[test]
+    fn suppression_swaps_care_recommendations_for_one_card() {
+        let mut lawn = profile(GrassType::TallFescue);
+        let env = EnvironmentalSummary::default();
+        let today = date(2026, 12, 1);
+        let recs = || {
+            vec![
+                rec("irrigation_forecast", RecommendationCategory::Irrigation),
+                rec("fertilizer", RecommendationCategory::Fertilizer),
+                rec("mowing_height", RecommendationCategory::Mowing),
+                rec("winter_injury", RecommendationCategory::FrostWarning),
+            ]
+        };
+
+        assert_eq!(suppress_when_dormant(recs(), &env, &lawn, today).len(), 4);
+
+        lawn.dormant = true;
+        let ids: Vec<_> = suppress_when_dormant(recs(), &env, &lawn, today)
+            .into_iter()
+            .map(|r| r.id)
+            .collect();
+        assert_eq!(ids, vec!["winter_injury", DORMANCY_STATUS_ID]);
+    }
+}
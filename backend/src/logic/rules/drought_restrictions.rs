@@ -0,0 +1,124 @@
+use super::{Gate, Rule};
+use crate::models::{
+    Application, DataSource, DroughtCategory, EnvironmentalSummary, LawnProfile, Recommendation,
+    RecommendationCategory, Severity,
+};
+
+/// Drought restrictions rule - official drought status from the U.S. Drought Monitor
+///
+/// Water utilities and state drought plans tie outdoor watering limits to the
+/// Drought Monitor map, typically from Severe Drought (D2) up. When most of the
+/// lawn's county reaches that level, plan for limited irrigation before the
+/// restrictions are announced.
+///
+/// Severity levels:
+/// - Advisory: D2 (Severe Drought)
+/// - Warning: D3-D4 (Extreme / Exceptional Drought)
+pub struct DroughtRestrictionsRule;
+
+/// County status at which watering restrictions become likely.
+const RESTRICTIONS_LIKELY: DroughtCategory = DroughtCategory::D2;
+
+impl Rule for DroughtRestrictionsRule {
+    fn evaluate(
+        &self,
+        env: &EnvironmentalSummary,
+        profile: &LawnProfile,
+        history: &[Application],
+    ) -> Option<Recommendation> {
+        self.explain(env, profile, history).ok()
+    }
+
+    fn explain(
+        &self,
+        env: &EnvironmentalSummary,
+        _profile: &LawnProfile,
+        _history: &[Application],
+    ) -> Result<Recommendation, Gate> {
+        let drought = env
+            .drought
+            .as_ref()
+            .ok_or_else(|| Gate::MissingData("Drought Monitor status".into()))?;
+
+        if drought.category < RESTRICTIONS_LIKELY {
+            return Err(Gate::ConditionsNotMet(format!(
+                "{} is {}",
+                drought.county, drought.category
+            )));
+        }
+
+        let severity = if drought.category >= DroughtCategory::D3 {
+            Severity::Warning
+        } else {
+            Severity::Advisory
+        };
+        let d2_or_worse = drought.coverage.d2;
+
+        Ok(Recommendation::new(
+            "drought_restrictions",
+            RecommendationCategory::Irrigation,
+            severity,
+            format!("Watering Restrictions Likely: {}", drought.category.label()),
+            format!(
+                "The U.S. Drought Monitor map of {} shows {} across most of the county \
+                 ({:.0}% in D2 or worse). Outdoor watering limits commonly start at this level.",
+                drought.county,
+                drought.category.label().to_lowercase(),
+                d2_or_worse
+            ),
+        )
+        .with_explanation(
+            "The Drought Monitor is the weekly federal drought map (NDMC, USDA, NOAA). Many \
+             water utilities and state drought plans key lawn-watering restrictions to it. \
+             Established tall fescue survives 3-4 weeks of summer dormancy; about 1/4-1/2 inch \
+             of water every two weeks keeps the crowns alive if full irrigation is restricted.",
+        )
+        .with_data_point(
+            "Drought Status",
+            drought.category.to_string(),
+            DataSource::DroughtMonitor.as_str(),
+        )
+        .with_data_point(
+            "Area in D2+",
+            format!("{:.0}%", d2_or_worse),
+            DataSource::DroughtMonitor.as_str(),
+        )
+        .with_data_point(
+            "Map Date",
+            drought.map_date.format("%b %-d").to_string(),
+            DataSource::DroughtMonitor.as_str(),
+        )
+        .with_action(
+            "Check your water utility's current restrictions before irrigating. If watering \
+             is limited, water deeply once a week in the early morning, raise the mowing \
+             height, keep traffic off the lawn, and hold fertilizer until rain returns.",
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::GrassType;
+    use crate::testing::{at, profile, EnvBuilder};
+
+    #[test]
+    fn severity_follows_county_category() {
+        let rule = DroughtRestrictionsRule;
+        let lawn = profile(GrassType::TallFescue);
+        let env = |category| EnvBuilder::new(at(2025, 8, 20)).drought(category).build();
+
+        assert!(matches!(
+            rule.explain(&EnvBuilder::new(at(2025, 8, 20)).build(), &lawn, &[]),
+            Err(Gate::MissingData(_))
+        ));
+        assert!(matches!(
+            rule.explain(&env(DroughtCategory::D1), &lawn, &[]),
+            Err(Gate::ConditionsNotMet(_))
+        ));
+        let severe = rule.explain(&env(DroughtCategory::D2), &lawn, &[]).unwrap();
+        assert_eq!(severe.severity, Severity::Advisory);
+        let extreme = rule.explain(&env(DroughtCategory::D3), &lawn, &[]).unwrap();
+        assert_eq!(extreme.severity, Severity::Warning);
+    }
+}
@@ -1,18 +1,85 @@
+use super::hysteresis::HysteresisTracker;
 use super::{
-    aeration::AerationRule, application_window::ApplicationWindowRule,
-    broadleaf_herbicide::BroadleafHerbicideRule, disease_pressure::DiseasePressureRule,
-    fall_fertilization::FallFertilizationRule, fall_overseeding::FallOverseedingRule,
-    fertilizer::FertilizerRule, fungicide::FungicideRule, gray_leaf_spot::GrayLeafSpotRule,
-    grub_control::GrubControlRule, heat_stress::HeatStressRule,
-    irrigation_forecast::IrrigationForecastRule, mowing_height::MowingHeightRule,
-    pre_emergent::PreEmergentRule, pythium_blight::PythiumBlightRule, rain_delay::RainDelayRule,
-    red_thread::RedThreadRule, soil_temp_forecast::SoilTempForecastRule,
-    spring_nitrogen::SpringNitrogenRule, Rule,
+    aeration::AerationRule,
+    air_quality::AirQualityRule,
+    application_window::ApplicationWindowRule,
+    broadleaf_herbicide::BroadleafHerbicideRule,
+    current_date,
+    disease_pressure::DiseasePressureRule,
+    dormancy,
+    drought_restrictions::DroughtRestrictionsRule,
+    fall_fertilization::FallFertilizationRule,
+    fall_nitrogen_cutoff::FallNitrogenCutoffRule,
+    fall_overseeding::FallOverseedingRule,
+    fall_pest_scouting::FallPestScoutingRule,
+    fertilizer::FertilizerRule,
+    fungicide::FungicideRule,
+    germination::{self, GerminationTrackerRule, SeedlingWeatherRule},
+    gray_leaf_spot::GrayLeafSpotRule,
+    grub_control::GrubControlRule,
+    heat_stress::HeatStressRule,
+    irrigation_forecast::IrrigationForecastRule,
+    mowing_height::MowingHeightRule,
+    post_process,
+    posture::RiskPosture,
+    pre_emergent::PreEmergentRule,
+    pythium_blight::PythiumBlightRule,
+    rain_delay::RainDelayRule,
+    red_thread::RedThreadRule,
+    seeding_conflict::SeedingConflictRule,
+    soil_temp_forecast::SoilTempForecastRule,
+    spring_nitrogen::SpringNitrogenRule,
+    tall_grass::TallGrassRule,
+    window_closing::WindowClosingRule,
+    winter_injury::WinterInjuryRule,
+    Gate, Rule, WindowProjection,
 };
-use crate::models::{Application, EnvironmentalSummary, LawnProfile, Recommendation};
+use crate::clock;
+use crate::i18n::Catalog;
+use crate::logic::stress_index;
+use crate::models::{Application, EnvironmentalSummary, LawnProfile, Recommendation, Severity};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Serialize;
+use std::collections::HashSet;
+use std::sync::RwLock;
+
+/// Per-rule outcome for the explain view: what fired, and what stopped the rest.
+#[derive(Debug, Clone, Serialize)]
+pub struct RuleTrace {
+    pub rule: String,
+    pub fired: bool,
+    pub recommendation_id: Option<String>,
+    pub title: Option<String>,
+    pub severity: Option<Severity>,
+    pub gate: Option<Gate>,
+    /// Set when the recommendation fired but was held back: dismissed/addressed
+    /// (by the API layer) or blocked on a new seeding, sustained turf stress or
+    /// dormancy (by the engine).
+    pub suppressed_by: Option<&'static str>,
+}
+
+/// Whether a registered rule is enabled, for the Rules settings tab.
+#[derive(Debug, Clone, Serialize)]
+pub struct RuleStatus {
+    pub rule: String,
+    pub enabled: bool,
+    /// Disabled via `RULES_DISABLED`; can't be re-enabled from the UI.
+    pub locked: bool,
+}
 
 pub struct RulesEngine {
     rules: Vec<Box<dyn Rule>>,
+    /// Latch state for rules that declare moisture hysteresis, keyed by profile
+    /// id and rule name.
+    moisture_latches: HysteresisTracker,
+    /// Rule names disabled in configuration. Fixed for the life of the process.
+    config_disabled: HashSet<String>,
+    /// Rule names disabled from the UI (persisted in the settings table).
+    user_disabled: RwLock<HashSet<String>>,
+    /// Risk posture from the Rules settings tab, applied around every rule.
+    posture: RwLock<RiskPosture>,
+    /// Translates recommendation text for the configured `LOCALE`.
+    catalog: Catalog,
 }
 
 impl RulesEngine {
@@ -29,7 +96,11 @@ impl RulesEngine {
             // Fall rules
             Box::new(FallOverseedingRule),
             Box::new(FallFertilizationRule),
+            Box::new(FallNitrogenCutoffRule),
             Box::new(AerationRule),
+            Box::new(FallPestScoutingRule),
+            // Winter rules
+            Box::new(WinterInjuryRule),
             // Disease rules (year-round)
             Box::new(DiseasePressureRule),
             Box::new(GrayLeafSpotRule),
@@ -38,25 +109,284 @@ impl RulesEngine {
             // Forecast-based rules (year-round)
             Box::new(RainDelayRule),
             Box::new(IrrigationForecastRule),
+            Box::new(DroughtRestrictionsRule),
+            Box::new(AirQualityRule),
             Box::new(HeatStressRule),
             Box::new(ApplicationWindowRule),
             Box::new(MowingHeightRule),
+            Box::new(TallGrassRule),
             // Proactive forecast-based rules
             Box::new(SoilTempForecastRule),
+            // Seeding follow-up rules
+            Box::new(GerminationTrackerRule),
+            Box::new(SeedlingWeatherRule),
+            Box::new(SeedingConflictRule),
+            // Last-week escalation for fixed seasonal windows
+            Box::new(WindowClosingRule),
         ];
 
-        Self { rules }
+        Self {
+            rules,
+            moisture_latches: HysteresisTracker::default(),
+            config_disabled: HashSet::new(),
+            user_disabled: RwLock::new(HashSet::new()),
+            posture: RwLock::new(RiskPosture::default()),
+            catalog: Catalog::english(),
+        }
+    }
+
+    /// Built-in rules plus user-defined rules (declarative or scripted), evaluated
+    /// after the built-ins.
+    pub fn with_custom_rules(custom_rules: Vec<Box<dyn Rule>>) -> Self {
+        let mut engine = Self::new();
+        engine.rules.extend(custom_rules);
+        engine
+    }
+
+    /// Disable rules by name from configuration. Unknown names are logged and ignored.
+    pub fn with_disabled(mut self, names: &[String]) -> Self {
+        self.config_disabled = self.known_names(names);
+        self
+    }
+
+    /// Translate recommendation text with `catalog`.
+    pub fn with_catalog(mut self, catalog: Catalog) -> Self {
+        self.catalog = catalog;
+        self
+    }
+
+    pub fn catalog(&self) -> &Catalog {
+        &self.catalog
+    }
+
+    /// Replace the set of rules disabled from the UI.
+    pub fn set_user_disabled(&self, names: &[String]) {
+        let known = self.known_names(names);
+        *self
+            .user_disabled
+            .write()
+            .unwrap_or_else(|e| e.into_inner()) = known;
+    }
+
+    pub fn set_posture(&self, posture: RiskPosture) {
+        *self.posture.write().unwrap_or_else(|e| e.into_inner()) = posture;
+    }
+
+    pub fn posture(&self) -> RiskPosture {
+        *self.posture.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub fn has_rule(&self, name: &str) -> bool {
+        self.rules.iter().any(|r| r.name() == name)
     }
 
+    pub fn is_enabled(&self, name: &str) -> bool {
+        !self.config_disabled.contains(name)
+            && !self
+                .user_disabled
+                .read()
+                .unwrap_or_else(|e| e.into_inner())
+                .contains(name)
+    }
+
+    /// Every registered rule, in evaluation order, with its enabled state.
+    pub fn rule_statuses(&self) -> Vec<RuleStatus> {
+        self.rules
+            .iter()
+            .map(|r| RuleStatus {
+                rule: r.name().to_string(),
+                enabled: self.is_enabled(r.name()),
+                locked: self.config_disabled.contains(r.name()),
+            })
+            .collect()
+    }
+
+    fn known_names(&self, names: &[String]) -> HashSet<String> {
+        names
+            .iter()
+            .filter(|name| {
+                let known = self.has_rule(name);
+                if !known {
+                    tracing::warn!(rule = %name, "Ignoring unknown rule in disabled list");
+                }
+                known
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Evaluate every rule as of `now` and return the deduplicated,
+    /// conflict-resolved list, most severe first. Live callers pass `Utc::now()`.
     pub fn evaluate(
         &self,
         env: &EnvironmentalSummary,
         profile: &LawnProfile,
         history: &[Application],
+        now: DateTime<Utc>,
     ) -> Vec<Recommendation> {
+        self.evaluate_with_latches(env, profile, history, &self.moisture_latches, now)
+    }
+
+    /// [`evaluate`](Self::evaluate) against caller-owned moisture latches, so a
+    /// backtest can replay past days without disturbing the live latch state.
+    pub fn evaluate_with_latches(
+        &self,
+        env: &EnvironmentalSummary,
+        profile: &LawnProfile,
+        history: &[Application],
+        latches: &HysteresisTracker,
+        now: DateTime<Utc>,
+    ) -> Vec<Recommendation> {
+        clock::at(now, || {
+            let env = &*env.without_stale(now);
+            let latches = self.moisture_latches_on(env, profile, latches, now);
+            let posture = self.posture();
+
+            let recommendations = self
+                .rules
+                .iter()
+                .zip(latches)
+                .filter(|(rule, latch_on)| *latch_on && self.is_enabled(rule.name()))
+                .filter_map(|(rule, _)| {
+                    let input = rule.posture_input();
+                    let env = posture.adjust_env(env, input);
+                    let rec = rule.evaluate(&env, profile, history)?;
+                    Some(posture.adjust(rec, input))
+                })
+                .collect();
+            let recommendations = germination::suppress_for_seedlings(
+                recommendations,
+                profile,
+                history,
+                current_date(),
+            );
+            let recommendations = stress_index::suppress_under_stress(recommendations, env);
+            let mut recommendations = post_process(dormancy::suppress_when_dormant(
+                recommendations,
+                env,
+                profile,
+                current_date(),
+            ));
+            for rec in &mut recommendations {
+                self.catalog.localize(rec);
+            }
+            recommendations
+        })
+    }
+
+    /// Evaluate every rule as of `now` and report whether it fired or which gate
+    /// stopped it.
+    pub fn explain(
+        &self,
+        env: &EnvironmentalSummary,
+        profile: &LawnProfile,
+        history: &[Application],
+        now: DateTime<Utc>,
+    ) -> Vec<RuleTrace> {
+        clock::at(now, || {
+            self.explain_now(&env.without_stale(now), profile, history, now)
+        })
+    }
+
+    fn explain_now(
+        &self,
+        env: &EnvironmentalSummary,
+        profile: &LawnProfile,
+        history: &[Application],
+        now: DateTime<Utc>,
+    ) -> Vec<RuleTrace> {
+        // Explaining is read-only: feed the reading to a copy of the live latches
+        let scratch = self.moisture_latches.snapshot();
+        let latches = self.moisture_latches_on(env, profile, &scratch, now);
+        let seedlings = germination::germination_status(profile, history, current_date()).is_some();
+        let stressed = stress_index::sustained(env).is_some();
+        let dormant = dormancy::detect(env, profile, current_date()).is_some();
+        let posture = self.posture();
+
         self.rules
             .iter()
-            .filter_map(|rule| rule.evaluate(env, profile, history))
+            .zip(latches)
+            .map(|(rule, latch_on)| {
+                let outcome = if !self.is_enabled(rule.name()) {
+                    Err(Gate::Disabled)
+                } else if latch_on {
+                    let input = rule.posture_input();
+                    rule.explain(&posture.adjust_env(env, input), profile, history)
+                        .map(|rec| posture.adjust(rec, input))
+                } else {
+                    Err(Gate::MoistureLatchOff)
+                };
+                match outcome {
+                    Ok(rec) => RuleTrace {
+                        rule: rule.name().to_string(),
+                        fired: true,
+                        suppressed_by: if seedlings && germination::is_seedling_unsafe(&rec) {
+                            Some("seedlings")
+                        } else if stressed && stress_index::is_stress_unsafe(&rec) {
+                            Some("turf stress")
+                        } else if dormant && dormancy::is_dormancy_unsafe(&rec) {
+                            Some("dormancy")
+                        } else {
+                            None
+                        },
+                        severity: Some(rec.severity),
+                        recommendation_id: Some(rec.id),
+                        title: Some(self.catalog.translate(&rec.title)),
+                        gate: None,
+                    },
+                    Err(gate) => RuleTrace {
+                        rule: rule.name().to_string(),
+                        fired: false,
+                        recommendation_id: None,
+                        title: None,
+                        severity: None,
+                        gate: Some(gate),
+                        suppressed_by: None,
+                    },
+                }
+            })
+            .collect()
+    }
+
+    /// Upcoming and currently open seasonal windows from enabled rules, soonest first.
+    pub fn project_windows(
+        &self,
+        env: &EnvironmentalSummary,
+        profile: &LawnProfile,
+        history: &[Application],
+        today: NaiveDate,
+    ) -> Vec<WindowProjection> {
+        let mut windows: Vec<_> = self
+            .rules
+            .iter()
+            .filter(|rule| self.is_enabled(rule.name()))
+            .filter_map(|rule| rule.project_window(env, profile, history, today))
+            .collect();
+        windows.sort_by_key(|w| w.days);
+        windows
+    }
+
+    /// Update moisture latches and return, per rule, whether it may be evaluated.
+    /// Rules without hysteresis (or with no moisture reading) are always on.
+    fn moisture_latches_on(
+        &self,
+        env: &EnvironmentalSummary,
+        profile: &LawnProfile,
+        latches: &HysteresisTracker,
+        now: DateTime<Utc>,
+    ) -> Vec<bool> {
+        let moisture = env.current.as_ref().and_then(|c| c.primary_soil_moisture());
+        // Hold times run on the summary's clock so replayed days age the latches too
+        let now = env.last_updated.unwrap_or(now);
+
+        self.rules
+            .iter()
+            .map(|rule| match (rule.moisture_hysteresis(profile), moisture) {
+                (Some(spec), Some(m)) => {
+                    latches.update((profile.id, rule.name().to_string()), &spec, m, now)
+                }
+                _ => true,
+            })
             .collect()
     }
 }
@@ -66,3 +396,170 @@ impl Default for RulesEngine {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::GrassType;
+    use crate::testing::{at, date, profile, EnvBuilder};
+
+    #[test]
+    fn explain_lists_every_rule() {
+        let engine = RulesEngine::new();
+        let traces = engine.explain(
+            &EnvironmentalSummary::default(),
+            &profile(GrassType::TallFescue),
+            &[],
+            at(2026, 5, 1),
+        );
+        assert_eq!(traces.len(), engine.rules.len());
+        assert!(traces.iter().any(|t| t.rule == "PreEmergentRule"));
+        assert!(traces.iter().all(|t| t.fired == t.gate.is_none()));
+    }
+
+    #[test]
+    fn explain_reports_missing_forecast_for_irrigation() {
+        let engine = RulesEngine::new();
+        let traces = engine.explain(
+            &EnvironmentalSummary::default(),
+            &profile(GrassType::TallFescue),
+            &[],
+            at(2026, 5, 1),
+        );
+        let irrigation = traces
+            .iter()
+            .find(|t| t.rule == "IrrigationForecastRule")
+            .unwrap();
+        assert!(!irrigation.fired);
+        assert!(matches!(irrigation.gate, Some(Gate::MissingData(_))));
+    }
+
+    #[test]
+    fn explain_reports_warm_season_not_applicable() {
+        let engine = RulesEngine::new();
+        let traces = engine.explain(
+            &EnvironmentalSummary::default(),
+            &profile(GrassType::Bermuda),
+            &[],
+            at(2026, 5, 1),
+        );
+        let pre = traces.iter().find(|t| t.rule == "PreEmergentRule").unwrap();
+        assert!(matches!(pre.gate, Some(Gate::NotApplicable(_))));
+    }
+
+    #[test]
+    fn disabled_rules_are_skipped_and_reported() {
+        let engine = RulesEngine::new().with_disabled(&["GrubControlRule".into()]);
+        engine.set_user_disabled(&["RainDelayRule".into(), "NoSuchRule".into()]);
+
+        assert!(!engine.is_enabled("GrubControlRule"));
+        assert!(!engine.is_enabled("RainDelayRule"));
+        assert!(engine.is_enabled("PreEmergentRule"));
+
+        let statuses = engine.rule_statuses();
+        let grub = statuses
+            .iter()
+            .find(|s| s.rule == "GrubControlRule")
+            .unwrap();
+        assert!(!grub.enabled && grub.locked);
+        let rain = statuses.iter().find(|s| s.rule == "RainDelayRule").unwrap();
+        assert!(!rain.enabled && !rain.locked);
+
+        let traces = engine.explain(
+            &EnvironmentalSummary::default(),
+            &profile(GrassType::TallFescue),
+            &[],
+            at(2026, 5, 1),
+        );
+        let grub = traces.iter().find(|t| t.rule == "GrubControlRule").unwrap();
+        assert_eq!(grub.gate, Some(Gate::Disabled));
+
+        engine.set_user_disabled(&[]);
+        assert!(engine.is_enabled("RainDelayRule"));
+    }
+
+    #[test]
+    fn dormant_lawn_gets_a_status_card_instead_of_care_advice() {
+        let engine = RulesEngine::new();
+        let mut lawn = profile(GrassType::TallFescue);
+        lawn.dormant = true;
+        let env = EnvBuilder::new(at(2026, 7, 20))
+            .soil_temp(84.0, 82.0)
+            .soil_moisture(0.10)
+            .air(94.0, 40.0)
+            .build();
+
+        let recs = engine.evaluate(&env, &lawn, &[], at(2026, 7, 20));
+        assert!(recs.iter().any(|r| r.id == dormancy::DORMANCY_STATUS_ID));
+        assert!(!recs.iter().any(dormancy::is_dormancy_unsafe));
+
+        let traces = engine.explain(&env, &lawn, &[], at(2026, 7, 20));
+        assert!(
+            traces
+                .iter()
+                .filter(|t| t.fired && t.suppressed_by == Some("dormancy"))
+                .count()
+                > 0
+        );
+    }
+
+    #[test]
+    fn moisture_latches_are_per_profile() {
+        let engine = RulesEngine::new();
+        let now = at(2026, 7, 20);
+        let irrigation = engine
+            .rules
+            .iter()
+            .position(|r| r.name() == "IrrigationForecastRule")
+            .unwrap();
+        let dry_lawn = profile(GrassType::TallFescue);
+        let mut wet_lawn = profile(GrassType::TallFescue);
+        wet_lawn.id = Some(2);
+        let dry = EnvBuilder::new(now).soil_moisture(0.05).build();
+        let wet = EnvBuilder::new(now).soil_moisture(0.40).build();
+
+        let latches = engine.moisture_latches_on(&dry, &dry_lawn, &engine.moisture_latches, now);
+        assert!(latches[irrigation]);
+        // The dry lawn's latch must not hold the wet lawn's alert on
+        let latches = engine.moisture_latches_on(&wet, &wet_lawn, &engine.moisture_latches, now);
+        assert!(!latches[irrigation]);
+        let latches = engine.moisture_latches_on(&dry, &dry_lawn, &engine.moisture_latches, now);
+        assert!(latches[irrigation]);
+    }
+
+    #[test]
+    fn explain_leaves_live_latches_alone() {
+        let engine = RulesEngine::new();
+        let now = at(2026, 7, 20);
+        let lawn = profile(GrassType::TallFescue);
+        let irrigation = engine
+            .rules
+            .iter()
+            .position(|r| r.name() == "IrrigationForecastRule")
+            .unwrap();
+
+        let dry = EnvBuilder::new(now).soil_moisture(0.05).build();
+        let traces = engine.explain(&dry, &lawn, &[], now);
+        assert!(traces
+            .iter()
+            .all(|t| t.gate != Some(Gate::MoistureLatchOff)));
+
+        // The dry reading seen by explain must not have latched the live alert on
+        let wet = EnvBuilder::new(now).soil_moisture(0.40).build();
+        let latches = engine.moisture_latches_on(&wet, &lawn, &engine.moisture_latches, now);
+        assert!(!latches[irrigation]);
+    }
+
+    #[test]
+    fn window_countdown_snapshot() {
+        let engine = RulesEngine::new();
+        let today = date(2026, 3, 20);
+        let env = EnvBuilder::new(at(2026, 3, 20))
+            .soil_temp(47.0, 47.0)
+            .soil_predictions(today, &[48.0, 49.0, 50.0, 51.0, 52.0, 53.0, 54.0])
+            .build();
+        let windows = engine.project_windows(&env, &profile(GrassType::TallFescue), &[], today);
+
+        insta::assert_json_snapshot!(windows);
+    }
+}
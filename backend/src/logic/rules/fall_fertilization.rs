@@ -1,10 +1,11 @@
 use super::thresholds::*;
-use super::Rule;
+use super::{covered_soil_temp_avg, current_date, Rule};
+use crate::logic::frost;
 use crate::models::{
     Application, ApplicationType, DataSource, EnvironmentalSummary, LawnProfile, Recommendation,
-    RecommendationCategory, Severity,
+    RecommendationCategory, Severity, SquareFeet,
 };
-use chrono::{Datelike, Local, NaiveDate};
+use chrono::{Datelike, Duration, NaiveDate};
 
 /// Fall fertilization program rule
 ///
@@ -12,10 +13,10 @@ use chrono::{Datelike, Local, NaiveDate};
 /// While top growth slows, roots are actively growing and storing carbohydrates
 /// for winter survival and spring green-up.
 ///
-/// Program:
+/// Program, timed from the typical first fall frost (months for zone 7a):
 /// - Early Fall (Sept): Recovery feeding after summer stress
 /// - Mid Fall (Oct): Main fall feeding for root development
-/// - Late Fall (Nov): "Winterizer" before dormancy
+/// - Late Fall (Nov): "Winterizer" from the first frost until dormancy
 ///
 /// Optimal conditions: Soil temp 50-60°F, grass still green
 pub struct FallFertilizationRule;
@@ -32,20 +33,22 @@ impl Rule for FallFertilizationRule {
             return None;
         }
 
-        let today = Local::now().date_naive();
+        let today = current_date();
         let current_year = today.year();
 
-        // Define fall fertilization window (Sept 1 - Nov 30)
-        let window_start = NaiveDate::from_ymd_opt(current_year, 9, 1)?;
-        let window_end = NaiveDate::from_ymd_opt(current_year, 11, 30)?;
+        // Fall fertilization window, placed around the typical first frost
+        let first_frost = frost::frost_outlook(&env.frost_years, &profile.usda_zone, current_year)
+            .first_fall_median();
+        let phases = FallPhases::around(first_frost);
 
         // Only evaluate during the window
-        if today < window_start || today > window_end {
+        if today < phases.early || today >= phases.end {
             return None;
         }
+        let window_start = phases.early;
 
         // Get soil temperature
-        let soil_temp_avg = env.soil_temp_7day_avg_f?;
+        let soil_temp_avg = covered_soil_temp_avg(env).ok()?;
 
         // Count fall fertilizer applications this year
         let fall_apps: Vec<&Application> = history
@@ -64,7 +67,7 @@ impl Rule for FallFertilizationRule {
         let days_since_last = last_app_date.map(|d| (today - d).num_days()).unwrap_or(999);
 
         // Determine which phase of fall fertilization we're in
-        let phase = determine_fall_phase(today, current_year);
+        let phase = phases.phase(today);
 
         // Check if soil temp is appropriate
         let soil_temp_ok = (FALL_FERT_SOIL_LOW_F..=FALL_FERT_SOIL_HIGH_F).contains(&soil_temp_avg);
@@ -105,25 +108,40 @@ impl Rule for FallFertilizationRule {
 
 #[derive(Debug)]
 enum FallPhase {
-    Early, // Sept 1 - Sept 30
+    Early, // Sept 1 - Sept 30 in zone 7a
     Mid,   // Oct 1 - Oct 31
     Late,  // Nov 1 - Nov 30
     TooLate,
 }
 
-fn determine_fall_phase(today: NaiveDate, year: i32) -> FallPhase {
-    let oct_1 = NaiveDate::from_ymd_opt(year, 10, 1).unwrap();
-    let nov_1 = NaiveDate::from_ymd_opt(year, 11, 1).unwrap();
-    let dec_1 = NaiveDate::from_ymd_opt(year, 12, 1).unwrap();
+/// Start of each fall phase for one year.
+struct FallPhases {
+    early: NaiveDate,
+    mid: NaiveDate,
+    late: NaiveDate,
+    end: NaiveDate,
+}
 
-    if today < oct_1 {
-        FallPhase::Early
-    } else if today < nov_1 {
-        FallPhase::Mid
-    } else if today < dec_1 {
-        FallPhase::Late
-    } else {
-        FallPhase::TooLate
+impl FallPhases {
+    fn around(first_frost: NaiveDate) -> Self {
+        Self {
+            early: first_frost - Duration::days(FALL_FERT_EARLY_DAYS_BEFORE_FROST),
+            mid: first_frost - Duration::days(FALL_FERT_MID_DAYS_BEFORE_FROST),
+            late: first_frost + Duration::days(FALL_FERT_LATE_DAYS_AFTER_FROST),
+            end: first_frost + Duration::days(FALL_FERT_END_DAYS_AFTER_FROST),
+        }
+    }
+
+    fn phase(&self, today: NaiveDate) -> FallPhase {
+        if today < self.mid {
+            FallPhase::Early
+        } else if today < self.late {
+            FallPhase::Mid
+        } else if today < self.end {
+            FallPhase::Late
+        } else {
+            FallPhase::TooLate
+        }
     }
 }
 
@@ -133,7 +151,7 @@ fn build_early_fall_rec(
     env: &EnvironmentalSummary,
 ) -> Recommendation {
     let lawn_size = profile.lawn_size_sqft.unwrap_or(DEFAULT_LAWN_SIZE_SQFT);
-    let n_needed = lawn_size / 1000.0 * EARLY_FALL_N_RATE_LBS_PER_KSQFT;
+    let n_needed = SquareFeet(lawn_size).apply_rate(EARLY_FALL_N_RATE_LBS_PER_KSQFT);
 
     let mut rec = Recommendation::new(
         "fall_fert_early",
@@ -193,7 +211,7 @@ fn build_mid_fall_rec(
     env: &EnvironmentalSummary,
 ) -> Recommendation {
     let lawn_size = profile.lawn_size_sqft.unwrap_or(DEFAULT_LAWN_SIZE_SQFT);
-    let n_needed = lawn_size / 1000.0 * MID_FALL_N_RATE_LBS_PER_KSQFT;
+    let n_needed = SquareFeet(lawn_size).apply_rate(MID_FALL_N_RATE_LBS_PER_KSQFT);
 
     let severity = if app_count == 0 {
         Severity::Warning // Missed early fall app
@@ -252,7 +270,7 @@ fn build_mid_fall_rec(
 
 fn build_late_fall_rec(soil_temp: f64, app_count: usize, profile: &LawnProfile) -> Recommendation {
     let lawn_size = profile.lawn_size_sqft.unwrap_or(DEFAULT_LAWN_SIZE_SQFT);
-    let n_needed = lawn_size / 1000.0 * WINTERIZER_N_RATE_LBS_PER_KSQFT;
+    let n_needed = SquareFeet(lawn_size).apply_rate(WINTERIZER_N_RATE_LBS_PER_KSQFT);
 
     let severity = if app_count == 0 {
         Severity::Warning // Missed all fall apps - at least get winterizer
@@ -0,0 +1,217 @@
+use super::thresholds::*;
+use super::{current_date, Gate, Rule};
+use crate::logic::frost;
+use crate::models::{
+    Application, ApplicationType, DataSource, EnvironmentalSummary, LawnProfile, Recommendation,
+    RecommendationCategory, Severity,
+};
+use chrono::{Datelike, Duration, NaiveDate};
+
+/// Fall nitrogen cutoff rule (August - December)
+///
+/// Nitrogen late in the season pushes tender new growth that hasn't hardened
+/// off when cold arrives, which invites winterkill and snow mold.
+/// - Warm-season grasses: last safe N date is 6 weeks before the average first
+///   frost, so growth slows into dormancy.
+/// - Cool-season grasses: the winterizer goes down after the first frost, but
+///   not once the ground is freezing — 6 weeks after the average first frost.
+///
+/// The first frost comes from the station's prior years of lows when there
+/// are enough of them, otherwise from the USDA zone.
+///
+/// Severity:
+/// - Warning: a fertilizer application is planned (future-dated) or was
+///   logged in the last 2 weeks past the cutoff
+pub struct FallNitrogenCutoffRule;
+
+impl Rule for FallNitrogenCutoffRule {
+    fn evaluate(
+        &self,
+        env: &EnvironmentalSummary,
+        profile: &LawnProfile,
+        history: &[Application],
+    ) -> Option<Recommendation> {
+        self.explain(env, profile, history).ok()
+    }
+
+    fn explain(
+        &self,
+        env: &EnvironmentalSummary,
+        profile: &LawnProfile,
+        history: &[Application],
+    ) -> Result<Recommendation, Gate> {
+        let today = current_date();
+        let year = today.year();
+        if today.month() < 8 {
+            return Err(Gate::OutOfSeason("Fall cutoff is checked Aug-Dec".into()));
+        }
+
+        let outlook = frost::frost_outlook(&env.frost_years, &profile.usda_zone, year);
+        let fall_frost = outlook.first_fall.ok_or_else(|| {
+            Gate::NotApplicable(format!(
+                "Zone {} has no typical fall frost",
+                profile.usda_zone
+            ))
+        })?;
+        let (first_frost, source) = (fall_frost.median, fall_frost.source);
+        let cutoff = nitrogen_cutoff(profile, first_frost);
+
+        let lookback = today - Duration::days(N_CUTOFF_LOOKBACK_DAYS);
+        let late: Vec<&Application> = history
+            .iter()
+            .filter(|a| {
+                a.application_type == ApplicationType::Fertilizer
+                    && a.plant_id.is_none()
+                    && a.nitrogen_pct != Some(0.0)
+                    && a.application_date.year() == year
+                    && a.application_date > cutoff
+                    && a.application_date >= lookback
+            })
+            .collect();
+        let Some(latest) = late.iter().map(|a| a.application_date).max() else {
+            return Err(Gate::ConditionsNotMet(format!(
+                "No fertilizer after the {} cutoff",
+                cutoff.format("%b %-d")
+            )));
+        };
+        let planned = latest > today;
+
+        let (title, description) = if planned {
+            (
+                "Planned Fertilizer Past Fall Cutoff",
+                format!(
+                    "Fertilizer is planned for {}, after the last safe nitrogen date ({}).",
+                    latest.format("%b %-d"),
+                    cutoff.format("%b %-d")
+                ),
+            )
+        } else {
+            (
+                "Fertilizer Past Fall Cutoff",
+                format!(
+                    "Fertilizer was logged on {}, after the last safe nitrogen date ({}).",
+                    latest.format("%b %-d"),
+                    cutoff.format("%b %-d")
+                ),
+            )
+        };
+
+        let explanation = if profile.grass_type.is_cool_season() {
+            format!(
+                "{} keeps using nitrogen after the first frost, but once the ground \
+                 starts freezing it can't take it up. Late nitrogen then sits on lush \
+                 growth going into winter, which favors snow mold and leaches in spring.",
+                profile.grass_type
+            )
+        } else {
+            format!(
+                "{} needs to slow down and harden off before dormancy. Nitrogen in the \
+                 6 weeks before the first frost pushes tender growth that is easily \
+                 winterkilled and slow to green up.",
+                profile.grass_type
+            )
+        };
+
+        let action = if planned {
+            "Skip the planned application or move it to spring green-up."
+        } else {
+            "Don't apply more nitrogen this season. Avoid heavy traffic and mow at the \
+             normal height until dormancy."
+        };
+
+        let mut rec = Recommendation::new(
+            format!("fall_n_cutoff_{}", year),
+            RecommendationCategory::Fertilizer,
+            Severity::Warning,
+            title,
+            description,
+        )
+        .with_explanation(explanation)
+        .with_data_point(
+            "Typical First Frost",
+            format!("{} ({})", first_frost.format("%b %-d"), source.as_str()),
+            DataSource::Calculated.as_str(),
+        )
+        .with_data_point(
+            "Last Safe N Date",
+            cutoff.format("%b %-d").to_string(),
+            DataSource::Calculated.as_str(),
+        )
+        .with_data_point(
+            "Applications Past Cutoff",
+            late.len().to_string(),
+            DataSource::Calculated.as_str(),
+        )
+        .with_action(action);
+        if let Some(advice) = outlook.fall_advice(FROST_ADVICE_RISK) {
+            rec = rec.with_data_point("Frost Risk", advice, DataSource::Calculated.as_str());
+        }
+
+        Ok(rec)
+    }
+}
+
+/// Last safe nitrogen date for this lawn given the average first frost.
+fn nitrogen_cutoff(profile: &LawnProfile, first_frost: NaiveDate) -> NaiveDate {
+    if profile.grass_type.is_cool_season() {
+        first_frost + Duration::days(COOL_SEASON_N_CUTOFF_DAYS_AFTER_FROST)
+    } else {
+        first_frost - Duration::days(WARM_SEASON_N_CUTOFF_DAYS_BEFORE_FROST)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logic::rules::with_current_date;
+    use crate::models::{FrostYear, GrassType};
+    use crate::testing::{application, at, date, profile, EnvBuilder};
+
+    #[test]
+    fn warns_on_fertilizer_past_the_cutoff() {
+        let rule = FallNitrogenCutoffRule;
+        let env = EnvBuilder::new(at(2025, 10, 1)).build();
+        let bermuda = profile(GrassType::Bermuda);
+        let fescue = profile(GrassType::TallFescue);
+        // Zone 7a: first frost ~Oct 30, Bermuda cutoff Sep 18.
+        let planned = [application(ApplicationType::Fertilizer, date(2025, 10, 10))];
+
+        with_current_date(date(2025, 10, 1), || {
+            let rec = rule.explain(&env, &bermuda, &planned).unwrap();
+            assert_eq!(rec.severity, Severity::Warning);
+            assert_eq!(rec.title, "Planned Fertilizer Past Fall Cutoff");
+
+            let logged = [application(ApplicationType::Fertilizer, date(2025, 9, 25))];
+            let rec = rule.explain(&env, &bermuda, &logged).unwrap();
+            assert_eq!(rec.title, "Fertilizer Past Fall Cutoff");
+
+            // Cool-season cutoff is after the frost, so October feeding is fine.
+            assert!(matches!(
+                rule.explain(&env, &fescue, &planned),
+                Err(Gate::ConditionsNotMet(_))
+            ));
+        });
+
+        // Station history moves the cutoff: a Nov 20 typical frost puts it at Oct 9.
+        let mut late_frost = env.clone();
+        late_frost.frost_years = (2022..=2024)
+            .map(|year| FrostYear {
+                year,
+                last_spring: None,
+                first_fall: Some(date(year, 11, 20)),
+            })
+            .collect();
+        with_current_date(date(2025, 10, 1), || {
+            assert!(rule.explain(&late_frost, &bermuda, &planned).is_ok());
+            let early = [application(ApplicationType::Fertilizer, date(2025, 10, 5))];
+            assert!(rule.explain(&late_frost, &bermuda, &early).is_err());
+        });
+
+        with_current_date(date(2025, 6, 1), || {
+            assert!(matches!(
+                rule.explain(&env, &bermuda, &planned),
+                Err(Gate::OutOfSeason(_))
+            ));
+        });
+    }
+}
@@ -1,10 +1,109 @@
 use super::thresholds::*;
-use super::Rule;
+use super::window_projection::SoilWindow;
+use super::{covered_soil_temp_avg, current_date, Gate, Rule, WindowProjection};
+use crate::logic::frost::{self, FrostOutlook};
+use crate::models::soil_temp_prediction::{CrossingDirection, SoilTempPrediction};
 use crate::models::{
-    Application, ApplicationType, DataSource, EnvironmentalSummary, LawnProfile, Recommendation,
-    RecommendationCategory, Severity,
+    Application, ApplicationType, DailyForecast, DataSource, EnvironmentalSummary, LawnProfile,
+    Recommendation, RecommendationCategory, Severity, SquareFeet,
 };
-use chrono::{Datelike, Local, NaiveDate};
+use chrono::{Datelike, Duration, NaiveDate};
+
+/// Verdict on whether the coming forecast period favors seed germination.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeedingVerdict {
+    Good,
+    Marginal,
+    Poor,
+}
+
+impl SeedingVerdict {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SeedingVerdict::Good => "Good week to seed",
+            SeedingVerdict::Marginal => "Marginal week to seed",
+            SeedingVerdict::Poor => "Poor week to seed",
+        }
+    }
+}
+
+/// Scored germination outlook over the available forecast days.
+#[derive(Debug, Clone)]
+pub struct GerminationOutlook {
+    /// 0-100; 40 pts soil temp, 30 pts rain frequency, 30 pts absence of heat spikes
+    pub score: f64,
+    pub verdict: SeedingVerdict,
+    pub days_scored: usize,
+    pub soil_days_in_range: usize,
+    pub rain_days: usize,
+    pub heat_spike_days: usize,
+}
+
+/// Score the forecast period for germination success.
+///
+/// Soil temperature uses the regression projection when available, otherwise
+/// the current 7-day average is assumed to hold. Rain days are good (less
+/// hand-watering); a third of days with rain earns full marks. Any day with a
+/// high at or above `OVERSEED_FORECAST_HOT_F` is a heat spike that stresses
+/// emerging seedlings.
+pub fn score_germination_outlook(
+    days: &[&DailyForecast],
+    soil_predictions: Option<&[SoilTempPrediction]>,
+    soil_temp_avg: f64,
+) -> Option<GerminationOutlook> {
+    if days.is_empty() {
+        return None;
+    }
+    let n = days.len();
+    let in_range = |t: f64| (OVERSEED_SOIL_LOW_F..=OVERSEED_SOIL_HIGH_F).contains(&t);
+
+    let soil_days_in_range = days
+        .iter()
+        .filter(|d| {
+            let projected = soil_predictions
+                .and_then(|preds| preds.iter().find(|p| p.date == d.date))
+                .map(|p| p.predicted_soil_temp_f)
+                .unwrap_or(soil_temp_avg);
+            in_range(projected)
+        })
+        .count();
+
+    let rain_days = days
+        .iter()
+        .filter(|d| {
+            d.total_precipitation_mm >= PRECIP_TRACE_MM
+                || d.max_precipitation_prob >= PRECIP_PROB_LIKELY
+        })
+        .count();
+
+    let heat_spike_days = days
+        .iter()
+        .filter(|d| d.high_temp_f >= OVERSEED_FORECAST_HOT_F)
+        .count();
+
+    let soil_score = 40.0 * soil_days_in_range as f64 / n as f64;
+    let rain_target = (n as f64 / 3.0).max(1.0);
+    let rain_score = 30.0 * (rain_days as f64 / rain_target).min(1.0);
+    let heat_score = 30.0 * (1.0 - heat_spike_days as f64 / n as f64);
+    let score = soil_score + rain_score + heat_score;
+
+    let verdict = if score >= OVERSEED_OUTLOOK_GOOD_SCORE {
+        SeedingVerdict::Good
+    } else if score >= OVERSEED_OUTLOOK_MARGINAL_SCORE {
+        SeedingVerdict::Marginal
+    } else {
+        SeedingVerdict::Poor
+    };
+
+    Some(GerminationOutlook {
+        score,
+        verdict,
+        days_scored: n,
+        soil_days_in_range,
+        rain_days,
+        heat_spike_days,
+    })
+}
 
 /// Fall overseeding timing rule
 ///
@@ -13,12 +112,25 @@ use chrono::{Datelike, Local, NaiveDate};
 /// - Too early: Seedlings die from heat stress
 /// - Too late: Not enough time to establish before winter
 ///
-/// Optimal window: Soil temp 50-65°F, late August through October
+/// Optimal window: Soil temp 50-65°F, from 11 weeks before the typical first
+/// fall frost until it (mid-August through October in zone 7a)
+/// Seeding with a 10%+ chance of frost inside the 6 weeks seedlings need to
+/// establish is called out
 /// Germination requires consistent moisture for 10-14 days
+/// Forecast days are scored into a good/marginal/poor "week to seed" verdict
 /// GDD >= 2500 indicates season maturity (fall window approaching)
 /// GDD >= 3000 + low time remaining escalates severity
 pub struct FallOverseedingRule;
 
+/// Dates the fall overseeding window runs between.
+fn overseed_season(outlook: &FrostOutlook) -> (NaiveDate, NaiveDate) {
+    let first_frost = outlook.first_fall_median();
+    (
+        first_frost - Duration::days(OVERSEED_WINDOW_DAYS_BEFORE_FROST),
+        first_frost,
+    )
+}
+
 impl Rule for FallOverseedingRule {
     fn evaluate(
         &self,
@@ -26,21 +138,37 @@ impl Rule for FallOverseedingRule {
         profile: &LawnProfile,
         history: &[Application],
     ) -> Option<Recommendation> {
+        self.explain(env, profile, history).ok()
+    }
+
+    fn explain(
+        &self,
+        env: &EnvironmentalSummary,
+        profile: &LawnProfile,
+        history: &[Application],
+    ) -> Result<Recommendation, Gate> {
         // Only relevant for cool-season grasses
         if !profile.grass_type.is_cool_season() {
-            return None;
+            return Err(Gate::NotApplicable(format!(
+                "{} is not a cool-season grass",
+                profile.grass_type
+            )));
         }
 
-        let today = Local::now().date_naive();
+        let today = current_date();
         let current_year = today.year();
 
-        // Define the overseeding window (Aug 15 - Oct 31 for Zone 7a)
-        let window_start = NaiveDate::from_ymd_opt(current_year, 8, 15)?;
-        let window_end = NaiveDate::from_ymd_opt(current_year, 10, 31)?;
+        // The overseeding window runs up to the typical first frost
+        let frost = frost::frost_outlook(&env.frost_years, &profile.usda_zone, current_year);
+        let (window_start, window_end) = overseed_season(&frost);
 
         // Only evaluate during the window
         if today < window_start || today > window_end {
-            return None;
+            return Err(Gate::OutOfSeason(format!(
+                "Overseeding window is {} - {}",
+                window_start.format("%b %-d"),
+                window_end.format("%b %-d")
+            )));
         }
 
         // Check if already overseeded this fall
@@ -51,32 +179,33 @@ impl Rule for FallOverseedingRule {
         });
 
         if already_seeded {
-            return None;
+            return Err(Gate::AlreadyApplied(format!(
+                "Overseed already logged this fall ({})",
+                current_year
+            )));
         }
 
         // Get soil temperature
-        let soil_temp_avg = env.soil_temp_7day_avg_f?;
-        let current_soil_temp = env.current.as_ref()?.soil_temp_10_f?;
+        let soil_temp_avg = covered_soil_temp_avg(env)?;
+        let current_soil_temp = env
+            .current
+            .as_ref()
+            .and_then(|c| c.soil_temp_10_f)
+            .ok_or_else(|| Gate::MissingData("Current soil temp (10cm)".into()))?;
 
         // GDD data for season maturity assessment
         let gdd_ytd = env.gdd_base50_ytd;
         let season_mature = gdd_ytd.is_some_and(|gdd| gdd >= OVERSEED_GDD_SEASON_MATURE);
         let season_late = gdd_ytd.is_some_and(|gdd| gdd >= OVERSEED_GDD_SEASON_LATE);
 
-        // Check forecast for upcoming conditions (if available)
-        let forecast_favorable = env
-            .forecast
-            .as_ref()
-            .map(|f| {
-                // Check if next 14 days have reasonable temps
-                let days = f.next_days(14);
-                let avg_high: f64 =
-                    days.iter().map(|d| d.high_temp_f).sum::<f64>() / days.len().max(1) as f64;
-
-                // Favorable if highs are moderate (rain helps but irrigation can substitute)
-                avg_high < OVERSEED_FORECAST_HOT_F
-            })
-            .unwrap_or(true);
+        // Score the upcoming forecast for germination success (if available)
+        let outlook = env.forecast.as_ref().and_then(|f| {
+            score_germination_outlook(
+                &f.next_days(OVERSEED_OUTLOOK_DAYS),
+                env.soil_temp_predictions.as_deref(),
+                soil_temp_avg,
+            )
+        });
 
         // Calculate days remaining in window
         let days_remaining = (window_end - today).num_days();
@@ -100,16 +229,29 @@ impl Rule for FallOverseedingRule {
                 Severity::Advisory
             };
 
+            let mut description = format!(
+                "Soil temperature ({:.1}°F) is ideal for TTTF seed germination. \
+                 {} days remaining in optimal window.",
+                soil_temp_avg, days_remaining
+            );
+            let establish_by = today + Duration::days(OVERSEED_ESTABLISH_DAYS);
+            if let Some(risk) = frost
+                .fall_risk_by(establish_by)
+                .filter(|&risk| risk >= FROST_ADVICE_RISK)
+            {
+                description.push_str(&format!(
+                    " There's a {:.0}% chance of frost before seed sown now gets the 6 weeks \
+                     it needs to establish.",
+                    risk * 100.0
+                ));
+            }
+
             let mut rec = Recommendation::new(
                 format!("fall_overseeding_{}", current_year),
                 RecommendationCategory::Overseeding,
                 severity,
                 "Fall Overseeding Window Open",
-                format!(
-                    "Soil temperature ({:.1}°F) is ideal for TTTF seed germination. \
-                     {} days remaining in optimal window.",
-                    soil_temp_avg, days_remaining
-                ),
+                description,
             );
 
             rec = rec
@@ -146,19 +288,35 @@ impl Rule for FallOverseedingRule {
                     DataSource::Calculated.as_str(),
                 );
             }
+            if let Some(advice) = frost.fall_advice(FROST_ADVICE_RISK) {
+                rec = rec.with_data_point("Frost Risk", advice, DataSource::Calculated.as_str());
+            }
 
-            // Add forecast note if available
-            if !forecast_favorable {
-                rec = rec.with_data_point(
-                    "Forecast Note",
-                    "Hot weather ahead - monitor seedlings",
-                    DataSource::OpenWeatherMap.as_str(),
-                );
+            // Add germination outlook if forecast available
+            if let Some(ref o) = outlook {
+                rec = rec
+                    .with_data_point(
+                        "Seeding Outlook",
+                        format!("{} ({:.0}/100)", o.verdict.as_str(), o.score),
+                        DataSource::OpenWeatherMap.as_str(),
+                    )
+                    .with_data_point(
+                        "Next Days",
+                        format!(
+                            "{}/{} soil in range, {} rain, {} hot (≥{:.0}°F)",
+                            o.soil_days_in_range,
+                            o.days_scored,
+                            o.rain_days,
+                            o.heat_spike_days,
+                            OVERSEED_FORECAST_HOT_F
+                        ),
+                        DataSource::Calculated.as_str(),
+                    );
             }
 
             let seeding_rate = if profile.lawn_size_sqft.unwrap_or(DEFAULT_LAWN_SIZE_SQFT) > 0.0 {
                 let sqft = profile.lawn_size_sqft.unwrap_or(DEFAULT_LAWN_SIZE_SQFT);
-                let lbs_needed = sqft / 1000.0 * OVERSEED_RATE_LBS_PER_KSQFT;
+                let lbs_needed = SquareFeet(sqft).apply_rate(OVERSEED_RATE_LBS_PER_KSQFT);
                 format!(
                     "For your {:.0} sqft lawn: ~{:.0} lbs of TTTF seed ({:.0} lbs/1000 sqft for overseeding). \
                      Mow low (2\"), dethatch or aerate first for seed-to-soil contact. \
@@ -176,13 +334,27 @@ impl Rule for FallOverseedingRule {
                 )
             };
 
-            rec = rec.with_action(seeding_rate);
+            let action = match outlook.as_ref().map(|o| o.verdict) {
+                Some(SeedingVerdict::Poor) => format!(
+                    "{} Forecast looks poor for germination this week — consider waiting a \
+                     few days if the window allows.",
+                    seeding_rate
+                ),
+                Some(SeedingVerdict::Marginal) => format!(
+                    "{} Forecast is marginal — plan on extra watering to keep seed moist.",
+                    seeding_rate
+                ),
+                _ => seeding_rate,
+            };
+
+            rec = rec.with_action(action);
 
-            Some(rec)
+            Ok(rec)
         } else if soil_temp_avg > OVERSEED_SOIL_HIGH_F && soil_temp_avg <= OVERSEED_WARM_LIMIT_F {
             // Soil still warm - might be early in window
-            if today < NaiveDate::from_ymd_opt(current_year, 9, 15)? {
-                // Early September - wait for cooler temps
+            let wait_until = window_end - Duration::days(OVERSEED_WARM_WAIT_DAYS_BEFORE_FROST);
+            if today < wait_until {
+                // Early in the window - wait for cooler temps
                 let gdd_note = if season_mature {
                     " GDD indicates the season is maturing — the fall overseeding window is approaching."
                 } else {
@@ -202,8 +374,11 @@ impl Rule for FallOverseedingRule {
                 )
                 .with_explanation(format!(
                     "TTTF germinates best when soil is {:.0}-{:.0}°F. Seeding when soil is too warm \
-                     can stress seedlings. The window typically opens mid-September in Zone 7a.{}",
-                    OVERSEED_SOIL_LOW_F, OVERSEED_SOIL_HIGH_F, gdd_note
+                     can stress seedlings. The window typically opens around {}.{}",
+                    OVERSEED_SOIL_LOW_F,
+                    OVERSEED_SOIL_HIGH_F,
+                    wait_until.format("%b %-d"),
+                    gdd_note
                 ))
                 .with_data_point(
                     "Soil Temp",
@@ -215,7 +390,7 @@ impl Rule for FallOverseedingRule {
                      gather supplies. Monitor soil temps weekly.",
                 );
 
-                Some(rec)
+                Ok(rec)
             } else {
                 // Late September+ with warm soil - seed anyway, window closing
                 let rec = Recommendation::new(
@@ -239,7 +414,7 @@ impl Rule for FallOverseedingRule {
                      seedlings cool. Soil temps will drop as nights get cooler.",
                 );
 
-                Some(rec)
+                Ok(rec)
             }
         } else if soil_temp_avg < OVERSEED_SOIL_LOW_F {
             // Getting cold - urgent if not seeded
@@ -266,15 +441,55 @@ impl Rule for FallOverseedingRule {
                     OVERSEED_SOIL_LOW_F
                 ));
 
-                Some(rec)
+                Ok(rec)
             } else {
                 // Very late - probably too late for this year
-                None
+                Err(Gate::ConditionsNotMet(format!(
+                    "Soil {:.1}°F with only {} days left — too late this year",
+                    soil_temp_avg, days_remaining
+                )))
             }
         } else {
-            None
+            Err(Gate::ConditionsNotMet(format!(
+                "7-day soil temp {:.1}°F above {:.0}°F",
+                soil_temp_avg, OVERSEED_WARM_LIMIT_F
+            )))
         }
     }
+
+    fn project_window(
+        &self,
+        env: &EnvironmentalSummary,
+        profile: &LawnProfile,
+        history: &[Application],
+        today: NaiveDate,
+    ) -> Option<WindowProjection> {
+        if !profile.grass_type.is_cool_season() {
+            return None;
+        }
+        let (season_start, season_end) = overseed_season(&frost::frost_outlook(
+            &env.frost_years,
+            &profile.usda_zone,
+            today.year(),
+        ));
+        let already_seeded = history.iter().any(|app| {
+            app.application_type == ApplicationType::Overseed
+                && app.application_date >= season_start
+        });
+        if already_seeded {
+            return None;
+        }
+
+        SoilWindow {
+            name: "Fall Overseeding",
+            open_f: OVERSEED_SOIL_HIGH_F,
+            close_f: OVERSEED_SOIL_LOW_F,
+            direction: CrossingDirection::Falling,
+            season_start,
+            season_end,
+        }
+        .project(env, today)
+    }
 }
 
 #[cfg(test)]
@@ -301,18 +516,104 @@ mod tests {
             soil_type: None,
             lawn_size_sqft: Some(5000.0),
             irrigation_type: None,
+            location: None,
+            noaa_station_wbanno: None,
+            dormant: false,
+            mowing_height_in: None,
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
         }
     }
 
+    fn day(offset: i64, high: f64, precip_mm: f64, prob: f64) -> DailyForecast {
+        DailyForecast {
+            date: chrono::Utc::now().date_naive() + chrono::Duration::days(offset),
+            high_temp_f: high,
+            low_temp_f: high - 20.0,
+            avg_humidity: 60.0,
+            total_precipitation_mm: precip_mm,
+            total_snow_mm: 0.0,
+            max_precipitation_prob: prob,
+            dominant_condition: crate::models::WeatherCondition::Clear,
+            avg_wind_speed_mph: 5.0,
+            max_wind_gust_mph: None,
+        }
+    }
+
+    #[test]
+    fn outlook_good_with_mild_temps_and_rain() {
+        let days: Vec<DailyForecast> = (0..6)
+            .map(|i| {
+                if i % 3 == 0 {
+                    day(i, 72.0, 6.0, 0.8)
+                } else {
+                    day(i, 72.0, 0.0, 0.1)
+                }
+            })
+            .collect();
+        let refs: Vec<&DailyForecast> = days.iter().collect();
+        let o = score_germination_outlook(&refs, None, 58.0).unwrap();
+        assert_eq!(o.verdict, SeedingVerdict::Good);
+        assert_eq!(o.rain_days, 2);
+        assert_eq!(o.heat_spike_days, 0);
+        assert!((o.score - 100.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn outlook_poor_with_heat_and_warm_soil() {
+        let days: Vec<DailyForecast> = (0..5).map(|i| day(i, 90.0, 0.0, 0.0)).collect();
+        let refs: Vec<&DailyForecast> = days.iter().collect();
+        let o = score_germination_outlook(&refs, None, 72.0).unwrap();
+        assert_eq!(o.verdict, SeedingVerdict::Poor);
+        assert_eq!(o.heat_spike_days, 5);
+        assert_eq!(o.soil_days_in_range, 0);
+    }
+
+    #[test]
+    fn outlook_marginal_when_dry_with_one_hot_day() {
+        let mut days: Vec<DailyForecast> = (0..5).map(|i| day(i, 75.0, 0.0, 0.1)).collect();
+        days[0].high_temp_f = 88.0;
+        let refs: Vec<&DailyForecast> = days.iter().collect();
+        // 40 (soil) + 0 (no rain) + 24 (4/5 days without heat) = 64
+        let o = score_germination_outlook(&refs, None, 58.0).unwrap();
+        assert_eq!(o.verdict, SeedingVerdict::Marginal);
+        assert!((o.score - 64.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn outlook_uses_soil_projection_when_available() {
+        use crate::models::soil_temp_prediction::PredictionConfidence;
+        let days: Vec<DailyForecast> = (0..4).map(|i| day(i, 70.0, 0.0, 0.0)).collect();
+        let refs: Vec<&DailyForecast> = days.iter().collect();
+        // Current average is too warm, but the projection cools into range
+        let preds: Vec<SoilTempPrediction> = days
+            .iter()
+            .map(|d| SoilTempPrediction {
+                date: d.date,
+                predicted_soil_temp_f: 60.0,
+                confidence: PredictionConfidence::Medium,
+                air_temp_used_f: 60.0,
+                source_description: "test".into(),
+            })
+            .collect();
+        let o = score_germination_outlook(&refs, Some(&preds), 70.0).unwrap();
+        assert_eq!(o.soil_days_in_range, 4);
+        let o = score_germination_outlook(&refs, None, 70.0).unwrap();
+        assert_eq!(o.soil_days_in_range, 0);
+    }
+
+    #[test]
+    fn outlook_none_without_forecast_days() {
+        assert!(score_germination_outlook(&[], None, 58.0).is_none());
+    }
+
     #[test]
     fn gdd_none_degrades_gracefully() {
         // GDD = None should not change behavior vs pre-GDD code.
         let env = base_env(58.0, 57.0);
         assert!(env.gdd_base50_ytd.is_none());
         let rule = FallOverseedingRule;
-        // Calendar-gated (Aug 15 - Oct 31), but should not panic regardless.
+        // Calendar-gated (Aug 15 - Oct 30 in zone 7a), but should not panic regardless.
         let _ = rule.evaluate(&env, &base_profile(), &[]);
     }
 
@@ -335,7 +636,7 @@ mod tests {
     #[test]
     fn gdd_season_late_with_low_time_escalates() {
         // GDD = 3000 (season late) — should escalate if days_remaining < 21.
-        // This test is date-sensitive (Aug 15 - Oct 31 window).
+        // This test is date-sensitive (Aug 15 - Oct 30 window in zone 7a).
         let mut env = base_env(58.0, 57.0);
         env.gdd_base50_ytd = Some(3000.0);
         let rule = FallOverseedingRule;
@@ -359,4 +660,36 @@ mod tests {
         let _ = rule.evaluate(&env, &base_profile(), &[]);
         // Cannot assert on exact output without controlling date, but should not panic.
     }
+
+    #[test]
+    fn window_follows_the_first_frost() {
+        use crate::logic::rules::with_current_date;
+        use crate::testing::date;
+
+        let env = base_env(58.0, 57.0);
+        let zone_7a = base_profile();
+        let mut zone_5a = base_profile();
+        zone_5a.usda_zone = "5a".into();
+        let rule = FallOverseedingRule;
+
+        // Zone 5a's first frost (~Oct 5) has already closed the window
+        with_current_date(date(2026, 10, 10), || {
+            assert!(rule.explain(&env, &zone_7a, &[]).is_ok());
+            assert!(matches!(
+                rule.explain(&env, &zone_5a, &[]),
+                Err(Gate::OutOfSeason(_))
+            ));
+        });
+
+        // Early in the window there's time to establish; late, frost is likely first
+        let early = with_current_date(date(2026, 9, 1), || rule.explain(&env, &zone_7a, &[]));
+        let early = early.unwrap();
+        assert!(!early.description.contains("chance of frost"));
+        assert!(early
+            .data_points
+            .iter()
+            .any(|dp| dp.label == "Frost Risk" && dp.value.contains("Oct 16")));
+        let late = with_current_date(date(2026, 9, 25), || rule.explain(&env, &zone_7a, &[]));
+        assert!(late.unwrap().description.contains("chance of frost"));
+    }
 }
@@ -0,0 +1,246 @@
+use super::thresholds::*;
+use super::{current_date, Rule};
+use crate::models::{
+    Application, ApplicationType, DataSource, EnvironmentalSummary, LawnProfile, Recommendation,
+    RecommendationCategory, Severity, WeatherForecast,
+};
+use chrono::{Datelike, NaiveDate};
+
+/// Fall pest scouting rule - fall armyworm and sod webworm (August - October)
+///
+/// Fall armyworm moths ride warm, humid air north in late summer and larvae can
+/// strip a lawn in days; sod webworm's later generations feed over the same
+/// period. Neither is worth treating preventively — the rule prompts a soap
+/// flush so a treatment decision is based on what's actually in the turf.
+///
+/// Conditions:
+/// - Window: August 1 - October 31
+/// - At least one warm (high ≥75°F), humid (≥70%) day in the 3-day forecast
+/// - No scouting logged in the last 7 days
+///
+/// Severity:
+/// - Advisory: warm, humid days ahead
+/// - Warning: 3+ warm nights (lows ≥65°F) with 7-day humidity ≥80%, or a
+///   recent overseeding (seedlings can be wiped out)
+pub struct FallPestScoutingRule;
+
+/// Conditions driving the rule.
+struct PestOutlook {
+    warm_humid_days: usize,
+    warm_nights: u32,
+    humidity_7day: Option<f64>,
+    days_since_scouted: Option<i64>,
+    recent_overseed: bool,
+}
+
+impl PestOutlook {
+    fn from_inputs(
+        env: &EnvironmentalSummary,
+        forecast: &WeatherForecast,
+        history: &[Application],
+        today: NaiveDate,
+    ) -> Self {
+        let days_since = |kind: ApplicationType| {
+            history
+                .iter()
+                .filter(|app| app.application_type == kind && app.application_date <= today)
+                .map(|app| (today - app.application_date).num_days())
+                .min()
+        };
+
+        Self {
+            warm_humid_days: forecast
+                .next_days(3)
+                .iter()
+                .filter(|d| {
+                    d.high_temp_f >= FALL_PEST_HIGH_F && d.avg_humidity >= FALL_PEST_HUMIDITY
+                })
+                .count(),
+            warm_nights: forecast.consecutive_warm_nights(FALL_PEST_WARM_NIGHT_F),
+            humidity_7day: env.humidity_7day_avg,
+            days_since_scouted: days_since(ApplicationType::Scouting),
+            recent_overseed: days_since(ApplicationType::Overseed)
+                .is_some_and(|d| d <= SEEDLING_VULNERABLE_DAYS),
+        }
+    }
+
+    fn sustained_pressure(&self) -> bool {
+        self.warm_nights >= FALL_PEST_WARM_NIGHTS_ESCALATE
+            && self
+                .humidity_7day
+                .is_some_and(|h| h >= FALL_PEST_HUMIDITY_ESCALATE)
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        if self.warm_humid_days == 0 {
+            return None;
+        }
+        if self
+            .days_since_scouted
+            .is_some_and(|d| d < SCOUTING_INTERVAL_DAYS)
+        {
+            return None;
+        }
+
+        Some(if self.sustained_pressure() || self.recent_overseed {
+            Severity::Warning
+        } else {
+            Severity::Advisory
+        })
+    }
+}
+
+impl Rule for FallPestScoutingRule {
+    fn evaluate(
+        &self,
+        env: &EnvironmentalSummary,
+        _profile: &LawnProfile,
+        history: &[Application],
+    ) -> Option<Recommendation> {
+        // Window: August 1 - October 31
+        let today = current_date();
+        if !(8..=10).contains(&today.month()) {
+            return None;
+        }
+
+        let forecast = env.forecast.as_ref()?;
+        let outlook = PestOutlook::from_inputs(env, forecast, history, today);
+        let severity = outlook.severity()?;
+
+        let mut description = format!(
+            "{} warm, humid day{} in the forecast favor fall armyworm and sod webworm feeding.",
+            outlook.warm_humid_days,
+            if outlook.warm_humid_days == 1 {
+                ""
+            } else {
+                "s"
+            }
+        );
+        if outlook.sustained_pressure() {
+            description.push_str(&format!(
+                " {} warm nights in a row with sustained humidity keep moths flying and larvae feeding.",
+                outlook.warm_nights
+            ));
+        }
+        if outlook.recent_overseed {
+            description.push_str(
+                " Recently overseeded turf is at high risk: larvae can wipe out seedlings in days.",
+            );
+        }
+
+        let mut rec = Recommendation::new(
+            "fall_pest_scouting",
+            RecommendationCategory::PestScouting,
+            severity,
+            if severity == Severity::Warning {
+                "Scout for Armyworms and Webworms Now"
+            } else {
+                "Scout for Fall Lawn Caterpillars"
+            },
+            description,
+        )
+        .with_explanation(
+            "Fall armyworm moths migrate north on warm, humid air in late summer, and \
+             sod webworm's later generations feed through early fall. Larvae feed at \
+             night and hide in the thatch by day, so damage often looks like drought \
+             until it spreads. Warm nights and high humidity speed development and \
+             egg-laying. Treat only when a soap flush finds larvae above threshold.",
+        )
+        .with_data_point(
+            "Warm, Humid Days (3d)",
+            format!("{}", outlook.warm_humid_days),
+            DataSource::OpenWeatherMap.as_str(),
+        )
+        .with_data_point(
+            "Warm Nights (≥65°F)",
+            format!("{}", outlook.warm_nights),
+            DataSource::OpenWeatherMap.as_str(),
+        )
+        .with_data_point(
+            "Last Scouted",
+            outlook
+                .days_since_scouted
+                .map(|d| format!("{} days ago", d))
+                .unwrap_or_else(|| "Never".to_string()),
+            DataSource::History.as_str(),
+        );
+
+        if let Some(humidity) = outlook.humidity_7day {
+            rec = rec.with_data_point(
+                "7-Day Avg Humidity",
+                format!("{:.0}%", humidity),
+                DataSource::HomeAssistant.as_str(),
+            );
+        }
+
+        Some(rec.with_action(
+            "Do a soap flush at dusk: mix 2 tablespoons of dish soap in a gallon of water, \
+             pour it over about 2 sq ft at the edge of any browning, and watch for 5-10 \
+             minutes. Treat if you count 2+ armyworms per sq ft or 12+ sod webworms per sq yd. \
+             Log the check as a Scouting entry to quiet this reminder for a week.",
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{application, date, ForecastBuilder};
+
+    fn outlook(warm_humid_days: usize, warm_nights: u32, humidity_7day: f64) -> PestOutlook {
+        PestOutlook {
+            warm_humid_days,
+            warm_nights,
+            humidity_7day: Some(humidity_7day),
+            days_since_scouted: None,
+            recent_overseed: false,
+        }
+    }
+
+    #[test]
+    fn warm_humid_forecast_prompts_scouting() {
+        assert_eq!(outlook(0, 0, 70.0).severity(), None);
+        assert_eq!(outlook(2, 1, 70.0).severity(), Some(Severity::Advisory));
+    }
+
+    #[test]
+    fn warm_nights_with_humidity_or_new_seed_escalate() {
+        assert_eq!(outlook(2, 3, 85.0).severity(), Some(Severity::Warning));
+        // Warm nights alone don't escalate
+        assert_eq!(outlook(2, 3, 70.0).severity(), Some(Severity::Advisory));
+
+        let mut o = outlook(1, 0, 60.0);
+        o.recent_overseed = true;
+        assert_eq!(o.severity(), Some(Severity::Warning));
+    }
+
+    #[test]
+    fn recent_scouting_quiets_the_reminder() {
+        let mut o = outlook(2, 3, 85.0);
+        o.days_since_scouted = Some(3);
+        assert_eq!(o.severity(), None);
+        o.days_since_scouted = Some(SCOUTING_INTERVAL_DAYS);
+        assert_eq!(o.severity(), Some(Severity::Warning));
+    }
+
+    #[test]
+    fn planned_entries_dont_count_as_done() {
+        let today = date(2026, 9, 10);
+        let forecast = ForecastBuilder::starting(today)
+            .day(82.0, 66.0, 75.0, 0.0)
+            .build();
+        let history = [
+            application(ApplicationType::Scouting, date(2026, 9, 12)),
+            application(ApplicationType::Overseed, date(2026, 9, 20)),
+        ];
+        let o = PestOutlook::from_inputs(
+            &EnvironmentalSummary::default(),
+            &forecast,
+            &history,
+            today,
+        );
+        assert_eq!(o.days_since_scouted, None);
+        assert!(!o.recent_overseed);
+        assert_eq!(o.severity(), Some(Severity::Advisory));
+    }
+}
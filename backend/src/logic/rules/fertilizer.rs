@@ -1,8 +1,9 @@
 use super::thresholds::*;
 use super::Rule;
+use crate::logic::stress_index;
 use crate::models::{
     Application, DataSource, EnvironmentalSummary, LawnProfile, Recommendation,
-    RecommendationCategory, Severity,
+    RecommendationCategory, Severity, StressLevel,
 };
 
 /// Fertilizer stress avoidance rule
@@ -13,6 +14,7 @@ use crate::models::{
 /// Block conditions:
 /// - Ambient temp >85°F
 /// - Soil moisture <0.10 (drought stress) or >0.40 (saturated)
+/// - Turf stress index High for several days running (see `logic::stress_index`)
 pub struct FertilizerRule;
 
 impl Rule for FertilizerRule {
@@ -73,12 +75,27 @@ impl Rule for FertilizerRule {
             }
         }
 
+        // Check sustained turf stress (heat, drought, disease and traffic combined)
+        let stress = stress_index::sustained(env);
+        if let Some(stress) = stress {
+            warnings.push(format!(
+                "Turf stress index has been High or worse for {} days (now {:.0}/100)",
+                stress.sustained_high_days, stress.score
+            ));
+            data_points.push((
+                "Turf Stress Index",
+                format!("{:.0} ({})", stress.score, stress.level),
+                DataSource::Calculated.as_str(),
+            ));
+        }
+
         if warnings.is_empty() {
             return None;
         }
 
         let severity = if ambient_temp > HEAT_STRESS_WARNING_TEMP_F
             || soil_moisture.is_some_and(|m| m < SOIL_MOISTURE_SEVERE_DROUGHT)
+            || stress.is_some_and(|s| s.level == StressLevel::Severe)
         {
             Severity::Critical
         } else {
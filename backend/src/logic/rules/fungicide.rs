@@ -1,6 +1,7 @@
 use super::disease_common::{
     add_frac_data_points, append_rotation_warning, build_rotation_guidance,
 };
+use super::posture::PostureInput;
 use super::thresholds::*;
 use super::Rule;
 use crate::models::{
@@ -169,4 +170,8 @@ impl Rule for FungicideRule {
 
         Some(add_frac_data_points(rec, &advice))
     }
+
+    fn posture_input(&self) -> Option<PostureInput> {
+        Some(PostureInput::Humidity)
+    }
 }
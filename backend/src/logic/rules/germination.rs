@@ -0,0 +1,452 @@
+use super::thresholds::*;
+use super::{current_date, Gate, Rule};
+use crate::models::{
+    Application, ApplicationType, DailyForecast, DataSource, EnvironmentalSummary, GrassType,
+    LawnProfile, Recommendation, RecommendationCategory, Severity, VolumetricWaterContent,
+};
+use chrono::{Duration, NaiveDate};
+use serde::Serialize;
+
+/// Germination tracker — daily countdown after an Overseed application
+///
+/// New seed has to stay moist until it germinates; a single dry afternoon in
+/// the first week can kill the sprouts (Penn State Extension, "Seeding Lawns").
+/// For the first 14 days after seeding this posts a day-by-day watering reminder:
+/// - Days 0-7: light watering 2-3 times a day, keep the top 1/2 inch damp
+/// - Days 8-14: once or twice a day, a little deeper as roots go down
+///
+/// Forecast rain of 5mm+ today stands in for one watering. A drying seedbed
+/// (soil moisture < 20%) escalates to Warning.
+pub struct GerminationTrackerRule;
+
+/// Seedling weather alert — forecast frost or heat while seedlings are young
+///
+/// For six weeks after seeding, seedlings have no root depth to ride out
+/// extremes. Warns when the next 3 days bring a low ≤32°F or a high ≥90°F;
+/// a hard freeze (≤28°F) is Critical.
+pub struct SeedlingWeatherRule;
+
+/// Where a lawn is in the weeks after its latest overseeding, for the
+/// Dashboard's germination card.
+#[derive(Debug, Clone, Serialize)]
+pub struct GerminationStatus {
+    pub seeded_on: NaiveDate,
+    /// Days since seeding (0 on seeding day).
+    pub day: i64,
+    /// Length of the watering countdown.
+    pub tracking_days: i64,
+    /// First day herbicide and pre-emergent recommendations come back.
+    pub herbicide_safe_on: NaiveDate,
+}
+
+/// Most recent Overseed on or before `today`.
+fn latest_seeding(history: &[Application], today: NaiveDate) -> Option<NaiveDate> {
+    history
+        .iter()
+        .filter(|a| a.application_type == ApplicationType::Overseed && a.application_date <= today)
+        .map(|a| a.application_date)
+        .max()
+}
+
+/// How long herbicides stay off after seeding. Ryegrass is mowable in about
+/// four weeks, fescues five, bluegrass needs the full six.
+pub fn herbicide_block_days(grass_type: GrassType) -> i64 {
+    match grass_type {
+        GrassType::PerennialRyegrass => SEEDLING_HERBICIDE_BLOCK_FAST_DAYS,
+        GrassType::TallFescue | GrassType::FineFescue => SEEDLING_HERBICIDE_BLOCK_DAYS,
+        _ => SEEDLING_HERBICIDE_BLOCK_SLOW_DAYS,
+    }
+}
+
+/// Germination status while the herbicide block is still running.
+pub fn germination_status(
+    profile: &LawnProfile,
+    history: &[Application],
+    today: NaiveDate,
+) -> Option<GerminationStatus> {
+    let seeded_on = latest_seeding(history, today)?;
+    let block = herbicide_block_days(profile.grass_type);
+    let day = (today - seeded_on).num_days();
+    (day < block).then(|| GerminationStatus {
+        seeded_on,
+        day,
+        tracking_days: GERMINATION_TRACK_DAYS,
+        herbicide_safe_on: seeded_on + Duration::days(block),
+    })
+}
+
+/// Drop herbicide and pre-emergent recommendations while new seedlings are
+/// establishing. Applied by the engine to every rule's output, so custom and
+/// scripted rules are covered too.
+pub fn suppress_for_seedlings(
+    recommendations: Vec<Recommendation>,
+    profile: &LawnProfile,
+    history: &[Application],
+    today: NaiveDate,
+) -> Vec<Recommendation> {
+    if germination_status(profile, history, today).is_none() {
+        return recommendations;
+    }
+    recommendations
+        .into_iter()
+        .filter(|r| !is_seedling_unsafe(r))
+        .collect()
+}
+
+/// Herbicides kill seedlings outright; most pre-emergents stop seed from rooting.
+pub fn is_seedling_unsafe(rec: &Recommendation) -> bool {
+    matches!(
+        rec.category,
+        RecommendationCategory::Herbicide | RecommendationCategory::PreEmergent
+    )
+}
+
+/// Forecast days from today through the lookahead window, by their own dates
+/// so replayed days line up with the pinned date.
+fn upcoming_days(env: &EnvironmentalSummary, today: NaiveDate) -> Vec<&DailyForecast> {
+    let end = today + Duration::days(SEEDLING_WEATHER_LOOKAHEAD_DAYS);
+    env.forecast
+        .as_ref()
+        .map(|f| {
+            f.daily_summary
+                .iter()
+                .filter(|d| d.date >= today && d.date < end)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+impl Rule for GerminationTrackerRule {
+    fn evaluate(
+        &self,
+        env: &EnvironmentalSummary,
+        profile: &LawnProfile,
+        history: &[Application],
+    ) -> Option<Recommendation> {
+        self.explain(env, profile, history).ok()
+    }
+
+    fn explain(
+        &self,
+        env: &EnvironmentalSummary,
+        _profile: &LawnProfile,
+        history: &[Application],
+    ) -> Result<Recommendation, Gate> {
+        let today = current_date();
+        let seeded_on = latest_seeding(history, today)
+            .ok_or_else(|| Gate::NotApplicable("No overseeding logged".into()))?;
+        let day = (today - seeded_on).num_days();
+        if day > GERMINATION_TRACK_DAYS {
+            return Err(Gate::OutOfSeason(format!(
+                "Seeded {} days ago; tracking runs {} days",
+                day, GERMINATION_TRACK_DAYS
+            )));
+        }
+
+        let moisture = env.current.as_ref().and_then(|c| c.primary_soil_moisture());
+        let drying = moisture.is_some_and(|m| m < GERMINATION_DRY_SEEDBED);
+        let rain_today_mm = upcoming_days(env, today)
+            .first()
+            .filter(|d| d.date == today)
+            .map(|d| d.total_precipitation_mm);
+
+        let germinating = day <= GERMINATION_LIGHT_WATERING_DAYS;
+        let mut action = if germinating {
+            "Water lightly 2-3 times today (about 5-10 minutes each) so the top 1/2 inch \
+             never dries out. Avoid heavy watering that washes seed away."
+                .to_string()
+        } else {
+            "Water once or twice today, a little longer each cycle, to follow the roots \
+             down. Stay off the area as much as possible."
+                .to_string()
+        };
+        if let Some(rain) = rain_today_mm.filter(|r| *r >= GERMINATION_RAIN_SKIP_MM) {
+            action.push_str(&format!(
+                " Rain is forecast today ({:.1}mm), so skip one watering cycle.",
+                rain
+            ));
+        }
+
+        let severity = if drying {
+            Severity::Warning
+        } else {
+            Severity::Advisory
+        };
+        let title = if drying {
+            format!("Seedbed Drying Out (Day {})", day)
+        } else {
+            format!("Germination Day {} of {}", day, GERMINATION_TRACK_DAYS)
+        };
+        let description = if germinating {
+            format!(
+                "Seed went down {}. Until it germinates the seedbed has to stay \
+                 consistently moist.",
+                seeded_on
+            )
+        } else {
+            format!(
+                "Seed went down {}. Seedlings are emerging; shift to fewer, deeper \
+                 waterings through day {}.",
+                seeded_on, GERMINATION_TRACK_DAYS
+            )
+        };
+
+        let mut rec = Recommendation::new(
+            format!("germination_{}", seeded_on),
+            RecommendationCategory::Overseeding,
+            severity,
+            title,
+            description,
+        )
+        .with_explanation(
+            "Grass seed takes 5-21 days to germinate depending on species, and a seed \
+             that has started to sprout dies if it dries out even once. Light, frequent \
+             watering keeps the surface damp without puddling or washing seed. Once \
+             seedlings are up, gradually longer and less frequent watering pushes roots \
+             deeper before winter or summer stress.",
+        )
+        .with_data_point("Seeded", seeded_on, DataSource::History.as_str())
+        .with_data_point(
+            "Day",
+            format!("{} of {}", day, GERMINATION_TRACK_DAYS),
+            DataSource::Calculated.as_str(),
+        );
+
+        if let Some(m) = moisture {
+            rec = rec.with_data_point(
+                "Soil Moisture",
+                format!("{:.0}", VolumetricWaterContent(m)),
+                DataSource::SoilData.as_str(),
+            );
+        }
+        if let Some(rain) = rain_today_mm {
+            rec = rec.with_data_point(
+                "Rain Today",
+                format!("{:.1}mm", rain),
+                DataSource::OpenWeatherMap.as_str(),
+            );
+        }
+
+        Ok(rec.with_action(action))
+    }
+}
+
+impl Rule for SeedlingWeatherRule {
+    fn evaluate(
+        &self,
+        env: &EnvironmentalSummary,
+        profile: &LawnProfile,
+        history: &[Application],
+    ) -> Option<Recommendation> {
+        self.explain(env, profile, history).ok()
+    }
+
+    fn explain(
+        &self,
+        env: &EnvironmentalSummary,
+        _profile: &LawnProfile,
+        history: &[Application],
+    ) -> Result<Recommendation, Gate> {
+        let today = current_date();
+        let seeded_on = latest_seeding(history, today)
+            .ok_or_else(|| Gate::NotApplicable("No overseeding logged".into()))?;
+        let day = (today - seeded_on).num_days();
+        if day > SEEDLING_PROTECTION_DAYS {
+            return Err(Gate::OutOfSeason(format!(
+                "Seeded {} days ago; seedlings are established after {} days",
+                day, SEEDLING_PROTECTION_DAYS
+            )));
+        }
+
+        if env.forecast.is_none() {
+            return Err(Gate::MissingData("Weather forecast".into()));
+        }
+        let days = upcoming_days(env, today);
+        let coldest = days
+            .iter()
+            .min_by(|a, b| a.low_temp_f.total_cmp(&b.low_temp_f))
+            .ok_or_else(|| Gate::MissingData("Forecast for the next 3 days".into()))?;
+        let hottest = days
+            .iter()
+            .max_by(|a, b| a.high_temp_f.total_cmp(&b.high_temp_f))
+            .ok_or_else(|| Gate::MissingData("Forecast for the next 3 days".into()))?;
+
+        let frost = coldest.low_temp_f <= SEEDLING_FROST_LOW_F;
+        let heat = hottest.high_temp_f >= SEEDLING_HEAT_HIGH_F;
+        if !frost && !heat {
+            return Err(Gate::ConditionsNotMet(format!(
+                "Forecast {:.0}-{:.0}°F is safe for seedlings",
+                coldest.low_temp_f, hottest.high_temp_f
+            )));
+        }
+
+        let severity = if coldest.low_temp_f <= SEEDLING_HARD_FREEZE_LOW_F {
+            Severity::Critical
+        } else {
+            Severity::Warning
+        };
+
+        let (title, description, action) = if frost {
+            (
+                "Frost Threatens New Seedlings",
+                format!(
+                    "A low of {:.0}°F is forecast for {}, {} days after seeding.",
+                    coldest.low_temp_f, coldest.date, day
+                ),
+                "Water lightly in the afternoon before the cold night — moist soil holds \
+                 heat better. Keep off frosted seedlings until they thaw. If a hard \
+                 freeze hits before germination, expect to reseed thin spots.",
+            )
+        } else {
+            (
+                "Heat Threatens New Seedlings",
+                format!(
+                    "A high of {:.0}°F is forecast for {}, {} days after seeding.",
+                    hottest.high_temp_f, hottest.date, day
+                ),
+                "Add a short midday watering to cool the surface and keep seedlings \
+                 from wilting. Don't mow or walk on the area until the heat breaks.",
+            )
+        };
+
+        Ok(Recommendation::new(
+            format!("seedling_weather_{}", seeded_on),
+            RecommendationCategory::Overseeding,
+            severity,
+            title,
+            description,
+        )
+        .with_explanation(
+            "Seedlings under six weeks old have roots only an inch or two deep, so they \
+             can't draw on deeper soil moisture in heat or recover from frost-damaged \
+             crowns the way mature turf does.",
+        )
+        .with_data_point("Seeded", seeded_on, DataSource::History.as_str())
+        .with_data_point(
+            "Forecast Low",
+            format!("{:.0}°F", coldest.low_temp_f),
+            DataSource::OpenWeatherMap.as_str(),
+        )
+        .with_data_point(
+            "Forecast High",
+            format!("{:.0}°F", hottest.high_temp_f),
+            DataSource::OpenWeatherMap.as_str(),
+        )
+        .with_action(action))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::with_current_date;
+    use super::*;
+    use crate::testing::{application, at, date, profile, EnvBuilder, ForecastBuilder};
+
+    fn seeded(on: NaiveDate) -> Vec<Application> {
+        vec![application(ApplicationType::Overseed, on)]
+    }
+
+    #[test]
+    fn tracker_counts_down_the_first_two_weeks() {
+        let env = EnvBuilder::new(at(2025, 9, 15)).soil_moisture(0.30).build();
+        let history = seeded(date(2025, 9, 10));
+        let rec = with_current_date(date(2025, 9, 15), || {
+            GerminationTrackerRule.evaluate(&env, &profile(GrassType::TallFescue), &history)
+        })
+        .unwrap();
+        assert_eq!(rec.id, "germination_2025-09-10");
+        assert_eq!(rec.title, "Germination Day 5 of 14");
+        assert_eq!(rec.severity, Severity::Advisory);
+
+        let after = with_current_date(date(2025, 9, 25), || {
+            GerminationTrackerRule.explain(&env, &profile(GrassType::TallFescue), &history)
+        });
+        assert!(matches!(after, Err(Gate::OutOfSeason(_))));
+    }
+
+    #[test]
+    fn dry_seedbed_escalates_and_rain_skips_a_cycle() {
+        let env = EnvBuilder::new(at(2025, 9, 12))
+            .soil_moisture(0.12)
+            .forecast(
+                ForecastBuilder::starting(date(2025, 9, 12))
+                    .day(75.0, 55.0, 70.0, 8.0)
+                    .build(),
+            )
+            .build();
+        let rec = with_current_date(date(2025, 9, 12), || {
+            GerminationTrackerRule.evaluate(
+                &env,
+                &profile(GrassType::TallFescue),
+                &seeded(date(2025, 9, 10)),
+            )
+        })
+        .unwrap();
+        assert_eq!(rec.severity, Severity::Warning);
+        assert!(rec.suggested_action.unwrap().contains("skip one watering"));
+    }
+
+    #[test]
+    fn seedling_weather_flags_frost_and_hard_freeze() {
+        let forecast = |low: f64| {
+            EnvBuilder::new(at(2025, 10, 20))
+                .forecast(
+                    ForecastBuilder::starting(date(2025, 10, 20))
+                        .day(60.0, 45.0, 60.0, 0.0)
+                        .day(55.0, low, 60.0, 0.0)
+                        .build(),
+                )
+                .build()
+        };
+        let history = seeded(date(2025, 10, 1));
+        let run = |low: f64| {
+            with_current_date(date(2025, 10, 20), || {
+                SeedlingWeatherRule.evaluate(
+                    &forecast(low),
+                    &profile(GrassType::TallFescue),
+                    &history,
+                )
+            })
+        };
+
+        assert!(run(40.0).is_none());
+        assert_eq!(run(31.0).unwrap().severity, Severity::Warning);
+        assert_eq!(run(26.0).unwrap().severity, Severity::Critical);
+    }
+
+    #[test]
+    fn herbicides_suppressed_until_block_ends() {
+        let recs = || {
+            vec![
+                Recommendation::new(
+                    "broadleaf_fall",
+                    RecommendationCategory::Herbicide,
+                    Severity::Advisory,
+                    "t",
+                    "d",
+                ),
+                Recommendation::new(
+                    "mowing",
+                    RecommendationCategory::Mowing,
+                    Severity::Info,
+                    "t",
+                    "d",
+                ),
+            ]
+        };
+        let ryegrass = profile(GrassType::PerennialRyegrass);
+        let history = seeded(date(2025, 9, 1));
+
+        let during = suppress_for_seedlings(recs(), &ryegrass, &history, date(2025, 9, 28));
+        assert_eq!(during.len(), 1);
+        assert_eq!(during[0].id, "mowing");
+
+        let after = suppress_for_seedlings(recs(), &ryegrass, &history, date(2025, 9, 29));
+        assert_eq!(after.len(), 2);
+
+        // Bluegrass gets the full six weeks
+        let kbg = profile(GrassType::KentuckyBluegrass);
+        let status = germination_status(&kbg, &history, date(2025, 9, 29)).unwrap();
+        assert_eq!(status.herbicide_safe_on, date(2025, 10, 13));
+    }
+}
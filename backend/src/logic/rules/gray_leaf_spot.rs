@@ -1,11 +1,13 @@
 use super::disease_common::gray_leaf_spot_fungicide_rec;
+use super::posture::PostureInput;
 use super::thresholds::*;
-use super::Rule;
+use super::{current_date, Rule};
+use crate::logic::calculations;
 use crate::models::{
     analyze_fungicide_rotation, Application, ApplicationType, DataSource, EnvironmentalSummary,
     LawnProfile, Recommendation, RecommendationCategory, Severity,
 };
-use chrono::{Datelike, Local, NaiveDate};
+use chrono::{Datelike, NaiveDate};
 
 /// Gray Leaf Spot rule (Pyricularia grisea / Magnaporthe oryzae)
 ///
@@ -13,7 +15,8 @@ use chrono::{Datelike, Local, NaiveDate};
 ///
 /// Conditions (NC State Extension):
 /// - Window: July 1 - September 30
-/// - Triggers: Ambient 70-95°F + humidity >85% sustained (14+ hrs leaf wetness proxy)
+/// - Triggers: Ambient 70-95°F + humidity >85%, or 14+ hrs of forecast leaf wetness
+///   (dew point spread < 3°F)
 /// - Risk amplifier: Recent overseeding (within 60 days) — newly established turf is
 ///   extremely susceptible
 /// - Excessive nitrogen increases susceptibility
@@ -35,7 +38,7 @@ impl Rule for GrayLeafSpotRule {
             return None;
         }
 
-        let today = Local::now().date_naive();
+        let today = current_date();
         let current_year = today.year();
 
         // Window: July 1 - September 30
@@ -57,8 +60,10 @@ impl Rule for GrayLeafSpotRule {
             return None;
         }
 
-        // Check humidity threshold (>85%)
-        if humidity <= HUMIDITY_HIGH_DISEASE {
+        // Check humidity threshold (>85%), or 14+ hrs of forecast leaf wetness
+        let leaf_wetness =
+            calculations::leaf_wetness_hours(&forecast.next_hours(LEAF_WETNESS_WINDOW_HOURS));
+        if humidity <= HUMIDITY_HIGH_DISEASE && leaf_wetness < LEAF_WETNESS_SEVERE_HOURS {
             return None;
         }
 
@@ -134,6 +139,11 @@ impl Rule for GrayLeafSpotRule {
             "Favorable Days",
             format!("{}", favorable_days),
             DataSource::OpenWeatherMap.as_str(),
+        )
+        .with_data_point(
+            "Leaf Wetness (24h)",
+            format!("{:.0} hrs", leaf_wetness),
+            DataSource::Calculated.as_str(),
         );
 
         // FRAC-aware product recommendation for gray leaf spot
@@ -166,4 +176,8 @@ impl Rule for GrayLeafSpotRule {
 
         Some(rec)
     }
+
+    fn posture_input(&self) -> Option<PostureInput> {
+        Some(PostureInput::Humidity)
+    }
 }
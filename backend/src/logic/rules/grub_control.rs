@@ -1,10 +1,13 @@
+use super::posture::PostureInput;
 use super::thresholds::*;
-use super::Rule;
+use super::window_projection::SoilWindow;
+use super::{covered_soil_temp_avg, current_date, Rule, WindowProjection};
+use crate::models::soil_temp_prediction::CrossingDirection;
 use crate::models::{
     Application, ApplicationType, DataSource, EnvironmentalSummary, LawnProfile, Recommendation,
     RecommendationCategory, Severity,
 };
-use chrono::{Datelike, Local, NaiveDate};
+use chrono::{Datelike, NaiveDate};
 
 /// Grub control timing rule
 ///
@@ -23,7 +26,7 @@ impl Rule for GrubControlRule {
         _profile: &LawnProfile,
         history: &[Application],
     ) -> Option<Recommendation> {
-        let today = Local::now().date_naive();
+        let today = current_date();
         let current_year = today.year();
 
         // Define the calendar application window
@@ -31,10 +34,13 @@ impl Rule for GrubControlRule {
         let window_end = NaiveDate::from_ymd_opt(current_year, 7, 4)?;
         let gdd_early_start = NaiveDate::from_ymd_opt(current_year, 4, 1)?;
 
-        // GDD-based early window: GDD >= 500 opens as early as Apr 1
+        // GDD-based early window: GDD >= 500 opens as early as Apr 1. It still
+        // closes with the calendar window; GDD only climbs from there.
         let gdd_ytd = env.gdd_base50_ytd;
         let gdd_opens_window = gdd_ytd
-            .map(|gdd| gdd >= GRUB_GDD_WINDOW_OPEN && today >= gdd_early_start)
+            .map(|gdd| {
+                gdd >= GRUB_GDD_WINDOW_OPEN && today >= gdd_early_start && today <= window_end
+            })
             .unwrap_or(false);
 
         // Only relevant during the window (GDD-extended or calendar)
@@ -56,7 +62,7 @@ impl Rule for GrubControlRule {
         }
 
         // Get soil temperature
-        let soil_temp_avg = env.soil_temp_7day_avg_f?;
+        let soil_temp_avg = covered_soil_temp_avg(env).ok()?;
         let current_soil_temp = env.current.as_ref()?.soil_temp_10_f?;
 
         // GDD urgency levels
@@ -178,6 +184,39 @@ impl Rule for GrubControlRule {
             None
         }
     }
+
+    fn project_window(
+        &self,
+        env: &EnvironmentalSummary,
+        _profile: &LawnProfile,
+        history: &[Application],
+        today: NaiveDate,
+    ) -> Option<WindowProjection> {
+        let year = today.year();
+        let season_start = NaiveDate::from_ymd_opt(year, 4, 1)?;
+        let already_applied = history.iter().any(|app| {
+            (app.application_type == ApplicationType::GrubControl
+                || app.application_type == ApplicationType::Insecticide)
+                && app.application_date >= season_start
+        });
+        if already_applied {
+            return None;
+        }
+
+        SoilWindow {
+            name: "Grub Control",
+            open_f: GRUB_CONTROL_SOIL_LOW_F,
+            close_f: GRUB_CONTROL_SOIL_HIGH_F,
+            direction: CrossingDirection::Rising,
+            season_start,
+            season_end: NaiveDate::from_ymd_opt(year, 7, 4)?,
+        }
+        .project(env, today)
+    }
+
+    fn posture_input(&self) -> Option<PostureInput> {
+        Some(PostureInput::RisingSoilTemp)
+    }
 }
 
 #[cfg(test)]
@@ -204,6 +243,10 @@ mod tests {
             soil_type: None,
             lawn_size_sqft: Some(5000.0),
             irrigation_type: None,
+            location: None,
+            noaa_station_wbanno: None,
+            dormant: false,
+            mowing_height_in: None,
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
         }
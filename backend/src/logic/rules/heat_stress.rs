@@ -1,23 +1,115 @@
 use super::thresholds::*;
 use super::Rule;
+use crate::logic::calculations::{heat_index_f, wet_bulb_f};
 use crate::models::{
-    Application, DataSource, EnvironmentalSummary, LawnProfile, Recommendation,
-    RecommendationCategory, Severity,
+    Application, DataSource, DegreesF, EnvironmentalSummary, LawnProfile, Recommendation,
+    RecommendationCategory, Severity, WeatherForecast,
 };
+use chrono::NaiveDate;
 
 /// Heat stress warning rule - warns about upcoming heat stress conditions
 ///
-/// Cool-season grasses (TTTF, KBG, PRG) struggle when temps exceed 85°F.
+/// Cool-season grasses (TTTF, KBG, PRG) struggle when temps exceed 85°F, and
+/// humid heat is worse than dry heat. Warm nights are the real killer: with lows
+/// above 70°F the plant never gets to recover from the day's respiration load.
 ///
 /// Conditions:
-/// - Max temp >85°F forecasted in next 3 days
+/// - Peak heat (higher of max temp and max heat index) >85°F in next 3 days, or
+/// - 2+ consecutive nights with lows ≥70°F
 ///
 /// Severity levels:
-/// - Advisory: 85-90°F expected
-/// - Warning: 90-95°F expected
-/// - Critical: >95°F expected
+/// - Advisory: 85-90°F peak heat, or warm nights alone
+/// - Warning: 90-95°F peak heat
+/// - Critical: >95°F peak heat
+/// - 3+ consecutive warm nights escalate one level
+/// - Info: nothing near-term, but 3+ days ≥90°F start within the next 8 days
 pub struct HeatStressRule;
 
+/// Forecast heat load driving the rule.
+struct HeatOutlook {
+    max_temp: f64,
+    max_heat_index: f64,
+    max_wet_bulb: Option<f64>,
+    hot_days: usize,
+    warm_nights: u32,
+    /// First day of a coming run of [`HEAT_WAVE_MIN_DAYS`] days at or above
+    /// [`HEAT_STRESS_WARNING_TEMP_F`], and how long it lasts.
+    heat_wave: Option<(NaiveDate, usize)>,
+}
+
+impl HeatOutlook {
+    fn from_forecast(forecast: &WeatherForecast) -> Option<Self> {
+        let max_temp = forecast.max_temp_next_days(3)?;
+        let points = forecast.next_hours(HEAT_STRESS_WINDOW_HOURS);
+        let max_heat_index = points
+            .iter()
+            .map(|p| heat_index_f(DegreesF(p.temp_f), p.humidity_percent).0)
+            .reduce(f64::max)
+            .unwrap_or(max_temp);
+        let max_wet_bulb = points
+            .iter()
+            .map(|p| wet_bulb_f(DegreesF(p.temp_f), p.humidity_percent).0)
+            .reduce(f64::max);
+
+        Some(Self {
+            max_temp,
+            max_heat_index,
+            max_wet_bulb,
+            hot_days: forecast
+                .next_days(5)
+                .iter()
+                .take_while(|d| d.high_temp_f >= HEAT_STRESS_TEMP_F)
+                .count(),
+            warm_nights: forecast.consecutive_warm_nights(WARM_NIGHT_LOW_F),
+            heat_wave: heat_wave(forecast),
+        })
+    }
+
+    /// Air temp or heat index, whichever is higher. Dry heat still stresses turf
+    /// even when the heat index reads below the thermometer.
+    fn peak_heat(&self) -> f64 {
+        self.max_temp.max(self.max_heat_index)
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        let peak = self.peak_heat();
+        let base = if peak >= HEAT_STRESS_CRITICAL_TEMP_F {
+            Severity::Critical
+        } else if peak >= HEAT_STRESS_WARNING_TEMP_F {
+            Severity::Warning
+        } else if peak >= HEAT_STRESS_TEMP_F || self.warm_nights >= WARM_NIGHTS_ALERT_COUNT {
+            Severity::Advisory
+        } else {
+            return None;
+        };
+
+        Some(if self.warm_nights >= WARM_NIGHTS_ESCALATE_COUNT {
+            match base {
+                Severity::Info | Severity::Advisory => Severity::Warning,
+                _ => Severity::Critical,
+            }
+        } else {
+            base
+        })
+    }
+}
+
+fn heat_wave(forecast: &WeatherForecast) -> Option<(NaiveDate, usize)> {
+    let days = forecast.next_days(HEAT_WAVE_HORIZON_DAYS);
+    let mut run: Option<(NaiveDate, usize)> = None;
+    for day in days {
+        if day.high_temp_f >= HEAT_STRESS_WARNING_TEMP_F {
+            let (start, len) = run.unwrap_or((day.date, 0));
+            run = Some((start, len + 1));
+        } else if run.is_some_and(|(_, len)| len >= HEAT_WAVE_MIN_DAYS) {
+            break;
+        } else {
+            run = None;
+        }
+    }
+    run.filter(|(_, len)| *len >= HEAT_WAVE_MIN_DAYS)
+}
+
 impl Rule for HeatStressRule {
     fn evaluate(
         &self,
@@ -31,54 +123,40 @@ impl Rule for HeatStressRule {
         }
 
         let forecast = env.forecast.as_ref()?;
+        let outlook = HeatOutlook::from_forecast(forecast)?;
 
-        // Find max temp in next 3 days
-        let max_temp = forecast.max_temp_next_days(3)?;
-
-        // No warning if temps are mild
-        if max_temp < HEAT_STRESS_TEMP_F {
-            return None;
+        // No warning if days are mild and nights cool off
+        match outlook.severity() {
+            Some(severity) => Some(self.build_recommendation(severity, &outlook)),
+            None => outlook
+                .heat_wave
+                .map(|(start, days)| heat_wave_ahead(start, days)),
         }
-
-        // Count consecutive hot days
-        let hot_days: usize = forecast
-            .next_days(5)
-            .iter()
-            .take_while(|d| d.high_temp_f >= HEAT_STRESS_TEMP_F)
-            .count();
-
-        let severity = if max_temp >= HEAT_STRESS_CRITICAL_TEMP_F {
-            Severity::Critical
-        } else if max_temp >= HEAT_STRESS_WARNING_TEMP_F {
-            Severity::Warning
-        } else {
-            Severity::Advisory
-        };
-
-        Some(self.build_recommendation(severity, max_temp, hot_days))
     }
 }
 
 impl HeatStressRule {
-    fn build_recommendation(
-        &self,
-        severity: Severity,
-        max_temp: f64,
-        hot_days: usize,
-    ) -> Recommendation {
+    fn build_recommendation(&self, severity: Severity, outlook: &HeatOutlook) -> Recommendation {
         let title = match severity {
             Severity::Critical => "Extreme Heat Stress Expected",
             Severity::Warning => "Heat Stress Warning",
             _ => "Warm Weather Ahead",
         };
 
-        let description = format!(
-            "Temperatures up to {:.0}°F expected over the next {} days. \
+        let mut description = format!(
+            "Temperatures up to {:.0}°F (feels like {:.0}°F) expected over the next {} days. \
              Cool-season grasses experience stress above {:.0}°F.",
-            max_temp,
-            hot_days.max(1),
+            outlook.max_temp,
+            outlook.peak_heat(),
+            outlook.hot_days.max(1),
             HEAT_STRESS_TEMP_F
         );
+        if outlook.warm_nights >= WARM_NIGHTS_ALERT_COUNT {
+            description.push_str(&format!(
+                " {} consecutive nights won't drop below {:.0}°F, so turf gets no overnight recovery.",
+                outlook.warm_nights, WARM_NIGHT_LOW_F
+            ));
+        }
 
         let action = match severity {
             Severity::Critical => {
@@ -101,7 +179,7 @@ impl HeatStressRule {
             }
         };
 
-        Recommendation::new(
+        let mut rec = Recommendation::new(
             "heat_stress_forecast",
             RecommendationCategory::HeatStress,
             severity,
@@ -111,24 +189,141 @@ impl HeatStressRule {
         .with_explanation(format!(
             "Tall Fescue and other cool-season grasses evolved for temperatures between \
              {:.0}-{:.0}°F. Above {:.0}°F, photosynthesis slows and root growth stops. Above {:.0}°F, \
-             the grass may enter summer dormancy. Fertilizing during heat stress forces \
+             the grass may enter summer dormancy. Humidity compounds the load by limiting \
+             evaporative cooling, and nights above {:.0}°F keep respiration burning \
+             carbohydrates with no chance to recover. Fertilizing during heat stress forces \
              top growth at the expense of roots, weakening the plant. Taller grass shades \
              the crown and soil, reducing heat stress.",
             COOL_SEASON_IDEAL_LOW_F,
             COOL_SEASON_IDEAL_HIGH_F,
             HEAT_STRESS_TEMP_F,
-            HEAT_STRESS_WARNING_TEMP_F
+            HEAT_STRESS_WARNING_TEMP_F,
+            WARM_NIGHT_LOW_F
         ))
         .with_data_point(
             "Max Forecast Temp",
-            format!("{:.0}°F", max_temp),
+            format!("{:.0}°F", outlook.max_temp),
             DataSource::OpenWeatherMap.as_str(),
         )
+        .with_data_point(
+            "Max Heat Index",
+            format!("{:.0}°F", outlook.max_heat_index),
+            DataSource::Calculated.as_str(),
+        )
         .with_data_point(
             "Hot Days",
-            format!("{}", hot_days),
+            format!("{}", outlook.hot_days),
             DataSource::OpenWeatherMap.as_str(),
         )
-        .with_action(action)
+        .with_data_point(
+            "Warm Nights (≥70°F)",
+            format!("{}", outlook.warm_nights),
+            DataSource::OpenWeatherMap.as_str(),
+        );
+
+        if let Some(wet_bulb) = outlook.max_wet_bulb {
+            rec = rec.with_data_point(
+                "Max Wet-Bulb",
+                format!("{:.0}°F", wet_bulb),
+                DataSource::Calculated.as_str(),
+            );
+        }
+
+        rec.with_action(action)
+    }
+}
+
+/// Early notice for a heat wave beyond the 3-day window, while there's
+/// still time to finish feeding and mowing low before it arrives.
+fn heat_wave_ahead(start: NaiveDate, days: usize) -> Recommendation {
+    Recommendation::new(
+        "heat_wave_ahead",
+        RecommendationCategory::HeatStress,
+        Severity::Info,
+        "Heat Wave Ahead",
+        format!(
+            "{} days at or above {:.0}°F forecast starting {}.",
+            days,
+            HEAT_STRESS_WARNING_TEMP_F,
+            start.format("%a %b %-d")
+        ),
+    )
+    .with_explanation(
+        "Cool-season turf handles a heat wave best when it goes in well watered, \
+         unfertilized and mown tall. Changes made once the heat arrives add stress \
+         instead of relieving it.",
+    )
+    .with_data_point(
+        "Heat Wave Start",
+        start.format("%Y-%m-%d").to_string(),
+        DataSource::OpenWeatherMap.as_str(),
+    )
+    .with_data_point(
+        "Days ≥90°F",
+        format!("{}", days),
+        DataSource::OpenWeatherMap.as_str(),
+    )
+    .with_action(
+        "Finish any fertilizer applications now and start raising mowing height \
+         gradually so it reaches 3.5-4 inches before the heat arrives.",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock;
+    use crate::models::GrassType;
+    use crate::testing::{at, date, profile, EnvBuilder, ForecastBuilder};
+
+    fn outlook(max_temp: f64, max_heat_index: f64, warm_nights: u32) -> HeatOutlook {
+        HeatOutlook {
+            max_temp,
+            max_heat_index,
+            max_wet_bulb: None,
+            hot_days: 1,
+            warm_nights,
+            heat_wave: None,
+        }
+    }
+
+    #[test]
+    fn mild_days_and_cool_nights_are_silent() {
+        assert_eq!(outlook(82.0, 83.0, 0).severity(), None);
+        assert_eq!(outlook(82.0, 83.0, 1).severity(), None);
+    }
+
+    #[test]
+    fn heat_index_drives_severity_above_air_temp() {
+        // 88°F on the thermometer but 96°F heat index is critical
+        assert_eq!(outlook(88.0, 96.0, 0).severity(), Some(Severity::Critical));
+        // Dry heat: air temp still counts when heat index reads lower
+        assert_eq!(outlook(91.0, 89.0, 0).severity(), Some(Severity::Warning));
+    }
+
+    #[test]
+    fn warm_nights_alert_and_escalate() {
+        assert_eq!(outlook(80.0, 80.0, 2).severity(), Some(Severity::Advisory));
+        assert_eq!(outlook(86.0, 86.0, 3).severity(), Some(Severity::Warning));
+        assert_eq!(outlook(92.0, 92.0, 3).severity(), Some(Severity::Critical));
+    }
+
+    #[test]
+    fn heat_wave_beyond_three_days_is_flagged_early() {
+        let mut forecast = ForecastBuilder::starting(date(2026, 7, 1));
+        for high in [80.0, 81.0, 80.0, 82.0, 92.0, 94.0, 93.0, 84.0] {
+            forecast = forecast.day(high, 62.0, 45.0, 0.0);
+        }
+        let env = EnvBuilder::new(at(2026, 7, 1))
+            .forecast(forecast.build())
+            .build();
+
+        let rec = clock::at(at(2026, 7, 1), || {
+            HeatStressRule.evaluate(&env, &profile(GrassType::TallFescue), &[])
+        })
+        .expect("heat wave ahead");
+        assert_eq!(rec.severity, Severity::Info);
+        assert!(rec.description.starts_with("3 days at or above 90°F"));
+        assert!(rec.description.contains("Sun Jul 5"));
     }
 }
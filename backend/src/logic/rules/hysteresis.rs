@@ -0,0 +1,159 @@
+//! Hysteresis for threshold-driven alerts.
+//!
+//! Soil moisture hovering around a threshold makes a naive rule flap on and off
+//! between refreshes. A rule opts in by returning a [`Hysteresis`] spec from
+//! [`Rule::moisture_hysteresis`](super::Rule::moisture_hysteresis); the engine
+//! then tracks a latch per lawn and rule so the alert only turns on past `trigger`, only
+//! turns off once the value recovers past `clear`, and never clears sooner than
+//! `min_hold` after it turned on.
+
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// A latch that alerts on low values (e.g. dry soil).
+#[derive(Debug, Clone, Copy)]
+pub struct Hysteresis {
+    pub trigger: f64,
+    pub clear: f64,
+    pub min_hold: Duration,
+}
+
+impl Hysteresis {
+    fn crosses_trigger(&self, value: f64) -> bool {
+        value < self.trigger
+    }
+
+    fn past_clear(&self, value: f64) -> bool {
+        value >= self.clear
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LatchState {
+    pub active: bool,
+    pub changed_at: DateTime<Utc>,
+}
+
+/// Compute the next latch state for a new reading.
+pub fn next_state(
+    spec: &Hysteresis,
+    prev: Option<LatchState>,
+    value: f64,
+    now: DateTime<Utc>,
+) -> LatchState {
+    match prev {
+        Some(state) if state.active => {
+            let held_long_enough = now - state.changed_at >= spec.min_hold;
+            if spec.past_clear(value) && held_long_enough {
+                LatchState {
+                    active: false,
+                    changed_at: now,
+                }
+            } else {
+                state
+            }
+        }
+        _ if spec.crosses_trigger(value) => LatchState {
+            active: true,
+            changed_at: now,
+        },
+        Some(state) => state,
+        None => LatchState {
+            active: false,
+            changed_at: now,
+        },
+    }
+}
+
+/// Identifies one latch: the lawn profile id and the rule name. Keying by name
+/// keeps latches stable when custom or script rules are added to the engine.
+pub type LatchKey = (Option<i64>, String);
+
+/// Per-lawn, per-rule latch storage shared across requests.
+#[derive(Debug, Default)]
+pub struct HysteresisTracker {
+    states: Mutex<HashMap<LatchKey, LatchState>>,
+}
+
+impl HysteresisTracker {
+    /// Feed a reading for the latch at `key` and return whether its alert is latched on.
+    pub fn update(&self, key: LatchKey, spec: &Hysteresis, value: f64, now: DateTime<Utc>) -> bool {
+        let mut states = self.states.lock().unwrap_or_else(|e| e.into_inner());
+        let state = next_state(spec, states.get(&key).copied(), value, now);
+        states.insert(key, state);
+        state.active
+    }
+
+    /// An independent copy of the current latches, for read-only callers that
+    /// must not move the live state.
+    pub fn snapshot(&self) -> Self {
+        let states = self.states.lock().unwrap_or_else(|e| e.into_inner());
+        Self {
+            states: Mutex::new(states.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dry_spec() -> Hysteresis {
+        Hysteresis {
+            trigger: 0.20,
+            clear: 0.23,
+            min_hold: Duration::hours(12),
+        }
+    }
+
+    #[test]
+    fn inactive_until_trigger_crossed() {
+        let now = Utc::now();
+        let state = next_state(&dry_spec(), None, 0.21, now);
+        assert!(!state.active);
+
+        let state = next_state(&dry_spec(), Some(state), 0.19, now);
+        assert!(state.active);
+    }
+
+    #[test]
+    fn stays_active_inside_band() {
+        let t0 = Utc::now();
+        let on = next_state(&dry_spec(), None, 0.18, t0);
+        // Hovering between trigger and clear a day later keeps the alert on
+        let later = next_state(&dry_spec(), Some(on), 0.215, t0 + Duration::hours(24));
+        assert!(later.active);
+        assert_eq!(later.changed_at, t0);
+    }
+
+    #[test]
+    fn clears_past_clear_threshold_after_hold() {
+        let t0 = Utc::now();
+        let on = next_state(&dry_spec(), None, 0.18, t0);
+        let off = next_state(&dry_spec(), Some(on), 0.25, t0 + Duration::hours(13));
+        assert!(!off.active);
+    }
+
+    #[test]
+    fn min_hold_prevents_early_clear() {
+        let t0 = Utc::now();
+        let on = next_state(&dry_spec(), None, 0.18, t0);
+        // A brief spike (e.g. a passing shower) within the hold window is ignored
+        let still_on = next_state(&dry_spec(), Some(on), 0.30, t0 + Duration::hours(2));
+        assert!(still_on.active);
+    }
+
+    #[test]
+    fn tracker_keeps_rules_independent() {
+        let tracker = HysteresisTracker::default();
+        let now = Utc::now();
+        let a = || (Some(1), "RuleA".to_string());
+        let b = || (Some(1), "RuleB".to_string());
+        assert!(tracker.update(a(), &dry_spec(), 0.18, now));
+        assert!(!tracker.update(b(), &dry_spec(), 0.21, now));
+        // Rule A remains latched while rule B stays off in the same band
+        assert!(tracker.update(a(), &dry_spec(), 0.21, now));
+        assert!(!tracker.update(b(), &dry_spec(), 0.21, now));
+    }
+}
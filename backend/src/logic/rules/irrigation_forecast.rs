@@ -1,20 +1,31 @@
+use super::hysteresis::Hysteresis;
 use super::thresholds::*;
-use super::Rule;
+use super::{Gate, Rule};
 use crate::models::{
-    Application, DataSource, EnvironmentalSummary, LawnProfile, Recommendation,
-    RecommendationCategory, Severity,
+    Application, DataSource, DroughtCategory, EnvironmentalSummary, LawnProfile, Recommendation,
+    RecommendationCategory, Severity, SoilWaterLimits, VolumetricWaterContent,
 };
 
 /// Irrigation forecast rule - recommends irrigation based on forecast drought
 ///
 /// Conditions:
 /// - No significant rain (<0.1") forecasted for next 5 days
-/// - Current soil moisture below threshold
+/// - Plant-available water below threshold
+///
+/// Plant-available water (PAW) is the share of the water between wilting point
+/// and field capacity still in the soil, using the profile's soil texture
+/// (Loam when unset), so 18% moisture reads as wet sand but dry clay.
 ///
 /// Severity levels:
-/// - Advisory: No rain 5 days, moisture 0.15-0.20
-/// - Warning: No rain 5 days, moisture 0.10-0.15
-/// - Critical: No rain 5 days, moisture < 0.10
+/// - Advisory: No rain 5 days, PAW 35-50%
+/// - Warning: No rain 5 days, PAW 20-35%
+/// - Critical: No rain 5 days, PAW < 20%
+///
+/// An official drought (U.S. Drought Monitor D1+) is shown alongside, and from
+/// D2 the action reminds to check local watering restrictions.
+///
+/// Moisture gating uses hysteresis: the alert turns on below 50% PAW and stays
+/// on until it recovers above 60% (and at least 12 hours have passed).
 pub struct IrrigationForecastRule;
 
 impl Rule for IrrigationForecastRule {
@@ -22,15 +33,37 @@ impl Rule for IrrigationForecastRule {
         &self,
         env: &EnvironmentalSummary,
         profile: &LawnProfile,
-        _history: &[Application],
+        history: &[Application],
     ) -> Option<Recommendation> {
-        let forecast = env.forecast.as_ref()?;
-        let current = env.current.as_ref()?;
-        let soil_moisture = current.primary_soil_moisture()?;
+        self.explain(env, profile, history).ok()
+    }
+
+    fn explain(
+        &self,
+        env: &EnvironmentalSummary,
+        profile: &LawnProfile,
+        _history: &[Application],
+    ) -> Result<Recommendation, Gate> {
+        let forecast = env
+            .forecast
+            .as_ref()
+            .ok_or_else(|| Gate::MissingData("Weather forecast".into()))?;
+        let soil_moisture = env
+            .current
+            .as_ref()
+            .and_then(|c| c.primary_soil_moisture())
+            .ok_or_else(|| Gate::MissingData("Soil moisture".into()))?;
+        let limits = SoilWaterLimits::for_soil(profile.soil_type);
+        let available = limits.plant_available(soil_moisture);
 
-        // Skip if soil moisture is adequate
-        if soil_moisture >= SOIL_MOISTURE_ADEQUATE {
-            return None;
+        // Skip if soil moisture has recovered past the clear threshold; the
+        // engine's latch handles the trigger side of the band.
+        if available >= IRRIGATION_PAW_CLEAR {
+            return Err(Gate::ConditionsNotMet(format!(
+                "Soil moisture {:.0} is adequate ({:.0}% of available water)",
+                VolumetricWaterContent(soil_moisture),
+                available * 100.0
+            )));
         }
 
         // Check for rain in next 5 days (120 hours)
@@ -39,7 +72,7 @@ impl Rule for IrrigationForecastRule {
 
         // If rain is expected, no irrigation recommendation
         if rain_5day.is_some() {
-            return None;
+            return Err(Gate::ConditionsNotMet("Rain expected within 5 days".into()));
         }
 
         // Calculate total precipitation expected in next 5 days
@@ -51,13 +84,16 @@ impl Rule for IrrigationForecastRule {
 
         // If meaningful rain expected (>2.5mm / 0.1"), no recommendation
         if total_precip > PRECIP_TRACE_MM {
-            return None;
+            return Err(Gate::ConditionsNotMet(format!(
+                "{:.1} mm rain forecast over 5 days",
+                total_precip
+            )));
         }
 
-        // Determine severity based on soil moisture
-        let severity = if soil_moisture < SOIL_MOISTURE_DROUGHT {
+        // Determine severity based on plant-available water
+        let severity = if available < IRRIGATION_PAW_CRITICAL {
             Severity::Critical
-        } else if soil_moisture < SOIL_MOISTURE_IRRIGATION_WARNING {
+        } else if available < IRRIGATION_PAW_WARNING {
             Severity::Warning
         } else {
             Severity::Advisory
@@ -73,7 +109,38 @@ impl Rule for IrrigationForecastRule {
             })
             .count();
 
-        Some(self.build_recommendation(severity, soil_moisture, dry_days, profile))
+        let mut rec =
+            self.build_recommendation(severity, soil_moisture, available, dry_days, profile);
+        if let Some(drought) = env
+            .drought
+            .as_ref()
+            .filter(|d| d.category >= DroughtCategory::D1)
+        {
+            rec = rec.with_data_point(
+                "Drought Status",
+                drought.category.to_string(),
+                DataSource::DroughtMonitor.as_str(),
+            );
+            if drought.category >= DroughtCategory::D2 {
+                rec.suggested_action = rec.suggested_action.map(|action| {
+                    format!(
+                        "{} Your county is in {}: check local watering restrictions first.",
+                        action,
+                        drought.category.label().to_lowercase()
+                    )
+                });
+            }
+        }
+        Ok(rec)
+    }
+
+    fn moisture_hysteresis(&self, profile: &LawnProfile) -> Option<Hysteresis> {
+        let limits = SoilWaterLimits::for_soil(profile.soil_type);
+        Some(Hysteresis {
+            trigger: limits.vwc_at(IRRIGATION_PAW_ADVISORY),
+            clear: limits.vwc_at(IRRIGATION_PAW_CLEAR),
+            min_hold: chrono::Duration::hours(SOIL_MOISTURE_ALERT_MIN_HOLD_HOURS),
+        })
     }
 }
 
@@ -82,8 +149,9 @@ impl IrrigationForecastRule {
         &self,
         severity: Severity,
         soil_moisture: f64,
+        available: f64,
         dry_days: usize,
-        _profile: &LawnProfile,
+        profile: &LawnProfile,
     ) -> Recommendation {
         let title = match severity {
             Severity::Critical => "Irrigation Urgently Needed",
@@ -91,10 +159,13 @@ impl IrrigationForecastRule {
             _ => "Consider Irrigation",
         };
 
+        let soil = profile.soil_type.map_or("loam (assumed)", |s| s.as_str());
         let description = format!(
-            "Soil moisture is low ({:.0}%) and no significant rain is forecasted for {} days. \
-             Cool-season grasses need consistent moisture.",
-            soil_moisture * 100.0,
+            "Soil moisture is low ({:.0}, {:.0}% of the water available in {}) and no \
+             significant rain is forecasted for {} days. Cool-season grasses need consistent moisture.",
+            VolumetricWaterContent(soil_moisture),
+            available * 100.0,
+            soil.to_lowercase(),
             dry_days
         );
 
@@ -122,13 +193,18 @@ impl IrrigationForecastRule {
         )
         .with_explanation(
             "Tall Fescue requires 1-1.5 inches of water per week during the growing season. \
-             When soil moisture drops below 10-15% and no rain is expected, supplemental \
-             irrigation prevents drought stress, thinning, and weed invasion. Water deeply \
+             Once half the plant-available water (between wilting point and field capacity \
+             for your soil) is used and no rain is expected, supplemental irrigation prevents drought stress, thinning, and weed invasion. Water deeply \
              (to 6 inches) to encourage deep root growth.",
         )
         .with_data_point(
             "Soil Moisture",
-            format!("{:.0}%", soil_moisture * 100.0),
+            format!("{:.0}", VolumetricWaterContent(soil_moisture)),
+            DataSource::SoilData.as_str(),
+        )
+        .with_data_point(
+            "Plant-Available Water",
+            format!("{:.0}%", available * 100.0),
             DataSource::SoilData.as_str(),
         )
         .with_data_point(
@@ -139,3 +215,54 @@ impl IrrigationForecastRule {
         .with_action(action)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{GrassType, SoilType};
+    use crate::testing::{at, date, profile, EnvBuilder, ForecastBuilder};
+
+    fn dry_week(moisture: f64) -> EnvironmentalSummary {
+        let mut forecast = ForecastBuilder::starting(date(2026, 7, 10));
+        for _ in 0..6 {
+            forecast = forecast.day(86.0, 66.0, 55.0, 0.0);
+        }
+        EnvBuilder::new(at(2026, 7, 10))
+            .soil_moisture(moisture)
+            .forecast(forecast.build())
+            .build()
+    }
+
+    fn with_soil(soil: SoilType) -> LawnProfile {
+        let mut p = profile(GrassType::TallFescue);
+        p.soil_type = Some(soil);
+        p
+    }
+
+    #[test]
+    fn same_moisture_reads_differently_by_soil() {
+        let env = dry_week(0.17);
+
+        // 17% is nearly field capacity in sandy loam
+        let sandy = IrrigationForecastRule.explain(&env, &with_soil(SoilType::SandyLoam), &[]);
+        assert!(matches!(sandy, Err(Gate::ConditionsNotMet(_))));
+
+        // ...and under a tenth of the available water in clay loam
+        let clay = IrrigationForecastRule
+            .explain(&env, &with_soil(SoilType::ClayLoam), &[])
+            .unwrap();
+        assert_eq!(clay.severity, Severity::Critical);
+    }
+
+    #[test]
+    fn hysteresis_follows_soil_limits() {
+        let clay = IrrigationForecastRule
+            .moisture_hysteresis(&with_soil(SoilType::Clay))
+            .unwrap();
+        let sandy = IrrigationForecastRule
+            .moisture_hysteresis(&with_soil(SoilType::Sandy))
+            .unwrap();
+        assert!((clay.trigger - 0.32).abs() < 1e-9);
+        assert!(sandy.trigger < clay.trigger && sandy.clear < clay.clear);
+    }
+}
@@ -1,29 +1,121 @@
 pub mod aeration;
+pub mod air_quality;
 pub mod application_window;
 pub mod broadleaf_herbicide;
+pub mod custom;
 pub mod disease_common;
 pub mod disease_pressure;
+pub mod dormancy;
+pub mod drought_restrictions;
 pub mod engine;
 pub mod fall_fertilization;
+pub mod fall_nitrogen_cutoff;
 pub mod fall_overseeding;
+pub mod fall_pest_scouting;
 pub mod fertilizer;
 pub mod fungicide;
+pub mod germination;
 pub mod gray_leaf_spot;
 pub mod grub_control;
 pub mod heat_stress;
+pub mod hysteresis;
 pub mod irrigation_forecast;
 pub mod mowing_height;
+pub mod post_process;
+pub mod posture;
 pub mod pre_emergent;
 pub mod pythium_blight;
 pub mod rain_delay;
 pub mod red_thread;
+#[cfg(test)]
+mod scenarios;
+pub mod script;
+pub mod seeding_conflict;
 pub mod soil_temp_forecast;
 pub mod spring_nitrogen;
+pub mod tall_grass;
 pub mod thresholds;
+pub mod window_closing;
+pub mod window_projection;
+pub mod winter_injury;
 
 pub use engine::RulesEngine;
+pub use post_process::post_process;
+pub use window_projection::{WindowPhase, WindowProjection};
 
+use crate::clock;
 use crate::models::{Application, EnvironmentalSummary, LawnProfile, Recommendation};
+use chrono::NaiveDate;
+use hysteresis::Hysteresis;
+use posture::PostureInput;
+use serde::Serialize;
+
+/// The date rules evaluate against: the local date of the evaluation clock
+/// ([`clock::now`]), i.e. today in live use or the day being replayed.
+pub fn current_date() -> NaiveDate {
+    clock::today()
+}
+
+/// Run `f` with [`current_date`] pinned to `date` (local noon) on this thread,
+/// for tests that evaluate a single rule outside the engine.
+#[cfg(test)]
+pub fn with_current_date<T>(date: NaiveDate, f: impl FnOnce() -> T) -> T {
+    clock::at(clock::local_noon(date), f)
+}
+
+/// The 7-day soil temp average, unless too few hourly samples back it
+/// (see [`thresholds::MIN_AGGREGATE_COVERAGE`]).
+pub fn covered_soil_temp_avg(env: &EnvironmentalSummary) -> Result<f64, Gate> {
+    covered(
+        env.soil_temp_7day_avg_f,
+        env.coverage.soil_temp,
+        "7-day soil temp average",
+    )
+}
+
+/// The 7-day ambient temp average, unless too few hourly samples back it.
+pub fn covered_ambient_temp_avg(env: &EnvironmentalSummary) -> Result<f64, Gate> {
+    covered(
+        env.ambient_temp_7day_avg_f,
+        env.coverage.ambient_temp,
+        "7-day ambient temp average",
+    )
+}
+
+fn covered(value: Option<f64>, coverage: Option<f64>, label: &str) -> Result<f64, Gate> {
+    let value = value.ok_or_else(|| Gate::MissingData(label.to_string()))?;
+    match coverage {
+        Some(share) if share < thresholds::MIN_AGGREGATE_COVERAGE => {
+            Err(Gate::MissingData(format!(
+                "{} covers only {:.0}% of expected readings (needs {:.0}%)",
+                label,
+                share * 100.0,
+                thresholds::MIN_AGGREGATE_COVERAGE * 100.0
+            )))
+        }
+        _ => Ok(value),
+    }
+}
+
+/// Why a rule stayed silent. Returned by [`Rule::explain`] for the explain view.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", content = "detail")]
+pub enum Gate {
+    /// Rule doesn't apply to this lawn (e.g. warm-season grass).
+    NotApplicable(String),
+    /// Outside the rule's calendar window.
+    OutOfSeason(String),
+    /// The relevant application was already logged.
+    AlreadyApplied(String),
+    /// A required input (soil temp, forecast, ...) is unavailable.
+    MissingData(String),
+    /// Inputs present, but thresholds not met.
+    ConditionsNotMet(String),
+    /// Engine-level moisture hysteresis latch is off.
+    MoistureLatchOff,
+    /// Switched off in configuration or on the Rules settings tab.
+    Disabled,
+}
 
 /// Trait for agronomic rules
 pub trait Rule: Send + Sync {
@@ -34,4 +126,48 @@ pub trait Rule: Send + Sync {
         profile: &LawnProfile,
         history: &[Application],
     ) -> Option<Recommendation>;
+
+    /// Soil moisture hysteresis for moisture-driven rules. When set, the engine
+    /// only evaluates the rule while its moisture latch is on; the rule itself
+    /// should reject moisture past the `clear` threshold. Thresholds are raw
+    /// volumetric moisture and may depend on the profile's soil.
+    fn moisture_hysteresis(&self, _profile: &LawnProfile) -> Option<Hysteresis> {
+        None
+    }
+
+    /// The input whose thresholds the risk posture shifts for this rule. The
+    /// engine hands the rule an adjusted summary; the rule itself is unaware.
+    fn posture_input(&self) -> Option<PostureInput> {
+        None
+    }
+
+    /// Projected open/close date of the rule's seasonal window, for the Dashboard
+    /// countdown. Only seasonal rules with a well-defined window implement this.
+    fn project_window(
+        &self,
+        _env: &EnvironmentalSummary,
+        _profile: &LawnProfile,
+        _history: &[Application],
+        _today: NaiveDate,
+    ) -> Option<WindowProjection> {
+        None
+    }
+
+    /// Short display name, derived from the implementing type.
+    fn name(&self) -> &str {
+        let full = std::any::type_name::<Self>();
+        full.rsplit("::").next().unwrap_or(full)
+    }
+
+    /// Like `evaluate`, but reports which gate stopped the rule. Rules that
+    /// don't override this only report a generic "conditions not met".
+    fn explain(
+        &self,
+        env: &EnvironmentalSummary,
+        profile: &LawnProfile,
+        history: &[Application],
+    ) -> Result<Recommendation, Gate> {
+        self.evaluate(env, profile, history)
+            .ok_or_else(|| Gate::ConditionsNotMet("Rule does not report a detailed trace".into()))
+    }
 }
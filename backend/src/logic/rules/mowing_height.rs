@@ -1,5 +1,6 @@
 use super::thresholds::*;
-use super::Rule;
+use super::{covered_ambient_temp_avg, Rule};
+use crate::logic::stress_index;
 use crate::models::{
     Application, DataSource, EnvironmentalSummary, LawnProfile, Recommendation,
     RecommendationCategory, Severity,
@@ -12,6 +13,9 @@ use crate::models::{
 /// - Summer (>75°F avg): 3-4" (taller to shade crown/soil)
 /// - Fall (50-75°F avg): 2.5-3.5"
 ///
+/// Sustained turf stress (heat, drought, disease and traffic combined) raises
+/// the recommendation to the summer maximum even in milder weeks.
+///
 /// Key principle: Never remove more than 1/3 of the green leaf area at once.
 pub struct MowingHeightRule;
 
@@ -26,11 +30,14 @@ impl Rule for MowingHeightRule {
             return None;
         }
 
-        let ambient_avg = env.ambient_temp_7day_avg_f?;
+        let ambient_avg = covered_ambient_temp_avg(env).ok()?;
+        let stress = stress_index::sustained(env);
 
         // Determine season based on temperature
         let (height_range, season, severity) = if ambient_avg > MOWING_HEAT_STRESS_TEMP_F {
             ("3.5-4 inches", "Summer (heat stress)", Severity::Warning)
+        } else if stress.is_some() && ambient_avg >= MOWING_ACTIVE_GROWTH_TEMP_F {
+            ("3.5-4 inches", "Turf stress", Severity::Warning)
         } else if ambient_avg > MOWING_SUMMER_TEMP_F {
             ("3-4 inches", "Summer", Severity::Advisory)
         } else if ambient_avg >= MOWING_ACTIVE_GROWTH_TEMP_F {
@@ -40,16 +47,25 @@ impl Rule for MowingHeightRule {
             return None;
         };
 
-        let rec = Recommendation::new(
+        let mut description = format!(
+            "With a 7-day average temp of {:.0}°F, recommended TTTF mowing height \
+             is {}.",
+            ambient_avg, height_range
+        );
+        if let Some(stress) = stress {
+            description.push_str(&format!(
+                " The turf stress index has been High or worse for {} days; raise the \
+                 height to shade the crown and soil.",
+                stress.sustained_high_days
+            ));
+        }
+
+        let mut rec = Recommendation::new(
             "mowing_height",
             RecommendationCategory::Mowing,
             severity,
             format!("Mowing Height: {} ({})", height_range, season),
-            format!(
-                "With a 7-day average temp of {:.0}°F, recommended TTTF mowing height \
-                 is {}.",
-                ambient_avg, height_range
-            ),
+            description,
         )
         .with_explanation(
             "Tall Fescue mowing height should follow seasonal temperatures \
@@ -72,7 +88,7 @@ impl Rule for MowingHeightRule {
             "Set mowing height to {}. Never cut more than 1/3 of the blade at once. \
              If changing height, adjust gradually over 1-2 mowings.{}",
             height_range,
-            if ambient_avg > MOWING_HEAT_STRESS_TEMP_F {
+            if ambient_avg > MOWING_HEAT_STRESS_TEMP_F || stress.is_some() {
                 " Consider skipping mowing during extreme heat — reduced mowing \
                  frequency reduces stress on the plant."
             } else {
@@ -80,6 +96,37 @@ impl Rule for MowingHeightRule {
             }
         ));
 
+        if let Some(stress) = stress {
+            rec = rec.with_data_point(
+                "Turf Stress Index",
+                format!("{:.0} ({})", stress.score, stress.level),
+                DataSource::Calculated.as_str(),
+            );
+        }
+
         Some(rec)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::GrassType;
+    use crate::testing::{at, profile, EnvBuilder};
+
+    #[test]
+    fn sustained_stress_raises_the_height() {
+        let rule = MowingHeightRule;
+        let fescue = profile(GrassType::TallFescue);
+        let week = || EnvBuilder::new(at(2026, 6, 20)).weekly(72.0, 65.0, 0.0);
+
+        let mild = rule.evaluate(&week().build(), &fescue, &[]).unwrap();
+        assert_eq!(mild.severity, Severity::Info);
+
+        let stressed = rule
+            .evaluate(&week().stress(68.0, 4).build(), &fescue, &[])
+            .unwrap();
+        assert_eq!(stressed.severity, Severity::Warning);
+        assert!(stressed.title.starts_with("Mowing Height: 3.5-4 inches"));
+    }
+}
@@ -0,0 +1,202 @@
+//! Engine-level cleanup of the combined recommendation list.
+//!
+//! Rules are evaluated independently, so the raw output can contain duplicate
+//! ids (a built-in and a custom rule sharing one) and advice that contradicts
+//! itself, e.g. "good application window" next to "rain imminent, delay
+//! applications". [`post_process`] deduplicates, resolves those conflicts, and
+//! sorts by severity (highest first) then category.
+
+use crate::models::{DataPoint, DataSource, Recommendation, RecommendationCategory, Severity};
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+const RAIN_DELAY_ID: &str = "rain_delay";
+const APPLICATION_WINDOW_ID: &str = "application_window";
+
+/// Categories whose recommendations amount to "apply a product now".
+const PRODUCT_APPLICATION_CATEGORIES: &[RecommendationCategory] = &[
+    RecommendationCategory::PreEmergent,
+    RecommendationCategory::GrubControl,
+    RecommendationCategory::Fertilizer,
+    RecommendationCategory::Fungicide,
+    RecommendationCategory::Herbicide,
+];
+
+/// Deduplicate by id, resolve apply-vs-delay conflicts, and sort.
+pub fn post_process(recommendations: Vec<Recommendation>) -> Vec<Recommendation> {
+    let mut recs = dedup_by_id(recommendations);
+    resolve_conflicts(&mut recs);
+    recs.sort_by(|a, b| {
+        Reverse(a.severity)
+            .cmp(&Reverse(b.severity))
+            .then(a.category.cmp(&b.category))
+            .then_with(|| a.id.cmp(&b.id))
+    });
+    recs
+}
+
+/// Keep one recommendation per id: the most severe, or the first on a tie.
+fn dedup_by_id(recommendations: Vec<Recommendation>) -> Vec<Recommendation> {
+    let mut index: HashMap<String, usize> = HashMap::new();
+    let mut out: Vec<Recommendation> = Vec::with_capacity(recommendations.len());
+
+    for rec in recommendations {
+        match index.get(&rec.id) {
+            Some(&i) => {
+                if rec.severity > out[i].severity {
+                    out[i] = rec;
+                }
+            }
+            None => {
+                index.insert(rec.id.clone(), out.len());
+                out.push(rec);
+            }
+        }
+    }
+    out
+}
+
+fn resolve_conflicts(recs: &mut Vec<Recommendation>) {
+    let Some(rain_severity) = recs
+        .iter()
+        .find(|r| r.id == RAIN_DELAY_ID)
+        .map(|r| r.severity)
+    else {
+        return;
+    };
+
+    // A "good window" and a rain delay are two halves of the same advice: fold
+    // the window into the rain delay so the user sees one timing recommendation.
+    if let Some(window_idx) = recs.iter().position(|r| r.id == APPLICATION_WINDOW_ID) {
+        let window = recs.remove(window_idx);
+        if let Some(rain) = recs.iter_mut().find(|r| r.id == RAIN_DELAY_ID) {
+            rain.data_points.push(DataPoint::new(
+                "Next Good Window",
+                window.title.trim_start_matches("Good Application Window: "),
+                DataSource::OpenWeatherMap.as_str(),
+            ));
+            if let Some(action) = window.suggested_action {
+                rain.suggested_action = Some(match rain.suggested_action.take() {
+                    Some(existing) => format!("{} {}", existing, action),
+                    None => action,
+                });
+            }
+        }
+    }
+
+    // Advisory-level rain is 48h out; only annotate when it's close enough to
+    // wash a product off.
+    if rain_severity < Severity::Warning {
+        return;
+    }
+    let note = format!(
+        "Rain delay ({}) is in effect: hold this application until the rain has passed.",
+        rain_severity
+    );
+    for rec in recs
+        .iter_mut()
+        .filter(|r| PRODUCT_APPLICATION_CATEGORIES.contains(&r.category))
+    {
+        rec.conflict_note = Some(note.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rec(id: &str, category: RecommendationCategory, severity: Severity) -> Recommendation {
+        Recommendation::new(id, category, severity, id, "")
+    }
+
+    #[test]
+    fn sorts_by_severity_then_category() {
+        let out = post_process(vec![
+            rec("mow", RecommendationCategory::Mowing, Severity::Advisory),
+            rec(
+                "heat",
+                RecommendationCategory::HeatStress,
+                Severity::Critical,
+            ),
+            rec(
+                "pre",
+                RecommendationCategory::PreEmergent,
+                Severity::Advisory,
+            ),
+        ]);
+        let ids: Vec<_> = out.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, ["heat", "pre", "mow"]);
+    }
+
+    #[test]
+    fn dedup_keeps_most_severe() {
+        let out = post_process(vec![
+            rec("dup", RecommendationCategory::General, Severity::Info),
+            rec("dup", RecommendationCategory::General, Severity::Warning),
+            rec("dup", RecommendationCategory::General, Severity::Advisory),
+        ]);
+        assert_eq!(out.len(), 1);
+        assert_eq!(out[0].severity, Severity::Warning);
+    }
+
+    #[test]
+    fn rain_delay_absorbs_application_window_and_annotates_products() {
+        let window = rec(
+            APPLICATION_WINDOW_ID,
+            RecommendationCategory::ApplicationTiming,
+            Severity::Info,
+        )
+        .with_action("Plan applications for Friday.");
+        let window = Recommendation {
+            title: "Good Application Window: Friday".into(),
+            ..window
+        };
+        let out = post_process(vec![
+            window,
+            rec(
+                RAIN_DELAY_ID,
+                RecommendationCategory::ApplicationTiming,
+                Severity::Critical,
+            )
+            .with_action("Do not apply."),
+            rec(
+                "fert",
+                RecommendationCategory::Fertilizer,
+                Severity::Advisory,
+            ),
+            rec("mow", RecommendationCategory::Mowing, Severity::Advisory),
+        ]);
+
+        assert!(out.iter().all(|r| r.id != APPLICATION_WINDOW_ID));
+        let rain = out.iter().find(|r| r.id == RAIN_DELAY_ID).unwrap();
+        assert!(rain
+            .data_points
+            .iter()
+            .any(|d| d.label == "Next Good Window" && d.value == "Friday"));
+        assert_eq!(
+            rain.suggested_action.as_deref(),
+            Some("Do not apply. Plan applications for Friday.")
+        );
+        let fert = out.iter().find(|r| r.id == "fert").unwrap();
+        assert!(fert.conflict_note.is_some());
+        let mow = out.iter().find(|r| r.id == "mow").unwrap();
+        assert!(mow.conflict_note.is_none());
+    }
+
+    #[test]
+    fn advisory_rain_does_not_annotate_products() {
+        let out = post_process(vec![
+            rec(
+                RAIN_DELAY_ID,
+                RecommendationCategory::ApplicationTiming,
+                Severity::Advisory,
+            ),
+            rec(
+                "fert",
+                RecommendationCategory::Fertilizer,
+                Severity::Advisory,
+            ),
+        ]);
+        assert!(out.iter().all(|r| r.conflict_note.is_none()));
+    }
+}
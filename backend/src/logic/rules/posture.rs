@@ -0,0 +1,200 @@
+//! Risk posture: a user setting that makes the whole engine more or less
+//! eager. It's a modifier layer around the rules, not inside them:
+//! - Inputs: rules that opt in via [`Rule::posture_input`](super::Rule::posture_input)
+//!   see soil temps or humidity shifted toward (conservative) or away from
+//!   (aggressive) their thresholds, so they fire earlier or later.
+//! - Outputs: conservative raises Advisory to Warning and aggressive lowers
+//!   Warning to Advisory. Nothing moves into or out of Info or Critical, so a
+//!   posture never invents an emergency or buries one.
+
+use crate::models::{DataSource, EnvironmentalSummary, Recommendation, Severity};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// Soil temp lead (°F) for rules that fire as the soil warms: conservative
+/// opens a 50°F window at 48°F.
+pub const POSTURE_SOIL_LEAD_F: f64 = 2.0;
+
+/// Humidity lead (percentage points) for disease rules.
+pub const POSTURE_HUMIDITY_LEAD: f64 = 5.0;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RiskPosture {
+    /// Alert earlier and louder.
+    Conservative,
+    #[default]
+    Standard,
+    /// Alert later and quieter.
+    Aggressive,
+}
+
+/// Which input a rule's thresholds key on, for the posture to shift.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PostureInput {
+    /// Windows that open as the soil warms (pre-emergent, grub control).
+    RisingSoilTemp,
+    /// Disease pressure from humidity (current, 7-day and forecast).
+    Humidity,
+}
+
+impl RiskPosture {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RiskPosture::Conservative => "Conservative",
+            RiskPosture::Standard => "Standard",
+            RiskPosture::Aggressive => "Aggressive",
+        }
+    }
+
+    /// +1 conservative, 0 standard, -1 aggressive.
+    fn direction(&self) -> f64 {
+        match self {
+            RiskPosture::Conservative => 1.0,
+            RiskPosture::Standard => 0.0,
+            RiskPosture::Aggressive => -1.0,
+        }
+    }
+
+    /// The summary a rule keyed on `input` should see.
+    pub fn adjust_env<'a>(
+        &self,
+        env: &'a EnvironmentalSummary,
+        input: Option<PostureInput>,
+    ) -> Cow<'a, EnvironmentalSummary> {
+        let Some(input) = input.filter(|_| *self != RiskPosture::Standard) else {
+            return Cow::Borrowed(env);
+        };
+        let mut env = env.clone();
+        match input {
+            PostureInput::RisingSoilTemp => {
+                let lead = POSTURE_SOIL_LEAD_F * self.direction();
+                shift(&mut env.soil_temp_7day_avg_f, lead, None);
+                if let Some(current) = env.current.as_mut() {
+                    shift(&mut current.soil_temp_10_f, lead, None);
+                }
+            }
+            PostureInput::Humidity => {
+                let lead = POSTURE_HUMIDITY_LEAD * self.direction();
+                shift(&mut env.humidity_7day_avg, lead, Some(100.0));
+                if let Some(current) = env.current.as_mut() {
+                    shift(&mut current.humidity_percent, lead, Some(100.0));
+                }
+                if let Some(forecast) = env.forecast.as_mut() {
+                    for p in &mut forecast.hourly {
+                        p.humidity_percent = (p.humidity_percent + lead).clamp(0.0, 100.0);
+                    }
+                    for d in &mut forecast.daily_summary {
+                        d.avg_humidity = (d.avg_humidity + lead).clamp(0.0, 100.0);
+                    }
+                }
+            }
+        }
+        Cow::Owned(env)
+    }
+
+    /// Shift the severity and note the posture on a fired recommendation.
+    pub fn adjust(&self, mut rec: Recommendation, input: Option<PostureInput>) -> Recommendation {
+        if *self == RiskPosture::Standard {
+            return rec;
+        }
+        rec.severity = match (self, rec.severity) {
+            (RiskPosture::Conservative, Severity::Advisory) => Severity::Warning,
+            (RiskPosture::Aggressive, Severity::Warning) => Severity::Advisory,
+            (_, severity) => severity,
+        };
+        let note = match input {
+            Some(PostureInput::RisingSoilTemp) => format!(
+                "{} (soil temps read {:+.0}°F)",
+                self.as_str(),
+                POSTURE_SOIL_LEAD_F * self.direction()
+            ),
+            Some(PostureInput::Humidity) => format!(
+                "{} (humidity read {:+.0} pts)",
+                self.as_str(),
+                POSTURE_HUMIDITY_LEAD * self.direction()
+            ),
+            None => self.as_str().to_string(),
+        };
+        rec.with_data_point("Risk Posture", note, DataSource::Calculated.as_str())
+    }
+}
+
+fn shift(value: &mut Option<f64>, by: f64, max: Option<f64>) {
+    if let Some(v) = value.as_mut() {
+        *v += by;
+        if let Some(max) = max {
+            *v = v.clamp(0.0, max);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logic::rules::RulesEngine;
+    use crate::models::GrassType;
+    use crate::testing::{at, profile, EnvBuilder};
+
+    #[test]
+    fn conservative_opens_pre_emergent_window_early() {
+        let engine = RulesEngine::new();
+        let env = EnvBuilder::new(at(2026, 3, 20))
+            .soil_temp(48.0, 48.0)
+            .build();
+        let fescue = profile(GrassType::TallFescue);
+        let pre_emergent = |engine: &RulesEngine| {
+            engine
+                .evaluate(&env, &fescue, &[], at(2026, 3, 20))
+                .into_iter()
+                .find(|r| r.category == crate::models::RecommendationCategory::PreEmergent)
+        };
+
+        assert!(pre_emergent(&engine).is_none());
+
+        engine.set_posture(RiskPosture::Conservative);
+        let rec = pre_emergent(&engine).expect("window opens at 48°F");
+        assert!(rec
+            .data_points
+            .iter()
+            .any(|d| d.label == "Risk Posture" && d.value.contains("+2°F")));
+
+        engine.set_posture(RiskPosture::Aggressive);
+        assert!(pre_emergent(&engine).is_none());
+    }
+
+    #[test]
+    fn severity_table() {
+        use RiskPosture::*;
+        use Severity::*;
+        let shifted = |posture: RiskPosture, severity| {
+            let rec = Recommendation::new(
+                "id",
+                crate::models::RecommendationCategory::DiseasePressure,
+                severity,
+                "t",
+                "d",
+            );
+            posture.adjust(rec, None).severity
+        };
+        // (from, conservative, standard, aggressive)
+        let table = [
+            (Info, Info, Info, Info),
+            (Advisory, Warning, Advisory, Advisory),
+            (Warning, Warning, Warning, Advisory),
+            (Critical, Critical, Critical, Critical),
+        ];
+        for (from, conservative, standard, aggressive) in table {
+            assert_eq!(shifted(Conservative, from), conservative, "{from:?}");
+            assert_eq!(shifted(Standard, from), standard, "{from:?}");
+            assert_eq!(shifted(Aggressive, from), aggressive, "{from:?}");
+        }
+        let info = Recommendation::new(
+            "id",
+            crate::models::RecommendationCategory::DiseasePressure,
+            Info,
+            "t",
+            "d",
+        );
+        assert!(Standard.adjust(info, None).data_points.is_empty());
+    }
+}
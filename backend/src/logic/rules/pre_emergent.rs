@@ -1,10 +1,16 @@
+use super::posture::PostureInput;
 use super::thresholds::*;
-use super::Rule;
+use super::window_projection::SoilWindow;
+use super::{covered_soil_temp_avg, current_date, Gate, Rule, WindowProjection};
+use crate::logic::frost::{self, FrostOutlook};
+use crate::logic::gdd::crabgrass_model;
+use crate::logic::soil_anomaly;
+use crate::models::soil_temp_prediction::CrossingDirection;
 use crate::models::{
-    Application, ApplicationType, DataSource, EnvironmentalSummary, LawnProfile, Recommendation,
-    RecommendationCategory, Severity,
+    Application, ApplicationType, CrabgrassStatus, DataSource, EnvironmentalSummary, LawnProfile,
+    Recommendation, RecommendationCategory, Severity,
 };
-use chrono::{Datelike, Local};
+use chrono::{Datelike, Duration, NaiveDate};
 
 /// Pre-emergent herbicide timing rule
 ///
@@ -12,9 +18,20 @@ use chrono::{Datelike, Local};
 /// reaches 55°F for 3+ consecutive days. Pre-emergent should be
 /// applied before this threshold is reached.
 ///
-/// Window: Soil temp 50-60°F (7-day average at 10cm depth)
+/// Window: Soil temp 50-60°F (7-day average at 10cm depth), within a season
+/// placed around the typical last spring frost (Feb-May in zone 7a) and moved
+/// earlier or later when soil runs warmer or colder than normal
 pub struct PreEmergentRule;
 
+/// Dates the spring pre-emergent season runs between, `lead_days` early.
+fn spring_season(outlook: &FrostOutlook, lead_days: i64) -> (NaiveDate, NaiveDate) {
+    let last_frost = outlook.last_spring_median() - Duration::days(lead_days);
+    (
+        last_frost - Duration::days(PRE_EMERGENT_SEASON_DAYS_BEFORE_LAST_FROST),
+        last_frost + Duration::days(PRE_EMERGENT_SEASON_DAYS_AFTER_LAST_FROST),
+    )
+}
+
 impl Rule for PreEmergentRule {
     fn evaluate(
         &self,
@@ -22,52 +39,77 @@ impl Rule for PreEmergentRule {
         profile: &LawnProfile,
         history: &[Application],
     ) -> Option<Recommendation> {
+        self.explain(env, profile, history).ok()
+    }
+
+    fn explain(
+        &self,
+        env: &EnvironmentalSummary,
+        profile: &LawnProfile,
+        history: &[Application],
+    ) -> Result<Recommendation, Gate> {
         // Only relevant for cool-season grasses
         if !profile.grass_type.is_cool_season() {
-            return None;
+            return Err(Gate::NotApplicable(format!(
+                "{} is not a cool-season grass",
+                profile.grass_type
+            )));
         }
 
-        // Only relevant in spring (Feb-May)
-        let month = Local::now().month();
-        if !(2..=5).contains(&month) {
-            return None;
+        // Only relevant in spring, around the last frost
+        let today = current_date();
+        let current_year = today.year();
+        let outlook = frost::frost_outlook(&env.frost_years, &profile.usda_zone, current_year);
+        let (season_start, season_end) =
+            spring_season(&outlook, soil_anomaly::spring_lead_days(env));
+        if today < season_start || today > season_end {
+            return Err(Gate::OutOfSeason(format!(
+                "Spring window is {} - {}{}",
+                season_start.format("%b %-d"),
+                season_end.format("%b %-d"),
+                soil_anomaly::spring_shift(env)
+                    .map(|shift| format!(" ({})", shift))
+                    .unwrap_or_default()
+            )));
         }
 
         // Check if already applied this year
-        let current_year = Local::now().year();
         let already_applied = history.iter().any(|app| {
             app.application_type == ApplicationType::PreEmergent
                 && app.application_date.year() == current_year
         });
 
         if already_applied {
-            return None;
+            return Err(Gate::AlreadyApplied(format!(
+                "Pre-emergent already logged in {}",
+                current_year
+            )));
         }
 
         // Get 7-day soil temp average
-        let soil_temp_avg = env.soil_temp_7day_avg_f?;
+        let soil_temp_avg = covered_soil_temp_avg(env)?;
 
         // Current soil temp for display
-        let current_soil_temp = env.current.as_ref()?.soil_temp_10_f?;
+        let current_soil_temp = env
+            .current
+            .as_ref()
+            .and_then(|c| c.soil_temp_10_f)
+            .ok_or_else(|| Gate::MissingData("Current soil temp (10cm)".into()))?;
 
         // GDD-enhanced urgency: if GDD data is available, escalate based on crabgrass model
         let gdd_ytd = env.gdd_base50_ytd;
-        let gdd_urgency = gdd_ytd.map(|gdd| {
-            if gdd >= 200.0 {
-                3 // Post-germination
-            } else if gdd >= 150.0 {
-                2 // Germination likely
-            } else if gdd >= 75.0 {
-                1 // Approaching
-            } else {
-                0 // Pre-germination
-            }
+        let crabgrass = gdd_ytd.map(crabgrass_model);
+        let germinating = crabgrass.as_ref().map(|m| {
+            matches!(
+                m.status,
+                CrabgrassStatus::GerminationLikely | CrabgrassStatus::PostGermination
+            )
         });
 
         if (PRE_EMERGENT_SOIL_LOW_F..=PRE_EMERGENT_SOIL_HIGH_F).contains(&soil_temp_avg) {
             // Optimal window — escalate severity if GDD indicates urgency
             let severity =
-                if gdd_urgency.unwrap_or(0) >= 2 || soil_temp_avg >= PRE_EMERGENT_URGENCY_SOIL_F {
+                if germinating.unwrap_or(false) || soil_temp_avg >= PRE_EMERGENT_URGENCY_SOIL_F {
                     Severity::Warning
                 } else {
                     Severity::Advisory
@@ -124,26 +166,41 @@ impl Rule for PreEmergentRule {
                     DataSource::Calculated.as_str(),
                 );
             }
+            if let Some(advice) = outlook.spring_advice(FROST_ADVICE_RISK) {
+                rec = rec.with_data_point("Frost Risk", advice, DataSource::Calculated.as_str());
+            }
 
             rec = rec.with_action(
                 "Apply pre-emergent herbicide (prodiamine, dithiopyr, or pendimethalin) \
                      at label rate. Water in within 24 hours if no rain.",
             );
 
-            Some(rec)
+            Ok(rec)
         } else if soil_temp_avg > PRE_EMERGENT_SOIL_HIGH_F
             && soil_temp_avg <= PRE_EMERGENT_LATE_SOIL_F
         {
-            // Late window - urgent
-            let rec = Recommendation::new(
+            // Late window - urgent once germination is under way. Without GDD
+            // data assume it is; with it, a flush that hasn't started yet is
+            // only a warning.
+            let germinated_pct = crabgrass.as_ref().map(|m| m.germination_pct);
+            let severity = if germinated_pct.is_some_and(|pct| pct <= 0.0) {
+                Severity::Warning
+            } else {
+                Severity::Critical
+            };
+            let progress = germinated_pct
+                .map(|pct| format!(" Crabgrass germination is ~{:.0}% complete.", pct))
+                .unwrap_or_default();
+
+            let mut rec = Recommendation::new(
                 format!("pre_emergent_late_{}", current_year),
                 RecommendationCategory::PreEmergent,
-                Severity::Critical,
+                severity,
                 "Pre-Emergent Window Closing",
                 format!(
                     "Soil temperature is above optimal range. Crabgrass may have begun germinating. \
-                     7-day average: {:.1}°F",
-                    soil_temp_avg
+                     7-day average: {:.1}°F.{}",
+                    soil_temp_avg, progress
                 ),
             )
             .with_explanation(format!(
@@ -156,15 +213,73 @@ impl Rule for PreEmergentRule {
                 "7-Day Avg Soil Temp",
                 format!("{:.1}°F", soil_temp_avg),
                 DataSource::SoilData.as_str(),
-            )
-            .with_action(
-                "Apply pre-emergent immediately if not yet done. Consider products with \
-                 post-emergent activity like quinclorac combinations.",
             );
 
-            Some(rec)
+            if let Some(pct) = germinated_pct {
+                rec = rec.with_data_point(
+                    "Crabgrass Germination",
+                    format!("~{:.0}%", pct),
+                    DataSource::Calculated.as_str(),
+                );
+            }
+
+            rec = if germinated_pct.is_some_and(|pct| pct >= CRABGRASS_PRE_EMERGENT_FUTILE_PCT) {
+                rec.with_action(
+                    "Most crabgrass has already germinated; pre-emergent alone won't stop it. \
+                     Use a product with post-emergent activity like quinclorac or dithiopyr \
+                     and spot-treat emerged seedlings.",
+                )
+            } else {
+                rec.with_action(
+                    "Apply pre-emergent immediately if not yet done. Consider products with \
+                     post-emergent activity like quinclorac combinations.",
+                )
+            };
+
+            Ok(rec)
         } else {
-            None
+            Err(Gate::ConditionsNotMet(format!(
+                "7-day soil temp {:.1}°F outside {:.0}-{:.0}°F window",
+                soil_temp_avg, PRE_EMERGENT_SOIL_LOW_F, PRE_EMERGENT_LATE_SOIL_F
+            )))
+        }
+    }
+
+    fn project_window(
+        &self,
+        env: &EnvironmentalSummary,
+        profile: &LawnProfile,
+        history: &[Application],
+        today: NaiveDate,
+    ) -> Option<WindowProjection> {
+        if !profile.grass_type.is_cool_season() {
+            return None;
         }
+        let year = today.year();
+        let already_applied = history.iter().any(|app| {
+            app.application_type == ApplicationType::PreEmergent
+                && app.application_date.year() == year
+        });
+        if already_applied {
+            return None;
+        }
+        let (season_start, season_end) = spring_season(
+            &frost::frost_outlook(&env.frost_years, &profile.usda_zone, year),
+            soil_anomaly::spring_lead_days(env),
+        );
+
+        SoilWindow {
+            name: "Pre-Emergent",
+            open_f: PRE_EMERGENT_SOIL_LOW_F,
+            close_f: PRE_EMERGENT_SOIL_HIGH_F,
+            direction: CrossingDirection::Rising,
+            season_start,
+            season_end,
+        }
+        .project(env, today)
+    }
+
+    fn posture_input(&self) -> Option<PostureInput> {
+        Some(PostureInput::RisingSoilTemp)
     }
 }
@@ -1,10 +1,11 @@
+use super::posture::PostureInput;
 use super::thresholds::*;
-use super::Rule;
+use super::{current_date, Rule};
 use crate::models::{
     analyze_fungicide_rotation, Application, DataSource, EnvironmentalSummary, LawnProfile,
     Recommendation, RecommendationCategory, Severity,
 };
-use chrono::{Datelike, Local, NaiveDate};
+use chrono::{Datelike, NaiveDate};
 
 /// Pythium Blight rule (Pythium spp.)
 ///
@@ -32,7 +33,7 @@ impl Rule for PythiumBlightRule {
             return None;
         }
 
-        let today = Local::now().date_naive();
+        let today = current_date();
         let current_year = today.year();
 
         // Window: June 1 - September 30
@@ -153,4 +154,8 @@ impl Rule for PythiumBlightRule {
 
         Some(rec)
     }
+
+    fn posture_input(&self) -> Option<PostureInput> {
+        Some(PostureInput::Humidity)
+    }
 }
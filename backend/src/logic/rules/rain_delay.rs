@@ -1,7 +1,7 @@
 use super::thresholds::*;
 use super::Rule;
 use crate::models::{
-    Application, DataSource, EnvironmentalSummary, LawnProfile, Recommendation,
+    Application, DataSource, EnvironmentalSummary, LawnProfile, Millimeters, Recommendation,
     RecommendationCategory, Severity,
 };
 
@@ -85,7 +85,7 @@ impl RainDelayRule {
         expected_mm: f64,
         probability: f64,
     ) -> Recommendation {
-        let expected_inches = expected_mm / 25.4;
+        let expected_inches = Millimeters(expected_mm).to_inches().0;
         let prob_percent = probability * 100.0;
 
         let title = match severity {
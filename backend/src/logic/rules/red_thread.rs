@@ -1,10 +1,11 @@
+use super::posture::PostureInput;
 use super::thresholds::*;
-use super::Rule;
+use super::{current_date, Rule};
 use crate::models::{
     Application, ApplicationType, DataSource, EnvironmentalSummary, LawnProfile, Recommendation,
     RecommendationCategory, Severity,
 };
-use chrono::{Datelike, Local};
+use chrono::Datelike;
 
 /// Red Thread rule (Laetisaria fuciformis)
 ///
@@ -33,7 +34,7 @@ impl Rule for RedThreadRule {
             return None;
         }
 
-        let today = Local::now().date_naive();
+        let today = current_date();
         let month = today.month();
 
         // Window: March-May or September-November
@@ -158,4 +159,8 @@ impl Rule for RedThreadRule {
 
         Some(rec)
     }
+
+    fn posture_input(&self) -> Option<PostureInput> {
+        Some(PostureInput::Humidity)
+    }
 }
@@ -0,0 +1,97 @@
+//! Table-driven rule tests. Each fixture under `tests/scenarios/` describes a
+//! lawn on a given day and the expected outcome of the rules it cares about;
+//! every fixture runs through a fresh engine with the date pinned, so moisture
+//! latches never carry over from the fixture before it.
+
+use super::engine::RuleTrace;
+use super::RulesEngine;
+use crate::testing::ScenarioFixture;
+use std::collections::BTreeSet;
+use std::path::Path;
+
+fn fixtures() -> Vec<(String, ScenarioFixture)> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/scenarios");
+    let mut paths: Vec<_> = std::fs::read_dir(&dir)
+        .unwrap_or_else(|e| panic!("reading {}: {}", dir.display(), e))
+        .map(|entry| entry.unwrap().path())
+        .filter(|p| p.extension().is_some_and(|ext| ext == "yaml"))
+        .collect();
+    paths.sort();
+
+    paths
+        .into_iter()
+        .map(|path| {
+            let file = path.file_name().unwrap().to_string_lossy().into_owned();
+            let raw = std::fs::read_to_string(&path).unwrap();
+            let fixture = serde_yaml_ng::from_str(&raw)
+                .unwrap_or_else(|e| panic!("{}: invalid fixture: {}", file, e));
+            (file, fixture)
+        })
+        .collect()
+}
+
+/// The severity when the rule fired, otherwise the kind of gate that stopped it.
+fn outcome(trace: &RuleTrace) -> String {
+    match (&trace.severity, &trace.gate) {
+        (Some(severity), _) => severity.as_str().to_string(),
+        (None, Some(gate)) => serde_json::to_value(gate).unwrap()["kind"]
+            .as_str()
+            .unwrap()
+            .to_string(),
+        (None, None) => "Unknown".to_string(),
+    }
+}
+
+fn matches(expected: &str, trace: &RuleTrace) -> bool {
+    match expected {
+        "Silent" => !trace.fired,
+        _ => outcome(trace) == expected,
+    }
+}
+
+#[test]
+fn scenarios_match_expectations() {
+    let mut failures = Vec::new();
+
+    for (file, fixture) in fixtures() {
+        let expect = fixture.expect.clone();
+        let scenario = fixture.into_scenario();
+        let traces = scenario.trace(&RulesEngine::new());
+
+        for (rule, expected) in &expect {
+            match traces.iter().find(|t| &t.rule == rule) {
+                Some(trace) if matches(expected, trace) => {}
+                Some(trace) => failures.push(format!(
+                    "{} ({}): {} expected {}, got {} ({:?})",
+                    file,
+                    scenario.name,
+                    rule,
+                    expected,
+                    outcome(trace),
+                    trace.gate
+                )),
+                None => failures.push(format!("{}: unknown rule {}", file, rule)),
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+#[test]
+fn every_rule_fires_in_some_scenario() {
+    let fired: BTreeSet<String> = fixtures()
+        .into_iter()
+        .flat_map(|(_, fixture)| fixture.into_scenario().trace(&RulesEngine::new()))
+        .filter(|t| t.fired)
+        .map(|t| t.rule)
+        .collect();
+
+    let missing: Vec<_> = RulesEngine::new()
+        .rule_statuses()
+        .into_iter()
+        .map(|s| s.rule)
+        .filter(|rule| !fired.contains(rule))
+        .collect();
+    assert!(missing.is_empty(), "no scenario fires {:?}", missing);
+}
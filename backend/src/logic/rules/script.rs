@@ -0,0 +1,308 @@
+//! Scripted rules: `.rhai` files dropped in a rules directory (`RULES_DIR`) for logic
+//! that can't be expressed declaratively in `custom.rs`.
+//!
+//! Each script defines `fn evaluate(env, profile, history)` and returns `()` when it
+//! doesn't fire, or a map describing the recommendation:
+//!
+//! ```rhai
+//! fn evaluate(env, profile, history) {
+//!     let soil = env.soil_temp_7day_avg_f;
+//!     if soil == () || soil < 55.0 { return (); }
+//!     let recent = history.filter(|a| a.application_type == "Fertilizer");
+//!     if recent.len() > 0 { return (); }
+//!     #{
+//!         id: "script_first_feed",
+//!         title: "First Feeding",
+//!         severity: "Advisory",
+//!         category: "Fertilizer",
+//!         description: `Soil averaging ${soil}°F with no fertilizer logged yet.`,
+//!         action: "Apply 0.5 lb N/1000 sqft.",
+//!     }
+//! }
+//! ```
+//!
+//! `env`, `profile`, and `history` are read-only copies of `EnvironmentalSummary`,
+//! `LawnProfile`, and the application list (same field names as the JSON API).
+//! Scripts run sandboxed: no module imports, no `eval`, and bounded operations,
+//! call depth, and string/array/map sizes so a runaway script can't stall evaluation.
+
+use super::{Gate, Rule};
+use crate::error::{Result, TurfOpsError};
+use crate::models::{
+    Application, EnvironmentalSummary, LawnProfile, Recommendation, RecommendationCategory,
+    Severity,
+};
+use rhai::{Dynamic, Engine, Scope, AST};
+use serde::Deserialize;
+use std::path::Path;
+use std::sync::Arc;
+
+const ENTRY_POINT: &str = "evaluate";
+const MAX_OPERATIONS: u64 = 100_000;
+
+/// Recommendation fields a script returns.
+#[derive(Debug, Deserialize)]
+struct ScriptRecommendation {
+    id: String,
+    title: String,
+    severity: Severity,
+    #[serde(default = "default_category")]
+    category: RecommendationCategory,
+    #[serde(default)]
+    description: String,
+    explanation: Option<String>,
+    action: Option<String>,
+}
+
+fn default_category() -> RecommendationCategory {
+    RecommendationCategory::General
+}
+
+/// Engine with scripting limits applied; shared by every script rule.
+pub fn sandboxed_engine() -> Engine {
+    let mut engine = Engine::new();
+    engine.set_max_operations(MAX_OPERATIONS);
+    engine.set_max_call_levels(32);
+    engine.set_max_expr_depths(64, 32);
+    engine.set_max_string_size(10_000);
+    engine.set_max_array_size(10_000);
+    engine.set_max_map_size(1_000);
+    engine.set_module_resolver(rhai::module_resolvers::DummyModuleResolver::new());
+    engine.disable_symbol("eval");
+    engine.on_print(|msg| tracing::debug!(target: "turfops::script", "{}", msg));
+    engine
+}
+
+pub struct ScriptRule {
+    name: String,
+    engine: Arc<Engine>,
+    ast: AST,
+}
+
+impl ScriptRule {
+    /// Compile a script and check it defines `evaluate(env, profile, history)`.
+    pub fn compile(name: &str, source: &str, engine: Arc<Engine>) -> Result<Self> {
+        let ast = engine
+            .compile(source)
+            .map_err(|e| TurfOpsError::Config(format!("Script rule '{}': {}", name, e)))?;
+
+        let has_entry = ast
+            .iter_functions()
+            .any(|f| f.name == ENTRY_POINT && f.params.len() == 3);
+        if !has_entry {
+            return Err(TurfOpsError::Config(format!(
+                "Script rule '{}' must define fn {}(env, profile, history)",
+                name, ENTRY_POINT
+            )));
+        }
+
+        Ok(Self {
+            name: format!("Script: {}", name),
+            engine,
+            ast,
+        })
+    }
+
+    fn run(
+        &self,
+        env: &EnvironmentalSummary,
+        profile: &LawnProfile,
+        history: &[Application],
+    ) -> std::result::Result<Option<ScriptRecommendation>, String> {
+        let expose = |what: &str, value: std::result::Result<Dynamic, _>| {
+            value.map_err(|e: Box<rhai::EvalAltResult>| format!("Cannot expose {}: {}", what, e))
+        };
+        let args = (
+            expose("env", rhai::serde::to_dynamic(env))?,
+            expose("profile", rhai::serde::to_dynamic(profile))?,
+            expose("history", rhai::serde::to_dynamic(history))?,
+        );
+
+        let result: Dynamic = self
+            .engine
+            .call_fn(&mut Scope::new(), &self.ast, ENTRY_POINT, args)
+            .map_err(|e| e.to_string())?;
+
+        if result.is_unit() {
+            return Ok(None);
+        }
+        rhai::serde::from_dynamic(&result)
+            .map(Some)
+            .map_err(|e| format!("Invalid return value: {}", e))
+    }
+}
+
+impl Rule for ScriptRule {
+    fn evaluate(
+        &self,
+        env: &EnvironmentalSummary,
+        profile: &LawnProfile,
+        history: &[Application],
+    ) -> Option<Recommendation> {
+        self.explain(env, profile, history).ok()
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn explain(
+        &self,
+        env: &EnvironmentalSummary,
+        profile: &LawnProfile,
+        history: &[Application],
+    ) -> std::result::Result<Recommendation, Gate> {
+        let out = match self.run(env, profile, history) {
+            Ok(Some(out)) => out,
+            Ok(None) => {
+                return Err(Gate::ConditionsNotMet(
+                    "Script returned no recommendation".into(),
+                ))
+            }
+            Err(e) => {
+                tracing::warn!(rule = %self.name, error = %e, "Script rule failed");
+                return Err(Gate::ConditionsNotMet(format!("Script error: {}", e)));
+            }
+        };
+
+        let mut rec = Recommendation::new(
+            out.id,
+            out.category,
+            out.severity,
+            out.title,
+            out.description,
+        )
+        .with_explanation(
+            out.explanation
+                .unwrap_or_else(|| format!("User-defined script rule ({}).", self.name)),
+        );
+        if let Some(action) = out.action {
+            rec = rec.with_action(action);
+        }
+        Ok(rec)
+    }
+}
+
+/// Load every `*.rhai` file in `dir` (sorted by file name). A script that fails to
+/// compile stops startup, matching how invalid custom rules are handled.
+pub fn load_script_rules(dir: &str) -> Result<Vec<ScriptRule>> {
+    let entries = std::fs::read_dir(dir)
+        .map_err(|e| TurfOpsError::Config(format!("Cannot read rules directory {}: {}", dir, e)))?;
+
+    let mut paths: Vec<_> = entries
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| p.extension().is_some_and(|ext| ext == "rhai"))
+        .collect();
+    paths.sort();
+
+    let engine = Arc::new(sandboxed_engine());
+    let rules = paths
+        .iter()
+        .map(|path| {
+            let source = std::fs::read_to_string(path).map_err(|e| {
+                TurfOpsError::Config(format!("Cannot read {}: {}", path.display(), e))
+            })?;
+            ScriptRule::compile(&script_name(path), &source, engine.clone())
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    tracing::info!(count = rules.len(), dir = %dir, "Script rules loaded");
+    Ok(rules)
+}
+
+fn script_name(path: &Path) -> String {
+    path.file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.display().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{DataSource, EnvironmentalReading, GrassType};
+    use chrono::Utc;
+
+    const FIRST_FEED: &str = r#"
+        fn evaluate(env, profile, history) {
+            let soil = env.soil_temp_7day_avg_f;
+            if soil == () || soil < 55.0 { return (); }
+            if history.len() > 0 { return (); }
+            #{
+                id: "script_first_feed",
+                title: "First Feeding for " + profile.name,
+                severity: "Advisory",
+                category: "Fertilizer",
+                description: `Soil averaging ${soil}°F`,
+            }
+        }
+    "#;
+
+    fn compile(source: &str) -> Result<ScriptRule> {
+        ScriptRule::compile("test", source, Arc::new(sandboxed_engine()))
+    }
+
+    fn profile() -> LawnProfile {
+        LawnProfile {
+            id: Some(1),
+            name: "Front".into(),
+            grass_type: GrassType::TallFescue,
+            usda_zone: "7a".into(),
+            soil_type: None,
+            lawn_size_sqft: None,
+            irrigation_type: None,
+            location: None,
+            noaa_station_wbanno: None,
+            dormant: false,
+            mowing_height_in: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn env(soil_avg: Option<f64>) -> EnvironmentalSummary {
+        EnvironmentalSummary {
+            current: Some(EnvironmentalReading::new(DataSource::SoilData)),
+            soil_temp_7day_avg_f: soil_avg,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn script_fires_with_returned_fields() {
+        let rule = compile(FIRST_FEED).unwrap();
+        let rec = rule.explain(&env(Some(58.0)), &profile(), &[]).unwrap();
+        assert_eq!(rec.id, "script_first_feed");
+        assert_eq!(rec.title, "First Feeding for Front");
+        assert_eq!(rec.severity, Severity::Advisory);
+        assert_eq!(rec.category, RecommendationCategory::Fertilizer);
+        assert_eq!(rule.name(), "Script: test");
+    }
+
+    #[test]
+    fn script_returning_unit_does_not_fire() {
+        let rule = compile(FIRST_FEED).unwrap();
+        assert!(rule.evaluate(&env(Some(50.0)), &profile(), &[]).is_none());
+        assert!(rule.evaluate(&env(None), &profile(), &[]).is_none());
+    }
+
+    #[test]
+    fn missing_entry_point_is_rejected() {
+        assert!(compile("fn check(env) { () }").is_err());
+        assert!(compile("fn evaluate(env, profile, history) {").is_err());
+    }
+
+    #[test]
+    fn runaway_script_is_stopped() {
+        let rule = compile("fn evaluate(env, profile, history) { loop {} }").unwrap();
+        let gate = rule.explain(&env(None), &profile(), &[]).unwrap_err();
+        assert!(matches!(gate, Gate::ConditionsNotMet(msg) if msg.starts_with("Script error")));
+    }
+
+    #[test]
+    fn imports_are_disabled() {
+        let rule =
+            compile(r#"fn evaluate(env, profile, history) { import "fs" as fs; () }"#).unwrap();
+        let gate = rule.explain(&env(None), &profile(), &[]).unwrap_err();
+        assert!(matches!(gate, Gate::ConditionsNotMet(msg) if msg.starts_with("Script error")));
+    }
+}
@@ -0,0 +1,293 @@
+use super::thresholds::*;
+use super::{current_date, Gate, Rule};
+use crate::models::{
+    product_info, Application, ApplicationType, DataSource, EnvironmentalSummary, LawnProfile,
+    Recommendation, RecommendationCategory, Severity,
+};
+use chrono::{Duration, NaiveDate};
+
+/// Herbicide/seeding interaction guard
+///
+/// Most pre-emergents (prodiamine, dithiopyr, pendimethalin) stop grass seed
+/// from rooting just as they stop crabgrass, and new seedlings are injured by
+/// them until they've been mowed a few times. Checks logged and planned
+/// (future-dated) applications on the same area for:
+/// - An Overseed inside a pre-emergent's residual window
+/// - A pre-emergent too soon after an Overseed
+///
+/// Residual and seeded-turf intervals come from the product catalog; unknown
+/// products get a conservative 90/60 days. Mesotrione and siduron are
+/// seeding-safe and never conflict.
+pub struct SeedingConflictRule;
+
+/// A pre-emergent and an Overseed that landed too close together.
+struct Conflict<'a> {
+    pre_emergent: &'a Application,
+    seeding: &'a Application,
+    /// The seeding fell inside the pre-emergent's residual (vs. the
+    /// pre-emergent following the seeding too soon).
+    seed_after_pre: bool,
+    /// Day the later application would have been safe.
+    safe_on: NaiveDate,
+}
+
+impl Conflict<'_> {
+    fn later(&self) -> &Application {
+        if self.seed_after_pre {
+            self.seeding
+        } else {
+            self.pre_emergent
+        }
+    }
+}
+
+/// Catalog intervals for a pre-emergent: (reseed interval, seeded-turf wait).
+fn intervals(app: &Application) -> (i64, i64) {
+    match app.product_name.as_deref().and_then(product_info) {
+        Some(p) => (p.reseed_interval_days, p.seeded_turf_days),
+        None => (
+            PRE_EMERGENT_DEFAULT_RESIDUAL_DAYS,
+            PRE_EMERGENT_DEFAULT_SEEDED_TURF_DAYS,
+        ),
+    }
+}
+
+/// Applications on different areas don't interact; whole-lawn ones touch every area.
+fn same_ground(a: &Application, b: &Application) -> bool {
+    a.area_id.is_none() || b.area_id.is_none() || a.area_id == b.area_id
+}
+
+fn conflict<'a>(pre: &'a Application, seed: &'a Application) -> Option<Conflict<'a>> {
+    if !same_ground(pre, seed) {
+        return None;
+    }
+    let (reseed_days, seeded_days) = intervals(pre);
+    let gap = (seed.application_date - pre.application_date).num_days();
+    if gap >= 0 && gap < reseed_days {
+        Some(Conflict {
+            pre_emergent: pre,
+            seeding: seed,
+            seed_after_pre: true,
+            safe_on: pre.application_date + Duration::days(reseed_days),
+        })
+    } else if gap < 0 && -gap < seeded_days {
+        Some(Conflict {
+            pre_emergent: pre,
+            seeding: seed,
+            seed_after_pre: false,
+            safe_on: seed.application_date + Duration::days(seeded_days),
+        })
+    } else {
+        None
+    }
+}
+
+impl Rule for SeedingConflictRule {
+    fn evaluate(
+        &self,
+        env: &EnvironmentalSummary,
+        profile: &LawnProfile,
+        history: &[Application],
+    ) -> Option<Recommendation> {
+        self.explain(env, profile, history).ok()
+    }
+
+    fn explain(
+        &self,
+        _env: &EnvironmentalSummary,
+        _profile: &LawnProfile,
+        history: &[Application],
+    ) -> Result<Recommendation, Gate> {
+        let today = current_date();
+        let recent_from = today - Duration::days(SEEDING_CONFLICT_LOOKBACK_DAYS);
+        let of_type = |kind: ApplicationType| {
+            history
+                .iter()
+                .filter(move |a| a.application_type == kind)
+                .collect::<Vec<_>>()
+        };
+        let pre_emergents = of_type(ApplicationType::PreEmergent);
+        let seedings = of_type(ApplicationType::Overseed);
+        if pre_emergents.is_empty() || seedings.is_empty() {
+            return Err(Gate::NotApplicable(
+                "Needs both a pre-emergent and an overseeding logged".into(),
+            ));
+        }
+
+        // Report the most recent (or furthest planned) conflict
+        let conflict = pre_emergents
+            .iter()
+            .flat_map(|pre| seedings.iter().filter_map(|seed| conflict(pre, seed)))
+            .filter(|c| c.later().application_date >= recent_from)
+            .max_by_key(|c| c.later().application_date)
+            .ok_or_else(|| {
+                Gate::ConditionsNotMet(
+                    "No recent seeding within a pre-emergent's residual window".into(),
+                )
+            })?;
+
+        let planned = conflict.later().application_date > today;
+        let pre_name = conflict
+            .pre_emergent
+            .product_name
+            .clone()
+            .unwrap_or_else(|| "pre-emergent".into());
+        let pre_date = conflict.pre_emergent.application_date;
+        let seed_date = conflict.seeding.application_date;
+
+        let (title, description, action) = match (conflict.seed_after_pre, planned) {
+            (true, true) => (
+                "Planned Seeding Inside Pre-Emergent Residual",
+                format!(
+                    "Seeding planned for {} falls inside the residual of {} applied {}. \
+                     The seed is unlikely to establish.",
+                    seed_date, pre_name, pre_date
+                ),
+                format!(
+                    "Move the seeding to {} or later. If it can't wait, rake or core \
+                     aerate heavily to break the barrier, or seed with a mesotrione \
+                     (Tenacity) or siduron product instead.",
+                    conflict.safe_on
+                ),
+            ),
+            (true, false) => (
+                "Seeded Inside Pre-Emergent Residual",
+                format!(
+                    "Seed went down {}, inside the residual of {} applied {}. Expect \
+                     poor or patchy germination.",
+                    seed_date, pre_name, pre_date
+                ),
+                format!(
+                    "Watch germination over the next 2-3 weeks. Reseed thin areas on or \
+                     after {}, when the barrier has broken down.",
+                    conflict.safe_on
+                ),
+            ),
+            (false, true) => (
+                "Planned Pre-Emergent Too Soon After Seeding",
+                format!(
+                    "{} is planned for {}, only {} days after seeding on {}. It can \
+                     injure seedlings that haven't established.",
+                    pre_name,
+                    pre_date,
+                    (pre_date - seed_date).num_days(),
+                    seed_date
+                ),
+                format!(
+                    "Hold the pre-emergent until {} (after the new grass has been mowed \
+                     2-3 times). Mesotrione (Tenacity) is labeled for newly seeded turf \
+                     if crabgrass pressure can't wait.",
+                    conflict.safe_on
+                ),
+            ),
+            (false, false) => (
+                "Pre-Emergent Applied Too Soon After Seeding",
+                format!(
+                    "{} went down {}, only {} days after seeding on {}. Seedlings may \
+                     thin or stall.",
+                    pre_name,
+                    pre_date,
+                    (pre_date - seed_date).num_days(),
+                    seed_date
+                ),
+                "Keep the seeded area evenly moist and avoid further herbicides until \
+                 the new grass is established. Reseed bare spots after the residual \
+                 breaks down."
+                    .to_string(),
+            ),
+        };
+
+        Ok(Recommendation::new(
+            format!("seeding_conflict_{}", conflict.later().application_date),
+            RecommendationCategory::Overseeding,
+            Severity::Warning,
+            title,
+            description,
+        )
+        .with_explanation(
+            "Pre-emergent herbicides form a chemical barrier in the top of the soil that \
+             stops germinating seeds from rooting — grass seed as well as crabgrass. \
+             The barrier lasts months, and young grass stays sensitive to it until it \
+             has been mowed a few times. Label intervals differ by product.",
+        )
+        .with_data_point("Pre-Emergent", &pre_name, DataSource::History.as_str())
+        .with_data_point("Applied", pre_date, DataSource::History.as_str())
+        .with_data_point("Seeded", seed_date, DataSource::History.as_str())
+        .with_data_point("Safe On", conflict.safe_on, DataSource::Calculated.as_str())
+        .with_action(action))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::with_current_date;
+    use super::*;
+    use crate::models::GrassType;
+    use crate::testing::{application, date, profile};
+
+    fn pre_emergent(product: &str, on: NaiveDate) -> Application {
+        let mut app = application(ApplicationType::PreEmergent, on);
+        app.product_name = Some(product.into());
+        app
+    }
+
+    fn run(history: &[Application]) -> Result<Recommendation, Gate> {
+        with_current_date(date(2025, 6, 1), || {
+            SeedingConflictRule.explain(
+                &EnvironmentalSummary::default(),
+                &profile(GrassType::TallFescue),
+                history,
+            )
+        })
+    }
+
+    #[test]
+    fn planned_seeding_inside_prodiamine_residual() {
+        let history = vec![
+            pre_emergent("Barricade 65WG", date(2025, 4, 1)),
+            application(ApplicationType::Overseed, date(2025, 6, 15)),
+        ];
+        let rec = run(&history).unwrap();
+        assert_eq!(rec.title, "Planned Seeding Inside Pre-Emergent Residual");
+        // 120-day prodiamine residual
+        assert!(rec.suggested_action.unwrap().contains("2025-07-30"));
+    }
+
+    #[test]
+    fn pre_emergent_too_soon_after_seeding() {
+        let history = vec![
+            application(ApplicationType::Overseed, date(2025, 5, 10)),
+            pre_emergent("Dimension", date(2025, 5, 25)),
+        ];
+        let rec = run(&history).unwrap();
+        assert_eq!(rec.title, "Pre-Emergent Applied Too Soon After Seeding");
+        assert_eq!(rec.id, "seeding_conflict_2025-05-25");
+    }
+
+    #[test]
+    fn seeding_safe_products_and_separate_areas_dont_conflict() {
+        let tenacity = vec![
+            pre_emergent("Tenacity", date(2025, 5, 20)),
+            application(ApplicationType::Overseed, date(2025, 5, 20)),
+        ];
+        assert!(matches!(run(&tenacity), Err(Gate::ConditionsNotMet(_))));
+
+        let mut front = pre_emergent("prodiamine", date(2025, 4, 1));
+        front.area_id = Some(1);
+        let mut back = application(ApplicationType::Overseed, date(2025, 5, 20));
+        back.area_id = Some(2);
+        assert!(matches!(
+            run(&[front, back]),
+            Err(Gate::ConditionsNotMet(_))
+        ));
+    }
+
+    #[test]
+    fn old_conflicts_age_out() {
+        let history = vec![
+            pre_emergent("prodiamine", date(2025, 3, 1)),
+            application(ApplicationType::Overseed, date(2025, 4, 1)),
+        ];
+        assert!(matches!(run(&history), Err(Gate::ConditionsNotMet(_))));
+    }
+}
@@ -1,10 +1,11 @@
 use super::thresholds::*;
-use super::Rule;
+use super::{covered_soil_temp_avg, current_date, Rule};
+use crate::logic::soil_anomaly;
 use crate::models::{
     Application, ApplicationType, DataSource, EnvironmentalSummary, LawnProfile, Recommendation,
-    RecommendationCategory, Severity,
+    RecommendationCategory, Severity, SquareFeet,
 };
-use chrono::{Datelike, Local, NaiveDate};
+use chrono::{Datelike, Duration, NaiveDate};
 
 /// Spring nitrogen delay rule
 ///
@@ -19,6 +20,9 @@ use chrono::{Datelike, Local, NaiveDate};
 /// - Let the lawn "wake up" naturally first
 ///
 /// This rule warns against early nitrogen and advises patience.
+///
+/// The Feb - May season and May 1 cutoff move earlier or later with the
+/// spring when soil runs warmer or colder than normal for the week.
 pub struct SpringNitrogenRule;
 
 impl Rule for SpringNitrogenRule {
@@ -33,23 +37,33 @@ impl Rule for SpringNitrogenRule {
             return None;
         }
 
-        let today = Local::now().date_naive();
+        let today = current_date();
         let current_year = today.year();
 
+        // Where the spring stands: the calendar, moved by a warm or cold spring
+        let lead = Duration::days(soil_anomaly::spring_lead_days(env));
+        let season_day = today + lead;
+        let timed = |rec: Recommendation| match soil_anomaly::spring_shift(env) {
+            Some(shift) => {
+                rec.with_data_point("Spring Timing", shift, DataSource::SoilData.as_str())
+            }
+            None => rec,
+        };
+
         // Only relevant in late winter/early spring (Feb - May)
-        let month = today.month();
+        let month = season_day.month();
         if !(2..=5).contains(&month) {
             return None;
         }
 
         // Get soil temperature
-        let soil_temp_avg = env.soil_temp_7day_avg_f?;
+        let soil_temp_avg = covered_soil_temp_avg(env).ok()?;
 
         // GDD data
         let gdd_ytd = env.gdd_base50_ytd;
 
         // Check for spring fertilizer applications
-        let spring_start = NaiveDate::from_ymd_opt(current_year, 2, 1)?;
+        let spring_start = NaiveDate::from_ymd_opt(current_year, 2, 1)? - lead;
         let spring_fert_apps: Vec<&Application> = history
             .iter()
             .filter(|app| {
@@ -64,13 +78,13 @@ impl Rule for SpringNitrogenRule {
         // A4: May 1 hard cutoff (Missouri g6705)
         if month == 5 {
             if !has_spring_fert {
-                return Some(build_may_cutoff_warning(soil_temp_avg));
+                return Some(timed(build_may_cutoff_warning(soil_temp_avg)));
             }
             return None;
         }
 
         // Determine appropriate recommendation
-        if soil_temp_avg < SPRING_N_APPROACHING_SOIL_F {
+        let rec = if soil_temp_avg < SPRING_N_APPROACHING_SOIL_F {
             // Too cold - definitely don't fertilize
             if has_spring_fert {
                 // Already fertilized when too cold - warn about the mistake
@@ -102,7 +116,8 @@ impl Rule for SpringNitrogenRule {
         } else {
             // Above 65°F - late spring, different considerations
             None
-        }
+        };
+        rec.map(timed)
     }
 }
 
@@ -269,7 +284,7 @@ fn build_ready_to_fertilize(
     gdd_ytd: Option<f64>,
 ) -> Recommendation {
     let lawn_size = profile.lawn_size_sqft.unwrap_or(DEFAULT_LAWN_SIZE_SQFT);
-    let n_needed = lawn_size / 1000.0 * SPRING_N_RATE_LBS_PER_KSQFT;
+    let n_needed = SquareFeet(lawn_size).apply_rate(SPRING_N_RATE_LBS_PER_KSQFT);
 
     let gdd_note = if let Some(gdd) = gdd_ytd {
         if gdd >= SPRING_N_GDD_ESTABLISHED {
@@ -357,6 +372,10 @@ mod tests {
             soil_type: None,
             lawn_size_sqft: Some(5000.0),
             irrigation_type: None,
+            location: None,
+            noaa_station_wbanno: None,
+            dormant: false,
+            mowing_height_in: None,
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
         }
@@ -386,7 +405,7 @@ mod tests {
         let result = rule.evaluate(&env, &base_profile(), &[]);
         // The soil-temp/GDD branch only runs Feb-Apr; May returns the cutoff
         // recommendation regardless of GDD, and other months return None.
-        let month = Local::now().date_naive().month();
+        let month = current_date().month();
         if (2..=4).contains(&month) {
             if let Some(rec) = result {
                 // Should still be "almost ready" (Info), not promoted
@@ -402,7 +421,7 @@ mod tests {
         env.gdd_base50_ytd = Some(50.0);
         let rule = SpringNitrogenRule;
         let result = rule.evaluate(&env, &base_profile(), &[]);
-        let month = Local::now().date_naive().month();
+        let month = current_date().month();
         if (2..=4).contains(&month) {
             if let Some(rec) = result {
                 // With GDD >= 50, should be promoted to spring_n_ready
@@ -419,7 +438,7 @@ mod tests {
         env.gdd_base50_ytd = Some(150.0);
         let rule = SpringNitrogenRule;
         let result = rule.evaluate(&env, &base_profile(), &[]);
-        let month = Local::now().date_naive().month();
+        let month = current_date().month();
         if (2..=4).contains(&month) {
             if let Some(rec) = result {
                 assert_eq!(rec.id, "spring_n_ready");
@@ -431,4 +450,45 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn warm_spring_opens_the_season_early() {
+        use crate::logic::rules::with_current_date;
+        use crate::models::SoilTempAnomaly;
+
+        let mut env = base_env(45.0);
+        let date = |m, d| NaiveDate::from_ymd_opt(2026, m, d).unwrap();
+
+        // Jan 25 is before the season on the calendar
+        with_current_date(date(1, 25), || {
+            assert!(SpringNitrogenRule
+                .evaluate(&env, &base_profile(), &[])
+                .is_none());
+        });
+
+        // Soil 4.2°F warm puts the spring two weeks ahead
+        env.soil_temp_anomaly = Some(SoilTempAnomaly {
+            normal_f: 40.8,
+            anomaly_f: 4.2,
+            years: 5,
+            lead_days: 14,
+        });
+        with_current_date(date(1, 25), || {
+            let rec = SpringNitrogenRule
+                .evaluate(&env, &base_profile(), &[])
+                .unwrap();
+            assert_eq!(rec.id, "spring_n_wait");
+            assert!(rec
+                .data_points
+                .iter()
+                .any(|dp| dp.value == "14 days early: soil 4.2°F above normal"));
+        });
+        // ...and the May 1 cutoff arrives in mid-April
+        with_current_date(date(4, 20), || {
+            let rec = SpringNitrogenRule
+                .evaluate(&env, &base_profile(), &[])
+                .unwrap();
+            assert_eq!(rec.id, "spring_n_may_cutoff");
+        });
+    }
 }
@@ -0,0 +1,298 @@
+use super::thresholds::*;
+use super::{covered_ambient_temp_avg, current_date, Gate, Rule};
+use crate::models::{
+    Application, ApplicationType, DataSource, EnvironmentalSummary, LawnProfile, Recommendation,
+    RecommendationCategory, Severity,
+};
+use chrono::{Duration, NaiveDate};
+
+/// Tall grass rule — the one-third rule against modeled growth since the last mow
+///
+/// Growth since the last logged Mowing is estimated from growing degree days
+/// (base 50°F, from the 7-day average temp) and the week's water:
+/// - ~0.012" of leaf per GDD, capped at a 75°F day for cool-season grass
+/// - Half that when rain plus logged irrigation came to under 1/2" for the week
+///
+/// Growth is measured against the profile's target height of cut (or the grass
+/// type's default). When mowing back to it would take off more than 1/3 of the
+/// blade, the rule suggests a staged height reduction:
+/// - 2 cuts: Advisory
+/// - 3+ cuts: Warning (scalping risk — cutting that far at once exposes brown
+///   stems and stresses the crown)
+pub struct TallGrassRule;
+
+/// Modeled leaf growth since the last mowing.
+struct Growth {
+    last_mow: NaiveDate,
+    days: i64,
+    gdd: f64,
+    /// Rain plus logged irrigation over the last 7 days, when rain is known.
+    water_mm: Option<f64>,
+    inches: f64,
+}
+
+fn last_mow(history: &[Application], today: NaiveDate) -> Option<NaiveDate> {
+    history
+        .iter()
+        .filter(|a| a.application_type == ApplicationType::Mowing && a.application_date <= today)
+        .map(|a| a.application_date)
+        .max()
+}
+
+fn estimate_growth(
+    env: &EnvironmentalSummary,
+    profile: &LawnProfile,
+    history: &[Application],
+    today: NaiveDate,
+) -> Result<Growth, Gate> {
+    let last_mow =
+        last_mow(history, today).ok_or_else(|| Gate::NotApplicable("No mowing logged".into()))?;
+    let ambient_avg = covered_ambient_temp_avg(env)?;
+
+    let mut daily_gdd = (ambient_avg - 50.0).max(0.0);
+    if profile.grass_type.is_cool_season() {
+        daily_gdd = daily_gdd.min(MOW_COOL_SEASON_MAX_DAILY_GDD);
+    }
+    if daily_gdd == 0.0 {
+        return Err(Gate::ConditionsNotMet(format!(
+            "7-day average {:.0}°F is too cool for top growth",
+            ambient_avg
+        )));
+    }
+
+    let days = (today - last_mow).num_days();
+    let gdd = daily_gdd * days.min(MOW_GROWTH_LOOKBACK_DAYS) as f64;
+
+    let week_start = today - Duration::days(7);
+    let irrigated = history
+        .iter()
+        .filter(|a| a.application_type == ApplicationType::Irrigation)
+        .filter(|a| a.application_date > week_start && a.application_date <= today)
+        .count() as f64;
+    let water_mm = env
+        .precipitation_7day_total_mm
+        .map(|rain| rain + irrigated * STRESS_IRRIGATION_MM);
+    let water_factor = match water_mm {
+        Some(mm) if mm < MOW_DRY_WEEK_MM => MOW_DRY_GROWTH_FACTOR,
+        _ => 1.0,
+    };
+
+    Ok(Growth {
+        last_mow,
+        days,
+        gdd,
+        water_mm,
+        inches: gdd * MOW_GROWTH_IN_PER_GDD * water_factor,
+    })
+}
+
+/// Heights for successive cuts from `current` down to `target`, none taking
+/// more than [`MOW_MAX_REMOVAL`] of the blade. Rounded to 1/4".
+fn staged_heights(current: f64, target: f64) -> Vec<f64> {
+    let mut cuts = Vec::new();
+    let mut height = current;
+    while height * (1.0 - MOW_MAX_REMOVAL) > target {
+        height *= 1.0 - MOW_MAX_REMOVAL;
+        cuts.push((height * 4.0).round() / 4.0);
+    }
+    cuts.push(target);
+    cuts.dedup();
+    cuts
+}
+
+impl Rule for TallGrassRule {
+    fn evaluate(
+        &self,
+        env: &EnvironmentalSummary,
+        profile: &LawnProfile,
+        history: &[Application],
+    ) -> Option<Recommendation> {
+        self.explain(env, profile, history).ok()
+    }
+
+    fn explain(
+        &self,
+        env: &EnvironmentalSummary,
+        profile: &LawnProfile,
+        history: &[Application],
+    ) -> Result<Recommendation, Gate> {
+        let today = current_date();
+        let growth = estimate_growth(env, profile, history, today)?;
+        let target = profile.target_mowing_height_in();
+        let height = target + growth.inches;
+        let removal = growth.inches / height;
+        if removal <= MOW_MAX_REMOVAL {
+            return Err(Gate::ConditionsNotMet(format!(
+                "About {:.1}\" of growth since {}; mowing to {:.1}\" takes {:.0}% of the blade",
+                growth.inches,
+                growth.last_mow,
+                target,
+                removal * 100.0
+            )));
+        }
+
+        let cuts = staged_heights(height, target);
+        let scalp_risk = cuts.len() >= MOW_SCALP_RISK_CUTS;
+        let severity = if scalp_risk {
+            Severity::Warning
+        } else {
+            Severity::Advisory
+        };
+        let plan = cuts
+            .iter()
+            .map(|h| format!("{:.2}\"", h))
+            .collect::<Vec<_>>()
+            .join(" → ");
+
+        let mut description = format!(
+            "The lawn has likely grown to about {:.1}\" in the {} days since the last mow. \
+             Cutting straight back to {:.1}\" would remove {:.0}% of the blade, more than \
+             the one-third rule allows.",
+            height,
+            growth.days,
+            target,
+            removal * 100.0
+        );
+        if scalp_risk {
+            description.push_str(
+                " At this length a single cut will scalp it, leaving brown stems and a \
+                 stressed crown.",
+            );
+        }
+
+        let mut rec = Recommendation::new(
+            "tall_grass",
+            RecommendationCategory::Mowing,
+            severity,
+            if scalp_risk {
+                "Overgrown Lawn: Scalping Risk"
+            } else {
+                "Lawn Past the One-Third Rule"
+            },
+            description,
+        )
+        .with_explanation(
+            "Removing more than a third of the leaf at once stops root growth while the \
+             plant rebuilds top growth, and exposes pale stems that burn in sun. Growth is \
+             modeled from growing degree days since the last logged mowing, slowed in dry \
+             weeks; an actual ruler reading beats the estimate.",
+        )
+        .with_data_point("Last Mowed", growth.last_mow, DataSource::History.as_str())
+        .with_data_point(
+            "GDD Since Mow",
+            format!("{:.0}", growth.gdd),
+            DataSource::Calculated.as_str(),
+        )
+        .with_data_point(
+            "Estimated Height",
+            format!("{:.1}\"", height),
+            DataSource::Calculated.as_str(),
+        )
+        .with_data_point(
+            "Target Height",
+            format!("{:.1}\"", target),
+            if profile.mowing_height_in.is_some() {
+                DataSource::Manual.as_str()
+            } else {
+                DataSource::Agronomic.as_str()
+            },
+        )
+        .with_action(format!(
+            "Lower the height in {} cuts, {} days apart: {}. Mow dry grass with a sharp \
+             blade and leave the clippings if they don't clump.",
+            cuts.len(),
+            MOW_STAGE_INTERVAL_DAYS,
+            plan
+        ));
+        if let Some(mm) = growth.water_mm {
+            rec = rec.with_data_point(
+                "Water (7d)",
+                format!("{:.0}mm", mm),
+                DataSource::Calculated.as_str(),
+            );
+        }
+
+        Ok(rec)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logic::rules::with_current_date;
+    use crate::models::GrassType;
+    use crate::testing::{application, at, date, profile, EnvBuilder};
+
+    fn run(
+        env: &EnvironmentalSummary,
+        profile: &LawnProfile,
+        history: &[Application],
+    ) -> Result<Recommendation, Gate> {
+        with_current_date(date(2026, 5, 20), || {
+            TallGrassRule.explain(env, profile, history)
+        })
+    }
+
+    #[test]
+    fn staged_heights_never_take_more_than_a_third() {
+        assert_eq!(staged_heights(4.5, 3.5), vec![3.5]);
+        assert_eq!(staged_heights(6.0, 3.0), vec![4.0, 3.0]);
+        assert_eq!(staged_heights(9.0, 3.0), vec![6.0, 4.0, 3.0]);
+    }
+
+    #[test]
+    fn long_gap_in_spring_flush_warns_of_scalping() {
+        let fescue = profile(GrassType::TallFescue);
+        let env = EnvBuilder::new(at(2026, 5, 20))
+            .weekly(66.0, 60.0, 20.0)
+            .build();
+
+        // Four days of 16 GDD is well under a third
+        let recent = [application(ApplicationType::Mowing, date(2026, 5, 16))];
+        assert!(matches!(
+            run(&env, &fescue, &recent),
+            Err(Gate::ConditionsNotMet(_))
+        ));
+
+        let gap = [application(ApplicationType::Mowing, date(2026, 5, 4))];
+        let rec = run(&env, &fescue, &gap).unwrap();
+        assert_eq!(rec.severity, Severity::Advisory);
+
+        let long_gap = [application(ApplicationType::Mowing, date(2026, 4, 22))];
+        let rec = run(&env, &fescue, &long_gap).unwrap();
+        assert_eq!(rec.severity, Severity::Warning);
+        assert!(rec.suggested_action.unwrap().contains("3.50\""));
+    }
+
+    #[test]
+    fn dry_week_and_lower_target_change_the_outcome() {
+        let gap = [application(ApplicationType::Mowing, date(2026, 5, 4))];
+        let dry = EnvBuilder::new(at(2026, 5, 20))
+            .weekly(66.0, 60.0, 0.0)
+            .build();
+        let fescue = profile(GrassType::TallFescue);
+        assert!(run(&dry, &fescue, &gap).is_err());
+
+        // Logged watering makes up the rain
+        let watered = [
+            gap[0].clone(),
+            application(ApplicationType::Irrigation, date(2026, 5, 18)),
+        ];
+        assert!(run(&dry, &fescue, &watered).is_ok());
+
+        let mut short = fescue.clone();
+        short.mowing_height_in = Some(2.0);
+        assert!(run(&dry, &short, &gap).is_ok());
+    }
+
+    #[test]
+    fn silent_without_a_logged_mowing() {
+        let env = EnvBuilder::new(at(2026, 5, 20))
+            .weekly(66.0, 60.0, 20.0)
+            .build();
+        assert!(matches!(
+            run(&env, &profile(GrassType::TallFescue), &[]),
+            Err(Gate::NotApplicable(_))
+        ));
+    }
+}
@@ -3,6 +3,14 @@
 // All numeric thresholds used across the 18 rule files are defined here
 // for easy reference, maintenance, and future configurability.
 
+// =============================================================================
+// Data coverage
+// =============================================================================
+
+/// Share of the expected hourly samples a 7-day temperature average needs
+/// before temperature-threshold rules act on it.
+pub const MIN_AGGREGATE_COVERAGE: f64 = 0.5;
+
 // =============================================================================
 // Temperature thresholds (°F)
 // =============================================================================
@@ -17,6 +25,25 @@ pub const HEAT_STRESS_WARNING_TEMP_F: f64 = 90.0;
 /// Temperature above which heat stress severity escalates to Critical.
 pub const HEAT_STRESS_CRITICAL_TEMP_F: f64 = 95.0;
 
+/// Overnight low above which cool-season turf can't recover from daytime heat.
+pub const WARM_NIGHT_LOW_F: f64 = 70.0;
+
+/// Consecutive warm nights that trigger a heat stress alert on their own.
+pub const WARM_NIGHTS_ALERT_COUNT: u32 = 2;
+
+/// Consecutive warm nights that escalate heat stress severity one level.
+pub const WARM_NIGHTS_ESCALATE_COUNT: u32 = 3;
+
+/// Forecast look-ahead for peak heat index and wet-bulb.
+pub const HEAT_STRESS_WINDOW_HOURS: u32 = 72;
+
+/// Days searched for a coming heat wave. Only One Call forecasts reach this
+/// far; the 5-day forecast just sees less of it.
+pub const HEAT_WAVE_HORIZON_DAYS: u32 = 8;
+
+/// Consecutive days at or above [`HEAT_STRESS_WARNING_TEMP_F`] that make a heat wave.
+pub const HEAT_WAVE_MIN_DAYS: usize = 3;
+
 /// Cool-season grass ideal growth range — low end (°F).
 pub const COOL_SEASON_IDEAL_LOW_F: f64 = 60.0;
 
@@ -37,6 +64,15 @@ pub const PRE_EMERGENT_URGENCY_SOIL_F: f64 = 55.0;
 /// Pre-emergent late window — efficacy drops significantly above this.
 pub const PRE_EMERGENT_LATE_SOIL_F: f64 = 70.0;
 
+/// Estimated crabgrass germination (%) past which a late pre-emergent is
+/// pointed at post-emergent products instead.
+pub const CRABGRASS_PRE_EMERGENT_FUTILE_PCT: f64 = 50.0;
+
+/// Pre-emergent season relative to the typical last spring frost: opens 9
+/// weeks before and closes 8 weeks after (Feb 1 - May 31 in zone 7a).
+pub const PRE_EMERGENT_SEASON_DAYS_BEFORE_LAST_FROST: i64 = 63;
+pub const PRE_EMERGENT_SEASON_DAYS_AFTER_LAST_FROST: i64 = 56;
+
 /// Grub control application window — lower bound (°F, 7-day avg).
 pub const GRUB_CONTROL_SOIL_LOW_F: f64 = 60.0;
 
@@ -64,6 +100,17 @@ pub const OVERSEED_PEAK_HIGH_F: f64 = 62.0;
 /// Fall overseeding — soil too warm, wait for cooler (°F).
 pub const OVERSEED_WARM_LIMIT_F: f64 = 75.0;
 
+/// Overseeding window opens this many days before the typical first fall
+/// frost and closes on it (Aug 15 - Oct 30 in zone 7a).
+pub const OVERSEED_WINDOW_DAYS_BEFORE_FROST: i64 = 76;
+
+/// Until this many days before the typical first frost, warm soil means
+/// waiting rather than seeding anyway (mid-September in zone 7a).
+pub const OVERSEED_WARM_WAIT_DAYS_BEFORE_FROST: i64 = 45;
+
+/// Seedlings need about 6 weeks of growth before a frost to survive winter.
+pub const OVERSEED_ESTABLISH_DAYS: i64 = 42;
+
 /// Fall fertilization — soil temp OK range lower bound (°F).
 pub const FALL_FERT_SOIL_LOW_F: f64 = 45.0;
 
@@ -166,21 +213,85 @@ pub const APP_WINDOW_MIN_AVG_F: f64 = 50.0;
 /// Forecast average high above which overseeding seedlings are stressed.
 pub const OVERSEED_FORECAST_HOT_F: f64 = 85.0;
 
+// -- Overseeding germination outlook --
+
+/// Germination outlook score (0-100) at or above which it's a good week to seed.
+pub const OVERSEED_OUTLOOK_GOOD_SCORE: f64 = 70.0;
+
+/// Germination outlook score at or above which seeding is marginal (below = poor).
+pub const OVERSEED_OUTLOOK_MARGINAL_SCORE: f64 = 45.0;
+
+/// How many forecast days the germination outlook looks ahead.
+pub const OVERSEED_OUTLOOK_DAYS: u32 = 14;
+
+// -- Germination tracking (after an Overseed application) --
+
+/// Days after seeding the daily watering countdown runs.
+pub const GERMINATION_TRACK_DAYS: i64 = 14;
+
+/// Days after seeding before the seed has typically germinated; until then the
+/// top 1/2 inch must never dry out.
+pub const GERMINATION_LIGHT_WATERING_DAYS: i64 = 7;
+
+/// Soil moisture below which the seedbed is drying out (volumetric fraction).
+pub const GERMINATION_DRY_SEEDBED: f64 = 0.20;
+
+/// Forecast rain (mm) today that replaces a watering cycle.
+pub const GERMINATION_RAIN_SKIP_MM: f64 = 5.0;
+
+/// Days after seeding that forecast frost/heat is flagged as a seedling threat.
+pub const SEEDLING_PROTECTION_DAYS: i64 = 42;
+
+/// Forecast days checked for seedling-threatening temperatures.
+pub const SEEDLING_WEATHER_LOOKAHEAD_DAYS: i64 = 3;
+
+/// Forecast low at or below which seedlings risk frost damage (°F).
+pub const SEEDLING_FROST_LOW_F: f64 = 32.0;
+
+/// Forecast low at or below which a hard freeze can kill seedlings (°F).
+pub const SEEDLING_HARD_FREEZE_LOW_F: f64 = 28.0;
+
+/// Forecast high at or above which shallow-rooted seedlings scorch (°F).
+pub const SEEDLING_HEAT_HIGH_F: f64 = 90.0;
+
+/// Herbicide and pre-emergent block after seeding, ryegrass (days). It
+/// establishes fastest, so four weeks and two mowings is enough.
+pub const SEEDLING_HERBICIDE_BLOCK_FAST_DAYS: i64 = 28;
+
+/// Herbicide block after seeding, tall and fine fescue (days).
+pub const SEEDLING_HERBICIDE_BLOCK_DAYS: i64 = 35;
+
+/// Herbicide block after seeding, bluegrass and mixes (days). Bluegrass can
+/// take three weeks just to germinate.
+pub const SEEDLING_HERBICIDE_BLOCK_SLOW_DAYS: i64 = 42;
+
 // =============================================================================
 // Soil moisture thresholds (volumetric fraction, 0.0–1.0)
 // =============================================================================
 
-/// Below this soil moisture, drought stress occurs and irrigation is critical.
+/// Below this soil moisture, drought stress occurs — hold off on fertilizer.
 pub const SOIL_MOISTURE_DROUGHT: f64 = 0.10;
 
 /// Below this soil moisture, severe drought — fertilizer severity escalates to Critical.
 pub const SOIL_MOISTURE_SEVERE_DROUGHT: f64 = 0.05;
 
-/// Irrigation warning threshold — moisture below this needs attention soon.
-pub const SOIL_MOISTURE_IRRIGATION_WARNING: f64 = 0.15;
+/// Plant-available water share below which irrigation is advised. Half the
+/// available water gone is the usual management-allowed depletion for turf.
+pub const IRRIGATION_PAW_ADVISORY: f64 = 0.50;
 
-/// Above this moisture, irrigation not needed.
-pub const SOIL_MOISTURE_ADEQUATE: f64 = 0.20;
+/// Plant-available water share below which irrigation is needed soon.
+pub const IRRIGATION_PAW_WARNING: f64 = 0.35;
+
+/// Plant-available water share below which turf is close to wilting.
+pub const IRRIGATION_PAW_CRITICAL: f64 = 0.20;
+
+/// Once an irrigation alert is on, plant-available water must recover above
+/// this to clear it. The band above `IRRIGATION_PAW_ADVISORY` stops readings
+/// that hover around the threshold from toggling the alert on every refresh.
+pub const IRRIGATION_PAW_CLEAR: f64 = 0.60;
+
+/// Minimum time a moisture-driven alert stays on before it may clear (hours).
+pub const SOIL_MOISTURE_ALERT_MIN_HOLD_HOURS: i64 = 12;
 
 /// Above this soil moisture, soil is saturated — avoid fertilizer (risk of leaching).
 pub const SOIL_MOISTURE_SATURATED: f64 = 0.40;
@@ -207,6 +318,33 @@ pub const HUMIDITY_APP_WINDOW_LOW: f64 = 70.0;
 /// Application window — max acceptable humidity.
 pub const HUMIDITY_APP_WINDOW_MAX: f64 = 85.0;
 
+// -- Leaf wetness proxy --
+
+/// Dew point spread (air temp minus dew point) below which leaves are treated as wet.
+pub const LEAF_WETNESS_DEW_SPREAD_F: f64 = 3.0;
+
+/// Look-ahead for the forecast leaf wetness proxy.
+pub const LEAF_WETNESS_WINDOW_HOURS: u32 = 24;
+
+/// Overnight leaf wetness: a forecast step counts as wet above this humidity...
+pub const OVERNIGHT_WET_HUMIDITY_PERCENT: f64 = 90.0;
+
+/// ...or with the dew point within this many degrees of the air temperature.
+pub const OVERNIGHT_WET_DEW_SPREAD_F: f64 = 2.0;
+
+/// Local hours bounding a night for the overnight leaf wetness estimate (6 pm to 8 am).
+pub const OVERNIGHT_START_HOUR: u32 = 18;
+pub const OVERNIGHT_END_HOUR: u32 = 8;
+
+/// Nights ahead covered by the overnight leaf wetness estimate.
+pub const OVERNIGHT_WETNESS_NIGHTS: u64 = 3;
+
+/// Leaf wetness hours at which dollar spot infection becomes likely (NC State: 10-12 hrs).
+pub const LEAF_WETNESS_DISEASE_HOURS: f64 = 10.0;
+
+/// Leaf wetness hours that strongly favor foliar disease (gray leaf spot: 14+ hrs).
+pub const LEAF_WETNESS_SEVERE_HOURS: f64 = 14.0;
+
 // =============================================================================
 // Precipitation thresholds
 // =============================================================================
@@ -226,6 +364,12 @@ pub const PRECIP_PROB_LIKELY: f64 = 0.5;
 /// Precipitation probability threshold for Pythium/thunderstorm risk.
 pub const PRECIP_PROB_THUNDERSTORM: f64 = 0.6;
 
+/// Application window — thunderstorm probability that rules a day out.
+pub const APP_WINDOW_STORM_EXCLUDE_PROB: f64 = 0.3;
+
+/// Application window — thunderstorm probability that adds a lightning advisory.
+pub const APP_WINDOW_STORM_ADVISORY_PROB: f64 = 0.1;
+
 /// Rain delay — critical probability threshold.
 pub const RAIN_DELAY_CRITICAL_PROB: f64 = 0.7;
 
@@ -248,6 +392,132 @@ pub const WIND_APP_WINDOW_MAX_MPH: f64 = 10.0;
 /// Application window — calm wind threshold (bonus for scoring).
 pub const WIND_CALM_MPH: f64 = 5.0;
 
+/// Spray window — max gust; gusts carry fine droplets off target.
+pub const SPRAY_MAX_GUST_MPH: f64 = 15.0;
+
+/// Spray window — local hours searched, [start, end). Mornings are calmest
+/// before daytime heating stirs up the wind.
+pub const SPRAY_MORNING_START_HOUR: u32 = 5;
+pub const SPRAY_MORNING_END_HOUR: u32 = 12;
+
+// =============================================================================
+// Trend thresholds (mean change between consecutive windows)
+// =============================================================================
+
+/// Soil temperature change (°F) that counts as rising/falling.
+pub const TREND_SOIL_TEMP_F: f64 = 2.0;
+
+/// Ambient temperature change (°F); air swings more than soil, so the bar is higher.
+pub const TREND_AMBIENT_TEMP_F: f64 = 3.0;
+
+/// Volumetric soil moisture change (m³/m³) that counts as wetting/drying.
+pub const TREND_SOIL_MOISTURE: f64 = 0.02;
+
+// =============================================================================
+// Fall pest scouting thresholds (fall armyworm, sod webworm)
+// =============================================================================
+
+/// Forecast daily high (°F) warm enough for active larval feeding.
+pub const FALL_PEST_HIGH_F: f64 = 75.0;
+
+/// Forecast daily humidity (%) that favors outbreaks.
+pub const FALL_PEST_HUMIDITY: f64 = 70.0;
+
+/// Overnight low (°F) that keeps moths flying and larvae feeding through the night.
+pub const FALL_PEST_WARM_NIGHT_F: f64 = 65.0;
+
+/// Consecutive warm nights that, with sustained humidity, escalate to Warning.
+pub const FALL_PEST_WARM_NIGHTS_ESCALATE: u32 = 3;
+
+/// 7-day average humidity (%) that, with warm nights, escalates to Warning.
+pub const FALL_PEST_HUMIDITY_ESCALATE: f64 = 80.0;
+
+/// Days a logged scouting event quiets the reminder.
+pub const SCOUTING_INTERVAL_DAYS: i64 = 7;
+
+/// Days after overseeding during which seedlings are most vulnerable to feeding.
+pub const SEEDLING_VULNERABLE_DAYS: i64 = 45;
+
+// =============================================================================
+// Winter injury thresholds
+// =============================================================================
+
+/// Soil temperature (°F) at or below which the ground is treated as frozen.
+pub const SOIL_FROZEN_F: f64 = 32.0;
+
+/// Forecast snow (mm, 5 days) that counts as a meaningful snow cover.
+pub const SNOW_COVER_MIN_MM: f64 = 10.0;
+
+/// Days with highs at or below freezing after snowfall that keep the cover in place.
+pub const SNOW_COVER_PERSIST_DAYS: usize = 3;
+
+/// Daily high (°F) at or below which snow cover isn't expected to melt.
+pub const SNOW_COVER_MAX_HIGH_F: f64 = 34.0;
+
+/// Look-back for late-season nitrogen that leaves lush, snow mold-prone growth.
+pub const LATE_N_LOOKBACK_DAYS: i64 = 45;
+
+// =============================================================================
+// Fall nitrogen cutoff
+// =============================================================================
+
+/// Daily low (°F) at or below which a day counts as the first fall frost.
+pub const FROST_LOW_F: f64 = 32.0;
+
+/// Prior years of station frost history needed before it replaces the zone estimate.
+pub const FROST_HISTORY_MIN_YEARS: usize = 3;
+
+/// Year-to-year spread (days, one standard deviation) assumed for a zone's
+/// frost dates; NOAA normals put the 10% and 50% dates about two weeks apart.
+pub const FROST_ZONE_STD_DEV_DAYS: f64 = 11.0;
+
+/// Smallest spread fitted to station history, so a few similar years don't
+/// make the dates look more certain than they are.
+pub const FROST_MIN_STD_DEV_DAYS: f64 = 7.0;
+
+/// Frost risk quoted in rule advice ("10% chance of frost after Apr 19").
+pub const FROST_ADVICE_RISK: f64 = 0.10;
+
+/// Soil normal — days of the 7-day window a prior year needs recorded to
+/// count toward the calendar-week normal, and years needed for a normal.
+pub const SOIL_NORMAL_MIN_DAYS: usize = 4;
+pub const SOIL_NORMAL_MIN_YEARS: usize = 2;
+
+/// Soil normal — anomaly (°F) below which the season runs on the calendar.
+pub const SOIL_ANOMALY_MIN_F: f64 = 2.0;
+
+/// Soil normal — how fast 10cm soil warms through spring (°F/day, ~40°F in
+/// early March to ~65°F by mid-May), to turn an anomaly into days of lead.
+pub const SPRING_SOIL_WARMING_F_PER_DAY: f64 = 0.3;
+
+/// Soil normal — most a spring window moves either way (days).
+pub const SPRING_SHIFT_MAX_DAYS: i64 = 21;
+
+/// Warm-season grasses: last safe nitrogen this many days before the average
+/// first frost, so new growth hardens off before dormancy.
+pub const WARM_SEASON_N_CUTOFF_DAYS_BEFORE_FROST: i64 = 42;
+
+/// Cool-season grasses keep taking up nitrogen after the first frost (the
+/// winterizer), but not once the ground is freezing: last safe date this many
+/// days after the average first frost.
+pub const COOL_SEASON_N_CUTOFF_DAYS_AFTER_FROST: i64 = 42;
+
+/// Look-back for logged fertilizer past the cutoff; planned (future-dated)
+/// applications are always checked.
+pub const N_CUTOFF_LOOKBACK_DAYS: i64 = 14;
+
+/// Late-season N (lbs N per 1000 sqft, summed) considered heavy.
+pub const LATE_N_HEAVY_LBS_PER_KSQFT: f64 = 1.0;
+
+/// Average daily wind (mph) that drives desiccation on exposed, frozen turf.
+pub const DESICCATION_WIND_MPH: f64 = 15.0;
+
+/// Average humidity (%) below which winter air is drying.
+pub const DESICCATION_HUMIDITY_MAX: f64 = 60.0;
+
+/// Dry, windy days without snow cover that escalate desiccation to Warning.
+pub const DESICCATION_WARNING_DAYS: usize = 2;
+
 // =============================================================================
 // Time / duration thresholds
 // =============================================================================
@@ -255,15 +525,30 @@ pub const WIND_CALM_MPH: f64 = 5.0;
 /// Days remaining in grub control window before severity escalates.
 pub const GRUB_URGENCY_DAYS: i64 = 14;
 
+/// Days before a seasonal window closes (unmet) that escalate to Critical.
+pub const WINDOW_CLOSING_DAYS: i64 = 7;
+
 /// Overseeding — days remaining that triggers "running low on time" escalation.
 pub const OVERSEED_LOW_TIME_DAYS: i64 = 21;
 
 /// Overseeding — days remaining for secondary urgency check.
 pub const OVERSEED_URGENT_DAYS: i64 = 14;
 
+/// How far ahead the "Coming up" outlook projects window openings (days).
+pub const WINDOW_OUTLOOK_MAX_DAYS: i64 = 21;
+
 /// Days between fall fertilizer applications.
 pub const FALL_FERT_MIN_INTERVAL_DAYS: i64 = 21;
 
+/// Fall fertilization phases relative to the typical first fall frost (zone
+/// 7a's Oct 30 gives Sep 1, Oct 1, Nov 1 and Nov 30): early recovery feeding
+/// from 59 days before, the main feeding from 29 days before, the winterizer
+/// from 2 days after, and nothing more from 32 days after.
+pub const FALL_FERT_EARLY_DAYS_BEFORE_FROST: i64 = 59;
+pub const FALL_FERT_MID_DAYS_BEFORE_FROST: i64 = 29;
+pub const FALL_FERT_LATE_DAYS_AFTER_FROST: i64 = 2;
+pub const FALL_FERT_END_DAYS_AFTER_FROST: i64 = 32;
+
 /// Days for nitrogen deficiency check (red thread / dollar spot risk).
 pub const N_DEFICIENCY_DAYS_45: i64 = 45;
 
@@ -273,6 +558,32 @@ pub const N_DEFICIENCY_DAYS_60: i64 = 60;
 /// Broadleaf herbicide — suppression window after overseeding (days).
 pub const HERBICIDE_OVERSEED_BUFFER_DAYS: i64 = 60;
 
+/// Pre-emergent residual assumed when the product isn't in the catalog (days).
+pub const PRE_EMERGENT_DEFAULT_RESIDUAL_DAYS: i64 = 90;
+
+/// Days after seeding before an uncatalogued pre-emergent is safe on new grass.
+pub const PRE_EMERGENT_DEFAULT_SEEDED_TURF_DAYS: i64 = 60;
+
+/// Efficacy review — a pre-emergent down more than this many days before
+/// soil crossed 50°F is judged early (residual spent before germination peaks).
+pub const PRE_EMERGENT_MAX_LEAD_DAYS: i64 = 21;
+
+/// Efficacy review — protection assumed from a fungicide application (days).
+/// Labels run 14-28 days; 21 is the common preventive interval.
+pub const FUNGICIDE_PROTECTION_DAYS: i64 = 21;
+
+/// Efficacy review — disease-risk days in the week before a fungicide at
+/// which it's judged curative (late) rather than preventive.
+pub const FUNGICIDE_LATE_RISK_DAYS: usize = 3;
+
+/// Efficacy review — hours at or above `HUMIDITY_DISEASE_RISK` that, with a
+/// warm night, make a day disease-favorable.
+pub const DISEASE_HUMID_HOURS: usize = 10;
+
+/// Seeding conflicts are reported while the later application is no older
+/// than this (days). Planned (future-dated) applications are always checked.
+pub const SEEDING_CONFLICT_LOOKBACK_DAYS: i64 = 30;
+
 /// Forecast rain check window for irrigation (hours).
 pub const IRRIGATION_FORECAST_HOURS: u32 = 120;
 
@@ -340,3 +651,90 @@ pub const SPRING_HERBICIDE_GDD_LOW: f64 = 50.0;
 
 /// Broadleaf herbicide spring — upper bound, weeds less susceptible (GDD base 50).
 pub const SPRING_HERBICIDE_GDD_HIGH: f64 = 150.0;
+
+// -- Turf stress index (logic/stress_index.rs) --
+
+/// Stress index — daily high at which heat starts to count (cool-season turf).
+pub const STRESS_HEAT_START_F: f64 = 80.0;
+
+/// Stress index — daily high at which heat stress maxes out.
+pub const STRESS_HEAT_MAX_F: f64 = HEAT_STRESS_CRITICAL_TEMP_F;
+
+/// Stress index — trailing window for the ET water deficit (days).
+pub const STRESS_DEFICIT_WINDOW_DAYS: usize = 7;
+
+/// Stress index — weekly deficit that maxes out drought stress (mm, ~1 inch:
+/// a full week's water need for cool-season turf in summer).
+pub const STRESS_DEFICIT_MAX_MM: f64 = 25.4;
+
+/// Stress index — water assumed per logged irrigation (mm, ~1/2 inch).
+pub const STRESS_IRRIGATION_MM: f64 = 12.7;
+
+/// Stress index — hours at or above `HUMIDITY_DISEASE_RISK` that max out
+/// disease pressure (with a warm night).
+pub const STRESS_HUMID_HOURS_MAX: f64 = 12.0;
+
+/// Stress index — nightly low range over which humid hours start to matter
+/// (cool nights suppress brown patch and pythium).
+pub const STRESS_WARM_NIGHT_START_F: f64 = 60.0;
+pub const STRESS_WARM_NIGHT_FULL_F: f64 = 70.0;
+
+/// Stress index — traffic journal notes in the trailing window that max out
+/// wear stress.
+pub const STRESS_TRAFFIC_NOTES_MAX: f64 = 3.0;
+
+/// Stress index — score at which the day counts as High stress.
+pub const STRESS_HIGH: f64 = 60.0;
+
+/// Stress index — score at which the day counts as Severe stress.
+pub const STRESS_SEVERE: f64 = 80.0;
+
+/// Stress index — consecutive High days that suppress fertilizer and raise
+/// the recommended mowing height.
+pub const STRESS_SUSTAINED_DAYS: u32 = 3;
+
+// -- Dormancy (logic/rules/dormancy.rs) --
+
+/// Dormancy — 7-day soil temp below which cool-season turf stops growing
+/// for the winter.
+pub const COOL_SEASON_DORMANT_SOIL_F: f64 = 40.0;
+
+/// Dormancy — 7-day soil temp below which warm-season turf browns out for
+/// the winter.
+pub const WARM_SEASON_DORMANT_SOIL_F: f64 = 50.0;
+
+/// Dormancy — 7-day soil temp above which cool-season roots stop growing;
+/// with dry soil the lawn goes summer-dormant.
+pub const SUMMER_DORMANT_SOIL_F: f64 = 80.0;
+
+/// Dormancy — share of plant-available water (0 = wilting point) at or below
+/// which hot soil counts as summer dormancy rather than heat stress.
+pub const SUMMER_DORMANT_AVAILABLE_WATER: f64 = 0.1;
+
+// -- Tall grass / one-third rule (logic/rules/tall_grass.rs) --
+
+/// Tall grass — vertical leaf growth per GDD (base 50°F) in a well-watered
+/// week (inches). About 1.25"/week at a 65°F average.
+pub const MOW_GROWTH_IN_PER_GDD: f64 = 0.012;
+
+/// Tall grass — daily GDD past which cool-season growth stops speeding up
+/// (a 75°F average day); heat slows it rather than pushing it.
+pub const MOW_COOL_SEASON_MAX_DAILY_GDD: f64 = 25.0;
+
+/// Tall grass — rain plus logged irrigation over 7 days (mm, ~1/2 inch)
+/// below which growth is scaled back by `MOW_DRY_GROWTH_FACTOR`.
+pub const MOW_DRY_WEEK_MM: f64 = 12.7;
+pub const MOW_DRY_GROWTH_FACTOR: f64 = 0.5;
+
+/// Tall grass — most of the blade one mowing should remove (the one-third rule).
+pub const MOW_MAX_REMOVAL: f64 = 1.0 / 3.0;
+
+/// Tall grass — longest gap the 7-day average is extrapolated over (days).
+/// Older mowings count growth for this many days only.
+pub const MOW_GROWTH_LOOKBACK_DAYS: i64 = 28;
+
+/// Tall grass — staged cuts at or above which scalping becomes the risk.
+pub const MOW_SCALP_RISK_CUTS: usize = 3;
+
+/// Tall grass — days to wait between staged cuts.
+pub const MOW_STAGE_INTERVAL_DAYS: i64 = 3;
@@ -0,0 +1,271 @@
+//! Fixed seasonal application windows. Several rules go silent once their
+//! window ends, so a window that closes with no matching application logged
+//! is a missed window: recorded as an event and listed on the season report.
+//! [`WindowClosingRule`] escalates to Critical in each window's last week.
+
+use super::thresholds::WINDOW_CLOSING_DAYS;
+use super::{current_date, Gate, Rule};
+use crate::models::{
+    Application, ApplicationType, DataSource, EnvironmentalSummary, LawnProfile, Recommendation,
+    RecommendationCategory, Severity,
+};
+use chrono::{Datelike, NaiveDate};
+use serde::Serialize;
+
+pub struct SeasonalWindow {
+    pub id: &'static str,
+    pub name: &'static str,
+    /// (month, day) the window opens and closes, both inclusive.
+    pub opens: (u32, u32),
+    pub closes: (u32, u32),
+    /// Logging any of these inside the window meets it.
+    pub types: &'static [ApplicationType],
+    pub category: RecommendationCategory,
+    pub cool_season_only: bool,
+    pub action: &'static str,
+}
+
+/// Calendar bounds of the pre-emergent, grub and winterizer rules' windows.
+pub const SEASONAL_WINDOWS: &[SeasonalWindow] = &[
+    SeasonalWindow {
+        id: "pre_emergent",
+        name: "Pre-Emergent",
+        opens: (2, 1),
+        closes: (5, 31),
+        types: &[ApplicationType::PreEmergent],
+        category: RecommendationCategory::PreEmergent,
+        cool_season_only: true,
+        action: "Apply pre-emergent (prodiamine or dithiopyr) now, or plan on \
+                 post-emergent crabgrass control this summer.",
+    },
+    SeasonalWindow {
+        id: "grub_preventative",
+        name: "Grub Preventative",
+        opens: (4, 1),
+        closes: (7, 4),
+        types: &[ApplicationType::GrubControl, ApplicationType::Insecticide],
+        category: RecommendationCategory::GrubControl,
+        cool_season_only: false,
+        action: "Apply chlorantraniliprole (GrubEx) or imidacloprid and water in. \
+                 After this, only curative products (trichlorfon) work on grubs.",
+    },
+    SeasonalWindow {
+        id: "winterizer",
+        name: "Winterizer",
+        opens: (11, 1),
+        closes: (11, 30),
+        types: &[ApplicationType::Fertilizer],
+        category: RecommendationCategory::Fertilizer,
+        cool_season_only: true,
+        action: "Apply the late-fall nitrogen feeding while the grass is still green.",
+    },
+];
+
+impl SeasonalWindow {
+    pub fn dates(&self, year: i32) -> Option<(NaiveDate, NaiveDate)> {
+        Some((
+            NaiveDate::from_ymd_opt(year, self.opens.0, self.opens.1)?,
+            NaiveDate::from_ymd_opt(year, self.closes.0, self.closes.1)?,
+        ))
+    }
+
+    pub fn applies_to(&self, profile: &LawnProfile) -> bool {
+        !self.cool_season_only || profile.grass_type.is_cool_season()
+    }
+
+    fn met(&self, opens: NaiveDate, closes: NaiveDate, history: &[Application]) -> bool {
+        history.iter().any(|app| {
+            self.types.contains(&app.application_type)
+                && (opens..=closes).contains(&app.application_date)
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MissedWindow {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub opened: NaiveDate,
+    pub closed: NaiveDate,
+}
+
+impl MissedWindow {
+    /// Stable per-window, per-year key, e.g. `winterizer_2025`.
+    pub fn key(&self) -> String {
+        format!("{}_{}", self.id, self.closed.year())
+    }
+}
+
+/// `year`'s windows that closed before `today` with no matching application.
+pub fn missed_windows(
+    profile: &LawnProfile,
+    history: &[Application],
+    year: i32,
+    today: NaiveDate,
+) -> Vec<MissedWindow> {
+    SEASONAL_WINDOWS
+        .iter()
+        .filter(|w| w.applies_to(profile))
+        .filter_map(|w| {
+            let (opened, closed) = w.dates(year)?;
+            (closed < today && !w.met(opened, closed, history)).then_some(MissedWindow {
+                id: w.id,
+                name: w.name,
+                opened,
+                closed,
+            })
+        })
+        .collect()
+}
+
+/// Critical reminder in the last week of a seasonal window with nothing logged.
+pub struct WindowClosingRule;
+
+impl Rule for WindowClosingRule {
+    fn evaluate(
+        &self,
+        env: &EnvironmentalSummary,
+        profile: &LawnProfile,
+        history: &[Application],
+    ) -> Option<Recommendation> {
+        self.explain(env, profile, history).ok()
+    }
+
+    fn explain(
+        &self,
+        _env: &EnvironmentalSummary,
+        profile: &LawnProfile,
+        history: &[Application],
+    ) -> Result<Recommendation, Gate> {
+        let today = current_date();
+        let closing: Vec<(&SeasonalWindow, NaiveDate, NaiveDate)> = SEASONAL_WINDOWS
+            .iter()
+            .filter(|w| w.applies_to(profile))
+            .filter_map(|w| {
+                let (opens, closes) = w.dates(today.year())?;
+                let days_left = (closes - today).num_days();
+                (today >= opens && (0..WINDOW_CLOSING_DAYS).contains(&days_left))
+                    .then_some((w, opens, closes))
+            })
+            .collect();
+
+        if closing.is_empty() {
+            return Err(Gate::OutOfSeason(format!(
+                "No seasonal window in its last {} days",
+                WINDOW_CLOSING_DAYS
+            )));
+        }
+        let Some(&(window, opens, closes)) = closing
+            .iter()
+            .find(|(w, opens, closes)| !w.met(*opens, *closes, history))
+        else {
+            return Err(Gate::AlreadyApplied(format!(
+                "{} logged in its window",
+                closing[0].0.name
+            )));
+        };
+
+        let days_left = (closes - today).num_days();
+        let when = match days_left {
+            0 => "Today".to_string(),
+            1 => "Tomorrow".to_string(),
+            n => format!("in {} Days", n),
+        };
+        Ok(Recommendation::new(
+            format!("window_closing_{}_{}", window.id, today.year()),
+            window.category,
+            Severity::Critical,
+            format!("{} Window Closes {}", window.name, when),
+            format!(
+                "The {} window closes {} and no matching application is logged this year.",
+                window.name.to_lowercase(),
+                closes.format("%B %-d")
+            ),
+        )
+        .with_explanation(
+            "Once the window ends the rule that tracks it goes quiet, and the season \
+             report counts it as missed. Log the application if it was already made.",
+        )
+        .with_data_point(
+            "Window",
+            format!("{} – {}", opens.format("%b %-d"), closes.format("%b %-d")),
+            DataSource::Agronomic.as_str(),
+        )
+        .with_data_point(
+            "Days Left",
+            days_left.to_string(),
+            DataSource::Calculated.as_str(),
+        )
+        .with_action(window.action))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logic::rules::with_current_date;
+    use crate::models::GrassType;
+    use crate::testing::{application, date, profile, EnvBuilder};
+
+    #[test]
+    fn lists_closed_windows_without_a_matching_application() {
+        let fescue = profile(GrassType::TallFescue);
+        let apps = vec![
+            application(ApplicationType::PreEmergent, date(2025, 3, 20)),
+            // Before the grub window opens, so it doesn't count
+            application(ApplicationType::Insecticide, date(2025, 3, 25)),
+        ];
+
+        let missed = missed_windows(&fescue, &apps, 2025, date(2025, 12, 1));
+        let ids: Vec<&str> = missed.iter().map(|m| m.id).collect();
+        assert_eq!(ids, vec!["grub_preventative", "winterizer"]);
+        assert_eq!(missed[1].key(), "winterizer_2025");
+
+        // The winterizer window is still open on Nov 30
+        assert_eq!(
+            missed_windows(&fescue, &apps, 2025, date(2025, 11, 30)).len(),
+            1
+        );
+        // Warm-season lawns only have the grub window
+        let bermuda = profile(GrassType::Bermuda);
+        assert_eq!(
+            missed_windows(&bermuda, &[], 2025, date(2025, 12, 1))
+                .iter()
+                .map(|m| m.id)
+                .collect::<Vec<_>>(),
+            vec!["grub_preventative"]
+        );
+    }
+
+    #[test]
+    fn escalates_in_the_last_week_only() {
+        let fescue = profile(GrassType::TallFescue);
+        let env = EnvBuilder::new(crate::testing::at(2025, 6, 30)).build();
+        let explain = |d: NaiveDate, apps: &[Application]| {
+            with_current_date(d, || WindowClosingRule.explain(&env, &fescue, apps))
+        };
+
+        let rec = explain(date(2025, 6, 30), &[]).unwrap();
+        assert_eq!(rec.id, "window_closing_grub_preventative_2025");
+        assert_eq!(rec.severity, Severity::Critical);
+        assert_eq!(rec.title, "Grub Preventative Window Closes in 4 Days");
+        assert_eq!(
+            explain(date(2025, 7, 4), &[]).unwrap().title,
+            "Grub Preventative Window Closes Today"
+        );
+
+        assert!(matches!(
+            explain(date(2025, 6, 27), &[]),
+            Err(Gate::OutOfSeason(_))
+        ));
+        assert!(matches!(
+            explain(date(2025, 7, 5), &[]),
+            Err(Gate::OutOfSeason(_))
+        ));
+        let applied = [application(ApplicationType::GrubControl, date(2025, 5, 20))];
+        assert!(matches!(
+            explain(date(2025, 6, 30), &applied),
+            Err(Gate::AlreadyApplied(_))
+        ));
+    }
+}
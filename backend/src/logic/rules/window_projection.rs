@@ -0,0 +1,214 @@
+use super::covered_soil_temp_avg;
+use super::thresholds::WINDOW_OUTLOOK_MAX_DAYS;
+use crate::logic::soil_temp_prediction::project_crossing;
+use crate::models::soil_temp_prediction::{CrossingDirection, PredictionConfidence};
+use crate::models::EnvironmentalSummary;
+use chrono::NaiveDate;
+use serde::Serialize;
+
+/// Which edge of the window a countdown points at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum WindowPhase {
+    /// Window hasn't opened yet; the date is when it opens.
+    Opens,
+    /// Window is open now; the date is when it closes.
+    Closes,
+}
+
+/// A rule's projected application window, for the Dashboard countdown panel.
+#[derive(Debug, Clone, Serialize)]
+pub struct WindowProjection {
+    pub window: String,
+    pub phase: WindowPhase,
+    pub date: NaiveDate,
+    pub days: i64,
+    /// What moves the edge, e.g. "soil reaches 50°F" or "season ends".
+    pub trigger: String,
+    pub confidence: PredictionConfidence,
+    /// True when extrapolated past the end of the soil temp model's predictions.
+    pub projected: bool,
+}
+
+/// A soil-temperature window bounded by a calendar season.
+///
+/// The window opens when the 7-day soil average crosses `open_f` in `direction`
+/// and closes when it crosses `close_f` (same direction) or the season ends.
+pub struct SoilWindow {
+    pub name: &'static str,
+    pub open_f: f64,
+    pub close_f: f64,
+    pub direction: CrossingDirection,
+    pub season_start: NaiveDate,
+    pub season_end: NaiveDate,
+}
+
+impl SoilWindow {
+    /// Project the next open or close date from the soil temp forecast.
+    /// Openings past the season or beyond `WINDOW_OUTLOOK_MAX_DAYS` are omitted,
+    /// as are windows the soil has already moved through.
+    pub fn project(
+        &self,
+        env: &EnvironmentalSummary,
+        today: NaiveDate,
+    ) -> Option<WindowProjection> {
+        if today > self.season_end {
+            return None;
+        }
+        let soil_f = covered_soil_temp_avg(env).ok()?;
+        let predictions = env.soil_temp_predictions.as_deref().unwrap_or_default();
+        let crossing = |threshold_f: f64| {
+            let (date, confidence, projected) =
+                project_crossing(soil_f, predictions, today, threshold_f, self.direction)?;
+            let trigger = match self.direction {
+                CrossingDirection::Rising => format!("soil reaches {:.0}°F", threshold_f),
+                CrossingDirection::Falling => format!("soil drops below {:.0}°F", threshold_f),
+            };
+            Some((date, confidence, projected, trigger))
+        };
+        let calendar =
+            |date, trigger: &str| (date, PredictionConfidence::High, false, trigger.to_string());
+
+        let (past_open, past_close) = match self.direction {
+            CrossingDirection::Rising => (soil_f >= self.open_f, soil_f >= self.close_f),
+            CrossingDirection::Falling => (soil_f < self.open_f, soil_f < self.close_f),
+        };
+        if past_close {
+            return None;
+        }
+
+        let (phase, (date, confidence, projected, trigger)) = if !past_open {
+            let (date, confidence, projected, trigger) = crossing(self.open_f)?;
+            if date > self.season_end {
+                return None;
+            }
+            if date < self.season_start {
+                (
+                    WindowPhase::Opens,
+                    calendar(self.season_start, "season starts"),
+                )
+            } else {
+                (WindowPhase::Opens, (date, confidence, projected, trigger))
+            }
+        } else if today < self.season_start {
+            // Soil is ready but the calendar window isn't
+            (
+                WindowPhase::Opens,
+                calendar(self.season_start, "season starts"),
+            )
+        } else {
+            let closes = crossing(self.close_f)
+                .filter(|(date, ..)| *date < self.season_end)
+                .unwrap_or_else(|| calendar(self.season_end, "season ends"));
+            (WindowPhase::Closes, closes)
+        };
+
+        let days = (date - today).num_days();
+        if phase == WindowPhase::Opens && days > WINDOW_OUTLOOK_MAX_DAYS {
+            return None;
+        }
+
+        Some(WindowProjection {
+            window: self.name.to_string(),
+            phase,
+            date,
+            days,
+            trigger,
+            confidence,
+            projected,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::soil_temp_prediction::SoilTempPrediction;
+
+    fn date(m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, m, d).unwrap()
+    }
+
+    fn pre_emergent() -> SoilWindow {
+        SoilWindow {
+            name: "Pre-Emergent",
+            open_f: 50.0,
+            close_f: 60.0,
+            direction: CrossingDirection::Rising,
+            season_start: date(2, 1),
+            season_end: date(5, 31),
+        }
+    }
+
+    /// Summary with a 7-day soil average and a forecast warming 1°F/day.
+    fn env(soil_f: f64, today: NaiveDate) -> EnvironmentalSummary {
+        let predictions = (1..=7)
+            .map(|i| SoilTempPrediction {
+                date: today + chrono::Duration::days(i),
+                predicted_soil_temp_f: soil_f + i as f64,
+                confidence: PredictionConfidence::High,
+                air_temp_used_f: 60.0,
+                source_description: "test".into(),
+            })
+            .collect();
+        EnvironmentalSummary {
+            soil_temp_7day_avg_f: Some(soil_f),
+            soil_temp_predictions: Some(predictions),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn counts_down_to_opening() {
+        let today = date(3, 20);
+        let p = pre_emergent().project(&env(47.0, today), today).unwrap();
+        assert_eq!(p.phase, WindowPhase::Opens);
+        assert_eq!(p.days, 3);
+        assert!(!p.projected);
+        assert_eq!(p.trigger, "soil reaches 50°F");
+    }
+
+    #[test]
+    fn open_window_counts_down_to_close() {
+        let today = date(4, 10);
+        let p = pre_emergent().project(&env(55.0, today), today).unwrap();
+        assert_eq!(p.phase, WindowPhase::Closes);
+        assert_eq!(p.days, 5);
+
+        // Nothing pushing it closed: the season end bounds it
+        let mut flat = env(55.0, today);
+        flat.soil_temp_predictions = None;
+        let p = pre_emergent().project(&flat, today).unwrap();
+        assert_eq!(p.phase, WindowPhase::Closes);
+        assert_eq!(p.date, date(5, 31));
+    }
+
+    #[test]
+    fn skips_passed_and_out_of_season_windows() {
+        let today = date(4, 10);
+        assert!(pre_emergent().project(&env(63.0, today), today).is_none());
+        assert!(pre_emergent()
+            .project(&env(45.0, date(6, 5)), date(6, 5))
+            .is_none());
+    }
+
+    #[test]
+    fn ready_soil_waits_for_season_start() {
+        let mut overseed = SoilWindow {
+            name: "Fall Overseeding",
+            open_f: 65.0,
+            close_f: 50.0,
+            direction: CrossingDirection::Falling,
+            season_start: date(8, 15),
+            season_end: date(10, 31),
+        };
+        let today = date(8, 5);
+        let p = overseed.project(&env(63.0, today), today).unwrap();
+        assert_eq!(p.phase, WindowPhase::Opens);
+        assert_eq!(p.date, date(8, 15));
+
+        overseed.season_start = date(8, 1);
+        let p = overseed.project(&env(63.0, today), today).unwrap();
+        assert_eq!(p.phase, WindowPhase::Closes);
+        assert_eq!(p.date, date(10, 31));
+    }
+}
@@ -0,0 +1,323 @@
+use super::thresholds::*;
+use super::{current_date, Rule};
+use crate::models::{
+    Application, ApplicationType, DataSource, EnvironmentalSummary, LawnProfile, Recommendation,
+    RecommendationCategory, Severity, WeatherForecast,
+};
+use chrono::{Datelike, NaiveDate};
+
+/// Winter injury rule - snow mold and desiccation risk (November - March)
+///
+/// Cool-season turf is hurt over winter in two opposite ways:
+/// - Snow mold (Microdochium / Typhula): snow falling on unfrozen ground keeps
+///   the crown wet at 32-40°F for days. Lush growth from late, heavy nitrogen
+///   makes it worse.
+/// - Desiccation: frozen soil can't resupply water, so dry wind on bare,
+///   exposed turf pulls moisture from the leaves faster than roots replace it.
+///
+/// Severity (snow mold):
+/// - Advisory: meaningful snow forecast on unfrozen soil
+/// - Warning: snow cover expected to persist, or heavy N in the last 45 days
+/// - Critical: both
+///
+/// Severity (desiccation):
+/// - Advisory: one dry, windy day on frozen soil with no snow cover
+/// - Warning: 2+ such days
+pub struct WinterInjuryRule;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum WinterRisk {
+    SnowMold(Severity),
+    Desiccation(Severity),
+}
+
+/// Winter conditions driving the rule.
+struct WinterOutlook {
+    soil_temp: f64,
+    snow_mm: f64,
+    /// Days, starting with the first snow, whose highs keep the cover from melting.
+    cover_days: usize,
+    late_n_lbs: f64,
+    dry_windy_days: usize,
+}
+
+impl WinterOutlook {
+    fn from_inputs(
+        soil_temp: f64,
+        forecast: &WeatherForecast,
+        history: &[Application],
+        today: NaiveDate,
+    ) -> Self {
+        let days = forecast.next_days(5);
+        let snow_mm = days.iter().map(|d| d.total_snow_mm).sum();
+        let cover_days = days
+            .iter()
+            .skip_while(|d| d.total_snow_mm <= 0.0)
+            .take_while(|d| d.high_temp_f <= SNOW_COVER_MAX_HIGH_F)
+            .count();
+        let dry_windy_days = forecast
+            .next_days(3)
+            .iter()
+            .filter(|d| {
+                d.avg_wind_speed_mph >= DESICCATION_WIND_MPH
+                    && d.avg_humidity < DESICCATION_HUMIDITY_MAX
+            })
+            .count();
+
+        Self {
+            soil_temp,
+            snow_mm,
+            cover_days,
+            late_n_lbs: late_nitrogen_lbs(history, today),
+            dry_windy_days,
+        }
+    }
+
+    fn assess(&self) -> Option<WinterRisk> {
+        let snow_cover = self.snow_mm >= SNOW_COVER_MIN_MM;
+
+        if self.soil_temp > SOIL_FROZEN_F {
+            if !snow_cover {
+                return None;
+            }
+            let persistent = self.cover_days >= SNOW_COVER_PERSIST_DAYS;
+            let lush = self.late_n_lbs >= LATE_N_HEAVY_LBS_PER_KSQFT;
+            let severity = match (persistent, lush) {
+                (true, true) => Severity::Critical,
+                (true, false) | (false, true) => Severity::Warning,
+                (false, false) => Severity::Advisory,
+            };
+            return Some(WinterRisk::SnowMold(severity));
+        }
+
+        // Snow on frozen ground insulates rather than harms
+        if snow_cover || self.dry_windy_days == 0 {
+            return None;
+        }
+        Some(WinterRisk::Desiccation(
+            if self.dry_windy_days >= DESICCATION_WARNING_DAYS {
+                Severity::Warning
+            } else {
+                Severity::Advisory
+            },
+        ))
+    }
+}
+
+/// Nitrogen (lbs N per 1000 sqft) from fertilizer applied in the late-season
+/// look-back. Planned (future-dated) applications haven't fed the turf yet.
+fn late_nitrogen_lbs(history: &[Application], today: NaiveDate) -> f64 {
+    history
+        .iter()
+        .filter(|app| {
+            app.application_type == ApplicationType::Fertilizer
+                && app.application_date <= today
+                && (today - app.application_date).num_days() <= LATE_N_LOOKBACK_DAYS
+        })
+        .filter_map(|app| Some(app.nitrogen_pct? / 100.0 * app.rate_per_1000sqft?))
+        .sum()
+}
+
+impl Rule for WinterInjuryRule {
+    fn evaluate(
+        &self,
+        env: &EnvironmentalSummary,
+        profile: &LawnProfile,
+        history: &[Application],
+    ) -> Option<Recommendation> {
+        if !profile.grass_type.is_cool_season() {
+            return None;
+        }
+
+        // Window: November - March
+        let today = current_date();
+        if !matches!(today.month(), 11 | 12 | 1..=3) {
+            return None;
+        }
+
+        let forecast = env.forecast.as_ref()?;
+        let soil_temp = env.current.as_ref()?.soil_temp_10_f?;
+
+        let outlook = WinterOutlook::from_inputs(soil_temp, forecast, history, today);
+        Some(match outlook.assess()? {
+            WinterRisk::SnowMold(severity) => snow_mold_recommendation(severity, &outlook),
+            WinterRisk::Desiccation(severity) => desiccation_recommendation(severity, &outlook),
+        })
+    }
+}
+
+fn snow_mold_recommendation(severity: Severity, outlook: &WinterOutlook) -> Recommendation {
+    let mut description = format!(
+        "About {:.0} mm of snow is forecast while the soil is still unfrozen ({:.0}°F).",
+        outlook.snow_mm, outlook.soil_temp
+    );
+    if outlook.cover_days >= SNOW_COVER_PERSIST_DAYS {
+        description.push_str(&format!(
+            " Highs stay near freezing for {} days, so the cover should persist.",
+            outlook.cover_days
+        ));
+    }
+    if outlook.late_n_lbs >= LATE_N_HEAVY_LBS_PER_KSQFT {
+        description.push_str(&format!(
+            " {:.1} lbs N/1000 sqft applied in the last {} days leaves lush, susceptible growth.",
+            outlook.late_n_lbs, LATE_N_LOOKBACK_DAYS
+        ));
+    }
+
+    Recommendation::new(
+        "snow_mold_risk",
+        RecommendationCategory::DiseasePressure,
+        severity,
+        if severity == Severity::Advisory {
+            "Snow on Unfrozen Ground"
+        } else {
+            "Snow Mold Risk Elevated"
+        },
+        description,
+    )
+    .with_explanation(
+        "Snow mold (pink snow mold, Microdochium nivale; gray snow mold, Typhula spp.) \
+         develops under snow that falls on unfrozen ground: the crown stays wet at \
+         32-40°F with no light, ideal for the fungus. Extended cover lengthens the \
+         infection period, and tender growth from late-season nitrogen is the most \
+         susceptible tissue (Penn State Extension). Damage shows as matted, straw- or \
+         pink-tinged circles at snowmelt.",
+    )
+    .with_data_point(
+        "Soil Temp",
+        format!("{:.0}°F", outlook.soil_temp),
+        DataSource::SoilData.as_str(),
+    )
+    .with_data_point(
+        "Forecast Snow (5d)",
+        format!("{:.0} mm", outlook.snow_mm),
+        DataSource::OpenWeatherMap.as_str(),
+    )
+    .with_data_point(
+        "Cover Days",
+        format!("{}", outlook.cover_days),
+        DataSource::OpenWeatherMap.as_str(),
+    )
+    .with_data_point(
+        "Late-Season N",
+        format!("{:.1} lbs/1000 sqft", outlook.late_n_lbs),
+        DataSource::Calculated.as_str(),
+    )
+    .with_action(
+        "Hold off on any further nitrogen until spring. Keep mowing at normal height until \
+         growth stops so the canopy doesn't mat under snow. Avoid piling shoveled or plowed \
+         snow on the lawn. At melt, lightly rake matted areas to dry them out; fungicide is \
+         rarely needed on home lawns.",
+    )
+}
+
+fn desiccation_recommendation(severity: Severity, outlook: &WinterOutlook) -> Recommendation {
+    Recommendation::new(
+        "winter_desiccation",
+        RecommendationCategory::FrostWarning,
+        severity,
+        "Winter Desiccation Risk",
+        format!(
+            "Frozen soil ({:.0}°F) with no snow cover and {} dry, windy day{} ahead. \
+             Exposed areas can dry out faster than roots can resupply them.",
+            outlook.soil_temp,
+            outlook.dry_windy_days,
+            if outlook.dry_windy_days == 1 { "" } else { "s" }
+        ),
+    )
+    .with_explanation(format!(
+        "When the soil is frozen, roots can't take up water, but winter wind (≥{:.0} mph) \
+         and dry air (<{:.0}% humidity) keep pulling moisture from the leaves. Without \
+         snow as insulation, crowns on exposed slopes, high spots and wind-swept areas \
+         can desiccate and die.",
+        DESICCATION_WIND_MPH, DESICCATION_HUMIDITY_MAX
+    ))
+    .with_data_point(
+        "Soil Temp",
+        format!("{:.0}°F", outlook.soil_temp),
+        DataSource::SoilData.as_str(),
+    )
+    .with_data_point(
+        "Dry, Windy Days (3d)",
+        format!("{}", outlook.dry_windy_days),
+        DataSource::OpenWeatherMap.as_str(),
+    )
+    .with_action(
+        "Keep foot traffic off frozen turf. If the soil thaws during a dry spell, water \
+         exposed areas lightly. Consider a breathable cover or snow fence on the most \
+         exposed spots.",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outlook(soil_temp: f64, snow_mm: f64, cover_days: usize, late_n_lbs: f64) -> WinterOutlook {
+        WinterOutlook {
+            soil_temp,
+            snow_mm,
+            cover_days,
+            late_n_lbs,
+            dry_windy_days: 0,
+        }
+    }
+
+    #[test]
+    fn snow_on_unfrozen_ground_escalates_with_cover_and_late_n() {
+        assert_eq!(
+            outlook(36.0, 15.0, 1, 0.0).assess(),
+            Some(WinterRisk::SnowMold(Severity::Advisory))
+        );
+        assert_eq!(
+            outlook(36.0, 15.0, 4, 0.0).assess(),
+            Some(WinterRisk::SnowMold(Severity::Warning))
+        );
+        assert_eq!(
+            outlook(36.0, 15.0, 4, 1.2).assess(),
+            Some(WinterRisk::SnowMold(Severity::Critical))
+        );
+        // Too little snow to matter
+        assert_eq!(outlook(36.0, 3.0, 4, 1.2).assess(), None);
+    }
+
+    fn fertilizer(on: NaiveDate) -> Application {
+        serde_json::from_value(serde_json::json!({
+            "lawn_profile_id": 1,
+            "application_type": "Fertilizer",
+            "application_date": on,
+            "nitrogen_pct": 46.0,
+            "rate_per_1000sqft": 2.0,
+            "created_at": "2025-01-01T00:00:00Z",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn late_nitrogen_skips_planned_applications() {
+        let today = NaiveDate::from_ymd_opt(2025, 11, 20).unwrap();
+        let history = [
+            fertilizer(today - chrono::Duration::days(20)),
+            fertilizer(today + chrono::Duration::days(5)),
+        ];
+        assert!((late_nitrogen_lbs(&history, today) - 0.92).abs() < 1e-9);
+    }
+
+    #[test]
+    fn desiccation_needs_frozen_bare_windy_ground() {
+        let mut o = outlook(30.0, 0.0, 0, 0.0);
+        assert_eq!(o.assess(), None);
+
+        o.dry_windy_days = 1;
+        assert_eq!(
+            o.assess(),
+            Some(WinterRisk::Desiccation(Severity::Advisory))
+        );
+        o.dry_windy_days = 3;
+        assert_eq!(o.assess(), Some(WinterRisk::Desiccation(Severity::Warning)));
+
+        // Snow cover on frozen ground insulates
+        o.snow_mm = 20.0;
+        assert_eq!(o.assess(), None);
+    }
+}
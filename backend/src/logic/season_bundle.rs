@@ -0,0 +1,301 @@
+//! Anonymized season bundle: one year's grass type, zone, turf application
+//! timeline and weekly weather aggregates in a single JSON document, for
+//! sharing a season or moving it to another install. It carries no names,
+//! location, notes, row ids or credentials; importing adds the timeline's
+//! applications to the active profile.
+
+use super::efficacy::DayConditions;
+use crate::models::{
+    lawn_fraction, Application, ApplicationScope, ApplicationType, GrassType, LawnArea,
+    LawnProfile, Millimeters, SoilType, WeatherSnapshot,
+};
+use chrono::{DateTime, Datelike, NaiveDate, Utc, Weekday};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+pub const BUNDLE_FORMAT: &str = "turfops-season";
+pub const BUNDLE_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeasonBundle {
+    pub format: String,
+    pub version: u32,
+    pub year: i32,
+    pub exported_at: DateTime<Utc>,
+    pub grass_type: GrassType,
+    pub usda_zone: String,
+    pub soil_type: Option<SoilType>,
+    /// Oldest first.
+    pub applications: Vec<BundleApplication>,
+    #[serde(default)]
+    pub weeks: Vec<WeekAggregate>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundleApplication {
+    pub date: NaiveDate,
+    pub application_type: ApplicationType,
+    pub product_name: Option<String>,
+    pub rate_per_1000sqft: Option<f64>,
+    pub nitrogen_pct: Option<f64>,
+    pub phosphorus_pct: Option<f64>,
+    pub potassium_pct: Option<f64>,
+    /// Share of the lawn covered (area size over lawn size) instead of square feet.
+    pub lawn_fraction: f64,
+    pub weather_snapshot: Option<WeatherSnapshot>,
+}
+
+/// Station conditions for a Monday-to-Sunday week.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WeekAggregate {
+    pub week_start: NaiveDate,
+    pub days: usize,
+    pub avg_soil_temp_f: Option<f64>,
+    pub avg_high_f: Option<f64>,
+    pub avg_low_f: Option<f64>,
+    pub precipitation_in: f64,
+    pub disease_risk_days: usize,
+}
+
+/// Applications from a bundle ready to insert, and how many were already logged.
+#[derive(Debug)]
+pub struct ImportPlan {
+    pub applications: Vec<Application>,
+    pub skipped: usize,
+}
+
+fn mean(values: impl Iterator<Item = f64>) -> Option<f64> {
+    let (sum, n) = values.fold((0.0, 0usize), |(s, n), v| (s + v, n + 1));
+    (n > 0).then(|| sum / n as f64)
+}
+
+pub fn weekly_aggregates(days: &[DayConditions]) -> Vec<WeekAggregate> {
+    let mut by_week: BTreeMap<NaiveDate, Vec<&DayConditions>> = BTreeMap::new();
+    for d in days {
+        by_week
+            .entry(d.date.week(Weekday::Mon).first_day())
+            .or_default()
+            .push(d);
+    }
+    by_week
+        .into_iter()
+        .map(|(week_start, days)| WeekAggregate {
+            week_start,
+            days: days.len(),
+            avg_soil_temp_f: mean(days.iter().filter_map(|d| d.soil_temp_10_f)),
+            avg_high_f: mean(days.iter().filter_map(|d| d.high_temp_f)),
+            avg_low_f: mean(days.iter().filter_map(|d| d.low_temp_f)),
+            precipitation_in: Millimeters(days.iter().filter_map(|d| d.precipitation_mm).sum())
+                .to_inches()
+                .0,
+            disease_risk_days: days.iter().filter(|d| d.disease_risk).count(),
+        })
+        .collect()
+}
+
+/// Bundle `year`'s turf applications (plant care is left out) and conditions.
+pub fn build_bundle(
+    year: i32,
+    profile: &LawnProfile,
+    applications: &[Application],
+    areas: &[LawnArea],
+    days: &[DayConditions],
+) -> SeasonBundle {
+    let mut timeline: Vec<BundleApplication> = applications
+        .iter()
+        .filter(|a| a.plant_id.is_none() && a.application_date.year() == year)
+        .map(|a| BundleApplication {
+            date: a.application_date,
+            application_type: a.application_type,
+            product_name: a.product_name.clone(),
+            rate_per_1000sqft: a.rate_per_1000sqft,
+            nitrogen_pct: a.nitrogen_pct,
+            phosphorus_pct: a.phosphorus_pct,
+            potassium_pct: a.potassium_pct,
+            lawn_fraction: lawn_fraction(a, areas, profile.lawn_size_sqft),
+            weather_snapshot: a.weather_snapshot.clone(),
+        })
+        .collect();
+    timeline.sort_by_key(|a| a.date);
+
+    SeasonBundle {
+        format: BUNDLE_FORMAT.to_string(),
+        version: BUNDLE_VERSION,
+        year,
+        exported_at: Utc::now(),
+        grass_type: profile.grass_type,
+        usda_zone: profile.usda_zone.clone(),
+        soil_type: profile.soil_type,
+        applications: timeline,
+        weeks: weekly_aggregates(days),
+    }
+}
+
+impl SeasonBundle {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.format != BUNDLE_FORMAT {
+            return Err(format!("Not a season bundle (format {:?})", self.format));
+        }
+        if self.version > BUNDLE_VERSION {
+            return Err(format!(
+                "Season bundle version {} is newer than this install supports ({})",
+                self.version, BUNDLE_VERSION
+            ));
+        }
+        if let Some(a) = self
+            .applications
+            .iter()
+            .find(|a| a.application_type.scope() == ApplicationScope::PlantRequired)
+        {
+            return Err(format!(
+                "{} on {} needs a plant and can't be imported",
+                a.application_type, a.date
+            ));
+        }
+        // Duplicates are only looked for within the bundle's year
+        if let Some(a) = self.applications.iter().find(|a| a.date.year() != self.year) {
+            return Err(format!(
+                "{} on {} is outside the bundle's year {}",
+                a.application_type, a.date, self.year
+            ));
+        }
+        Ok(())
+    }
+
+    /// Turn the timeline into applications for `profile`, skipping any with
+    /// the same date, type and product as one already in `existing`.
+    pub fn plan_import(&self, profile: &LawnProfile, existing: &[Application]) -> ImportPlan {
+        let profile_id = profile.id.unwrap_or_default();
+        let logged = |a: &BundleApplication| {
+            existing.iter().any(|e| {
+                e.plant_id.is_none()
+                    && e.application_date == a.date
+                    && e.application_type == a.application_type
+                    && e.product_name == a.product_name
+            })
+        };
+        let (skip, import): (Vec<_>, Vec<_>) = self.applications.iter().partition(|a| logged(a));
+
+        ImportPlan {
+            applications: import
+                .into_iter()
+                .map(|a| Application {
+                    id: None,
+                    lawn_profile_id: profile_id,
+                    application_type: a.application_type,
+                    product_name: a.product_name.clone(),
+                    application_date: a.date,
+                    rate_per_1000sqft: a.rate_per_1000sqft,
+                    coverage_sqft: profile
+                        .lawn_size_sqft
+                        .filter(|_| a.lawn_fraction < 1.0)
+                        .map(|size| size * a.lawn_fraction),
+                    notes: None,
+                    weather_snapshot: a.weather_snapshot.clone(),
+                    nitrogen_pct: a.nitrogen_pct,
+                    phosphorus_pct: a.phosphorus_pct,
+                    potassium_pct: a.potassium_pct,
+                    plant_id: None,
+                    follow_up_date: None,
+                    area_id: None,
+                    equipment_id: None,
+                    custom_type_id: None,
+                    created_at: Utc::now(),
+                })
+                .collect(),
+            skipped: skip.len(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::SunExposure;
+    use crate::testing::{application, date, profile};
+
+    fn day(d: NaiveDate, rain: f64, risk: bool) -> DayConditions {
+        DayConditions {
+            date: d,
+            soil_temp_10_f: Some(60.0),
+            high_temp_f: Some(80.0),
+            low_temp_f: Some(60.0),
+            precipitation_mm: Some(rain),
+            humid_hours: 0,
+            disease_risk: risk,
+        }
+    }
+
+    #[test]
+    fn exports_an_anonymized_timeline_and_imports_it_once() {
+        let mut home = profile(GrassType::TallFescue);
+        home.name = "123 Main St".into();
+        home.lawn_size_sqft = Some(5000.0);
+        let mut fert = application(ApplicationType::Fertilizer, date(2025, 9, 6));
+        fert.id = Some(7);
+        fert.product_name = Some("Milorganite".into());
+        fert.notes = Some("Neighbor's spreader".into());
+        fert.area_id = Some(2);
+        fert.equipment_id = Some(3);
+        let mut plant = application(ApplicationType::Fungicide, date(2025, 6, 1));
+        plant.plant_id = Some(4);
+        let apps = [
+            fert,
+            plant,
+            application(ApplicationType::PreEmergent, date(2025, 3, 20)),
+            application(ApplicationType::PreEmergent, date(2024, 3, 20)),
+        ];
+        let areas = [LawnArea {
+            id: Some(2),
+            lawn_profile_id: 1,
+            name: "Front".into(),
+            size_sqft: Some(1000.0),
+            sun_exposure: SunExposure::FullSun,
+            grass_type: None,
+            grass_mix: None,
+            created_at: crate::testing::at(2025, 1, 1),
+        }];
+        // Mon Sep 1 .. Mon Sep 8 spans two weeks
+        let days: Vec<_> = (1..=8)
+            .map(|d| day(date(2025, 9, d), 25.4, d == 2))
+            .collect();
+
+        let bundle = build_bundle(2025, &home, &apps, &areas, &days);
+        let json = serde_json::to_string(&bundle).unwrap();
+        for private in ["123 Main St", "Neighbor", "Front", "\"id\"", "area_id"] {
+            assert!(!json.contains(private), "{private} leaked: {json}");
+        }
+        let types: Vec<_> = bundle.applications.iter().map(|a| a.date).collect();
+        assert_eq!(types, vec![date(2025, 3, 20), date(2025, 9, 6)]);
+        assert_eq!(bundle.applications[1].lawn_fraction, 0.2);
+        assert_eq!(bundle.weeks.len(), 2);
+        assert_eq!(bundle.weeks[0].week_start, date(2025, 9, 1));
+        assert!((bundle.weeks[0].precipitation_in - 7.0).abs() < 1e-9);
+        assert_eq!(bundle.weeks[0].disease_risk_days, 1);
+
+        let parsed: SeasonBundle = serde_json::from_str(&json).unwrap();
+        assert!(parsed.validate().is_ok());
+        let mut other = profile(GrassType::TallFescue);
+        other.lawn_size_sqft = Some(8000.0);
+        let already = [application(ApplicationType::PreEmergent, date(2025, 3, 20))];
+        let plan = parsed.plan_import(&other, &already);
+        assert_eq!(plan.skipped, 1);
+        assert_eq!(plan.applications.len(), 1);
+        assert_eq!(plan.applications[0].coverage_sqft, Some(1600.0));
+        assert_eq!(plan.applications[0].notes, None);
+
+        let foreign = SeasonBundle {
+            format: "something-else".into(),
+            ..parsed.clone()
+        };
+        assert!(foreign.validate().is_err());
+        let future = SeasonBundle {
+            version: BUNDLE_VERSION + 1,
+            ..parsed.clone()
+        };
+        assert!(future.validate().is_err());
+        let mut stray = parsed;
+        stray.applications[0].date = date(2024, 12, 31);
+        assert!(stray.validate().unwrap_err().contains("outside the bundle's year 2025"));
+    }
+}
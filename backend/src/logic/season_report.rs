@@ -0,0 +1,895 @@
+//! Season summary: one year's applications, nitrogen, weather and review
+//! outcomes gathered into a shareable report, rendered as Markdown or HTML.
+
+use super::efficacy::DayConditions;
+use super::rules::thresholds::{HEAT_STRESS_WARNING_TEMP_F, PRECIP_TRACE_MM, SEEDLING_FROST_LOW_F};
+use super::rules::window_closing::{missed_windows, MissedWindow};
+use crate::models::{
+    lawn_fraction, nitrogen_budget::annual_n_target, AnnualReview, Application, ApplicationOutcome,
+    ApplicationType, IssueLevel, LawnArea, LawnProfile, Millimeters, Photo,
+};
+use chrono::{Datelike, NaiveDate};
+use serde::Serialize;
+use std::fmt::Write;
+use std::str::FromStr;
+
+const MONTH_NAMES: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Markdown,
+    Html,
+    Json,
+}
+
+impl FromStr for ReportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "markdown" | "md" => Ok(ReportFormat::Markdown),
+            "html" => Ok(ReportFormat::Html),
+            "json" => Ok(ReportFormat::Json),
+            _ => Err(format!(
+                "Unknown report format: {s} (use markdown, html or json)"
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TypeTotal {
+    pub application_type: ApplicationType,
+    pub count: usize,
+    /// Distinct products, in the order first used.
+    pub products: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WeatherEvent {
+    pub date: NaiveDate,
+    pub title: String,
+    pub detail: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MonthSummary {
+    pub month: u32,
+    pub avg_soil_temp_f: Option<f64>,
+    pub avg_high_f: Option<f64>,
+    pub precipitation_in: f64,
+    pub disease_risk_days: usize,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Compliance {
+    pub addressed: usize,
+    pub dismissed: usize,
+}
+
+impl Compliance {
+    /// Share of acted-on recommendations that were addressed rather than dismissed.
+    pub fn rate(&self) -> Option<f64> {
+        let total = self.addressed + self.dismissed;
+        (total > 0).then(|| self.addressed as f64 / total as f64)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OverseedResult {
+    pub date: NaiveDate,
+    pub product_name: Option<String>,
+    /// From the saved annual review, if one rated it.
+    pub outcome: Option<ApplicationOutcome>,
+}
+
+/// A journal photo with the application and weather around it.
+#[derive(Debug, Clone, Serialize)]
+pub struct PhotoEntry {
+    pub date: NaiveDate,
+    pub path: String,
+    pub caption: Option<String>,
+    /// `None` for the whole lawn.
+    pub area_name: Option<String>,
+    /// Latest application on or before the photo, e.g. "Fertilizer (Milorganite)".
+    pub last_application: Option<String>,
+    pub days_since_application: Option<i64>,
+    pub high_temp_f: Option<f64>,
+    pub precipitation_mm: Option<f64>,
+}
+
+impl PhotoEntry {
+    pub fn new(
+        photo: &Photo,
+        applications: &[Application],
+        areas: &[LawnArea],
+        days: &[DayConditions],
+    ) -> Self {
+        let last = photo.preceding_application(applications);
+        let day = days.iter().find(|d| d.date == photo.taken_on);
+        PhotoEntry {
+            date: photo.taken_on,
+            path: photo.path.clone(),
+            caption: photo.caption.clone(),
+            area_name: photo
+                .area_id
+                .and_then(|id| areas.iter().find(|a| a.id == Some(id)))
+                .map(|a| a.name.clone()),
+            last_application: last.map(|a| match &a.product_name {
+                Some(p) => format!("{} ({p})", a.application_type.as_str()),
+                None => a.application_type.as_str().to_string(),
+            }),
+            days_since_application: last.map(|a| (photo.taken_on - a.application_date).num_days()),
+            high_temp_f: day.and_then(|d| d.high_temp_f),
+            precipitation_mm: day.and_then(|d| d.precipitation_mm),
+        }
+    }
+
+    /// "Fertilizer (Milorganite) 3 days before; 84°F, 0.20 in rain"
+    pub fn context(&self) -> String {
+        let mut parts = Vec::new();
+        if let (Some(app), Some(days)) = (&self.last_application, self.days_since_application) {
+            parts.push(match days {
+                0 => format!("{app} same day"),
+                1 => format!("{app} 1 day before"),
+                n => format!("{app} {n} days before"),
+            });
+        }
+        let mut weather = Vec::new();
+        if let Some(t) = self.high_temp_f {
+            weather.push(format!("{t:.0}°F"));
+        }
+        if let Some(mm) = self.precipitation_mm.filter(|mm| *mm >= PRECIP_TRACE_MM) {
+            weather.push(format!("{:.2} rain", Millimeters(mm).to_inches()));
+        }
+        if !weather.is_empty() {
+            parts.push(weather.join(", "));
+        }
+        parts.join("; ")
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SeasonReport {
+    pub year: i32,
+    pub profile_name: String,
+    pub grass_type: String,
+    pub application_count: usize,
+    pub totals: Vec<TypeTotal>,
+    pub n_applied_lbs_per_1000sqft: f64,
+    pub n_recommended_lbs_per_1000sqft: f64,
+    pub total_precipitation_in: f64,
+    pub weather_events: Vec<WeatherEvent>,
+    pub months: Vec<MonthSummary>,
+    pub compliance: Compliance,
+    pub overseeding: Vec<OverseedResult>,
+    /// Seasonal windows (pre-emergent, grub, winterizer) that closed with no
+    /// matching application.
+    pub missed_windows: Vec<MissedWindow>,
+    /// Thin/bare areas as rated in the annual review.
+    pub thin_areas: Option<IssueLevel>,
+    pub photos: Vec<PhotoEntry>,
+}
+
+pub struct SeasonReportInput<'a> {
+    pub year: i32,
+    /// Windows still open on this date aren't reported as missed.
+    pub today: NaiveDate,
+    pub profile: &'a LawnProfile,
+    /// Applications made in `year`.
+    pub applications: &'a [Application],
+    pub areas: &'a [LawnArea],
+    /// Daily conditions for `year`, oldest first.
+    pub days: &'a [DayConditions],
+    pub compliance: Compliance,
+    pub review: Option<&'a AnnualReview>,
+    /// Journal photos taken in `year`, oldest first.
+    pub photos: &'a [Photo],
+}
+
+fn totals(applications: &[Application]) -> Vec<TypeTotal> {
+    let mut totals: Vec<TypeTotal> = Vec::new();
+    for app in applications {
+        let i = match totals
+            .iter()
+            .position(|t| t.application_type == app.application_type)
+        {
+            Some(i) => i,
+            None => {
+                totals.push(TypeTotal {
+                    application_type: app.application_type,
+                    count: 0,
+                    products: Vec::new(),
+                });
+                totals.len() - 1
+            }
+        };
+        let total = &mut totals[i];
+        total.count += 1;
+        if let Some(product) = &app.product_name {
+            if !total.products.contains(product) {
+                total.products.push(product.clone());
+            }
+        }
+    }
+    totals.sort_by_key(|t| std::cmp::Reverse(t.count));
+    totals
+}
+
+fn mean(values: impl Iterator<Item = f64>) -> Option<f64> {
+    let (sum, count) = values.fold((0.0, 0usize), |(s, n), v| (s + v, n + 1));
+    (count > 0).then(|| sum / count as f64)
+}
+
+fn months(days: &[DayConditions]) -> Vec<MonthSummary> {
+    (1..=12)
+        .filter_map(|month| {
+            let in_month: Vec<&DayConditions> =
+                days.iter().filter(|d| d.date.month() == month).collect();
+            if in_month.is_empty() {
+                return None;
+            }
+            Some(MonthSummary {
+                month,
+                avg_soil_temp_f: mean(in_month.iter().filter_map(|d| d.soil_temp_10_f)),
+                avg_high_f: mean(in_month.iter().filter_map(|d| d.high_temp_f)),
+                precipitation_in: Millimeters(
+                    in_month.iter().filter_map(|d| d.precipitation_mm).sum(),
+                )
+                .to_inches()
+                .0,
+                disease_risk_days: in_month.iter().filter(|d| d.disease_risk).count(),
+            })
+        })
+        .collect()
+}
+
+/// Hottest day, heat-day count, last spring and first fall frosts, wettest
+/// day and the longest dry spell (a week or more).
+fn weather_events(days: &[DayConditions]) -> Vec<WeatherEvent> {
+    let mut events = Vec::new();
+
+    let by_high = |d: &&DayConditions| d.high_temp_f.map(|t| (t * 10.0) as i64);
+    if let Some(d) = days
+        .iter()
+        .filter(|d| d.high_temp_f.is_some())
+        .max_by_key(by_high)
+    {
+        events.push(WeatherEvent {
+            date: d.date,
+            title: "Hottest day".into(),
+            detail: format!("{:.0}°F", d.high_temp_f.unwrap_or_default()),
+        });
+    }
+    let heat_days = days
+        .iter()
+        .filter(|d| {
+            d.high_temp_f
+                .is_some_and(|t| t >= HEAT_STRESS_WARNING_TEMP_F)
+        })
+        .collect::<Vec<_>>();
+    if let Some(first) = heat_days.first() {
+        events.push(WeatherEvent {
+            date: first.date,
+            title: "Heat days".into(),
+            detail: format!(
+                "{} day{} at or above {:.0}°F, the first on this date",
+                heat_days.len(),
+                if heat_days.len() == 1 { "" } else { "s" },
+                HEAT_STRESS_WARNING_TEMP_F
+            ),
+        });
+    }
+
+    let frost = |d: &&DayConditions| d.low_temp_f.is_some_and(|t| t <= SEEDLING_FROST_LOW_F);
+    if let Some(d) = days.iter().filter(|d| d.date.month() <= 6).rfind(frost) {
+        events.push(WeatherEvent {
+            date: d.date,
+            title: "Last spring frost".into(),
+            detail: format!("Low of {:.0}°F", d.low_temp_f.unwrap_or_default()),
+        });
+    }
+    if let Some(d) = days.iter().filter(|d| d.date.month() >= 7).find(frost) {
+        events.push(WeatherEvent {
+            date: d.date,
+            title: "First fall frost".into(),
+            detail: format!("Low of {:.0}°F", d.low_temp_f.unwrap_or_default()),
+        });
+    }
+
+    let by_rain = |d: &&DayConditions| d.precipitation_mm.map(|p| (p * 10.0) as i64);
+    if let Some(d) = days
+        .iter()
+        .filter(|d| d.precipitation_mm.is_some_and(|p| p >= PRECIP_TRACE_MM))
+        .max_by_key(by_rain)
+    {
+        events.push(WeatherEvent {
+            date: d.date,
+            title: "Wettest day".into(),
+            detail: format!(
+                "{:.2} of rain",
+                Millimeters(d.precipitation_mm.unwrap_or_default()).to_inches()
+            ),
+        });
+    }
+
+    // Longest run of days reporting less than a trace of rain
+    let mut best: Option<(NaiveDate, usize)> = None;
+    let mut run: Option<(NaiveDate, usize)> = None;
+    for d in days {
+        let dry = d.precipitation_mm.is_some_and(|p| p < PRECIP_TRACE_MM);
+        run = match (dry, run) {
+            (true, Some((start, n))) => Some((start, n + 1)),
+            (true, None) => Some((d.date, 1)),
+            (false, _) => None,
+        };
+        if let Some((start, n)) = run {
+            if best.is_none_or(|(_, b)| n > b) {
+                best = Some((start, n));
+            }
+        }
+    }
+    if let Some((start, n)) = best.filter(|(_, n)| *n >= 7) {
+        events.push(WeatherEvent {
+            date: start,
+            title: "Longest dry spell".into(),
+            detail: format!("{n} days without measurable rain"),
+        });
+    }
+
+    events.sort_by_key(|e| e.date);
+    events
+}
+
+pub fn build_season_report(input: &SeasonReportInput) -> SeasonReport {
+    let turf_apps: Vec<Application> = input
+        .applications
+        .iter()
+        .filter(|a| a.plant_id.is_none())
+        .cloned()
+        .collect();
+
+    let n_applied = turf_apps
+        .iter()
+        .filter_map(|a| match (a.nitrogen_pct, a.rate_per_1000sqft) {
+            (Some(n), Some(rate)) if n > 0.0 && rate > 0.0 => {
+                Some(n / 100.0 * rate * lawn_fraction(a, input.areas, input.profile.lawn_size_sqft))
+            }
+            _ => None,
+        })
+        .sum();
+
+    let mut overseeding: Vec<OverseedResult> = turf_apps
+        .iter()
+        .filter(|a| a.application_type == ApplicationType::Overseed)
+        .map(|a| OverseedResult {
+            date: a.application_date,
+            product_name: a.product_name.clone(),
+            outcome: input.review.and_then(|r| {
+                r.application_reviews
+                    .iter()
+                    .find(|ar| Some(ar.application_id) == a.id)
+                    .map(|ar| ar.outcome)
+            }),
+        })
+        .collect();
+    overseeding.sort_by_key(|o| o.date);
+
+    SeasonReport {
+        year: input.year,
+        profile_name: input.profile.name.clone(),
+        grass_type: input.profile.grass_type.as_str().to_string(),
+        application_count: input.applications.len(),
+        totals: totals(input.applications),
+        n_applied_lbs_per_1000sqft: n_applied,
+        n_recommended_lbs_per_1000sqft: annual_n_target(input.profile.grass_type)
+            .recommended_lbs_per_1000sqft,
+        total_precipitation_in: Millimeters(
+            input.days.iter().filter_map(|d| d.precipitation_mm).sum(),
+        )
+        .to_inches()
+        .0,
+        weather_events: weather_events(input.days),
+        months: months(input.days),
+        compliance: input.compliance.clone(),
+        overseeding,
+        missed_windows: missed_windows(input.profile, &turf_apps, input.year, input.today),
+        thin_areas: input.review.map(|r| r.observations.thin_areas),
+        photos: input
+            .photos
+            .iter()
+            .map(|p| PhotoEntry::new(p, &turf_apps, input.areas, input.days))
+            .collect(),
+    }
+}
+
+fn fmt_date(d: NaiveDate) -> String {
+    d.format("%b %-d").to_string()
+}
+
+fn fmt_temp(t: Option<f64>) -> String {
+    t.map(|t| format!("{t:.0}°F")).unwrap_or_else(|| "—".into())
+}
+
+fn outcome_label(o: Option<ApplicationOutcome>) -> &'static str {
+    o.map(|o| o.as_str()).unwrap_or("Not reviewed")
+}
+
+/// Text bar scaled so `max` fills `width` characters.
+fn text_bar(value: f64, max: f64, width: usize) -> String {
+    if max <= 0.0 || value <= 0.0 {
+        return String::new();
+    }
+    let n = ((value / max) * width as f64).round().max(1.0) as usize;
+    "█".repeat(n.min(width))
+}
+
+pub fn render_markdown(r: &SeasonReport) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "# {} Season Report — {}\n", r.year, r.profile_name);
+    let _ = writeln!(out, "*{}*\n", r.grass_type);
+
+    let _ = writeln!(out, "## Applications\n");
+    let _ = writeln!(out, "{} applications logged.\n", r.application_count);
+    if !r.totals.is_empty() {
+        let _ = writeln!(out, "| Type | Count | Products |");
+        let _ = writeln!(out, "|---|---:|---|");
+        for t in &r.totals {
+            let _ = writeln!(
+                out,
+                "| {} | {} | {} |",
+                t.application_type.as_str(),
+                t.count,
+                t.products.join(", ")
+            );
+        }
+        out.push('\n');
+    }
+    let _ = writeln!(
+        out,
+        "**Nitrogen:** {:.2} lbs N/1000 sq ft applied (target {:.1}).\n",
+        r.n_applied_lbs_per_1000sqft, r.n_recommended_lbs_per_1000sqft
+    );
+
+    let _ = writeln!(out, "## Weather\n");
+    let _ = writeln!(
+        out,
+        "{:.1} in of rain for the year.\n",
+        r.total_precipitation_in
+    );
+    for e in &r.weather_events {
+        let _ = writeln!(
+            out,
+            "- **{}** ({}): {}",
+            e.title,
+            fmt_date(e.date),
+            e.detail
+        );
+    }
+    if !r.months.is_empty() {
+        let max_rain = r
+            .months
+            .iter()
+            .map(|m| m.precipitation_in)
+            .fold(0.0, f64::max);
+        let max_soil = r
+            .months
+            .iter()
+            .filter_map(|m| m.avg_soil_temp_f)
+            .fold(0.0, f64::max);
+        let _ = writeln!(out, "\n```");
+        let _ = writeln!(out, "Month  Soil temp                   Rain");
+        for m in &r.months {
+            let soil = m.avg_soil_temp_f.unwrap_or_default();
+            let _ = writeln!(
+                out,
+                "{:<5}  {:<20} {:>4}  {:<12} {:.1} in",
+                MONTH_NAMES[(m.month - 1) as usize],
+                text_bar(soil, max_soil, 20),
+                fmt_temp(m.avg_soil_temp_f),
+                text_bar(m.precipitation_in, max_rain, 12),
+                m.precipitation_in
+            );
+        }
+        let _ = writeln!(out, "```");
+    }
+    out.push('\n');
+
+    let _ = writeln!(out, "## Recommendations\n");
+    match r.compliance.rate() {
+        Some(rate) => {
+            let _ = writeln!(
+                out,
+                "{} addressed, {} dismissed ({:.0}% followed).\n",
+                r.compliance.addressed,
+                r.compliance.dismissed,
+                rate * 100.0
+            );
+        }
+        None => {
+            let _ = writeln!(
+                out,
+                "No recommendations were marked addressed or dismissed.\n"
+            );
+        }
+    }
+
+    if !r.missed_windows.is_empty() {
+        let _ = writeln!(out, "## Missed Windows\n");
+        for m in &r.missed_windows {
+            let _ = writeln!(
+                out,
+                "- **{}** ({} – {}): nothing logged",
+                m.name,
+                fmt_date(m.opened),
+                fmt_date(m.closed)
+            );
+        }
+        out.push('\n');
+    }
+
+    if !r.overseeding.is_empty() || r.thin_areas.is_some() {
+        let _ = writeln!(out, "## Overseeding\n");
+        for o in &r.overseeding {
+            let _ = writeln!(
+                out,
+                "- {}{}: {}",
+                fmt_date(o.date),
+                o.product_name
+                    .as_deref()
+                    .map(|p| format!(" ({p})"))
+                    .unwrap_or_default(),
+                outcome_label(o.outcome)
+            );
+        }
+        if let Some(level) = r.thin_areas {
+            let _ = writeln!(out, "\nThin/bare areas at season end: {}.", level.as_str());
+        }
+    }
+
+    if !r.photos.is_empty() {
+        let _ = writeln!(out, "\n## Photos\n");
+        for p in &r.photos {
+            let context = p.context();
+            let _ = writeln!(
+                out,
+                "- {}{}: `{}`{}{}",
+                fmt_date(p.date),
+                p.area_name
+                    .as_deref()
+                    .map(|a| format!(" ({a})"))
+                    .unwrap_or_default(),
+                p.path,
+                p.caption
+                    .as_deref()
+                    .map(|c| format!(" — {c}"))
+                    .unwrap_or_default(),
+                if context.is_empty() {
+                    String::new()
+                } else {
+                    format!(" [{context}]")
+                }
+            );
+        }
+    }
+
+    out
+}
+
+pub fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Inline SVG column chart, one column per month.
+fn svg_columns(
+    months: &[MonthSummary],
+    value: impl Fn(&MonthSummary) -> f64,
+    color: &str,
+) -> String {
+    const W: f64 = 30.0;
+    const H: f64 = 80.0;
+    let max = months.iter().map(&value).fold(0.0, f64::max);
+    let mut svg = format!(
+        "<svg width=\"{}\" height=\"{}\" role=\"img\">",
+        W * months.len() as f64,
+        H + 14.0
+    );
+    for (i, m) in months.iter().enumerate() {
+        let h = if max > 0.0 { value(m) / max * H } else { 0.0 };
+        let x = i as f64 * W;
+        let _ = write!(
+            svg,
+            "<rect x=\"{:.0}\" y=\"{:.1}\" width=\"{:.0}\" height=\"{:.1}\" fill=\"{color}\"/>\
+             <text x=\"{:.0}\" y=\"{:.0}\" font-size=\"9\" text-anchor=\"middle\">{}</text>",
+            x + 4.0,
+            H - h,
+            W - 8.0,
+            h,
+            x + W / 2.0,
+            H + 12.0,
+            MONTH_NAMES[(m.month - 1) as usize]
+        );
+    }
+    svg.push_str("</svg>");
+    svg
+}
+
+pub fn render_html(r: &SeasonReport) -> String {
+    let mut out = String::new();
+    let title = format!("{} Season Report — {}", r.year, escape(&r.profile_name));
+    let _ = write!(
+        out,
+        "<!DOCTYPE html><html><head><meta charset=\"utf-8\"><title>{title}</title>\
+         <style>body{{font-family:sans-serif;max-width:760px;margin:2rem auto;color:#2d3748}}\
+         table{{border-collapse:collapse}}td,th{{border-bottom:1px solid #e2e8f0;padding:4px 10px;text-align:left}}\
+         </style></head><body><h1>{title}</h1><p><em>{}</em></p>",
+        escape(&r.grass_type)
+    );
+
+    let _ = write!(
+        out,
+        "<h2>Applications</h2><p>{} applications logged.</p>",
+        r.application_count
+    );
+    if !r.totals.is_empty() {
+        out.push_str("<table><tr><th>Type</th><th>Count</th><th>Products</th></tr>");
+        for t in &r.totals {
+            let _ = write!(
+                out,
+                "<tr><td>{}</td><td>{}</td><td>{}</td></tr>",
+                t.application_type.as_str(),
+                t.count,
+                escape(&t.products.join(", "))
+            );
+        }
+        out.push_str("</table>");
+    }
+    let _ = write!(
+        out,
+        "<p><strong>Nitrogen:</strong> {:.2} lbs N/1000 sq ft applied (target {:.1}).</p>",
+        r.n_applied_lbs_per_1000sqft, r.n_recommended_lbs_per_1000sqft
+    );
+
+    let _ = write!(
+        out,
+        "<h2>Weather</h2><p>{:.1} in of rain for the year.</p><ul>",
+        r.total_precipitation_in
+    );
+    for e in &r.weather_events {
+        let _ = write!(
+            out,
+            "<li><strong>{}</strong> ({}): {}</li>",
+            e.title,
+            fmt_date(e.date),
+            e.detail
+        );
+    }
+    out.push_str("</ul>");
+    if !r.months.is_empty() {
+        let _ = write!(
+            out,
+            "<h3>Average soil temp</h3>{}<h3>Rainfall</h3>{}",
+            svg_columns(
+                &r.months,
+                |m| m.avg_soil_temp_f.unwrap_or_default(),
+                "#dd6b20"
+            ),
+            svg_columns(&r.months, |m| m.precipitation_in, "#3182ce")
+        );
+    }
+
+    out.push_str("<h2>Recommendations</h2>");
+    match r.compliance.rate() {
+        Some(rate) => {
+            let _ = write!(
+                out,
+                "<p>{} addressed, {} dismissed ({:.0}% followed).</p>",
+                r.compliance.addressed,
+                r.compliance.dismissed,
+                rate * 100.0
+            );
+        }
+        None => out.push_str("<p>No recommendations were marked addressed or dismissed.</p>"),
+    }
+
+    if !r.missed_windows.is_empty() {
+        out.push_str("<h2>Missed Windows</h2><ul>");
+        for m in &r.missed_windows {
+            let _ = write!(
+                out,
+                "<li><strong>{}</strong> ({} – {}): nothing logged</li>",
+                m.name,
+                fmt_date(m.opened),
+                fmt_date(m.closed)
+            );
+        }
+        out.push_str("</ul>");
+    }
+
+    if !r.overseeding.is_empty() || r.thin_areas.is_some() {
+        out.push_str("<h2>Overseeding</h2><ul>");
+        for o in &r.overseeding {
+            let _ = write!(
+                out,
+                "<li>{}{}: {}</li>",
+                fmt_date(o.date),
+                o.product_name
+                    .as_deref()
+                    .map(|p| format!(" ({})", escape(p)))
+                    .unwrap_or_default(),
+                outcome_label(o.outcome)
+            );
+        }
+        out.push_str("</ul>");
+        if let Some(level) = r.thin_areas {
+            let _ = write!(
+                out,
+                "<p>Thin/bare areas at season end: {}.</p>",
+                level.as_str()
+            );
+        }
+    }
+
+    if !r.photos.is_empty() {
+        out.push_str("<h2>Photos</h2><table><tr><th>Date</th><th>Area</th><th>File</th><th>Caption</th><th>Conditions</th></tr>");
+        for p in &r.photos {
+            let _ = write!(
+                out,
+                "<tr><td>{}</td><td>{}</td><td><code>{}</code></td><td>{}</td><td>{}</td></tr>",
+                fmt_date(p.date),
+                escape(p.area_name.as_deref().unwrap_or("Whole lawn")),
+                escape(&p.path),
+                escape(p.caption.as_deref().unwrap_or("")),
+                escape(&p.context())
+            );
+        }
+        out.push_str("</table>");
+    }
+
+    out.push_str("</body></html>");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::GrassType;
+    use crate::testing::{application, date, profile};
+    use chrono::Duration;
+
+    fn day(d: NaiveDate, high: f64, low: f64, rain: f64) -> DayConditions {
+        DayConditions {
+            date: d,
+            soil_temp_10_f: Some(low + 5.0),
+            high_temp_f: Some(high),
+            low_temp_f: Some(low),
+            precipitation_mm: Some(rain),
+            humid_hours: 0,
+            disease_risk: false,
+        }
+    }
+
+    fn season() -> Vec<DayConditions> {
+        (0..365)
+            .map(|i| {
+                let d = date(2025, 1, 1) + Duration::days(i);
+                let warm = (d.ordinal() as f64 / 365.0 * std::f64::consts::PI).sin();
+                let rain = if i % 5 == 0 { 10.0 } else { 0.0 };
+                day(d, 30.0 + 65.0 * warm, 15.0 + 55.0 * warm, rain)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn totals_and_nitrogen() {
+        let profile = profile(GrassType::TallFescue);
+        let mut fert = application(ApplicationType::Fertilizer, date(2025, 4, 1));
+        fert.product_name = Some("Milorganite".into());
+        fert.nitrogen_pct = Some(6.0);
+        fert.rate_per_1000sqft = Some(10.0);
+        let mut fert2 = fert.clone();
+        fert2.application_date = date(2025, 9, 1);
+        let apps = [
+            fert,
+            fert2,
+            application(ApplicationType::PreEmergent, date(2025, 3, 20)),
+        ];
+        let days = season();
+        let report = build_season_report(&SeasonReportInput {
+            year: 2025,
+            today: date(2026, 1, 10),
+            profile: &profile,
+            applications: &apps,
+            areas: &[],
+            days: &days,
+            compliance: Compliance {
+                addressed: 3,
+                dismissed: 1,
+            },
+            review: None,
+            photos: &[],
+        });
+
+        assert_eq!(report.application_count, 3);
+        assert_eq!(
+            report.totals[0].application_type,
+            ApplicationType::Fertilizer
+        );
+        assert_eq!(report.totals[0].count, 2);
+        assert_eq!(report.totals[0].products, vec!["Milorganite".to_string()]);
+        assert!((report.n_applied_lbs_per_1000sqft - 1.2).abs() < 1e-9);
+        assert_eq!(report.months.len(), 12);
+        assert_eq!(report.compliance.rate(), Some(0.75));
+        let missed: Vec<&str> = report.missed_windows.iter().map(|m| m.name).collect();
+        assert_eq!(missed, vec!["Grub Preventative", "Winterizer"]);
+    }
+
+    #[test]
+    fn weather_events_find_extremes_and_frosts() {
+        let mut days = season();
+        days[200].precipitation_mm = Some(60.0);
+        let events = weather_events(&days);
+        let titles: Vec<&str> = events.iter().map(|e| e.title.as_str()).collect();
+        for expected in [
+            "Hottest day",
+            "Heat days",
+            "Last spring frost",
+            "First fall frost",
+            "Wettest day",
+        ] {
+            assert!(titles.contains(&expected), "missing {expected}: {titles:?}");
+        }
+        let wettest = events.iter().find(|e| e.title == "Wettest day").unwrap();
+        assert_eq!(wettest.date, days[200].date);
+        // Rain every fifth day never leaves a week-long dry spell
+        assert!(!titles.contains(&"Longest dry spell"));
+    }
+
+    #[test]
+    fn renders_markdown_and_escaped_html() {
+        let mut profile = profile(GrassType::TallFescue);
+        profile.name = "Front <yard>".into();
+        let days = season();
+        let photo = Photo {
+            id: Some(1),
+            lawn_profile_id: 1,
+            area_id: None,
+            taken_on: date(2025, 9, 20),
+            path: "seed & feed/IMG_0142.jpg".into(),
+            caption: Some("Germination".into()),
+            created_at: chrono::Utc::now(),
+        };
+        let report = build_season_report(&SeasonReportInput {
+            year: 2025,
+            today: date(2026, 1, 10),
+            profile: &profile,
+            applications: &[application(ApplicationType::Overseed, date(2025, 9, 5))],
+            areas: &[],
+            days: &days,
+            compliance: Compliance::default(),
+            review: None,
+            photos: &[photo],
+        });
+        assert_eq!(report.photos[0].days_since_application, Some(15));
+
+        let md = render_markdown(&report);
+        assert!(md.starts_with("# 2025 Season Report — Front <yard>"));
+        assert!(md.contains("## Overseeding"));
+        assert!(md.contains("Not reviewed"));
+        assert!(md.contains("- **Pre-Emergent** (Feb 1 – May 31): nothing logged"));
+        assert!(md.contains(
+            "- Sep 20: `seed & feed/IMG_0142.jpg` — Germination [Overseed 15 days before; "
+        ));
+
+        let html = render_html(&report);
+        assert!(html.contains("Front &lt;yard&gt;"));
+        assert!(html.contains("<svg"));
+        assert!(html.contains("<code>seed &amp; feed/IMG_0142.jpg</code>"));
+        assert_eq!("MD".parse::<ReportFormat>(), Ok(ReportFormat::Markdown));
+    }
+}
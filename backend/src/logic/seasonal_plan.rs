@@ -699,4 +699,42 @@ mod tests {
             ActivityStatus::Missed
         ));
     }
+
+    #[test]
+    fn calendar_plan_snapshot() {
+        use crate::testing::{application, date};
+
+        // Three seasons of a smooth annual soil temperature curve, coldest
+        // (35°F) in late January and warmest (80°F) in late July, each season
+        // a few days later than the last
+        let daily: Vec<_> = (2023..=2025)
+            .flat_map(|year| {
+                let shift = (year - 2023) as f64 * 4.0;
+                (1..=365).filter_map(move |doy| {
+                    let date = NaiveDate::from_yo_opt(year, doy)?;
+                    let phase = (doy as f64 - 25.0 - shift) / 365.0 * std::f64::consts::TAU;
+                    Some(DailySoilTempAvg {
+                        date,
+                        avg_temp_f: 57.5 - 22.5 * phase.cos(),
+                    })
+                })
+            })
+            .collect();
+        let crossings: Vec<_> = (2023..=2025)
+            .flat_map(|year| {
+                let days: Vec<_> = daily
+                    .iter()
+                    .filter(|d| d.date.year() == year)
+                    .cloned()
+                    .collect();
+                find_threshold_crossings(year, &days)
+            })
+            .collect();
+
+        let applied = [application(ApplicationType::PreEmergent, date(2026, 4, 12))];
+        let activities = build_activities(2026, &crossings, &applied, date(2026, 4, 20));
+
+        // The Calendar page renders these activities directly
+        insta::assert_json_snapshot!(activities);
+    }
 }
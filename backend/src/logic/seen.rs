@@ -0,0 +1,55 @@
+//! "New since last visit" markers. The severity each recommendation had when
+//! it was last viewed is kept by id; anything not seen before is New, anything
+//! now more severe is Escalated. A recommendation that eases off is not
+//! flagged, but viewing it records the lower severity so a later rise is.
+
+use crate::models::{Recommendation, RecommendationChange, Severity};
+use std::collections::HashMap;
+
+/// Set `change` on each recommendation against the severities last viewed.
+pub fn mark_changes(recommendations: &mut [Recommendation], seen: &HashMap<String, Severity>) {
+    for rec in recommendations {
+        rec.change = match seen.get(&rec.id) {
+            None => Some(RecommendationChange::New),
+            Some(&severity) if rec.severity > severity => Some(RecommendationChange::Escalated),
+            Some(_) => None,
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::RecommendationCategory;
+
+    #[test]
+    fn flags_unseen_and_escalated_recommendations() {
+        let rec = |id: &str, severity| {
+            Recommendation::new(id, RecommendationCategory::Irrigation, severity, id, "")
+        };
+        let mut recs = vec![
+            rec("irrigation_forecast", Severity::Warning),
+            rec("disease_pressure", Severity::Warning),
+            rec("mowing_height", Severity::Info),
+            rec("pre_emergent", Severity::Advisory),
+        ];
+        let seen = HashMap::from([
+            ("irrigation_forecast".to_string(), Severity::Advisory),
+            ("disease_pressure".to_string(), Severity::Warning),
+            ("mowing_height".to_string(), Severity::Advisory),
+        ]);
+
+        mark_changes(&mut recs, &seen);
+        let changes: Vec<_> = recs.iter().map(|r| r.change).collect();
+        assert_eq!(
+            changes,
+            vec![
+                Some(RecommendationChange::Escalated),
+                None,
+                // Eased off since it was seen
+                None,
+                Some(RecommendationChange::New),
+            ]
+        );
+    }
+}
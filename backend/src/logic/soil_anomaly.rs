@@ -0,0 +1,155 @@
+//! How this week's soil compares to the same week in prior years. The 7-day
+//! soil temp average is set against the mean of the same seven calendar days
+//! in each earlier year of the station archive ("+4.2°F vs normal"), and a
+//! warm or cold spring moves the spring windows (pre-emergent, spring
+//! nitrogen) earlier or later by roughly the days soil takes to warm that
+//! much, instead of leaving them on the calendar.
+
+use crate::logic::rules::thresholds::{
+    SOIL_ANOMALY_MIN_F, SOIL_NORMAL_MIN_DAYS, SOIL_NORMAL_MIN_YEARS, SPRING_SHIFT_MAX_DAYS,
+    SPRING_SOIL_WARMING_F_PER_DAY,
+};
+use crate::models::seasonal_plan::DailySoilTempAvg;
+use crate::models::{EnvironmentalSummary, SoilTempAnomaly};
+use chrono::{Datelike, Duration, NaiveDate};
+use std::collections::BTreeMap;
+
+/// `date` moved back `years` years; Feb 29 lands on Feb 28.
+fn years_back(date: NaiveDate, years: i32) -> Option<NaiveDate> {
+    let year = date.year() - years;
+    NaiveDate::from_ymd_opt(year, date.month(), date.day())
+        .or_else(|| NaiveDate::from_ymd_opt(year, date.month(), date.day() - 1))
+}
+
+/// Mean soil temp over the seven days ending `today` in each prior year of
+/// `history` with at least `SOIL_NORMAL_MIN_DAYS` of them recorded, averaged
+/// across those years. `None` with fewer than `SOIL_NORMAL_MIN_YEARS`.
+/// Returns the normal and the years behind it.
+pub fn week_normal(history: &[DailySoilTempAvg], today: NaiveDate) -> Option<(f64, usize)> {
+    let by_day: BTreeMap<NaiveDate, f64> = history.iter().map(|d| (d.date, d.avg_temp_f)).collect();
+    let oldest = by_day.keys().next()?.year();
+    let week_start = today - Duration::days(6);
+
+    let year_means: Vec<f64> = (1..=today.year() - oldest)
+        .filter_map(|back| {
+            let (start, end) = (years_back(week_start, back)?, years_back(today, back)?);
+            let temps: Vec<f64> = by_day.range(start..=end).map(|(_, t)| *t).collect();
+            (temps.len() >= SOIL_NORMAL_MIN_DAYS)
+                .then(|| temps.iter().sum::<f64>() / temps.len() as f64)
+        })
+        .collect();
+    if year_means.len() < SOIL_NORMAL_MIN_YEARS {
+        return None;
+    }
+    let normal = year_means.iter().sum::<f64>() / year_means.len() as f64;
+    Some((normal, year_means.len()))
+}
+
+/// Days a spring `anomaly_f` warm (negative when cold) puts the season
+/// ahead of the calendar, capped at `SPRING_SHIFT_MAX_DAYS` either way.
+pub fn lead_days(anomaly_f: f64) -> i64 {
+    if anomaly_f.abs() < SOIL_ANOMALY_MIN_F {
+        return 0;
+    }
+    ((anomaly_f / SPRING_SOIL_WARMING_F_PER_DAY).round() as i64)
+        .clamp(-SPRING_SHIFT_MAX_DAYS, SPRING_SHIFT_MAX_DAYS)
+}
+
+/// The 7-day average `avg_f` against the normal for the week ending `today`.
+pub fn soil_temp_anomaly(
+    history: &[DailySoilTempAvg],
+    today: NaiveDate,
+    avg_f: f64,
+) -> Option<SoilTempAnomaly> {
+    let (normal_f, years) = week_normal(history, today)?;
+    let anomaly_f = avg_f - normal_f;
+    Some(SoilTempAnomaly {
+        normal_f,
+        anomaly_f,
+        years,
+        lead_days: lead_days(anomaly_f),
+    })
+}
+
+/// Days the spring windows open ahead of (or, negative, behind) the calendar.
+pub fn spring_lead_days(env: &EnvironmentalSummary) -> i64 {
+    env.soil_temp_anomaly.map_or(0, |a| a.lead_days)
+}
+
+/// "12 days early: soil 3.6°F above normal" when the anomaly moves the
+/// spring windows; `None` while the season runs on the calendar.
+pub fn spring_shift(env: &EnvironmentalSummary) -> Option<String> {
+    env.soil_temp_anomaly.filter(|a| a.lead_days != 0).map(|a| {
+        format!(
+            "{} days {}: soil {:.1}°F {} normal",
+            a.lead_days.abs(),
+            if a.lead_days > 0 { "early" } else { "late" },
+            a.anomaly_f.abs(),
+            if a.anomaly_f > 0.0 { "above" } else { "below" }
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    /// Daily means for every day of `years`, warming 0.3°F a day from 35°F
+    /// on Jan 1 plus `offset` for each year.
+    fn archive(years: &[(i32, f64)]) -> Vec<DailySoilTempAvg> {
+        years
+            .iter()
+            .flat_map(|&(year, offset)| {
+                (0..365).map(move |i| {
+                    let date = day(year, 1, 1) + Duration::days(i);
+                    DailySoilTempAvg {
+                        date,
+                        avg_temp_f: 35.0 + 0.3 * date.ordinal0() as f64 + offset,
+                    }
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn normal_averages_the_same_week_across_prior_years() {
+        let history = archive(&[(2023, -2.0), (2024, 0.0), (2025, 2.0)]);
+        let today = day(2026, 4, 10);
+        let (normal, years) = week_normal(&history, today).unwrap();
+        assert_eq!(years, 3);
+        // Apr 4-10 is ordinal0 93-99 (2024 is a leap year: 94-100)
+        let expected = 35.0 + 0.3 * (96.0 + 97.0 + 96.0) / 3.0;
+        assert!((normal - expected).abs() < 0.01, "{normal} vs {expected}");
+
+        // A week that spans the new year reaches into December; with no 2022
+        // in the archive, Dec 28 2022 - Jan 3 2023 has too few days to count
+        let (_, years) = week_normal(&history, day(2026, 1, 3)).unwrap();
+        assert_eq!(years, 2);
+
+        // One year isn't a normal, and this year's own data doesn't count
+        let thin = archive(&[(2025, 0.0), (2026, 0.0)]);
+        assert!(week_normal(&thin, today).is_none());
+    }
+
+    #[test]
+    fn anomaly_shifts_the_spring_within_limits() {
+        let history = archive(&[(2024, 0.0), (2025, 0.0)]);
+        let today = day(2026, 3, 20);
+        let (normal, _) = week_normal(&history, today).unwrap();
+
+        let warm = soil_temp_anomaly(&history, today, normal + 4.2).unwrap();
+        assert!((warm.anomaly_f - 4.2).abs() < 1e-9);
+        assert_eq!(warm.lead_days, 14);
+
+        let cold = soil_temp_anomaly(&history, today, normal - 3.0).unwrap();
+        assert_eq!(cold.lead_days, -10);
+
+        // Within normal variation the calendar stands; extremes are capped
+        assert_eq!(lead_days(1.5), 0);
+        assert_eq!(lead_days(-12.0), -SPRING_SHIFT_MAX_DAYS);
+    }
+}
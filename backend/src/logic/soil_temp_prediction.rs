@@ -273,6 +273,54 @@ pub fn predict_threshold_crossings(
     crossings
 }
 
+/// When soil temp first crosses `threshold_f` in `direction`, as
+/// `(date, confidence, projected)`. Uses the first predicted crossing when the model
+/// covers it; otherwise extrapolates the predicted trend (least-squares over current +
+/// predictions) at Low confidence. `None` if already past or heading away.
+pub fn project_crossing(
+    current_soil_temp_f: f64,
+    predictions: &[SoilTempPrediction],
+    today: NaiveDate,
+    threshold_f: f64,
+    direction: CrossingDirection,
+) -> Option<(NaiveDate, PredictionConfidence, bool)> {
+    let not_yet = |t: f64| match direction {
+        CrossingDirection::Rising => t < threshold_f,
+        CrossingDirection::Falling => t >= threshold_f,
+    };
+    if !not_yet(current_soil_temp_f) {
+        return None;
+    }
+
+    let modeled = predictions
+        .iter()
+        .find(|p| !not_yet(p.predicted_soil_temp_f))
+        .map(|p| (p.date, p.confidence, false));
+
+    modeled.or_else(|| {
+        let mut xs = vec![0.0];
+        let mut ys = vec![current_soil_temp_f];
+        for p in predictions {
+            xs.push((p.date - today).num_days() as f64);
+            ys.push(p.predicted_soil_temp_f);
+        }
+        let (slope, _, _) = linear_regression(&xs, &ys)?;
+        let heading_toward = match direction {
+            CrossingDirection::Rising => slope > 0.0,
+            CrossingDirection::Falling => slope < 0.0,
+        };
+        if !heading_toward {
+            return None;
+        }
+        let days = ((threshold_f - current_soil_temp_f) / slope).ceil() as i64;
+        Some((
+            today + chrono::Duration::days(days),
+            PredictionConfidence::Low,
+            true,
+        ))
+    })
+}
+
 /// Build a complete SoilTempForecast from paired data, recent air temps, and forecast.
 pub fn build_forecast(
     daily_pairs: &[(NaiveDate, f64, f64)],
@@ -531,4 +579,52 @@ mod tests {
         let forecast: Vec<(NaiveDate, f64)> = vec![];
         assert!(build_forecast(&pairs, &recent, &forecast, 50.0).is_none());
     }
+
+    fn pred(date: NaiveDate, temp: f64) -> SoilTempPrediction {
+        SoilTempPrediction {
+            date,
+            predicted_soil_temp_f: temp,
+            confidence: PredictionConfidence::Medium,
+            air_temp_used_f: temp,
+            source_description: "test".to_string(),
+        }
+    }
+
+    #[test]
+    fn crossing_within_predictions() {
+        let today = NaiveDate::from_ymd_opt(2026, 3, 16).unwrap();
+        let predictions = vec![
+            pred(today + chrono::Duration::days(1), 48.0),
+            pred(today + chrono::Duration::days(2), 50.5),
+        ];
+        let (date, confidence, projected) =
+            project_crossing(47.0, &predictions, today, 50.0, CrossingDirection::Rising).unwrap();
+        assert_eq!(date, today + chrono::Duration::days(2));
+        assert!(!projected);
+        assert_eq!(confidence, PredictionConfidence::Medium);
+    }
+
+    #[test]
+    fn crossing_extrapolated_from_trend() {
+        // Rising 1°F/day from 40°F: 50°F reached in ~10 days, beyond the 5-day forecast
+        let today = NaiveDate::from_ymd_opt(2026, 3, 1).unwrap();
+        let predictions: Vec<_> = (1..=5)
+            .map(|i| pred(today + chrono::Duration::days(i), 40.0 + i as f64))
+            .collect();
+        let (date, confidence, projected) =
+            project_crossing(40.0, &predictions, today, 50.0, CrossingDirection::Rising).unwrap();
+        assert_eq!(date, today + chrono::Duration::days(10));
+        assert!(projected);
+        assert_eq!(confidence, PredictionConfidence::Low);
+    }
+
+    #[test]
+    fn crossing_none_when_already_past_or_heading_away() {
+        let today = NaiveDate::from_ymd_opt(2026, 8, 20).unwrap();
+        let cooling: Vec<_> = (1..=5)
+            .map(|i| pred(today + chrono::Duration::days(i), 55.0 - i as f64))
+            .collect();
+        assert!(project_crossing(55.0, &cooling, today, 60.0, CrossingDirection::Rising).is_none());
+        assert!(project_crossing(55.0, &cooling, today, 50.0, CrossingDirection::Rising).is_none());
+    }
 }
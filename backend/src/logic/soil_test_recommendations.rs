@@ -414,6 +414,10 @@ mod tests {
             soil_type: soil,
             lawn_size_sqft: Some(5000.0),
             irrigation_type: None,
+            location: None,
+            noaa_station_wbanno: None,
+            dormant: false,
+            mowing_height_in: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         }
@@ -0,0 +1,387 @@
+//! Turf stress index: a daily 0-100 score for cool-season turf blending heat
+//! (the day's high), drought (the trailing week's ET not replaced by rain or
+//! logged irrigation), disease pressure (humid hours on a warm night) and wear
+//! (Traffic journal notes). Built from the station's hourly readings.
+//!
+//! Sustained High stress holds back fertilizer recommendations (applied by the
+//! engine, like the seedling block) and raises the recommended mowing height.
+
+use super::calculations::hargreaves_et0_mm;
+use super::rules::thresholds::*;
+use crate::datasources::weather::WeatherLakeClient;
+use crate::db::{observation_queries, queries};
+use crate::error::{Result, TurfOpsError};
+use crate::models::{
+    ApplicationType, DailyStress, DegreesF, EnvironmentalReading, EnvironmentalSummary,
+    LawnProfile, ObservationCategory, Recommendation, RecommendationCategory, StressComponents,
+    StressIndexSummary, StressLevel, StressSnapshot,
+};
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, Utc};
+use sqlx::PgPool;
+use std::collections::BTreeMap;
+
+const WEIGHT_HEAT: f64 = 0.35;
+const WEIGHT_DROUGHT: f64 = 0.35;
+const WEIGHT_DISEASE: f64 = 0.20;
+const WEIGHT_TRAFFIC: f64 = 0.10;
+
+/// Latitude for ET when the profile has no location set.
+const DEFAULT_LATITUDE: f64 = 40.0;
+
+/// Hourly temperatures a day needs before it's scored.
+const MIN_HOURS_PER_DAY: usize = 12;
+
+/// Days of history returned for the dashboard sparkline.
+pub const HISTORY_DAYS: i64 = 30;
+
+/// The recommendation that already says "don't fertilize now"; never suppressed.
+const FERTILIZER_BLOCK_ID: &str = "fertilizer_block";
+
+/// One local day of station weather, aggregated from hourly readings.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DailyConditions {
+    pub date: NaiveDate,
+    pub high_f: f64,
+    pub low_f: f64,
+    pub rain_mm: f64,
+    /// Hours at or above `HUMIDITY_DISEASE_RISK`.
+    pub humid_hours: f64,
+}
+
+/// Group hourly readings by local date, oldest first. Days with fewer than
+/// [`MIN_HOURS_PER_DAY`] temperatures are skipped rather than scored on a
+/// partial high/low.
+pub fn daily_conditions(readings: &[EnvironmentalReading]) -> Vec<DailyConditions> {
+    let mut by_day: BTreeMap<NaiveDate, Vec<&EnvironmentalReading>> = BTreeMap::new();
+    for r in readings {
+        by_day
+            .entry(r.timestamp.with_timezone(&Local).date_naive())
+            .or_default()
+            .push(r);
+    }
+
+    by_day
+        .into_iter()
+        .filter_map(|(date, hours)| {
+            let temps: Vec<f64> = hours.iter().filter_map(|r| r.ambient_temp_f).collect();
+            if temps.len() < MIN_HOURS_PER_DAY {
+                return None;
+            }
+            Some(DailyConditions {
+                date,
+                high_f: temps.iter().copied().fold(f64::MIN, f64::max),
+                low_f: temps.iter().copied().fold(f64::MAX, f64::min),
+                rain_mm: hours.iter().filter_map(|r| r.precipitation_mm).sum(),
+                humid_hours: hours
+                    .iter()
+                    .filter(|r| {
+                        r.humidity_percent
+                            .is_some_and(|h| h >= HUMIDITY_DISEASE_RISK)
+                    })
+                    .count() as f64,
+            })
+        })
+        .collect()
+}
+
+/// 0 at `start`, 100 at `full`, linear between.
+fn ramp(value: f64, start: f64, full: f64) -> f64 {
+    ((value - start) / (full - start)).clamp(0.0, 1.0) * 100.0
+}
+
+pub fn level_for(score: f64) -> StressLevel {
+    if score >= STRESS_SEVERE {
+        StressLevel::Severe
+    } else if score >= STRESS_HIGH {
+        StressLevel::High
+    } else if score >= STRESS_HIGH / 2.0 {
+        StressLevel::Moderate
+    } else {
+        StressLevel::Low
+    }
+}
+
+/// Score each day. The drought and traffic factors look back over the trailing
+/// `STRESS_DEFICIT_WINDOW_DAYS` (by date, so gaps shorten the window rather than
+/// stretch it); include that many warm-up days before the first one you show.
+pub fn score_days(
+    conditions: &[DailyConditions],
+    latitude: f64,
+    irrigated: &[NaiveDate],
+    traffic_notes: &[NaiveDate],
+) -> Vec<DailyStress> {
+    let window = Duration::days(STRESS_DEFICIT_WINDOW_DAYS as i64);
+    let in_window = |date: NaiveDate, day: NaiveDate| date <= day && date > day - window;
+
+    let water: Vec<(f64, f64)> = conditions
+        .iter()
+        .map(|c| {
+            let et = hargreaves_et0_mm(
+                DegreesF(c.high_f),
+                DegreesF(c.low_f),
+                latitude,
+                c.date.ordinal(),
+            )
+            .0;
+            let irrigation =
+                irrigated.iter().filter(|d| **d == c.date).count() as f64 * STRESS_IRRIGATION_MM;
+            (et, c.rain_mm + irrigation)
+        })
+        .collect();
+
+    conditions
+        .iter()
+        .zip(&water)
+        .map(|(day, &(et_mm, water_mm))| {
+            let deficit = conditions
+                .iter()
+                .zip(&water)
+                .filter(|(c, _)| in_window(c.date, day.date))
+                .map(|(_, (et, water))| et - water)
+                .sum::<f64>()
+                .max(0.0);
+            let notes = traffic_notes
+                .iter()
+                .filter(|d| in_window(**d, day.date))
+                .count() as f64;
+
+            let components = StressComponents {
+                heat: ramp(day.high_f, STRESS_HEAT_START_F, STRESS_HEAT_MAX_F),
+                drought: ramp(deficit, 0.0, STRESS_DEFICIT_MAX_MM),
+                disease: ramp(day.humid_hours, 0.0, STRESS_HUMID_HOURS_MAX)
+                    * ramp(
+                        day.low_f,
+                        STRESS_WARM_NIGHT_START_F,
+                        STRESS_WARM_NIGHT_FULL_F,
+                    )
+                    / 100.0,
+                traffic: ramp(notes, 0.0, STRESS_TRAFFIC_NOTES_MAX),
+            };
+            let score = (components.heat * WEIGHT_HEAT
+                + components.drought * WEIGHT_DROUGHT
+                + components.disease * WEIGHT_DISEASE
+                + components.traffic * WEIGHT_TRAFFIC)
+                .round();
+
+            DailyStress {
+                date: day.date,
+                score,
+                level: level_for(score),
+                components,
+                high_temp_f: day.high_f,
+                et_mm,
+                water_mm,
+                water_deficit_mm: deficit,
+            }
+        })
+        .collect()
+}
+
+/// Wrap scored days (oldest first) with the latest day and the current run of
+/// High-or-worse days.
+pub fn summarize(days: Vec<DailyStress>) -> StressIndexSummary {
+    let sustained_high_days = days
+        .iter()
+        .rev()
+        .take_while(|d| d.level >= StressLevel::High)
+        .count() as u32;
+    StressIndexSummary {
+        current: days.last().cloned(),
+        sustained_high_days,
+        days,
+    }
+}
+
+impl StressIndexSummary {
+    pub fn snapshot(&self) -> Option<StressSnapshot> {
+        self.current.as_ref().map(|d| StressSnapshot {
+            score: d.score,
+            level: d.level,
+            sustained_high_days: self.sustained_high_days,
+        })
+    }
+}
+
+/// The stress snapshot, when it has stayed High for `STRESS_SUSTAINED_DAYS`.
+pub fn sustained(env: &EnvironmentalSummary) -> Option<&StressSnapshot> {
+    env.stress_index
+        .as_ref()
+        .filter(|s| s.sustained_high_days >= STRESS_SUSTAINED_DAYS)
+}
+
+/// Drop fertilizer recommendations while stress is sustained. Applied by the
+/// engine to every rule's output, so custom and scripted rules are covered too.
+pub fn suppress_under_stress(
+    recommendations: Vec<Recommendation>,
+    env: &EnvironmentalSummary,
+) -> Vec<Recommendation> {
+    if sustained(env).is_none() {
+        return recommendations;
+    }
+    recommendations
+        .into_iter()
+        .filter(|r| !is_stress_unsafe(r))
+        .collect()
+}
+
+/// Nitrogen pushes top growth the roots can't support in heat and drought.
+pub fn is_stress_unsafe(rec: &Recommendation) -> bool {
+    rec.category == RecommendationCategory::Fertilizer && rec.id != FERTILIZER_BLOCK_ID
+}
+
+/// The last [`HISTORY_DAYS`] of the index up to `now`, from the station's
+/// hourly readings plus the profile's irrigation log and traffic notes.
+pub async fn load(
+    pool: &PgPool,
+    client: &WeatherLakeClient,
+    profile: &LawnProfile,
+    now: DateTime<Utc>,
+) -> Result<StressIndexSummary> {
+    let profile_id = profile
+        .id
+        .ok_or_else(|| TurfOpsError::InvalidData("Profile missing ID".into()))?;
+    let today = now.with_timezone(&Local).date_naive();
+    let first_shown = today - Duration::days(HISTORY_DAYS - 1);
+    let start = now - Duration::days(HISTORY_DAYS + STRESS_DEFICIT_WINDOW_DAYS as i64);
+
+    let readings = client.fetch_range(start, now).await?;
+    let irrigated: Vec<NaiveDate> = queries::get_applications_for_profile_in_range(
+        pool,
+        profile_id,
+        start.date_naive(),
+        today + Duration::days(1),
+    )
+    .await?
+    .into_iter()
+    .filter(|a| a.application_type == ApplicationType::Irrigation)
+    .map(|a| a.application_date)
+    .collect();
+    let traffic: Vec<NaiveDate> =
+        observation_queries::get_observations_in_range(pool, profile_id, start, now)
+            .await?
+            .into_iter()
+            .filter(|o| o.category == ObservationCategory::Traffic)
+            .map(|o| o.observed_at.with_timezone(&Local).date_naive())
+            .collect();
+
+    let latitude = profile
+        .location
+        .as_ref()
+        .map_or(DEFAULT_LATITUDE, |l| l.latitude);
+    let mut days = score_days(&daily_conditions(&readings), latitude, &irrigated, &traffic);
+    days.retain(|d| d.date >= first_shown);
+    Ok(summarize(days))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::local_noon;
+    use crate::models::DataSource;
+    use crate::testing::{date, EnvBuilder};
+
+    fn day(on: NaiveDate, high_f: f64, low_f: f64, rain_mm: f64) -> DailyConditions {
+        DailyConditions {
+            date: on,
+            high_f,
+            low_f,
+            rain_mm,
+            humid_hours: 0.0,
+        }
+    }
+
+    fn hot_dry_week(first: NaiveDate) -> Vec<DailyConditions> {
+        (0..7)
+            .map(|i| day(first + Duration::days(i), 94.0, 72.0, 0.0))
+            .collect()
+    }
+
+    #[test]
+    fn hourly_readings_roll_up_by_local_day() {
+        let noon = local_noon(date(2026, 7, 10));
+        let readings: Vec<_> = (-6..6)
+            .map(|h| {
+                let mut r = EnvironmentalReading::new(DataSource::SoilData);
+                r.timestamp = noon + Duration::hours(h);
+                r.ambient_temp_f = Some(70.0 + h as f64);
+                r.humidity_percent = Some(if h < 0 { 92.0 } else { 50.0 });
+                r.precipitation_mm = Some(0.5);
+                r
+            })
+            .collect();
+
+        let days = daily_conditions(&readings);
+        assert_eq!(days.len(), 1);
+        assert_eq!(days[0].date, date(2026, 7, 10));
+        assert_eq!((days[0].high_f, days[0].low_f), (75.0, 64.0));
+        assert_eq!(days[0].rain_mm, 6.0);
+        assert_eq!(days[0].humid_hours, 6.0);
+
+        // Too few hours to trust the high and low
+        assert!(daily_conditions(&readings[..6]).is_empty());
+    }
+
+    #[test]
+    fn heat_and_drought_build_over_a_dry_week() {
+        let days = score_days(&hot_dry_week(date(2026, 7, 6)), 40.0, &[], &[]);
+        let (first, last) = (&days[0], &days[6]);
+
+        assert!(first.components.heat > 90.0);
+        assert!(last.water_deficit_mm > STRESS_DEFICIT_MAX_MM);
+        assert_eq!(last.components.drought, 100.0);
+        assert!(first.score < last.score);
+        assert!(last.level >= StressLevel::High, "{:?}", last);
+        assert_eq!(summarize(days).sustained_high_days, 4);
+    }
+
+    #[test]
+    fn rain_irrigation_and_traffic_move_the_score() {
+        let week = hot_dry_week(date(2026, 7, 6));
+        let dry = score_days(&week, 40.0, &[], &[]);
+
+        let mut wet = week.clone();
+        wet[5].rain_mm = 25.0;
+        let irrigated = [date(2026, 7, 12)];
+        let watered = score_days(&wet, 40.0, &irrigated, &[]);
+        assert_eq!(watered[6].water_mm, STRESS_IRRIGATION_MM);
+        assert!(watered[6].water_deficit_mm < dry[6].water_deficit_mm - 37.0);
+        assert!(watered[6].score < dry[6].score);
+
+        let notes = [date(2026, 7, 11), date(2026, 7, 12), date(2026, 7, 12)];
+        let worn = score_days(&week, 40.0, &[], &notes);
+        assert_eq!(worn[6].components.traffic, 100.0);
+        assert_eq!(worn[6].score, dry[6].score + 10.0);
+    }
+
+    #[test]
+    fn sustained_stress_suppresses_fertilizer_only() {
+        let recs = || {
+            vec![
+                Recommendation::new(
+                    "spring_nitrogen",
+                    RecommendationCategory::Fertilizer,
+                    crate::models::Severity::Advisory,
+                    "Apply N",
+                    "",
+                ),
+                Recommendation::new(
+                    FERTILIZER_BLOCK_ID,
+                    RecommendationCategory::Fertilizer,
+                    crate::models::Severity::Warning,
+                    "Hold fertilizer",
+                    "",
+                ),
+            ]
+        };
+        let env = |days| {
+            EnvBuilder::new(local_noon(date(2026, 7, 12)))
+                .stress(72.0, days)
+                .build()
+        };
+
+        assert_eq!(suppress_under_stress(recs(), &env(2)).len(), 2);
+        let kept = suppress_under_stress(recs(), &env(STRESS_SUSTAINED_DAYS));
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0].id, FERTILIZER_BLOCK_ID);
+    }
+}
@@ -0,0 +1,113 @@
+//! Weather backfill: rebuild a `WeatherSnapshot` for a back-dated or imported
+//! application from the station's hourly history for that day, so questions
+//! like "what soil temp did I seed at last year?" work for entries made after
+//! the fact. Snapshots are daily means and flagged `reconstructed`. Dates
+//! before the station's history fall back to the Open-Meteo archive.
+
+use crate::models::{EnvironmentalReading, HistoricalDay, WeatherSnapshot};
+use chrono::{DateTime, Duration, Local, NaiveDate, TimeZone, Utc};
+
+/// Local midnight to the next local midnight for `date`, in UTC.
+pub fn day_bounds(date: NaiveDate) -> (DateTime<Utc>, DateTime<Utc>) {
+    let midnight = date.and_time(chrono::NaiveTime::MIN);
+    let start = Local
+        .from_local_datetime(&midnight)
+        .earliest()
+        .map(|t| t.with_timezone(&Utc))
+        .unwrap_or_else(|| midnight.and_utc());
+    (start, start + Duration::days(1))
+}
+
+fn mean(values: impl Iterator<Item = f64>) -> Option<f64> {
+    let (sum, count) = values.fold((0.0, 0usize), |(s, n), v| (s + v, n + 1));
+    (count > 0).then(|| sum / count as f64)
+}
+
+/// Daily means of the readings; `None` when the day has no usable values.
+pub fn snapshot_from_readings(readings: &[EnvironmentalReading]) -> Option<WeatherSnapshot> {
+    let snapshot = WeatherSnapshot {
+        soil_temp_10cm_f: mean(readings.iter().filter_map(|r| r.soil_temp_10_f)),
+        ambient_temp_f: mean(readings.iter().filter_map(|r| r.ambient_temp_f)),
+        humidity_percent: mean(readings.iter().filter_map(|r| r.humidity_percent)),
+        soil_moisture: mean(readings.iter().filter_map(|r| r.soil_moisture_10)),
+        reconstructed: true,
+    };
+    let any = snapshot.soil_temp_10cm_f.is_some()
+        || snapshot.ambient_temp_f.is_some()
+        || snapshot.humidity_percent.is_some()
+        || snapshot.soil_moisture.is_some();
+    any.then_some(snapshot)
+}
+
+/// Snapshot from an archived day; `None` when the archive had nothing yet.
+pub fn snapshot_from_history(day: &HistoricalDay) -> Option<WeatherSnapshot> {
+    let snapshot = WeatherSnapshot {
+        soil_temp_10cm_f: day.soil_temp_f,
+        ambient_temp_f: day.mean_temp_f,
+        humidity_percent: day.humidity_percent,
+        soil_moisture: day.soil_moisture,
+        reconstructed: true,
+    };
+    let any = snapshot.soil_temp_10cm_f.is_some() || snapshot.ambient_temp_f.is_some();
+    any.then_some(snapshot)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::DataSource;
+    use crate::testing::{at, date};
+
+    fn reading(soil: Option<f64>, air: Option<f64>) -> EnvironmentalReading {
+        let mut r = EnvironmentalReading::new(DataSource::SoilData);
+        r.timestamp = at(2025, 9, 10);
+        r.soil_temp_10_f = soil;
+        r.ambient_temp_f = air;
+        r
+    }
+
+    #[test]
+    fn snapshot_is_daily_mean_of_available_values() {
+        let snap = snapshot_from_readings(&[
+            reading(Some(60.0), Some(70.0)),
+            reading(Some(64.0), None),
+            reading(None, Some(80.0)),
+        ])
+        .unwrap();
+        assert_eq!(snap.soil_temp_10cm_f, Some(62.0));
+        assert_eq!(snap.ambient_temp_f, Some(75.0));
+        assert_eq!(snap.humidity_percent, None);
+        assert!(snap.reconstructed);
+    }
+
+    #[test]
+    fn empty_day_gives_no_snapshot() {
+        assert!(snapshot_from_readings(&[]).is_none());
+        assert!(snapshot_from_readings(&[reading(None, None)]).is_none());
+        let (start, end) = day_bounds(date(2025, 9, 10));
+        assert_eq!(end - start, Duration::days(1));
+    }
+
+    #[test]
+    fn archived_day_maps_to_snapshot() {
+        let mut day = HistoricalDay {
+            date: date(2018, 9, 10),
+            high_temp_f: Some(80.0),
+            low_temp_f: Some(60.0),
+            mean_temp_f: Some(70.5),
+            humidity_percent: Some(64.0),
+            precipitation_mm: Some(0.0),
+            soil_temp_f: Some(68.0),
+            soil_moisture: Some(0.28),
+        };
+        let snap = snapshot_from_history(&day).unwrap();
+        assert_eq!(snap.ambient_temp_f, Some(70.5));
+        assert_eq!(snap.soil_temp_10cm_f, Some(68.0));
+        assert!(snap.reconstructed);
+
+        // Too recent for the archive
+        day.mean_temp_f = None;
+        day.soil_temp_f = None;
+        assert!(snapshot_from_history(&day).is_none());
+    }
+}
@@ -1,19 +1,32 @@
 mod api;
+mod cli;
+mod clock;
 mod config;
 mod datasources;
 mod db;
 mod error;
+mod i18n;
+mod logging;
 mod logic;
 mod models;
 mod state;
+#[cfg(test)]
+mod testing;
 
 use crate::config::Config;
 use crate::datasources::OpenRouterClient;
-use crate::db::{pool::create_pool, queries};
+use crate::db::pool::create_pool;
+use crate::db::{queries, schema_check, settings_queries};
+use crate::i18n::Catalog;
 use crate::logic::data_sync::DataSyncService;
+use crate::logic::product_labels;
+use crate::logic::rules::custom::load_custom_rules;
+use crate::logic::rules::script::load_script_rules;
+use crate::logic::rules::{Rule, RulesEngine};
 use crate::models::{GrassType, IrrigationType, LawnProfile, SoilType};
 use crate::state::AppState;
-use axum::routing::{get, patch, post, put};
+use axum::middleware;
+use axum::routing::{delete, get, patch, post, put};
 use axum::Router;
 use std::net::SocketAddr;
 use std::path::PathBuf;
@@ -21,7 +34,6 @@ use std::str::FromStr;
 use tower_http::cors::CorsLayer;
 use tower_http::limit::RequestBodyLimitLayer;
 use tower_http::services::{ServeDir, ServeFile};
-use tracing_subscriber::EnvFilter;
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
@@ -31,58 +43,164 @@ async fn main() -> anyhow::Result<()> {
     }
 
     // Initialize tracing
-    tracing_subscriber::fmt()
-        .with_env_filter(EnvFilter::try_from_default_env().unwrap_or_else(|_| "info".into()))
-        .init();
+    let log_file = logging::LogFileConfig::from_env();
+    let _log_guard = logging::init(log_file.as_ref());
+
+    // Parsed before config so `completions` and `man` work without a database configured
+    let invocation = cli::parse(std::env::args_os()).unwrap_or_else(|e| e.exit());
+    if let Some(generated) = invocation.as_ref().and_then(|i| cli::generate(&i.command)) {
+        std::io::Write::write_all(&mut std::io::stdout(), &generated?)?;
+        return Ok(());
+    }
 
     // Load config from environment
     let config = Config::from_env()?;
     tracing::info!("Configuration loaded");
 
+    // A subcommand runs once against the database and exits; no arguments serves the app
+    if let Some(invocation) = invocation {
+        std::process::exit(cli::run(invocation, &config).await?);
+    }
+
+    // One server per database; held until the process exits
+    let instance_lock = db::instance_lock::acquire(&config.database.connect_options()).await?;
+
     // Connect to app database and run migrations
     let pool = create_pool(config.database.connect_options()).await?;
+    for missing in schema_check::missing_indexes(&schema_check::list_indexes(&pool).await?) {
+        tracing::warn!(
+            table = missing.table,
+            columns = ?missing.columns,
+            "Missing index for {}; run `turfops-backend check-schema`",
+            missing.purpose
+        );
+    }
+
+    // Before anything reads or writes notes and locations
+    db::encryption::prepare(&pool, config.database.encryption_key.as_ref()).await?;
 
     // Create default profile if DB is empty
     ensure_default_profile(&pool, &config).await?;
 
-    // Initialize data sync service (connects to external datasources)
-    let sync_service = DataSyncService::initialize(&config, pool.clone()).await;
+    logic::retention::spawn_retention_task(pool.clone(), config.retention.clone());
 
-    // Initialize OpenRouter client (optional, used for plant maintenance plans)
-    let openrouter = config
-        .openrouter
-        .as_ref()
-        .filter(|c| c.enabled && !c.api_key.is_empty())
-        .map(|c| {
-            tracing::info!(model = %c.model, "OpenRouter client configured for plant plans");
-            OpenRouterClient::new(c.clone())
-        });
-    if openrouter.is_none() {
+    let state = build_state(&config, pool.clone())
+        .await?
+        .with_log_dir(log_file.map(|f| f.dir));
+
+    logic::connectivity::spawn_connection_monitor(state.clone());
+
+    if let Some(digest) = config.digest.clone() {
         tracing::info!(
-            "OpenRouter not configured — landscape maintenance plan generation unavailable"
+            frequency = ?digest.frequency,
+            hour = digest.hour,
+            recipients = digest.to.len(),
+            quiet_hours = ?config.notifications.quiet_hours,
+            "Digest email enabled"
         );
+        logic::digest::spawn_digest_task(state.clone(), digest, config.notifications.clone());
     }
 
-    // Create app state
-    let state = AppState::new(pool, sync_service, openrouter);
-
     // Build router
     let app = Router::new()
         .route("/api/v1/health", get(api::health::health_check))
+        .route("/glance", get(api::glance::get_glance))
+        .route(
+            "/api/v1/annual-reviews",
+            get(api::annual_review::list_annual_reviews),
+        )
+        .route(
+            "/api/v1/annual-reviews/{year}",
+            get(api::annual_review::get_annual_review).put(api::annual_review::save_annual_review),
+        )
+        .route(
+            "/api/v1/annual-reviews/{year}/suggestions",
+            post(api::annual_review::suggest_adjustments),
+        )
         .route("/api/v1/dashboard", get(api::dashboard::get_dashboard))
+        .route(
+            "/api/v1/dashboard/layout",
+            get(api::dashboard::get_layout).put(api::dashboard::set_layout),
+        )
+        .route(
+            "/api/v1/efficacy/{year}",
+            get(api::efficacy::get_efficacy_report),
+        )
+        .route(
+            "/api/v1/reports/{year}",
+            get(api::report::get_season_report),
+        )
+        .route(
+            "/api/v1/season-bundles",
+            post(api::season_bundle::import_season_bundle),
+        )
+        .route(
+            "/api/v1/season-bundles/{year}",
+            get(api::season_bundle::export_season_bundle),
+        )
         .route(
             "/api/v1/profile",
             get(api::profile::get_profile).put(api::profile::update_profile),
         )
+        .route(
+            "/api/v1/profiles",
+            get(api::profile::list_profiles).post(api::profile::create_profile),
+        )
+        .route(
+            "/api/v1/profiles/active",
+            put(api::profile::set_active_profile),
+        )
+        .route(
+            "/api/v1/profiles/{id}",
+            delete(api::profile::delete_profile),
+        )
+        .route(
+            "/api/v1/areas",
+            get(api::areas::list_areas).post(api::areas::create_area),
+        )
+        .route(
+            "/api/v1/areas/{id}",
+            put(api::areas::update_area).delete(api::areas::delete_area),
+        )
+        .route(
+            "/api/v1/application-types",
+            get(api::application_types::list_application_types)
+                .post(api::application_types::create_custom_type),
+        )
+        .route(
+            "/api/v1/application-types/{id}",
+            put(api::application_types::update_custom_type)
+                .delete(api::application_types::delete_custom_type),
+        )
         .route(
             "/api/v1/applications",
             get(api::applications::list_applications).post(api::applications::create_application),
         )
+        .route(
+            "/api/v1/products",
+            get(api::products::list_products).post(api::products::create_product),
+        )
+        .route(
+            "/api/v1/products/import",
+            post(api::products::import_products),
+        )
+        .route(
+            "/api/v1/products/{id}",
+            put(api::products::update_product).delete(api::products::delete_product),
+        )
         .route(
             "/api/v1/applications/{id}",
             put(api::applications::update_application)
                 .delete(api::applications::delete_application),
         )
+        .route(
+            "/api/v1/applications/backfill-weather",
+            post(api::applications::backfill_weather),
+        )
+        .route(
+            "/api/v1/applications/agenda",
+            get(api::calendar::get_agenda),
+        )
         .route(
             "/api/v1/applications/calendar",
             get(api::calendar::get_calendar),
@@ -95,20 +213,106 @@ async fn main() -> anyhow::Result<()> {
             "/api/v1/environmental/refresh",
             post(api::environmental::refresh_environmental),
         )
+        .route(
+            "/api/v1/environmental/gauge-ranges",
+            get(api::environmental::get_gauge_ranges),
+        )
+        .route(
+            "/api/v1/environmental/frost-dates",
+            get(api::environmental::get_frost_dates),
+        )
         .route(
             "/api/v1/recommendations",
             get(api::recommendations::list_recommendations),
         )
+        .route(
+            "/api/v1/recommendations/explain",
+            get(api::recommendations::explain_recommendations),
+        )
+        .route(
+            "/api/v1/recommendations/seen",
+            post(api::recommendations::mark_recommendations_seen),
+        )
         .route(
             "/api/v1/recommendations/{id}",
             patch(api::recommendations::patch_recommendation),
         )
+        .route(
+            "/api/v1/location",
+            get(api::location::get_location)
+                .put(api::location::set_location)
+                .delete(api::location::clear_profile_location),
+        )
+        .route(
+            "/api/v1/location/search",
+            get(api::location::search_location),
+        )
+        .route("/api/v1/locale", get(api::locale::get_locale))
+        .route(
+            "/api/v1/ui-state",
+            get(api::ui_state::get_ui_state).put(api::ui_state::set_ui_state),
+        )
+        .route("/api/v1/rules", get(api::rules::list_rules))
+        .route("/api/v1/rules/backtest", get(api::rules::backtest))
+        .route(
+            "/api/v1/rules/posture",
+            get(api::rules::get_posture).put(api::rules::set_posture),
+        )
+        .route("/api/v1/rules/{name}", put(api::rules::set_rule_enabled))
+        .route(
+            "/api/v1/equipment",
+            get(api::equipment::list_equipment).post(api::equipment::create_equipment),
+        )
+        .route(
+            "/api/v1/equipment/{id}",
+            put(api::equipment::update_equipment).delete(api::equipment::delete_equipment),
+        )
+        .route(
+            "/api/v1/equipment/{id}/maintenance",
+            post(api::equipment::log_maintenance),
+        )
+        .route(
+            "/api/v1/equipment/{id}/maintenance/{event_id}",
+            delete(api::equipment::delete_maintenance),
+        )
+        .route("/api/v1/events", get(api::events::list_events))
+        .route("/api/v1/logs", get(api::logs::tail_logs))
         .route("/api/v1/gdd", get(api::gdd::get_gdd))
+        .route(
+            "/api/v1/stress-index",
+            get(api::stress_index::get_stress_index),
+        )
         .route("/api/v1/historical", get(api::historical::get_historical))
+        .route(
+            "/api/v1/inventory",
+            get(api::inventory::list_inventory).post(api::inventory::create_inventory_item),
+        )
+        .route(
+            "/api/v1/inventory/{id}",
+            put(api::inventory::update_inventory_item)
+                .delete(api::inventory::delete_inventory_item),
+        )
         .route(
             "/api/v1/nitrogen-budget",
             get(api::nitrogen_budget::get_nitrogen_budget),
         )
+        .route(
+            "/api/v1/observations",
+            get(api::observations::list_observations).post(api::observations::create_observation),
+        )
+        .route(
+            "/api/v1/observations/{id}",
+            put(api::observations::update_observation)
+                .delete(api::observations::delete_observation),
+        )
+        .route(
+            "/api/v1/photos",
+            get(api::photos::list_photos).post(api::photos::create_photo),
+        )
+        .route(
+            "/api/v1/photos/{id}",
+            put(api::photos::update_photo).delete(api::photos::delete_photo),
+        )
         .route(
             "/api/v1/seasonal-plan",
             get(api::seasonal_plan::get_seasonal_plan),
@@ -143,6 +347,10 @@ async fn main() -> anyhow::Result<()> {
             "/api/v1/soil-tests/{id}",
             put(api::soil_tests::update_soil_test).delete(api::soil_tests::delete_soil_test),
         )
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            api::error_log::record_server_errors,
+        ))
         .layer(RequestBodyLimitLayer::new(1024 * 1024)) // 1MB request body limit
         .layer(build_cors_layer(&config))
         .with_state(state);
@@ -169,9 +377,68 @@ async fn main() -> anyhow::Result<()> {
     tracing::info!("Starting server on {}", addr);
 
     let listener = tokio::net::TcpListener::bind(addr).await?;
-    axum::serve(listener, app).await?;
+    axum::serve(listener, app)
+        .with_graceful_shutdown(instance_lock.lost())
+        .await?;
 
-    Ok(())
+    // Serving only stops once another server holds the lock
+    anyhow::bail!("Another TurfOps server took over this database")
+}
+
+/// Data sources, the rules engine and optional clients, shared by the server
+/// and the CLI subcommands.
+async fn build_state(config: &Config, pool: sqlx::PgPool) -> anyhow::Result<AppState> {
+    // Initialize data sync service (connects to external datasources)
+    let mut sync_service = DataSyncService::initialize(config, pool.clone()).await;
+    // A location picked on the Settings page overrides OWM_LATITUDE/OWM_LONGITUDE
+    if let Some(location) = settings_queries::get_location(&pool).await? {
+        tracing::info!(name = %location.name, "Using forecast location from settings");
+        sync_service.set_location(&location);
+    }
+
+    // Initialize OpenRouter client (optional, used for plant maintenance plans)
+    let openrouter = config
+        .openrouter
+        .as_ref()
+        .filter(|c| c.enabled && !c.api_key.is_empty())
+        .map(|c| {
+            tracing::info!(model = %c.model, "OpenRouter client configured for plant plans");
+            OpenRouterClient::new(c.clone())
+        });
+    if openrouter.is_none() {
+        tracing::info!(
+            "OpenRouter not configured — landscape maintenance plan generation unavailable"
+        );
+    }
+
+    // Custom rules: an invalid file or script fails startup rather than silently dropping rules
+    let mut custom_rules: Vec<Box<dyn Rule>> = Vec::new();
+    if let Some(path) = config.rules.custom_rules_path.as_deref() {
+        for rule in load_custom_rules(path)? {
+            custom_rules.push(Box::new(rule));
+        }
+    }
+    if let Some(dir) = config.rules.rules_dir.as_deref() {
+        for rule in load_script_rules(dir)? {
+            custom_rules.push(Box::new(rule));
+        }
+    }
+    let catalog = Catalog::load(&config.locale.locale, config.locale.dir.as_deref())?;
+    let rules_engine = RulesEngine::with_custom_rules(custom_rules)
+        .with_disabled(&config.rules.disabled)
+        .with_catalog(catalog);
+    rules_engine.set_user_disabled(&settings_queries::get_disabled_rules(&pool).await?);
+    rules_engine.set_posture(settings_queries::get_risk_posture(&pool).await?);
+    // Without label products rules still have the built-in catalog
+    if let Err(e) = product_labels::reload(&pool).await {
+        tracing::warn!(
+            "Failed to load label products, using the built-in catalog: {}",
+            e
+        );
+    }
+
+    Ok(AppState::new(pool, sync_service, openrouter, rules_engine)
+        .with_dashboard_layout(config.dashboard.panels.clone()))
 }
 
 /// Build CORS layer from config. Defaults to same-origin (the server's own address)
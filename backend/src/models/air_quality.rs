@@ -0,0 +1,50 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// OpenWeatherMap's 1-5 air quality index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum AirQualityLevel {
+    Good,
+    Fair,
+    Moderate,
+    Poor,
+    VeryPoor,
+}
+
+impl AirQualityLevel {
+    pub fn from_index(aqi: u8) -> Option<Self> {
+        match aqi {
+            1 => Some(AirQualityLevel::Good),
+            2 => Some(AirQualityLevel::Fair),
+            3 => Some(AirQualityLevel::Moderate),
+            4 => Some(AirQualityLevel::Poor),
+            5 => Some(AirQualityLevel::VeryPoor),
+            _ => None,
+        }
+    }
+
+    pub fn index(&self) -> u8 {
+        *self as u8 + 1
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            AirQualityLevel::Good => "Good",
+            AirQualityLevel::Fair => "Fair",
+            AirQualityLevel::Moderate => "Moderate",
+            AirQualityLevel::Poor => "Poor",
+            AirQualityLevel::VeryPoor => "Very Poor",
+        }
+    }
+}
+
+/// Current air quality at the lawn from the OWM Air Pollution API.
+/// Concentrations are µg/m³.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AirQuality {
+    pub level: AirQualityLevel,
+    pub pm2_5: Option<f64>,
+    pub pm10: Option<f64>,
+    pub o3: Option<f64>,
+    pub observed_at: DateTime<Utc>,
+}
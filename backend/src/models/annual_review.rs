@@ -0,0 +1,124 @@
+use super::ApplicationType;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+/// How well a season's application worked, as judged by the user at review time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ApplicationOutcome {
+    Effective,
+    Partial,
+    Ineffective,
+    #[default]
+    Unsure,
+}
+
+impl ApplicationOutcome {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApplicationOutcome::Effective => "Effective",
+            ApplicationOutcome::Partial => "Partial",
+            ApplicationOutcome::Ineffective => "Ineffective",
+            ApplicationOutcome::Unsure => "Unsure",
+        }
+    }
+
+    /// Partial or ineffective — worth adjusting next year.
+    pub fn fell_short(&self) -> bool {
+        matches!(
+            self,
+            ApplicationOutcome::Partial | ApplicationOutcome::Ineffective
+        )
+    }
+}
+
+impl std::fmt::Display for ApplicationOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// How much of a problem was observed over the season.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Serialize, Deserialize)]
+pub enum IssueLevel {
+    #[default]
+    None,
+    Minor,
+    Moderate,
+    Severe,
+}
+
+impl IssueLevel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IssueLevel::None => "None",
+            IssueLevel::Minor => "Minor",
+            IssueLevel::Moderate => "Moderate",
+            IssueLevel::Severe => "Severe",
+        }
+    }
+}
+
+impl std::fmt::Display for IssueLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// User's verdict on one of the season's major applications.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApplicationReview {
+    pub application_id: i64,
+    pub application_type: ApplicationType,
+    pub application_date: NaiveDate,
+    pub product_name: Option<String>,
+    #[serde(default)]
+    pub outcome: ApplicationOutcome,
+}
+
+/// End-of-season observations.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReviewObservations {
+    #[serde(default)]
+    pub disease: IssueLevel,
+    #[serde(default)]
+    pub weeds: IssueLevel,
+    #[serde(default)]
+    pub thin_areas: IssueLevel,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AdjustmentKind {
+    /// Shift a rule threshold (e.g. trigger pre-emergent at a lower soil temp)
+    Threshold,
+    /// Change the program template (add/split/rotate an application)
+    Program,
+}
+
+/// A suggested change for next season, accepted or declined by the user.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewAdjustment {
+    /// Stable key so re-generated suggestions can be matched to saved choices
+    pub key: String,
+    pub kind: AdjustmentKind,
+    pub title: String,
+    pub detail: String,
+    pub current_value: Option<f64>,
+    pub suggested_value: Option<f64>,
+    #[serde(default)]
+    pub accepted: bool,
+}
+
+/// A saved season-end review.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnnualReview {
+    pub id: Option<i64>,
+    pub lawn_profile_id: i64,
+    pub year: i32,
+    pub application_reviews: Vec<ApplicationReview>,
+    pub observations: ReviewObservations,
+    pub adjustments: Vec<ReviewAdjustment>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
@@ -17,6 +17,8 @@ pub enum ApplicationType {
     Sulfur,
     Wetting,
     Mowing,
+    Scouting,
+    Irrigation,
     Other,
     // Plant-scoped types (carry plant_id on Application)
     Pruning,
@@ -27,6 +29,30 @@ pub enum ApplicationType {
 }
 
 impl ApplicationType {
+    pub const ALL: [ApplicationType; 21] = [
+        ApplicationType::PreEmergent,
+        ApplicationType::PostEmergent,
+        ApplicationType::Fertilizer,
+        ApplicationType::Fungicide,
+        ApplicationType::Insecticide,
+        ApplicationType::GrubControl,
+        ApplicationType::Overseed,
+        ApplicationType::Aeration,
+        ApplicationType::Dethatching,
+        ApplicationType::Lime,
+        ApplicationType::Sulfur,
+        ApplicationType::Wetting,
+        ApplicationType::Mowing,
+        ApplicationType::Scouting,
+        ApplicationType::Irrigation,
+        ApplicationType::Other,
+        ApplicationType::Pruning,
+        ApplicationType::PlantFertilizer,
+        ApplicationType::Mulching,
+        ApplicationType::Deadheading,
+        ApplicationType::WinterProtection,
+    ];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             ApplicationType::PreEmergent => "Pre-Emergent",
@@ -42,6 +68,8 @@ impl ApplicationType {
             ApplicationType::Sulfur => "Sulfur",
             ApplicationType::Wetting => "Wetting Agent",
             ApplicationType::Mowing => "Mowing",
+            ApplicationType::Scouting => "Scouting",
+            ApplicationType::Irrigation => "Irrigation",
             ApplicationType::Other => "Other",
             ApplicationType::Pruning => "Pruning",
             ApplicationType::PlantFertilizer => "Plant Fertilizer",
@@ -64,6 +92,7 @@ impl ApplicationType {
             | ApplicationType::Fungicide
             | ApplicationType::Insecticide
             | ApplicationType::Wetting
+            | ApplicationType::Irrigation
             | ApplicationType::Other => ApplicationScope::Universal,
             ApplicationType::PreEmergent
             | ApplicationType::PostEmergent
@@ -73,7 +102,8 @@ impl ApplicationType {
             | ApplicationType::Dethatching
             | ApplicationType::Lime
             | ApplicationType::Sulfur
-            | ApplicationType::Mowing => ApplicationScope::TurfOnly,
+            | ApplicationType::Mowing
+            | ApplicationType::Scouting => ApplicationScope::TurfOnly,
         }
     }
 }
@@ -106,6 +136,8 @@ impl FromStr for ApplicationType {
             "sulfur" => Ok(ApplicationType::Sulfur),
             "wetting" | "wettingagent" => Ok(ApplicationType::Wetting),
             "mowing" | "mow" => Ok(ApplicationType::Mowing),
+            "scouting" | "scout" => Ok(ApplicationType::Scouting),
+            "irrigation" | "watering" => Ok(ApplicationType::Irrigation),
             "other" => Ok(ApplicationType::Other),
             "pruning" | "prune" => Ok(ApplicationType::Pruning),
             "plantfertilizer" => Ok(ApplicationType::PlantFertilizer),
@@ -129,6 +161,10 @@ pub struct WeatherSnapshot {
     pub ambient_temp_f: Option<f64>,
     pub humidity_percent: Option<f64>,
     pub soil_moisture: Option<f64>,
+    /// Rebuilt from station history for the application date (daily means)
+    /// rather than recorded at the time.
+    #[serde(default)]
+    pub reconstructed: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -149,6 +185,15 @@ pub struct Application {
     pub plant_id: Option<i64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub follow_up_date: Option<NaiveDate>,
+    /// Lawn area this was applied to; `None` means the whole lawn.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub area_id: Option<i64>,
+    /// Mower, spreader or sprayer used; counts toward its usage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub equipment_id: Option<i64>,
+    /// User-defined type this is logged as; `application_type` is its base.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_type_id: Option<i64>,
     pub created_at: chrono::DateTime<Utc>,
 }
 
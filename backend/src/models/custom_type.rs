@@ -0,0 +1,158 @@
+use super::{Application, ApplicationType};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// A user-defined application type. It's a named, colored variant of a
+/// built-in `base_type`: applications tagged with it store the base type, so
+/// everything that keys on the category (rules, nitrogen, reports) still works.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomApplicationType {
+    pub id: Option<i64>,
+    pub name: String,
+    /// `#rrggbb`.
+    pub color: String,
+    pub base_type: ApplicationType,
+    pub created_at: DateTime<Utc>,
+}
+
+impl CustomApplicationType {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("Type name can't be empty".into());
+        }
+        if ApplicationType::from_str(&self.name).is_ok() {
+            return Err(format!("{} is already a built-in type", self.name));
+        }
+        let hex = self.color.strip_prefix('#').unwrap_or_default();
+        if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(format!("Color {} must be #rrggbb", self.color));
+        }
+        Ok(())
+    }
+}
+
+/// One choice in the merged type list: a built-in type, or a custom type
+/// with the built-in it maps to.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TypeEntry {
+    pub label: String,
+    pub base_type: ApplicationType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_type_id: Option<i64>,
+    /// Custom types only; built-in colors live in the frontend.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+}
+
+/// The built-in application types merged with the user's custom ones.
+#[derive(Debug, Clone, Default)]
+pub struct TypeRegistry {
+    custom: Vec<CustomApplicationType>,
+}
+
+impl TypeRegistry {
+    pub fn new(custom: Vec<CustomApplicationType>) -> Self {
+        Self { custom }
+    }
+
+    pub fn get(&self, id: i64) -> Option<&CustomApplicationType> {
+        self.custom.iter().find(|t| t.id == Some(id))
+    }
+
+    /// Built-in types in enum order, then custom types by name.
+    pub fn entries(&self) -> Vec<TypeEntry> {
+        let mut custom: Vec<&CustomApplicationType> = self.custom.iter().collect();
+        custom.sort_by_key(|t| t.name.to_lowercase());
+        ApplicationType::ALL
+            .iter()
+            .map(|t| TypeEntry {
+                label: t.as_str().to_string(),
+                base_type: *t,
+                custom_type_id: None,
+                color: None,
+            })
+            .chain(custom.into_iter().map(|t| TypeEntry {
+                label: t.name.clone(),
+                base_type: t.base_type,
+                custom_type_id: t.id,
+                color: Some(t.color.clone()),
+            }))
+            .collect()
+    }
+
+    /// A type by name: built-in names and aliases first, then custom names
+    /// (case-insensitive). Returns the base type and the custom type's id.
+    pub fn resolve(&self, name: &str) -> Option<(ApplicationType, Option<i64>)> {
+        if let Ok(t) = ApplicationType::from_str(name) {
+            return Some((t, None));
+        }
+        let name = name.trim();
+        self.custom
+            .iter()
+            .find(|t| t.name.eq_ignore_ascii_case(name))
+            .map(|t| (t.base_type, t.id))
+    }
+
+    /// What to call an application: its custom type's name, else the built-in label.
+    pub fn label(&self, app: &Application) -> String {
+        app.custom_type_id
+            .and_then(|id| self.get(id))
+            .map_or_else(|| app.application_type.to_string(), |t| t.name.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn topdressing() -> CustomApplicationType {
+        CustomApplicationType {
+            id: Some(7),
+            name: "Topdressing".into(),
+            color: "#a16207".into(),
+            base_type: ApplicationType::Other,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn registry_merges_custom_types_after_built_ins() {
+        let registry = TypeRegistry::new(vec![topdressing()]);
+        let entries = registry.entries();
+        assert_eq!(entries.len(), ApplicationType::ALL.len() + 1);
+        let last = entries.last().unwrap();
+        assert_eq!(last.label, "Topdressing");
+        assert_eq!(last.custom_type_id, Some(7));
+
+        assert_eq!(
+            registry.resolve("topdressing"),
+            Some((ApplicationType::Other, Some(7)))
+        );
+        assert_eq!(
+            registry.resolve("Fertilizer"),
+            Some((ApplicationType::Fertilizer, None))
+        );
+        assert_eq!(registry.resolve("Compost tea"), None);
+
+        let mut app =
+            crate::testing::application(ApplicationType::Other, crate::testing::date(2026, 5, 1));
+        assert_eq!(registry.label(&app), "Other");
+        app.custom_type_id = Some(7);
+        assert_eq!(registry.label(&app), "Topdressing");
+    }
+
+    #[test]
+    fn custom_type_validation() {
+        assert!(topdressing().validate().is_ok());
+        let bad = |f: fn(&mut CustomApplicationType)| {
+            let mut t = topdressing();
+            f(&mut t);
+            t.validate().is_err()
+        };
+        assert!(bad(|t| t.name = " ".into()));
+        assert!(bad(|t| t.name = "mowing".into()));
+        assert!(bad(|t| t.color = "red".into()));
+        assert!(bad(|t| t.color = "#12345g".into()));
+    }
+}
@@ -0,0 +1,155 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::str::FromStr;
+
+/// A panel the Dashboard can show below its header and connection status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DashboardPanel {
+    Gauges,
+    /// Daily highs, lows and rain for the forecast days.
+    Forecast,
+    Germination,
+    Gdd,
+    StressIndex,
+    Drought,
+    NitrogenBudget,
+    SoilTempForecast,
+    /// Upcoming and open seasonal windows.
+    Windows,
+    Alerts,
+    RecentApplications,
+}
+
+impl DashboardPanel {
+    pub const ALL: [DashboardPanel; 11] = [
+        DashboardPanel::Gauges,
+        DashboardPanel::Forecast,
+        DashboardPanel::Germination,
+        DashboardPanel::Gdd,
+        DashboardPanel::StressIndex,
+        DashboardPanel::Drought,
+        DashboardPanel::NitrogenBudget,
+        DashboardPanel::SoilTempForecast,
+        DashboardPanel::Windows,
+        DashboardPanel::Alerts,
+        DashboardPanel::RecentApplications,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DashboardPanel::Gauges => "gauges",
+            DashboardPanel::Forecast => "forecast",
+            DashboardPanel::Germination => "germination",
+            DashboardPanel::Gdd => "gdd",
+            DashboardPanel::StressIndex => "stress_index",
+            DashboardPanel::Drought => "drought",
+            DashboardPanel::NitrogenBudget => "nitrogen_budget",
+            DashboardPanel::SoilTempForecast => "soil_temp_forecast",
+            DashboardPanel::Windows => "windows",
+            DashboardPanel::Alerts => "alerts",
+            DashboardPanel::RecentApplications => "recent_applications",
+        }
+    }
+}
+
+impl FromStr for DashboardPanel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim().to_lowercase().replace(['-', ' '], "_");
+        DashboardPanel::ALL
+            .into_iter()
+            .find(|p| p.as_str() == name)
+            .ok_or_else(|| format!("Unknown dashboard panel: {}", s.trim()))
+    }
+}
+
+impl std::fmt::Display for DashboardPanel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Which Dashboard panels appear and in what order. Panels left out are
+/// hidden; the default is every panel but the forecast, as the Dashboard
+/// was laid out before it could be changed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DashboardLayout {
+    pub panels: Vec<DashboardPanel>,
+}
+
+impl Default for DashboardLayout {
+    fn default() -> Self {
+        DashboardLayout {
+            panels: DashboardPanel::ALL
+                .into_iter()
+                .filter(|p| *p != DashboardPanel::Forecast)
+                .collect(),
+        }
+    }
+}
+
+impl DashboardLayout {
+    pub fn validate(&self) -> Result<(), String> {
+        let mut seen = HashSet::new();
+        for panel in &self.panels {
+            if !seen.insert(panel) {
+                return Err(format!("Dashboard panel {} is listed twice", panel));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Comma-separated panel names in display order, as in `DASHBOARD_PANELS`.
+impl FromStr for DashboardLayout {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let layout = DashboardLayout {
+            panels: s
+                .split(',')
+                .filter(|name| !name.trim().is_empty())
+                .map(str::parse)
+                .collect::<Result<_, _>>()?,
+        };
+        layout.validate()?;
+        Ok(layout)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_panel_lists_in_order() {
+        let layout: DashboardLayout = "alerts, Stress-Index,gauges,".parse().unwrap();
+        assert_eq!(
+            layout.panels,
+            vec![
+                DashboardPanel::Alerts,
+                DashboardPanel::StressIndex,
+                DashboardPanel::Gauges
+            ]
+        );
+        assert!("gauges,radar".parse::<DashboardLayout>().is_err());
+        assert!("alerts,gauges,alerts".parse::<DashboardLayout>().is_err());
+
+        let json = serde_json::to_string(&layout).unwrap();
+        assert_eq!(json, r#"{"panels":["alerts","stress_index","gauges"]}"#);
+        for panel in DashboardPanel::ALL {
+            assert_eq!(panel.as_str().parse::<DashboardPanel>(), Ok(panel));
+        }
+    }
+
+    #[test]
+    fn default_keeps_the_original_arrangement() {
+        let layout = DashboardLayout::default();
+        assert!(layout.validate().is_ok());
+        assert_eq!(layout.panels.first(), Some(&DashboardPanel::Gauges));
+        assert!(!layout.panels.contains(&DashboardPanel::Forecast));
+        assert_eq!(layout.panels.len(), DashboardPanel::ALL.len() - 1);
+    }
+}
@@ -0,0 +1,70 @@
+//! Canonical wire formats for client-supplied dates: `YYYY-MM-DD` for calendar
+//! days and RFC 3339 for instants. The database stores native DATE/TIMESTAMPTZ,
+//! so these parsers are the only place strings become dates. They reject
+//! anything else with an error naming the field, and never fall back to "now".
+
+use crate::error::{Result, TurfOpsError};
+use chrono::{DateTime, NaiveDate, Utc};
+
+pub const DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// Parse a `YYYY-MM-DD` date. Unpadded forms like `2026-4-1` are rejected so
+/// stored and echoed dates always sort as strings too.
+pub fn parse_date(field: &str, raw: &str) -> Result<NaiveDate> {
+    let raw = raw.trim();
+    NaiveDate::parse_from_str(raw, DATE_FORMAT)
+        .ok()
+        .filter(|_| raw.len() == 10)
+        .ok_or_else(|| {
+            TurfOpsError::InvalidData(format!("Invalid {}: {}. Expected YYYY-MM-DD", field, raw))
+        })
+}
+
+/// [`parse_date`] for an optional field; a missing or blank value is `None`.
+pub fn parse_optional_date(field: &str, raw: Option<&str>) -> Result<Option<NaiveDate>> {
+    raw.filter(|s| !s.trim().is_empty())
+        .map(|s| parse_date(field, s))
+        .transpose()
+}
+
+/// Parse an RFC 3339 timestamp (any offset) into UTC.
+pub fn parse_timestamp(field: &str, raw: &str) -> Result<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(raw.trim())
+        .map(|t| t.with_timezone(&Utc))
+        .map_err(|_| {
+            TurfOpsError::InvalidData(format!(
+                "Invalid {}: {}. Expected an RFC 3339 timestamp",
+                field, raw
+            ))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dates_must_be_canonical() {
+        assert_eq!(
+            parse_date("application_date", " 2026-04-01 ").unwrap(),
+            NaiveDate::from_ymd_opt(2026, 4, 1).unwrap()
+        );
+        for bad in ["2026-4-1", "04/01/2026", "2026-02-30", ""] {
+            let err = parse_date("application_date", bad).unwrap_err().to_string();
+            assert!(err.contains("application_date"), "{}", err);
+        }
+
+        assert_eq!(
+            parse_optional_date("planting_date", Some("  ")).unwrap(),
+            None
+        );
+        assert!(parse_optional_date("planting_date", Some("soon")).is_err());
+    }
+
+    #[test]
+    fn timestamps_normalize_to_utc() {
+        let t = parse_timestamp("observed_at", "2026-07-10T08:30:00-04:00").unwrap();
+        assert_eq!(t.to_rfc3339(), "2026-07-10T12:30:00+00:00");
+        assert!(parse_timestamp("observed_at", "2026-07-10 08:30").is_err());
+    }
+}
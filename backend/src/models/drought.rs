@@ -0,0 +1,68 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// U.S. Drought Monitor intensity category, `None` when not even abnormally dry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum DroughtCategory {
+    None,
+    D0,
+    D1,
+    D2,
+    D3,
+    D4,
+}
+
+impl DroughtCategory {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DroughtCategory::None => "None",
+            DroughtCategory::D0 => "D0",
+            DroughtCategory::D1 => "D1",
+            DroughtCategory::D2 => "D2",
+            DroughtCategory::D3 => "D3",
+            DroughtCategory::D4 => "D4",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            DroughtCategory::None => "No Drought",
+            DroughtCategory::D0 => "Abnormally Dry",
+            DroughtCategory::D1 => "Moderate Drought",
+            DroughtCategory::D2 => "Severe Drought",
+            DroughtCategory::D3 => "Extreme Drought",
+            DroughtCategory::D4 => "Exceptional Drought",
+        }
+    }
+}
+
+impl std::fmt::Display for DroughtCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.as_str(), self.label())
+    }
+}
+
+/// Percent of the county in each category or worse (USDM "cumulative" stats).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DroughtCoverage {
+    pub d0: f64,
+    pub d1: f64,
+    pub d2: f64,
+    pub d3: f64,
+    pub d4: f64,
+}
+
+/// The latest weekly U.S. Drought Monitor map for the lawn's county.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DroughtStatus {
+    /// Category covering most of the county.
+    pub category: DroughtCategory,
+    /// Worst category present anywhere in the county.
+    pub worst_category: DroughtCategory,
+    pub coverage: DroughtCoverage,
+    /// Map release (valid-from) date; maps come out Thursdays for the prior Tuesday.
+    pub map_date: NaiveDate,
+    pub county_fips: String,
+    /// e.g. "Chester County, PA".
+    pub county: String,
+}
@@ -1,11 +1,13 @@
 use super::forecast::WeatherForecast;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum DataSource {
     SoilData,
     HomeAssistant,
+    Ecowitt,
+    Tempest,
     OpenWeatherMap,
     OpenRouter,
     Cached,
@@ -17,6 +19,7 @@ pub enum DataSource {
     MissouriExtension,
     Rotation,
     SoilTestData,
+    DroughtMonitor,
 }
 
 impl DataSource {
@@ -24,6 +27,8 @@ impl DataSource {
         match self {
             DataSource::SoilData => "NOAA USCRN",
             DataSource::HomeAssistant => "Patio Sensor",
+            DataSource::Ecowitt => "Ecowitt Station",
+            DataSource::Tempest => "Tempest Station",
             DataSource::OpenWeatherMap => "OpenWeatherMap",
             DataSource::OpenRouter => "OpenRouter",
             DataSource::Cached => "Cached",
@@ -35,6 +40,7 @@ impl DataSource {
             DataSource::MissouriExtension => "Missouri Extension",
             DataSource::Rotation => "Rotation",
             DataSource::SoilTestData => "Soil Test",
+            DataSource::DroughtMonitor => "U.S. Drought Monitor",
         }
     }
 }
@@ -62,6 +68,9 @@ pub struct EnvironmentalReading {
     pub ambient_temp_f: Option<f64>,
     pub humidity_percent: Option<f64>,
     pub precipitation_mm: Option<f64>,
+    /// A value was dropped as an implausible spike (see `datasources::quality`).
+    #[serde(default)]
+    pub suspect: bool,
 }
 
 impl EnvironmentalReading {
@@ -82,6 +91,7 @@ impl EnvironmentalReading {
             ambient_temp_f: None,
             humidity_percent: None,
             precipitation_mm: None,
+            suspect: false,
         }
     }
 
@@ -106,6 +116,11 @@ pub struct EnvironmentalSummary {
     pub humidity_7day_avg: Option<f64>,
     pub precipitation_7day_total_mm: Option<f64>,
     pub soil_temp_trend: Trend,
+    /// Trend of the primary soil moisture depth.
+    #[serde(default)]
+    pub soil_moisture_trend: Trend,
+    #[serde(default)]
+    pub ambient_temp_trend: Trend,
     pub last_updated: Option<DateTime<Utc>>,
     /// Weather forecast data (5-day/3-hour) from OpenWeatherMap
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -113,6 +128,12 @@ pub struct EnvironmentalSummary {
     /// Year-to-date Growing Degree Days (base 50°F)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub gdd_base50_ytd: Option<f64>,
+    /// Last spring and first fall frost in each prior year of station lows
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub frost_years: Vec<FrostYear>,
+    /// 7-day soil temp against the same week in prior years of the archive
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub soil_temp_anomaly: Option<SoilTempAnomaly>,
     /// Predicted soil temperatures from regression model
     #[serde(skip_serializing_if = "Option::is_none")]
     pub soil_temp_predictions: Option<Vec<super::soil_temp_prediction::SoilTempPrediction>>,
@@ -120,6 +141,192 @@ pub struct EnvironmentalSummary {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub predicted_threshold_crossings:
         Option<Vec<super::soil_temp_prediction::ThresholdPrediction>>,
+    /// Latest turf stress index and how long it has stayed high
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stress_index: Option<super::stress_index::StressSnapshot>,
+    /// County status from the latest U.S. Drought Monitor map
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub drought: Option<super::drought::DroughtStatus>,
+    /// Current air quality, when OpenWeatherMap air quality is enabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub air_quality: Option<super::air_quality::AirQuality>,
+    /// Dew point, wet-bulb, VPD and leaf wetness derived from temp + humidity
+    #[serde(default)]
+    pub derived: DerivedMetrics,
+    /// What the quality pass did to the 7-day readings
+    #[serde(default)]
+    pub data_quality: DataQuality,
+    /// Share of expected hourly samples behind each 7-day aggregate
+    #[serde(default)]
+    pub coverage: AggregateCoverage,
+    /// When each current value was observed, for the staleness watchdog
+    #[serde(default)]
+    pub freshness: Freshness,
+}
+
+/// Observation time of each current value and how old it may get before it
+/// counts as stale. A station outage otherwise leaves the last soil reading
+/// looking current. Times are `None` when unknown (no source, older caches).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct Freshness {
+    /// `STALE_DATA_HOURS`; `None` disables the watchdog.
+    pub max_age_hours: Option<u32>,
+    pub soil_temp: Option<DateTime<Utc>>,
+    pub soil_moisture: Option<DateTime<Utc>>,
+    pub ambient_temp: Option<DateTime<Utc>>,
+    pub humidity: Option<DateTime<Utc>>,
+    pub precipitation: Option<DateTime<Utc>>,
+}
+
+impl Freshness {
+    fn is_stale(&self, observed: Option<DateTime<Utc>>, now: DateTime<Utc>) -> bool {
+        match (self.max_age_hours, observed) {
+            (Some(hours), Some(at)) => now - at > chrono::Duration::hours(hours.into()),
+            _ => false,
+        }
+    }
+
+    /// Names of the metrics older than the limit at `now`.
+    pub fn stale_metrics(&self, now: DateTime<Utc>) -> Vec<&'static str> {
+        [
+            ("soil_temp", self.soil_temp),
+            ("soil_moisture", self.soil_moisture),
+            ("ambient_temp", self.ambient_temp),
+            ("humidity", self.humidity),
+            ("precipitation", self.precipitation),
+        ]
+        .into_iter()
+        .filter(|(_, at)| self.is_stale(*at, now))
+        .map(|(name, _)| name)
+        .collect()
+    }
+}
+
+impl EnvironmentalSummary {
+    /// The summary with current values past `STALE_DATA_HOURS` removed, so rules
+    /// treat them as missing. 7-day aggregates are left alone; coverage already
+    /// accounts for an outage inside the window.
+    pub fn without_stale(&self, now: DateTime<Utc>) -> std::borrow::Cow<'_, Self> {
+        let f = &self.freshness;
+        let stale = |at| f.is_stale(at, now);
+        let any = [
+            f.soil_temp,
+            f.soil_moisture,
+            f.ambient_temp,
+            f.humidity,
+            f.precipitation,
+        ]
+        .into_iter()
+        .any(stale);
+        let Some(current) = self.current.as_ref().filter(|_| any) else {
+            return std::borrow::Cow::Borrowed(self);
+        };
+
+        let mut current = current.clone();
+        if stale(f.soil_temp) {
+            current.soil_temp_5_f = None;
+            current.soil_temp_10_f = None;
+            current.soil_temp_20_f = None;
+            current.soil_temp_50_f = None;
+            current.soil_temp_100_f = None;
+        }
+        if stale(f.soil_moisture) {
+            current.soil_moisture_5 = None;
+            current.soil_moisture_10 = None;
+            current.soil_moisture_20 = None;
+            current.soil_moisture_50 = None;
+            current.soil_moisture_100 = None;
+        }
+        if stale(f.ambient_temp) {
+            current.ambient_temp_f = None;
+        }
+        if stale(f.humidity) {
+            current.humidity_percent = None;
+        }
+        if stale(f.precipitation) {
+            current.precipitation_mm = None;
+        }
+        std::borrow::Cow::Owned(Self {
+            current: Some(current),
+            ..self.clone()
+        })
+    }
+}
+
+/// Share (0-1) of the expected hourly samples present in the 7-day window
+/// behind each aggregate. `None` when unknown (e.g. older cached summaries).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct AggregateCoverage {
+    pub soil_temp: Option<f64>,
+    pub ambient_temp: Option<f64>,
+    pub humidity: Option<f64>,
+    pub precipitation: Option<f64>,
+}
+
+/// One year's frost dates at the station: the last daily low at or below 32°F
+/// before July and the first one from July on. `None` when that half of the
+/// year had no frost in the archive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FrostYear {
+    pub year: i32,
+    pub last_spring: Option<NaiveDate>,
+    pub first_fall: Option<NaiveDate>,
+}
+
+/// The 7-day soil temp average against the same seven calendar days in prior
+/// years of the station archive.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SoilTempAnomaly {
+    /// Mean 10cm soil temp over these calendar days in prior years (°F).
+    pub normal_f: f64,
+    /// 7-day average minus the normal; positive when running warm.
+    pub anomaly_f: f64,
+    /// Prior years behind the normal.
+    pub years: usize,
+    /// Days the spring windows open ahead of the calendar (negative when
+    /// behind), 0 while the anomaly is within normal variation.
+    pub lead_days: i64,
+}
+
+/// Values rejected, flagged or filled in while cleaning the 7-day readings.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DataQuality {
+    /// Physically impossible values (sentinels, humidity over 100%, ...).
+    pub rejected: usize,
+    /// Spikes left out of the averages and trends.
+    pub suspect: usize,
+    /// Short gaps filled by linear interpolation.
+    pub interpolated: usize,
+}
+
+/// Metrics computed from ambient temperature and humidity (see `logic::calculations`).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DerivedMetrics {
+    pub dew_point_f: Option<f64>,
+    /// Air temperature minus dew point; under ~3°F dew forms on leaves.
+    pub dew_point_spread_f: Option<f64>,
+    pub wet_bulb_f: Option<f64>,
+    /// Vapor pressure deficit (kPa).
+    pub vpd_kpa: Option<f64>,
+    /// Forecast hours in the next 24 with dew point spread below 3°F.
+    pub leaf_wetness_hours_24h: Option<f64>,
+    /// Likely leaf wetness for each of the next three nights, from the hourly forecast.
+    #[serde(default)]
+    pub overnight_wetness: Vec<NightWetness>,
+}
+
+/// One night (6 pm to 8 am local) of the overnight leaf wetness estimate.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NightWetness {
+    /// Date the night starts on.
+    pub evening: NaiveDate,
+    /// Hours with humidity over 90% or dew point spread under 2°F.
+    pub wet_hours: f64,
+    /// Hours of the night the forecast covers; less than 14 for the night
+    /// under way or at the end of the forecast.
+    pub forecast_hours: f64,
+    pub max_humidity_percent: f64,
+    pub min_dew_point_spread_f: f64,
 }
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
@@ -152,38 +359,42 @@ impl std::fmt::Display for Trend {
     }
 }
 
-pub fn celsius_to_fahrenheit(c: f64) -> f64 {
-    c * 9.0 / 5.0 + 32.0
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
-    fn celsius_to_fahrenheit_known_values() {
-        // Freezing point of water
-        assert!((celsius_to_fahrenheit(0.0) - 32.0).abs() < 0.001);
-        // Boiling point of water
-        assert!((celsius_to_fahrenheit(100.0) - 212.0).abs() < 0.001);
-        // Body temperature
-        assert!((celsius_to_fahrenheit(37.0) - 98.6).abs() < 0.1);
-        // -40 is same in both scales
-        assert!((celsius_to_fahrenheit(-40.0) - (-40.0)).abs() < 0.001);
-    }
-
-    #[test]
-    fn agronomic_temperatures() {
-        // Pre-emergent window: 50-60°F = ~10-15.5°C
-        assert!((celsius_to_fahrenheit(10.0) - 50.0).abs() < 0.1);
-        assert!((celsius_to_fahrenheit(15.5) - 59.9).abs() < 0.1);
+    fn stale_current_values_are_dropped_for_rules() {
+        let now = crate::testing::at(2026, 5, 1);
+        let mut current = EnvironmentalReading::new(DataSource::Cached);
+        current.soil_temp_10_f = Some(55.0);
+        current.ambient_temp_f = Some(70.0);
+        let summary = EnvironmentalSummary {
+            current: Some(current),
+            soil_temp_7day_avg_f: Some(54.0),
+            freshness: Freshness {
+                max_age_hours: Some(6),
+                soil_temp: Some(now - chrono::Duration::hours(9)),
+                ambient_temp: Some(now - chrono::Duration::minutes(5)),
+                ..Freshness::default()
+            },
+            ..EnvironmentalSummary::default()
+        };
 
-        // Grub control window: 60-75°F = ~15.5-24°C
-        assert!((celsius_to_fahrenheit(15.5) - 59.9).abs() < 0.1);
-        assert!((celsius_to_fahrenheit(24.0) - 75.2).abs() < 0.1);
+        assert_eq!(summary.freshness.stale_metrics(now), vec!["soil_temp"]);
+        let fresh = summary.without_stale(now);
+        let current = fresh.current.as_ref().unwrap();
+        assert_eq!(current.soil_temp_10_f, None);
+        assert_eq!(current.ambient_temp_f, Some(70.0));
+        assert_eq!(fresh.soil_temp_7day_avg_f, Some(54.0));
 
-        // Heat stress threshold: 85°F = ~29.4°C
-        assert!((celsius_to_fahrenheit(29.4) - 84.9).abs() < 0.2);
+        // Disabled watchdog keeps everything
+        let mut off = summary.clone();
+        off.freshness.max_age_hours = None;
+        assert!(matches!(
+            off.without_stale(now),
+            std::borrow::Cow::Borrowed(_)
+        ));
     }
 
     #[test]
@@ -225,4 +436,26 @@ mod tests {
         assert!(Trend::Stable.as_str().contains("Stable"));
         assert!(Trend::Unknown.as_str().contains("Unknown"));
     }
+
+    #[test]
+    fn summary_payload_snapshot() {
+        use crate::testing::{at, date, EnvBuilder, ForecastBuilder};
+
+        let forecast = ForecastBuilder::starting(date(2026, 5, 10))
+            .day(78.0, 58.0, 65.0, 0.0)
+            .day(72.0, 60.0, 85.0, 12.0)
+            .build();
+        let env = EnvBuilder::new(at(2026, 5, 10))
+            .soil_temp(61.5, 59.8)
+            .soil_moisture(0.27)
+            .air(74.0, 55.0)
+            .weekly(68.0, 62.0, 8.4)
+            .soil_trend(Trend::Rising)
+            .gdd(310.0)
+            .forecast(forecast)
+            .build();
+
+        // The Dashboard and Environmental pages render this payload directly
+        insta::assert_json_snapshot!(env);
+    }
 }
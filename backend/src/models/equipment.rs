@@ -0,0 +1,247 @@
+use super::{Application, ApplicationType};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Engine hours a mowing adds when the mower doesn't set its own.
+pub const DEFAULT_MOWER_HOURS_PER_USE: f64 = 1.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EquipmentKind {
+    Mower,
+    Spreader,
+    Sprayer,
+    Other,
+}
+
+impl EquipmentKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EquipmentKind::Mower => "Mower",
+            EquipmentKind::Spreader => "Spreader",
+            EquipmentKind::Sprayer => "Sprayer",
+            EquipmentKind::Other => "Other",
+        }
+    }
+
+    /// Application types this kind of equipment is normally used for. An
+    /// untagged application of one of these counts toward the profile's only
+    /// piece of this kind.
+    pub fn usage_types(&self) -> &'static [ApplicationType] {
+        match self {
+            EquipmentKind::Mower => &[ApplicationType::Mowing],
+            EquipmentKind::Spreader => &[
+                ApplicationType::Fertilizer,
+                ApplicationType::PreEmergent,
+                ApplicationType::GrubControl,
+                ApplicationType::Lime,
+                ApplicationType::Sulfur,
+                ApplicationType::Overseed,
+            ],
+            EquipmentKind::Sprayer => &[
+                ApplicationType::PostEmergent,
+                ApplicationType::Fungicide,
+                ApplicationType::Insecticide,
+                ApplicationType::Wetting,
+            ],
+            EquipmentKind::Other => &[],
+        }
+    }
+}
+
+impl FromStr for EquipmentKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "mower" => Ok(EquipmentKind::Mower),
+            "spreader" => Ok(EquipmentKind::Spreader),
+            "sprayer" => Ok(EquipmentKind::Sprayer),
+            "other" => Ok(EquipmentKind::Other),
+            _ => Err(format!("Unknown equipment kind: {}", s)),
+        }
+    }
+}
+
+impl std::fmt::Display for EquipmentKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EquipmentTask {
+    BladeSharpening,
+    OilChange,
+    AirFilter,
+    SparkPlug,
+    Winterize,
+    Calibration,
+    Cleaning,
+    Other,
+}
+
+impl EquipmentTask {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EquipmentTask::BladeSharpening => "Blade Sharpening",
+            EquipmentTask::OilChange => "Oil Change",
+            EquipmentTask::AirFilter => "Air Filter",
+            EquipmentTask::SparkPlug => "Spark Plug",
+            EquipmentTask::Winterize => "Winterize",
+            EquipmentTask::Calibration => "Calibration",
+            EquipmentTask::Cleaning => "Cleaning",
+            EquipmentTask::Other => "Other",
+        }
+    }
+}
+
+impl FromStr for EquipmentTask {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace([' ', '-'], "").as_str() {
+            "bladesharpening" | "sharpening" | "blades" => Ok(EquipmentTask::BladeSharpening),
+            "oilchange" | "oil" => Ok(EquipmentTask::OilChange),
+            "airfilter" => Ok(EquipmentTask::AirFilter),
+            "sparkplug" => Ok(EquipmentTask::SparkPlug),
+            "winterize" | "winterizing" => Ok(EquipmentTask::Winterize),
+            "calibration" | "calibrate" => Ok(EquipmentTask::Calibration),
+            "cleaning" | "clean" => Ok(EquipmentTask::Cleaning),
+            "other" => Ok(EquipmentTask::Other),
+            _ => Err(format!("Unknown maintenance task: {}", s)),
+        }
+    }
+}
+
+impl std::fmt::Display for EquipmentTask {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// A mower, spreader or sprayer. Usage comes from the applications it was
+/// used for; `starting_hours` is the hour meter when it was added.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Equipment {
+    pub id: Option<i64>,
+    pub lawn_profile_id: i64,
+    pub name: String,
+    pub kind: EquipmentKind,
+    /// Engine hours one use adds (a typical mow); mowers default to
+    /// [`DEFAULT_MOWER_HOURS_PER_USE`].
+    pub hours_per_use: Option<f64>,
+    pub starting_hours: f64,
+    pub notes: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Equipment {
+    /// Whether `app` was a use of this piece: tagged to it, or untagged with
+    /// one of its kind's types when it's the profile's only one of that kind.
+    pub fn used_for(&self, app: &Application, sole_of_kind: bool) -> bool {
+        match app.equipment_id {
+            Some(id) => self.id == Some(id),
+            None => sole_of_kind && self.kind.usage_types().contains(&app.application_type),
+        }
+    }
+
+    /// Hours each use adds, if the piece runs on an hour meter.
+    pub fn use_hours(&self) -> Option<f64> {
+        match self.kind {
+            EquipmentKind::Mower => Some(self.hours_per_use.unwrap_or(DEFAULT_MOWER_HOURS_PER_USE)),
+            _ => self.hours_per_use,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MaintenanceEvent {
+    pub id: Option<i64>,
+    pub equipment_id: i64,
+    pub task: EquipmentTask,
+    pub performed_on: NaiveDate,
+    pub notes: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A piece of equipment with its usage to date and maintenance history.
+#[derive(Debug, Clone, Serialize)]
+pub struct EquipmentStatus {
+    #[serde(flatten)]
+    pub equipment: Equipment,
+    /// Logged uses since the piece was added.
+    pub uses: usize,
+    /// Hour meter estimate: starting hours plus uses x hours per use.
+    pub hours: Option<f64>,
+    /// Newest first.
+    pub maintenance: Vec<MaintenanceEvent>,
+    #[serde(skip)]
+    pub use_dates: Vec<NaiveDate>,
+}
+
+impl EquipmentStatus {
+    /// Most recent time `task` was done, if ever.
+    pub fn last_done(&self, task: EquipmentTask) -> Option<NaiveDate> {
+        self.maintenance
+            .iter()
+            .filter(|m| m.task == task)
+            .map(|m| m.performed_on)
+            .max()
+    }
+
+    /// Uses logged after `since` (exclusive).
+    pub fn uses_after(&self, since: NaiveDate) -> usize {
+        self.use_dates.iter().filter(|d| **d > since).count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{application, at, date};
+
+    fn equipment(id: i64, kind: EquipmentKind) -> Equipment {
+        Equipment {
+            id: Some(id),
+            lawn_profile_id: 1,
+            name: "Honda".into(),
+            kind,
+            hours_per_use: None,
+            starting_hours: 0.0,
+            notes: None,
+            created_at: at(2025, 1, 1),
+        }
+    }
+
+    #[test]
+    fn tagged_uses_count_and_untagged_only_for_sole_piece() {
+        let mower = equipment(1, EquipmentKind::Mower);
+        let mut tagged = application(ApplicationType::Fertilizer, date(2025, 5, 1));
+        tagged.equipment_id = Some(1);
+        let untagged = application(ApplicationType::Mowing, date(2025, 5, 1));
+        let mut other = application(ApplicationType::Mowing, date(2025, 5, 1));
+        other.equipment_id = Some(2);
+
+        assert!(mower.used_for(&tagged, false));
+        assert!(mower.used_for(&untagged, true));
+        assert!(!mower.used_for(&untagged, false));
+        assert!(!mower.used_for(&other, true));
+        // Untagged types outside the kind don't count
+        let fert = application(ApplicationType::Fertilizer, date(2025, 5, 1));
+        assert!(!mower.used_for(&fert, true));
+    }
+
+    #[test]
+    fn use_hours_defaults_for_mowers_only() {
+        assert_eq!(
+            equipment(1, EquipmentKind::Mower).use_hours(),
+            Some(DEFAULT_MOWER_HOURS_PER_USE)
+        );
+        assert_eq!(equipment(1, EquipmentKind::Sprayer).use_hours(), None);
+        assert_eq!(
+            EquipmentTask::from_str("blade sharpening"),
+            Ok(EquipmentTask::BladeSharpening)
+        );
+    }
+}
@@ -0,0 +1,160 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// What kind of activity an audit event records.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EventKind {
+    DataRefresh,
+    RuleEvaluation,
+    ApplicationAdded,
+    ApplicationUpdated,
+    ApplicationDeleted,
+    DataSourceError,
+    /// An API request failed with a server error.
+    RequestFailed,
+    /// The retention task pruned old rows.
+    Maintenance,
+    /// The recommendation digest email went out (or failed to).
+    DigestSent,
+    /// A seasonal application window closed with nothing logged in it.
+    MissedWindow,
+}
+
+impl EventKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EventKind::DataRefresh => "Data Refresh",
+            EventKind::RuleEvaluation => "Rule Evaluation",
+            EventKind::ApplicationAdded => "Application Added",
+            EventKind::ApplicationUpdated => "Application Updated",
+            EventKind::ApplicationDeleted => "Application Deleted",
+            EventKind::DataSourceError => "Data Source Error",
+            EventKind::RequestFailed => "Request Failed",
+            EventKind::Maintenance => "Maintenance",
+            EventKind::DigestSent => "Digest Sent",
+            EventKind::MissedWindow => "Missed Window",
+        }
+    }
+}
+
+impl FromStr for EventKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace([' ', '_', '-'], "").as_str() {
+            "datarefresh" => Ok(EventKind::DataRefresh),
+            "ruleevaluation" => Ok(EventKind::RuleEvaluation),
+            "applicationadded" => Ok(EventKind::ApplicationAdded),
+            "applicationupdated" => Ok(EventKind::ApplicationUpdated),
+            "applicationdeleted" => Ok(EventKind::ApplicationDeleted),
+            "datasourceerror" => Ok(EventKind::DataSourceError),
+            "requestfailed" => Ok(EventKind::RequestFailed),
+            "maintenance" => Ok(EventKind::Maintenance),
+            "digestsent" => Ok(EventKind::DigestSent),
+            "missedwindow" => Ok(EventKind::MissedWindow),
+            _ => Err(format!("Unknown event kind: {}", s)),
+        }
+    }
+}
+
+impl std::fmt::Display for EventKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum EventLevel {
+    Info,
+    Warning,
+    Error,
+}
+
+impl EventLevel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EventLevel::Info => "Info",
+            EventLevel::Warning => "Warning",
+            EventLevel::Error => "Error",
+        }
+    }
+}
+
+impl FromStr for EventLevel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "info" => Ok(EventLevel::Info),
+            "warning" | "warn" => Ok(EventLevel::Warning),
+            "error" => Ok(EventLevel::Error),
+            _ => Err(format!("Unknown event level: {}", s)),
+        }
+    }
+}
+
+impl std::fmt::Display for EventLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// A persisted activity log entry, used to reconstruct why a recommendation
+/// did or didn't appear (what data was fetched, what failed, what changed).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Event {
+    pub id: Option<i64>,
+    pub occurred_at: DateTime<Utc>,
+    pub kind: EventKind,
+    pub level: EventLevel,
+    pub message: String,
+    pub details: Option<String>,
+}
+
+impl Event {
+    pub fn new(kind: EventKind, level: EventLevel, message: impl Into<String>) -> Self {
+        Self {
+            id: None,
+            occurred_at: Utc::now(),
+            kind,
+            level,
+            message: message.into(),
+            details: None,
+        }
+    }
+
+    pub fn with_details(mut self, details: impl Into<String>) -> Self {
+        self.details = Some(details.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn event_kind_from_str_accepts_variants() {
+        assert_eq!(
+            EventKind::from_str("DataRefresh").unwrap(),
+            EventKind::DataRefresh
+        );
+        assert_eq!(
+            EventKind::from_str("data_source_error").unwrap(),
+            EventKind::DataSourceError
+        );
+        assert_eq!(
+            EventKind::from_str("Application Added").unwrap(),
+            EventKind::ApplicationAdded
+        );
+        assert!(EventKind::from_str("bogus").is_err());
+    }
+
+    #[test]
+    fn event_level_ordering() {
+        assert!(EventLevel::Info < EventLevel::Warning);
+        assert!(EventLevel::Warning < EventLevel::Error);
+        assert_eq!(EventLevel::from_str("warn").unwrap(), EventLevel::Warning);
+    }
+}
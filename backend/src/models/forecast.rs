@@ -1,28 +1,33 @@
+use crate::clock;
 use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 
-/// Weather forecast data from OpenWeatherMap 5-day/3-hour API
+/// Weather forecast data from OpenWeatherMap: the 5-day/3-hour API, or One
+/// Call 3.0 when `OWM_ONE_CALL` is on.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WeatherForecast {
     pub fetched_at: DateTime<Utc>,
     pub location: ForecastLocation,
-    pub hourly: Vec<ForecastPoint>,        // 3-hour intervals
-    pub daily_summary: Vec<DailyForecast>, // Aggregated by day
+    pub hourly: Vec<ForecastPoint>, // 3-hour intervals, or hourly for 48h with One Call
+    pub daily_summary: Vec<DailyForecast>, // Aggregated by day, or One Call's 8 daily values
+    /// Precipitation for the next hour, minute by minute (One Call only).
+    #[serde(default)]
+    pub minutely: Vec<MinutelyPrecipitation>,
 }
 
 impl WeatherForecast {
-    /// Get forecast points for the next N hours
+    /// Get forecast points for the next N hours of the evaluation clock
     pub fn next_hours(&self, hours: u32) -> Vec<&ForecastPoint> {
-        let cutoff = Utc::now() + chrono::Duration::hours(hours as i64);
+        let cutoff = clock::now() + chrono::Duration::hours(hours as i64);
         self.hourly
             .iter()
             .filter(|p| p.timestamp <= cutoff)
             .collect()
     }
 
-    /// Get forecast points for the next N days
+    /// Get forecast points for the next N days of the evaluation clock
     pub fn next_days(&self, days: u32) -> Vec<&DailyForecast> {
-        let today = Utc::now().date_naive();
+        let today = clock::now().date_naive();
         let cutoff = today + chrono::Duration::days(days as i64);
         self.daily_summary
             .iter()
@@ -53,6 +58,14 @@ impl WeatherForecast {
         }
     }
 
+    /// Forecast points on `date`, grouped by UTC date like `daily_summary`.
+    pub fn points_on(&self, date: NaiveDate) -> Vec<&ForecastPoint> {
+        self.hourly
+            .iter()
+            .filter(|p| p.timestamp.date_naive() == date)
+            .collect()
+    }
+
     /// Find the maximum temperature in the forecast period
     pub fn max_temp_next_days(&self, days: u32) -> Option<f64> {
         self.next_days(days)
@@ -61,6 +74,14 @@ impl WeatherForecast {
             .max_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
     }
 
+    /// Count consecutive days, starting today, whose overnight low stays at or above `threshold_f`
+    pub fn consecutive_warm_nights(&self, threshold_f: f64) -> u32 {
+        self.daily_summary
+            .iter()
+            .take_while(|d| d.low_temp_f >= threshold_f)
+            .count() as u32
+    }
+
     /// Count consecutive days with high humidity
     pub fn consecutive_high_humidity_days(&self, threshold: f64) -> u32 {
         let mut count = 0;
@@ -75,6 +96,13 @@ impl WeatherForecast {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MinutelyPrecipitation {
+    pub timestamp: DateTime<Utc>,
+    /// Precipitation rate, mm/h.
+    pub precipitation_mm: f64,
+}
+
 #[derive(Debug, Clone)]
 pub struct RainForecast {
     pub expected_mm: f64,
@@ -96,7 +124,9 @@ pub struct ForecastPoint {
     pub temp_f: f64,
     pub feels_like_f: f64,
     pub humidity_percent: f64,
-    pub precipitation_mm: f64,   // rain + snow
+    pub precipitation_mm: f64, // rain + snow
+    #[serde(default)]
+    pub snow_mm: f64, // snow portion of precipitation_mm
     pub precipitation_prob: f64, // 0.0-1.0
     pub wind_speed_mph: f64,
     pub wind_gust_mph: Option<f64>,
@@ -112,6 +142,8 @@ pub struct DailyForecast {
     pub low_temp_f: f64,
     pub avg_humidity: f64,
     pub total_precipitation_mm: f64,
+    #[serde(default)]
+    pub total_snow_mm: f64,
     pub max_precipitation_prob: f64,
     pub dominant_condition: WeatherCondition,
     pub avg_wind_speed_mph: f64,
@@ -184,4 +216,24 @@ mod tests {
         assert_eq!(WeatherCondition::from_owm_id(801), WeatherCondition::Clouds);
         assert_eq!(WeatherCondition::from_owm_id(600), WeatherCondition::Snow);
     }
+
+    #[test]
+    fn look_aheads_follow_the_evaluation_clock() {
+        use crate::testing::{at, date, ForecastBuilder};
+
+        let forecast = ForecastBuilder::starting(date(2025, 6, 1))
+            .day(80.0, 60.0, 60.0, 0.0)
+            .day(82.0, 61.0, 60.0, 0.0)
+            .day(90.0, 70.0, 60.0, 0.0)
+            .build();
+
+        clock::at(at(2025, 6, 1), || {
+            assert_eq!(forecast.next_days(1).len(), 2);
+            assert_eq!(forecast.max_temp_next_days(1), Some(82.0));
+            assert_eq!(forecast.next_hours(11).len(), 8);
+        });
+        clock::at(at(2025, 6, 2), || {
+            assert_eq!(forecast.max_temp_next_days(1), Some(90.0));
+        });
+    }
 }
@@ -1,4 +1,4 @@
-use crate::models::{Application, ApplicationType};
+use crate::models::{product_info, Application, ApplicationType};
 use chrono::{Datelike, Local};
 use serde::{Deserialize, Serialize};
 
@@ -56,6 +56,28 @@ impl FracClass {
     pub fn is_multisite(&self) -> bool {
         matches!(self, FracClass::FracM3 | FracClass::FracM5)
     }
+
+    /// The class for a label's FRAC group ("11", "FRAC 11", "M3", "M03").
+    /// `None` for groups outside the classes tracked here.
+    pub fn from_group(group: &str) -> Option<FracClass> {
+        let upper = group.trim().to_uppercase();
+        let code = upper.strip_prefix("FRAC").unwrap_or(&upper).trim();
+        let (multisite, number) = match code.strip_prefix('M') {
+            Some(number) => (true, number),
+            None => (false, code),
+        };
+        match (multisite, number.trim_start_matches('0')) {
+            (false, "1") => Some(FracClass::Frac1),
+            (false, "3") => Some(FracClass::Frac3),
+            (false, "7") => Some(FracClass::Frac7),
+            (false, "11") => Some(FracClass::Frac11),
+            (false, "12") => Some(FracClass::Frac12),
+            (false, "14") => Some(FracClass::Frac14),
+            (true, "3") => Some(FracClass::FracM3),
+            (true, "5") => Some(FracClass::FracM5),
+            _ => None,
+        }
+    }
 }
 
 impl std::fmt::Display for FracClass {
@@ -64,9 +86,17 @@ impl std::fmt::Display for FracClass {
     }
 }
 
-/// Attempt to identify a FRAC class from a product name string.
-/// Matches against known active ingredients and trade names (case-insensitive).
+/// Attempt to identify a FRAC class from a product name string. A label
+/// product's FRAC group wins; otherwise matches against known active
+/// ingredients and trade names (case-insensitive).
 pub fn frac_class_for_product(name: &str) -> Option<FracClass> {
+    if let Some(class) = product_info(name)
+        .and_then(|p| p.frac_group)
+        .and_then(|g| FracClass::from_group(&g))
+    {
+        return Some(class);
+    }
+
     let lower = name.to_lowercase();
 
     // FRAC 1
@@ -269,6 +299,15 @@ mod tests {
         assert_eq!(frac_class_for_product("random stuff"), None);
     }
 
+    #[test]
+    fn label_groups() {
+        assert_eq!(FracClass::from_group("11"), Some(FracClass::Frac11));
+        assert_eq!(FracClass::from_group("FRAC 3"), Some(FracClass::Frac3));
+        assert_eq!(FracClass::from_group("m03"), Some(FracClass::FracM3));
+        assert_eq!(FracClass::from_group("M5"), Some(FracClass::FracM5));
+        assert_eq!(FracClass::from_group("2"), None);
+    }
+
     #[test]
     fn multisite_identification() {
         assert!(FracClass::FracM3.is_multisite());
@@ -297,6 +336,9 @@ mod tests {
             potassium_pct: None,
             plant_id: None,
             follow_up_date: None,
+            area_id: None,
+            equipment_id: None,
+            custom_type_id: None,
             created_at: Utc::now(),
         }
     }
@@ -24,6 +24,8 @@ pub struct CrabgrassModel {
     pub germination_threshold: f64,
     pub current_gdd: f64,
     pub status: CrabgrassStatus,
+    /// Estimated share of the spring flush already germinated, 0-100.
+    pub germination_pct: f64,
     pub estimated_germination_date: Option<NaiveDate>,
 }
 
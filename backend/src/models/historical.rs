@@ -1,4 +1,4 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -17,3 +17,26 @@ pub struct HistoricalData {
     pub precipitation_mm: Vec<TimeSeriesPoint>,
     pub gdd_accumulation: Vec<TimeSeriesPoint>,
 }
+
+/// One day of archived weather (Open-Meteo), for dates the station lake
+/// doesn't cover. Soil values are modeled for the 7-28cm layer, not measured.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoricalDay {
+    pub date: NaiveDate,
+    pub high_temp_f: Option<f64>,
+    pub low_temp_f: Option<f64>,
+    pub mean_temp_f: Option<f64>,
+    pub humidity_percent: Option<f64>,
+    pub precipitation_mm: Option<f64>,
+    pub soil_temp_f: Option<f64>,
+    /// Volumetric, 0-1 like the station's `soil_moisture_10`.
+    pub soil_moisture: Option<f64>,
+}
+
+impl HistoricalDay {
+    /// Base-50°F GDD from the day's high and low, as the lake's `gdd50` is computed.
+    pub fn gdd50(&self) -> Option<f64> {
+        let (high, low) = (self.high_temp_f?, self.low_temp_f?);
+        Some(((high + low) / 2.0 - 50.0).max(0.0))
+    }
+}
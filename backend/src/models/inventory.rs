@@ -0,0 +1,190 @@
+use super::{product_info, Application, ApplicationType, SquareFeet};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProductUnit {
+    Pounds,
+    Ounces,
+    Gallons,
+    FluidOunces,
+}
+
+impl ProductUnit {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProductUnit::Pounds => "lb",
+            ProductUnit::Ounces => "oz",
+            ProductUnit::Gallons => "gal",
+            ProductUnit::FluidOunces => "fl oz",
+        }
+    }
+}
+
+impl FromStr for ProductUnit {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace([' ', '.'], "").as_str() {
+            "pounds" | "pound" | "lb" | "lbs" => Ok(ProductUnit::Pounds),
+            "ounces" | "ounce" | "oz" => Ok(ProductUnit::Ounces),
+            "gallons" | "gallon" | "gal" => Ok(ProductUnit::Gallons),
+            "fluidounces" | "fluidounce" | "floz" => Ok(ProductUnit::FluidOunces),
+            _ => Err(format!("Unknown unit: {}", s)),
+        }
+    }
+}
+
+impl std::fmt::Display for ProductUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// A product on the shelf. `quantity` is what was on hand on `counted_on`;
+/// applications logged since then draw it down (see [`InventoryStatus`]).
+/// Application rates are taken to be in the item's unit per 1000 sqft.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InventoryItem {
+    pub id: Option<i64>,
+    pub lawn_profile_id: i64,
+    pub product_name: String,
+    /// What the product is used for, to match it against recommendations.
+    pub application_type: Option<ApplicationType>,
+    pub unit: ProductUnit,
+    /// Bag or jug size, for "add a bag" restocking.
+    pub package_size: Option<f64>,
+    pub quantity: f64,
+    pub counted_on: NaiveDate,
+    /// Label rate, used to size the next application.
+    pub rate_per_1000sqft: Option<f64>,
+    pub notes: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl InventoryItem {
+    /// Whether `app` used this product: same name, ignoring case, or both
+    /// names resolve to the same catalog product ("Barricade" vs "Prodiamine 65 WDG").
+    pub fn matches(&self, app: &Application) -> bool {
+        let Some(name) = app.product_name.as_deref() else {
+            return false;
+        };
+        if name.trim().eq_ignore_ascii_case(self.product_name.trim()) {
+            return true;
+        }
+        match (product_info(name), product_info(&self.product_name)) {
+            (Some(a), Some(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+/// Amount of product an application used: rate x coverage. Coverage falls
+/// back to the lawn size; no rate means the amount is unknown.
+pub fn application_amount(app: &Application, lawn_size_sqft: f64) -> Option<f64> {
+    let rate = app.rate_per_1000sqft?;
+    Some(SquareFeet(app.coverage_sqft.unwrap_or(lawn_size_sqft)).apply_rate(rate))
+}
+
+/// An inventory item with what's left after applications since its count.
+#[derive(Debug, Clone, Serialize)]
+pub struct InventoryStatus {
+    #[serde(flatten)]
+    pub item: InventoryItem,
+    pub used_since_count: f64,
+    /// Never below zero; `used_since_count` shows any overdraw.
+    pub remaining: f64,
+    /// Amount one application to the whole lawn takes, if a rate is known.
+    pub per_application: Option<f64>,
+}
+
+impl InventoryStatus {
+    pub fn new(
+        item: InventoryItem,
+        applications: &[Application],
+        lawn_size_sqft: f64,
+        today: NaiveDate,
+    ) -> Self {
+        let used_since_count: f64 = applications
+            .iter()
+            .filter(|a| a.application_date >= item.counted_on && a.application_date <= today)
+            .filter(|a| item.matches(a))
+            .filter_map(|a| application_amount(a, lawn_size_sqft))
+            .sum();
+        // Fall back to the rate from the most recent logged use
+        let rate = item.rate_per_1000sqft.or_else(|| {
+            applications
+                .iter()
+                .filter(|a| item.matches(a))
+                .max_by_key(|a| a.application_date)
+                .and_then(|a| a.rate_per_1000sqft)
+        });
+        Self {
+            remaining: (item.quantity - used_since_count).max(0.0),
+            per_application: rate.map(|r| SquareFeet(lawn_size_sqft).apply_rate(r)),
+            used_since_count,
+            item,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{application, at, date};
+
+    fn prodiamine(quantity: f64) -> InventoryItem {
+        InventoryItem {
+            id: Some(1),
+            lawn_profile_id: 1,
+            product_name: "Prodiamine 65 WDG".into(),
+            application_type: Some(ApplicationType::PreEmergent),
+            unit: ProductUnit::Ounces,
+            package_size: Some(80.0),
+            quantity,
+            counted_on: date(2025, 3, 1),
+            rate_per_1000sqft: None,
+            notes: None,
+            created_at: at(2025, 3, 1),
+        }
+    }
+
+    fn used(product: &str, on: NaiveDate, rate: f64, coverage: Option<f64>) -> Application {
+        let mut app = application(ApplicationType::PreEmergent, on);
+        app.product_name = Some(product.into());
+        app.rate_per_1000sqft = Some(rate);
+        app.coverage_sqft = coverage;
+        app
+    }
+
+    #[test]
+    fn product_unit_from_str() {
+        assert_eq!(ProductUnit::from_str("lbs"), Ok(ProductUnit::Pounds));
+        assert_eq!(
+            ProductUnit::from_str("fl. oz"),
+            Ok(ProductUnit::FluidOunces)
+        );
+        assert!(ProductUnit::from_str("bushel").is_err());
+    }
+
+    #[test]
+    fn applications_since_count_draw_down_inventory() {
+        let apps = vec![
+            // Before the count: already reflected in quantity
+            used("Prodiamine 65 WDG", date(2025, 2, 20), 0.37, None),
+            // Catalog match on a different name
+            used("Barricade", date(2025, 3, 15), 0.37, Some(4000.0)),
+            used("prodiamine 65 wdg", date(2025, 5, 1), 0.2, None),
+            used("Dimension", date(2025, 5, 1), 0.5, None),
+        ];
+        let status = InventoryStatus::new(prodiamine(5.0), &apps, 5000.0, date(2025, 6, 1));
+        assert!((status.used_since_count - 2.48).abs() < 1e-9);
+        assert!((status.remaining - 2.52).abs() < 1e-9);
+        // Most recent rate sizes the next application
+        assert_eq!(status.per_application, Some(1.0));
+
+        let short = InventoryStatus::new(prodiamine(1.0), &apps, 5000.0, date(2025, 6, 1));
+        assert_eq!(short.remaining, 0.0);
+    }
+}
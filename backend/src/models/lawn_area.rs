@@ -0,0 +1,147 @@
+use super::{Application, GrassType, LawnProfile};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SunExposure {
+    /// 6+ hours of direct sun.
+    FullSun,
+    /// Roughly 3-6 hours, or dappled light all day.
+    PartShade,
+    /// Under 3 hours of direct sun.
+    FullShade,
+}
+
+impl SunExposure {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SunExposure::FullSun => "Full Sun",
+            SunExposure::PartShade => "Part Shade",
+            SunExposure::FullShade => "Full Shade",
+        }
+    }
+}
+
+impl FromStr for SunExposure {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "fullsun" | "full sun" | "sun" => Ok(SunExposure::FullSun),
+            "partshade" | "part shade" | "partial shade" | "part sun" => Ok(SunExposure::PartShade),
+            "fullshade" | "full shade" | "shade" => Ok(SunExposure::FullShade),
+            _ => Err(format!("Unknown sun exposure: {}", s)),
+        }
+    }
+}
+
+impl std::fmt::Display for SunExposure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// A section of a lawn profile that gets its own treatment, e.g. the shaded
+/// backyard vs. the sunny front strip.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LawnArea {
+    pub id: Option<i64>,
+    pub lawn_profile_id: i64,
+    pub name: String,
+    pub size_sqft: Option<f64>,
+    pub sun_exposure: SunExposure,
+    /// Overrides the profile's grass type when rules run for this area.
+    pub grass_type: Option<GrassType>,
+    /// Free-text seed mix, e.g. "80% TTTF / 20% KBG".
+    pub grass_mix: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl LawnArea {
+    /// The profile as rules should see it for this area: the area's grass and
+    /// size stand in for the whole lawn's.
+    pub fn scope_profile(&self, profile: &LawnProfile) -> LawnProfile {
+        LawnProfile {
+            grass_type: self.grass_type.unwrap_or(profile.grass_type),
+            lawn_size_sqft: self.size_sqft.or(profile.lawn_size_sqft),
+            ..profile.clone()
+        }
+    }
+
+    /// Whether `app` treated this area: tagged to it, or untagged (whole lawn).
+    pub fn covers(&self, app: &Application) -> bool {
+        app.area_id.is_none() || app.area_id == self.id
+    }
+}
+
+/// Share of the whole lawn an application covered, from its area's size.
+/// Untagged applications, and areas or lawns without a size, count as 1.0.
+pub fn lawn_fraction(app: &Application, areas: &[LawnArea], lawn_size_sqft: Option<f64>) -> f64 {
+    let area_size = app
+        .area_id
+        .and_then(|id| areas.iter().find(|a| a.id == Some(id)))
+        .and_then(|a| a.size_sqft);
+    match (area_size, lawn_size_sqft) {
+        (Some(area), Some(lawn)) if lawn > 0.0 => (area / lawn).min(1.0),
+        _ => 1.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ApplicationType;
+    use crate::testing::{application, at, date, profile};
+
+    fn area(id: i64, size: Option<f64>) -> LawnArea {
+        LawnArea {
+            id: Some(id),
+            lawn_profile_id: 1,
+            name: "Backyard".into(),
+            size_sqft: size,
+            sun_exposure: SunExposure::FullShade,
+            grass_type: Some(GrassType::FineFescue),
+            grass_mix: None,
+            created_at: at(2025, 1, 1),
+        }
+    }
+
+    #[test]
+    fn sun_exposure_from_str() {
+        assert_eq!(
+            SunExposure::from_str("PartShade"),
+            Ok(SunExposure::PartShade)
+        );
+        assert_eq!(SunExposure::from_str("shade"), Ok(SunExposure::FullShade));
+        assert!(SunExposure::from_str("moonlight").is_err());
+    }
+
+    #[test]
+    fn scope_profile_uses_area_grass_and_size() {
+        let scoped = area(2, Some(1200.0)).scope_profile(&profile(GrassType::TallFescue));
+        assert_eq!(scoped.grass_type, GrassType::FineFescue);
+        assert_eq!(scoped.lawn_size_sqft, Some(1200.0));
+    }
+
+    #[test]
+    fn covers_tagged_and_whole_lawn_applications() {
+        let backyard = area(2, None);
+        let mut app = application(ApplicationType::Fertilizer, date(2025, 5, 1));
+        assert!(backyard.covers(&app));
+        app.area_id = Some(2);
+        assert!(backyard.covers(&app));
+        app.area_id = Some(3);
+        assert!(!backyard.covers(&app));
+    }
+
+    #[test]
+    fn lawn_fraction_weights_by_area_size() {
+        let areas = vec![area(2, Some(1250.0))];
+        let mut app = application(ApplicationType::Fertilizer, date(2025, 5, 1));
+        assert_eq!(lawn_fraction(&app, &areas, Some(5000.0)), 1.0);
+        app.area_id = Some(2);
+        assert_eq!(lawn_fraction(&app, &areas, Some(5000.0)), 0.25);
+        assert_eq!(lawn_fraction(&app, &areas, None), 1.0);
+    }
+}
@@ -1,3 +1,4 @@
+use super::Location;
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
@@ -27,6 +28,19 @@ impl GrassType {
         }
     }
 
+    /// Typical home-lawn height of cut (inches), used when the profile
+    /// doesn't set its own.
+    pub fn default_mowing_height_in(&self) -> f64 {
+        match self {
+            GrassType::TallFescue => 3.5,
+            GrassType::KentuckyBluegrass | GrassType::FineFescue | GrassType::StAugustine => 3.0,
+            GrassType::PerennialRyegrass => 2.5,
+            GrassType::Zoysia => 1.5,
+            GrassType::Bermuda => 1.0,
+            GrassType::Mixed => 3.0,
+        }
+    }
+
     pub fn is_cool_season(&self) -> bool {
         matches!(
             self,
@@ -83,6 +97,48 @@ impl SoilType {
             SoilType::SandyLoam => "Sandy Loam",
         }
     }
+
+    /// Typical wilting point and field capacity for the texture. A lab test of
+    /// the actual soil can differ by a few points.
+    pub fn water_limits(&self) -> SoilWaterLimits {
+        let (wilting_point, field_capacity) = match self {
+            SoilType::Sandy => (0.05, 0.12),
+            SoilType::SandyLoam => (0.08, 0.18),
+            SoilType::Loam => (0.12, 0.27),
+            SoilType::SiltLoam => (0.13, 0.31),
+            SoilType::ClayLoam => (0.18, 0.34),
+            SoilType::Clay => (0.24, 0.40),
+        };
+        SoilWaterLimits {
+            wilting_point,
+            field_capacity,
+        }
+    }
+}
+
+/// Volumetric water content (0.0–1.0) bounding the water turf can use.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SoilWaterLimits {
+    pub wilting_point: f64,
+    pub field_capacity: f64,
+}
+
+impl SoilWaterLimits {
+    /// Limits for a profile's soil; Loam when the soil type isn't set.
+    pub fn for_soil(soil: Option<SoilType>) -> Self {
+        soil.unwrap_or(SoilType::Loam).water_limits()
+    }
+
+    /// Share of plant-available water left at `vwc`: 0.0 at wilting point,
+    /// 1.0 at field capacity (clamped; wetter soil reads 1.0).
+    pub fn plant_available(&self, vwc: f64) -> f64 {
+        ((vwc - self.wilting_point) / (self.field_capacity - self.wilting_point)).clamp(0.0, 1.0)
+    }
+
+    /// Volumetric water content at a plant-available share.
+    pub fn vwc_at(&self, available: f64) -> f64 {
+        self.wilting_point + available * (self.field_capacity - self.wilting_point)
+    }
 }
 
 impl FromStr for SoilType {
@@ -152,6 +208,19 @@ pub struct LawnProfile {
     pub soil_type: Option<SoilType>,
     pub lawn_size_sqft: Option<f64>,
     pub irrigation_type: Option<IrrigationType>,
+    /// Forecast location for this lawn; `None` uses the global location.
+    #[serde(default)]
+    pub location: Option<Location>,
+    /// USCRN station for this lawn; `None` uses `NOAA_STATION_WBANNO`.
+    #[serde(default)]
+    pub noaa_station_wbanno: Option<i32>,
+    /// Marked dormant by hand, for when the station can't tell (no soil probe,
+    /// or a drought the sensors miss).
+    #[serde(default)]
+    pub dormant: bool,
+    /// Target height of cut (inches); `None` uses the grass type's default.
+    #[serde(default)]
+    pub mowing_height_in: Option<f64>,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
 }
@@ -167,12 +236,24 @@ impl LawnProfile {
             soil_type: None,
             lawn_size_sqft: None,
             irrigation_type: None,
+            location: None,
+            noaa_station_wbanno: None,
+            dormant: false,
+            mowing_height_in: None,
             created_at: now,
             updated_at: now,
         }
     }
 }
 
+impl LawnProfile {
+    /// Height of cut the one-third rule is measured against.
+    pub fn target_mowing_height_in(&self) -> f64 {
+        self.mowing_height_in
+            .unwrap_or_else(|| self.grass_type.default_mowing_height_in())
+    }
+}
+
 impl Default for LawnProfile {
     fn default() -> Self {
         Self::new(
@@ -183,10 +264,39 @@ impl Default for LawnProfile {
     }
 }
 
+/// Normalize a USDA hardiness zone ("7A", "Zone 7a", " 7a ") to `7a`.
+/// Zones run 1a-13b on the 2023 USDA map.
+pub fn normalize_usda_zone(s: &str) -> Result<String, String> {
+    let trimmed = s.trim().to_lowercase();
+    let zone = trimmed.strip_prefix("zone").unwrap_or(&trimmed).trim();
+    let number = zone
+        .strip_suffix('a')
+        .or_else(|| zone.strip_suffix('b'))
+        .unwrap_or_default();
+
+    match number.parse::<u8>() {
+        Ok(n) if (1..=13).contains(&n) => Ok(format!("{}{}", n, &zone[number.len()..])),
+        _ => Err(format!(
+            "Invalid USDA zone: {}. Expected 1a-13b, e.g. 7a",
+            s.trim()
+        )),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn usda_zone_normalizes_and_validates() {
+        assert_eq!(normalize_usda_zone("7a"), Ok("7a".to_string()));
+        assert_eq!(normalize_usda_zone(" Zone 10B "), Ok("10b".to_string()));
+        assert!(normalize_usda_zone("7").is_err());
+        assert!(normalize_usda_zone("14a").is_err());
+        assert!(normalize_usda_zone("0b").is_err());
+        assert!(normalize_usda_zone("").is_err());
+    }
+
     #[test]
     fn grass_type_from_str_valid() {
         assert_eq!(GrassType::from_str("TallFescue"), Ok(GrassType::TallFescue));
@@ -250,6 +360,19 @@ mod tests {
         assert!("unknown".parse::<GrassType>().is_err());
     }
 
+    #[test]
+    fn plant_available_water_depends_on_texture() {
+        // 18% VWC is near field capacity in sandy loam but close to wilting in clay
+        let sandy = SoilType::SandyLoam.water_limits();
+        let clay = SoilType::Clay.water_limits();
+        assert!((sandy.plant_available(0.18) - 1.0).abs() < 1e-9);
+        assert_eq!(clay.plant_available(0.18), 0.0);
+
+        let loam = SoilWaterLimits::for_soil(None);
+        assert_eq!(loam, SoilType::Loam.water_limits());
+        assert!((loam.plant_available(loam.vwc_at(0.4)) - 0.4).abs() < 1e-9);
+    }
+
     #[test]
     fn soil_type_from_str_valid() {
         assert_eq!(SoilType::from_str("clay"), Ok(SoilType::Clay));
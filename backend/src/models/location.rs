@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+
+/// Coordinates used for the OpenWeatherMap forecast, optionally with the place
+/// name they were geocoded from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Location {
+    /// e.g. "West Chester, Pennsylvania, US". Empty when entered as raw coordinates.
+    #[serde(default)]
+    pub name: String,
+    pub latitude: f64,
+    pub longitude: f64,
+    /// USDA hardiness zone for the location, looked up when it was found by US ZIP.
+    #[serde(default)]
+    pub usda_zone: Option<String>,
+}
+
+impl Location {
+    pub fn validate(&self) -> Result<(), String> {
+        if !(-90.0..=90.0).contains(&self.latitude) {
+            return Err(format!("Latitude {} is outside -90..90", self.latitude));
+        }
+        if !(-180.0..=180.0).contains(&self.longitude) {
+            return Err(format!("Longitude {} is outside -180..180", self.longitude));
+        }
+        Ok(())
+    }
+}
+
+/// Whether a geocoding query is a postal code (`19382`, `19382,US`) rather than a
+/// place name. OWM resolves those through a separate endpoint.
+pub fn is_postal_code(query: &str) -> bool {
+    let code = query.split(',').next().unwrap_or_default().trim();
+    !code.is_empty()
+        && code.chars().any(|c| c.is_ascii_digit())
+        && code
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == ' ' || c == '-')
+}
+
+/// The 5-digit ZIP in a US postal code query ("19382", "19382-1234", "19382,US").
+pub fn us_zip(query: &str) -> Option<&str> {
+    let mut parts = query.split(',').map(str::trim);
+    let code = parts.next()?;
+    if parts
+        .next()
+        .is_some_and(|country| !country.eq_ignore_ascii_case("us"))
+    {
+        return None;
+    }
+    let zip = code.get(..5)?;
+    let rest = &code[5..];
+    let valid_rest = rest.is_empty()
+        || (rest.len() == 5
+            && rest.starts_with('-')
+            && rest[1..].chars().all(|c| c.is_ascii_digit()));
+    (zip.chars().all(|c| c.is_ascii_digit()) && valid_rest).then_some(zip)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_rejects_out_of_range_coordinates() {
+        let mut loc = Location {
+            name: String::new(),
+            latitude: 39.86,
+            longitude: -75.79,
+            usda_zone: None,
+        };
+        assert!(loc.validate().is_ok());
+        loc.latitude = 91.0;
+        assert!(loc.validate().is_err());
+        loc.latitude = 39.86;
+        loc.longitude = -190.0;
+        assert!(loc.validate().is_err());
+        // NaN is never in range
+        loc.longitude = f64::NAN;
+        assert!(loc.validate().is_err());
+    }
+
+    #[test]
+    fn postal_codes_are_detected() {
+        assert!(is_postal_code("19382"));
+        assert!(is_postal_code("19382,US"));
+        assert!(is_postal_code("SW1A 1AA,GB"));
+        assert!(!is_postal_code("West Chester, PA"));
+        assert!(!is_postal_code("Paris"));
+        assert!(!is_postal_code(""));
+    }
+
+    #[test]
+    fn us_zip_extracts_five_digits() {
+        assert_eq!(us_zip("19382"), Some("19382"));
+        assert_eq!(us_zip("19382-1234, US"), Some("19382"));
+        assert_eq!(us_zip("19382,GB"), None);
+        assert_eq!(us_zip("1938"), None);
+        assert_eq!(us_zip("SW1A 1AA"), None);
+    }
+}
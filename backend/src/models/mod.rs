@@ -1,24 +1,59 @@
+pub mod air_quality;
+pub mod annual_review;
 pub mod application;
+pub mod custom_type;
+pub mod dashboard_layout;
+pub mod dates;
+pub mod drought;
 pub mod environmental;
+pub mod equipment;
+pub mod event;
 pub mod forecast;
 pub mod frac_class;
 pub mod gdd;
 pub mod historical;
+pub mod inventory;
+pub mod lawn_area;
 pub mod lawn_profile;
+pub mod location;
 pub mod nitrogen_budget;
+pub mod observation;
+pub mod photo;
 pub mod plant;
+pub mod product;
+pub mod rain_check;
 pub mod recommendation;
 pub mod seasonal_plan;
 pub mod soil_temp_prediction;
 pub mod soil_test;
+pub mod stress_index;
+pub mod ui_state;
+pub mod units;
 
+pub use air_quality::*;
+pub use annual_review::*;
 pub use application::*;
+pub use custom_type::*;
+pub use dashboard_layout::*;
+pub use drought::*;
 pub use environmental::*;
+pub use equipment::*;
+pub use event::*;
 pub use forecast::*;
 pub use frac_class::*;
 pub use gdd::*;
 pub use historical::*;
+pub use inventory::*;
+pub use lawn_area::*;
 pub use lawn_profile::*;
+pub use location::*;
 pub use nitrogen_budget::*;
+pub use observation::*;
+pub use photo::*;
+pub use product::*;
+pub use rain_check::*;
 pub use recommendation::*;
 pub use soil_test::*;
+pub use stress_index::*;
+pub use ui_state::*;
+pub use units::*;
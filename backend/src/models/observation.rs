@@ -0,0 +1,81 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// What a journal observation is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ObservationCategory {
+    Weeds,
+    Disease,
+    Insect,
+    /// Foot traffic, pets, play areas: wear that adds to turf stress.
+    Traffic,
+    General,
+}
+
+impl ObservationCategory {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ObservationCategory::Weeds => "Weeds",
+            ObservationCategory::Disease => "Disease",
+            ObservationCategory::Insect => "Insect",
+            ObservationCategory::Traffic => "Traffic",
+            ObservationCategory::General => "General",
+        }
+    }
+}
+
+impl FromStr for ObservationCategory {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "weeds" | "weed" => Ok(ObservationCategory::Weeds),
+            "disease" => Ok(ObservationCategory::Disease),
+            "insect" | "insects" => Ok(ObservationCategory::Insect),
+            "traffic" | "wear" => Ok(ObservationCategory::Traffic),
+            "general" => Ok(ObservationCategory::General),
+            _ => Err(format!("Unknown observation category: {}", s)),
+        }
+    }
+}
+
+impl std::fmt::Display for ObservationCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// A timestamped field note for the season journal, e.g. "crabgrass breaking
+/// through along the driveway".
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Observation {
+    pub id: Option<i64>,
+    pub lawn_profile_id: i64,
+    pub observed_at: DateTime<Utc>,
+    pub category: ObservationCategory,
+    pub notes: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn observation_category_from_str() {
+        assert_eq!(
+            ObservationCategory::from_str("Weeds"),
+            Ok(ObservationCategory::Weeds)
+        );
+        assert_eq!(
+            ObservationCategory::from_str("insects"),
+            Ok(ObservationCategory::Insect)
+        );
+        assert_eq!(
+            ObservationCategory::from_str("wear"),
+            Ok(ObservationCategory::Traffic)
+        );
+        assert!(ObservationCategory::from_str("photo").is_err());
+    }
+}
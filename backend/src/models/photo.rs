@@ -0,0 +1,72 @@
+use super::Application;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A progress photo in the photo journal. Only the file's path or name is
+/// stored; TurfOps never reads or renders the image.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Photo {
+    pub id: Option<i64>,
+    pub lawn_profile_id: i64,
+    /// Lawn area pictured; `None` for the whole lawn.
+    pub area_id: Option<i64>,
+    pub taken_on: NaiveDate,
+    pub path: String,
+    pub caption: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Photo {
+    /// The latest turf application on or before the photo's date that
+    /// covered the pictured area (untagged applications cover every area).
+    pub fn preceding_application<'a>(&self, apps: &'a [Application]) -> Option<&'a Application> {
+        apps.iter()
+            .filter(|a| a.plant_id.is_none() && a.application_date <= self.taken_on)
+            .filter(|a| self.area_id.is_none() || a.area_id.is_none() || a.area_id == self.area_id)
+            .max_by_key(|a| (a.application_date, a.id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ApplicationType;
+    use crate::testing::{application, date};
+
+    #[test]
+    fn preceding_application_respects_date_and_area() {
+        let photo = Photo {
+            id: Some(1),
+            lawn_profile_id: 1,
+            area_id: Some(2),
+            taken_on: date(2025, 9, 20),
+            path: "IMG_0142.jpg".into(),
+            caption: None,
+            created_at: Utc::now(),
+        };
+        let mut other_area = application(ApplicationType::Fertilizer, date(2025, 9, 18));
+        other_area.area_id = Some(3);
+        let mut after = application(ApplicationType::Fungicide, date(2025, 9, 21));
+        after.area_id = Some(2);
+        let whole_lawn = application(ApplicationType::Overseed, date(2025, 9, 5));
+        let apps = [whole_lawn, other_area, after];
+
+        let found = photo.preceding_application(&apps).unwrap();
+        assert_eq!(found.application_type, ApplicationType::Overseed);
+
+        let whole = Photo {
+            area_id: None,
+            ..photo
+        };
+        assert_eq!(
+            whole.preceding_application(&apps).unwrap().application_type,
+            ApplicationType::Fertilizer
+        );
+        assert!(Photo {
+            taken_on: date(2025, 9, 1),
+            ..whole
+        }
+        .preceding_application(&apps)
+        .is_none());
+    }
+}
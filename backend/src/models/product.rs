@@ -0,0 +1,475 @@
+use chrono::{DateTime, Utc};
+use serde::de::{self, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::str::FromStr;
+use std::sync::{LazyLock, RwLock};
+
+/// Label facts for a turf product, looked up by the product name logged on
+/// an application. Durations are typical label values at the standard rate;
+/// higher rates extend them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProductInfo {
+    pub name: String,
+    pub active_ingredient: String,
+    /// Lowercase trade names and actives that identify the product.
+    keywords: Vec<String>,
+    /// FRAC group from the label ("11", "M3"), when known.
+    pub frac_group: Option<String>,
+    /// Days after application before seed will establish (pre-emergent residual).
+    pub reseed_interval_days: i64,
+    /// Days after seeding before the product is safe on the new grass.
+    pub seeded_turf_days: i64,
+    /// Irrigation or rain needed to move the product into the soil.
+    pub water_in: Option<WaterIn>,
+    /// How long people and pets should stay off the treated area.
+    pub reentry: Option<ReEntry>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WaterIn {
+    pub inches: f64,
+    /// Days after application (0 = same day) by which it must be watered in.
+    pub within_days: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReEntry {
+    /// Until the spray has dried on the leaf.
+    UntilDry,
+    /// Until the granules have been watered in.
+    AfterWateringIn,
+}
+
+impl ReEntry {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReEntry::UntilDry => "until_dry",
+            ReEntry::AfterWateringIn => "after_watering_in",
+        }
+    }
+}
+
+impl FromStr for ReEntry {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().replace([' ', '-'], "_").as_str() {
+            "until_dry" => Ok(ReEntry::UntilDry),
+            "after_watering_in" => Ok(ReEntry::AfterWateringIn),
+            other => Err(format!(
+                "Unknown re-entry {:?} (until_dry or after_watering_in)",
+                other
+            )),
+        }
+    }
+}
+
+/// Built-in catalog entry; see [`ProductInfo`].
+struct BuiltIn {
+    name: &'static str,
+    active_ingredient: &'static str,
+    keywords: &'static [&'static str],
+    reseed_interval_days: i64,
+    seeded_turf_days: i64,
+    water_in: Option<WaterIn>,
+    reentry: Option<ReEntry>,
+}
+
+impl From<&BuiltIn> for ProductInfo {
+    fn from(p: &BuiltIn) -> Self {
+        Self {
+            name: p.name.to_string(),
+            active_ingredient: p.active_ingredient.to_string(),
+            keywords: p.keywords.iter().map(|k| k.to_string()).collect(),
+            frac_group: None,
+            reseed_interval_days: p.reseed_interval_days,
+            seeded_turf_days: p.seeded_turf_days,
+            water_in: p.water_in,
+            reentry: p.reentry,
+        }
+    }
+}
+
+/// Products common on residential cool-season turf.
+const PRODUCT_CATALOG: &[BuiltIn] = &[
+    // Pre-emergents
+    BuiltIn {
+        name: "Prodiamine",
+        active_ingredient: "prodiamine",
+        keywords: &["prodiamine", "barricade"],
+        reseed_interval_days: 120,
+        seeded_turf_days: 60,
+        water_in: Some(WaterIn {
+            inches: 0.5,
+            within_days: 14,
+        }),
+        reentry: Some(ReEntry::UntilDry),
+    },
+    BuiltIn {
+        name: "Dithiopyr",
+        active_ingredient: "dithiopyr",
+        keywords: &["dithiopyr", "dimension"],
+        reseed_interval_days: 90,
+        seeded_turf_days: 60,
+        water_in: Some(WaterIn {
+            inches: 0.5,
+            within_days: 7,
+        }),
+        reentry: Some(ReEntry::UntilDry),
+    },
+    BuiltIn {
+        name: "Pendimethalin",
+        active_ingredient: "pendimethalin",
+        keywords: &["pendimethalin", "pendulum", "halts"],
+        reseed_interval_days: 120,
+        seeded_turf_days: 60,
+        water_in: Some(WaterIn {
+            inches: 0.5,
+            within_days: 7,
+        }),
+        reentry: Some(ReEntry::UntilDry),
+    },
+    BuiltIn {
+        name: "Oxadiazon",
+        active_ingredient: "oxadiazon",
+        keywords: &["oxadiazon", "ronstar"],
+        reseed_interval_days: 120,
+        seeded_turf_days: 90,
+        water_in: Some(WaterIn {
+            inches: 0.5,
+            within_days: 3,
+        }),
+        reentry: Some(ReEntry::AfterWateringIn),
+    },
+    BuiltIn {
+        name: "Mesotrione",
+        active_ingredient: "mesotrione",
+        keywords: &["mesotrione", "tenacity"],
+        reseed_interval_days: 0,
+        seeded_turf_days: 0,
+        water_in: None,
+        reentry: Some(ReEntry::UntilDry),
+    },
+    BuiltIn {
+        name: "Siduron",
+        active_ingredient: "siduron",
+        keywords: &["siduron", "tupersan"],
+        reseed_interval_days: 0,
+        seeded_turf_days: 0,
+        water_in: None,
+        reentry: Some(ReEntry::UntilDry),
+    },
+    // Grub control
+    BuiltIn {
+        name: "Chlorantraniliprole",
+        active_ingredient: "chlorantraniliprole",
+        keywords: &["chlorantraniliprole", "grubex", "grub ex", "acelepryn"],
+        reseed_interval_days: 0,
+        seeded_turf_days: 0,
+        water_in: Some(WaterIn {
+            inches: 0.5,
+            within_days: 1,
+        }),
+        reentry: Some(ReEntry::AfterWateringIn),
+    },
+    BuiltIn {
+        name: "Imidacloprid",
+        active_ingredient: "imidacloprid",
+        keywords: &["imidacloprid", "merit"],
+        reseed_interval_days: 0,
+        seeded_turf_days: 0,
+        water_in: Some(WaterIn {
+            inches: 0.5,
+            within_days: 1,
+        }),
+        reentry: Some(ReEntry::AfterWateringIn),
+    },
+    // Broadleaf herbicides (foliar: keep dry, no watering in)
+    BuiltIn {
+        name: "2,4-D three-way",
+        active_ingredient: "2,4-D + MCPP + dicamba",
+        keywords: &["2,4-d", "trimec", "speedzone", "weed b gon", "weed-b-gon"],
+        reseed_interval_days: 0,
+        seeded_turf_days: 28,
+        water_in: None,
+        reentry: Some(ReEntry::UntilDry),
+    },
+    // Fungicides
+    BuiltIn {
+        name: "Azoxystrobin",
+        active_ingredient: "azoxystrobin",
+        keywords: &["azoxystrobin", "heritage"],
+        reseed_interval_days: 0,
+        seeded_turf_days: 0,
+        water_in: None,
+        reentry: Some(ReEntry::UntilDry),
+    },
+    BuiltIn {
+        name: "Propiconazole",
+        active_ingredient: "propiconazole",
+        keywords: &["propiconazole", "banner maxx"],
+        reseed_interval_days: 0,
+        seeded_turf_days: 0,
+        water_in: None,
+        reentry: Some(ReEntry::UntilDry),
+    },
+    BuiltIn {
+        name: "Chlorothalonil",
+        active_ingredient: "chlorothalonil",
+        keywords: &["chlorothalonil", "daconil"],
+        reseed_interval_days: 0,
+        seeded_turf_days: 0,
+        water_in: None,
+        reentry: Some(ReEntry::UntilDry),
+    },
+];
+
+static BUILT_IN_PRODUCTS: LazyLock<Vec<ProductInfo>> =
+    LazyLock::new(|| PRODUCT_CATALOG.iter().map(ProductInfo::from).collect());
+
+/// Label products from the database. Rules look products up without a
+/// database handle, so the server loads these at startup and swaps them in
+/// after every import or edit (`logic::product_labels::reload`).
+static LABEL_PRODUCTS: RwLock<Vec<ProductInfo>> = RwLock::new(Vec::new());
+
+/// Replace the label products consulted by [`product_info`].
+pub fn set_label_products(products: Vec<ProductInfo>) {
+    *LABEL_PRODUCTS.write().expect("label products lock") = products;
+}
+
+/// Look up a product by name. Matches trade names and active ingredients
+/// (case-insensitive), so "Barricade 65WG" finds prodiamine. Label products
+/// win over the built-in catalog.
+pub fn product_info(name: &str) -> Option<ProductInfo> {
+    let labels = LABEL_PRODUCTS.read().expect("label products lock");
+    find_product(&labels, name)
+        .or_else(|| find_product(&BUILT_IN_PRODUCTS, name))
+        .cloned()
+}
+
+fn find_product<'a>(catalog: &'a [ProductInfo], name: &str) -> Option<&'a ProductInfo> {
+    let lower = name.to_lowercase();
+    catalog
+        .iter()
+        .find(|p| p.keywords.iter().any(|k| lower.contains(k.as_str())))
+}
+
+/// One product in a label file, or a product entered by hand. Blank
+/// durations mean the label sets no restriction.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LabelEntry {
+    pub name: String,
+    pub active_ingredient: String,
+    /// Other trade names that identify the product. A list, or one string
+    /// separated by semicolons (the CSV form).
+    #[serde(default, deserialize_with = "keyword_list")]
+    pub keywords: Vec<String>,
+    #[serde(default)]
+    pub frac_group: Option<String>,
+    #[serde(default)]
+    pub irac_group: Option<String>,
+    /// Label rate, in `rate_unit` per 1000 sq ft.
+    #[serde(default)]
+    pub rate_per_1000sqft: Option<f64>,
+    #[serde(default)]
+    pub rate_unit: Option<String>,
+    /// Irrigation needed to activate the product, and the days (0 = same
+    /// day) it has to arrive within. Both or neither.
+    #[serde(default)]
+    pub water_in_inches: Option<f64>,
+    #[serde(default)]
+    pub water_in_days: Option<i64>,
+    #[serde(default)]
+    pub reentry: Option<ReEntry>,
+    /// Pre-emergent residual: days before seed will establish.
+    #[serde(default)]
+    pub residual_days: Option<i64>,
+    /// Days after seeding before the product is safe on new grass.
+    #[serde(default)]
+    pub seeded_turf_days: Option<i64>,
+}
+
+impl LabelEntry {
+    /// Trim text fields and drop blank optional ones.
+    pub fn normalized(self) -> Self {
+        let text = |s: Option<String>| s.map(|s| s.trim().to_string()).filter(|s| !s.is_empty());
+        Self {
+            name: self.name.trim().to_string(),
+            active_ingredient: self.active_ingredient.trim().to_string(),
+            keywords: self
+                .keywords
+                .iter()
+                .map(|k| k.trim().to_string())
+                .filter(|k| !k.is_empty())
+                .collect(),
+            frac_group: text(self.frac_group),
+            irac_group: text(self.irac_group),
+            rate_unit: text(self.rate_unit),
+            ..self
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("Product name can't be empty".into());
+        }
+        if self.active_ingredient.trim().is_empty() {
+            return Err(format!("{}: active ingredient can't be empty", self.name));
+        }
+        if self.water_in_inches.is_some() != self.water_in_days.is_some() {
+            return Err(format!(
+                "{}: water_in_inches and water_in_days go together",
+                self.name
+            ));
+        }
+        let negative = self.rate_per_1000sqft.is_some_and(|v| v < 0.0)
+            || self.water_in_inches.is_some_and(|v| v < 0.0)
+            || [
+                self.water_in_days,
+                self.residual_days,
+                self.seeded_turf_days,
+            ]
+            .iter()
+            .any(|d| d.is_some_and(|d| d < 0));
+        if negative {
+            return Err(format!("{}: rates and days can't be negative", self.name));
+        }
+        Ok(())
+    }
+
+    /// What rules see. The name, active ingredient and keywords all identify it.
+    pub fn info(&self) -> ProductInfo {
+        let keywords = std::iter::once(&self.name)
+            .chain(std::iter::once(&self.active_ingredient))
+            .chain(&self.keywords)
+            .map(|k| k.trim().to_lowercase())
+            .filter(|k| !k.is_empty())
+            .collect();
+        ProductInfo {
+            name: self.name.clone(),
+            active_ingredient: self.active_ingredient.clone(),
+            keywords,
+            frac_group: self.frac_group.clone(),
+            reseed_interval_days: self.residual_days.unwrap_or(0),
+            seeded_turf_days: self.seeded_turf_days.unwrap_or(0),
+            water_in: self
+                .water_in_inches
+                .zip(self.water_in_days)
+                .map(|(inches, within_days)| WaterIn {
+                    inches,
+                    within_days,
+                }),
+            reentry: self.reentry,
+        }
+    }
+}
+
+/// Accept keywords as a list or as one semicolon-separated string.
+fn keyword_list<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<String>, D::Error> {
+    struct Keywords;
+
+    impl<'de> Visitor<'de> for Keywords {
+        type Value = Vec<String>;
+
+        fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.write_str("a list of keywords or a semicolon-separated string")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            Ok(v.split(';')
+                .map(|k| k.trim().to_string())
+                .filter(|k| !k.is_empty())
+                .collect())
+        }
+
+        // CSV reads a bare number ("3336") as one
+        fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+            Ok(vec![v.to_string()])
+        }
+
+        fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(Vec::new())
+        }
+
+        fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(Vec::new())
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut keywords = Vec::new();
+            while let Some(k) = seq.next_element::<String>()? {
+                keywords.push(k);
+            }
+            Ok(keywords)
+        }
+    }
+
+    deserializer.deserialize_any(Keywords)
+}
+
+/// A label product as stored.
+#[derive(Debug, Clone, Serialize)]
+pub struct LabelProduct {
+    pub id: i64,
+    #[serde(flatten)]
+    pub label: LabelEntry,
+    /// Added or changed by hand; imports leave it alone.
+    pub user_edited: bool,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn product_info_matches_trade_names() {
+        assert_eq!(product_info("Barricade 65WG").unwrap().name, "Prodiamine");
+        assert_eq!(
+            product_info("Scotts Halts Crabgrass Preventer")
+                .unwrap()
+                .name,
+            "Pendimethalin"
+        );
+        assert_eq!(product_info("Tenacity").unwrap().reseed_interval_days, 0);
+        assert_eq!(
+            product_info("Scotts GrubEx").unwrap().reentry,
+            Some(ReEntry::AfterWateringIn)
+        );
+        assert!(product_info("Milorganite").is_none());
+    }
+
+    #[test]
+    fn label_entries_match_by_name_active_and_keywords() {
+        let entry: LabelEntry = serde_json::from_value(serde_json::json!({
+            "name": "Dimension 2EW",
+            "active_ingredient": "dithiopyr",
+            "keywords": "Dimension EW; Crabgrass Preventer Plus",
+            "water_in_inches": 0.5,
+            "water_in_days": 7,
+            "residual_days": 90,
+        }))
+        .unwrap();
+        assert!(entry.validate().is_ok());
+        let catalog = vec![entry.info()];
+
+        let found = find_product(&catalog, "Crabgrass Preventer Plus 0-0-7").unwrap();
+        assert_eq!(found.reseed_interval_days, 90);
+        assert_eq!(
+            found.water_in,
+            Some(WaterIn {
+                inches: 0.5,
+                within_days: 7
+            })
+        );
+        assert!(find_product(&catalog, "Generic Dithiopyr").is_some());
+        assert!(find_product(&catalog, "Barricade").is_none());
+
+        let half_watering = LabelEntry {
+            water_in_days: None,
+            ..entry
+        };
+        assert!(half_watering.validate().is_err());
+    }
+}
@@ -0,0 +1,24 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A forecast rain event and, once reconciled, what actually fell.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RainCheck {
+    pub id: Option<i64>,
+    pub lawn_profile_id: i64,
+    pub window_start: DateTime<Utc>,
+    pub window_end: DateTime<Utc>,
+    pub forecast_mm: f64,
+    /// Station total over the window. `None` with `reconciled_at` set means the
+    /// station had no precipitation data and the check was given up on.
+    pub observed_mm: Option<f64>,
+    pub reconciled_at: Option<DateTime<Utc>>,
+}
+
+/// When and how much rain the forecast calls for.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RainWindow {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub forecast_mm: f64,
+}
@@ -1,10 +1,12 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+/// Declaration order doubles as display order for recommendations of equal severity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum RecommendationCategory {
     PreEmergent,
     GrubControl,
+    PestScouting,
     Fertilizer,
     Fungicide,
     Overseeding,
@@ -19,6 +21,7 @@ pub enum RecommendationCategory {
     SoilTempForecast,
     SoilTest,
     PlantMaintenance,
+    Equipment,
     General,
 }
 
@@ -27,6 +30,7 @@ impl RecommendationCategory {
         match self {
             RecommendationCategory::PreEmergent => "Pre-Emergent",
             RecommendationCategory::GrubControl => "Grub Control",
+            RecommendationCategory::PestScouting => "Pest Scouting",
             RecommendationCategory::Fertilizer => "Fertilizer",
             RecommendationCategory::Fungicide => "Fungicide",
             RecommendationCategory::Overseeding => "Overseeding",
@@ -41,6 +45,7 @@ impl RecommendationCategory {
             RecommendationCategory::SoilTempForecast => "Soil Temp Forecast",
             RecommendationCategory::SoilTest => "Soil Test",
             RecommendationCategory::PlantMaintenance => "Plant Maintenance",
+            RecommendationCategory::Equipment => "Equipment",
             RecommendationCategory::General => "General",
         }
     }
@@ -71,12 +76,59 @@ impl Severity {
     }
 }
 
+impl std::str::FromStr for Severity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "info" => Ok(Severity::Info),
+            "advisory" => Ok(Severity::Advisory),
+            "warning" => Ok(Severity::Warning),
+            "critical" => Ok(Severity::Critical),
+            _ => Err(format!("Unknown severity: {}", s)),
+        }
+    }
+}
+
 impl std::fmt::Display for Severity {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.as_str())
     }
 }
 
+/// Number of recommendations at each severity.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct SeverityCounts {
+    pub critical: usize,
+    pub warning: usize,
+    pub advisory: usize,
+    pub info: usize,
+}
+
+impl SeverityCounts {
+    pub fn tally(recommendations: &[Recommendation]) -> Self {
+        let mut counts = Self::default();
+        for rec in recommendations {
+            match rec.severity {
+                Severity::Critical => counts.critical += 1,
+                Severity::Warning => counts.warning += 1,
+                Severity::Advisory => counts.advisory += 1,
+                Severity::Info => counts.info += 1,
+            }
+        }
+        counts
+    }
+}
+
+/// How a recommendation differs from when it was last viewed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RecommendationChange {
+    /// Not seen before.
+    New,
+    /// Seen before at a lower severity.
+    Escalated,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DataPoint {
     pub label: String,
@@ -104,6 +156,15 @@ pub struct Recommendation {
     pub explanation: String,
     pub data_points: Vec<DataPoint>,
     pub suggested_action: Option<String>,
+    /// Set by the engine when another active recommendation contradicts this one.
+    #[serde(default)]
+    pub conflict_note: Option<String>,
+    /// Set by the API layer when inventory on hand won't cover the application.
+    #[serde(default)]
+    pub inventory_note: Option<String>,
+    /// Set by the API layer against the severity last viewed; `None` when unchanged.
+    #[serde(default)]
+    pub change: Option<RecommendationChange>,
     pub created_at: DateTime<Utc>,
     pub dismissed: bool,
     pub addressed: bool,
@@ -126,6 +187,9 @@ impl Recommendation {
             explanation: String::new(),
             data_points: Vec::new(),
             suggested_action: None,
+            conflict_note: None,
+            inventory_note: None,
+            change: None,
             created_at: Utc::now(),
             dismissed: false,
             addressed: false,
@@ -156,3 +220,30 @@ impl Recommendation {
         !self.dismissed && !self.addressed
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn severity_counts_tally_each_level() {
+        let rec = |id: &str, severity| {
+            Recommendation::new(id, RecommendationCategory::Mowing, severity, "t", "d")
+        };
+        let counts = SeverityCounts::tally(&[
+            rec("a", Severity::Critical),
+            rec("b", Severity::Warning),
+            rec("c", Severity::Warning),
+            rec("d", Severity::Info),
+        ]);
+        assert_eq!(
+            counts,
+            SeverityCounts {
+                critical: 1,
+                warning: 2,
+                advisory: 0,
+                info: 1,
+            }
+        );
+    }
+}
@@ -0,0 +1,68 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// Band of the 0-100 turf stress index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum StressLevel {
+    Low,
+    Moderate,
+    High,
+    Severe,
+}
+
+impl StressLevel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StressLevel::Low => "Low",
+            StressLevel::Moderate => "Moderate",
+            StressLevel::High => "High",
+            StressLevel::Severe => "Severe",
+        }
+    }
+}
+
+impl std::fmt::Display for StressLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Per-factor scores (each 0-100) behind a day's index.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StressComponents {
+    pub heat: f64,
+    pub drought: f64,
+    pub disease: f64,
+    pub traffic: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DailyStress {
+    pub date: NaiveDate,
+    pub score: f64,
+    pub level: StressLevel,
+    pub components: StressComponents,
+    pub high_temp_f: f64,
+    /// Reference evapotranspiration (Hargreaves), mm.
+    pub et_mm: f64,
+    /// Rain plus logged irrigation, mm.
+    pub water_mm: f64,
+    /// Trailing-week ET not replaced by rain or irrigation, mm.
+    pub water_deficit_mm: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StressIndexSummary {
+    pub current: Option<DailyStress>,
+    /// Consecutive days, ending with `current`, at High or above.
+    pub sustained_high_days: u32,
+    pub days: Vec<DailyStress>,
+}
+
+/// The latest index as the rules see it (`EnvironmentalSummary.stress_index`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StressSnapshot {
+    pub score: f64,
+    pub level: StressLevel,
+    pub sustained_high_days: u32,
+}
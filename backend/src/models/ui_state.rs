@@ -0,0 +1,80 @@
+use super::ApplicationType;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// Where the user left the web UI, restored the next time it opens. Every
+/// field is optional; a missing one leaves that screen at its default.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UiState {
+    /// Route of the last screen shown, e.g. `/calendar`.
+    pub last_path: Option<String>,
+    pub applications_type: Option<ApplicationType>,
+    /// Custom type picked in the applications filter, in place of a built-in.
+    pub applications_custom_type: Option<i64>,
+    /// Applications scope filter: `all`, `turf` or `landscape`.
+    pub applications_scope: Option<String>,
+    /// Month the calendar was on, as `YYYY-MM`.
+    pub calendar_month: Option<String>,
+    /// Calendar layout: `month` grid or `week` agenda.
+    pub calendar_view: Option<String>,
+    /// Recommendation id open in the detail pane.
+    pub selected_recommendation: Option<String>,
+}
+
+impl UiState {
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(path) = &self.last_path {
+            // Same-origin routes only, so a restore can't redirect elsewhere
+            if !path.starts_with('/') || path.starts_with("//") || path.contains('\\') {
+                return Err(format!("last_path {:?} is not an app route", path));
+            }
+        }
+        if let Some(scope) = &self.applications_scope {
+            if !["all", "turf", "landscape"].contains(&scope.as_str()) {
+                return Err(format!(
+                    "applications_scope {:?} must be all, turf or landscape",
+                    scope
+                ));
+            }
+        }
+        if let Some(view) = &self.calendar_view {
+            if !["month", "week"].contains(&view.as_str()) {
+                return Err(format!("calendar_view {:?} must be month or week", view));
+            }
+        }
+        if let Some(month) = &self.calendar_month {
+            if NaiveDate::parse_from_str(&format!("{}-01", month), "%Y-%m-%d").is_err() {
+                return Err(format!("calendar_month {:?} must be YYYY-MM", month));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validates_routes_scopes_and_months() {
+        let state: UiState = serde_json::from_str(
+            r#"{"last_path": "/calendar", "calendar_month": "2026-10", "applications_type": "Fertilizer", "unknown": 1}"#,
+        )
+        .unwrap();
+        assert!(state.validate().is_ok());
+        assert_eq!(state.applications_type, Some(ApplicationType::Fertilizer));
+        assert!(UiState::default().validate().is_ok());
+
+        let with = |f: fn(&mut UiState)| {
+            let mut s = UiState::default();
+            f(&mut s);
+            s.validate()
+        };
+        assert!(with(|s| s.last_path = Some("https://example.com".into())).is_err());
+        assert!(with(|s| s.last_path = Some("//example.com".into())).is_err());
+        assert!(with(|s| s.applications_scope = Some("garden".into())).is_err());
+        assert!(with(|s| s.calendar_month = Some("2026-13".into())).is_err());
+        assert!(with(|s| s.calendar_view = Some("day".into())).is_err());
+    }
+}
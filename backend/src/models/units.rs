@@ -0,0 +1,180 @@
+//! Units of measure. Rules and calculations work in °F, millimeters of water
+//! and square feet; sources and reports don't always. These wrappers make each
+//! conversion an explicit method call: sources convert °C and inch readings
+//! through them on the way in, `calculations.rs` takes and returns them, and
+//! rules and reports format through them on the way out.
+//!
+//! They stop at those call sites. The stored model fields
+//! (`EnvironmentalReading`, `EnvironmentalSummary`) and the rule thresholds
+//! are still plain `f64`, with the unit carried in the name (`_f`, `_mm`,
+//! `_sqft`), so a comparison between them isn't checked by the compiler.
+//!
+//! All are `#[serde(transparent)]`, so they serialize as the bare number.
+//! `Display` appends the unit and honors precision: `{:.2}` of `Inches(0.5)`
+//! prints `0.50 in`.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+pub const MM_PER_INCH: f64 = 25.4;
+
+/// Write `value` with the formatter's precision (if any) and then `unit`.
+fn write_with_unit(f: &mut fmt::Formatter<'_>, value: f64, unit: &str) -> fmt::Result {
+    match f.precision() {
+        Some(p) => write!(f, "{:.*}{}", p, value, unit),
+        None => write!(f, "{}{}", value, unit),
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct DegreesF(pub f64);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct DegreesC(pub f64);
+
+impl DegreesF {
+    pub fn to_celsius(self) -> DegreesC {
+        DegreesC((self.0 - 32.0) * 5.0 / 9.0)
+    }
+}
+
+impl DegreesC {
+    pub fn to_fahrenheit(self) -> DegreesF {
+        DegreesF(self.0 * 9.0 / 5.0 + 32.0)
+    }
+}
+
+impl fmt::Display for DegreesF {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_with_unit(f, self.0, "°F")
+    }
+}
+
+impl fmt::Display for DegreesC {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_with_unit(f, self.0, "°C")
+    }
+}
+
+/// Depth of water: rain, irrigation, evapotranspiration.
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Millimeters(pub f64);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Inches(pub f64);
+
+impl Millimeters {
+    pub fn to_inches(self) -> Inches {
+        Inches(self.0 / MM_PER_INCH)
+    }
+}
+
+impl Inches {
+    pub fn to_millimeters(self) -> Millimeters {
+        Millimeters(self.0 * MM_PER_INCH)
+    }
+}
+
+impl fmt::Display for Millimeters {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_with_unit(f, self.0, " mm")
+    }
+}
+
+impl fmt::Display for Inches {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_with_unit(f, self.0, " in")
+    }
+}
+
+/// Lawn or product coverage area. Label rates are per 1,000 sq ft.
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SquareFeet(pub f64);
+
+impl SquareFeet {
+    /// Area in the 1,000 sq ft units label rates are given in.
+    pub fn thousands(self) -> f64 {
+        self.0 / 1000.0
+    }
+
+    /// Total for a `rate` given per 1,000 sq ft.
+    pub fn apply_rate(self, rate_per_ksqft: f64) -> f64 {
+        self.thousands() * rate_per_ksqft
+    }
+}
+
+impl fmt::Display for SquareFeet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_with_unit(f, self.0, " sq ft")
+    }
+}
+
+/// Soil moisture as a volume fraction (m³/m³, 0-1). Shown as a percentage.
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct VolumetricWaterContent(pub f64);
+
+impl VolumetricWaterContent {
+    pub fn percent(self) -> f64 {
+        self.0 * 100.0
+    }
+}
+
+impl fmt::Display for VolumetricWaterContent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_with_unit(f, self.percent(), "%")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn c_to_f(c: f64) -> f64 {
+        DegreesC(c).to_fahrenheit().0
+    }
+
+    #[test]
+    fn celsius_to_fahrenheit_known_values() {
+        // Freezing point of water
+        assert!((c_to_f(0.0) - 32.0).abs() < 0.001);
+        // Boiling point of water
+        assert!((c_to_f(100.0) - 212.0).abs() < 0.001);
+        // Body temperature
+        assert!((c_to_f(37.0) - 98.6).abs() < 0.1);
+        // -40 is same in both scales
+        assert!((c_to_f(-40.0) - (-40.0)).abs() < 0.001);
+        assert!((DegreesF(212.0).to_celsius().0 - 100.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn agronomic_temperatures() {
+        // Pre-emergent window: 50-60°F = ~10-15.5°C
+        assert!((c_to_f(10.0) - 50.0).abs() < 0.1);
+        assert!((c_to_f(15.5) - 59.9).abs() < 0.1);
+
+        // Grub control window: 60-75°F = ~15.5-24°C
+        assert!((c_to_f(15.5) - 59.9).abs() < 0.1);
+        assert!((c_to_f(24.0) - 75.2).abs() < 0.1);
+
+        // Heat stress threshold: 85°F = ~29.4°C
+        assert!((c_to_f(29.4) - 84.9).abs() < 0.2);
+    }
+
+    #[test]
+    fn conversions_round_trip_and_display_with_units() {
+        assert_eq!(Inches(1.0).to_millimeters(), Millimeters(25.4));
+        assert!((Millimeters(12.7).to_inches().0 - 0.5).abs() < 1e-9);
+        assert_eq!(SquareFeet(5000.0).apply_rate(0.75), 3.75);
+
+        assert_eq!(format!("{:.2}", Millimeters(12.7).to_inches()), "0.50 in");
+        assert_eq!(format!("{:.0}", DegreesF(72.4)), "72°F");
+        assert_eq!(format!("{:.0}", VolumetricWaterContent(0.22)), "22%");
+        assert_eq!(serde_json::to_string(&Millimeters(3.5)).unwrap(), "3.5");
+    }
+}
@@ -1,6 +1,11 @@
-use crate::datasources::OpenRouterClient;
+use crate::datasources::{HardinessZoneClient, OpenRouterClient};
+use crate::logic::api_quota::ApiQuota;
+use crate::logic::connectivity::ConnectionMonitor;
 use crate::logic::data_sync::DataSyncService;
+use crate::logic::gauge_ranges::GaugeRangeCache;
 use crate::logic::rules::RulesEngine;
+use crate::models::DashboardLayout;
+use std::path::PathBuf;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
@@ -9,7 +14,18 @@ pub struct AppState {
     pub pool: sqlx::PgPool,
     pub rules_engine: Arc<RulesEngine>,
     pub sync_service: Arc<RwLock<DataSyncService>>,
+    /// OpenWeatherMap calls today, readable without the sync service lock.
+    pub forecast_quota: Option<Arc<ApiQuota>>,
+    /// Latest background datasource check.
+    pub connections: Arc<ConnectionMonitor>,
+    /// Dashboard gauge scales fitted to each profile's station history.
+    pub gauge_ranges: Arc<GaugeRangeCache>,
     pub openrouter: Option<Arc<OpenRouterClient>>,
+    pub hardiness: Arc<HardinessZoneClient>,
+    /// Directory of the rotating log file, when file logging is on.
+    pub log_dir: Option<PathBuf>,
+    /// Layout from `DASHBOARD_PANELS`, which takes the place of the saved one.
+    pub dashboard_layout: Option<DashboardLayout>,
 }
 
 impl AppState {
@@ -17,12 +33,29 @@ impl AppState {
         pool: sqlx::PgPool,
         sync_service: DataSyncService,
         openrouter: Option<OpenRouterClient>,
+        rules_engine: RulesEngine,
     ) -> Self {
         Self {
             pool,
-            rules_engine: Arc::new(RulesEngine::new()),
+            rules_engine: Arc::new(rules_engine),
+            forecast_quota: sync_service.forecast_quota(),
             sync_service: Arc::new(RwLock::new(sync_service)),
+            connections: Arc::new(ConnectionMonitor::default()),
+            gauge_ranges: Arc::new(GaugeRangeCache::default()),
             openrouter: openrouter.map(Arc::new),
+            hardiness: Arc::new(HardinessZoneClient::new()),
+            log_dir: None,
+            dashboard_layout: None,
         }
     }
+
+    pub fn with_log_dir(mut self, log_dir: Option<PathBuf>) -> Self {
+        self.log_dir = log_dir;
+        self
+    }
+
+    pub fn with_dashboard_layout(mut self, layout: Option<DashboardLayout>) -> Self {
+        self.dashboard_layout = layout;
+        self
+    }
 }
@@ -0,0 +1,521 @@
+//! Test fixtures: builders for environmental summaries, forecasts, applications
+//! and profiles, and rule scenarios (`tests/scenarios/*.yaml`).
+//!
+//! Everything is pinned to caller-supplied dates rather than `Utc::now()` so
+//! snapshot tests (`insta::assert_json_snapshot!`) produce the same output on
+//! every run.
+
+use crate::clock;
+use crate::logic::rules::engine::RuleTrace;
+use crate::logic::rules::RulesEngine;
+use crate::logic::soil_temp_prediction::predict_threshold_crossings;
+use crate::models::soil_temp_prediction::{PredictionConfidence, SoilTempPrediction};
+use crate::models::{
+    AirQuality, AirQualityLevel, Application, ApplicationType, DailyForecast, DataSource,
+    DroughtCategory, DroughtCoverage, DroughtStatus, EnvironmentalReading, EnvironmentalSummary,
+    ForecastLocation, ForecastPoint, GrassType, LawnProfile, SoilType, StressSnapshot, Trend,
+    WeatherCondition, WeatherForecast,
+};
+use chrono::{DateTime, Duration, NaiveDate, TimeZone, Utc};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+/// Noon UTC on the given day.
+pub fn at(year: i32, month: u32, day: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(year, month, day, 12, 0, 0).unwrap()
+}
+
+pub fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+pub fn profile(grass_type: GrassType) -> LawnProfile {
+    let created = at(2025, 1, 1);
+    LawnProfile {
+        id: Some(1),
+        name: "Test".into(),
+        grass_type,
+        usda_zone: "7a".into(),
+        soil_type: None,
+        lawn_size_sqft: Some(5000.0),
+        irrigation_type: None,
+        location: None,
+        noaa_station_wbanno: None,
+        dormant: false,
+        mowing_height_in: None,
+        created_at: created,
+        updated_at: created,
+    }
+}
+
+pub fn application(kind: ApplicationType, on: NaiveDate) -> Application {
+    Application {
+        id: None,
+        lawn_profile_id: 1,
+        application_type: kind,
+        product_name: None,
+        application_date: on,
+        rate_per_1000sqft: None,
+        coverage_sqft: None,
+        notes: None,
+        weather_snapshot: None,
+        nitrogen_pct: None,
+        phosphorus_pct: None,
+        potassium_pct: None,
+        plant_id: None,
+        follow_up_date: None,
+        area_id: None,
+        equipment_id: None,
+        custom_type_id: None,
+        created_at: at(2025, 1, 1),
+    }
+}
+
+/// Builds an `EnvironmentalSummary` around a single current reading.
+pub struct EnvBuilder {
+    summary: EnvironmentalSummary,
+}
+
+impl EnvBuilder {
+    pub fn new(observed: DateTime<Utc>) -> Self {
+        let mut reading = EnvironmentalReading::new(DataSource::SoilData);
+        reading.timestamp = observed;
+        Self {
+            summary: EnvironmentalSummary {
+                current: Some(reading),
+                last_updated: Some(observed),
+                ..Default::default()
+            },
+        }
+    }
+
+    fn reading(&mut self) -> &mut EnvironmentalReading {
+        self.summary
+            .current
+            .as_mut()
+            .expect("builder always has a reading")
+    }
+
+    /// Current 10cm soil temperature and its 7-day average.
+    pub fn soil_temp(mut self, current_f: f64, avg_7day_f: f64) -> Self {
+        self.reading().soil_temp_10_f = Some(current_f);
+        self.summary.soil_temp_7day_avg_f = Some(avg_7day_f);
+        self
+    }
+
+    pub fn soil_moisture(mut self, moisture: f64) -> Self {
+        self.reading().soil_moisture_10 = Some(moisture);
+        self
+    }
+
+    pub fn air(mut self, temp_f: f64, humidity: f64) -> Self {
+        let reading = self.reading();
+        reading.ambient_temp_f = Some(temp_f);
+        reading.humidity_percent = Some(humidity);
+        self
+    }
+
+    pub fn weekly(mut self, ambient_avg_f: f64, humidity_avg: f64, precip_mm: f64) -> Self {
+        self.summary.ambient_temp_7day_avg_f = Some(ambient_avg_f);
+        self.summary.humidity_7day_avg = Some(humidity_avg);
+        self.summary.precipitation_7day_total_mm = Some(precip_mm);
+        self
+    }
+
+    pub fn soil_trend(mut self, trend: Trend) -> Self {
+        self.summary.soil_temp_trend = trend;
+        self
+    }
+
+    /// Modeled soil temperatures for the days after `today`, one per day.
+    pub fn soil_predictions(mut self, today: NaiveDate, temps_f: &[f64]) -> Self {
+        let predictions = temps_f
+            .iter()
+            .zip(1..)
+            .map(|(&temp_f, i)| SoilTempPrediction {
+                date: today + Duration::days(i),
+                predicted_soil_temp_f: temp_f,
+                confidence: PredictionConfidence::High,
+                air_temp_used_f: temp_f + 10.0,
+                source_description: "fixture".into(),
+            })
+            .collect();
+        self.summary.soil_temp_predictions = Some(predictions);
+        self
+    }
+
+    pub fn gdd(mut self, gdd_ytd: f64) -> Self {
+        self.summary.gdd_base50_ytd = Some(gdd_ytd);
+        self
+    }
+
+    /// Latest stress index score, High or worse for `sustained_days` running.
+    pub fn stress(mut self, score: f64, sustained_days: u32) -> Self {
+        self.summary.stress_index = Some(StressSnapshot {
+            score,
+            level: crate::logic::stress_index::level_for(score),
+            sustained_high_days: sustained_days,
+        });
+        self
+    }
+
+    /// Drought Monitor status with `category` covering the whole county.
+    pub fn drought(mut self, category: DroughtCategory) -> Self {
+        let share = |c: DroughtCategory| if category >= c { 100.0 } else { 0.0 };
+        let map_date = self.reading().timestamp.date_naive();
+        self.summary.drought = Some(DroughtStatus {
+            category,
+            worst_category: category,
+            coverage: DroughtCoverage {
+                d0: share(DroughtCategory::D0),
+                d1: share(DroughtCategory::D1),
+                d2: share(DroughtCategory::D2),
+                d3: share(DroughtCategory::D3),
+                d4: share(DroughtCategory::D4),
+            },
+            map_date,
+            county_fips: "42029".into(),
+            county: "Chester County, PA".into(),
+        });
+        self
+    }
+
+    pub fn air_quality(mut self, level: AirQualityLevel) -> Self {
+        self.summary.air_quality = Some(AirQuality {
+            level,
+            pm2_5: None,
+            pm10: None,
+            o3: None,
+            observed_at: self.reading().timestamp,
+        });
+        self
+    }
+
+    pub fn forecast(mut self, forecast: WeatherForecast) -> Self {
+        self.summary.forecast = Some(forecast);
+        self
+    }
+
+    pub fn build(self) -> EnvironmentalSummary {
+        self.summary
+    }
+}
+
+/// Builds a `WeatherForecast` one day at a time, with eight 3-hour points per day.
+pub struct ForecastBuilder {
+    next: NaiveDate,
+    forecast: WeatherForecast,
+}
+
+/// Share of the day's low-to-high swing at each 3-hour step (00:00-21:00 local).
+const DIURNAL_SHAPE: [f64; 8] = [0.15, 0.0, 0.1, 0.5, 0.9, 1.0, 0.7, 0.35];
+
+impl ForecastBuilder {
+    pub fn starting(first_day: NaiveDate) -> Self {
+        Self {
+            next: first_day,
+            forecast: WeatherForecast {
+                fetched_at: first_day.and_hms_opt(0, 0, 0).unwrap().and_utc(),
+                location: ForecastLocation {
+                    city: "Test".into(),
+                    country: "US".into(),
+                    latitude: 39.9,
+                    longitude: -75.4,
+                },
+                hourly: Vec::new(),
+                daily_summary: Vec::new(),
+                minutely: Vec::new(),
+            },
+        }
+    }
+
+    /// Append a day; rain is spread evenly over its eight points.
+    pub fn day(mut self, high_f: f64, low_f: f64, humidity: f64, rain_mm: f64) -> Self {
+        let condition = if rain_mm > 0.0 {
+            WeatherCondition::Rain
+        } else {
+            WeatherCondition::Clear
+        };
+        let rain_prob = if rain_mm > 0.0 { 0.8 } else { 0.0 };
+        let midnight = self.next.and_hms_opt(0, 0, 0).unwrap().and_utc();
+
+        for (i, shape) in DIURNAL_SHAPE.iter().enumerate() {
+            let temp_f = low_f + (high_f - low_f) * shape;
+            self.forecast.hourly.push(ForecastPoint {
+                timestamp: midnight + Duration::hours(3 * i as i64),
+                temp_f,
+                feels_like_f: temp_f,
+                humidity_percent: humidity,
+                precipitation_mm: rain_mm / DIURNAL_SHAPE.len() as f64,
+                snow_mm: 0.0,
+                precipitation_prob: rain_prob,
+                wind_speed_mph: 5.0,
+                wind_gust_mph: None,
+                cloud_cover_percent: if rain_mm > 0.0 { 90.0 } else { 10.0 },
+                weather_condition: condition,
+            });
+        }
+        self.forecast.daily_summary.push(DailyForecast {
+            date: self.next,
+            high_temp_f: high_f,
+            low_temp_f: low_f,
+            avg_humidity: humidity,
+            total_precipitation_mm: rain_mm,
+            total_snow_mm: 0.0,
+            max_precipitation_prob: rain_prob,
+            dominant_condition: condition,
+            avg_wind_speed_mph: 5.0,
+            max_wind_gust_mph: None,
+        });
+
+        self.next += Duration::days(1);
+        self
+    }
+
+    /// Turn the last day's precipitation into (or add) snow.
+    pub fn snow(mut self, snow_mm: f64) -> Self {
+        let day = self
+            .forecast
+            .daily_summary
+            .last_mut()
+            .expect("snow() follows day()");
+        day.total_snow_mm = snow_mm;
+        day.total_precipitation_mm = day.total_precipitation_mm.max(snow_mm);
+        day.dominant_condition = WeatherCondition::Snow;
+        let per_point = snow_mm / DIURNAL_SHAPE.len() as f64;
+        for point in self
+            .forecast
+            .hourly
+            .iter_mut()
+            .rev()
+            .take(DIURNAL_SHAPE.len())
+        {
+            point.snow_mm = per_point;
+            point.precipitation_mm = point.precipitation_mm.max(per_point);
+            point.weather_condition = WeatherCondition::Snow;
+        }
+        self
+    }
+
+    /// Set the last day's average wind.
+    pub fn wind(mut self, avg_mph: f64) -> Self {
+        if let Some(day) = self.forecast.daily_summary.last_mut() {
+            day.avg_wind_speed_mph = avg_mph;
+        }
+        for point in self
+            .forecast
+            .hourly
+            .iter_mut()
+            .rev()
+            .take(DIURNAL_SHAPE.len())
+        {
+            point.wind_speed_mph = avg_mph;
+        }
+        self
+    }
+
+    pub fn build(self) -> WeatherForecast {
+        self.forecast
+    }
+}
+
+/// A lawn on a given day: profile, conditions and application history, run
+/// through the rules engine at local noon of that day.
+pub struct Scenario {
+    pub name: String,
+    pub today: NaiveDate,
+    pub profile: LawnProfile,
+    pub env: EnvironmentalSummary,
+    pub history: Vec<Application>,
+}
+
+impl Scenario {
+    pub fn new(
+        name: impl Into<String>,
+        today: NaiveDate,
+        grass_type: GrassType,
+        env: EnvironmentalSummary,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            today,
+            profile: profile(grass_type),
+            env,
+            history: Vec::new(),
+        }
+    }
+
+    pub fn soil(mut self, soil_type: SoilType) -> Self {
+        self.profile.soil_type = Some(soil_type);
+        self
+    }
+
+    pub fn applied(mut self, kind: ApplicationType, on: NaiveDate) -> Self {
+        self.history.push(application(kind, on));
+        self
+    }
+
+    /// Fertilizer with enough detail for the nitrogen math.
+    pub fn fertilized(mut self, on: NaiveDate, nitrogen_pct: f64, rate_per_1000sqft: f64) -> Self {
+        let mut app = application(ApplicationType::Fertilizer, on);
+        app.nitrogen_pct = Some(nitrogen_pct);
+        app.rate_per_1000sqft = Some(rate_per_1000sqft);
+        self.history.push(app);
+        self
+    }
+
+    /// Every rule's outcome on the scenario's day.
+    pub fn trace(&self, engine: &RulesEngine) -> Vec<RuleTrace> {
+        engine.explain(
+            &self.env,
+            &self.profile,
+            &self.history,
+            clock::local_noon(self.today),
+        )
+    }
+}
+
+/// A [`Scenario`] as stored under `tests/scenarios/`, with the expected outcome
+/// per rule: a severity (`"Warning"`) when it should fire, a gate kind
+/// (`"OutOfSeason"`) or `"Silent"` when it shouldn't. Rules left out aren't checked.
+#[derive(Debug, Deserialize)]
+pub struct ScenarioFixture {
+    pub name: String,
+    pub date: NaiveDate,
+    pub grass: GrassType,
+    pub soil_type: Option<SoilType>,
+    pub soil_temp: Option<SoilTempFixture>,
+    pub soil_moisture: Option<f64>,
+    pub air: Option<AirFixture>,
+    pub weekly: Option<WeeklyFixture>,
+    pub soil_trend: Option<Trend>,
+    pub gdd: Option<f64>,
+    /// County Drought Monitor category.
+    pub drought: Option<DroughtCategory>,
+    /// OWM air quality level.
+    pub air_quality: Option<AirQualityLevel>,
+    /// Modeled soil temps for the days after `date`.
+    #[serde(default)]
+    pub soil_predictions: Vec<f64>,
+    /// Forecast days starting on `date`.
+    #[serde(default)]
+    pub forecast: Vec<ForecastDayFixture>,
+    #[serde(default)]
+    pub applications: Vec<ApplicationFixture>,
+    pub expect: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SoilTempFixture {
+    pub current_f: f64,
+    pub avg_7day_f: f64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AirFixture {
+    pub temp_f: f64,
+    pub humidity: f64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WeeklyFixture {
+    pub ambient_avg_f: f64,
+    pub humidity_avg: f64,
+    pub precip_mm: f64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ForecastDayFixture {
+    pub high_f: f64,
+    pub low_f: f64,
+    pub humidity: f64,
+    #[serde(default)]
+    pub rain_mm: f64,
+    #[serde(default)]
+    pub snow_mm: f64,
+    pub wind_mph: Option<f64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ApplicationFixture {
+    #[serde(rename = "type")]
+    pub kind: ApplicationType,
+    pub date: NaiveDate,
+    pub nitrogen_pct: Option<f64>,
+    pub rate_per_1000sqft: Option<f64>,
+}
+
+impl ScenarioFixture {
+    pub fn into_scenario(self) -> Scenario {
+        let mut env = EnvBuilder::new(
+            self.date
+                .and_hms_opt(12, 0, 0)
+                .expect("noon is a valid time")
+                .and_utc(),
+        );
+        if let Some(t) = self.soil_temp {
+            env = env.soil_temp(t.current_f, t.avg_7day_f);
+        }
+        if let Some(m) = self.soil_moisture {
+            env = env.soil_moisture(m);
+        }
+        if let Some(a) = self.air {
+            env = env.air(a.temp_f, a.humidity);
+        }
+        if let Some(w) = self.weekly {
+            env = env.weekly(w.ambient_avg_f, w.humidity_avg, w.precip_mm);
+        }
+        if let Some(trend) = self.soil_trend {
+            env = env.soil_trend(trend);
+        }
+        if let Some(gdd) = self.gdd {
+            env = env.gdd(gdd);
+        }
+        if let Some(category) = self.drought {
+            env = env.drought(category);
+        }
+        if let Some(level) = self.air_quality {
+            env = env.air_quality(level);
+        }
+        if !self.soil_predictions.is_empty() {
+            env = env.soil_predictions(self.date, &self.soil_predictions);
+        }
+        if !self.forecast.is_empty() {
+            let mut forecast = ForecastBuilder::starting(self.date);
+            for d in &self.forecast {
+                forecast = forecast.day(d.high_f, d.low_f, d.humidity, d.rain_mm);
+                if d.snow_mm > 0.0 {
+                    forecast = forecast.snow(d.snow_mm);
+                }
+                if let Some(mph) = d.wind_mph {
+                    forecast = forecast.wind(mph);
+                }
+            }
+            env = env.forecast(forecast.build());
+        }
+
+        let mut env = env.build();
+        // Crossings come with the predictions, as they do from a refresh
+        if let (Some(predictions), Some(current)) = (
+            env.soil_temp_predictions.as_ref(),
+            env.current.as_ref().and_then(|c| c.soil_temp_10_f),
+        ) {
+            env.predicted_threshold_crossings =
+                Some(predict_threshold_crossings(current, predictions, self.date));
+        }
+
+        let mut scenario = Scenario::new(self.name, self.date, self.grass, env);
+        if let Some(soil) = self.soil_type {
+            scenario = scenario.soil(soil);
+        }
+        for app in self.applications {
+            scenario = match (app.nitrogen_pct, app.rate_per_1000sqft) {
+                (Some(n), Some(rate)) if app.kind == ApplicationType::Fertilizer => {
+                    scenario.fertilized(app.date, n, rate)
+                }
+                _ => scenario.applied(app.kind, app.date),
+            };
+        }
+        scenario
+    }
+}
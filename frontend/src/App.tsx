@@ -1,12 +1,22 @@
-import { Component, lazy, Suspense, type ReactNode } from 'react';
-import { BrowserRouter, Link, Route, Routes } from 'react-router-dom';
+import { Component, lazy, Suspense, useEffect, type ReactNode } from 'react';
+import { BrowserRouter, Link, Route, Routes, useLocation, useNavigate } from 'react-router-dom';
 import Layout from './components/Layout';
+import RefreshProvider from './components/RefreshProvider';
+import ToastProvider from './components/ToastProvider';
+import { t } from './utils/i18n';
+import { rememberUi, uiState } from './utils/uiState';
 
 const Dashboard = lazy(() => import('./pages/Dashboard'));
+const ActivityLog = lazy(() => import('./pages/ActivityLog'));
+const AnnualReview = lazy(() => import('./pages/AnnualReview'));
 const Applications = lazy(() => import('./pages/Applications'));
 const Calendar = lazy(() => import('./pages/Calendar'));
 const Environmental = lazy(() => import('./pages/Environmental'));
+const Inventory = lazy(() => import('./pages/Inventory'));
+const Equipment = lazy(() => import('./pages/Equipment'));
 const Landscape = lazy(() => import('./pages/Landscape'));
+const Observations = lazy(() => import('./pages/Observations'));
+const Photos = lazy(() => import('./pages/Photos'));
 const Recommendations = lazy(() => import('./pages/Recommendations'));
 const SoilTests = lazy(() => import('./pages/SoilTests'));
 const SeasonalPlan = lazy(() => import('./pages/SeasonalPlan'));
@@ -27,7 +37,7 @@ class ErrorBoundary extends Component<
       return (
         <div style={{ padding: '2rem', fontFamily: 'system-ui' }}>
           <h1 style={{ color: '#e53e3e', fontSize: '1.5rem' }}>
-            Something went wrong
+            {t('Something went wrong')}
           </h1>
           <p style={{ color: '#4a5568', margin: '1rem 0' }}>
             {this.state.error.message}
@@ -46,7 +56,7 @@ class ErrorBoundary extends Component<
               cursor: 'pointer',
             }}
           >
-            Return to Dashboard
+            {t('Return to Dashboard')}
           </button>
         </div>
       );
@@ -58,45 +68,81 @@ class ErrorBoundary extends Component<
 function NotFound() {
   return (
     <div style={{ padding: '2rem', textAlign: 'center' }}>
-      <h1 style={{ fontSize: '1.5rem', color: '#2d3748' }}>Page Not Found</h1>
+      <h1 style={{ fontSize: '1.5rem', color: '#2d3748' }}>{t('Page Not Found')}</h1>
       <p style={{ color: '#718096', margin: '1rem 0' }}>
-        The page you're looking for doesn't exist.
+        {t("The page you're looking for doesn't exist.")}
       </p>
       <Link
         to="/"
         style={{ color: '#3182ce', textDecoration: 'underline' }}
       >
-        Go to Dashboard
+        {t('Go to Dashboard')}
       </Link>
     </div>
   );
 }
 
-const routeFallback = (
-  <div style={{ padding: '2rem', color: '#718096' }}>Loading...</div>
-);
+// Set once the saved screen has been restored, so StrictMode's second mount
+// (and later visits to the Dashboard) don't redirect again.
+let restored = false;
+
+/** Reopen the last screen when the app starts at `/`, then track the route. */
+function SessionRestore() {
+  const location = useLocation();
+  const navigate = useNavigate();
+
+  useEffect(() => {
+    if (restored) return;
+    restored = true;
+    const last = uiState().last_path;
+    if (location.pathname === '/' && !location.search && last && last !== '/') {
+      navigate(last, { replace: true });
+    }
+  }, [location, navigate]);
+
+  useEffect(() => {
+    if (restored) rememberUi({ last_path: location.pathname });
+  }, [location.pathname]);
+
+  return null;
+}
+
+function RouteFallback() {
+  return <div style={{ padding: '2rem', color: '#718096' }}>{t('Loading...')}</div>;
+}
 
 export default function App() {
   return (
     <ErrorBoundary>
-      <BrowserRouter>
-        <Suspense fallback={routeFallback}>
-          <Routes>
-            <Route element={<Layout />}>
-              <Route index element={<Dashboard />} />
-              <Route path="applications" element={<Applications />} />
-              <Route path="landscape" element={<Landscape />} />
-              <Route path="calendar" element={<Calendar />} />
-              <Route path="environmental" element={<Environmental />} />
-              <Route path="recommendations" element={<Recommendations />} />
-              <Route path="soil-tests" element={<SoilTests />} />
-              <Route path="seasonal-plan" element={<SeasonalPlan />} />
-              <Route path="settings" element={<Settings />} />
-              <Route path="*" element={<NotFound />} />
-            </Route>
-          </Routes>
-        </Suspense>
-      </BrowserRouter>
+      <ToastProvider>
+        <RefreshProvider>
+          <BrowserRouter>
+            <SessionRestore />
+            <Suspense fallback={<RouteFallback />}>
+              <Routes>
+                <Route element={<Layout />}>
+                  <Route index element={<Dashboard />} />
+                  <Route path="applications" element={<Applications />} />
+                  <Route path="inventory" element={<Inventory />} />
+                  <Route path="equipment" element={<Equipment />} />
+                  <Route path="landscape" element={<Landscape />} />
+                  <Route path="calendar" element={<Calendar />} />
+                  <Route path="journal" element={<Observations />} />
+                  <Route path="photos" element={<Photos />} />
+                  <Route path="environmental" element={<Environmental />} />
+                  <Route path="recommendations" element={<Recommendations />} />
+                  <Route path="soil-tests" element={<SoilTests />} />
+                  <Route path="seasonal-plan" element={<SeasonalPlan />} />
+                  <Route path="review" element={<AnnualReview />} />
+                  <Route path="activity" element={<ActivityLog />} />
+                  <Route path="settings" element={<Settings />} />
+                  <Route path="*" element={<NotFound />} />
+                </Route>
+              </Routes>
+            </Suspense>
+          </BrowserRouter>
+        </RefreshProvider>
+      </ToastProvider>
     </ErrorBoundary>
   );
 }
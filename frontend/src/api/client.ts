@@ -1,25 +1,105 @@
 import type {
+  ActivityEvent,
+  AgendaResponse,
+  AnnualReview,
   Application,
+  ApplicationReview,
+  BacktestReport,
   CalendarResponse,
+  CustomApplicationType,
+  DashboardLayout,
+  DashboardPanel,
   DashboardResponse,
+  EfficacyReport,
   EnvironmentalSummary,
+  Equipment,
+  FrostDates,
+  GaugeRanges,
   GddSummary,
   HealthResponse,
   HistoricalData,
+  InventoryItem,
+  LabelEntry,
+  LabelFormat,
+  LabelImportReport,
+  LabelProduct,
+  LawnArea,
   LawnProfile,
+  Location,
+  LogTail,
   NitrogenBudget,
+  Observation,
+  Photo,
   Plant,
   PlantType,
   Recommendation,
+  ReviewAdjustment,
+  ReviewObservations,
+  RiskPosture,
+  RuleStatus,
+  RuleTrace,
+  SeasonImportSummary,
   SeasonalPlan,
   SoilTempForecast,
   SoilTest,
   SoilTestSummary,
+  StressIndexSummary,
+  TypeEntry,
+  UiCatalog,
+  UiState,
 } from '../types';
 
 const BASE = '/api/v1';
 const DEFAULT_TIMEOUT_MS = 15_000;
 
+/** Error category returned by the API alongside the message. */
+export type ApiErrorKind =
+  | 'not_found'
+  | 'invalid_data'
+  | 'data_source_unavailable'
+  | 'database_busy'
+  | 'timeout'
+  | 'internal'
+  | 'unknown';
+
+/** A non-2xx API response. `message` is the server's error text. */
+export class ApiError extends Error {
+  readonly status: number;
+  readonly kind: ApiErrorKind;
+
+  constructor(status: number, kind: ApiErrorKind, message: string) {
+    super(message);
+    this.name = 'ApiError';
+    this.status = status;
+    this.kind = kind;
+  }
+}
+
+/** True when the request failed only because an optional data source isn't set up. */
+export function isUnavailable(e: unknown): boolean {
+  return e instanceof ApiError && e.kind === 'data_source_unavailable';
+}
+
+/** Message suitable for showing to the user. */
+export function errorMessage(e: unknown, fallback: string): string {
+  return e instanceof Error && e.message ? e.message : fallback;
+}
+
+function parseErrorBody(status: number, body: string): ApiError {
+  try {
+    const parsed = JSON.parse(body);
+    if (typeof parsed?.error === 'string') {
+      return new ApiError(status, parsed.kind ?? 'unknown', parsed.error);
+    }
+  } catch {
+    // Not JSON (proxy error page, plain text); fall through
+  }
+  // Sanitize: strip potential stack traces/internal paths, limit length
+  const sanitized = body.length > 200 ? body.slice(0, 200) + '...' : body;
+  const safeMessage = sanitized.replace(/\/[^\s:]+\.(rs|js|ts):\d+/g, '[internal]');
+  return new ApiError(status, 'unknown', `${status}: ${safeMessage}`);
+}
+
 async function fetchJson<T>(
   url: string,
   init?: RequestInit,
@@ -35,11 +115,7 @@ async function fetchJson<T>(
       ...init,
     });
     if (!res.ok) {
-      const body = await res.text();
-      // Sanitize: strip potential stack traces/internal paths, limit length
-      const sanitized = body.length > 200 ? body.slice(0, 200) + '...' : body;
-      const safeMessage = sanitized.replace(/\/[^\s:]+\.(rs|js|ts):\d+/g, '[internal]');
-      throw new Error(`${res.status}: ${safeMessage}`);
+      throw parseErrorBody(res.status, await res.text());
     }
     // 204 No Content
     if (res.status === 204) return undefined as unknown as T;
@@ -57,10 +133,29 @@ async function fetchJson<T>(
 // Health
 export const getHealth = () => fetchJson<HealthResponse>(`${BASE}/health`);
 
+export const getLocale = () => fetchJson<UiCatalog>(`${BASE}/locale`);
+
+export const getUiState = () => fetchJson<UiState>(`${BASE}/ui-state`);
+
+export const saveUiState = (state: UiState) =>
+  fetchJson<UiState>(`${BASE}/ui-state`, {
+    method: 'PUT',
+    body: JSON.stringify(state),
+  });
+
 // Dashboard
 export const getDashboard = () =>
   fetchJson<DashboardResponse>(`${BASE}/dashboard`);
 
+export const getDashboardLayout = () =>
+  fetchJson<DashboardLayout>(`${BASE}/dashboard/layout`);
+
+export const saveDashboardLayout = (panels: DashboardPanel[]) =>
+  fetchJson<DashboardLayout>(`${BASE}/dashboard/layout`, {
+    method: 'PUT',
+    body: JSON.stringify({ panels }),
+  });
+
 // Profile
 export const getProfile = () => fetchJson<LawnProfile>(`${BASE}/profile`);
 
@@ -70,10 +165,31 @@ export const updateProfile = (data: Partial<LawnProfile>) =>
     body: JSON.stringify(data),
   });
 
+export const listProfiles = () => fetchJson<LawnProfile[]>(`${BASE}/profiles`);
+
+export const createProfile = (data: { name: string; grass_type: string; usda_zone: string }) =>
+  fetchJson<LawnProfile>(`${BASE}/profiles`, {
+    method: 'POST',
+    body: JSON.stringify(data),
+  });
+
+export const setActiveProfile = (id: number) =>
+  fetchJson<LawnProfile>(`${BASE}/profiles/active`, {
+    method: 'PUT',
+    body: JSON.stringify({ id }),
+  });
+
+export const deleteProfile = (id: number) =>
+  fetchJson<void>(`${BASE}/profiles/${id}`, { method: 'DELETE' });
+
 // Applications
-export const getApplications = (type?: string) => {
-  const params = type ? `?type=${encodeURIComponent(type)}` : '';
-  return fetchJson<Application[]>(`${BASE}/applications${params}`);
+export const getApplications = (type?: string, areaId?: number, customTypeId?: number) => {
+  const params = new URLSearchParams();
+  if (type) params.set('type', type);
+  if (areaId != null) params.set('area_id', String(areaId));
+  if (customTypeId != null) params.set('custom_type_id', String(customTypeId));
+  const qs = params.toString();
+  return fetchJson<Application[]>(`${BASE}/applications${qs ? `?${qs}` : ''}`);
 };
 
 export const createApplication = (data: {
@@ -88,6 +204,9 @@ export const createApplication = (data: {
   potassium_pct?: number;
   plant_id?: number | null;
   follow_up_date?: string | null;
+  area_id?: number | null;
+  equipment_id?: number | null;
+  custom_type_id?: number | null;
 }) =>
   fetchJson<Application>(`${BASE}/applications`, {
     method: 'POST',
@@ -108,6 +227,9 @@ export const updateApplication = (
     potassium_pct?: number;
     plant_id?: number | null;
     follow_up_date?: string | null;
+    area_id?: number | null;
+    equipment_id?: number | null;
+    custom_type_id?: number | null;
   }
 ) =>
   fetchJson<Application>(`${BASE}/applications/${id}`, {
@@ -118,7 +240,162 @@ export const updateApplication = (
 export const deleteApplication = (id: number) =>
   fetchJson<void>(`${BASE}/applications/${id}`, { method: 'DELETE' });
 
+export const backfillApplicationWeather = () =>
+  fetchJson<{ updated: number; missing: number }>(`${BASE}/applications/backfill-weather`, {
+    method: 'POST',
+  });
+
+// Application types
+type CustomTypeData = {
+  name: string;
+  color: string;
+  base_type: string;
+};
+
+export const listApplicationTypes = () =>
+  fetchJson<{ types: TypeEntry[]; custom: CustomApplicationType[] }>(
+    `${BASE}/application-types`
+  );
+
+export const createCustomType = (data: CustomTypeData) =>
+  fetchJson<CustomApplicationType>(`${BASE}/application-types`, {
+    method: 'POST',
+    body: JSON.stringify(data),
+  });
+
+export const updateCustomType = (id: number, data: CustomTypeData) =>
+  fetchJson<CustomApplicationType>(`${BASE}/application-types/${id}`, {
+    method: 'PUT',
+    body: JSON.stringify(data),
+  });
+
+export const deleteCustomType = (id: number) =>
+  fetchJson<void>(`${BASE}/application-types/${id}`, { method: 'DELETE' });
+
+// Product label catalog
+export const listLabelProducts = () => fetchJson<LabelProduct[]>(`${BASE}/products`);
+
+export const createLabelProduct = (data: LabelEntry) =>
+  fetchJson<LabelProduct>(`${BASE}/products`, {
+    method: 'POST',
+    body: JSON.stringify(data),
+  });
+
+export const updateLabelProduct = (id: number, data: LabelEntry) =>
+  fetchJson<LabelProduct>(`${BASE}/products/${id}`, {
+    method: 'PUT',
+    body: JSON.stringify(data),
+  });
+
+export const deleteLabelProduct = (id: number) =>
+  fetchJson<void>(`${BASE}/products/${id}`, { method: 'DELETE' });
+
+export const importLabelProducts = (format: LabelFormat, content: string) =>
+  fetchJson<LabelImportReport>(`${BASE}/products/import`, {
+    method: 'POST',
+    body: JSON.stringify({ format, content }),
+  });
+
+// Lawn areas
+type AreaData = {
+  name: string;
+  size_sqft?: number;
+  sun_exposure: string;
+  grass_type?: string;
+  grass_mix?: string;
+};
+
+export const listAreas = () => fetchJson<LawnArea[]>(`${BASE}/areas`);
+
+export const createArea = (data: AreaData) =>
+  fetchJson<LawnArea>(`${BASE}/areas`, {
+    method: 'POST',
+    body: JSON.stringify(data),
+  });
+
+export const updateArea = (id: number, data: AreaData) =>
+  fetchJson<LawnArea>(`${BASE}/areas/${id}`, {
+    method: 'PUT',
+    body: JSON.stringify(data),
+  });
+
+export const deleteArea = (id: number) =>
+  fetchJson<void>(`${BASE}/areas/${id}`, { method: 'DELETE' });
+
+// Equipment
+export type EquipmentData = {
+  name: string;
+  kind: string;
+  hours_per_use?: number;
+  starting_hours?: number;
+  notes?: string;
+};
+
+export const listEquipment = () => fetchJson<Equipment[]>(`${BASE}/equipment`);
+
+export const createEquipment = (data: EquipmentData) =>
+  fetchJson<Equipment>(`${BASE}/equipment`, {
+    method: 'POST',
+    body: JSON.stringify(data),
+  });
+
+export const updateEquipment = (id: number, data: EquipmentData) =>
+  fetchJson<Equipment>(`${BASE}/equipment/${id}`, {
+    method: 'PUT',
+    body: JSON.stringify(data),
+  });
+
+export const deleteEquipment = (id: number) =>
+  fetchJson<void>(`${BASE}/equipment/${id}`, { method: 'DELETE' });
+
+export const logMaintenance = (
+  id: number,
+  data: { task: string; performed_on?: string; notes?: string }
+) =>
+  fetchJson<Equipment>(`${BASE}/equipment/${id}/maintenance`, {
+    method: 'POST',
+    body: JSON.stringify(data),
+  });
+
+export const deleteMaintenance = (id: number, eventId: number) =>
+  fetchJson<void>(`${BASE}/equipment/${id}/maintenance/${eventId}`, { method: 'DELETE' });
+
+// Inventory
+export type InventoryData = {
+  product_name: string;
+  application_type?: string;
+  unit: string;
+  package_size?: number;
+  quantity: number;
+  counted_on?: string;
+  rate_per_1000sqft?: number;
+  notes?: string;
+};
+
+export const listInventory = () => fetchJson<InventoryItem[]>(`${BASE}/inventory`);
+
+export const createInventoryItem = (data: InventoryData) =>
+  fetchJson<InventoryItem>(`${BASE}/inventory`, {
+    method: 'POST',
+    body: JSON.stringify(data),
+  });
+
+export const updateInventoryItem = (id: number, data: InventoryData) =>
+  fetchJson<InventoryItem>(`${BASE}/inventory/${id}`, {
+    method: 'PUT',
+    body: JSON.stringify(data),
+  });
+
+export const deleteInventoryItem = (id: number) =>
+  fetchJson<void>(`${BASE}/inventory/${id}`, { method: 'DELETE' });
+
 // Calendar
+/** Seven days from `start` (YYYY-MM-DD; defaults to this week's Sunday). */
+export const getAgenda = (start?: string) =>
+  fetchJson<AgendaResponse>(
+    `${BASE}/applications/agenda${start ? `?start=${encodeURIComponent(start)}` : ''}`
+  );
+
 export const getCalendar = (year?: number, month?: number) => {
   const params = new URLSearchParams();
   if (year) params.set('year', String(year));
@@ -129,18 +406,101 @@ export const getCalendar = (year?: number, month?: number) => {
   );
 };
 
+// Observations (season journal)
+export const getObservations = (category?: string) => {
+  const params = category ? `?category=${encodeURIComponent(category)}` : '';
+  return fetchJson<Observation[]>(`${BASE}/observations${params}`);
+};
+
+type ObservationData = {
+  observed_at?: string;
+  category: string;
+  notes: string;
+};
+
+export const createObservation = (data: ObservationData) =>
+  fetchJson<Observation>(`${BASE}/observations`, {
+    method: 'POST',
+    body: JSON.stringify(data),
+  });
+
+export const updateObservation = (id: number, data: ObservationData) =>
+  fetchJson<Observation>(`${BASE}/observations/${id}`, {
+    method: 'PUT',
+    body: JSON.stringify(data),
+  });
+
+export const deleteObservation = (id: number) =>
+  fetchJson<void>(`${BASE}/observations/${id}`, { method: 'DELETE' });
+
+// Photo journal (file paths only, no image storage)
+export const getPhotos = (areaId?: number) => {
+  const params = areaId ? `?area_id=${areaId}` : '';
+  return fetchJson<Photo[]>(`${BASE}/photos${params}`);
+};
+
+type PhotoData = {
+  taken_on?: string;
+  area_id: number | null;
+  path: string;
+  caption: string | null;
+};
+
+export const createPhoto = (data: PhotoData) =>
+  fetchJson<Photo>(`${BASE}/photos`, {
+    method: 'POST',
+    body: JSON.stringify(data),
+  });
+
+export const updatePhoto = (id: number, data: PhotoData) =>
+  fetchJson<Photo>(`${BASE}/photos/${id}`, {
+    method: 'PUT',
+    body: JSON.stringify(data),
+  });
+
+export const deletePhoto = (id: number) =>
+  fetchJson<void>(`${BASE}/photos/${id}`, { method: 'DELETE' });
+
 // Environmental
 export const getEnvironmental = () =>
   fetchJson<EnvironmentalSummary>(`${BASE}/environmental`);
 
-export const refreshEnvironmental = () =>
-  fetchJson<EnvironmentalSummary>(`${BASE}/environmental/refresh`, {
+export const getGaugeRanges = () =>
+  fetchJson<GaugeRanges>(`${BASE}/environmental/gauge-ranges`);
+
+export const getFrostDates = () =>
+  fetchJson<FrostDates>(`${BASE}/environmental/frost-dates`);
+
+export const refreshEnvironmental = (deep = false) =>
+  fetchJson<EnvironmentalSummary>(`${BASE}/environmental/refresh${deep ? '?deep=true' : ''}`, {
     method: 'POST',
   });
 
+// Activity log
+export const getEvents = (filters: { kind?: string; level?: string; limit?: number } = {}) => {
+  const params = new URLSearchParams();
+  if (filters.kind) params.set('kind', filters.kind);
+  if (filters.level) params.set('level', filters.level);
+  if (filters.limit) params.set('limit', String(filters.limit));
+  const qs = params.toString();
+  return fetchJson<ActivityEvent[]>(`${BASE}/events${qs ? `?${qs}` : ''}`);
+};
+
 // Recommendations
-export const getRecommendations = () =>
-  fetchJson<Recommendation[]>(`${BASE}/recommendations`);
+const areaQuery = (areaId?: number) => (areaId != null ? `?area_id=${areaId}` : '');
+
+export const getRecommendations = (areaId?: number) =>
+  fetchJson<Recommendation[]>(`${BASE}/recommendations${areaQuery(areaId)}`);
+
+export const explainRecommendations = (areaId?: number) =>
+  fetchJson<RuleTrace[]>(`${BASE}/recommendations/explain${areaQuery(areaId)}`);
+
+/** Record recommendations as viewed, clearing their new/escalated markers. */
+export const markRecommendationsSeen = (recs: Pick<Recommendation, 'id' | 'severity'>[]) =>
+  fetchJson<void>(`${BASE}/recommendations/seen`, {
+    method: 'POST',
+    body: JSON.stringify(recs.map(({ id, severity }) => ({ id, severity }))),
+  });
 
 export const patchRecommendation = (
   id: string,
@@ -151,6 +511,97 @@ export const patchRecommendation = (
     { method: 'PATCH', body: JSON.stringify(data) }
   );
 
+// Rules
+export const getRules = () => fetchJson<RuleStatus[]>(`${BASE}/rules`);
+
+export const setRuleEnabled = (rule: string, enabled: boolean) =>
+  fetchJson<RuleStatus>(`${BASE}/rules/${encodeURIComponent(rule)}`, {
+    method: 'PUT',
+    body: JSON.stringify({ enabled }),
+  });
+
+export const getRiskPosture = () =>
+  fetchJson<{ posture: RiskPosture }>(`${BASE}/rules/posture`);
+
+export const setRiskPosture = (posture: RiskPosture) =>
+  fetchJson<{ posture: RiskPosture }>(`${BASE}/rules/posture`, {
+    method: 'PUT',
+    body: JSON.stringify({ posture }),
+  });
+
+// A season of hourly lake data takes a while to replay
+export const runBacktest = (from: string, to: string) =>
+  fetchJson<BacktestReport>(
+    `${BASE}/rules/backtest?from=${encodeURIComponent(from)}&to=${encodeURIComponent(to)}`,
+    undefined,
+    60_000
+  );
+
+// Server log
+export const getLogTail = (lines: number) =>
+  fetchJson<LogTail>(`${BASE}/logs?lines=${lines}`);
+
+// Forecast location
+export const getLocation = () => fetchJson<Location | null>(`${BASE}/location`);
+
+export const searchLocation = (q: string) =>
+  fetchJson<Location[]>(`${BASE}/location/search?q=${encodeURIComponent(q)}`);
+
+/** `global` sets the default for every profile; `profile` only the active one. */
+export type LocationScope = 'global' | 'profile';
+
+export const setLocation = (location: Location, scope: LocationScope = 'global') =>
+  fetchJson<Location>(`${BASE}/location?scope=${scope}`, {
+    method: 'PUT',
+    body: JSON.stringify(location),
+  });
+
+/** Drop the active profile's own location; returns the global one it now uses. */
+export const clearProfileLocation = () =>
+  fetchJson<Location | null>(`${BASE}/location`, { method: 'DELETE' });
+
+// Annual review
+type AnnualReviewData = {
+  application_reviews: ApplicationReview[];
+  observations: ReviewObservations;
+  adjustments: ReviewAdjustment[];
+};
+
+export const getAnnualReviews = () =>
+  fetchJson<AnnualReview[]>(`${BASE}/annual-reviews`);
+
+export const getAnnualReview = (year: number) =>
+  fetchJson<AnnualReview>(`${BASE}/annual-reviews/${year}`);
+
+export const suggestReviewAdjustments = (year: number, data: AnnualReviewData) =>
+  fetchJson<ReviewAdjustment[]>(`${BASE}/annual-reviews/${year}/suggestions`, {
+    method: 'POST',
+    body: JSON.stringify(data),
+  });
+
+export const saveAnnualReview = (year: number, data: AnnualReviewData) =>
+  fetchJson<AnnualReview>(`${BASE}/annual-reviews/${year}`, {
+    method: 'PUT',
+    body: JSON.stringify(data),
+  });
+
+export const getEfficacyReport = (year: number) =>
+  fetchJson<EfficacyReport>(`${BASE}/efficacy/${year}`);
+
+/** Download link for the shareable season report. */
+export const seasonReportUrl = (year: number, format: 'markdown' | 'html') =>
+  `${BASE}/reports/${year}?format=${format}`;
+
+/** Download link for the anonymized season bundle (JSON). */
+export const seasonBundleUrl = (year: number) => `${BASE}/season-bundles/${year}`;
+
+/** Import a season bundle file's parsed JSON into the active profile. */
+export const importSeasonBundle = (bundle: unknown) =>
+  fetchJson<SeasonImportSummary>(`${BASE}/season-bundles`, {
+    method: 'POST',
+    body: JSON.stringify(bundle),
+  });
+
 // GDD
 export const getGdd = (year?: number) => {
   const params = year ? `?year=${year}` : '';
@@ -171,6 +622,10 @@ export const getNitrogenBudget = (year?: number) => {
 export const getSoilTempForecast = () =>
   fetchJson<SoilTempForecast>(`${BASE}/soil-temp-forecast`);
 
+// Turf stress index
+export const getStressIndex = () =>
+  fetchJson<StressIndexSummary>(`${BASE}/stress-index`);
+
 // Soil Tests
 export const getSoilTests = () =>
   fetchJson<SoilTest[]>(`${BASE}/soil-tests`);
@@ -0,0 +1,39 @@
+import type { AirQuality } from '../types';
+import { AIR_QUALITY_COLORS, AIR_QUALITY_LABELS } from '../types';
+import { t } from '../utils/i18n';
+
+interface AirQualityIndicatorProps {
+  air: AirQuality;
+}
+
+/** Compact air quality pill for the Dashboard header row. */
+export default function AirQualityIndicator({ air }: AirQualityIndicatorProps) {
+  const color = AIR_QUALITY_COLORS[air.level];
+  const details = [
+    air.pm2_5 != null ? `PM2.5 ${air.pm2_5.toFixed(0)} µg/m³` : null,
+    air.o3 != null ? `O₃ ${air.o3.toFixed(0)} µg/m³` : null,
+  ]
+    .filter(Boolean)
+    .join(' · ');
+
+  return (
+    <span
+      style={{ ...styles.pill, backgroundColor: color + '22', borderColor: color, color }}
+      title={details || undefined}
+    >
+      {t('Air quality')}: {t(AIR_QUALITY_LABELS[air.level])}
+    </span>
+  );
+}
+
+const styles: Record<string, React.CSSProperties> = {
+  pill: {
+    display: 'inline-block',
+    padding: '2px 10px',
+    borderRadius: 12,
+    fontSize: '0.75rem',
+    fontWeight: 600,
+    border: '1px solid',
+    whiteSpace: 'nowrap' as const,
+  },
+};
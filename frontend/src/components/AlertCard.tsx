@@ -1,24 +1,35 @@
+import ChangeBadge from './ChangeBadge';
 import type { Recommendation } from '../types';
 import { SEVERITY_COLORS, SEVERITY_SYMBOLS } from '../types';
 
 interface AlertCardProps {
   rec: Recommendation;
+  /** Keyboard shortcut number shown on the card. */
+  shortcut?: number;
+  onClick?: () => void;
 }
 
-export default function AlertCard({ rec }: AlertCardProps) {
+export default function AlertCard({ rec, shortcut, onClick }: AlertCardProps) {
   const color = SEVERITY_COLORS[rec.severity];
   const symbol = SEVERITY_SYMBOLS[rec.severity];
 
   return (
-    <div style={{ ...styles.card, borderLeftColor: color }}>
+    <div
+      style={{ ...styles.card, borderLeftColor: color, cursor: onClick ? 'pointer' : undefined }}
+      onClick={onClick}
+    >
       <div style={styles.header}>
+        {shortcut !== undefined && <kbd style={styles.shortcut}>{shortcut}</kbd>}
         <span style={{ ...styles.badge, backgroundColor: color }}>
           {symbol} {rec.severity}
         </span>
         <span style={styles.category}>{rec.category}</span>
+        <ChangeBadge change={rec.change} />
       </div>
       <div style={styles.title}>{rec.title}</div>
       <div style={styles.description}>{rec.description}</div>
+      {rec.conflict_note && <div style={styles.conflict}>{rec.conflict_note}</div>}
+      {rec.inventory_note && <div style={styles.conflict}>{rec.inventory_note}</div>}
     </div>
   );
 }
@@ -45,6 +56,14 @@ const styles: Record<string, React.CSSProperties> = {
     padding: '2px 8px',
     borderRadius: 10,
   },
+  shortcut: {
+    fontSize: '0.7rem',
+    fontFamily: 'inherit',
+    color: '#4a5568',
+    border: '1px solid #cbd5e0',
+    borderRadius: 4,
+    padding: '0 5px',
+  },
   category: {
     fontSize: '0.75rem',
     color: '#718096',
@@ -60,4 +79,9 @@ const styles: Record<string, React.CSSProperties> = {
     color: '#4a5568',
     lineHeight: 1.4,
   },
+  conflict: {
+    fontSize: '0.78rem',
+    color: '#c05621',
+    marginTop: 4,
+  },
 };
@@ -0,0 +1,101 @@
+import { useEffect } from 'react';
+import { Link, useNavigate } from 'react-router-dom';
+import AlertCard from './AlertCard';
+import { sharedStyles } from '../styles/shared';
+import type { Recommendation, Severity, SeverityCounts } from '../types';
+import { SEVERITY_COLORS } from '../types';
+import { isTypingTarget } from '../utils/keyboard';
+
+interface AlertsPanelProps {
+  recommendations: Recommendation[];
+  counts: SeverityCounts;
+  total: number;
+}
+
+const COUNT_ORDER: { key: keyof SeverityCounts; severity: Severity }[] = [
+  { key: 'critical', severity: 'Critical' },
+  { key: 'warning', severity: 'Warning' },
+  { key: 'advisory', severity: 'Advisory' },
+  { key: 'info', severity: 'Info' },
+];
+
+function recommendationLink(id: string): string {
+  return `/recommendations?focus=${encodeURIComponent(id)}`;
+}
+
+/** Most severe active alerts with per-severity counts; number keys open one. */
+export default function AlertsPanel({ recommendations, counts, total }: AlertsPanelProps) {
+  const navigate = useNavigate();
+
+  useEffect(() => {
+    const onKey = (e: KeyboardEvent) => {
+      if (isTypingTarget(e.target) || e.ctrlKey || e.metaKey || e.altKey) return;
+      const n = Number(e.key);
+      if (Number.isInteger(n) && n >= 1 && n <= recommendations.length) {
+        navigate(recommendationLink(recommendations[n - 1].id));
+      }
+    };
+    window.addEventListener('keydown', onKey);
+    return () => window.removeEventListener('keydown', onKey);
+  }, [recommendations, navigate]);
+
+  const hidden = total - recommendations.length;
+
+  return (
+    <div>
+      <div style={styles.header}>
+        <h2 style={{ ...sharedStyles.sectionTitle, margin: 0 }}>Active Alerts</h2>
+        {COUNT_ORDER.filter(({ key }) => counts[key] > 0).map(({ key, severity }) => (
+          <span
+            key={key}
+            style={{ ...styles.count, backgroundColor: SEVERITY_COLORS[severity] }}
+          >
+            {counts[key]} {severity}
+          </span>
+        ))}
+      </div>
+      {recommendations.length === 0 ? (
+        <div style={sharedStyles.empty}>No active recommendations</div>
+      ) : (
+        <>
+          {recommendations.map((r, i) => (
+            <AlertCard
+              key={r.id}
+              rec={r}
+              shortcut={i + 1}
+              onClick={() => navigate(recommendationLink(r.id))}
+            />
+          ))}
+          {hidden > 0 && (
+            <Link to="/recommendations" style={styles.more}>
+              +{hidden} more alert{hidden === 1 ? '' : 's'} — view all
+            </Link>
+          )}
+        </>
+      )}
+    </div>
+  );
+}
+
+const styles: Record<string, React.CSSProperties> = {
+  header: {
+    display: 'flex',
+    alignItems: 'center',
+    gap: 6,
+    flexWrap: 'wrap',
+    marginBottom: '0.75rem',
+  },
+  count: {
+    color: '#fff',
+    fontSize: '0.7rem',
+    fontWeight: 600,
+    padding: '2px 8px',
+    borderRadius: 10,
+  },
+  more: {
+    display: 'block',
+    fontSize: '0.82rem',
+    color: '#3182ce',
+    fontWeight: 600,
+  },
+};
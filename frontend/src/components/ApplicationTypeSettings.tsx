@@ -0,0 +1,209 @@
+import { useState } from 'react';
+import { createCustomType, deleteCustomType, errorMessage, updateCustomType } from '../api/client';
+import { sharedStyles } from '../styles/shared';
+import type { ApplicationType, CustomApplicationType } from '../types';
+import { APPLICATION_TYPE_LABELS, APPLICATION_TYPES } from '../types';
+import { customTypes, setCustomTypes } from '../utils/typeRegistry';
+import EnumSelect from './EnumSelect';
+import { useToast } from './toastContext';
+
+export default function ApplicationTypeSettings() {
+  const [types, setTypes] = useState<CustomApplicationType[]>(customTypes);
+  const [editing, setEditing] = useState<CustomApplicationType | 'new' | null>(null);
+  const { notify } = useToast();
+
+  // Keep the shared registry in step so filters and the calendar see changes
+  const replace = (next: CustomApplicationType[]) => {
+    setCustomTypes(next);
+    setTypes(customTypes());
+  };
+
+  const handleSaved = (saved: CustomApplicationType) => {
+    replace([...types.filter((t) => t.id !== saved.id), saved]);
+    setEditing(null);
+    notify(`Saved ${saved.name}`, 'success');
+  };
+
+  const handleDelete = async (type: CustomApplicationType) => {
+    if (type.id == null) return;
+    const base = APPLICATION_TYPE_LABELS[type.base_type];
+    if (!window.confirm(`Delete ${type.name}? Its applications are kept as ${base}.`)) return;
+    try {
+      await deleteCustomType(type.id);
+      replace(types.filter((t) => t.id !== type.id));
+      notify(`Deleted ${type.name}`, 'success');
+    } catch (e) {
+      notify(errorMessage(e, 'Failed to delete type'), 'error');
+    }
+  };
+
+  return (
+    <div style={sharedStyles.card}>
+      <p style={styles.help}>
+        Add your own application types, like Topdressing or Biostimulant, instead of
+        logging them as Other. Each counts as a built-in category, so recommendations,
+        nitrogen totals and reports treat it as that category; the name and color show
+        up in filters, the calendar and its legend.
+      </p>
+
+      {types.length > 0 && (
+        <table style={sharedStyles.table}>
+          <thead>
+            <tr>
+              <th style={sharedStyles.th}>Type</th>
+              <th style={sharedStyles.th}>Counts as</th>
+              <th style={sharedStyles.th}></th>
+            </tr>
+          </thead>
+          <tbody>
+            {types.map((t) => (
+              <tr key={t.id}>
+                <td style={sharedStyles.td}>
+                  <span style={{ ...styles.swatch, backgroundColor: t.color }} />
+                  {t.name}
+                </td>
+                <td style={sharedStyles.td}>{APPLICATION_TYPE_LABELS[t.base_type]}</td>
+                <td style={sharedStyles.td}>
+                  <button style={styles.linkBtn} onClick={() => setEditing(t)}>
+                    Edit
+                  </button>
+                  <button
+                    style={{ ...styles.linkBtn, color: '#e53e3e' }}
+                    onClick={() => handleDelete(t)}
+                  >
+                    Delete
+                  </button>
+                </td>
+              </tr>
+            ))}
+          </tbody>
+        </table>
+      )}
+
+      {editing ? (
+        <TypeForm
+          key={editing === 'new' ? 'new' : editing.id}
+          initial={editing === 'new' ? null : editing}
+          onSaved={handleSaved}
+          onCancel={() => setEditing(null)}
+        />
+      ) : (
+        <button style={styles.addBtn} onClick={() => setEditing('new')}>
+          + Add type
+        </button>
+      )}
+    </div>
+  );
+}
+
+function TypeForm({
+  initial,
+  onSaved,
+  onCancel,
+}: {
+  initial: CustomApplicationType | null;
+  onSaved: (type: CustomApplicationType) => void;
+  onCancel: () => void;
+}) {
+  const [name, setName] = useState(initial?.name ?? '');
+  const [color, setColor] = useState(initial?.color ?? '#805ad5');
+  const [baseType, setBaseType] = useState<ApplicationType>(initial?.base_type ?? 'Other');
+  const [saving, setSaving] = useState(false);
+  const { notify } = useToast();
+
+  const handleSubmit = async (e: React.FormEvent) => {
+    e.preventDefault();
+    setSaving(true);
+    const data = { name, color, base_type: baseType };
+    try {
+      onSaved(
+        initial?.id != null
+          ? await updateCustomType(initial.id, data)
+          : await createCustomType(data)
+      );
+    } catch (err) {
+      notify(errorMessage(err, 'Failed to save type'), 'error');
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  return (
+    <form onSubmit={handleSubmit} style={styles.form}>
+      <input
+        style={styles.input}
+        value={name}
+        onChange={(e) => setName(e.target.value)}
+        placeholder="Name (e.g. Topdressing)"
+        required
+      />
+      <input
+        type="color"
+        style={styles.colorInput}
+        value={color}
+        onChange={(e) => setColor(e.target.value)}
+        aria-label="Color"
+      />
+      <EnumSelect
+        style={styles.input}
+        options={APPLICATION_TYPES}
+        labels={APPLICATION_TYPE_LABELS}
+        value={baseType}
+        onChange={(v) => {
+          if (v) setBaseType(v);
+        }}
+        ariaLabel="Counts as"
+      />
+      <button type="submit" style={{ ...styles.addBtn, marginTop: 0 }} disabled={saving}>
+        {saving ? 'Saving...' : initial ? 'Update' : 'Add'}
+      </button>
+      <button type="button" style={styles.linkBtn} onClick={onCancel} disabled={saving}>
+        Cancel
+      </button>
+    </form>
+  );
+}
+
+const styles: Record<string, React.CSSProperties> = {
+  help: { color: '#718096', fontSize: '0.85rem', marginTop: 0 },
+  form: { display: 'flex', gap: 8, flexWrap: 'wrap' as const, marginTop: '1rem' },
+  input: {
+    padding: '0.4rem 0.6rem',
+    borderRadius: 6,
+    border: '1px solid #e2e8f0',
+    fontSize: '0.85rem',
+  },
+  colorInput: {
+    width: 42,
+    height: 32,
+    padding: 2,
+    borderRadius: 6,
+    border: '1px solid #e2e8f0',
+  },
+  swatch: {
+    display: 'inline-block',
+    width: 10,
+    height: 10,
+    borderRadius: '50%',
+    marginRight: 8,
+  },
+  linkBtn: {
+    background: 'none',
+    border: 'none',
+    color: '#3182ce',
+    cursor: 'pointer',
+    fontSize: '0.8rem',
+    padding: '0 4px',
+  },
+  addBtn: {
+    marginTop: '1rem',
+    padding: '0.4rem 1rem',
+    backgroundColor: '#38a169',
+    color: '#fff',
+    border: 'none',
+    borderRadius: 6,
+    cursor: 'pointer',
+    fontWeight: 600,
+    fontSize: '0.85rem',
+  },
+};
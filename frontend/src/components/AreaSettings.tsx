@@ -0,0 +1,244 @@
+import { useEffect, useState } from 'react';
+import { createArea, deleteArea, errorMessage, listAreas, updateArea } from '../api/client';
+import { sharedStyles } from '../styles/shared';
+import type { GrassType, LawnArea, SunExposure } from '../types';
+import { GRASS_TYPE_LABELS, GRASS_TYPES, SUN_EXPOSURE_LABELS } from '../types';
+import EnumSelect from './EnumSelect';
+import { useToast } from './toastContext';
+
+interface Props {
+  /** Whole-lawn size, to show how much of it the areas account for. */
+  lawnSizeSqft: number | null;
+}
+
+export default function AreaSettings({ lawnSizeSqft }: Props) {
+  const [areas, setAreas] = useState<LawnArea[]>([]);
+  const [loading, setLoading] = useState(true);
+  const [editing, setEditing] = useState<LawnArea | 'new' | null>(null);
+  const { notify } = useToast();
+
+  useEffect(() => {
+    listAreas()
+      .then(setAreas)
+      .catch((e) => notify(`Failed to load areas: ${errorMessage(e, 'request failed')}`, 'error'))
+      .finally(() => setLoading(false));
+  }, [notify]);
+
+  const handleSaved = (saved: LawnArea) => {
+    setAreas((prev) =>
+      [...prev.filter((a) => a.id !== saved.id), saved].sort((a, b) =>
+        a.name.localeCompare(b.name)
+      )
+    );
+    setEditing(null);
+    notify(`Saved ${saved.name}`, 'success');
+  };
+
+  const handleDelete = async (area: LawnArea) => {
+    if (area.id == null) return;
+    if (!window.confirm(`Delete ${area.name}? Its applications become whole-lawn entries.`)) {
+      return;
+    }
+    try {
+      await deleteArea(area.id);
+      setAreas((prev) => prev.filter((a) => a.id !== area.id));
+      notify(`Deleted ${area.name}`, 'success');
+    } catch (e) {
+      notify(errorMessage(e, 'Failed to delete area'), 'error');
+    }
+  };
+
+  if (loading) return <div style={sharedStyles.loading}>Loading...</div>;
+
+  const mapped = areas.reduce((sum, a) => sum + (a.size_sqft ?? 0), 0);
+
+  return (
+    <div style={sharedStyles.card}>
+      <p style={styles.help}>
+        Split the lawn into areas that get different treatment, like a shady backyard
+        and a sunny front strip. Tag applications to an area and view recommendations
+        for it on its own; untagged applications count for every area.
+      </p>
+
+      {areas.length > 0 && (
+        <table style={sharedStyles.table}>
+          <thead>
+            <tr>
+              <th style={sharedStyles.th}>Area</th>
+              <th style={sharedStyles.th}>Size</th>
+              <th style={sharedStyles.th}>Sun</th>
+              <th style={sharedStyles.th}>Grass</th>
+              <th style={sharedStyles.th}></th>
+            </tr>
+          </thead>
+          <tbody>
+            {areas.map((a) => (
+              <tr key={a.id}>
+                <td style={sharedStyles.td}>{a.name}</td>
+                <td style={sharedStyles.td}>
+                  {a.size_sqft != null ? `${a.size_sqft.toLocaleString()} sqft` : '-'}
+                </td>
+                <td style={sharedStyles.td}>{SUN_EXPOSURE_LABELS[a.sun_exposure]}</td>
+                <td style={sharedStyles.td}>
+                  {a.grass_type ? GRASS_TYPE_LABELS[a.grass_type] : 'Profile default'}
+                  {a.grass_mix && <div style={styles.muted}>{a.grass_mix}</div>}
+                </td>
+                <td style={sharedStyles.td}>
+                  <button style={styles.linkBtn} onClick={() => setEditing(a)}>
+                    Edit
+                  </button>
+                  <button
+                    style={{ ...styles.linkBtn, color: '#e53e3e' }}
+                    onClick={() => handleDelete(a)}
+                  >
+                    Delete
+                  </button>
+                </td>
+              </tr>
+            ))}
+          </tbody>
+        </table>
+      )}
+
+      {lawnSizeSqft != null && mapped > 0 && (
+        <p style={styles.muted}>
+          Areas cover {mapped.toLocaleString()} of {lawnSizeSqft.toLocaleString()} sqft.
+        </p>
+      )}
+
+      {editing ? (
+        <AreaForm
+          key={editing === 'new' ? 'new' : editing.id}
+          initial={editing === 'new' ? null : editing}
+          onSaved={handleSaved}
+          onCancel={() => setEditing(null)}
+        />
+      ) : (
+        <button style={styles.addBtn} onClick={() => setEditing('new')}>
+          + Add area
+        </button>
+      )}
+    </div>
+  );
+}
+
+function AreaForm({
+  initial,
+  onSaved,
+  onCancel,
+}: {
+  initial: LawnArea | null;
+  onSaved: (area: LawnArea) => void;
+  onCancel: () => void;
+}) {
+  const [name, setName] = useState(initial?.name ?? '');
+  const [size, setSize] = useState(initial?.size_sqft?.toString() ?? '');
+  const [sun, setSun] = useState<SunExposure>(initial?.sun_exposure ?? 'FullSun');
+  const [grassType, setGrassType] = useState<GrassType | ''>(initial?.grass_type ?? '');
+  const [grassMix, setGrassMix] = useState(initial?.grass_mix ?? '');
+  const [saving, setSaving] = useState(false);
+  const { notify } = useToast();
+
+  const handleSubmit = async (e: React.FormEvent) => {
+    e.preventDefault();
+    setSaving(true);
+    const data = {
+      name,
+      size_sqft: size ? parseFloat(size) : undefined,
+      sun_exposure: sun,
+      grass_type: grassType || undefined,
+      grass_mix: grassMix || undefined,
+    };
+    try {
+      onSaved(initial?.id != null ? await updateArea(initial.id, data) : await createArea(data));
+    } catch (err) {
+      notify(errorMessage(err, 'Failed to save area'), 'error');
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  return (
+    <form onSubmit={handleSubmit} style={styles.form}>
+      <input
+        style={styles.input}
+        value={name}
+        onChange={(e) => setName(e.target.value)}
+        placeholder="Name (e.g. Backyard)"
+        required
+      />
+      <input
+        type="number"
+        style={{ ...styles.input, width: 110 }}
+        value={size}
+        onChange={(e) => setSize(e.target.value)}
+        placeholder="sqft"
+        min="1"
+      />
+      <select
+        style={styles.input}
+        value={sun}
+        onChange={(e) => setSun(e.target.value as SunExposure)}
+        aria-label="Sun exposure"
+      >
+        {(Object.keys(SUN_EXPOSURE_LABELS) as SunExposure[]).map((s) => (
+          <option key={s} value={s}>
+            {SUN_EXPOSURE_LABELS[s]}
+          </option>
+        ))}
+      </select>
+      <EnumSelect
+        style={styles.input}
+        options={GRASS_TYPES}
+        labels={GRASS_TYPE_LABELS}
+        value={grassType}
+        onChange={setGrassType}
+        emptyLabel="Profile grass type"
+        ariaLabel="Grass type"
+      />
+      <input
+        style={styles.input}
+        value={grassMix}
+        onChange={(e) => setGrassMix(e.target.value)}
+        placeholder="Mix (e.g. 80% TTTF / 20% KBG)"
+      />
+      <button type="submit" style={{ ...styles.addBtn, marginTop: 0 }} disabled={saving}>
+        {saving ? 'Saving...' : initial ? 'Update' : 'Add'}
+      </button>
+      <button type="button" style={styles.linkBtn} onClick={onCancel} disabled={saving}>
+        Cancel
+      </button>
+    </form>
+  );
+}
+
+const styles: Record<string, React.CSSProperties> = {
+  help: { color: '#718096', fontSize: '0.85rem', marginTop: 0 },
+  muted: { fontSize: '0.75rem', color: '#a0aec0' },
+  form: { display: 'flex', gap: 8, flexWrap: 'wrap' as const, marginTop: '1rem' },
+  input: {
+    padding: '0.4rem 0.6rem',
+    borderRadius: 6,
+    border: '1px solid #e2e8f0',
+    fontSize: '0.85rem',
+  },
+  linkBtn: {
+    background: 'none',
+    border: 'none',
+    color: '#3182ce',
+    cursor: 'pointer',
+    fontSize: '0.8rem',
+    padding: '0 4px',
+  },
+  addBtn: {
+    marginTop: '1rem',
+    padding: '0.4rem 1rem',
+    backgroundColor: '#38a169',
+    color: '#fff',
+    border: 'none',
+    borderRadius: 6,
+    cursor: 'pointer',
+    fontWeight: 600,
+    fontSize: '0.85rem',
+  },
+};
@@ -0,0 +1,32 @@
+import type { RecommendationChange } from '../types';
+
+const CHANGE_COLORS: Record<RecommendationChange, string> = {
+  New: '#3182ce',
+  Escalated: '#dd6b20',
+};
+
+/** "New" or "Escalated" marker for a recommendation that changed since last viewed. */
+export default function ChangeBadge({ change }: { change: RecommendationChange | null }) {
+  if (!change) return null;
+  const color = CHANGE_COLORS[change];
+  return (
+    <span
+      style={{ ...styles.badge, color, borderColor: color }}
+      title={change === 'New' ? 'New since your last visit' : 'More severe since your last visit'}
+    >
+      {change === 'New' ? 'New' : '▲ Escalated'}
+    </span>
+  );
+}
+
+const styles: Record<string, React.CSSProperties> = {
+  badge: {
+    fontSize: '0.68rem',
+    fontWeight: 700,
+    padding: '1px 7px',
+    borderRadius: 10,
+    border: '1px solid',
+    textTransform: 'uppercase' as const,
+    letterSpacing: 0.3,
+  },
+};
@@ -0,0 +1,186 @@
+import { useEffect, useState } from 'react';
+import { errorMessage, getDashboardLayout, saveDashboardLayout } from '../api/client';
+import { sharedStyles } from '../styles/shared';
+import type { DashboardPanel } from '../types';
+import { useToast } from './toastContext';
+
+const PANEL_LABELS: Record<DashboardPanel, string> = {
+  gauges: 'Gauges',
+  forecast: 'Forecast',
+  germination: 'Germination countdown',
+  gdd: 'Growing degree days',
+  stress_index: 'Turf stress index',
+  drought: 'Drought status',
+  nitrogen_budget: 'Nitrogen budget',
+  soil_temp_forecast: 'Soil temp forecast',
+  windows: 'Upcoming windows',
+  alerts: 'Alerts',
+  recent_applications: 'Recent applications',
+};
+
+const ALL_PANELS = Object.keys(PANEL_LABELS) as DashboardPanel[];
+
+/** Matches the server's default: everything but the forecast. */
+const DEFAULT_PANELS = ALL_PANELS.filter((p) => p !== 'forecast');
+
+interface Row {
+  panel: DashboardPanel;
+  shown: boolean;
+}
+
+/** Shown panels in order, then the hidden ones. */
+function toRows(panels: DashboardPanel[]): Row[] {
+  return [
+    ...panels.map((panel) => ({ panel, shown: true })),
+    ...ALL_PANELS.filter((p) => !panels.includes(p)).map((panel) => ({ panel, shown: false })),
+  ];
+}
+
+export default function DashboardLayoutSettings() {
+  const [rows, setRows] = useState<Row[] | null>(null);
+  const [locked, setLocked] = useState(false);
+  const [dirty, setDirty] = useState(false);
+  const [saving, setSaving] = useState(false);
+  const { notify } = useToast();
+
+  useEffect(() => {
+    let cancelled = false;
+    getDashboardLayout()
+      .then((layout) => {
+        if (cancelled) return;
+        setRows(toRows(layout.panels));
+        setLocked(layout.locked);
+      })
+      .catch((e) => notify(errorMessage(e, 'Failed to load dashboard layout'), 'error'));
+    return () => {
+      cancelled = true;
+    };
+  }, [notify]);
+
+  if (!rows) return <div style={sharedStyles.loading}>Loading layout...</div>;
+
+  const change = (next: Row[]) => {
+    setRows(next);
+    setDirty(true);
+  };
+
+  const move = (index: number, by: number) => {
+    const target = index + by;
+    if (target < 0 || target >= rows.length) return;
+    const next = [...rows];
+    [next[index], next[target]] = [next[target], next[index]];
+    change(next);
+  };
+
+  const handleSave = async () => {
+    setSaving(true);
+    try {
+      const saved = await saveDashboardLayout(rows.filter((r) => r.shown).map((r) => r.panel));
+      setRows(toRows(saved.panels));
+      setDirty(false);
+      notify('Dashboard layout saved', 'success');
+    } catch (e) {
+      notify(errorMessage(e, 'Failed to save dashboard layout'), 'error');
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  return (
+    <div style={sharedStyles.card}>
+      <p style={styles.help}>
+        Choose which panels the Dashboard shows and in what order. Neighboring widgets share a
+        grid, and alerts and recent applications sit side by side when listed together.
+        {locked && (
+          <>
+            {' '}
+            The layout is set with <code>DASHBOARD_PANELS</code> and can't be changed here.
+          </>
+        )}
+      </p>
+      {rows.map((row, i) => (
+        <div key={row.panel} style={styles.row}>
+          <label style={styles.label}>
+            <input
+              type="checkbox"
+              checked={row.shown}
+              disabled={locked}
+              onChange={() =>
+                change(rows.map((r) => (r === row ? { ...r, shown: !r.shown } : r)))
+              }
+            />
+            <span style={row.shown ? styles.name : styles.nameOff}>
+              {PANEL_LABELS[row.panel]}
+            </span>
+          </label>
+          <button
+            style={styles.moveBtn}
+            disabled={locked || i === 0}
+            onClick={() => move(i, -1)}
+            aria-label={`Move ${PANEL_LABELS[row.panel]} up`}
+          >
+            ↑
+          </button>
+          <button
+            style={styles.moveBtn}
+            disabled={locked || i === rows.length - 1}
+            onClick={() => move(i, 1)}
+            aria-label={`Move ${PANEL_LABELS[row.panel]} down`}
+          >
+            ↓
+          </button>
+        </div>
+      ))}
+      {!locked && (
+        <div style={styles.actions}>
+          <button style={styles.saveBtn} onClick={handleSave} disabled={saving || !dirty}>
+            {saving ? 'Saving...' : 'Save layout'}
+          </button>
+          <button style={styles.linkBtn} onClick={() => change(toRows(DEFAULT_PANELS))}>
+            Reset to default
+          </button>
+        </div>
+      )}
+    </div>
+  );
+}
+
+const styles: Record<string, React.CSSProperties> = {
+  help: { color: '#718096', fontSize: '0.85rem', marginTop: 0 },
+  row: {
+    display: 'flex',
+    alignItems: 'center',
+    gap: 6,
+    padding: '0.35rem 0',
+    borderBottom: '1px solid #edf2f7',
+  },
+  label: { display: 'flex', alignItems: 'center', gap: 10, flex: 1, cursor: 'pointer' },
+  name: { fontSize: '0.9rem', color: '#2d3748' },
+  nameOff: { fontSize: '0.9rem', color: '#a0aec0' },
+  moveBtn: {
+    background: 'none',
+    border: '1px solid #e2e8f0',
+    borderRadius: 4,
+    cursor: 'pointer',
+    padding: '0 6px',
+    fontSize: '0.85rem',
+  },
+  actions: { display: 'flex', alignItems: 'center', gap: 8, marginTop: '1rem' },
+  saveBtn: {
+    padding: '0.4rem 1rem',
+    backgroundColor: '#38a169',
+    color: '#fff',
+    border: 'none',
+    borderRadius: 6,
+    cursor: 'pointer',
+    fontWeight: 600,
+    fontSize: '0.85rem',
+  },
+  linkBtn: {
+    background: 'none',
+    border: 'none',
+    color: '#3182ce',
+    cursor: 'pointer',
+    fontSize: '0.8rem',
+  },
+};
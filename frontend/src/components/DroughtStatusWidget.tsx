@@ -0,0 +1,140 @@
+import type { DroughtCategory, DroughtStatus } from '../types';
+import { DROUGHT_CATEGORY_COLORS, DROUGHT_CATEGORY_LABELS } from '../types';
+
+interface DroughtStatusWidgetProps {
+  status: DroughtStatus;
+}
+
+const BANDS: { key: keyof DroughtStatus['coverage']; category: DroughtCategory }[] = [
+  { key: 'd0', category: 'D0' },
+  { key: 'd1', category: 'D1' },
+  { key: 'd2', category: 'D2' },
+  { key: 'd3', category: 'D3' },
+  { key: 'd4', category: 'D4' },
+];
+
+export default function DroughtStatusWidget({ status }: DroughtStatusWidgetProps) {
+  const color = DROUGHT_CATEGORY_COLORS[status.category];
+  const restrictionsLikely = ['D2', 'D3', 'D4'].includes(status.category);
+  const mapDate = new Date(status.map_date + 'T00:00:00').toLocaleDateString(undefined, {
+    month: 'short',
+    day: 'numeric',
+  });
+
+  return (
+    <div style={styles.card}>
+      <div style={styles.header}>
+        <div style={styles.label}>Drought Monitor</div>
+        <span
+          style={{
+            ...styles.statusBadge,
+            backgroundColor: color + '22',
+            color,
+            borderColor: color,
+          }}
+        >
+          {status.category === 'None' ? 'None' : status.category}
+        </span>
+      </div>
+
+      <div style={styles.value}>{DROUGHT_CATEGORY_LABELS[status.category]}</div>
+      <div style={styles.county}>{status.county}</div>
+
+      {/* Cumulative share of the county in each category or worse */}
+      <div style={styles.bands}>
+        {BANDS.map((b) => (
+          <div key={b.key} style={styles.band} title={DROUGHT_CATEGORY_LABELS[b.category]}>
+            <div style={styles.bandBg}>
+              <div
+                style={{
+                  ...styles.bandFill,
+                  width: `${Math.min(status.coverage[b.key], 100)}%`,
+                  backgroundColor: DROUGHT_CATEGORY_COLORS[b.category],
+                }}
+              />
+            </div>
+            <span style={styles.bandLabel}>
+              {b.category}+ {status.coverage[b.key].toFixed(0)}%
+            </span>
+          </div>
+        ))}
+      </div>
+
+      <div style={styles.footnote}>
+        {restrictionsLikely ? 'Watering restrictions likely · ' : ''}Map of {mapDate}
+      </div>
+    </div>
+  );
+}
+
+const styles: Record<string, React.CSSProperties> = {
+  card: {
+    backgroundColor: '#fff',
+    borderRadius: 8,
+    padding: '1rem',
+    boxShadow: '0 1px 3px rgba(0,0,0,0.08)',
+  },
+  header: {
+    display: 'flex',
+    justifyContent: 'space-between',
+    alignItems: 'center',
+    marginBottom: 6,
+  },
+  label: {
+    fontSize: '0.75rem',
+    fontWeight: 600,
+    color: '#718096',
+    textTransform: 'uppercase' as const,
+  },
+  statusBadge: {
+    display: 'inline-block',
+    padding: '1px 8px',
+    borderRadius: 10,
+    fontSize: '0.7rem',
+    fontWeight: 600,
+    border: '1px solid',
+  },
+  value: {
+    fontSize: '1.2rem',
+    fontWeight: 700,
+    color: '#1a202c',
+  },
+  county: {
+    fontSize: '0.75rem',
+    color: '#718096',
+    marginBottom: 8,
+  },
+  bands: {
+    display: 'flex',
+    flexDirection: 'column' as const,
+    gap: 3,
+    marginBottom: 6,
+  },
+  band: {
+    display: 'flex',
+    alignItems: 'center',
+    gap: 6,
+  },
+  bandBg: {
+    flex: 1,
+    height: 5,
+    borderRadius: 3,
+    backgroundColor: '#edf2f7',
+    overflow: 'hidden',
+  },
+  bandFill: {
+    height: '100%',
+    borderRadius: 3,
+  },
+  bandLabel: {
+    fontSize: '0.65rem',
+    color: '#718096',
+    width: 56,
+    textAlign: 'right' as const,
+  },
+  footnote: {
+    fontSize: '0.65rem',
+    color: '#a0aec0',
+    textAlign: 'center' as const,
+  },
+};
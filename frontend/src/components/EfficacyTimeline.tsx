@@ -0,0 +1,229 @@
+import { useEffect, useState } from 'react';
+import { getEfficacyReport } from '../api/client';
+import { appTypeBadgeStyle, sharedStyles } from '../styles/shared';
+import type { EfficacyDay, EfficacyReport, TimingVerdict } from '../types';
+import { APPLICATION_TYPE_COLORS, APPLICATION_TYPE_LABELS } from '../types';
+
+const VERDICT_LABELS: Record<TimingVerdict, string> = {
+  Early: 'Early',
+  OnTime: 'On time',
+  Late: 'Late',
+  Unknown: 'No call',
+};
+
+const VERDICT_COLORS: Record<TimingVerdict, string> = {
+  Early: '#d69e2e',
+  OnTime: '#38a169',
+  Late: '#e53e3e',
+  Unknown: '#a0aec0',
+};
+
+/** Timeline covers the growing season only. */
+const SEASON_START_MONTH = 3;
+const SEASON_END_MONTH = 11;
+
+function soilColor(temp: number | null): string {
+  if (temp === null) return '#edf2f7';
+  if (temp < 50) return '#bee3f8';
+  if (temp < 55) return '#9ae6b4';
+  if (temp < 60) return '#68d391';
+  if (temp < 70) return '#f6e05e';
+  return '#f6ad55';
+}
+
+function formatDay(iso: string): string {
+  return new Date(iso + 'T00:00:00').toLocaleDateString(undefined, {
+    month: 'short',
+    day: 'numeric',
+  });
+}
+
+function inSeason(day: EfficacyDay): boolean {
+  const month = Number(day.date.slice(5, 7));
+  return month >= SEASON_START_MONTH && month <= SEASON_END_MONTH;
+}
+
+export default function EfficacyTimeline({ year }: { year: number }) {
+  const [report, setReport] = useState<EfficacyReport | null>(null);
+  const [loading, setLoading] = useState(true);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    let cancelled = false;
+    setLoading(true);
+    (async () => {
+      try {
+        const r = await getEfficacyReport(year);
+        if (cancelled) return;
+        setReport(r);
+        setError(null);
+      } catch (e) {
+        if (!cancelled) setError(e instanceof Error ? e.message : 'Failed to load efficacy');
+      } finally {
+        if (!cancelled) setLoading(false);
+      }
+    })();
+    return () => {
+      cancelled = true;
+    };
+  }, [year]);
+
+  const days = report?.days.filter(inSeason) ?? [];
+  const dayIndex = new Map(days.map((d, i) => [d.date, i]));
+
+  return (
+    <div style={sharedStyles.card}>
+      <h2 style={styles.heading}>Timing vs. conditions</h2>
+      <p style={styles.help}>
+        Each application against the soil temperatures and disease-favorable days that
+        followed it. Pre-emergents are judged against when the 7-day soil average crossed
+        50/55/60°F; fungicides by the risk days their protection window covered.
+      </p>
+
+      {error && <div style={sharedStyles.error}>{error}</div>}
+      {loading && <p style={sharedStyles.loading}>Loading...</p>}
+
+      {report && !loading && (
+        <>
+          {days.length > 0 && (
+            <div style={styles.timeline}>
+              <div style={styles.strip}>
+                {days.map((d) => (
+                  <div
+                    key={d.date}
+                    title={`${formatDay(d.date)}: soil ${
+                      d.soil_temp_10_f?.toFixed(1) ?? '—'
+                    }°F${d.disease_risk ? ', disease-favorable' : ''}`}
+                    style={{ ...styles.day, backgroundColor: soilColor(d.soil_temp_10_f) }}
+                  >
+                    {d.disease_risk && <div style={styles.risk} />}
+                  </div>
+                ))}
+              </div>
+              <div style={styles.markers}>
+                {report.entries.map((e) => {
+                  const i = dayIndex.get(e.date);
+                  if (i === undefined) return null;
+                  return (
+                    <div
+                      key={`${e.application_id}-${e.date}`}
+                      title={`${formatDay(e.date)}: ${APPLICATION_TYPE_LABELS[e.application_type]}`}
+                      style={{
+                        ...styles.marker,
+                        left: `${(i / days.length) * 100}%`,
+                        backgroundColor: APPLICATION_TYPE_COLORS[e.application_type],
+                      }}
+                    />
+                  );
+                })}
+              </div>
+              <div style={styles.axis}>
+                <span>{formatDay(days[0].date)}</span>
+                <span>{formatDay(days[days.length - 1].date)}</span>
+              </div>
+              <div style={styles.legend}>
+                Soil: <span style={{ ...styles.swatch, backgroundColor: soilColor(45) }} />
+                &lt;50°F <span style={{ ...styles.swatch, backgroundColor: soilColor(52) }} />
+                50–55 <span style={{ ...styles.swatch, backgroundColor: soilColor(57) }} />
+                55–60 <span style={{ ...styles.swatch, backgroundColor: soilColor(65) }} />
+                60–70 <span style={{ ...styles.swatch, backgroundColor: soilColor(75) }} />
+                70+ · <span style={{ ...styles.swatch, backgroundColor: '#c53030' }} /> disease
+                risk ({report.disease_risk_days} days)
+              </div>
+            </div>
+          )}
+
+          {report.entries.length === 0 ? (
+            <p style={styles.help}>No applications logged in {year}.</p>
+          ) : (
+            <table style={sharedStyles.table}>
+              <thead>
+                <tr>
+                  <th style={sharedStyles.th}>Date</th>
+                  <th style={sharedStyles.th}>Application</th>
+                  <th style={sharedStyles.th}>Timing</th>
+                  <th style={sharedStyles.th}>What followed</th>
+                </tr>
+              </thead>
+              <tbody>
+                {report.entries.map((e) => (
+                  <tr key={`${e.application_id}-${e.date}`}>
+                    <td style={{ ...sharedStyles.td, whiteSpace: 'nowrap' }}>
+                      {formatDay(e.date)}
+                    </td>
+                    <td style={sharedStyles.td}>
+                      <span style={appTypeBadgeStyle(sharedStyles.badge, e.application_type)}>
+                        {APPLICATION_TYPE_LABELS[e.application_type]}
+                      </span>
+                      {e.product_name && <div style={styles.product}>{e.product_name}</div>}
+                    </td>
+                    <td
+                      style={{
+                        ...sharedStyles.td,
+                        color: e.verdict ? VERDICT_COLORS[e.verdict] : '#a0aec0',
+                        fontWeight: 600,
+                      }}
+                    >
+                      {e.verdict ? VERDICT_LABELS[e.verdict] : '—'}
+                    </td>
+                    <td style={sharedStyles.td}>
+                      {e.summary}
+                      {e.observations.map((o) => (
+                        <div key={`${o.date}-${o.notes}`} style={styles.observation}>
+                          {formatDay(o.date)} · {o.category}: {o.notes}
+                        </div>
+                      ))}
+                    </td>
+                  </tr>
+                ))}
+              </tbody>
+            </table>
+          )}
+        </>
+      )}
+    </div>
+  );
+}
+
+const styles: Record<string, React.CSSProperties> = {
+  heading: { fontSize: '1.1rem', margin: '0 0 0.5rem', color: '#2d3748' },
+  help: { color: '#718096', fontSize: '0.85rem', marginTop: 0 },
+  timeline: { marginBottom: '1rem' },
+  strip: { display: 'flex', height: 28, borderRadius: 4, overflow: 'hidden' },
+  day: { flex: 1, position: 'relative' },
+  risk: {
+    position: 'absolute',
+    top: 0,
+    left: 0,
+    right: 0,
+    height: 6,
+    backgroundColor: '#c53030',
+  },
+  markers: { position: 'relative', height: 14 },
+  marker: {
+    position: 'absolute',
+    top: 2,
+    width: 10,
+    height: 10,
+    borderRadius: '50%',
+    transform: 'translateX(-50%)',
+    border: '1px solid #fff',
+  },
+  axis: {
+    display: 'flex',
+    justifyContent: 'space-between',
+    fontSize: '0.75rem',
+    color: '#a0aec0',
+  },
+  legend: { fontSize: '0.75rem', color: '#718096', marginTop: 4 },
+  swatch: {
+    display: 'inline-block',
+    width: 10,
+    height: 10,
+    borderRadius: 2,
+    margin: '0 2px 0 6px',
+    verticalAlign: 'middle',
+  },
+  product: { fontSize: '0.75rem', color: '#718096', marginTop: 2 },
+  observation: { fontSize: '0.75rem', color: '#805ad5', marginTop: 2 },
+};
@@ -0,0 +1,43 @@
+import type { CSSProperties } from 'react';
+
+interface EnumSelectProps<T extends string> {
+  options: readonly T[];
+  labels: Record<T, string>;
+  value: T | '';
+  onChange: (value: T | '') => void;
+  /** Text for a blank choice; omit when a value is required. */
+  emptyLabel?: string;
+  style?: CSSProperties;
+  ariaLabel?: string;
+}
+
+/**
+ * Pick one value of a backend enum by its display label. Typing jumps to
+ * (and repeated keys cycle through) labels starting with those letters, so
+ * nobody has to spell out "KentuckyBluegrass".
+ */
+export default function EnumSelect<T extends string>({
+  options,
+  labels,
+  value,
+  onChange,
+  emptyLabel,
+  style,
+  ariaLabel,
+}: EnumSelectProps<T>) {
+  return (
+    <select
+      style={style}
+      value={value}
+      onChange={(e) => onChange(e.target.value as T | '')}
+      aria-label={ariaLabel}
+    >
+      {emptyLabel !== undefined && <option value="">{emptyLabel}</option>}
+      {options.map((option) => (
+        <option key={option} value={option}>
+          {labels[option]}
+        </option>
+      ))}
+    </select>
+  );
+}
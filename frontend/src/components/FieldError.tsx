@@ -0,0 +1,9 @@
+/** Validation message shown under a form field. */
+export default function FieldError({ message }: { message?: string }) {
+  if (!message) return null;
+  return (
+    <div role="alert" style={{ fontSize: '0.8rem', color: '#c53030', marginTop: 4 }}>
+      {message}
+    </div>
+  );
+}
@@ -0,0 +1,73 @@
+import { sharedStyles } from '../styles/shared';
+import type { MinutelyPrecipitation, WeatherForecast } from '../types';
+import { mmToInches } from '../utils/units';
+
+/** One line on rain in the next hour from One Call's minutely data. */
+function nextHourRain(minutely: MinutelyPrecipitation[]): string {
+  const first = minutely.find((m) => m.precipitation_mm > 0);
+  if (!first) return 'No rain expected in the next hour';
+  const total = minutely.reduce((sum, m) => sum + m.precipitation_mm, 0);
+  const minutes = Math.max(
+    0,
+    Math.round((new Date(first.timestamp).getTime() - Date.now()) / 60_000),
+  );
+  const when = minutes === 0 ? 'now' : `in ${minutes} min`;
+  return `Rain starting ${when}, ${mmToInches(total).toFixed(2)} in over the next hour`;
+}
+
+/** Daily forecast cards, with next-hour rain when One Call provides it. */
+export default function ForecastStrip({ forecast }: { forecast: WeatherForecast | null }) {
+  if (!forecast || forecast.daily_summary.length === 0) return null;
+
+  return (
+    <>
+      <h2 style={sharedStyles.sectionTitle}>
+        {forecast.daily_summary.length}-Day Forecast
+      </h2>
+      {forecast.minutely && forecast.minutely.length > 0 && (
+        <div style={styles.nextHourRain}>{nextHourRain(forecast.minutely)}</div>
+      )}
+      <div style={styles.forecastGrid}>
+        {forecast.daily_summary.map((day) => (
+          <div key={day.date} style={styles.forecastCard}>
+            <div style={styles.forecastDate}>{day.date}</div>
+            <div style={styles.forecastCondition}>
+              {day.dominant_condition}
+            </div>
+            <div style={styles.forecastTemp}>
+              {day.high_temp_f.toFixed(0)}{'\u00B0'} / {day.low_temp_f.toFixed(0)}{'\u00B0'}
+            </div>
+            <div style={styles.forecastDetail}>
+              Humidity: {day.avg_humidity.toFixed(0)}%
+            </div>
+            <div style={styles.forecastDetail}>
+              Precip: {mmToInches(day.total_precipitation_mm).toFixed(2)} in (
+              {(day.max_precipitation_prob * 100).toFixed(0)}%)
+            </div>
+          </div>
+        ))}
+      </div>
+    </>
+  );
+}
+
+const styles: Record<string, React.CSSProperties> = {
+  forecastGrid: {
+    display: 'grid',
+    gridTemplateColumns: 'repeat(auto-fit, minmax(150px, 1fr))',
+    gap: '0.75rem',
+    marginBottom: '1.5rem',
+  },
+  forecastCard: {
+    backgroundColor: '#fff',
+    borderRadius: 8,
+    padding: '0.8rem',
+    boxShadow: '0 1px 3px rgba(0,0,0,0.08)',
+    textAlign: 'center' as const,
+  },
+  forecastDate: { fontSize: '0.8rem', fontWeight: 600, color: '#2d3748' },
+  forecastCondition: { fontSize: '0.85rem', color: '#4a5568', margin: '4px 0' },
+  forecastTemp: { fontSize: '1.1rem', fontWeight: 600, color: '#1a202c' },
+  forecastDetail: { fontSize: '0.7rem', color: '#718096', marginTop: 2 },
+  nextHourRain: { fontSize: '0.85rem', color: '#4a5568', marginBottom: '0.75rem' },
+};
@@ -0,0 +1,78 @@
+import { useEffect, useState } from 'react';
+import { getFrostDates } from '../api/client';
+import { sharedStyles } from '../styles/shared';
+import type { FrostDates, FrostDistribution } from '../types';
+
+function formatDay(date: string): string {
+  return new Date(date + 'T00:00:00').toLocaleDateString('en-US', {
+    month: 'short',
+    day: 'numeric',
+  });
+}
+
+function sourceLabel(d: FrostDistribution): string {
+  return d.source === 'StationHistory'
+    ? `${d.years} years of station lows`
+    : 'USDA zone estimate';
+}
+
+/** This year's last spring and first fall frost with the 10% risk dates. */
+export default function FrostDatesCard() {
+  const [frost, setFrost] = useState<FrostDates | null>(null);
+
+  useEffect(() => {
+    let cancelled = false;
+    getFrostDates()
+      .then((f) => {
+        if (!cancelled) setFrost(f);
+      })
+      .catch(() => {});
+    return () => {
+      cancelled = true;
+    };
+  }, []);
+
+  if (!frost || (!frost.last_spring && !frost.first_fall)) return null;
+
+  const rows: [string, FrostDistribution | null, string | null][] = [
+    ['Last spring frost', frost.last_spring, frost.spring_advice],
+    ['First fall frost', frost.first_fall, frost.fall_advice],
+  ];
+
+  return (
+    <>
+      <h2 style={sharedStyles.sectionTitle}>Frost Dates</h2>
+      <div style={styles.grid}>
+        {rows.map(([label, dist, advice]) =>
+          dist ? (
+            <div key={label} style={styles.card}>
+              <div style={styles.label}>{label}</div>
+              <div style={styles.value}>~{formatDay(dist.median)}</div>
+              {advice && <div style={styles.advice}>{advice}</div>}
+              <div style={styles.source}>{sourceLabel(dist)}</div>
+            </div>
+          ) : null
+        )}
+      </div>
+    </>
+  );
+}
+
+const styles: Record<string, React.CSSProperties> = {
+  grid: {
+    display: 'grid',
+    gridTemplateColumns: 'repeat(auto-fit, minmax(220px, 1fr))',
+    gap: '1rem',
+    marginBottom: '1.5rem',
+  },
+  card: {
+    backgroundColor: '#fff',
+    borderRadius: 8,
+    padding: '0.8rem 1rem',
+    boxShadow: '0 1px 3px rgba(0,0,0,0.08)',
+  },
+  label: { fontSize: '0.75rem', color: '#718096', marginBottom: 4 },
+  value: { fontSize: '1.2rem', fontWeight: 600, color: '#2d3748' },
+  advice: { fontSize: '0.85rem', color: '#2b6cb0', marginTop: 4 },
+  source: { fontSize: '0.7rem', color: '#a0aec0', marginTop: 4 },
+};
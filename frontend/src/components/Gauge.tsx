@@ -1,10 +1,21 @@
+import { formatAge } from '../utils/freshness';
+import type { GaugeBand, GaugeMarker, GaugeThresholds } from './gaugeConfigs';
+
 interface GaugeProps {
   label: string;
   value: number | null;
   unit: string;
   min: number;
   max: number;
-  thresholds?: { warn: number; critical: number };
+  thresholds?: GaugeThresholds;
+  /** Agronomic ranges drawn under the bar (one row each). */
+  bands?: GaugeBand[];
+  /** Single points ticked across the bar. */
+  markers?: GaugeMarker[];
+  /** Observation time when the value is stale; dims the gauge. */
+  staleSince?: string | null;
+  /** Tooltip on the bar, e.g. where an adaptive range came from. */
+  rangeNote?: string;
 }
 
 export default function Gauge({
@@ -14,25 +25,40 @@ export default function Gauge({
   min,
   max,
   thresholds,
+  bands = [],
+  markers = [],
+  staleSince = null,
+  rangeNote,
 }: GaugeProps) {
-  const pct =
-    value !== null ? Math.min(100, Math.max(0, ((value - min) / (max - min)) * 100)) : 0;
+  const toPct = (v: number) => Math.min(100, Math.max(0, ((v - min) / (max - min)) * 100));
+  const pct = value !== null ? toPct(value) : 0;
 
   let barColor = '#48bb78'; // green
   if (value !== null && thresholds) {
     if (value >= thresholds.critical) barColor = '#fc8181';
     else if (value >= thresholds.warn) barColor = '#ecc94b';
+    else if (thresholds.lowCritical !== undefined && value <= thresholds.lowCritical)
+      barColor = '#fc8181';
+    else if (thresholds.lowWarn !== undefined && value <= thresholds.lowWarn)
+      barColor = '#ecc94b';
   }
 
+  const activeBands = value !== null ? bands.filter((b) => value >= b.from && value <= b.to) : [];
+
   return (
-    <div style={styles.container}>
+    <div style={staleSince ? { ...styles.container, ...styles.stale } : styles.container}>
       <div style={styles.header}>
         <span style={styles.label}>{label}</span>
         <span style={styles.value}>
           {value !== null ? value.toFixed(1) : '--'} {unit}
         </span>
       </div>
-      <div style={styles.track}>
+      {staleSince && (
+        <div style={styles.staleNote} title={new Date(staleSince).toLocaleString()}>
+          Stale: last reading {formatAge(staleSince)}, ignored by rules
+        </div>
+      )}
+      <div style={styles.track} title={rangeNote}>
         <div
           style={{
             ...styles.bar,
@@ -40,22 +66,70 @@ export default function Gauge({
             backgroundColor: barColor,
           }}
         />
+        {markers.map((m) => (
+          <div
+            key={m.label}
+            title={`${m.label}: ${m.at}${unit}`}
+            style={{ ...styles.tick, left: `${toPct(m.at)}%` }}
+          />
+        ))}
       </div>
+      {bands.map((b) => (
+        <div key={b.label} style={styles.bandRow} title={`${b.label}: ${b.from}–${b.to}${unit}`}>
+          <div
+            style={{
+              ...styles.band,
+              left: `${toPct(b.from)}%`,
+              width: `${toPct(b.to) - toPct(b.from)}%`,
+              backgroundColor: b.color,
+            }}
+          />
+        </div>
+      ))}
+      {(bands.length > 0 || markers.length > 0) && (
+        <div style={styles.legend}>
+          {bands.map((b) => (
+            <span
+              key={b.label}
+              style={{
+                ...styles.legendItem,
+                fontWeight: activeBands.includes(b) ? 700 : 400,
+              }}
+            >
+              <span style={{ ...styles.swatch, backgroundColor: b.color }} />
+              {b.label} {b.from}–{b.to}
+              {unit}
+            </span>
+          ))}
+          {markers.map((m) => (
+            <span key={m.label} style={styles.legendItem}>
+              <span style={styles.tickSwatch} />
+              {m.label} {m.at}
+              {unit}
+            </span>
+          ))}
+        </div>
+      )}
     </div>
   );
 }
 
 const styles: Record<string, React.CSSProperties> = {
   container: { marginBottom: '1rem' },
+  stale: { opacity: 0.55 },
+  staleNote: { fontSize: '0.7rem', color: '#c05621', marginBottom: 4 },
   header: {
     display: 'flex',
+    flexWrap: 'wrap' as const,
     justifyContent: 'space-between',
+    columnGap: 8,
     marginBottom: 4,
     fontSize: '0.85rem',
   },
   label: { color: '#4a5568', fontWeight: 500 },
   value: { color: '#2d3748', fontWeight: 600 },
   track: {
+    position: 'relative',
     height: 8,
     backgroundColor: '#e2e8f0',
     borderRadius: 4,
@@ -66,4 +140,26 @@ const styles: Record<string, React.CSSProperties> = {
     borderRadius: 4,
     transition: 'width 0.4s ease',
   },
+  tick: {
+    position: 'absolute',
+    top: 0,
+    bottom: 0,
+    width: 2,
+    marginLeft: -1,
+    backgroundColor: '#2d3748',
+    opacity: 0.6,
+  },
+  bandRow: { position: 'relative', height: 4, marginTop: 2 },
+  band: { position: 'absolute', top: 0, bottom: 0, borderRadius: 2 },
+  legend: {
+    display: 'flex',
+    flexWrap: 'wrap' as const,
+    columnGap: 10,
+    marginTop: 4,
+    fontSize: '0.7rem',
+    color: '#718096',
+  },
+  legendItem: { display: 'inline-flex', alignItems: 'center', gap: 3 },
+  swatch: { display: 'inline-block', width: 8, height: 8, borderRadius: 2 },
+  tickSwatch: { display: 'inline-block', width: 2, height: 8, backgroundColor: '#4a5568' },
 };
@@ -51,6 +51,12 @@ export default function GddWidget({ data }: GddWidgetProps) {
         />
       </div>
 
+      {crabgrass_model.germination_pct > 0 && (
+        <div style={styles.germination}>
+          Crabgrass germination ~{crabgrass_model.germination_pct.toFixed(0)}% complete
+        </div>
+      )}
+
       {sparkData.length > 5 && (
         <div style={styles.sparkContainer}>
           <ResponsiveContainer width="100%" height={50}>
@@ -135,6 +141,12 @@ const styles: Record<string, React.CSSProperties> = {
     borderRadius: 3,
     transition: 'width 0.3s ease',
   },
+  germination: {
+    fontSize: '0.8rem',
+    fontWeight: 600,
+    color: '#4a5568',
+    marginBottom: 4,
+  },
   sparkContainer: {
     marginTop: 4,
     marginBottom: 4,
@@ -0,0 +1,84 @@
+import type { GerminationStatus } from '../types';
+
+interface GerminationWidgetProps {
+  status: GerminationStatus;
+}
+
+function formatDate(date: string): string {
+  return new Date(date + 'T00:00:00').toLocaleDateString(undefined, {
+    month: 'short',
+    day: 'numeric',
+  });
+}
+
+export default function GerminationWidget({ status }: GerminationWidgetProps) {
+  const tracking = status.day <= status.tracking_days;
+  const pct = Math.min(100, (status.day / status.tracking_days) * 100);
+
+  return (
+    <div style={styles.card}>
+      <div style={styles.label}>Germination</div>
+      <div style={styles.headline}>
+        {tracking
+          ? `Day ${status.day} of ${status.tracking_days}`
+          : 'Seedlings establishing'}
+      </div>
+      {tracking && (
+        <div style={styles.track}>
+          <div style={{ ...styles.fill, width: `${pct}%` }} />
+        </div>
+      )}
+      <div style={styles.detail}>Seeded {formatDate(status.seeded_on)}</div>
+      <div style={styles.detail}>
+        Herbicides and pre-emergents held until {formatDate(status.herbicide_safe_on)}
+      </div>
+      <div style={styles.hint}>
+        {tracking
+          ? 'Keep the seedbed moist; watering reminders appear in alerts.'
+          : 'Water deeper and less often as roots establish.'}
+      </div>
+    </div>
+  );
+}
+
+const styles: Record<string, React.CSSProperties> = {
+  card: {
+    backgroundColor: '#fff',
+    borderRadius: 8,
+    padding: '1rem',
+    boxShadow: '0 1px 3px rgba(0,0,0,0.08)',
+  },
+  label: {
+    fontSize: '0.75rem',
+    fontWeight: 600,
+    color: '#718096',
+    textTransform: 'uppercase' as const,
+    marginBottom: 6,
+  },
+  headline: {
+    fontSize: '1.25rem',
+    fontWeight: 700,
+    color: '#1a202c',
+  },
+  track: {
+    height: 6,
+    backgroundColor: '#edf2f7',
+    borderRadius: 3,
+    margin: '6px 0',
+    overflow: 'hidden',
+  },
+  fill: {
+    height: '100%',
+    backgroundColor: '#38a169',
+  },
+  detail: {
+    fontSize: '0.8rem',
+    color: '#4a5568',
+    marginTop: 2,
+  },
+  hint: {
+    fontSize: '0.7rem',
+    color: '#a0aec0',
+    marginTop: 6,
+  },
+};
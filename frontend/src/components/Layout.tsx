@@ -1,52 +1,103 @@
+import { useState } from 'react';
 import { NavLink, Outlet } from 'react-router-dom';
+import { useCompactLayout } from '../hooks/useCompactLayout';
+import { t } from '../utils/i18n';
+import { confirmDiscard } from '../utils/unsavedChanges';
+import QuickActions from './QuickActions';
+import { useRefresh } from './refreshContext';
 
 const NAV_ITEMS = [
   { to: '/', label: 'Dashboard' },
   { to: '/applications', label: 'Applications' },
+  { to: '/inventory', label: 'Inventory' },
+  { to: '/equipment', label: 'Equipment' },
   { to: '/landscape', label: 'Landscape' },
   { to: '/calendar', label: 'Calendar' },
+  { to: '/journal', label: 'Journal' },
+  { to: '/photos', label: 'Photos' },
   { to: '/environmental', label: 'Environmental' },
   { to: '/recommendations', label: 'Recommendations' },
   { to: '/soil-tests', label: 'Soil Tests' },
   { to: '/seasonal-plan', label: 'Seasonal Plan' },
+  { to: '/review', label: 'Annual Review' },
+  { to: '/activity', label: 'Activity Log' },
   { to: '/settings', label: 'Settings' },
 ];
 
 export default function Layout() {
+  const compact = useCompactLayout();
+  const [menuOpen, setMenuOpen] = useState(false);
+  const showNav = !compact || menuOpen;
+  const { refreshing, deep, generation, refresh } = useRefresh();
+
   return (
-    <div style={{ display: 'flex', minHeight: '100vh' }}>
-      <nav style={styles.sidebar} aria-label="Main navigation">
+    <div style={compact ? styles.shellCompact : styles.shell}>
+      <nav style={compact ? styles.topbar : styles.sidebar} aria-label="Main navigation">
         <div style={styles.logo}>
           <span style={{ fontSize: '1.4rem' }}>TurfOps</span>
+          <button
+            style={styles.refreshBtn}
+            onClick={(e) => refresh(e.shiftKey)}
+            disabled={refreshing}
+            title={t('Refresh (r) · Deep refresh (Shift+R)')}
+            aria-label={refreshing ? t('Refreshing…') : t('Refresh')}
+          >
+            <span style={refreshing ? styles.spinning : undefined} aria-hidden="true">
+              ↻
+            </span>
+            {refreshing && deep && <span style={styles.deepLabel}>{t('deep')}</span>}
+          </button>
+          {compact && (
+            <button
+              style={styles.menuBtn}
+              aria-expanded={menuOpen}
+              onClick={() => setMenuOpen(!menuOpen)}
+            >
+              {menuOpen ? t('Close') : t('Menu')}
+            </button>
+          )}
         </div>
-        <ul style={styles.navList} role="list">
-          {NAV_ITEMS.map((item) => (
-            <li key={item.to}>
-              <NavLink
-                to={item.to}
-                end={item.to === '/'}
-                aria-current={undefined}
-                style={({ isActive }) => ({
-                  ...styles.navLink,
-                  backgroundColor: isActive ? '#2d3748' : 'transparent',
-                  color: isActive ? '#68d391' : '#cbd5e0',
-                  fontWeight: isActive ? 700 : 400,
-                })}
-              >
-                {item.label}
-              </NavLink>
-            </li>
-          ))}
-        </ul>
+        {showNav && (
+          <ul style={styles.navList} role="list">
+            {NAV_ITEMS.map((item) => (
+              <li key={item.to}>
+                <NavLink
+                  to={item.to}
+                  end={item.to === '/'}
+                  aria-current={undefined}
+                  onClick={(e) => {
+                    if (!confirmDiscard()) {
+                      e.preventDefault();
+                      return;
+                    }
+                    setMenuOpen(false);
+                  }}
+                  style={({ isActive }) => ({
+                    ...styles.navLink,
+                    backgroundColor: isActive ? '#2d3748' : 'transparent',
+                    color: isActive ? '#68d391' : '#cbd5e0',
+                    fontWeight: isActive ? 700 : 400,
+                  })}
+                >
+                  {t(item.label)}
+                </NavLink>
+              </li>
+            ))}
+          </ul>
+        )}
       </nav>
-      <main style={styles.content} role="main">
-        <Outlet />
+      <main style={compact ? styles.contentCompact : styles.content} role="main">
+        {/* A finished refresh remounts the page so it reloads its data */}
+        <Outlet key={generation} />
       </main>
+      <QuickActions pages={NAV_ITEMS} />
     </div>
   );
 }
 
 const styles: Record<string, React.CSSProperties> = {
+  shell: { display: 'flex', minHeight: '100vh' },
+  shellCompact: { display: 'flex', flexDirection: 'column', minHeight: '100vh' },
   sidebar: {
     width: 220,
     backgroundColor: '#1a202c',
@@ -55,11 +106,46 @@ const styles: Record<string, React.CSSProperties> = {
     flexDirection: 'column',
     flexShrink: 0,
   },
+  topbar: {
+    backgroundColor: '#1a202c',
+    color: '#fff',
+  },
   logo: {
+    display: 'flex',
+    justifyContent: 'space-between',
+    alignItems: 'center',
     padding: '1.2rem 1rem',
     borderBottom: '1px solid #2d3748',
     fontWeight: 700,
   },
+  refreshBtn: {
+    display: 'flex',
+    alignItems: 'center',
+    gap: 4,
+    marginLeft: 'auto',
+    marginRight: 8,
+    padding: '0.2rem 0.5rem',
+    backgroundColor: 'transparent',
+    color: '#cbd5e0',
+    border: '1px solid #4a5568',
+    borderRadius: 6,
+    cursor: 'pointer',
+    fontSize: '1rem',
+  },
+  spinning: {
+    display: 'inline-block',
+    animation: 'spin 0.8s linear infinite',
+  },
+  deepLabel: { fontSize: '0.7rem' },
+  menuBtn: {
+    padding: '0.3rem 0.75rem',
+    backgroundColor: 'transparent',
+    color: '#cbd5e0',
+    border: '1px solid #4a5568',
+    borderRadius: 6,
+    cursor: 'pointer',
+    fontSize: '0.85rem',
+  },
   navList: {
     listStyle: 'none',
     margin: 0,
@@ -76,8 +162,17 @@ const styles: Record<string, React.CSSProperties> = {
   },
   content: {
     flex: 1,
+    // Let wide tables scroll inside the page instead of widening it
+    minWidth: 0,
     padding: '1.5rem 2rem',
     backgroundColor: '#f7fafc',
     overflowY: 'auto' as const,
   },
+  contentCompact: {
+    flex: 1,
+    minWidth: 0,
+    padding: '1rem',
+    backgroundColor: '#f7fafc',
+    overflowX: 'auto' as const,
+  },
 };
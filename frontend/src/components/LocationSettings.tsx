@@ -0,0 +1,292 @@
+import { useEffect, useState } from 'react';
+import {
+  clearProfileLocation,
+  getLocation,
+  getProfile,
+  searchLocation,
+  setLocation,
+  updateProfile,
+  type LocationScope,
+} from '../api/client';
+import { sharedStyles } from '../styles/shared';
+import type { Location } from '../types';
+import { useToast } from './toastContext';
+
+function formatCoords(l: Location): string {
+  return `${l.latitude.toFixed(4)}, ${l.longitude.toFixed(4)}`;
+}
+
+export default function LocationSettings() {
+  const [current, setCurrent] = useState<Location | null | undefined>(undefined);
+  const [query, setQuery] = useState('');
+  const [results, setResults] = useState<Location[] | null>(null);
+  const [searching, setSearching] = useState(false);
+  const [saving, setSaving] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+  const [profileZone, setProfileZone] = useState<string | null>(null);
+  const [profileName, setProfileName] = useState('');
+  const [hasOwnLocation, setHasOwnLocation] = useState(false);
+  const [scope, setScope] = useState<LocationScope>('global');
+  const { notify } = useToast();
+
+  useEffect(() => {
+    let cancelled = false;
+    Promise.all([getLocation(), getProfile()])
+      .then(([l, p]) => {
+        if (cancelled) return;
+        setCurrent(l);
+        setProfileZone(p.usda_zone);
+        setProfileName(p.name);
+        setHasOwnLocation(p.location !== null);
+        setScope(p.location !== null ? 'profile' : 'global');
+      })
+      .catch((e) => {
+        if (!cancelled) setError(e instanceof Error ? e.message : 'Failed to load location');
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, []);
+
+  const handleSearch = async (e: React.FormEvent) => {
+    e.preventDefault();
+    if (!query.trim()) return;
+    setSearching(true);
+    setError(null);
+    try {
+      setResults(await searchLocation(query.trim()));
+    } catch (e) {
+      setError(e instanceof Error ? e.message : 'Geocoding failed');
+    } finally {
+      setSearching(false);
+    }
+  };
+
+  const handleUse = async (l: Location) => {
+    setSaving(true);
+    try {
+      const saved = await setLocation(l, scope);
+      // A new global default doesn't change a profile that has its own location
+      if (scope === 'profile' || !hasOwnLocation) setCurrent(saved);
+      if (scope === 'profile') setHasOwnLocation(true);
+      setResults(null);
+      setQuery('');
+      notify(
+        `${scope === 'profile' ? `${profileName} forecast` : 'Default forecast'} location set to ${
+          saved.name || formatCoords(saved)
+        }`,
+        'success'
+      );
+    } catch (e) {
+      notify(e instanceof Error ? e.message : 'Failed to save location', 'error');
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  const handleClearOwn = async () => {
+    try {
+      setCurrent(await clearProfileLocation());
+      setHasOwnLocation(false);
+      setScope('global');
+      notify(`${profileName} now uses the default location`, 'success');
+    } catch (e) {
+      notify(e instanceof Error ? e.message : 'Failed to clear location', 'error');
+    }
+  };
+
+  const handleUseZone = async (zone: string) => {
+    try {
+      const updated = await updateProfile({ usda_zone: zone });
+      setProfileZone(updated.usda_zone);
+      notify(`Lawn profile zone set to ${updated.usda_zone}`, 'success');
+    } catch (e) {
+      notify(e instanceof Error ? e.message : 'Failed to update profile', 'error');
+    }
+  };
+
+  const locationZone = current?.usda_zone ?? null;
+  const zoneMismatch =
+    locationZone !== null && profileZone !== null && locationZone !== profileZone;
+
+  if (current === undefined && !error) {
+    return <div style={sharedStyles.loading}>Loading location...</div>;
+  }
+
+  return (
+    <div style={sharedStyles.card}>
+      {error && <div style={sharedStyles.error}>{error}</div>}
+
+      {current === null ? (
+        <p style={styles.help}>
+          Forecasts are disabled. Set <code>OWM_API_KEY</code> to enable OpenWeatherMap and
+          location search.
+        </p>
+      ) : (
+        current && (
+          <>
+            <div style={styles.currentLabel}>Forecast location</div>
+            <div style={styles.current}>
+              {current.name || 'Coordinates from configuration'}
+              <span style={styles.coords}>{formatCoords(current)}</span>
+              {locationZone && <span style={styles.zone}>USDA zone {locationZone}</span>}
+            </div>
+            {hasOwnLocation && (
+              <p style={styles.help}>
+                {profileName} has its own location.{' '}
+                <button style={styles.linkBtn} onClick={handleClearOwn}>
+                  Use the default location instead
+                </button>
+              </p>
+            )}
+
+            {zoneMismatch && (
+              <div style={styles.zoneWarning}>
+                <span>
+                  Your lawn profile says zone {profileZone}, but this location is in zone{' '}
+                  {locationZone}. Rules use the profile zone.
+                </span>
+                <button style={styles.useBtn} onClick={() => handleUseZone(locationZone)}>
+                  Use zone {locationZone}
+                </button>
+              </div>
+            )}
+
+            <form onSubmit={handleSearch} style={styles.searchRow}>
+              <input
+                style={styles.input}
+                value={query}
+                onChange={(e) => setQuery(e.target.value)}
+                placeholder="City (West Chester, PA, US) or ZIP (19382)"
+              />
+              <button type="submit" style={styles.searchBtn} disabled={searching}>
+                {searching ? 'Searching...' : 'Search'}
+              </button>
+            </form>
+
+            <div style={styles.scopeRow} role="radiogroup" aria-label="Apply location to">
+              <label>
+                <input
+                  type="radio"
+                  checked={scope === 'global'}
+                  onChange={() => setScope('global')}
+                />{' '}
+                Default for all profiles
+              </label>
+              <label>
+                <input
+                  type="radio"
+                  checked={scope === 'profile'}
+                  onChange={() => setScope('profile')}
+                />{' '}
+                Only {profileName}
+              </label>
+            </div>
+
+            {results && results.length === 0 && (
+              <p style={styles.help}>No matches. Try adding a state or country code.</p>
+            )}
+            {results?.map((r) => (
+              <div key={`${r.latitude},${r.longitude}`} style={styles.result}>
+                <div>
+                  <div style={styles.resultName}>{r.name}</div>
+                  <div style={styles.coords}>
+                    {formatCoords(r)}
+                    {r.usda_zone && ` · USDA zone ${r.usda_zone}`}
+                  </div>
+                </div>
+                <button style={styles.useBtn} disabled={saving} onClick={() => handleUse(r)}>
+                  Use this location
+                </button>
+              </div>
+            ))}
+          </>
+        )
+      )}
+    </div>
+  );
+}
+
+const styles: Record<string, React.CSSProperties> = {
+  help: { color: '#718096', fontSize: '0.85rem', marginTop: 0 },
+  currentLabel: {
+    fontSize: '0.75rem',
+    fontWeight: 600,
+    color: '#718096',
+    textTransform: 'uppercase' as const,
+    marginBottom: 4,
+  },
+  current: {
+    fontSize: '0.95rem',
+    color: '#2d3748',
+    display: 'flex',
+    gap: 10,
+    alignItems: 'baseline',
+    marginBottom: '1rem',
+  },
+  coords: { fontSize: '0.8rem', color: '#a0aec0' },
+  zone: { fontSize: '0.8rem', color: '#38a169', fontWeight: 600 },
+  zoneWarning: {
+    display: 'flex',
+    justifyContent: 'space-between',
+    alignItems: 'center',
+    gap: 12,
+    padding: '0.6rem 0.75rem',
+    marginBottom: '1rem',
+    backgroundColor: '#fffaf0',
+    border: '1px solid #f6ad55',
+    borderRadius: 6,
+    fontSize: '0.85rem',
+    color: '#7b341e',
+  },
+  searchRow: { display: 'flex', gap: 8, marginBottom: '0.75rem' },
+  scopeRow: {
+    display: 'flex',
+    gap: 16,
+    fontSize: '0.85rem',
+    color: '#4a5568',
+    marginBottom: '0.75rem',
+  },
+  linkBtn: {
+    background: 'none',
+    border: 'none',
+    color: '#3182ce',
+    cursor: 'pointer',
+    fontSize: '0.85rem',
+    padding: 0,
+  },
+  input: {
+    flex: 1,
+    padding: '0.5rem 0.75rem',
+    borderRadius: 6,
+    border: '1px solid #e2e8f0',
+    fontSize: '0.9rem',
+  },
+  searchBtn: {
+    padding: '0.5rem 1rem',
+    backgroundColor: '#3182ce',
+    color: '#fff',
+    border: 'none',
+    borderRadius: 6,
+    cursor: 'pointer',
+    fontWeight: 600,
+  },
+  result: {
+    display: 'flex',
+    justifyContent: 'space-between',
+    alignItems: 'center',
+    padding: '0.5rem 0',
+    borderBottom: '1px solid #edf2f7',
+  },
+  resultName: { fontSize: '0.9rem', color: '#2d3748' },
+  useBtn: {
+    padding: '0.35rem 0.75rem',
+    backgroundColor: '#38a169',
+    color: '#fff',
+    border: 'none',
+    borderRadius: 6,
+    cursor: 'pointer',
+    fontSize: '0.8rem',
+    fontWeight: 600,
+  },
+};
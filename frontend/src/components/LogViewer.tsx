@@ -0,0 +1,131 @@
+import { useCallback, useEffect, useState } from 'react';
+import { errorMessage, getLogTail, isUnavailable } from '../api/client';
+import { sharedStyles } from '../styles/shared';
+import type { LogTail } from '../types';
+
+const LINE_OPTIONS = [100, 200, 500, 1000, 2000];
+
+const LEVEL_COLORS: Record<string, string> = {
+  ERROR: '#fc8181',
+  WARN: '#f6e05e',
+};
+
+function lineColor(line: string): string | undefined {
+  const level = Object.keys(LEVEL_COLORS).find((l) => line.includes(` ${l} `));
+  return level ? LEVEL_COLORS[level] : undefined;
+}
+
+export default function LogViewer() {
+  const [lines, setLines] = useState(200);
+  const [tail, setTail] = useState<LogTail | null>(null);
+  const [disabled, setDisabled] = useState(false);
+  const [loading, setLoading] = useState(true);
+  const [error, setError] = useState<string | null>(null);
+
+  const fetchTail = useCallback(async () => {
+    setLoading(true);
+    try {
+      setTail(await getLogTail(lines));
+      setError(null);
+    } catch (e) {
+      if (isUnavailable(e)) {
+        setDisabled(true);
+      } else {
+        setError(errorMessage(e, 'Failed to load log'));
+      }
+    } finally {
+      setLoading(false);
+    }
+  }, [lines]);
+
+  useEffect(() => {
+    fetchTail();
+  }, [fetchTail]);
+
+  if (disabled) {
+    return (
+      <div style={sharedStyles.card}>
+        <p style={styles.help}>
+          File logging is off. Set <code>LOG_DIR</code> to write a rotating{' '}
+          <code>turfops.log</code> there and view its tail here.
+        </p>
+      </div>
+    );
+  }
+
+  return (
+    <div style={sharedStyles.card}>
+      <div style={styles.toolbar}>
+        <span style={styles.file}>{tail?.file ?? 'No log file yet'}</span>
+        <select
+          style={styles.select}
+          value={lines}
+          onChange={(e) => setLines(Number(e.target.value))}
+          aria-label="Lines to show"
+        >
+          {LINE_OPTIONS.map((n) => (
+            <option key={n} value={n}>
+              Last {n} lines
+            </option>
+          ))}
+        </select>
+        <button style={styles.refreshBtn} onClick={fetchTail} disabled={loading}>
+          {loading ? 'Loading...' : 'Refresh'}
+        </button>
+      </div>
+
+      {error && <div style={sharedStyles.error}>{error}</div>}
+
+      {tail && (
+        <pre style={styles.log}>
+          {tail.lines.length === 0
+            ? 'Log is empty.'
+            : tail.lines.map((line, i) => (
+                <div key={i} style={{ color: lineColor(line) }}>
+                  {line}
+                </div>
+              ))}
+        </pre>
+      )}
+    </div>
+  );
+}
+
+const styles: Record<string, React.CSSProperties> = {
+  help: { color: '#718096', fontSize: '0.85rem', margin: 0 },
+  toolbar: {
+    display: 'flex',
+    alignItems: 'center',
+    gap: 12,
+    flexWrap: 'wrap' as const,
+    marginBottom: '1rem',
+  },
+  file: { flex: 1, fontSize: '0.85rem', color: '#4a5568', fontFamily: 'monospace' },
+  select: {
+    padding: '0.4rem 0.6rem',
+    borderRadius: 6,
+    border: '1px solid #e2e8f0',
+    fontSize: '0.85rem',
+  },
+  refreshBtn: {
+    padding: '0.4rem 1rem',
+    backgroundColor: '#3182ce',
+    color: '#fff',
+    border: 'none',
+    borderRadius: 6,
+    cursor: 'pointer',
+    fontWeight: 600,
+    fontSize: '0.85rem',
+  },
+  log: {
+    margin: 0,
+    padding: '0.75rem',
+    maxHeight: '60vh',
+    overflow: 'auto',
+    backgroundColor: '#1a202c',
+    color: '#e2e8f0',
+    borderRadius: 6,
+    fontSize: '0.75rem',
+    lineHeight: 1.4,
+  },
+};
@@ -0,0 +1,62 @@
+import { sharedStyles } from '../styles/shared';
+import type { NightWetness } from '../types';
+
+/** Wet hours at or above this favor dollar spot infection. */
+const LONG_WETNESS_HOURS = 10;
+
+function nightLabel(evening: string, index: number): string {
+  if (index === 0) return 'Tonight';
+  const day = new Date(evening + 'T00:00:00').toLocaleDateString('en-US', { weekday: 'long' });
+  return `${day} night`;
+}
+
+/** Forecast hours of likely leaf wetness for each of the next nights. */
+export default function OvernightWetnessCard({ nights }: { nights?: NightWetness[] }) {
+  if (!nights || nights.length === 0) return null;
+
+  return (
+    <>
+      <h2 style={sharedStyles.sectionTitle}>Overnight Leaf Wetness</h2>
+      <div style={styles.grid}>
+        {nights.map((night, i) => {
+          const long = night.wet_hours >= LONG_WETNESS_HOURS;
+          return (
+            <div
+              key={night.evening}
+              style={{ ...styles.card, borderTop: `3px solid ${long ? '#dd6b20' : '#e2e8f0'}` }}
+            >
+              <div style={styles.label}>{nightLabel(night.evening, i)}</div>
+              <div style={{ ...styles.value, color: long ? '#c05621' : '#2d3748' }}>
+                {night.wet_hours.toFixed(0)} of {night.forecast_hours.toFixed(0)} hrs wet
+              </div>
+              <div style={styles.detail}>
+                Peak humidity {night.max_humidity_percent.toFixed(0)}%, spread down to{' '}
+                {night.min_dew_point_spread_f.toFixed(1)}°F
+              </div>
+              {long && <div style={styles.warning}>Long enough for dollar spot</div>}
+            </div>
+          );
+        })}
+      </div>
+    </>
+  );
+}
+
+const styles: Record<string, React.CSSProperties> = {
+  grid: {
+    display: 'grid',
+    gridTemplateColumns: 'repeat(auto-fit, minmax(200px, 1fr))',
+    gap: '1rem',
+    marginBottom: '1.5rem',
+  },
+  card: {
+    backgroundColor: '#fff',
+    borderRadius: 8,
+    padding: '0.8rem 1rem',
+    boxShadow: '0 1px 3px rgba(0,0,0,0.08)',
+  },
+  label: { fontSize: '0.75rem', color: '#718096', marginBottom: 4 },
+  value: { fontSize: '1.2rem', fontWeight: 600 },
+  detail: { fontSize: '0.75rem', color: '#718096', marginTop: 4 },
+  warning: { fontSize: '0.8rem', color: '#c05621', marginTop: 4 },
+};
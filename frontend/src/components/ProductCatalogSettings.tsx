@@ -0,0 +1,329 @@
+import { useCallback, useEffect, useState } from 'react';
+import {
+  createLabelProduct,
+  deleteLabelProduct,
+  errorMessage,
+  importLabelProducts,
+  listLabelProducts,
+  updateLabelProduct,
+} from '../api/client';
+import { sharedStyles } from '../styles/shared';
+import type { LabelEntry, LabelFormat, LabelProduct, ReEntry } from '../types';
+import EnumSelect from './EnumSelect';
+import { useToast } from './toastContext';
+
+const REENTRY: readonly ReEntry[] = ['until_dry', 'after_watering_in'];
+const REENTRY_LABELS: Record<ReEntry, string> = {
+  until_dry: 'Until dry',
+  after_watering_in: 'After watering in',
+};
+
+function labelFormat(fileName: string): LabelFormat | null {
+  const ext = fileName.split('.').pop()?.toLowerCase();
+  if (ext === 'yaml' || ext === 'yml') return 'yaml';
+  if (ext === 'csv' || ext === 'json') return ext;
+  return null;
+}
+
+export default function ProductCatalogSettings() {
+  const [products, setProducts] = useState<LabelProduct[]>([]);
+  const [editing, setEditing] = useState<LabelProduct | 'new' | null>(null);
+  const { notify } = useToast();
+
+  const load = useCallback(
+    () =>
+      listLabelProducts()
+        .then(setProducts)
+        .catch((e) => notify(errorMessage(e, 'Failed to load products'), 'error')),
+    [notify]
+  );
+
+  useEffect(() => {
+    load();
+  }, [load]);
+
+  const handleImport = async (e: React.ChangeEvent<HTMLInputElement>) => {
+    const file = e.target.files?.[0];
+    e.target.value = '';
+    if (!file) return;
+    const format = labelFormat(file.name);
+    if (!format) {
+      notify('Label files are .yaml, .csv or .json', 'error');
+      return;
+    }
+    try {
+      const report = await importLabelProducts(format, await file.text());
+      const kept = report.kept.length ? `, kept ${report.kept.length} you edited` : '';
+      notify(`Added ${report.added.length}, updated ${report.updated.length}${kept}`, 'success');
+      await load();
+    } catch (err) {
+      notify(errorMessage(err, 'Failed to import label file'), 'error');
+    }
+  };
+
+  const handleSaved = (saved: LabelProduct) => {
+    setProducts((prev) =>
+      [...prev.filter((p) => p.id !== saved.id), saved].sort((a, b) =>
+        a.name.localeCompare(b.name)
+      )
+    );
+    setEditing(null);
+    notify(`Saved ${saved.name}`, 'success');
+  };
+
+  const handleDelete = async (product: LabelProduct) => {
+    if (!window.confirm(`Delete ${product.name}?`)) return;
+    try {
+      await deleteLabelProduct(product.id);
+      setProducts((prev) => prev.filter((p) => p.id !== product.id));
+      notify(`Deleted ${product.name}`, 'success');
+    } catch (e) {
+      notify(errorMessage(e, 'Failed to delete product'), 'error');
+    }
+  };
+
+  return (
+    <div style={sharedStyles.card}>
+      <p style={styles.help}>
+        Label facts recommendations use for the products you log: watering in, re-entry,
+        pre-emergent residual and FRAC group for fungicide rotation. Import a label file
+        (YAML, CSV or JSON) to add or refresh products; ones you've edited here are kept
+        as you left them. Products not listed fall back to the built-in catalog.
+      </p>
+
+      <label style={{ ...styles.addBtn, display: 'inline-block', marginTop: 0 }}>
+        Import label file
+        <input
+          type="file"
+          accept=".yaml,.yml,.csv,.json"
+          onChange={handleImport}
+          style={{ display: 'none' }}
+        />
+      </label>
+
+      {products.length > 0 && (
+        <table style={{ ...sharedStyles.table, marginTop: '1rem' }}>
+          <thead>
+            <tr>
+              <th style={sharedStyles.th}>Product</th>
+              <th style={sharedStyles.th}>Active</th>
+              <th style={sharedStyles.th}>FRAC / IRAC</th>
+              <th style={sharedStyles.th}>Rate /1000 sqft</th>
+              <th style={sharedStyles.th}>Water in</th>
+              <th style={sharedStyles.th}>Residual</th>
+              <th style={sharedStyles.th}></th>
+            </tr>
+          </thead>
+          <tbody>
+            {products.map((p) => (
+              <tr key={p.id}>
+                <td style={sharedStyles.td}>
+                  {p.name}
+                  {p.user_edited && <span style={styles.edited}>edited</span>}
+                </td>
+                <td style={sharedStyles.td}>{p.active_ingredient}</td>
+                <td style={sharedStyles.td}>
+                  {[p.frac_group, p.irac_group].map((g) => g ?? '—').join(' / ')}
+                </td>
+                <td style={sharedStyles.td}>
+                  {p.rate_per_1000sqft != null
+                    ? `${p.rate_per_1000sqft} ${p.rate_unit ?? ''}`
+                    : '—'}
+                </td>
+                <td style={sharedStyles.td}>
+                  {p.water_in_inches != null
+                    ? `${p.water_in_inches}" within ${p.water_in_days}d`
+                    : '—'}
+                </td>
+                <td style={sharedStyles.td}>{p.residual_days ? `${p.residual_days}d` : '—'}</td>
+                <td style={sharedStyles.td}>
+                  <button style={styles.linkBtn} onClick={() => setEditing(p)}>
+                    Edit
+                  </button>
+                  <button
+                    style={{ ...styles.linkBtn, color: '#e53e3e' }}
+                    onClick={() => handleDelete(p)}
+                  >
+                    Delete
+                  </button>
+                </td>
+              </tr>
+            ))}
+          </tbody>
+        </table>
+      )}
+
+      {editing ? (
+        <ProductForm
+          key={editing === 'new' ? 'new' : editing.id}
+          initial={editing === 'new' ? null : editing}
+          onSaved={handleSaved}
+          onCancel={() => setEditing(null)}
+        />
+      ) : (
+        <button style={styles.addBtn} onClick={() => setEditing('new')}>
+          + Add product
+        </button>
+      )}
+    </div>
+  );
+}
+
+const num = (v: string) => (v.trim() === '' ? null : Number(v));
+const text = (v: string) => (v.trim() === '' ? null : v.trim());
+const str = (v: number | string | null | undefined) => (v == null ? '' : String(v));
+
+function ProductForm({
+  initial,
+  onSaved,
+  onCancel,
+}: {
+  initial: LabelProduct | null;
+  onSaved: (product: LabelProduct) => void;
+  onCancel: () => void;
+}) {
+  const [name, setName] = useState(initial?.name ?? '');
+  const [active, setActive] = useState(initial?.active_ingredient ?? '');
+  const [keywords, setKeywords] = useState(initial?.keywords.join('; ') ?? '');
+  const [frac, setFrac] = useState(str(initial?.frac_group));
+  const [irac, setIrac] = useState(str(initial?.irac_group));
+  const [rate, setRate] = useState(str(initial?.rate_per_1000sqft));
+  const [rateUnit, setRateUnit] = useState(str(initial?.rate_unit));
+  const [waterInches, setWaterInches] = useState(str(initial?.water_in_inches));
+  const [waterDays, setWaterDays] = useState(str(initial?.water_in_days));
+  const [reentry, setReentry] = useState<ReEntry | ''>(initial?.reentry ?? '');
+  const [residual, setResidual] = useState(str(initial?.residual_days));
+  const [seeded, setSeeded] = useState(str(initial?.seeded_turf_days));
+  const [saving, setSaving] = useState(false);
+  const { notify } = useToast();
+
+  const handleSubmit = async (e: React.FormEvent) => {
+    e.preventDefault();
+    setSaving(true);
+    const data: LabelEntry = {
+      name,
+      active_ingredient: active,
+      keywords: keywords
+        .split(';')
+        .map((k) => k.trim())
+        .filter(Boolean),
+      frac_group: text(frac),
+      irac_group: text(irac),
+      rate_per_1000sqft: num(rate),
+      rate_unit: text(rateUnit),
+      water_in_inches: num(waterInches),
+      water_in_days: num(waterDays),
+      reentry: reentry || null,
+      residual_days: num(residual),
+      seeded_turf_days: num(seeded),
+    };
+    try {
+      onSaved(
+        initial ? await updateLabelProduct(initial.id, data) : await createLabelProduct(data)
+      );
+    } catch (err) {
+      notify(errorMessage(err, 'Failed to save product'), 'error');
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  const field = (
+    value: string,
+    set: (v: string) => void,
+    placeholder: string,
+    type: 'text' | 'number' = 'text'
+  ) => (
+    <input
+      style={styles.input}
+      type={type}
+      step={type === 'number' ? 'any' : undefined}
+      min={type === 'number' ? 0 : undefined}
+      value={value}
+      onChange={(e) => set(e.target.value)}
+      placeholder={placeholder}
+      aria-label={placeholder}
+    />
+  );
+
+  return (
+    <form onSubmit={handleSubmit} style={styles.form}>
+      <input
+        style={styles.input}
+        value={name}
+        onChange={(e) => setName(e.target.value)}
+        placeholder="Name (e.g. Dimension 2EW)"
+        required
+      />
+      <input
+        style={styles.input}
+        value={active}
+        onChange={(e) => setActive(e.target.value)}
+        placeholder="Active ingredient"
+        required
+      />
+      {field(keywords, setKeywords, 'Other names (; separated)')}
+      {field(frac, setFrac, 'FRAC group')}
+      {field(irac, setIrac, 'IRAC group')}
+      {field(rate, setRate, 'Rate per 1000 sqft', 'number')}
+      {field(rateUnit, setRateUnit, 'Rate unit (oz, lb)')}
+      {field(waterInches, setWaterInches, 'Water in (inches)', 'number')}
+      {field(waterDays, setWaterDays, 'Water in within (days)', 'number')}
+      <EnumSelect
+        style={styles.input}
+        options={REENTRY}
+        labels={REENTRY_LABELS}
+        value={reentry}
+        onChange={setReentry}
+        emptyLabel="Re-entry: not listed"
+        ariaLabel="Re-entry"
+      />
+      {field(residual, setResidual, 'Residual (days)', 'number')}
+      {field(seeded, setSeeded, 'Safe on new seed after (days)', 'number')}
+      <button type="submit" style={{ ...styles.addBtn, marginTop: 0 }} disabled={saving}>
+        {saving ? 'Saving...' : initial ? 'Update' : 'Add'}
+      </button>
+      <button type="button" style={styles.linkBtn} onClick={onCancel} disabled={saving}>
+        Cancel
+      </button>
+    </form>
+  );
+}
+
+const styles: Record<string, React.CSSProperties> = {
+  help: { color: '#718096', fontSize: '0.85rem', marginTop: 0 },
+  form: { display: 'flex', gap: 8, flexWrap: 'wrap' as const, marginTop: '1rem' },
+  input: {
+    padding: '0.4rem 0.6rem',
+    borderRadius: 6,
+    border: '1px solid #e2e8f0',
+    fontSize: '0.85rem',
+  },
+  edited: {
+    marginLeft: 8,
+    padding: '1px 6px',
+    borderRadius: 8,
+    backgroundColor: '#edf2f7',
+    color: '#4a5568',
+    fontSize: '0.7rem',
+  },
+  linkBtn: {
+    background: 'none',
+    border: 'none',
+    color: '#3182ce',
+    cursor: 'pointer',
+    fontSize: '0.8rem',
+    padding: '0 4px',
+  },
+  addBtn: {
+    marginTop: '1rem',
+    padding: '0.4rem 1rem',
+    backgroundColor: '#38a169',
+    color: '#fff',
+    border: 'none',
+    borderRadius: 6,
+    cursor: 'pointer',
+    fontWeight: 600,
+    fontSize: '0.85rem',
+  },
+};
@@ -0,0 +1,196 @@
+import { useEffect, useState } from 'react';
+import {
+  createProfile,
+  deleteProfile,
+  errorMessage,
+  listProfiles,
+  setActiveProfile,
+} from '../api/client';
+import { sharedStyles } from '../styles/shared';
+import type { GrassType, LawnProfile } from '../types';
+import { GRASS_TYPE_LABELS, GRASS_TYPES } from '../types';
+import { confirmDiscard } from '../utils/unsavedChanges';
+import EnumSelect from './EnumSelect';
+import { useToast } from './toastContext';
+
+interface Props {
+  active: LawnProfile;
+  onSwitched: (profile: LawnProfile) => void;
+}
+
+export default function ProfileSwitcher({ active, onSwitched }: Props) {
+  const [profiles, setProfiles] = useState<LawnProfile[]>([]);
+  const [adding, setAdding] = useState(false);
+  const [name, setName] = useState('');
+  const [grassType, setGrassType] = useState<GrassType>(active.grass_type);
+  const [zone, setZone] = useState(active.usda_zone);
+  const [busy, setBusy] = useState(false);
+  const { notify } = useToast();
+
+  useEffect(() => {
+    listProfiles()
+      .then(setProfiles)
+      .catch((e) => notify(`Failed to load profiles: ${errorMessage(e, 'request failed')}`, 'error'));
+  }, [notify, active.id]);
+
+  const handleSwitch = async (id: number) => {
+    if (!confirmDiscard()) return;
+    setBusy(true);
+    try {
+      const profile = await setActiveProfile(id);
+      onSwitched(profile);
+      notify(`Now showing ${profile.name}`, 'success');
+    } catch (e) {
+      notify(errorMessage(e, 'Failed to switch profile'), 'error');
+    } finally {
+      setBusy(false);
+    }
+  };
+
+  const handleCreate = async (e: React.FormEvent) => {
+    e.preventDefault();
+    setBusy(true);
+    try {
+      const created = await createProfile({ name, grass_type: grassType, usda_zone: zone });
+      setProfiles((prev) => [...prev, created]);
+      setAdding(false);
+      setName('');
+      notify(`Added ${created.name}`, 'success');
+    } catch (e) {
+      notify(errorMessage(e, 'Failed to add profile'), 'error');
+    } finally {
+      setBusy(false);
+    }
+  };
+
+  const handleDelete = async (p: LawnProfile) => {
+    if (p.id == null) return;
+    if (!window.confirm(`Delete ${p.name} and everything logged for it?`)) return;
+    try {
+      await deleteProfile(p.id);
+      setProfiles((prev) => prev.filter((x) => x.id !== p.id));
+      notify(`Deleted ${p.name}`, 'success');
+    } catch (e) {
+      notify(errorMessage(e, 'Failed to delete profile'), 'error');
+    }
+  };
+
+  return (
+    <div style={{ ...sharedStyles.card, marginBottom: '1rem' }}>
+      <div style={styles.row}>
+        <label style={styles.label} htmlFor="active-profile">
+          Showing
+        </label>
+        <select
+          id="active-profile"
+          style={styles.select}
+          value={active.id ?? ''}
+          disabled={busy}
+          onChange={(e) => handleSwitch(Number(e.target.value))}
+        >
+          {profiles.map((p) => (
+            <option key={p.id} value={p.id ?? ''}>
+              {p.name}
+            </option>
+          ))}
+        </select>
+        <button style={styles.linkBtn} onClick={() => setAdding(!adding)}>
+          {adding ? 'Cancel' : '+ Add profile'}
+        </button>
+      </div>
+
+      {profiles.length > 1 && (
+        <ul style={styles.list}>
+          {profiles
+            .filter((p) => p.id !== active.id)
+            .map((p) => (
+              <li key={p.id} style={styles.item}>
+                {p.name}
+                <span style={styles.muted}>
+                  {GRASS_TYPE_LABELS[p.grass_type]} · zone {p.usda_zone}
+                  {p.location && ` · ${p.location.name || 'own location'}`}
+                </span>
+                <button
+                  style={{ ...styles.linkBtn, color: '#e53e3e' }}
+                  onClick={() => handleDelete(p)}
+                >
+                  Delete
+                </button>
+              </li>
+            ))}
+        </ul>
+      )}
+
+      {adding && (
+        <form onSubmit={handleCreate} style={styles.addForm}>
+          <input
+            style={styles.select}
+            value={name}
+            onChange={(e) => setName(e.target.value)}
+            placeholder="Name (e.g. Rental - Front)"
+            required
+          />
+          <EnumSelect
+            style={styles.select}
+            options={GRASS_TYPES}
+            labels={GRASS_TYPE_LABELS}
+            value={grassType}
+            onChange={(v) => {
+              if (v) setGrassType(v);
+            }}
+          />
+          <input
+            style={{ ...styles.select, width: 80 }}
+            value={zone}
+            onChange={(e) => setZone(e.target.value)}
+            placeholder="Zone"
+            required
+          />
+          <button type="submit" style={styles.addBtn} disabled={busy}>
+            Add
+          </button>
+        </form>
+      )}
+    </div>
+  );
+}
+
+const styles: Record<string, React.CSSProperties> = {
+  row: { display: 'flex', alignItems: 'center', gap: 10, flexWrap: 'wrap' as const },
+  label: { fontSize: '0.8rem', color: '#718096', fontWeight: 600 },
+  select: {
+    padding: '0.4rem 0.6rem',
+    borderRadius: 6,
+    border: '1px solid #e2e8f0',
+    fontSize: '0.85rem',
+  },
+  linkBtn: {
+    background: 'none',
+    border: 'none',
+    color: '#3182ce',
+    cursor: 'pointer',
+    fontSize: '0.8rem',
+    padding: '0 4px',
+  },
+  list: { listStyle: 'none', margin: '0.75rem 0 0', padding: 0 },
+  item: {
+    display: 'flex',
+    alignItems: 'baseline',
+    gap: 10,
+    padding: '0.3rem 0',
+    fontSize: '0.85rem',
+    color: '#2d3748',
+  },
+  muted: { flex: 1, fontSize: '0.75rem', color: '#a0aec0' },
+  addForm: { display: 'flex', gap: 8, flexWrap: 'wrap' as const, marginTop: '0.75rem' },
+  addBtn: {
+    padding: '0.4rem 1rem',
+    backgroundColor: '#38a169',
+    color: '#fff',
+    border: 'none',
+    borderRadius: 6,
+    cursor: 'pointer',
+    fontWeight: 600,
+    fontSize: '0.85rem',
+  },
+};
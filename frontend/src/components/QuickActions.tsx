@@ -0,0 +1,268 @@
+import { useCallback, useEffect, useMemo, useState } from 'react';
+import { useNavigate } from 'react-router-dom';
+import {
+  errorMessage,
+  getProfile,
+  listProfiles,
+  setActiveProfile,
+} from '../api/client';
+import type { LawnProfile } from '../types';
+import { useRefresh } from './refreshContext';
+import { useToast } from './toastContext';
+
+interface Action {
+  id: string;
+  label: string;
+  hint?: string;
+  run: () => void | Promise<void>;
+}
+
+interface QuickActionsProps {
+  /** Pages the palette can jump to. */
+  pages: { to: string; label: string }[];
+}
+
+function todayISO(): string {
+  const d = new Date();
+  return new Date(d.getTime() - d.getTimezoneOffset() * 60_000).toISOString().slice(0, 10);
+}
+
+/** Command palette (Ctrl+K / ⌘K) for frequent tasks from any page. */
+export default function QuickActions({ pages }: QuickActionsProps) {
+  const [open, setOpen] = useState(false);
+  const [query, setQuery] = useState('');
+  const [active, setActive] = useState(0);
+  const [pickingDate, setPickingDate] = useState(false);
+  const [jumpDate, setJumpDate] = useState(todayISO);
+  const [profiles, setProfiles] = useState<LawnProfile[]>([]);
+  const navigate = useNavigate();
+  const { notify } = useToast();
+  const { refresh } = useRefresh();
+
+  const close = useCallback(() => {
+    setOpen(false);
+    setQuery('');
+    setActive(0);
+    setPickingDate(false);
+  }, []);
+
+  useEffect(() => {
+    const onKey = (e: KeyboardEvent) => {
+      if (e.key.toLowerCase() === 'k' && (e.ctrlKey || e.metaKey)) {
+        e.preventDefault();
+        setOpen((v) => !v);
+      } else if (e.key === 'Escape') {
+        close();
+      }
+    };
+    window.addEventListener('keydown', onKey);
+    return () => window.removeEventListener('keydown', onKey);
+  }, [close]);
+
+  // Profiles are listed fresh each time so new ones show up
+  useEffect(() => {
+    if (!open) return;
+    Promise.all([listProfiles(), getProfile()])
+      .then(([all, current]) => setProfiles(all.filter((p) => p.id !== current.id)))
+      .catch(() => setProfiles([]));
+  }, [open]);
+
+  const actions = useMemo<Action[]>(() => {
+    const go = (to: string) => () => navigate(to);
+    return [
+      { id: 'log-app', label: 'Log application', run: go('/applications?new=1') },
+      { id: 'log-mowing', label: 'Log mowing', run: go('/applications?new=Mowing') },
+      { id: 'log-irrigation', label: 'Log irrigation', run: go('/applications?new=Irrigation') },
+      {
+        id: 'refresh',
+        label: 'Refresh data',
+        hint: 'Pull the latest sensor readings (r)',
+        run: () => refresh(false),
+      },
+      {
+        id: 'calendar-date',
+        label: 'Jump to date on calendar…',
+        run: () => setPickingDate(true),
+      },
+      ...profiles
+        .filter((p) => p.id !== null)
+        .map((p) => ({
+          id: `profile-${p.id}`,
+          label: `Switch to ${p.name}`,
+          hint: 'Profile',
+          run: async () => {
+            try {
+              await setActiveProfile(p.id as number);
+              // Every page holds profile data; start clean
+              window.location.assign('/');
+            } catch (e) {
+              notify(errorMessage(e, 'Failed to switch profile'), 'error');
+            }
+          },
+        })),
+      ...pages.map((p) => ({ id: `page-${p.to}`, label: p.label, hint: 'Go to', run: go(p.to) })),
+    ];
+  }, [navigate, notify, pages, profiles, refresh]);
+
+  const filtered = useMemo(() => {
+    const q = query.trim().toLowerCase();
+    return q ? actions.filter((a) => a.label.toLowerCase().includes(q)) : actions;
+  }, [actions, query]);
+
+  const runAction = (action: Action | undefined) => {
+    if (!action) return;
+    if (action.id !== 'calendar-date') close();
+    void action.run();
+  };
+
+  const handleKeyDown = (e: React.KeyboardEvent) => {
+    if (e.key === 'ArrowDown') {
+      e.preventDefault();
+      setActive((i) => Math.min(i + 1, filtered.length - 1));
+    } else if (e.key === 'ArrowUp') {
+      e.preventDefault();
+      setActive((i) => Math.max(i - 1, 0));
+    } else if (e.key === 'Enter') {
+      e.preventDefault();
+      runAction(filtered[active]);
+    }
+  };
+
+  const handleJump = (e: React.FormEvent) => {
+    e.preventDefault();
+    close();
+    navigate(`/calendar?date=${jumpDate}`);
+  };
+
+  if (!open) return null;
+
+  return (
+    <div style={styles.backdrop} onClick={close}>
+      <div
+        style={styles.panel}
+        role="dialog"
+        aria-label="Quick actions"
+        onClick={(e) => e.stopPropagation()}
+      >
+        {pickingDate ? (
+          <form onSubmit={handleJump} style={styles.dateForm}>
+            <label style={styles.dateLabel}>
+              Jump to
+              <input
+                type="date"
+                style={styles.input}
+                value={jumpDate}
+                onChange={(e) => setJumpDate(e.target.value)}
+                autoFocus
+                required
+              />
+            </label>
+            <button type="submit" style={styles.goBtn}>
+              Open calendar
+            </button>
+          </form>
+        ) : (
+          <>
+            <input
+              style={styles.input}
+              placeholder="Type an action or page…"
+              value={query}
+              onChange={(e) => {
+                setQuery(e.target.value);
+                setActive(0);
+              }}
+              onKeyDown={handleKeyDown}
+              autoFocus
+              aria-label="Search actions"
+            />
+            <ul style={styles.list} role="listbox">
+              {filtered.length === 0 && <li style={styles.empty}>No matching actions</li>}
+              {filtered.map((a, i) => (
+                <li
+                  key={a.id}
+                  role="option"
+                  aria-selected={i === active}
+                  style={{
+                    ...styles.item,
+                    backgroundColor: i === active ? '#ebf8ff' : 'transparent',
+                  }}
+                  onMouseEnter={() => setActive(i)}
+                  onClick={() => runAction(a)}
+                >
+                  <span>{a.label}</span>
+                  {a.hint && <span style={styles.hint}>{a.hint}</span>}
+                </li>
+              ))}
+            </ul>
+          </>
+        )}
+        <div style={styles.footer}>↑↓ to move · Enter to run · Esc to close</div>
+      </div>
+    </div>
+  );
+}
+
+const styles: Record<string, React.CSSProperties> = {
+  backdrop: {
+    position: 'fixed',
+    inset: 0,
+    backgroundColor: 'rgba(26, 32, 44, 0.45)',
+    display: 'flex',
+    justifyContent: 'center',
+    alignItems: 'flex-start',
+    paddingTop: '12vh',
+    zIndex: 1000,
+  },
+  panel: {
+    width: 'min(520px, 92vw)',
+    backgroundColor: '#fff',
+    borderRadius: 10,
+    boxShadow: '0 10px 30px rgba(0,0,0,0.25)',
+    padding: '0.75rem',
+  },
+  input: {
+    width: '100%',
+    padding: '0.6rem 0.75rem',
+    borderRadius: 6,
+    border: '1px solid #e2e8f0',
+    fontSize: '0.95rem',
+    boxSizing: 'border-box',
+  },
+  list: {
+    listStyle: 'none',
+    margin: '0.5rem 0 0',
+    padding: 0,
+    maxHeight: '50vh',
+    overflowY: 'auto',
+  },
+  item: {
+    display: 'flex',
+    justifyContent: 'space-between',
+    padding: '0.5rem 0.75rem',
+    borderRadius: 6,
+    cursor: 'pointer',
+    fontSize: '0.9rem',
+    color: '#2d3748',
+  },
+  hint: { color: '#a0aec0', fontSize: '0.75rem' },
+  empty: { padding: '0.5rem 0.75rem', color: '#a0aec0', fontSize: '0.85rem' },
+  dateForm: { display: 'flex', gap: 8, alignItems: 'flex-end' },
+  dateLabel: {
+    flex: 1,
+    display: 'flex',
+    flexDirection: 'column',
+    gap: 4,
+    fontSize: '0.85rem',
+    color: '#4a5568',
+  },
+  goBtn: {
+    padding: '0.6rem 1rem',
+    backgroundColor: '#3182ce',
+    color: '#fff',
+    border: 'none',
+    borderRadius: 6,
+    cursor: 'pointer',
+    fontWeight: 600,
+  },
+  footer: { marginTop: '0.5rem', fontSize: '0.72rem', color: '#a0aec0' },
+};
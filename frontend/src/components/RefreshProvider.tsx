@@ -0,0 +1,65 @@
+import { useCallback, useEffect, useMemo, useRef, useState, type ReactNode } from 'react';
+import { errorMessage, refreshEnvironmental } from '../api/client';
+import { isTypingTarget } from '../utils/keyboard';
+import { confirmDiscard } from '../utils/unsavedChanges';
+import { RefreshContext } from './refreshContext';
+import { useToast } from './toastContext';
+
+/** Presses this soon after the last accepted one are ignored. */
+const REFRESH_DEBOUNCE_MS = 1000;
+
+export default function RefreshProvider({ children }: { children: ReactNode }) {
+  const { notify } = useToast();
+  const [refreshing, setRefreshing] = useState(false);
+  const [deep, setDeep] = useState(false);
+  const [generation, setGeneration] = useState(0);
+  const inFlight = useRef(false);
+  const lastPress = useRef(0);
+
+  const refresh = useCallback(
+    (deepRefresh = false) => {
+      const now = Date.now();
+      if (inFlight.current || now - lastPress.current < REFRESH_DEBOUNCE_MS) return;
+      lastPress.current = now;
+      // The page remounts afterwards, which would drop unsaved edits
+      if (!confirmDiscard()) return;
+
+      inFlight.current = true;
+      setDeep(deepRefresh);
+      setRefreshing(true);
+      refreshEnvironmental(deepRefresh)
+        .then(() => {
+          setGeneration((g) => g + 1);
+          notify(deepRefresh ? 'All data re-synced' : 'Environmental data refreshed', 'success');
+        })
+        .catch((e) => notify(errorMessage(e, 'Refresh failed'), 'error'))
+        .finally(() => {
+          inFlight.current = false;
+          lastPress.current = Date.now();
+          setRefreshing(false);
+        });
+    },
+    [notify]
+  );
+
+  // r refreshes, Shift+R refreshes deep, on every page
+  useEffect(() => {
+    const onKey = (e: KeyboardEvent) => {
+      if (isTypingTarget(e.target) || e.ctrlKey || e.metaKey || e.altKey || e.repeat) return;
+      if (e.key === 'r' && !e.shiftKey) {
+        refresh(false);
+      } else if (e.key === 'R' && e.shiftKey) {
+        refresh(true);
+      }
+    };
+    window.addEventListener('keydown', onKey);
+    return () => window.removeEventListener('keydown', onKey);
+  }, [refresh]);
+
+  const api = useMemo(
+    () => ({ refreshing, deep, generation, refresh }),
+    [refreshing, deep, generation, refresh]
+  );
+
+  return <RefreshContext.Provider value={api}>{children}</RefreshContext.Provider>;
+}
@@ -0,0 +1,152 @@
+import { useState } from 'react';
+import { runBacktest } from '../api/client';
+import { sharedStyles } from '../styles/shared';
+import type { BacktestReport } from '../types';
+import { SEVERITY_COLORS } from '../types';
+
+function isoDate(d: Date): string {
+  const local = new Date(d.getTime() - d.getTimezoneOffset() * 60_000);
+  return local.toISOString().slice(0, 10);
+}
+
+function formatDay(iso: string): string {
+  return new Date(iso + 'T00:00:00').toLocaleDateString(undefined, {
+    month: 'short',
+    day: 'numeric',
+  });
+}
+
+export default function RuleBacktest() {
+  const lastYear = new Date().getFullYear() - 1;
+  const [from, setFrom] = useState(`${lastYear}-01-01`);
+  const [to, setTo] = useState(`${lastYear}-12-31`);
+  const [report, setReport] = useState<BacktestReport | null>(null);
+  const [running, setRunning] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+
+  const handleRun = async (e: React.FormEvent) => {
+    e.preventDefault();
+    setRunning(true);
+    setError(null);
+    try {
+      setReport(await runBacktest(from, to));
+    } catch (e) {
+      setError(e instanceof Error ? e.message : 'Backtest failed');
+    } finally {
+      setRunning(false);
+    }
+  };
+
+  const yesterday = new Date();
+  yesterday.setDate(yesterday.getDate() - 1);
+
+  return (
+    <div style={sharedStyles.card}>
+      <p style={styles.help}>
+        Replay archived sensor data through the current rules, one day at a time, to see
+        which recommendations would have fired when. Forecasts aren't archived, so
+        forecast-driven rules stay silent. Applications you logged are taken into account.
+      </p>
+
+      <form onSubmit={handleRun} style={styles.form}>
+        <label style={styles.label}>
+          From
+          <input
+            type="date"
+            style={styles.input}
+            value={from}
+            onChange={(e) => setFrom(e.target.value)}
+            required
+          />
+        </label>
+        <label style={styles.label}>
+          To
+          <input
+            type="date"
+            style={styles.input}
+            value={to}
+            max={isoDate(yesterday)}
+            onChange={(e) => setTo(e.target.value)}
+            required
+          />
+        </label>
+        <button type="submit" style={styles.runBtn} disabled={running}>
+          {running ? 'Replaying...' : 'Run Backtest'}
+        </button>
+      </form>
+
+      {error && <div style={sharedStyles.error}>{error}</div>}
+
+      {report && (
+        <>
+          <p style={styles.summary}>
+            {report.days_evaluated} day{report.days_evaluated === 1 ? '' : 's'} replayed
+            {report.days_without_data > 0 &&
+              `, ${report.days_without_data} skipped for missing data`}
+            . {report.runs.length} recommendation run{report.runs.length === 1 ? '' : 's'}.
+          </p>
+          {report.runs.length > 0 && (
+            <table style={sharedStyles.table}>
+              <thead>
+                <tr>
+                  <th style={sharedStyles.th}>Dates</th>
+                  <th style={sharedStyles.th}>Recommendation</th>
+                  <th style={sharedStyles.th}>Peak</th>
+                  <th style={sharedStyles.th}>Days</th>
+                </tr>
+              </thead>
+              <tbody>
+                {report.runs.map((r) => (
+                  <tr key={`${r.recommendation_id}-${r.start}`}>
+                    <td style={{ ...sharedStyles.td, whiteSpace: 'nowrap' }}>
+                      {r.start === r.end
+                        ? formatDay(r.start)
+                        : `${formatDay(r.start)} – ${formatDay(r.end)}`}
+                    </td>
+                    <td style={sharedStyles.td}>
+                      {r.title}
+                      <div style={styles.category}>{r.category}</div>
+                    </td>
+                    <td style={{ ...sharedStyles.td, color: SEVERITY_COLORS[r.severity] }}>
+                      {r.severity}
+                    </td>
+                    <td style={sharedStyles.td}>{r.days}</td>
+                  </tr>
+                ))}
+              </tbody>
+            </table>
+          )}
+        </>
+      )}
+    </div>
+  );
+}
+
+const styles: Record<string, React.CSSProperties> = {
+  help: { color: '#718096', fontSize: '0.85rem', marginTop: 0 },
+  form: { display: 'flex', gap: 12, alignItems: 'flex-end', marginBottom: '1rem' },
+  label: {
+    display: 'flex',
+    flexDirection: 'column' as const,
+    gap: 4,
+    fontSize: '0.85rem',
+    color: '#4a5568',
+  },
+  input: {
+    padding: '0.4rem 0.6rem',
+    borderRadius: 6,
+    border: '1px solid #e2e8f0',
+    fontSize: '0.85rem',
+  },
+  runBtn: {
+    padding: '0.5rem 1rem',
+    backgroundColor: '#3182ce',
+    color: '#fff',
+    border: 'none',
+    borderRadius: 6,
+    cursor: 'pointer',
+    fontWeight: 600,
+  },
+  summary: { fontSize: '0.85rem', color: '#4a5568' },
+  category: { fontSize: '0.75rem', color: '#a0aec0' },
+};
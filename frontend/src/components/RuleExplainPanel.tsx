@@ -0,0 +1,77 @@
+import { useEffect, useState } from 'react';
+import { explainRecommendations } from '../api/client';
+import { sharedStyles } from '../styles/shared';
+import type { RuleTrace } from '../types';
+import { GATE_LABELS } from '../types';
+import { ruleLabel } from '../utils/rules';
+
+interface Props {
+  /** Explain for one lawn area instead of the whole profile. */
+  areaId?: number;
+}
+
+export default function RuleExplainPanel({ areaId }: Props) {
+  const [traces, setTraces] = useState<RuleTrace[] | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    let cancelled = false;
+    explainRecommendations(areaId)
+      .then((t) => {
+        if (!cancelled) setTraces(t);
+      })
+      .catch((e) => {
+        if (!cancelled) setError(e instanceof Error ? e.message : 'Failed to load rule trace');
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, [areaId]);
+
+  if (error) return <div style={sharedStyles.error}>{error}</div>;
+  if (!traces) return <div style={sharedStyles.loading}>Evaluating rules...</div>;
+
+  const fired = traces.filter((t) => t.fired).length;
+
+  return (
+    <div style={{ marginBottom: '1.5rem' }}>
+      <h3 style={sharedStyles.sectionTitle}>
+        Rule trace — {fired} of {traces.length} rules fired
+      </h3>
+      <table style={sharedStyles.table}>
+        <thead>
+          <tr>
+            <th style={sharedStyles.th}>Rule</th>
+            <th style={sharedStyles.th}>Result</th>
+            <th style={sharedStyles.th}>Detail</th>
+          </tr>
+        </thead>
+        <tbody>
+          {traces.map((t) => (
+            <tr key={t.rule}>
+              <td style={sharedStyles.td}>{ruleLabel(t.rule)}</td>
+              <td style={sharedStyles.td}>
+                {t.fired ? (
+                  <span style={{ ...styles.result, color: t.suppressed_by ? '#a0aec0' : '#22c55e' }}>
+                    {t.suppressed_by ? `Fired (${t.suppressed_by})` : 'Fired'}
+                  </span>
+                ) : (
+                  <span style={styles.result}>
+                    {t.gate ? GATE_LABELS[t.gate.kind] : 'Silent'}
+                  </span>
+                )}
+              </td>
+              <td style={{ ...sharedStyles.td, color: '#718096' }}>
+                {t.fired ? t.title : t.gate?.detail ?? ''}
+              </td>
+            </tr>
+          ))}
+        </tbody>
+      </table>
+    </div>
+  );
+}
+
+const styles: Record<string, React.CSSProperties> = {
+  result: { fontWeight: 600, fontSize: '0.8rem', color: '#4a5568' },
+};
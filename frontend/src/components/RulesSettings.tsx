@@ -0,0 +1,141 @@
+import { useEffect, useState } from 'react';
+import { getRiskPosture, getRules, setRiskPosture, setRuleEnabled } from '../api/client';
+import { sharedStyles } from '../styles/shared';
+import type { RiskPosture, RuleStatus } from '../types';
+import { t } from '../utils/i18n';
+import { ruleLabel } from '../utils/rules';
+import { useToast } from './toastContext';
+
+const POSTURES: { value: RiskPosture; hint: string }[] = [
+  {
+    value: 'Conservative',
+    hint: 'Alerts earlier and louder: soil windows open 2°F sooner, disease rules read humidity 5 points higher, advisories become warnings.',
+  },
+  { value: 'Standard', hint: 'Thresholds and severities as documented.' },
+  {
+    value: 'Aggressive',
+    hint: 'Alerts later and quieter: soil windows open 2°F later, disease rules read humidity 5 points lower, warnings become advisories.',
+  },
+];
+
+export default function RulesSettings() {
+  const [rules, setRules] = useState<RuleStatus[] | null>(null);
+  const [error, setError] = useState<string | null>(null);
+  const [pending, setPending] = useState<string | null>(null);
+  const [posture, setPosture] = useState<RiskPosture | null>(null);
+  const { notify } = useToast();
+
+  useEffect(() => {
+    let cancelled = false;
+    getRules()
+      .then((r) => {
+        if (!cancelled) setRules(r);
+      })
+      .catch((e) => {
+        if (!cancelled) setError(e instanceof Error ? e.message : 'Failed to load rules');
+      });
+    getRiskPosture()
+      .then((p) => {
+        if (!cancelled) setPosture(p.posture);
+      })
+      .catch(() => {
+        if (!cancelled) setPosture('Standard');
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, []);
+
+  const toggle = async (status: RuleStatus) => {
+    setPending(status.rule);
+    try {
+      const updated = await setRuleEnabled(status.rule, !status.enabled);
+      setRules((prev) => prev?.map((r) => (r.rule === updated.rule ? updated : r)) ?? null);
+      notify(
+        `${ruleLabel(updated.rule)} ${updated.enabled ? 'enabled' : 'disabled'}`,
+        'success',
+      );
+    } catch (e) {
+      notify(e instanceof Error ? e.message : 'Failed to update rule', 'error');
+    } finally {
+      setPending(null);
+    }
+  };
+
+  const changePosture = async (next: RiskPosture) => {
+    const previous = posture;
+    setPosture(next);
+    try {
+      await setRiskPosture(next);
+      notify(`Risk posture set to ${next}`, 'success');
+    } catch (e) {
+      setPosture(previous);
+      notify(e instanceof Error ? e.message : 'Failed to update risk posture', 'error');
+    }
+  };
+
+  if (error) return <div style={sharedStyles.error}>{error}</div>;
+  if (!rules) return <div style={sharedStyles.loading}>Loading rules...</div>;
+
+  return (
+    <div style={sharedStyles.card}>
+      <div style={styles.posture}>
+        <label style={styles.name}>
+          {t('Risk posture')}{' '}
+          <select
+            value={posture ?? 'Standard'}
+            disabled={!posture}
+            onChange={(e) => changePosture(e.target.value as RiskPosture)}
+          >
+            {POSTURES.map((p) => (
+              <option key={p.value} value={p.value}>
+                {t(p.value)}
+              </option>
+            ))}
+          </select>
+        </label>
+        <p style={styles.help}>
+          {t(POSTURES.find((p) => p.value === (posture ?? 'Standard'))?.hint ?? '')}
+        </p>
+      </div>
+      <p style={styles.help}>
+        Disabled rules are never evaluated and won't produce recommendations. Rules
+        disabled with <code>RULES_DISABLED</code> are locked here.
+      </p>
+      {rules.map((r) => (
+        <label key={r.rule} style={styles.row}>
+          <input
+            type="checkbox"
+            checked={r.enabled}
+            disabled={r.locked || pending === r.rule}
+            onChange={() => toggle(r)}
+          />
+          <span style={r.enabled ? styles.name : styles.nameOff}>{ruleLabel(r.rule)}</span>
+          {r.locked && <span style={styles.locked}>set in config</span>}
+        </label>
+      ))}
+    </div>
+  );
+}
+
+const styles: Record<string, React.CSSProperties> = {
+  help: { color: '#718096', fontSize: '0.85rem', marginTop: 0 },
+  posture: { marginBottom: '1rem', paddingBottom: '0.5rem', borderBottom: '1px solid #e2e8f0' },
+  row: {
+    display: 'flex',
+    alignItems: 'center',
+    gap: 10,
+    padding: '0.45rem 0',
+    borderBottom: '1px solid #edf2f7',
+    cursor: 'pointer',
+  },
+  name: { fontSize: '0.9rem', color: '#2d3748' },
+  nameOff: { fontSize: '0.9rem', color: '#a0aec0' },
+  locked: {
+    fontSize: '0.7rem',
+    color: '#718096',
+    border: '1px solid #e2e8f0',
+    borderRadius: 10,
+    padding: '0 6px',
+  },
+};
@@ -0,0 +1,151 @@
+import {
+  ResponsiveContainer,
+  AreaChart,
+  Area,
+  ReferenceLine,
+  YAxis,
+} from 'recharts';
+import type { StressIndexSummary } from '../types';
+import { STRESS_LEVEL_COLORS } from '../types';
+
+interface StressIndexWidgetProps {
+  data: StressIndexSummary;
+}
+
+/** Score at which a day counts as High stress (mirrors STRESS_HIGH). */
+const HIGH_STRESS = 60;
+
+const FACTORS: { key: 'heat' | 'drought' | 'disease' | 'traffic'; label: string }[] = [
+  { key: 'heat', label: 'Heat' },
+  { key: 'drought', label: 'Drought' },
+  { key: 'disease', label: 'Disease' },
+  { key: 'traffic', label: 'Traffic' },
+];
+
+export default function StressIndexWidget({ data }: StressIndexWidgetProps) {
+  const { current, sustained_high_days, days } = data;
+  if (!current) return null;
+
+  const color = STRESS_LEVEL_COLORS[current.level];
+  const sparkData = days.map((d) => ({ score: d.score }));
+
+  return (
+    <div style={styles.card}>
+      <div style={styles.header}>
+        <div style={styles.label}>Turf Stress</div>
+        <span
+          style={{
+            ...styles.statusBadge,
+            backgroundColor: color + '22',
+            color,
+            borderColor: color,
+          }}
+        >
+          {current.level}
+        </span>
+      </div>
+
+      <div style={styles.valueRow}>
+        <span style={styles.bigValue}>{current.score.toFixed(0)}</span>
+        <span style={styles.target}> / 100</span>
+      </div>
+
+      <div style={styles.factors}>
+        {FACTORS.map((f) => (
+          <span key={f.key}>
+            {f.label} {current.components[f.key].toFixed(0)}
+          </span>
+        ))}
+      </div>
+
+      {sparkData.length > 5 && (
+        <div style={styles.sparkContainer}>
+          <ResponsiveContainer width="100%" height={50}>
+            <AreaChart data={sparkData} margin={{ top: 2, right: 0, left: 0, bottom: 2 }}>
+              <defs>
+                <linearGradient id="stressGrad" x1="0" y1="0" x2="0" y2="1">
+                  <stop offset="5%" stopColor={color} stopOpacity={0.3} />
+                  <stop offset="95%" stopColor={color} stopOpacity={0} />
+                </linearGradient>
+              </defs>
+              <YAxis hide domain={[0, 100]} />
+              <ReferenceLine y={HIGH_STRESS} stroke="#ef4444" strokeDasharray="3 2" />
+              <Area
+                type="monotone"
+                dataKey="score"
+                stroke={color}
+                fill="url(#stressGrad)"
+                strokeWidth={1.5}
+                dot={false}
+              />
+            </AreaChart>
+          </ResponsiveContainer>
+        </div>
+      )}
+
+      <div style={styles.footnote}>
+        {sustained_high_days > 0
+          ? `High for ${sustained_high_days} day${sustained_high_days === 1 ? '' : 's'}: hold fertilizer, mow taller`
+          : `Last ${days.length} days`}
+      </div>
+    </div>
+  );
+}
+
+const styles: Record<string, React.CSSProperties> = {
+  card: {
+    backgroundColor: '#fff',
+    borderRadius: 8,
+    padding: '1rem',
+    boxShadow: '0 1px 3px rgba(0,0,0,0.08)',
+  },
+  header: {
+    display: 'flex',
+    justifyContent: 'space-between',
+    alignItems: 'center',
+    marginBottom: 6,
+  },
+  label: {
+    fontSize: '0.75rem',
+    fontWeight: 600,
+    color: '#718096',
+    textTransform: 'uppercase' as const,
+  },
+  statusBadge: {
+    display: 'inline-block',
+    padding: '1px 8px',
+    borderRadius: 10,
+    fontSize: '0.7rem',
+    fontWeight: 600,
+    border: '1px solid',
+  },
+  valueRow: {
+    marginBottom: 6,
+  },
+  bigValue: {
+    fontSize: '1.8rem',
+    fontWeight: 700,
+    color: '#1a202c',
+  },
+  target: {
+    fontSize: '1rem',
+    color: '#a0aec0',
+    fontWeight: 500,
+  },
+  factors: {
+    display: 'flex',
+    justifyContent: 'space-between',
+    fontSize: '0.7rem',
+    color: '#718096',
+    marginBottom: 4,
+  },
+  sparkContainer: {
+    marginTop: 4,
+    marginBottom: 4,
+  },
+  footnote: {
+    fontSize: '0.65rem',
+    color: '#a0aec0',
+    textAlign: 'center' as const,
+  },
+};
@@ -0,0 +1,194 @@
+import { useCallback, useEffect, useMemo, useRef, useState, type ReactNode } from 'react';
+import {
+  TOAST_COLORS,
+  ToastContext,
+  type ToastLevel,
+  type ToastMessage,
+} from './toastContext';
+import { isTypingTarget } from '../utils/keyboard';
+
+/** How long a toast stays on screen. Errors linger a bit longer. */
+const TOAST_TTL_MS = 4000;
+const ERROR_TTL_MS = 7000;
+/** Max toasts visible at once; older ones are dropped from view (not from history). */
+const MAX_VISIBLE = 4;
+/** How many past messages the history panel keeps. */
+const HISTORY_LIMIT = 50;
+
+export default function ToastProvider({ children }: { children: ReactNode }) {
+  const [active, setActive] = useState<ToastMessage[]>([]);
+  const [history, setHistory] = useState<ToastMessage[]>([]);
+  const [showHistory, setShowHistory] = useState(false);
+  const nextId = useRef(1);
+  const timers = useRef(new Map<number, ReturnType<typeof setTimeout>>());
+
+  const dismiss = useCallback((id: number) => {
+    setActive((prev) => prev.filter((t) => t.id !== id));
+    const timer = timers.current.get(id);
+    if (timer) {
+      clearTimeout(timer);
+      timers.current.delete(id);
+    }
+  }, []);
+
+  const notify = useCallback(
+    (text: string, level: ToastLevel = 'info') => {
+      const msg: ToastMessage = { id: nextId.current++, level, text, createdAt: Date.now() };
+      setActive((prev) => [...prev, msg].slice(-MAX_VISIBLE));
+      setHistory((prev) => [msg, ...prev].slice(0, HISTORY_LIMIT));
+      const ttl = level === 'error' ? ERROR_TTL_MS : TOAST_TTL_MS;
+      timers.current.set(msg.id, setTimeout(() => dismiss(msg.id), ttl));
+    },
+    [dismiss]
+  );
+
+  // Clear pending timers on unmount
+  useEffect(() => {
+    const pending = timers.current;
+    return () => {
+      pending.forEach((t) => clearTimeout(t));
+      pending.clear();
+    };
+  }, []);
+
+  // Last line of defense: a promise nobody caught still gets a visible error
+  useEffect(() => {
+    const onRejection = (e: PromiseRejectionEvent) => {
+      const reason = e.reason;
+      notify(reason instanceof Error && reason.message ? reason.message : 'Unexpected error', 'error');
+    };
+    window.addEventListener('unhandledrejection', onRejection);
+    return () => window.removeEventListener('unhandledrejection', onRejection);
+  }, [notify]);
+
+  // Shift+M toggles the recent-messages panel; Escape closes it
+  useEffect(() => {
+    const onKey = (e: KeyboardEvent) => {
+      if (isTypingTarget(e.target)) return;
+      if (e.key === 'M' && e.shiftKey && !e.ctrlKey && !e.metaKey && !e.altKey) {
+        setShowHistory((v) => !v);
+      } else if (e.key === 'Escape') {
+        setShowHistory(false);
+      }
+    };
+    window.addEventListener('keydown', onKey);
+    return () => window.removeEventListener('keydown', onKey);
+  }, []);
+
+  const api = useMemo(() => ({ notify }), [notify]);
+
+  return (
+    <ToastContext.Provider value={api}>
+      {children}
+
+      <div style={styles.stack} role="status" aria-live="polite">
+        {active.map((t) => (
+          <div
+            key={t.id}
+            style={{ ...styles.toast, borderLeftColor: TOAST_COLORS[t.level] }}
+          >
+            <span style={{ flex: 1 }}>{t.text}</span>
+            <button
+              style={styles.close}
+              onClick={() => dismiss(t.id)}
+              aria-label="Dismiss message"
+            >
+              ×
+            </button>
+          </div>
+        ))}
+      </div>
+
+      {showHistory && (
+        <div style={styles.historyPanel} role="dialog" aria-label="Recent messages">
+          <div style={styles.historyHeader}>
+            <strong>Recent messages</strong>
+            <span style={styles.hint}>Shift+M / Esc to close</span>
+          </div>
+          {history.length === 0 ? (
+            <p style={styles.hint}>No messages yet.</p>
+          ) : (
+            <ul style={styles.historyList}>
+              {history.map((t) => (
+                <li key={t.id} style={styles.historyItem}>
+                  <span style={{ ...styles.dot, backgroundColor: TOAST_COLORS[t.level] }} />
+                  <span style={styles.historyTime}>
+                    {new Date(t.createdAt).toLocaleTimeString()}
+                  </span>
+                  <span>{t.text}</span>
+                </li>
+              ))}
+            </ul>
+          )}
+        </div>
+      )}
+    </ToastContext.Provider>
+  );
+}
+
+const styles: Record<string, React.CSSProperties> = {
+  stack: {
+    position: 'fixed',
+    bottom: '1rem',
+    right: '1rem',
+    display: 'flex',
+    flexDirection: 'column',
+    gap: '0.5rem',
+    zIndex: 1000,
+    maxWidth: 360,
+  },
+  toast: {
+    display: 'flex',
+    alignItems: 'flex-start',
+    gap: '0.5rem',
+    backgroundColor: '#fff',
+    color: '#2d3748',
+    borderLeft: '4px solid',
+    borderRadius: 6,
+    padding: '0.6rem 0.75rem',
+    fontSize: '0.85rem',
+    boxShadow: '0 4px 12px rgba(0,0,0,0.15)',
+  },
+  close: {
+    background: 'none',
+    border: 'none',
+    color: '#a0aec0',
+    cursor: 'pointer',
+    fontSize: '1rem',
+    lineHeight: 1,
+    padding: 0,
+  },
+  historyPanel: {
+    position: 'fixed',
+    top: '1rem',
+    right: '1rem',
+    width: 380,
+    maxWidth: 'calc(100vw - 2rem)',
+    maxHeight: '60vh',
+    overflowY: 'auto' as const,
+    backgroundColor: '#fff',
+    borderRadius: 8,
+    padding: '0.75rem 1rem',
+    boxShadow: '0 8px 24px rgba(0,0,0,0.2)',
+    zIndex: 1001,
+    fontSize: '0.85rem',
+    color: '#2d3748',
+  },
+  historyHeader: {
+    display: 'flex',
+    justifyContent: 'space-between',
+    alignItems: 'center',
+    marginBottom: '0.5rem',
+  },
+  hint: { color: '#a0aec0', fontSize: '0.75rem' },
+  historyList: { listStyle: 'none', margin: 0, padding: 0 },
+  historyItem: {
+    display: 'flex',
+    alignItems: 'center',
+    gap: '0.5rem',
+    padding: '0.3rem 0',
+    borderBottom: '1px solid #edf2f7',
+  },
+  dot: { width: 8, height: 8, borderRadius: '50%', flexShrink: 0 },
+  historyTime: { color: '#718096', fontSize: '0.75rem', flexShrink: 0 },
+};
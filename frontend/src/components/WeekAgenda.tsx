@@ -0,0 +1,181 @@
+import { useEffect, useState } from 'react';
+import { errorMessage, getAgenda } from '../api/client';
+import { appTypeBadgeStyle, sharedStyles } from '../styles/shared';
+import type { AgendaDay, DailyForecast, PlannedActivity } from '../types';
+import { ACTIVITY_STATUS_COLORS } from '../types';
+import { typeLabel } from '../utils/typeRegistry';
+import { formatInches } from '../utils/units';
+
+/** `YYYY-MM-DD` of a local date. */
+export function isoDate(d: Date): string {
+  const pad = (n: number) => String(n).padStart(2, '0');
+  return `${d.getFullYear()}-${pad(d.getMonth() + 1)}-${pad(d.getDate())}`;
+}
+
+/** `date` moved by `days`, both `YYYY-MM-DD`. */
+export function addDays(date: string, days: number): string {
+  const d = new Date(date + 'T00:00:00');
+  d.setDate(d.getDate() + days);
+  return isoDate(d);
+}
+
+/** Sunday on or before `date`, like the month grid's first column. */
+export function weekStartOf(date: string): string {
+  return addDays(date, -new Date(date + 'T00:00:00').getDay());
+}
+
+interface Props {
+  /** Sunday the week starts on, `YYYY-MM-DD`. */
+  start: string;
+  /** Seasonal plan windows open on a date. */
+  activitiesFor: (date: string) => PlannedActivity[];
+}
+
+/** Seven days of logged and planned applications with each day's forecast. */
+export default function WeekAgenda({ start, activitiesFor }: Props) {
+  const [days, setDays] = useState<AgendaDay[] | null>(null);
+  const [error, setError] = useState<string | null>(null);
+  const today = isoDate(new Date());
+
+  useEffect(() => {
+    let cancelled = false;
+    getAgenda(start)
+      .then((r) => {
+        if (!cancelled) {
+          setDays(r.days);
+          setError(null);
+        }
+      })
+      .catch((e) => {
+        if (!cancelled) setError(errorMessage(e, 'Failed to load the week'));
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, [start]);
+
+  if (error) return <div style={sharedStyles.error}>{error}</div>;
+  if (!days) return <p style={sharedStyles.loading}>Loading...</p>;
+
+  return (
+    <div style={styles.list}>
+      {days.map((day) => {
+        const activities = activitiesFor(day.date);
+        const empty =
+          day.applications.length === 0 &&
+          day.follow_ups.length === 0 &&
+          activities.length === 0;
+        return (
+          <div
+            key={day.date}
+            style={{ ...styles.day, ...(day.date === today ? styles.today : {}) }}
+          >
+            <div style={styles.dayHeader}>
+              <span style={styles.dayName}>
+                {new Date(day.date + 'T00:00:00').toLocaleDateString('en-US', {
+                  weekday: 'short',
+                  month: 'short',
+                  day: 'numeric',
+                })}
+              </span>
+              {day.forecast && <ForecastSummary forecast={day.forecast} />}
+            </div>
+
+            {day.applications.map((app) => (
+              <div key={`app-${app.id}`} style={styles.item}>
+                <span
+                  style={appTypeBadgeStyle(
+                    styles.badge,
+                    app.application_type,
+                    app.custom_type_id
+                  )}
+                >
+                  {typeLabel(app)}
+                </span>
+                {app.product_name && <span>{app.product_name}</span>}
+                {day.date > today && <span style={styles.planned}>planned</span>}
+              </div>
+            ))}
+            {day.follow_ups.map((app) => (
+              <div key={`fu-${app.id}`} style={styles.item}>
+                <span
+                  style={{
+                    ...appTypeBadgeStyle(styles.badge, app.application_type, app.custom_type_id),
+                    backgroundColor: 'transparent',
+                  }}
+                >
+                  {typeLabel(app)}
+                </span>
+                <span style={styles.muted}>follow-up to {app.application_date}</span>
+              </div>
+            ))}
+            {activities.map((a) => (
+              <div key={a.id} style={styles.item}>
+                <span
+                  style={{
+                    ...styles.activityBar,
+                    backgroundColor: ACTIVITY_STATUS_COLORS[a.status],
+                  }}
+                />
+                <span style={styles.muted}>{a.name} window</span>
+              </div>
+            ))}
+            {empty && <div style={styles.muted}>Nothing logged or planned</div>}
+          </div>
+        );
+      })}
+    </div>
+  );
+}
+
+function ForecastSummary({ forecast: f }: { forecast: DailyForecast }) {
+  const rain = f.max_precipitation_prob >= 0.2 || f.total_precipitation_mm > 0;
+  return (
+    <span style={styles.forecast}>
+      {f.dominant_condition} · {f.high_temp_f.toFixed(0)}°/{f.low_temp_f.toFixed(0)}°F
+      {rain &&
+        ` · ${Math.round(f.max_precipitation_prob * 100)}% rain` +
+          (f.total_precipitation_mm > 0 ? `, ${formatInches(f.total_precipitation_mm)}` : '')}
+      {f.avg_wind_speed_mph >= 10 && ` · wind ${f.avg_wind_speed_mph.toFixed(0)} mph`}
+    </span>
+  );
+}
+
+const styles: Record<string, React.CSSProperties> = {
+  list: { display: 'flex', flexDirection: 'column', gap: 8, marginBottom: '1rem' },
+  day: {
+    backgroundColor: '#fff',
+    borderRadius: 8,
+    padding: '0.75rem 1rem',
+    boxShadow: '0 1px 3px rgba(0,0,0,0.08)',
+  },
+  today: { boxShadow: 'inset 3px 0 0 #3182ce, 0 1px 3px rgba(0,0,0,0.08)' },
+  dayHeader: {
+    display: 'flex',
+    justifyContent: 'space-between',
+    alignItems: 'baseline',
+    flexWrap: 'wrap' as const,
+    gap: 8,
+    marginBottom: 6,
+  },
+  dayName: { fontWeight: 600, color: '#2d3748' },
+  forecast: { fontSize: '0.8rem', color: '#4a5568' },
+  item: { display: 'flex', alignItems: 'center', gap: 8, fontSize: '0.85rem', marginTop: 4 },
+  badge: {
+    display: 'inline-block',
+    padding: '1px 8px',
+    borderRadius: 10,
+    fontSize: '0.75rem',
+    fontWeight: 600,
+    border: '1px solid',
+  },
+  planned: {
+    fontSize: '0.7rem',
+    color: '#3182ce',
+    border: '1px dashed #3182ce',
+    borderRadius: 10,
+    padding: '0 6px',
+  },
+  activityBar: { width: 14, height: 4, borderRadius: 2, display: 'inline-block' },
+  muted: { fontSize: '0.8rem', color: '#718096' },
+};
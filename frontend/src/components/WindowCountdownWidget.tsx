@@ -0,0 +1,109 @@
+import type { WindowProjection } from '../types';
+import { PREDICTION_CONFIDENCE_COLORS } from '../types';
+
+interface WindowCountdownWidgetProps {
+  windows: WindowProjection[];
+}
+
+function formatDate(date: string): string {
+  return new Date(date + 'T00:00:00').toLocaleDateString(undefined, {
+    month: 'short',
+    day: 'numeric',
+  });
+}
+
+function countdown(w: WindowProjection): string {
+  const verb = w.phase === 'Opens' ? 'opens' : 'closes';
+  if (w.days <= 0) return `${verb} today`;
+  const approx = w.projected || w.confidence !== 'High' ? '~' : '';
+  return `${verb} in ${approx}${w.days} day${w.days === 1 ? '' : 's'}`;
+}
+
+export default function WindowCountdownWidget({ windows }: WindowCountdownWidgetProps) {
+  return (
+    <div style={styles.card}>
+      <div style={styles.label}>Window Countdown</div>
+      {windows.map((w) => {
+        const confColor = PREDICTION_CONFIDENCE_COLORS[w.confidence];
+        return (
+          <div key={w.window} style={styles.row}>
+            <div style={styles.rowHeader}>
+              <span style={styles.window}>
+                {w.window} window {countdown(w)}
+              </span>
+              <span
+                style={{
+                  ...styles.confBadge,
+                  backgroundColor: confColor + '22',
+                  color: confColor,
+                  borderColor: confColor,
+                }}
+              >
+                {w.confidence}
+              </span>
+            </div>
+            <div style={styles.detail}>
+              {formatDate(w.date)}, when {w.trigger}
+            </div>
+            {w.projected && (
+              <div style={styles.note}>Projected from forecast trend</div>
+            )}
+          </div>
+        );
+      })}
+      <div style={styles.hint}>Plan product purchases and schedules ahead of these dates.</div>
+    </div>
+  );
+}
+
+const styles: Record<string, React.CSSProperties> = {
+  card: {
+    backgroundColor: '#fff',
+    borderRadius: 8,
+    padding: '1rem',
+    boxShadow: '0 1px 3px rgba(0,0,0,0.08)',
+  },
+  label: {
+    fontSize: '0.75rem',
+    fontWeight: 600,
+    color: '#718096',
+    textTransform: 'uppercase' as const,
+    marginBottom: 6,
+  },
+  row: {
+    padding: '6px 0',
+    borderBottom: '1px solid #edf2f7',
+  },
+  rowHeader: {
+    display: 'flex',
+    justifyContent: 'space-between',
+    alignItems: 'center',
+  },
+  window: {
+    fontSize: '0.9rem',
+    fontWeight: 600,
+    color: '#1a202c',
+  },
+  confBadge: {
+    display: 'inline-block',
+    padding: '1px 8px',
+    borderRadius: 10,
+    fontSize: '0.7rem',
+    fontWeight: 600,
+    border: '1px solid',
+  },
+  detail: {
+    fontSize: '0.8rem',
+    color: '#4a5568',
+    marginTop: 2,
+  },
+  note: {
+    fontSize: '0.7rem',
+    color: '#a0aec0',
+  },
+  hint: {
+    fontSize: '0.7rem',
+    color: '#a0aec0',
+    marginTop: 6,
+  },
+};
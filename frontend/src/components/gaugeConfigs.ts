@@ -1,9 +1,35 @@
+import type { GaugeRanges, GrassType, SoilType } from '../types';
+
+export interface GaugeThresholds {
+  warn: number;
+  critical: number;
+  /** At or below these the bar also turns yellow / red (e.g. drought). */
+  lowWarn?: number;
+  lowCritical?: number;
+}
+
+export interface GaugeBand {
+  from: number;
+  to: number;
+  label: string;
+  color: string;
+}
+
+export interface GaugeMarker {
+  at: number;
+  label: string;
+}
+
 export interface GaugeConfig {
   label: string;
   unit: string;
   min: number;
   max: number;
-  thresholds: { warn: number; critical: number };
+  thresholds: GaugeThresholds;
+  bands?: GaugeBand[];
+  markers?: GaugeMarker[];
+  /** Tooltip on the bar explaining where min/max came from. */
+  rangeNote?: string;
 }
 
 export const SOIL_TEMP_GAUGE: GaugeConfig = {
@@ -37,3 +63,89 @@ export const SOIL_MOISTURE_GAUGE: GaugeConfig = {
   max: 50,
   thresholds: { warn: 40, critical: 45 },
 };
+
+const WARM_SEASON: GrassType[] = ['Bermuda', 'Zoysia', 'StAugustine'];
+
+/** Seed germination soil temps (°F) by season type. */
+const GERMINATION_RANGE: Record<'cool' | 'warm', [number, number]> = {
+  cool: [50, 65],
+  warm: [65, 80],
+};
+
+/** Soil temp gauge with the crabgrass pre-emergent window and the grass's germination range. */
+export function soilTempGauge(grass: GrassType | null | undefined): GaugeConfig {
+  const [germLow, germHigh] =
+    GERMINATION_RANGE[grass && WARM_SEASON.includes(grass) ? 'warm' : 'cool'];
+  return {
+    ...SOIL_TEMP_GAUGE,
+    bands: [
+      { from: 50, to: 55, label: 'Pre-emergent', color: '#805ad5' },
+      { from: germLow, to: germHigh, label: 'Germination', color: '#38a169' },
+    ],
+  };
+}
+
+/** Volumetric water content (%) at wilting point, field capacity and saturation. */
+interface MoistureLimits {
+  wilt: number;
+  fieldCapacity: number;
+  saturation: number;
+}
+
+const MOISTURE_LIMITS: Record<SoilType, MoistureLimits> = {
+  Sandy: { wilt: 5, fieldCapacity: 12, saturation: 38 },
+  SandyLoam: { wilt: 8, fieldCapacity: 18, saturation: 41 },
+  Loam: { wilt: 12, fieldCapacity: 27, saturation: 43 },
+  SiltLoam: { wilt: 13, fieldCapacity: 31, saturation: 45 },
+  ClayLoam: { wilt: 18, fieldCapacity: 34, saturation: 46 },
+  Clay: { wilt: 24, fieldCapacity: 40, saturation: 48 },
+};
+
+/**
+ * Soil moisture gauge marked with the wilt point, field capacity and saturation
+ * for the soil texture (Loam when unset). Yellow once half the plant-available
+ * water is gone, red at wilt point or saturation.
+ */
+export function soilMoistureGauge(soil: SoilType | null | undefined): GaugeConfig {
+  const { wilt, fieldCapacity, saturation } = MOISTURE_LIMITS[soil ?? 'Loam'];
+  return {
+    ...SOIL_MOISTURE_GAUGE,
+    thresholds: {
+      warn: (fieldCapacity + saturation) / 2,
+      critical: saturation,
+      lowWarn: (wilt + fieldCapacity) / 2,
+      lowCritical: wilt,
+    },
+    bands: [{ from: wilt, to: fieldCapacity, label: 'Plant-available', color: '#4299e1' }],
+    markers: [
+      { at: wilt, label: 'Wilt point' },
+      { at: fieldCapacity, label: 'Field capacity' },
+      { at: saturation, label: 'Saturation' },
+    ],
+  };
+}
+
+/**
+ * `config` rescaled to a range fitted to local history. Bands and markers that
+ * fall entirely outside it are dropped rather than pinned to an end. Static
+ * ranges (thin history) leave the config as is.
+ */
+export function withRange(
+  config: GaugeConfig,
+  ranges: GaugeRanges | null,
+  metric: Exclude<keyof GaugeRanges, 'history_years'>
+): GaugeConfig {
+  const range = ranges?.[metric];
+  if (!ranges || !range?.adaptive) return config;
+  const { min, max } = range;
+  return {
+    ...config,
+    min,
+    max,
+    bands: config.bands?.filter((b) => b.to >= min && b.from <= max),
+    markers: config.markers?.filter((m) => m.at >= min && m.at <= max),
+    rangeNote:
+      `Scaled to the 5th–95th percentile of up to ${ranges.history_years} years ` +
+      `of local readings (${min}–${max}${config.unit})`,
+  };
+}
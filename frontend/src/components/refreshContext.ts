@@ -0,0 +1,23 @@
+import { createContext, useContext } from 'react';
+
+export interface RefreshApi {
+  /** A refresh is in flight. */
+  refreshing: boolean;
+  /** The in-flight refresh is a deep one. */
+  deep: boolean;
+  /** Bumped after each finished refresh; pages remount on it. */
+  generation: number;
+  /** Refresh sensor data and forecast, or everything when `deep`. Repeat presses are ignored. */
+  refresh: (deep?: boolean) => void;
+}
+
+export const RefreshContext = createContext<RefreshApi>({
+  refreshing: false,
+  deep: false,
+  generation: 0,
+  refresh: () => {},
+});
+
+export function useRefresh(): RefreshApi {
+  return useContext(RefreshContext);
+}
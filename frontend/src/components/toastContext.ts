@@ -0,0 +1,28 @@
+import { createContext, useContext } from 'react';
+
+export type ToastLevel = 'info' | 'success' | 'warning' | 'error';
+
+export interface ToastMessage {
+  id: number;
+  level: ToastLevel;
+  text: string;
+  createdAt: number;
+}
+
+export interface ToastApi {
+  /** Queue a status message; it expires automatically after a few seconds. */
+  notify: (text: string, level?: ToastLevel) => void;
+}
+
+export const TOAST_COLORS: Record<ToastLevel, string> = {
+  info: '#3b82f6',
+  success: '#22c55e',
+  warning: '#eab308',
+  error: '#ef4444',
+};
+
+export const ToastContext = createContext<ToastApi>({ notify: () => {} });
+
+export function useToast(): ToastApi {
+  return useContext(ToastContext);
+}
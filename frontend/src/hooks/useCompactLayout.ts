@@ -0,0 +1,21 @@
+import { useSyncExternalStore } from 'react';
+
+/** Below this viewport width the app switches to its single-column layout. */
+export const COMPACT_MAX_WIDTH = 768;
+
+const query = `(max-width: ${COMPACT_MAX_WIDTH}px)`;
+
+function subscribe(onChange: () => void) {
+  const mql = window.matchMedia(query);
+  mql.addEventListener('change', onChange);
+  return () => mql.removeEventListener('change', onChange);
+}
+
+/** True on narrow viewports (phones, split-screen windows). */
+export function useCompactLayout(): boolean {
+  return useSyncExternalStore(
+    subscribe,
+    () => window.matchMedia(query).matches,
+    () => false
+  );
+}
@@ -0,0 +1,68 @@
+import { useCallback, useEffect, useMemo, useRef, useState, type FormEvent } from 'react';
+import { markDirty } from '../utils/unsavedChanges';
+
+export type FieldErrors<T> = Partial<Record<keyof T, string>>;
+
+/**
+ * Form state with inline validation and dirty tracking.
+ *
+ * `values` start from (and `reset` returns to) a saved baseline; the form is
+ * dirty while any field differs from it, and a dirty form makes page unloads
+ * and `confirmDiscard` ask before dropping the edits. On submit, `validate`
+ * runs first: failures land in `errors` and the first invalid field (in
+ * `initial`'s key order) gets focus.
+ */
+export function useForm<T extends object>(
+  initial: T,
+  validate: (values: T) => FieldErrors<T> = () => ({})
+) {
+  const [values, setValues] = useState<T>(initial);
+  const [baseline, setBaseline] = useState<T>(initial);
+  const [errors, setErrors] = useState<FieldErrors<T>>({});
+  const fields = useRef<Partial<Record<keyof T, HTMLElement | null>>>({});
+
+  const dirty = useMemo(
+    () => (Object.keys(values) as (keyof T)[]).some((k) => values[k] !== baseline[k]),
+    [values, baseline]
+  );
+
+  useEffect(() => {
+    if (!dirty) return;
+    return markDirty();
+  }, [dirty]);
+
+  const set = useCallback(<K extends keyof T>(field: K, value: T[K]) => {
+    setValues((prev) => ({ ...prev, [field]: value }));
+    setErrors((prev) => (prev[field] ? { ...prev, [field]: undefined } : prev));
+  }, []);
+
+  /** Replace values and baseline, e.g. after loading or saving. */
+  const reset = useCallback((next: T) => {
+    setValues(next);
+    setBaseline(next);
+    setErrors({});
+  }, []);
+
+  /** Ref callback so validation can focus the field. */
+  const fieldRef = useCallback(
+    (field: keyof T) => (el: HTMLElement | null) => {
+      fields.current[field] = el;
+    },
+    []
+  );
+
+  const handleSubmit =
+    (onValid: (values: T) => Promise<void> | void) => async (e: FormEvent) => {
+      e.preventDefault();
+      const found = validate(values);
+      setErrors(found);
+      const firstInvalid = (Object.keys(values) as (keyof T)[]).find((k) => found[k]);
+      if (firstInvalid !== undefined) {
+        fields.current[firstInvalid]?.focus();
+        return;
+      }
+      await onValid(values);
+    };
+
+  return { values, set, errors, dirty, reset, fieldRef, handleSubmit };
+}
@@ -0,0 +1,27 @@
+import { useEffect, useState } from 'react';
+import { getGaugeRanges } from '../api/client';
+import type { GaugeRanges } from '../types';
+
+// Fitted from years of history, so one fetch per page load is plenty
+let pending: Promise<GaugeRanges | null> | null = null;
+
+/** Gauge scales fitted to local history; null until loaded or if unavailable. */
+export function useGaugeRanges(): GaugeRanges | null {
+  const [ranges, setRanges] = useState<GaugeRanges | null>(null);
+
+  useEffect(() => {
+    let cancelled = false;
+    pending ??= getGaugeRanges().catch(() => {
+      pending = null;
+      return null;
+    });
+    pending.then((r) => {
+      if (!cancelled) setRanges(r);
+    });
+    return () => {
+      cancelled = true;
+    };
+  }, []);
+
+  return ranges;
+}
@@ -2,9 +2,14 @@ import { StrictMode } from 'react';
 import { createRoot } from 'react-dom/client';
 import App from './App';
 import './index.css';
+import { loadLocale } from './utils/i18n';
+import { loadTypeRegistry } from './utils/typeRegistry';
+import { loadUiState } from './utils/uiState';
 
-createRoot(document.getElementById('root')!).render(
-  <StrictMode>
-    <App />
-  </StrictMode>
+Promise.all([loadLocale(), loadUiState(), loadTypeRegistry()]).then(() =>
+  createRoot(document.getElementById('root')!).render(
+    <StrictMode>
+      <App />
+    </StrictMode>
+  )
 );
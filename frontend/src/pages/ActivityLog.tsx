@@ -0,0 +1,165 @@
+import { useCallback, useEffect, useState } from 'react';
+import { getEvents } from '../api/client';
+import { sharedStyles } from '../styles/shared';
+import type { ActivityEvent, EventKind, EventLevel } from '../types';
+import { EVENT_KIND_LABELS, EVENT_LEVEL_COLORS } from '../types';
+import { t } from '../utils/i18n';
+
+const EVENT_KINDS = Object.keys(EVENT_KIND_LABELS) as EventKind[];
+const EVENT_LEVELS: EventLevel[] = ['Info', 'Warning', 'Error'];
+
+export default function ActivityLog() {
+  const [events, setEvents] = useState<ActivityEvent[]>([]);
+  const [kind, setKind] = useState('');
+  const [level, setLevel] = useState('');
+  const [expanded, setExpanded] = useState<number | null>(null);
+  const [loading, setLoading] = useState(true);
+  const [error, setError] = useState<string | null>(null);
+
+  const fetchEvents = useCallback(async () => {
+    try {
+      const data = await getEvents({
+        kind: kind || undefined,
+        level: level || undefined,
+      });
+      setEvents(data);
+      setError(null);
+    } catch (e) {
+      setError(e instanceof Error ? e.message : 'Failed to load activity log');
+    } finally {
+      setLoading(false);
+    }
+  }, [kind, level]);
+
+  useEffect(() => {
+    fetchEvents();
+  }, [fetchEvents]);
+
+  return (
+    <div>
+      <div style={sharedStyles.headerRow}>
+        <h1 style={sharedStyles.pageTitle}>{t('Activity Log')}</h1>
+        <button style={styles.refreshBtn} onClick={fetchEvents}>
+          Reload
+        </button>
+      </div>
+
+      {error && <div style={sharedStyles.error}>{error}</div>}
+
+      <div style={styles.filterRow}>
+        <label style={styles.filterLabel}>Kind:</label>
+        <select style={styles.select} value={kind} onChange={(e) => setKind(e.target.value)}>
+          <option value="">All</option>
+          {EVENT_KINDS.map((k) => (
+            <option key={k} value={k}>
+              {EVENT_KIND_LABELS[k]}
+            </option>
+          ))}
+        </select>
+
+        <label style={{ ...styles.filterLabel, marginLeft: 16 }}>Minimum level:</label>
+        <select style={styles.select} value={level} onChange={(e) => setLevel(e.target.value)}>
+          <option value="">All</option>
+          {EVENT_LEVELS.map((l) => (
+            <option key={l} value={l}>
+              {l}
+            </option>
+          ))}
+        </select>
+      </div>
+
+      {loading ? (
+        <p style={sharedStyles.loading}>Loading...</p>
+      ) : events.length === 0 ? (
+        <p style={sharedStyles.empty}>No events recorded yet.</p>
+      ) : (
+        <table style={sharedStyles.table}>
+          <thead>
+            <tr>
+              <th style={sharedStyles.th}>Time</th>
+              <th style={sharedStyles.th}>Level</th>
+              <th style={sharedStyles.th}>Kind</th>
+              <th style={sharedStyles.th}>Message</th>
+            </tr>
+          </thead>
+          <tbody>
+            {events.map((ev, index) => {
+              const key = ev.id ?? index;
+              const color = EVENT_LEVEL_COLORS[ev.level];
+              return (
+                <tr
+                  key={key}
+                  style={ev.details ? styles.clickableRow : undefined}
+                  onClick={() =>
+                    ev.details && setExpanded(expanded === key ? null : key)
+                  }
+                >
+                  <td style={{ ...sharedStyles.td, whiteSpace: 'nowrap' }}>
+                    {new Date(ev.occurred_at).toLocaleString()}
+                  </td>
+                  <td style={sharedStyles.td}>
+                    <span
+                      style={{
+                        ...sharedStyles.badge,
+                        color,
+                        borderColor: color,
+                        backgroundColor: color + '22',
+                      }}
+                    >
+                      {ev.level}
+                    </span>
+                  </td>
+                  <td style={sharedStyles.td}>{EVENT_KIND_LABELS[ev.kind]}</td>
+                  <td style={sharedStyles.td}>
+                    {ev.message}
+                    {expanded === key && ev.details && (
+                      <div style={styles.details}>{ev.details}</div>
+                    )}
+                  </td>
+                </tr>
+              );
+            })}
+          </tbody>
+        </table>
+      )}
+    </div>
+  );
+}
+
+const styles: Record<string, React.CSSProperties> = {
+  refreshBtn: {
+    padding: '0.5rem 1rem',
+    backgroundColor: '#3182ce',
+    color: '#fff',
+    border: 'none',
+    borderRadius: 6,
+    cursor: 'pointer',
+    fontWeight: 600,
+    fontSize: '0.85rem',
+  },
+  filterRow: {
+    display: 'flex',
+    alignItems: 'center',
+    gap: 8,
+    marginBottom: '1rem',
+  },
+  filterLabel: { fontSize: '0.85rem', color: '#4a5568' },
+  select: {
+    padding: '0.4rem 0.6rem',
+    borderRadius: 6,
+    border: '1px solid #e2e8f0',
+    fontSize: '0.85rem',
+  },
+  clickableRow: { cursor: 'pointer' },
+  details: {
+    marginTop: 6,
+    padding: '0.4rem 0.6rem',
+    backgroundColor: '#f7fafc',
+    borderRadius: 4,
+    fontFamily: 'monospace',
+    fontSize: '0.75rem',
+    color: '#4a5568',
+    whiteSpace: 'pre-wrap' as const,
+    wordBreak: 'break-word' as const,
+  },
+};
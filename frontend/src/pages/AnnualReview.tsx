@@ -0,0 +1,449 @@
+import { useEffect, useState } from 'react';
+import {
+  getAnnualReview,
+  importSeasonBundle,
+  saveAnnualReview,
+  seasonBundleUrl,
+  seasonReportUrl,
+  suggestReviewAdjustments,
+} from '../api/client';
+import EfficacyTimeline from '../components/EfficacyTimeline';
+import { useToast } from '../components/toastContext';
+import { appTypeBadgeStyle, sharedStyles } from '../styles/shared';
+import type {
+  AnnualReview as AnnualReviewData,
+  ApplicationOutcome,
+  ApplicationReview,
+  IssueLevel,
+  ReviewAdjustment,
+  ReviewObservations,
+} from '../types';
+import { APPLICATION_TYPE_LABELS } from '../types';
+import { t } from '../utils/i18n';
+
+const OUTCOMES: ApplicationOutcome[] = ['Effective', 'Partial', 'Ineffective', 'Unsure'];
+const ISSUE_LEVELS: IssueLevel[] = ['None', 'Minor', 'Moderate', 'Severe'];
+const STEPS = ['Outcomes', 'Observations', 'Adjustments', 'Done'] as const;
+
+const OBSERVATION_FIELDS: { key: 'disease' | 'weeds' | 'thin_areas'; label: string }[] = [
+  { key: 'disease', label: 'Disease' },
+  { key: 'weeds', label: 'Weeds' },
+  { key: 'thin_areas', label: 'Thin / bare areas' },
+];
+
+export default function AnnualReview() {
+  const currentYear = new Date().getFullYear();
+  const [year, setYear] = useState(currentYear);
+  const [step, setStep] = useState(0);
+  const [review, setReview] = useState<AnnualReviewData | null>(null);
+  const [outcomes, setOutcomes] = useState<ApplicationReview[]>([]);
+  const [observations, setObservations] = useState<ReviewObservations>({
+    disease: 'None',
+    weeds: 'None',
+    thin_areas: 'None',
+    notes: null,
+  });
+  const [adjustments, setAdjustments] = useState<ReviewAdjustment[]>([]);
+  const [loading, setLoading] = useState(true);
+  const [busy, setBusy] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+  const { notify } = useToast();
+
+  useEffect(() => {
+    let cancelled = false;
+    setLoading(true);
+    (async () => {
+      try {
+        const r = await getAnnualReview(year);
+        if (cancelled) return;
+        setReview(r);
+        setOutcomes(r.application_reviews);
+        setObservations(r.observations);
+        setAdjustments(r.adjustments);
+        setStep(0);
+        setError(null);
+      } catch (e) {
+        if (!cancelled) setError(e instanceof Error ? e.message : 'Failed to load review');
+      } finally {
+        if (!cancelled) setLoading(false);
+      }
+    })();
+    return () => {
+      cancelled = true;
+    };
+  }, [year]);
+
+  const payload = () => ({
+    application_reviews: outcomes,
+    observations,
+    adjustments,
+  });
+
+  const goToAdjustments = async () => {
+    setBusy(true);
+    try {
+      setAdjustments(await suggestReviewAdjustments(year, payload()));
+      setStep(2);
+      setError(null);
+    } catch (e) {
+      setError(e instanceof Error ? e.message : 'Failed to generate suggestions');
+    } finally {
+      setBusy(false);
+    }
+  };
+
+  const handleSave = async () => {
+    setBusy(true);
+    try {
+      const saved = await saveAnnualReview(year, payload());
+      setReview(saved);
+      setStep(3);
+      setError(null);
+      notify(`${year} review saved`, 'success');
+    } catch (e) {
+      setError(e instanceof Error ? e.message : 'Failed to save review');
+    } finally {
+      setBusy(false);
+    }
+  };
+
+  const handleImport = async (e: React.ChangeEvent<HTMLInputElement>) => {
+    const file = e.target.files?.[0];
+    e.target.value = '';
+    if (!file) return;
+    try {
+      const summary = await importSeasonBundle(JSON.parse(await file.text()));
+      notify(
+        `${summary.year} bundle: ${summary.imported} imported, ${summary.skipped} already logged` +
+          (summary.grass_type_mismatch ? ' (different grass type)' : ''),
+        'success',
+      );
+    } catch (err) {
+      notify(
+        `Import failed: ${err instanceof Error ? err.message : 'unreadable file'}`,
+        'error',
+      );
+    }
+  };
+
+  const setOutcome = (index: number, outcome: ApplicationOutcome) =>
+    setOutcomes((prev) => prev.map((r, i) => (i === index ? { ...r, outcome } : r)));
+
+  const toggleAdjustment = (key: string) =>
+    setAdjustments((prev) =>
+      prev.map((a) => (a.key === key ? { ...a, accepted: !a.accepted } : a)),
+    );
+
+  const accepted = adjustments.filter((a) => a.accepted);
+
+  return (
+    <div>
+      <div style={sharedStyles.headerRow}>
+        <h1 style={sharedStyles.pageTitle}>{t('Annual Review')}</h1>
+        <div style={styles.reportLinks}>
+          Season report:
+          <a href={seasonReportUrl(year, 'markdown')} style={styles.reportLink}>
+            Markdown
+          </a>
+          <a href={seasonReportUrl(year, 'html')} style={styles.reportLink}>
+            HTML
+          </a>
+          <span>· Season data:</span>
+          <a href={seasonBundleUrl(year)} style={styles.reportLink}>
+            Export
+          </a>
+          <label style={{ ...styles.reportLink, cursor: 'pointer' }}>
+            Import
+            <input
+              type="file"
+              accept="application/json,.json"
+              onChange={handleImport}
+              style={{ display: 'none' }}
+            />
+          </label>
+        </div>
+        <select
+          style={styles.select}
+          value={year}
+          onChange={(e) => setYear(Number(e.target.value))}
+        >
+          {[0, 1, 2, 3, 4].map((offset) => (
+            <option key={offset} value={currentYear - offset}>
+              {currentYear - offset}
+            </option>
+          ))}
+        </select>
+      </div>
+
+      {review?.completed_at && step !== 3 && (
+        <div style={styles.info}>
+          Completed {new Date(review.completed_at).toLocaleDateString()} — changes will
+          replace the saved review.
+        </div>
+      )}
+
+      {error && <div style={sharedStyles.error}>{error}</div>}
+
+      <div style={styles.steps}>
+        {STEPS.map((label, i) => (
+          <span key={label} style={i === step ? styles.stepActive : styles.step}>
+            {i + 1}. {label}
+          </span>
+        ))}
+      </div>
+
+      {loading ? (
+        <p style={sharedStyles.loading}>Loading...</p>
+      ) : (
+        <div style={sharedStyles.card}>
+          {step === 0 && (
+            <>
+              <h2 style={sharedStyles.sectionTitle}>How did each application work?</h2>
+              {outcomes.length === 0 ? (
+                <p style={styles.muted}>No major applications logged for {year}.</p>
+              ) : (
+                <table style={sharedStyles.table}>
+                  <thead>
+                    <tr>
+                      <th style={sharedStyles.th}>Date</th>
+                      <th style={sharedStyles.th}>Type</th>
+                      <th style={sharedStyles.th}>Product</th>
+                      <th style={sharedStyles.th}>Outcome</th>
+                    </tr>
+                  </thead>
+                  <tbody>
+                    {outcomes.map((r, i) => (
+                      <tr key={r.application_id}>
+                        <td style={sharedStyles.td}>{r.application_date}</td>
+                        <td style={sharedStyles.td}>
+                          <span style={appTypeBadgeStyle(sharedStyles.badge, r.application_type)}>
+                            {APPLICATION_TYPE_LABELS[r.application_type]}
+                          </span>
+                        </td>
+                        <td style={sharedStyles.td}>{r.product_name || '-'}</td>
+                        <td style={sharedStyles.td}>
+                          <select
+                            style={styles.select}
+                            value={r.outcome}
+                            onChange={(e) => setOutcome(i, e.target.value as ApplicationOutcome)}
+                          >
+                            {OUTCOMES.map((o) => (
+                              <option key={o} value={o}>
+                                {o}
+                              </option>
+                            ))}
+                          </select>
+                        </td>
+                      </tr>
+                    ))}
+                  </tbody>
+                </table>
+              )}
+              <div style={styles.actions}>
+                <button style={styles.primaryBtn} onClick={() => setStep(1)}>
+                  Next
+                </button>
+              </div>
+            </>
+          )}
+
+          {step === 1 && (
+            <>
+              <h2 style={sharedStyles.sectionTitle}>What did you see this season?</h2>
+              <div style={styles.obsGrid}>
+                {OBSERVATION_FIELDS.map(({ key, label }) => (
+                  <div key={key}>
+                    <label style={styles.label}>{label}</label>
+                    <select
+                      style={styles.select}
+                      value={observations[key]}
+                      onChange={(e) =>
+                        setObservations({ ...observations, [key]: e.target.value as IssueLevel })
+                      }
+                    >
+                      {ISSUE_LEVELS.map((l) => (
+                        <option key={l} value={l}>
+                          {l}
+                        </option>
+                      ))}
+                    </select>
+                  </div>
+                ))}
+              </div>
+              <label style={styles.label}>Notes</label>
+              <textarea
+                style={styles.textarea}
+                value={observations.notes ?? ''}
+                onChange={(e) =>
+                  setObservations({ ...observations, notes: e.target.value || null })
+                }
+                placeholder="e.g. brown patch in the back corner in July"
+              />
+              <div style={styles.actions}>
+                <button style={styles.secondaryBtn} onClick={() => setStep(0)}>
+                  Back
+                </button>
+                <button style={styles.primaryBtn} onClick={goToAdjustments} disabled={busy}>
+                  {busy ? 'Analyzing...' : 'Suggest adjustments'}
+                </button>
+              </div>
+            </>
+          )}
+
+          {step === 2 && (
+            <>
+              <h2 style={sharedStyles.sectionTitle}>Adjustments for {year + 1}</h2>
+              {adjustments.length === 0 ? (
+                <p style={styles.muted}>No changes suggested. Keep the current program.</p>
+              ) : (
+                adjustments.map((a) => (
+                  <label key={a.key} style={styles.adjustment}>
+                    <input
+                      type="checkbox"
+                      checked={a.accepted}
+                      onChange={() => toggleAdjustment(a.key)}
+                    />
+                    <div>
+                      <div style={styles.adjTitle}>
+                        {a.title}{' '}
+                        <span style={styles.kind}>{a.kind}</span>
+                      </div>
+                      <div style={styles.muted}>{a.detail}</div>
+                      {a.current_value !== null && a.suggested_value !== null && (
+                        <div style={styles.values}>
+                          {a.current_value} → {a.suggested_value}
+                        </div>
+                      )}
+                    </div>
+                  </label>
+                ))
+              )}
+              <div style={styles.actions}>
+                <button style={styles.secondaryBtn} onClick={() => setStep(1)}>
+                  Back
+                </button>
+                <button style={styles.primaryBtn} onClick={handleSave} disabled={busy}>
+                  {busy ? 'Saving...' : 'Save review'}
+                </button>
+              </div>
+            </>
+          )}
+
+          {step === 3 && (
+            <>
+              <h2 style={sharedStyles.sectionTitle}>{year} review saved</h2>
+              {accepted.length === 0 ? (
+                <p style={styles.muted}>No adjustments accepted.</p>
+              ) : (
+                <ul style={styles.list}>
+                  {accepted.map((a) => (
+                    <li key={a.key}>{a.title}</li>
+                  ))}
+                </ul>
+              )}
+              <div style={styles.actions}>
+                <button style={styles.secondaryBtn} onClick={() => setStep(0)}>
+                  Edit review
+                </button>
+              </div>
+            </>
+          )}
+        </div>
+      )}
+
+      <EfficacyTimeline year={year} />
+    </div>
+  );
+}
+
+const styles: Record<string, React.CSSProperties> = {
+  select: {
+    padding: '0.4rem 0.6rem',
+    borderRadius: 6,
+    border: '1px solid #e2e8f0',
+    fontSize: '0.85rem',
+  },
+  info: {
+    padding: '0.5rem 1rem',
+    backgroundColor: '#ebf8ff',
+    color: '#2b6cb0',
+    borderRadius: 6,
+    marginBottom: '1rem',
+    fontSize: '0.85rem',
+  },
+  steps: { display: 'flex', gap: 16, marginBottom: '1rem', fontSize: '0.85rem' },
+  step: { color: '#a0aec0' },
+  stepActive: { color: '#2d3748', fontWeight: 600 },
+  muted: { color: '#718096', fontSize: '0.85rem' },
+  reportLinks: {
+    marginLeft: 'auto',
+    marginRight: 12,
+    display: 'flex',
+    gap: 8,
+    fontSize: '0.85rem',
+    color: '#718096',
+  },
+  reportLink: { color: '#3182ce', fontWeight: 600 },
+  obsGrid: {
+    display: 'grid',
+    gridTemplateColumns: 'repeat(auto-fit, minmax(180px, 1fr))',
+    gap: '1rem',
+    marginBottom: '1rem',
+  },
+  label: {
+    display: 'block',
+    fontSize: '0.8rem',
+    color: '#718096',
+    marginBottom: 4,
+    fontWeight: 600,
+  },
+  textarea: {
+    width: '100%',
+    minHeight: 70,
+    padding: '0.5rem 0.75rem',
+    borderRadius: 6,
+    border: '1px solid #e2e8f0',
+    fontSize: '0.85rem',
+    fontFamily: 'inherit',
+  },
+  adjustment: {
+    display: 'flex',
+    gap: 10,
+    alignItems: 'flex-start',
+    padding: '0.6rem 0',
+    borderBottom: '1px solid #edf2f7',
+    cursor: 'pointer',
+  },
+  adjTitle: { fontWeight: 600, color: '#2d3748', fontSize: '0.9rem' },
+  kind: {
+    fontSize: '0.7rem',
+    color: '#718096',
+    border: '1px solid #e2e8f0',
+    borderRadius: 10,
+    padding: '0 6px',
+    marginLeft: 4,
+  },
+  values: { fontSize: '0.8rem', color: '#2b6cb0', marginTop: 2 },
+  list: { margin: '0 0 0 1.2rem', color: '#2d3748', fontSize: '0.9rem' },
+  actions: { display: 'flex', gap: 8, marginTop: '1rem' },
+  primaryBtn: {
+    padding: '0.5rem 1.2rem',
+    backgroundColor: '#3182ce',
+    color: '#fff',
+    border: 'none',
+    borderRadius: 6,
+    cursor: 'pointer',
+    fontWeight: 600,
+    fontSize: '0.85rem',
+  },
+  secondaryBtn: {
+    padding: '0.5rem 1.2rem',
+    backgroundColor: '#edf2f7',
+    color: '#2d3748',
+    border: 'none',
+    borderRadius: 6,
+    cursor: 'pointer',
+    fontWeight: 600,
+    fontSize: '0.85rem',
+  },
+};
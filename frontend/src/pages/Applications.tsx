@@ -1,19 +1,42 @@
 import { useCallback, useEffect, useMemo, useState } from 'react';
+import { useSearchParams } from 'react-router-dom';
 import {
+  backfillApplicationWeather,
   createApplication,
   deleteApplication,
+  errorMessage,
   getApplications,
+  listAreas,
+  listEquipment,
   listPlants,
   updateApplication,
 } from '../api/client';
+import EnumSelect from '../components/EnumSelect';
+import { useToast } from '../components/toastContext';
 import { appTypeBadgeStyle, sharedStyles } from '../styles/shared';
-import type { Application, ApplicationType, Plant } from '../types';
+import type {
+  Application,
+  ApplicationType,
+  Equipment,
+  LawnArea,
+  Plant,
+  WeatherSnapshot,
+} from '../types';
 import {
-  APPLICATION_TYPE_LABELS,
+  APPLICATION_TYPES,
   canTargetPlant,
   isPlantRequiredApplicationType,
   isTurfOnlyApplicationType,
 } from '../types';
+import { t } from '../utils/i18n';
+import {
+  fromChoice,
+  toChoice,
+  typeChoices,
+  typeLabel,
+  type TypeChoice,
+} from '../utils/typeRegistry';
+import { rememberUi, uiState } from '../utils/uiState';
 
 type ScopeFilter = 'all' | 'turf' | 'landscape';
 
@@ -23,42 +46,60 @@ function addDaysISO(dateStr: string, days: number): string {
   return d.toISOString().split('T')[0];
 }
 
-const APP_TYPES: ApplicationType[] = [
-  'PreEmergent',
-  'PostEmergent',
-  'Fertilizer',
-  'Fungicide',
-  'Insecticide',
-  'GrubControl',
-  'Overseed',
-  'Aeration',
-  'Dethatching',
-  'Lime',
-  'Sulfur',
-  'Wetting',
-  'Mowing',
-  'Other',
-  'Pruning',
-  'PlantFertilizer',
-  'Mulching',
-  'Deadheading',
-  'WinterProtection',
-];
-
 export default function Applications() {
+  const { notify } = useToast();
   const [apps, setApps] = useState<Application[]>([]);
-  const [filter, setFilter] = useState('');
-  const [scopeFilter, setScopeFilter] = useState<ScopeFilter>('all');
+  const [filter, setFilter] = useState<TypeChoice | ''>(() => {
+    const saved = uiState();
+    if (saved.applications_custom_type != null) return `custom:${saved.applications_custom_type}`;
+    return saved.applications_type ?? '';
+  });
+  const [scopeFilter, setScopeFilter] = useState<ScopeFilter>(
+    () => uiState().applications_scope ?? 'all'
+  );
   const [loading, setLoading] = useState(true);
   const [error, setError] = useState<string | null>(null);
   const [showForm, setShowForm] = useState(false);
   const [editing, setEditing] = useState<Application | null>(null);
   const [deletingId, setDeletingId] = useState<number | null>(null);
   const [plants, setPlants] = useState<Plant[]>([]);
+  const [areas, setAreas] = useState<LawnArea[]>([]);
+  const [areaFilter, setAreaFilter] = useState('');
+  const [equipment, setEquipment] = useState<Equipment[]>([]);
+  // ?new=<type> from quick actions opens the form, preset to that type
+  const [searchParams, setSearchParams] = useSearchParams();
+  const [newType, setNewType] = useState<ApplicationType | undefined>(undefined);
+
+  useEffect(() => {
+    const requested = searchParams.get('new');
+    if (requested === null) return;
+    const type = APPLICATION_TYPES.find((t) => t === requested);
+    setEditing(null);
+    setNewType(type);
+    setShowForm(true);
+    setSearchParams({}, { replace: true });
+  }, [searchParams, setSearchParams]);
+
+  // Built-in type, or the custom type picked in the filter
+  const filterType = filter ? fromChoice(filter) : null;
+  const filterCustom = filterType?.custom_type_id ?? undefined;
+  const filterBuiltIn = filterCustom == null ? filterType?.application_type : undefined;
+
+  useEffect(() => {
+    rememberUi({
+      applications_type: filterBuiltIn ?? null,
+      applications_custom_type: filterCustom ?? null,
+      applications_scope: scopeFilter,
+    });
+  }, [filterBuiltIn, filterCustom, scopeFilter]);
 
   const fetchApps = useCallback(async () => {
     try {
-      const data = await getApplications(filter || undefined);
+      const data = await getApplications(
+        filterBuiltIn,
+        areaFilter ? Number(areaFilter) : undefined,
+        filterCustom
+      );
       setApps(data);
       setError(null);
     } catch (e) {
@@ -66,7 +107,7 @@ export default function Applications() {
     } finally {
       setLoading(false);
     }
-  }, [filter]);
+  }, [filterBuiltIn, filterCustom, areaFilter]);
 
   useEffect(() => {
     fetchApps();
@@ -75,8 +116,37 @@ export default function Applications() {
   useEffect(() => {
     listPlants()
       .then(setPlants)
-      .catch(() => setPlants([]));
-  }, []);
+      .catch((e) => {
+        setPlants([]);
+        notify(`Failed to load plants: ${errorMessage(e, 'request failed')}`, 'error');
+      });
+  }, [notify]);
+
+  useEffect(() => {
+    listAreas()
+      .then(setAreas)
+      .catch((e) => {
+        setAreas([]);
+        notify(`Failed to load areas: ${errorMessage(e, 'request failed')}`, 'error');
+      });
+  }, [notify]);
+
+  useEffect(() => {
+    listEquipment()
+      .then(setEquipment)
+      .catch((e) => {
+        setEquipment([]);
+        notify(`Failed to load equipment: ${errorMessage(e, 'request failed')}`, 'error');
+      });
+  }, [notify]);
+
+  const areaNameById = useMemo(() => {
+    const map = new Map<number, string>();
+    for (const a of areas) {
+      if (a.id != null) map.set(a.id, a.name);
+    }
+    return map;
+  }, [areas]);
 
   const plantNameById = useMemo(() => {
     const map = new Map<number, string>();
@@ -92,6 +162,8 @@ export default function Applications() {
     return apps.filter((a) => a.plant_id == null);
   }, [apps, scopeFilter]);
 
+  const choices = useMemo(typeChoices, []);
+
   const handleDelete = async (id: number) => {
     if (!confirm('Delete this application?')) return;
     setDeletingId(id);
@@ -125,25 +197,61 @@ export default function Applications() {
     setError(null);
   };
 
+  const [backfilling, setBackfilling] = useState(false);
+
+  /** Fill in conditions for entries logged after the fact. */
+  const handleBackfill = async () => {
+    setBackfilling(true);
+    try {
+      const { updated, missing } = await backfillApplicationWeather();
+      notify(
+        updated === 0 && missing === 0
+          ? 'Every past application already has weather'
+          : `Reconstructed weather for ${updated} application${updated === 1 ? '' : 's'}` +
+              (missing > 0 ? `; no station data for ${missing}` : ''),
+        'success'
+      );
+      if (updated > 0) fetchApps();
+    } catch (e) {
+      notify(errorMessage(e, 'Weather backfill failed'), 'error');
+    } finally {
+      setBackfilling(false);
+    }
+  };
+
   const handleToggleAdd = () => {
     if (editing) setEditing(null);
+    setNewType(undefined);
     setShowForm((v) => !v);
   };
 
   return (
     <div>
       <div style={sharedStyles.headerRow}>
-        <h1 style={sharedStyles.pageTitle}>Applications</h1>
-        <button style={styles.addBtn} onClick={handleToggleAdd} disabled={editing != null}>
-          {showForm ? 'Cancel' : '+ Add Application'}
-        </button>
+        <h1 style={sharedStyles.pageTitle}>{t('Applications')}</h1>
+        <div style={styles.rowActions}>
+          <button
+            style={styles.editBtn}
+            onClick={handleBackfill}
+            disabled={backfilling}
+            title="Rebuild soil temp, air temp, humidity and moisture from station history for past entries that have none"
+          >
+            {backfilling ? 'Backfilling…' : 'Backfill weather'}
+          </button>
+          <button style={styles.addBtn} onClick={handleToggleAdd} disabled={editing != null}>
+            {showForm ? 'Cancel' : '+ Add Application'}
+          </button>
+        </div>
       </div>
 
       {(showForm || editing) && (
         <ApplicationForm
-          key={editing?.id ?? 'new'}
+          key={editing?.id ?? `new-${newType ?? ''}`}
           initial={editing}
+          defaultType={newType}
           plants={plants}
+          areas={areas}
+          equipment={equipment}
           onSaved={handleSaved}
           onCancel={editing ? handleCancelEdit : () => setShowForm(false)}
           onError={setError}
@@ -174,18 +282,32 @@ export default function Applications() {
         <label style={{ ...styles.filterLabel, marginLeft: 16 }}>
           Type:
         </label>
-        <select
+        <EnumSelect
           style={styles.select}
+          options={choices.options}
+          labels={choices.labels}
           value={filter}
-          onChange={(e) => setFilter(e.target.value)}
-        >
-          <option value="">All</option>
-          {APP_TYPES.map((t) => (
-            <option key={t} value={t}>
-              {APPLICATION_TYPE_LABELS[t]}
-            </option>
-          ))}
-        </select>
+          onChange={setFilter}
+          emptyLabel="All"
+        />
+
+        {areas.length > 0 && (
+          <>
+            <label style={{ ...styles.filterLabel, marginLeft: 16 }}>Area:</label>
+            <select
+              style={styles.select}
+              value={areaFilter}
+              onChange={(e) => setAreaFilter(e.target.value)}
+            >
+              <option value="">Whole lawn</option>
+              {areas.map((a) => (
+                <option key={a.id} value={a.id ?? ''}>
+                  {a.name}
+                </option>
+              ))}
+            </select>
+          </>
+        )}
       </div>
 
       {loading ? (
@@ -203,6 +325,7 @@ export default function Applications() {
               <th style={sharedStyles.th}>Rate/1k sqft</th>
               <th style={sharedStyles.th}>N-P-K</th>
               <th style={sharedStyles.th}>Coverage</th>
+              <th style={sharedStyles.th}>Conditions</th>
               <th style={sharedStyles.th}>Follow-up</th>
               <th style={sharedStyles.th}>Notes</th>
               <th style={sharedStyles.th}></th>
@@ -214,15 +337,25 @@ export default function Applications() {
                 <td style={sharedStyles.td}>{app.application_date}</td>
                 <td style={sharedStyles.td}>
                   <span
-                    style={appTypeBadgeStyle(sharedStyles.badge, app.application_type)}
+                    style={appTypeBadgeStyle(
+                      sharedStyles.badge,
+                      app.application_type,
+                      app.custom_type_id
+                    )}
                   >
-                    {APPLICATION_TYPE_LABELS[app.application_type]}
+                    {typeLabel(app)}
                   </span>
                 </td>
                 <td style={sharedStyles.td}>
                   {app.plant_id != null
                     ? (plantNameById.get(app.plant_id) ?? `Plant #${app.plant_id}`)
-                    : <span style={styles.turfTag}>Turf</span>}
+                    : (
+                      <span style={styles.turfTag}>
+                        {app.area_id != null
+                          ? (areaNameById.get(app.area_id) ?? `Area #${app.area_id}`)
+                          : 'Turf'}
+                      </span>
+                    )}
                 </td>
                 <td style={sharedStyles.td}>{app.product_name || '-'}</td>
                 <td style={sharedStyles.td}>
@@ -240,6 +373,9 @@ export default function Applications() {
                     ? `${app.coverage_sqft.toLocaleString()} sqft`
                     : '-'}
                 </td>
+                <td style={{ ...sharedStyles.td, whiteSpace: 'nowrap' }}>
+                  {formatConditions(app.weather_snapshot)}
+                </td>
                 <td style={sharedStyles.td}>
                   {app.follow_up_date ?? '-'}
                 </td>
@@ -271,23 +407,48 @@ export default function Applications() {
   );
 }
 
+/** Soil and air temp at application time; "~" marks a reconstructed daily mean. */
+function formatConditions(w: WeatherSnapshot | null): React.ReactNode {
+  if (!w || (w.soil_temp_10cm_f == null && w.ambient_temp_f == null)) return '-';
+  const parts = [
+    w.soil_temp_10cm_f != null ? `soil ${w.soil_temp_10cm_f.toFixed(0)}°F` : null,
+    w.ambient_temp_f != null ? `air ${w.ambient_temp_f.toFixed(0)}°F` : null,
+  ].filter(Boolean);
+  return (
+    <span title={w.reconstructed ? 'Reconstructed from station history (daily mean)' : undefined}>
+      {w.reconstructed ? '~' : ''}
+      {parts.join(' · ')}
+    </span>
+  );
+}
+
 function ApplicationForm({
   initial,
+  defaultType,
   plants,
+  areas,
+  equipment,
   onSaved,
   onCancel,
   onError,
 }: {
   initial?: Application | null;
+  /** Type for a new entry; defaults to Fertilizer. */
+  defaultType?: ApplicationType;
   plants: Plant[];
+  areas: LawnArea[];
+  equipment: Equipment[];
   onSaved: () => void;
   onCancel: () => void;
   onError: (msg: string) => void;
 }) {
   const isEdit = initial != null && initial.id != null;
-  const [appType, setAppType] = useState<ApplicationType>(
-    initial?.application_type ?? 'Fertilizer'
+  const [typeChoice, setTypeChoice] = useState<TypeChoice>(
+    initial ? toChoice(initial) : (defaultType ?? 'Fertilizer')
   );
+  const choices = useMemo(typeChoices, []);
+  // Scope checks go by the built-in type a custom type maps to
+  const { application_type: appType, custom_type_id: customTypeId } = fromChoice(typeChoice);
   const [productName, setProductName] = useState(initial?.product_name ?? '');
   const [date, setDate] = useState(
     initial?.application_date ?? new Date().toISOString().split('T')[0]
@@ -311,6 +472,12 @@ function ApplicationForm({
   const [plantId, setPlantId] = useState<string>(
     initial?.plant_id != null ? String(initial.plant_id) : ''
   );
+  const [areaId, setAreaId] = useState<string>(
+    initial?.area_id != null ? String(initial.area_id) : ''
+  );
+  const [equipmentId, setEquipmentId] = useState<string>(
+    initial?.equipment_id != null ? String(initial.equipment_id) : ''
+  );
   const [followUpEnabled, setFollowUpEnabled] = useState(
     initial?.follow_up_date != null
   );
@@ -321,6 +488,13 @@ function ApplicationForm({
   const turfOnly = isTurfOnlyApplicationType(appType);
   const plantSelectable = canTargetPlant(appType);
 
+  // Tagging an area fills in its size as the coverage, unless one was typed.
+  const handleAreaChange = (value: string) => {
+    setAreaId(value);
+    const area = areas.find((a) => String(a.id) === value);
+    if (area?.size_sqft != null && !coverage) setCoverage(String(area.size_sqft));
+  };
+
   // When type changes, drop any incompatible plant selection.
   useEffect(() => {
     if (turfOnly) setPlantId('');
@@ -348,6 +522,7 @@ function ApplicationForm({
     setSubmitting(true);
     const payload = {
       application_type: appType,
+      custom_type_id: customTypeId,
       product_name: productName || undefined,
       application_date: date,
       rate_per_1000sqft: rate ? parseFloat(rate) : undefined,
@@ -358,6 +533,8 @@ function ApplicationForm({
       potassium_pct: potassiumPct ? parseFloat(potassiumPct) : undefined,
       plant_id: plantSelectable && plantId ? parseInt(plantId, 10) : undefined,
       follow_up_date: followUpEnabled && followUpDate ? followUpDate : undefined,
+      area_id: areaId && !plantId ? parseInt(areaId, 10) : undefined,
+      equipment_id: equipmentId ? parseInt(equipmentId, 10) : undefined,
     };
     try {
       if (isEdit && initial?.id != null) {
@@ -378,17 +555,15 @@ function ApplicationForm({
       <div style={styles.formGrid}>
         <div>
           <label style={styles.formLabel}>Type</label>
-          <select
+          <EnumSelect
             style={styles.input}
-            value={appType}
-            onChange={(e) => setAppType(e.target.value as ApplicationType)}
-          >
-            {APP_TYPES.map((t) => (
-              <option key={t} value={t}>
-                {APPLICATION_TYPE_LABELS[t]}
-              </option>
-            ))}
-          </select>
+            options={choices.options}
+            labels={choices.labels}
+            value={typeChoice}
+            onChange={(v) => {
+              if (v) setTypeChoice(v);
+            }}
+          />
         </div>
         {plantSelectable && (
           <div>
@@ -413,6 +588,41 @@ function ApplicationForm({
             </select>
           </div>
         )}
+        {areas.length > 0 && !plantId && (
+          <div>
+            <label style={styles.formLabel}>Area</label>
+            <select
+              style={styles.input}
+              value={areaId}
+              onChange={(e) => handleAreaChange(e.target.value)}
+            >
+              <option value="">Whole lawn</option>
+              {areas.map((a) => (
+                <option key={a.id} value={a.id ?? ''}>
+                  {a.name}
+                  {a.size_sqft != null ? ` (${a.size_sqft.toLocaleString()} sqft)` : ''}
+                </option>
+              ))}
+            </select>
+          </div>
+        )}
+        {equipment.length > 0 && (
+          <div>
+            <label style={styles.formLabel}>Equipment</label>
+            <select
+              style={styles.input}
+              value={equipmentId}
+              onChange={(e) => setEquipmentId(e.target.value)}
+            >
+              <option value="">Not recorded</option>
+              {equipment.map((eq) => (
+                <option key={eq.id} value={eq.id ?? ''}>
+                  {eq.name}
+                </option>
+              ))}
+            </select>
+          </div>
+        )}
         <div>
           <label style={styles.formLabel}>Date</label>
           <input
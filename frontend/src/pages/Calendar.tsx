@@ -1,17 +1,31 @@
-import { useEffect, useMemo, useState } from 'react';
-import { getCalendar, getSeasonalPlan } from '../api/client';
+import { useCallback, useEffect, useMemo, useState } from 'react';
+import { useSearchParams } from 'react-router-dom';
+import { errorMessage, getCalendar, getSeasonalPlan, isUnavailable } from '../api/client';
+import { useToast } from '../components/toastContext';
+import WeekAgenda, { addDays, isoDate, weekStartOf } from '../components/WeekAgenda';
+import { useCompactLayout } from '../hooks/useCompactLayout';
 import { appTypeBadgeStyle } from '../styles/shared';
 import type {
   Application,
   CalendarResponse,
+  Observation,
   PlannedActivity,
   SeasonalPlan,
 } from '../types';
-import {
-  ACTIVITY_STATUS_COLORS,
-  APPLICATION_TYPE_COLORS,
-  APPLICATION_TYPE_LABELS,
-} from '../types';
+import { ACTIVITY_STATUS_COLORS, OBSERVATION_CATEGORY_COLORS } from '../types';
+import { t } from '../utils/i18n';
+import { isTypingTarget } from '../utils/keyboard';
+import { customTypes, typeColor, typeLabel } from '../utils/typeRegistry';
+import { rememberUi, uiState } from '../utils/uiState';
+
+const WEEKDAYS = ['Sun', 'Mon', 'Tue', 'Wed', 'Thu', 'Fri', 'Sat'];
+
+/** The saved `YYYY-MM` month as [year, month], if there is one. */
+function savedMonth(): [number, number] | null {
+  const saved = uiState().calendar_month;
+  if (!saved || !/^\d{4}-\d{2}$/.test(saved)) return null;
+  return [Number(saved.slice(0, 4)), Number(saved.slice(5, 7))];
+}
 
 function formatDateRange(start: string, end: string): string {
   const s = new Date(start + 'T00:00:00');
@@ -22,13 +36,66 @@ function formatDateRange(start: string, end: string): string {
 }
 
 export default function Calendar() {
+  const compact = useCompactLayout();
   const today = new Date();
-  const [year, setYear] = useState(today.getFullYear());
-  const [month, setMonth] = useState(today.getMonth() + 1);
+  const [year, setYear] = useState(() => savedMonth()?.[0] ?? today.getFullYear());
+  const [month, setMonth] = useState(() => savedMonth()?.[1] ?? today.getMonth() + 1);
   const [data, setData] = useState<CalendarResponse | null>(null);
   const [plan, setPlan] = useState<SeasonalPlan | null>(null);
   const [selectedDate, setSelectedDate] = useState<string | null>(null);
+  const [view, setView] = useState<'month' | 'week'>(() => uiState().calendar_view ?? 'month');
+  const [weekStart, setWeekStart] = useState(() => weekStartOf(isoDate(today)));
   const [error, setError] = useState<string | null>(null);
+  const { notify } = useToast();
+  const [searchParams] = useSearchParams();
+  const jumpTo = searchParams.get('date');
+
+  // ?date=YYYY-MM-DD (quick actions) opens that month with the day selected
+  useEffect(() => {
+    if (!jumpTo || !/^\d{4}-\d{2}-\d{2}$/.test(jumpTo)) return;
+    setYear(Number(jumpTo.slice(0, 4)));
+    setMonth(Number(jumpTo.slice(5, 7)));
+    setSelectedDate(jumpTo);
+  }, [jumpTo]);
+
+  useEffect(() => {
+    rememberUi({ calendar_month: `${year}-${String(month).padStart(2, '0')}` });
+  }, [year, month]);
+
+  useEffect(() => {
+    rememberUi({ calendar_view: view });
+  }, [view]);
+
+  // Week view: ←/→ step a week. The month follows so the plan overlay and the
+  // month grid stay in step.
+  const showWeek = useCallback((start: string) => {
+    setWeekStart(start);
+    setYear(Number(start.slice(0, 4)));
+    setMonth(Number(start.slice(5, 7)));
+  }, []);
+
+  useEffect(() => {
+    if (view !== 'week') return;
+    const onKey = (e: KeyboardEvent) => {
+      if (isTypingTarget(e.target) || e.ctrlKey || e.metaKey || e.altKey) return;
+      if (e.key === 'ArrowLeft') showWeek(addDays(weekStart, -7));
+      if (e.key === 'ArrowRight') showWeek(addDays(weekStart, 7));
+    };
+    window.addEventListener('keydown', onKey);
+    return () => window.removeEventListener('keydown', onKey);
+  }, [view, weekStart, showWeek]);
+
+  /** Switch layouts; the week opens on the selected day, else in the month shown. */
+  const switchView = (next: 'month' | 'week') => {
+    if (next === 'week') {
+      const todayKey = isoDate(today);
+      const monthKey = `${year}-${String(month).padStart(2, '0')}`;
+      const anchor =
+        selectedDate ?? (todayKey.startsWith(monthKey) ? todayKey : `${monthKey}-01`);
+      showWeek(weekStartOf(anchor));
+    }
+    setView(next);
+  };
 
   // Fetch calendar applications
   useEffect(() => {
@@ -50,20 +117,25 @@ export default function Calendar() {
     };
   }, [year, month]);
 
-  // Fetch seasonal plan (per year, silently fail)
+  // Fetch seasonal plan (per year). The overlay is optional, so a missing
+  // data source is silent, but a real failure is reported.
   useEffect(() => {
     let cancelled = false;
     getSeasonalPlan(year)
       .then((p) => {
         if (!cancelled) setPlan(p);
       })
-      .catch(() => {
-        if (!cancelled) setPlan(null);
+      .catch((e) => {
+        if (cancelled) return;
+        setPlan(null);
+        if (!isUnavailable(e)) {
+          notify(`Seasonal plan unavailable: ${errorMessage(e, 'request failed')}`, 'error');
+        }
       });
     return () => {
       cancelled = true;
     };
-  }, [year]);
+  }, [year, notify]);
 
   const prevMonth = () => {
     if (month === 1) {
@@ -157,23 +229,47 @@ export default function Calendar() {
   const selectedActivities: PlannedActivity[] = selectedDate
     ? getActivitiesForDate(selectedDate)
     : [];
+  const selectedObservations: Observation[] =
+    selectedDate ? data?.observations?.[selectedDate] ?? [] : [];
 
   return (
     <div>
-      <h1 style={styles.title}>Calendar</h1>
+      <h1 style={styles.title}>{t('Calendar')}</h1>
 
       {error && <div style={styles.error}>{error}</div>}
 
       <div style={styles.navRow}>
-        <button style={styles.navBtn} onClick={prevMonth}>
+        <button
+          style={styles.navBtn}
+          onClick={view === 'week' ? () => showWeek(addDays(weekStart, -7)) : prevMonth}
+          title={view === 'week' ? 'Previous week (←)' : undefined}
+        >
           &larr;
         </button>
         <span style={styles.monthLabel}>
-          {monthName} {year}
+          {view === 'week'
+            ? `${formatDateRange(weekStart, addDays(weekStart, 6))}, ${weekStart.slice(0, 4)}`
+            : `${monthName} ${year}`}
         </span>
-        <button style={styles.navBtn} onClick={nextMonth}>
+        <button
+          style={styles.navBtn}
+          onClick={view === 'week' ? () => showWeek(addDays(weekStart, 7)) : nextMonth}
+          title={view === 'week' ? 'Next week (→)' : undefined}
+        >
           &rarr;
         </button>
+        <div style={styles.viewToggle}>
+          {(['month', 'week'] as const).map((v) => (
+            <button
+              key={v}
+              type="button"
+              style={{ ...styles.viewBtn, ...(view === v ? styles.viewBtnActive : {}) }}
+              onClick={() => switchView(v)}
+            >
+              {v === 'month' ? 'Month' : 'Week'}
+            </button>
+          ))}
+        </div>
       </div>
 
       {/* Legend */}
@@ -193,6 +289,10 @@ export default function Calendar() {
           />
           Follow-up due
         </span>
+        <span style={styles.legendItem}>
+          <span style={{ ...styles.dot, ...styles.obsMarker, backgroundColor: '#4a5568' }} />
+          Observation
+        </span>
         <span style={styles.legendDivider}>|</span>
         <span style={styles.legendItem}>
           <span style={{ ...styles.legendBar, backgroundColor: ACTIVITY_STATUS_COLORS.Active }} />
@@ -210,266 +310,326 @@ export default function Calendar() {
           <span style={{ ...styles.legendBar, backgroundColor: ACTIVITY_STATUS_COLORS.Missed }} />
           Missed
         </span>
+        {customTypes().length > 0 && <span style={styles.legendDivider}>|</span>}
+        {customTypes().map((c) => (
+          <span key={c.id} style={styles.legendItem}>
+            <span style={{ ...styles.dot, backgroundColor: c.color }} />
+            {c.name}
+          </span>
+        ))}
       </div>
 
-      <div style={styles.calGrid}>
-        <table style={styles.table}>
-          <thead>
-            <tr>
-              {['Sun', 'Mon', 'Tue', 'Wed', 'Thu', 'Fri', 'Sat'].map((d) => (
-                <th key={d} style={styles.dayHeader}>
-                  {d}
-                </th>
-              ))}
-            </tr>
-          </thead>
-          <tbody>
-            {weeks.map((wk, wi) => (
-              <tr key={wi}>
-                {wk.map((day, di) => {
-                  if (day === null)
-                    return <td key={di} style={styles.emptyCell} />;
-                  const dk = dateKey(day);
-                  const dayApps = loggedAppsByDate[dk] || [];
-                  const dayFollowUps = followUpsByDate[dk] || [];
-                  const dayActivities = getActivitiesForDate(dk);
-                  const hasContent =
-                    dayApps.length > 0 ||
-                    dayFollowUps.length > 0 ||
-                    dayActivities.length > 0;
-                  const isSelected = dk === selectedDate;
-                  return (
-                    <td
-                      key={di}
-                      style={{
-                        ...styles.cell,
-                        backgroundColor: isSelected
-                          ? '#ebf8ff'
-                          : dayActivities.length > 0
-                            ? '#f8faff'
-                            : '#fff',
-                        cursor: hasContent ? 'pointer' : 'default',
-                      }}
-                      onClick={() => hasContent && setSelectedDate(dk)}
-                    >
-                      <div style={styles.dayNum}>{day}</div>
-                      {/* Application dots */}
-                      <div style={styles.dots}>
-                        {dayApps.map((a, i) => (
-                          <span
-                            key={`app-${i}`}
-                            style={{
-                              ...styles.dot,
-                              backgroundColor:
-                                APPLICATION_TYPE_COLORS[a.application_type],
-                            }}
-                            title={
-                              APPLICATION_TYPE_LABELS[a.application_type]
-                            }
-                          />
-                        ))}
-                        {dayFollowUps.map((a, i) => (
-                          <span
-                            key={`fu-${i}`}
-                            style={{
-                              ...styles.dot,
-                              backgroundColor: 'transparent',
-                              border: `2px solid ${APPLICATION_TYPE_COLORS[a.application_type]}`,
-                              boxSizing: 'border-box',
-                            }}
-                            title={`Follow-up: ${APPLICATION_TYPE_LABELS[a.application_type]}`}
-                          />
-                        ))}
-                      </div>
-                      {/* Planned activity bars */}
-                      {dayActivities.length > 0 && (
-                        <div style={styles.activityBars}>
-                          {dayActivities.map((a) => {
-                            const isPlant = a.category === 'Plant Maintenance';
-                            const color = ACTIVITY_STATUS_COLORS[a.status];
-                            return (
+      {view === 'week' ? (
+        <WeekAgenda start={weekStart} activitiesFor={getActivitiesForDate} />
+      ) : (
+        <>
+          <div style={styles.calGrid}>
+            <table style={styles.table}>
+              <thead>
+                <tr>
+                  {WEEKDAYS.map((d) => (
+                    <th key={d} style={styles.dayHeader} title={d}>
+                      {compact ? d[0] : d}
+                    </th>
+                  ))}
+                </tr>
+              </thead>
+              <tbody>
+                {weeks.map((wk, wi) => (
+                  <tr key={wi}>
+                    {wk.map((day, di) => {
+                      if (day === null)
+                        return <td key={di} style={styles.emptyCell} />;
+                      const dk = dateKey(day);
+                      const dayApps = loggedAppsByDate[dk] || [];
+                      const dayFollowUps = followUpsByDate[dk] || [];
+                      const dayActivities = getActivitiesForDate(dk);
+                      const dayObservations = data?.observations?.[dk] || [];
+                      const hasContent =
+                        dayApps.length > 0 ||
+                        dayFollowUps.length > 0 ||
+                        dayActivities.length > 0 ||
+                        dayObservations.length > 0;
+                      const isSelected = dk === selectedDate;
+                      return (
+                        <td
+                          key={di}
+                          style={{
+                            ...(compact ? styles.cellCompact : styles.cell),
+                            backgroundColor: isSelected
+                              ? '#ebf8ff'
+                              : dayActivities.length > 0
+                                ? '#f8faff'
+                                : '#fff',
+                            cursor: hasContent ? 'pointer' : 'default',
+                          }}
+                          onClick={() => hasContent && setSelectedDate(dk)}
+                        >
+                          <div style={styles.dayNum}>{day}</div>
+                          {/* Application dots */}
+                          <div style={styles.dots}>
+                            {dayApps.map((a, i) => (
                               <span
-                                key={a.id}
+                                key={`app-${i}`}
                                 style={{
-                                  ...styles.activityBar,
-                                  backgroundColor: isPlant ? 'transparent' : color,
-                                  border: isPlant ? `2px solid ${color}` : 'none',
-                                  height: isPlant ? 2 : 4,
+                                  ...styles.dot,
+                                  backgroundColor: typeColor(a),
                                 }}
-                                title={`${a.name}${isPlant ? ' (plant)' : ''}`}
+                                title={typeLabel(a)}
                               />
-                            );
-                          })}
+                            ))}
+                            {dayFollowUps.map((a, i) => (
+                              <span
+                                key={`fu-${i}`}
+                                style={{
+                                  ...styles.dot,
+                                  backgroundColor: 'transparent',
+                                  border: `2px solid ${typeColor(a)}`,
+                                  boxSizing: 'border-box',
+                                }}
+                                title={`Follow-up: ${typeLabel(a)}`}
+                              />
+                            ))}
+                            {dayObservations.map((o) => (
+                              <span
+                                key={`obs-${o.id}`}
+                                style={{
+                                  ...styles.dot,
+                                  ...styles.obsMarker,
+                                  backgroundColor: OBSERVATION_CATEGORY_COLORS[o.category],
+                                }}
+                                title={`${o.category}: ${o.notes}`}
+                              />
+                            ))}
+                          </div>
+                          {/* Planned activity bars */}
+                          {dayActivities.length > 0 && (
+                            <div style={styles.activityBars}>
+                              {dayActivities.map((a) => {
+                                const isPlant = a.category === 'Plant Maintenance';
+                                const color = ACTIVITY_STATUS_COLORS[a.status];
+                                return (
+                                  <span
+                                    key={a.id}
+                                    style={{
+                                      ...styles.activityBar,
+                                      backgroundColor: isPlant ? 'transparent' : color,
+                                      border: isPlant ? `2px solid ${color}` : 'none',
+                                      height: isPlant ? 2 : 4,
+                                    }}
+                                    title={`${a.name}${isPlant ? ' (plant)' : ''}`}
+                                  />
+                                );
+                              })}
+                            </div>
+                          )}
+                        </td>
+                      );
+                    })}
+                  </tr>
+                ))}
+              </tbody>
+            </table>
+          </div>
+
+          {/* Detail panel */}
+          {selectedDate && (
+            <div style={styles.detail}>
+              <h3 style={{ margin: '0 0 0.75rem' }}>{selectedDate}</h3>
+
+              {/* Applications section */}
+              {selectedApps.length > 0 && (
+                <div style={{ marginBottom: '1rem' }}>
+                  <h4 style={styles.sectionLabel}>Applications</h4>
+                  {selectedApps.map((app) => (
+                    <div key={app.id} style={styles.detailCard}>
+                      <span
+                        style={appTypeBadgeStyle(
+                          styles.badge,
+                          app.application_type,
+                          app.custom_type_id
+                        )}
+                      >
+                        {typeLabel(app)}
+                      </span>
+                      {app.product_name && (
+                        <span style={{ marginLeft: 8 }}>{app.product_name}</span>
+                      )}
+                      {app.follow_up_date && (
+                        <div style={styles.notes}>
+                          Follow-up scheduled for {app.follow_up_date}
                         </div>
                       )}
-                    </td>
-                  );
-                })}
-              </tr>
-            ))}
-          </tbody>
-        </table>
-      </div>
-
-      {/* Detail panel */}
-      {selectedDate && (
-        <div style={styles.detail}>
-          <h3 style={{ margin: '0 0 0.75rem' }}>{selectedDate}</h3>
-
-          {/* Applications section */}
-          {selectedApps.length > 0 && (
-            <div style={{ marginBottom: '1rem' }}>
-              <h4 style={styles.sectionLabel}>Applications</h4>
-              {selectedApps.map((app) => (
-                <div key={app.id} style={styles.detailCard}>
-                  <span
-                    style={appTypeBadgeStyle(
-                      styles.badge,
-                      app.application_type
-                    )}
-                  >
-                    {APPLICATION_TYPE_LABELS[app.application_type]}
-                  </span>
-                  {app.product_name && (
-                    <span style={{ marginLeft: 8 }}>{app.product_name}</span>
-                  )}
-                  {app.follow_up_date && (
-                    <div style={styles.notes}>
-                      Follow-up scheduled for {app.follow_up_date}
+                      {app.notes && (
+                        <div style={styles.notes}>{app.notes}</div>
+                      )}
                     </div>
-                  )}
-                  {app.notes && (
-                    <div style={styles.notes}>{app.notes}</div>
-                  )}
+                  ))}
                 </div>
-              ))}
-            </div>
-          )}
+              )}
 
-          {/* Follow-ups due on this date */}
-          {selectedFollowUps.length > 0 && (
-            <div style={{ marginBottom: '1rem' }}>
-              <h4 style={styles.sectionLabel}>Follow-ups Due</h4>
-              {selectedFollowUps.map((app) => (
-                <div key={`fu-${app.id}`} style={styles.detailCard}>
-                  <span
-                    style={{
-                      ...appTypeBadgeStyle(styles.badge, app.application_type),
-                      backgroundColor: 'transparent',
-                    }}
-                  >
-                    {APPLICATION_TYPE_LABELS[app.application_type]}
-                  </span>
-                  {app.product_name && (
-                    <span style={{ marginLeft: 8 }}>{app.product_name}</span>
-                  )}
-                  <div style={styles.notes}>
-                    Originally applied {app.application_date}
-                  </div>
+              {/* Follow-ups due on this date */}
+              {selectedFollowUps.length > 0 && (
+                <div style={{ marginBottom: '1rem' }}>
+                  <h4 style={styles.sectionLabel}>Follow-ups Due</h4>
+                  {selectedFollowUps.map((app) => (
+                    <div key={`fu-${app.id}`} style={styles.detailCard}>
+                      <span
+                        style={{
+                          ...appTypeBadgeStyle(
+                            styles.badge,
+                            app.application_type,
+                            app.custom_type_id
+                          ),
+                          backgroundColor: 'transparent',
+                        }}
+                      >
+                        {typeLabel(app)}
+                      </span>
+                      {app.product_name && (
+                        <span style={{ marginLeft: 8 }}>{app.product_name}</span>
+                      )}
+                      <div style={styles.notes}>
+                        Originally applied {app.application_date}
+                      </div>
+                    </div>
+                  ))}
                 </div>
-              ))}
-            </div>
-          )}
+              )}
 
-          {/* Planned activities section — grouped by turf vs plants */}
-          {selectedActivities.length > 0 && (
-            <div>
-              <h4 style={styles.sectionLabel}>
-                {selectedActivities.some((a) => a.category === 'Plant Maintenance')
-                  ? 'Turf Activities'
-                  : 'Planned Activities'}
-              </h4>
-              {selectedActivities
-                .filter((a) => a.category !== 'Plant Maintenance')
-                .map((activity) => (
-                <div key={activity.id} style={styles.activityDetailCard}>
-                  <div style={styles.activityDetailHeader}>
-                    <span
-                      style={{
-                        ...styles.statusBadge,
-                        backgroundColor:
-                          ACTIVITY_STATUS_COLORS[activity.status] + '22',
-                        color: ACTIVITY_STATUS_COLORS[activity.status],
-                        borderColor:
-                          ACTIVITY_STATUS_COLORS[activity.status],
-                      }}
-                    >
-                      {activity.status}
-                    </span>
-                    <span style={{ fontWeight: 500 }}>{activity.name}</span>
-                  </div>
-                  <div style={styles.activityMeta}>
-                    {formatDateRange(
-                      activity.date_window.predicted_start,
-                      activity.date_window.predicted_end
-                    )}
-                    <span style={styles.confidence}>
-                      {' '}
-                      · {activity.date_window.confidence} confidence
-                    </span>
-                  </div>
-                  {activity.details.soil_temp_trigger && (
-                    <div style={styles.activityTrigger}>
-                      Soil temp: {activity.details.soil_temp_trigger}
-                    </div>
-                  )}
-                  {activity.details.product_suggestions.length > 0 && (
-                    <div style={styles.activityProducts}>
-                      Products: {activity.details.product_suggestions.join(', ')}
-                    </div>
-                  )}
-                  {activity.details.notes && (
-                    <div style={styles.notes}>{activity.details.notes}</div>
-                  )}
+              {/* Journal observations */}
+              {selectedObservations.length > 0 && (
+                <div style={{ marginBottom: '1rem' }}>
+                  <h4 style={styles.sectionLabel}>Observations</h4>
+                  {selectedObservations.map((o) => {
+                    const color = OBSERVATION_CATEGORY_COLORS[o.category];
+                    return (
+                      <div key={`obs-${o.id}`} style={styles.detailCard}>
+                        <span
+                          style={{
+                            ...styles.badge,
+                            color,
+                            borderColor: color,
+                            backgroundColor: color + '22',
+                          }}
+                        >
+                          {o.category}
+                        </span>
+                        <span style={{ marginLeft: 8, color: '#718096' }}>
+                          {new Date(o.observed_at).toLocaleTimeString([], {
+                            hour: 'numeric',
+                            minute: '2-digit',
+                          })}
+                        </span>
+                        <div style={styles.notes}>{o.notes}</div>
+                      </div>
+                    );
+                  })}
                 </div>
-              ))}
-              {/* Plant maintenance subsection */}
-              {selectedActivities.some((a) => a.category === 'Plant Maintenance') && (
-                <>
-                  <h4 style={{ ...styles.sectionLabel, marginTop: '1rem' }}>
-                    Plant Maintenance
+              )}
+
+              {/* Planned activities section — grouped by turf vs plants */}
+              {selectedActivities.length > 0 && (
+                <div>
+                  <h4 style={styles.sectionLabel}>
+                    {selectedActivities.some((a) => a.category === 'Plant Maintenance')
+                      ? 'Turf Activities'
+                      : 'Planned Activities'}
                   </h4>
                   {selectedActivities
-                    .filter((a) => a.category === 'Plant Maintenance')
+                    .filter((a) => a.category !== 'Plant Maintenance')
                     .map((activity) => (
-                      <div key={activity.id} style={styles.activityDetailCard}>
-                        <div style={styles.activityDetailHeader}>
-                          <span
-                            style={{
-                              ...styles.statusBadge,
-                              backgroundColor:
-                                ACTIVITY_STATUS_COLORS[activity.status] + '22',
-                              color: ACTIVITY_STATUS_COLORS[activity.status],
-                              borderColor: ACTIVITY_STATUS_COLORS[activity.status],
-                            }}
-                          >
-                            {activity.status}
-                          </span>
-                          <span style={{ fontWeight: 500 }}>{activity.name}</span>
-                        </div>
-                        <div style={styles.activityMeta}>
-                          {formatDateRange(
-                            activity.date_window.predicted_start,
-                            activity.date_window.predicted_end
-                          )}
-                        </div>
-                        <div style={styles.notes}>{activity.description}</div>
-                        {activity.details.notes && (
-                          <div style={styles.notes}>{activity.details.notes}</div>
+                    <div key={activity.id} style={styles.activityDetailCard}>
+                      <div style={styles.activityDetailHeader}>
+                        <span
+                          style={{
+                            ...styles.statusBadge,
+                            backgroundColor:
+                              ACTIVITY_STATUS_COLORS[activity.status] + '22',
+                            color: ACTIVITY_STATUS_COLORS[activity.status],
+                            borderColor:
+                              ACTIVITY_STATUS_COLORS[activity.status],
+                          }}
+                        >
+                          {activity.status}
+                        </span>
+                        <span style={{ fontWeight: 500 }}>{activity.name}</span>
+                      </div>
+                      <div style={styles.activityMeta}>
+                        {formatDateRange(
+                          activity.date_window.predicted_start,
+                          activity.date_window.predicted_end
                         )}
+                        <span style={styles.confidence}>
+                          {' '}
+                          · {activity.date_window.confidence} confidence
+                        </span>
                       </div>
-                    ))}
-                </>
+                      {activity.details.soil_temp_trigger && (
+                        <div style={styles.activityTrigger}>
+                          Soil temp: {activity.details.soil_temp_trigger}
+                        </div>
+                      )}
+                      {activity.details.product_suggestions.length > 0 && (
+                        <div style={styles.activityProducts}>
+                          Products: {activity.details.product_suggestions.join(', ')}
+                        </div>
+                      )}
+                      {activity.details.notes && (
+                        <div style={styles.notes}>{activity.details.notes}</div>
+                      )}
+                    </div>
+                  ))}
+                  {/* Plant maintenance subsection */}
+                  {selectedActivities.some((a) => a.category === 'Plant Maintenance') && (
+                    <>
+                      <h4 style={{ ...styles.sectionLabel, marginTop: '1rem' }}>
+                        Plant Maintenance
+                      </h4>
+                      {selectedActivities
+                        .filter((a) => a.category === 'Plant Maintenance')
+                        .map((activity) => (
+                          <div key={activity.id} style={styles.activityDetailCard}>
+                            <div style={styles.activityDetailHeader}>
+                              <span
+                                style={{
+                                  ...styles.statusBadge,
+                                  backgroundColor:
+                                    ACTIVITY_STATUS_COLORS[activity.status] + '22',
+                                  color: ACTIVITY_STATUS_COLORS[activity.status],
+                                  borderColor: ACTIVITY_STATUS_COLORS[activity.status],
+                                }}
+                              >
+                                {activity.status}
+                              </span>
+                              <span style={{ fontWeight: 500 }}>{activity.name}</span>
+                            </div>
+                            <div style={styles.activityMeta}>
+                              {formatDateRange(
+                                activity.date_window.predicted_start,
+                                activity.date_window.predicted_end
+                              )}
+                            </div>
+                            <div style={styles.notes}>{activity.description}</div>
+                            {activity.details.notes && (
+                              <div style={styles.notes}>{activity.details.notes}</div>
+                            )}
+                          </div>
+                        ))}
+                    </>
+                  )}
+                </div>
               )}
+
+              {selectedApps.length === 0 &&
+                selectedFollowUps.length === 0 &&
+                selectedObservations.length === 0 &&
+                selectedActivities.length === 0 && (
+                  <p style={{ color: '#a0aec0' }}>No items on this date.</p>
+                )}
             </div>
           )}
-
-          {selectedApps.length === 0 &&
-            selectedFollowUps.length === 0 &&
-            selectedActivities.length === 0 && (
-              <p style={{ color: '#a0aec0' }}>No items on this date.</p>
-            )}
-        </div>
+        </>
       )}
     </div>
   );
@@ -500,6 +660,22 @@ const styles: Record<string, React.CSSProperties> = {
     fontSize: '1rem',
   },
   monthLabel: { fontSize: '1.1rem', fontWeight: 600, color: '#2d3748' },
+  viewToggle: {
+    display: 'inline-flex',
+    marginLeft: 'auto',
+    border: '1px solid #e2e8f0',
+    borderRadius: 6,
+    overflow: 'hidden',
+  },
+  viewBtn: {
+    padding: '0.3rem 0.8rem',
+    backgroundColor: '#fff',
+    border: 'none',
+    cursor: 'pointer',
+    fontSize: '0.8rem',
+    color: '#4a5568',
+  },
+  viewBtnActive: { backgroundColor: '#3182ce', color: '#fff' },
   legend: {
     display: 'flex',
     alignItems: 'center',
@@ -548,6 +724,14 @@ const styles: Record<string, React.CSSProperties> = {
     borderBottom: '1px solid #edf2f7',
     borderRight: '1px solid #edf2f7',
   },
+  cellCompact: {
+    padding: '0.25rem',
+    height: 56,
+    verticalAlign: 'top' as const,
+    borderBottom: '1px solid #edf2f7',
+    borderRight: '1px solid #edf2f7',
+    overflow: 'hidden',
+  },
   emptyCell: {
     backgroundColor: '#f7fafc',
     borderBottom: '1px solid #edf2f7',
@@ -561,6 +745,7 @@ const styles: Record<string, React.CSSProperties> = {
     borderRadius: '50%',
     display: 'inline-block',
   },
+  obsMarker: { borderRadius: 2 },
   activityBars: {
     display: 'flex',
     gap: 3,
@@ -1,28 +1,54 @@
-import { useCallback, useEffect, useRef, useState } from 'react';
-import { getDashboard, getGdd, getNitrogenBudget, getSoilTempForecast } from '../api/client';
-import AlertCard from '../components/AlertCard';
+import { Fragment, useCallback, useEffect, useRef, useState } from 'react';
+import {
+  getDashboard,
+  getGdd,
+  getNitrogenBudget,
+  getSoilTempForecast,
+  getStressIndex,
+} from '../api/client';
+import AirQualityIndicator from '../components/AirQualityIndicator';
+import AlertsPanel from '../components/AlertsPanel';
+import DroughtStatusWidget from '../components/DroughtStatusWidget';
+import ForecastStrip from '../components/ForecastStrip';
 import GddWidget from '../components/GddWidget';
+import GerminationWidget from '../components/GerminationWidget';
 import Gauge from '../components/Gauge';
 import NitrogenBudgetWidget from '../components/NitrogenBudgetWidget';
 import SoilTempForecastWidget from '../components/SoilTempForecastWidget';
+import StressIndexWidget from '../components/StressIndexWidget';
+import WindowCountdownWidget from '../components/WindowCountdownWidget';
 import {
-  SOIL_TEMP_GAUGE,
   AMBIENT_TEMP_GAUGE,
   HUMIDITY_GAUGE,
-  SOIL_MOISTURE_GAUGE,
+  soilMoistureGauge,
+  soilTempGauge,
+  withRange,
 } from '../components/gaugeConfigs';
+import { useGaugeRanges } from '../hooks/useGaugeRanges';
 import { appTypeBadgeStyle, sharedStyles } from '../styles/shared';
-import type { DashboardResponse, GddSummary, NitrogenBudget, SoilTempForecast } from '../types';
-import { APPLICATION_TYPE_LABELS } from '../types';
+import type {
+  DashboardPanel,
+  DashboardResponse,
+  GddSummary,
+  NitrogenBudget,
+  QuotaUsage,
+  SoilTempForecast,
+  StressIndexSummary,
+} from '../types';
+import { staleSince } from '../utils/freshness';
+import { anomalyColor, anomalyTitle, formatAnomaly } from '../utils/soilAnomaly';
+import { typeLabel } from '../utils/typeRegistry';
 import { formatInches } from '../utils/units';
 
 const POLL_INTERVAL = 30_000; // 30 seconds
 
 export default function Dashboard() {
+  const ranges = useGaugeRanges();
   const [data, setData] = useState<DashboardResponse | null>(null);
   const [gddData, setGddData] = useState<GddSummary | null>(null);
   const [nBudget, setNBudget] = useState<NitrogenBudget | null>(null);
   const [soilForecast, setSoilForecast] = useState<SoilTempForecast | null>(null);
+  const [stress, setStress] = useState<StressIndexSummary | null>(null);
   const [error, setError] = useState<string | null>(null);
   const [loading, setLoading] = useState(true);
   const abortRef = useRef<AbortController | null>(null);
@@ -32,17 +58,19 @@ export default function Dashboard() {
     const controller = new AbortController();
     abortRef.current = controller;
     try {
-      const [d, gdd, nb, sf] = await Promise.all([
+      const [d, gdd, nb, sf, si] = await Promise.all([
         getDashboard(),
         getGdd().catch(() => null),
         getNitrogenBudget().catch(() => null),
         getSoilTempForecast().catch(() => null),
+        getStressIndex().catch(() => null),
       ]);
       if (!controller.signal.aborted) {
         setData(d);
         setGddData(gdd);
         setNBudget(nb);
         setSoilForecast(sf);
+        setStress(si);
         setError(null);
       }
     } catch (e) {
@@ -97,43 +125,30 @@ export default function Dashboard() {
   if (error && !data) return <div role="alert" style={sharedStyles.error}>Error: {error}</div>;
   if (!data) return null;
 
-  const { profile, environmental, recommendations, recent_applications, connections } = data;
+  const {
+    profile,
+    environmental,
+    recommendations,
+    alert_counts,
+    total_alerts,
+    recent_applications,
+    connections,
+    openweathermap_usage,
+    windows,
+    germination,
+    layout,
+  } = data;
   const current = environmental.current;
 
-  return (
-    <div>
-      {error && (
-        <div role="alert" style={styles.errorBanner}>
-          Data may be stale: {error}
-        </div>
-      )}
-      <div style={styles.headerRow}>
-        <h1 style={sharedStyles.pageTitle}>{profile.name}</h1>
-        <div style={styles.meta}>
-          {profile.grass_type} &middot; Zone {profile.usda_zone}
-          {environmental.last_updated && (
-            <span style={styles.updated}>
-              {' '}
-              &middot; Updated{' '}
-              {new Date(environmental.last_updated).toLocaleTimeString()}
-            </span>
-          )}
-        </div>
-      </div>
-
-      {/* Connection indicators */}
-      <div style={styles.connections}>
-        <ConnectionDot label="SoilData" ok={connections.soildata} />
-        <ConnectionDot label="Home Assistant" ok={connections.homeassistant} />
-        <ConnectionDot label="OpenWeatherMap" ok={connections.openweathermap} />
-      </div>
-
-      {/* Gauges */}
+  // What each panel shows; empty ones are skipped
+  const content: Record<DashboardPanel, React.ReactNode> = {
+    gauges: (
       <div style={sharedStyles.gaugeGrid}>
         <div style={sharedStyles.card}>
           <Gauge
-            {...SOIL_TEMP_GAUGE}
+            {...withRange(soilTempGauge(profile.grass_type), ranges, 'soil_temp_f')}
             value={current?.soil_temp_10_f ?? null}
+            staleSince={staleSince(environmental.freshness, 'soil_temp')}
           />
           {environmental.soil_temp_7day_avg_f !== null && (
             <div style={styles.subtext}>
@@ -141,27 +156,42 @@ export default function Dashboard() {
               {' '}{trendArrow(environmental.soil_temp_trend)}
             </div>
           )}
+          {environmental.soil_temp_anomaly && (
+            <div
+              style={{
+                ...styles.subtext,
+                color: anomalyColor(environmental.soil_temp_anomaly),
+              }}
+              title={anomalyTitle(environmental.soil_temp_anomaly)}
+            >
+              {formatAnomaly(environmental.soil_temp_anomaly)}
+            </div>
+          )}
         </div>
         <div style={sharedStyles.card}>
           <Gauge
-            {...AMBIENT_TEMP_GAUGE}
+            {...withRange(AMBIENT_TEMP_GAUGE, ranges, 'ambient_temp_f')}
             value={current?.ambient_temp_f ?? null}
+            staleSince={staleSince(environmental.freshness, 'ambient_temp')}
           />
           {environmental.ambient_temp_7day_avg_f !== null && (
             <div style={styles.subtext}>
               7-day avg: {environmental.ambient_temp_7day_avg_f.toFixed(1)}°F
+              {' '}{trendArrow(environmental.ambient_temp_trend)}
             </div>
           )}
         </div>
         <div style={sharedStyles.card}>
           <Gauge
-            {...HUMIDITY_GAUGE}
+            {...withRange(HUMIDITY_GAUGE, ranges, 'humidity_percent')}
             value={current?.humidity_percent ?? null}
+            staleSince={staleSince(environmental.freshness, 'humidity')}
           />
         </div>
         <div style={sharedStyles.card}>
           <Gauge
-            {...SOIL_MOISTURE_GAUGE}
+            {...withRange(soilMoistureGauge(profile.soil_type), ranges, 'soil_moisture_percent')}
+            staleSince={staleSince(environmental.freshness, 'soil_moisture')}
             value={
               current?.soil_moisture_10 !== null && current?.soil_moisture_10 !== undefined
                 ? current.soil_moisture_10 * 100
@@ -171,86 +201,235 @@ export default function Dashboard() {
           {environmental.precipitation_7day_total_mm !== null && (
             <div style={styles.subtext}>
               7-day precip: {formatInches(environmental.precipitation_7day_total_mm)}
+              {' '}{trendArrow(environmental.soil_moisture_trend)}
             </div>
           )}
         </div>
       </div>
+    ),
+    forecast: environmental.forecast?.daily_summary.length ? (
+      <ForecastStrip forecast={environmental.forecast} />
+    ) : null,
+    germination: germination && <GerminationWidget status={germination} />,
+    gdd: gddData && <GddWidget data={gddData} />,
+    stress_index: stress?.current && <StressIndexWidget data={stress} />,
+    drought: environmental.drought && <DroughtStatusWidget status={environmental.drought} />,
+    nitrogen_budget: nBudget && <NitrogenBudgetWidget data={nBudget} />,
+    soil_temp_forecast: soilForecast && (
+      <SoilTempForecastWidget
+        crossings={soilForecast.threshold_crossings}
+        predictions={soilForecast.predictions}
+        currentSoilTemp={current?.soil_temp_10_f ?? null}
+      />
+    ),
+    windows: windows.length > 0 && <WindowCountdownWidget windows={windows} />,
+    alerts: (
+      <AlertsPanel recommendations={recommendations} counts={alert_counts} total={total_alerts} />
+    ),
+    recent_applications: (
+      <>
+        <h2 style={sharedStyles.sectionTitle}>Recent Applications</h2>
+        {recent_applications.length === 0 ? (
+          <div style={sharedStyles.empty}>No applications recorded</div>
+        ) : (
+          <table style={sharedStyles.table}>
+            <thead>
+              <tr>
+                <th style={sharedStyles.th}>Date</th>
+                <th style={sharedStyles.th}>Type</th>
+                <th style={sharedStyles.th}>Product</th>
+              </tr>
+            </thead>
+            <tbody>
+              {recent_applications.map((app, index) => (
+                <tr key={app.id ?? `app-${index}`}>
+                  <td style={sharedStyles.td}>{app.application_date}</td>
+                  <td style={sharedStyles.td}>
+                    <span
+                      style={appTypeBadgeStyle(
+                        sharedStyles.badge,
+                        app.application_type,
+                        app.custom_type_id
+                      )}
+                    >
+                      {typeLabel(app)}
+                    </span>
+                  </td>
+                  <td style={sharedStyles.td}>{app.product_name || '-'}</td>
+                </tr>
+              ))}
+            </tbody>
+          </table>
+        )}
+      </>
+    ),
+  };
 
-      {/* GDD, Nitrogen Budget & Soil Temp Forecast widgets */}
-      {(gddData || nBudget || soilForecast) && (
-        <div style={styles.widgetGrid}>
-          {gddData && <GddWidget data={gddData} />}
-          {nBudget && <NitrogenBudgetWidget data={nBudget} />}
-          {soilForecast && (
-            <SoilTempForecastWidget
-              crossings={soilForecast.threshold_crossings}
-              predictions={soilForecast.predictions}
-              currentSoilTemp={current?.soil_temp_10_f ?? null}
-            />
-          )}
+  return (
+    <div>
+      {error && (
+        <div role="alert" style={styles.errorBanner}>
+          Data may be stale: {error}
         </div>
       )}
-
-      {/* Alerts & Recent Apps side by side */}
-      <div style={styles.twoCol}>
-        <div style={{ flex: 1 }}>
-          <h2 style={sharedStyles.sectionTitle}>Active Alerts</h2>
-          {recommendations.length === 0 ? (
-            <div style={sharedStyles.empty}>No active recommendations</div>
-          ) : (
-            recommendations.map((r) => <AlertCard key={r.id} rec={r} />)
+      <div style={styles.headerRow}>
+        <h1 style={sharedStyles.pageTitle}>{profile.name}</h1>
+        <div style={styles.meta}>
+          {profile.grass_type} &middot; Zone {profile.usda_zone}
+          {environmental.last_updated && (
+            <span style={styles.updated}>
+              {' '}
+              &middot; Updated{' '}
+              {new Date(environmental.last_updated).toLocaleTimeString()}
+            </span>
           )}
-        </div>
-        <div style={{ flex: 1 }}>
-          <h2 style={sharedStyles.sectionTitle}>Recent Applications</h2>
-          {recent_applications.length === 0 ? (
-            <div style={sharedStyles.empty}>No applications recorded</div>
-          ) : (
-            <table style={sharedStyles.table}>
-              <thead>
-                <tr>
-                  <th style={sharedStyles.th}>Date</th>
-                  <th style={sharedStyles.th}>Type</th>
-                  <th style={sharedStyles.th}>Product</th>
-                </tr>
-              </thead>
-              <tbody>
-                {recent_applications.map((app, index) => (
-                  <tr key={app.id ?? `app-${index}`}>
-                    <td style={sharedStyles.td}>{app.application_date}</td>
-                    <td style={sharedStyles.td}>
-                      <span
-                        style={appTypeBadgeStyle(sharedStyles.badge, app.application_type)}
-                      >
-                        {APPLICATION_TYPE_LABELS[app.application_type]}
-                      </span>
-                    </td>
-                    <td style={sharedStyles.td}>{app.product_name || '-'}</td>
-                  </tr>
-                ))}
-              </tbody>
-            </table>
+          {environmental.air_quality && (
+            <span style={styles.airQuality}>
+              <AirQualityIndicator air={environmental.air_quality} />
+            </span>
           )}
         </div>
       </div>
+
+      {/* Connection indicators */}
+      <div style={styles.connections}>
+        <ConnectionDot
+          label="SoilData"
+          ok={connections.soildata}
+          pending={connections.pending}
+          timedOut={connections.timed_out?.includes('uscrn')}
+        />
+        <ConnectionDot
+          label="Home Assistant"
+          ok={connections.homeassistant}
+          pending={connections.pending}
+          timedOut={connections.timed_out?.includes('homeassistant')}
+        />
+        {connections.sources?.ecowitt !== undefined && (
+          <ConnectionDot
+            label="Ecowitt"
+            ok={connections.sources.ecowitt}
+            pending={connections.pending}
+            timedOut={connections.timed_out?.includes('ecowitt')}
+          />
+        )}
+        {connections.sources?.tempest !== undefined && (
+          <ConnectionDot
+            label="Tempest"
+            ok={connections.sources.tempest}
+            pending={connections.pending}
+            timedOut={connections.timed_out?.includes('tempest')}
+          />
+        )}
+        <ConnectionDot
+          label="OpenWeatherMap"
+          ok={connections.openweathermap}
+          pending={connections.pending}
+          timedOut={connections.timed_out?.includes('openweathermap')}
+          usage={openweathermap_usage}
+        />
+      </div>
+
+      {groupPanels(layout).map((group) => {
+        const shown = group.panels.filter((p) => content[p]);
+        if (shown.length === 0) return null;
+        const key = group.panels.join('-');
+        if (group.kind === 'widget') {
+          return (
+            <div key={key} style={styles.widgetGrid}>
+              {shown.map((p) => (
+                <Fragment key={p}>{content[p]}</Fragment>
+              ))}
+            </div>
+          );
+        }
+        if (group.kind === 'column') {
+          return (
+            <div key={key} style={styles.twoCol}>
+              {shown.map((p) => (
+                <div key={p} style={{ flex: 1 }}>
+                  {content[p]}
+                </div>
+              ))}
+            </div>
+          );
+        }
+        // Full-width panels are never grouped
+        return <Fragment key={key}>{content[shown[0]]}</Fragment>;
+      })}
     </div>
   );
 }
 
-function ConnectionDot({ label, ok }: { label: string; ok: boolean }) {
+function ConnectionDot({
+  label,
+  ok,
+  pending,
+  timedOut,
+  usage,
+}: {
+  label: string;
+  ok: boolean;
+  pending?: boolean;
+  timedOut?: boolean;
+  usage?: QuotaUsage | null;
+}) {
+  const title = pending
+    ? 'Connecting…'
+    : timedOut
+      ? 'Timed out'
+      : usage
+        ? `${usage.calls}${usage.limit ? ` of ${usage.limit}` : ''} calls today (resets at ` +
+          `midnight UTC); forecast refreshes every ${usage.refresh_minutes} min`
+        : undefined;
+  const nearLimit = usage != null && usage.limit > 0 && usage.stretched;
   return (
-    <span style={styles.connItem}>
+    <span style={styles.connItem} title={title}>
       <span
         style={{
           ...styles.dot,
-          backgroundColor: ok ? '#48bb78' : '#a0aec0',
+          backgroundColor: pending
+            ? '#ecc94b'
+            : timedOut
+              ? '#ed8936'
+              : ok
+                ? '#48bb78'
+                : '#a0aec0',
         }}
       />
       {label}
+      {pending && '…'}
+      {timedOut && ' (timed out)'}
+      {usage && usage.limit > 0 && (
+        <span style={nearLimit ? styles.quotaNear : styles.quota}>
+          {usage.calls}/{usage.limit}
+        </span>
+      )}
     </span>
   );
 }
 
+type PanelGroup = { kind: 'full' | 'widget' | 'column'; panels: DashboardPanel[] };
+
+/** Full-width panels stand alone; neighboring widgets share a grid and alerts
+ * and recent applications share a row, whatever order they're listed in. */
+function groupPanels(panels: DashboardPanel[]): PanelGroup[] {
+  const kindOf = (p: DashboardPanel): PanelGroup['kind'] =>
+    p === 'gauges' || p === 'forecast'
+      ? 'full'
+      : p === 'alerts' || p === 'recent_applications'
+        ? 'column'
+        : 'widget';
+  const groups: PanelGroup[] = [];
+  for (const panel of panels) {
+    const kind = kindOf(panel);
+    const last = groups[groups.length - 1];
+    if (last && last.kind === kind && kind !== 'full') last.panels.push(panel);
+    else groups.push({ kind, panels: [panel] });
+  }
+  return groups;
+}
+
 function trendArrow(trend: string): string {
   switch (trend) {
     case 'Rising':
@@ -276,6 +455,7 @@ const styles: Record<string, React.CSSProperties> = {
   headerRow: { marginBottom: '1rem' },
   meta: { color: '#718096', fontSize: '0.85rem', marginTop: 4 },
   updated: { color: '#a0aec0' },
+  airQuality: { marginLeft: 10 },
   connections: {
     display: 'flex',
     gap: 16,
@@ -290,10 +470,12 @@ const styles: Record<string, React.CSSProperties> = {
     borderRadius: '50%',
     display: 'inline-block',
   },
+  quota: { color: '#a0aec0' },
+  quotaNear: { color: '#dd6b20', fontWeight: 600 },
   subtext: { fontSize: '0.75rem', color: '#718096', marginTop: 4 },
   widgetGrid: {
     display: 'grid',
-    gridTemplateColumns: 'repeat(auto-fit, minmax(280px, 1fr))',
+    gridTemplateColumns: 'repeat(auto-fit, minmax(min(280px, 100%), 1fr))',
     gap: '1rem',
     marginBottom: '1.5rem',
   },
@@ -1,51 +1,75 @@
 import { useCallback, useEffect, useRef, useState } from 'react';
 import {
+  errorMessage,
   getEnvironmental,
   getHistorical,
+  getProfile,
   getSoilTempForecast,
-  refreshEnvironmental,
 } from '../api/client';
+import ForecastStrip from '../components/ForecastStrip';
+import FrostDatesCard from '../components/FrostDatesCard';
 import Gauge from '../components/Gauge';
+import OvernightWetnessCard from '../components/OvernightWetnessCard';
 import PredictionChart from '../components/PredictionChart';
 import TrendChart from '../components/TrendChart';
+import { useRefresh } from '../components/refreshContext';
+import { useToast } from '../components/toastContext';
 import {
-  SOIL_TEMP_GAUGE,
   AMBIENT_TEMP_GAUGE,
   HUMIDITY_GAUGE,
-  SOIL_MOISTURE_GAUGE,
+  soilMoistureGauge,
+  soilTempGauge,
+  withRange,
 } from '../components/gaugeConfigs';
+import { useGaugeRanges } from '../hooks/useGaugeRanges';
 import { sharedStyles } from '../styles/shared';
-import type { EnvironmentalSummary, HistoricalData, SoilTempForecast } from '../types';
+import type {
+  EnvironmentalSummary,
+  HistoricalData,
+  LawnProfile,
+  SoilTempForecast,
+} from '../types';
 import { PREDICTION_CONFIDENCE_COLORS } from '../types';
+import { staleSince } from '../utils/freshness';
+import { t } from '../utils/i18n';
+import { anomalyColor, anomalyTitle, formatAnomaly } from '../utils/soilAnomaly';
 import { mmToInches } from '../utils/units';
 
 const POLL_INTERVAL = 30_000;
 
+/** Below this share of samples, temperature rules ignore the 7-day average (MIN_AGGREGATE_COVERAGE). */
+const LOW_COVERAGE = 0.5;
+
 type HistRange = '7d' | '30d' | '90d';
 
 export default function Environmental() {
+  const ranges = useGaugeRanges();
   const [data, setData] = useState<EnvironmentalSummary | null>(null);
   const [histData, setHistData] = useState<HistoricalData | null>(null);
   const [soilForecast, setSoilForecast] = useState<SoilTempForecast | null>(null);
+  const [profile, setProfile] = useState<LawnProfile | null>(null);
   const [histRange, setHistRange] = useState<HistRange>('7d');
   const [histLoading, setHistLoading] = useState(false);
   const [error, setError] = useState<string | null>(null);
   const [loading, setLoading] = useState(true);
-  const [refreshing, setRefreshing] = useState(false);
+  const { refreshing, refresh } = useRefresh();
   const abortRef = useRef<AbortController | null>(null);
+  const { notify } = useToast();
 
   const fetchData = useCallback(async () => {
     abortRef.current?.abort();
     const controller = new AbortController();
     abortRef.current = controller;
     try {
-      const [d, sf] = await Promise.all([
+      const [d, sf, p] = await Promise.all([
         getEnvironmental(),
         getSoilTempForecast().catch(() => null),
+        getProfile().catch(() => null),
       ]);
       if (!controller.signal.aborted) {
         setData(d);
         setSoilForecast(sf);
+        setProfile(p);
         setError(null);
       }
     } catch (e) {
@@ -108,24 +132,13 @@ export default function Environmental() {
           setHistLoading(false);
         }
       })
-      .catch(() => {
-        if (!cancelled) setHistLoading(false);
+      .catch((e) => {
+        if (cancelled) return;
+        setHistLoading(false);
+        notify(`Failed to load history: ${errorMessage(e, 'request failed')}`, 'error');
       });
     return () => { cancelled = true; };
-  }, [histRange]);
-
-  const handleRefresh = async () => {
-    setRefreshing(true);
-    try {
-      const d = await refreshEnvironmental();
-      setData(d);
-      setError(null);
-    } catch (e) {
-      setError(e instanceof Error ? e.message : 'Refresh failed');
-    } finally {
-      setRefreshing(false);
-    }
-  };
+  }, [histRange, notify]);
 
   if (loading) return <div style={sharedStyles.loading}>Loading...</div>;
 
@@ -143,10 +156,10 @@ export default function Environmental() {
   return (
     <div>
       <div style={sharedStyles.headerRow}>
-        <h1 style={sharedStyles.pageTitle}>Environmental Data</h1>
+        <h1 style={sharedStyles.pageTitle}>{t('Environmental Data')}</h1>
         <button
           style={styles.refreshBtn}
-          onClick={handleRefresh}
+          onClick={() => refresh(false)}
           disabled={refreshing}
         >
           {refreshing ? 'Refreshing...' : 'Refresh Now'}
@@ -159,6 +172,8 @@ export default function Environmental() {
         <p style={styles.updated}>
           Last updated: {new Date(data.last_updated).toLocaleString()}
           {data.soil_temp_trend && ` | Soil temp trend: ${data.soil_temp_trend}`}
+          {data.soil_moisture_trend && ` | Moisture: ${data.soil_moisture_trend}`}
+          {data.ambient_temp_trend && ` | Air temp: ${data.ambient_temp_trend}`}
         </p>
       )}
 
@@ -166,25 +181,29 @@ export default function Environmental() {
       <div style={sharedStyles.gaugeGrid}>
         <div style={sharedStyles.card}>
           <Gauge
-            {...SOIL_TEMP_GAUGE}
+            {...withRange(soilTempGauge(profile?.grass_type), ranges, 'soil_temp_f')}
             value={current?.soil_temp_10_f ?? null}
+            staleSince={staleSince(data?.freshness, 'soil_temp')}
           />
         </div>
         <div style={sharedStyles.card}>
           <Gauge
-            {...AMBIENT_TEMP_GAUGE}
+            {...withRange(AMBIENT_TEMP_GAUGE, ranges, 'ambient_temp_f')}
             value={current?.ambient_temp_f ?? null}
+            staleSince={staleSince(data?.freshness, 'ambient_temp')}
           />
         </div>
         <div style={sharedStyles.card}>
           <Gauge
-            {...HUMIDITY_GAUGE}
+            {...withRange(HUMIDITY_GAUGE, ranges, 'humidity_percent')}
             value={current?.humidity_percent ?? null}
+            staleSince={staleSince(data?.freshness, 'humidity')}
           />
         </div>
         <div style={sharedStyles.card}>
           <Gauge
-            {...SOIL_MOISTURE_GAUGE}
+            {...withRange(soilMoistureGauge(profile?.soil_type), ranges, 'soil_moisture_percent')}
+            staleSince={staleSince(data?.freshness, 'soil_moisture')}
             value={
               current?.soil_moisture_10 != null ? current.soil_moisture_10 * 100 : null
             }
@@ -198,25 +217,75 @@ export default function Environmental() {
           label="7-Day Soil Temp Avg"
           value={data?.soil_temp_7day_avg_f}
           unit={'\u00B0F'}
+          coverage={data?.coverage.soil_temp}
+          note={
+            data?.soil_temp_anomaly && (
+              <div
+                style={{ ...styles.coverage, color: anomalyColor(data.soil_temp_anomaly) }}
+                title={anomalyTitle(data.soil_temp_anomaly)}
+              >
+                {formatAnomaly(data.soil_temp_anomaly)}
+              </div>
+            )
+          }
         />
         <SummaryCard
           label="7-Day Ambient Avg"
           value={data?.ambient_temp_7day_avg_f}
           unit={'\u00B0F'}
+          coverage={data?.coverage.ambient_temp}
         />
         <SummaryCard
           label="7-Day Humidity Avg"
           value={data?.humidity_7day_avg}
           unit="%"
+          coverage={data?.coverage.humidity}
         />
         <SummaryCard
           label="7-Day Precipitation"
           value={data?.precipitation_7day_total_mm != null ? mmToInches(data.precipitation_7day_total_mm) : null}
           unit="in"
           decimals={2}
+          coverage={data?.coverage.precipitation}
         />
       </div>
 
+      {data && data.data_quality.rejected + data.data_quality.suspect + data.data_quality.interpolated > 0 && (
+        <p style={styles.updated}>
+          Data quality: {data.data_quality.rejected} impossible values rejected,{' '}
+          {data.data_quality.suspect} suspect spikes excluded,{' '}
+          {data.data_quality.interpolated} short gaps interpolated
+        </p>
+      )}
+
+      {/* Derived from current temp + humidity */}
+      <h2 style={sharedStyles.sectionTitle}>Humidity-Derived Metrics</h2>
+      <div style={styles.summaryGrid}>
+        <SummaryCard label="Dew Point" value={data?.derived.dew_point_f} unit={'\u00B0F'} />
+        <SummaryCard
+          label="Dew Point Spread"
+          value={data?.derived.dew_point_spread_f}
+          unit={'\u00B0F'}
+        />
+        <SummaryCard label="Wet-Bulb" value={data?.derived.wet_bulb_f} unit={'\u00B0F'} />
+        <SummaryCard
+          label="Vapor Pressure Deficit"
+          value={data?.derived.vpd_kpa}
+          unit="kPa"
+          decimals={2}
+        />
+        <SummaryCard
+          label="Leaf Wetness (next 24h)"
+          value={data?.derived.leaf_wetness_hours_24h}
+          unit="hrs"
+          decimals={0}
+        />
+      </div>
+
+      <OvernightWetnessCard nights={data?.derived.overnight_wetness} />
+
+      <FrostDatesCard />
+
       {/* Soil depth table */}
       <h2 style={sharedStyles.sectionTitle}>Soil Profile by Depth</h2>
       <table style={{ ...sharedStyles.table, marginBottom: '1.5rem' }}>
@@ -244,32 +313,7 @@ export default function Environmental() {
         </tbody>
       </table>
 
-      {/* Forecast */}
-      {data?.forecast && data.forecast.daily_summary.length > 0 && (
-        <>
-          <h2 style={sharedStyles.sectionTitle}>5-Day Forecast</h2>
-          <div style={styles.forecastGrid}>
-            {data.forecast.daily_summary.map((day) => (
-              <div key={day.date} style={styles.forecastCard}>
-                <div style={styles.forecastDate}>{day.date}</div>
-                <div style={styles.forecastCondition}>
-                  {day.dominant_condition}
-                </div>
-                <div style={styles.forecastTemp}>
-                  {day.high_temp_f.toFixed(0)}{'\u00B0'} / {day.low_temp_f.toFixed(0)}{'\u00B0'}
-                </div>
-                <div style={styles.forecastDetail}>
-                  Humidity: {day.avg_humidity.toFixed(0)}%
-                </div>
-                <div style={styles.forecastDetail}>
-                  Precip: {mmToInches(day.total_precipitation_mm).toFixed(2)} in (
-                  {(day.max_precipitation_prob * 100).toFixed(0)}%)
-                </div>
-              </div>
-            ))}
-          </div>
-        </>
-      )}
+      <ForecastStrip forecast={data?.forecast ?? null} />
 
       {/* Soil Temperature Forecast */}
       {soilForecast && (
@@ -396,18 +440,35 @@ function SummaryCard({
   value,
   unit,
   decimals = 1,
+  coverage,
+  note,
 }: {
   label: string;
   value: number | null | undefined;
   unit: string;
   decimals?: number;
+  /** Share (0-1) of expected hourly samples behind the value. */
+  coverage?: number | null;
+  note?: React.ReactNode;
 }) {
+  const partial = value != null && coverage != null && coverage < 0.95;
   return (
     <div style={styles.summaryCard}>
       <div style={styles.summaryLabel}>{label}</div>
       <div style={styles.summaryValue}>
         {value != null ? `${value.toFixed(decimals)} ${unit}` : '--'}
       </div>
+      {partial && (
+        <div
+          style={{
+            ...styles.coverage,
+            color: coverage < LOW_COVERAGE ? '#c53030' : '#a0aec0',
+          }}
+        >
+          avg over {Math.round(coverage * 100)}% of expected samples
+        </div>
+      )}
+      {note}
     </div>
   );
 }
@@ -439,23 +500,7 @@ const styles: Record<string, React.CSSProperties> = {
   },
   summaryLabel: { fontSize: '0.75rem', color: '#718096', marginBottom: 4 },
   summaryValue: { fontSize: '1.2rem', fontWeight: 600, color: '#2d3748' },
-  forecastGrid: {
-    display: 'grid',
-    gridTemplateColumns: 'repeat(auto-fit, minmax(150px, 1fr))',
-    gap: '0.75rem',
-    marginBottom: '1.5rem',
-  },
-  forecastCard: {
-    backgroundColor: '#fff',
-    borderRadius: 8,
-    padding: '0.8rem',
-    boxShadow: '0 1px 3px rgba(0,0,0,0.08)',
-    textAlign: 'center' as const,
-  },
-  forecastDate: { fontSize: '0.8rem', fontWeight: 600, color: '#2d3748' },
-  forecastCondition: { fontSize: '0.85rem', color: '#4a5568', margin: '4px 0' },
-  forecastTemp: { fontSize: '1.1rem', fontWeight: 600, color: '#1a202c' },
-  forecastDetail: { fontSize: '0.7rem', color: '#718096', marginTop: 2 },
+  coverage: { fontSize: '0.7rem', marginTop: 4 },
   trendHeader: {
     display: 'flex',
     justifyContent: 'space-between',
@@ -483,7 +528,7 @@ const styles: Record<string, React.CSSProperties> = {
   },
   chartGrid: {
     display: 'grid',
-    gridTemplateColumns: 'repeat(auto-fit, minmax(380px, 1fr))',
+    gridTemplateColumns: 'repeat(auto-fit, minmax(min(380px, 100%), 1fr))',
     gap: '1rem',
     marginBottom: '1.5rem',
   },
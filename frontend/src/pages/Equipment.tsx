@@ -0,0 +1,420 @@
+import { useCallback, useEffect, useState } from 'react';
+import {
+  createEquipment,
+  deleteEquipment,
+  deleteMaintenance,
+  errorMessage,
+  listEquipment,
+  logMaintenance,
+  updateEquipment,
+  type EquipmentData,
+} from '../api/client';
+import { useToast } from '../components/toastContext';
+import { sharedStyles } from '../styles/shared';
+import type { Equipment as EquipmentItem, EquipmentKind, EquipmentTask } from '../types';
+import { EQUIPMENT_TASK_LABELS } from '../types';
+import { t } from '../utils/i18n';
+
+const KINDS: EquipmentKind[] = ['Mower', 'Spreader', 'Sprayer', 'Other'];
+
+/** Tasks offered first for each kind; the rest follow. */
+const KIND_TASKS: Record<EquipmentKind, EquipmentTask[]> = {
+  Mower: ['BladeSharpening', 'OilChange', 'AirFilter', 'SparkPlug', 'Winterize', 'Cleaning'],
+  Spreader: ['Calibration', 'Cleaning', 'Winterize'],
+  Sprayer: ['Calibration', 'Cleaning', 'Winterize'],
+  Other: ['Cleaning', 'Winterize'],
+};
+
+function today(): string {
+  const d = new Date();
+  const local = new Date(d.getTime() - d.getTimezoneOffset() * 60_000);
+  return local.toISOString().slice(0, 10);
+}
+
+/** Most recent date each task was done. */
+function lastDone(item: EquipmentItem): [EquipmentTask, string][] {
+  const seen = new Map<EquipmentTask, string>();
+  for (const m of item.maintenance) {
+    if (!seen.has(m.task)) seen.set(m.task, m.performed_on);
+  }
+  return [...seen.entries()];
+}
+
+export default function Equipment() {
+  const [items, setItems] = useState<EquipmentItem[]>([]);
+  const [loading, setLoading] = useState(true);
+  const [editing, setEditing] = useState<EquipmentItem | 'new' | null>(null);
+  const { notify } = useToast();
+
+  const fetchItems = useCallback(async () => {
+    try {
+      setItems(await listEquipment());
+    } catch (e) {
+      notify(`Failed to load equipment: ${errorMessage(e, 'request failed')}`, 'error');
+    } finally {
+      setLoading(false);
+    }
+  }, [notify]);
+
+  useEffect(() => {
+    fetchItems();
+  }, [fetchItems]);
+
+  const replace = (saved: EquipmentItem) => {
+    setItems((prev) =>
+      [...prev.filter((i) => i.id !== saved.id), saved].sort((a, b) =>
+        a.name.localeCompare(b.name)
+      )
+    );
+  };
+
+  const handleSaved = (saved: EquipmentItem) => {
+    replace(saved);
+    setEditing(null);
+    notify(`Saved ${saved.name}`, 'success');
+  };
+
+  const handleDelete = async (item: EquipmentItem) => {
+    if (item.id == null) return;
+    if (!window.confirm(`Remove ${item.name}? Its maintenance log is deleted too.`)) return;
+    try {
+      await deleteEquipment(item.id);
+      setItems((prev) => prev.filter((i) => i.id !== item.id));
+      notify(`Removed ${item.name}`, 'success');
+    } catch (e) {
+      notify(errorMessage(e, 'Failed to delete'), 'error');
+    }
+  };
+
+  const handleDeleteEvent = async (item: EquipmentItem, eventId: number) => {
+    if (item.id == null) return;
+    try {
+      await deleteMaintenance(item.id, eventId);
+      replace({ ...item, maintenance: item.maintenance.filter((m) => m.id !== eventId) });
+    } catch (e) {
+      notify(errorMessage(e, 'Failed to delete entry'), 'error');
+    }
+  };
+
+  return (
+    <div>
+      <div style={sharedStyles.headerRow}>
+        <h1 style={sharedStyles.pageTitle}>{t('Equipment')}</h1>
+        <button style={styles.addBtn} onClick={() => setEditing(editing ? null : 'new')}>
+          {editing ? 'Cancel' : '+ Add Equipment'}
+        </button>
+      </div>
+
+      <p style={styles.help}>
+        Usage counts come from applications tagged with the equipment; a profile's only mower,
+        spreader or sprayer also picks up untagged mowings and applications of its kind. Service
+        and seasonal reminders show up on the Recommendations page.
+      </p>
+
+      {editing && (
+        <EquipmentForm
+          key={editing === 'new' ? 'new' : editing.id}
+          initial={editing === 'new' ? null : editing}
+          onSaved={handleSaved}
+        />
+      )}
+
+      {loading ? (
+        <p style={sharedStyles.loading}>Loading...</p>
+      ) : items.length === 0 ? (
+        <p style={sharedStyles.empty}>No equipment yet.</p>
+      ) : (
+        items.map((item) => (
+          <div key={item.id} style={{ ...sharedStyles.card, marginBottom: '1rem' }}>
+            <div style={styles.cardHeader}>
+              <div>
+                <h3 style={styles.name}>{item.name}</h3>
+                <div style={styles.muted}>
+                  {item.kind} · {item.uses} use{item.uses === 1 ? '' : 's'} logged
+                  {item.hours != null && ` · ~${item.hours.toFixed(0)} h`}
+                </div>
+                {item.notes && <div style={styles.muted}>{item.notes}</div>}
+              </div>
+              <div style={{ whiteSpace: 'nowrap' }}>
+                <button style={styles.linkBtn} onClick={() => setEditing(item)}>
+                  Edit
+                </button>
+                <button
+                  style={{ ...styles.linkBtn, color: '#e53e3e' }}
+                  onClick={() => handleDelete(item)}
+                >
+                  Delete
+                </button>
+              </div>
+            </div>
+
+            {lastDone(item).length > 0 && (
+              <div style={styles.lastDone}>
+                {lastDone(item).map(([task, on]) => (
+                  <span key={task} style={sharedStyles.badge}>
+                    {EQUIPMENT_TASK_LABELS[task]}: {on}
+                  </span>
+                ))}
+              </div>
+            )}
+
+            <MaintenanceForm item={item} onLogged={replace} />
+
+            {item.maintenance.length > 0 && (
+              <table style={{ ...sharedStyles.table, marginTop: '0.75rem' }}>
+                <tbody>
+                  {item.maintenance.map((m) => (
+                    <tr key={m.id}>
+                      <td style={{ ...sharedStyles.td, whiteSpace: 'nowrap' }}>{m.performed_on}</td>
+                      <td style={sharedStyles.td}>{EQUIPMENT_TASK_LABELS[m.task]}</td>
+                      <td style={sharedStyles.td}>{m.notes ?? ''}</td>
+                      <td style={sharedStyles.td}>
+                        {m.id != null && (
+                          <button
+                            style={{ ...styles.linkBtn, color: '#e53e3e' }}
+                            onClick={() => handleDeleteEvent(item, m.id as number)}
+                          >
+                            Delete
+                          </button>
+                        )}
+                      </td>
+                    </tr>
+                  ))}
+                </tbody>
+              </table>
+            )}
+          </div>
+        ))
+      )}
+    </div>
+  );
+}
+
+function MaintenanceForm({
+  item,
+  onLogged,
+}: {
+  item: EquipmentItem;
+  onLogged: (item: EquipmentItem) => void;
+}) {
+  const tasks = [
+    ...KIND_TASKS[item.kind],
+    ...(Object.keys(EQUIPMENT_TASK_LABELS) as EquipmentTask[]).filter(
+      (t) => !KIND_TASKS[item.kind].includes(t)
+    ),
+  ];
+  const [task, setTask] = useState<EquipmentTask>(tasks[0]);
+  const [date, setDate] = useState(today());
+  const [notes, setNotes] = useState('');
+  const [saving, setSaving] = useState(false);
+  const { notify } = useToast();
+
+  const handleSubmit = async (e: React.FormEvent) => {
+    e.preventDefault();
+    if (item.id == null) return;
+    setSaving(true);
+    try {
+      onLogged(
+        await logMaintenance(item.id, { task, performed_on: date, notes: notes || undefined })
+      );
+      setNotes('');
+      notify(`Logged ${EQUIPMENT_TASK_LABELS[task].toLowerCase()} for ${item.name}`, 'success');
+    } catch (err) {
+      notify(errorMessage(err, 'Failed to log maintenance'), 'error');
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  return (
+    <form onSubmit={handleSubmit} style={styles.formRow}>
+      <select
+        style={styles.input}
+        value={task}
+        onChange={(e) => setTask(e.target.value as EquipmentTask)}
+      >
+        {tasks.map((t) => (
+          <option key={t} value={t}>
+            {EQUIPMENT_TASK_LABELS[t]}
+          </option>
+        ))}
+      </select>
+      <input
+        type="date"
+        style={styles.input}
+        value={date}
+        max={today()}
+        onChange={(e) => setDate(e.target.value)}
+        required
+      />
+      <input
+        style={{ ...styles.input, flex: 1 }}
+        value={notes}
+        placeholder="Notes"
+        onChange={(e) => setNotes(e.target.value)}
+      />
+      <button type="submit" disabled={saving} style={styles.submitBtn}>
+        {saving ? 'Logging...' : 'Log'}
+      </button>
+    </form>
+  );
+}
+
+function EquipmentForm({
+  initial,
+  onSaved,
+}: {
+  initial: EquipmentItem | null;
+  onSaved: (item: EquipmentItem) => void;
+}) {
+  const [name, setName] = useState(initial?.name ?? '');
+  const [kind, setKind] = useState<EquipmentKind>(initial?.kind ?? 'Mower');
+  const [hoursPerUse, setHoursPerUse] = useState(initial?.hours_per_use?.toString() ?? '');
+  const [startingHours, setStartingHours] = useState(
+    initial?.starting_hours.toString() ?? ''
+  );
+  const [notes, setNotes] = useState(initial?.notes ?? '');
+  const [saving, setSaving] = useState(false);
+  const { notify } = useToast();
+
+  const handleSubmit = async (e: React.FormEvent) => {
+    e.preventDefault();
+    setSaving(true);
+    const data: EquipmentData = {
+      name,
+      kind,
+      hours_per_use: hoursPerUse ? parseFloat(hoursPerUse) : undefined,
+      starting_hours: startingHours ? parseFloat(startingHours) : undefined,
+      notes: notes || undefined,
+    };
+    try {
+      onSaved(
+        initial?.id != null
+          ? await updateEquipment(initial.id, data)
+          : await createEquipment(data)
+      );
+    } catch (err) {
+      notify(errorMessage(err, 'Failed to save equipment'), 'error');
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  return (
+    <form onSubmit={handleSubmit} style={{ ...sharedStyles.card, marginBottom: '1.5rem' }}>
+      <h3 style={sharedStyles.sectionTitle}>{initial ? 'Edit Equipment' : 'New Equipment'}</h3>
+      <div style={styles.formRow}>
+        <label style={styles.label}>
+          Name *
+          <input
+            style={styles.input}
+            value={name}
+            onChange={(e) => setName(e.target.value)}
+            placeholder="e.g. Honda HRN"
+            required
+          />
+        </label>
+        <label style={styles.label}>
+          Kind
+          <select
+            style={styles.input}
+            value={kind}
+            onChange={(e) => setKind(e.target.value as EquipmentKind)}
+          >
+            {KINDS.map((k) => (
+              <option key={k} value={k}>
+                {k}
+              </option>
+            ))}
+          </select>
+        </label>
+        <label style={styles.label}>
+          Hours per use
+          <input
+            type="number"
+            step="any"
+            min="0"
+            style={{ ...styles.input, width: 110 }}
+            value={hoursPerUse}
+            onChange={(e) => setHoursPerUse(e.target.value)}
+            placeholder={kind === 'Mower' ? '1' : ''}
+          />
+        </label>
+        <label style={styles.label}>
+          Hour meter now
+          <input
+            type="number"
+            step="any"
+            min="0"
+            style={{ ...styles.input, width: 110 }}
+            value={startingHours}
+            onChange={(e) => setStartingHours(e.target.value)}
+          />
+        </label>
+      </div>
+      <label style={styles.label}>
+        Notes
+        <input style={styles.input} value={notes} onChange={(e) => setNotes(e.target.value)} />
+      </label>
+      <button type="submit" disabled={saving} style={styles.submitBtn}>
+        {saving ? 'Saving...' : initial ? 'Update' : 'Save'}
+      </button>
+    </form>
+  );
+}
+
+const styles: Record<string, React.CSSProperties> = {
+  help: { color: '#718096', fontSize: '0.85rem', marginTop: 0 },
+  muted: { fontSize: '0.8rem', color: '#a0aec0' },
+  name: { margin: 0, fontSize: '1.05rem', color: '#2d3748' },
+  cardHeader: {
+    display: 'flex',
+    justifyContent: 'space-between',
+    alignItems: 'flex-start',
+    marginBottom: '0.75rem',
+  },
+  lastDone: { display: 'flex', gap: 6, flexWrap: 'wrap' as const, marginBottom: '0.75rem' },
+  addBtn: {
+    padding: '0.5rem 1rem',
+    backgroundColor: '#38a169',
+    color: '#fff',
+    border: 'none',
+    borderRadius: 6,
+    cursor: 'pointer',
+    fontWeight: 600,
+    fontSize: '0.85rem',
+  },
+  formRow: { display: 'flex', gap: 12, flexWrap: 'wrap' as const, alignItems: 'flex-end' },
+  label: {
+    display: 'flex',
+    flexDirection: 'column' as const,
+    gap: 4,
+    fontSize: '0.85rem',
+    color: '#4a5568',
+    marginBottom: '0.75rem',
+  },
+  input: {
+    padding: '0.4rem 0.6rem',
+    borderRadius: 6,
+    border: '1px solid #e2e8f0',
+    fontSize: '0.85rem',
+    fontFamily: 'inherit',
+  },
+  submitBtn: {
+    padding: '0.45rem 1.25rem',
+    backgroundColor: '#3182ce',
+    color: '#fff',
+    border: 'none',
+    borderRadius: 6,
+    cursor: 'pointer',
+    fontWeight: 600,
+  },
+  linkBtn: {
+    background: 'none',
+    border: 'none',
+    color: '#3182ce',
+    cursor: 'pointer',
+    fontSize: '0.8rem',
+    padding: '0 4px',
+  },
+};
@@ -0,0 +1,395 @@
+import { useCallback, useEffect, useState } from 'react';
+import {
+  createInventoryItem,
+  deleteInventoryItem,
+  errorMessage,
+  listInventory,
+  updateInventoryItem,
+  type InventoryData,
+} from '../api/client';
+import EnumSelect from '../components/EnumSelect';
+import { useToast } from '../components/toastContext';
+import { sharedStyles } from '../styles/shared';
+import type { ApplicationType, InventoryItem, ProductUnit } from '../types';
+import { APPLICATION_TYPE_LABELS, PRODUCT_UNIT_LABELS } from '../types';
+import { t } from '../utils/i18n';
+
+/** Product application types an inventory item can be matched to. */
+const PRODUCT_TYPES: ApplicationType[] = [
+  'PreEmergent',
+  'PostEmergent',
+  'Fertilizer',
+  'Fungicide',
+  'Insecticide',
+  'GrubControl',
+  'Overseed',
+  'Lime',
+  'Sulfur',
+  'Wetting',
+];
+
+function today(): string {
+  const d = new Date();
+  const local = new Date(d.getTime() - d.getTimezoneOffset() * 60_000);
+  return local.toISOString().slice(0, 10);
+}
+
+function fmt(amount: number, unit: ProductUnit): string {
+  return `${amount.toFixed(amount < 10 ? 1 : 0)} ${PRODUCT_UNIT_LABELS[unit]}`;
+}
+
+function toData(item: InventoryItem): InventoryData {
+  return {
+    product_name: item.product_name,
+    application_type: item.application_type ?? undefined,
+    unit: item.unit,
+    package_size: item.package_size ?? undefined,
+    quantity: item.quantity,
+    counted_on: item.counted_on,
+    rate_per_1000sqft: item.rate_per_1000sqft ?? undefined,
+    notes: item.notes ?? undefined,
+  };
+}
+
+export default function Inventory() {
+  const [items, setItems] = useState<InventoryItem[]>([]);
+  const [loading, setLoading] = useState(true);
+  const [editing, setEditing] = useState<InventoryItem | 'new' | null>(null);
+  const { notify } = useToast();
+
+  const fetchItems = useCallback(async () => {
+    try {
+      setItems(await listInventory());
+    } catch (e) {
+      notify(`Failed to load inventory: ${errorMessage(e, 'request failed')}`, 'error');
+    } finally {
+      setLoading(false);
+    }
+  }, [notify]);
+
+  useEffect(() => {
+    fetchItems();
+  }, [fetchItems]);
+
+  const replace = (saved: InventoryItem) => {
+    setItems((prev) =>
+      [...prev.filter((i) => i.id !== saved.id), saved].sort((a, b) =>
+        a.product_name.localeCompare(b.product_name)
+      )
+    );
+  };
+
+  const handleSaved = (saved: InventoryItem) => {
+    replace(saved);
+    setEditing(null);
+    notify(`Saved ${saved.product_name}`, 'success');
+  };
+
+  /** Add one package to what's left and recount from today. */
+  const handleRestock = async (item: InventoryItem) => {
+    if (item.id == null || item.package_size == null) return;
+    try {
+      const saved = await updateInventoryItem(item.id, {
+        ...toData(item),
+        quantity: item.remaining + item.package_size,
+        counted_on: today(),
+      });
+      replace(saved);
+      notify(`Added a ${fmt(item.package_size, item.unit)} package of ${item.product_name}`, 'success');
+    } catch (e) {
+      notify(errorMessage(e, 'Failed to restock'), 'error');
+    }
+  };
+
+  const handleDelete = async (item: InventoryItem) => {
+    if (item.id == null) return;
+    if (!window.confirm(`Remove ${item.product_name} from inventory?`)) return;
+    try {
+      await deleteInventoryItem(item.id);
+      setItems((prev) => prev.filter((i) => i.id !== item.id));
+      notify(`Removed ${item.product_name}`, 'success');
+    } catch (e) {
+      notify(errorMessage(e, 'Failed to delete'), 'error');
+    }
+  };
+
+  return (
+    <div>
+      <div style={sharedStyles.headerRow}>
+        <h1 style={sharedStyles.pageTitle}>{t('Inventory')}</h1>
+        <button style={styles.addBtn} onClick={() => setEditing(editing ? null : 'new')}>
+          {editing ? 'Cancel' : '+ Add Product'}
+        </button>
+      </div>
+
+      <p style={styles.help}>
+        Record what's on the shelf. Applications logged with a matching product name draw it
+        down by rate x coverage, and recommendations note when there isn't enough for the next
+        application.
+      </p>
+
+      {editing && (
+        <ItemForm
+          key={editing === 'new' ? 'new' : editing.id}
+          initial={editing === 'new' ? null : editing}
+          onSaved={handleSaved}
+        />
+      )}
+
+      {loading ? (
+        <p style={sharedStyles.loading}>Loading...</p>
+      ) : items.length === 0 ? (
+        <p style={sharedStyles.empty}>No products in inventory yet.</p>
+      ) : (
+        <table style={sharedStyles.table}>
+          <thead>
+            <tr>
+              <th style={sharedStyles.th}>Product</th>
+              <th style={sharedStyles.th}>Used For</th>
+              <th style={sharedStyles.th}>On Hand</th>
+              <th style={sharedStyles.th}>Per Application</th>
+              <th style={sharedStyles.th}>Counted</th>
+              <th style={sharedStyles.th} />
+            </tr>
+          </thead>
+          <tbody>
+            {items.map((i) => {
+              const short = i.per_application != null && i.remaining < i.per_application;
+              return (
+                <tr key={i.id}>
+                  <td style={sharedStyles.td}>
+                    {i.product_name}
+                    {i.notes && <div style={styles.muted}>{i.notes}</div>}
+                  </td>
+                  <td style={sharedStyles.td}>
+                    {i.application_type ? APPLICATION_TYPE_LABELS[i.application_type] : '-'}
+                  </td>
+                  <td style={{ ...sharedStyles.td, color: short ? '#c05621' : undefined }}>
+                    {fmt(i.remaining, i.unit)}
+                    {i.used_since_count > 0 && (
+                      <div style={styles.muted}>
+                        {fmt(i.used_since_count, i.unit)} used since count
+                      </div>
+                    )}
+                  </td>
+                  <td style={sharedStyles.td}>
+                    {i.per_application != null ? fmt(i.per_application, i.unit) : '-'}
+                  </td>
+                  <td style={{ ...sharedStyles.td, whiteSpace: 'nowrap' }}>{i.counted_on}</td>
+                  <td style={{ ...sharedStyles.td, whiteSpace: 'nowrap' }}>
+                    {i.package_size != null && (
+                      <button style={styles.linkBtn} onClick={() => handleRestock(i)}>
+                        + Package
+                      </button>
+                    )}
+                    <button style={styles.linkBtn} onClick={() => setEditing(i)}>
+                      Edit
+                    </button>
+                    <button
+                      style={{ ...styles.linkBtn, color: '#e53e3e' }}
+                      onClick={() => handleDelete(i)}
+                    >
+                      Delete
+                    </button>
+                  </td>
+                </tr>
+              );
+            })}
+          </tbody>
+        </table>
+      )}
+    </div>
+  );
+}
+
+function ItemForm({
+  initial,
+  onSaved,
+}: {
+  initial: InventoryItem | null;
+  onSaved: (item: InventoryItem) => void;
+}) {
+  const [name, setName] = useState(initial?.product_name ?? '');
+  const [appType, setAppType] = useState<ApplicationType | ''>(
+    initial?.application_type ?? ''
+  );
+  const [unit, setUnit] = useState<ProductUnit>(initial?.unit ?? 'Pounds');
+  const [packageSize, setPackageSize] = useState(initial?.package_size?.toString() ?? '');
+  // Editing recounts from what's left today
+  const [quantity, setQuantity] = useState(initial?.remaining.toString() ?? '');
+  const [countedOn, setCountedOn] = useState(today());
+  const [rate, setRate] = useState(initial?.rate_per_1000sqft?.toString() ?? '');
+  const [notes, setNotes] = useState(initial?.notes ?? '');
+  const [saving, setSaving] = useState(false);
+  const { notify } = useToast();
+
+  const handleSubmit = async (e: React.FormEvent) => {
+    e.preventDefault();
+    setSaving(true);
+    const data: InventoryData = {
+      product_name: name,
+      application_type: appType || undefined,
+      unit,
+      package_size: packageSize ? parseFloat(packageSize) : undefined,
+      quantity: parseFloat(quantity),
+      counted_on: countedOn,
+      rate_per_1000sqft: rate ? parseFloat(rate) : undefined,
+      notes: notes || undefined,
+    };
+    try {
+      onSaved(
+        initial?.id != null
+          ? await updateInventoryItem(initial.id, data)
+          : await createInventoryItem(data)
+      );
+    } catch (err) {
+      notify(errorMessage(err, 'Failed to save product'), 'error');
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  return (
+    <form onSubmit={handleSubmit} style={{ ...sharedStyles.card, marginBottom: '1.5rem' }}>
+      <h3 style={sharedStyles.sectionTitle}>{initial ? 'Edit Product' : 'New Product'}</h3>
+      <div style={styles.formRow}>
+        <label style={styles.label}>
+          Product *
+          <input
+            style={styles.input}
+            value={name}
+            onChange={(e) => setName(e.target.value)}
+            placeholder="e.g. Prodiamine 65 WDG"
+            required
+          />
+        </label>
+        <label style={styles.label}>
+          Used for
+          <EnumSelect
+            style={styles.input}
+            options={PRODUCT_TYPES}
+            labels={APPLICATION_TYPE_LABELS}
+            value={appType}
+            onChange={setAppType}
+            emptyLabel="-"
+          />
+        </label>
+        <label style={styles.label}>
+          Unit
+          <select
+            style={styles.input}
+            value={unit}
+            onChange={(e) => setUnit(e.target.value as ProductUnit)}
+          >
+            {(Object.keys(PRODUCT_UNIT_LABELS) as ProductUnit[]).map((u) => (
+              <option key={u} value={u}>
+                {PRODUCT_UNIT_LABELS[u]}
+              </option>
+            ))}
+          </select>
+        </label>
+      </div>
+      <div style={styles.formRow}>
+        <label style={styles.label}>
+          On hand *
+          <input
+            type="number"
+            step="any"
+            min="0"
+            style={{ ...styles.input, width: 110 }}
+            value={quantity}
+            onChange={(e) => setQuantity(e.target.value)}
+            required
+          />
+        </label>
+        <label style={styles.label}>
+          As of
+          <input
+            type="date"
+            style={styles.input}
+            value={countedOn}
+            onChange={(e) => setCountedOn(e.target.value)}
+            required
+          />
+        </label>
+        <label style={styles.label}>
+          Package size
+          <input
+            type="number"
+            step="any"
+            min="0"
+            style={{ ...styles.input, width: 110 }}
+            value={packageSize}
+            onChange={(e) => setPackageSize(e.target.value)}
+          />
+        </label>
+        <label style={styles.label}>
+          Label rate / 1000 sqft
+          <input
+            type="number"
+            step="any"
+            min="0"
+            style={{ ...styles.input, width: 110 }}
+            value={rate}
+            onChange={(e) => setRate(e.target.value)}
+          />
+        </label>
+      </div>
+      <label style={styles.label}>
+        Notes
+        <input style={styles.input} value={notes} onChange={(e) => setNotes(e.target.value)} />
+      </label>
+      <button type="submit" disabled={saving} style={styles.submitBtn}>
+        {saving ? 'Saving...' : initial ? 'Update' : 'Save'}
+      </button>
+    </form>
+  );
+}
+
+const styles: Record<string, React.CSSProperties> = {
+  help: { color: '#718096', fontSize: '0.85rem', marginTop: 0 },
+  muted: { fontSize: '0.75rem', color: '#a0aec0' },
+  addBtn: {
+    padding: '0.5rem 1rem',
+    backgroundColor: '#38a169',
+    color: '#fff',
+    border: 'none',
+    borderRadius: 6,
+    cursor: 'pointer',
+    fontWeight: 600,
+    fontSize: '0.85rem',
+  },
+  formRow: { display: 'flex', gap: 12, flexWrap: 'wrap' as const },
+  label: {
+    display: 'flex',
+    flexDirection: 'column' as const,
+    gap: 4,
+    fontSize: '0.85rem',
+    color: '#4a5568',
+    marginBottom: '0.75rem',
+  },
+  input: {
+    padding: '0.4rem 0.6rem',
+    borderRadius: 6,
+    border: '1px solid #e2e8f0',
+    fontSize: '0.85rem',
+    fontFamily: 'inherit',
+  },
+  submitBtn: {
+    padding: '0.5rem 1.25rem',
+    backgroundColor: '#3182ce',
+    color: '#fff',
+    border: 'none',
+    borderRadius: 6,
+    cursor: 'pointer',
+    fontWeight: 600,
+  },
+  linkBtn: {
+    background: 'none',
+    border: 'none',
+    color: '#3182ce',
+    cursor: 'pointer',
+    fontSize: '0.8rem',
+    padding: '0 4px',
+  },
+};
@@ -12,6 +12,7 @@ import {
   PLANT_TYPE_LABELS,
   TASK_TYPE_LABELS,
 } from '../types';
+import { t } from '../utils/i18n';
 
 const PLANT_TYPES: PlantType[] = [
   'Shrub',
@@ -114,7 +115,7 @@ export default function Landscape() {
   return (
     <div>
       <div style={sharedStyles.headerRow}>
-        <h1 style={sharedStyles.pageTitle}>Landscape</h1>
+        <h1 style={sharedStyles.pageTitle}>{t('Landscape')}</h1>
         <button style={styles.addBtn} onClick={() => setShowForm(!showForm)}>
           {showForm ? 'Cancel' : '+ Add Plant'}
         </button>
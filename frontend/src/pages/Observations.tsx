@@ -0,0 +1,290 @@
+import { useCallback, useEffect, useState } from 'react';
+import {
+  createObservation,
+  deleteObservation,
+  getObservations,
+  updateObservation,
+} from '../api/client';
+import { sharedStyles } from '../styles/shared';
+import type { Observation, ObservationCategory } from '../types';
+import { OBSERVATION_CATEGORIES, OBSERVATION_CATEGORY_COLORS } from '../types';
+import { t } from '../utils/i18n';
+
+/** Local "YYYY-MM-DDTHH:mm" for a datetime-local input. */
+function toLocalInput(d: Date): string {
+  const local = new Date(d.getTime() - d.getTimezoneOffset() * 60_000);
+  return local.toISOString().slice(0, 16);
+}
+
+export default function Observations() {
+  const [observations, setObservations] = useState<Observation[]>([]);
+  const [filter, setFilter] = useState('');
+  const [loading, setLoading] = useState(true);
+  const [error, setError] = useState<string | null>(null);
+  const [formOpen, setFormOpen] = useState(false);
+  const [editingId, setEditingId] = useState<number | null>(null);
+  const [submitting, setSubmitting] = useState(false);
+
+  const [observedAt, setObservedAt] = useState(toLocalInput(new Date()));
+  const [category, setCategory] = useState<ObservationCategory>('Weeds');
+  const [notes, setNotes] = useState('');
+
+  const fetchObservations = useCallback(async () => {
+    try {
+      setObservations(await getObservations(filter || undefined));
+      setError(null);
+    } catch (e) {
+      setError(e instanceof Error ? e.message : 'Failed to load journal');
+    } finally {
+      setLoading(false);
+    }
+  }, [filter]);
+
+  useEffect(() => {
+    fetchObservations();
+  }, [fetchObservations]);
+
+  const resetForm = () => {
+    setEditingId(null);
+    setObservedAt(toLocalInput(new Date()));
+    setCategory('Weeds');
+    setNotes('');
+  };
+
+  const handleEdit = (o: Observation) => {
+    setEditingId(o.id);
+    setObservedAt(toLocalInput(new Date(o.observed_at)));
+    setCategory(o.category);
+    setNotes(o.notes);
+    setFormOpen(true);
+  };
+
+  const handleSubmit = async (e: React.FormEvent) => {
+    e.preventDefault();
+    if (!notes.trim()) {
+      setError('Notes are required');
+      return;
+    }
+    setSubmitting(true);
+    setError(null);
+    try {
+      const data = {
+        observed_at: new Date(observedAt).toISOString(),
+        category,
+        notes,
+      };
+      if (editingId) {
+        await updateObservation(editingId, data);
+      } else {
+        await createObservation(data);
+      }
+      resetForm();
+      setFormOpen(false);
+      await fetchObservations();
+    } catch (e) {
+      setError(e instanceof Error ? e.message : 'Failed to save observation');
+    } finally {
+      setSubmitting(false);
+    }
+  };
+
+  const handleDelete = async (id: number) => {
+    try {
+      await deleteObservation(id);
+      await fetchObservations();
+    } catch (e) {
+      setError(e instanceof Error ? e.message : 'Failed to delete');
+    }
+  };
+
+  return (
+    <div>
+      <div style={sharedStyles.headerRow}>
+        <h1 style={sharedStyles.pageTitle}>{t('Journal')}</h1>
+        <button
+          style={styles.addBtn}
+          onClick={() => {
+            if (formOpen) resetForm();
+            setFormOpen(!formOpen);
+          }}
+        >
+          {formOpen ? 'Cancel' : '+ Add Observation'}
+        </button>
+      </div>
+
+      {error && <div style={sharedStyles.error}>{error}</div>}
+
+      {formOpen && (
+        <form onSubmit={handleSubmit} style={{ ...sharedStyles.card, marginBottom: '1.5rem' }}>
+          <h3 style={sharedStyles.sectionTitle}>
+            {editingId ? 'Edit Observation' : 'New Observation'}
+          </h3>
+          <div style={styles.formRow}>
+            <label style={styles.label}>
+              When
+              <input
+                type="datetime-local"
+                value={observedAt}
+                onChange={(e) => setObservedAt(e.target.value)}
+                required
+                style={styles.input}
+              />
+            </label>
+            <label style={styles.label}>
+              Category
+              <select
+                value={category}
+                onChange={(e) => setCategory(e.target.value as ObservationCategory)}
+                style={styles.input}
+              >
+                {OBSERVATION_CATEGORIES.map((c) => (
+                  <option key={c} value={c}>
+                    {c}
+                  </option>
+                ))}
+              </select>
+            </label>
+          </div>
+          <label style={styles.label}>
+            Notes *
+            <textarea
+              value={notes}
+              onChange={(e) => setNotes(e.target.value)}
+              placeholder="e.g. Crabgrass breaking through along the driveway edge"
+              rows={3}
+              required
+              style={styles.input}
+            />
+          </label>
+          <button type="submit" disabled={submitting} style={styles.submitBtn}>
+            {submitting ? 'Saving...' : editingId ? 'Update' : 'Save'}
+          </button>
+        </form>
+      )}
+
+      <div style={styles.filterRow}>
+        <label style={styles.filterLabel}>Category:</label>
+        <select style={styles.select} value={filter} onChange={(e) => setFilter(e.target.value)}>
+          <option value="">All</option>
+          {OBSERVATION_CATEGORIES.map((c) => (
+            <option key={c} value={c}>
+              {c}
+            </option>
+          ))}
+        </select>
+      </div>
+
+      {loading ? (
+        <p style={sharedStyles.loading}>Loading...</p>
+      ) : observations.length === 0 ? (
+        <p style={sharedStyles.empty}>No observations recorded yet.</p>
+      ) : (
+        <table style={sharedStyles.table}>
+          <thead>
+            <tr>
+              <th style={sharedStyles.th}>When</th>
+              <th style={sharedStyles.th}>Category</th>
+              <th style={sharedStyles.th}>Notes</th>
+              <th style={sharedStyles.th} />
+            </tr>
+          </thead>
+          <tbody>
+            {observations.map((o) => {
+              const color = OBSERVATION_CATEGORY_COLORS[o.category];
+              return (
+                <tr key={o.id}>
+                  <td style={{ ...sharedStyles.td, whiteSpace: 'nowrap' }}>
+                    {new Date(o.observed_at).toLocaleString()}
+                  </td>
+                  <td style={sharedStyles.td}>
+                    <span
+                      style={{
+                        ...sharedStyles.badge,
+                        color,
+                        borderColor: color,
+                        backgroundColor: color + '22',
+                      }}
+                    >
+                      {o.category}
+                    </span>
+                  </td>
+                  <td style={{ ...sharedStyles.td, whiteSpace: 'pre-wrap' }}>{o.notes}</td>
+                  <td style={{ ...sharedStyles.td, whiteSpace: 'nowrap' }}>
+                    <button style={styles.linkBtn} onClick={() => handleEdit(o)}>
+                      Edit
+                    </button>
+                    <button
+                      style={{ ...styles.linkBtn, color: '#e53e3e' }}
+                      onClick={() => handleDelete(o.id)}
+                    >
+                      Delete
+                    </button>
+                  </td>
+                </tr>
+              );
+            })}
+          </tbody>
+        </table>
+      )}
+    </div>
+  );
+}
+
+const styles: Record<string, React.CSSProperties> = {
+  addBtn: {
+    padding: '0.5rem 1rem',
+    backgroundColor: '#38a169',
+    color: '#fff',
+    border: 'none',
+    borderRadius: 6,
+    cursor: 'pointer',
+    fontWeight: 600,
+    fontSize: '0.85rem',
+  },
+  formRow: { display: 'flex', gap: 12, flexWrap: 'wrap' as const },
+  label: {
+    display: 'flex',
+    flexDirection: 'column' as const,
+    gap: 4,
+    fontSize: '0.85rem',
+    color: '#4a5568',
+    marginBottom: '0.75rem',
+  },
+  input: {
+    padding: '0.4rem 0.6rem',
+    borderRadius: 6,
+    border: '1px solid #e2e8f0',
+    fontSize: '0.85rem',
+    fontFamily: 'inherit',
+  },
+  submitBtn: {
+    padding: '0.5rem 1.25rem',
+    backgroundColor: '#3182ce',
+    color: '#fff',
+    border: 'none',
+    borderRadius: 6,
+    cursor: 'pointer',
+    fontWeight: 600,
+  },
+  filterRow: {
+    display: 'flex',
+    alignItems: 'center',
+    gap: 8,
+    marginBottom: '1rem',
+  },
+  filterLabel: { fontSize: '0.85rem', color: '#4a5568' },
+  select: {
+    padding: '0.4rem 0.6rem',
+    borderRadius: 6,
+    border: '1px solid #e2e8f0',
+    fontSize: '0.85rem',
+  },
+  linkBtn: {
+    background: 'none',
+    border: 'none',
+    color: '#3182ce',
+    cursor: 'pointer',
+    fontSize: '0.8rem',
+    padding: '0 4px',
+  },
+};
@@ -0,0 +1,299 @@
+import { useCallback, useEffect, useState } from 'react';
+import { createPhoto, deletePhoto, getPhotos, listAreas, updatePhoto } from '../api/client';
+import { sharedStyles } from '../styles/shared';
+import type { LawnArea, Photo } from '../types';
+import { t } from '../utils/i18n';
+
+/** Local "YYYY-MM-DD" for a date input. */
+function todayInput(): string {
+  const d = new Date();
+  const local = new Date(d.getTime() - d.getTimezoneOffset() * 60_000);
+  return local.toISOString().slice(0, 10);
+}
+
+export default function Photos() {
+  const [photos, setPhotos] = useState<Photo[]>([]);
+  const [areas, setAreas] = useState<LawnArea[]>([]);
+  const [areaFilter, setAreaFilter] = useState('');
+  const [loading, setLoading] = useState(true);
+  const [error, setError] = useState<string | null>(null);
+  const [formOpen, setFormOpen] = useState(false);
+  const [editingId, setEditingId] = useState<number | null>(null);
+  const [submitting, setSubmitting] = useState(false);
+
+  const [takenOn, setTakenOn] = useState(todayInput());
+  const [areaId, setAreaId] = useState('');
+  const [path, setPath] = useState('');
+  const [caption, setCaption] = useState('');
+
+  const fetchPhotos = useCallback(async () => {
+    try {
+      setPhotos(await getPhotos(areaFilter ? Number(areaFilter) : undefined));
+      setError(null);
+    } catch (e) {
+      setError(e instanceof Error ? e.message : 'Failed to load photos');
+    } finally {
+      setLoading(false);
+    }
+  }, [areaFilter]);
+
+  useEffect(() => {
+    fetchPhotos();
+  }, [fetchPhotos]);
+
+  useEffect(() => {
+    listAreas()
+      .then(setAreas)
+      .catch(() => setAreas([]));
+  }, []);
+
+  const areaName = (id: number | null) =>
+    id === null ? 'Whole lawn' : (areas.find((a) => a.id === id)?.name ?? `Area ${id}`);
+
+  const resetForm = () => {
+    setEditingId(null);
+    setTakenOn(todayInput());
+    setAreaId('');
+    setPath('');
+    setCaption('');
+  };
+
+  const handleEdit = (p: Photo) => {
+    setEditingId(p.id);
+    setTakenOn(p.taken_on);
+    setAreaId(p.area_id === null ? '' : String(p.area_id));
+    setPath(p.path);
+    setCaption(p.caption ?? '');
+    setFormOpen(true);
+  };
+
+  const handleSubmit = async (e: React.FormEvent) => {
+    e.preventDefault();
+    if (!path.trim()) {
+      setError('Path or file name is required');
+      return;
+    }
+    setSubmitting(true);
+    setError(null);
+    try {
+      const data = {
+        taken_on: takenOn,
+        area_id: areaId ? Number(areaId) : null,
+        path,
+        caption: caption || null,
+      };
+      if (editingId) {
+        await updatePhoto(editingId, data);
+      } else {
+        await createPhoto(data);
+      }
+      resetForm();
+      setFormOpen(false);
+      await fetchPhotos();
+    } catch (e) {
+      setError(e instanceof Error ? e.message : 'Failed to save photo');
+    } finally {
+      setSubmitting(false);
+    }
+  };
+
+  const handleDelete = async (id: number) => {
+    try {
+      await deletePhoto(id);
+      await fetchPhotos();
+    } catch (e) {
+      setError(e instanceof Error ? e.message : 'Failed to delete');
+    }
+  };
+
+  return (
+    <div>
+      <div style={sharedStyles.headerRow}>
+        <h1 style={sharedStyles.pageTitle}>{t('Photos')}</h1>
+        <button
+          style={styles.addBtn}
+          onClick={() => {
+            if (formOpen) resetForm();
+            setFormOpen(!formOpen);
+          }}
+        >
+          {formOpen ? 'Cancel' : '+ Add Photo'}
+        </button>
+      </div>
+
+      {error && <div style={sharedStyles.error}>{error}</div>}
+
+      {formOpen && (
+        <form onSubmit={handleSubmit} style={{ ...sharedStyles.card, marginBottom: '1.5rem' }}>
+          <h3 style={sharedStyles.sectionTitle}>{editingId ? 'Edit Photo' : 'New Photo'}</h3>
+          <div style={styles.formRow}>
+            <label style={styles.label}>
+              Taken
+              <input
+                type="date"
+                value={takenOn}
+                onChange={(e) => setTakenOn(e.target.value)}
+                required
+                style={styles.input}
+              />
+            </label>
+            <label style={styles.label}>
+              Area
+              <select
+                value={areaId}
+                onChange={(e) => setAreaId(e.target.value)}
+                style={styles.input}
+              >
+                <option value="">Whole lawn</option>
+                {areas.map((a) => (
+                  <option key={a.id ?? a.name} value={a.id ?? ''}>
+                    {a.name}
+                  </option>
+                ))}
+              </select>
+            </label>
+          </div>
+          <label style={styles.label}>
+            Path or file name *
+            <input
+              type="text"
+              value={path}
+              onChange={(e) => setPath(e.target.value)}
+              placeholder="e.g. ~/Pictures/lawn/2025-09-20-front.jpg"
+              required
+              style={styles.input}
+            />
+          </label>
+          <label style={styles.label}>
+            Caption
+            <input
+              type="text"
+              value={caption}
+              onChange={(e) => setCaption(e.target.value)}
+              placeholder="e.g. Two weeks after overseeding"
+              style={styles.input}
+            />
+          </label>
+          <button type="submit" disabled={submitting} style={styles.submitBtn}>
+            {submitting ? 'Saving...' : editingId ? 'Update' : 'Save'}
+          </button>
+        </form>
+      )}
+
+      <div style={styles.filterRow}>
+        <label style={styles.filterLabel}>Area:</label>
+        <select
+          style={styles.select}
+          value={areaFilter}
+          onChange={(e) => setAreaFilter(e.target.value)}
+        >
+          <option value="">All</option>
+          {areas.map((a) => (
+            <option key={a.id ?? a.name} value={a.id ?? ''}>
+              {a.name}
+            </option>
+          ))}
+        </select>
+      </div>
+
+      {loading ? (
+        <p style={sharedStyles.loading}>Loading...</p>
+      ) : photos.length === 0 ? (
+        <p style={sharedStyles.empty}>No photos recorded yet.</p>
+      ) : (
+        <table style={sharedStyles.table}>
+          <thead>
+            <tr>
+              <th style={sharedStyles.th}>Taken</th>
+              <th style={sharedStyles.th}>Area</th>
+              <th style={sharedStyles.th}>Caption</th>
+              <th style={sharedStyles.th}>File</th>
+              <th style={sharedStyles.th} />
+            </tr>
+          </thead>
+          <tbody>
+            {photos.map((p) => (
+              <tr key={p.id}>
+                <td style={{ ...sharedStyles.td, whiteSpace: 'nowrap' }}>{p.taken_on}</td>
+                <td style={sharedStyles.td}>{areaName(p.area_id)}</td>
+                <td style={sharedStyles.td}>{p.caption ?? '—'}</td>
+                <td style={{ ...sharedStyles.td, ...styles.path }}>{p.path}</td>
+                <td style={{ ...sharedStyles.td, whiteSpace: 'nowrap' }}>
+                  <button style={styles.linkBtn} onClick={() => handleEdit(p)}>
+                    Edit
+                  </button>
+                  <button
+                    style={{ ...styles.linkBtn, color: '#e53e3e' }}
+                    onClick={() => handleDelete(p.id)}
+                  >
+                    Delete
+                  </button>
+                </td>
+              </tr>
+            ))}
+          </tbody>
+        </table>
+      )}
+    </div>
+  );
+}
+
+const styles: Record<string, React.CSSProperties> = {
+  addBtn: {
+    padding: '0.5rem 1rem',
+    backgroundColor: '#38a169',
+    color: '#fff',
+    border: 'none',
+    borderRadius: 6,
+    cursor: 'pointer',
+    fontWeight: 600,
+    fontSize: '0.85rem',
+  },
+  formRow: { display: 'flex', gap: 12, flexWrap: 'wrap' as const },
+  label: {
+    display: 'flex',
+    flexDirection: 'column' as const,
+    gap: 4,
+    fontSize: '0.85rem',
+    color: '#4a5568',
+    marginBottom: '0.75rem',
+  },
+  input: {
+    padding: '0.4rem 0.6rem',
+    borderRadius: 6,
+    border: '1px solid #e2e8f0',
+    fontSize: '0.85rem',
+    fontFamily: 'inherit',
+  },
+  submitBtn: {
+    padding: '0.5rem 1.25rem',
+    backgroundColor: '#3182ce',
+    color: '#fff',
+    border: 'none',
+    borderRadius: 6,
+    cursor: 'pointer',
+    fontWeight: 600,
+  },
+  filterRow: {
+    display: 'flex',
+    alignItems: 'center',
+    gap: 8,
+    marginBottom: '1rem',
+  },
+  filterLabel: { fontSize: '0.85rem', color: '#4a5568' },
+  select: {
+    padding: '0.4rem 0.6rem',
+    borderRadius: 6,
+    border: '1px solid #e2e8f0',
+    fontSize: '0.85rem',
+  },
+  path: { fontFamily: 'monospace', fontSize: '0.8rem', wordBreak: 'break-all' as const },
+  linkBtn: {
+    background: 'none',
+    border: 'none',
+    color: '#3182ce',
+    cursor: 'pointer',
+    fontSize: '0.8rem',
+    padding: '0 4px',
+  },
+};
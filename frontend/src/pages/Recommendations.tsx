@@ -1,35 +1,75 @@
-import { useCallback, useEffect, useState } from 'react';
+import { useCallback, useEffect, useRef, useState } from 'react';
+import { useSearchParams } from 'react-router-dom';
 import {
+  errorMessage,
   getRecommendations,
+  listAreas,
+  markRecommendationsSeen,
   patchRecommendation,
 } from '../api/client';
+import ChangeBadge from '../components/ChangeBadge';
+import RuleExplainPanel from '../components/RuleExplainPanel';
+import { useToast } from '../components/toastContext';
 import { sharedStyles } from '../styles/shared';
-import type { Recommendation } from '../types';
+import type { LawnArea, Recommendation } from '../types';
 import { SEVERITY_COLORS, SEVERITY_SYMBOLS } from '../types';
+import { t } from '../utils/i18n';
+import { rememberUi, uiState } from '../utils/uiState';
 
 export default function Recommendations() {
   const [recs, setRecs] = useState<Recommendation[]>([]);
-  const [selected, setSelected] = useState<string | null>(null);
+  // A saved selection that no longer fires simply shows no detail pane
+  const [selected, setSelected] = useState<string | null>(
+    () => uiState().selected_recommendation ?? null
+  );
   const [error, setError] = useState<string | null>(null);
   const [loading, setLoading] = useState(true);
   const [actionInFlight, setActionInFlight] = useState<string | null>(null);
+  const [showExplain, setShowExplain] = useState(false);
+  const [areas, setAreas] = useState<LawnArea[]>([]);
+  const [areaId, setAreaId] = useState<number | undefined>(undefined);
+  const { notify } = useToast();
+  // ?focus=<id> from the dashboard alert shortcuts
+  const [searchParams] = useSearchParams();
+  const focus = searchParams.get('focus');
+  const focusApplied = useRef<string | null>(null);
 
   const fetchRecs = useCallback(async () => {
     try {
-      const data = await getRecommendations();
+      const data = await getRecommendations(areaId);
       setRecs(data);
       setError(null);
+      // Markers stay up for this visit; recording every severity shown (not just
+      // the changed ones) lets one that eased off flag again if it rises
+      if (data.length > 0) markRecommendationsSeen(data).catch(() => {});
     } catch (e) {
       setError(e instanceof Error ? e.message : 'Failed to load');
     } finally {
       setLoading(false);
     }
-  }, []);
+  }, [areaId]);
 
   useEffect(() => {
     fetchRecs();
   }, [fetchRecs]);
 
+  useEffect(() => {
+    if (!focus || focusApplied.current === focus || !recs.some((r) => r.id === focus)) return;
+    focusApplied.current = focus;
+    setSelected(focus);
+    document.getElementById(`rec-${focus}`)?.scrollIntoView({ block: 'nearest' });
+  }, [focus, recs]);
+
+  useEffect(() => {
+    rememberUi({ selected_recommendation: selected });
+  }, [selected]);
+
+  useEffect(() => {
+    listAreas()
+      .then(setAreas)
+      .catch((e) => notify(`Failed to load areas: ${errorMessage(e, 'request failed')}`, 'error'));
+  }, [notify]);
+
   const handleAction = async (
     id: string,
     action: 'addressed' | 'dismissed'
@@ -39,8 +79,12 @@ export default function Recommendations() {
       await patchRecommendation(id, { [action]: true });
       setRecs((prev) => prev.filter((r) => r.id !== id));
       if (selected === id) setSelected(null);
+      notify(
+        action === 'addressed' ? 'Marked as addressed' : 'Recommendation dismissed',
+        'success'
+      );
     } catch (e) {
-      setError(e instanceof Error ? e.message : 'Failed to update');
+      notify(e instanceof Error ? e.message : 'Failed to update', 'error');
     } finally {
       setActionInFlight(null);
     }
@@ -52,10 +96,32 @@ export default function Recommendations() {
 
   return (
     <div>
-      <h1 style={sharedStyles.pageTitle}>Recommendations</h1>
+      <div style={sharedStyles.headerRow}>
+        <h1 style={sharedStyles.pageTitle}>{t('Recommendations')}</h1>
+        {areas.length > 0 && (
+          <select
+            style={styles.areaSelect}
+            value={areaId ?? ''}
+            onChange={(e) => setAreaId(e.target.value ? Number(e.target.value) : undefined)}
+            aria-label="Lawn area"
+          >
+            <option value="">Whole lawn</option>
+            {areas.map((a) => (
+              <option key={a.id} value={a.id ?? ''}>
+                {a.name}
+              </option>
+            ))}
+          </select>
+        )}
+        <button style={styles.explainBtn} onClick={() => setShowExplain((v) => !v)}>
+          {showExplain ? 'Hide rule trace' : 'Why not? Explain rules'}
+        </button>
+      </div>
 
       {error && <div style={sharedStyles.error}>{error}</div>}
 
+      {showExplain && <RuleExplainPanel key={areaId ?? 'all'} areaId={areaId} />}
+
       {recs.length === 0 ? (
         <div style={styles.emptyGreen}>
           No active recommendations. Your lawn is looking good!
@@ -73,6 +139,7 @@ export default function Recommendations() {
               return (
                 <div
                   key={rec.id}
+                  id={`rec-${rec.id}`}
                   style={{
                     ...styles.listItem,
                     borderLeftColor: color,
@@ -85,6 +152,7 @@ export default function Recommendations() {
                       {symbol} {rec.severity}
                     </span>
                     <span style={styles.category}>{rec.category}</span>
+                    <ChangeBadge change={rec.change} />
                   </div>
                   <div style={styles.listTitle}>{rec.title}</div>
                   <div style={styles.listDesc}>{rec.description}</div>
@@ -161,6 +229,20 @@ export default function Recommendations() {
                 </div>
               )}
 
+              {selectedRec.conflict_note && (
+                <div style={styles.section}>
+                  <h3 style={sharedStyles.sectionTitle}>Conflict</h3>
+                  <p style={styles.sectionText}>{selectedRec.conflict_note}</p>
+                </div>
+              )}
+
+              {selectedRec.inventory_note && (
+                <div style={styles.section}>
+                  <h3 style={sharedStyles.sectionTitle}>Inventory</h3>
+                  <p style={styles.sectionText}>{selectedRec.inventory_note}</p>
+                </div>
+              )}
+
               {selectedRec.suggested_action && (
                 <div style={styles.section}>
                   <h3 style={sharedStyles.sectionTitle}>Suggested Action</h3>
@@ -178,6 +260,23 @@ export default function Recommendations() {
 }
 
 const styles: Record<string, React.CSSProperties> = {
+  areaSelect: {
+    marginLeft: 'auto',
+    marginRight: 8,
+    padding: '0.4rem 0.6rem',
+    borderRadius: 6,
+    border: '1px solid #e2e8f0',
+    fontSize: '0.85rem',
+  },
+  explainBtn: {
+    padding: '0.4rem 0.9rem',
+    backgroundColor: '#fff',
+    color: '#3182ce',
+    border: '1px solid #3182ce',
+    borderRadius: 6,
+    cursor: 'pointer',
+    fontSize: '0.85rem',
+  },
   emptyGreen: {
     color: '#48bb78',
     fontSize: '1rem',
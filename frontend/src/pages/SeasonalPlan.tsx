@@ -5,6 +5,7 @@ import type {
   PlannedActivity,
   ActivityStatus,
 } from '../types';
+import { t } from '../utils/i18n';
 
 const STATUS_COLORS: Record<ActivityStatus, string> = {
   Upcoming: '#3b82f6',
@@ -194,7 +195,7 @@ export default function SeasonalPlan() {
   if (loading) {
     return (
       <div style={styles.page}>
-        <h1 style={styles.title}>Seasonal Plan</h1>
+        <h1 style={styles.title}>{t('Seasonal Plan')}</h1>
         <p style={styles.loadingText}>
           Analyzing historical soil data to build your seasonal plan...
         </p>
@@ -205,7 +206,7 @@ export default function SeasonalPlan() {
   if (error) {
     return (
       <div style={styles.page}>
-        <h1 style={styles.title}>Seasonal Plan</h1>
+        <h1 style={styles.title}>{t('Seasonal Plan')}</h1>
         <div style={styles.error}>Failed to load seasonal plan: {error}</div>
       </div>
     );
@@ -233,7 +234,7 @@ export default function SeasonalPlan() {
     <div style={styles.page}>
       <div style={styles.header}>
         <div>
-          <h1 style={styles.title}>Seasonal Plan</h1>
+          <h1 style={styles.title}>{t('Seasonal Plan')}</h1>
           <p style={styles.subtitle}>
             Predicted activity windows based on {plan.data_years_used} years of
             soil temperature data
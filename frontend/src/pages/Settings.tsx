@@ -1,218 +1,376 @@
-import { useEffect, useState } from 'react';
-import { getProfile, updateProfile } from '../api/client';
+import { useCallback, useEffect, useState } from 'react';
+import { getLocation, getProfile, updateProfile } from '../api/client';
 import type { GrassType, IrrigationType, LawnProfile, SoilType } from '../types';
-import { GRASS_TYPE_LABELS } from '../types';
+import {
+  GRASS_TYPE_LABELS,
+  GRASS_TYPES,
+  IRRIGATION_TYPE_LABELS,
+  IRRIGATION_TYPES,
+  SOIL_TYPE_LABELS,
+  SOIL_TYPES,
+} from '../types';
+import ApplicationTypeSettings from '../components/ApplicationTypeSettings';
+import AreaSettings from '../components/AreaSettings';
+import DashboardLayoutSettings from '../components/DashboardLayoutSettings';
+import EnumSelect from '../components/EnumSelect';
+import FieldError from '../components/FieldError';
+import LocationSettings from '../components/LocationSettings';
+import LogViewer from '../components/LogViewer';
+import ProductCatalogSettings from '../components/ProductCatalogSettings';
+import ProfileSwitcher from '../components/ProfileSwitcher';
+import RuleBacktest from '../components/RuleBacktest';
+import RulesSettings from '../components/RulesSettings';
+import { useToast } from '../components/toastContext';
+import { useForm, type FieldErrors } from '../hooks/useForm';
+import { t } from '../utils/i18n';
+import { confirmDiscard } from '../utils/unsavedChanges';
 
-const GRASS_TYPES: GrassType[] = [
-  'KentuckyBluegrass',
-  'TallFescue',
-  'PerennialRyegrass',
-  'FineFescue',
-  'Bermuda',
-  'Zoysia',
-  'StAugustine',
-  'Mixed',
-];
+type SettingsTab =
+  | 'profile'
+  | 'areas'
+  | 'types'
+  | 'products'
+  | 'location'
+  | 'dashboard'
+  | 'rules'
+  | 'backtest'
+  | 'logs';
 
-const SOIL_TYPES: SoilType[] = [
-  'Clay',
-  'Loam',
-  'Sandy',
-  'SiltLoam',
-  'ClayLoam',
-  'SandyLoam',
-];
-
-const IRRIGATION_TYPES: IrrigationType[] = ['InGround', 'Hose', 'None'];
+interface ProfileForm {
+  name: string;
+  grassType: GrassType;
+  zone: string;
+  soilType: SoilType | '';
+  size: string;
+  irrigationType: IrrigationType | '';
+  station: string;
+  mowingHeight: string;
+  dormant: boolean;
+}
 
-const IRRIGATION_LABELS: Record<IrrigationType, string> = {
-  InGround: 'In-Ground',
-  Hose: 'Hose/Sprinkler',
-  None: 'None',
-};
+function toForm(p: LawnProfile | null): ProfileForm {
+  return {
+    name: p?.name ?? '',
+    grassType: p?.grass_type ?? 'TallFescue',
+    zone: p?.usda_zone ?? '',
+    soilType: p?.soil_type ?? '',
+    size: p?.lawn_size_sqft?.toString() ?? '',
+    irrigationType: p?.irrigation_type ?? '',
+    station: p?.noaa_station_wbanno?.toString() ?? '',
+    mowingHeight: p?.mowing_height_in?.toString() ?? '',
+    dormant: p?.dormant ?? false,
+  };
+}
 
-const SOIL_LABELS: Record<SoilType, string> = {
-  Clay: 'Clay',
-  Loam: 'Loam',
-  Sandy: 'Sandy',
-  SiltLoam: 'Silt Loam',
-  ClayLoam: 'Clay Loam',
-  SandyLoam: 'Sandy Loam',
-};
+/** Mirrors the backend's checks so mistakes show next to the field. */
+function validateProfile(v: ProfileForm): FieldErrors<ProfileForm> {
+  const errors: FieldErrors<ProfileForm> = {};
+  if (!v.name.trim()) errors.name = 'Give the lawn a name';
+  if (!/^(zone\s*)?(1[0-3]|[1-9])[ab]$/i.test(v.zone.trim())) {
+    errors.zone = 'Expected a zone from 1a to 13b, e.g. 7a';
+  }
+  if (v.size && !(parseFloat(v.size) > 0)) errors.size = 'Size must be a positive number';
+  if (v.station && !/^[1-9]\d*$/.test(v.station)) {
+    errors.station = 'Station IDs are positive whole numbers';
+  }
+  const height = parseFloat(v.mowingHeight);
+  if (v.mowingHeight && !(height >= 0.5 && height <= 6)) {
+    errors.mowingHeight = 'Mowing height must be between 0.5 and 6 inches';
+  }
+  return errors;
+}
 
 export default function Settings() {
+  const [tab, setTab] = useState<SettingsTab>('profile');
   const [profile, setProfile] = useState<LawnProfile | null>(null);
   const [error, setError] = useState<string | null>(null);
-  const [success, setSuccess] = useState(false);
+  const { notify } = useToast();
   const [loading, setLoading] = useState(true);
   const [saving, setSaving] = useState(false);
 
-  // Form state
-  const [name, setName] = useState('');
-  const [grassType, setGrassType] = useState<string>('TallFescue');
-  const [zone, setZone] = useState('');
-  const [soilType, setSoilType] = useState<string>('');
-  const [size, setSize] = useState('');
-  const [irrigationType, setIrrigationType] = useState<string>('');
+  const [locationZone, setLocationZone] = useState<string | null>(null);
+  const { values, set, errors, dirty, reset, fieldRef, handleSubmit } = useForm<ProfileForm>(
+    toForm(null),
+    validateProfile
+  );
+
+  const loadProfile = useCallback(
+    async (p: LawnProfile) => {
+      setProfile(p);
+      reset(toForm(p));
+      // Only a hint, so a missing forecast location shouldn't fail the page
+      const location = await getLocation().catch(() => null);
+      setLocationZone(location?.usda_zone ?? null);
+    },
+    [reset]
+  );
 
   useEffect(() => {
     (async () => {
       try {
-        const p = await getProfile();
-        setProfile(p);
-        setName(p.name);
-        setGrassType(p.grass_type);
-        setZone(p.usda_zone);
-        setSoilType(p.soil_type || '');
-        setSize(p.lawn_size_sqft?.toString() || '');
-        setIrrigationType(p.irrigation_type || '');
+        await loadProfile(await getProfile());
       } catch (e) {
         setError(e instanceof Error ? e.message : 'Failed to load profile');
       } finally {
         setLoading(false);
       }
     })();
-  }, []);
+  }, [loadProfile]);
 
-  const handleSave = async (e: React.FormEvent) => {
-    e.preventDefault();
+  const switchTab = (next: SettingsTab) => {
+    if (next !== tab && tab === 'profile' && !confirmDiscard()) return;
+    setTab(next);
+  };
+
+  const handleSave = handleSubmit(async (v) => {
     setSaving(true);
     setError(null);
-    setSuccess(false);
 
     try {
-      const validGrassType = GRASS_TYPES.includes(grassType as GrassType)
-        ? (grassType as GrassType)
-        : undefined;
-      const validSoilType =
-        soilType && SOIL_TYPES.includes(soilType as SoilType)
-          ? (soilType as SoilType)
-          : undefined;
-      const validIrrigationType =
-        irrigationType && IRRIGATION_TYPES.includes(irrigationType as IrrigationType)
-          ? (irrigationType as IrrigationType)
-          : undefined;
-
-      if (!validGrassType) {
-        setError('Please select a valid grass type');
-        setSaving(false);
-        return;
-      }
-
       const updated = await updateProfile({
-        name,
-        grass_type: validGrassType,
-        usda_zone: zone,
-        soil_type: validSoilType,
-        lawn_size_sqft: size ? parseFloat(size) : undefined,
-        irrigation_type: validIrrigationType,
+        name: v.name.trim(),
+        grass_type: v.grassType,
+        usda_zone: v.zone,
+        soil_type: v.soilType || undefined,
+        lawn_size_sqft: v.size ? parseFloat(v.size) : undefined,
+        irrigation_type: v.irrigationType || undefined,
+        noaa_station_wbanno: v.station ? parseInt(v.station, 10) : null,
+        mowing_height_in: v.mowingHeight ? parseFloat(v.mowingHeight) : null,
+        dormant: v.dormant,
       });
       setProfile(updated);
-      setSuccess(true);
-      setTimeout(() => setSuccess(false), 3000);
+      reset(toForm(updated));
+      notify('Profile saved successfully!', 'success');
     } catch (e) {
       setError(e instanceof Error ? e.message : 'Failed to save');
     } finally {
       setSaving(false);
     }
-  };
+  });
 
   if (loading)
     return <div style={{ color: '#718096', padding: '2rem' }}>Loading...</div>;
 
   return (
     <div>
-      <h1 style={styles.title}>Lawn Profile Settings</h1>
+      <h1 style={styles.title}>{t('Settings')}</h1>
 
-      {error && <div style={styles.error}>{error}</div>}
-      {success && <div style={styles.success}>Profile saved successfully!</div>}
-
-      <form onSubmit={handleSave} style={styles.form}>
-        <div style={styles.grid}>
-          <div>
-            <label style={styles.label}>Lawn Name</label>
-            <input
-              style={styles.input}
-              value={name}
-              onChange={(e) => setName(e.target.value)}
-              required
-            />
-          </div>
-          <div>
-            <label style={styles.label}>Grass Type</label>
-            <select
-              style={styles.input}
-              value={grassType}
-              onChange={(e) => setGrassType(e.target.value)}
-            >
-              {GRASS_TYPES.map((gt) => (
-                <option key={gt} value={gt}>
-                  {GRASS_TYPE_LABELS[gt]}
-                </option>
-              ))}
-            </select>
-          </div>
-          <div>
-            <label style={styles.label}>USDA Zone</label>
-            <input
-              style={styles.input}
-              value={zone}
-              onChange={(e) => setZone(e.target.value)}
-              placeholder="e.g. 7a"
-              required
-            />
-          </div>
-          <div>
-            <label style={styles.label}>Soil Type</label>
-            <select
-              style={styles.input}
-              value={soilType}
-              onChange={(e) => setSoilType(e.target.value)}
-            >
-              <option value="">Not specified</option>
-              {SOIL_TYPES.map((st) => (
-                <option key={st} value={st}>
-                  {SOIL_LABELS[st]}
-                </option>
-              ))}
-            </select>
-          </div>
-          <div>
-            <label style={styles.label}>Lawn Size (sqft)</label>
-            <input
-              type="number"
-              style={styles.input}
-              value={size}
-              onChange={(e) => setSize(e.target.value)}
-              placeholder="e.g. 5000"
-            />
-          </div>
-          <div>
-            <label style={styles.label}>Irrigation Type</label>
-            <select
-              style={styles.input}
-              value={irrigationType}
-              onChange={(e) => setIrrigationType(e.target.value)}
-            >
-              <option value="">Not specified</option>
-              {IRRIGATION_TYPES.map((it) => (
-                <option key={it} value={it}>
-                  {IRRIGATION_LABELS[it]}
-                </option>
-              ))}
-            </select>
-          </div>
-        </div>
-        <button type="submit" style={styles.saveBtn} disabled={saving}>
-          {saving ? 'Saving...' : 'Save Profile'}
+      <div style={styles.tabs}>
+        <button
+          style={tab === 'profile' ? styles.tabActive : styles.tab}
+          onClick={() => switchTab('profile')}
+        >
+          Lawn Profile
+        </button>
+        <button
+          style={tab === 'areas' ? styles.tabActive : styles.tab}
+          onClick={() => switchTab('areas')}
+        >
+          Areas
+        </button>
+        <button
+          style={tab === 'types' ? styles.tabActive : styles.tab}
+          onClick={() => switchTab('types')}
+        >
+          Application Types
+        </button>
+        <button
+          style={tab === 'products' ? styles.tabActive : styles.tab}
+          onClick={() => switchTab('products')}
+        >
+          Products
+        </button>
+        <button
+          style={tab === 'location' ? styles.tabActive : styles.tab}
+          onClick={() => switchTab('location')}
+        >
+          Location
         </button>
-      </form>
+        <button
+          style={tab === 'dashboard' ? styles.tabActive : styles.tab}
+          onClick={() => switchTab('dashboard')}
+        >
+          Dashboard
+        </button>
+        <button
+          style={tab === 'rules' ? styles.tabActive : styles.tab}
+          onClick={() => switchTab('rules')}
+        >
+          Rules
+        </button>
+        <button
+          style={tab === 'backtest' ? styles.tabActive : styles.tab}
+          onClick={() => switchTab('backtest')}
+        >
+          Backtest
+        </button>
+        <button
+          style={tab === 'logs' ? styles.tabActive : styles.tab}
+          onClick={() => switchTab('logs')}
+        >
+          Logs
+        </button>
+      </div>
+
+      {tab === 'logs' ? (
+        <LogViewer />
+      ) : tab === 'backtest' ? (
+        <RuleBacktest />
+      ) : tab === 'rules' ? (
+        <RulesSettings />
+      ) : tab === 'areas' ? (
+        <AreaSettings key={profile?.id} lawnSizeSqft={profile?.lawn_size_sqft ?? null} />
+      ) : tab === 'types' ? (
+        <ApplicationTypeSettings />
+      ) : tab === 'products' ? (
+        <ProductCatalogSettings />
+      ) : tab === 'location' ? (
+        <LocationSettings />
+      ) : tab === 'dashboard' ? (
+        <DashboardLayoutSettings />
+      ) : (
+        <>
+          {profile && <ProfileSwitcher active={profile} onSwitched={loadProfile} />}
+
+          {error && <div style={styles.error}>{error}</div>}
 
-      {profile && (
-        <div style={styles.meta}>
-          Created: {new Date(profile.created_at).toLocaleDateString()} | Last
-          updated: {new Date(profile.updated_at).toLocaleString()}
-        </div>
+          <form onSubmit={handleSave} style={styles.form} noValidate>
+            <div style={styles.grid}>
+              <div>
+                <label style={styles.label}>Lawn Name</label>
+                <input
+                  ref={fieldRef('name')}
+                  style={errors.name ? styles.inputInvalid : styles.input}
+                  value={values.name}
+                  onChange={(e) => set('name', e.target.value)}
+                  aria-invalid={errors.name ? true : undefined}
+                />
+                <FieldError message={errors.name} />
+              </div>
+              <div>
+                <label style={styles.label}>Grass Type</label>
+                <EnumSelect
+                  style={styles.input}
+                  options={GRASS_TYPES}
+                  labels={GRASS_TYPE_LABELS}
+                  value={values.grassType}
+                  onChange={(v) => {
+                    if (v) set('grassType', v);
+                  }}
+                />
+              </div>
+              <div>
+                <label style={styles.label}>USDA Zone</label>
+                <input
+                  ref={fieldRef('zone')}
+                  style={errors.zone ? styles.inputInvalid : styles.input}
+                  value={values.zone}
+                  onChange={(e) => set('zone', e.target.value)}
+                  placeholder="e.g. 7a"
+                  aria-invalid={errors.zone ? true : undefined}
+                />
+                <FieldError message={errors.zone} />
+                {locationZone && values.zone.trim().toLowerCase() !== locationZone && (
+                  <div style={styles.zoneHint}>
+                    Forecast location is in zone {locationZone}.{' '}
+                    <button
+                      type="button"
+                      style={styles.zoneHintBtn}
+                      onClick={() => set('zone', locationZone)}
+                    >
+                      Use {locationZone}
+                    </button>
+                  </div>
+                )}
+              </div>
+              <div>
+                <label style={styles.label}>Soil Type</label>
+                <EnumSelect
+                  style={styles.input}
+                  options={SOIL_TYPES}
+                  labels={SOIL_TYPE_LABELS}
+                  value={values.soilType}
+                  onChange={(v) => set('soilType', v)}
+                  emptyLabel="Not specified"
+                />
+              </div>
+              <div>
+                <label style={styles.label}>Lawn Size (sqft)</label>
+                <input
+                  ref={fieldRef('size')}
+                  type="number"
+                  style={errors.size ? styles.inputInvalid : styles.input}
+                  value={values.size}
+                  onChange={(e) => set('size', e.target.value)}
+                  placeholder="e.g. 5000"
+                  aria-invalid={errors.size ? true : undefined}
+                />
+                <FieldError message={errors.size} />
+              </div>
+              <div>
+                <label style={styles.label}>Irrigation Type</label>
+                <EnumSelect
+                  style={styles.input}
+                  options={IRRIGATION_TYPES}
+                  labels={IRRIGATION_TYPE_LABELS}
+                  value={values.irrigationType}
+                  onChange={(v) => set('irrigationType', v)}
+                  emptyLabel="Not specified"
+                />
+              </div>
+              <div>
+                <label style={styles.label}>USCRN Station (WBANNO)</label>
+                <input
+                  ref={fieldRef('station')}
+                  type="number"
+                  style={errors.station ? styles.inputInvalid : styles.input}
+                  value={values.station}
+                  onChange={(e) => set('station', e.target.value)}
+                  placeholder="Default station"
+                  aria-invalid={errors.station ? true : undefined}
+                />
+                <FieldError message={errors.station} />
+              </div>
+              <div>
+                <label style={styles.label}>Target Mowing Height (in)</label>
+                <input
+                  ref={fieldRef('mowingHeight')}
+                  type="number"
+                  step="0.25"
+                  style={errors.mowingHeight ? styles.inputInvalid : styles.input}
+                  value={values.mowingHeight}
+                  onChange={(e) => set('mowingHeight', e.target.value)}
+                  placeholder="Grass type default"
+                  aria-invalid={errors.mowingHeight ? true : undefined}
+                />
+                <FieldError message={errors.mowingHeight} />
+              </div>
+            </div>
+            <label style={styles.checkLabel}>
+              <input
+                type="checkbox"
+                checked={values.dormant}
+                onChange={(e) => set('dormant', e.target.checked)}
+              />{' '}
+              Lawn is dormant
+              <span style={styles.checkHint}>
+                Pauses watering, fertilizer and mowing recommendations. They also pause on
+                their own when soil temperature says the lawn has shut down.
+              </span>
+            </label>
+            <button type="submit" style={styles.saveBtn} disabled={saving}>
+              {saving ? 'Saving...' : 'Save Profile'}
+            </button>
+            {dirty && <span style={styles.unsaved}>Unsaved changes</span>}
+          </form>
+
+          {profile && (
+            <div style={styles.meta}>
+              Created: {new Date(profile.created_at).toLocaleDateString()} | Last
+              updated: {new Date(profile.updated_at).toLocaleString()}
+            </div>
+          )}
+        </>
       )}
     </div>
   );
@@ -220,6 +378,26 @@ export default function Settings() {
 
 const styles: Record<string, React.CSSProperties> = {
   title: { margin: '0 0 1rem', fontSize: '1.5rem', color: '#1a202c' },
+  tabs: { display: 'flex', gap: 4, marginBottom: '1rem', borderBottom: '1px solid #e2e8f0' },
+  tab: {
+    padding: '0.5rem 1rem',
+    background: 'none',
+    border: 'none',
+    borderBottom: '2px solid transparent',
+    color: '#718096',
+    cursor: 'pointer',
+    fontSize: '0.9rem',
+  },
+  tabActive: {
+    padding: '0.5rem 1rem',
+    background: 'none',
+    border: 'none',
+    borderBottom: '2px solid #3182ce',
+    color: '#2d3748',
+    cursor: 'pointer',
+    fontSize: '0.9rem',
+    fontWeight: 600,
+  },
   error: {
     padding: '0.5rem 1rem',
     backgroundColor: '#fed7d7',
@@ -228,14 +406,6 @@ const styles: Record<string, React.CSSProperties> = {
     marginBottom: '1rem',
     fontSize: '0.85rem',
   },
-  success: {
-    padding: '0.5rem 1rem',
-    backgroundColor: '#c6f6d5',
-    color: '#276749',
-    borderRadius: 6,
-    marginBottom: '1rem',
-    fontSize: '0.85rem',
-  },
   form: {
     backgroundColor: '#fff',
     borderRadius: 8,
@@ -249,6 +419,15 @@ const styles: Record<string, React.CSSProperties> = {
     gap: '1rem',
     marginBottom: '1rem',
   },
+  zoneHint: { fontSize: '0.8rem', color: '#c05621', marginTop: 4 },
+  zoneHintBtn: {
+    background: 'none',
+    border: 'none',
+    color: '#3182ce',
+    cursor: 'pointer',
+    fontSize: '0.8rem',
+    padding: 0,
+  },
   label: {
     display: 'block',
     fontSize: '0.8rem',
@@ -263,6 +442,16 @@ const styles: Record<string, React.CSSProperties> = {
     border: '1px solid #e2e8f0',
     fontSize: '0.9rem',
   },
+  inputInvalid: {
+    width: '100%',
+    padding: '0.5rem 0.75rem',
+    borderRadius: 6,
+    border: '1px solid #fc8181',
+    fontSize: '0.9rem',
+  },
+  checkLabel: { display: 'block', fontSize: '0.9rem', color: '#2d3748', marginBottom: '1rem' },
+  checkHint: { display: 'block', fontSize: '0.8rem', color: '#718096', marginTop: 2 },
+  unsaved: { marginLeft: 12, fontSize: '0.8rem', color: '#c05621' },
   saveBtn: {
     padding: '0.6rem 2rem',
     backgroundColor: '#3182ce',
@@ -13,6 +13,7 @@ import type {
 } from '../types';
 import { NUTRIENT_LEVEL_COLORS } from '../types';
 import { sharedStyles } from '../styles/shared';
+import { t } from '../utils/i18n';
 
 export default function SoilTests() {
   const [tests, setTests] = useState<SoilTest[]>([]);
@@ -160,7 +161,7 @@ export default function SoilTests() {
   return (
     <div>
       <div style={sharedStyles.headerRow}>
-        <h1 style={sharedStyles.pageTitle}>Soil Tests</h1>
+        <h1 style={sharedStyles.pageTitle}>{t('Soil Tests')}</h1>
         <button onClick={() => { if (formOpen) { resetForm(); setFormOpen(false); } else { setFormOpen(true); } }} style={styles.addBtn}>
           {formOpen ? 'Cancel' : '+ Add Soil Test'}
         </button>
@@ -350,7 +351,7 @@ function SoilTestRecommendationsPanel({ summary }: { summary: SoilTestSummary })
   return (
     <div style={{ marginBottom: '1.5rem' }}>
       <h2 style={sharedStyles.sectionTitle}>Recommendations</h2>
-      <div style={{ display: 'grid', gap: '1rem', gridTemplateColumns: 'repeat(auto-fit, minmax(320px, 1fr))' }}>
+      <div style={{ display: 'grid', gap: '1rem', gridTemplateColumns: 'repeat(auto-fit, minmax(min(320px, 100%), 1fr))' }}>
 
         {ph_recommendation && (
           <div style={sharedStyles.card}>
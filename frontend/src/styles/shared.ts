@@ -1,25 +1,29 @@
 import type React from 'react';
 import type { ApplicationType } from '../types';
-import { APPLICATION_TYPE_COLORS } from '../types';
+import { typeColor } from '../utils/typeRegistry';
 
-// Cache for computed badge styles keyed by application type
-const badgeStyleCache = new Map<ApplicationType, React.CSSProperties>();
+// Cache for computed badge styles keyed by color
+const badgeStyleCache = new Map<string, React.CSSProperties>();
 
-/** Returns a cached badge style for a given application type, avoiding recreation every render. */
+/**
+ * Returns a cached badge style for a given application type (or the custom
+ * type it was logged as), avoiding recreation every render.
+ */
 export function appTypeBadgeStyle(
   baseBadge: React.CSSProperties,
-  appType: ApplicationType
+  appType: ApplicationType,
+  customTypeId?: number | null
 ): React.CSSProperties {
-  let cached = badgeStyleCache.get(appType);
+  const color = typeColor({ application_type: appType, custom_type_id: customTypeId });
+  let cached = badgeStyleCache.get(color);
   if (!cached) {
-    const color = APPLICATION_TYPE_COLORS[appType];
     cached = {
       ...baseBadge,
       backgroundColor: color + '22',
       color,
       borderColor: color,
     };
-    badgeStyleCache.set(appType, cached);
+    badgeStyleCache.set(color, cached);
   }
   return cached;
 }
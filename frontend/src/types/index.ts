@@ -8,6 +8,14 @@ export interface LawnProfile {
   soil_type: SoilType | null;
   lawn_size_sqft: number | null;
   irrigation_type: IrrigationType | null;
+  /** Own forecast location; null follows the global one. */
+  location: Location | null;
+  /** Own USCRN station; null uses NOAA_STATION_WBANNO. */
+  noaa_station_wbanno: number | null;
+  /** Marked dormant by hand; rules also detect dormancy from soil temp. */
+  dormant: boolean;
+  /** Target height of cut in inches; null uses the grass type's default. */
+  mowing_height_in: number | null;
   created_at: string;
   updated_at: string;
 }
@@ -32,6 +40,98 @@ export type SoilType =
 
 export type IrrigationType = 'InGround' | 'Hose' | 'None';
 
+export type SunExposure = 'FullSun' | 'PartShade' | 'FullShade';
+
+export interface LawnArea {
+  id: number | null;
+  lawn_profile_id: number;
+  name: string;
+  size_sqft: number | null;
+  sun_exposure: SunExposure;
+  /** Overrides the profile's grass type for this area's recommendations. */
+  grass_type: GrassType | null;
+  grass_mix: string | null;
+  created_at: string;
+}
+
+export type ProductUnit = 'Pounds' | 'Ounces' | 'Gallons' | 'FluidOunces';
+
+export const PRODUCT_UNIT_LABELS: Record<ProductUnit, string> = {
+  Pounds: 'lb',
+  Ounces: 'oz',
+  Gallons: 'gal',
+  FluidOunces: 'fl oz',
+};
+
+/** An inventory item with what's left after applications since its count. */
+export interface InventoryItem {
+  id: number | null;
+  lawn_profile_id: number;
+  product_name: string;
+  application_type: ApplicationType | null;
+  unit: ProductUnit;
+  package_size: number | null;
+  /** Amount on hand as of counted_on. */
+  quantity: number;
+  counted_on: string;
+  rate_per_1000sqft: number | null;
+  notes: string | null;
+  created_at: string;
+  used_since_count: number;
+  remaining: number;
+  /** Amount one application to the whole lawn takes, if a rate is known. */
+  per_application: number | null;
+}
+
+export type EquipmentKind = 'Mower' | 'Spreader' | 'Sprayer' | 'Other';
+
+export type EquipmentTask =
+  | 'BladeSharpening'
+  | 'OilChange'
+  | 'AirFilter'
+  | 'SparkPlug'
+  | 'Winterize'
+  | 'Calibration'
+  | 'Cleaning'
+  | 'Other';
+
+export const EQUIPMENT_TASK_LABELS: Record<EquipmentTask, string> = {
+  BladeSharpening: 'Blade Sharpening',
+  OilChange: 'Oil Change',
+  AirFilter: 'Air Filter',
+  SparkPlug: 'Spark Plug',
+  Winterize: 'Winterize',
+  Calibration: 'Calibration',
+  Cleaning: 'Cleaning',
+  Other: 'Other',
+};
+
+export interface MaintenanceEvent {
+  id: number | null;
+  equipment_id: number;
+  task: EquipmentTask;
+  performed_on: string;
+  notes: string | null;
+  created_at: string;
+}
+
+/** A piece of equipment with usage from logged applications. */
+export interface Equipment {
+  id: number | null;
+  lawn_profile_id: number;
+  name: string;
+  kind: EquipmentKind;
+  hours_per_use: number | null;
+  starting_hours: number;
+  notes: string | null;
+  created_at: string;
+  uses: number;
+  /** Estimated hour meter reading; null when the piece has no hours. */
+  hours: number | null;
+  /** Newest first. */
+  maintenance: MaintenanceEvent[];
+}
+
 export interface Application {
   id: number | null;
   lawn_profile_id: number;
@@ -47,9 +147,71 @@ export interface Application {
   potassium_pct: number | null;
   plant_id?: number | null;
   follow_up_date?: string | null;
+  /** Lawn area this covered; absent means the whole lawn. */
+  area_id?: number | null;
+  /** Mower, spreader or sprayer used. */
+  equipment_id?: number | null;
+  /** Custom type it was logged as; application_type is that type's base. */
+  custom_type_id?: number | null;
   created_at: string;
 }
 
+/** User-defined application type: a named, colored variant of a built-in. */
+export interface CustomApplicationType {
+  id: number | null;
+  name: string;
+  /** #rrggbb */
+  color: string;
+  base_type: ApplicationType;
+  created_at: string;
+}
+
+export type ReEntry = 'until_dry' | 'after_watering_in';
+
+/** Label facts for one product, as a label file lists them. Blank durations
+ * mean no restriction. */
+export interface LabelEntry {
+  name: string;
+  active_ingredient: string;
+  keywords: string[];
+  frac_group: string | null;
+  irac_group: string | null;
+  rate_per_1000sqft: number | null;
+  rate_unit: string | null;
+  /** Irrigation to activate it and the days it must arrive within; both or neither. */
+  water_in_inches: number | null;
+  water_in_days: number | null;
+  reentry: ReEntry | null;
+  /** Pre-emergent residual: days before seed will establish. */
+  residual_days: number | null;
+  seeded_turf_days: number | null;
+}
+
+export interface LabelProduct extends LabelEntry {
+  id: number;
+  /** Added or changed by hand; imports leave it alone. */
+  user_edited: boolean;
+  updated_at: string;
+}
+
+export type LabelFormat = 'yaml' | 'csv' | 'json';
+
+export interface LabelImportReport {
+  added: string[];
+  updated: string[];
+  /** Listed in the file but edited by hand, so left as they are. */
+  kept: string[];
+  unchanged: number;
+}
+
+/** One choice in the merged built-in + custom type list. */
+export interface TypeEntry {
+  label: string;
+  base_type: ApplicationType;
+  custom_type_id?: number;
+  color?: string;
+}
+
 export type ApplicationType =
   | 'PreEmergent'
   | 'PostEmergent'
@@ -64,6 +226,8 @@ export type ApplicationType =
   | 'Sulfur'
   | 'Wetting'
   | 'Mowing'
+  | 'Scouting'
+  | 'Irrigation'
   | 'Other'
   | 'Pruning'
   | 'PlantFertilizer'
@@ -88,6 +252,7 @@ export const UNIVERSAL_APPLICATION_TYPES: ApplicationType[] = [
   'Fungicide',
   'Insecticide',
   'Wetting',
+  'Irrigation',
   'Other',
 ];
 
@@ -101,6 +266,7 @@ export const TURF_ONLY_APPLICATION_TYPES: ApplicationType[] = [
   'Lime',
   'Sulfur',
   'Mowing',
+  'Scouting',
 ];
 
 export function isPlantRequiredApplicationType(t: ApplicationType): boolean {
@@ -185,6 +351,8 @@ export interface WeatherSnapshot {
   ambient_temp_f: number | null;
   humidity_percent: number | null;
   soil_moisture: number | null;
+  /** Rebuilt from station history (daily means) rather than recorded at the time. */
+  reconstructed?: boolean;
 }
 
 export interface EnvironmentalSummary {
@@ -194,11 +362,121 @@ export interface EnvironmentalSummary {
   humidity_7day_avg: number | null;
   precipitation_7day_total_mm: number | null;
   soil_temp_trend: Trend;
+  soil_moisture_trend: Trend;
+  ambient_temp_trend: Trend;
   last_updated: string | null;
   forecast: WeatherForecast | null;
   gdd_base50_ytd: number | null;
+  frost_years?: FrostYear[];
+  soil_temp_anomaly?: SoilTempAnomaly;
   soil_temp_predictions?: SoilTempPrediction[];
   predicted_threshold_crossings?: ThresholdPrediction[];
+  stress_index?: StressSnapshot;
+  drought?: DroughtStatus;
+  air_quality?: AirQuality;
+  derived: DerivedMetrics;
+  data_quality: DataQuality;
+  coverage: AggregateCoverage;
+  freshness: Freshness;
+}
+
+/** Share (0-1) of expected hourly samples behind each 7-day aggregate; null when unknown. */
+export interface FrostYear {
+  year: number;
+  last_spring: string | null;
+  first_fall: string | null;
+}
+
+/** 7-day soil temp average against the same days in prior years of the archive. */
+export interface SoilTempAnomaly {
+  normal_f: number;
+  /** Positive when running warm. */
+  anomaly_f: number;
+  years: number;
+  /** Days the spring windows open ahead of the calendar; negative when behind. */
+  lead_days: number;
+}
+
+export type FrostDateSource = 'StationHistory' | 'Zone';
+
+/** When one frost falls this year: a normal spread around `median`. */
+export interface FrostDistribution {
+  median: string;
+  std_dev_days: number;
+  /** Years of station history behind it; 0 for a zone estimate. */
+  years: number;
+  source: FrostDateSource;
+}
+
+export interface FrostDates {
+  year: number;
+  last_spring: FrostDistribution | null;
+  first_fall: FrostDistribution | null;
+  spring_advice: string | null;
+  fall_advice: string | null;
+}
+
+/** Gauge scale; `adaptive` when fitted to the station's 5th-95th percentiles. */
+export interface GaugeRange {
+  min: number;
+  max: number;
+  adaptive: boolean;
+  samples: number;
+}
+
+export interface GaugeRanges {
+  soil_temp_f: GaugeRange;
+  ambient_temp_f: GaugeRange;
+  humidity_percent: GaugeRange;
+  soil_moisture_percent: GaugeRange;
+  history_years: number;
+}
+
+export interface AggregateCoverage {
+  soil_temp: number | null;
+  ambient_temp: number | null;
+  humidity: number | null;
+  precipitation: number | null;
+}
+
+/**
+ * When each current value was observed. Values older than `max_age_hours`
+ * (`STALE_DATA_HOURS`) are stale and rules treat them as missing.
+ */
+export interface Freshness {
+  max_age_hours: number | null;
+  soil_temp: string | null;
+  soil_moisture: string | null;
+  ambient_temp: string | null;
+  humidity: string | null;
+  precipitation: string | null;
+}
+
+/** What the quality pass did to the 7-day readings. */
+export interface DataQuality {
+  rejected: number;
+  suspect: number;
+  interpolated: number;
+}
+
+export interface DerivedMetrics {
+  dew_point_f: number | null;
+  dew_point_spread_f: number | null;
+  wet_bulb_f: number | null;
+  vpd_kpa: number | null;
+  leaf_wetness_hours_24h: number | null;
+  overnight_wetness: NightWetness[];
+}
+
+/** Forecast leaf wetness for one night (6 PM to 8 AM). */
+export interface NightWetness {
+  /** Date the night starts on. */
+  evening: string;
+  wet_hours: number;
+  /** Hours the forecast covers; under 14 for the night under way. */
+  forecast_hours: number;
+  max_humidity_percent: number;
+  min_dew_point_spread_f: number;
 }
 
 export interface EnvironmentalReading {
@@ -217,6 +495,8 @@ export interface EnvironmentalReading {
   ambient_temp_f: number | null;
   humidity_percent: number | null;
   precipitation_mm: number | null;
+  /** A value was dropped as an implausible spike. */
+  suspect: boolean;
 }
 
 export type Trend = 'Rising' | 'Falling' | 'Stable' | 'Unknown';
@@ -226,6 +506,13 @@ export interface WeatherForecast {
   location: ForecastLocation;
   hourly: ForecastPoint[];
   daily_summary: DailyForecast[];
+  /** Next hour of rain, minute by minute; only with One Call. */
+  minutely?: MinutelyPrecipitation[];
+}
+
+export interface MinutelyPrecipitation {
+  timestamp: string;
+  precipitation_mm: number;
 }
 
 export interface ForecastLocation {
@@ -241,6 +528,7 @@ export interface ForecastPoint {
   feels_like_f: number;
   humidity_percent: number;
   precipitation_mm: number;
+  snow_mm: number;
   precipitation_prob: number;
   wind_speed_mph: number;
   wind_gust_mph: number | null;
@@ -254,6 +542,7 @@ export interface DailyForecast {
   low_temp_f: number;
   avg_humidity: number;
   total_precipitation_mm: number;
+  total_snow_mm: number;
   max_precipitation_prob: number;
   dominant_condition: string;
   avg_wind_speed_mph: number;
@@ -269,13 +558,27 @@ export interface Recommendation {
   explanation: string;
   data_points: DataPoint[];
   suggested_action: string | null;
+  conflict_note: string | null;
+  /** Set when inventory on hand won't cover the application. */
+  inventory_note: string | null;
+  /** New or more severe since last viewed on the Recommendations page. */
+  change: RecommendationChange | null;
   created_at: string;
   dismissed: boolean;
   addressed: boolean;
 }
 
+export type RecommendationChange = 'New' | 'Escalated';
+
 export type Severity = 'Info' | 'Advisory' | 'Warning' | 'Critical';
 
+export interface SeverityCounts {
+  critical: number;
+  warning: number;
+  advisory: number;
+  info: number;
+}
+
 export interface DataPoint {
   label: string;
   value: string;
@@ -286,6 +589,28 @@ export interface ConnectionStatus {
   soildata: boolean;
   homeassistant: boolean;
   openweathermap: boolean;
+  /** Every sensor source by key (`uscrn`, `homeassistant`, ...). */
+  sources?: Record<string, boolean>;
+  /** Sources (by key, plus `openweathermap`) whose last check timed out. */
+  timed_out?: string[];
+  /** No check has finished since the server started; the flags are placeholders. */
+  pending?: boolean;
+}
+
+/** UI half of the server's localization catalog. */
+export interface UiCatalog {
+  locale: string;
+  messages: Record<string, string>;
+}
+
+export interface UiState {
+  last_path?: string | null;
+  applications_type?: ApplicationType | null;
+  applications_custom_type?: number | null;
+  applications_scope?: 'all' | 'turf' | 'landscape' | null;
+  calendar_month?: string | null;
+  calendar_view?: 'month' | 'week' | null;
+  selected_recommendation?: string | null;
 }
 
 export interface HealthResponse {
@@ -298,15 +623,118 @@ export interface HealthResponse {
 export interface DashboardResponse {
   profile: LawnProfile;
   environmental: EnvironmentalSummary;
+  /** The most severe active recommendations, most severe first. */
   recommendations: Recommendation[];
+  /** Every active recommendation by severity, including ones not shown. */
+  alert_counts: SeverityCounts;
+  total_alerts: number;
   recent_applications: Application[];
   connections: ConnectionStatus;
+  /** OpenWeatherMap calls today against the daily quota. */
+  openweathermap_usage: QuotaUsage | null;
+  windows: WindowProjection[];
+  germination: GerminationStatus | null;
+  /** Panels to show, in order. */
+  layout: DashboardPanel[];
+}
+
+export interface QuotaUsage {
+  /** UTC day being counted. */
+  day: string;
+  calls: number;
+  /** 0 when unlimited. */
+  limit: number;
+  /** Forecast refresh interval in effect. */
+  refresh_minutes: number;
+  /** Refreshes spaced out to stay under the quota. */
+  stretched: boolean;
+}
+
+export type DashboardPanel =
+  | 'gauges'
+  | 'forecast'
+  | 'germination'
+  | 'gdd'
+  | 'stress_index'
+  | 'drought'
+  | 'nitrogen_budget'
+  | 'soil_temp_forecast'
+  | 'windows'
+  | 'alerts'
+  | 'recent_applications';
+
+export interface DashboardLayout {
+  panels: DashboardPanel[];
+  /** Set by `DASHBOARD_PANELS`; can't be changed from Settings. */
+  locked: boolean;
+}
+
+export interface GerminationStatus {
+  seeded_on: string;
+  day: number;
+  tracking_days: number;
+  herbicide_safe_on: string;
 }
 
 export interface CalendarResponse {
   year: number;
   month: number;
   days: Record<string, Application[]>;
+  observations: Record<string, Observation[]>;
+}
+
+/** One day of the Calendar's week agenda. */
+export interface AgendaDay {
+  date: string;
+  /** Dated this day; after today these are planned. */
+  applications: Application[];
+  /** Earlier applications whose follow-up is due this day. */
+  follow_ups: Application[];
+  forecast: DailyForecast | null;
+}
+
+export interface AgendaResponse {
+  start: string;
+  days: AgendaDay[];
+}
+
+// Season journal
+
+export type ObservationCategory = 'Weeds' | 'Disease' | 'Insect' | 'Traffic' | 'General';
+
+export const OBSERVATION_CATEGORIES: ObservationCategory[] = [
+  'Weeds',
+  'Disease',
+  'Insect',
+  'Traffic',
+  'General',
+];
+
+export const OBSERVATION_CATEGORY_COLORS: Record<ObservationCategory, string> = {
+  Weeds: '#ca8a04',
+  Disease: '#d946ef',
+  Insect: '#ef4444',
+  Traffic: '#0ea5e9',
+  General: '#64748b',
+};
+
+export interface Observation {
+  id: number;
+  lawn_profile_id: number;
+  observed_at: string;
+  category: ObservationCategory;
+  notes: string;
+  created_at: string;
+}
+
+export interface Photo {
+  id: number;
+  lawn_profile_id: number;
+  area_id: number | null;
+  taken_on: string;
+  path: string;
+  caption: string | null;
+  created_at: string;
 }
 
 // GDD types
@@ -329,6 +757,8 @@ export interface CrabgrassModel {
   germination_threshold: number;
   current_gdd: number;
   status: CrabgrassStatus;
+  /** Estimated share of the spring flush already germinated, 0-100. */
+  germination_pct: number;
   estimated_germination_date: string | null;
 }
 
@@ -398,6 +828,115 @@ export const CRABGRASS_STATUS_COLORS: Record<CrabgrassStatus, string> = {
   PostGermination: '#ef4444',
 };
 
+// U.S. Drought Monitor
+
+export type DroughtCategory = 'None' | 'D0' | 'D1' | 'D2' | 'D3' | 'D4';
+
+export const DROUGHT_CATEGORY_LABELS: Record<DroughtCategory, string> = {
+  None: 'No Drought',
+  D0: 'Abnormally Dry',
+  D1: 'Moderate Drought',
+  D2: 'Severe Drought',
+  D3: 'Extreme Drought',
+  D4: 'Exceptional Drought',
+};
+
+/** USDM map palette. */
+export const DROUGHT_CATEGORY_COLORS: Record<DroughtCategory, string> = {
+  None: '#48bb78',
+  D0: '#d4c600',
+  D1: '#e8a000',
+  D2: '#e06000',
+  D3: '#c00000',
+  D4: '#730000',
+};
+
+export interface DroughtCoverage {
+  d0: number;
+  d1: number;
+  d2: number;
+  d3: number;
+  d4: number;
+}
+
+export interface DroughtStatus {
+  category: DroughtCategory;
+  worst_category: DroughtCategory;
+  coverage: DroughtCoverage;
+  map_date: string;
+  county_fips: string;
+  county: string;
+}
+
+// Air quality (OWM Air Pollution API)
+
+export type AirQualityLevel = 'Good' | 'Fair' | 'Moderate' | 'Poor' | 'VeryPoor';
+
+export const AIR_QUALITY_LABELS: Record<AirQualityLevel, string> = {
+  Good: 'Good',
+  Fair: 'Fair',
+  Moderate: 'Moderate',
+  Poor: 'Poor',
+  VeryPoor: 'Very Poor',
+};
+
+export const AIR_QUALITY_COLORS: Record<AirQualityLevel, string> = {
+  Good: '#48bb78',
+  Fair: '#a3c43a',
+  Moderate: '#eab308',
+  Poor: '#f97316',
+  VeryPoor: '#ef4444',
+};
+
+export interface AirQuality {
+  level: AirQualityLevel;
+  pm2_5: number | null;
+  pm10: number | null;
+  o3: number | null;
+  observed_at: string;
+}
+
+// Turf stress index
+
+export type StressLevel = 'Low' | 'Moderate' | 'High' | 'Severe';
+
+export const STRESS_LEVEL_COLORS: Record<StressLevel, string> = {
+  Low: '#48bb78',
+  Moderate: '#eab308',
+  High: '#f97316',
+  Severe: '#ef4444',
+};
+
+export interface StressComponents {
+  heat: number;
+  drought: number;
+  disease: number;
+  traffic: number;
+}
+
+export interface DailyStress {
+  date: string;
+  score: number;
+  level: StressLevel;
+  components: StressComponents;
+  high_temp_f: number;
+  et_mm: number;
+  water_mm: number;
+  water_deficit_mm: number;
+}
+
+export interface StressIndexSummary {
+  current: DailyStress | null;
+  sustained_high_days: number;
+  days: DailyStress[];
+}
+
+export interface StressSnapshot {
+  score: number;
+  level: StressLevel;
+  sustained_high_days: number;
+}
+
 // Soil Temperature Prediction types
 
 export type PredictionConfidence = 'High' | 'Medium' | 'Low';
@@ -435,6 +974,18 @@ export interface SoilTempForecast {
   generated_at: string;
 }
 
+export type WindowPhase = 'Opens' | 'Closes';
+
+export interface WindowProjection {
+  window: string;
+  phase: WindowPhase;
+  date: string;
+  days: number;
+  trigger: string;
+  confidence: PredictionConfidence;
+  projected: boolean;
+}
+
 export const PREDICTION_CONFIDENCE_COLORS: Record<PredictionConfidence, string> = {
   High: '#22c55e',
   Medium: '#eab308',
@@ -561,8 +1112,258 @@ export const NUTRIENT_LEVEL_COLORS: Record<NutrientLevel, string> = {
   High: '#eab308',
 };
 
+// Rule explain types
+
+export type GateKind =
+  | 'NotApplicable'
+  | 'OutOfSeason'
+  | 'AlreadyApplied'
+  | 'MissingData'
+  | 'ConditionsNotMet'
+  | 'MoistureLatchOff'
+  | 'Disabled';
+
+export interface Gate {
+  kind: GateKind;
+  detail?: string;
+}
+
+export interface RuleTrace {
+  rule: string;
+  fired: boolean;
+  recommendation_id: string | null;
+  title: string | null;
+  severity: Severity | null;
+  gate: Gate | null;
+  suppressed_by: 'dismissed' | 'addressed' | 'seedlings' | 'turf stress' | null;
+}
+
+export const GATE_LABELS: Record<GateKind, string> = {
+  NotApplicable: 'Not applicable',
+  OutOfSeason: 'Out of season',
+  AlreadyApplied: 'Already applied',
+  MissingData: 'Missing data',
+  ConditionsNotMet: 'Conditions not met',
+  MoistureLatchOff: 'Moisture alert latched off',
+  Disabled: 'Disabled in settings',
+};
+
+export interface RuleStatus {
+  rule: string;
+  enabled: boolean;
+  locked: boolean;
+}
+
+/** How eagerly rules fire: thresholds and severities shift around Standard. */
+export type RiskPosture = 'Conservative' | 'Standard' | 'Aggressive';
+
+/** A recommendation that fired on consecutive days of a backtest. */
+export interface BacktestRun {
+  recommendation_id: string;
+  title: string;
+  category: string;
+  severity: Severity;
+  start: string;
+  end: string;
+  days: number;
+}
+
+export interface BacktestReport {
+  from: string;
+  to: string;
+  days_evaluated: number;
+  days_without_data: number;
+  runs: BacktestRun[];
+}
+
+export interface LogTail {
+  /** Newest log file name, or null if nothing has been written yet. */
+  file: string | null;
+  lines: string[];
+}
+
+export interface Location {
+  name: string;
+  latitude: number;
+  longitude: number;
+  /** USDA hardiness zone, looked up for US ZIP searches */
+  usda_zone?: string | null;
+}
+
+// Activity log types
+
+export type EventKind =
+  | 'DataRefresh'
+  | 'RuleEvaluation'
+  | 'ApplicationAdded'
+  | 'ApplicationUpdated'
+  | 'ApplicationDeleted'
+  | 'DataSourceError'
+  | 'RequestFailed'
+  | 'Maintenance'
+  | 'DigestSent'
+  | 'MissedWindow';
+
+export type EventLevel = 'Info' | 'Warning' | 'Error';
+
+export interface ActivityEvent {
+  id: number | null;
+  occurred_at: string;
+  kind: EventKind;
+  level: EventLevel;
+  message: string;
+  details: string | null;
+}
+
+export const EVENT_KIND_LABELS: Record<EventKind, string> = {
+  DataRefresh: 'Data Refresh',
+  RuleEvaluation: 'Rule Evaluation',
+  ApplicationAdded: 'Application Added',
+  ApplicationUpdated: 'Application Updated',
+  ApplicationDeleted: 'Application Deleted',
+  DataSourceError: 'Data Source Error',
+  RequestFailed: 'Request Failed',
+  Maintenance: 'Maintenance',
+  DigestSent: 'Digest Sent',
+  MissedWindow: 'Missed Window',
+};
+
+export const EVENT_LEVEL_COLORS: Record<EventLevel, string> = {
+  Info: '#3b82f6',
+  Warning: '#eab308',
+  Error: '#ef4444',
+};
+
+// Annual review types
+
+export type ApplicationOutcome = 'Effective' | 'Partial' | 'Ineffective' | 'Unsure';
+
+export type IssueLevel = 'None' | 'Minor' | 'Moderate' | 'Severe';
+
+export type AdjustmentKind = 'Threshold' | 'Program';
+
+export interface ApplicationReview {
+  application_id: number;
+  application_type: ApplicationType;
+  application_date: string;
+  product_name: string | null;
+  outcome: ApplicationOutcome;
+}
+
+export interface ReviewObservations {
+  disease: IssueLevel;
+  weeds: IssueLevel;
+  thin_areas: IssueLevel;
+  notes: string | null;
+}
+
+export interface ReviewAdjustment {
+  key: string;
+  kind: AdjustmentKind;
+  title: string;
+  detail: string;
+  current_value: number | null;
+  suggested_value: number | null;
+  accepted: boolean;
+}
+
+/** Result of importing an anonymized season bundle. */
+export interface SeasonImportSummary {
+  year: number;
+  imported: number;
+  skipped: number;
+  grass_type_mismatch: boolean;
+}
+
+export interface AnnualReview {
+  id: number | null;
+  lawn_profile_id: number;
+  year: number;
+  application_reviews: ApplicationReview[];
+  observations: ReviewObservations;
+  adjustments: ReviewAdjustment[];
+  completed_at: string | null;
+  created_at: string;
+  updated_at: string;
+}
+
+// Efficacy review types
+
+export type TimingVerdict = 'Early' | 'OnTime' | 'Late' | 'Unknown';
+
+export interface EfficacyDay {
+  date: string;
+  soil_temp_10_f: number | null;
+  high_temp_f: number | null;
+  low_temp_f: number | null;
+  precipitation_mm: number | null;
+  humid_hours: number;
+  disease_risk: boolean;
+}
+
+export interface PreEmergentTiming {
+  soil_temp_at_application_f: number | null;
+  soil_50f_date: string | null;
+  soil_55f_date: string | null;
+  soil_60f_date: string | null;
+  /** Days before the 55°F crossing; negative when applied after it. */
+  lead_days: number | null;
+  residual_until: string;
+}
+
+export interface FungicideCoverage {
+  protected_until: string;
+  risk_days_before: number;
+  risk_days_covered: number;
+  risk_days_after: number;
+}
+
+export interface EfficacyEntry {
+  application_id: number | null;
+  application_type: ApplicationType;
+  product_name: string | null;
+  date: string;
+  verdict: TimingVerdict | null;
+  summary: string;
+  pre_emergent?: PreEmergentTiming;
+  fungicide?: FungicideCoverage;
+  observations: { date: string; category: ObservationCategory; notes: string }[];
+}
+
+export interface EfficacyReport {
+  year: number;
+  days: EfficacyDay[];
+  entries: EfficacyEntry[];
+  disease_risk_days: number;
+}
+
 // Display helpers
 
+/** Enum values in the order pickers list them. */
+export const APPLICATION_TYPES: ApplicationType[] = [
+  'PreEmergent',
+  'PostEmergent',
+  'Fertilizer',
+  'Fungicide',
+  'Insecticide',
+  'GrubControl',
+  'Overseed',
+  'Aeration',
+  'Dethatching',
+  'Lime',
+  'Sulfur',
+  'Wetting',
+  'Mowing',
+  'Scouting',
+  'Irrigation',
+  'Other',
+  'Pruning',
+  'PlantFertilizer',
+  'Mulching',
+  'Deadheading',
+  'WinterProtection',
+];
+
 export const APPLICATION_TYPE_LABELS: Record<ApplicationType, string> = {
   PreEmergent: 'Pre-Emergent',
   PostEmergent: 'Post-Emergent',
@@ -577,6 +1378,8 @@ export const APPLICATION_TYPE_LABELS: Record<ApplicationType, string> = {
   Sulfur: 'Sulfur',
   Wetting: 'Wetting Agent',
   Mowing: 'Mowing',
+  Scouting: 'Scouting',
+  Irrigation: 'Irrigation',
   Other: 'Other',
   Pruning: 'Pruning',
   PlantFertilizer: 'Plant Fertilizer',
@@ -599,6 +1402,8 @@ export const APPLICATION_TYPE_COLORS: Record<ApplicationType, string> = {
   Sulfur: '#facc15',
   Wetting: '#67e8f9',
   Mowing: '#16a34a',
+  Scouting: '#f97316',
+  Irrigation: '#0ea5e9',
   Other: '#9ca3af',
   Pruning: '#84cc16',
   PlantFertilizer: '#65a30d',
@@ -651,6 +1456,17 @@ export const SEVERITY_SYMBOLS: Record<Severity, string> = {
   Critical: '!',
 };
 
+export const GRASS_TYPES: GrassType[] = [
+  'KentuckyBluegrass',
+  'TallFescue',
+  'PerennialRyegrass',
+  'FineFescue',
+  'Bermuda',
+  'Zoysia',
+  'StAugustine',
+  'Mixed',
+];
+
 export const GRASS_TYPE_LABELS: Record<GrassType, string> = {
   KentuckyBluegrass: 'Kentucky Bluegrass',
   TallFescue: 'Tall Fescue',
@@ -661,3 +1477,35 @@ export const GRASS_TYPE_LABELS: Record<GrassType, string> = {
   StAugustine: 'St. Augustine',
   Mixed: 'Mixed',
 };
+
+export const SOIL_TYPES: SoilType[] = [
+  'Clay',
+  'Loam',
+  'Sandy',
+  'SiltLoam',
+  'ClayLoam',
+  'SandyLoam',
+];
+
+export const SOIL_TYPE_LABELS: Record<SoilType, string> = {
+  Clay: 'Clay',
+  Loam: 'Loam',
+  Sandy: 'Sandy',
+  SiltLoam: 'Silt Loam',
+  ClayLoam: 'Clay Loam',
+  SandyLoam: 'Sandy Loam',
+};
+
+export const IRRIGATION_TYPES: IrrigationType[] = ['InGround', 'Hose', 'None'];
+
+export const IRRIGATION_TYPE_LABELS: Record<IrrigationType, string> = {
+  InGround: 'In-Ground',
+  Hose: 'Hose/Sprinkler',
+  None: 'None',
+};
+
+export const SUN_EXPOSURE_LABELS: Record<SunExposure, string> = {
+  FullSun: 'Full Sun',
+  PartShade: 'Part Shade',
+  FullShade: 'Full Shade',
+};
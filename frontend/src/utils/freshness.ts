@@ -0,0 +1,24 @@
+import type { Freshness } from '../types';
+
+export type FreshnessMetric = Exclude<keyof Freshness, 'max_age_hours'>;
+
+/**
+ * Observation time of `metric` when it's older than the server's
+ * `STALE_DATA_HOURS` (rules ignore it), else null.
+ */
+export function staleSince(
+  freshness: Freshness | undefined,
+  metric: FreshnessMetric,
+  now = Date.now(),
+): string | null {
+  const observed = freshness?.[metric];
+  const maxHours = freshness?.max_age_hours;
+  if (!observed || maxHours == null) return null;
+  return now - new Date(observed).getTime() > maxHours * 3_600_000 ? observed : null;
+}
+
+/** "9h ago", "2d ago". */
+export function formatAge(iso: string, now = Date.now()): string {
+  const hours = Math.max(0, (now - new Date(iso).getTime()) / 3_600_000);
+  return hours < 48 ? `${Math.round(hours)}h ago` : `${Math.round(hours / 24)}d ago`;
+}
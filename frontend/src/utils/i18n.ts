@@ -0,0 +1,21 @@
+import { getLocale } from '../api/client';
+
+// UI labels for the server's LOCALE, keyed by their English text. Anything
+// missing from the catalog renders in English.
+let messages: Record<string, string> = {};
+
+/** Fetch the catalog once before the app renders. Failures leave English. */
+export async function loadLocale(): Promise<void> {
+  try {
+    const catalog = await getLocale();
+    messages = catalog.messages;
+    document.documentElement.lang = catalog.locale;
+  } catch {
+    messages = {};
+  }
+}
+
+/** Translate a UI label. */
+export function t(text: string): string {
+  return messages[text] ?? text;
+}
@@ -0,0 +1,4 @@
+/** Strip the trailing "Rule" and split CamelCase for display. */
+export function ruleLabel(name: string): string {
+  return name.replace(/Rule$/, '').replace(/([a-z])([A-Z])/g, '$1 $2');
+}
@@ -0,0 +1,26 @@
+import type { SoilTempAnomaly } from '../types';
+
+/** "+4.2°F vs normal" */
+export function formatAnomaly(anomaly: SoilTempAnomaly): string {
+  const sign = anomaly.anomaly_f >= 0 ? '+' : '';
+  return `${sign}${anomaly.anomaly_f.toFixed(1)}°F vs normal`;
+}
+
+/** The normal behind the anomaly and how far it moves the spring windows. */
+export function anomalyTitle(anomaly: SoilTempAnomaly): string {
+  const years = anomaly.years === 1 ? 'year' : 'years';
+  const normal =
+    `Normal for these days: ${anomaly.normal_f.toFixed(1)}°F ` +
+    `over ${anomaly.years} prior ${years}.`;
+  if (anomaly.lead_days === 0) return `${normal} Spring windows follow the calendar.`;
+  const days = Math.abs(anomaly.lead_days);
+  const when = anomaly.lead_days > 0 ? 'early' : 'late';
+  return `${normal} Spring windows open ${days} day${days === 1 ? '' : 's'} ${when}.`;
+}
+
+/** Warm anomalies in red, cold in blue, near-normal in gray. */
+export function anomalyColor(anomaly: SoilTempAnomaly): string {
+  if (anomaly.lead_days > 0) return '#c05621';
+  if (anomaly.lead_days < 0) return '#2b6cb0';
+  return '#718096';
+}
@@ -0,0 +1,70 @@
+import { listApplicationTypes } from '../api/client';
+import type { ApplicationType, CustomApplicationType } from '../types';
+import { APPLICATION_TYPE_COLORS, APPLICATION_TYPE_LABELS, APPLICATION_TYPES } from '../types';
+
+// Built-in application types merged with the user's custom ones (mirrors the
+// backend TypeRegistry). Custom types are loaded once before the app renders
+// and replaced when they're edited in Settings.
+let custom: CustomApplicationType[] = [];
+
+/** Select value for a custom type; built-ins use their enum name. */
+export type TypeChoice = ApplicationType | `custom:${number}`;
+
+/** Fetch custom types once before the app renders. Failures leave built-ins only. */
+export async function loadTypeRegistry(): Promise<void> {
+  try {
+    custom = (await listApplicationTypes()).custom;
+  } catch {
+    custom = [];
+  }
+}
+
+export function customTypes(): CustomApplicationType[] {
+  return custom;
+}
+
+export function setCustomTypes(types: CustomApplicationType[]): void {
+  custom = [...types].sort((a, b) => a.name.localeCompare(b.name));
+}
+
+type Typed = { application_type: ApplicationType; custom_type_id?: number | null };
+
+function customOf(app: Typed): CustomApplicationType | undefined {
+  return app.custom_type_id != null
+    ? custom.find((c) => c.id === app.custom_type_id)
+    : undefined;
+}
+
+/** Custom type name, else the built-in label. */
+export function typeLabel(app: Typed): string {
+  return customOf(app)?.name ?? APPLICATION_TYPE_LABELS[app.application_type];
+}
+
+/** Custom type color, else the built-in color. */
+export function typeColor(app: Typed): string {
+  return customOf(app)?.color ?? APPLICATION_TYPE_COLORS[app.application_type];
+}
+
+/** Every built-in and custom type, for selects. */
+export function typeChoices(): { options: TypeChoice[]; labels: Record<TypeChoice, string> } {
+  const options: TypeChoice[] = [...APPLICATION_TYPES];
+  const labels = { ...APPLICATION_TYPE_LABELS } as Record<TypeChoice, string>;
+  for (const c of custom) {
+    if (c.id == null) continue;
+    options.push(`custom:${c.id}`);
+    labels[`custom:${c.id}`] = c.name;
+  }
+  return { options, labels };
+}
+
+export function toChoice(app: Typed): TypeChoice {
+  return customOf(app) ? `custom:${app.custom_type_id}` : app.application_type;
+}
+
+/** The built-in type to send and the custom type id, if any. */
+export function fromChoice(choice: TypeChoice): Typed {
+  if (!choice.startsWith('custom:')) return { application_type: choice as ApplicationType };
+  const id = Number(choice.slice('custom:'.length));
+  const c = custom.find((t) => t.id === id);
+  return { application_type: c?.base_type ?? 'Other', custom_type_id: id };
+}
@@ -0,0 +1,37 @@
+import { getUiState, saveUiState } from '../api/client';
+import type { UiState } from '../types';
+
+// Where the user left the app (last screen, filters, selections), kept in the
+// backend settings table so it survives restarts and other browsers.
+let state: UiState = {};
+let saveTimer: ReturnType<typeof setTimeout> | undefined;
+
+/** Wait this long after the last change before saving, so clicks batch. */
+const SAVE_DELAY_MS = 500;
+
+/** Fetch the saved state once before the app renders. Failures start fresh. */
+export async function loadUiState(): Promise<void> {
+  try {
+    state = await getUiState();
+  } catch {
+    state = {};
+  }
+}
+
+/** The state as loaded, plus any changes remembered since. */
+export function uiState(): UiState {
+  return state;
+}
+
+/** Merge `patch` into the saved state. Writes are debounced; failures are ignored. */
+export function rememberUi(patch: UiState): void {
+  const changed = (Object.keys(patch) as (keyof UiState)[]).some(
+    (key) => (patch[key] ?? null) !== (state[key] ?? null)
+  );
+  if (!changed) return;
+  state = { ...state, ...patch };
+  clearTimeout(saveTimer);
+  saveTimer = setTimeout(() => {
+    saveUiState(state).catch(() => {});
+  }, SAVE_DELAY_MS);
+}
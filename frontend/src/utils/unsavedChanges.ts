@@ -0,0 +1,29 @@
+import { t } from './i18n';
+
+/** Forms currently holding edits that haven't been saved. */
+let dirtyForms = 0;
+
+function onBeforeUnload(e: BeforeUnloadEvent) {
+  e.preventDefault();
+}
+
+/** Count a form as dirty until the returned function is called. */
+export function markDirty(): () => void {
+  if (dirtyForms === 0) window.addEventListener('beforeunload', onBeforeUnload);
+  dirtyForms += 1;
+  let released = false;
+  return () => {
+    if (released) return;
+    released = true;
+    dirtyForms -= 1;
+    if (dirtyForms === 0) window.removeEventListener('beforeunload', onBeforeUnload);
+  };
+}
+
+/**
+ * Ask before in-app navigation would drop unsaved edits. True when it's fine
+ * to go ahead (nothing is dirty, or the user agreed to discard).
+ */
+export function confirmDiscard(): boolean {
+  return dirtyForms === 0 || window.confirm(t('You have unsaved changes. Leave without saving?'));
+}